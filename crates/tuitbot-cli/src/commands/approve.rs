@@ -7,6 +7,8 @@
 //!   --list          List pending items
 //!   --approve <ID>  Approve a specific item
 //!   --reject <ID>   Reject a specific item
+//!   --delete <ID>   Delete a specific item (restorable)
+//!   --restore <ID>  Restore a deleted or rejected item
 //!   --approve-all   Approve all pending items
 
 use std::io::{self, BufRead, Write};
@@ -59,8 +61,12 @@ pub async fn execute(
     args: ApproveArgs,
     output: OutputFormat,
 ) -> anyhow::Result<()> {
-    let is_non_interactive =
-        args.list || args.approve.is_some() || args.reject.is_some() || args.approve_all;
+    let is_non_interactive = args.list
+        || args.approve.is_some()
+        || args.reject.is_some()
+        || args.delete.is_some()
+        || args.restore.is_some()
+        || args.approve_all;
 
     if !config.approval_mode && !is_non_interactive {
         eprintln!("Approval mode is not enabled.");
@@ -134,6 +140,43 @@ pub async fn execute(
         return Ok(());
     }
 
+    if let Some(id) = args.delete {
+        let deleted = storage::approval_queue::soft_delete(&pool, id).await?;
+        pool.close().await;
+        if !deleted {
+            anyhow::bail!("Item #{id} was not found or is being posted.");
+        }
+        if output.is_json() {
+            let result = ApproveActionResult {
+                id,
+                status: "deleted".to_string(),
+            };
+            write_stdout(&serde_json::to_string(&result)?)?;
+        } else {
+            eprintln!("Deleted item #{id}. Restore it with `tuitbot approve --restore {id}`.");
+        }
+        return Ok(());
+    }
+
+    if let Some(id) = args.restore {
+        let restored = storage::approval_queue::restore(&pool, id).await?;
+        let item = storage::approval_queue::get_by_id(&pool, id).await?;
+        pool.close().await;
+        let Some(item) = item.filter(|_| restored) else {
+            anyhow::bail!("Item #{id} is not deleted or rejected.");
+        };
+        if output.is_json() {
+            let result = ApproveActionResult {
+                id,
+                status: item.status,
+            };
+            write_stdout(&serde_json::to_string(&result)?)?;
+        } else {
+            eprintln!("Restored item #{id} ({}).", item.status);
+        }
+        return Ok(());
+    }
+
     if args.approve_all {
         let pending = storage::approval_queue::get_pending(&pool).await?;
         let mut results = Vec::new();
@@ -0,0 +1,83 @@
+//! Implementation of the `tuitbot archive` command.
+//!
+//! `tuitbot archive` retires the bot while keeping its history browsable:
+//! it marks the install archived and compacts the database. Archived
+//! installs refuse `tuitbot run`/`tick`, and `tuitbot-server` serves them
+//! read-only. `tuitbot archive --undo` clears the flag.
+
+use serde::Serialize;
+use tuitbot_core::config::Config;
+use tuitbot_core::storage;
+use tuitbot_core::storage::archive::{ArchiveState, CompactStats};
+
+use super::{ArchiveArgs, OutputFormat};
+use crate::output::write_stdout;
+
+#[derive(Serialize)]
+struct ArchiveOutput {
+    #[serde(flatten)]
+    state: ArchiveState,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    compaction: Option<CompactStats>,
+}
+
+/// Execute the `tuitbot archive` command.
+pub async fn execute(
+    config: &Config,
+    args: ArchiveArgs,
+    output: OutputFormat,
+) -> anyhow::Result<()> {
+    let pool = storage::init_db(&config.storage.db_path).await?;
+
+    let result = if args.status {
+        storage::archive::get_archive_state(&pool)
+            .await
+            .map(|state| (state, None))
+    } else if args.undo {
+        storage::archive::set_archived(&pool, false, "cli")
+            .await
+            .map(|state| (state, None))
+    } else {
+        match storage::archive::set_archived(&pool, true, "cli").await {
+            Ok(state) => storage::archive::compact(&pool)
+                .await
+                .map(|stats| (state, Some(stats))),
+            Err(e) => Err(e),
+        }
+    };
+    pool.close().await;
+    let (state, compaction) = result?;
+
+    if output.is_json() {
+        write_stdout(&serde_json::to_string(&ArchiveOutput {
+            state,
+            compaction,
+        })?)?;
+    } else {
+        print_state(&state, compaction.as_ref());
+    }
+
+    Ok(())
+}
+
+fn print_state(state: &ArchiveState, compaction: Option<&CompactStats>) {
+    if state.archived {
+        eprintln!("Install archived — automation is disabled.");
+    } else {
+        eprintln!("Install is active — automation can run.");
+    }
+    if let Some(changed_at) = &state.changed_at {
+        eprintln!("  Changed: {changed_at}");
+    }
+    if let Some(stats) = compaction {
+        eprintln!(
+            "  Database compacted: {} -> {} bytes",
+            stats.bytes_before, stats.bytes_after
+        );
+    }
+    if state.archived {
+        eprintln!();
+        eprintln!("Run `tuitbot-server` to browse history read-only.");
+        eprintln!("Run `tuitbot archive --undo` to resume automation.");
+    }
+}
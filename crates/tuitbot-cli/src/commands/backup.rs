@@ -2,17 +2,32 @@
 
 use std::path::PathBuf;
 
+use anyhow::Context;
+use tuitbot_core::config::{Config, RemoteBackupConfig};
 use tuitbot_core::startup::data_dir;
 use tuitbot_core::storage;
+use tuitbot_core::storage::remote_backup::{self, S3Client};
 
-use super::BackupArgs;
+use super::{BackupArgs, BackupSubcommand};
 
 /// Execute the `tuitbot backup` command.
-pub async fn execute(args: BackupArgs) -> anyhow::Result<()> {
+pub async fn execute(args: BackupArgs, config_path: &str) -> anyhow::Result<()> {
     let data = data_dir();
     let db_path = data.join("tuitbot.db");
 
+    if let Some(BackupSubcommand::Restore {
+        from,
+        force,
+        validate_only,
+    }) = args.command
+    {
+        return restore(&from, force, validate_only, config_path).await;
+    }
+
     if args.list {
+        if args.remote {
+            return list_remote_backups(&remote_config(config_path)?).await;
+        }
         return list_backups(&data);
     }
 
@@ -37,6 +52,22 @@ pub async fn execute(args: BackupArgs) -> anyhow::Result<()> {
     // Open a read-only pool to the existing DB for VACUUM INTO.
     let pool = storage::init_db(&db_path.to_string_lossy()).await?;
 
+    if args.remote {
+        let remote = remote_config(config_path)?;
+        eprintln!("Uploading backup to bucket {}...", remote.bucket);
+        let result = remote_backup::upload_backup(&pool, &remote).await;
+        pool.close().await;
+        let result = result?;
+        eprintln!("Remote backup uploaded and verified:");
+        eprintln!("  URI: {}", result.uri);
+        eprintln!("  Size: {} bytes (gzip)", result.size_bytes);
+        eprintln!("  SHA-256: {}", result.sha256);
+        if result.pruned > 0 {
+            eprintln!("  Pruned {} old remote backup(s)", result.pruned);
+        }
+        return Ok(());
+    }
+
     eprintln!("Creating backup...");
     let result = storage::backup::create_backup(&pool, &backup_dir).await?;
     pool.close().await;
@@ -81,3 +112,60 @@ fn prune_backups(data_dir: &std::path::Path, keep: usize) -> anyhow::Result<()>
 
     Ok(())
 }
+
+/// `[remote_backup]` from the config file, with credentials required.
+fn remote_config(config_path: &str) -> anyhow::Result<RemoteBackupConfig> {
+    let config = Config::load(Some(config_path)).context("Failed to load configuration")?;
+    let remote = config.remote_backup;
+    if remote.bucket.trim().is_empty() || remote.access_key_id.trim().is_empty() {
+        anyhow::bail!("Set remote_backup.bucket and remote_backup.access_key_id in your config.");
+    }
+    if remote.secret_access_key.as_deref().unwrap_or("").is_empty() {
+        anyhow::bail!(
+            "Set remote_backup.secret_access_key or TUITBOT_REMOTE_BACKUP__SECRET_ACCESS_KEY."
+        );
+    }
+    Ok(remote)
+}
+
+async fn list_remote_backups(remote: &RemoteBackupConfig) -> anyhow::Result<()> {
+    let client = S3Client::new(remote);
+    let backups = remote_backup::list_remote_backups(&client, remote).await?;
+
+    if backups.is_empty() {
+        eprintln!("No remote backups found in bucket {}", remote.bucket);
+        return Ok(());
+    }
+
+    eprintln!("Remote backups in bucket {}:", remote.bucket);
+    for backup in &backups {
+        let modified = backup.last_modified.as_deref().unwrap_or("unknown");
+        let size_kb = backup.size_bytes / 1024;
+        eprintln!(
+            "  s3://{}/{} ({modified}) — {size_kb} KB",
+            remote.bucket, backup.key
+        );
+    }
+    eprintln!("\nTotal: {} backup(s)", backups.len());
+
+    Ok(())
+}
+
+/// `tuitbot backup restore --from <path|s3://bucket/key>`.
+async fn restore(
+    from: &str,
+    force: bool,
+    validate_only: bool,
+    config_path: &str,
+) -> anyhow::Result<()> {
+    if !from.starts_with("s3://") {
+        return super::restore::restore_file(&PathBuf::from(from), force, validate_only).await;
+    }
+
+    let remote = remote_config(config_path)?;
+    let download_dir = data_dir().join("backups");
+    eprintln!("Downloading {from}...");
+    let path = remote_backup::download_backup(&remote, from, &download_dir).await?;
+    eprintln!("  Checksum verified, saved to {}", path.display());
+    super::restore::restore_file(&path, force, validate_only).await
+}
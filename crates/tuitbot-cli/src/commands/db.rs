@@ -0,0 +1,64 @@
+//! Implementation of the `tuitbot db` command.
+//!
+//! `tuitbot db slow-queries` reports statements recorded in the slow-query
+//! log, grouped by statement kind and table, so frequent slow reads can be
+//! matched with a missing index. Recording is enabled with
+//! `storage.record_slow_queries`.
+
+use tuitbot_core::config::Config;
+use tuitbot_core::storage;
+use tuitbot_core::storage::query_log::SlowQueryStat;
+
+use super::{DbArgs, DbSubcommand, OutputFormat};
+use crate::output::write_stdout;
+
+/// Execute the `tuitbot db` command.
+pub async fn execute(config: &Config, args: DbArgs, output: OutputFormat) -> anyhow::Result<()> {
+    let pool = storage::init_db(&config.storage.db_path).await?;
+    let result = match args.command {
+        DbSubcommand::SlowQueries { hours, limit } => {
+            storage::query_log::get_slow_query_report(&pool, hours, limit).await
+        }
+    };
+    pool.close().await;
+    let stats = result?;
+
+    if output.is_json() {
+        write_stdout(&serde_json::to_string(&stats)?)?;
+        return Ok(());
+    }
+
+    if stats.is_empty() {
+        if config.storage.record_slow_queries {
+            eprintln!(
+                "No queries slower than {}ms were recorded.",
+                config.storage.slow_query_ms
+            );
+        } else {
+            eprintln!(
+                "No slow queries recorded. Set storage.record_slow_queries = true to record them."
+            );
+        }
+        return Ok(());
+    }
+
+    print_report(&stats);
+    Ok(())
+}
+
+fn print_report(stats: &[SlowQueryStat]) {
+    eprintln!(
+        "{:<36} {:>6} {:>9} {:>9} {:>9}",
+        "query", "count", "avg ms", "max ms", "avg rows"
+    );
+    for stat in stats {
+        eprintln!(
+            "{:<36} {:>6} {:>9.0} {:>9.0} {:>9.0}",
+            stat.name, stat.occurrences, stat.avg_ms, stat.max_ms, stat.avg_rows_returned
+        );
+    }
+    eprintln!();
+    eprintln!("Slowest by total time first. Latest statement for the top query:");
+    eprintln!("  {}", stats[0].statement.replace('\n', " "));
+    eprintln!("Repeated slow selects on one table usually need an index on the filtered columns.");
+}
@@ -0,0 +1,67 @@
+//! Implementation of the `tuitbot draft-only` command.
+//!
+//! `tuitbot draft-only` turns on draft-only mode: every post, reply, and
+//! thread is routed to the approval queue and approved items are held
+//! instead of posted, until `tuitbot draft-only --off` is run. The flag is
+//! stored in the database, so running processes pick it up without a
+//! restart.
+
+use tuitbot_core::config::Config;
+use tuitbot_core::storage;
+use tuitbot_core::storage::draft_only::DraftOnlyState;
+
+use super::{DraftOnlyArgs, OutputFormat};
+use crate::output::write_stdout;
+
+/// Execute the `tuitbot draft-only` command.
+pub async fn execute(
+    config: &Config,
+    args: DraftOnlyArgs,
+    output: OutputFormat,
+) -> anyhow::Result<()> {
+    let pool = storage::init_db(&config.storage.db_path).await?;
+
+    let result = if args.status {
+        storage::draft_only::get_draft_only(&pool).await
+    } else if args.off {
+        storage::draft_only::disable_draft_only(&pool, "cli").await
+    } else {
+        let reason = args
+            .reason
+            .as_deref()
+            .map(str::trim)
+            .filter(|r| !r.is_empty());
+        storage::draft_only::enable_draft_only(&pool, reason, "cli").await
+    };
+    pool.close().await;
+    let state = result?;
+
+    if output.is_json() {
+        write_stdout(&serde_json::to_string(&state)?)?;
+    } else {
+        print_state(&state);
+    }
+
+    Ok(())
+}
+
+fn print_state(state: &DraftOnlyState) {
+    if state.active {
+        eprintln!("DRAFT-ONLY MODE ON — posts are queued for review and approved items are held.");
+        if let Some(reason) = &state.reason {
+            eprintln!("  Reason:  {reason}");
+        }
+    } else {
+        eprintln!("Draft-only mode off — posting is enabled.");
+    }
+    if let Some(source) = &state.source {
+        eprintln!("  Source:  {source}");
+    }
+    if let Some(changed_at) = &state.changed_at {
+        eprintln!("  Changed: {changed_at}");
+    }
+    if state.active {
+        eprintln!();
+        eprintln!("Run `tuitbot draft-only --off` to resume posting.");
+    }
+}
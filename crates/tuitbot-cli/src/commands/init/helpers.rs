@@ -1,5 +1,7 @@
 //! Pure helper functions for string manipulation and parsing.
 
+use std::collections::BTreeMap;
+
 /// Convert a trimmed string to `Some` or `None` if empty.
 pub(super) fn non_empty(s: String) -> Option<String> {
     let trimmed = s.trim().to_string();
@@ -44,3 +46,21 @@ pub(super) fn format_toml_array(items: &[String]) -> String {
         .collect();
     format!("[{}]", inner.join(", "))
 }
+
+/// Format a weight map as a TOML inline table: `{ a = 1, b = 2 }`.
+pub(super) fn format_toml_weights(weights: &BTreeMap<String, u32>) -> String {
+    let inner: Vec<String> = weights
+        .iter()
+        .map(|(key, weight)| {
+            if key
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+            {
+                format!("{key} = {weight}")
+            } else {
+                format!("\"{}\" = {weight}", escape_toml(key))
+            }
+        })
+        .collect();
+    format!("{{ {} }}", inner.join(", "))
+}
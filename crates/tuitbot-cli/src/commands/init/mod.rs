@@ -2,16 +2,19 @@
 ///
 /// Walks new users through X API credentials, business profile, and LLM
 /// provider configuration in eight guided steps. Falls back to copying
-/// `config.example.toml` with `--non-interactive`.
+/// `config.example.toml` with `--non-interactive`. `--template <name>`
+/// pre-fills the business profile from a starter template.
 ///
 /// After writing the config the wizard offers to continue seamlessly
 /// through `auth → test → preview` so the user doesn't have to remember
 /// three separate commands.
 mod display;
 mod helpers;
+mod ollama;
 mod prompts;
 mod render;
 mod steps;
+mod templates;
 mod wizard;
 
 #[cfg(test)]
@@ -19,28 +22,33 @@ mod tests;
 
 use std::fs;
 use std::io::IsTerminal;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::time::Instant;
 
 use anyhow::{bail, Context, Result};
 use dialoguer::Confirm;
-use tuitbot_core::config::{Config, LlmConfig};
+use tuitbot_core::config::{history, Config, LlmConfig};
 use tuitbot_core::llm::factory::create_provider;
 use tuitbot_core::startup::data_dir;
+use tuitbot_core::x_api::tier::ApiTier;
 
 use display::{
     print_llm_validation_fail, print_llm_validation_ok, print_quickstart_banner,
     print_quickstart_summary, print_remaining_steps, print_summary, print_welcome_banner,
 };
+use ollama::select_ollama_model;
 use render::render_config_toml;
 use steps::{
     step_approval_mode, step_brand_voice, step_business_profile, step_llm_provider, step_persona,
-    step_quickstart, step_schedule, step_target_accounts, step_x_api,
+    step_quickstart, step_schedule, step_target_accounts, step_template, step_x_api,
 };
+use templates::StarterTemplate;
 use wizard::WizardResult;
 
 use super::{auth, test, tick, OutputFormat, TickArgs};
 
+pub(crate) use templates::TEMPLATE_NAMES;
+
 // Re-export prompt functions used by the upgrade command.
 pub(crate) use prompts::{
     prompt_approval_mode, prompt_enhanced_limits, prompt_persona, prompt_target_accounts,
@@ -50,7 +58,12 @@ pub(crate) use prompts::{
 const EXAMPLE_CONFIG: &str = include_str!("../../../config.example.toml");
 
 /// Run the init command.
-pub async fn execute(force: bool, non_interactive: bool, advanced: bool) -> Result<()> {
+pub async fn execute(
+    force: bool,
+    non_interactive: bool,
+    advanced: bool,
+    template: Option<&str>,
+) -> Result<()> {
     let dir = data_dir();
     let config_path: PathBuf = dir.join("config.toml");
 
@@ -64,7 +77,8 @@ pub async fn execute(force: bool, non_interactive: bool, advanced: bool) -> Resu
     }
 
     if non_interactive {
-        return write_template(&dir, &config_path);
+        let starter = template.map(StarterTemplate::load).transpose()?;
+        return write_template(&dir, &config_path, starter.as_ref());
     }
 
     // Guard: must be a real terminal for interactive mode.
@@ -76,16 +90,25 @@ pub async fn execute(force: bool, non_interactive: bool, advanced: bool) -> Resu
     }
 
     if advanced {
-        run_advanced_wizard(&dir, &config_path).await
+        run_advanced_wizard(&dir, &config_path, template).await
     } else {
-        run_quickstart(&dir, &config_path).await
+        run_quickstart(&dir, &config_path, template).await
     }
 }
 
-/// Non-interactive path: copy the embedded template.
-fn write_template(dir: &PathBuf, config_path: &PathBuf) -> Result<()> {
+/// Non-interactive path: copy the embedded template, with the business
+/// profile from a starter template when one is given.
+fn write_template(
+    dir: &PathBuf,
+    config_path: &Path,
+    starter: Option<&StarterTemplate>,
+) -> Result<()> {
+    let contents = match starter {
+        Some(starter) => starter.patch_config(EXAMPLE_CONFIG)?,
+        None => EXAMPLE_CONFIG.to_string(),
+    };
     fs::create_dir_all(dir)?;
-    fs::write(config_path, EXAMPLE_CONFIG)?;
+    history::write_config(config_path, &contents, "init")?;
 
     eprintln!("Created {}\n", config_path.display());
     eprintln!("Next steps:");
@@ -107,6 +130,7 @@ async fn validate_llm(result: &WizardResult) {
         api_key: result.llm_api_key.clone(),
         model: result.llm_model.clone(),
         base_url: result.llm_base_url.clone(),
+        ..Default::default()
     };
 
     let provider = match create_provider(&llm_config) {
@@ -132,15 +156,34 @@ async fn validate_llm(result: &WizardResult) {
     }
 }
 
+/// Flag risky settings in the config the wizard is about to write.
+fn print_lint_warnings(result: &WizardResult) {
+    let Ok(config) = toml::from_str::<Config>(&render_config_toml(result)) else {
+        return;
+    };
+    let warnings = config.lint();
+    if !warnings.is_empty() {
+        test::print_lint(&warnings);
+        eprintln!();
+    }
+}
+
 /// Quickstart path: 5 prompts → usable config → auth → test → preview.
-async fn run_quickstart(dir: &PathBuf, config_path: &PathBuf) -> Result<()> {
+async fn run_quickstart(dir: &PathBuf, config_path: &Path, template: Option<&str>) -> Result<()> {
     print_quickstart_banner();
 
-    let result = step_quickstart()?;
+    let starter = step_template(template)?;
+    let result = step_quickstart(starter.as_ref())?;
+    let result = select_ollama_model(result, false).await?;
+    let result = match &starter {
+        Some(starter) => starter.apply(result),
+        None => result,
+    };
 
     validate_llm(&result).await;
 
     print_quickstart_summary(&result);
+    print_lint_warnings(&result);
 
     let confirm = Confirm::new()
         .with_prompt("Save configuration?")
@@ -154,7 +197,7 @@ async fn run_quickstart(dir: &PathBuf, config_path: &PathBuf) -> Result<()> {
 
     fs::create_dir_all(dir)?;
     let toml = render_config_toml(&result);
-    fs::write(config_path, &toml)
+    history::write_config(config_path, &toml, "init")
         .with_context(|| format!("Failed to write {}", config_path.display()))?;
 
     eprintln!("\nWrote {}", config_path.display());
@@ -169,21 +212,32 @@ async fn run_quickstart(dir: &PathBuf, config_path: &PathBuf) -> Result<()> {
 }
 
 /// Advanced wizard: full 8-step setup with auth → test → preview chaining.
-async fn run_advanced_wizard(dir: &PathBuf, config_path: &PathBuf) -> Result<()> {
+async fn run_advanced_wizard(
+    dir: &PathBuf,
+    config_path: &Path,
+    template: Option<&str>,
+) -> Result<()> {
     print_welcome_banner();
 
+    let starter = step_template(template)?;
     let result = step_x_api()?;
-    let result = step_business_profile(result)?;
+    let result = step_business_profile(result, starter.as_ref())?;
     let result = step_brand_voice(result)?;
     let result = step_llm_provider(result)?;
+    let result = select_ollama_model(result, true).await?;
     let result = step_persona(result)?;
     let result = step_target_accounts(result)?;
     let result = step_approval_mode(result)?;
     let result = step_schedule(result)?;
+    let result = match &starter {
+        Some(starter) => starter.apply(result),
+        None => result,
+    };
 
     validate_llm(&result).await;
 
     print_summary(&result);
+    print_lint_warnings(&result);
 
     let confirm = Confirm::new()
         .with_prompt("Write this configuration?")
@@ -197,7 +251,7 @@ async fn run_advanced_wizard(dir: &PathBuf, config_path: &PathBuf) -> Result<()>
 
     fs::create_dir_all(dir)?;
     let toml = render_config_toml(&result);
-    fs::write(config_path, &toml)
+    history::write_config(config_path, &toml, "init")
         .with_context(|| format!("Failed to write {}", config_path.display()))?;
 
     eprintln!("\nWrote {}", config_path.display());
@@ -255,7 +309,7 @@ async fn chain_post_config(config: &Config, config_path_str: &str) -> Result<()>
         return Ok(());
     }
 
-    let all_passed = test::run_checks(config, config_path_str).await;
+    let all_passed = test::run_checks(config, config_path_str, ApiTier::Basic).await;
     if !all_passed {
         eprintln!("Fix the issues above, then:");
         print_remaining_steps(&[
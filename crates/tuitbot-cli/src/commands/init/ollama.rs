@@ -0,0 +1,76 @@
+/// Ollama model selection: offer the locally pulled models instead of a free-text prompt.
+use anyhow::Result;
+use console::Style;
+use dialoguer::{Input, Select};
+use tuitbot_core::llm::ollama::{self, OllamaModel};
+
+use super::wizard::WizardResult;
+
+/// Query the local Ollama instance and let the user pick a pulled model.
+///
+/// No-op for other providers. When Ollama isn't running or has no models,
+/// prints a hint and either prompts for a model name (`prompt_on_fallback`)
+/// or keeps the default.
+pub(super) async fn select_ollama_model(
+    prev: WizardResult,
+    prompt_on_fallback: bool,
+) -> Result<WizardResult> {
+    if prev.llm_provider != "ollama" {
+        return Ok(prev);
+    }
+
+    let yellow = Style::new().yellow();
+    let root = ollama::api_root(prev.llm_base_url.as_deref());
+
+    match ollama::list_models(prev.llm_base_url.as_deref()).await {
+        Ok(models) if !models.is_empty() => {
+            let model = pick_model(&models, &prev.llm_model)?;
+            return Ok(WizardResult {
+                llm_model: model,
+                ..prev
+            });
+        }
+        Ok(_) => eprintln!(
+            "{}",
+            yellow.apply_to(format!(
+                "  ⚠ Ollama has no models pulled. Run `ollama pull {}` before starting.",
+                prev.llm_model
+            ))
+        ),
+        Err(_) => eprintln!(
+            "{}",
+            yellow.apply_to(format!(
+                "  ⚠ Ollama is not running at {root}. Start it with `ollama serve`."
+            ))
+        ),
+    }
+
+    if !prompt_on_fallback {
+        return Ok(prev);
+    }
+
+    let model: String = Input::new()
+        .with_prompt("Model name (Enter to accept default)")
+        .default(prev.llm_model.clone())
+        .interact_text()?;
+    Ok(WizardResult {
+        llm_model: model.trim().to_string(),
+        ..prev
+    })
+}
+
+/// Show pulled models with size hints, preselecting the current default if pulled.
+fn pick_model(models: &[OllamaModel], current: &str) -> Result<String> {
+    let items: Vec<String> = models
+        .iter()
+        .map(|m| format!("{} ({})", m.name, m.size_label()))
+        .collect();
+    let default = models.iter().position(|m| m.matches(current)).unwrap_or(0);
+
+    let selection = Select::new()
+        .with_prompt("Ollama model")
+        .items(&items)
+        .default(default)
+        .interact()?;
+    Ok(models[selection].name.clone())
+}
@@ -1,5 +1,5 @@
 /// TOML config file rendering from wizard answers.
-use super::helpers::{escape_toml, format_toml_array};
+use super::helpers::{escape_toml, format_toml_array, format_toml_weights};
 use super::wizard::WizardResult;
 
 /// Render an optional TOML string field: either `key = "value"` or a commented-out placeholder.
@@ -84,6 +84,15 @@ pub(super) fn render_config_toml(r: &WizardResult) -> String {
         &r.content_pillars,
         "Your core topic here",
     );
+    let reply_archetype_weights_line = if r.reply_archetype_weights.is_empty() {
+        "# reply_archetype_weights = { ask_question = 30, agree_and_expand = 30, add_data = 20 }"
+            .to_string()
+    } else {
+        format!(
+            "reply_archetype_weights = {}",
+            format_toml_weights(&r.reply_archetype_weights)
+        )
+    };
 
     let targets_section = if r.target_accounts.is_empty() {
         "# --- Target Accounts ---\n\
@@ -154,6 +163,7 @@ competitor_keywords = []
 {persona_opinions_line}
 {persona_experiences_line}
 {content_pillars_line}
+{reply_archetype_weights_line}
 
 # --- Scoring Engine ---
 # Controls how tweets are scored for reply-worthiness (0-100 scale).
@@ -228,6 +238,7 @@ active_days = {active_days}
         persona_opinions_line = persona_opinions_line,
         persona_experiences_line = persona_experiences_line,
         content_pillars_line = content_pillars_line,
+        reply_archetype_weights_line = reply_archetype_weights_line,
         targets_section = targets_section,
         llm_provider = escape_toml(&r.llm_provider),
         api_key_line = api_key_line,
@@ -1,14 +1,50 @@
 /// Interactive wizard steps: quickstart (5 prompts) and advanced (8 steps).
+use std::collections::BTreeMap;
+
 use anyhow::Result;
 use dialoguer::{Confirm, Input, Select};
 
 use super::display::{print_step_header, print_step_subtitle, print_x_api_guide};
 use super::helpers::{capitalize, non_empty, parse_csv};
 use super::prompts;
+use super::templates::StarterTemplate;
 use super::wizard::WizardResult;
 
+/// Pick a starter template: the one named by `--template`, or ask.
+pub(super) fn step_template(name: Option<&str>) -> Result<Option<StarterTemplate>> {
+    if let Some(name) = name {
+        return StarterTemplate::load(name).map(Some);
+    }
+
+    let templates = StarterTemplate::all()?;
+    let mut items = vec!["None — start from a blank profile".to_string()];
+    items.extend(
+        templates
+            .iter()
+            .map(|t| format!("{} — {}", t.label, t.description)),
+    );
+    let selection = Select::new()
+        .with_prompt("Start from a template? (pre-fills keywords, topics, and tone)")
+        .items(&items)
+        .default(0)
+        .interact()?;
+    eprintln!();
+
+    Ok(selection
+        .checked_sub(1)
+        .map(|index| templates[index].clone()))
+}
+
+/// CSV default for a prompt pre-filled from a template.
+fn template_csv(
+    template: Option<&StarterTemplate>,
+    pick: fn(&StarterTemplate) -> &[String],
+) -> Option<String> {
+    template.map(|t| pick(t).join(", "))
+}
+
 /// Quickstart: collect 5 critical inputs, default everything else.
-pub(super) fn step_quickstart() -> Result<WizardResult> {
+pub(super) fn step_quickstart(template: Option<&StarterTemplate>) -> Result<WizardResult> {
     // Prompt 1: Product name (easy, builds momentum)
     let product_name: String = Input::new()
         .with_prompt("Product name")
@@ -22,7 +58,11 @@ pub(super) fn step_quickstart() -> Result<WizardResult> {
         .interact_text()?;
 
     // Prompt 2: Discovery keywords (CSV, at least 1)
-    let keywords_raw: String = Input::new()
+    let mut keywords_input = Input::new();
+    if let Some(default) = template_csv(template, |t| &t.product_keywords) {
+        keywords_input = keywords_input.default(default);
+    }
+    let keywords_raw: String = keywords_input
         .with_prompt("Discovery keywords (comma-separated)")
         .validate_with(|input: &String| -> std::result::Result<(), &str> {
             if parse_csv(input).is_empty() {
@@ -96,6 +136,7 @@ pub(super) fn step_quickstart() -> Result<WizardResult> {
         persona_opinions: vec![],
         persona_experiences: vec![],
         content_pillars: vec![],
+        reply_archetype_weights: BTreeMap::new(),
         target_accounts: vec![],
         approval_mode: true, // safe default
         timezone: "UTC".to_string(),
@@ -161,6 +202,7 @@ pub(super) fn step_x_api() -> Result<WizardResult> {
         persona_opinions: vec![],
         persona_experiences: vec![],
         content_pillars: vec![],
+        reply_archetype_weights: BTreeMap::new(),
         target_accounts: vec![],
         approval_mode: true,
         timezone: "UTC".to_string(),
@@ -183,7 +225,10 @@ pub(super) fn step_x_api() -> Result<WizardResult> {
 }
 
 /// Step 2/8: Business profile.
-pub(super) fn step_business_profile(prev: WizardResult) -> Result<WizardResult> {
+pub(super) fn step_business_profile(
+    prev: WizardResult,
+    template: Option<&StarterTemplate>,
+) -> Result<WizardResult> {
     print_step_header(2, "Business Profile");
 
     let product_name: String = Input::new()
@@ -206,11 +251,19 @@ pub(super) fn step_business_profile(prev: WizardResult) -> Result<WizardResult>
         .allow_empty(true)
         .interact_text()?;
 
-    let target_audience: String = Input::new()
+    let mut audience_input = Input::new();
+    if let Some(t) = template {
+        audience_input = audience_input.default(t.target_audience.clone());
+    }
+    let target_audience: String = audience_input
         .with_prompt("Target audience")
         .interact_text()?;
 
-    let keywords_raw: String = Input::new()
+    let mut keywords_input = Input::new();
+    if let Some(default) = template_csv(template, |t| &t.product_keywords) {
+        keywords_input = keywords_input.default(default);
+    }
+    let keywords_raw: String = keywords_input
         .with_prompt("Discovery keywords (comma-separated)")
         .validate_with(|input: &String| -> std::result::Result<(), &str> {
             if parse_csv(input).is_empty() {
@@ -221,7 +274,11 @@ pub(super) fn step_business_profile(prev: WizardResult) -> Result<WizardResult>
         })
         .interact_text()?;
 
-    let topics_raw: String = Input::new()
+    let mut topics_input = Input::new();
+    if let Some(default) = template_csv(template, |t| &t.industry_topics) {
+        topics_input = topics_input.default(default);
+    }
+    let topics_raw: String = topics_input
         .with_prompt("Content topics (comma-separated)")
         .validate_with(|input: &String| -> std::result::Result<(), &str> {
             if parse_csv(input).is_empty() {
@@ -317,10 +374,15 @@ pub(super) fn step_llm_provider(prev: WizardResult) -> Result<WizardResult> {
         _ => "",
     };
 
-    let model: String = Input::new()
-        .with_prompt("Model name (Enter to accept default)")
-        .default(default_model.to_string())
-        .interact_text()?;
+    // Ollama models are picked from the locally pulled list after the base URL is known.
+    let model: String = if provider == "ollama" {
+        default_model.to_string()
+    } else {
+        Input::new()
+            .with_prompt("Model name (Enter to accept default)")
+            .default(default_model.to_string())
+            .interact_text()?
+    };
 
     let base_url = if provider == "ollama" {
         let url: String = Input::new()
@@ -0,0 +1,138 @@
+/// Starter business-profile templates for `tuitbot init --template <name>`.
+///
+/// Each template is an embedded TOML file that pre-fills keywords, topics,
+/// content pillars, tone, and reply archetype weights for a common niche.
+/// Templates only fill fields the user left empty.
+use std::collections::BTreeMap;
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use toml_edit::{value, Array, DocumentMut, InlineTable};
+
+use super::wizard::WizardResult;
+
+/// Template names accepted by `--template`, in display order.
+pub(crate) const TEMPLATE_NAMES: &[&str] = &["saas", "newsletter", "devtool", "ecommerce"];
+
+const TEMPLATE_SOURCES: &[(&str, &str)] = &[
+    ("saas", include_str!("templates/saas.toml")),
+    ("newsletter", include_str!("templates/newsletter.toml")),
+    ("devtool", include_str!("templates/devtool.toml")),
+    ("ecommerce", include_str!("templates/ecommerce.toml")),
+];
+
+/// A starter business profile.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(super) struct StarterTemplate {
+    pub(super) label: String,
+    pub(super) description: String,
+    pub(super) target_audience: String,
+    pub(super) product_keywords: Vec<String>,
+    #[serde(default)]
+    pub(super) competitor_keywords: Vec<String>,
+    pub(super) industry_topics: Vec<String>,
+    pub(super) content_pillars: Vec<String>,
+    pub(super) brand_voice: Option<String>,
+    pub(super) reply_style: Option<String>,
+    pub(super) content_style: Option<String>,
+    #[serde(default)]
+    pub(super) reply_archetype_weights: BTreeMap<String, u32>,
+}
+
+impl StarterTemplate {
+    /// Load a template by name.
+    pub(super) fn load(name: &str) -> Result<Self> {
+        let (_, source) = TEMPLATE_SOURCES
+            .iter()
+            .find(|(n, _)| *n == name)
+            .with_context(|| {
+                format!(
+                    "Unknown template '{name}'. Available: {}",
+                    TEMPLATE_NAMES.join(", ")
+                )
+            })?;
+        toml::from_str(source).with_context(|| format!("Invalid built-in template '{name}'"))
+    }
+
+    /// All templates, in display order.
+    pub(super) fn all() -> Result<Vec<Self>> {
+        TEMPLATE_NAMES.iter().map(|name| Self::load(name)).collect()
+    }
+
+    /// Fill the fields the user left empty.
+    pub(super) fn apply(&self, r: WizardResult) -> WizardResult {
+        let or_template = |current: Option<String>, template: &Option<String>| {
+            current.or_else(|| template.clone())
+        };
+        WizardResult {
+            target_audience: if r.target_audience.is_empty() {
+                self.target_audience.clone()
+            } else {
+                r.target_audience
+            },
+            product_keywords: if r.product_keywords.is_empty() {
+                self.product_keywords.clone()
+            } else {
+                r.product_keywords
+            },
+            industry_topics: if r.industry_topics.is_empty() {
+                self.industry_topics.clone()
+            } else {
+                r.industry_topics
+            },
+            content_pillars: if r.content_pillars.is_empty() {
+                self.content_pillars.clone()
+            } else {
+                r.content_pillars
+            },
+            brand_voice: or_template(r.brand_voice, &self.brand_voice),
+            reply_style: or_template(r.reply_style, &self.reply_style),
+            content_style: or_template(r.content_style, &self.content_style),
+            reply_archetype_weights: if r.reply_archetype_weights.is_empty() {
+                self.reply_archetype_weights.clone()
+            } else {
+                r.reply_archetype_weights
+            },
+            ..r
+        }
+    }
+
+    /// Write the template's profile into the `[business]` table of an
+    /// existing config, keeping everything else.
+    pub(super) fn patch_config(&self, content: &str) -> Result<String> {
+        let mut doc: DocumentMut = content.parse().context("Failed to parse config template")?;
+        let business = doc["business"]
+            .or_insert(toml_edit::table())
+            .as_table_mut()
+            .context("[business] is not a table")?;
+
+        business["target_audience"] = value(&self.target_audience);
+        business["product_keywords"] = value(to_array(&self.product_keywords));
+        business["competitor_keywords"] = value(to_array(&self.competitor_keywords));
+        business["industry_topics"] = value(to_array(&self.industry_topics));
+        business["content_pillars"] = value(to_array(&self.content_pillars));
+        for (key, text) in [
+            ("brand_voice", &self.brand_voice),
+            ("reply_style", &self.reply_style),
+            ("content_style", &self.content_style),
+        ] {
+            if let Some(text) = text {
+                business[key] = value(text);
+            }
+        }
+        if !self.reply_archetype_weights.is_empty() {
+            let mut weights = InlineTable::new();
+            for (key, weight) in &self.reply_archetype_weights {
+                weights.insert(key, i64::from(*weight).into());
+            }
+            business["reply_archetype_weights"] = value(weights);
+        }
+
+        Ok(doc.to_string())
+    }
+}
+
+fn to_array(items: &[String]) -> Array {
+    items.iter().map(String::as_str).collect()
+}
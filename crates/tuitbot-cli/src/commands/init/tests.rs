@@ -1,6 +1,8 @@
 use super::helpers::{escape_toml, format_toml_array, parse_csv};
 use super::render::render_config_toml;
+use super::templates::{StarterTemplate, TEMPLATE_NAMES};
 use super::wizard::WizardResult;
+use super::EXAMPLE_CONFIG;
 
 #[test]
 fn parse_csv_basic() {
@@ -61,6 +63,7 @@ fn test_wizard_result() -> WizardResult {
         persona_opinions: vec![],
         persona_experiences: vec![],
         content_pillars: vec![],
+        reply_archetype_weights: Default::default(),
         target_accounts: vec![],
         approval_mode: false,
         llm_provider: "ollama".to_string(),
@@ -99,6 +102,7 @@ fn render_config_toml_is_valid_toml() {
         persona_opinions: vec![],
         persona_experiences: vec![],
         content_pillars: vec![],
+        reply_archetype_weights: Default::default(),
         target_accounts: vec![],
         approval_mode: false,
         llm_provider: "openai".to_string(),
@@ -323,6 +327,7 @@ fn render_quickstart_minimal_is_valid_toml() {
         persona_opinions: vec![],
         persona_experiences: vec![],
         content_pillars: vec![],
+        reply_archetype_weights: Default::default(),
         target_accounts: vec![],
         approval_mode: true,
         llm_provider: "ollama".to_string(),
@@ -408,6 +413,7 @@ fn quickstart_wizard_result() -> WizardResult {
         persona_opinions: vec![],
         persona_experiences: vec![],
         content_pillars: vec![],
+        reply_archetype_weights: Default::default(),
         target_accounts: vec![],
         approval_mode: true,
         llm_provider: "openai".to_string(),
@@ -498,6 +504,7 @@ fn advanced_wizard_result_still_renders_valid_toml() {
         persona_opinions: vec!["Rust is great".to_string()],
         persona_experiences: vec!["Built 3 startups".to_string()],
         content_pillars: vec!["Dev tools".to_string()],
+        reply_archetype_weights: Default::default(),
         target_accounts: vec!["levelsio".to_string()],
         approval_mode: false,
         llm_provider: "anthropic".to_string(),
@@ -641,3 +648,64 @@ fn advanced_config_fully_enriched() {
     assert_eq!(pc.completed_count(), 3);
     assert!(pc.next_incomplete().is_none());
 }
+
+#[test]
+fn starter_templates_render_valid_configs() {
+    let templates = StarterTemplate::all().expect("templates load");
+    assert_eq!(templates.len(), TEMPLATE_NAMES.len());
+
+    for template in &templates {
+        let result = template.apply(WizardResult {
+            target_audience: String::new(),
+            industry_topics: vec![],
+            ..test_wizard_result()
+        });
+        let config: tuitbot_core::config::Config =
+            toml::from_str(&render_config_toml(&result)).expect("rendered TOML should parse");
+        assert!(
+            config.validate().is_ok(),
+            "{} template fails validation",
+            template.label
+        );
+        assert_eq!(config.business.target_audience, template.target_audience);
+        assert_eq!(config.business.industry_topics, template.industry_topics);
+        assert_eq!(config.business.content_pillars, template.content_pillars);
+        assert_eq!(config.business.brand_voice, template.brand_voice);
+        assert_eq!(
+            config.business.reply_archetype_weights,
+            template.reply_archetype_weights
+        );
+    }
+}
+
+#[test]
+fn starter_template_keeps_user_answers() {
+    let template = StarterTemplate::load("devtool").unwrap();
+    let result = template.apply(WizardResult {
+        brand_voice: Some("Mine".to_string()),
+        ..test_wizard_result()
+    });
+    assert_eq!(result.brand_voice.as_deref(), Some("Mine"));
+    assert_eq!(result.product_keywords, vec!["test"]);
+    assert_eq!(result.target_audience, "devs");
+    assert_eq!(result.content_pillars, template.content_pillars);
+
+    assert!(StarterTemplate::load("crypto").is_err());
+}
+
+#[test]
+fn starter_template_patches_example_config() {
+    let template = StarterTemplate::load("newsletter").unwrap();
+    let patched = template.patch_config(EXAMPLE_CONFIG).unwrap();
+    let config: tuitbot_core::config::Config = toml::from_str(&patched).unwrap();
+
+    assert_eq!(config.business.product_keywords, template.product_keywords);
+    assert!(config.business.competitor_keywords.is_empty());
+    assert_eq!(config.business.reply_style, template.reply_style);
+    assert_eq!(
+        config.business.reply_archetype_weights,
+        template.reply_archetype_weights
+    );
+    // The rest of the example is left as is.
+    assert!(patched.contains("# --- Scoring Engine ---"));
+}
@@ -1,3 +1,5 @@
+use std::collections::BTreeMap;
+
 /// Collected wizard answers from all 8 steps.
 pub(super) struct WizardResult {
     // X API
@@ -18,6 +20,8 @@ pub(super) struct WizardResult {
     pub(super) persona_opinions: Vec<String>,
     pub(super) persona_experiences: Vec<String>,
     pub(super) content_pillars: Vec<String>,
+    // Reply archetype weights (from a starter template)
+    pub(super) reply_archetype_weights: BTreeMap<String, u32>,
     // Target accounts
     pub(super) target_accounts: Vec<String>,
     // Approval mode
@@ -0,0 +1,70 @@
+//! Implementation of the `tuitbot kill` command.
+//!
+//! `tuitbot kill --reason "<why>"` engages the compliance kill switch,
+//! halting every mutation path (automation loops, approval posting, MCP
+//! mutation tools) until `tuitbot kill --release` is run. The switch is
+//! stored in the database, so it takes effect in already-running processes
+//! and survives restarts.
+
+use tuitbot_core::config::Config;
+use tuitbot_core::storage;
+use tuitbot_core::storage::kill_switch::KillSwitchState;
+
+use super::{KillArgs, OutputFormat};
+use crate::output::write_stdout;
+
+/// Execute the `tuitbot kill` command.
+pub async fn execute(config: &Config, args: KillArgs, output: OutputFormat) -> anyhow::Result<()> {
+    let pool = storage::init_db(&config.storage.db_path).await?;
+
+    let result = if args.status {
+        storage::kill_switch::get_kill_switch(&pool).await
+    } else if args.release {
+        storage::kill_switch::release_kill_switch(&pool, "cli").await
+    } else {
+        match args.reason.as_deref().map(str::trim) {
+            Some(reason) if !reason.is_empty() => {
+                storage::kill_switch::engage_kill_switch(&pool, reason, "cli").await
+            }
+            _ => {
+                pool.close().await;
+                anyhow::bail!(
+                    "Pass --reason \"<why>\" to engage the kill switch, \
+                     --release to re-enable mutations, or --status to inspect it."
+                );
+            }
+        }
+    };
+    pool.close().await;
+    let state = result?;
+
+    if output.is_json() {
+        write_stdout(&serde_json::to_string(&state)?)?;
+    } else {
+        print_state(&state);
+    }
+
+    Ok(())
+}
+
+fn print_state(state: &KillSwitchState) {
+    if state.active {
+        eprintln!("KILL SWITCH ENGAGED — all mutations are halted.");
+        eprintln!(
+            "  Reason:  {}",
+            state.reason.as_deref().unwrap_or("(none given)")
+        );
+    } else {
+        eprintln!("Kill switch released — mutations are enabled.");
+    }
+    if let Some(source) = &state.source {
+        eprintln!("  Source:  {source}");
+    }
+    if let Some(changed_at) = &state.changed_at {
+        eprintln!("  Changed: {changed_at}");
+    }
+    if state.active {
+        eprintln!();
+        eprintln!("Run `tuitbot kill --release` to re-enable mutations.");
+    }
+}
@@ -1,14 +1,17 @@
-//! `tuitbot mcp` subcommands: serve, manifest, and setup.
+//! `tuitbot mcp` subcommands: serve, manifest, setup, and replay.
 //!
 //! - `serve`    — loads config (file + env overrides, no file required) and starts the MCP server.
 //! - `manifest` — prints the profile-specific tool manifest as JSON.
 //! - `setup`    — interactive wizard for MCP-only users (client ID → auth → register).
+//! - `replay`   — lists recorded tool invocations or re-executes a read-only one.
 mod detect;
+mod replay;
 mod setup;
 
 use tuitbot_core::config::Config;
 use tuitbot_mcp::Profile;
 
+use super::{McpReplayArgs, OutputFormat};
 use crate::output::write_stdout;
 
 /// Execute the `tuitbot mcp serve` subcommand.
@@ -45,3 +48,8 @@ pub fn print_manifest(profile_str: &str) -> anyhow::Result<()> {
 pub async fn execute_setup() -> anyhow::Result<()> {
     setup::run_setup().await
 }
+
+/// Execute the `tuitbot mcp replay` subcommand.
+pub async fn execute_replay(args: &McpReplayArgs, output: OutputFormat) -> anyhow::Result<()> {
+    replay::execute(args, output).await
+}
@@ -0,0 +1,65 @@
+//! `tuitbot mcp replay` — inspect and re-execute recorded tool invocations.
+//!
+//! Without an ID, lists recent invocations from the `mcp_invocations` log,
+//! optionally filtered by session, tool, or error code. With an ID,
+//! re-executes that invocation if it was read-only and prints the response.
+
+use tuitbot_core::config::Config;
+use tuitbot_core::storage;
+use tuitbot_core::storage::mcp_invocations::{InvocationFilter, McpInvocation};
+
+use super::super::{McpReplayArgs, OutputFormat};
+use crate::output::write_stdout;
+
+pub(super) async fn execute(args: &McpReplayArgs, output: OutputFormat) -> anyhow::Result<()> {
+    let config =
+        Config::load(None).map_err(|e| anyhow::anyhow!("Failed to load configuration: {e}"))?;
+
+    if let Some(id) = args.id {
+        let response = tuitbot_mcp::replay_invocation(config, id).await?;
+        return write_stdout(&response);
+    }
+
+    let pool = storage::init_db(&config.storage.db_path).await?;
+    let filter = InvocationFilter {
+        session_id: args.session.clone(),
+        tool_name: args.tool.clone(),
+        error_code: args.error_code.clone(),
+        limit: args.limit,
+    };
+    let result = storage::mcp_invocations::list_invocations(&pool, &filter).await;
+    pool.close().await;
+    let invocations = result?;
+
+    if output.is_json() {
+        write_stdout(&serde_json::to_string(&invocations)?)
+    } else {
+        print_table(&invocations);
+        Ok(())
+    }
+}
+
+fn print_table(invocations: &[McpInvocation]) {
+    if invocations.is_empty() {
+        eprintln!("No recorded invocations match.");
+        return;
+    }
+    eprintln!(
+        "{:>6}  {:<20}  {:<28}  {:<8}  {:>7}  {:<22}  SESSION",
+        "ID", "TIME", "TOOL", "STATUS", "MS", "ERROR"
+    );
+    for inv in invocations {
+        eprintln!(
+            "{:>6}  {:<20}  {:<28}  {:<8}  {:>7}  {:<22}  {}",
+            inv.id,
+            inv.created_at,
+            inv.tool_name,
+            if inv.success { "ok" } else { "error" },
+            inv.latency_ms,
+            inv.error_code.as_deref().unwrap_or("-"),
+            inv.session_id,
+        );
+    }
+    eprintln!();
+    eprintln!("Re-run a read-only invocation with `tuitbot mcp replay <ID>`.");
+}
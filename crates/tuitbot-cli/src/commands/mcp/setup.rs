@@ -11,7 +11,7 @@ use anyhow::{bail, Context, Result};
 use console::Style;
 use dialoguer::{Confirm, Input, Select};
 use toml_edit::DocumentMut;
-use tuitbot_core::config::Config;
+use tuitbot_core::config::{history, Config};
 use tuitbot_core::startup::data_dir;
 
 use super::detect;
@@ -199,7 +199,7 @@ fn write_or_merge_config(config_path: &std::path::Path, client_id: &str) -> Resu
         }
         doc["x_api"]["client_id"] = toml_edit::value(client_id);
 
-        fs::write(config_path, doc.to_string())
+        history::write_config(config_path, &doc.to_string(), "mcp setup")
             .with_context(|| format!("Failed to write {}", config_path.display()))?;
     } else {
         // Write minimal config
@@ -212,7 +212,7 @@ client_id = \"{client_id}\"
 approval_mode = true
 "
         );
-        fs::write(config_path, content)
+        history::write_config(config_path, &content, "mcp setup")
             .with_context(|| format!("Failed to write {}", config_path.display()))?;
     }
 
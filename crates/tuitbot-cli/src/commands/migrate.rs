@@ -0,0 +1,134 @@
+//! Implementation of the `tuitbot migrate` command.
+//!
+//! `export` writes the config, database, tokens, and media to a single
+//! encrypted bundle; `import` restores one on the new machine.
+
+use std::io::IsTerminal;
+use std::path::{Path, PathBuf};
+
+use chrono::Utc;
+use tuitbot_core::startup::{data_dir, expand_tilde};
+use tuitbot_core::storage;
+use tuitbot_core::storage::export_bundle;
+
+use super::{MigrateArgs, MigrateSubcommand};
+
+/// Environment variable read instead of prompting for the passphrase.
+const PASSPHRASE_ENV: &str = "TUITBOT_MIGRATE_PASSPHRASE";
+
+/// Shortest passphrase accepted for a new bundle.
+const MIN_PASSPHRASE_LEN: usize = 8;
+
+/// Execute the `tuitbot migrate` command.
+pub async fn execute(args: MigrateArgs, config_path: &str) -> anyhow::Result<()> {
+    let config_path = expand_tilde(config_path);
+    match args.command {
+        MigrateSubcommand::Export {
+            output,
+            exclude_secrets,
+        } => export(&config_path, output, !exclude_secrets).await,
+        MigrateSubcommand::Import { bundle, force } => {
+            import(&config_path, Path::new(&bundle), force).await
+        }
+    }
+}
+
+async fn export(
+    config_path: &Path,
+    output: Option<String>,
+    include_secrets: bool,
+) -> anyhow::Result<()> {
+    let data = data_dir();
+    let db_path = data.join("tuitbot.db");
+    if !config_path.exists() {
+        anyhow::bail!("Config not found at {}.", config_path.display());
+    }
+    if !db_path.exists() {
+        anyhow::bail!(
+            "Database not found at {}. Run `tuitbot init` first.",
+            db_path.display()
+        );
+    }
+
+    let dest = output.map(PathBuf::from).unwrap_or_else(|| {
+        PathBuf::from(format!(
+            "tuitbot-export-{}.tuitbot",
+            Utc::now().format("%Y%m%d_%H%M%S")
+        ))
+    });
+    let passphrase = passphrase(true)?;
+
+    let pool = storage::init_db(&db_path.to_string_lossy()).await?;
+    eprintln!("Exporting...");
+    let result = export_bundle::export_bundle(
+        &pool,
+        config_path,
+        &data,
+        &dest,
+        &passphrase,
+        include_secrets,
+    )
+    .await;
+    pool.close().await;
+    let manifest = result?;
+
+    eprintln!("Export bundle written: {}", dest.display());
+    eprintln!("  Files: {}", manifest.files.len());
+    eprintln!("  Media: {}", manifest.media_count());
+    if !manifest.secrets_included {
+        eprintln!("  Secrets were left out; re-enter them after import.");
+    }
+    eprintln!(
+        "\nOn the new machine: tuitbot migrate import {}",
+        dest.display()
+    );
+    Ok(())
+}
+
+async fn import(config_path: &Path, bundle: &Path, force: bool) -> anyhow::Result<()> {
+    if !bundle.exists() {
+        anyhow::bail!("Bundle not found: {}", bundle.display());
+    }
+    let passphrase = passphrase(false)?;
+
+    eprintln!("Decrypting and verifying {}...", bundle.display());
+    let manifest =
+        export_bundle::import_bundle(bundle, &passphrase, config_path, &data_dir(), force).await?;
+
+    eprintln!("Import complete:");
+    eprintln!(
+        "  Exported: {} (v{})",
+        manifest.created_at, manifest.tuitbot_version
+    );
+    eprintln!("  Config: {}", config_path.display());
+    eprintln!("  Media: {}", manifest.media_count());
+    if !manifest.contains("tokens.json") {
+        eprintln!("  No tokens in bundle; run `tuitbot auth`.");
+    }
+    if !manifest.secrets_included {
+        eprintln!("  Secrets were left out of the bundle; add them to the config.");
+    }
+    eprintln!("Run `tuitbot test` to verify connectivity.");
+    Ok(())
+}
+
+/// Read the bundle passphrase from the environment or a prompt.
+fn passphrase(confirm: bool) -> anyhow::Result<String> {
+    let value = match std::env::var(PASSPHRASE_ENV) {
+        Ok(value) => value,
+        Err(_) if std::io::stdin().is_terminal() => {
+            let prompt = dialoguer::Password::new().with_prompt("Bundle passphrase");
+            let prompt = if confirm {
+                prompt.with_confirmation("Confirm passphrase", "Passphrases do not match")
+            } else {
+                prompt
+            };
+            prompt.interact()?
+        }
+        Err(_) => anyhow::bail!("Set {PASSPHRASE_ENV} when not running in a terminal."),
+    };
+    if confirm && value.chars().count() < MIN_PASSPHRASE_LEN {
+        anyhow::bail!("Passphrase must be at least {MIN_PASSPHRASE_LEN} characters.");
+    }
+    Ok(value)
+}
@@ -3,20 +3,31 @@
 /// Each subcommand struct defines its flags and arguments
 /// matching the CLI interface contract.
 pub mod approve;
+pub mod archive;
 pub mod auth;
 pub mod backup;
+pub mod db;
+pub mod draft_only;
 pub mod init;
+pub mod kill;
 pub mod mcp;
+pub mod migrate;
+pub mod notify;
+pub mod privacy;
+pub mod reply_to;
 pub mod restore;
+pub mod resume;
 pub mod run;
 pub mod settings;
 pub mod stats;
+pub mod targets;
 pub mod test;
 pub mod tick;
 pub mod update;
 pub mod upgrade;
 
 use clap::Args;
+use tuitbot_core::x_api::tier::ApiTier;
 
 /// Output format for machine-readable output.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -52,6 +63,10 @@ pub struct InitArgs {
     /// Run the full 8-step advanced setup wizard
     #[arg(long)]
     pub advanced: bool,
+
+    /// Pre-fill the business profile from a starter template
+    #[arg(long, value_parser = init::TEMPLATE_NAMES.to_vec())]
+    pub template: Option<String>,
 }
 
 /// Arguments for the `run` subcommand.
@@ -60,6 +75,10 @@ pub struct RunArgs {
     /// Print periodic status summary (0 = disabled)
     #[arg(long, default_value = "0")]
     pub status_interval: u64,
+
+    /// Also serve the dashboard API on the [server] host and port
+    #[arg(long)]
+    pub with_api: bool,
 }
 
 /// Arguments for the `auth` subcommand.
@@ -72,7 +91,15 @@ pub struct AuthArgs {
 
 /// Arguments for the `test` subcommand.
 #[derive(Debug, Args)]
-pub struct TestArgs;
+pub struct TestArgs {
+    /// Also flag risky or contradictory settings (warnings only)
+    #[arg(long)]
+    pub lint: bool,
+
+    /// X API tier for the usage forecast: free, basic, or pro
+    #[arg(long, default_value = "basic")]
+    pub tier: ApiTier,
+}
 
 /// Arguments for the `discover` subcommand.
 #[derive(Debug, Args)]
@@ -133,6 +160,29 @@ pub struct ScoreArgs {
     pub tweet_id: String,
 }
 
+/// Arguments for the `reply-to` subcommand.
+#[derive(Debug, Args)]
+pub struct ReplyToArgs {
+    /// URL of the X post to reply to
+    pub url: String,
+
+    /// Number of candidate replies to draft (2-3)
+    #[arg(long, default_value_t = 3, value_parser = clap::value_parser!(u8).range(2..=3))]
+    pub candidates: u8,
+
+    /// Queue candidate N (1-based) for approval
+    #[arg(long)]
+    pub pick: Option<usize>,
+
+    /// Post the picked candidate now instead of queueing it
+    #[arg(long, requires = "pick")]
+    pub send: bool,
+
+    /// Allow the replies to mention the product
+    #[arg(long)]
+    pub mention_product: bool,
+}
+
 /// Arguments for the `stats` subcommand.
 #[derive(Debug, Args)]
 pub struct StatsArgs;
@@ -152,6 +202,14 @@ pub struct ApproveArgs {
     #[arg(long)]
     pub reject: Option<i64>,
 
+    /// Delete a specific item by ID (restorable until retention cleanup)
+    #[arg(long)]
+    pub delete: Option<i64>,
+
+    /// Restore a deleted or rejected item by ID
+    #[arg(long)]
+    pub restore: Option<i64>,
+
     /// Approve all pending items
     #[arg(long)]
     pub approve_all: bool,
@@ -159,7 +217,11 @@ pub struct ApproveArgs {
 
 /// Arguments for the `settings` subcommand.
 #[derive(Debug, Args)]
+#[command(args_conflicts_with_subcommands = true)]
 pub struct SettingsArgs {
+    #[command(subcommand)]
+    pub command: Option<SettingsSubcommand>,
+
     /// Show current configuration (read-only)
     #[arg(long)]
     pub show: bool,
@@ -173,6 +235,51 @@ pub struct SettingsArgs {
     pub category: Option<String>,
 }
 
+/// Settings subcommands.
+#[derive(Debug, clap::Subcommand)]
+pub enum SettingsSubcommand {
+    /// List saved config versions and what each one changed
+    History {
+        /// Maximum number of versions to list
+        #[arg(long, default_value = "20")]
+        limit: usize,
+    },
+    /// Restore a saved config version by ID
+    Rollback {
+        /// Version ID from `tuitbot settings history`
+        id: u64,
+
+        /// Skip confirmation prompt
+        #[arg(long)]
+        force: bool,
+    },
+    /// List or apply named bundles of limits, scoring, approval, and cadence settings
+    Preset {
+        #[command(subcommand)]
+        action: PresetAction,
+    },
+}
+
+/// `settings preset` actions.
+#[derive(Debug, clap::Subcommand)]
+pub enum PresetAction {
+    /// List the available presets
+    List,
+    /// Show the changes a preset makes, then apply it
+    Apply {
+        /// Preset name: conservative, balanced, or aggressive
+        name: String,
+
+        /// Skip confirmation prompt
+        #[arg(long)]
+        force: bool,
+
+        /// Show the changes without writing the config
+        #[arg(long)]
+        dry_run: bool,
+    },
+}
+
 /// Arguments for the `update` subcommand.
 #[derive(Debug, Args)]
 pub struct UpdateArgs {
@@ -191,12 +298,28 @@ pub struct UpdateArgs {
 
 /// Arguments for the `upgrade` subcommand.
 #[derive(Debug, Args)]
+#[command(args_conflicts_with_subcommands = true)]
 pub struct UpgradeArgs {
+    #[command(subcommand)]
+    pub command: Option<UpgradeSubcommand>,
+
     /// Skip interactive prompts and apply default values for new features
     #[arg(long)]
     pub non_interactive: bool,
 }
 
+/// Upgrade subcommands.
+#[derive(Debug, clap::Subcommand)]
+pub enum UpgradeSubcommand {
+    /// Add config sections missing from an older config.toml and flag
+    /// settings this version no longer reads
+    Config {
+        /// Add missing sections with default values without prompting
+        #[arg(long)]
+        non_interactive: bool,
+    },
+}
+
 /// Arguments for the `tick` subcommand.
 #[derive(Debug, Args)]
 pub struct TickArgs {
@@ -220,7 +343,11 @@ pub struct TickArgs {
 
 /// Arguments for the `backup` subcommand.
 #[derive(Debug, Args)]
+#[command(args_conflicts_with_subcommands = true)]
 pub struct BackupArgs {
+    #[command(subcommand)]
+    pub command: Option<BackupSubcommand>,
+
     /// Custom output directory for the backup file
     #[arg(long)]
     pub output_dir: Option<String>,
@@ -232,6 +359,29 @@ pub struct BackupArgs {
     /// Keep N most recent backups, delete the rest
     #[arg(long)]
     pub prune: Option<usize>,
+
+    /// Use the `[remote_backup]` bucket: upload a snapshot, or list remote backups with --list
+    #[arg(long)]
+    pub remote: bool,
+}
+
+/// Backup subcommands.
+#[derive(Debug, clap::Subcommand)]
+pub enum BackupSubcommand {
+    /// Restore the database from a local file or an s3://bucket/key snapshot
+    Restore {
+        /// Backup to restore: a file path or s3://bucket/key
+        #[arg(long)]
+        from: String,
+
+        /// Skip confirmation prompt
+        #[arg(long)]
+        force: bool,
+
+        /// Validate backup without restoring
+        #[arg(long)]
+        validate_only: bool,
+    },
 }
 
 /// Arguments for the `restore` subcommand.
@@ -249,6 +399,37 @@ pub struct RestoreArgs {
     pub validate_only: bool,
 }
 
+/// Arguments for the `migrate` subcommand.
+#[derive(Debug, Args)]
+pub struct MigrateArgs {
+    #[command(subcommand)]
+    pub command: MigrateSubcommand,
+}
+
+/// Migrate subcommands.
+#[derive(Debug, clap::Subcommand)]
+pub enum MigrateSubcommand {
+    /// Write config, database, tokens, and media to one encrypted bundle
+    Export {
+        /// Bundle file to write (default: ./tuitbot-export-<timestamp>.tuitbot)
+        #[arg(long, short)]
+        output: Option<String>,
+
+        /// Leave API keys, client secrets, and passwords out of the config
+        #[arg(long)]
+        exclude_secrets: bool,
+    },
+    /// Restore an encrypted bundle on this machine
+    Import {
+        /// Bundle file written by `tuitbot migrate export`
+        bundle: String,
+
+        /// Replace an existing config and database
+        #[arg(long)]
+        force: bool,
+    },
+}
+
 /// Arguments for the `mcp` subcommand.
 #[derive(Debug, Args)]
 pub struct McpArgs {
@@ -273,4 +454,200 @@ pub enum McpSubcommand {
     },
     /// Guided setup for MCP-only users (Client ID → auth → register)
     Setup,
+    /// List recorded tool invocations, or re-execute a read-only one by ID
+    Replay(McpReplayArgs),
+}
+
+/// Arguments for the `mcp replay` subcommand.
+#[derive(Debug, Args)]
+pub struct McpReplayArgs {
+    /// Invocation ID to re-execute (omit to list recorded invocations)
+    pub id: Option<i64>,
+
+    /// Only list invocations from this MCP session
+    #[arg(long)]
+    pub session: Option<String>,
+
+    /// Only list invocations of this tool
+    #[arg(long)]
+    pub tool: Option<String>,
+
+    /// Only list invocations that failed with this error code
+    #[arg(long)]
+    pub error_code: Option<String>,
+
+    /// Maximum number of invocations to list
+    #[arg(long, default_value = "20")]
+    pub limit: u32,
+}
+
+/// Arguments for the `privacy` subcommand.
+#[derive(Debug, Args)]
+pub struct PrivacyArgs {
+    #[command(subcommand)]
+    pub command: PrivacySubcommand,
+}
+
+/// Privacy subcommands.
+#[derive(Debug, clap::Subcommand)]
+pub enum PrivacySubcommand {
+    /// Delete or anonymize all stored data about an external X user
+    Forget {
+        /// Username (with or without @) or numeric user ID
+        user: String,
+
+        /// Skip confirmation prompt
+        #[arg(long)]
+        force: bool,
+    },
+}
+
+/// Arguments for the `kill` subcommand.
+#[derive(Debug, Args)]
+pub struct KillArgs {
+    /// Why mutations are being halted (required to engage)
+    #[arg(long, conflicts_with_all = ["release", "status"])]
+    pub reason: Option<String>,
+
+    /// Release the kill switch and re-enable mutations
+    #[arg(long, conflicts_with = "status")]
+    pub release: bool,
+
+    /// Show the current kill switch state without changing it
+    #[arg(long)]
+    pub status: bool,
+}
+
+/// Arguments for the `draft-only` subcommand.
+#[derive(Debug, Args)]
+pub struct DraftOnlyArgs {
+    /// Why posting is being paused (shown in status and the dashboard)
+    #[arg(long, conflicts_with_all = ["off", "status"])]
+    pub reason: Option<String>,
+
+    /// Turn draft-only mode off and release held items
+    #[arg(long, conflicts_with = "status")]
+    pub off: bool,
+
+    /// Show whether draft-only mode is on without changing it
+    #[arg(long)]
+    pub status: bool,
+}
+
+/// Arguments for the `resume` subcommand.
+#[derive(Debug, Args)]
+pub struct ResumeArgs {
+    /// Show whether safe mode is on and why, without lifting it
+    #[arg(long)]
+    pub status: bool,
+}
+
+/// Arguments for the `archive` subcommand.
+#[derive(Debug, Args)]
+pub struct ArchiveArgs {
+    /// Clear the archived flag so automation can run again
+    #[arg(long, conflicts_with = "status")]
+    pub undo: bool,
+
+    /// Show whether the install is archived without changing it
+    #[arg(long)]
+    pub status: bool,
+}
+
+/// Arguments for the `db` subcommand.
+#[derive(Debug, Args)]
+pub struct DbArgs {
+    #[command(subcommand)]
+    pub command: DbSubcommand,
+}
+
+/// Database diagnostics subcommands.
+#[derive(Debug, clap::Subcommand)]
+pub enum DbSubcommand {
+    /// Report recorded slow queries, grouped by statement kind and table
+    SlowQueries {
+        /// Only include queries from the last N hours
+        #[arg(long, default_value_t = 168)]
+        hours: u32,
+
+        /// Maximum number of query names to show
+        #[arg(long, default_value_t = 20)]
+        limit: u32,
+    },
+}
+
+/// Arguments for the `notify` subcommand.
+#[derive(Debug, Args)]
+pub struct NotifyArgs {
+    #[command(subcommand)]
+    pub command: NotifySubcommand,
+}
+
+/// Notify subcommands.
+#[derive(Debug, clap::Subcommand)]
+pub enum NotifySubcommand {
+    /// Send a test message to each notification channel
+    Test {
+        /// Only test the channel at this position in `[[notifications.channels]]` (0-based)
+        #[arg(long)]
+        channel: Option<usize>,
+    },
+}
+
+/// Arguments for the `targets` subcommand.
+#[derive(Debug, Args)]
+pub struct TargetsArgs {
+    #[command(subcommand)]
+    pub command: TargetsSubcommand,
+}
+
+/// Targets subcommands.
+#[derive(Debug, clap::Subcommand)]
+pub enum TargetsSubcommand {
+    /// List target accounts with their tier and cadence
+    List,
+    /// Move a target account into a tier
+    Tier {
+        /// Username (with or without @)
+        username: String,
+
+        /// Tier name
+        #[arg(value_parser = ["dream_100", "general"])]
+        tier: String,
+    },
+    /// Stop monitoring a target account (restorable until retention cleanup)
+    Remove {
+        /// Username (with or without @)
+        username: String,
+    },
+    /// Resume monitoring a removed target account
+    Restore {
+        /// Username (with or without @)
+        username: String,
+    },
+    /// List removed target accounts that can still be restored
+    Removed,
+    /// Import many target accounts from a CSV file or a following list
+    #[command(group(clap::ArgGroup::new("source").required(true).args(["csv", "from_following"])))]
+    Import {
+        /// CSV file with one username, @handle, or profile URL per row (first column)
+        #[arg(long)]
+        csv: Option<String>,
+
+        /// Import the accounts this user follows
+        #[arg(long, value_name = "USERNAME")]
+        from_following: Option<String>,
+
+        /// Tier assigned to imported accounts
+        #[arg(long, default_value = "general", value_parser = ["dream_100", "general"])]
+        tier: String,
+
+        /// Most accounts to read from the following list
+        #[arg(long, default_value_t = 100)]
+        limit: usize,
+
+        /// Validate and report without changing anything
+        #[arg(long)]
+        dry_run: bool,
+    },
 }
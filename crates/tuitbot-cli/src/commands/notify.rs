@@ -0,0 +1,98 @@
+//! Implementation of the `tuitbot notify` command.
+//!
+//! `tuitbot notify test` sends a test message to every channel in
+//! `[[notifications.channels]]` (or one, with `--channel`) and reports
+//! which deliveries failed, so URLs and credentials can be checked without
+//! waiting for a real event.
+
+use serde::Serialize;
+use tuitbot_core::config::Config;
+use tuitbot_server::notify::{self, Channel};
+
+use super::{NotifyArgs, NotifySubcommand, OutputFormat};
+use crate::output::write_stdout;
+
+/// Result of testing one channel.
+#[derive(Debug, Serialize)]
+struct ChannelCheck {
+    index: usize,
+    kind: String,
+    ok: bool,
+    error: Option<String>,
+}
+
+/// Execute the `tuitbot notify` command.
+pub async fn execute(
+    config: &Config,
+    args: NotifyArgs,
+    output: OutputFormat,
+) -> anyhow::Result<()> {
+    match args.command {
+        NotifySubcommand::Test { channel } => test(config, channel, output).await,
+    }
+}
+
+async fn test(config: &Config, only: Option<usize>, output: OutputFormat) -> anyhow::Result<()> {
+    let channels = &config.notifications.channels;
+    if channels.is_empty() {
+        anyhow::bail!("No [[notifications.channels]] configured.");
+    }
+    if let Some(index) = only {
+        if index >= channels.len() {
+            anyhow::bail!(
+                "--channel {index} is out of range; {} channel(s) configured.",
+                channels.len()
+            );
+        }
+    }
+
+    let client = notify::http_client();
+    let mut checks = Vec::new();
+    for (index, channel) in channels.iter().enumerate() {
+        if only.is_some_and(|i| i != index) {
+            continue;
+        }
+        let check = match Channel::parse(&channel.url) {
+            Ok(parsed) => {
+                let result = notify::send(
+                    &client,
+                    &parsed,
+                    "Tuitbot test notification",
+                    "If you can read this, the channel is set up.",
+                    0,
+                )
+                .await;
+                ChannelCheck {
+                    index,
+                    kind: parsed.kind().to_string(),
+                    ok: result.is_ok(),
+                    error: result.err(),
+                }
+            }
+            Err(e) => ChannelCheck {
+                index,
+                kind: channel.scheme().to_string(),
+                ok: false,
+                error: Some(e),
+            },
+        };
+        checks.push(check);
+    }
+
+    if output.is_json() {
+        write_stdout(&serde_json::to_string(&checks)?)?;
+    } else {
+        for check in &checks {
+            match &check.error {
+                None => eprintln!("[{}] {:<9} ok", check.index, check.kind),
+                Some(e) => eprintln!("[{}] {:<9} FAILED: {e}", check.index, check.kind),
+            }
+        }
+    }
+
+    let failed = checks.iter().filter(|c| !c.ok).count();
+    if failed > 0 {
+        anyhow::bail!("{failed} of {} channel(s) failed.", checks.len());
+    }
+    Ok(())
+}
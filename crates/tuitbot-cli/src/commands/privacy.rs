@@ -0,0 +1,149 @@
+//! Implementation of the `tuitbot privacy` command.
+//!
+//! `tuitbot privacy forget <username|user_id>` wipes stored data about a
+//! specific external X user and prints a report of what was removed.
+
+use std::io::IsTerminal;
+
+use tuitbot_core::config::Config;
+use tuitbot_core::storage;
+use tuitbot_core::storage::privacy::ForgetReport;
+
+use super::{OutputFormat, PrivacyArgs, PrivacySubcommand};
+use crate::output::write_stdout;
+
+/// Execute the `tuitbot privacy` command.
+pub async fn execute(
+    config: &Config,
+    args: PrivacyArgs,
+    output: OutputFormat,
+) -> anyhow::Result<()> {
+    match args.command {
+        PrivacySubcommand::Forget { user, force } => forget(config, &user, force, output).await,
+    }
+}
+
+async fn forget(
+    config: &Config,
+    user: &str,
+    force: bool,
+    output: OutputFormat,
+) -> anyhow::Result<()> {
+    let pool = storage::init_db(&config.storage.db_path).await?;
+
+    let Some(target) = storage::privacy::resolve_target(&pool, user).await? else {
+        pool.close().await;
+        anyhow::bail!("Empty user identifier. Pass a username or numeric user ID.");
+    };
+
+    if !force && !output.is_json() && std::io::stdin().is_terminal() {
+        eprintln!(
+            "This will delete or anonymize all stored data about {} (id: {}).",
+            target
+                .username
+                .as_deref()
+                .map(|u| format!("@{u}"))
+                .unwrap_or_else(|| "<unknown username>".to_string()),
+            target.user_id.as_deref().unwrap_or("<unknown>"),
+        );
+        eprint!("Continue? [y/N] ");
+        let mut input = String::new();
+        std::io::stdin().read_line(&mut input)?;
+        if !input.trim().eq_ignore_ascii_case("y") {
+            pool.close().await;
+            eprintln!("Aborted.");
+            return Ok(());
+        }
+    }
+
+    let result = storage::privacy::forget_user(&pool, user).await;
+    pool.close().await;
+    let report = result?;
+
+    if output.is_json() {
+        write_stdout(&serde_json::to_string(&report)?)?;
+    } else {
+        print_report(&report);
+    }
+
+    Ok(())
+}
+
+fn print_report(report: &ForgetReport) {
+    eprintln!();
+    eprintln!("=== Privacy Wipe Report ===");
+    eprintln!(
+        "  User:     {} (id: {})",
+        report.target.username.as_deref().unwrap_or("-"),
+        report.target.user_id.as_deref().unwrap_or("-"),
+    );
+    eprintln!();
+    eprintln!("  Deleted:");
+    eprintln!(
+        "    Discovered tweets:     {}",
+        report.discovered_tweets_deleted
+    );
+    eprintln!(
+        "    Target tweets:         {}",
+        report.target_tweets_deleted
+    );
+    eprintln!(
+        "    Target replies:        {}",
+        report.target_reply_history_deleted
+    );
+    eprintln!(
+        "    Target accounts:       {}",
+        report.target_accounts_deleted
+    );
+    eprintln!(
+        "    Author interactions:   {}",
+        report.author_interactions_deleted
+    );
+    eprintln!(
+        "    Inbound engagements:   {}",
+        report.inbound_engagements_deleted
+    );
+    eprintln!(
+        "    Pending approvals:     {}",
+        report.approval_items_deleted
+    );
+    eprintln!(
+        "    Ingested candidates:   {}",
+        report.ingested_candidates_deleted
+    );
+    eprintln!("  Anonymized:");
+    eprintln!("    Sent replies:          {}", report.replies_anonymized);
+    eprintln!(
+        "    Reviewed approvals:    {}",
+        report.approval_items_anonymized
+    );
+    eprintln!("  Redacted traces:");
+    eprintln!(
+        "    Mutation audit:        {}",
+        report.mutation_audit_redacted
+    );
+    eprintln!(
+        "    MCP telemetry:         {}",
+        report.mcp_telemetry_redacted
+    );
+    eprintln!(
+        "    MCP invocations:       {}",
+        report.mcp_invocations_redacted
+    );
+    eprintln!(
+        "    Staged actions:        {}",
+        report.staged_actions_redacted
+    );
+    eprintln!(
+        "    Post intents:          {}",
+        report.post_intents_redacted
+    );
+    eprintln!("    Hook runs:             {}", report.hook_runs_redacted);
+    eprintln!("    Action log:            {}", report.action_log_redacted);
+    eprintln!();
+    if report.total_affected == 0 {
+        eprintln!("No stored data referenced this user.");
+    } else {
+        eprintln!("Total rows affected: {}", report.total_affected);
+    }
+}
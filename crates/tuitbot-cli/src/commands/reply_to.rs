@@ -0,0 +1,150 @@
+//! Implementation of the `tuitbot reply-to` command.
+//!
+//! `tuitbot reply-to <tweet-url>` fetches one post, runs it through the
+//! same gates, scoring, and reply generation as the discovery loop, and
+//! prints 2-3 candidate replies with their QA reports. `--pick N` queues
+//! candidate N for approval; `--pick N --send` posts it right away, which
+//! is refused when it has hard QA flags or the tweet needs review.
+
+use tuitbot_core::automation::loop_helpers::SafetyChecker;
+use tuitbot_core::automation::manual_reply::{self, ManualReply};
+use tuitbot_core::automation::PostExecutor;
+use tuitbot_core::config::Config;
+use tuitbot_core::storage::action_log;
+use tuitbot_core::workflow::ingest::parse_tweet_url;
+
+use super::{OutputFormat, ReplyToArgs};
+use crate::deps::RuntimeDeps;
+use crate::output::write_stdout;
+
+/// Execute the `tuitbot reply-to` command.
+pub async fn execute(
+    config: &Config,
+    args: ReplyToArgs,
+    output: OutputFormat,
+) -> anyhow::Result<()> {
+    let Some((tweet_id, _)) = parse_tweet_url(&args.url) else {
+        anyhow::bail!("{} is not an X post URL.", args.url);
+    };
+    let pick = match args.pick {
+        Some(0) => anyhow::bail!("--pick is 1-based."),
+        pick => pick.map(|n| n - 1),
+    };
+
+    let deps = RuntimeDeps::init(config, false).await?;
+    let tweet = manual_reply::fetch_tweet(deps.x_client.as_ref(), &tweet_id)
+        .await
+        .map_err(|e| anyhow::anyhow!("{e}"))?;
+    let reply = manual_reply::prepare(
+        deps.scorer.as_ref(),
+        deps.reply_gen.as_ref(),
+        deps.safety.as_ref(),
+        config,
+        &tweet,
+        usize::from(args.candidates),
+        args.mention_product,
+    )
+    .await
+    .map_err(|e| anyhow::anyhow!("Cannot reply to @{}: {e}", tweet.author_username))?;
+
+    if output.is_json() {
+        write_stdout(&serde_json::to_string(&reply)?)?;
+    } else {
+        print_reply(&reply);
+    }
+
+    let Some(index) = pick else {
+        if !output.is_json() {
+            eprintln!();
+            eprintln!(
+                "Run again with --pick N to queue a candidate, or --pick N --send to post it."
+            );
+        }
+        return Ok(());
+    };
+    if index >= reply.candidates.len() {
+        anyhow::bail!(
+            "--pick {} is out of range; {} candidate(s) drafted.",
+            index + 1,
+            reply.candidates.len()
+        );
+    }
+
+    if args.send {
+        send(&deps, &reply, index).await
+    } else {
+        let id = manual_reply::queue_candidate(&deps.pool, &reply, index).await?;
+        eprintln!("Queued candidate {} for approval as item {id}.", index + 1);
+        Ok(())
+    }
+}
+
+/// Post candidate `index` directly, then record it like a loop reply.
+async fn send(deps: &RuntimeDeps, reply: &ManualReply, index: usize) -> anyhow::Result<()> {
+    if let Err(reason) = reply.can_send(index) {
+        anyhow::bail!("Not sending: {reason}.");
+    }
+    if !deps.safety.can_reply().await {
+        anyhow::bail!("Not sending: the daily reply limit is reached.");
+    }
+
+    let text = &reply.candidates[index].text;
+    let posted_id = deps
+        .post_executor
+        .execute_reply(&reply.tweet_id, text, &[])
+        .await
+        .map_err(|e| anyhow::anyhow!("Posting failed: {e}"))?;
+    if let Err(e) = deps
+        .safety
+        .record_reply_explained(&reply.tweet_id, text, reply.explanation(index))
+        .await
+    {
+        tracing::warn!(error = %e, "Failed to record manual reply");
+    }
+    action_log::log_action(
+        &deps.pool,
+        "manual_reply",
+        "success",
+        Some(&format!("Replied to @{} ({posted_id})", reply.author)),
+        None,
+    )
+    .await?;
+    eprintln!("Posted candidate {} as {posted_id}.", index + 1);
+    Ok(())
+}
+
+fn print_reply(reply: &ManualReply) {
+    let threshold = if reply.meets_threshold {
+        "meets threshold"
+    } else {
+        "below threshold"
+    };
+    eprintln!("@{}: {}", reply.author, reply.text);
+    eprintln!("Score {:.0} ({threshold})", reply.score);
+    if !reply.matched_keywords.is_empty() {
+        eprintln!("Keywords: {}", reply.matched_keywords.join(", "));
+    }
+    if let Some(reason) = &reply.needs_review {
+        eprintln!("Needs review: {reason}");
+    }
+
+    for (i, candidate) in reply.candidates.iter().enumerate() {
+        let check = &candidate.check;
+        eprintln!();
+        eprintln!(
+            "[{}] QA {:.0}/100{}{}",
+            i + 1,
+            check.score,
+            candidate
+                .archetype
+                .as_deref()
+                .map(|a| format!(", {a}"))
+                .unwrap_or_default(),
+            if check.passed { "" } else { ", BLOCKED" }
+        );
+        eprintln!("    {}", candidate.text);
+        for flag in check.hard_flags.iter().chain(&check.soft_flags) {
+            eprintln!("    {:<4} {}: {}", flag.severity, flag.code, flag.message);
+        }
+    }
+}
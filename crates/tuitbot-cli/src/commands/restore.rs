@@ -1,7 +1,7 @@
 //! Implementation of the `tuitbot restore` command.
 
 use std::io::IsTerminal;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use tuitbot_core::startup::data_dir;
 use tuitbot_core::storage;
@@ -11,14 +11,23 @@ use super::RestoreArgs;
 /// Execute the `tuitbot restore` command.
 pub async fn execute(args: RestoreArgs) -> anyhow::Result<()> {
     let backup_path = PathBuf::from(&args.backup_path);
+    restore_file(&backup_path, args.force, args.validate_only).await
+}
 
+/// Validate `backup_path` and, unless `validate_only`, restore it over the
+/// current database.
+pub(crate) async fn restore_file(
+    backup_path: &Path,
+    force: bool,
+    validate_only: bool,
+) -> anyhow::Result<()> {
     if !backup_path.exists() {
         anyhow::bail!("Backup file not found: {}", backup_path.display());
     }
 
     // Validate.
     eprintln!("Validating backup: {}", backup_path.display());
-    let validation = storage::backup::validate_backup(&backup_path).await?;
+    let validation = storage::backup::validate_backup(backup_path).await?;
 
     for msg in &validation.messages {
         eprintln!("  {msg}");
@@ -30,14 +39,14 @@ pub async fn execute(args: RestoreArgs) -> anyhow::Result<()> {
 
     eprintln!("  Tables: {}", validation.tables.join(", "));
 
-    if args.validate_only {
+    if validate_only {
         eprintln!("\nValidation passed. Use without --validate-only to restore.");
         return Ok(());
     }
 
     // Confirm unless --force.
     let target = data_dir().join("tuitbot.db");
-    if !args.force && std::io::stdin().is_terminal() {
+    if !force && std::io::stdin().is_terminal() {
         eprintln!("\nThis will replace the database at {}", target.display());
         eprintln!("A safety backup of the current database will be created first.");
         eprint!("Continue? [y/N] ");
@@ -51,7 +60,7 @@ pub async fn execute(args: RestoreArgs) -> anyhow::Result<()> {
 
     // Restore.
     eprintln!("Restoring...");
-    storage::backup::restore_from_backup(&backup_path, &target).await?;
+    storage::backup::restore_from_backup(backup_path, &target).await?;
     eprintln!("Restore complete: {}", target.display());
     eprintln!("Restart the server or run `tuitbot test` to verify connectivity.");
 
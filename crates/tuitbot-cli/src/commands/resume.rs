@@ -0,0 +1,67 @@
+//! Implementation of the `tuitbot resume` command.
+//!
+//! Lifts safe mode, which `tuitbot run` enters at startup when the previous
+//! session crashed or ended on a streak of posting failures. A kill switch
+//! engaged by hand is left alone; release it with `tuitbot kill --release`.
+
+use tuitbot_core::automation::safe_mode;
+use tuitbot_core::config::Config;
+use tuitbot_core::storage;
+
+use super::{OutputFormat, ResumeArgs};
+use crate::output::write_stdout;
+
+/// Execute the `tuitbot resume` command.
+pub async fn execute(
+    config: &Config,
+    args: ResumeArgs,
+    output: OutputFormat,
+) -> anyhow::Result<()> {
+    let pool = storage::init_db(&config.storage.db_path).await?;
+
+    let reason = safe_mode::safe_mode_reason(&pool).await;
+    let resumed = match &reason {
+        Ok(Some(_)) if !args.status => safe_mode::resume(&pool, "cli")
+            .await
+            .map(|state| state.is_some()),
+        _ => Ok(false),
+    };
+    let kill_switch = storage::kill_switch::get_kill_switch(&pool).await;
+    pool.close().await;
+    let (reason, resumed, kill_switch) = (reason?, resumed?, kill_switch?);
+
+    if output.is_json() {
+        write_stdout(&serde_json::to_string(&serde_json::json!({
+            "safe_mode": reason.is_some() && !resumed,
+            "reason": reason,
+            "resumed": resumed,
+            "kill_switch_active": kill_switch.active,
+        }))?)?;
+        return Ok(());
+    }
+
+    match (reason, resumed) {
+        (Some(reason), true) => {
+            eprintln!("Safe mode lifted — posting is re-enabled.");
+            eprintln!("  Trigger was: {reason}");
+            eprintln!("A running `tuitbot run` starts its posting loops within a minute.");
+        }
+        (Some(reason), false) => {
+            eprintln!("SAFE MODE — posting is disabled, only read loops run.");
+            eprintln!("  Trigger: {reason}");
+            eprintln!();
+            eprintln!("Run `tuitbot resume` to re-enable posting.");
+        }
+        (None, _) => {
+            eprintln!("Not in safe mode.");
+            if kill_switch.active {
+                eprintln!(
+                    "The kill switch is engaged ({}); run `tuitbot kill --release` to lift it.",
+                    kill_switch.reason.as_deref().unwrap_or("no reason given")
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -7,26 +7,42 @@
 use std::sync::Arc;
 use std::time::Duration;
 
+use tuitbot_core::automation::adapters::{ApprovalQueueAdapter, RedditSearchAdapter};
 use tuitbot_core::automation::circuit_breaker::CircuitBreaker;
+use tuitbot_core::automation::mention_ack::AckPolicy;
+use tuitbot_core::automation::safe_mode::{self, run_after_resume};
 use tuitbot_core::automation::{
-    run_approval_poster, run_posting_queue_with_approval, run_token_refresh_loop,
+    run_account_health_monitor, run_approval_poster, run_approval_triage, run_blocklist_sync,
+    run_content_mix_loop, run_posting_queue_with_approval, run_reddit_poster,
+    run_remote_backup_loop, run_token_refresh_loop, run_usage_reconciliation_loop, run_warmup_loop,
     scheduler_from_config, status_reporter::run_status_reporter, AnalyticsLoop, ContentLoop,
-    DiscoveryLoop, MentionsLoop, PostExecutor, Runtime, TargetLoop, ThreadLoop,
+    DiscoveryLoop, DiscoverySourceRegistry, MentionsLoop, PostExecutor, RedditLoop, Runtime,
+    SessionTracker, TargetLoop, ThreadLoop,
 };
 use tuitbot_core::config::{Config, OperatingMode};
+use tuitbot_core::reddit::{RedditApi, RedditHttpClient};
 use tuitbot_core::startup::format_startup_banner;
+use tuitbot_core::storage::accounts::DEFAULT_ACCOUNT_ID;
+use tuitbot_core::toolkit::media::MediaLimits;
 use tuitbot_core::x_api::XApiClient;
 
+use tuitbot_server::embedded::EmbeddedServer;
+
+use super::RunArgs;
 use crate::deps::RuntimeDeps;
 
 /// Execute the `tuitbot run` command.
 ///
 /// Startup sequence:
 /// 1. Initialize all shared dependencies via `RuntimeDeps`
-/// 2. Print startup banner
-/// 3. Spawn automation loops based on tier
-/// 4. Run until shutdown
-pub async fn execute(config: &Config, status_interval: u64) -> anyhow::Result<()> {
+/// 2. Open the session, entering safe mode after a crash or failure streak
+/// 3. Print startup banner
+/// 4. Spawn automation loops based on tier (and the API server with `--with-api`);
+///    in safe mode, posting loops wait for `tuitbot resume`
+/// 5. Run until shutdown
+pub async fn execute(config: &Config, config_path: &str, args: RunArgs) -> anyhow::Result<()> {
+    let status_interval = args.status_interval;
+
     // 1. Initialize all shared dependencies.
     let mut deps = RuntimeDeps::init(config, false).await?;
 
@@ -37,11 +53,38 @@ pub async fn execute(config: &Config, status_interval: u64) -> anyhow::Result<()
         config.logging.status_interval_seconds
     };
 
-    // 3. Print startup banner (always visible, even in default mode).
+    // 3. Open this session; a crash or posting failure streak in the last
+    // one starts the agent in safe mode.
+    let (session, _) = SessionTracker::start(deps.pool.clone(), &config.safe_mode).await?;
+
+    // 4. Print startup banner (always visible, even in default mode).
     let banner = format_startup_banner(deps.tier, &deps.capabilities, effective_interval);
     eprintln!("{banner}");
+    if let Ok(state) = tuitbot_core::storage::kill_switch::get_kill_switch(&deps.pool).await {
+        if safe_mode::is_safe_mode(&state) {
+            eprintln!(
+                "SAFE MODE: {}. Posting is disabled and only read loops run until \
+                 `tuitbot resume`.",
+                state.reason.as_deref().unwrap_or("no reason given")
+            );
+        } else if state.active {
+            eprintln!(
+                "WARNING: kill switch engaged ({}). All mutations are halted until \
+                 `tuitbot kill --release`.",
+                state.reason.as_deref().unwrap_or("no reason given")
+            );
+        }
+    }
+    if let Ok(state) = tuitbot_core::storage::draft_only::get_draft_only(&deps.pool).await {
+        if state.active {
+            eprintln!(
+                "DRAFT-ONLY: posts are queued for review and approved items are held \
+                 until `tuitbot draft-only --off`."
+            );
+        }
+    }
 
-    // 4. Create runtime and spawn tasks.
+    // 5. Create runtime and spawn tasks.
     let mut runtime = Runtime::new();
     let min_delay = Duration::from_secs(config.limits.min_action_delay_seconds);
     let max_delay = Duration::from_secs(config.limits.max_action_delay_seconds);
@@ -53,6 +96,23 @@ pub async fn execute(config: &Config, status_interval: u64) -> anyhow::Result<()
         Duration::from_secs(config.circuit_breaker.cooldown_seconds),
     );
 
+    // Bind the embedded API server before spawning anything, so a port
+    // conflict aborts startup cleanly.
+    let api_server = if args.with_api {
+        let server = EmbeddedServer::bind(
+            deps.pool.clone(),
+            config,
+            tuitbot_core::startup::expand_tilde(config_path),
+            Some(circuit_breaker.clone()),
+            Some(deps.write_queue.clone()),
+        )
+        .await?;
+        eprintln!("Dashboard API: http://{}", server.local_addr()?);
+        Some(server)
+    } else {
+        None
+    };
+
     // Spawn posting queue consumer.
     let cancel = runtime.cancel_token();
     let post_rx = deps.post_rx.take().expect("post_rx not yet consumed");
@@ -78,18 +138,138 @@ pub async fn execute(config: &Config, status_interval: u64) -> anyhow::Result<()
     {
         let cancel = runtime.cancel_token();
         let tm = deps.token_manager.clone();
-        let xc = deps.x_client.clone();
-        runtime.spawn("token-refresh", run_token_refresh_loop(tm, xc, cancel));
+        runtime.spawn("token-refresh", run_token_refresh_loop(tm, cancel));
     }
 
+    // Keep this session's heartbeat fresh; closed on clean shutdown.
+    runtime.spawn(
+        "session-heartbeat",
+        session.run_heartbeat(runtime.cancel_token()),
+    );
+
     // Spawn approval poster loop (always — processes approved items from queue).
     {
         let cancel = runtime.cancel_token();
         let pool = deps.pool.clone();
+        let writes = deps.write_queue.clone();
         let xc = deps.x_client.clone() as Arc<dyn XApiClient>;
+        let media_limits = MediaLimits::from(&config.media);
+        let cb = Some(circuit_breaker.clone());
+        let hooks = deps.hooks.clone();
+        let protected = config.blocklist.protected.clone();
         runtime.spawn(
             "approval-poster",
-            run_approval_poster(pool, xc, min_delay, max_delay, cancel),
+            run_after_resume(
+                pool.clone(),
+                "approval-poster",
+                run_approval_poster(
+                    pool,
+                    Some(writes),
+                    xc,
+                    min_delay,
+                    max_delay,
+                    media_limits,
+                    cb,
+                    hooks,
+                    protected,
+                    cancel,
+                ),
+                runtime.cancel_token(),
+            ),
+        );
+    }
+
+    // Spawn approval triage (auto-approves items that clear [auto_triage]).
+    if config.auto_triage.enabled {
+        let llm = if config.auto_triage.llm_check {
+            let provider = tuitbot_core::llm::factory::create_provider(&config.llm)
+                .map_err(|e| anyhow::anyhow!("LLM provider creation failed: {e}"))?;
+            Some(Arc::from(provider))
+        } else {
+            None
+        };
+        let cancel = runtime.cancel_token();
+        let pool = deps.pool.clone();
+        runtime.spawn(
+            "approval-triage",
+            run_approval_triage(pool, config.clone(), llm, cancel),
+        );
+    }
+
+    // Spawn account health monitor (requires search to probe visibility).
+    if config.health_monitor.enabled && deps.capabilities.search {
+        let cancel = runtime.cancel_token();
+        let pool = deps.pool.clone();
+        let xc = deps.x_client.clone() as Arc<dyn XApiClient>;
+        let cb = Some(circuit_breaker.clone());
+        let health_config = config.health_monitor.clone();
+        runtime.spawn(
+            "health-monitor",
+            run_account_health_monitor(pool, xc, cb, health_config, cancel),
+        );
+    }
+
+    // Mirror X mutes and blocks into the author blocklist.
+    if config.blocklist.sync_enabled {
+        let cancel = runtime.cancel_token();
+        let pool = deps.pool.clone();
+        let xc = deps.x_client.clone() as Arc<dyn XApiClient>;
+        runtime.spawn(
+            "blocklist-sync",
+            run_blocklist_sync(
+                pool,
+                DEFAULT_ACCOUNT_ID.to_string(),
+                xc,
+                config.blocklist.clone(),
+                cancel,
+            ),
+        );
+    }
+
+    // Compare X's official post usage with the ledger and adjust pacing.
+    if config.usage_reconciliation.enabled {
+        let cancel = runtime.cancel_token();
+        let pool = deps.pool.clone();
+        let xc = deps.x_client.clone() as Arc<dyn XApiClient>;
+        runtime.spawn(
+            "usage-reconciliation",
+            run_usage_reconciliation_loop(
+                pool,
+                DEFAULT_ACCOUNT_ID.to_string(),
+                xc,
+                config.usage_reconciliation.clone(),
+                cancel,
+            ),
+        );
+    }
+
+    // Step warm-up caps up as the schedule advances.
+    if config.warmup.enabled {
+        let cancel = runtime.cancel_token();
+        let pool = deps.pool.clone();
+        runtime.spawn(
+            "warmup-scheduler",
+            run_warmup_loop(pool, DEFAULT_ACCOUNT_ID.to_string(), config.clone(), cancel),
+        );
+    }
+
+    // Plan each day's content mix and cap the day's limits to it.
+    if config.content_mix.enabled {
+        let cancel = runtime.cancel_token();
+        let pool = deps.pool.clone();
+        runtime.spawn(
+            "content-mix-planner",
+            run_content_mix_loop(pool, DEFAULT_ACCOUNT_ID.to_string(), config.clone(), cancel),
+        );
+    }
+
+    // Upload database snapshots to the remote bucket.
+    if config.remote_backup.enabled {
+        let cancel = runtime.cancel_token();
+        let pool = deps.pool.clone();
+        runtime.spawn(
+            "remote-backup",
+            run_remote_backup_loop(pool, config.remote_backup.clone(), cancel),
         );
     }
 
@@ -99,7 +279,7 @@ pub async fn execute(config: &Config, status_interval: u64) -> anyhow::Result<()
     if !is_composer {
         // Content loop (all tiers)
         {
-            let content_loop = ContentLoop::new(
+            let mut content_loop = ContentLoop::new(
                 deps.tweet_gen.clone(),
                 deps.content_safety.clone(),
                 deps.content_storage.clone(),
@@ -107,7 +287,15 @@ pub async fn execute(config: &Config, status_interval: u64) -> anyhow::Result<()
                 config.intervals.content_post_window_seconds,
                 false,
             )
-            .with_topic_scorer(deps.topic_scorer.clone());
+            .with_topic_scorer(deps.topic_scorer.clone())
+            .with_review_pillars(config.business.approval_pillars());
+            if config.trending.enabled {
+                content_loop = content_loop.with_trending(
+                    deps.trend_source.clone(),
+                    config.business.content_pillars.clone(),
+                    config.trending.share,
+                );
+            }
 
             let cancel = runtime.cancel_token();
             let scheduler = scheduler_from_config(
@@ -116,9 +304,17 @@ pub async fn execute(config: &Config, status_interval: u64) -> anyhow::Result<()
                 config.limits.max_action_delay_seconds,
             );
             let schedule = deps.active_schedule.clone();
-            runtime.spawn("content-loop", async move {
-                content_loop.run(cancel, scheduler, schedule).await;
-            });
+            runtime.spawn(
+                "content-loop",
+                run_after_resume(
+                    deps.pool.clone(),
+                    "content-loop",
+                    async move {
+                        content_loop.run(cancel, scheduler, schedule).await;
+                    },
+                    runtime.cancel_token(),
+                ),
+            );
         }
 
         // Thread loop (all tiers)
@@ -131,7 +327,8 @@ pub async fn execute(config: &Config, status_interval: u64) -> anyhow::Result<()
                 config.business.effective_industry_topics().to_vec(),
                 config.intervals.thread_interval_seconds,
                 false,
-            );
+            )
+            .with_format(config.thread_format.clone());
 
             let cancel = runtime.cancel_token();
             let scheduler = scheduler_from_config(
@@ -140,16 +337,24 @@ pub async fn execute(config: &Config, status_interval: u64) -> anyhow::Result<()
                 config.limits.max_action_delay_seconds,
             );
             let schedule = deps.active_schedule.clone();
-            runtime.spawn("thread-loop", async move {
-                thread_loop.run(cancel, scheduler, schedule).await;
-            });
+            runtime.spawn(
+                "thread-loop",
+                run_after_resume(
+                    deps.pool.clone(),
+                    "thread-loop",
+                    async move {
+                        thread_loop.run(cancel, scheduler, schedule).await;
+                    },
+                    runtime.cancel_token(),
+                ),
+            );
         }
     }
 
     // --- Tier-gated loops ---
     if deps.capabilities.discovery {
         // Discovery loop: in composer mode, run with dry_run=true (read-only).
-        let discovery_loop = DiscoveryLoop::new(
+        let mut discovery_loop = DiscoveryLoop::new(
             deps.searcher.clone(),
             deps.scorer.clone(),
             deps.reply_gen.clone(),
@@ -159,7 +364,21 @@ pub async fn execute(config: &Config, status_interval: u64) -> anyhow::Result<()
             deps.keywords.clone(),
             config.scoring.threshold as f32,
             is_composer, // dry_run in composer mode
-        );
+        )
+        .with_quotes(deps.reply_gen.clone(), config.quote_tweets.clone())
+        .with_dedup_window(Duration::from_secs(
+            config.intervals.discovery_dedup_window_seconds,
+        ))
+        .with_generation_concurrency(config.llm.generation_concurrency())
+        .with_sources(DiscoverySourceRegistry::from_config(
+            &config.discovery_sources,
+        ));
+        if let Some(pacer) = &deps.pacer {
+            discovery_loop = discovery_loop.with_pacer(pacer.clone());
+        }
+        if let Some(calibrator) = &deps.calibrator {
+            discovery_loop = discovery_loop.with_calibrator(calibrator.clone());
+        }
 
         let cancel = runtime.cancel_token();
         let scheduler = scheduler_from_config(
@@ -168,9 +387,22 @@ pub async fn execute(config: &Config, status_interval: u64) -> anyhow::Result<()
             config.limits.max_action_delay_seconds,
         );
         let schedule = deps.active_schedule.clone();
-        runtime.spawn("discovery-loop", async move {
+        let task = async move {
             discovery_loop.run(cancel, scheduler, schedule).await;
-        });
+        };
+        if is_composer {
+            runtime.spawn("discovery-loop", task);
+        } else {
+            runtime.spawn(
+                "discovery-loop",
+                run_after_resume(
+                    deps.pool.clone(),
+                    "discovery-loop",
+                    task,
+                    runtime.cancel_token(),
+                ),
+            );
+        }
     }
 
     if deps.capabilities.mentions && !is_composer {
@@ -181,6 +413,11 @@ pub async fn execute(config: &Config, status_interval: u64) -> anyhow::Result<()
             deps.safety.clone(),
             deps.post_sender.clone(),
             false,
+        )
+        .with_max_age_hours(config.scoring.freshness.mentions_max_age_hours)
+        .with_auto_ack(
+            AckPolicy::new(config.mention_ack.clone()),
+            deps.mentions_fetcher.clone(),
         );
 
         let cancel = runtime.cancel_token();
@@ -191,14 +428,22 @@ pub async fn execute(config: &Config, status_interval: u64) -> anyhow::Result<()
         );
         let schedule = deps.active_schedule.clone();
         let storage_clone = deps.loop_storage.clone();
-        runtime.spawn("mentions-loop", async move {
-            mentions_loop
-                .run(cancel, scheduler, schedule, storage_clone)
-                .await;
-        });
+        runtime.spawn(
+            "mentions-loop",
+            run_after_resume(
+                deps.pool.clone(),
+                "mentions-loop",
+                async move {
+                    mentions_loop
+                        .run(cancel, scheduler, schedule, storage_clone)
+                        .await;
+                },
+                runtime.cancel_token(),
+            ),
+        );
 
         // Target loop (autopilot only)
-        let target_loop = TargetLoop::new(
+        let mut target_loop = TargetLoop::new(
             deps.target_adapter.clone(),
             deps.target_adapter.clone(),
             deps.reply_gen.clone(),
@@ -207,6 +452,9 @@ pub async fn execute(config: &Config, status_interval: u64) -> anyhow::Result<()
             deps.post_sender.clone(),
             deps.target_loop_config.clone(),
         );
+        if let Some(pacer) = &deps.pacer {
+            target_loop = target_loop.with_pacer(pacer.clone());
+        }
 
         let cancel = runtime.cancel_token();
         let scheduler = scheduler_from_config(
@@ -215,9 +463,17 @@ pub async fn execute(config: &Config, status_interval: u64) -> anyhow::Result<()
             config.limits.max_action_delay_seconds,
         );
         let schedule = deps.active_schedule.clone();
-        runtime.spawn("target-loop", async move {
-            target_loop.run(cancel, scheduler, schedule).await;
-        });
+        runtime.spawn(
+            "target-loop",
+            run_after_resume(
+                deps.pool.clone(),
+                "target-loop",
+                async move {
+                    target_loop.run(cancel, scheduler, schedule).await;
+                },
+                runtime.cancel_token(),
+            ),
+        );
     }
 
     // Analytics loop runs in both modes (passive data collection).
@@ -235,6 +491,54 @@ pub async fn execute(config: &Config, status_interval: u64) -> anyhow::Result<()
         });
     }
 
+    // --- Reddit (independent of the X tier; comments always go through approval) ---
+    if config.reddit.enabled {
+        let client: Arc<dyn RedditApi> = Arc::new(RedditHttpClient::new(&config.reddit));
+        let reddit_loop = RedditLoop::new(
+            Arc::new(RedditSearchAdapter::new(
+                client.clone(),
+                config.reddit.max_results,
+            )),
+            deps.scorer.clone(),
+            deps.reply_gen.clone(),
+            deps.loop_storage.clone(),
+            Arc::new(ApprovalQueueAdapter::new(deps.pool.clone())),
+            config.reddit.subreddits.clone(),
+            deps.keywords.clone(),
+            false,
+        )
+        .with_protected_entities(&config.blocklist.protected);
+
+        let cancel = runtime.cancel_token();
+        let scheduler = scheduler_from_config(
+            config.reddit.check_interval_seconds,
+            config.limits.min_action_delay_seconds,
+            config.limits.max_action_delay_seconds,
+        );
+        let schedule = deps.active_schedule.clone();
+        runtime.spawn("reddit-loop", async move {
+            reddit_loop.run(cancel, scheduler, schedule).await;
+        });
+
+        let cancel = runtime.cancel_token();
+        let pool = deps.pool.clone();
+        runtime.spawn(
+            "reddit-poster",
+            run_after_resume(
+                pool.clone(),
+                "reddit-poster",
+                run_reddit_poster(pool, client, min_delay, max_delay, cancel),
+                runtime.cancel_token(),
+            ),
+        );
+    }
+
+    // --- Embedded API server ---
+    if let Some(server) = api_server {
+        let cancel = runtime.cancel_token();
+        runtime.spawn("api-server", server.serve(cancel));
+    }
+
     // --- Status reporter ---
     if effective_interval > 0 {
         let scheduler = scheduler_from_config(effective_interval, 0, 0);
@@ -250,7 +554,7 @@ pub async fn execute(config: &Config, status_interval: u64) -> anyhow::Result<()
         "All automation loops spawned, running until shutdown"
     );
 
-    // 5. Run until shutdown signal.
+    // 6. Run until shutdown signal.
     runtime.run_until_shutdown().await;
 
     tracing::info!("Shutdown complete.");
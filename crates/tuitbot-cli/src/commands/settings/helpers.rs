@@ -1,3 +1,5 @@
+use std::collections::BTreeMap;
+
 use anyhow::{bail, Context, Result};
 use console::Style;
 use dialoguer::{Confirm, Input, MultiSelect, Select};
@@ -290,3 +292,20 @@ pub(super) fn format_toml_array(items: &[String]) -> String {
         .collect();
     format!("[{}]", inner.join(", "))
 }
+
+pub(super) fn format_toml_weights(weights: &BTreeMap<String, u32>) -> String {
+    let inner: Vec<String> = weights
+        .iter()
+        .map(|(key, weight)| {
+            if key
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+            {
+                format!("{key} = {weight}")
+            } else {
+                format!("\"{}\" = {weight}", escape_toml(key))
+            }
+        })
+        .collect();
+    format!("{{ {} }}", inner.join(", "))
+}
@@ -0,0 +1,142 @@
+//! `tuitbot settings history` and `tuitbot settings rollback`.
+//!
+//! Every config write records a snapshot (see
+//! `tuitbot_core::config::history`); these commands list the snapshots with
+//! the settings each one changed and restore an earlier one.
+
+use std::io::IsTerminal;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use console::Style;
+use serde::Serialize;
+use tuitbot_core::config::history::{self, ConfigChange, ConfigSnapshot};
+
+use crate::commands::OutputFormat;
+use crate::output::write_stdout;
+
+#[derive(Serialize)]
+struct HistoryEntry {
+    #[serde(flatten)]
+    snapshot: ConfigSnapshot,
+    current: bool,
+    /// `None` for the oldest version kept.
+    changes: Option<Vec<ConfigChange>>,
+}
+
+/// List the newest `limit` snapshots with their changes.
+pub(super) fn show_history(config_path: &Path, limit: usize, output: OutputFormat) -> Result<()> {
+    let snapshots = history::list_snapshots(config_path)?;
+    let current = std::fs::read_to_string(config_path).ok();
+
+    let mut entries = Vec::new();
+    for snapshot in snapshots.into_iter().rev().take(limit) {
+        let contents = history::read_snapshot(config_path, snapshot.id)?;
+        entries.push(HistoryEntry {
+            current: current.as_deref() == Some(contents.as_str()),
+            changes: history::snapshot_changes(config_path, snapshot.id)?,
+            snapshot,
+        });
+    }
+
+    if output.is_json() {
+        write_stdout(&serde_json::to_string(&entries)?)?;
+        return Ok(());
+    }
+
+    if entries.is_empty() {
+        eprintln!(
+            "No saved config versions yet. Versions are recorded whenever settings are changed."
+        );
+        return Ok(());
+    }
+
+    let bold = Style::new().bold();
+    let dim = Style::new().dim();
+    for entry in &entries {
+        let marker = if entry.current { "  (current)" } else { "" };
+        eprintln!(
+            "{}  {}  {}{}",
+            bold.apply_to(format!("#{}", entry.snapshot.id)),
+            entry.snapshot.created_at.format("%Y-%m-%d %H:%M:%S UTC"),
+            entry.snapshot.source,
+            marker,
+        );
+        match &entry.changes {
+            None => eprintln!("    {}", dim.apply_to("oldest saved version")),
+            Some(changes) if changes.is_empty() => {
+                eprintln!("    {}", dim.apply_to("no setting changes"))
+            }
+            Some(changes) => {
+                for change in changes {
+                    eprintln!("    {}", format_change(change));
+                }
+            }
+        }
+        eprintln!();
+    }
+    eprintln!(
+        "{}",
+        dim.apply_to("Restore a version with: tuitbot settings rollback <id>")
+    );
+    Ok(())
+}
+
+/// Restore snapshot `id`, after confirming the changes it will make.
+pub(super) fn rollback(
+    config_path: &Path,
+    id: u64,
+    force: bool,
+    output: OutputFormat,
+) -> Result<()> {
+    let target = history::read_snapshot(config_path, id)?;
+    let current = std::fs::read_to_string(config_path).unwrap_or_default();
+    let changes = history::diff_configs(&current, &target);
+
+    if changes.is_empty() && current == target {
+        eprintln!("Config already matches version #{id}. Nothing to do.");
+        return Ok(());
+    }
+
+    if !force && !output.is_json() && std::io::stdin().is_terminal() {
+        eprintln!("Rolling back to version #{id} will change:");
+        for change in &changes {
+            eprintln!("    {}", format_change(change));
+        }
+        eprint!("Continue? [y/N] ");
+        let mut input = String::new();
+        std::io::stdin().read_line(&mut input)?;
+        if !input.trim().eq_ignore_ascii_case("y") {
+            eprintln!("Aborted.");
+            return Ok(());
+        }
+    }
+
+    let snapshot = history::rollback(config_path, id)
+        .with_context(|| format!("Failed to roll back to version #{id}"))?;
+
+    if output.is_json() {
+        write_stdout(&serde_json::to_string(&serde_json::json!({
+            "restored": id,
+            "snapshot": snapshot,
+            "changes": changes,
+        }))?)?;
+    } else {
+        eprintln!(
+            "Restored version #{id} to {} (recorded as #{}).",
+            config_path.display(),
+            snapshot.id
+        );
+        eprintln!("Restart tuitbot for the change to take effect.");
+    }
+    Ok(())
+}
+
+pub(super) fn format_change(change: &ConfigChange) -> String {
+    match (&change.old, &change.new) {
+        (Some(old), Some(new)) => format!("{}: {old} -> {new}", change.key),
+        (None, Some(new)) => format!("+ {} = {new}", change.key),
+        (Some(old), None) => format!("- {} (was {old})", change.key),
+        (None, None) => change.key.clone(),
+    }
+}
@@ -5,9 +5,14 @@
 /// - `tuitbot settings --show`       — pretty-print current config
 /// - `tuitbot settings --set K=V`    — direct one-shot set
 /// - `tuitbot settings <category>`   — jump to a specific category
+/// - `tuitbot settings history`      — list saved config versions
+/// - `tuitbot settings rollback <id>` — restore a saved version
+/// - `tuitbot settings preset apply <name>` — apply a policy preset
 mod enrich;
 mod helpers;
+mod history;
 mod interactive;
+mod preset;
 mod render;
 mod set;
 mod show;
@@ -21,11 +26,28 @@ use std::path::PathBuf;
 use anyhow::{bail, Result};
 use tuitbot_core::config::Config;
 
-use super::{OutputFormat, SettingsArgs};
+use super::{OutputFormat, PresetAction, SettingsArgs, SettingsSubcommand};
 
 /// Entry point for the settings command.
 pub async fn execute(args: SettingsArgs, config_path: &str, output: OutputFormat) -> Result<()> {
     let expanded = expand_tilde(config_path);
+
+    // These work even when the current file is missing or no longer loads.
+    match &args.command {
+        Some(SettingsSubcommand::History { limit }) => {
+            return history::show_history(&expanded, *limit, output);
+        }
+        Some(SettingsSubcommand::Rollback { id, force }) => {
+            return history::rollback(&expanded, *id, *force, output);
+        }
+        Some(SettingsSubcommand::Preset {
+            action: PresetAction::List,
+        }) => {
+            return preset::list_presets(output);
+        }
+        Some(SettingsSubcommand::Preset { .. }) | None => {}
+    }
+
     if !expanded.exists() {
         bail!(
             "Config file not found: {}\nRun 'tuitbot init' first.",
@@ -40,6 +62,18 @@ pub async fn execute(args: SettingsArgs, config_path: &str, output: OutputFormat
         )
     })?;
 
+    if let Some(SettingsSubcommand::Preset {
+        action:
+            PresetAction::Apply {
+                name,
+                force,
+                dry_run,
+            },
+    }) = &args.command
+    {
+        return preset::apply_preset(config, name, &expanded, *force, *dry_run, output);
+    }
+
     if args.show {
         if output.is_json() {
             show::show_config_json(&config)?;
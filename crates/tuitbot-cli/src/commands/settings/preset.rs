@@ -0,0 +1,133 @@
+//! `tuitbot settings preset` — list and apply policy presets.
+//!
+//! Applying a preset shows the settings it changes first. The values are
+//! written into config.toml like any other edit, so each one can still be
+//! changed individually afterwards.
+
+use std::io::IsTerminal;
+use std::path::Path;
+
+use anyhow::{anyhow, Result};
+use console::Style;
+use serde::Serialize;
+use tuitbot_core::config::history::{self, ConfigChange};
+use tuitbot_core::config::{Config, PolicyPreset};
+
+use super::history::format_change;
+use super::render::{render_config, validate_config, write_config_with_backup};
+use crate::commands::OutputFormat;
+use crate::output::write_stdout;
+
+#[derive(Serialize)]
+struct PresetEntry {
+    name: PolicyPreset,
+    description: &'static str,
+}
+
+/// List the available presets.
+pub(super) fn list_presets(output: OutputFormat) -> Result<()> {
+    if output.is_json() {
+        let entries: Vec<PresetEntry> = PolicyPreset::ALL
+            .into_iter()
+            .map(|preset| PresetEntry {
+                name: preset,
+                description: preset.description(),
+            })
+            .collect();
+        write_stdout(&serde_json::to_string(&entries)?)?;
+        return Ok(());
+    }
+
+    let bold = Style::new().bold();
+    for preset in PolicyPreset::ALL {
+        eprintln!(
+            "{:<14}{}",
+            bold.apply_to(preset.name()),
+            preset.description()
+        );
+    }
+    eprintln!();
+    eprintln!(
+        "{}",
+        Style::new()
+            .dim()
+            .apply_to("Apply one with: tuitbot settings preset apply <name>")
+    );
+    Ok(())
+}
+
+/// The settings `preset` would change in `config`, and the updated config.
+pub(super) fn preview(config: &Config, preset: PolicyPreset) -> (Config, Vec<ConfigChange>) {
+    let mut updated = config.clone();
+    preset.apply(&mut updated);
+    let changes = history::diff_configs(&render_config(config), &render_config(&updated));
+    (updated, changes)
+}
+
+/// Show the changes `name` makes and write them after confirmation.
+pub(super) fn apply_preset(
+    config: Config,
+    name: &str,
+    config_path: &Path,
+    force: bool,
+    dry_run: bool,
+    output: OutputFormat,
+) -> Result<()> {
+    let preset: PolicyPreset = name.parse().map_err(|e: String| anyhow!(e))?;
+    let (updated, changes) = preview(&config, preset);
+
+    if output.is_json() {
+        if !dry_run && !changes.is_empty() {
+            validate_config(&updated)?;
+            write_config_with_backup(
+                &updated,
+                &config_path.display().to_string(),
+                &format!("settings preset {preset}"),
+            )?;
+        }
+        write_stdout(&serde_json::to_string(&serde_json::json!({
+            "preset": preset,
+            "applied": !dry_run && !changes.is_empty(),
+            "changes": changes,
+        }))?)?;
+        return Ok(());
+    }
+
+    if changes.is_empty() {
+        eprintln!("Config already matches the {preset} preset. Nothing to do.");
+        return Ok(());
+    }
+
+    eprintln!("The {preset} preset will change:");
+    for change in &changes {
+        eprintln!("    {}", format_change(change));
+    }
+
+    if dry_run {
+        eprintln!("Dry run: config not written.");
+        return Ok(());
+    }
+
+    validate_config(&updated)?;
+
+    if !force && std::io::stdin().is_terminal() {
+        eprint!("Apply? [y/N] ");
+        let mut input = String::new();
+        std::io::stdin().read_line(&mut input)?;
+        if !input.trim().eq_ignore_ascii_case("y") {
+            eprintln!("Aborted.");
+            return Ok(());
+        }
+    }
+
+    write_config_with_backup(
+        &updated,
+        &config_path.display().to_string(),
+        &format!("settings preset {preset}"),
+    )?;
+    eprintln!(
+        "Applied the {preset} preset to {}. Adjust individual values with `tuitbot settings --set`.",
+        config_path.display()
+    );
+    Ok(())
+}
@@ -4,9 +4,9 @@ use std::path::Path;
 use anyhow::{bail, Context, Result};
 use console::Style;
 use dialoguer::Confirm;
-use tuitbot_core::config::Config;
+use tuitbot_core::config::{history, Config};
 
-use super::helpers::{escape_toml, format_toml_array, ChangeTracker};
+use super::helpers::{escape_toml, format_toml_array, format_toml_weights, ChangeTracker};
 
 pub(super) fn render_config(config: &Config) -> String {
     let client_secret_line = match &config.x_api.client_secret {
@@ -64,13 +64,26 @@ pub(super) fn render_config(config: &Config) -> String {
         )
     };
 
-    let targets_section = if config.targets.accounts.is_empty() {
-        "# --- Target Accounts ---\n\
-         # Monitor specific accounts and reply to their conversations.\n\
-         [targets]\n\
-         # accounts = [\"elonmusk\", \"levelsio\"]\n\
-         accounts = []"
+    let reply_archetype_weights_line = if config.business.reply_archetype_weights.is_empty() {
+        "# reply_archetype_weights = { ask_question = 30, agree_and_expand = 30, add_data = 20 }"
             .to_string()
+    } else {
+        format!(
+            "reply_archetype_weights = {}",
+            format_toml_weights(&config.business.reply_archetype_weights)
+        )
+    };
+
+    let targets_section = if config.targets.accounts.is_empty() {
+        format!(
+            "# --- Target Accounts ---\n\
+             # Monitor specific accounts and reply to their conversations.\n\
+             [targets]\n\
+             # accounts = [\"elonmusk\", \"levelsio\"]\n\
+             accounts = []\n\
+             max_target_replies_per_day = {max_target}",
+            max_target = config.targets.max_target_replies_per_day,
+        )
     } else {
         format!(
             "# --- Target Accounts ---\n\
@@ -145,6 +158,7 @@ industry_topics = {industry_topics}
 {persona_opinions_line}
 {persona_experiences_line}
 {content_pillars_line}
+{reply_archetype_weights_line}
 
 # --- Scoring Engine ---
 # Controls how tweets are scored for reply-worthiness (0-100 scale).
@@ -164,6 +178,8 @@ content_type_max = {content_type_max:.1}
 max_replies_per_day = {max_replies_per_day}
 max_tweets_per_day = {max_tweets_per_day}
 max_threads_per_week = {max_threads_per_week}
+max_likes_per_day = {max_likes_per_day}
+max_follows_per_day = {max_follows_per_day}
 min_action_delay_seconds = {min_action_delay_seconds}
 max_action_delay_seconds = {max_action_delay_seconds}
 max_replies_per_author_per_day = {max_replies_per_author_per_day}
@@ -225,6 +241,7 @@ active_days = {active_days}
         persona_opinions_line = persona_opinions_line,
         persona_experiences_line = persona_experiences_line,
         content_pillars_line = content_pillars_line,
+        reply_archetype_weights_line = reply_archetype_weights_line,
         threshold = config.scoring.threshold,
         keyword_relevance_max = config.scoring.keyword_relevance_max,
         follower_count_max = config.scoring.follower_count_max,
@@ -235,6 +252,8 @@ active_days = {active_days}
         max_replies_per_day = config.limits.max_replies_per_day,
         max_tweets_per_day = config.limits.max_tweets_per_day,
         max_threads_per_week = config.limits.max_threads_per_week,
+        max_likes_per_day = config.limits.max_likes_per_day,
+        max_follows_per_day = config.limits.max_follows_per_day,
         min_action_delay_seconds = config.limits.min_action_delay_seconds,
         max_action_delay_seconds = config.limits.max_action_delay_seconds,
         max_replies_per_author_per_day = config.limits.max_replies_per_author_per_day,
@@ -259,7 +278,12 @@ active_days = {active_days}
     )
 }
 
-pub(super) fn write_config_with_backup(config: &Config, config_path: &str) -> Result<()> {
+/// Write the config, keeping a `.bak` copy and recording a history snapshot.
+pub(super) fn write_config_with_backup(
+    config: &Config,
+    config_path: &str,
+    source: &str,
+) -> Result<()> {
     let path = super::expand_tilde(config_path);
 
     // Create backup
@@ -270,7 +294,7 @@ pub(super) fn write_config_with_backup(config: &Config, config_path: &str) -> Re
     }
 
     let toml_str = render_config(config);
-    fs::write(&path, toml_str)
+    history::write_config(&path, &toml_str, source)
         .with_context(|| format!("Failed to write config to {}", path.display()))?;
 
     Ok(())
@@ -337,7 +361,7 @@ pub(super) fn save_flow(
     }
 
     let path_str = config_path.display().to_string();
-    write_config_with_backup(config, &path_str)?;
+    write_config_with_backup(config, &path_str, "settings")?;
 
     eprintln!("Saved to {}", config_path.display());
 
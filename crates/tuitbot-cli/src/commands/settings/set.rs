@@ -299,6 +299,27 @@ pub(super) fn set_direct(config: &mut Config, kv: &str, config_path: &Path) -> R
             "content_type_max",
             value,
         )?,
+        "scoring.freshness.discovery_decay_after_hours" => set_u32(
+            &mut tracker,
+            &mut config.scoring.freshness.discovery_decay_after_hours,
+            "scoring.freshness",
+            "discovery_decay_after_hours",
+            value,
+        )?,
+        "scoring.freshness.discovery_max_age_hours" => set_u32(
+            &mut tracker,
+            &mut config.scoring.freshness.discovery_max_age_hours,
+            "scoring.freshness",
+            "discovery_max_age_hours",
+            value,
+        )?,
+        "scoring.freshness.mentions_max_age_hours" => set_u32(
+            &mut tracker,
+            &mut config.scoring.freshness.mentions_max_age_hours,
+            "scoring.freshness",
+            "mentions_max_age_hours",
+            value,
+        )?,
 
         // Limits
         "limits.max_replies_per_day" => set_u32(
@@ -322,6 +343,20 @@ pub(super) fn set_direct(config: &mut Config, kv: &str, config_path: &Path) -> R
             "max_threads_per_week",
             value,
         )?,
+        "limits.max_likes_per_day" => set_u32(
+            &mut tracker,
+            &mut config.limits.max_likes_per_day,
+            "limits",
+            "max_likes_per_day",
+            value,
+        )?,
+        "limits.max_follows_per_day" => set_u32(
+            &mut tracker,
+            &mut config.limits.max_follows_per_day,
+            "limits",
+            "max_follows_per_day",
+            value,
+        )?,
         "limits.min_action_delay_seconds" => set_u64(
             &mut tracker,
             &mut config.limits.min_action_delay_seconds,
@@ -465,6 +500,20 @@ pub(super) fn set_direct(config: &mut Config, kv: &str, config_path: &Path) -> R
             "retention_days",
             value,
         )?,
+        "storage.slow_query_ms" => set_u64(
+            &mut tracker,
+            &mut config.storage.slow_query_ms,
+            "storage",
+            "slow_query_ms",
+            value,
+        )?,
+        "storage.record_slow_queries" => set_bool(
+            &mut tracker,
+            &mut config.storage.record_slow_queries,
+            "storage",
+            "record_slow_queries",
+            value,
+        )?,
 
         // Logging
         "logging.status_interval_seconds" => set_u64(
@@ -538,7 +587,7 @@ pub(super) fn set_direct(config: &mut Config, kv: &str, config_path: &Path) -> R
 
     validate_config(config)?;
     let path_str = config_path.display().to_string();
-    write_config_with_backup(config, &path_str)?;
+    write_config_with_backup(config, &path_str, "settings --set")?;
 
     let bold = Style::new().bold();
     eprintln!("{}", bold.apply_to("Updated:"));
@@ -137,6 +137,11 @@ pub(super) fn show_config(config: &Config) {
         "  Threads/week:        {}",
         config.limits.max_threads_per_week
     );
+    eprintln!("  Likes/day:           {}", config.limits.max_likes_per_day);
+    eprintln!(
+        "  Follows/day:         {}",
+        config.limits.max_follows_per_day
+    );
     eprintln!(
         "  Action delay:        {}-{} seconds",
         config.limits.min_action_delay_seconds, config.limits.max_action_delay_seconds
@@ -185,6 +190,12 @@ pub(super) fn show_config(config: &Config) {
         "  Content type:        {:.0} pts max",
         config.scoring.content_type_max
     );
+    eprintln!(
+        "  Freshness:           decay after {}h, cutoff {}h (mentions {}h)",
+        config.scoring.freshness.discovery_decay_after_hours,
+        config.scoring.freshness.discovery_max_age_hours,
+        config.scoring.freshness.mentions_max_age_hours
+    );
 
     // Timing
     eprintln!();
@@ -270,6 +281,15 @@ pub(super) fn show_config(config: &Config) {
         "  Data retention:      {} days",
         config.storage.retention_days
     );
+    eprintln!(
+        "  Slow queries:        >= {}ms{}",
+        config.storage.slow_query_ms,
+        if config.storage.record_slow_queries {
+            " (recorded)"
+        } else {
+            ""
+        }
+    );
     eprintln!(
         "  Status interval:     {}",
         if config.logging.status_interval_seconds == 0 {
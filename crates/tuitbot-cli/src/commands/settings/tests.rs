@@ -1,7 +1,8 @@
-use tuitbot_core::config::Config;
+use tuitbot_core::config::{Config, PolicyPreset};
 use tuitbot_core::safety::redact::mask_optional_secret as mask_secret;
 
 use super::helpers::*;
+use super::preset::preview;
 use super::render::render_config;
 use super::show::*;
 
@@ -177,3 +178,42 @@ fn render_config_escapes_special_chars() {
     assert_eq!(parsed.business.product_description, "line\\break");
     assert_eq!(parsed.x_api.client_id, "id-\"test\"");
 }
+
+#[test]
+fn saving_settings_records_history() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let path = dir.path().join("config.toml");
+    let path_str = path.display().to_string();
+
+    let mut config = Config::default();
+    super::render::write_config_with_backup(&config, &path_str, "settings").expect("write");
+    config.limits.max_replies_per_day = 12;
+    super::render::write_config_with_backup(&config, &path_str, "settings --set").expect("write");
+
+    let history = tuitbot_core::config::history::list_snapshots(&path).expect("history");
+    assert_eq!(history.len(), 2);
+    assert_eq!(history[1].source, "settings --set");
+    let changes = tuitbot_core::config::history::snapshot_changes(&path, history[1].id)
+        .expect("changes")
+        .expect("has previous");
+    assert!(changes
+        .iter()
+        .any(|c| c.key == "limits.max_replies_per_day" && c.new.as_deref() == Some("12")));
+    assert!(path.with_extension("toml.bak").exists());
+}
+
+#[test]
+fn preview_lists_only_changed_settings() {
+    let config: Config = toml::from_str("[targets]").unwrap();
+    let (_, changes) = preview(&config, PolicyPreset::Balanced);
+    assert!(changes.is_empty());
+
+    let (updated, changes) = preview(&config, PolicyPreset::Conservative);
+    assert!(updated.approval_mode);
+    let keys: Vec<&str> = changes.iter().map(|c| c.key.as_str()).collect();
+    assert!(keys.contains(&"limits.max_replies_per_day"));
+    assert!(keys.contains(&"scoring.threshold"));
+    assert!(keys.contains(&"targets.max_target_replies_per_day"));
+    assert!(!keys.contains(&"approval_mode"));
+    assert!(!keys.iter().any(|k| k.starts_with("business.")));
+}
@@ -0,0 +1,284 @@
+//! Implementation of the `tuitbot targets` command.
+//!
+//! Lists monitored target accounts with their tier and changes the tier of
+//! a target. The tier selects the engagement cadence configured under
+//! `[targets.tiers]`, which the target loop enforces on its next iteration.
+//! Removed targets are kept, and can be restored, until the retention
+//! cleanup purges them. `import` seeds many targets at once and appends
+//! them to `targets.accounts` in the config file.
+
+use anyhow::Context;
+use toml_edit::{Array, DocumentMut};
+use tuitbot_core::config::{history, Config, TargetTier};
+use tuitbot_core::startup::expand_tilde;
+use tuitbot_core::storage;
+use tuitbot_core::workflow::target_import::{self, ImportReport};
+
+use super::{OutputFormat, TargetsArgs, TargetsSubcommand};
+use crate::deps::RuntimeDeps;
+use crate::output::write_stdout;
+
+/// Execute the `tuitbot targets` command.
+pub async fn execute(
+    config: &Config,
+    config_path: &str,
+    args: TargetsArgs,
+    output: OutputFormat,
+) -> anyhow::Result<()> {
+    if let TargetsSubcommand::Import {
+        csv,
+        from_following,
+        tier,
+        limit,
+        dry_run,
+    } = args.command
+    {
+        let source = match (csv, from_following) {
+            (Some(path), _) => {
+                let path = expand_tilde(&path);
+                let content = std::fs::read_to_string(&path)
+                    .with_context(|| format!("Failed to read {}", path.display()))?;
+                ImportSource::Csv(target_import::parse_csv(&content))
+            }
+            (None, Some(username)) => ImportSource::Following(username, limit),
+            (None, None) => anyhow::bail!("Pass --csv or --from-following."),
+        };
+        return import(config, config_path, source, &tier, dry_run, output).await;
+    }
+
+    let pool = storage::init_db(&config.storage.db_path).await?;
+    let result = match args.command {
+        TargetsSubcommand::List => list(&pool, config, output).await,
+        TargetsSubcommand::Tier { username, tier } => {
+            set_tier(&pool, &username, &tier, output).await
+        }
+        TargetsSubcommand::Remove { username } => remove(&pool, &username, output).await,
+        TargetsSubcommand::Restore { username } => restore(&pool, &username, output).await,
+        TargetsSubcommand::Removed => list_removed(&pool, output).await,
+        TargetsSubcommand::Import { .. } => unreachable!("handled above"),
+    };
+    pool.close().await;
+    result
+}
+
+async fn list(pool: &storage::DbPool, config: &Config, output: OutputFormat) -> anyhow::Result<()> {
+    let accounts = storage::target_accounts::get_enriched_target_accounts(pool).await?;
+
+    if output.is_json() {
+        write_stdout(&serde_json::to_string(&accounts)?)?;
+        return Ok(());
+    }
+
+    if accounts.is_empty() {
+        eprintln!("No target accounts yet. They appear after the target loop first runs.");
+        return Ok(());
+    }
+
+    for account in &accounts {
+        let tier = TargetTier::parse(&account.tier).unwrap_or_default();
+        let cadence = config.targets.tiers.cadence(tier);
+        eprintln!(
+            "@{:<20} {:<10} {}/week  actions: {}{}",
+            account.username,
+            tier,
+            cadence.max_interactions_per_week,
+            cadence.allowed_actions.join(","),
+            if cadence.require_approval {
+                "  (approval required)"
+            } else {
+                ""
+            },
+        );
+    }
+    Ok(())
+}
+
+async fn set_tier(
+    pool: &storage::DbPool,
+    username: &str,
+    tier: &str,
+    output: OutputFormat,
+) -> anyhow::Result<()> {
+    let username = username.trim().trim_start_matches('@');
+    let Some(tier) = TargetTier::parse(tier) else {
+        anyhow::bail!("Invalid tier '{tier}'. Valid tiers: dream_100, general");
+    };
+
+    if !storage::target_accounts::set_target_tier(pool, username, tier.as_str()).await? {
+        anyhow::bail!("No active target account @{username}");
+    }
+
+    if output.is_json() {
+        write_stdout(
+            &serde_json::json!({"username": username, "tier": tier.as_str()}).to_string(),
+        )?;
+    } else {
+        eprintln!("@{username} is now in the {tier} tier.");
+    }
+    Ok(())
+}
+
+async fn remove(
+    pool: &storage::DbPool,
+    username: &str,
+    output: OutputFormat,
+) -> anyhow::Result<()> {
+    let username = username.trim().trim_start_matches('@');
+    if !storage::target_accounts::deactivate_target_account(pool, username).await? {
+        anyhow::bail!("No active target account @{username}");
+    }
+
+    if output.is_json() {
+        write_stdout(&serde_json::json!({"username": username, "status": "removed"}).to_string())?;
+    } else {
+        eprintln!("Removed @{username}. Restore it with `tuitbot targets restore {username}`.");
+    }
+    Ok(())
+}
+
+async fn restore(
+    pool: &storage::DbPool,
+    username: &str,
+    output: OutputFormat,
+) -> anyhow::Result<()> {
+    let username = username.trim().trim_start_matches('@');
+    if !storage::target_accounts::restore_target_account(pool, username).await? {
+        anyhow::bail!("No removed target account @{username}");
+    }
+
+    if output.is_json() {
+        write_stdout(&serde_json::json!({"username": username, "status": "restored"}).to_string())?;
+    } else {
+        eprintln!("@{username} is monitored again.");
+    }
+    Ok(())
+}
+
+async fn list_removed(pool: &storage::DbPool, output: OutputFormat) -> anyhow::Result<()> {
+    let accounts = storage::target_accounts::get_deleted_target_accounts(pool).await?;
+
+    if output.is_json() {
+        write_stdout(&serde_json::to_string(&accounts)?)?;
+        return Ok(());
+    }
+
+    if accounts.is_empty() {
+        eprintln!("No removed target accounts.");
+        return Ok(());
+    }
+
+    for account in &accounts {
+        eprintln!(
+            "@{:<20} {:<10} {} replies",
+            account.username, account.tier, account.total_replies_sent
+        );
+    }
+    Ok(())
+}
+
+/// Where `targets import` reads accounts from.
+enum ImportSource {
+    /// Entries parsed from a CSV file.
+    Csv(Vec<String>),
+    /// A username and how many of its followed accounts to read.
+    Following(String, usize),
+}
+
+async fn import(
+    config: &Config,
+    config_path: &str,
+    source: ImportSource,
+    tier: &str,
+    dry_run: bool,
+    output: OutputFormat,
+) -> anyhow::Result<()> {
+    let Some(tier) = TargetTier::parse(tier) else {
+        anyhow::bail!("Invalid tier '{tier}'. Valid tiers: dream_100, general");
+    };
+
+    let deps = RuntimeDeps::init(config, true).await?;
+    let client = deps.x_client.as_ref();
+    let existing = &config.targets.accounts;
+    let report = match source {
+        ImportSource::Csv(entries) => {
+            target_import::import_handles(&deps.pool, client, &entries, existing, tier, dry_run)
+                .await?
+        }
+        ImportSource::Following(username, limit) => {
+            target_import::import_following(
+                &deps.pool, client, &username, limit, existing, tier, dry_run,
+            )
+            .await?
+        }
+    };
+
+    if !dry_run && !report.added.is_empty() {
+        append_to_config(config_path, &report.added)?;
+    }
+
+    if output.is_json() {
+        write_stdout(&serde_json::to_string(&report)?)?;
+    } else {
+        print_report(&report, tier, dry_run);
+    }
+    Ok(())
+}
+
+/// Append `usernames` to `[targets].accounts`, keeping comments intact.
+fn append_to_config(config_path: &str, usernames: &[String]) -> anyhow::Result<()> {
+    let path = expand_tilde(config_path);
+    let content = std::fs::read_to_string(&path)
+        .with_context(|| format!("Failed to read {}", path.display()))?;
+    let mut doc: DocumentMut = content
+        .parse()
+        .context("Failed to parse config for editing")?;
+
+    let targets = doc
+        .entry("targets")
+        .or_insert(toml_edit::table())
+        .as_table_mut()
+        .context("[targets] is not a table")?;
+    let accounts = targets
+        .entry("accounts")
+        .or_insert(toml_edit::value(Array::new()))
+        .as_array_mut()
+        .context("targets.accounts is not an array")?;
+    for username in usernames {
+        accounts.push(username.as_str());
+    }
+
+    history::write_config(&path, &doc.to_string(), "targets import")
+        .with_context(|| format!("Failed to write {}", path.display()))?;
+    Ok(())
+}
+
+fn print_report(report: &ImportReport, tier: TargetTier, dry_run: bool) {
+    let verb = if dry_run { "Would import" } else { "Imported" };
+    eprintln!(
+        "{verb} {} account(s) into the {tier} tier.",
+        report.added.len()
+    );
+    if !report.added.is_empty() {
+        eprintln!(
+            "  {}",
+            report
+                .added
+                .iter()
+                .map(|u| format!("@{u}"))
+                .collect::<Vec<_>>()
+                .join(" ")
+        );
+    }
+    for (label, entries) in [("Skipped", &report.skipped), ("Invalid", &report.invalid)] {
+        if entries.is_empty() {
+            continue;
+        }
+        eprintln!("{label} {}:", entries.len());
+        for entry in entries {
+            eprintln!("  {:<24} {}", entry.entry, entry.reason);
+        }
+    }
+    if !dry_run && !report.added.is_empty() {
+        eprintln!("Added to targets.accounts; the target loop picks them up on the next start.");
+    }
+}
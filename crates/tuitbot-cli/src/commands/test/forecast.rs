@@ -0,0 +1,41 @@
+//! X API usage forecast for `tuitbot test`.
+
+use tuitbot_core::x_api::forecast::UsageForecast;
+
+use super::CheckResult;
+
+const LABEL: &str = "API forecast";
+
+/// Summarize projected usage. Exceeding a cap is a warning, not a failure.
+pub(super) fn check_forecast(forecast: &UsageForecast) -> CheckResult {
+    let mut message = forecast.summary();
+    for warning in forecast.warnings() {
+        message.push_str(&format!("; WARNING: {warning}"));
+    }
+    CheckResult::ok(LABEL, message)
+}
+
+/// Print the arithmetic behind the forecast to stderr.
+pub(super) fn print_forecast(forecast: &UsageForecast) {
+    eprintln!();
+    eprintln!(
+        "X API usage forecast ({} tier, {}h/day, {} days/week, upper bound):",
+        forecast.tier, forecast.active_hours_per_day, forecast.active_days_per_week
+    );
+    for (kind, lines) in [("read", &forecast.reads), ("write", &forecast.writes)] {
+        for line in lines {
+            eprintln!("  {kind:<6}{:<11}{}", line.source, line.math);
+        }
+    }
+    eprintln!(
+        "  total reads  {:.0} of {} per month",
+        forecast.reads_per_month, forecast.quota.reads_per_month
+    );
+    eprintln!(
+        "  total writes {:.0} of {} per month",
+        forecast.writes_per_month, forecast.quota.writes_per_month
+    );
+    for warning in forecast.warnings() {
+        eprintln!("  WARN  {warning}");
+    }
+}
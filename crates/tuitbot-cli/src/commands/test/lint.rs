@@ -0,0 +1,16 @@
+//! Config lint output for `tuitbot test --lint` and the setup wizard.
+
+use tuitbot_core::config::LintWarning;
+
+/// Print lint warnings to stderr. Warnings never fail `tuitbot test`.
+pub fn print_lint(warnings: &[LintWarning]) {
+    eprintln!();
+    if warnings.is_empty() {
+        eprintln!("Lint: no risky or contradictory settings found.");
+        return;
+    }
+    eprintln!("Lint: {} warning(s)", warnings.len());
+    for warning in warnings {
+        eprintln!("  WARN  {warning}");
+    }
+}
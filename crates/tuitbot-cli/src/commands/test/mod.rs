@@ -4,18 +4,27 @@
 //! running the agent. Each check runs independently -- a failure
 //! in one does not skip others.
 
+mod forecast;
+mod lint;
+mod ollama;
 #[cfg(test)]
 mod tests;
+mod warmup;
 
 use serde::Serialize;
-use tuitbot_core::config::Config;
+use tuitbot_core::config::{Config, LintWarning};
 use tuitbot_core::error::LlmError;
 use tuitbot_core::llm::factory::create_provider;
 use tuitbot_core::startup::{expand_tilde, load_tokens_from_file, StartupError, StoredTokens};
+use tuitbot_core::x_api::client::is_production_url;
+use tuitbot_core::x_api::forecast::UsageForecast;
+use tuitbot_core::x_api::tier::ApiTier;
 
 use super::OutputFormat;
 use crate::output::write_stdout;
 
+pub use lint::print_lint;
+
 /// A single diagnostic check result.
 #[derive(Clone, Serialize)]
 struct CheckResult {
@@ -73,6 +82,10 @@ struct TestOutput {
     checks: Vec<CheckResult>,
     #[serde(skip_serializing_if = "Option::is_none")]
     auth_details: Option<AuthDetails>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    lint: Option<Vec<LintWarning>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    forecast: Option<UsageForecast>,
 }
 
 struct AuthEvaluation {
@@ -113,6 +126,8 @@ fn build_test_output(checks: Vec<CheckResult>, auth_details: Option<AuthDetails>
         passed,
         checks,
         auth_details,
+        lint: None,
+        forecast: None,
     }
 }
 
@@ -120,15 +135,21 @@ fn build_test_output(checks: Vec<CheckResult>, auth_details: Option<AuthDetails>
 ///
 /// Returns `true` if all checks pass, `false` if any fail.
 /// Does **not** call `process::exit` — callers decide what to do on failure.
-pub async fn run_checks(config: &Config, config_path: &str) -> bool {
+/// The X API usage forecast is computed for `tier`.
+pub async fn run_checks(config: &Config, config_path: &str, tier: ApiTier) -> bool {
     let mut results = collect_checks(config, config_path);
     results.push(check_llm_connectivity(config).await);
+    results.extend(ollama::check_ollama_models(config).await);
+    results.extend(warmup::check_warmup(config).await);
+    let usage = UsageForecast::for_config(config, tier);
+    results.push(forecast::check_forecast(&usage));
 
     // Print results.
     eprintln!();
     for result in &results {
         eprintln!("{result}");
     }
+    forecast::print_forecast(&usage);
     eprintln!();
 
     let all_passed = results.iter().all(|r| r.passed);
@@ -150,23 +171,39 @@ pub async fn run_checks(config: &Config, config_path: &str) -> bool {
 /// Execute the `tuitbot test` command.
 ///
 /// Runs all diagnostic checks and reports results. Exits with code 1
-/// if any check fails.
+/// if any check fails. With `lint`, also reports risky or contradictory
+/// settings; lint warnings do not affect the exit code. Projected X API
+/// usage is checked against `tier`'s monthly caps; exceeding them only warns.
 pub async fn execute(
     config: &Config,
     config_path: &str,
+    lint: bool,
+    tier: ApiTier,
     output: OutputFormat,
 ) -> anyhow::Result<()> {
     if output.is_json() {
         let auth = evaluate_auth(load_tokens_from_file());
         let mut checks = collect_checks_with_auth(config, config_path, auth.checks);
         checks.push(check_llm_connectivity(config).await);
-        let output = build_test_output(checks, Some(auth.details));
+        checks.extend(ollama::check_ollama_models(config).await);
+        checks.extend(warmup::check_warmup(config).await);
+        let usage = UsageForecast::for_config(config, tier);
+        checks.push(forecast::check_forecast(&usage));
+        let mut output = build_test_output(checks, Some(auth.details));
+        output.lint = lint.then(|| config.lint());
+        output.forecast = Some(usage);
         write_stdout(&serde_json::to_string(&output)?)?;
         if !output.passed {
             std::process::exit(1);
         }
-    } else if !run_checks(config, config_path).await {
-        std::process::exit(1);
+    } else {
+        let passed = run_checks(config, config_path, tier).await;
+        if lint {
+            print_lint(&config.lint());
+        }
+        if !passed {
+            std::process::exit(1);
+        }
     }
     Ok(())
 }
@@ -175,7 +212,17 @@ pub async fn execute(
 fn check_config(config: &Config, config_path: &str) -> CheckResult {
     let path = expand_tilde(config_path);
     match config.validate() {
-        Ok(()) => CheckResult::ok("Configuration", format!("loaded from {}", path.display())),
+        Ok(()) => {
+            let mut message = format!("loaded from {}", path.display());
+            if let Some(url) = config.x_api.base_url.as_deref().map(str::trim) {
+                if !url.is_empty() && !is_production_url(url) {
+                    message.push_str(&format!(
+                        "; WARNING: X API base URL {url} is not production X"
+                    ));
+                }
+            }
+            CheckResult::ok("Configuration", message)
+        }
         Err(errors) => {
             let msgs: Vec<String> = errors.iter().map(|e| e.to_string()).collect();
             CheckResult::fail("Configuration", msgs.join("; "))
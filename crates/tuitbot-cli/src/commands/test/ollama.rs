@@ -0,0 +1,76 @@
+//! Ollama model discovery check for `tuitbot test`.
+
+use tuitbot_core::config::Config;
+use tuitbot_core::error::LlmError;
+use tuitbot_core::llm::ollama::{self, OllamaModel};
+
+use super::CheckResult;
+
+const LABEL: &str = "Ollama models";
+
+/// List pulled models and validate `llm.model` against them.
+///
+/// Returns `None` when the configured provider is not Ollama.
+pub(super) async fn check_ollama_models(config: &Config) -> Option<CheckResult> {
+    if config.llm.provider != "ollama" {
+        return None;
+    }
+    let base_url = config.llm.base_url.as_deref();
+    let listed = ollama::list_models(base_url).await;
+    Some(evaluate_models(
+        &config.llm.model,
+        &ollama::api_root(base_url),
+        listed,
+    ))
+}
+
+/// Turn a discovery result into a check, naming the fix when it fails.
+pub(super) fn evaluate_models(
+    wanted: &str,
+    api_root: &str,
+    listed: Result<Vec<OllamaModel>, LlmError>,
+) -> CheckResult {
+    let models = match listed {
+        Ok(models) => models,
+        Err(e) => {
+            return CheckResult::fail(
+                LABEL,
+                format!("Ollama not reachable at {api_root} ({e}); start it with `ollama serve`"),
+            );
+        }
+    };
+
+    if models.is_empty() {
+        return CheckResult::fail(
+            LABEL,
+            "no models pulled; run `ollama pull llama3.2`".to_string(),
+        );
+    }
+
+    let available = models
+        .iter()
+        .map(|m| format!("{} ({})", m.name, m.size_label()))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    if wanted.is_empty() {
+        return CheckResult::ok(LABEL, format!("pulled: {available}"));
+    }
+
+    match ollama::find_model(&models, wanted) {
+        Some(found) => CheckResult::ok(
+            LABEL,
+            format!(
+                "{} pulled ({}); available: {available}",
+                found.name,
+                found.size_label()
+            ),
+        ),
+        None => CheckResult::fail(
+            LABEL,
+            format!(
+                "model '{wanted}' not pulled; run `ollama pull {wanted}` (available: {available})"
+            ),
+        ),
+    }
+}
@@ -18,6 +18,8 @@ fn valid_tokens() -> StoredTokens {
             "bookmark.write".to_string(),
             "dm.read".to_string(),
             "dm.write".to_string(),
+            "mute.read".to_string(),
+            "block.read".to_string(),
             "offline.access".to_string(),
         ],
     }
@@ -119,6 +121,19 @@ fn json_output_contains_auth_details() {
 
     assert!(value.get("auth_details").is_some());
     assert!(value["auth_details"].is_object());
+    assert!(value.get("lint").is_none());
+}
+
+#[test]
+fn json_output_includes_lint_warnings_when_requested() {
+    let mut config = Config::default();
+    config.limits.banned_phrases.clear();
+    let mut output = build_test_output(vec![], None);
+    output.lint = Some(config.lint());
+    let value = serde_json::to_value(output).expect("serialize output");
+
+    assert_eq!(value["lint"][0]["field"], "limits.banned_phrases");
+    assert!(value["passed"].as_bool().unwrap());
 }
 
 #[test]
@@ -128,6 +143,7 @@ fn check_llm_connectivity_not_configured() {
         api_key: None,
         model: String::new(),
         base_url: None,
+        ..Default::default()
     };
     let result = check_llm_connectivity_sync(&config);
     assert!(!result.passed);
@@ -142,6 +158,7 @@ fn check_llm_connectivity_bad_provider_returns_fail() {
         api_key: None,
         model: String::new(),
         base_url: None,
+        ..Default::default()
     };
     let result = check_llm_connectivity_sync(&config);
     assert!(!result.passed);
@@ -170,6 +187,31 @@ fn next_step_guidance_any_fail() {
     assert!(guidance.is_none());
 }
 
+// ============================================================================
+// check_config
+// ============================================================================
+
+#[test]
+fn check_config_warns_about_non_production_x_api() {
+    let mut config = tuitbot_core::config::Config::default();
+    config.business.product_name = "TestApp".to_string();
+    config.business.product_keywords = vec!["rust".to_string()];
+    config.llm.provider = "ollama".to_string();
+
+    let result = check_config(&config, "config.toml");
+    assert!(result.passed);
+    assert!(!result.message.contains("WARNING"));
+
+    config.x_api.base_url = Some("http://localhost:9000/2".to_string());
+    let result = check_config(&config, "config.toml");
+    assert!(result.passed);
+    assert!(result.message.contains("localhost:9000"));
+
+    config.x_api.base_url = Some("https://api.x.com/2".to_string());
+    let result = check_config(&config, "config.toml");
+    assert!(!result.message.contains("WARNING"));
+}
+
 // ============================================================================
 // check_business_profile
 // ============================================================================
@@ -336,3 +378,99 @@ fn check_database_existing_reports_size() {
 
     let _ = std::fs::remove_file(&tmp);
 }
+
+// ============================================================================
+// Ollama model discovery
+// ============================================================================
+
+fn pulled(names: &[&str]) -> Vec<tuitbot_core::llm::ollama::OllamaModel> {
+    names
+        .iter()
+        .map(|n| tuitbot_core::llm::ollama::OllamaModel {
+            name: n.to_string(),
+            size: 2_000_000_000,
+        })
+        .collect()
+}
+
+#[test]
+fn ollama_configured_model_pulled_passes() {
+    let result = ollama::evaluate_models(
+        "llama3.2",
+        "http://localhost:11434",
+        Ok(pulled(&["llama3.2:latest"])),
+    );
+    assert!(result.passed);
+    assert!(result.message.contains("llama3.2:latest pulled (2.0 GB)"));
+}
+
+#[test]
+fn ollama_missing_model_fails_with_pull_hint() {
+    let result = ollama::evaluate_models(
+        "mistral",
+        "http://localhost:11434",
+        Ok(pulled(&["llama3.2:latest"])),
+    );
+    assert!(!result.passed);
+    assert!(result.message.contains("ollama pull mistral"));
+    assert!(result.message.contains("llama3.2:latest"));
+}
+
+#[test]
+fn ollama_unreachable_fails_with_serve_hint() {
+    let result = ollama::evaluate_models(
+        "llama3.2",
+        "http://localhost:11434",
+        Err(LlmError::NotConfigured),
+    );
+    assert!(!result.passed);
+    assert!(result.message.contains("ollama serve"));
+}
+
+#[tokio::test]
+async fn ollama_check_skipped_for_other_providers() {
+    let mut config = tuitbot_core::config::Config::default();
+    config.llm.provider = "openai".to_string();
+    assert!(ollama::check_ollama_models(&config).await.is_none());
+}
+
+// ============================================================================
+// Account warm-up
+// ============================================================================
+
+#[tokio::test]
+async fn warmup_check_reports_first_phase_caps() {
+    let mut config = tuitbot_core::config::Config::default();
+    assert!(warmup::check_warmup(&config).await.is_none());
+
+    config.warmup.enabled = true;
+    config.limits.max_replies_per_day = 20;
+    config.storage.db_path = "/nonexistent/tuitbot-warmup-test.db".to_string();
+    let result = warmup::check_warmup(&config).await.expect("check");
+    assert!(result.passed);
+    assert!(result
+        .message
+        .starts_with("starts on first run, week 1 of 4 (25% of limits)"));
+    assert!(result.message.contains("5 replies/day"));
+
+    let done = warmup::evaluate_phase(Ok(None));
+    assert!(done.passed);
+    assert!(done.message.contains("full limits"));
+}
+
+#[test]
+fn forecast_check_warns_without_failing() {
+    let mut config = Config::default();
+    config.intervals.discovery_search_seconds = 60;
+    let usage = UsageForecast::for_config(&config, ApiTier::Basic);
+    let check = forecast::check_forecast(&usage);
+    assert!(check.passed);
+    assert!(check.message.contains("Basic tier"));
+    assert!(check.message.contains("WARNING: reads"));
+
+    let mut output = build_test_output(vec![check], None);
+    output.forecast = Some(usage);
+    let value = serde_json::to_value(output).expect("serialize output");
+    assert_eq!(value["forecast"]["quota"]["reads_per_month"], 15_000);
+    assert!(value["forecast"]["reads"].is_array());
+}
@@ -0,0 +1,58 @@
+//! Account warm-up check for `tuitbot test`.
+
+use tuitbot_core::automation::warmup::{phase_percent, warmup_status_for};
+use tuitbot_core::automation::{WarmupCaps, WarmupPhase};
+use tuitbot_core::config::Config;
+use tuitbot_core::startup::expand_tilde;
+use tuitbot_core::storage::{self, accounts::DEFAULT_ACCOUNT_ID};
+
+use super::CheckResult;
+
+const LABEL: &str = "Warm-up";
+
+/// Report the current warm-up phase and the caps it allows.
+///
+/// Returns `None` when warm-up is disabled.
+pub(super) async fn check_warmup(config: &Config) -> Option<CheckResult> {
+    if !config.warmup.enabled {
+        return None;
+    }
+    let db_path = expand_tilde(&config.storage.db_path);
+    if !db_path.exists() {
+        return Some(evaluate_phase(Ok(first_phase(config))));
+    }
+    let phase = match storage::init_db(&db_path.to_string_lossy()).await {
+        Ok(pool) => {
+            let phase = warmup_status_for(&pool, DEFAULT_ACCOUNT_ID, config).await;
+            pool.close().await;
+            phase.map_err(|e| e.to_string())
+        }
+        Err(e) => Err(e.to_string()),
+    };
+    Some(evaluate_phase(phase))
+}
+
+/// Phase the warm-up starts in on the first run.
+fn first_phase(config: &Config) -> Option<WarmupPhase> {
+    let (week, percent) = phase_percent(&config.warmup, chrono::Duration::zero())?;
+    Some(WarmupPhase {
+        week: week + 1,
+        total_weeks: config.warmup.weeks,
+        percent,
+        started_at: None,
+        caps: WarmupCaps::scaled(&config.limits, percent),
+    })
+}
+
+/// Turn a warm-up lookup into a check.
+pub(super) fn evaluate_phase(phase: Result<Option<WarmupPhase>, String>) -> CheckResult {
+    match phase {
+        Ok(Some(phase)) if phase.started_at.is_none() => CheckResult::ok(
+            LABEL,
+            format!("starts on first run, {}", phase.format_line()),
+        ),
+        Ok(Some(phase)) => CheckResult::ok(LABEL, phase.format_line()),
+        Ok(None) => CheckResult::ok(LABEL, "complete, full limits apply"),
+        Err(e) => CheckResult::fail(LABEL, format!("cannot read warm-up progress: {e}")),
+    }
+}
@@ -15,9 +15,10 @@ use fs2::FileExt;
 use serde::Serialize;
 use tokio_util::sync::CancellationToken;
 
+use tuitbot_core::automation::mention_ack::AckPolicy;
 use tuitbot_core::automation::{
-    run_posting_queue_with_approval, AnalyticsLoop, ContentLoop, DiscoveryLoop, MentionsLoop,
-    PostExecutor, TargetLoop, ThreadLoop,
+    run_posting_queue_with_approval, AnalyticsLoop, ContentLoop, DiscoveryLoop,
+    DiscoverySourceRegistry, MentionsLoop, PostExecutor, TargetLoop, ThreadLoop,
 };
 use tuitbot_core::config::{Config, OperatingMode};
 
@@ -237,7 +238,7 @@ pub async fn execute(
             reason: "disabled in composer mode".to_string(),
         }
     } else {
-        run_mentions(&deps, &filter, &mut errors).await
+        run_mentions(&deps, &filter, config, &mut errors).await
     };
 
     // --- Target (autopilot only) ---
@@ -377,7 +378,7 @@ async fn run_discovery(
         };
     }
 
-    let discovery_loop = DiscoveryLoop::new(
+    let mut discovery_loop = DiscoveryLoop::new(
         deps.searcher.clone(),
         deps.scorer.clone(),
         deps.reply_gen.clone(),
@@ -387,15 +388,32 @@ async fn run_discovery(
         deps.keywords.clone(),
         config.scoring.threshold as f32,
         deps.target_loop_config.dry_run,
-    );
+    )
+    .with_quotes(deps.reply_gen.clone(), config.quote_tweets.clone())
+    .with_dedup_window(Duration::from_secs(
+        config.intervals.discovery_dedup_window_seconds,
+    ))
+    .with_generation_concurrency(config.llm.generation_concurrency())
+    .with_sources(DiscoverySourceRegistry::from_config(
+        &config.discovery_sources,
+    ));
+    if let Some(pacer) = &deps.pacer {
+        discovery_loop = discovery_loop.with_pacer(pacer.clone());
+    }
+    if let Some(calibrator) = &deps.calibrator {
+        discovery_loop = discovery_loop.with_calibrator(calibrator.clone());
+    }
 
     match discovery_loop.run_once(None).await {
         Ok((_results, summary)) => LoopOutcome::Completed {
             detail: format!(
-                "found={}, qualifying={}, replied={}, skipped={}, failed={}",
+                "found={}, qualifying={}, replied={}, quoted={}, queued={}, surfaced={}, skipped={}, failed={}",
                 summary.tweets_found,
                 summary.qualifying,
                 summary.replied,
+                summary.quoted,
+                summary.queued,
+                summary.surfaced,
                 summary.skipped,
                 summary.failed
             ),
@@ -414,6 +432,7 @@ async fn run_discovery(
 async fn run_mentions(
     deps: &RuntimeDeps,
     filter: &LoopFilter,
+    config: &Config,
     errors: &mut Vec<LoopErrorJson>,
 ) -> LoopOutcome {
     if !filter.mentions {
@@ -434,6 +453,11 @@ async fn run_mentions(
         deps.safety.clone(),
         deps.post_sender.clone(),
         deps.target_loop_config.dry_run,
+    )
+    .with_max_age_hours(config.scoring.freshness.mentions_max_age_hours)
+    .with_auto_ack(
+        AckPolicy::new(config.mention_ack.clone()),
+        deps.mentions_fetcher.clone(),
     );
 
     let storage: Arc<dyn tuitbot_core::automation::LoopStorage> = deps.loop_storage.clone();
@@ -451,11 +475,21 @@ async fn run_mentions(
                 .iter()
                 .filter(|r| matches!(r, tuitbot_core::automation::MentionResult::Failed { .. }))
                 .count();
+            let acknowledged = results
+                .iter()
+                .filter(|r| {
+                    matches!(
+                        r,
+                        tuitbot_core::automation::MentionResult::Acknowledged { .. }
+                    )
+                })
+                .count();
             LoopOutcome::Completed {
                 detail: format!(
-                    "total={}, replied={}, skipped={}, failed={}",
+                    "total={}, replied={}, acknowledged={}, skipped={}, failed={}",
                     results.len(),
                     replied,
+                    acknowledged,
                     skipped,
                     failed
                 ),
@@ -495,7 +529,7 @@ async fn run_target(
         };
     }
 
-    let target_loop = TargetLoop::new(
+    let mut target_loop = TargetLoop::new(
         deps.target_adapter.clone(),
         deps.target_adapter.clone(),
         deps.reply_gen.clone(),
@@ -504,6 +538,9 @@ async fn run_target(
         deps.post_sender.clone(),
         deps.target_loop_config.clone(),
     );
+    if let Some(pacer) = &deps.pacer {
+        target_loop = target_loop.with_pacer(pacer.clone());
+    }
 
     match target_loop.run_iteration().await {
         Ok(results) => {
@@ -511,6 +548,10 @@ async fn run_target(
                 .iter()
                 .filter(|r| matches!(r, tuitbot_core::automation::TargetResult::Replied { .. }))
                 .count();
+            let queued = results
+                .iter()
+                .filter(|r| matches!(r, tuitbot_core::automation::TargetResult::Queued { .. }))
+                .count();
             let skipped = results
                 .iter()
                 .filter(|r| matches!(r, tuitbot_core::automation::TargetResult::Skipped { .. }))
@@ -521,9 +562,10 @@ async fn run_target(
                 .count();
             LoopOutcome::Completed {
                 detail: format!(
-                    "total={}, replied={}, skipped={}, failed={}",
+                    "total={}, replied={}, queued={}, skipped={}, failed={}",
                     results.len(),
                     replied,
+                    queued,
                     skipped,
                     failed
                 ),
@@ -559,7 +601,7 @@ async fn run_content(
         };
     }
 
-    let content_loop = ContentLoop::new(
+    let mut content_loop = ContentLoop::new(
         deps.tweet_gen.clone(),
         deps.content_safety.clone(),
         deps.content_storage.clone(),
@@ -567,7 +609,15 @@ async fn run_content(
         config.intervals.content_post_window_seconds,
         deps.target_loop_config.dry_run,
     )
-    .with_topic_scorer(deps.topic_scorer.clone());
+    .with_topic_scorer(deps.topic_scorer.clone())
+    .with_review_pillars(config.business.approval_pillars());
+    if config.trending.enabled {
+        content_loop = content_loop.with_trending(
+            deps.trend_source.clone(),
+            config.business.content_pillars.clone(),
+            config.trending.share,
+        );
+    }
 
     match content_loop.run_once(None).await {
         tuitbot_core::automation::ContentResult::Posted { topic, content } => {
@@ -575,6 +625,9 @@ async fn run_content(
                 detail: format!("topic='{}', chars={}", topic, content.len()),
             }
         }
+        tuitbot_core::automation::ContentResult::Queued { topic, .. } => LoopOutcome::Completed {
+            detail: format!("topic='{topic}', queued for approval"),
+        },
         tuitbot_core::automation::ContentResult::TooSoon {
             elapsed_secs,
             window_secs,
@@ -627,7 +680,8 @@ async fn run_thread(
         effective_topics.to_vec(),
         config.intervals.thread_interval_seconds,
         deps.target_loop_config.dry_run,
-    );
+    )
+    .with_format(config.thread_format.clone());
 
     match thread_loop.run_once(None, None).await {
         tuitbot_core::automation::ThreadResult::Posted {
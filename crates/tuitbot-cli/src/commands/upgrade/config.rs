@@ -0,0 +1,410 @@
+//! `tuitbot upgrade config` — bring an existing config.toml up to date.
+//!
+//! On top of the feature-group wizard used by `tuitbot upgrade`, this:
+//! - adds top-level sections the current version knows about but the file
+//!   lacks, filled in with their defaults;
+//! - flags keys the current version does not read (renamed or removed
+//!   settings, typos) and optionally removes them.
+//!
+//! The file is edited with `toml_edit`, so existing values and comments are
+//! kept, and every rewrite is recorded in the config history.
+
+use std::fs;
+use std::io::IsTerminal;
+use std::path::Path;
+
+use anyhow::{bail, Context, Result};
+use console::Style;
+use dialoguer::Confirm;
+use toml_edit::DocumentMut;
+use tuitbot_core::config::{history, Config};
+
+use super::{
+    apply_answers, default_answers, detect_missing_features_from_str, expand_tilde, prompt_answers,
+    UpgradeGroup,
+};
+
+/// One segment of a path into the config document.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Segment {
+    Key(String),
+    Index(usize),
+}
+
+/// A key present in the file that the current version ignores.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct UnknownKey {
+    path: Vec<Segment>,
+}
+
+impl std::fmt::Display for UnknownKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, segment) in self.path.iter().enumerate() {
+            match segment {
+                Segment::Key(key) if i == 0 => write!(f, "{key}")?,
+                Segment::Key(key) => write!(f, ".{key}")?,
+                Segment::Index(index) => write!(f, "[{index}]")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Everything `upgrade config` would change in a config file.
+#[derive(Debug)]
+struct ConfigUpgradePlan {
+    groups: Vec<UpgradeGroup>,
+    /// Missing top-level sections with their default values.
+    sections: Vec<(String, toml::Value)>,
+    unknown: Vec<UnknownKey>,
+}
+
+impl ConfigUpgradePlan {
+    fn detect(content: &str) -> Result<Self> {
+        let groups = detect_missing_features_from_str(content)?;
+
+        let file: toml::Value = content.parse().context("Failed to parse config as TOML")?;
+        let config: Config = toml::from_str(content)
+            .context("Config has invalid values; fix them before upgrading")?;
+        let parsed = toml::Value::try_from(&config).context("Failed to serialize config")?;
+        let defaults = toml::Value::try_from(
+            toml::from_str::<Config>("").context("Failed to build default config")?,
+        )
+        .context("Failed to serialize default config")?;
+
+        // Sections the feature-group wizard fills in are left to it.
+        let wizard_sections: Vec<&str> = UpgradeGroup::all()
+            .iter()
+            .flat_map(|group| group.key_paths())
+            .filter_map(|path| path.split('.').next())
+            .collect();
+        let file_root = file.as_table().context("Config root is not a TOML table")?;
+        let sections = defaults
+            .as_table()
+            .into_iter()
+            .flatten()
+            .filter(|(key, value)| {
+                !wizard_sections.contains(&key.as_str())
+                    && value.as_table().is_some_and(|t| !t.is_empty())
+                    && !file_root.contains_key(key.as_str())
+            })
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect();
+
+        let mut unknown = Vec::new();
+        find_unknown_keys(&mut Vec::new(), &file, &parsed, &mut unknown);
+
+        Ok(Self {
+            groups,
+            sections,
+            unknown,
+        })
+    }
+
+    fn is_empty(&self) -> bool {
+        self.groups.is_empty() && self.sections.is_empty() && self.unknown.is_empty()
+    }
+}
+
+/// Collect paths present in `file` but dropped when the config is parsed and
+/// re-serialized.
+fn find_unknown_keys(
+    path: &mut Vec<Segment>,
+    file: &toml::Value,
+    parsed: &toml::Value,
+    out: &mut Vec<UnknownKey>,
+) {
+    match (file, parsed) {
+        (toml::Value::Table(file), toml::Value::Table(parsed)) => {
+            for (key, value) in file {
+                path.push(Segment::Key(key.clone()));
+                match parsed.get(key) {
+                    Some(parsed_value) => find_unknown_keys(path, value, parsed_value, out),
+                    None => out.push(UnknownKey { path: path.clone() }),
+                }
+                path.pop();
+            }
+        }
+        (toml::Value::Array(file), toml::Value::Array(parsed)) => {
+            for (index, (value, parsed_value)) in file.iter().zip(parsed).enumerate() {
+                path.push(Segment::Index(index));
+                find_unknown_keys(path, value, parsed_value, out);
+                path.pop();
+            }
+        }
+        _ => {}
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Execution
+// ---------------------------------------------------------------------------
+
+/// Run `tuitbot upgrade config`.
+pub async fn execute(non_interactive: bool, config_path_str: &str) -> Result<()> {
+    let config_path = expand_tilde(config_path_str);
+
+    if !config_path.exists() {
+        bail!(
+            "Config file not found: {}\nRun 'tuitbot init' first.",
+            config_path.display()
+        );
+    }
+
+    let content = fs::read_to_string(&config_path)
+        .with_context(|| format!("Failed to read {}", config_path.display()))?;
+    let plan = ConfigUpgradePlan::detect(&content)?;
+
+    if plan.is_empty() {
+        eprintln!("Config is up to date — nothing to upgrade.");
+        return Ok(());
+    }
+
+    if !non_interactive && !std::io::stdin().is_terminal() {
+        bail!(
+            "Interactive upgrade requires a terminal.\n\
+             Use --non-interactive to add new sections with their default values."
+        );
+    }
+
+    let bold = Style::new().bold();
+    let dim = Style::new().dim();
+
+    let (answers, sections, remove_unknown) = if non_interactive {
+        let sections: Vec<&str> = plan.sections.iter().map(|(k, _)| k.as_str()).collect();
+        (default_answers(&plan.groups), sections, false)
+    } else {
+        eprintln!();
+        eprintln!("{}", bold.apply_to("Config Upgrade"));
+        eprintln!();
+
+        let answers = prompt_answers(&plan.groups)?;
+
+        let mut sections = Vec::new();
+        for (name, defaults) in &plan.sections {
+            eprintln!("{}", bold.apply_to(format!("New section: [{name}]")));
+            for line in section_toml(name, defaults)?.lines() {
+                eprintln!("  {}", dim.apply_to(line));
+            }
+            if Confirm::new()
+                .with_prompt(format!("Add [{name}] with these defaults?"))
+                .default(true)
+                .interact()?
+            {
+                sections.push(name.as_str());
+            }
+            eprintln!();
+        }
+
+        let mut remove_unknown = false;
+        if !plan.unknown.is_empty() {
+            eprintln!(
+                "{}",
+                bold.apply_to("Keys not recognized by this version (renamed, removed, or typos):")
+            );
+            for key in &plan.unknown {
+                eprintln!("  • {key}");
+            }
+            remove_unknown = Confirm::new()
+                .with_prompt("Remove them from config.toml?")
+                .default(false)
+                .interact()?;
+            eprintln!();
+        }
+
+        (answers, sections, remove_unknown)
+    };
+
+    let mut doc: DocumentMut = content
+        .parse()
+        .context("Failed to parse config for editing")?;
+    apply_answers(&mut doc, &plan.groups, &answers);
+    for (name, defaults) in plan
+        .sections
+        .iter()
+        .filter(|(name, _)| sections.contains(&name.as_str()))
+    {
+        insert_section(&mut doc, name, defaults)?;
+    }
+    if remove_unknown {
+        for key in &plan.unknown {
+            remove_key(&mut doc, &key.path);
+        }
+    }
+
+    let updated = doc.to_string();
+    if updated != content {
+        write_upgraded(&config_path, &content, &updated)?;
+        eprintln!("{}", bold.apply_to("Config updated successfully!"));
+        for group in &plan.groups {
+            eprintln!("  • Configured {}", group.display_name());
+        }
+        for name in &sections {
+            eprintln!("  • Added [{name}]");
+        }
+        if remove_unknown {
+            eprintln!("  • Removed {} unrecognized key(s)", plan.unknown.len());
+        }
+        eprintln!("  Backup saved to {}.bak", config_path.display());
+    } else {
+        eprintln!("No changes made.");
+    }
+
+    if !plan.unknown.is_empty() && !remove_unknown {
+        eprintln!();
+        eprintln!("Keys not recognized by this version (left in place, ignored):");
+        for key in &plan.unknown {
+            eprintln!("  • {key}");
+        }
+    }
+    eprintln!();
+
+    Ok(())
+}
+
+fn write_upgraded(config_path: &Path, original: &str, updated: &str) -> Result<()> {
+    let backup_path = config_path.with_extension("toml.bak");
+    fs::write(&backup_path, original)
+        .with_context(|| format!("Failed to write backup to {}", backup_path.display()))?;
+    history::write_config(config_path, updated, "upgrade config")
+        .with_context(|| format!("Failed to write {}", config_path.display()))?;
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// TOML editing
+// ---------------------------------------------------------------------------
+
+/// Render a section with its default values as a standalone TOML document.
+fn section_toml(name: &str, defaults: &toml::Value) -> Result<String> {
+    let mut root = toml::value::Table::new();
+    root.insert(name.to_string(), defaults.clone());
+    toml::to_string(&root).with_context(|| format!("Failed to render [{name}]"))
+}
+
+/// Append a section with its default values. The document is re-parsed so
+/// nested tables stay grouped under their parent.
+fn insert_section(doc: &mut DocumentMut, name: &str, defaults: &toml::Value) -> Result<()> {
+    if doc.contains_key(name) {
+        return Ok(());
+    }
+    let mut text = doc.to_string();
+    if !text.ends_with('\n') {
+        text.push('\n');
+    }
+    text.push_str("\n# --- Added by `tuitbot upgrade config` (defaults) ---\n");
+    text.push_str(&section_toml(name, defaults)?);
+    *doc = text.parse().context("Failed to parse upgraded config")?;
+    Ok(())
+}
+
+fn remove_key(doc: &mut DocumentMut, path: &[Segment]) {
+    let Some((Segment::Key(last), parents)) = path.split_last() else {
+        return;
+    };
+    let mut item = doc.as_item_mut();
+    for segment in parents {
+        let next = match segment {
+            Segment::Key(key) => item.get_mut(key.as_str()),
+            Segment::Index(index) => item.get_mut(*index),
+        };
+        match next {
+            Some(next) => item = next,
+            None => return,
+        }
+    }
+    if let Some(table) = item.as_table_like_mut() {
+        table.remove(last);
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONFIG: &str = r#"
+approval_mode = true
+
+[x_api]
+client_id = "abc"
+
+# Product details
+[business]
+product_name = "Docklet"
+product_keywords = ["macos"]
+persona_opinions = []
+persona_experiences = []
+content_pillars = []
+retired_setting = "old"
+
+[targets]
+accounts = []
+
+[limits]
+max_replies_per_author_per_day = 1
+banned_phrases = []
+product_mention_ratio = 0.2
+
+[legacy]
+enabled = true
+"#;
+
+    fn unknown_paths(plan: &ConfigUpgradePlan) -> Vec<String> {
+        plan.unknown.iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn detects_missing_sections_and_unknown_keys() {
+        let plan = ConfigUpgradePlan::detect(CONFIG).unwrap();
+        assert!(plan.groups.is_empty());
+
+        let sections: Vec<&str> = plan.sections.iter().map(|(k, _)| k.as_str()).collect();
+        assert!(sections.contains(&"circuit_breaker"));
+        assert!(!sections.contains(&"business"));
+        assert!(!sections.contains(&"limits"));
+        assert!(!sections.contains(&"targets"));
+
+        assert_eq!(
+            unknown_paths(&plan),
+            vec!["business.retired_setting", "legacy"]
+        );
+    }
+
+    #[test]
+    fn up_to_date_config_has_nothing_to_do() {
+        let full = toml::to_string(&toml::from_str::<Config>(CONFIG).unwrap()).unwrap();
+        let plan = ConfigUpgradePlan::detect(&full).unwrap();
+        assert!(plan.sections.is_empty());
+        assert!(plan.unknown.is_empty());
+    }
+
+    #[test]
+    fn insert_and_remove_preserve_comments_and_values() {
+        let plan = ConfigUpgradePlan::detect(CONFIG).unwrap();
+        let mut doc: DocumentMut = CONFIG.parse().unwrap();
+        for (name, defaults) in &plan.sections {
+            insert_section(&mut doc, name, defaults).unwrap();
+        }
+        for key in &plan.unknown {
+            remove_key(&mut doc, &key.path);
+        }
+        let updated = doc.to_string();
+
+        assert!(updated.contains("# Product details"));
+        assert!(updated.contains("product_name = \"Docklet\""));
+        assert!(updated.contains("[circuit_breaker]"));
+        assert!(!updated.contains("retired_setting"));
+        assert!(!updated.contains("[legacy]"));
+
+        let config: Config = toml::from_str(&updated).unwrap();
+        assert!(config.approval_mode);
+        assert_eq!(config.business.product_name, "Docklet");
+
+        let after = ConfigUpgradePlan::detect(&updated).unwrap();
+        assert!(after.is_empty(), "{after:?}");
+    }
+}
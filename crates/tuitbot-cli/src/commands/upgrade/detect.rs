@@ -0,0 +1,118 @@
+//! Detection of feature groups missing from an existing config file.
+
+use std::fs;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+
+/// Feature groups that may be missing from older config files.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UpgradeGroup {
+    /// business.persona_opinions, .persona_experiences, .content_pillars
+    Persona,
+    /// [targets] section
+    Targets,
+    /// approval_mode (top-level)
+    ApprovalMode,
+    /// limits.max_replies_per_author_per_day, .banned_phrases, .product_mention_ratio
+    EnhancedLimits,
+}
+
+impl UpgradeGroup {
+    /// All upgrade groups in recommended configuration order.
+    pub(super) fn all() -> &'static [UpgradeGroup] {
+        &[
+            UpgradeGroup::Persona,
+            UpgradeGroup::Targets,
+            UpgradeGroup::ApprovalMode,
+            UpgradeGroup::EnhancedLimits,
+        ]
+    }
+
+    /// TOML key paths that belong to this group.
+    pub(super) fn key_paths(&self) -> &[&str] {
+        match self {
+            UpgradeGroup::Persona => &[
+                "business.persona_opinions",
+                "business.persona_experiences",
+                "business.content_pillars",
+            ],
+            UpgradeGroup::Targets => &["targets"],
+            UpgradeGroup::ApprovalMode => &["approval_mode"],
+            UpgradeGroup::EnhancedLimits => &[
+                "limits.max_replies_per_author_per_day",
+                "limits.banned_phrases",
+                "limits.product_mention_ratio",
+            ],
+        }
+    }
+
+    /// Human-readable name for display.
+    pub(crate) fn display_name(&self) -> &str {
+        match self {
+            UpgradeGroup::Persona => "Persona",
+            UpgradeGroup::Targets => "Target Accounts",
+            UpgradeGroup::ApprovalMode => "Approval Mode",
+            UpgradeGroup::EnhancedLimits => "Enhanced Safety Limits",
+        }
+    }
+
+    /// One-line description of the feature.
+    pub(crate) fn description(&self) -> &str {
+        match self {
+            UpgradeGroup::Persona => {
+                "Strong opinions, experiences, and content pillars for authentic content"
+            }
+            UpgradeGroup::Targets => "Monitor specific accounts and reply to their conversations",
+            UpgradeGroup::ApprovalMode => "Queue posts for human review before posting",
+            UpgradeGroup::EnhancedLimits => {
+                "Per-author reply limits, banned phrases, and product mention ratio"
+            }
+        }
+    }
+}
+
+/// Detect which feature groups are missing from the config file.
+pub fn detect_missing_features(config_path: &Path) -> Result<Vec<UpgradeGroup>> {
+    let content = fs::read_to_string(config_path)
+        .with_context(|| format!("Failed to read {}", config_path.display()))?;
+    detect_missing_features_from_str(&content)
+}
+
+/// Detect missing features from a TOML string (testable without filesystem).
+pub(super) fn detect_missing_features_from_str(content: &str) -> Result<Vec<UpgradeGroup>> {
+    let table: toml::Value = content.parse().context("Failed to parse config as TOML")?;
+    let root = table
+        .as_table()
+        .context("Config root is not a TOML table")?;
+
+    let mut missing = Vec::new();
+
+    for group in UpgradeGroup::all() {
+        // A group is missing if ANY of its key paths are absent
+        let any_missing = group.key_paths().iter().any(|p| !key_exists(root, p));
+        if any_missing {
+            missing.push(*group);
+        }
+    }
+
+    Ok(missing)
+}
+
+/// Walk a dot-separated key path in a TOML table.
+pub(super) fn key_exists(table: &toml::value::Table, dotted_path: &str) -> bool {
+    let segments: Vec<&str> = dotted_path.split('.').collect();
+    let mut current: &toml::Value = &toml::Value::Table(table.clone());
+
+    for segment in &segments {
+        match current.as_table() {
+            Some(t) => match t.get(*segment) {
+                Some(v) => current = v,
+                None => return false,
+            },
+            None => return false,
+        }
+    }
+
+    true
+}
@@ -0,0 +1,119 @@
+//! Interactive and non-interactive collection of upgrade answers.
+
+use std::path::Path;
+
+use anyhow::Result;
+use console::Style;
+
+use super::patch::patch_config;
+use super::UpgradeGroup;
+use crate::commands::init::{
+    prompt_approval_mode, prompt_enhanced_limits, prompt_persona, prompt_target_accounts,
+};
+
+/// Collected answers from the upgrade wizard.
+pub(super) struct UpgradeAnswers {
+    pub(super) persona: Option<(Vec<String>, Vec<String>, Vec<String>)>,
+    pub(super) targets: Option<Vec<String>>,
+    pub(super) approval_mode: Option<bool>,
+    pub(super) enhanced_limits: Option<(u32, Vec<String>, f32)>,
+}
+
+pub(crate) fn run_upgrade_wizard(config_path: &Path, missing: &[UpgradeGroup]) -> Result<()> {
+    let bold = Style::new().bold();
+
+    eprintln!();
+    eprintln!("{}", bold.apply_to("Upgrade Wizard"));
+    eprintln!();
+
+    let answers = prompt_answers(missing)?;
+    patch_config(config_path, missing, &answers)?;
+
+    eprintln!("{}", bold.apply_to("Config updated successfully!"));
+    eprintln!("  Backup saved to {}.bak", config_path.display());
+    eprintln!();
+
+    Ok(())
+}
+
+/// Walk the user through each missing feature group.
+pub(super) fn prompt_answers(missing: &[UpgradeGroup]) -> Result<UpgradeAnswers> {
+    let bold = Style::new().bold();
+
+    let mut answers = UpgradeAnswers {
+        persona: None,
+        targets: None,
+        approval_mode: None,
+        enhanced_limits: None,
+    };
+
+    for group in missing {
+        eprintln!("{}", bold.apply_to(group.display_name()));
+        eprintln!("  {}", group.description());
+        eprintln!();
+
+        match group {
+            UpgradeGroup::Persona => {
+                answers.persona = Some(prompt_persona()?);
+            }
+            UpgradeGroup::Targets => {
+                answers.targets = Some(prompt_target_accounts()?);
+            }
+            UpgradeGroup::ApprovalMode => {
+                answers.approval_mode = Some(prompt_approval_mode()?);
+            }
+            UpgradeGroup::EnhancedLimits => {
+                answers.enhanced_limits = Some(prompt_enhanced_limits()?);
+            }
+        }
+    }
+
+    Ok(answers)
+}
+
+pub(crate) fn apply_defaults(config_path: &Path, missing: &[UpgradeGroup]) -> Result<()> {
+    patch_config(config_path, missing, &default_answers(missing))?;
+
+    eprintln!("Applied default values for new features:");
+    for group in missing {
+        eprintln!("  • {}", group.display_name());
+    }
+    eprintln!("Backup saved to {}.bak", config_path.display());
+
+    Ok(())
+}
+
+/// Default values for each missing feature group.
+pub(super) fn default_answers(missing: &[UpgradeGroup]) -> UpgradeAnswers {
+    UpgradeAnswers {
+        persona: if missing.contains(&UpgradeGroup::Persona) {
+            Some((vec![], vec![], vec![]))
+        } else {
+            None
+        },
+        targets: if missing.contains(&UpgradeGroup::Targets) {
+            Some(vec![])
+        } else {
+            None
+        },
+        approval_mode: if missing.contains(&UpgradeGroup::ApprovalMode) {
+            Some(false)
+        } else {
+            None
+        },
+        enhanced_limits: if missing.contains(&UpgradeGroup::EnhancedLimits) {
+            Some((
+                1,
+                vec![
+                    "check out".to_string(),
+                    "you should try".to_string(),
+                    "I recommend".to_string(),
+                    "link in bio".to_string(),
+                ],
+                0.2,
+            ))
+        } else {
+            None
+        },
+    }
+}
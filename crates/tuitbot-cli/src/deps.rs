@@ -5,34 +5,40 @@
 
 use std::sync::Arc;
 
-use chrono::Utc;
 use tokio::sync::mpsc;
 
 use tuitbot_core::automation::adapters::{
     AnalyticsStorageAdapter, ApprovalQueueAdapter, ContentSafetyAdapter, ContentStorageAdapter,
-    LlmReplyAdapter, LlmThreadAdapter, LlmTweetAdapter, PostSenderAdapter, SafetyAdapter,
-    ScoringAdapter, StatusQuerierAdapter, StorageAdapter, TargetStorageAdapter, TopicScorerAdapter,
-    XApiMentionsAdapter, XApiPostExecutorAdapter, XApiProfileAdapter, XApiSearchAdapter,
-    XApiTargetAdapter, XApiThreadPosterAdapter,
+    LlmReplyAdapter, LlmThreadAdapter, LlmTweetAdapter, PacerAdapter, PostSenderAdapter,
+    SafetyAdapter, ScoringAdapter, StatusQuerierAdapter, StorageAdapter, TargetStorageAdapter,
+    ThresholdCalibratorAdapter, TopicScorerAdapter, TrendSourceAdapter, XApiMentionsAdapter,
+    XApiPostExecutorAdapter, XApiProfileAdapter, XApiSearchAdapter, XApiTargetAdapter,
+    XApiThreadPosterAdapter,
 };
 use tuitbot_core::automation::schedule::ActiveSchedule;
-use tuitbot_core::automation::{create_posting_queue, ApprovalQueue, PostAction, TargetLoopConfig};
+use tuitbot_core::automation::{blocklist_sync, content_mix, warmup};
+use tuitbot_core::automation::{
+    create_posting_queue, ApprovalQueue, PacingPlanner, PostAction, TargetLoopConfig,
+};
 use tuitbot_core::config::Config;
 use tuitbot_core::content::ContentGenerator;
+use tuitbot_core::hooks::HookRunner;
 use tuitbot_core::llm::factory::create_provider;
 use tuitbot_core::safety::SafetyGuard;
-use tuitbot_core::scoring::ScoringEngine;
-use tuitbot_core::startup::{
-    expand_tilde, load_tokens_from_file, token_file_path, ApiTier, TierCapabilities,
-};
+use tuitbot_core::scoring::{ScoringEngine, ScoringPlugin};
+use tuitbot_core::startup::{expand_tilde, ApiTier, TierCapabilities};
 use tuitbot_core::storage;
-use tuitbot_core::x_api::auth::{TokenManager, Tokens};
+use tuitbot_core::storage::accounts::DEFAULT_ACCOUNT_ID;
+use tuitbot_core::storage::write_queue::WriteQueue;
+use tuitbot_core::x_api::auth::TokenManager;
 use tuitbot_core::x_api::tier::{self, detect_tier};
 use tuitbot_core::x_api::{XApiClient, XApiHttpClient};
 
 /// All shared dependencies needed by the automation loops.
 pub struct RuntimeDeps {
     pub pool: sqlx::SqlitePool,
+    /// Shared by the loops' storage adapters and the embedded API server.
+    pub write_queue: WriteQueue,
     pub tier: ApiTier,
     pub capabilities: TierCapabilities,
 
@@ -60,18 +66,28 @@ pub struct RuntimeDeps {
     pub target_storage: Arc<TargetStorageAdapter>,
     pub analytics_storage: Arc<AnalyticsStorageAdapter>,
     pub topic_scorer: Arc<TopicScorerAdapter>,
+    pub trend_source: Arc<TrendSourceAdapter>,
     pub post_sender: Arc<PostSenderAdapter>,
     pub status_querier: Arc<StatusQuerierAdapter>,
 
     // Schedule
     pub active_schedule: Option<Arc<ActiveSchedule>>,
 
+    // Request pacing (None when disabled)
+    pub pacer: Option<Arc<PacerAdapter>>,
+
+    // Score threshold auto-calibration (None when disabled)
+    pub calibrator: Option<Arc<ThresholdCalibratorAdapter>>,
+
     // Posting queue
     pub post_rx: Option<mpsc::Receiver<PostAction>>,
 
     // Approval
     pub approval_queue: Option<Arc<dyn ApprovalQueue>>,
 
+    // Lifecycle hooks (empty when none are configured)
+    pub hooks: Arc<HookRunner>,
+
     // Token refresh
     pub token_manager: Arc<TokenManager>,
     pub x_client: Arc<XApiHttpClient>,
@@ -91,46 +107,20 @@ impl RuntimeDeps {
         let db_path = expand_tilde(&config.storage.db_path);
         tracing::info!(path = %db_path.display(), "Database path configured");
 
-        // 2. Load OAuth tokens and create token manager.
-        let stored = load_tokens_from_file().map_err(|e| anyhow::anyhow!("{e}"))?;
-
-        let auth_tokens = Tokens {
-            access_token: stored.access_token.clone(),
-            refresh_token: stored.refresh_token.clone().unwrap_or_default(),
-            expires_at: stored.expires_at.unwrap_or_else(Utc::now),
-            scopes: stored.scopes.clone(),
-        };
-
-        let token_manager = Arc::new(TokenManager::new(
-            auth_tokens,
-            config.x_api.client_id.clone(),
-            token_file_path(),
-        ));
-
-        // Attempt refresh if token is expired or near expiry, instead of bailing.
-        if let Err(e) = token_manager.refresh_if_needed().await {
-            if stored.is_expired() {
-                anyhow::bail!(
-                    "Authentication expired and refresh failed ({e}). Run `tuitbot auth` to re-authenticate."
-                );
-            }
-            // Token not yet expired but refresh failed — log and continue.
-            tracing::warn!(error = %e, "Token refresh attempt failed, continuing with current token");
-        }
-
-        let current_token = token_manager
-            .tokens_lock()
-            .read()
-            .await
-            .access_token
-            .clone();
-        tracing::info!(
-            expires_in = %stored.format_expiry(),
-            "OAuth tokens loaded"
+        // 2. Load OAuth tokens, refreshing them if expired or near expiry.
+        let token_manager = Arc::new(
+            TokenManager::load(&config.x_api.client_id)
+                .await
+                .map_err(|e| anyhow::anyhow!("{e}"))?,
         );
+        let expires_at = token_manager.tokens_lock().read().await.expires_at;
+        tracing::info!(%expires_at, "OAuth tokens loaded");
 
         // 3. Determine API tier by probing the search endpoint.
-        let x_client = XApiHttpClient::new(current_token);
+        let x_client =
+            XApiHttpClient::from_config(token_manager.access_token().await, &config.x_api)
+                .with_protected_entities(&config.blocklist.protected);
+        token_manager.attach(&x_client).await;
         let detected = detect_tier(&x_client)
             .await
             .map_err(|e| anyhow::anyhow!("Tier detection failed: {e}"))?;
@@ -147,12 +137,40 @@ impl RuntimeDeps {
             .await
             .map_err(|e| anyhow::anyhow!("Database initialization failed: {e}"))?;
         tracing::info!("Database initialized");
+        if storage::archive::is_archived(&pool).await? {
+            pool.close().await;
+            anyhow::bail!(
+                "This install is archived. Browse its history with `tuitbot-server`, \
+                 or run `tuitbot archive --undo` to resume automation."
+            );
+        }
 
         // 4b. Inject DB pool into X API client for usage tracking.
         x_client.set_pool(pool.clone()).await;
 
-        // 5. Initialize rate limits.
-        storage::rate_limits::init_rate_limits(&pool, &config.limits, &config.intervals)
+        // 5. Initialize rate limits, scaled down while the account warms up.
+        let (limits, warmup_phase) = warmup::advance_warmup_for(&pool, DEFAULT_ACCOUNT_ID, config)
+            .await
+            .map_err(|e| anyhow::anyhow!("Warm-up initialization failed: {e}"))?;
+        if let Some(phase) = &warmup_phase {
+            tracing::info!("Account warm-up: {}", phase.format_line());
+        }
+        // 5a. Plan today's content mix; the plan caps the day's limits.
+        content_mix::ensure_plan_for(&pool, DEFAULT_ACCOUNT_ID, config)
+            .await
+            .map_err(|e| anyhow::anyhow!("Content plan initialization failed: {e}"))?;
+        let limits = content_mix::planned_limits_for(&pool, DEFAULT_ACCOUNT_ID, config, limits)
+            .await
+            .map_err(|e| anyhow::anyhow!("Content plan initialization failed: {e}"))?;
+        storage::rate_limits::init_rate_limits(&pool, &limits, &config.intervals)
+            .await
+            .map_err(|e| anyhow::anyhow!("Rate limit initialization failed: {e}"))?;
+        if config.reddit.enabled {
+            storage::rate_limits::init_reddit_rate_limit(&pool, config.reddit.max_comments_per_day)
+                .await
+                .map_err(|e| anyhow::anyhow!("Rate limit initialization failed: {e}"))?;
+        }
+        storage::rate_limits::set_reset_timezone(&pool, config.rate_limit_timezone())
             .await
             .map_err(|e| anyhow::anyhow!("Rate limit initialization failed: {e}"))?;
         tracing::info!("Rate limits initialized");
@@ -162,10 +180,18 @@ impl RuntimeDeps {
             .await
             .map_err(|e| anyhow::anyhow!("Failed to persist API tier: {e}"))?;
 
+        // 5c. Load manual blocklist entries; X mutes and blocks sync at run time.
+        blocklist_sync::sync_manual_blocklist_for(&pool, DEFAULT_ACCOUNT_ID, &config.blocklist)
+            .await
+            .map_err(|e| anyhow::anyhow!("Blocklist initialization failed: {e}"))?;
+
         // 6. Create LLM provider and content generator.
         let provider = create_provider(&config.llm)
             .map_err(|e| anyhow::anyhow!("LLM provider creation failed: {e}"))?;
-        let content_gen = Arc::new(ContentGenerator::new(provider, config.business.clone()));
+        let content_gen = Arc::new(
+            ContentGenerator::new(provider, config.business.clone())
+                .with_disclosure(config.disclosure.clone()),
+        );
         tracing::info!("LLM provider and content generator initialized");
 
         // 7. Create scoring engine and safety guard.
@@ -176,8 +202,17 @@ impl RuntimeDeps {
             .chain(config.business.competitor_keywords.iter())
             .cloned()
             .collect();
-        let scoring_engine = Arc::new(ScoringEngine::new(config.scoring.clone(), keywords.clone()));
-        let safety_guard = Arc::new(SafetyGuard::new(pool.clone()));
+        let scoring_plugin = ScoringPlugin::load(&config.scoring.plugin)
+            .map_err(|e| anyhow::anyhow!("Scoring plugin initialization failed: {e}"))?;
+        let scoring_engine = Arc::new(
+            ScoringEngine::new(config.scoring.clone(), keywords.clone())
+                .with_plugin(scoring_plugin),
+        );
+        let safety_guard = Arc::new(
+            SafetyGuard::new(pool.clone())
+                .with_protected_entities(&config.blocklist.protected)
+                .with_trusted_authors(&config.trusted_authors),
+        );
         tracing::info!("Scoring engine and safety guard initialized");
 
         // 8. Get own user ID.
@@ -193,21 +228,31 @@ impl RuntimeDeps {
         let (post_tx, post_rx) = create_posting_queue();
 
         // 10. Create adapter structs.
+        // Loop writes share one queue so they never race each other (or the
+        // embedded API server) for the SQLite write lock.
+        let write_queue = WriteQueue::spawn(pool.clone());
         // Cast to trait object once for all adapters (AD-06).
         let dyn_client: Arc<dyn XApiClient> = x_client.clone() as Arc<dyn XApiClient>;
         let searcher: Arc<XApiSearchAdapter> = Arc::new(XApiSearchAdapter::new(dyn_client.clone()));
-        let mentions_fetcher: Arc<XApiMentionsAdapter> = Arc::new(XApiMentionsAdapter::new(
-            dyn_client.clone(),
-            own_user_id.clone(),
-        ));
+        let mentions_fetcher: Arc<XApiMentionsAdapter> = Arc::new(
+            XApiMentionsAdapter::new(dyn_client.clone(), own_user_id.clone())
+                .with_kill_switch(pool.clone()),
+        );
         let target_adapter: Arc<XApiTargetAdapter> =
             Arc::new(XApiTargetAdapter::new(dyn_client.clone()));
         let profile_adapter: Arc<XApiProfileAdapter> =
             Arc::new(XApiProfileAdapter::new(dyn_client.clone()));
-        let post_executor: Arc<XApiPostExecutorAdapter> =
-            Arc::new(XApiPostExecutorAdapter::new(dyn_client.clone()));
-        let thread_poster: Arc<XApiThreadPosterAdapter> =
-            Arc::new(XApiThreadPosterAdapter::new(dyn_client));
+        let hooks = Arc::new(HookRunner::new(config.hooks.clone()).with_pool(pool.clone()));
+        let post_executor: Arc<XApiPostExecutorAdapter> = Arc::new(
+            XApiPostExecutorAdapter::new(dyn_client.clone())
+                .with_kill_switch(pool.clone())
+                .with_hooks(hooks.clone()),
+        );
+        let thread_poster: Arc<XApiThreadPosterAdapter> = Arc::new(
+            XApiThreadPosterAdapter::new(dyn_client.clone())
+                .with_kill_switch(pool.clone())
+                .with_hooks(hooks.clone()),
+        );
 
         let reply_gen: Arc<LlmReplyAdapter> =
             Arc::new(LlmReplyAdapter::new(content_gen.clone(), pool.clone()));
@@ -217,29 +262,42 @@ impl RuntimeDeps {
             Arc::new(LlmThreadAdapter::new(content_gen.clone(), pool.clone()));
 
         let scorer: Arc<ScoringAdapter> = Arc::new(ScoringAdapter::new(scoring_engine));
-        let safety: Arc<SafetyAdapter> =
-            Arc::new(SafetyAdapter::new(safety_guard.clone(), pool.clone()));
+        let safety: Arc<SafetyAdapter> = Arc::new(
+            SafetyAdapter::new(safety_guard.clone(), pool.clone())
+                .with_write_queue(write_queue.clone())
+                .with_qa_config(Arc::new(config.clone()))
+                .with_context_check(config.context_check.clone())
+                .with_reciprocity(config.reciprocity.clone())
+                .with_author_overlap(config.author_overlap.clone(), dyn_client.clone()),
+        );
         let content_safety: Arc<ContentSafetyAdapter> =
             Arc::new(ContentSafetyAdapter::new(safety_guard));
 
-        let loop_storage: Arc<StorageAdapter> = Arc::new(StorageAdapter::new(pool.clone()));
-        let content_storage: Arc<ContentStorageAdapter> =
-            Arc::new(ContentStorageAdapter::new(pool.clone(), post_tx.clone()));
+        let loop_storage: Arc<StorageAdapter> =
+            Arc::new(StorageAdapter::new(pool.clone()).with_write_queue(write_queue.clone()));
+        let content_storage: Arc<ContentStorageAdapter> = Arc::new(
+            ContentStorageAdapter::new(pool.clone(), post_tx.clone())
+                .with_write_queue(write_queue.clone()),
+        );
         let target_storage: Arc<TargetStorageAdapter> =
-            Arc::new(TargetStorageAdapter::new(pool.clone()));
-        let analytics_storage: Arc<AnalyticsStorageAdapter> =
-            Arc::new(AnalyticsStorageAdapter::new(pool.clone()));
+            Arc::new(TargetStorageAdapter::new(pool.clone()).with_write_queue(write_queue.clone()));
+        let analytics_storage: Arc<AnalyticsStorageAdapter> = Arc::new(
+            AnalyticsStorageAdapter::new(pool.clone()).with_write_queue(write_queue.clone()),
+        );
         let topic_scorer: Arc<TopicScorerAdapter> = Arc::new(TopicScorerAdapter::new(pool.clone()));
+        let trend_source: Arc<TrendSourceAdapter> = Arc::new(TrendSourceAdapter::new(
+            pool.clone(),
+            config.trending.lookback_hours,
+            config.trending.min_tweets,
+        ));
         let post_sender: Arc<PostSenderAdapter> = Arc::new(PostSenderAdapter::new(post_tx));
-        let status_querier: Arc<StatusQuerierAdapter> =
-            Arc::new(StatusQuerierAdapter::new(pool.clone()));
-
-        // Approval queue (enabled if approval_mode is set or in composer mode).
-        let approval_queue: Option<Arc<dyn ApprovalQueue>> = if config.effective_approval_mode() {
-            Some(Arc::new(ApprovalQueueAdapter::new(pool.clone())))
-        } else {
-            None
-        };
+        // Approval queue: reviews everything if approval_mode is set or in
+        // composer mode, and catches every post while draft-only mode is on.
+        let approval_queue: Option<Arc<dyn ApprovalQueue>> = Some(Arc::new(
+            ApprovalQueueAdapter::new(pool.clone())
+                .with_write_queue(write_queue.clone())
+                .with_approval_mode(config.effective_approval_mode()),
+        ));
 
         // Parse active hours schedule.
         let active_schedule: Option<Arc<ActiveSchedule>> =
@@ -252,15 +310,46 @@ impl RuntimeDeps {
                 Arc::new(s)
             });
 
+        // Request pacing spreads daily read budgets across the active window.
+        let pacing_planner = config
+            .pacing
+            .enabled
+            .then(|| PacingPlanner::new(&config.pacing, active_schedule.clone()));
+        let pacer: Option<Arc<PacerAdapter>> = pacing_planner.clone().map(|planner| {
+            Arc::new(PacerAdapter::new(pool.clone(), planner).with_write_queue(write_queue.clone()))
+        });
+        let mut status_querier = StatusQuerierAdapter::new(pool.clone());
+        if let Some(planner) = pacing_planner {
+            status_querier = status_querier.with_pacing(planner);
+        }
+
+        // Threshold calibration steps the score threshold toward the daily reply target.
+        let calibrator: Option<Arc<ThresholdCalibratorAdapter>> =
+            config.scoring.calibration.enabled.then(|| {
+                Arc::new(ThresholdCalibratorAdapter::new(
+                    pool.clone(),
+                    config.scoring.clone(),
+                ))
+            });
+        if config.scoring.calibration.enabled {
+            status_querier = status_querier.with_calibration(config.scoring.clone());
+        }
+        if config.warmup.enabled {
+            status_querier = status_querier.with_warmup(config.clone());
+        }
+        let status_querier = Arc::new(status_querier);
+
         // Target loop config.
         let target_loop_config = TargetLoopConfig {
             accounts: config.targets.accounts.clone(),
             max_target_replies_per_day: config.targets.max_target_replies_per_day,
+            tiers: config.targets.tiers.clone(),
             dry_run,
         };
 
         Ok(Self {
             pool,
+            write_queue,
             tier,
             capabilities,
             searcher,
@@ -280,11 +369,15 @@ impl RuntimeDeps {
             target_storage,
             analytics_storage,
             topic_scorer,
+            trend_source,
             post_sender,
             status_querier,
             active_schedule,
+            pacer,
+            calibrator,
             post_rx: Some(post_rx),
             approval_queue,
+            hooks,
             token_manager,
             x_client: x_client.clone(),
             keywords,
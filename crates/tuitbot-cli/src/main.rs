@@ -9,8 +9,11 @@ pub mod output;
 use std::io::IsTerminal;
 
 use clap::Parser;
-use tracing_subscriber::EnvFilter;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::{EnvFilter, Layer};
 use tuitbot_core::config::Config;
+use tuitbot_core::storage;
 
 /// Autonomous X growth assistant
 #[derive(Parser)]
@@ -71,14 +74,15 @@ enum Commands {
     Settings(commands::SettingsArgs),
     /// Score a specific tweet
     Score(commands::ScoreArgs),
+    /// Draft replies to one tweet by URL, then queue or post one
+    ReplyTo(commands::ReplyToArgs),
     /// Show analytics dashboard
     Stats(commands::StatsArgs),
     /// Review and approve queued posts
     Approve(commands::ApproveArgs),
     /// Check for updates and upgrade binary + config
     Update(commands::UpdateArgs),
-    /// Configure new features added since last setup
-    #[command(hide = true)]
+    /// Bring an older config.toml up to date (`tuitbot upgrade config`)
     Upgrade(commands::UpgradeArgs),
     /// Run each enabled loop once and exit (for external schedulers)
     Tick(commands::TickArgs),
@@ -88,6 +92,24 @@ enum Commands {
     Backup(commands::BackupArgs),
     /// Restore database from a backup
     Restore(commands::RestoreArgs),
+    /// Move the bot to another machine with an encrypted bundle
+    Migrate(commands::MigrateArgs),
+    /// Privacy tools (forget a specific external user)
+    Privacy(commands::PrivacyArgs),
+    /// Halt all mutations immediately (compliance kill switch)
+    Kill(commands::KillArgs),
+    /// Queue every post for review and hold approved items (draft-only mode)
+    DraftOnly(commands::DraftOnlyArgs),
+    /// Lift safe mode and re-enable posting after a crash or failure streak
+    Resume(commands::ResumeArgs),
+    /// Manage target account tiers
+    Targets(commands::TargetsArgs),
+    /// Check notification channels
+    Notify(commands::NotifyArgs),
+    /// Database diagnostics (slow-query report)
+    Db(commands::DbArgs),
+    /// Retire the bot: compact the database and serve history read-only
+    Archive(commands::ArchiveArgs),
 }
 
 #[tokio::main]
@@ -129,10 +151,14 @@ async fn run() -> anyhow::Result<()> {
         EnvFilter::new("tuitbot=info,tuitbot_core=info,warn")
     };
 
-    tracing_subscriber::fmt()
-        .with_env_filter(filter)
-        .with_target(cli.verbose)
-        .compact()
+    tracing_subscriber::registry()
+        .with(
+            tracing_subscriber::fmt::layer()
+                .with_target(cli.verbose)
+                .compact()
+                .with_filter(filter),
+        )
+        .with(storage::query_log::layer())
         .init();
 
     let output_format = commands::OutputFormat::from_str(&cli.output);
@@ -140,7 +166,13 @@ async fn run() -> anyhow::Result<()> {
     // Handle `init`, `update`, `upgrade`, and `settings` before general config
     // loading (they manage their own config lifecycle).
     if let Commands::Init(args) = cli.command {
-        return commands::init::execute(args.force, args.non_interactive, args.advanced).await;
+        return commands::init::execute(
+            args.force,
+            args.non_interactive,
+            args.advanced,
+            args.template.as_deref(),
+        )
+        .await;
     }
     if let Commands::Update(args) = cli.command {
         return commands::update::execute(
@@ -152,17 +184,25 @@ async fn run() -> anyhow::Result<()> {
         .await;
     }
     if let Commands::Upgrade(args) = cli.command {
-        return commands::upgrade::execute(args.non_interactive, &cli.config).await;
+        return match args.command {
+            Some(commands::UpgradeSubcommand::Config { non_interactive }) => {
+                commands::upgrade::config::execute(non_interactive, &cli.config).await
+            }
+            None => commands::upgrade::execute(args.non_interactive, &cli.config).await,
+        };
     }
     if let Commands::Settings(args) = cli.command {
         return commands::settings::execute(args, &cli.config, output_format).await;
     }
     if let Commands::Backup(args) = cli.command {
-        return commands::backup::execute(args).await;
+        return commands::backup::execute(args, &cli.config).await;
     }
     if let Commands::Restore(args) = cli.command {
         return commands::restore::execute(args).await;
     }
+    if let Commands::Migrate(args) = cli.command {
+        return commands::migrate::execute(args, &cli.config).await;
+    }
     if let Commands::Mcp(ref args) = cli.command {
         return match &args.command {
             commands::McpSubcommand::Manifest { ref profile } => {
@@ -172,6 +212,9 @@ async fn run() -> anyhow::Result<()> {
                 commands::mcp::execute_serve(profile).await
             }
             commands::McpSubcommand::Setup => commands::mcp::execute_setup().await,
+            commands::McpSubcommand::Replay(ref replay_args) => {
+                commands::mcp::execute_replay(replay_args, output_format).await
+            }
         };
     }
 
@@ -194,7 +237,7 @@ async fn run() -> anyhow::Result<()> {
                     .unwrap_or(false);
 
                 if run_init {
-                    return commands::init::execute(false, false, false).await;
+                    return commands::init::execute(false, false, false, None).await;
                 }
             }
 
@@ -205,6 +248,8 @@ async fn run() -> anyhow::Result<()> {
         }
     };
 
+    storage::query_log::configure(&config.storage);
+
     // Check for config upgrade opportunity before `run`
     if matches!(&cli.command, Commands::Run(_)) && std::io::stdin().is_terminal() {
         commands::update::check_before_run(&cli.config).await?;
@@ -217,11 +262,12 @@ async fn run() -> anyhow::Result<()> {
         | Commands::Settings(_)
         | Commands::Backup(_)
         | Commands::Restore(_)
+        | Commands::Migrate(_)
         | Commands::Mcp(_) => {
             unreachable!()
         }
         Commands::Run(args) => {
-            commands::run::execute(&config, args.status_interval).await?;
+            commands::run::execute(&config, &cli.config, args).await?;
         }
         Commands::Tick(args) => {
             let mut config = config;
@@ -233,8 +279,9 @@ async fn run() -> anyhow::Result<()> {
         Commands::Auth(args) => {
             commands::auth::execute(&config, args.mode.as_deref()).await?;
         }
-        Commands::Test(_args) => {
-            commands::test::execute(&config, &cli.config, output_format).await?;
+        Commands::Test(args) => {
+            commands::test::execute(&config, &cli.config, args.lint, args.tier, output_format)
+                .await?;
         }
         Commands::Discover(_args) => {
             eprintln!("discover: not yet available (requires WP08 merge)");
@@ -251,12 +298,39 @@ async fn run() -> anyhow::Result<()> {
         Commands::Score(_args) => {
             eprintln!("score: not yet available (requires WP06 merge)");
         }
+        Commands::ReplyTo(args) => {
+            commands::reply_to::execute(&config, args, output_format).await?;
+        }
         Commands::Stats(_args) => {
             commands::stats::execute(&config, output_format).await?;
         }
         Commands::Approve(args) => {
             commands::approve::execute(&config, args, output_format).await?;
         }
+        Commands::Privacy(args) => {
+            commands::privacy::execute(&config, args, output_format).await?;
+        }
+        Commands::Kill(args) => {
+            commands::kill::execute(&config, args, output_format).await?;
+        }
+        Commands::DraftOnly(args) => {
+            commands::draft_only::execute(&config, args, output_format).await?;
+        }
+        Commands::Resume(args) => {
+            commands::resume::execute(&config, args, output_format).await?;
+        }
+        Commands::Targets(args) => {
+            commands::targets::execute(&config, &cli.config, args, output_format).await?;
+        }
+        Commands::Notify(args) => {
+            commands::notify::execute(&config, args, output_format).await?;
+        }
+        Commands::Db(args) => {
+            commands::db::execute(&config, args, output_format).await?;
+        }
+        Commands::Archive(args) => {
+            commands::archive::execute(&config, args, output_format).await?;
+        }
     }
 
     Ok(())
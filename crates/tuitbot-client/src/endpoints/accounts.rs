@@ -0,0 +1,56 @@
+use serde_json::Value;
+use tuitbot_types::accounts::{
+    CreateAccountRequest, RemoveRoleRequest, SetRoleRequest, UpdateAccountRequest,
+};
+
+use crate::{segment, Result, TuitbotClient};
+
+impl TuitbotClient {
+    /// `GET /api/accounts`
+    pub async fn list_accounts(&self) -> Result<Value> {
+        self.get("/accounts").await
+    }
+
+    /// `GET /api/accounts/{id}`
+    pub async fn get_account(&self, id: &str) -> Result<Value> {
+        self.get(&format!("/accounts/{}", segment(id))).await
+    }
+
+    /// `POST /api/accounts`
+    pub async fn create_account(&self, body: &CreateAccountRequest) -> Result<Value> {
+        self.post("/accounts", body).await
+    }
+
+    /// `PATCH /api/accounts/{id}`
+    pub async fn update_account(&self, id: &str, body: &UpdateAccountRequest) -> Result<Value> {
+        self.patch(&format!("/accounts/{}", segment(id)), body)
+            .await
+    }
+
+    /// `DELETE /api/accounts/{id}`
+    pub async fn delete_account(&self, id: &str) -> Result<Value> {
+        self.delete(&format!("/accounts/{}", segment(id))).await
+    }
+
+    /// `GET /api/accounts/{id}/roles`
+    pub async fn list_roles(&self, id: &str) -> Result<Value> {
+        self.get(&format!("/accounts/{}/roles", segment(id))).await
+    }
+
+    /// `POST /api/accounts/{id}/roles`
+    pub async fn set_role(&self, id: &str, body: &SetRoleRequest) -> Result<Value> {
+        self.post(&format!("/accounts/{}/roles", segment(id)), body)
+            .await
+    }
+
+    /// `DELETE /api/accounts/{id}/roles`
+    pub async fn remove_role(&self, id: &str, body: &RemoveRoleRequest) -> Result<Value> {
+        let request = self
+            .request(
+                reqwest::Method::DELETE,
+                &format!("/accounts/{}/roles", segment(id)),
+            )
+            .json(body);
+        self.send(request).await
+    }
+}
@@ -0,0 +1,63 @@
+use reqwest::Method;
+use serde_json::Value;
+use tuitbot_types::replies::ReplyExplanationResponse;
+
+use crate::{query, Result, TuitbotClient};
+
+impl TuitbotClient {
+    /// `GET /api/activity`
+    ///
+    /// `action_type` and `status` filter the log; `None` returns everything.
+    pub async fn list_activity(
+        &self,
+        limit: Option<u32>,
+        offset: Option<u32>,
+        action_type: Option<&str>,
+        status: Option<&str>,
+    ) -> Result<Value> {
+        let params = query(&[
+            ("limit", limit.map(|v| v.to_string())),
+            ("offset", offset.map(|v| v.to_string())),
+            ("type", action_type.map(str::to_string)),
+            ("status", status.map(str::to_string)),
+        ]);
+        self.get_query("/activity", &params).await
+    }
+
+    /// `GET /api/activity/export`
+    ///
+    /// Returns the export body as text; `format` is `"csv"` (default) or `"json"`.
+    pub async fn export_activity(
+        &self,
+        format: Option<&str>,
+        action_type: Option<&str>,
+        status: Option<&str>,
+    ) -> Result<String> {
+        let params = query(&[
+            ("format", format.map(str::to_string)),
+            ("type", action_type.map(str::to_string)),
+            ("status", status.map(str::to_string)),
+        ]);
+        self.send_text(self.request(Method::GET, "/activity/export").query(&params))
+            .await
+    }
+
+    /// `GET /api/activity/rate-limits`
+    pub async fn rate_limit_usage(&self) -> Result<Value> {
+        self.get("/activity/rate-limits").await
+    }
+
+    /// `GET /api/replies`
+    pub async fn list_replies(&self, limit: Option<u32>, offset: Option<u32>) -> Result<Value> {
+        let params = query(&[
+            ("limit", limit.map(|v| v.to_string())),
+            ("offset", offset.map(|v| v.to_string())),
+        ]);
+        self.get_query("/replies", &params).await
+    }
+
+    /// `GET /api/replies/{id}/explanation`
+    pub async fn reply_explanation(&self, id: i64) -> Result<ReplyExplanationResponse> {
+        self.get(&format!("/replies/{id}/explanation")).await
+    }
+}
@@ -0,0 +1,41 @@
+use serde_json::Value;
+use tuitbot_types::admin::{EnableDraftOnlyRequest, EngageKillSwitchRequest};
+
+use crate::{Result, TuitbotClient};
+
+impl TuitbotClient {
+    /// `GET /api/admin/kill`
+    pub async fn get_kill_switch(&self) -> Result<Value> {
+        self.get("/admin/kill").await
+    }
+
+    /// `POST /api/admin/kill` — halt all mutations until released.
+    pub async fn engage_kill_switch(&self, body: &EngageKillSwitchRequest) -> Result<Value> {
+        self.post("/admin/kill", body).await
+    }
+
+    /// `DELETE /api/admin/kill`
+    pub async fn release_kill_switch(&self) -> Result<Value> {
+        self.delete("/admin/kill").await
+    }
+
+    /// `GET /api/admin/draft-only`
+    pub async fn get_draft_only(&self) -> Result<Value> {
+        self.get("/admin/draft-only").await
+    }
+
+    /// `POST /api/admin/draft-only` — queue all posts for review until lifted.
+    pub async fn enable_draft_only(&self, body: &EnableDraftOnlyRequest) -> Result<Value> {
+        self.post("/admin/draft-only", body).await
+    }
+
+    /// `DELETE /api/admin/draft-only`
+    pub async fn disable_draft_only(&self) -> Result<Value> {
+        self.delete("/admin/draft-only").await
+    }
+
+    /// `POST /api/admin/resume` — lift safe mode and re-enable posting.
+    pub async fn resume_safe_mode(&self) -> Result<Value> {
+        self.post_empty("/admin/resume").await
+    }
+}
@@ -0,0 +1,129 @@
+use serde_json::Value;
+use tuitbot_types::analytics::{InspirationResponse, ReciprocityResponse, ThreadAnalyticsResponse};
+
+use crate::{query, Result, TuitbotClient};
+
+impl TuitbotClient {
+    /// `GET /api/analytics/summary`
+    pub async fn analytics_summary(&self) -> Result<Value> {
+        self.get("/analytics/summary").await
+    }
+
+    /// `GET /api/analytics/followers`
+    ///
+    /// Passing `from`/`to` switches to the bucketed response; `granularity`
+    /// is `hour`, `day`, or `week`.
+    pub async fn analytics_followers(
+        &self,
+        days: Option<u32>,
+        from: Option<&str>,
+        to: Option<&str>,
+        granularity: Option<&str>,
+    ) -> Result<Value> {
+        let params = query(&[
+            ("days", days.map(|v| v.to_string())),
+            ("from", from.map(str::to_string)),
+            ("to", to.map(str::to_string)),
+            ("granularity", granularity.map(str::to_string)),
+        ]);
+        self.get_query("/analytics/followers", &params).await
+    }
+
+    /// `GET /api/analytics/performance`
+    pub async fn analytics_performance(
+        &self,
+        from: Option<&str>,
+        to: Option<&str>,
+        granularity: Option<&str>,
+    ) -> Result<Value> {
+        let params = query(&[
+            ("from", from.map(str::to_string)),
+            ("to", to.map(str::to_string)),
+            ("granularity", granularity.map(str::to_string)),
+        ]);
+        self.get_query("/analytics/performance", &params).await
+    }
+
+    /// `GET /api/analytics/topics`
+    pub async fn analytics_topics(&self, limit: Option<u32>) -> Result<Value> {
+        let params = query(&[("limit", limit.map(|v| v.to_string()))]);
+        self.get_query("/analytics/topics", &params).await
+    }
+
+    /// `GET /api/analytics/approvals`
+    pub async fn analytics_approvals(&self, from: Option<&str>, to: Option<&str>) -> Result<Value> {
+        let params = query(&[
+            ("from", from.map(str::to_string)),
+            ("to", to.map(str::to_string)),
+        ]);
+        self.get_query("/analytics/approvals", &params).await
+    }
+
+    /// `GET /api/analytics/qa`
+    pub async fn analytics_qa(
+        &self,
+        from: Option<&str>,
+        to: Option<&str>,
+        limit: Option<u32>,
+    ) -> Result<Value> {
+        let params = query(&[
+            ("from", from.map(str::to_string)),
+            ("to", to.map(str::to_string)),
+            ("limit", limit.map(|v| v.to_string())),
+        ]);
+        self.get_query("/analytics/qa", &params).await
+    }
+
+    /// `GET /api/analytics/inspiration`
+    pub async fn analytics_inspiration(
+        &self,
+        from: Option<&str>,
+        to: Option<&str>,
+    ) -> Result<InspirationResponse> {
+        let params = query(&[
+            ("from", from.map(str::to_string)),
+            ("to", to.map(str::to_string)),
+        ]);
+        self.get_query("/analytics/inspiration", &params).await
+    }
+
+    /// `GET /api/analytics/threads`
+    pub async fn analytics_threads(
+        &self,
+        from: Option<&str>,
+        to: Option<&str>,
+    ) -> Result<ThreadAnalyticsResponse> {
+        let params = query(&[
+            ("from", from.map(str::to_string)),
+            ("to", to.map(str::to_string)),
+        ]);
+        self.get_query("/analytics/threads", &params).await
+    }
+
+    /// `GET /api/analytics/reciprocity`
+    ///
+    /// Authors we reply to at least `min_outbound` times (server default: 3)
+    /// who rarely engage back.
+    pub async fn analytics_reciprocity(
+        &self,
+        from: Option<&str>,
+        to: Option<&str>,
+        min_outbound: Option<u32>,
+        limit: Option<u32>,
+    ) -> Result<ReciprocityResponse> {
+        let params = query(&[
+            ("from", from.map(str::to_string)),
+            ("to", to.map(str::to_string)),
+            ("min_outbound", min_outbound.map(|v| v.to_string())),
+            ("limit", limit.map(|v| v.to_string())),
+        ]);
+        self.get_query("/analytics/reciprocity", &params).await
+    }
+
+    /// `GET /api/analytics/recent-performance`
+    pub async fn analytics_recent_performance(&self, limit: Option<u32>) -> Result<Value> {
+        let params = query(&[("limit", limit.map(|v| v.to_string()))]);
+        self.get_query("/analytics/recent-performance", &params)
+            .await
+    }
+}
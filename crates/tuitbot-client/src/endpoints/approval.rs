@@ -0,0 +1,112 @@
+use reqwest::Method;
+use serde_json::Value;
+use tuitbot_types::approval::{
+    AddCommentRequest, ApprovalComment, BatchApproveRequest, EditContentRequest,
+    RestoreItemResponse, ReviewAction,
+};
+
+use crate::{query, Result, TuitbotClient};
+
+impl TuitbotClient {
+    /// `GET /api/approval`
+    ///
+    /// `status` is a comma-separated list (server default: `pending`).
+    pub async fn list_approval_items(
+        &self,
+        status: Option<&str>,
+        action_type: Option<&str>,
+        reviewed_by: Option<&str>,
+        since: Option<&str>,
+    ) -> Result<Value> {
+        let params = query(&[
+            ("status", status.map(str::to_string)),
+            ("type", action_type.map(str::to_string)),
+            ("reviewed_by", reviewed_by.map(str::to_string)),
+            ("since", since.map(str::to_string)),
+        ]);
+        self.get_query("/approval", &params).await
+    }
+
+    /// `GET /api/approval/stats`
+    pub async fn approval_stats(&self) -> Result<Value> {
+        self.get("/approval/stats").await
+    }
+
+    /// `PATCH /api/approval/{id}`
+    pub async fn edit_approval_item(&self, id: i64, body: &EditContentRequest) -> Result<Value> {
+        self.patch(&format!("/approval/{id}"), body).await
+    }
+
+    /// `DELETE /api/approval/{id}`
+    ///
+    /// Soft-deletes the item; it can be restored until retention purges it.
+    pub async fn delete_approval_item(&self, id: i64) -> Result<Value> {
+        self.delete(&format!("/approval/{id}")).await
+    }
+
+    /// `POST /api/approval/{id}/restore`
+    ///
+    /// Restores a deleted item, or returns a rejected item to the queue.
+    pub async fn restore_approval_item(&self, id: i64) -> Result<RestoreItemResponse> {
+        self.post_empty(&format!("/approval/{id}/restore")).await
+    }
+
+    /// `GET /api/approval/deleted`
+    pub async fn list_deleted_approval_items(&self) -> Result<Value> {
+        self.get("/approval/deleted").await
+    }
+
+    /// `POST /api/approval/{id}/approve`
+    pub async fn approve_item(&self, id: i64, review: &ReviewAction) -> Result<Value> {
+        self.post(&format!("/approval/{id}/approve"), review).await
+    }
+
+    /// `POST /api/approval/{id}/reject`
+    pub async fn reject_item(&self, id: i64, review: &ReviewAction) -> Result<Value> {
+        self.post(&format!("/approval/{id}/reject"), review).await
+    }
+
+    /// `POST /api/approval/approve-all`
+    pub async fn approve_all(&self, body: &BatchApproveRequest) -> Result<Value> {
+        self.post("/approval/approve-all", body).await
+    }
+
+    /// `GET /api/approval/{id}/history`
+    pub async fn approval_edit_history(&self, id: i64) -> Result<Value> {
+        self.get(&format!("/approval/{id}/history")).await
+    }
+
+    /// `GET /api/approval/{id}/comments`
+    ///
+    /// The full comment thread, oldest first.
+    pub async fn list_approval_comments(&self, id: i64) -> Result<Vec<ApprovalComment>> {
+        self.get(&format!("/approval/{id}/comments")).await
+    }
+
+    /// `POST /api/approval/{id}/comments`
+    pub async fn add_approval_comment(
+        &self,
+        id: i64,
+        body: &AddCommentRequest,
+    ) -> Result<ApprovalComment> {
+        self.post(&format!("/approval/{id}/comments"), body).await
+    }
+
+    /// `GET /api/approval/export`
+    ///
+    /// Returns the export body as text; `format` is `"csv"` (default) or `"json"`.
+    pub async fn export_approval_items(
+        &self,
+        format: Option<&str>,
+        status: Option<&str>,
+        action_type: Option<&str>,
+    ) -> Result<String> {
+        let params = query(&[
+            ("format", format.map(str::to_string)),
+            ("status", status.map(str::to_string)),
+            ("type", action_type.map(str::to_string)),
+        ]);
+        self.send_text(self.request(Method::GET, "/approval/export").query(&params))
+            .await
+    }
+}
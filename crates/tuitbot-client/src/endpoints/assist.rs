@@ -0,0 +1,47 @@
+use tuitbot_types::assist::{
+    AssistImproveRequest, AssistImproveResponse, AssistReplyRequest, AssistReplyResponse,
+    AssistThreadRequest, AssistThreadResponse, AssistTopicsResponse, AssistTweetRequest,
+    AssistTweetResponse, ModeResponse, OptimalTimesResponse,
+};
+
+use crate::{Result, TuitbotClient};
+
+impl TuitbotClient {
+    /// `POST /api/assist/tweet`
+    pub async fn assist_tweet(&self, body: &AssistTweetRequest) -> Result<AssistTweetResponse> {
+        self.post("/assist/tweet", body).await
+    }
+
+    /// `POST /api/assist/reply`
+    pub async fn assist_reply(&self, body: &AssistReplyRequest) -> Result<AssistReplyResponse> {
+        self.post("/assist/reply", body).await
+    }
+
+    /// `POST /api/assist/thread`
+    pub async fn assist_thread(&self, body: &AssistThreadRequest) -> Result<AssistThreadResponse> {
+        self.post("/assist/thread", body).await
+    }
+
+    /// `POST /api/assist/improve`
+    pub async fn assist_improve(
+        &self,
+        body: &AssistImproveRequest,
+    ) -> Result<AssistImproveResponse> {
+        self.post("/assist/improve", body).await
+    }
+
+    /// `GET /api/assist/topics`
+    pub async fn assist_topics(&self) -> Result<AssistTopicsResponse> {
+        self.get("/assist/topics").await
+    }
+
+    /// `GET /api/assist/optimal-times`
+    pub async fn assist_optimal_times(&self) -> Result<OptimalTimesResponse> {
+        self.get("/assist/optimal-times").await
+    }
+
+    /// `GET /api/assist/mode`
+    pub async fn assist_mode(&self) -> Result<ModeResponse> {
+        self.get("/assist/mode").await
+    }
+}
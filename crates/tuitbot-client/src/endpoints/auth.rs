@@ -0,0 +1,24 @@
+use serde_json::Value;
+use tuitbot_types::auth::{AuthStatusResponse, LoginRequest, LoginResponse};
+
+use crate::{Result, TuitbotClient};
+
+impl TuitbotClient {
+    /// `POST /api/auth/login`
+    ///
+    /// The session cookie set by the server is not kept; use
+    /// [`with_token`](Self::with_token) for authenticated calls.
+    pub async fn login(&self, body: &LoginRequest) -> Result<LoginResponse> {
+        self.post("/auth/login", body).await
+    }
+
+    /// `POST /api/auth/logout`
+    pub async fn logout(&self) -> Result<Value> {
+        self.post_empty("/auth/logout").await
+    }
+
+    /// `GET /api/auth/status`
+    pub async fn auth_status(&self) -> Result<AuthStatusResponse> {
+        self.get("/auth/status").await
+    }
+}
@@ -0,0 +1,106 @@
+use serde_json::Value;
+use tuitbot_types::content::{
+    CalendarItem, ComposeRequest, ComposeThreadRequest, ComposeTweetRequest, CreateDraftRequest,
+    EditDraftRequest, EditScheduledRequest, ScheduleDraftRequest,
+};
+
+use crate::{query, Result, TuitbotClient};
+
+impl TuitbotClient {
+    /// `GET /api/content/tweets`
+    pub async fn list_tweets(&self, limit: Option<u32>) -> Result<Value> {
+        let params = query(&[("limit", limit.map(|v| v.to_string()))]);
+        self.get_query("/content/tweets", &params).await
+    }
+
+    /// `POST /api/content/tweets`
+    pub async fn compose_tweet(&self, body: &ComposeTweetRequest) -> Result<Value> {
+        self.post("/content/tweets", body).await
+    }
+
+    /// `GET /api/content/threads`
+    pub async fn list_threads(&self, limit: Option<u32>) -> Result<Value> {
+        let params = query(&[("limit", limit.map(|v| v.to_string()))]);
+        self.get_query("/content/threads", &params).await
+    }
+
+    /// `POST /api/content/threads`
+    pub async fn compose_thread(&self, body: &ComposeThreadRequest) -> Result<Value> {
+        self.post("/content/threads", body).await
+    }
+
+    /// `POST /api/content/compose`
+    pub async fn compose(&self, body: &ComposeRequest) -> Result<Value> {
+        self.post("/content/compose", body).await
+    }
+
+    /// `GET /api/content/calendar` for the ISO 8601 range `from`..`to`.
+    pub async fn calendar(&self, from: &str, to: &str) -> Result<Vec<CalendarItem>> {
+        self.get_query("/content/calendar", &[("from", from), ("to", to)])
+            .await
+    }
+
+    /// `GET /api/content/schedule`
+    pub async fn schedule(&self) -> Result<Value> {
+        self.get("/content/schedule").await
+    }
+
+    /// `PATCH /api/content/scheduled/{id}`
+    pub async fn edit_scheduled(&self, id: i64, body: &EditScheduledRequest) -> Result<Value> {
+        self.patch(&format!("/content/scheduled/{id}"), body).await
+    }
+
+    /// `DELETE /api/content/scheduled/{id}`
+    pub async fn cancel_scheduled(&self, id: i64) -> Result<Value> {
+        self.delete(&format!("/content/scheduled/{id}")).await
+    }
+
+    /// `GET /api/content/drafts`
+    pub async fn list_drafts(&self) -> Result<Value> {
+        self.get("/content/drafts").await
+    }
+
+    /// `POST /api/content/drafts`
+    pub async fn create_draft(&self, body: &CreateDraftRequest) -> Result<Value> {
+        self.post("/content/drafts", body).await
+    }
+
+    /// `GET /api/content/drafts/{id}`
+    pub async fn get_draft(&self, id: i64) -> Result<Value> {
+        self.get(&format!("/content/drafts/{id}")).await
+    }
+
+    /// `PATCH /api/content/drafts/{id}`
+    pub async fn edit_draft(&self, id: i64, body: &EditDraftRequest) -> Result<Value> {
+        self.patch(&format!("/content/drafts/{id}"), body).await
+    }
+
+    /// `DELETE /api/content/drafts/{id}`
+    pub async fn delete_draft(&self, id: i64) -> Result<Value> {
+        self.delete(&format!("/content/drafts/{id}")).await
+    }
+
+    /// `POST /api/content/drafts/{id}/check`
+    pub async fn check_draft(&self, id: i64) -> Result<Value> {
+        self.post_empty(&format!("/content/drafts/{id}/check"))
+            .await
+    }
+
+    /// `POST /api/content/drafts/{id}/promote`
+    pub async fn promote_draft(&self, id: i64) -> Result<Value> {
+        self.post_empty(&format!("/content/drafts/{id}/promote"))
+            .await
+    }
+
+    /// `POST /api/content/drafts/{id}/schedule`
+    pub async fn schedule_draft(&self, id: i64, body: &ScheduleDraftRequest) -> Result<Value> {
+        self.post(&format!("/content/drafts/{id}/schedule"), body)
+            .await
+    }
+
+    /// `POST /api/content/drafts/{id}/publish`
+    pub async fn publish_draft(&self, id: i64) -> Result<Value> {
+        self.post_empty(&format!("/content/drafts/{id}/publish"))
+            .await
+    }
+}
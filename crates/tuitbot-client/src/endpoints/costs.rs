@@ -0,0 +1,45 @@
+use serde_json::Value;
+
+use crate::{query, Result, TuitbotClient};
+
+impl TuitbotClient {
+    /// `GET /api/costs/summary`
+    pub async fn costs_summary(&self) -> Result<Value> {
+        self.get("/costs/summary").await
+    }
+
+    /// `GET /api/costs/daily`
+    pub async fn costs_daily(&self, days: Option<u32>) -> Result<Value> {
+        self.get_days("/costs/daily", days).await
+    }
+
+    /// `GET /api/costs/by-model`
+    pub async fn costs_by_model(&self, days: Option<u32>) -> Result<Value> {
+        self.get_days("/costs/by-model", days).await
+    }
+
+    /// `GET /api/costs/by-type`
+    pub async fn costs_by_type(&self, days: Option<u32>) -> Result<Value> {
+        self.get_days("/costs/by-type", days).await
+    }
+
+    /// `GET /api/costs/x-api/summary`
+    pub async fn x_api_costs_summary(&self) -> Result<Value> {
+        self.get("/costs/x-api/summary").await
+    }
+
+    /// `GET /api/costs/x-api/daily`
+    pub async fn x_api_costs_daily(&self, days: Option<u32>) -> Result<Value> {
+        self.get_days("/costs/x-api/daily", days).await
+    }
+
+    /// `GET /api/costs/x-api/by-endpoint`
+    pub async fn x_api_costs_by_endpoint(&self, days: Option<u32>) -> Result<Value> {
+        self.get_days("/costs/x-api/by-endpoint", days).await
+    }
+
+    async fn get_days(&self, path: &str, days: Option<u32>) -> Result<Value> {
+        let params = query(&[("days", days.map(|v| v.to_string()))]);
+        self.get_query(path, &params).await
+    }
+}
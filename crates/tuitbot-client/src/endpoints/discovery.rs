@@ -0,0 +1,62 @@
+use serde_json::Value;
+use tuitbot_types::discovery::{
+    ComposeReplyRequest, ComposeReplyResponse, DiscoveryTweet, QueueReplyRequest,
+};
+
+use crate::{query, segment, Result, TuitbotClient};
+
+impl TuitbotClient {
+    /// `GET /api/discovery/feed`
+    pub async fn discovery_feed(
+        &self,
+        min_score: Option<f64>,
+        max_score: Option<f64>,
+        keyword: Option<&str>,
+        limit: Option<u32>,
+    ) -> Result<Vec<DiscoveryTweet>> {
+        let params = query(&[
+            ("min_score", min_score.map(|v| v.to_string())),
+            ("max_score", max_score.map(|v| v.to_string())),
+            ("keyword", keyword.map(str::to_string)),
+            ("limit", limit.map(|v| v.to_string())),
+        ]);
+        self.get_query("/discovery/feed", &params).await
+    }
+
+    /// `GET /api/discovery/keywords`
+    pub async fn discovery_keywords(&self) -> Result<Vec<String>> {
+        self.get("/discovery/keywords").await
+    }
+
+    /// `GET /api/discovery/keywords/stats`
+    pub async fn discovery_keyword_stats(&self, days: Option<u32>) -> Result<Value> {
+        let params = query(&[("days", days.map(|v| v.to_string()))]);
+        self.get_query("/discovery/keywords/stats", &params).await
+    }
+
+    /// `POST /api/discovery/{tweet_id}/compose-reply`
+    pub async fn compose_discovery_reply(
+        &self,
+        tweet_id: &str,
+        body: &ComposeReplyRequest,
+    ) -> Result<ComposeReplyResponse> {
+        self.post(
+            &format!("/discovery/{}/compose-reply", segment(tweet_id)),
+            body,
+        )
+        .await
+    }
+
+    /// `POST /api/discovery/{tweet_id}/queue-reply`
+    pub async fn queue_discovery_reply(
+        &self,
+        tweet_id: &str,
+        body: &QueueReplyRequest,
+    ) -> Result<Value> {
+        self.post(
+            &format!("/discovery/{}/queue-reply", segment(tweet_id)),
+            body,
+        )
+        .await
+    }
+}
@@ -0,0 +1,21 @@
+use tuitbot_types::hooks::HookRun;
+
+use crate::{query, Result, TuitbotClient};
+
+impl TuitbotClient {
+    /// `GET /api/hooks/runs`
+    ///
+    /// Recent hook runs, newest first, optionally for one approval item
+    /// (server default limit: 50).
+    pub async fn list_hook_runs(
+        &self,
+        item_id: Option<i64>,
+        limit: Option<u32>,
+    ) -> Result<Vec<HookRun>> {
+        let params = query(&[
+            ("item_id", item_id.map(|v| v.to_string())),
+            ("limit", limit.map(|v| v.to_string())),
+        ]);
+        self.get_query("/hooks/runs", &params).await
+    }
+}
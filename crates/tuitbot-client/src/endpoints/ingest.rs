@@ -0,0 +1,20 @@
+use tuitbot_types::ingest::{
+    IngestCandidatesRequest, IngestCandidatesResponse, IngestRequest, IngestResponse,
+};
+
+use crate::{Result, TuitbotClient};
+
+impl TuitbotClient {
+    /// `POST /api/ingest`
+    pub async fn ingest(&self, body: &IngestRequest) -> Result<IngestResponse> {
+        self.post("/ingest", body).await
+    }
+
+    /// `POST /api/ingest/candidates`
+    pub async fn ingest_candidates(
+        &self,
+        body: &IngestCandidatesRequest,
+    ) -> Result<IngestCandidatesResponse> {
+        self.post("/ingest/candidates", body).await
+    }
+}
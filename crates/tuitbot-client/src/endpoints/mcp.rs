@@ -0,0 +1,93 @@
+use serde_json::Value;
+
+use crate::{query, segment, Result, TuitbotClient};
+
+impl TuitbotClient {
+    /// `GET /api/mcp/policy`
+    pub async fn mcp_policy(&self) -> Result<Value> {
+        self.get("/mcp/policy").await
+    }
+
+    /// `PATCH /api/mcp/policy` with a partial policy object.
+    pub async fn patch_mcp_policy(&self, patch: &Value) -> Result<Value> {
+        self.patch("/mcp/policy", patch).await
+    }
+
+    /// `GET /api/mcp/policy/templates`
+    pub async fn mcp_policy_templates(&self) -> Result<Value> {
+        self.get("/mcp/policy/templates").await
+    }
+
+    /// `POST /api/mcp/policy/templates/{name}`
+    pub async fn apply_mcp_policy_template(&self, name: &str) -> Result<Value> {
+        self.post_empty(&format!("/mcp/policy/templates/{}", segment(name)))
+            .await
+    }
+
+    /// `GET /api/mcp/telemetry/summary` over the last `hours` (default 24).
+    pub async fn mcp_telemetry_summary(&self, hours: Option<u32>) -> Result<Value> {
+        self.get_hours("/mcp/telemetry/summary", hours).await
+    }
+
+    /// `GET /api/mcp/telemetry/metrics`
+    pub async fn mcp_telemetry_metrics(&self, hours: Option<u32>) -> Result<Value> {
+        self.get_hours("/mcp/telemetry/metrics", hours).await
+    }
+
+    /// `GET /api/mcp/telemetry/errors`
+    pub async fn mcp_telemetry_errors(&self, hours: Option<u32>) -> Result<Value> {
+        self.get_hours("/mcp/telemetry/errors", hours).await
+    }
+
+    /// `GET /api/mcp/telemetry/recent`
+    pub async fn mcp_telemetry_recent(&self, limit: Option<u32>) -> Result<Value> {
+        let params = query(&[("limit", limit.map(|v| v.to_string()))]);
+        self.get_query("/mcp/telemetry/recent", &params).await
+    }
+
+    /// `GET /api/mcp/staged`
+    pub async fn list_staged_actions(&self) -> Result<Value> {
+        self.get("/mcp/staged").await
+    }
+
+    /// `POST /api/mcp/staged/{id}/approve`
+    pub async fn approve_staged_action(&self, id: i64) -> Result<Value> {
+        self.post_empty(&format!("/mcp/staged/{id}/approve")).await
+    }
+
+    /// `POST /api/mcp/staged/{id}/reject`
+    pub async fn reject_staged_action(&self, id: i64) -> Result<Value> {
+        self.post_empty(&format!("/mcp/staged/{id}/reject")).await
+    }
+
+    /// `GET /api/mcp/origins`
+    pub async fn list_origins(
+        &self,
+        session_id: Option<&str>,
+        agent: Option<&str>,
+        record_type: Option<&str>,
+        limit: Option<u32>,
+    ) -> Result<Value> {
+        let params = query(&[
+            ("session_id", session_id.map(str::to_string)),
+            ("agent", agent.map(str::to_string)),
+            ("record_type", record_type.map(str::to_string)),
+            ("limit", limit.map(|v| v.to_string())),
+        ]);
+        self.get_query("/mcp/origins", &params).await
+    }
+
+    /// `GET /api/mcp/origins/{record_type}/{record_id}`
+    pub async fn get_origin(&self, record_type: &str, record_id: i64) -> Result<Value> {
+        self.get(&format!(
+            "/mcp/origins/{}/{record_id}",
+            segment(record_type)
+        ))
+        .await
+    }
+
+    async fn get_hours(&self, path: &str, hours: Option<u32>) -> Result<Value> {
+        let params = query(&[("hours", hours.map(|v| v.to_string()))]);
+        self.get_query(path, &params).await
+    }
+}
@@ -0,0 +1,29 @@
+use reqwest::multipart::{Form, Part};
+use reqwest::Method;
+use serde_json::Value;
+
+use crate::{segment, Result, TuitbotClient};
+
+impl TuitbotClient {
+    /// `POST /api/media/upload` — upload one file as multipart form data.
+    pub async fn upload_media(&self, filename: &str, data: Vec<u8>) -> Result<Value> {
+        let form = Form::new().part("file", Part::bytes(data).file_name(filename.to_string()));
+        self.send(self.request(Method::POST, "/media/upload").multipart(form))
+            .await
+    }
+
+    /// `GET /api/media/file?path=` — raw bytes of a stored media file.
+    pub async fn media_file(&self, path: &str) -> Result<Vec<u8>> {
+        self.send_bytes(
+            self.request(Method::GET, "/media/file")
+                .query(&[("path", path)]),
+        )
+        .await
+    }
+
+    /// `GET /api/media/{id}` — raw bytes of a media item.
+    pub async fn media_by_id(&self, id: &str) -> Result<Vec<u8>> {
+        self.send_bytes(self.request(Method::GET, &format!("/media/{}", segment(id))))
+            .await
+    }
+}
@@ -0,0 +1,21 @@
+//! Endpoint methods on [`TuitbotClient`](crate::TuitbotClient), grouped
+//! like the server's route modules.
+
+mod accounts;
+mod activity;
+mod admin;
+mod analytics;
+mod approval;
+mod assist;
+mod auth;
+mod content;
+mod costs;
+mod discovery;
+mod hooks;
+mod ingest;
+mod mcp;
+mod media;
+mod settings;
+mod strategy;
+mod system;
+mod targets;
@@ -0,0 +1,57 @@
+use serde_json::Value;
+use tuitbot_types::lan::{LanStatus, ToggleLanRequest};
+use tuitbot_types::settings::{TestLlmRequest, TestLlmResponse, ValidationResponse};
+
+use crate::{Result, TuitbotClient};
+
+impl TuitbotClient {
+    /// `GET /api/settings`
+    pub async fn get_settings(&self) -> Result<Value> {
+        self.get("/settings").await
+    }
+
+    /// `PATCH /api/settings` with a partial config object.
+    pub async fn patch_settings(&self, patch: &Value) -> Result<Value> {
+        self.patch("/settings", patch).await
+    }
+
+    /// `GET /api/settings/status`
+    pub async fn config_status(&self) -> Result<Value> {
+        self.get("/settings/status").await
+    }
+
+    /// `POST /api/settings/init` with a full config object.
+    pub async fn init_settings(&self, config: &Value) -> Result<Value> {
+        self.post("/settings/init", config).await
+    }
+
+    /// `POST /api/settings/validate` — check a partial config without saving it.
+    pub async fn validate_settings(&self, patch: &Value) -> Result<ValidationResponse> {
+        self.post("/settings/validate", patch).await
+    }
+
+    /// `GET /api/settings/defaults`
+    pub async fn settings_defaults(&self) -> Result<Value> {
+        self.get("/settings/defaults").await
+    }
+
+    /// `POST /api/settings/test-llm`
+    pub async fn test_llm(&self, body: &TestLlmRequest) -> Result<TestLlmResponse> {
+        self.post("/settings/test-llm", body).await
+    }
+
+    /// `GET /api/settings/lan`
+    pub async fn lan_status(&self) -> Result<LanStatus> {
+        self.get("/settings/lan").await
+    }
+
+    /// `PATCH /api/settings/lan`
+    pub async fn toggle_lan(&self, body: &ToggleLanRequest) -> Result<Value> {
+        self.patch("/settings/lan", body).await
+    }
+
+    /// `POST /api/settings/lan/reset-passphrase`
+    pub async fn reset_passphrase(&self) -> Result<Value> {
+        self.post_empty("/settings/lan/reset-passphrase").await
+    }
+}
@@ -0,0 +1,26 @@
+use serde_json::Value;
+
+use crate::{query, Result, TuitbotClient};
+
+impl TuitbotClient {
+    /// `GET /api/strategy/current`
+    pub async fn strategy_current(&self) -> Result<Value> {
+        self.get("/strategy/current").await
+    }
+
+    /// `GET /api/strategy/history`
+    pub async fn strategy_history(&self, limit: Option<u32>) -> Result<Value> {
+        let params = query(&[("limit", limit.map(|v| v.to_string()))]);
+        self.get_query("/strategy/history", &params).await
+    }
+
+    /// `POST /api/strategy/refresh`
+    pub async fn strategy_refresh(&self) -> Result<Value> {
+        self.post_empty("/strategy/refresh").await
+    }
+
+    /// `GET /api/strategy/inputs`
+    pub async fn strategy_inputs(&self) -> Result<Value> {
+        self.get("/strategy/inputs").await
+    }
+}
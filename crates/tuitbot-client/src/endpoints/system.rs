@@ -0,0 +1,40 @@
+use serde_json::Value;
+
+use crate::{Result, TuitbotClient};
+
+impl TuitbotClient {
+    /// `GET /api/health` — liveness probe, no auth required.
+    pub async fn health(&self) -> Result<Value> {
+        self.get("/health").await
+    }
+
+    /// `GET /api/health/detailed`
+    pub async fn health_detailed(&self) -> Result<Value> {
+        self.get("/health/detailed").await
+    }
+
+    /// `GET /api/public/feed` — recent sent posts, no auth required (404 when disabled).
+    pub async fn public_feed(&self) -> Result<Value> {
+        self.get("/public/feed").await
+    }
+
+    /// `GET /api/summary`
+    pub async fn summary(&self) -> Result<Value> {
+        self.get("/summary").await
+    }
+
+    /// `GET /api/runtime/status`
+    pub async fn runtime_status(&self) -> Result<Value> {
+        self.get("/runtime/status").await
+    }
+
+    /// `POST /api/runtime/start`
+    pub async fn runtime_start(&self) -> Result<Value> {
+        self.post_empty("/runtime/start").await
+    }
+
+    /// `POST /api/runtime/stop`
+    pub async fn runtime_stop(&self) -> Result<Value> {
+        self.post_empty("/runtime/stop").await
+    }
+}
@@ -0,0 +1,52 @@
+use serde_json::Value;
+use tuitbot_types::targets::{AddTargetRequest, RestoreTargetResponse, UpdateTargetRequest};
+
+use crate::{query, segment, Result, TuitbotClient};
+
+impl TuitbotClient {
+    /// `GET /api/targets`
+    pub async fn list_targets(&self) -> Result<Value> {
+        self.get("/targets").await
+    }
+
+    /// `POST /api/targets`
+    pub async fn add_target(&self, body: &AddTargetRequest) -> Result<Value> {
+        self.post("/targets", body).await
+    }
+
+    /// `PATCH /api/targets/{username}`
+    pub async fn update_target(&self, username: &str, body: &UpdateTargetRequest) -> Result<Value> {
+        self.patch(&format!("/targets/{}", segment(username)), body)
+            .await
+    }
+
+    /// `DELETE /api/targets/{username}`
+    pub async fn remove_target(&self, username: &str) -> Result<Value> {
+        self.delete(&format!("/targets/{}", segment(username)))
+            .await
+    }
+
+    /// `POST /api/targets/{username}/restore`
+    pub async fn restore_target(&self, username: &str) -> Result<RestoreTargetResponse> {
+        self.post_empty(&format!("/targets/{}/restore", segment(username)))
+            .await
+    }
+
+    /// `GET /api/targets/removed`
+    pub async fn list_removed_targets(&self) -> Result<Value> {
+        self.get("/targets/removed").await
+    }
+
+    /// `GET /api/targets/{username}/timeline`
+    pub async fn target_timeline(&self, username: &str, limit: Option<i64>) -> Result<Value> {
+        let params = query(&[("limit", limit.map(|v| v.to_string()))]);
+        self.get_query(&format!("/targets/{}/timeline", segment(username)), &params)
+            .await
+    }
+
+    /// `GET /api/targets/{username}/stats`
+    pub async fn target_stats(&self, username: &str) -> Result<Value> {
+        self.get(&format!("/targets/{}/stats", segment(username)))
+            .await
+    }
+}
@@ -0,0 +1,33 @@
+//! Client error type.
+
+use reqwest::StatusCode;
+
+/// Errors returned by [`TuitbotClient`](crate::TuitbotClient) calls.
+#[derive(Debug, thiserror::Error)]
+pub enum ClientError {
+    /// The request could not be sent or the response body could not be read.
+    #[error("HTTP request failed: {0}")]
+    Http(#[from] reqwest::Error),
+
+    /// The server answered with a non-success status.
+    ///
+    /// `message` is the `error` field of the JSON body, or the raw body when
+    /// the server did not send JSON.
+    #[error("API error ({status}): {message}")]
+    Api { status: StatusCode, message: String },
+
+    /// The response body did not match the expected type.
+    #[error("failed to decode response: {0}")]
+    Decode(#[from] serde_json::Error),
+}
+
+impl ClientError {
+    /// HTTP status of an [`Api`](Self::Api) error.
+    pub fn status(&self) -> Option<StatusCode> {
+        match self {
+            Self::Api { status, .. } => Some(*status),
+            Self::Http(e) => e.status(),
+            Self::Decode(_) => None,
+        }
+    }
+}
@@ -0,0 +1,201 @@
+//! Typed async client for the Tuitbot HTTP API.
+//!
+//! [`TuitbotClient`] has one method per `tuitbot-server` endpoint. Request
+//! and response bodies come from `tuitbot-types`, the same types the server
+//! handlers use, so the two sides cannot drift apart. Endpoints whose
+//! response is built ad hoc on the server return [`serde_json::Value`].
+//!
+//! ```no_run
+//! # async fn run() -> Result<(), tuitbot_client::ClientError> {
+//! use tuitbot_client::TuitbotClient;
+//!
+//! let client = TuitbotClient::new("http://127.0.0.1:3001")
+//!     .with_token(std::fs::read_to_string("/home/me/.tuitbot/api_token").unwrap().trim());
+//! let health = client.health().await?;
+//! println!("server version {}", health["version"]);
+//! # Ok(())
+//! # }
+//! ```
+
+mod endpoints;
+mod error;
+
+use reqwest::{Method, RequestBuilder, Response};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+
+pub use error::ClientError;
+pub use tuitbot_types as types;
+
+/// Result alias for client calls.
+pub type Result<T> = std::result::Result<T, ClientError>;
+
+/// Client for a running `tuitbot-server` (or `tuitbot run --with-api`).
+#[derive(Debug, Clone)]
+pub struct TuitbotClient {
+    http: reqwest::Client,
+    base_url: String,
+    token: Option<String>,
+    account_id: Option<String>,
+}
+
+impl TuitbotClient {
+    /// Create a client for the server at `base_url`, e.g. `http://127.0.0.1:3001`.
+    pub fn new(base_url: impl Into<String>) -> Self {
+        Self::with_http_client(reqwest::Client::new(), base_url)
+    }
+
+    /// Create a client that sends requests through an existing `reqwest::Client`.
+    pub fn with_http_client(http: reqwest::Client, base_url: impl Into<String>) -> Self {
+        Self {
+            http,
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            token: None,
+            account_id: None,
+        }
+    }
+
+    /// Authenticate with the local API token (`~/.tuitbot/api_token`).
+    pub fn with_token(mut self, token: impl Into<String>) -> Self {
+        self.token = Some(token.into());
+        self
+    }
+
+    /// Act on behalf of `account_id` (sent as `X-Account-Id`).
+    ///
+    /// Without it the server uses the default account.
+    pub fn with_account(mut self, account_id: impl Into<String>) -> Self {
+        self.account_id = Some(account_id.into());
+        self
+    }
+
+    /// Base URL the client sends requests to.
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// Start a request to `path` under `/api`, with auth and account headers.
+    fn request(&self, method: Method, path: &str) -> RequestBuilder {
+        let mut builder = self
+            .http
+            .request(method, format!("{}/api{path}", self.base_url));
+        if let Some(token) = &self.token {
+            builder = builder.bearer_auth(token);
+        }
+        if let Some(account_id) = &self.account_id {
+            builder = builder.header("X-Account-Id", account_id);
+        }
+        builder
+    }
+
+    async fn get<T: DeserializeOwned>(&self, path: &str) -> Result<T> {
+        self.send(self.request(Method::GET, path)).await
+    }
+
+    async fn get_query<T: DeserializeOwned, Q: Serialize + ?Sized>(
+        &self,
+        path: &str,
+        query: &Q,
+    ) -> Result<T> {
+        self.send(self.request(Method::GET, path).query(query))
+            .await
+    }
+
+    async fn post<T: DeserializeOwned, B: Serialize + ?Sized>(
+        &self,
+        path: &str,
+        body: &B,
+    ) -> Result<T> {
+        self.send(self.request(Method::POST, path).json(body)).await
+    }
+
+    async fn post_empty<T: DeserializeOwned>(&self, path: &str) -> Result<T> {
+        self.send(self.request(Method::POST, path)).await
+    }
+
+    async fn patch<T: DeserializeOwned, B: Serialize + ?Sized>(
+        &self,
+        path: &str,
+        body: &B,
+    ) -> Result<T> {
+        self.send(self.request(Method::PATCH, path).json(body))
+            .await
+    }
+
+    async fn delete<T: DeserializeOwned>(&self, path: &str) -> Result<T> {
+        self.send(self.request(Method::DELETE, path)).await
+    }
+
+    /// Send a request and decode its JSON body.
+    async fn send<T: DeserializeOwned>(&self, request: RequestBuilder) -> Result<T> {
+        let bytes = checked(request.send().await?).await?.bytes().await?;
+        Ok(serde_json::from_slice(&bytes)?)
+    }
+
+    /// Send a request and return its body as text (CSV exports).
+    async fn send_text(&self, request: RequestBuilder) -> Result<String> {
+        Ok(checked(request.send().await?).await?.text().await?)
+    }
+
+    /// Send a request and return its raw body (media files).
+    async fn send_bytes(&self, request: RequestBuilder) -> Result<Vec<u8>> {
+        Ok(checked(request.send().await?)
+            .await?
+            .bytes()
+            .await?
+            .to_vec())
+    }
+}
+
+/// Turn a non-success response into [`ClientError::Api`].
+async fn checked(response: Response) -> Result<Response> {
+    let status = response.status();
+    if status.is_success() {
+        return Ok(response);
+    }
+    let body = response.text().await.unwrap_or_default();
+    let message = serde_json::from_str::<Value>(&body)
+        .ok()
+        .and_then(|v| v["error"].as_str().map(str::to_string))
+        .unwrap_or(body);
+    Err(ClientError::Api { status, message })
+}
+
+/// Query string pairs, skipping unset optional parameters.
+fn query<'a>(pairs: &[(&'a str, Option<String>)]) -> Vec<(&'a str, String)> {
+    pairs
+        .iter()
+        .filter_map(|(key, value)| value.clone().map(|v| (*key, v)))
+        .collect()
+}
+
+/// Percent-encode a value for use as a single path segment.
+fn segment(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'_' | b'.' | b'~') {
+            out.push(byte as char);
+        } else {
+            out.push_str(&format!("%{byte:02X}"));
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn segment_escapes_reserved_characters() {
+        assert_eq!(segment("alice_01"), "alice_01");
+        assert_eq!(segment("a/b c"), "a%2Fb%20c");
+    }
+
+    #[test]
+    fn base_url_trailing_slash_is_trimmed() {
+        let client = TuitbotClient::new("http://localhost:3001/");
+        assert_eq!(client.base_url(), "http://localhost:3001");
+    }
+}
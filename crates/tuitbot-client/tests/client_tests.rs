@@ -0,0 +1,428 @@
+//! End-to-end tests: the typed client against a real server on a random port.
+
+use tokio_util::sync::CancellationToken;
+use tuitbot_client::types::approval::AddCommentRequest;
+use tuitbot_client::types::content::{ComposeTweetRequest, CreateDraftRequest, EditDraftRequest};
+use tuitbot_client::types::ingest::{IngestRequest, InlineNode};
+use tuitbot_client::types::targets::AddTargetRequest;
+use tuitbot_client::{ClientError, TuitbotClient};
+use tuitbot_core::config::Config;
+use tuitbot_core::storage;
+use tuitbot_server::embedded::EmbeddedServer;
+
+struct TestServer {
+    client: TuitbotClient,
+    pool: storage::DbPool,
+    cancel: CancellationToken,
+    task: tokio::task::JoinHandle<()>,
+    _dir: tempfile::TempDir,
+}
+
+impl TestServer {
+    async fn start() -> Self {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let pool = storage::init_test_db().await.expect("init test db");
+        let mut config = Config::default();
+        config.server.port = 0;
+
+        let server = EmbeddedServer::bind(
+            pool.clone(),
+            &config,
+            dir.path().join("config.toml"),
+            None,
+            None,
+        )
+        .await
+        .expect("bind");
+        let addr = server.local_addr().expect("local addr");
+        let token = std::fs::read_to_string(dir.path().join("api_token")).expect("api token");
+
+        let cancel = CancellationToken::new();
+        let task = tokio::spawn(server.serve(cancel.clone()));
+        let client = TuitbotClient::new(format!("http://{addr}")).with_token(token.trim());
+        Self {
+            client,
+            pool,
+            cancel,
+            task,
+            _dir: dir,
+        }
+    }
+
+    async fn stop(self) {
+        self.cancel.cancel();
+        self.task.await.expect("server task");
+    }
+}
+
+#[tokio::test]
+async fn health_and_auth_errors() {
+    let server = TestServer::start().await;
+
+    let health = server.client.health().await.expect("health");
+    assert_eq!(health["status"], "ok");
+
+    let anonymous = TuitbotClient::new(server.client.base_url());
+    let err = anonymous.analytics_summary().await.unwrap_err();
+    assert_eq!(err.status(), Some(reqwest::StatusCode::UNAUTHORIZED));
+    assert!(matches!(err, ClientError::Api { .. }));
+
+    server.stop().await;
+}
+
+#[tokio::test]
+async fn drafts_round_trip_through_shared_types() {
+    let server = TestServer::start().await;
+    let client = &server.client;
+
+    let created = client
+        .create_draft(&CreateDraftRequest {
+            content_type: "tweet".to_string(),
+            content: "Draft from the typed client".to_string(),
+            source: "manual".to_string(),
+            blocks: None,
+            planned_for: None,
+        })
+        .await
+        .expect("create draft");
+    let id = created["id"].as_i64().expect("draft id");
+
+    client
+        .edit_draft(
+            id,
+            &EditDraftRequest {
+                content: Some("Edited draft".to_string()),
+                ..Default::default()
+            },
+        )
+        .await
+        .expect("edit draft");
+    let draft = client.get_draft(id).await.expect("get draft");
+    assert_eq!(draft["content"], "Edited draft");
+
+    client.delete_draft(id).await.expect("delete draft");
+    let err = client.get_draft(id).await.unwrap_err();
+    assert_eq!(err.status(), Some(reqwest::StatusCode::NOT_FOUND));
+
+    server.stop().await;
+}
+
+#[tokio::test]
+async fn typed_responses_decode() {
+    let server = TestServer::start().await;
+    let client = &server.client;
+
+    let composed = client
+        .compose_tweet(&ComposeTweetRequest {
+            text: "Composed from the client".to_string(),
+            scheduled_for: None,
+        })
+        .await
+        .expect("compose tweet");
+    let id = composed["id"].as_i64().expect("scheduled id");
+    let items = client
+        .calendar("2000-01-01T00:00:00Z", "2099-01-01T00:00:00Z")
+        .await
+        .expect("calendar");
+    let item = items
+        .iter()
+        .find(|item| item.id == id)
+        .expect("in calendar");
+    assert_eq!(item.content, "Composed from the client");
+
+    client
+        .add_target(&AddTargetRequest {
+            username: "rustlang".to_string(),
+            tier: None,
+        })
+        .await
+        .expect("add target");
+    let targets = client.list_targets().await.expect("list targets");
+    assert_eq!(targets[0]["username"], "rustlang");
+
+    let ingest = client
+        .ingest(&IngestRequest {
+            inline_nodes: vec![InlineNode {
+                relative_path: "notes/client.md".to_string(),
+                body_text: "Inline note".to_string(),
+                title: None,
+                tags: None,
+            }],
+            ..Default::default()
+        })
+        .await
+        .expect("ingest");
+    assert_eq!(ingest.ingested, 1);
+
+    server.stop().await;
+}
+
+#[tokio::test]
+async fn reply_explanation_decodes() {
+    let server = TestServer::start().await;
+
+    let reply = storage::replies::ReplySent {
+        id: 0,
+        target_tweet_id: "t1".to_string(),
+        reply_tweet_id: Some("r1".to_string()),
+        reply_content: "Have you tried profiling first?".to_string(),
+        llm_provider: None,
+        llm_model: None,
+        created_at: "2026-03-01T12:00:00Z".to_string(),
+        status: "sent".to_string(),
+        error_message: None,
+    };
+    let id = storage::replies::insert_reply(&server.pool, &reply)
+        .await
+        .expect("insert reply");
+    let explanation = storage::reply_explanations::ReplyExplanation {
+        source: "discovery".to_string(),
+        matched_keywords: vec!["rust".to_string()],
+        ..Default::default()
+    };
+    storage::reply_explanations::save(&server.pool, id, &explanation)
+        .await
+        .expect("save explanation");
+
+    let response = server.client.reply_explanation(id).await.expect("explain");
+    assert_eq!(response.reply.target_tweet_id, "t1");
+    assert_eq!(response.explanation, Some(explanation));
+
+    server.stop().await;
+}
+
+#[tokio::test]
+async fn analytics_reports_decode() {
+    let server = TestServer::start().await;
+
+    storage::tweets::insert_discovered_tweet(
+        &server.pool,
+        &storage::tweets::DiscoveredTweet {
+            id: "d1".to_string(),
+            author_id: "a1".to_string(),
+            author_username: "alice".to_string(),
+            content: "Rust tip".to_string(),
+            like_count: 0,
+            retweet_count: 0,
+            reply_count: 0,
+            quote_count: 0,
+            impression_count: None,
+            relevance_score: Some(80.0),
+            matched_keyword: Some("rust".to_string()),
+            discovered_at: "2026-03-02T09:00:00Z".to_string(),
+            replied_to: 1,
+        },
+    )
+    .await
+    .expect("insert tweet");
+    storage::replies::insert_reply(
+        &server.pool,
+        &storage::replies::ReplySent {
+            id: 0,
+            target_tweet_id: "d1".to_string(),
+            reply_tweet_id: Some("r1".to_string()),
+            reply_content: "Nice".to_string(),
+            llm_provider: None,
+            llm_model: None,
+            created_at: "2026-03-02T10:00:00Z".to_string(),
+            status: "sent".to_string(),
+            error_message: None,
+        },
+    )
+    .await
+    .expect("insert reply");
+    storage::threads::insert_original_tweet(
+        &server.pool,
+        &storage::threads::OriginalTweet {
+            id: 0,
+            tweet_id: Some("o1".to_string()),
+            content: "Trending take".to_string(),
+            topic: Some("rust".to_string()),
+            llm_provider: None,
+            created_at: "2026-03-02T08:00:00Z".to_string(),
+            status: "sent".to_string(),
+            error_message: None,
+            inspiration: storage::threads::INSPIRATION_TREND.to_string(),
+        },
+    )
+    .await
+    .expect("insert original tweet");
+
+    let threads = server
+        .client
+        .analytics_threads(Some("2026-03-01"), Some("2026-03-07"))
+        .await
+        .expect("thread analytics");
+    assert_eq!(threads.range.from, "2026-03-01T00:00:00");
+    assert_eq!(threads.range.to, "2026-03-08T00:00:00");
+    assert_eq!(threads.report.threads_measured, 0);
+
+    let reciprocity = server
+        .client
+        .analytics_reciprocity(Some("2026-03-01"), Some("2026-03-07"), Some(1), None)
+        .await
+        .expect("reciprocity");
+    assert_eq!(reciprocity.range, threads.range);
+    assert_eq!(reciprocity.authors.len(), 1);
+    assert_eq!(reciprocity.authors[0].author_username, "alice");
+    assert_eq!(reciprocity.authors[0].outbound, 1);
+    assert_eq!(reciprocity.authors[0].ratio, 0.0);
+
+    let inspiration = server
+        .client
+        .analytics_inspiration(Some("2026-03-01"), Some("2026-03-07"))
+        .await
+        .expect("inspiration");
+    assert_eq!(inspiration.range, threads.range);
+    assert_eq!(inspiration.groups.len(), 1);
+    assert_eq!(inspiration.groups[0].inspiration, "trend");
+    assert_eq!(inspiration.groups[0].post_count, 1);
+
+    server.stop().await;
+}
+
+#[tokio::test]
+async fn deleted_items_and_targets_restore() {
+    let server = TestServer::start().await;
+    let client = &server.client;
+
+    let id = storage::approval_queue::enqueue(
+        &server.pool,
+        "tweet",
+        "",
+        "",
+        "Queued tweet",
+        "General",
+        "",
+        0.0,
+        "[]",
+    )
+    .await
+    .expect("enqueue");
+    client.delete_approval_item(id).await.expect("delete item");
+    let deleted = client
+        .list_deleted_approval_items()
+        .await
+        .expect("list deleted");
+    assert_eq!(deleted[0]["id"], id);
+
+    let restored = client
+        .restore_approval_item(id)
+        .await
+        .expect("restore item");
+    assert_eq!(restored.id, id);
+    assert_eq!(restored.item_status, "pending");
+
+    client
+        .add_target(&AddTargetRequest {
+            username: "rustlang".to_string(),
+            tier: None,
+        })
+        .await
+        .expect("add target");
+    client
+        .remove_target("rustlang")
+        .await
+        .expect("remove target");
+    let removed = client.list_removed_targets().await.expect("list removed");
+    assert_eq!(removed[0]["username"], "rustlang");
+
+    let restored = client
+        .restore_target("rustlang")
+        .await
+        .expect("restore target");
+    assert_eq!(restored.username, "rustlang");
+    let err = client.restore_target("rustlang").await.unwrap_err();
+    assert_eq!(err.status(), Some(reqwest::StatusCode::NOT_FOUND));
+
+    server.stop().await;
+}
+
+#[tokio::test]
+async fn approval_comments_thread() {
+    let server = TestServer::start().await;
+    let client = &server.client;
+
+    let id = storage::approval_queue::enqueue(
+        &server.pool,
+        "tweet",
+        "",
+        "",
+        "Queued tweet",
+        "General",
+        "",
+        0.0,
+        "[]",
+    )
+    .await
+    .expect("enqueue");
+
+    let first = client
+        .add_approval_comment(
+            id,
+            &AddCommentRequest {
+                body: "Tone it down".to_string(),
+                author: "alice".to_string(),
+                parent_id: None,
+            },
+        )
+        .await
+        .expect("add comment");
+    let reply = client
+        .add_approval_comment(
+            id,
+            &AddCommentRequest {
+                body: "Done".to_string(),
+                author: "bob".to_string(),
+                parent_id: Some(first.id),
+            },
+        )
+        .await
+        .expect("add reply");
+    assert_eq!(reply.parent_id, Some(first.id));
+
+    let thread = client.list_approval_comments(id).await.expect("comments");
+    assert_eq!(thread, vec![first, reply]);
+
+    server.stop().await;
+}
+
+#[tokio::test]
+async fn hook_runs_decode() {
+    let server = TestServer::start().await;
+
+    storage::hook_runs::record(
+        &server.pool,
+        &storage::hook_runs::NewHookRun {
+            event: "pre_post",
+            hook_name: "profanity",
+            action_type: "reply",
+            item_id: Some(7),
+            verdict: "deny",
+            reason: Some("blocked word"),
+            exit_code: Some(1),
+            duration_ms: 12,
+            ..Default::default()
+        },
+    )
+    .await
+    .expect("record run");
+
+    let runs = server
+        .client
+        .list_hook_runs(Some(7), None)
+        .await
+        .expect("hook runs");
+    assert_eq!(runs.len(), 1);
+    assert_eq!(runs[0].hook_name, "profanity");
+    assert_eq!(runs[0].verdict, "deny");
+    assert_eq!(runs[0].exit_code, Some(1));
+    assert!(server
+        .client
+        .list_hook_runs(Some(8), None)
+        .await
+        .expect("hook runs")
+        .is_empty());
+
+    server.stop().await;
+}
@@ -0,0 +1,56 @@
+//! Approval queue queries against a 100k-row table, the size a busy
+//! account reaches after a few months without pruning.
+
+mod common;
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use tuitbot_core::storage::approval_queue;
+
+use common::BenchDb;
+
+const ROWS: u32 = 100_000;
+
+fn bench_approval_queue(c: &mut Criterion) {
+    let rt = tokio::runtime::Runtime::new().expect("runtime");
+    let db = rt.block_on(async {
+        let db = BenchDb::new().await;
+        db.seed_approval_queue(ROWS).await;
+        db
+    });
+    let pool = &db.pool;
+
+    let mut group = c.benchmark_group("approval_queue_100k");
+    group.bench_function("pending_count", |b| {
+        b.to_async(&rt)
+            .iter(|| async { black_box(approval_queue::pending_count(pool).await.unwrap()) })
+    });
+    group.bench_function("get_stats", |b| {
+        b.to_async(&rt)
+            .iter(|| async { black_box(approval_queue::get_stats(pool).await.unwrap()) })
+    });
+    group.bench_function("get_pending", |b| {
+        b.to_async(&rt)
+            .iter(|| async { black_box(approval_queue::get_pending(pool).await.unwrap()) })
+    });
+    group.bench_function("get_filtered_reply_pending", |b| {
+        b.to_async(&rt).iter(|| async {
+            black_box(
+                approval_queue::get_filtered(pool, &["pending"], Some("reply"), None, None)
+                    .await
+                    .unwrap(),
+            )
+        })
+    });
+    group.bench_function("get_by_id", |b| {
+        b.to_async(&rt)
+            .iter(|| async { black_box(approval_queue::get_by_id(pool, 54_321).await.unwrap()) })
+    });
+    group.finish();
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default().sample_size(20);
+    targets = bench_approval_queue
+}
+criterion_main!(benches);
@@ -0,0 +1,45 @@
+//! Phrasing dedup against large reply histories.
+//!
+//! `is_phrasing_similar` loads the last `limit` replies and compares word
+//! sets with Jaccard similarity, so cost grows with the history window.
+
+mod common;
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use tuitbot_core::safety::DedupChecker;
+
+use common::BenchDb;
+
+const HISTORY: u32 = 20_000;
+
+fn bench_dedup(c: &mut Criterion) {
+    let rt = tokio::runtime::Runtime::new().expect("runtime");
+    let db = rt.block_on(async {
+        let db = BenchDb::new().await;
+        db.seed_replies(HISTORY).await;
+        db
+    });
+    let checker = DedupChecker::new(db.pool.clone());
+
+    // Unlike every seeded reply, so the whole window is scanned.
+    let candidate = "Pinning the toolchain file fixed our nightly breakage \
+                     and cut cold build times in half last quarter";
+
+    let mut group = c.benchmark_group("dedup_phrasing");
+    for limit in [100_i64, 1_000, 10_000] {
+        group.throughput(Throughput::Elements(limit as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(limit), &limit, |b, &limit| {
+            b.to_async(&rt).iter(|| async {
+                black_box(checker.is_phrasing_similar(candidate, limit).await.unwrap())
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default().sample_size(20);
+    targets = bench_dedup
+}
+criterion_main!(benches);
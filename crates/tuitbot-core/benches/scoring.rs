@@ -0,0 +1,72 @@
+//! Tweet scoring throughput: one discovery search returns up to 100 tweets,
+//! and every one is scored before the threshold check.
+
+use chrono::{Duration, Utc};
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use tuitbot_core::config::ScoringConfig;
+use tuitbot_core::scoring::{find_matched_keywords, ScoringEngine, TweetData};
+
+fn keywords() -> Vec<String> {
+    [
+        "rust async",
+        "tokio",
+        "ci pipeline",
+        "flaky tests",
+        "deadlock",
+        "build times",
+        "cargo",
+        "observability",
+    ]
+    .iter()
+    .map(|k| k.to_string())
+    .collect()
+}
+
+fn tweets(count: usize) -> Vec<TweetData> {
+    let now = Utc::now();
+    (0..count)
+        .map(|i| TweetData {
+            text: format!(
+                "Tweet {i}: anyone else seeing tokio deadlocks in their ci pipeline? \
+                 Build times doubled since we bumped cargo. https://example.com/post/{i}"
+            ),
+            created_at: (now - Duration::minutes(i as i64 % 720)).to_rfc3339(),
+            likes: (i as u64 * 7) % 300,
+            retweets: (i as u64 * 3) % 40,
+            replies: (i as u64) % 25,
+            author_username: format!("author{i}"),
+            author_followers: (i as u64 * 131) % 50_000,
+            has_media: i % 5 == 0,
+            is_quote_tweet: i % 11 == 0,
+            urls: vec![format!("https://example.com/post/{i}")],
+        })
+        .collect()
+}
+
+fn bench_scoring(c: &mut Criterion) {
+    let engine = ScoringEngine::new(ScoringConfig::default(), keywords());
+    let batch = tweets(100);
+    let now = Utc::now();
+
+    let mut group = c.benchmark_group("scoring");
+    group.throughput(Throughput::Elements(batch.len() as u64));
+    group.bench_function("score_tweet_x100", |b| {
+        b.iter(|| {
+            for tweet in &batch {
+                black_box(engine.score_tweet_at(tweet, now));
+            }
+        })
+    });
+    group.bench_function("find_matched_keywords_x100", |b| {
+        let keywords = keywords();
+        b.iter(|| {
+            for tweet in &batch {
+                black_box(find_matched_keywords(&tweet.text, &keywords));
+            }
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_scoring);
+criterion_main!(benches);
@@ -0,0 +1,18 @@
+-- Account health monitor: periodic visibility self-checks.
+-- Each row records how many of the account's own recent tweets and replies
+-- were findable via search, and the resulting health status.
+CREATE TABLE IF NOT EXISTS account_health_checks (
+    id              INTEGER PRIMARY KEY AUTOINCREMENT,
+    account_id      TEXT NOT NULL DEFAULT '00000000-0000-0000-0000-000000000000',
+    status          TEXT NOT NULL,                   -- 'healthy', 'degraded', 'unknown'
+    tweets_checked  INTEGER NOT NULL DEFAULT 0,
+    tweets_found    INTEGER NOT NULL DEFAULT 0,
+    replies_checked INTEGER NOT NULL DEFAULT 0,
+    replies_visible INTEGER NOT NULL DEFAULT 0,
+    slowdown_factor REAL NOT NULL DEFAULT 1.0,       -- Posting delay multiplier applied after this check
+    details         TEXT NOT NULL DEFAULT '[]',      -- JSON array of per-post probe results
+    checked_at      TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))
+);
+
+CREATE INDEX IF NOT EXISTS idx_account_health_checks_account
+    ON account_health_checks(account_id, checked_at);
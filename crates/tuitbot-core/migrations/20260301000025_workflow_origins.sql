@@ -0,0 +1,18 @@
+-- Originating agent / session / goal for rows created by MCP tools.
+-- Links approval items, scheduled content, and mutation audit records back
+-- to the MCP workflow context that created them.
+CREATE TABLE IF NOT EXISTS workflow_origins (
+    id          INTEGER PRIMARY KEY AUTOINCREMENT,
+    account_id  TEXT NOT NULL DEFAULT '00000000-0000-0000-0000-000000000000',
+    record_type TEXT NOT NULL,                      -- 'approval_queue', 'scheduled_content', 'mutation_audit'
+    record_id   INTEGER NOT NULL,
+    agent       TEXT,                               -- MCP client name, e.g. 'claude-code'
+    session_id  TEXT,
+    goal        TEXT,
+    created_at  TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))
+);
+
+CREATE INDEX IF NOT EXISTS idx_workflow_origins_record
+    ON workflow_origins(record_type, record_id);
+CREATE INDEX IF NOT EXISTS idx_workflow_origins_session
+    ON workflow_origins(session_id, id);
@@ -0,0 +1,7 @@
+-- Prompt-cache and batch accounting for LLM usage.
+-- Cached tokens are a subset of input_tokens; savings_usd is list price
+-- minus the billed cost after cache and batch discounts.
+ALTER TABLE llm_usage ADD COLUMN cache_read_tokens INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE llm_usage ADD COLUMN cache_write_tokens INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE llm_usage ADD COLUMN batch INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE llm_usage ADD COLUMN savings_usd REAL NOT NULL DEFAULT 0.0;
@@ -0,0 +1,3 @@
+-- Approval queue metrics (review time, approval rate, reviewer throughput)
+-- captured in the weekly strategy report.
+ALTER TABLE strategy_reports ADD COLUMN approval_metrics_json TEXT NOT NULL DEFAULT '{}';
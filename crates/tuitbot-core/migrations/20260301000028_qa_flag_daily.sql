@@ -0,0 +1,12 @@
+-- Daily QA flag counts by rule, for trend analytics.
+CREATE TABLE IF NOT EXISTS qa_flag_daily (
+    account_id TEXT NOT NULL DEFAULT '00000000-0000-0000-0000-000000000000',
+    day        TEXT NOT NULL,             -- YYYY-MM-DD (UTC)
+    code       TEXT NOT NULL,             -- QA rule code, e.g. 'language_mismatch'
+    category   TEXT NOT NULL,             -- language, brand, compliance
+    severity   TEXT NOT NULL,             -- hard, soft
+    count      INTEGER NOT NULL DEFAULT 0,
+    PRIMARY KEY (account_id, day, code, severity)
+);
+
+CREATE INDEX IF NOT EXISTS idx_qa_flag_daily_day ON qa_flag_daily(account_id, day);
@@ -0,0 +1,4 @@
+-- Duplicate collapse: when two pending items target the same tweet or are
+-- near-identical, the lower-scored one gets status 'collapsed' and points at
+-- the item that was kept.
+ALTER TABLE approval_queue ADD COLUMN collapsed_into INTEGER DEFAULT NULL REFERENCES approval_queue(id);
@@ -0,0 +1,4 @@
+-- Link a draft to the approval queue item it was promoted into.
+-- Promoted drafts move to status 'promoted' and keep this reference so the
+-- dashboard can follow a draft through review.
+ALTER TABLE scheduled_content ADD COLUMN approval_queue_id INTEGER;
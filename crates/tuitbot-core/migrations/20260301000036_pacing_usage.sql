@@ -0,0 +1,9 @@
+-- Requests made per paced endpoint per local day, so the pacing planner
+-- survives restarts without overspending the daily budget.
+CREATE TABLE IF NOT EXISTS pacing_usage (
+    account_id TEXT NOT NULL DEFAULT '00000000-0000-0000-0000-000000000000',
+    endpoint TEXT NOT NULL,
+    day TEXT NOT NULL,
+    request_count INTEGER NOT NULL DEFAULT 0,
+    PRIMARY KEY (account_id, endpoint, day)
+);
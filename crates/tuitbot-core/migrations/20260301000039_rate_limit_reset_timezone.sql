@@ -0,0 +1,9 @@
+-- Daily and weekly rate limit windows now start at midnight in a configured
+-- timezone instead of whenever the previous window happened to expire.
+ALTER TABLE rate_limits ADD COLUMN reset_timezone TEXT NOT NULL DEFAULT 'UTC';
+
+-- Align open day-based windows to the UTC midnight they started on; startup
+-- moves them to the configured timezone.
+UPDATE rate_limits
+SET period_start = strftime('%Y-%m-%dT00:00:00Z', period_start)
+WHERE period_seconds % 86400 = 0;
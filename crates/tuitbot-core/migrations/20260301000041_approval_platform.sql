@@ -0,0 +1,5 @@
+-- Platform an approval item is posted to: 'x' for everything queued so far,
+-- 'reddit' for suggested Reddit comments. Each platform has its own poster.
+ALTER TABLE approval_queue ADD COLUMN platform TEXT NOT NULL DEFAULT 'x';
+
+CREATE INDEX IF NOT EXISTS idx_approval_queue_platform_status ON approval_queue(platform, status);
@@ -0,0 +1,8 @@
+-- Why each sent reply was made: matched keywords, score breakdown, archetype,
+-- winning ancestors used as context, and QA score, as one JSON document.
+CREATE TABLE IF NOT EXISTS reply_explanations (
+    reply_id INTEGER PRIMARY KEY REFERENCES replies_sent(id) ON DELETE CASCADE,
+    account_id TEXT NOT NULL DEFAULT '00000000-0000-0000-0000-000000000000',
+    explanation TEXT NOT NULL,
+    created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))
+);
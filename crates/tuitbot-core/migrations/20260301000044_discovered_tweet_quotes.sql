@@ -0,0 +1,2 @@
+-- Quote counts on discovered tweets, for the controversy context check.
+ALTER TABLE discovered_tweets ADD COLUMN quote_count INTEGER NOT NULL DEFAULT 0;
@@ -0,0 +1,3 @@
+-- Where an original tweet's topic came from: 'calendar' (topic rotation) or
+-- 'trend' (a clustered discovery topic), for trend vs calendar analytics.
+ALTER TABLE original_tweets ADD COLUMN inspiration TEXT NOT NULL DEFAULT 'calendar';
@@ -0,0 +1,12 @@
+-- Statements that ran at or above the configured slow-query threshold.
+CREATE TABLE IF NOT EXISTS slow_queries (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    name TEXT NOT NULL,
+    statement TEXT NOT NULL,
+    elapsed_ms REAL NOT NULL,
+    rows_returned INTEGER NOT NULL DEFAULT 0,
+    rows_affected INTEGER NOT NULL DEFAULT 0,
+    created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))
+);
+
+CREATE INDEX IF NOT EXISTS idx_slow_queries_name ON slow_queries(name, created_at);
@@ -0,0 +1,10 @@
+-- Soft delete for approval items and target accounts.
+--
+-- Deleted rows keep their data until the retention cleanup purges them,
+-- so a mistaken delete or rejection can be restored.
+ALTER TABLE approval_queue ADD COLUMN deleted_at TEXT DEFAULT NULL;
+ALTER TABLE target_accounts ADD COLUMN deleted_at TEXT DEFAULT NULL;
+
+-- Targets removed before this migration start their retention window now.
+UPDATE target_accounts SET deleted_at = strftime('%Y-%m-%dT%H:%M:%SZ', 'now')
+    WHERE status = 'inactive';
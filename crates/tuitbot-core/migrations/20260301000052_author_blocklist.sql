@@ -0,0 +1,17 @@
+-- Authors the agent never engages with.
+--
+-- source is 'x_mute' or 'x_block' for accounts synced from the user's X
+-- mutes and blocks (replaced wholesale on each sync), or 'manual' for
+-- usernames listed in [blocklist].authors. Manual rows have no author_id
+-- and match by username.
+CREATE TABLE IF NOT EXISTS author_blocklist (
+    account_id      TEXT NOT NULL,
+    source          TEXT NOT NULL,
+    author_username TEXT NOT NULL COLLATE NOCASE,
+    author_id       TEXT,
+    synced_at       TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
+    PRIMARY KEY (account_id, source, author_username)
+);
+
+CREATE INDEX IF NOT EXISTS idx_author_blocklist_author_id
+    ON author_blocklist(account_id, author_id);
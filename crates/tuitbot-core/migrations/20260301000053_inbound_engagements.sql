@@ -0,0 +1,17 @@
+-- Engagement received from other accounts, for the reciprocity guard.
+--
+-- One row per tweet in which an author engaged us (currently mentions and
+-- replies picked up by the mentions loop). Our side of the relationship
+-- comes from replies_sent.
+CREATE TABLE IF NOT EXISTS inbound_engagements (
+    account_id      TEXT NOT NULL,
+    tweet_id        TEXT NOT NULL,
+    author_id       TEXT NOT NULL,
+    author_username TEXT NOT NULL,
+    kind            TEXT NOT NULL DEFAULT 'mention',
+    created_at      TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
+    PRIMARY KEY (account_id, tweet_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_inbound_engagements_author
+    ON inbound_engagements(account_id, author_id, created_at);
@@ -0,0 +1,23 @@
+-- Each run of the usage reconciliation job compares the posts X says the
+-- project consumed with the posts our own x_api_usage ledger accounts for,
+-- over the same window of complete UTC days.
+--
+-- drift is official_usage - ledger_usage (positive when we undercount).
+-- pacing_scale is the factor applied to the pacing budgets until the next
+-- run (1.0 when the ledger is not undercounting).
+CREATE TABLE IF NOT EXISTS usage_reconciliations (
+    id             INTEGER PRIMARY KEY AUTOINCREMENT,
+    account_id     TEXT NOT NULL DEFAULT '00000000-0000-0000-0000-000000000000',
+    window_start   TEXT NOT NULL,
+    window_end     TEXT NOT NULL,
+    official_usage INTEGER NOT NULL,
+    ledger_usage   INTEGER NOT NULL,
+    drift          INTEGER NOT NULL,
+    pacing_scale   REAL NOT NULL DEFAULT 1.0,
+    project_usage  INTEGER,
+    project_cap    INTEGER,
+    created_at     TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))
+);
+
+CREATE INDEX IF NOT EXISTS idx_usage_reconciliations_account
+    ON usage_reconciliations(account_id, created_at);
@@ -0,0 +1,15 @@
+-- Externally pushed candidates that are not X posts (raw text from another
+-- system). They are kept out of discovered_tweets, which only holds posts
+-- fetched from X with their real author IDs.
+CREATE TABLE IF NOT EXISTS ingested_candidates (
+    id              TEXT NOT NULL,
+    account_id      TEXT NOT NULL DEFAULT '00000000-0000-0000-0000-000000000000',
+    source          TEXT NOT NULL,
+    author_username TEXT NOT NULL DEFAULT '',
+    content         TEXT NOT NULL,
+    url             TEXT,
+    relevance_score REAL,
+    matched_keyword TEXT,
+    ingested_at     TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
+    PRIMARY KEY (account_id, id)
+);
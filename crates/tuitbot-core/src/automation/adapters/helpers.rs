@@ -4,13 +4,28 @@ use std::collections::HashMap;
 
 use chrono::{DateTime, NaiveDateTime, Utc};
 
-use crate::error::{LlmError, XApiError};
+use crate::error::{LlmError, RedditError, StorageError, XApiError};
+use crate::storage::write_queue::{log_action_via, WriteQueue};
+use crate::storage::DbPool;
 use crate::toolkit::ToolkitError;
 use crate::x_api::SearchResponse;
 
 use super::super::analytics_loop::AnalyticsError;
 use super::super::loop_helpers::{ContentLoopError, LoopError, LoopTweet};
 
+pub(super) use crate::storage::write_queue::write_turn;
+
+/// Insert an action log entry, batched through the write queue when attached.
+pub(super) async fn log_action_queued(
+    pool: &DbPool,
+    writes: Option<&WriteQueue>,
+    action_type: &str,
+    status: &str,
+    message: &str,
+) -> Result<(), StorageError> {
+    log_action_via(pool, writes, action_type, status, Some(message), None).await
+}
+
 /// Convert an X API `SearchResponse` to a `Vec<LoopTweet>`.
 ///
 /// Joins tweet data with user data from the `includes` expansion to populate
@@ -27,6 +42,8 @@ pub(super) fn search_response_to_loop_tweets(response: SearchResponse) -> Vec<Lo
         .into_iter()
         .map(|tweet| {
             let user = users.get(tweet.author_id.as_str());
+            let urls = tweet.expanded_urls();
+            let has_media = tweet.has_media();
             LoopTweet {
                 id: tweet.id,
                 text: tweet.text,
@@ -37,6 +54,9 @@ pub(super) fn search_response_to_loop_tweets(response: SearchResponse) -> Vec<Lo
                 likes: tweet.public_metrics.like_count,
                 retweets: tweet.public_metrics.retweet_count,
                 replies: tweet.public_metrics.reply_count,
+                quotes: tweet.public_metrics.quote_count,
+                urls,
+                has_media,
             }
         })
         .collect()
@@ -93,6 +113,16 @@ pub(super) fn sqlx_to_content_error(e: sqlx::Error) -> ContentLoopError {
 }
 
 /// Map `StorageError` to `LoopError`.
+/// Convert a Reddit error to a loop error.
+pub(super) fn reddit_to_loop_error(e: RedditError) -> LoopError {
+    match e {
+        RedditError::RateLimited { retry_after } => LoopError::RateLimited { retry_after },
+        RedditError::Auth { .. } => LoopError::AuthExpired,
+        RedditError::Network { source } => LoopError::NetworkError(source.to_string()),
+        other => LoopError::Other(other.to_string()),
+    }
+}
+
 pub(super) fn storage_to_loop_error(e: crate::error::StorageError) -> LoopError {
     LoopError::StorageError(e.to_string())
 }
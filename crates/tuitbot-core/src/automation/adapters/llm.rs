@@ -2,10 +2,16 @@
 
 use std::sync::Arc;
 
-use super::super::loop_helpers::{ContentLoopError, LoopError, ReplyGenerator, TweetGenerator};
+use super::super::loop_helpers::{
+    ContentLoopError, GeneratedReply, LoopError, QuoteGenerator, ReplyGenerator, TweetGenerator,
+};
 use super::super::thread_loop::ThreadGenerator;
+use super::super::trending::TrendTopic;
 use super::helpers::{llm_to_content_error, llm_to_loop_error};
-use crate::content::ContentGenerator;
+use crate::content::{ContentGenerator, QuoteArchetype, ReplyArchetype};
+use crate::context::winning_dna;
+use crate::llm::TokenUsage;
+use crate::storage::reply_explanations::AncestorRef;
 use crate::storage::DbPool;
 
 /// Record LLM usage to the database (fire-and-forget).
@@ -14,19 +20,15 @@ pub(super) async fn record_llm_usage(
     generation_type: &str,
     provider: &str,
     model: &str,
-    input_tokens: u32,
-    output_tokens: u32,
+    usage: &TokenUsage,
 ) {
-    let pricing = crate::llm::pricing::lookup(provider, model);
-    let cost = pricing.compute_cost(input_tokens, output_tokens);
-    if let Err(e) = crate::storage::llm_usage::insert_llm_usage(
+    if let Err(e) = crate::storage::llm_usage::record_usage(
         pool,
         generation_type,
         provider,
         model,
-        input_tokens,
-        output_tokens,
-        cost,
+        usage,
+        false,
     )
     .await
     {
@@ -64,8 +66,97 @@ impl ReplyGenerator for LlmReplyAdapter {
             "reply",
             &output.provider,
             &output.model,
-            output.usage.input_tokens,
-            output.usage.output_tokens,
+            &output.usage,
+        )
+        .await;
+        Ok(output.text)
+    }
+
+    /// Picks the archetype up front and injects winning ancestors into the
+    /// prompt, so both can be reported alongside the reply with the pillar
+    /// override the generator applied.
+    async fn generate_reply_explained(
+        &self,
+        tweet_text: &str,
+        author: &str,
+        mention_product: bool,
+    ) -> Result<GeneratedReply, LoopError> {
+        let business = self.generator.business();
+        let archetype = (!business.reply_archetype_weights.is_empty()).then(|| {
+            ReplyArchetype::select_weighted(
+                &business.reply_archetype_weights,
+                &mut rand::thread_rng(),
+            )
+        });
+
+        let mut topic_keywords = business.product_keywords.clone();
+        topic_keywords.extend(business.competitor_keywords.clone());
+        topic_keywords.extend(business.effective_industry_topics().to_vec());
+        let context = winning_dna::build_draft_context(
+            &self.pool,
+            &topic_keywords,
+            winning_dna::MAX_ANCESTORS,
+            winning_dna::RECENCY_HALF_LIFE_DAYS,
+        )
+        .await
+        .ok();
+        let rag_prompt = context
+            .as_ref()
+            .map(|ctx| ctx.prompt_block.as_str())
+            .filter(|s| !s.is_empty());
+
+        let output = self
+            .generator
+            .generate_reply_with_context(tweet_text, author, mention_product, archetype, rag_prompt)
+            .await
+            .map_err(llm_to_loop_error)?;
+        record_llm_usage(
+            &self.pool,
+            "reply",
+            &output.provider,
+            &output.model,
+            &output.usage,
+        )
+        .await;
+
+        let ancestors = context
+            .map(|ctx| {
+                ctx.winning_ancestors
+                    .into_iter()
+                    .map(|a| AncestorRef {
+                        tweet_id: a.tweet_id,
+                        content_preview: a.content_preview,
+                        engagement_score: a.engagement_score,
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        let pillar = business.pillar_override(tweet_text);
+        Ok(GeneratedReply {
+            text: output.text,
+            archetype: archetype.map(|a| a.to_string()),
+            ancestors,
+            topic_override: pillar.map(|(name, _)| name.to_string()),
+            require_approval: pillar.is_some_and(|(_, o)| o.require_approval),
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl QuoteGenerator for LlmReplyAdapter {
+    async fn generate_quote(&self, tweet_text: &str, author: &str) -> Result<String, LoopError> {
+        let archetype = QuoteArchetype::select(&mut rand::thread_rng());
+        let output = self
+            .generator
+            .generate_quote(tweet_text, author, Some(archetype))
+            .await
+            .map_err(llm_to_loop_error)?;
+        record_llm_usage(
+            &self.pool,
+            "quote",
+            &output.provider,
+            &output.model,
+            &output.usage,
         )
         .await;
         Ok(output.text)
@@ -97,8 +188,29 @@ impl TweetGenerator for LlmTweetAdapter {
             "tweet",
             &output.provider,
             &output.model,
-            output.usage.input_tokens,
-            output.usage.output_tokens,
+            &output.usage,
+        )
+        .await;
+        Ok(output.text)
+    }
+
+    async fn generate_trend_tweet(
+        &self,
+        topic: &str,
+        trend: &TrendTopic,
+    ) -> Result<String, ContentLoopError> {
+        let context = trend.prompt_context();
+        let output = self
+            .generator
+            .generate_tweet_with_context(topic, None, Some(&context))
+            .await
+            .map_err(llm_to_content_error)?;
+        record_llm_usage(
+            &self.pool,
+            "tweet",
+            &output.provider,
+            &output.model,
+            &output.usage,
         )
         .await;
         Ok(output.text)
@@ -134,8 +246,7 @@ impl ThreadGenerator for LlmThreadAdapter {
             "thread",
             &output.provider,
             &output.model,
-            output.usage.input_tokens,
-            output.usage.output_tokens,
+            &output.usage,
         )
         .await;
         Ok(output.tweets)
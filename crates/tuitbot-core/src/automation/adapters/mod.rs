@@ -1,13 +1,16 @@
 //! Adapter implementations bridging port traits to real dependencies.
 //!
 //! Each adapter struct wraps one or more concrete dependencies (X API client,
-//! content generator, scoring engine, safety guard, database pool, posting queue)
-//! and implements the port traits defined in [`loop_helpers`], [`analytics_loop`],
-//! [`target_loop`], [`thread_loop`], [`posting_queue`], and [`status_reporter`].
+//! Reddit client, content generator, scoring engine, safety guard, database
+//! pool, posting queue) and implements the port traits defined in
+//! [`loop_helpers`], [`analytics_loop`], [`target_loop`], [`thread_loop`],
+//! [`reddit_loop`], [`posting_queue`], [`pacing`], and [`status_reporter`].
 
 mod helpers;
 mod llm;
+mod pacing;
 mod queue;
+mod reddit;
 mod safety;
 mod scoring;
 mod status;
@@ -18,7 +21,9 @@ mod x_api;
 mod tests;
 
 pub use llm::*;
+pub use pacing::*;
 pub use queue::*;
+pub use reddit::*;
 pub use safety::*;
 pub use scoring::*;
 pub use status::*;
@@ -0,0 +1,89 @@
+//! Request pacer adapter implementation.
+
+use chrono::Utc;
+
+use super::super::pacing::{PaceDecision, PaceStatus, PacedEndpoint, PacingPlanner, RequestPacer};
+use super::helpers::write_turn;
+use crate::storage::write_queue::WriteQueue;
+use crate::storage::{self, DbPool};
+
+/// Adapts `DbPool` + `PacingPlanner` to the `RequestPacer` port trait.
+///
+/// Usage is counted per endpoint per window day in the `pacing_usage` table.
+/// Budgets are scaled by the latest usage reconciliation, if any.
+pub struct PacerAdapter {
+    pool: DbPool,
+    planner: PacingPlanner,
+    writes: Option<WriteQueue>,
+}
+
+impl PacerAdapter {
+    pub fn new(pool: DbPool, planner: PacingPlanner) -> Self {
+        Self {
+            pool,
+            planner,
+            writes: None,
+        }
+    }
+
+    /// Route writes through `writes` instead of straight to the pool.
+    pub fn with_write_queue(mut self, writes: WriteQueue) -> Self {
+        self.writes = Some(writes);
+        self
+    }
+}
+
+/// `planner` with budgets scaled by the latest usage reconciliation.
+async fn reconciled(pool: &DbPool, planner: &PacingPlanner) -> PacingPlanner {
+    match storage::usage_reconciliation::current_pacing_scale(pool).await {
+        Ok(scale) => planner.clone().with_budget_scale(scale),
+        Err(e) => {
+            tracing::warn!(error = %e, "Failed to read pacing scale");
+            planner.clone()
+        }
+    }
+}
+
+/// Current pace snapshot for every paced endpoint.
+pub(crate) async fn query_pace_statuses(
+    pool: &DbPool,
+    planner: &PacingPlanner,
+) -> Result<Vec<PaceStatus>, String> {
+    let planner = reconciled(pool, planner).await;
+    let position = planner.position(Utc::now());
+    let day = position.day.to_string();
+    let mut statuses = Vec::with_capacity(PacedEndpoint::ALL.len());
+    for endpoint in PacedEndpoint::ALL {
+        let used = storage::pacing::get_pacing_usage(pool, endpoint.as_str(), &day)
+            .await
+            .map_err(|e| e.to_string())?;
+        statuses.push(planner.status(endpoint, used, &position));
+    }
+    Ok(statuses)
+}
+
+#[async_trait::async_trait]
+impl RequestPacer for PacerAdapter {
+    async fn check(&self, endpoint: PacedEndpoint) -> PaceDecision {
+        let planner = reconciled(&self.pool, &self.planner).await;
+        let position = planner.position(Utc::now());
+        let day = position.day.to_string();
+        match storage::pacing::get_pacing_usage(&self.pool, endpoint.as_str(), &day).await {
+            Ok(used) => planner.decide(endpoint, used, &position),
+            Err(e) => {
+                tracing::warn!(endpoint = endpoint.as_str(), error = %e, "Failed to read pacing usage");
+                PaceDecision::Proceed
+            }
+        }
+    }
+
+    async fn record(&self, endpoint: PacedEndpoint) {
+        let day = self.planner.position(Utc::now()).day.to_string();
+        let _turn = write_turn(self.writes.as_ref()).await;
+        if let Err(e) =
+            storage::pacing::increment_pacing_usage(&self.pool, endpoint.as_str(), &day).await
+        {
+            tracing::warn!(endpoint = endpoint.as_str(), error = %e, "Failed to record pacing usage");
+        }
+    }
+}
@@ -4,6 +4,9 @@ use tokio::sync::mpsc;
 
 use super::super::loop_helpers::{LoopError, PostSender};
 use super::super::posting_queue::{ApprovalQueue, PostAction};
+use super::helpers::write_turn;
+use crate::safety::trusted;
+use crate::storage::write_queue::WriteQueue;
 use crate::storage::{self, DbPool};
 
 /// Adapts `mpsc::Sender<PostAction>` to the `PostSender` port trait.
@@ -15,17 +18,55 @@ impl PostSenderAdapter {
     pub fn new(tx: mpsc::Sender<PostAction>) -> Self {
         Self { tx }
     }
+
+    async fn reply(
+        &self,
+        tweet_id: &str,
+        content: &str,
+        trusted_author: Option<&str>,
+    ) -> Result<(), LoopError> {
+        let (result_tx, result_rx) = tokio::sync::oneshot::channel();
+        self.tx
+            .send(PostAction::Reply {
+                tweet_id: tweet_id.to_string(),
+                content: content.to_string(),
+                media_ids: vec![],
+                trusted_author: trusted_author.map(str::to_string),
+                result_tx: Some(result_tx),
+            })
+            .await
+            .map_err(|e| LoopError::Other(format!("posting queue send failed: {e}")))?;
+
+        result_rx
+            .await
+            .map_err(|e| LoopError::Other(format!("posting queue result recv failed: {e}")))?
+            .map_err(|e| LoopError::Other(format!("post action failed: {e}")))?;
+
+        Ok(())
+    }
 }
 
 #[async_trait::async_trait]
 impl PostSender for PostSenderAdapter {
     async fn send_reply(&self, tweet_id: &str, content: &str) -> Result<(), LoopError> {
+        self.reply(tweet_id, content, None).await
+    }
+
+    async fn send_trusted_reply(
+        &self,
+        tweet_id: &str,
+        author: &str,
+        content: &str,
+    ) -> Result<(), LoopError> {
+        self.reply(tweet_id, content, Some(author)).await
+    }
+
+    async fn send_quote(&self, tweet_id: &str, content: &str) -> Result<(), LoopError> {
         let (result_tx, result_rx) = tokio::sync::oneshot::channel();
         self.tx
-            .send(PostAction::Reply {
+            .send(PostAction::Quote {
                 tweet_id: tweet_id.to_string(),
                 content: content.to_string(),
-                media_ids: vec![],
                 result_tx: Some(result_tx),
             })
             .await
@@ -42,12 +83,31 @@ impl PostSender for PostSenderAdapter {
 
 /// Adapts `DbPool` to the `ApprovalQueue` port trait.
 pub struct ApprovalQueueAdapter {
-    pool: DbPool,
+    pub(super) pool: DbPool,
+    pub(super) writes: Option<WriteQueue>,
+    approval_mode: bool,
 }
 
 impl ApprovalQueueAdapter {
     pub fn new(pool: DbPool) -> Self {
-        Self { pool }
+        Self {
+            pool,
+            writes: None,
+            approval_mode: true,
+        }
+    }
+
+    /// Route writes through `writes` instead of straight to the pool.
+    pub fn with_write_queue(mut self, writes: WriteQueue) -> Self {
+        self.writes = Some(writes);
+        self
+    }
+
+    /// Whether `approval_mode` is on. When it is off, actions only reach
+    /// the queue while draft-only mode is on.
+    pub fn with_approval_mode(mut self, approval_mode: bool) -> Self {
+        self.approval_mode = approval_mode;
+        self
     }
 }
 
@@ -60,6 +120,7 @@ impl ApprovalQueue for ApprovalQueueAdapter {
         media_paths: &[String],
     ) -> Result<i64, String> {
         let media_json = serde_json::to_string(media_paths).unwrap_or_else(|_| "[]".to_string());
+        let _turn = write_turn(self.writes.as_ref()).await;
         storage::approval_queue::enqueue(
             &self.pool,
             "reply",
@@ -77,6 +138,7 @@ impl ApprovalQueue for ApprovalQueueAdapter {
 
     async fn queue_tweet(&self, content: &str, media_paths: &[String]) -> Result<i64, String> {
         let media_json = serde_json::to_string(media_paths).unwrap_or_else(|_| "[]".to_string());
+        let _turn = write_turn(self.writes.as_ref()).await;
         storage::approval_queue::enqueue(
             &self.pool,
             "tweet",
@@ -91,4 +153,36 @@ impl ApprovalQueue for ApprovalQueueAdapter {
         .await
         .map_err(|e| e.to_string())
     }
+
+    async fn queue_quote(&self, tweet_id: &str, content: &str) -> Result<i64, String> {
+        let _turn = write_turn(self.writes.as_ref()).await;
+        storage::approval_queue::enqueue(
+            &self.pool, "quote", tweet_id, "", // target_author not available here
+            content, "",  // topic
+            "",  // archetype
+            0.0, // score
+            "[]",
+        )
+        .await
+        .map_err(|e| e.to_string())
+    }
+
+    async fn record_trusted_bypass(&self, author: &str, tweet_id: &str) {
+        let _turn = write_turn(self.writes.as_ref()).await;
+        let detail = format!("tweet {tweet_id}");
+        if let Err(e) = trusted::log_bypass(&self.pool, author, "approval", &detail).await {
+            tracing::warn!(error = %e, "Failed to log trusted author bypass");
+        }
+    }
+
+    fn approval_mode(&self) -> bool {
+        self.approval_mode
+    }
+
+    /// Fails closed: if the flag cannot be read, the action is queued.
+    async fn draft_only(&self) -> bool {
+        storage::draft_only::is_draft_only_active(&self.pool)
+            .await
+            .unwrap_or(true)
+    }
 }
@@ -0,0 +1,64 @@
+//! Reddit adapter implementations.
+
+use std::sync::Arc;
+
+use super::super::reddit_loop::{RedditCommentQueue, RedditPostSearcher};
+use super::super::reddit_poster::REDDIT_COMMENT_ACTION;
+use super::super::LoopError;
+use super::helpers::{reddit_to_loop_error, storage_to_loop_error, write_turn};
+use super::queue::ApprovalQueueAdapter;
+use crate::reddit::{RedditApi, RedditPost};
+use crate::storage::approval_queue::{self, PLATFORM_REDDIT};
+
+/// Adapts `RedditApi` to the `RedditPostSearcher` port trait.
+pub struct RedditSearchAdapter {
+    client: Arc<dyn RedditApi>,
+    max_results: u32,
+}
+
+impl RedditSearchAdapter {
+    pub fn new(client: Arc<dyn RedditApi>, max_results: u32) -> Self {
+        Self {
+            client,
+            max_results,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl RedditPostSearcher for RedditSearchAdapter {
+    async fn search_posts(
+        &self,
+        subreddit: &str,
+        query: &str,
+    ) -> Result<Vec<RedditPost>, LoopError> {
+        self.client
+            .search_subreddit(subreddit, query, self.max_results)
+            .await
+            .map_err(reddit_to_loop_error)
+    }
+}
+
+#[async_trait::async_trait]
+impl RedditCommentQueue for ApprovalQueueAdapter {
+    async fn queue_comment(
+        &self,
+        post: &RedditPost,
+        content: &str,
+        score: f32,
+    ) -> Result<i64, LoopError> {
+        let _turn = write_turn(self.writes.as_ref()).await;
+        approval_queue::enqueue_on_platform(
+            &self.pool,
+            PLATFORM_REDDIT,
+            REDDIT_COMMENT_ACTION,
+            &post.name,
+            &post.author,
+            content,
+            &format!("r/{}", post.subreddit),
+            f64::from(score),
+        )
+        .await
+        .map_err(storage_to_loop_error)
+    }
+}
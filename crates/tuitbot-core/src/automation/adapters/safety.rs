@@ -1,23 +1,119 @@
 //! Safety adapter implementations.
 
 use std::sync::Arc;
+use std::time::Duration;
 
 use chrono::Utc;
 
-use super::super::loop_helpers::{ContentSafety, LoopError, SafetyChecker};
-use super::helpers::storage_to_loop_error;
-use crate::safety::SafetyGuard;
+use super::super::loop_helpers::{ContentSafety, LoopError, LoopTweet, SafetyChecker};
+use super::helpers::{storage_to_loop_error, write_turn};
+use crate::config::{AuthorOverlapConfig, Config, ContextCheckConfig, ReciprocityConfig};
+use crate::safety::overlap::{check_author_overlap, AuthorTweetCache, AuthorTweets};
+use crate::safety::{
+    ContextVerdict, DenialReason, OverlapVerdict, ReciprocityVerdict, SafetyGuard, TrustBypass,
+};
+use crate::storage::reply_explanations::{self, ReplyExplanation};
+use crate::storage::write_queue::WriteQueue;
 use crate::storage::{self, DbPool};
+use crate::x_api::XApiClient;
+
+/// Author timeline source and cache for the author overlap check.
+struct AuthorOverlap {
+    config: AuthorOverlapConfig,
+    client: Arc<dyn XApiClient>,
+    cache: AuthorTweetCache,
+}
 
 /// Adapts `SafetyGuard` to the `SafetyChecker` port trait.
 pub struct SafetyAdapter {
     guard: Arc<SafetyGuard>,
     pool: DbPool,
+    writes: Option<WriteQueue>,
+    qa_config: Option<Arc<Config>>,
+    context_check: ContextCheckConfig,
+    reciprocity: ReciprocityConfig,
+    author_overlap: Option<AuthorOverlap>,
 }
 
 impl SafetyAdapter {
     pub fn new(guard: Arc<SafetyGuard>, pool: DbPool) -> Self {
-        Self { guard, pool }
+        Self {
+            guard,
+            pool,
+            writes: None,
+            qa_config: None,
+            context_check: ContextCheckConfig::default(),
+            reciprocity: ReciprocityConfig::default(),
+            author_overlap: None,
+        }
+    }
+
+    /// Route writes through `writes` instead of straight to the pool.
+    pub fn with_write_queue(mut self, writes: WriteQueue) -> Self {
+        self.writes = Some(writes);
+        self
+    }
+
+    /// Score recorded replies against the draft QA rules in `config`.
+    pub fn with_qa_config(mut self, config: Arc<Config>) -> Self {
+        self.qa_config = Some(config);
+        self
+    }
+
+    /// Check reply targets for controversy signals under `config`.
+    pub fn with_context_check(mut self, config: ContextCheckConfig) -> Self {
+        self.context_check = config;
+        self
+    }
+
+    /// De-prioritize or skip one-sided reply targets under `config`.
+    pub fn with_reciprocity(mut self, config: ReciprocityConfig) -> Self {
+        self.reciprocity = config;
+        self
+    }
+
+    /// Compare drafted replies with the author's recent tweets, fetched
+    /// through `client`, when `config` is enabled.
+    pub fn with_author_overlap(
+        mut self,
+        config: AuthorOverlapConfig,
+        client: Arc<dyn XApiClient>,
+    ) -> Self {
+        if config.enabled {
+            let cache = AuthorTweetCache::new(Duration::from_secs(config.cache_minutes * 60));
+            self.author_overlap = Some(AuthorOverlap {
+                config,
+                client,
+                cache,
+            });
+        }
+        self
+    }
+
+    /// Insert a pending reply row and count it against the rate limit.
+    async fn insert_reply(&self, tweet_id: &str, reply_content: &str) -> Result<i64, LoopError> {
+        let reply = storage::replies::ReplySent {
+            id: 0,
+            target_tweet_id: tweet_id.to_string(),
+            reply_tweet_id: None,
+            reply_content: reply_content.to_string(),
+            llm_provider: None,
+            llm_model: None,
+            created_at: Utc::now().to_rfc3339(),
+            status: "pending".to_string(),
+            error_message: None,
+        };
+        let _turn = write_turn(self.writes.as_ref()).await;
+        let id = storage::replies::insert_reply(&self.pool, &reply)
+            .await
+            .map_err(storage_to_loop_error)?;
+
+        self.guard
+            .record_reply()
+            .await
+            .map_err(storage_to_loop_error)?;
+
+        Ok(id)
     }
 }
 
@@ -47,31 +143,123 @@ impl SafetyChecker for SafetyAdapter {
         }
     }
 
-    async fn record_reply(&self, tweet_id: &str, reply_content: &str) -> Result<(), LoopError> {
-        // Insert a reply record for dedup tracking.
-        let reply = storage::replies::ReplySent {
-            id: 0,
-            target_tweet_id: tweet_id.to_string(),
-            reply_tweet_id: None,
-            reply_content: reply_content.to_string(),
-            llm_provider: None,
-            llm_model: None,
-            created_at: Utc::now().to_rfc3339(),
-            status: "pending".to_string(),
-            error_message: None,
-        };
-        storage::replies::insert_reply(&self.pool, &reply)
+    fn check_protected_author(&self, tweet: &LoopTweet) -> Result<(), DenialReason> {
+        self.guard.check_protected_author(&tweet.author_username)
+    }
+
+    fn trusted_bypass(&self, tweet: &LoopTweet) -> TrustBypass {
+        self.guard.trusted_bypass(&tweet.author_username)
+    }
+
+    async fn check_blocked_author(&self, tweet: &LoopTweet) -> Result<(), DenialReason> {
+        match self
+            .guard
+            .check_blocked_author(&tweet.author_id, &tweet.author_username)
             .await
-            .map_err(storage_to_loop_error)?;
+        {
+            Ok(verdict) => verdict,
+            Err(e) => {
+                tracing::warn!(error = %e, "Blocklist check error, treating author as allowed");
+                Ok(())
+            }
+        }
+    }
 
-        // Increment rate limit counter.
-        self.guard
-            .record_reply()
+    async fn check_context(&self, tweet: &LoopTweet) -> ContextVerdict {
+        match self
+            .guard
+            .check_context(
+                &self.context_check,
+                &tweet.author_id,
+                tweet.quotes,
+                tweet.likes,
+            )
             .await
-            .map_err(storage_to_loop_error)?;
+        {
+            Ok(verdict) => verdict,
+            Err(e) => {
+                tracing::warn!(error = %e, "Context check error, treating tweet as clear");
+                ContextVerdict::Clear
+            }
+        }
+    }
+
+    async fn check_reciprocity(&self, tweet: &LoopTweet) -> ReciprocityVerdict {
+        match self
+            .guard
+            .check_reciprocity(&self.reciprocity, &tweet.author_id)
+            .await
+        {
+            Ok(verdict) => verdict,
+            Err(e) => {
+                tracing::warn!(error = %e, "Reciprocity check error, treating author as clear");
+                ReciprocityVerdict::Clear
+            }
+        }
+    }
+
+    async fn check_author_overlap(&self, tweet: &LoopTweet, reply: &str) -> OverlapVerdict {
+        let Some(overlap) = &self.author_overlap else {
+            return OverlapVerdict::Clear;
+        };
+        let author_tweets = match overlap.cache.get(&tweet.author_id) {
+            Some(tweets) => tweets,
+            None => {
+                match crate::toolkit::read::get_user_tweets(
+                    &*overlap.client,
+                    &tweet.author_id,
+                    overlap.config.recent_tweets,
+                    None,
+                )
+                .await
+                {
+                    Ok(response) => {
+                        let tweets: AuthorTweets =
+                            response.data.into_iter().map(|t| (t.id, t.text)).collect();
+                        overlap.cache.insert(&tweet.author_id, tweets.clone());
+                        tweets
+                    }
+                    Err(e) => {
+                        tracing::warn!(error = %e, "Author timeline fetch failed, skipping overlap check");
+                        return OverlapVerdict::Clear;
+                    }
+                }
+            }
+        };
+        check_author_overlap(&overlap.config, reply, &tweet.id, &author_tweets)
+    }
 
+    async fn record_reply(&self, tweet_id: &str, reply_content: &str) -> Result<(), LoopError> {
+        self.insert_reply(tweet_id, reply_content).await?;
         Ok(())
     }
+
+    async fn record_reply_explained(
+        &self,
+        tweet_id: &str,
+        reply_content: &str,
+        mut explanation: ReplyExplanation,
+    ) -> Result<(), LoopError> {
+        let reply_id = self.insert_reply(tweet_id, reply_content).await?;
+
+        if let Some(config) = &self.qa_config {
+            let pillar = explanation
+                .topic_override
+                .as_ref()
+                .and_then(|p| config.business.pillar_overrides.get(p));
+            let check = crate::workflow::draft_workspace::evaluate_draft_with(
+                config,
+                "reply",
+                reply_content,
+                pillar,
+            );
+            explanation.qa_score = Some(check.score);
+        }
+        let _turn = write_turn(self.writes.as_ref()).await;
+        reply_explanations::save(&self.pool, reply_id, &explanation)
+            .await
+            .map_err(storage_to_loop_error)
+    }
 }
 
 /// Adapts `SafetyGuard` to the `ContentSafety` port trait.
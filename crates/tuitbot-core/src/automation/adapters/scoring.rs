@@ -1,9 +1,17 @@
-//! Scoring adapter implementation.
+//! Scoring adapter implementations.
 
 use std::sync::Arc;
 
-use super::super::loop_helpers::{LoopTweet, ScoreResult, TweetScorer};
+use chrono::Utc;
+
+use super::super::loop_helpers::{
+    LoopError, LoopTweet, ScoreResult, ThresholdCalibrator, TweetScorer,
+};
+use super::helpers::storage_to_loop_error;
+use crate::config::ScoringConfig;
 use crate::scoring::{self, ScoringEngine, TweetData};
+use crate::storage::reply_explanations::ScoreSignals;
+use crate::storage::{self, DbPool};
 
 /// Adapts `ScoringEngine` to the `TweetScorer` port trait.
 pub struct ScoringAdapter {
@@ -26,8 +34,9 @@ impl TweetScorer for ScoringAdapter {
             replies: tweet.replies,
             author_username: tweet.author_username.clone(),
             author_followers: tweet.author_followers,
-            has_media: false,
+            has_media: tweet.has_media,
             is_quote_tweet: false,
+            urls: tweet.urls.clone(),
         };
 
         let score = self.engine.score_tweet(&data);
@@ -36,7 +45,48 @@ impl TweetScorer for ScoringAdapter {
         ScoreResult {
             total: score.total,
             meets_threshold: score.meets_threshold,
+            past_cutoff: score.past_cutoff,
             matched_keywords,
+            entity_type: score.entity_type,
+            signals: Some(ScoreSignals {
+                total: score.total,
+                keyword_relevance: score.keyword_relevance,
+                follower: score.follower,
+                recency: score.recency,
+                engagement: score.engagement,
+                reply_count: score.reply_count,
+                content_type: score.content_type,
+                entity_adjustment: score.entity_adjustment,
+                freshness_penalty: score.freshness_penalty,
+                plugin_adjustment: score.plugin_adjustment,
+                plugin_rationale: score.plugin_rationale,
+            }),
         }
     }
 }
+
+/// Adapts `DbPool` + `ScoringConfig` to the `ThresholdCalibrator` port trait.
+///
+/// The first call on each UTC day calibrates and stores that day's threshold.
+pub struct ThresholdCalibratorAdapter {
+    pool: DbPool,
+    config: ScoringConfig,
+}
+
+impl ThresholdCalibratorAdapter {
+    pub fn new(pool: DbPool, config: ScoringConfig) -> Self {
+        Self { pool, config }
+    }
+}
+
+#[async_trait::async_trait]
+impl ThresholdCalibrator for ThresholdCalibratorAdapter {
+    async fn effective_threshold(&self) -> Result<f32, LoopError> {
+        let today = Utc::now().date_naive();
+        let calibration =
+            storage::threshold_calibrations::calibrate(&self.pool, &self.config, today)
+                .await
+                .map_err(storage_to_loop_error)?;
+        Ok(calibration.threshold as f32)
+    }
+}
@@ -2,17 +2,49 @@
 
 use chrono::{DateTime, Utc};
 
+use super::super::pacing::{PaceStatus, PacingPlanner};
+use super::super::safe_mode::safe_mode_reason;
 use super::super::status_reporter::{ActionCounts, StatusQuerier};
+use super::super::warmup::{warmup_status_for, WarmupPhase};
+use super::pacing::query_pace_statuses;
+use crate::config::{Config, ScoringConfig};
+use crate::storage::accounts::DEFAULT_ACCOUNT_ID;
 use crate::storage::{self, DbPool};
 
 /// Adapts `DbPool` to the `StatusQuerier` port trait.
 pub struct StatusQuerierAdapter {
     pool: DbPool,
+    pacing: Option<PacingPlanner>,
+    scoring: Option<ScoringConfig>,
+    warmup: Option<Config>,
 }
 
 impl StatusQuerierAdapter {
     pub fn new(pool: DbPool) -> Self {
-        Self { pool }
+        Self {
+            pool,
+            pacing: None,
+            scoring: None,
+            warmup: None,
+        }
+    }
+
+    /// Include per-endpoint pace state in status reports.
+    pub fn with_pacing(mut self, planner: PacingPlanner) -> Self {
+        self.pacing = Some(planner);
+        self
+    }
+
+    /// Include the auto-calibrated score threshold in status reports.
+    pub fn with_calibration(mut self, scoring: ScoringConfig) -> Self {
+        self.scoring = Some(scoring);
+        self
+    }
+
+    /// Include the account warm-up phase in status reports.
+    pub fn with_warmup(mut self, config: Config) -> Self {
+        self.warmup = Some(config);
+        self
     }
 }
 
@@ -34,4 +66,36 @@ impl StatusQuerier for StatusQuerierAdapter {
             threads_posted: *counts.get("thread_posted").unwrap_or(&0) as u64,
         })
     }
+
+    async fn query_pace_state(&self) -> Result<Vec<PaceStatus>, String> {
+        match &self.pacing {
+            Some(planner) => query_pace_statuses(&self.pool, planner).await,
+            None => Ok(Vec::new()),
+        }
+    }
+
+    async fn query_effective_threshold(&self) -> Result<Option<u32>, String> {
+        let Some(scoring) = &self.scoring else {
+            return Ok(None);
+        };
+        storage::threshold_calibrations::current_threshold(&self.pool, scoring)
+            .await
+            .map(Some)
+            .map_err(|e| e.to_string())
+    }
+
+    async fn query_warmup_phase(&self) -> Result<Option<WarmupPhase>, String> {
+        let Some(config) = &self.warmup else {
+            return Ok(None);
+        };
+        warmup_status_for(&self.pool, DEFAULT_ACCOUNT_ID, config)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    async fn query_safe_mode(&self) -> Result<Option<String>, String> {
+        safe_mode_reason(&self.pool)
+            .await
+            .map_err(|e| e.to_string())
+    }
 }
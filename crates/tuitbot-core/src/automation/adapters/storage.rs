@@ -3,14 +3,23 @@
 use chrono::{DateTime, Utc};
 use tokio::sync::mpsc;
 
-use super::super::analytics_loop::{AnalyticsError, AnalyticsStorage};
+use super::super::analytics_loop::{AnalyticsError, AnalyticsStorage, TweetMetrics};
 use super::super::loop_helpers::{
-    ContentLoopError, ContentStorage, LoopError, LoopStorage, LoopTweet, TopicScorer,
+    ContentLoopError, ContentStorage, LoopError, LoopStorage, LoopTweet, TopicScorer, TrendSource,
+    TOPIC_APPROVAL_RISK,
 };
 use super::super::posting_queue::PostAction;
 use super::super::target_loop::TargetStorage;
-use super::helpers::{parse_datetime, sqlx_to_content_error, storage_to_loop_error};
+use super::helpers::{
+    log_action_queued, parse_datetime, sqlx_to_content_error, storage_to_loop_error, write_turn,
+};
+use crate::config::TargetTier;
+use crate::storage::accounts::DEFAULT_ACCOUNT_ID;
+use crate::storage::keyword_hits::KeywordCluster;
+use crate::storage::thread_performance::ThreadTweetRef;
+use crate::storage::write_queue::WriteQueue;
 use crate::storage::{self, DbPool};
+use crate::workflow::draft_workspace::{store_approval_check, DraftCheck, DraftFlag};
 
 /// Adapts `DbPool` to the `LoopStorage` port trait.
 ///
@@ -18,11 +27,18 @@ use crate::storage::{self, DbPool};
 /// discovered tweet recording, and action logging.
 pub struct StorageAdapter {
     pool: DbPool,
+    writes: Option<WriteQueue>,
 }
 
 impl StorageAdapter {
     pub fn new(pool: DbPool) -> Self {
-        Self { pool }
+        Self { pool, writes: None }
+    }
+
+    /// Route writes through `writes` instead of straight to the pool.
+    pub fn with_write_queue(mut self, writes: WriteQueue) -> Self {
+        self.writes = Some(writes);
+        self
     }
 }
 
@@ -35,6 +51,7 @@ impl LoopStorage for StorageAdapter {
     }
 
     async fn set_cursor(&self, key: &str, value: &str) -> Result<(), LoopError> {
+        let _turn = write_turn(self.writes.as_ref()).await;
         storage::cursors::set_cursor(&self.pool, key, value)
             .await
             .map_err(storage_to_loop_error)
@@ -46,6 +63,23 @@ impl LoopStorage for StorageAdapter {
             .map_err(storage_to_loop_error)
     }
 
+    async fn tweet_seen_within(
+        &self,
+        tweet_id: &str,
+        window: std::time::Duration,
+    ) -> Result<bool, LoopError> {
+        storage::keyword_hits::tweet_seen_within(&self.pool, tweet_id, window.as_secs())
+            .await
+            .map_err(storage_to_loop_error)
+    }
+
+    async fn record_keyword_hit(&self, tweet_id: &str, keyword: &str) -> Result<(), LoopError> {
+        let _turn = write_turn(self.writes.as_ref()).await;
+        storage::keyword_hits::record_keyword_hit(&self.pool, tweet_id, keyword)
+            .await
+            .map_err(storage_to_loop_error)
+    }
+
     async fn store_discovered_tweet(
         &self,
         tweet: &LoopTweet,
@@ -60,38 +94,140 @@ impl LoopStorage for StorageAdapter {
             like_count: tweet.likes as i64,
             retweet_count: tweet.retweets as i64,
             reply_count: tweet.replies as i64,
+            quote_count: tweet.quotes as i64,
             impression_count: None,
             relevance_score: Some(score as f64),
             matched_keyword: Some(keyword.to_string()),
             discovered_at: Utc::now().to_rfc3339(),
             replied_to: 0,
         };
+        let _turn = write_turn(self.writes.as_ref()).await;
         storage::tweets::insert_discovered_tweet(&self.pool, &discovered)
             .await
             .map_err(storage_to_loop_error)
     }
 
+    async fn record_inbound_engagement(&self, tweet: &LoopTweet) -> Result<(), LoopError> {
+        let _turn = write_turn(self.writes.as_ref()).await;
+        storage::reciprocity::record_inbound(
+            &self.pool,
+            &tweet.id,
+            &tweet.author_id,
+            &tweet.author_username,
+            &tweet.created_at,
+        )
+        .await
+        .map_err(storage_to_loop_error)
+    }
+
     async fn log_action(
         &self,
         action_type: &str,
         status: &str,
         message: &str,
     ) -> Result<(), LoopError> {
-        storage::action_log::log_action(&self.pool, action_type, status, Some(message), None)
-            .await
-            .map_err(storage_to_loop_error)
+        log_action_queued(
+            &self.pool,
+            self.writes.as_ref(),
+            action_type,
+            status,
+            message,
+        )
+        .await
+        .map_err(storage_to_loop_error)
+    }
+
+    async fn recent_action_messages(
+        &self,
+        action_type: &str,
+        window: std::time::Duration,
+    ) -> Result<Vec<(DateTime<Utc>, String)>, LoopError> {
+        let since = Utc::now() - chrono::Duration::from_std(window).unwrap_or_default();
+        let entries = storage::action_log::get_actions_since(
+            &self.pool,
+            &since.format("%Y-%m-%dT%H:%M:%SZ").to_string(),
+            Some(action_type),
+        )
+        .await
+        .map_err(storage_to_loop_error)?;
+        Ok(entries
+            .into_iter()
+            .filter_map(|e| {
+                Some((
+                    parse_datetime(&e.created_at)?,
+                    e.message.unwrap_or_default(),
+                ))
+            })
+            .collect())
+    }
+
+    async fn queue_reply_for_approval(
+        &self,
+        tweet_id: &str,
+        author: &str,
+        content: &str,
+        score: f32,
+        risk: Option<&str>,
+        qa_flags: &[DraftFlag],
+    ) -> Result<(), LoopError> {
+        let risks = serde_json::to_string(&risk.into_iter().collect::<Vec<_>>())
+            .unwrap_or_else(|_| "[]".to_string());
+        let reason = match (risk, qa_flags.first()) {
+            (None, Some(flag)) => flag.code.as_str(),
+            (Some(risk), _) if risk.starts_with(TOPIC_APPROVAL_RISK) => TOPIC_APPROVAL_RISK,
+            _ => "context_check",
+        };
+        let _turn = write_turn(self.writes.as_ref()).await;
+        let id = storage::approval_queue::enqueue_with_context(
+            &self.pool,
+            "reply",
+            tweet_id,
+            author,
+            content,
+            "",
+            "",
+            f64::from(score),
+            "[]",
+            Some(reason),
+            Some(&risks),
+        )
+        .await
+        .map_err(storage_to_loop_error)?;
+        store_qa_flags(&self.pool, id, qa_flags).await
+    }
+}
+
+/// Store soft QA flags raised by the loops on approval item `id`.
+async fn store_qa_flags(pool: &DbPool, id: i64, qa_flags: &[DraftFlag]) -> Result<(), LoopError> {
+    if qa_flags.is_empty() {
+        return Ok(());
     }
+    let check = DraftCheck::from_flags(Vec::new(), qa_flags.to_vec());
+    store_approval_check(pool, DEFAULT_ACCOUNT_ID, id, &check)
+        .await
+        .map_err(storage_to_loop_error)
 }
 
 /// Adapts `DbPool` + posting queue to the `ContentStorage` port trait.
 pub struct ContentStorageAdapter {
     pool: DbPool,
     post_tx: mpsc::Sender<PostAction>,
+    writes: Option<WriteQueue>,
 }
 
 impl ContentStorageAdapter {
     pub fn new(pool: DbPool, post_tx: mpsc::Sender<PostAction>) -> Self {
-        Self { pool, post_tx }
+        Self {
+            pool,
+            post_tx,
+            writes: None,
+        }
+    }
+
+    /// Route writes through `writes` instead of straight to the pool.
+    pub fn with_write_queue(mut self, writes: WriteQueue) -> Self {
+        self.writes = Some(writes);
+        self
     }
 }
 
@@ -118,7 +254,12 @@ impl ContentStorage for ContentStorageAdapter {
         Ok(time_str.and_then(|s| parse_datetime(&s)))
     }
 
-    async fn post_tweet(&self, topic: &str, content: &str) -> Result<(), ContentLoopError> {
+    async fn post_tweet(
+        &self,
+        topic: &str,
+        content: &str,
+        inspiration: &str,
+    ) -> Result<(), ContentLoopError> {
         // Send to the posting queue and await result.
         let (result_tx, result_rx) = tokio::sync::oneshot::channel();
         self.post_tx
@@ -145,7 +286,9 @@ impl ContentStorage for ContentStorageAdapter {
             created_at: Utc::now().to_rfc3339(),
             status: "sent".to_string(),
             error_message: None,
+            inspiration: inspiration.to_string(),
         };
+        let _turn = write_turn(self.writes.as_ref()).await;
         storage::threads::insert_original_tweet(&self.pool, &original)
             .await
             .map_err(|e| ContentLoopError::StorageError(e.to_string()))?;
@@ -158,6 +301,33 @@ impl ContentStorage for ContentStorageAdapter {
         Ok(())
     }
 
+    async fn queue_tweet_for_approval(
+        &self,
+        topic: &str,
+        pillar: &str,
+        content: &str,
+    ) -> Result<(), ContentLoopError> {
+        let risks = serde_json::to_string(&[format!("{TOPIC_APPROVAL_RISK}: {pillar}")])
+            .unwrap_or_else(|_| "[]".to_string());
+        let _turn = write_turn(self.writes.as_ref()).await;
+        storage::approval_queue::enqueue_with_context(
+            &self.pool,
+            "tweet",
+            "",
+            "",
+            content,
+            topic,
+            "",
+            0.0,
+            "[]",
+            Some(TOPIC_APPROVAL_RISK),
+            Some(&risks),
+        )
+        .await
+        .map_err(|e| ContentLoopError::StorageError(e.to_string()))?;
+        Ok(())
+    }
+
     async fn create_thread(
         &self,
         topic: &str,
@@ -171,6 +341,7 @@ impl ContentStorage for ContentStorageAdapter {
             created_at: Utc::now().to_rfc3339(),
             status: "pending".to_string(),
         };
+        let _turn = write_turn(self.writes.as_ref()).await;
         let id = storage::threads::insert_thread(&self.pool, &thread)
             .await
             .map_err(|e| ContentLoopError::StorageError(e.to_string()))?;
@@ -188,6 +359,7 @@ impl ContentStorage for ContentStorageAdapter {
             .parse()
             .map_err(|_| ContentLoopError::StorageError("invalid thread_id".to_string()))?;
 
+        let _turn = write_turn(self.writes.as_ref()).await;
         sqlx::query(
             "UPDATE threads SET status = ?1, tweet_count = ?2, root_tweet_id = ?3 WHERE id = ?4",
         )
@@ -220,6 +392,7 @@ impl ContentStorage for ContentStorageAdapter {
             .parse()
             .map_err(|_| ContentLoopError::StorageError("invalid thread_id".to_string()))?;
 
+        let _turn = write_turn(self.writes.as_ref()).await;
         sqlx::query(
             "INSERT INTO thread_tweets (thread_id, position, tweet_id, content, created_at)
              VALUES (?1, ?2, ?3, ?4, datetime('now'))",
@@ -241,9 +414,15 @@ impl ContentStorage for ContentStorageAdapter {
         status: &str,
         message: &str,
     ) -> Result<(), ContentLoopError> {
-        storage::action_log::log_action(&self.pool, action_type, status, Some(message), None)
-            .await
-            .map_err(|e| ContentLoopError::StorageError(e.to_string()))
+        log_action_queued(
+            &self.pool,
+            self.writes.as_ref(),
+            action_type,
+            status,
+            message,
+        )
+        .await
+        .map_err(|e| ContentLoopError::StorageError(e.to_string()))
     }
 
     async fn next_scheduled_item(&self) -> Result<Option<(i64, String, String)>, ContentLoopError> {
@@ -262,6 +441,7 @@ impl ContentStorage for ContentStorageAdapter {
         id: i64,
         tweet_id: Option<&str>,
     ) -> Result<(), ContentLoopError> {
+        let _turn = write_turn(self.writes.as_ref()).await;
         storage::scheduled_content::update_status(&self.pool, id, "posted", tweet_id)
             .await
             .map_err(|e| ContentLoopError::StorageError(e.to_string()))
@@ -271,11 +451,18 @@ impl ContentStorage for ContentStorageAdapter {
 /// Adapts `DbPool` to the `TargetStorage` port trait.
 pub struct TargetStorageAdapter {
     pool: DbPool,
+    writes: Option<WriteQueue>,
 }
 
 impl TargetStorageAdapter {
     pub fn new(pool: DbPool) -> Self {
-        Self { pool }
+        Self { pool, writes: None }
+    }
+
+    /// Route writes through `writes` instead of straight to the pool.
+    pub fn with_write_queue(mut self, writes: WriteQueue) -> Self {
+        self.writes = Some(writes);
+        self
     }
 }
 
@@ -286,6 +473,7 @@ impl TargetStorage for TargetStorageAdapter {
         account_id: &str,
         username: &str,
     ) -> Result<(), LoopError> {
+        let _turn = write_turn(self.writes.as_ref()).await;
         storage::target_accounts::upsert_target_account(&self.pool, account_id, username)
             .await
             .map_err(storage_to_loop_error)
@@ -307,6 +495,7 @@ impl TargetStorage for TargetStorageAdapter {
         like_count: i64,
         relevance_score: f64,
     ) -> Result<(), LoopError> {
+        let _turn = write_turn(self.writes.as_ref()).await;
         storage::target_accounts::store_target_tweet(
             &self.pool,
             tweet_id,
@@ -322,12 +511,14 @@ impl TargetStorage for TargetStorageAdapter {
     }
 
     async fn mark_target_tweet_replied(&self, tweet_id: &str) -> Result<(), LoopError> {
+        let _turn = write_turn(self.writes.as_ref()).await;
         storage::target_accounts::mark_target_tweet_replied(&self.pool, tweet_id)
             .await
             .map_err(storage_to_loop_error)
     }
 
     async fn record_target_reply(&self, account_id: &str) -> Result<(), LoopError> {
+        let _turn = write_turn(self.writes.as_ref()).await;
         storage::target_accounts::record_target_reply(&self.pool, account_id)
             .await
             .map_err(storage_to_loop_error)
@@ -339,26 +530,103 @@ impl TargetStorage for TargetStorageAdapter {
             .map_err(storage_to_loop_error)
     }
 
+    async fn get_target_tier(&self, account_id: &str) -> Result<TargetTier, LoopError> {
+        let account = storage::target_accounts::get_target_account(&self.pool, account_id)
+            .await
+            .map_err(storage_to_loop_error)?;
+        Ok(account
+            .and_then(|a| TargetTier::parse(&a.tier))
+            .unwrap_or_default())
+    }
+
+    async fn count_target_interactions_this_week(
+        &self,
+        account_id: &str,
+    ) -> Result<i64, LoopError> {
+        storage::target_accounts::count_target_interactions_this_week(&self.pool, account_id)
+            .await
+            .map_err(storage_to_loop_error)
+    }
+
+    async fn record_target_interaction(
+        &self,
+        account_id: &str,
+        tweet_id: &str,
+        status: &str,
+    ) -> Result<(), LoopError> {
+        let _turn = write_turn(self.writes.as_ref()).await;
+        storage::target_accounts::record_target_reply_history(
+            &self.pool, account_id, tweet_id, status,
+        )
+        .await
+        .map_err(storage_to_loop_error)
+    }
+
+    async fn get_target_since_id(&self, account_id: &str) -> Result<Option<String>, LoopError> {
+        storage::target_accounts::get_target_since_id(&self.pool, account_id)
+            .await
+            .map_err(storage_to_loop_error)
+    }
+
+    async fn set_target_since_id(&self, account_id: &str, since_id: &str) -> Result<(), LoopError> {
+        let _turn = write_turn(self.writes.as_ref()).await;
+        storage::target_accounts::set_target_since_id(&self.pool, account_id, since_id)
+            .await
+            .map_err(storage_to_loop_error)
+    }
+
+    async fn queue_target_reply(
+        &self,
+        tweet_id: &str,
+        username: &str,
+        content: &str,
+        qa_flags: &[DraftFlag],
+    ) -> Result<(), LoopError> {
+        let _turn = write_turn(self.writes.as_ref()).await;
+        let id = storage::approval_queue::enqueue(
+            &self.pool, "reply", tweet_id, username, content, "",  // topic
+            "",  // archetype
+            0.0, // score
+            "[]",
+        )
+        .await
+        .map_err(storage_to_loop_error)?;
+        store_qa_flags(&self.pool, id, qa_flags).await
+    }
+
     async fn log_action(
         &self,
         action_type: &str,
         status: &str,
         message: &str,
     ) -> Result<(), LoopError> {
-        storage::action_log::log_action(&self.pool, action_type, status, Some(message), None)
-            .await
-            .map_err(storage_to_loop_error)
+        log_action_queued(
+            &self.pool,
+            self.writes.as_ref(),
+            action_type,
+            status,
+            message,
+        )
+        .await
+        .map_err(storage_to_loop_error)
     }
 }
 
 /// Adapts `DbPool` to the `AnalyticsStorage` port trait.
 pub struct AnalyticsStorageAdapter {
     pool: DbPool,
+    writes: Option<WriteQueue>,
 }
 
 impl AnalyticsStorageAdapter {
     pub fn new(pool: DbPool) -> Self {
-        Self { pool }
+        Self { pool, writes: None }
+    }
+
+    /// Route writes through `writes` instead of straight to the pool.
+    pub fn with_write_queue(mut self, writes: WriteQueue) -> Self {
+        self.writes = Some(writes);
+        self
     }
 }
 
@@ -370,6 +638,7 @@ impl AnalyticsStorage for AnalyticsStorageAdapter {
         following: i64,
         tweets: i64,
     ) -> Result<(), AnalyticsError> {
+        let _turn = write_turn(self.writes.as_ref()).await;
         storage::analytics::upsert_follower_snapshot(&self.pool, followers, following, tweets)
             .await
             .map_err(|e| AnalyticsError::StorageError(e.to_string()))
@@ -429,6 +698,7 @@ impl AnalyticsStorage for AnalyticsStorageAdapter {
         impressions: i64,
         score: f64,
     ) -> Result<(), AnalyticsError> {
+        let _turn = write_turn(self.writes.as_ref()).await;
         storage::analytics::upsert_reply_performance(
             &self.pool,
             reply_id,
@@ -450,6 +720,7 @@ impl AnalyticsStorage for AnalyticsStorageAdapter {
         impressions: i64,
         score: f64,
     ) -> Result<(), AnalyticsError> {
+        let _turn = write_turn(self.writes.as_ref()).await;
         storage::analytics::upsert_tweet_performance(
             &self.pool,
             tweet_id,
@@ -463,12 +734,41 @@ impl AnalyticsStorage for AnalyticsStorageAdapter {
         .map_err(|e| AnalyticsError::StorageError(e.to_string()))
     }
 
+    async fn get_thread_tweets_needing_measurement(
+        &self,
+    ) -> Result<Vec<ThreadTweetRef>, AnalyticsError> {
+        storage::thread_performance::get_tweets_needing_measurement(&self.pool)
+            .await
+            .map_err(|e| AnalyticsError::StorageError(e.to_string()))
+    }
+
+    async fn store_thread_tweet_performance(
+        &self,
+        tweet: &ThreadTweetRef,
+        metrics: &TweetMetrics,
+        score: f64,
+    ) -> Result<(), AnalyticsError> {
+        let _turn = write_turn(self.writes.as_ref()).await;
+        storage::thread_performance::upsert_tweet_performance(
+            &self.pool,
+            tweet,
+            metrics.likes,
+            metrics.retweets,
+            metrics.replies,
+            metrics.impressions,
+            score,
+        )
+        .await
+        .map_err(|e| AnalyticsError::StorageError(e.to_string()))
+    }
+
     async fn update_content_score(
         &self,
         topic: &str,
         format: &str,
         score: f64,
     ) -> Result<(), AnalyticsError> {
+        let _turn = write_turn(self.writes.as_ref()).await;
         storage::analytics::update_content_score(&self.pool, topic, format, score)
             .await
             .map_err(|e| AnalyticsError::StorageError(e.to_string()))
@@ -480,9 +780,49 @@ impl AnalyticsStorage for AnalyticsStorageAdapter {
         status: &str,
         message: &str,
     ) -> Result<(), AnalyticsError> {
-        storage::action_log::log_action(&self.pool, action_type, status, Some(message), None)
-            .await
-            .map_err(|e| AnalyticsError::StorageError(e.to_string()))
+        log_action_queued(
+            &self.pool,
+            self.writes.as_ref(),
+            action_type,
+            status,
+            message,
+        )
+        .await
+        .map_err(|e| AnalyticsError::StorageError(e.to_string()))
+    }
+}
+
+/// Adapts `DbPool` to the `TrendSource` port trait.
+pub struct TrendSourceAdapter {
+    pool: DbPool,
+    lookback_hours: u32,
+    min_tweets: u32,
+}
+
+impl TrendSourceAdapter {
+    pub fn new(pool: DbPool, lookback_hours: u32, min_tweets: u32) -> Self {
+        Self {
+            pool,
+            lookback_hours,
+            min_tweets,
+        }
+    }
+}
+
+/// Clusters considered per trend pick.
+const TREND_CLUSTER_LIMIT: u32 = 10;
+
+#[async_trait::async_trait]
+impl TrendSource for TrendSourceAdapter {
+    async fn trending_clusters(&self) -> Result<Vec<KeywordCluster>, ContentLoopError> {
+        storage::keyword_hits::get_keyword_clusters(
+            &self.pool,
+            self.lookback_hours,
+            self.min_tweets,
+            TREND_CLUSTER_LIMIT,
+        )
+        .await
+        .map_err(|e| ContentLoopError::StorageError(e.to_string()))
     }
 }
 
@@ -2,7 +2,9 @@ use std::sync::Arc;
 
 use super::*;
 use crate::automation::analytics_loop::{EngagementFetcher, ProfileFetcher};
-use crate::automation::loop_helpers::{LoopError, MentionsFetcher, ThreadPoster, TweetSearcher};
+use crate::automation::loop_helpers::{
+    ContentLoopError, LoopError, MentionsFetcher, ThreadPoster, TweetSearcher,
+};
 use crate::automation::posting_queue::PostExecutor;
 use crate::automation::target_loop::{TargetTweetFetcher, TargetUserManager};
 use crate::x_api::types::*;
@@ -29,6 +31,8 @@ impl XApiClient for MockXApiClient {
                 created_at: String::new(),
                 public_metrics: PublicMetrics::default(),
                 conversation_id: None,
+                entities: None,
+                attachments: None,
             }],
             includes: None,
             meta: SearchMeta {
@@ -54,6 +58,8 @@ impl XApiClient for MockXApiClient {
                 created_at: String::new(),
                 public_metrics: PublicMetrics::default(),
                 conversation_id: None,
+                entities: None,
+                attachments: None,
             }],
             includes: None,
             meta: SearchMeta {
@@ -91,6 +97,8 @@ impl XApiClient for MockXApiClient {
             created_at: String::new(),
             public_metrics: PublicMetrics::default(),
             conversation_id: None,
+            entities: None,
+            attachments: None,
         })
     }
 
@@ -156,12 +164,12 @@ async fn mentions_adapter_routes_through_toolkit() {
     assert_eq!(tweets[0].id, "m1");
 }
 
-// --- TargetTweetFetcher (routes through toolkit::read::get_user_tweets) ---
+// --- TargetTweetFetcher (routes through toolkit::read::get_user_tweets_since) ---
 
 #[tokio::test]
 async fn target_adapter_fetch_routes_through_toolkit() {
     let adapter = XApiTargetAdapter::new(mock_client());
-    let tweets = adapter.fetch_user_tweets("u1").await.unwrap();
+    let tweets = adapter.fetch_user_tweets("u1", None).await.unwrap();
     assert!(tweets.is_empty()); // mock returns empty
 }
 
@@ -210,6 +218,48 @@ async fn post_executor_tweet_routes_through_toolkit() {
     assert_eq!(id, "pt1");
 }
 
+#[tokio::test]
+async fn post_executor_refuses_while_kill_switch_engaged() {
+    let pool = crate::storage::init_test_db().await.expect("init db");
+    crate::storage::kill_switch::engage_kill_switch(&pool, "audit", "test")
+        .await
+        .expect("engage");
+
+    let adapter = XApiPostExecutorAdapter::new(mock_client()).with_kill_switch(pool.clone());
+    let err = adapter.execute_tweet("hello", &[]).await.unwrap_err();
+    assert!(err.contains("kill switch engaged: audit"));
+
+    let poster = XApiThreadPosterAdapter::new(mock_client()).with_kill_switch(pool);
+    let err = poster.post_tweet("thread start").await.unwrap_err();
+    assert!(matches!(err, ContentLoopError::PostFailed(_)));
+}
+
+// --- Storage adapters with a write queue ---
+
+#[tokio::test]
+async fn storage_adapter_writes_through_write_queue() {
+    use crate::automation::loop_helpers::LoopStorage;
+    use crate::storage::write_queue::WriteQueue;
+
+    let pool = crate::storage::init_test_db().await.expect("init db");
+    let writes = WriteQueue::spawn(pool.clone());
+    let adapter = StorageAdapter::new(pool.clone()).with_write_queue(writes.clone());
+
+    adapter.set_cursor("since_id", "42").await.expect("cursor");
+    adapter
+        .log_action("search", "success", "queued")
+        .await
+        .expect("log");
+
+    assert_eq!(
+        adapter.get_cursor("since_id").await.expect("get"),
+        Some("42".to_string())
+    );
+    let stats = writes.stats();
+    assert_eq!(stats.log_rows, 1);
+    assert_eq!(stats.writes_total, 2);
+}
+
 // --- ThreadPoster (routes through toolkit::write) ---
 
 #[tokio::test]
@@ -297,6 +347,6 @@ async fn toolkit_error_maps_to_loop_error() {
 #[tokio::test]
 async fn empty_id_triggers_toolkit_validation() {
     let adapter = XApiTargetAdapter::new(mock_client());
-    let err = adapter.fetch_user_tweets("").await.unwrap_err();
+    let err = adapter.fetch_user_tweets("", None).await.unwrap_err();
     assert!(matches!(err, LoopError::Other(_)));
 }
@@ -4,7 +4,8 @@ use std::sync::Arc;
 
 use super::super::analytics_loop::{AnalyticsError, EngagementFetcher, ProfileFetcher};
 use super::super::loop_helpers::{
-    ContentLoopError, LoopError, LoopTweet, MentionsFetcher, ThreadPoster, TweetSearcher,
+    ContentLoopError, LoopError, LoopTweet, MentionsFetcher, ThreadPoster, TweetLiker,
+    TweetSearcher,
 };
 use super::super::posting_queue::PostExecutor;
 use super::super::target_loop::{TargetTweetFetcher, TargetUserManager};
@@ -12,6 +13,8 @@ use super::helpers::{
     search_response_to_loop_tweets, toolkit_to_analytics_error, toolkit_to_content_error,
     toolkit_to_loop_error,
 };
+use crate::hooks::{HookAction, HookEvent, HookGate, HookRunner};
+use crate::storage::{agent_sessions, draft_only, kill_switch, DbPool};
 use crate::x_api::XApiClient;
 
 /// Adapts `XApiClient` to the `TweetSearcher` port trait via toolkit.
@@ -39,6 +42,7 @@ impl TweetSearcher for XApiSearchAdapter {
 pub struct XApiMentionsAdapter {
     client: Arc<dyn XApiClient>,
     own_user_id: String,
+    kill_switch_pool: Option<DbPool>,
 }
 
 impl XApiMentionsAdapter {
@@ -46,8 +50,15 @@ impl XApiMentionsAdapter {
         Self {
             client,
             own_user_id,
+            kill_switch_pool: None,
         }
     }
+
+    /// Refuse to like mentions while the kill switch stored in `pool` is engaged.
+    pub fn with_kill_switch(mut self, pool: DbPool) -> Self {
+        self.kill_switch_pool = Some(pool);
+        self
+    }
 }
 
 #[async_trait::async_trait]
@@ -61,6 +72,19 @@ impl MentionsFetcher for XApiMentionsAdapter {
     }
 }
 
+#[async_trait::async_trait]
+impl TweetLiker for XApiMentionsAdapter {
+    async fn like_tweet(&self, tweet_id: &str) -> Result<(), LoopError> {
+        if let Some(halt) = kill_switch_halt(self.kill_switch_pool.as_ref()).await {
+            return Err(LoopError::Other(halt));
+        }
+        crate::toolkit::engage::like_tweet(&*self.client, &self.own_user_id, tweet_id)
+            .await
+            .map_err(toolkit_to_loop_error)?;
+        Ok(())
+    }
+}
+
 /// Adapts `XApiClient` to `TargetTweetFetcher` and `TargetUserManager` via toolkit.
 pub struct XApiTargetAdapter {
     client: Arc<dyn XApiClient>,
@@ -74,10 +98,15 @@ impl XApiTargetAdapter {
 
 #[async_trait::async_trait]
 impl TargetTweetFetcher for XApiTargetAdapter {
-    async fn fetch_user_tweets(&self, user_id: &str) -> Result<Vec<LoopTweet>, LoopError> {
-        let response = crate::toolkit::read::get_user_tweets(&*self.client, user_id, 10, None)
-            .await
-            .map_err(toolkit_to_loop_error)?;
+    async fn fetch_user_tweets(
+        &self,
+        user_id: &str,
+        since_id: Option<&str>,
+    ) -> Result<Vec<LoopTweet>, LoopError> {
+        let response =
+            crate::toolkit::read::get_user_tweets_since(&*self.client, user_id, 10, since_id)
+                .await
+                .map_err(toolkit_to_loop_error)?;
         Ok(search_response_to_loop_tweets(response))
     }
 }
@@ -140,11 +169,100 @@ impl EngagementFetcher for XApiProfileAdapter {
 /// Adapts `XApiClient` to `PostExecutor` (for the posting queue) via toolkit.
 pub struct XApiPostExecutorAdapter {
     client: Arc<dyn XApiClient>,
+    kill_switch_pool: Option<DbPool>,
+    hooks: Option<Arc<HookRunner>>,
 }
 
 impl XApiPostExecutorAdapter {
     pub fn new(client: Arc<dyn XApiClient>) -> Self {
-        Self { client }
+        Self {
+            client,
+            kill_switch_pool: None,
+            hooks: None,
+        }
+    }
+
+    /// Refuse to post while the kill switch stored in `pool` is engaged or
+    /// draft-only mode is on, and count post outcomes on this process's
+    /// session for safe mode.
+    pub fn with_kill_switch(mut self, pool: DbPool) -> Self {
+        self.kill_switch_pool = Some(pool);
+        self
+    }
+
+    /// Run the `pre_post` and `post_post` hooks around each post.
+    pub fn with_hooks(mut self, hooks: Arc<HookRunner>) -> Self {
+        self.hooks = Some(hooks);
+        self
+    }
+}
+
+/// Run the `pre_post` hooks for `action`. Returns the text to post, or the
+/// denial as an error message.
+async fn hook_pre_post(hooks: Option<&HookRunner>, action: &HookAction) -> Result<String, String> {
+    let Some(hooks) = hooks else {
+        return Ok(action.content.clone());
+    };
+    match hooks.gate(HookEvent::PrePost, action).await {
+        HookGate::Allow => Ok(action.content.clone()),
+        HookGate::Modify(content) => Ok(content),
+        HookGate::Deny { hook, reason } => Err(format!("denied by hook {hook}: {reason}")),
+    }
+}
+
+/// Notify the `post_post` hooks that `action` went out as `content`.
+async fn hook_post_post(
+    hooks: Option<&HookRunner>,
+    mut action: HookAction,
+    content: String,
+    posted_id: Option<&str>,
+) {
+    let (Some(hooks), Some(posted_id)) = (hooks, posted_id) else {
+        return;
+    };
+    action.content = content;
+    action.posted_id = Some(posted_id.to_string());
+    hooks.notify(HookEvent::PostPost, &action).await;
+}
+
+/// Count a post outcome on this process's session, if it has one.
+async fn record_post_outcome(pool: Option<&DbPool>, error: Option<String>) {
+    let Some(pool) = pool else {
+        return;
+    };
+    if let Err(e) =
+        agent_sessions::record_post_outcome(pool, std::process::id(), error.as_deref()).await
+    {
+        tracing::warn!(error = %e, "Failed to record post outcome");
+    }
+}
+
+/// Returns the halt reason if the kill switch is engaged.
+///
+/// Fails closed: if the flag cannot be read, posting is refused.
+async fn kill_switch_halt(pool: Option<&DbPool>) -> Option<String> {
+    let pool = pool?;
+    match kill_switch::get_kill_switch(pool).await {
+        Ok(state) if state.active => Some(format!(
+            "kill switch engaged: {}",
+            state.reason.unwrap_or_default()
+        )),
+        Ok(_) => None,
+        Err(e) => Some(format!("kill switch state unavailable: {e}")),
+    }
+}
+
+/// Returns the reason posting is refused: the kill switch or draft-only mode.
+///
+/// Fails closed like [`kill_switch_halt`].
+async fn posting_halt(pool: Option<&DbPool>) -> Option<String> {
+    if let Some(halt) = kill_switch_halt(pool).await {
+        return Some(halt);
+    }
+    match draft_only::is_draft_only_active(pool?).await {
+        Ok(true) => Some("draft-only mode is on, posting is disabled".to_string()),
+        Ok(false) => None,
+        Err(e) => Some(format!("draft-only state unavailable: {e}")),
     }
 }
 
@@ -156,48 +274,134 @@ impl PostExecutor for XApiPostExecutorAdapter {
         content: &str,
         media_ids: &[String],
     ) -> Result<String, String> {
+        if let Some(halt) = posting_halt(self.kill_switch_pool.as_ref()).await {
+            return Err(halt);
+        }
+        let action = HookAction::new("reply", content).with_target(tweet_id);
+        let content = hook_pre_post(self.hooks.as_deref(), &action).await?;
         let media = if media_ids.is_empty() {
             None
         } else {
             Some(media_ids)
         };
-        crate::toolkit::write::reply_to_tweet(&*self.client, content, tweet_id, media)
-            .await
-            .map(|posted| posted.id)
-            .map_err(|e| e.to_string())
+        let result =
+            crate::toolkit::write::reply_to_tweet(&*self.client, &content, tweet_id, media)
+                .await
+                .map(|posted| posted.id)
+                .map_err(|e| e.to_string());
+        record_post_outcome(self.kill_switch_pool.as_ref(), result.clone().err()).await;
+        hook_post_post(
+            self.hooks.as_deref(),
+            action,
+            content,
+            result.as_deref().ok(),
+        )
+        .await;
+        result
     }
 
     async fn execute_tweet(&self, content: &str, media_ids: &[String]) -> Result<String, String> {
+        if let Some(halt) = posting_halt(self.kill_switch_pool.as_ref()).await {
+            return Err(halt);
+        }
+        let action = HookAction::new("tweet", content);
+        let content = hook_pre_post(self.hooks.as_deref(), &action).await?;
         let media = if media_ids.is_empty() {
             None
         } else {
             Some(media_ids)
         };
-        crate::toolkit::write::post_tweet(&*self.client, content, media)
+        let result = crate::toolkit::write::post_tweet(&*self.client, &content, media)
             .await
             .map(|posted| posted.id)
-            .map_err(|e| e.to_string())
+            .map_err(|e| e.to_string());
+        record_post_outcome(self.kill_switch_pool.as_ref(), result.clone().err()).await;
+        hook_post_post(
+            self.hooks.as_deref(),
+            action,
+            content,
+            result.as_deref().ok(),
+        )
+        .await;
+        result
+    }
+
+    async fn execute_quote(&self, tweet_id: &str, content: &str) -> Result<String, String> {
+        if let Some(halt) = posting_halt(self.kill_switch_pool.as_ref()).await {
+            return Err(halt);
+        }
+        let action = HookAction::new("quote", content).with_target(tweet_id);
+        let content = hook_pre_post(self.hooks.as_deref(), &action).await?;
+        let result = crate::toolkit::write::quote_tweet(&*self.client, &content, tweet_id)
+            .await
+            .map(|posted| posted.id)
+            .map_err(|e| e.to_string());
+        record_post_outcome(self.kill_switch_pool.as_ref(), result.clone().err()).await;
+        hook_post_post(
+            self.hooks.as_deref(),
+            action,
+            content,
+            result.as_deref().ok(),
+        )
+        .await;
+        result
     }
 }
 
 /// Adapts `XApiClient` to `ThreadPoster` (for direct thread posting) via toolkit.
 pub struct XApiThreadPosterAdapter {
     client: Arc<dyn XApiClient>,
+    kill_switch_pool: Option<DbPool>,
+    hooks: Option<Arc<HookRunner>>,
 }
 
 impl XApiThreadPosterAdapter {
     pub fn new(client: Arc<dyn XApiClient>) -> Self {
-        Self { client }
+        Self {
+            client,
+            kill_switch_pool: None,
+            hooks: None,
+        }
+    }
+
+    /// Refuse to post while the kill switch stored in `pool` is engaged or
+    /// draft-only mode is on, and count post outcomes on this process's
+    /// session for safe mode.
+    pub fn with_kill_switch(mut self, pool: DbPool) -> Self {
+        self.kill_switch_pool = Some(pool);
+        self
+    }
+
+    /// Run the `pre_post` and `post_post` hooks around each post.
+    pub fn with_hooks(mut self, hooks: Arc<HookRunner>) -> Self {
+        self.hooks = Some(hooks);
+        self
     }
 }
 
 #[async_trait::async_trait]
 impl ThreadPoster for XApiThreadPosterAdapter {
     async fn post_tweet(&self, content: &str) -> Result<String, ContentLoopError> {
-        crate::toolkit::write::post_tweet(&*self.client, content, None)
+        if let Some(halt) = posting_halt(self.kill_switch_pool.as_ref()).await {
+            return Err(ContentLoopError::PostFailed(halt));
+        }
+        let action = HookAction::new("tweet", content);
+        let content = hook_pre_post(self.hooks.as_deref(), &action)
             .await
-            .map(|posted| posted.id)
-            .map_err(toolkit_to_content_error)
+            .map_err(ContentLoopError::PostFailed)?;
+        let result = crate::toolkit::write::post_tweet(&*self.client, &content, None)
+            .await
+            .map(|posted| posted.id);
+        let error = result.as_ref().err().map(ToString::to_string);
+        record_post_outcome(self.kill_switch_pool.as_ref(), error).await;
+        hook_post_post(
+            self.hooks.as_deref(),
+            action,
+            content,
+            result.as_deref().ok(),
+        )
+        .await;
+        result.map_err(toolkit_to_content_error)
     }
 
     async fn reply_to_tweet(
@@ -205,9 +409,26 @@ impl ThreadPoster for XApiThreadPosterAdapter {
         in_reply_to: &str,
         content: &str,
     ) -> Result<String, ContentLoopError> {
-        crate::toolkit::write::reply_to_tweet(&*self.client, content, in_reply_to, None)
+        if let Some(halt) = posting_halt(self.kill_switch_pool.as_ref()).await {
+            return Err(ContentLoopError::PostFailed(halt));
+        }
+        let action = HookAction::new("thread_tweet", content).with_target(in_reply_to);
+        let content = hook_pre_post(self.hooks.as_deref(), &action)
             .await
-            .map(|posted| posted.id)
-            .map_err(toolkit_to_content_error)
+            .map_err(ContentLoopError::PostFailed)?;
+        let result =
+            crate::toolkit::write::reply_to_tweet(&*self.client, &content, in_reply_to, None)
+                .await
+                .map(|posted| posted.id);
+        let error = result.as_ref().err().map(ToString::to_string);
+        record_post_outcome(self.kill_switch_pool.as_ref(), error).await;
+        hook_post_post(
+            self.hooks.as_deref(),
+            action,
+            content,
+            result.as_deref().ok(),
+        )
+        .await;
+        result.map_err(toolkit_to_content_error)
     }
 }
@@ -4,10 +4,12 @@
 //! 1. Snapshot follower counts via the X API.
 //! 2. Fetch engagement metrics on content posted ~24h ago.
 //! 3. Compute performance scores and update running averages.
-//! 4. Alert on significant follower drops.
+//! 4. Measure each tweet of posted threads to track drop-off by position.
+//! 5. Alert on significant follower drops.
 
 use super::loop_helpers::ConsecutiveErrorTracker;
 use super::scheduler::LoopScheduler;
+use crate::storage::thread_performance::ThreadTweetRef;
 use std::sync::Arc;
 use std::time::Duration;
 use tokio_util::sync::CancellationToken;
@@ -71,6 +73,19 @@ pub trait AnalyticsStorage: Send + Sync {
         score: f64,
     ) -> Result<(), AnalyticsError>;
 
+    /// Get posted thread tweets, at every position, that need performance measurement.
+    async fn get_thread_tweets_needing_measurement(
+        &self,
+    ) -> Result<Vec<ThreadTweetRef>, AnalyticsError>;
+
+    /// Store performance metrics for one tweet of a thread.
+    async fn store_thread_tweet_performance(
+        &self,
+        tweet: &ThreadTweetRef,
+        metrics: &TweetMetrics,
+        score: f64,
+    ) -> Result<(), AnalyticsError>;
+
     /// Update the content score running average for a topic.
     async fn update_content_score(
         &self,
@@ -175,6 +190,7 @@ impl AnalyticsLoop {
                         followers = summary.follower_count,
                         replies_measured = summary.replies_measured,
                         tweets_measured = summary.tweets_measured,
+                        thread_tweets_measured = summary.thread_tweets_measured,
                         "Analytics iteration complete"
                     );
                 }
@@ -301,14 +317,40 @@ impl AnalyticsLoop {
             }
         }
 
+        // 4. Measure every tweet of posted threads
+        let thread_tweets = self.storage.get_thread_tweets_needing_measurement().await?;
+        for tweet in &thread_tweets {
+            match self
+                .engagement_fetcher
+                .get_tweet_metrics(&tweet.tweet_id)
+                .await
+            {
+                Ok(m) => {
+                    let score =
+                        compute_performance_score(m.likes, m.replies, m.retweets, m.impressions);
+                    let _ = self
+                        .storage
+                        .store_thread_tweet_performance(tweet, &m, score)
+                        .await;
+                    summary.thread_tweets_measured += 1;
+                }
+                Err(e) => {
+                    tracing::debug!(tweet_id = %tweet.tweet_id, error = %e, "Failed to fetch thread tweet metrics");
+                }
+            }
+        }
+
         let _ = self
             .storage
             .log_action(
                 "analytics",
                 "success",
                 &format!(
-                    "Followers: {}, replies measured: {}, tweets measured: {}",
-                    summary.follower_count, summary.replies_measured, summary.tweets_measured,
+                    "Followers: {}, replies measured: {}, tweets measured: {}, thread tweets measured: {}",
+                    summary.follower_count,
+                    summary.replies_measured,
+                    summary.tweets_measured,
+                    summary.thread_tweets_measured,
                 ),
             )
             .await;
@@ -323,6 +365,7 @@ pub struct AnalyticsSummary {
     pub follower_count: i64,
     pub replies_measured: usize,
     pub tweets_measured: usize,
+    pub thread_tweets_measured: usize,
 }
 
 /// Compute the performance score for content engagement.
@@ -370,6 +413,8 @@ mod tests {
         tweet_ids: Vec<String>,
         reply_perfs: Mutex<Vec<(String, f64)>>,
         tweet_perfs: Mutex<Vec<(String, f64)>>,
+        thread_tweets: Vec<ThreadTweetRef>,
+        thread_perfs: Mutex<Vec<(i64, i64, f64)>>,
     }
 
     impl MockAnalyticsStorage {
@@ -381,6 +426,8 @@ mod tests {
                 tweet_ids: Vec::new(),
                 reply_perfs: Mutex::new(Vec::new()),
                 tweet_perfs: Mutex::new(Vec::new()),
+                thread_tweets: Vec::new(),
+                thread_perfs: Mutex::new(Vec::new()),
             }
         }
 
@@ -398,6 +445,11 @@ mod tests {
             self.tweet_ids = ids;
             self
         }
+
+        fn with_thread_tweets(mut self, tweets: Vec<ThreadTweetRef>) -> Self {
+            self.thread_tweets = tweets;
+            self
+        }
     }
 
     #[async_trait::async_trait]
@@ -458,6 +510,25 @@ mod tests {
             Ok(())
         }
 
+        async fn get_thread_tweets_needing_measurement(
+            &self,
+        ) -> Result<Vec<ThreadTweetRef>, AnalyticsError> {
+            Ok(self.thread_tweets.clone())
+        }
+
+        async fn store_thread_tweet_performance(
+            &self,
+            tweet: &ThreadTweetRef,
+            _metrics: &TweetMetrics,
+            score: f64,
+        ) -> Result<(), AnalyticsError> {
+            self.thread_perfs
+                .lock()
+                .expect("lock")
+                .push((tweet.thread_id, tweet.position, score));
+            Ok(())
+        }
+
         async fn update_content_score(
             &self,
             _topic: &str,
@@ -552,6 +623,38 @@ mod tests {
         assert_eq!(storage.tweet_perfs.lock().expect("lock").len(), 1);
     }
 
+    #[tokio::test]
+    async fn iteration_measures_every_thread_position() {
+        let tweets = (0..3)
+            .map(|position| ThreadTweetRef {
+                thread_id: 7,
+                position,
+                tweet_id: format!("th{position}"),
+            })
+            .collect();
+        let storage = Arc::new(MockAnalyticsStorage::new().with_thread_tweets(tweets));
+        let analytics = AnalyticsLoop::new(
+            Arc::new(MockProfileFetcher {
+                metrics: default_profile(),
+            }),
+            Arc::new(MockEngagementFetcher {
+                metrics: default_tweet_metrics(),
+            }),
+            storage.clone(),
+        );
+
+        let summary = analytics.run_iteration().await.expect("iteration");
+        assert_eq!(summary.thread_tweets_measured, 3);
+        let positions: Vec<i64> = storage
+            .thread_perfs
+            .lock()
+            .expect("lock")
+            .iter()
+            .map(|(_, position, _)| *position)
+            .collect();
+        assert_eq!(positions, vec![0, 1, 2]);
+    }
+
     #[tokio::test]
     async fn iteration_detects_follower_drop() {
         // Yesterday: 1000, Today: 970 (3% drop)
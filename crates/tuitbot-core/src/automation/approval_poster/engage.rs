@@ -0,0 +1,39 @@
+//! Executing approved likes and follows.
+
+use crate::storage::approval_queue::ApprovalItem;
+use crate::storage::write_queue::{write_via, WriteQueue};
+use crate::storage::{self, DbPool};
+use crate::toolkit;
+use crate::x_api::XApiClient;
+
+/// Execute an approved like or follow within its daily rate limit.
+///
+/// The rate limit slot is claimed before the call, so concurrent posters
+/// cannot overshoot the limit and a failed call still counts against it.
+/// Returns the target ID, which is recorded as the item's posted ID.
+pub(super) async fn engage_item(
+    pool: &DbPool,
+    writes: Option<&WriteQueue>,
+    client: &dyn XApiClient,
+    item: &ApprovalItem,
+) -> Result<String, String> {
+    let target = item.target_tweet_id.as_str();
+    if target.is_empty() {
+        return Err(format!("{} item has no target", item.action_type));
+    }
+    let claim = storage::rate_limits::check_and_increment_rate_limit(pool, &item.action_type);
+    let allowed = write_via(writes, claim).await.map_err(|e| e.to_string())?;
+    if !allowed {
+        return Err(format!("daily {} limit reached", item.action_type));
+    }
+
+    let me = toolkit::read::get_me(client)
+        .await
+        .map_err(|e| e.to_string())?;
+    match item.action_type.as_str() {
+        "like" => toolkit::engage::like_tweet(client, &me.id, target).await,
+        _ => toolkit::engage::follow_user(client, &me.id, target).await,
+    }
+    .map_err(|e| e.to_string())?;
+    Ok(target.to_string())
+}
@@ -0,0 +1,116 @@
+//! Checks an approved item must pass before it is claimed for posting.
+
+use crate::hooks::{HookEvent, HookGate, HookRunner, HOOK_EDITOR};
+use crate::safety::protected;
+use crate::storage::approval_queue::{ApprovalItem, ReviewAction};
+use crate::storage::write_queue::{log_action_via, write_via, WriteQueue};
+use crate::storage::{self, DbPool};
+
+/// Reviewer recorded on items the poster rejects for safety reasons.
+pub(super) const SAFETY_ACTOR: &str = "safety";
+
+/// Run the `pre_post` hooks for an approved item before it is claimed.
+///
+/// Returns the item to post, with any rewrite applied, or `None` when a hook
+/// denied it (the item is rejected) or it could not be reloaded.
+pub(super) async fn run_pre_post_hooks(
+    pool: &DbPool,
+    writes: Option<&WriteQueue>,
+    hooks: &HookRunner,
+    item: ApprovalItem,
+) -> Option<ApprovalItem> {
+    if !hooks.has(HookEvent::PrePost) {
+        return Some(item);
+    }
+    let gate = match hooks.gate_item(HookEvent::PrePost, pool, &item).await {
+        Ok(gate) => gate,
+        Err(e) => {
+            tracing::warn!(id = item.id, error = %e, "Failed to apply hook rewrite");
+            return None;
+        }
+    };
+    match gate {
+        HookGate::Allow => Some(item),
+        HookGate::Modify(_) => match storage::approval_queue::get_by_id(pool, item.id).await {
+            Ok(item) => item,
+            Err(e) => {
+                tracing::warn!(error = %e, "Failed to reload rewritten item");
+                None
+            }
+        },
+        HookGate::Deny { hook, reason } => {
+            let review = ReviewAction {
+                actor: Some(HOOK_EDITOR.to_string()),
+                notes: Some(format!("Denied by hook {hook}: {reason}")),
+                expected_version: Some(item.version),
+            };
+            let reject = storage::approval_queue::update_status_with_review(
+                pool, item.id, "rejected", &review,
+            );
+            if let Err(e) = write_via(writes, reject).await {
+                tracing::warn!(id = item.id, error = %e, "Failed to reject hook-denied item");
+            }
+            let metadata = serde_json::json!({
+                "approval_id": item.id,
+                "hook": hook,
+                "reason": reason,
+            });
+            let _ = log_action_via(
+                pool,
+                writes,
+                &format!("{}_posted", item.action_type),
+                "skipped",
+                Some(&format!("Hook {hook} denied approved item {}", item.id)),
+                Some(&metadata.to_string()),
+            )
+            .await;
+            None
+        }
+    }
+}
+
+/// Reject an approved item whose target author is a protected entity.
+///
+/// Returns `true` when the item was refused. `protected` must already be
+/// normalized. The X API client refuses such writes too, looking the author
+/// up on X, for items whose recorded author is missing or stale.
+pub(super) async fn refuse_protected_target(
+    pool: &DbPool,
+    writes: Option<&WriteQueue>,
+    protected: &[String],
+    item: &ApprovalItem,
+) -> bool {
+    if !protected::is_protected(&item.target_author, protected) {
+        return false;
+    }
+    let username = protected::normalize_username(&item.target_author);
+    tracing::warn!(
+        id = item.id,
+        author = %username,
+        "Approved item targets a protected entity, rejecting"
+    );
+    let review = ReviewAction {
+        actor: Some(SAFETY_ACTOR.to_string()),
+        notes: Some(format!("@{username} is a protected entity")),
+        expected_version: Some(item.version),
+    };
+    let reject =
+        storage::approval_queue::update_status_with_review(pool, item.id, "rejected", &review);
+    if let Err(e) = write_via(writes, reject).await {
+        tracing::warn!(id = item.id, error = %e, "Failed to reject protected-target item");
+    }
+    let metadata = serde_json::json!({ "approval_id": item.id, "author": username });
+    let _ = log_action_via(
+        pool,
+        writes,
+        &format!("{}_posted", item.action_type),
+        "skipped",
+        Some(&format!(
+            "Approved item {} targets a protected entity",
+            item.id
+        )),
+        Some(&metadata.to_string()),
+    )
+    .await;
+    true
+}
@@ -0,0 +1,291 @@
+//! Approval poster loop: polls for approved items and posts them to X.
+//!
+//! When `approval_mode` is enabled, posts go into the approval queue rather
+//! than being posted directly. This loop watches for items that have been
+//! approved by the user and posts them via the X API. Approved likes and
+//! follows are executed the same way, within their daily rate limits.
+//! Posts pass through the `pre_post` hooks first, which can reject or
+//! rewrite them; `post_post` hooks are notified once they are live.
+//! Items aimed at a protected entity are rejected without being sent.
+
+mod engage;
+mod gates;
+mod posting;
+#[cfg(test)]
+mod tests;
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use rand::Rng;
+use tokio_util::sync::CancellationToken;
+
+use super::circuit_breaker::CircuitBreaker;
+use super::posting_lock::{PostingLock, DEFAULT_STALE_AFTER};
+use crate::hooks::{HookAction, HookEvent, HookRunner};
+use crate::safety::protected;
+use crate::storage::write_queue::{log_action_via, write_via, WriteQueue};
+use crate::storage::{self, DbPool};
+use crate::toolkit::media::MediaLimits;
+use crate::x_api::XApiClient;
+use engage::engage_item;
+use gates::{refuse_protected_target, run_pre_post_hooks};
+use posting::{post_item, upload_media, validate_media};
+
+/// Run the approval poster loop.
+///
+/// Polls the approval queue for approved items and posts them to X.
+/// Uses randomized delay between `min_delay` and `max_delay` to appear human-like.
+/// Attached media is validated against `media_limits` before upload. When a
+/// `circuit_breaker` is provided, its delay multiplier stretches the pause
+/// between posts. Approved items are held while the kill switch is engaged
+/// or draft-only mode is on.
+/// Only the process holding the cross-process posting lock posts. Items
+/// whose target author is in `protected` are rejected. Queue updates and
+/// action logs go through `writes` when attached.
+#[allow(clippy::too_many_arguments)]
+pub async fn run_approval_poster(
+    pool: DbPool,
+    writes: Option<WriteQueue>,
+    x_client: Arc<dyn XApiClient>,
+    min_delay: Duration,
+    max_delay: Duration,
+    media_limits: MediaLimits,
+    circuit_breaker: Option<Arc<CircuitBreaker>>,
+    hooks: Arc<HookRunner>,
+    protected: Vec<String>,
+    cancel: CancellationToken,
+) {
+    tracing::info!("Approval poster loop started");
+    let writes = writes.as_ref();
+    let protected = protected::normalize_protected(&protected);
+
+    // Poll interval when no items are found.
+    let idle_interval = Duration::from_secs(15);
+    let mut kill_switch_halted = false;
+    let mut draft_only_held = false;
+
+    // The heartbeat is refreshed once per round, so a live holder must not
+    // look stale while sleeping between posts.
+    let stale_after = DEFAULT_STALE_AFTER.max(max_delay * 2 + idle_interval);
+    let mut lock = PostingLock::new(pool.clone(), stale_after);
+
+    loop {
+        tokio::select! {
+            biased;
+            () = cancel.cancelled() => {
+                tracing::info!("Approval poster received cancellation");
+                break;
+            }
+            () = tokio::time::sleep(idle_interval) => {}
+        }
+
+        // Hold approved items while the kill switch is engaged (fails closed).
+        let halted = storage::kill_switch::is_kill_switch_active(&pool)
+            .await
+            .unwrap_or(true);
+        if halted != kill_switch_halted {
+            if halted {
+                tracing::warn!("Kill switch engaged, approval posting paused");
+            } else {
+                tracing::info!("Kill switch released, approval posting resumed");
+            }
+            kill_switch_halted = halted;
+        }
+        if halted {
+            continue;
+        }
+
+        // Draft-only mode keeps approved items queued until it is lifted.
+        let drafting = storage::draft_only::is_draft_only_active(&pool)
+            .await
+            .unwrap_or(true);
+        if drafting != draft_only_held {
+            if drafting {
+                tracing::warn!("Draft-only mode on, approved items are held");
+            } else {
+                tracing::info!("Draft-only mode lifted, posting held items");
+            }
+            draft_only_held = drafting;
+        }
+        if drafting {
+            continue;
+        }
+
+        if !lock.acquire().await {
+            continue;
+        }
+
+        match storage::approval_queue::get_next_approved(&pool).await {
+            Ok(Some(item)) => {
+                if refuse_protected_target(&pool, writes, &protected, &item).await {
+                    continue;
+                }
+                let item = if item.is_engagement() {
+                    item
+                } else {
+                    match run_pre_post_hooks(&pool, writes, &hooks, item).await {
+                        Some(item) => item,
+                        None => continue,
+                    }
+                };
+
+                // Only the poster that wins the claim sends the item; a
+                // concurrent reject or another poster makes this a no-op.
+                let claim =
+                    storage::approval_queue::claim_for_posting(&pool, item.id, item.version);
+                match write_via(writes, claim).await {
+                    Ok(true) => {}
+                    Ok(false) => {
+                        tracing::info!(
+                            id = item.id,
+                            "Approved item changed before posting, skipping"
+                        );
+                        continue;
+                    }
+                    Err(e) => {
+                        tracing::warn!(id = item.id, error = %e, "Failed to claim approved item");
+                        continue;
+                    }
+                }
+
+                tracing::info!(
+                    id = item.id,
+                    action_type = %item.action_type,
+                    "Posting approved item"
+                );
+
+                let result = if item.is_engagement() {
+                    engage_item(&pool, writes, &*x_client, &item).await
+                } else {
+                    // Parse media paths from JSON.
+                    let media_paths: Vec<String> =
+                        serde_json::from_str(&item.media_paths).unwrap_or_default();
+
+                    // Upload media if any.
+                    let media_ids = if media_paths.is_empty() {
+                        vec![]
+                    } else {
+                        let (prepared, records) = validate_media(&media_paths, &media_limits).await;
+                        if let Ok(json) = serde_json::to_string(&records) {
+                            let record = storage::approval_queue::update_media_validation(
+                                &pool, item.id, &json,
+                            );
+                            if let Err(e) = write_via(writes, record).await {
+                                tracing::warn!(
+                                    id = item.id,
+                                    error = %e,
+                                    "Failed to record media validation results"
+                                );
+                            }
+                        }
+                        match upload_media(&*x_client, item.id, prepared).await {
+                            Ok(ids) => ids,
+                            Err(e) => {
+                                tracing::warn!(
+                                    id = item.id,
+                                    error = %e,
+                                    "Failed to upload media for approved item, posting without media"
+                                );
+                                vec![]
+                            }
+                        }
+                    };
+
+                    post_item(&pool, &*x_client, &item, &media_ids).await
+                };
+
+                match result {
+                    Ok(tweet_id) => {
+                        tracing::info!(
+                            id = item.id,
+                            tweet_id = %tweet_id,
+                            "Approved item posted successfully"
+                        );
+                        let mark = storage::approval_queue::mark_posted(&pool, item.id, &tweet_id);
+                        if let Err(e) = write_via(writes, mark).await {
+                            tracing::warn!(
+                                id = item.id,
+                                error = %e,
+                                "Failed to mark approved item as posted"
+                            );
+                        }
+                        if !item.is_engagement() {
+                            let mut action = HookAction::from_item(&item);
+                            action.posted_id = Some(tweet_id.clone());
+                            hooks.notify(HookEvent::PostPost, &action).await;
+                        }
+                        // Log the action.
+                        let metadata = serde_json::json!({
+                            "approval_id": item.id,
+                            "tweet_id": tweet_id,
+                            "in_reply_to_id": item.target_tweet_id,
+                        });
+                        let _ = log_action_via(
+                            &pool,
+                            writes,
+                            &format!("{}_posted", item.action_type),
+                            "success",
+                            Some(&format!("Posted approved item {}", item.id)),
+                            Some(&metadata.to_string()),
+                        )
+                        .await;
+                    }
+                    Err(e) => {
+                        tracing::warn!(
+                            id = item.id,
+                            error = %e,
+                            "Failed to post approved item"
+                        );
+                        let release =
+                            storage::approval_queue::release_posting_claim(&pool, item.id);
+                        if let Err(e) = write_via(writes, release).await {
+                            tracing::warn!(
+                                id = item.id,
+                                error = %e,
+                                "Failed to release posting claim"
+                            );
+                        }
+                        let _ = log_action_via(
+                            &pool,
+                            writes,
+                            &format!("{}_posted", item.action_type),
+                            "error",
+                            Some(&format!("Failed to post approved item {}: {}", item.id, e)),
+                            Some(&serde_json::json!({ "approval_id": item.id }).to_string()),
+                        )
+                        .await;
+                    }
+                }
+
+                // Jittered delay between posts.
+                let mut delay = randomized_delay(min_delay, max_delay);
+                if let Some(ref cb) = circuit_breaker {
+                    delay = cb.scale_delay(delay);
+                }
+                if !delay.is_zero() {
+                    tokio::time::sleep(delay).await;
+                }
+            }
+            Ok(None) => {
+                // No approved items — continue polling.
+            }
+            Err(e) => {
+                tracing::warn!(error = %e, "Failed to query approved items");
+            }
+        }
+    }
+
+    lock.release().await;
+    tracing::info!("Approval poster loop stopped");
+}
+
+/// Compute a randomized delay between `min` and `max`.
+pub(super) fn randomized_delay(min: Duration, max: Duration) -> Duration {
+    if min >= max || (min.is_zero() && max.is_zero()) {
+        return min;
+    }
+    let min_ms = min.as_millis() as u64;
+    let max_ms = max.as_millis() as u64;
+    Duration::from_millis(rand::thread_rng().gen_range(min_ms..=max_ms))
+}
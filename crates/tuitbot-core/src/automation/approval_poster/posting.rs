@@ -0,0 +1,143 @@
+//! Posting approved tweets, replies, and quotes with their media.
+
+use crate::storage::approval_queue::ApprovalItem;
+use crate::storage::{self, DbPool};
+use crate::toolkit::media::{MediaLimits, MediaValidation, PreparedMedia};
+use crate::workflow::idempotent_post::{self, OutboundPost};
+use crate::x_api::media::UploadProgress;
+use crate::x_api::XApiClient;
+
+/// Post an approved item at most once, even across retries after an
+/// ambiguous failure. Replies and quotes with an empty target are posted
+/// as tweets.
+pub(super) async fn post_item(
+    pool: &DbPool,
+    client: &dyn XApiClient,
+    item: &ApprovalItem,
+    media_ids: &[String],
+) -> Result<String, String> {
+    let in_reply_to_id = match item.action_type.as_str() {
+        "reply" if !item.target_tweet_id.is_empty() => Some(item.target_tweet_id.as_str()),
+        // tweet, thread_tweet, quote, or reply with empty target
+        _ => None,
+    };
+    let quote_tweet_id = match item.action_type.as_str() {
+        "quote" if !item.target_tweet_id.is_empty() => Some(item.target_tweet_id.as_str()),
+        _ => None,
+    };
+    let scope = format!("approval:{}", item.id);
+    let post = OutboundPost {
+        scope: &scope,
+        text: &item.generated_content,
+        in_reply_to_id,
+        quote_tweet_id,
+        media_ids: (!media_ids.is_empty()).then_some(media_ids),
+    };
+    idempotent_post::post_once(pool, client, None, post)
+        .await
+        .map(|out| out.tweet_id)
+        .map_err(|e| e.to_string())
+}
+
+/// Validation outcome for one media file, as recorded on the approval item.
+#[derive(Debug, serde::Serialize)]
+pub(super) struct MediaValidationRecord {
+    path: String,
+    #[serde(flatten)]
+    validation: Option<MediaValidation>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+/// Read and validate local media files before upload.
+///
+/// Oversized images are transcoded by the toolkit. Returns the prepared media
+/// (or the first failure) together with a per-file record for the approval item.
+pub(super) async fn validate_media(
+    media_paths: &[String],
+    limits: &MediaLimits,
+) -> (
+    Result<Vec<PreparedMedia>, String>,
+    Vec<MediaValidationRecord>,
+) {
+    use crate::x_api::types::{ImageFormat, MediaType};
+
+    let mut prepared = Vec::with_capacity(media_paths.len());
+    let mut records = Vec::with_capacity(media_paths.len());
+    let mut first_error = None;
+
+    for path in media_paths {
+        let expanded = storage::expand_tilde(path);
+        let result = match tokio::fs::read(&expanded).await {
+            Ok(data) => {
+                // Infer media type via toolkit, falling back to JPEG.
+                let declared = crate::toolkit::media::infer_media_type(&expanded)
+                    .unwrap_or(MediaType::Image(ImageFormat::Jpeg));
+                crate::toolkit::media::prepare_media_with_limits(&data, declared, limits)
+                    .map_err(|e| e.to_string())
+            }
+            Err(e) => Err(format!("Failed to read media file: {e}")),
+        };
+
+        match result {
+            Ok(media) => {
+                records.push(MediaValidationRecord {
+                    path: path.clone(),
+                    validation: Some(media.validation.clone()),
+                    error: None,
+                });
+                prepared.push(media);
+            }
+            Err(e) => {
+                if first_error.is_none() {
+                    first_error = Some(format!("Media validation failed for {path}: {e}"));
+                }
+                records.push(MediaValidationRecord {
+                    path: path.clone(),
+                    validation: None,
+                    error: Some(e),
+                });
+            }
+        }
+    }
+
+    match first_error {
+        Some(e) => (Err(e), records),
+        None => (Ok(prepared), records),
+    }
+}
+
+/// Upload validated media to X via toolkit and return their media IDs.
+///
+/// Chunked uploads (GIF/video) log their progress against the approval item.
+pub(super) async fn upload_media(
+    client: &dyn XApiClient,
+    item_id: i64,
+    prepared: Result<Vec<PreparedMedia>, String>,
+) -> Result<Vec<String>, String> {
+    let prepared = prepared?;
+    let report = |p: &UploadProgress| {
+        tracing::info!(
+            id = item_id,
+            phase = ?p.phase,
+            segments_sent = p.segments_sent,
+            segment_count = p.segment_count,
+            retries = p.retries,
+            processing_percent = ?p.processing_percent,
+            "Media upload progress"
+        );
+    };
+    let mut media_ids = Vec::with_capacity(prepared.len());
+    for media in prepared {
+        let media_id = crate::toolkit::media::upload_media_with_progress(
+            client,
+            &media.data,
+            media.media_type,
+            &report,
+        )
+        .await
+        .map_err(|e| format!("Failed to upload media: {e}"))?;
+        media_ids.push(media_id.0);
+    }
+    Ok(media_ids)
+}
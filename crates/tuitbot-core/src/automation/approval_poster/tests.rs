@@ -0,0 +1,221 @@
+use std::sync::Mutex;
+
+use super::gates::SAFETY_ACTOR;
+use super::*;
+use crate::config::{IntervalsConfig, LimitsConfig};
+use crate::error::XApiError;
+use crate::hooks::HOOK_EDITOR;
+use crate::storage::approval_queue::ApprovalItem;
+use crate::storage::init_test_db;
+use crate::x_api::types::*;
+
+/// Records like/follow calls as `(action, user_id, target)`.
+#[derive(Default)]
+struct EngageClient {
+    calls: Mutex<Vec<(String, String, String)>>,
+}
+
+impl EngageClient {
+    fn record(&self, action: &str, user_id: &str, target: &str) {
+        self.calls.lock().expect("lock").push((
+            action.to_string(),
+            user_id.to_string(),
+            target.to_string(),
+        ));
+    }
+}
+
+#[async_trait::async_trait]
+impl XApiClient for EngageClient {
+    async fn search_tweets(
+        &self,
+        _: &str,
+        _: u32,
+        _: Option<&str>,
+        _: Option<&str>,
+    ) -> Result<SearchResponse, XApiError> {
+        unimplemented!()
+    }
+    async fn get_mentions(
+        &self,
+        _: &str,
+        _: Option<&str>,
+        _: Option<&str>,
+    ) -> Result<MentionResponse, XApiError> {
+        unimplemented!()
+    }
+    async fn post_tweet(&self, _: &str) -> Result<PostedTweet, XApiError> {
+        unimplemented!()
+    }
+    async fn reply_to_tweet(&self, _: &str, _: &str) -> Result<PostedTweet, XApiError> {
+        unimplemented!()
+    }
+    async fn get_tweet(&self, _: &str) -> Result<Tweet, XApiError> {
+        unimplemented!()
+    }
+    async fn get_me(&self) -> Result<User, XApiError> {
+        Ok(User {
+            id: "me".to_string(),
+            username: "me".to_string(),
+            name: "Me".to_string(),
+            public_metrics: UserMetrics::default(),
+        })
+    }
+    async fn get_user_tweets(
+        &self,
+        _: &str,
+        _: u32,
+        _: Option<&str>,
+    ) -> Result<SearchResponse, XApiError> {
+        unimplemented!()
+    }
+    async fn get_user_by_username(&self, _: &str) -> Result<User, XApiError> {
+        unimplemented!()
+    }
+    async fn like_tweet(&self, user_id: &str, tweet_id: &str) -> Result<bool, XApiError> {
+        self.record("like", user_id, tweet_id);
+        Ok(true)
+    }
+    async fn follow_user(&self, user_id: &str, target: &str) -> Result<bool, XApiError> {
+        self.record("follow", user_id, target);
+        Ok(true)
+    }
+}
+
+async fn approved_item(pool: &DbPool, action_type: &str, target: &str) -> ApprovalItem {
+    let id = storage::approval_queue::enqueue(pool, action_type, target, "", "", "", "", 0.0, "[]")
+        .await
+        .expect("enqueue");
+    storage::approval_queue::get_by_id(pool, id)
+        .await
+        .expect("get")
+        .expect("item")
+}
+
+#[tokio::test]
+async fn engage_item_likes_and_follows_target() {
+    let pool = init_test_db().await.expect("init db");
+    let client = EngageClient::default();
+
+    let like = approved_item(&pool, "like", "t1").await;
+    let follow = approved_item(&pool, "follow", "u2").await;
+    assert_eq!(
+        engage_item(&pool, None, &client, &like).await.unwrap(),
+        "t1"
+    );
+    assert_eq!(
+        engage_item(&pool, None, &client, &follow).await.unwrap(),
+        "u2"
+    );
+
+    let calls = client.calls.lock().expect("lock").clone();
+    assert_eq!(
+        calls,
+        vec![
+            ("like".to_string(), "me".to_string(), "t1".to_string()),
+            ("follow".to_string(), "me".to_string(), "u2".to_string()),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn engage_item_respects_daily_limit() {
+    let pool = init_test_db().await.expect("init db");
+    let limits = LimitsConfig {
+        max_likes_per_day: 1,
+        ..LimitsConfig::default()
+    };
+    storage::rate_limits::init_rate_limits(&pool, &limits, &IntervalsConfig::default())
+        .await
+        .expect("init rate limits");
+    let client = EngageClient::default();
+
+    let first = approved_item(&pool, "like", "t1").await;
+    let second = approved_item(&pool, "like", "t2").await;
+    assert!(engage_item(&pool, None, &client, &first).await.is_ok());
+    let err = engage_item(&pool, None, &client, &second)
+        .await
+        .unwrap_err();
+    assert_eq!(err, "daily like limit reached");
+    assert_eq!(client.calls.lock().expect("lock").len(), 1);
+}
+
+#[tokio::test]
+async fn protected_target_is_rejected_before_posting() {
+    let pool = init_test_db().await.expect("init db");
+    let id = storage::approval_queue::enqueue(
+        &pool,
+        "reply",
+        "t1",
+        "@Reporter",
+        "Hi",
+        "",
+        "",
+        0.0,
+        "[]",
+    )
+    .await
+    .expect("enqueue");
+    let item = storage::approval_queue::get_by_id(&pool, id)
+        .await
+        .expect("get")
+        .expect("item");
+    let protected = protected::normalize_protected(&["reporter".to_string()]);
+    let writes = WriteQueue::spawn(pool.clone());
+
+    let other = approved_item(&pool, "like", "t2").await;
+    assert!(!refuse_protected_target(&pool, Some(&writes), &protected, &other).await);
+    assert!(refuse_protected_target(&pool, Some(&writes), &protected, &item).await);
+
+    let item = storage::approval_queue::get_by_id(&pool, id)
+        .await
+        .expect("get")
+        .expect("item");
+    assert_eq!(item.status, "rejected");
+    assert_eq!(item.reviewed_by.as_deref(), Some(SAFETY_ACTOR));
+    // The rejection and its log entry both went through the queue.
+    let stats = writes.stats();
+    assert_eq!((stats.writes_total, stats.log_rows), (2, 1));
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn pre_post_hook_denial_rejects_item() {
+    let pool = init_test_db().await.expect("init db");
+    let item = approved_item(&pool, "reply", "t1").await;
+    storage::approval_queue::update_status(&pool, item.id, "approved")
+        .await
+        .expect("approve");
+    let item = storage::approval_queue::get_by_id(&pool, item.id)
+        .await
+        .expect("get")
+        .expect("item");
+
+    let hooks = HookRunner::new(crate::config::HooksConfig {
+        scripts: vec![crate::config::HookConfig {
+            name: "policy".to_string(),
+            event: "pre_post".to_string(),
+            command: "/bin/sh".to_string(),
+            args: vec!["-c".to_string(), "echo 'off-brand' >&2; exit 1".to_string()],
+            timeout_secs: 5,
+            fail_open: false,
+            inherit_env: false,
+            working_dir: None,
+        }],
+        ..Default::default()
+    });
+    assert!(run_pre_post_hooks(&pool, None, &hooks, item.clone())
+        .await
+        .is_none());
+
+    let item = storage::approval_queue::get_by_id(&pool, item.id)
+        .await
+        .expect("get")
+        .expect("item");
+    assert_eq!(item.status, "rejected");
+    assert_eq!(item.reviewed_by.as_deref(), Some(HOOK_EDITOR));
+    assert_eq!(
+        item.review_notes.as_deref(),
+        Some("Denied by hook policy: off-brand")
+    );
+}
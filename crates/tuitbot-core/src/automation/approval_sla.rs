@@ -0,0 +1,291 @@
+//! Approval SLA: reminders and escalation for items left pending too long.
+//!
+//! With `[approval_sla]` enabled, an item pending longer than `hours`
+//! (counting only active hours by default) breaches the SLA. Each breach
+//! raises the item's priority to the number of SLA periods it has been
+//! waiting, which sorts it to the top of the queue, and is returned as an
+//! [`SlaBreach`] so the caller can send a reminder. An item is reported
+//! once per period. [`get_sla_compliance_for`] summarizes, per reviewer,
+//! how many decisions landed within the SLA.
+
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::automation::schedule::ActiveSchedule;
+use crate::config::Config;
+use crate::error::StorageError;
+use crate::storage::analytics_range::AnalyticsRange;
+use crate::storage::approval_queue::sla::{self, ReviewTime};
+use crate::storage::DbPool;
+
+/// A pending item that just passed another SLA period.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SlaBreach {
+    pub id: i64,
+    pub account_id: String,
+    pub action_type: String,
+    /// New priority: the number of SLA periods the item has waited.
+    pub priority: i64,
+    /// Counted hours pending (active hours only, if configured).
+    pub pending_hours: f64,
+}
+
+/// SLA compliance for one reviewer.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ReviewerSla {
+    pub reviewer: String,
+    pub reviewed: i64,
+    pub within_sla: i64,
+    /// Share of decisions made within the SLA (0.0–1.0).
+    pub compliance_rate: f64,
+    /// Mean counted hours from enqueue to decision.
+    pub avg_response_hours: f64,
+}
+
+/// SLA compliance for a range, overall and per reviewer.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SlaCompliance {
+    pub sla_hours: u32,
+    pub active_hours_only: bool,
+    pub reviewed: i64,
+    pub within_sla: i64,
+    pub compliance_rate: f64,
+    pub reviewers: Vec<ReviewerSla>,
+}
+
+/// Measures how long items wait under the configured SLA.
+struct SlaClock {
+    sla: Duration,
+    schedule: Option<ActiveSchedule>,
+}
+
+impl SlaClock {
+    fn new(config: &Config) -> Self {
+        let schedule = if config.approval_sla.active_hours_only {
+            ActiveSchedule::from_config(&config.schedule)
+        } else {
+            None
+        };
+        Self {
+            sla: Duration::from_secs(u64::from(config.approval_sla.hours.max(1)) * 3600),
+            schedule,
+        }
+    }
+
+    /// Counted wait between two Unix timestamps.
+    fn elapsed(&self, from_epoch: i64, to: DateTime<Utc>) -> Duration {
+        let from = DateTime::from_timestamp(from_epoch, 0).unwrap_or(to);
+        match &self.schedule {
+            Some(schedule) => schedule.active_time_between(from, to),
+            None => (to - from).to_std().unwrap_or_default(),
+        }
+    }
+
+    /// Whole SLA periods in `elapsed`.
+    fn periods(&self, elapsed: Duration) -> i64 {
+        (elapsed.as_secs() / self.sla.as_secs()) as i64
+    }
+}
+
+/// Escalate pending items that passed another SLA period since the last
+/// check. Returns the items to send reminders for; empty when the SLA is
+/// disabled.
+pub async fn escalate_breaches(
+    pool: &DbPool,
+    config: &Config,
+    now: DateTime<Utc>,
+) -> Result<Vec<SlaBreach>, StorageError> {
+    if !config.approval_sla.enabled {
+        return Ok(Vec::new());
+    }
+    let clock = SlaClock::new(config);
+    let mut breaches = Vec::new();
+    for item in sla::get_pending_ages(pool).await? {
+        let elapsed = clock.elapsed(item.created_epoch, now);
+        let priority = clock.periods(elapsed);
+        if priority <= item.priority || !sla::raise_priority(pool, item.id, priority).await? {
+            continue;
+        }
+        tracing::info!(
+            id = item.id,
+            priority,
+            pending_hours = elapsed.as_secs() / 3600,
+            "Approval item breached SLA, escalated"
+        );
+        breaches.push(SlaBreach {
+            id: item.id,
+            account_id: item.account_id,
+            action_type: item.action_type,
+            priority,
+            pending_hours: elapsed.as_secs_f64() / 3600.0,
+        });
+    }
+    Ok(breaches)
+}
+
+/// SLA compliance for items enqueued in `range` for an account.
+pub async fn get_sla_compliance_for(
+    pool: &DbPool,
+    account_id: &str,
+    range: &AnalyticsRange,
+    config: &Config,
+) -> Result<SlaCompliance, StorageError> {
+    let reviews = sla::get_review_times_for(pool, account_id, range).await?;
+    Ok(compliance(&reviews, config))
+}
+
+fn compliance(reviews: &[ReviewTime], config: &Config) -> SlaCompliance {
+    let clock = SlaClock::new(config);
+    // reviewer -> (reviewed, within, total seconds)
+    let mut by_reviewer: BTreeMap<&str, (i64, i64, u64)> = BTreeMap::new();
+    for review in reviews {
+        let reviewed_at = DateTime::from_timestamp(review.reviewed_epoch, 0).unwrap_or_default();
+        let elapsed = clock.elapsed(review.created_epoch, reviewed_at);
+        let entry = by_reviewer.entry(&review.reviewer).or_default();
+        entry.0 += 1;
+        entry.1 += i64::from(elapsed <= clock.sla);
+        entry.2 += elapsed.as_secs();
+    }
+
+    let mut reviewers: Vec<ReviewerSla> = by_reviewer
+        .into_iter()
+        .map(|(reviewer, (reviewed, within_sla, secs))| ReviewerSla {
+            reviewer: reviewer.to_string(),
+            reviewed,
+            within_sla,
+            compliance_rate: ratio(within_sla, reviewed),
+            avg_response_hours: secs as f64 / reviewed as f64 / 3600.0,
+        })
+        .collect();
+    reviewers.sort_by_key(|r| std::cmp::Reverse(r.reviewed));
+
+    let reviewed = reviewers.iter().map(|r| r.reviewed).sum();
+    let within_sla = reviewers.iter().map(|r| r.within_sla).sum();
+    SlaCompliance {
+        sla_hours: config.approval_sla.hours,
+        active_hours_only: config.approval_sla.active_hours_only,
+        reviewed,
+        within_sla,
+        compliance_rate: ratio(within_sla, reviewed),
+        reviewers,
+    }
+}
+
+fn ratio(part: i64, whole: i64) -> f64 {
+    if whole > 0 {
+        part as f64 / whole as f64
+    } else {
+        0.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::ApprovalSlaConfig;
+    use crate::storage::{approval_queue, init_test_db};
+
+    fn config(active_hours_only: bool) -> Config {
+        let mut config = Config {
+            approval_sla: ApprovalSlaConfig {
+                enabled: true,
+                hours: 4,
+                active_hours_only,
+            },
+            ..Config::default()
+        };
+        config.schedule.timezone = "UTC".to_string();
+        config.schedule.active_hours_start = 8;
+        config.schedule.active_hours_end = 22;
+        config
+    }
+
+    fn utc(s: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(s).unwrap().with_timezone(&Utc)
+    }
+
+    async fn pending_at(pool: &DbPool, created_at: &str) -> i64 {
+        let id = approval_queue::enqueue(
+            pool, "reply", created_at, "alice", created_at, "", "", 50.0, "[]",
+        )
+        .await
+        .unwrap();
+        sqlx::query("UPDATE approval_queue SET created_at = ? WHERE id = ?")
+            .bind(created_at)
+            .bind(id)
+            .execute(pool)
+            .await
+            .unwrap();
+        id
+    }
+
+    #[tokio::test]
+    async fn escalates_once_per_period() {
+        let pool = init_test_db().await.unwrap();
+        let old = pending_at(&pool, "2026-03-02T09:00:00Z").await;
+        let fresh = pending_at(&pool, "2026-03-02T17:00:00Z").await;
+        let config = config(true);
+
+        let breaches = escalate_breaches(&pool, &config, utc("2026-03-02T18:00:00Z"))
+            .await
+            .unwrap();
+        assert_eq!(breaches.len(), 1);
+        assert_eq!((breaches[0].id, breaches[0].priority), (old, 2));
+
+        // Same period: no repeat reminder.
+        let again = escalate_breaches(&pool, &config, utc("2026-03-02T18:30:00Z"))
+            .await
+            .unwrap();
+        assert!(again.is_empty());
+
+        // Overnight hours don't count: 17:00-22:00 plus 08:00-08:30 is 5.5h.
+        let next_day = escalate_breaches(&pool, &config, utc("2026-03-03T08:30:00Z"))
+            .await
+            .unwrap();
+        let ids: Vec<i64> = next_day.iter().map(|b| b.id).collect();
+        assert_eq!(ids, vec![old, fresh]);
+
+        let pending = approval_queue::get_pending(&pool).await.unwrap();
+        assert_eq!(pending[0].id, old);
+        assert_eq!(pending[0].priority, 3);
+    }
+
+    #[tokio::test]
+    async fn disabled_sla_does_nothing() {
+        let pool = init_test_db().await.unwrap();
+        pending_at(&pool, "2026-03-01T00:00:00Z").await;
+        let config = Config::default();
+        let breaches = escalate_breaches(&pool, &config, utc("2026-03-05T00:00:00Z"))
+            .await
+            .unwrap();
+        assert!(breaches.is_empty());
+    }
+
+    #[test]
+    fn compliance_per_reviewer() {
+        let at = |s: &str| utc(s).timestamp();
+        let review = |reviewer: &str, from: &str, to: &str| ReviewTime {
+            reviewer: reviewer.to_string(),
+            created_epoch: at(from),
+            reviewed_epoch: at(to),
+        };
+        let reviews = [
+            review("ana", "2026-03-02T09:00:00Z", "2026-03-02T10:00:00Z"),
+            review("ana", "2026-03-02T09:00:00Z", "2026-03-02T15:00:00Z"),
+            review("bo", "2026-03-02T21:00:00Z", "2026-03-03T09:00:00Z"),
+        ];
+
+        let stats = compliance(&reviews, &config(true));
+        assert_eq!((stats.reviewed, stats.within_sla), (3, 2));
+        assert_eq!(stats.reviewers[0].reviewer, "ana");
+        assert_eq!(stats.reviewers[0].compliance_rate, 0.5);
+        assert_eq!(stats.reviewers[1].avg_response_hours, 2.0);
+
+        // Wall-clock time: bo's 12h overnight wait breaches.
+        let stats = compliance(&reviews, &config(false));
+        assert_eq!(stats.within_sla, 1);
+    }
+}
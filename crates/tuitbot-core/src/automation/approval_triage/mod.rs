@@ -0,0 +1,353 @@
+//! Approval triage loop: auto-approves queued items that clear strict rules.
+//!
+//! When `[auto_triage]` is enabled, pending items are checked as they enter
+//! the approval queue. An item is approved without review only when every
+//! rule in [`AutoTriageConfig`] passes and, if configured, the LLM agrees.
+//! Everything else stays pending for a human. Auto-approvals are recorded
+//! with the `auto-triage` reviewer and an `approval_auto_approved` action log
+//! entry, and are capped per 24 hours. Nothing is approved while the kill
+//! switch is engaged. Items the rules would approve still pass through the
+//! `pre_approve` hooks, which can hold or rewrite them; rewritten text is
+//! triaged again before it is approved.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio_util::sync::CancellationToken;
+
+use crate::config::{AutoTriageConfig, BusinessProfile, Config};
+use crate::hooks::{HookEvent, HookGate, HookRunner};
+use crate::llm::{GenerationParams, LlmProvider};
+use crate::storage::accounts::DEFAULT_ACCOUNT_ID;
+use crate::storage::approval_queue::{self, ApprovalItem, ReviewAction, PLATFORM_X};
+use crate::storage::{self, DbPool};
+use crate::workflow::draft_workspace::{evaluate_draft, store_approval_check, DraftCheck};
+
+/// Reviewer recorded on auto-approved items.
+pub const AUTO_TRIAGE_ACTOR: &str = "auto-triage";
+
+/// Action log type for auto-approvals, also used for the daily cap.
+pub const AUTO_APPROVED_ACTION: &str = "approval_auto_approved";
+
+/// Outcome of triaging one item.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TriageDecision {
+    /// Approve, with the review note explaining why.
+    Approve(String),
+    /// Leave pending, with the reason.
+    Hold(String),
+}
+
+/// Run the approval triage loop until cancellation.
+///
+/// Pending items are checked every 30 seconds. An item that is held is not
+/// checked again until it is edited.
+pub async fn run_approval_triage(
+    pool: DbPool,
+    config: Config,
+    llm: Option<Arc<dyn LlmProvider>>,
+    cancel: CancellationToken,
+) {
+    tracing::info!(
+        max_per_day = config.auto_triage.max_per_day,
+        min_score = config.auto_triage.min_score,
+        "Approval triage loop started"
+    );
+
+    let interval = Duration::from_secs(30);
+    let mut held: HashSet<(i64, i64)> = HashSet::new();
+
+    loop {
+        tokio::select! {
+            biased;
+            () = cancel.cancelled() => break,
+            () = tokio::time::sleep(interval) => {}
+        }
+
+        let halted = storage::kill_switch::is_kill_switch_active(&pool)
+            .await
+            .unwrap_or(true);
+        if halted {
+            continue;
+        }
+
+        if let Err(e) = triage_pending(&pool, &config, llm.as_deref(), &mut held).await {
+            tracing::warn!(error = %e, "Approval triage failed");
+        }
+    }
+
+    tracing::info!("Approval triage loop stopped");
+}
+
+/// Triage every pending item not already held. Returns how many were
+/// approved.
+pub async fn triage_pending(
+    pool: &DbPool,
+    config: &Config,
+    llm: Option<&dyn LlmProvider>,
+    held: &mut HashSet<(i64, i64)>,
+) -> Result<usize, crate::error::StorageError> {
+    let triage = &config.auto_triage;
+    let since = (chrono::Utc::now() - chrono::Duration::hours(24))
+        .format("%Y-%m-%dT%H:%M:%SZ")
+        .to_string();
+    let used = storage::action_log::get_action_counts_since(pool, &since)
+        .await?
+        .get(AUTO_APPROVED_ACTION)
+        .copied()
+        .unwrap_or(0);
+    let mut remaining = i64::from(triage.max_per_day) - used;
+    if remaining <= 0 {
+        return Ok(0);
+    }
+
+    let hooks = HookRunner::new(config.hooks.clone()).with_pool(pool.clone());
+    let mut approved = 0;
+    for item in approval_queue::get_pending(pool).await? {
+        if remaining <= 0 {
+            tracing::info!(
+                max_per_day = triage.max_per_day,
+                "Auto-triage daily cap reached"
+            );
+            break;
+        }
+        if held.contains(&(item.id, item.version)) {
+            continue;
+        }
+
+        let Some(decision) = decide(pool, config, llm, &item, false).await? else {
+            // Retry on the next tick; the provider may be down.
+            continue;
+        };
+
+        match decision {
+            TriageDecision::Hold(reason) => {
+                tracing::debug!(id = item.id, reason = %reason, "Auto-triage left item pending");
+                held.insert((item.id, item.version));
+            }
+            TriageDecision::Approve(notes) => {
+                let (item, notes) =
+                    match hooks.gate_item(HookEvent::PreApprove, pool, &item).await? {
+                        HookGate::Allow => (item, notes),
+                        // The rewritten text gets the full triage again at its new version.
+                        HookGate::Modify(_) => {
+                            let Some(item) = approval_queue::get_by_id(pool, item.id).await? else {
+                                continue;
+                            };
+                            match decide(pool, config, llm, &item, true).await? {
+                                Some(TriageDecision::Approve(notes)) => (item, notes),
+                                Some(TriageDecision::Hold(reason)) => {
+                                    tracing::debug!(
+                                        id = item.id,
+                                        reason = %reason,
+                                        "Auto-triage left rewritten item pending"
+                                    );
+                                    held.insert((item.id, item.version));
+                                    continue;
+                                }
+                                None => continue,
+                            }
+                        }
+                        HookGate::Deny { hook, reason } => {
+                            tracing::debug!(
+                                id = item.id,
+                                hook = %hook,
+                                reason = %reason,
+                                "Hook kept item pending"
+                            );
+                            held.insert((item.id, item.version));
+                            continue;
+                        }
+                    };
+                if approve(pool, &item, &notes).await? {
+                    approved += 1;
+                    remaining -= 1;
+                }
+            }
+        }
+    }
+    Ok(approved)
+}
+
+/// QA, rule, and LLM verdict for `item`. `None` when the LLM check could
+/// not run, so the item should be retried on the next tick.
+///
+/// The QA result is stored on the item when it has none yet, or always when
+/// `refresh_qa` is set because the text changed since it was queued.
+async fn decide(
+    pool: &DbPool,
+    config: &Config,
+    llm: Option<&dyn LlmProvider>,
+    item: &ApprovalItem,
+    refresh_qa: bool,
+) -> Result<Option<TriageDecision>, crate::error::StorageError> {
+    let triage = &config.auto_triage;
+    let qa = evaluate_draft(config, &item.action_type, &item.generated_content);
+    if refresh_qa || item.qa_report.trim() == "{}" || item.qa_report.is_empty() {
+        store_approval_check(pool, DEFAULT_ACCOUNT_ID, item.id, &qa).await?;
+    }
+
+    Ok(
+        match rule_hold_reason(triage, &config.business, item, &qa) {
+            Some(reason) => Some(TriageDecision::Hold(reason)),
+            None if triage.llm_check => match llm {
+                Some(llm) => llm_decision(llm, config, item).await,
+                None => Some(TriageDecision::Hold(
+                    "no LLM provider for the final check".to_string(),
+                )),
+            },
+            None => Some(TriageDecision::Approve(format!(
+                "Auto-approved: score {:.0}",
+                item.score
+            ))),
+        },
+    )
+}
+
+/// Why the rules keep `item` pending, or `None` when every rule passes.
+pub fn rule_hold_reason(
+    triage: &AutoTriageConfig,
+    business: &BusinessProfile,
+    item: &ApprovalItem,
+    qa: &DraftCheck,
+) -> Option<String> {
+    if !triage.action_types.iter().any(|t| t == &item.action_type) {
+        return Some(format!("{} items are not auto-triaged", item.action_type));
+    }
+    if item.platform != PLATFORM_X {
+        return Some(format!("{} items are not auto-triaged", item.platform));
+    }
+    if item.score < triage.min_score {
+        return Some(format!(
+            "score {:.0} is below {:.0}",
+            item.score, triage.min_score
+        ));
+    }
+    let stored_flags = [&item.qa_hard_flags, &item.qa_soft_flags]
+        .iter()
+        .any(|flags| !json_list_is_empty(flags));
+    if !qa.hard_flags.is_empty() || !qa.soft_flags.is_empty() || stored_flags {
+        return Some("QA flags raised".to_string());
+    }
+    if !json_list_is_empty(&item.detected_risks) {
+        return Some("risks detected at generation".to_string());
+    }
+    if triage.is_sensitive_account(&item.target_author) {
+        return Some(format!("@{} is a sensitive account", item.target_author));
+    }
+    let text = format!("{}\n{}", item.generated_content, item.topic);
+    if let Some(keyword) = triage.sensitive_keyword_in(&text) {
+        return Some(format!("mentions sensitive keyword \"{keyword}\""));
+    }
+    match business.pillar_override(&text) {
+        Some((pillar, o)) if o.require_approval => {
+            Some(format!("topic \"{pillar}\" requires approval"))
+        }
+        _ => None,
+    }
+}
+
+/// Ask the LLM whether `item` is safe to post unreviewed. `None` when the
+/// provider fails.
+async fn llm_decision(
+    llm: &dyn LlmProvider,
+    config: &Config,
+    item: &ApprovalItem,
+) -> Option<TriageDecision> {
+    let system = format!(
+        "You review social media posts for {} before they go out without human review. \
+         Answer APPROVE only if the post is on-topic, friendly, accurate, not controversial, \
+         and not pushy about the product. Otherwise answer HOLD followed by a short reason. \
+         When in doubt, answer HOLD.",
+        config.business.product_name
+    );
+    let target = if item.target_author.is_empty() {
+        String::new()
+    } else {
+        format!("Replying to @{}. ", item.target_author)
+    };
+    let user_message = format!(
+        "{target}Topic: {}\n\nPost:\n{}",
+        item.topic, item.generated_content
+    );
+    let params = GenerationParams {
+        max_tokens: 60,
+        temperature: 0.0,
+        ..Default::default()
+    };
+
+    match llm.complete(&system, &user_message, &params).await {
+        Ok(resp) => Some(parse_llm_verdict(&resp.text, item.score)),
+        Err(e) => {
+            tracing::warn!(id = item.id, error = %e, "Auto-triage LLM check failed");
+            None
+        }
+    }
+}
+
+/// Parse an `APPROVE` / `HOLD <reason>` answer. Anything else holds.
+fn parse_llm_verdict(text: &str, score: f64) -> TriageDecision {
+    let text = text.trim();
+    if text.to_uppercase().starts_with("APPROVE") {
+        return TriageDecision::Approve(format!(
+            "Auto-approved: score {score:.0}, QA clean, LLM check passed"
+        ));
+    }
+    let reason = text
+        .strip_prefix("HOLD")
+        .unwrap_or(text)
+        .trim_start_matches([':', ' ', '-'])
+        .trim();
+    TriageDecision::Hold(if reason.is_empty() {
+        "LLM check did not approve".to_string()
+    } else {
+        format!("LLM: {reason}")
+    })
+}
+
+/// Approve `item` at the version that was checked. Returns `false` when it
+/// changed or was reviewed in the meantime.
+async fn approve(
+    pool: &DbPool,
+    item: &ApprovalItem,
+    notes: &str,
+) -> Result<bool, crate::error::StorageError> {
+    let review = ReviewAction {
+        actor: Some(AUTO_TRIAGE_ACTOR.to_string()),
+        notes: Some(notes.to_string()),
+        expected_version: Some(item.version),
+    };
+    match approval_queue::update_status_with_review(pool, item.id, "approved", &review).await {
+        Ok(()) => {}
+        Err(crate::error::StorageError::Conflict { .. }) => return Ok(false),
+        Err(e) => return Err(e),
+    }
+
+    let metadata = serde_json::json!({
+        "approval_id": item.id,
+        "actor": AUTO_TRIAGE_ACTOR,
+        "action_type": item.action_type,
+        "score": item.score,
+    });
+    storage::action_log::log_action(
+        pool,
+        AUTO_APPROVED_ACTION,
+        "success",
+        Some(&format!(
+            "Auto-approved {} item {}",
+            item.action_type, item.id
+        )),
+        Some(&metadata.to_string()),
+    )
+    .await?;
+    tracing::info!(id = item.id, action_type = %item.action_type, "Auto-approved queued item");
+    Ok(true)
+}
+
+fn json_list_is_empty(json: &str) -> bool {
+    serde_json::from_str::<Vec<serde_json::Value>>(json).map_or(true, |list| list.is_empty())
+}
+
+#[cfg(test)]
+mod tests;
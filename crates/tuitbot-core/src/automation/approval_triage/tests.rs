@@ -0,0 +1,210 @@
+use super::*;
+use crate::error::LlmError;
+use crate::llm::LlmResponse;
+use crate::storage::init_test_db;
+
+struct VerdictLlm(&'static str);
+
+#[async_trait::async_trait]
+impl LlmProvider for VerdictLlm {
+    fn name(&self) -> &str {
+        "mock"
+    }
+
+    async fn complete(
+        &self,
+        _system: &str,
+        _user_message: &str,
+        _params: &GenerationParams,
+    ) -> Result<LlmResponse, LlmError> {
+        Ok(LlmResponse {
+            text: self.0.to_string(),
+            usage: crate::llm::TokenUsage::default(),
+            model: "mock".to_string(),
+        })
+    }
+
+    async fn health_check(&self) -> Result<(), LlmError> {
+        Ok(())
+    }
+}
+
+fn triage_config(max_per_day: u32) -> Config {
+    Config {
+        auto_triage: AutoTriageConfig {
+            enabled: true,
+            max_per_day,
+            sensitive_accounts: vec!["@touchy".to_string()],
+            ..AutoTriageConfig::default()
+        },
+        ..Config::default()
+    }
+}
+
+async fn queue_reply(pool: &DbPool, target: &str, author: &str, score: f64) -> i64 {
+    approval_queue::enqueue(
+        pool,
+        "reply",
+        target,
+        author,
+        &format!("@{author} profiling before optimizing saved us weeks ({target})."),
+        "rust",
+        "agree_and_expand",
+        score,
+        "[]",
+    )
+    .await
+    .expect("enqueue")
+}
+
+#[tokio::test]
+async fn approves_only_items_that_clear_every_rule() {
+    let pool = init_test_db().await.expect("init db");
+    let good = queue_reply(&pool, "1", "alice", 92.0).await;
+    let low = queue_reply(&pool, "2", "bob", 60.0).await;
+    let sensitive = queue_reply(&pool, "3", "Touchy", 95.0).await;
+
+    let config = triage_config(5);
+    let mut held = HashSet::new();
+    let approved = triage_pending(&pool, &config, Some(&VerdictLlm("APPROVE")), &mut held)
+        .await
+        .expect("triage");
+    assert_eq!(approved, 1);
+
+    let item = approval_queue::get_by_id(&pool, good)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(item.status, "approved");
+    assert_eq!(item.reviewed_by.as_deref(), Some(AUTO_TRIAGE_ACTOR));
+    for id in [low, sensitive] {
+        let item = approval_queue::get_by_id(&pool, id).await.unwrap().unwrap();
+        assert_eq!(item.status, "pending");
+    }
+    assert_eq!(held.len(), 2);
+}
+
+#[tokio::test]
+async fn pillars_requiring_approval_are_never_auto_approved() {
+    let pool = init_test_db().await.expect("init db");
+    let id = queue_reply(&pool, "1", "alice", 92.0).await;
+
+    let mut config = triage_config(5);
+    config.business.pillar_overrides.insert(
+        "Rust".to_string(),
+        crate::config::PillarOverride {
+            require_approval: true,
+            ..Default::default()
+        },
+    );
+    let mut held = HashSet::new();
+    let approved = triage_pending(&pool, &config, Some(&VerdictLlm("APPROVE")), &mut held)
+        .await
+        .expect("triage");
+    assert_eq!(approved, 0);
+    let item = approval_queue::get_by_id(&pool, id).await.unwrap().unwrap();
+    assert_eq!(item.status, "pending");
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn pre_approve_hooks_hold_or_rewrite_items() {
+    let pool = init_test_db().await.expect("init db");
+    let denied = queue_reply(&pool, "1", "alice", 92.0).await;
+    let rewritten = queue_reply(&pool, "2", "bob", 92.0).await;
+    let flagged = queue_reply(&pool, "3", "carol", 92.0).await;
+
+    // Deny the reply to tweet 1, rewrite tweet 3 into text the rules
+    // hold, and rewrite everything else into clean text.
+    let script = r#"input=$(cat); case "$input" in *'"target_id":"1"'*) exit 1;; *'"target_id":"3"'*) echo '{"verdict":"modify","content":"Ask me about the lawsuit."}';; *) echo '{"verdict":"modify","content":"Rewritten by policy."}';; esac"#;
+    let mut config = triage_config(5);
+    config.auto_triage.sensitive_keywords = vec!["lawsuit".to_string()];
+    config.hooks.scripts.push(crate::config::HookConfig {
+        name: "policy".to_string(),
+        event: "pre_approve".to_string(),
+        command: "/bin/sh".to_string(),
+        args: vec!["-c".to_string(), script.to_string()],
+        timeout_secs: 5,
+        fail_open: false,
+        inherit_env: false,
+        working_dir: None,
+    });
+
+    let mut held = HashSet::new();
+    let approved = triage_pending(&pool, &config, Some(&VerdictLlm("APPROVE")), &mut held)
+        .await
+        .expect("triage");
+    assert_eq!(approved, 1);
+
+    let item = approval_queue::get_by_id(&pool, denied)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(item.status, "pending");
+    let item = approval_queue::get_by_id(&pool, rewritten)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(item.status, "approved");
+    assert_eq!(item.generated_content, "Rewritten by policy.");
+    let item = approval_queue::get_by_id(&pool, flagged)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(item.status, "pending");
+    assert_eq!(item.generated_content, "Ask me about the lawsuit.");
+}
+
+#[tokio::test]
+async fn llm_hold_and_daily_cap_keep_items_pending() {
+    let pool = init_test_db().await.expect("init db");
+    let first = queue_reply(&pool, "1", "alice", 92.0).await;
+    let config = triage_config(1);
+
+    let mut held = HashSet::new();
+    let verdict = VerdictLlm("HOLD: sounds salesy");
+    assert_eq!(
+        triage_pending(&pool, &config, Some(&verdict), &mut held)
+            .await
+            .unwrap(),
+        0
+    );
+    let item = approval_queue::get_by_id(&pool, first)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(item.status, "pending");
+
+    queue_reply(&pool, "2", "bob", 92.0).await;
+    queue_reply(&pool, "3", "carol", 92.0).await;
+    let approve = VerdictLlm("APPROVE");
+    assert_eq!(
+        triage_pending(&pool, &config, Some(&approve), &mut held)
+            .await
+            .unwrap(),
+        1
+    );
+    assert_eq!(
+        triage_pending(&pool, &config, Some(&approve), &mut held)
+            .await
+            .unwrap(),
+        0,
+        "cap of one per day"
+    );
+}
+
+#[test]
+fn parses_llm_verdicts() {
+    assert!(matches!(
+        parse_llm_verdict(" approve.", 90.0),
+        TriageDecision::Approve(_)
+    ));
+    assert_eq!(
+        parse_llm_verdict("HOLD: off-topic", 90.0),
+        TriageDecision::Hold("LLM: off-topic".to_string())
+    );
+    assert!(matches!(
+        parse_llm_verdict("maybe", 90.0),
+        TriageDecision::Hold(_)
+    ));
+}
@@ -0,0 +1,265 @@
+//! Author blocklist sync.
+//!
+//! Mirrors the account's X mutes and blocks into the local blocklist so the
+//! safety checks can skip those authors without an API call per tweet.
+//! Usernames from `[blocklist].authors` are written alongside as manual
+//! entries. A source that fails to fetch (for example when the token lacks
+//! `mute.read` or `block.read`) keeps its previous entries.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio_util::sync::CancellationToken;
+
+use crate::config::BlocklistConfig;
+use crate::error::{StorageError, XApiError};
+use crate::storage::blocklist::{self, SOURCE_MANUAL, SOURCE_X_BLOCK, SOURCE_X_MUTE};
+use crate::storage::DbPool;
+use crate::x_api::types::UsersResponse;
+use crate::x_api::XApiClient;
+
+/// Page size for the mutes and blocks lookups (the X API maximum).
+const PAGE_SIZE: u32 = 1000;
+
+/// Pages fetched per source before stopping.
+const MAX_PAGES: usize = 20;
+
+/// Outcome of one sync. A count is `None` when that source failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlocklistSyncReport {
+    pub muted: Option<usize>,
+    pub blocked: Option<usize>,
+}
+
+/// Replace the manual blocklist entries for a specific account with
+/// `config.authors`.
+pub async fn sync_manual_blocklist_for(
+    pool: &DbPool,
+    account_id: &str,
+    config: &BlocklistConfig,
+) -> Result<(), StorageError> {
+    let authors: Vec<(Option<String>, String)> =
+        config.authors.iter().map(|a| (None, a.clone())).collect();
+    blocklist::replace_source_for(pool, account_id, SOURCE_MANUAL, &authors).await
+}
+
+/// Fetch the X mutes and blocks of `user_id` and store them for a specific
+/// account.
+pub async fn sync_x_blocklist_for(
+    pool: &DbPool,
+    account_id: &str,
+    client: &dyn XApiClient,
+    user_id: &str,
+) -> Result<BlocklistSyncReport, StorageError> {
+    let muted = sync_source(pool, account_id, SOURCE_X_MUTE, |token| async move {
+        client
+            .get_muted_users(user_id, PAGE_SIZE, token.as_deref())
+            .await
+    })
+    .await?;
+    let blocked = sync_source(pool, account_id, SOURCE_X_BLOCK, |token| async move {
+        client
+            .get_blocked_users(user_id, PAGE_SIZE, token.as_deref())
+            .await
+    })
+    .await?;
+    Ok(BlocklistSyncReport { muted, blocked })
+}
+
+/// Page through one source and replace its entries. API failures are
+/// logged and leave the stored entries untouched.
+async fn sync_source<F, Fut>(
+    pool: &DbPool,
+    account_id: &str,
+    source: &str,
+    fetch: F,
+) -> Result<Option<usize>, StorageError>
+where
+    F: Fn(Option<String>) -> Fut,
+    Fut: std::future::Future<Output = Result<UsersResponse, XApiError>>,
+{
+    let mut authors = Vec::new();
+    let mut token = None;
+    for _ in 0..MAX_PAGES {
+        let page = match fetch(token.take()).await {
+            Ok(page) => page,
+            Err(e) => {
+                tracing::warn!(source, error = %e, "Blocklist sync failed; keeping previous entries");
+                return Ok(None);
+            }
+        };
+        authors.extend(page.data.into_iter().map(|u| (Some(u.id), u.username)));
+        match page.meta.next_token {
+            Some(next) => token = Some(next),
+            None => break,
+        }
+    }
+    blocklist::replace_source_for(pool, account_id, source, &authors).await?;
+    Ok(Some(authors.len()))
+}
+
+/// Sync the blocklist now and then every `config.sync_interval_seconds`.
+pub async fn run_blocklist_sync(
+    pool: DbPool,
+    account_id: String,
+    x_client: Arc<dyn XApiClient>,
+    config: BlocklistConfig,
+    cancel: CancellationToken,
+) {
+    tracing::info!(
+        interval_secs = config.sync_interval_seconds,
+        "Blocklist sync started"
+    );
+
+    let interval = Duration::from_secs(config.sync_interval_seconds);
+    let mut user_id: Option<String> = None;
+
+    loop {
+        if user_id.is_none() {
+            match x_client.get_me().await {
+                Ok(user) => user_id = Some(user.id),
+                Err(e) => tracing::warn!(error = %e, "Blocklist sync could not resolve account"),
+            }
+        }
+        if let Some(id) = user_id.as_deref() {
+            match sync_x_blocklist_for(&pool, &account_id, &*x_client, id).await {
+                Ok(report) => tracing::info!(
+                    muted = ?report.muted,
+                    blocked = ?report.blocked,
+                    "Blocklist synced from X"
+                ),
+                Err(e) => tracing::warn!(error = %e, "Failed to store synced blocklist"),
+            }
+        }
+
+        tokio::select! {
+            biased;
+            () = cancel.cancelled() => break,
+            () = tokio::time::sleep(interval) => {}
+        }
+    }
+
+    tracing::info!("Blocklist sync stopped");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::accounts::DEFAULT_ACCOUNT_ID;
+    use crate::storage::init_test_db;
+    use crate::x_api::types::*;
+
+    /// Two pages of mutes; blocks are denied.
+    struct MuteMock;
+
+    fn user(id: &str) -> User {
+        User {
+            id: id.to_string(),
+            username: format!("user_{id}"),
+            name: String::new(),
+            public_metrics: UserMetrics::default(),
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl XApiClient for MuteMock {
+        async fn search_tweets(
+            &self,
+            _: &str,
+            _: u32,
+            _: Option<&str>,
+            _: Option<&str>,
+        ) -> Result<SearchResponse, XApiError> {
+            unimplemented!()
+        }
+        async fn get_mentions(
+            &self,
+            _: &str,
+            _: Option<&str>,
+            _: Option<&str>,
+        ) -> Result<MentionResponse, XApiError> {
+            unimplemented!()
+        }
+        async fn post_tweet(&self, _: &str) -> Result<PostedTweet, XApiError> {
+            unimplemented!()
+        }
+        async fn reply_to_tweet(&self, _: &str, _: &str) -> Result<PostedTweet, XApiError> {
+            unimplemented!()
+        }
+        async fn get_tweet(&self, _: &str) -> Result<Tweet, XApiError> {
+            unimplemented!()
+        }
+        async fn get_me(&self) -> Result<User, XApiError> {
+            Ok(user("me"))
+        }
+        async fn get_user_tweets(
+            &self,
+            _: &str,
+            _: u32,
+            _: Option<&str>,
+        ) -> Result<SearchResponse, XApiError> {
+            unimplemented!()
+        }
+        async fn get_user_by_username(&self, _: &str) -> Result<User, XApiError> {
+            unimplemented!()
+        }
+        async fn get_muted_users(
+            &self,
+            _: &str,
+            _: u32,
+            pagination_token: Option<&str>,
+        ) -> Result<UsersResponse, XApiError> {
+            let (id, next_token) = match pagination_token {
+                None => ("1", Some("p2".to_string())),
+                Some(_) => ("2", None),
+            };
+            Ok(UsersResponse {
+                data: vec![user(id)],
+                meta: UsersMeta {
+                    result_count: 1,
+                    next_token,
+                },
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn sync_stores_all_pages_and_keeps_failed_source() {
+        let pool = init_test_db().await.expect("init db");
+        blocklist::replace_source(&pool, SOURCE_X_BLOCK, &[(None, "old".to_string())])
+            .await
+            .expect("seed");
+
+        let report = sync_x_blocklist_for(&pool, DEFAULT_ACCOUNT_ID, &MuteMock, "me")
+            .await
+            .expect("sync");
+        assert_eq!(
+            report,
+            BlocklistSyncReport {
+                muted: Some(2),
+                blocked: None
+            }
+        );
+        assert!(blocklist::find(&pool, "2", "")
+            .await
+            .expect("find")
+            .is_some());
+        assert!(blocklist::find(&pool, "", "old")
+            .await
+            .expect("find")
+            .is_some());
+
+        let config = BlocklistConfig {
+            authors: vec!["@Spammer".to_string()],
+            ..Default::default()
+        };
+        sync_manual_blocklist_for(&pool, DEFAULT_ACCOUNT_ID, &config)
+            .await
+            .expect("manual");
+        let hit = blocklist::find(&pool, "x", "spammer")
+            .await
+            .expect("find")
+            .expect("manual hit");
+        assert_eq!(hit.source, SOURCE_MANUAL);
+    }
+}
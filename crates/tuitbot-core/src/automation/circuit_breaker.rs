@@ -4,8 +4,13 @@
 //! trips from Closed → Open, pausing all mutations. After a cooldown
 //! period it transitions to HalfOpen, allowing a single probe mutation.
 //! A success resets to Closed; another failure re-opens.
+//!
+//! Independently of the breaker state, a delay multiplier lets other
+//! monitors (e.g. the account health monitor) slow posting down without
+//! pausing it entirely.
 
 use std::collections::VecDeque;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 
@@ -83,6 +88,8 @@ pub struct CircuitBreaker {
     rx: watch::Receiver<BreakerState>,
     error_threshold: u32,
     cooldown: Duration,
+    /// Posting delay multiplier, stored as `f64` bits (1.0 = normal pace).
+    delay_multiplier: AtomicU64,
 }
 
 struct BreakerInner {
@@ -109,6 +116,7 @@ impl CircuitBreaker {
             rx,
             error_threshold,
             cooldown,
+            delay_multiplier: AtomicU64::new(1.0f64.to_bits()),
         })
     }
 
@@ -227,6 +235,32 @@ impl CircuitBreaker {
         0
     }
 
+    /// Current posting delay multiplier (1.0 = normal pace).
+    pub fn delay_multiplier(&self) -> f64 {
+        f64::from_bits(self.delay_multiplier.load(Ordering::Relaxed))
+    }
+
+    /// Slow posting down by `factor` (clamped to at least 1.0).
+    pub fn set_delay_multiplier(&self, factor: f64) {
+        let factor = if factor.is_finite() {
+            factor.max(1.0)
+        } else {
+            1.0
+        };
+        let previous = f64::from_bits(
+            self.delay_multiplier
+                .swap(factor.to_bits(), Ordering::Relaxed),
+        );
+        if (previous - factor).abs() > f64::EPSILON {
+            tracing::info!(previous, factor, "Posting delay multiplier changed");
+        }
+    }
+
+    /// Scale a posting delay by the current multiplier.
+    pub fn scale_delay(&self, delay: Duration) -> Duration {
+        delay.mul_f64(self.delay_multiplier())
+    }
+
     /// Check if cooldown has expired and transition Open → HalfOpen.
     fn maybe_transition_to_half_open(&self, inner: &mut BreakerInner) {
         if inner.state == BreakerState::Open {
@@ -357,6 +391,27 @@ mod tests {
         assert!(remaining > 0 && remaining <= 10);
     }
 
+    #[test]
+    fn delay_multiplier_scales_and_clamps() {
+        let cb = CircuitBreaker::new(3, Duration::from_secs(60), Duration::from_secs(10));
+        assert_eq!(cb.delay_multiplier(), 1.0);
+        assert_eq!(
+            cb.scale_delay(Duration::from_secs(10)),
+            Duration::from_secs(10)
+        );
+
+        cb.set_delay_multiplier(3.0);
+        assert_eq!(
+            cb.scale_delay(Duration::from_secs(10)),
+            Duration::from_secs(30)
+        );
+
+        cb.set_delay_multiplier(0.2);
+        assert_eq!(cb.delay_multiplier(), 1.0);
+        cb.set_delay_multiplier(f64::NAN);
+        assert_eq!(cb.delay_multiplier(), 1.0);
+    }
+
     #[test]
     fn breaker_state_display() {
         assert_eq!(BreakerState::Closed.to_string(), "closed");
@@ -2,11 +2,16 @@
 //!
 //! Generates and posts original educational tweets on a configurable
 //! schedule, keeping the user's X account active with thought-leadership
-//! content. Rotates through configured topics to avoid repetition.
+//! content. Rotates through configured topics to avoid repetition, and can
+//! draw a share of topics from trending discovery clusters instead.
 
-use super::loop_helpers::{ContentSafety, ContentStorage, TopicScorer, TweetGenerator};
+use super::loop_helpers::{
+    ContentLoopError, ContentSafety, ContentStorage, TopicScorer, TrendSource, TweetGenerator,
+};
 use super::schedule::{apply_slot_jitter, schedule_gate, ActiveSchedule};
 use super::scheduler::LoopScheduler;
+use super::trending::{select_trend, TrendTopic};
+use crate::storage::threads::{INSPIRATION_CALENDAR, INSPIRATION_TREND};
 use rand::seq::SliceRandom;
 use rand::SeedableRng;
 use std::sync::Arc;
@@ -21,16 +26,28 @@ pub struct ContentLoop {
     safety: Arc<dyn ContentSafety>,
     storage: Arc<dyn ContentStorage>,
     topic_scorer: Option<Arc<dyn TopicScorer>>,
+    trending: Option<Trending>,
     topics: Vec<String>,
+    /// Pillars whose tweets go to the approval queue instead of posting.
+    review_pillars: Vec<String>,
     post_window_secs: u64,
     dry_run: bool,
 }
 
+/// Trend-inspired topic selection settings.
+struct Trending {
+    source: Arc<dyn TrendSource>,
+    pillars: Vec<String>,
+    share: f64,
+}
+
 /// Result of a content generation attempt.
 #[derive(Debug)]
 pub enum ContentResult {
     /// Tweet was posted (or would be in dry-run).
     Posted { topic: String, content: String },
+    /// Tweet was queued for approval because its pillar requires it.
+    Queued { topic: String, content: String },
     /// Skipped because not enough time has elapsed since last tweet.
     TooSoon { elapsed_secs: u64, window_secs: u64 },
     /// Skipped due to daily tweet rate limit.
@@ -56,7 +73,9 @@ impl ContentLoop {
             safety,
             storage,
             topic_scorer: None,
+            trending: None,
             topics,
+            review_pillars: Vec::new(),
             post_window_secs,
             dry_run,
         }
@@ -71,6 +90,32 @@ impl ContentLoop {
         self
     }
 
+    /// Draw a `share` (0.0-1.0) of posts from trending discovery clusters.
+    ///
+    /// A cluster is used only when it aligns with one of `pillars` (the
+    /// configured topics when empty); otherwise the loop falls back to its
+    /// regular topic rotation.
+    pub fn with_trending(
+        mut self,
+        source: Arc<dyn TrendSource>,
+        pillars: Vec<String>,
+        share: f64,
+    ) -> Self {
+        self.trending = Some(Trending {
+            source,
+            pillars,
+            share,
+        });
+        self
+    }
+
+    /// Queue tweets whose topic names one of `pillars` for approval
+    /// instead of posting them.
+    pub fn with_review_pillars(mut self, pillars: Vec<String>) -> Self {
+        self.review_pillars = pillars;
+        self
+    }
+
     /// Run the continuous content loop until cancellation.
     pub async fn run(
         &self,
@@ -202,6 +247,9 @@ impl ContentLoop {
                     "Content iteration: tweet posted"
                 );
             }
+            ContentResult::Queued { topic, .. } => {
+                tracing::info!(topic = %topic, "Content iteration: tweet queued for approval");
+            }
             ContentResult::TooSoon {
                 elapsed_secs,
                 window_secs,
@@ -241,13 +289,21 @@ impl ContentLoop {
             return ContentResult::RateLimited;
         }
 
+        // Try a trending topic for this share of posts
+        if let Some(result) = self.try_trend(rng).await {
+            return result;
+        }
+
         // Pick a topic using epsilon-greedy if scorer is available
         let topic = self.pick_topic_epsilon_greedy(recent_topics, rng).await;
 
-        let result = self.generate_and_post(&topic).await;
+        let result = self.generate_and_post(&topic, None).await;
 
         // Update recent_topics on success
-        if matches!(result, ContentResult::Posted { .. }) {
+        if matches!(
+            result,
+            ContentResult::Posted { .. } | ContentResult::Queued { .. }
+        ) {
             if recent_topics.len() >= max_recent {
                 recent_topics.remove(0);
             }
@@ -259,8 +315,8 @@ impl ContentLoop {
 
     /// Run a single content generation (for CLI `tuitbot post` command).
     ///
-    /// If `topic` is provided, uses that topic. Otherwise picks a random
-    /// topic from the configured list.
+    /// If `topic` is provided, uses that topic. Otherwise tries a trending
+    /// topic when configured, then picks a random topic from the configured list.
     pub async fn run_once(&self, topic: Option<&str>) -> ContentResult {
         let chosen_topic = match topic {
             Some(t) => t.to_string(),
@@ -281,7 +337,13 @@ impl ContentLoop {
             return ContentResult::RateLimited;
         }
 
-        self.generate_and_post(&chosen_topic).await
+        if topic.is_none() {
+            if let Some(result) = self.try_trend(&mut rand::thread_rng()).await {
+                return result;
+            }
+        }
+
+        self.generate_and_post(&chosen_topic, None).await
     }
 
     /// Run a single iteration of the continuous loop.
@@ -324,13 +386,21 @@ impl ContentLoop {
             return ContentResult::RateLimited;
         }
 
+        // Try a trending topic for this share of posts
+        if let Some(result) = self.try_trend(rng).await {
+            return result;
+        }
+
         // Pick a topic using epsilon-greedy if scorer is available
         let topic = self.pick_topic_epsilon_greedy(recent_topics, rng).await;
 
-        let result = self.generate_and_post(&topic).await;
+        let result = self.generate_and_post(&topic, None).await;
 
         // Update recent_topics on success
-        if matches!(result, ContentResult::Posted { .. }) {
+        if matches!(
+            result,
+            ContentResult::Posted { .. } | ContentResult::Queued { .. }
+        ) {
             if recent_topics.len() >= max_recent {
                 recent_topics.remove(0);
             }
@@ -380,6 +450,38 @@ impl ContentLoop {
         pick_topic(&self.topics, recent_topics, rng)
     }
 
+    /// Post on a trending topic for the configured share of posts.
+    ///
+    /// Returns `None` when trending is off, the roll says calendar, or no
+    /// recent cluster aligns with a pillar, so the caller falls back to the
+    /// regular rotation.
+    async fn try_trend(&self, rng: &mut impl rand::Rng) -> Option<ContentResult> {
+        let trending = self.trending.as_ref()?;
+        if rng.gen::<f64>() >= trending.share {
+            return None;
+        }
+
+        let clusters = match trending.source.trending_clusters().await {
+            Ok(clusters) => clusters,
+            Err(e) => {
+                tracing::warn!(error = %e, "Failed to load trending clusters, using topic rotation");
+                return None;
+            }
+        };
+        let pillars = if trending.pillars.is_empty() {
+            &self.topics
+        } else {
+            &trending.pillars
+        };
+        let Some(trend) = select_trend(&clusters, pillars) else {
+            tracing::debug!("No trending cluster aligned with a pillar, using topic rotation");
+            return None;
+        };
+
+        let topic = trend.pillar.clone();
+        Some(self.generate_and_post(&topic, Some(&trend)).await)
+    }
+
     /// Check for scheduled content due for posting and post it if found.
     ///
     /// Returns `Some(ContentResult)` if a scheduled item was handled,
@@ -408,7 +510,11 @@ impl ContentLoop {
                             &format!("Scheduled id={id}: {}", &content[..content.len().min(80)]),
                         )
                         .await;
-                } else if let Err(e) = self.storage.post_tweet("scheduled", &content).await {
+                } else if let Err(e) = self
+                    .storage
+                    .post_tweet("scheduled", &content, INSPIRATION_CALENDAR)
+                    .await
+                {
                     tracing::error!(error = %e, "Failed to post scheduled content");
                     return Some(ContentResult::Failed {
                         error: format!("Scheduled post failed: {e}"),
@@ -439,11 +545,18 @@ impl ContentLoop {
     }
 
     /// Generate a tweet and post it (or print in dry-run mode).
-    async fn generate_and_post(&self, topic: &str) -> ContentResult {
-        tracing::info!(topic = %topic, "Generating tweet on topic");
+    ///
+    /// With a `trend`, the tweet references that conversation and is tagged
+    /// as trend-inspired.
+    async fn generate_and_post(&self, topic: &str, trend: Option<&TrendTopic>) -> ContentResult {
+        tracing::info!(
+            topic = %topic,
+            trend = trend.map(|t| t.keyword.as_str()),
+            "Generating tweet on topic"
+        );
 
         // Generate tweet
-        let content = match self.generator.generate_tweet(topic).await {
+        let content = match self.generate(topic, trend).await {
             Ok(text) => text,
             Err(e) => {
                 return ContentResult::Failed {
@@ -463,7 +576,7 @@ impl ContentLoop {
             );
 
             let shorter_topic = format!("{topic} (IMPORTANT: keep under 280 characters)");
-            match self.generator.generate_tweet(&shorter_topic).await {
+            match self.generate(&shorter_topic, trend).await {
                 Ok(text)
                     if crate::content::length::tweet_weighted_len(&text)
                         <= crate::content::length::MAX_TWEET_CHARS =>
@@ -488,10 +601,27 @@ impl ContentLoop {
             content
         };
 
+        let (inspiration, label) = match trend {
+            Some(t) => (
+                INSPIRATION_TREND,
+                format!("Trend '{}' on '{topic}'", t.keyword),
+            ),
+            None => (INSPIRATION_CALENDAR, format!("Topic '{topic}'")),
+        };
+
+        let topic_lower = topic.to_lowercase();
+        if let Some(pillar) = self
+            .review_pillars
+            .iter()
+            .find(|p| topic_lower.contains(&p.to_lowercase()))
+        {
+            return self.queue_for_review(topic, pillar, content, &label).await;
+        }
+
         if self.dry_run {
             tracing::info!(
-                "DRY RUN: Would post tweet on topic '{}': \"{}\" ({} chars)",
-                topic,
+                "DRY RUN: Would post tweet ({}): \"{}\" ({} chars)",
+                label,
                 content,
                 content.len()
             );
@@ -501,11 +631,11 @@ impl ContentLoop {
                 .log_action(
                     "tweet",
                     "dry_run",
-                    &format!("Topic '{}': {}", topic, truncate_display(&content, 80)),
+                    &format!("{label}: {}", truncate_display(&content, 80)),
                 )
                 .await;
         } else {
-            if let Err(e) = self.storage.post_tweet(topic, &content).await {
+            if let Err(e) = self.storage.post_tweet(topic, &content, inspiration).await {
                 tracing::error!(error = %e, "Failed to post tweet");
                 let _ = self
                     .storage
@@ -521,7 +651,7 @@ impl ContentLoop {
                 .log_action(
                     "tweet",
                     "success",
-                    &format!("Topic '{}': {}", topic, truncate_display(&content, 80)),
+                    &format!("{label}: {}", truncate_display(&content, 80)),
                 )
                 .await;
         }
@@ -531,6 +661,57 @@ impl ContentLoop {
             content,
         }
     }
+
+    /// Send a tweet on a `pillar` that requires approval to the approval
+    /// queue instead of posting it.
+    async fn queue_for_review(
+        &self,
+        topic: &str,
+        pillar: &str,
+        content: String,
+        label: &str,
+    ) -> ContentResult {
+        if self.dry_run {
+            tracing::info!("DRY RUN: Would queue tweet ({label}) for approval: \"{content}\"");
+        } else if let Err(e) = self
+            .storage
+            .queue_tweet_for_approval(topic, pillar, &content)
+            .await
+        {
+            tracing::error!(error = %e, "Failed to queue tweet for approval");
+            return ContentResult::Failed {
+                error: e.to_string(),
+            };
+        }
+        let status = if self.dry_run { "dry_run" } else { "queued" };
+        let _ = self
+            .storage
+            .log_action(
+                "tweet",
+                status,
+                &format!(
+                    "{label} (pillar '{pillar}' requires approval): {}",
+                    truncate_display(&content, 80)
+                ),
+            )
+            .await;
+        ContentResult::Queued {
+            topic: topic.to_string(),
+            content,
+        }
+    }
+
+    /// Generate a tweet on `topic`, referencing `trend` when given.
+    async fn generate(
+        &self,
+        topic: &str,
+        trend: Option<&TrendTopic>,
+    ) -> Result<String, ContentLoopError> {
+        match trend {
+            Some(trend) => self.generator.generate_trend_tweet(topic, trend).await,
+            None => self.generator.generate_tweet(topic).await,
+        }
+    }
 }
 
 /// Pick a topic that is not in the recent list.
@@ -576,7 +757,7 @@ fn truncate_display(s: &str, max_len: usize) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::automation::ContentLoopError;
+    use crate::storage::keyword_hits::KeywordCluster;
     use std::sync::Mutex;
 
     // --- Mock implementations ---
@@ -639,7 +820,7 @@ mod tests {
 
     struct MockStorage {
         last_tweet: Mutex<Option<chrono::DateTime<chrono::Utc>>>,
-        posted_tweets: Mutex<Vec<(String, String)>>,
+        posted_tweets: Mutex<Vec<(String, String, String)>>,
         actions: Mutex<Vec<(String, String, String)>>,
     }
 
@@ -681,11 +862,31 @@ mod tests {
             Ok(Vec::new())
         }
 
-        async fn post_tweet(&self, topic: &str, content: &str) -> Result<(), ContentLoopError> {
-            self.posted_tweets
-                .lock()
-                .expect("lock")
-                .push((topic.to_string(), content.to_string()));
+        async fn post_tweet(
+            &self,
+            topic: &str,
+            content: &str,
+            inspiration: &str,
+        ) -> Result<(), ContentLoopError> {
+            self.posted_tweets.lock().expect("lock").push((
+                topic.to_string(),
+                content.to_string(),
+                inspiration.to_string(),
+            ));
+            Ok(())
+        }
+
+        async fn queue_tweet_for_approval(
+            &self,
+            topic: &str,
+            pillar: &str,
+            content: &str,
+        ) -> Result<(), ContentLoopError> {
+            self.posted_tweets.lock().expect("lock").push((
+                topic.to_string(),
+                content.to_string(),
+                format!("queued:{pillar}"),
+            ));
             Ok(())
         }
 
@@ -765,6 +966,30 @@ mod tests {
         assert_eq!(storage.posted_count(), 1);
     }
 
+    #[tokio::test]
+    async fn run_once_queues_tweets_on_review_pillars() {
+        let storage = Arc::new(MockStorage::new(None));
+        let content = ContentLoop::new(
+            Arc::new(MockGenerator {
+                response: "Great tweet about Rust!".to_string(),
+            }),
+            Arc::new(MockSafety {
+                can_tweet: true,
+                can_thread: true,
+            }),
+            storage.clone(),
+            make_topics(),
+            14400,
+            false,
+        )
+        .with_review_pillars(vec!["rust".to_string()]);
+
+        let result = content.run_once(Some("Rust")).await;
+        assert!(matches!(result, ContentResult::Queued { ref topic, .. } if topic == "Rust"));
+        let posted = storage.posted_tweets.lock().expect("lock");
+        assert_eq!(posted[0].2, "queued:rust");
+    }
+
     #[tokio::test]
     async fn run_once_dry_run_does_not_post() {
         let storage = Arc::new(MockStorage::new(None));
@@ -1002,6 +1227,95 @@ mod tests {
         }
     }
 
+    // --- Trending tests ---
+
+    struct TrendAwareGenerator;
+
+    #[async_trait::async_trait]
+    impl TweetGenerator for TrendAwareGenerator {
+        async fn generate_tweet(&self, topic: &str) -> Result<String, ContentLoopError> {
+            Ok(format!("calendar: {topic}"))
+        }
+
+        async fn generate_trend_tweet(
+            &self,
+            topic: &str,
+            trend: &TrendTopic,
+        ) -> Result<String, ContentLoopError> {
+            Ok(format!("trend: {} on {topic}", trend.keyword))
+        }
+    }
+
+    struct MockTrendSource {
+        clusters: Vec<KeywordCluster>,
+    }
+
+    #[async_trait::async_trait]
+    impl TrendSource for MockTrendSource {
+        async fn trending_clusters(&self) -> Result<Vec<KeywordCluster>, ContentLoopError> {
+            Ok(self.clusters.clone())
+        }
+    }
+
+    fn trending_loop(storage: Arc<MockStorage>, keyword: &str, share: f64) -> ContentLoop {
+        let source = Arc::new(MockTrendSource {
+            clusters: vec![KeywordCluster {
+                keyword: keyword.to_string(),
+                tweet_count: 4,
+                engagement: 120,
+                samples: vec![format!("Everyone is talking about {keyword} today")],
+            }],
+        });
+        ContentLoop::new(
+            Arc::new(TrendAwareGenerator),
+            Arc::new(MockSafety {
+                can_tweet: true,
+                can_thread: true,
+            }),
+            storage,
+            vec!["Rust".to_string()],
+            14400,
+            false,
+        )
+        .with_trending(source, Vec::new(), share)
+    }
+
+    #[tokio::test]
+    async fn aligned_trend_posts_tagged_tweet() {
+        let storage = Arc::new(MockStorage::new(None));
+        let content = trending_loop(storage.clone(), "rust", 1.0);
+
+        let result = content.run_once(None).await;
+        assert!(matches!(result, ContentResult::Posted { ref topic, .. } if topic == "Rust"));
+        let posted = storage.posted_tweets.lock().expect("lock").clone();
+        assert_eq!(
+            posted,
+            vec![(
+                "Rust".to_string(),
+                "trend: rust on Rust".to_string(),
+                "trend".to_string()
+            )]
+        );
+    }
+
+    #[tokio::test]
+    async fn unaligned_or_unrolled_trend_falls_back_to_calendar() {
+        let storage = Arc::new(MockStorage::new(None));
+        trending_loop(storage.clone(), "crypto", 1.0)
+            .run_once(None)
+            .await;
+        trending_loop(storage.clone(), "rust", 0.0)
+            .run_once(None)
+            .await;
+
+        let posted = storage.posted_tweets.lock().expect("lock").clone();
+        assert_eq!(posted.len(), 2);
+        for (_, content, inspiration) in posted {
+            assert_eq!(content, "calendar: Rust");
+            assert_eq!(inspiration, "calendar");
+        }
+    }
+
     #[tokio::test]
     async fn epsilon_greedy_exploits_top_topic() {
         let storage = Arc::new(MockStorage::new(None));
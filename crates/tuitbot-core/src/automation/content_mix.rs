@@ -0,0 +1,474 @@
+//! Daily content mix planner.
+//!
+//! At the start of each day (midnight in the rate limit timezone) the
+//! planner splits `content_mix.daily_budget` between replies, original
+//! tweets, and threads. Threads are planned first: on
+//! `schedule.thread_preferred_day` when one is set, otherwise spaced evenly
+//! across the week. The rest follows `reply_ratio`/`original_ratio`,
+//! shifted toward whichever kind has engaged better. The plan is stored,
+//! caps the day's rate limits (on top of `[limits]` and any warm-up), and
+//! is compared with what was actually posted once the day is over.
+
+use std::time::Duration;
+
+use chrono::{DateTime, Datelike, NaiveDate, TimeZone, Utc, Weekday};
+use chrono_tz::Tz;
+use serde::Serialize;
+use tokio_util::sync::CancellationToken;
+
+use super::warmup;
+use crate::config::{Config, ContentMixConfig, LimitsConfig};
+use crate::error::StorageError;
+use crate::storage::content_plans::{self, ContentPlanRow, PostCounts};
+use crate::storage::{action_log, analytics, rate_limits, DbPool};
+
+/// Action log type for a new day's plan.
+pub const PLAN_ACTION: &str = "content_plan";
+
+/// Action log type for a finished day's plan-vs-actual report.
+pub const PLAN_REPORT_ACTION: &str = "content_plan_report";
+
+/// How often the running agent checks for a new day.
+const CHECK_INTERVAL: Duration = Duration::from_secs(15 * 60);
+
+/// What the planner knows about the day it plans.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PlanInputs {
+    pub weekday: Weekday,
+    /// Preferred thread weekday, if configured.
+    pub thread_day: Option<Weekday>,
+    /// Threads posted in the six days before today.
+    pub threads_this_week: u32,
+    /// Whether a thread went out recently enough that another would crowd
+    /// it; only used without a preferred thread day.
+    pub recent_thread: bool,
+    /// Average performance score of measured replies.
+    pub reply_engagement: f64,
+    /// Average performance score of measured original tweets.
+    pub tweet_engagement: f64,
+}
+
+/// A day's allocation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DayPlan {
+    pub replies: u32,
+    pub originals: u32,
+    pub threads: u32,
+    pub rationale: String,
+}
+
+/// Split the budget for one day. Counts never exceed `limits`.
+pub fn plan_day(mix: &ContentMixConfig, limits: &LimitsConfig, inputs: &PlanInputs) -> DayPlan {
+    let mut notes = vec![format!("budget {}", mix.daily_budget)];
+
+    let weekly_left = limits
+        .max_threads_per_week
+        .saturating_sub(inputs.threads_this_week);
+    let thread_today = match inputs.thread_day {
+        Some(day) => day == inputs.weekday,
+        None => !inputs.recent_thread,
+    };
+    let threads = u32::from(thread_today && weekly_left > 0 && mix.daily_budget > 0);
+    if threads > 0 {
+        notes.push(match inputs.thread_day {
+            Some(day) => format!("thread day ({day})"),
+            None => "thread due".to_string(),
+        });
+    }
+
+    let rest = mix.daily_budget - threads;
+    let total_engagement = inputs.reply_engagement + inputs.tweet_engagement;
+    let shift = if total_engagement > 0.0 {
+        mix.performance_weight * (inputs.reply_engagement - inputs.tweet_engagement)
+            / total_engagement
+    } else {
+        0.0
+    };
+    let reply_share = mix.reply_ratio * (1.0 + shift);
+    let original_share = mix.original_ratio * (1.0 - shift);
+    let share_total = reply_share + original_share;
+    let replies = if share_total > 0.0 {
+        (f64::from(rest) * reply_share / share_total).round() as u32
+    } else {
+        0
+    };
+    // Whatever one kind cannot take under its cap goes to the other.
+    let replies = replies.min(rest).min(limits.max_replies_per_day);
+    let originals = (rest - replies).min(limits.max_tweets_per_day);
+    let replies = (rest - originals).min(limits.max_replies_per_day);
+    notes.push(format!(
+        "ratios {:.2}/{:.2}",
+        mix.reply_ratio, mix.original_ratio
+    ));
+    if shift != 0.0 {
+        notes.push(format!(
+            "engagement shift {shift:+.2} (replies vs originals)"
+        ));
+    }
+
+    DayPlan {
+        replies,
+        originals,
+        threads,
+        rationale: notes.join(", "),
+    }
+}
+
+/// Today's plan with what has been posted so far.
+#[derive(Debug, Clone, Serialize)]
+pub struct PlanStatus {
+    pub plan: ContentPlanRow,
+    pub posted: PostCounts,
+}
+
+impl PlanStatus {
+    /// Format plan progress as a single line.
+    pub fn format_line(&self) -> String {
+        format!(
+            "{}: replies {}/{}, originals {}/{}, threads {}/{}",
+            self.plan.day,
+            self.posted.replies,
+            self.plan.replies,
+            self.posted.originals,
+            self.plan.originals,
+            self.posted.threads,
+            self.plan.threads,
+        )
+    }
+}
+
+/// Report finished days and make sure today has a plan, for a specific
+/// account. Returns today's plan; `None` when the planner is disabled.
+pub async fn ensure_plan_for(
+    pool: &DbPool,
+    account_id: &str,
+    config: &Config,
+) -> Result<Option<ContentPlanRow>, StorageError> {
+    if !config.content_mix.enabled {
+        return Ok(None);
+    }
+    let tz = plan_timezone(config);
+    let today = local_day(&tz, Utc::now());
+    let day_key = today.to_string();
+
+    for plan in content_plans::get_unreported_before_for(pool, account_id, &day_key).await? {
+        report_day(pool, account_id, &tz, plan).await?;
+    }
+    if let Some(plan) = content_plans::get_plan_for(pool, account_id, &day_key).await? {
+        return Ok(Some(plan));
+    }
+
+    let inputs = gather_inputs(pool, account_id, config, &tz, today).await?;
+    let day_plan = plan_day(&config.content_mix, &config.limits, &inputs);
+    let plan = content_plans::insert_plan_for(
+        pool,
+        account_id,
+        &day_key,
+        day_plan.replies,
+        day_plan.originals,
+        day_plan.threads,
+        &day_plan.rationale,
+    )
+    .await?;
+    tracing::info!(
+        day = %plan.day,
+        replies = plan.replies,
+        originals = plan.originals,
+        threads = plan.threads,
+        "Content plan for today"
+    );
+    action_log::log_action_for(
+        pool,
+        account_id,
+        PLAN_ACTION,
+        "success",
+        Some(&format!(
+            "Planned {}: {} replies, {} originals, {} threads ({})",
+            plan.day, plan.replies, plan.originals, plan.threads, plan.rationale
+        )),
+        None,
+    )
+    .await?;
+    Ok(Some(plan))
+}
+
+/// `limits` capped by today's plan for a specific account. Unchanged when
+/// the planner is disabled or today has no plan yet.
+///
+/// The thread cap is weekly, so it is set to the threads already counted
+/// in the current window before today plus today's planned threads.
+pub async fn planned_limits_for(
+    pool: &DbPool,
+    account_id: &str,
+    config: &Config,
+    limits: LimitsConfig,
+) -> Result<LimitsConfig, StorageError> {
+    if !config.content_mix.enabled {
+        return Ok(limits);
+    }
+    let tz = plan_timezone(config);
+    let today = local_day(&tz, Utc::now());
+    let Some(plan) = content_plans::get_plan_for(pool, account_id, &today.to_string()).await?
+    else {
+        return Ok(limits);
+    };
+
+    let thread_row = rate_limits::get_all_rate_limits_for(pool, account_id)
+        .await?
+        .into_iter()
+        .find(|row| row.action_type == "thread");
+    let max_threads_per_week = match thread_row {
+        Some(row) => {
+            let (from, to) = day_bounds(&tz, today);
+            let today_posted =
+                content_plans::count_posts_between_for(pool, account_id, &from, &to).await?;
+            let before_today = row.request_count.saturating_sub(today_posted.threads);
+            let cap = before_today.saturating_add(plan.threads);
+            u32::try_from(cap)
+                .unwrap_or(u32::MAX)
+                .min(limits.max_threads_per_week)
+        }
+        None => limits.max_threads_per_week,
+    };
+
+    Ok(LimitsConfig {
+        max_replies_per_day: limits
+            .max_replies_per_day
+            .min(u32::try_from(plan.replies).unwrap_or(0)),
+        max_tweets_per_day: limits
+            .max_tweets_per_day
+            .min(u32::try_from(plan.originals).unwrap_or(0)),
+        max_threads_per_week,
+        ..limits
+    })
+}
+
+/// Today's plan and progress for a specific account, without changing
+/// anything. `None` when disabled or not planned yet.
+pub async fn plan_status_for(
+    pool: &DbPool,
+    account_id: &str,
+    config: &Config,
+) -> Result<Option<PlanStatus>, StorageError> {
+    if !config.content_mix.enabled {
+        return Ok(None);
+    }
+    let tz = plan_timezone(config);
+    let today = local_day(&tz, Utc::now());
+    let Some(plan) = content_plans::get_plan_for(pool, account_id, &today.to_string()).await?
+    else {
+        return Ok(None);
+    };
+    let (from, to) = day_bounds(&tz, today);
+    let posted = content_plans::count_posts_between_for(pool, account_id, &from, &to).await?;
+    Ok(Some(PlanStatus { plan, posted }))
+}
+
+/// Check for a new day every 15 minutes: report the finished day, plan the
+/// new one, and re-apply the caps.
+pub async fn run_content_mix_loop(
+    pool: DbPool,
+    account_id: String,
+    config: Config,
+    cancel: CancellationToken,
+) {
+    tracing::info!("Content mix planner started");
+    loop {
+        tokio::select! {
+            biased;
+            () = cancel.cancelled() => break,
+            () = tokio::time::sleep(CHECK_INTERVAL) => {}
+        }
+        if let Err(e) = ensure_plan_for(&pool, &account_id, &config).await {
+            tracing::warn!(error = %e, "Failed to update content plan");
+            continue;
+        }
+        if let Err(e) = warmup::apply_warmup_for(&pool, &account_id, &config).await {
+            tracing::warn!(error = %e, "Failed to apply content plan caps");
+        }
+    }
+    tracing::info!("Content mix planner stopped");
+}
+
+async fn report_day(
+    pool: &DbPool,
+    account_id: &str,
+    tz: &Tz,
+    plan: ContentPlanRow,
+) -> Result<(), StorageError> {
+    let Ok(day) = plan.day.parse::<NaiveDate>() else {
+        return Ok(());
+    };
+    let (from, to) = day_bounds(tz, day);
+    let actual = content_plans::count_posts_between_for(pool, account_id, &from, &to).await?;
+    content_plans::record_actuals_for(pool, account_id, &plan.day, &actual).await?;
+    let report = PlanStatus {
+        plan,
+        posted: actual,
+    };
+    tracing::info!("Content plan vs actual {}", report.format_line());
+    action_log::log_action_for(
+        pool,
+        account_id,
+        PLAN_REPORT_ACTION,
+        "success",
+        Some(&format!("Plan vs actual {}", report.format_line())),
+        None,
+    )
+    .await
+}
+
+async fn gather_inputs(
+    pool: &DbPool,
+    account_id: &str,
+    config: &Config,
+    tz: &Tz,
+    today: NaiveDate,
+) -> Result<PlanInputs, StorageError> {
+    let (today_start, _) = day_bounds(tz, today);
+    let (week_start, _) = day_bounds(tz, today - chrono::Days::new(6));
+    let week =
+        content_plans::count_posts_between_for(pool, account_id, &week_start, &today_start).await?;
+
+    // Without a preferred day, space threads evenly across the week.
+    let spacing = 7 / u64::from(config.limits.max_threads_per_week.clamp(1, 7));
+    let (spacing_start, _) = day_bounds(tz, today - chrono::Days::new(spacing - 1));
+    let recent =
+        content_plans::count_posts_between_for(pool, account_id, &spacing_start, &today_start)
+            .await?;
+
+    Ok(PlanInputs {
+        weekday: today.weekday(),
+        thread_day: config
+            .schedule
+            .thread_preferred_day
+            .as_deref()
+            .and_then(|d| d.parse().ok()),
+        threads_this_week: u32::try_from(week.threads).unwrap_or(u32::MAX),
+        recent_thread: spacing > 1 && recent.threads > 0,
+        reply_engagement: analytics::get_avg_reply_engagement_for(pool, account_id).await?,
+        tweet_engagement: analytics::get_avg_tweet_engagement_for(pool, account_id).await?,
+    })
+}
+
+/// Plans follow the rate limit timezone, so plan days line up with the
+/// daily caps they set.
+fn plan_timezone(config: &Config) -> Tz {
+    config.rate_limit_timezone().parse().unwrap_or(Tz::UTC)
+}
+
+fn local_day(tz: &Tz, now: DateTime<Utc>) -> NaiveDate {
+    now.with_timezone(tz).date_naive()
+}
+
+/// `[start, end)` of a local day as UTC timestamps in storage format.
+fn day_bounds(tz: &Tz, day: NaiveDate) -> (String, String) {
+    let midnight = |d: NaiveDate| {
+        let local = d.and_hms_opt(0, 0, 0).unwrap_or_default();
+        tz.from_local_datetime(&local)
+            .earliest()
+            .map(|t| t.with_timezone(&Utc))
+            .unwrap_or_else(|| Utc.from_utc_datetime(&local))
+            .format("%Y-%m-%dT%H:%M:%SZ")
+            .to_string()
+    };
+    (midnight(day), midnight(day + chrono::Days::new(1)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::accounts::DEFAULT_ACCOUNT_ID;
+    use crate::storage::init_test_db;
+
+    fn inputs(weekday: Weekday) -> PlanInputs {
+        PlanInputs {
+            weekday,
+            thread_day: Some(Weekday::Tue),
+            threads_this_week: 0,
+            recent_thread: false,
+            reply_engagement: 0.0,
+            tweet_engagement: 0.0,
+        }
+    }
+
+    #[test]
+    fn plans_thread_on_preferred_day_and_splits_the_rest() {
+        let mix = ContentMixConfig {
+            enabled: true,
+            ..Default::default()
+        };
+        let limits = Config::default().limits;
+
+        let tuesday = plan_day(&mix, &limits, &inputs(Weekday::Tue));
+        assert_eq!(
+            (tuesday.replies, tuesday.originals, tuesday.threads),
+            (5, 2, 1)
+        );
+        assert!(tuesday.rationale.contains("thread day (Tue)"));
+
+        let monday = plan_day(&mix, &limits, &inputs(Weekday::Mon));
+        assert_eq!(monday.threads, 0);
+        // Replies are capped at 5 a day, so originals take the extra one.
+        assert_eq!((monday.replies, monday.originals), (5, 3));
+
+        // A weekly thread already posted leaves no thread for Tuesday.
+        let mut done = inputs(Weekday::Tue);
+        done.threads_this_week = limits.max_threads_per_week;
+        assert_eq!(plan_day(&mix, &limits, &done).threads, 0);
+    }
+
+    #[test]
+    fn engagement_shifts_the_split() {
+        let mix = ContentMixConfig {
+            enabled: true,
+            daily_budget: 10,
+            reply_ratio: 0.5,
+            original_ratio: 0.5,
+            performance_weight: 1.0,
+        };
+        let mut limits = Config::default().limits;
+        limits.max_replies_per_day = 20;
+        limits.max_tweets_per_day = 20;
+        let mut day = inputs(Weekday::Mon);
+        day.tweet_engagement = 3.0;
+        day.reply_engagement = 1.0;
+        let plan = plan_day(&mix, &limits, &day);
+        assert_eq!((plan.replies, plan.originals), (3, 7));
+    }
+
+    #[tokio::test]
+    async fn plan_caps_limits_and_reports_finished_days() {
+        let pool = init_test_db().await.expect("init db");
+        let mut config = Config::default();
+        config.content_mix.enabled = true;
+        config.limits.max_replies_per_day = 20;
+        config.limits.max_tweets_per_day = 6;
+        content_plans::insert_plan_for(&pool, DEFAULT_ACCOUNT_ID, "2020-01-01", 3, 1, 0, "")
+            .await
+            .expect("old plan");
+
+        let plan = ensure_plan_for(&pool, DEFAULT_ACCOUNT_ID, &config)
+            .await
+            .expect("ensure")
+            .expect("plan");
+        assert_eq!(plan.replies + plan.originals + plan.threads, 8);
+        let old = content_plans::get_plan_for(&pool, DEFAULT_ACCOUNT_ID, "2020-01-01")
+            .await
+            .expect("get")
+            .expect("old plan");
+        assert_eq!(old.actual_replies, Some(0));
+
+        let limits = planned_limits_for(&pool, DEFAULT_ACCOUNT_ID, &config, config.limits.clone())
+            .await
+            .expect("limits");
+        assert_eq!(limits.max_replies_per_day, plan.replies as u32);
+        assert_eq!(limits.max_tweets_per_day, plan.originals as u32);
+
+        let status = plan_status_for(&pool, DEFAULT_ACCOUNT_ID, &config)
+            .await
+            .expect("status")
+            .expect("plan");
+        assert_eq!(status.posted, PostCounts::default());
+    }
+}
@@ -0,0 +1,36 @@
+//! Controversy context check before replying.
+
+use super::{DiscoveryLoop, DiscoveryResult};
+use crate::automation::loop_helpers::LoopTweet;
+use crate::safety::ContextVerdict;
+
+impl DiscoveryLoop {
+    /// Run the controversy context check on `tweet`.
+    ///
+    /// Returns the signal that sends the reply to the approval queue, if
+    /// any, or the final result when the policy skips the tweet.
+    pub(super) async fn context_review(
+        &self,
+        tweet: &LoopTweet,
+    ) -> Result<Option<String>, DiscoveryResult> {
+        match self.safety.check_context(tweet).await {
+            ContextVerdict::Clear => Ok(None),
+            ContextVerdict::Skip(denial) => {
+                let reason = denial.to_string();
+                let _ = self
+                    .storage
+                    .log_action(
+                        "discovery_reply",
+                        "skipped",
+                        &format!("Skipped @{}: {reason}", tweet.author_username),
+                    )
+                    .await;
+                Err(DiscoveryResult::Skipped {
+                    tweet_id: tweet.id.clone(),
+                    reason,
+                })
+            }
+            ContextVerdict::RequireApproval(denial) => Ok(Some(denial.to_string())),
+        }
+    }
+}
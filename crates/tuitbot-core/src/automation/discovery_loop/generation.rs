@@ -0,0 +1,92 @@
+//! Concurrent reply and quote generation for qualifying candidates.
+
+use tokio::task::JoinSet;
+
+use super::{Candidate, DiscoveryLoop, DiscoveryResult};
+use crate::automation::loop_helpers::{GeneratedReply, LoopError};
+
+impl DiscoveryLoop {
+    /// Generate text for up to `concurrency` qualifying candidates at once.
+    ///
+    /// Candidates are still posted one by one in search order, with the
+    /// rate limit re-checked before each post. Defaults to 1 (serial).
+    pub fn with_generation_concurrency(mut self, concurrency: usize) -> Self {
+        self.generation_concurrency = concurrency.max(1);
+        self
+    }
+
+    /// Generate text for a batch of candidates concurrently, then publish
+    /// each one in order and store its result in `slots`.
+    pub(super) async fn generate_and_publish(
+        &self,
+        batch: Vec<(usize, Candidate)>,
+        slots: &mut [Option<DiscoveryResult>],
+    ) {
+        let texts = match batch.as_slice() {
+            [] => return,
+            [(_, candidate)] => vec![self.generation(candidate).await],
+            _ => {
+                let mut tasks = JoinSet::new();
+                for (position, (_, candidate)) in batch.iter().enumerate() {
+                    let generation = self.generation(candidate);
+                    tasks.spawn(async move { (position, generation.await) });
+                }
+                let mut texts: Vec<Option<Result<GeneratedReply, LoopError>>> =
+                    batch.iter().map(|_| None).collect();
+                while let Some(joined) = tasks.join_next().await {
+                    match joined {
+                        Ok((position, text)) => texts[position] = Some(text),
+                        Err(e) => tracing::error!(error = %e, "Generation task failed"),
+                    }
+                }
+                texts
+                    .into_iter()
+                    .map(|text| {
+                        text.unwrap_or_else(|| {
+                            Err(LoopError::Other("generation task failed".to_string()))
+                        })
+                    })
+                    .collect()
+            }
+        };
+
+        for ((index, candidate), text) in batch.into_iter().zip(texts) {
+            slots[index] = Some(self.publish(candidate, text).await);
+        }
+    }
+
+    /// Future generating the reply or quote text for `candidate`.
+    ///
+    /// Owns everything it needs so it can run on its own task.
+    pub(super) fn generation(
+        &self,
+        candidate: &Candidate,
+    ) -> impl std::future::Future<Output = Result<GeneratedReply, LoopError>> + Send + 'static {
+        let text = candidate.tweet.text.clone();
+        let author = candidate.tweet.author_username.clone();
+        let quoter = match (&self.quotes, candidate.quote) {
+            (Some((generator, _)), true) => Some(generator.clone()),
+            _ => None,
+        };
+        let generator = self.generator.clone();
+        async move {
+            match quoter {
+                Some(quoter) => {
+                    quoter
+                        .generate_quote(&text, &author)
+                        .await
+                        .map(|text| GeneratedReply {
+                            text,
+                            ..GeneratedReply::default()
+                        })
+                }
+                // Product mention decided by caller or random
+                None => {
+                    generator
+                        .generate_reply_explained(&text, &author, true)
+                        .await
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,346 @@
+//! Tweet discovery loop.
+//!
+//! Searches X using configured keywords, scores each tweet with the
+//! scoring engine, filters by threshold, generates replies for
+//! qualifying tweets, and posts them through the posting queue.
+//! High-reach, on-topic tweets can be routed to a quote tweet instead
+//! (see [`QuoteTweetConfig`]). Rotates keywords across iterations to
+//! distribute API usage. Tweets surfaced by several keywords are scored
+//! once per dedup window, with every keyword credited for keyword stats.
+//! Searches that would run ahead of the daily pace curve are deferred when
+//! a [`RequestPacer`] is attached. With a [`ThresholdCalibrator`], scores
+//! are compared against the day's auto-calibrated threshold. Reply
+//! generation for qualifying candidates can run several LLM requests at
+//! once (see [`DiscoveryLoop::with_generation_concurrency`]). Extra
+//! [`DiscoverySource`](super::discovery_sources::DiscoverySource)s (e.g. Hacker News) are searched with the same
+//! keywords; their items are scored and stored like tweets, and qualifying
+//! ones are surfaced rather than replied to.
+
+mod context;
+mod generation;
+mod publish;
+mod quote;
+mod screening;
+mod search;
+mod sources;
+#[cfg(test)]
+mod tests;
+
+#[cfg(doc)]
+use super::discovery_sources::DiscoverySource;
+use super::discovery_sources::DiscoverySourceRegistry;
+use super::loop_helpers::{
+    ConsecutiveErrorTracker, LoopError, LoopStorage, LoopTweet, PostSender, QuoteGenerator,
+    ReplyGenerator, SafetyChecker, ScoreResult, ThresholdCalibrator, TweetScorer, TweetSearcher,
+};
+use super::pacing::RequestPacer;
+use super::schedule::{schedule_gate, ActiveSchedule};
+use super::scheduler::LoopScheduler;
+use crate::config::QuoteTweetConfig;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio_util::sync::CancellationToken;
+
+/// Discovery loop that finds and replies to relevant tweets.
+pub struct DiscoveryLoop {
+    searcher: Arc<dyn TweetSearcher>,
+    scorer: Arc<dyn TweetScorer>,
+    generator: Arc<dyn ReplyGenerator>,
+    safety: Arc<dyn SafetyChecker>,
+    storage: Arc<dyn LoopStorage>,
+    poster: Arc<dyn PostSender>,
+    keywords: Vec<String>,
+    threshold: f32,
+    dry_run: bool,
+    quotes: Option<(Arc<dyn QuoteGenerator>, QuoteTweetConfig)>,
+    pacer: Option<Arc<dyn RequestPacer>>,
+    calibrator: Option<Arc<dyn ThresholdCalibrator>>,
+    dedup_window: Duration,
+    generation_concurrency: usize,
+    sources: DiscoverySourceRegistry,
+}
+
+/// A tweet that passed screening and is waiting for generated text.
+struct Candidate {
+    tweet: LoopTweet,
+    score: ScoreResult,
+    threshold: f32,
+    /// Route to the quote generator instead of replying.
+    quote: bool,
+    /// Controversy signal that sends the reply to the approval queue.
+    review: Option<String>,
+}
+
+/// Default window during which a tweet seen by any keyword is not re-scored.
+const DEFAULT_DEDUP_WINDOW: Duration = Duration::from_secs(24 * 3600);
+
+/// Result of processing a single discovered tweet.
+#[derive(Debug)]
+pub enum DiscoveryResult {
+    /// Reply was sent (or would be sent in dry-run).
+    Replied {
+        tweet_id: String,
+        author: String,
+        score: f32,
+        reply_text: String,
+    },
+    /// Reply was queued for approval because of a controversy signal or QA flag.
+    Queued {
+        tweet_id: String,
+        author: String,
+        score: f32,
+        reply_text: String,
+        reason: String,
+    },
+    /// Quote tweet was sent (or would be sent in dry-run).
+    Quoted {
+        tweet_id: String,
+        author: String,
+        score: f32,
+        quote_text: String,
+    },
+    /// Item from a non-X source met the threshold; stored for review.
+    Surfaced {
+        item_id: String,
+        source: String,
+        score: f32,
+    },
+    /// Tweet scored below threshold.
+    BelowThreshold { tweet_id: String, score: f32 },
+    /// Tweet was skipped (safety check, already exists).
+    Skipped { tweet_id: String, reason: String },
+    /// Processing failed for this tweet.
+    Failed { tweet_id: String, error: String },
+}
+
+/// Summary of a discovery iteration.
+#[derive(Debug, Default)]
+pub struct DiscoverySummary {
+    /// Total tweets found across all keywords searched.
+    pub tweets_found: usize,
+    /// Tweets that scored above threshold.
+    pub qualifying: usize,
+    /// Replies sent (or would be sent in dry-run).
+    pub replied: usize,
+    /// Quote tweets sent (or would be sent in dry-run).
+    pub quoted: usize,
+    /// Replies queued for approval by the context or author overlap check.
+    pub queued: usize,
+    /// Items from non-X sources that met the threshold.
+    pub surfaced: usize,
+    /// Tweets skipped (safety, dedup, below threshold).
+    pub skipped: usize,
+    /// Tweets that failed processing.
+    pub failed: usize,
+}
+
+impl DiscoveryLoop {
+    /// Create a new discovery loop.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        searcher: Arc<dyn TweetSearcher>,
+        scorer: Arc<dyn TweetScorer>,
+        generator: Arc<dyn ReplyGenerator>,
+        safety: Arc<dyn SafetyChecker>,
+        storage: Arc<dyn LoopStorage>,
+        poster: Arc<dyn PostSender>,
+        keywords: Vec<String>,
+        threshold: f32,
+        dry_run: bool,
+    ) -> Self {
+        Self {
+            searcher,
+            scorer,
+            generator,
+            safety,
+            storage,
+            poster,
+            keywords,
+            threshold,
+            dry_run,
+            quotes: None,
+            pacer: None,
+            calibrator: None,
+            dedup_window: DEFAULT_DEDUP_WINDOW,
+            generation_concurrency: 1,
+            sources: DiscoverySourceRegistry::new(),
+        }
+    }
+
+    /// Skip re-scoring tweets that any keyword surfaced within `window`.
+    pub fn with_dedup_window(mut self, window: Duration) -> Self {
+        self.dedup_window = window;
+        self
+    }
+
+    /// Pace searches against the daily search budget.
+    pub fn with_pacer(mut self, pacer: Arc<dyn RequestPacer>) -> Self {
+        self.pacer = Some(pacer);
+        self
+    }
+
+    /// Compare scores against the auto-calibrated threshold instead of the
+    /// scorer's fixed one.
+    pub fn with_calibrator(mut self, calibrator: Arc<dyn ThresholdCalibrator>) -> Self {
+        self.calibrator = Some(calibrator);
+        self
+    }
+
+    /// Run the continuous discovery loop until cancellation.
+    ///
+    /// Rotates through keywords across iterations to distribute API usage.
+    pub async fn run(
+        &self,
+        cancel: CancellationToken,
+        scheduler: LoopScheduler,
+        schedule: Option<Arc<ActiveSchedule>>,
+    ) {
+        tracing::info!(
+            dry_run = self.dry_run,
+            keywords = self.keywords.len(),
+            threshold = self.threshold,
+            sources = ?self.sources.names(),
+            "Discovery loop started"
+        );
+
+        if self.keywords.is_empty() {
+            tracing::warn!("No keywords configured, discovery loop has nothing to search");
+            cancel.cancelled().await;
+            return;
+        }
+
+        let mut error_tracker = ConsecutiveErrorTracker::new(10, Duration::from_secs(300));
+        let mut keyword_index = 0usize;
+
+        loop {
+            if cancel.is_cancelled() {
+                break;
+            }
+
+            if !schedule_gate(&schedule, &cancel).await {
+                break;
+            }
+
+            if !self.search_allowed().await {
+                tokio::select! {
+                    _ = cancel.cancelled() => break,
+                    _ = scheduler.tick() => {},
+                }
+                continue;
+            }
+
+            // Select next keyword (round-robin)
+            let keyword = &self.keywords[keyword_index % self.keywords.len()];
+            keyword_index += 1;
+
+            match self.search_and_process(keyword, None).await {
+                Ok((_results, summary)) => {
+                    error_tracker.record_success();
+                    if summary.tweets_found > 0 {
+                        tracing::info!(
+                            keyword = %keyword,
+                            found = summary.tweets_found,
+                            qualifying = summary.qualifying,
+                            replied = summary.replied,
+                            quoted = summary.quoted,
+                            surfaced = summary.surfaced,
+                            "Discovery iteration complete"
+                        );
+                    }
+                }
+                Err(e) => {
+                    let should_pause = error_tracker.record_error();
+                    tracing::warn!(
+                        keyword = %keyword,
+                        error = %e,
+                        consecutive_errors = error_tracker.count(),
+                        "Discovery iteration failed"
+                    );
+
+                    if should_pause {
+                        tracing::warn!(
+                            pause_secs = error_tracker.pause_duration().as_secs(),
+                            "Pausing discovery loop due to consecutive errors"
+                        );
+                        tokio::select! {
+                            _ = cancel.cancelled() => break,
+                            _ = tokio::time::sleep(error_tracker.pause_duration()) => {},
+                        }
+                        error_tracker.reset();
+                        continue;
+                    }
+
+                    if let LoopError::RateLimited { retry_after } = &e {
+                        let backoff = super::loop_helpers::rate_limit_backoff(*retry_after, 0);
+                        tokio::select! {
+                            _ = cancel.cancelled() => break,
+                            _ = tokio::time::sleep(backoff) => {},
+                        }
+                        continue;
+                    }
+                }
+            }
+
+            tokio::select! {
+                _ = cancel.cancelled() => break,
+                _ = scheduler.tick() => {},
+            }
+        }
+
+        tracing::info!("Discovery loop stopped");
+    }
+
+    /// Run a single-shot discovery across all keywords.
+    ///
+    /// Used by the CLI `tuitbot discover` command. Searches all keywords
+    /// (not rotating) and returns all results sorted by score descending.
+    pub async fn run_once(
+        &self,
+        limit: Option<usize>,
+    ) -> Result<(Vec<DiscoveryResult>, DiscoverySummary), LoopError> {
+        let mut all_results = Vec::new();
+        let mut summary = DiscoverySummary::default();
+        let mut total_processed = 0usize;
+
+        for keyword in &self.keywords {
+            if let Some(max) = limit {
+                if total_processed >= max {
+                    break;
+                }
+            }
+
+            if !self.search_allowed().await {
+                break;
+            }
+
+            let remaining = limit.map(|max| max.saturating_sub(total_processed));
+            match self.search_and_process(keyword, remaining).await {
+                Ok((results, iter_summary)) => {
+                    summary.tweets_found += iter_summary.tweets_found;
+                    summary.qualifying += iter_summary.qualifying;
+                    summary.replied += iter_summary.replied;
+                    summary.quoted += iter_summary.quoted;
+                    summary.surfaced += iter_summary.surfaced;
+                    summary.skipped += iter_summary.skipped;
+                    summary.failed += iter_summary.failed;
+                    total_processed += iter_summary.tweets_found;
+                    all_results.extend(results);
+                }
+                Err(e) => {
+                    tracing::warn!(keyword = %keyword, error = %e, "Search failed for keyword");
+                }
+            }
+        }
+
+        Ok((all_results, summary))
+    }
+}
+
+/// Truncate a string for display.
+pub(super) fn truncate(s: &str, max_len: usize) -> String {
+    if s.len() <= max_len {
+        s.to_string()
+    } else {
+        format!("{}...", &s[..max_len])
+    }
+}
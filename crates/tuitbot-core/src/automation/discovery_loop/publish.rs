@@ -0,0 +1,267 @@
+//! Posting, queueing, or dry-run logging generated replies.
+
+use super::{truncate, Candidate, DiscoveryLoop, DiscoveryResult};
+use crate::automation::loop_helpers::{
+    send_reply_to, soft_flag_waiver, GeneratedReply, LoopError, LoopTweet, ScoreResult,
+};
+use crate::safety::trusted::TRUSTED_BYPASS_ACTION;
+use crate::safety::OverlapVerdict;
+use crate::storage::reply_explanations::ReplyExplanation;
+use crate::workflow::draft_workspace::DraftFlag;
+
+impl DiscoveryLoop {
+    /// Post (or dry-run log) generated text for a candidate.
+    pub(super) async fn publish(
+        &self,
+        candidate: Candidate,
+        text: Result<GeneratedReply, LoopError>,
+    ) -> DiscoveryResult {
+        let Candidate {
+            tweet,
+            score: score_result,
+            threshold,
+            quote,
+            review,
+        } = candidate;
+
+        let generated = match text {
+            Ok(generated) => generated,
+            Err(e) => {
+                tracing::error!(
+                    tweet_id = %tweet.id,
+                    error = %e,
+                    "Failed to generate {}",
+                    if quote { "quote tweet" } else { "reply" }
+                );
+                return DiscoveryResult::Failed {
+                    tweet_id: tweet.id.clone(),
+                    error: e.to_string(),
+                };
+            }
+        };
+
+        // Batched candidates passed the rate limit before earlier ones in
+        // the batch were posted; check again so the batch cannot overshoot.
+        if self.generation_concurrency > 1 && !self.dry_run && !self.safety.can_reply().await {
+            return DiscoveryResult::Skipped {
+                tweet_id: tweet.id.clone(),
+                reason: "rate limited".to_string(),
+            };
+        }
+
+        if quote {
+            return self
+                .post_quote(&tweet, &score_result, threshold, generated.text)
+                .await;
+        }
+
+        // Replies repeating the author's own recent tweets are skipped or
+        // flagged for review
+        let mut qa_flags = Vec::new();
+        match self
+            .safety
+            .check_author_overlap(&tweet, &generated.text)
+            .await
+        {
+            OverlapVerdict::Clear => {}
+            OverlapVerdict::Soft(flag) => {
+                match soft_flag_waiver(self.safety.as_ref(), &tweet, &flag) {
+                    Some(waiver) => {
+                        let _ = self
+                            .storage
+                            .log_action(TRUSTED_BYPASS_ACTION, "soft_qa", &waiver)
+                            .await;
+                    }
+                    None => qa_flags.push(flag),
+                }
+            }
+            OverlapVerdict::Hard(denial) => {
+                let reason = denial.to_string();
+                let _ = self
+                    .storage
+                    .log_action(
+                        "discovery_reply",
+                        "skipped",
+                        &format!("Skipped @{}: {reason}", tweet.author_username),
+                    )
+                    .await;
+                return DiscoveryResult::Skipped {
+                    tweet_id: tweet.id.clone(),
+                    reason,
+                };
+            }
+        }
+
+        let review = review.or_else(|| generated.approval_reason());
+        if review.is_some() || !qa_flags.is_empty() {
+            return self
+                .queue_reply(&tweet, &score_result, generated.text, review, qa_flags)
+                .await;
+        }
+        self.post_reply(&tweet, &score_result, threshold, generated)
+            .await
+    }
+
+    /// Send a generated reply to the approval queue instead of posting it.
+    ///
+    /// `risk` is the controversy signal, if any; `qa_flags` hold QA findings
+    /// on the reply text.
+    pub(super) async fn queue_reply(
+        &self,
+        tweet: &LoopTweet,
+        score_result: &ScoreResult,
+        reply_text: String,
+        risk: Option<String>,
+        qa_flags: Vec<DraftFlag>,
+    ) -> DiscoveryResult {
+        let reason = risk
+            .clone()
+            .or_else(|| qa_flags.first().map(|flag| flag.message.clone()))
+            .unwrap_or_default();
+        if self.dry_run {
+            tracing::info!(
+                "DRY RUN: Tweet {} by @{} -- Would queue for approval ({reason}): \"{}\"",
+                tweet.id,
+                tweet.author_username,
+                reply_text
+            );
+        } else {
+            if let Err(e) = self
+                .storage
+                .queue_reply_for_approval(
+                    &tweet.id,
+                    &tweet.author_username,
+                    &reply_text,
+                    score_result.total,
+                    risk.as_deref(),
+                    &qa_flags,
+                )
+                .await
+            {
+                tracing::error!(tweet_id = %tweet.id, error = %e, "Failed to queue reply");
+                return DiscoveryResult::Failed {
+                    tweet_id: tweet.id.clone(),
+                    error: e.to_string(),
+                };
+            }
+            let _ = self
+                .storage
+                .log_action(
+                    "discovery_reply",
+                    "queued",
+                    &format!(
+                        "Queued reply to @{} for approval ({reason}): {}",
+                        tweet.author_username,
+                        truncate(&reply_text, 50)
+                    ),
+                )
+                .await;
+        }
+
+        DiscoveryResult::Queued {
+            tweet_id: tweet.id.clone(),
+            author: tweet.author_username.clone(),
+            score: score_result.total,
+            reply_text,
+            reason,
+        }
+    }
+
+    /// Post a generated reply through the posting queue.
+    pub(super) async fn post_reply(
+        &self,
+        tweet: &LoopTweet,
+        score_result: &ScoreResult,
+        threshold: f32,
+        generated: GeneratedReply,
+    ) -> DiscoveryResult {
+        let entity = score_result.entity_type.as_str();
+        let reply_text = generated.text;
+
+        tracing::info!(
+            author = %tweet.author_username,
+            score = format!("{:.0}", score_result.total),
+            entity,
+            "Posted reply to @{}",
+            tweet.author_username,
+        );
+
+        if self.dry_run {
+            tracing::info!(
+                "DRY RUN: Tweet {} by @{} scored {:.0}/100 -- Would reply: \"{}\"",
+                tweet.id,
+                tweet.author_username,
+                score_result.total,
+                reply_text
+            );
+
+            let _ = self
+                .storage
+                .log_action(
+                    "discovery_reply",
+                    "dry_run",
+                    &format!(
+                        "Score {:.0}/{threshold:.0} ({entity}), reply to @{}: {}",
+                        score_result.total,
+                        tweet.author_username,
+                        truncate(&reply_text, 50)
+                    ),
+                )
+                .await;
+        } else {
+            if let Err(e) = send_reply_to(
+                self.poster.as_ref(),
+                self.safety.as_ref(),
+                tweet,
+                &reply_text,
+            )
+            .await
+            {
+                tracing::error!(tweet_id = %tweet.id, error = %e, "Failed to send reply");
+                return DiscoveryResult::Failed {
+                    tweet_id: tweet.id.clone(),
+                    error: e.to_string(),
+                };
+            }
+
+            let explanation = ReplyExplanation {
+                source: "discovery".to_string(),
+                matched_keywords: score_result.matched_keywords.clone(),
+                score: score_result.signals.clone(),
+                threshold: Some(threshold),
+                archetype: generated.archetype,
+                ancestors: generated.ancestors,
+                topic_override: generated.topic_override,
+                qa_score: None,
+            };
+            if let Err(e) = self
+                .safety
+                .record_reply_explained(&tweet.id, &reply_text, explanation)
+                .await
+            {
+                tracing::warn!(tweet_id = %tweet.id, error = %e, "Failed to record reply");
+            }
+
+            let _ = self
+                .storage
+                .log_action(
+                    "discovery_reply",
+                    "success",
+                    &format!(
+                        "Score {:.0}/{threshold:.0} ({entity}), replied to @{}: {}",
+                        score_result.total,
+                        tweet.author_username,
+                        truncate(&reply_text, 50)
+                    ),
+                )
+                .await;
+        }
+
+        DiscoveryResult::Replied {
+            tweet_id: tweet.id.clone(),
+            author: tweet.author_username.clone(),
+            score: score_result.total,
+            reply_text,
+        }
+    }
+}
@@ -0,0 +1,96 @@
+//! Routing high-reach, on-topic tweets to quote tweets.
+
+use std::sync::Arc;
+
+use super::{truncate, DiscoveryLoop, DiscoveryResult};
+use crate::automation::loop_helpers::{LoopTweet, QuoteGenerator, ScoreResult};
+use crate::config::QuoteTweetConfig;
+
+impl DiscoveryLoop {
+    /// Route candidates matching the quote-tweet rule to `generator` and
+    /// post them as quote tweets instead of replies.
+    pub fn with_quotes(
+        mut self,
+        generator: Arc<dyn QuoteGenerator>,
+        config: QuoteTweetConfig,
+    ) -> Self {
+        self.quotes = Some((generator, config));
+        self
+    }
+
+    /// Whether the quote-tweet rule routes `tweet` to the quote generator.
+    pub(super) fn wants_quote(&self, tweet: &LoopTweet, score: &ScoreResult) -> bool {
+        self.quotes.as_ref().is_some_and(|(_, config)| {
+            config.should_quote(
+                score.total,
+                tweet.author_followers,
+                score.matched_keywords.len(),
+            )
+        })
+    }
+
+    /// Post generated quote commentary as a quote tweet.
+    pub(super) async fn post_quote(
+        &self,
+        tweet: &LoopTweet,
+        score_result: &ScoreResult,
+        threshold: f32,
+        quote_text: String,
+    ) -> DiscoveryResult {
+        let entity = score_result.entity_type.as_str();
+        tracing::info!(
+            author = %tweet.author_username,
+            followers = tweet.author_followers,
+            score = format!("{:.0}", score_result.total),
+            entity,
+            "Quoting tweet by @{}",
+            tweet.author_username,
+        );
+
+        let status = if self.dry_run {
+            tracing::info!(
+                "DRY RUN: Tweet {} by @{} scored {:.0}/100 -- Would quote: \"{}\"",
+                tweet.id,
+                tweet.author_username,
+                score_result.total,
+                quote_text
+            );
+            "dry_run"
+        } else {
+            if let Err(e) = self.poster.send_quote(&tweet.id, &quote_text).await {
+                tracing::error!(tweet_id = %tweet.id, error = %e, "Failed to send quote tweet");
+                return DiscoveryResult::Failed {
+                    tweet_id: tweet.id.clone(),
+                    error: e.to_string(),
+                };
+            }
+
+            // Recorded like a reply so the tweet is not engaged with twice.
+            if let Err(e) = self.safety.record_reply(&tweet.id, &quote_text).await {
+                tracing::warn!(tweet_id = %tweet.id, error = %e, "Failed to record quote tweet");
+            }
+            "success"
+        };
+
+        let _ = self
+            .storage
+            .log_action(
+                "discovery_quote",
+                status,
+                &format!(
+                    "Score {:.0}/{threshold:.0} ({entity}), quoted @{}: {}",
+                    score_result.total,
+                    tweet.author_username,
+                    truncate(&quote_text, 50)
+                ),
+            )
+            .await;
+
+        DiscoveryResult::Quoted {
+            tweet_id: tweet.id.clone(),
+            author: tweet.author_username.clone(),
+            score: score_result.total,
+            quote_text,
+        }
+    }
+}
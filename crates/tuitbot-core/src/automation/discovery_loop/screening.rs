@@ -0,0 +1,176 @@
+//! Screening discovered tweets: blocklists, dedup, scoring, and threshold.
+
+use super::{Candidate, DiscoveryLoop, DiscoveryResult};
+use crate::automation::loop_helpers::{LoopTweet, ScoreResult};
+use crate::safety::ReciprocityVerdict;
+
+impl DiscoveryLoop {
+    /// Screen a discovered tweet: blocklist, dedup, score, store, threshold
+    /// and safety.
+    ///
+    /// Returns the candidate to generate for, or the final result when the
+    /// tweet is dropped. `calibrated` overrides the scorer's threshold
+    /// verdict when set.
+    pub(super) async fn screen_tweet(
+        &self,
+        tweet: &LoopTweet,
+        keyword: &str,
+        calibrated: Option<f32>,
+    ) -> Result<Candidate, DiscoveryResult> {
+        if let Err(denial) = self.safety.check_protected_author(tweet) {
+            return Err(DiscoveryResult::Skipped {
+                tweet_id: tweet.id.clone(),
+                reason: denial.to_string(),
+            });
+        }
+
+        if let Err(denial) = self.safety.check_blocked_author(tweet).await {
+            return Err(DiscoveryResult::Skipped {
+                tweet_id: tweet.id.clone(),
+                reason: denial.to_string(),
+            });
+        }
+
+        let (score_result, threshold) = self.score_and_store(tweet, keyword, calibrated).await?;
+
+        // Safety checks
+        if self.safety.has_replied_to(&tweet.id).await {
+            return Err(DiscoveryResult::Skipped {
+                tweet_id: tweet.id.clone(),
+                reason: "already replied".to_string(),
+            });
+        }
+
+        if !self.safety.can_reply().await {
+            return Err(DiscoveryResult::Skipped {
+                tweet_id: tweet.id.clone(),
+                reason: "rate limited".to_string(),
+            });
+        }
+
+        let review = self.context_review(tweet).await?;
+        let quote = review.is_none() && self.wants_quote(tweet, &score_result);
+
+        Ok(Candidate {
+            tweet: tweet.clone(),
+            score: score_result,
+            threshold,
+            quote,
+            review,
+        })
+    }
+
+    /// Dedup, score, and store a tweet, then check it against the threshold.
+    ///
+    /// Returns the score and the threshold it was compared against, or the
+    /// final result when the tweet is dropped.
+    pub(super) async fn score_and_store(
+        &self,
+        tweet: &LoopTweet,
+        keyword: &str,
+        calibrated: Option<f32>,
+    ) -> Result<(ScoreResult, f32), DiscoveryResult> {
+        // Check the shared dedup cache, then credit this keyword either way.
+        let seen = self
+            .storage
+            .tweet_seen_within(&tweet.id, self.dedup_window)
+            .await;
+        if let Err(e) = self.storage.record_keyword_hit(&tweet.id, keyword).await {
+            tracing::warn!(tweet_id = %tweet.id, error = %e, "Failed to record keyword hit");
+        }
+        match seen {
+            Ok(true) => {
+                tracing::debug!(tweet_id = %tweet.id, "Tweet already discovered, skipping");
+                return Err(DiscoveryResult::Skipped {
+                    tweet_id: tweet.id.clone(),
+                    reason: "already discovered".to_string(),
+                });
+            }
+            Ok(false) => {}
+            Err(e) => {
+                tracing::warn!(tweet_id = %tweet.id, error = %e, "Failed to check tweet existence");
+                // Continue anyway -- best effort dedup
+            }
+        }
+
+        // Score the tweet
+        let mut score_result = self.scorer.score(tweet);
+        let entity = score_result.entity_type.as_str();
+
+        // Store discovered tweet (even if below threshold, useful for analytics)
+        if let Err(e) = self
+            .storage
+            .store_discovered_tweet(tweet, score_result.total, keyword)
+            .await
+        {
+            tracing::warn!(tweet_id = %tweet.id, error = %e, "Failed to store discovered tweet");
+        }
+
+        // Tweets past the freshness cutoff are never replied to
+        if score_result.past_cutoff {
+            let reason = "older than the freshness cutoff".to_string();
+            let _ = self
+                .storage
+                .log_action(
+                    "discovery_reply",
+                    "skipped",
+                    &format!(
+                        "Skipped tweet {} by @{}: {reason}",
+                        tweet.id, tweet.author_username
+                    ),
+                )
+                .await;
+            return Err(DiscoveryResult::Skipped {
+                tweet_id: tweet.id.clone(),
+                reason,
+            });
+        }
+
+        // One-sided relationships lose points or are skipped
+        match self.safety.check_reciprocity(tweet).await {
+            ReciprocityVerdict::Clear => {}
+            ReciprocityVerdict::Deprioritize { penalty, reason } => {
+                tracing::debug!(tweet_id = %tweet.id, penalty, reason = %reason, "De-prioritizing one-sided author");
+                score_result.total = (score_result.total - penalty).max(0.0);
+                score_result.meets_threshold = score_result.total >= self.threshold;
+            }
+            ReciprocityVerdict::Skip(denial) => {
+                let reason = denial.to_string();
+                let _ = self
+                    .storage
+                    .log_action(
+                        "discovery_reply",
+                        "skipped",
+                        &format!("Skipped @{}: {reason}", tweet.author_username),
+                    )
+                    .await;
+                return Err(DiscoveryResult::Skipped {
+                    tweet_id: tweet.id.clone(),
+                    reason,
+                });
+            }
+        }
+
+        // Check threshold
+        let threshold = calibrated.unwrap_or(self.threshold);
+        let meets_threshold = match calibrated {
+            Some(threshold) => score_result.total >= threshold,
+            None => score_result.meets_threshold,
+        };
+        if !meets_threshold {
+            tracing::debug!(
+                tweet_id = %tweet.id,
+                score = score_result.total,
+                threshold,
+                entity,
+                "Tweet scored below threshold, skipping"
+            );
+            return Err(DiscoveryResult::BelowThreshold {
+                tweet_id: tweet.id.clone(),
+                score: score_result.total,
+            });
+        }
+
+        Ok((score_result, threshold))
+    }
+}
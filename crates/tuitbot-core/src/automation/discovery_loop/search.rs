@@ -0,0 +1,120 @@
+//! Searching a keyword and tallying the results.
+
+use super::{DiscoveryLoop, DiscoveryResult, DiscoverySummary};
+use crate::automation::loop_helpers::LoopError;
+use crate::automation::pacing::{PaceDecision, PacedEndpoint};
+
+impl DiscoveryLoop {
+    /// Calibrated threshold for this search, or `None` to use the scorer's verdict.
+    pub(super) async fn calibrated_threshold(&self) -> Option<f32> {
+        let calibrator = self.calibrator.as_ref()?;
+        match calibrator.effective_threshold().await {
+            Ok(threshold) => Some(threshold),
+            Err(e) => {
+                tracing::warn!(error = %e, "Failed to read calibrated threshold");
+                None
+            }
+        }
+    }
+
+    /// Check the search pace. Returns `false` if the search should wait.
+    pub(super) async fn search_allowed(&self) -> bool {
+        let Some(pacer) = &self.pacer else {
+            return true;
+        };
+        match pacer.check(PacedEndpoint::Search).await {
+            PaceDecision::Proceed => true,
+            PaceDecision::Defer { wait } => {
+                tracing::debug!(
+                    wait_secs = wait.as_secs(),
+                    "Search ahead of daily pace, deferring"
+                );
+                false
+            }
+            PaceDecision::Exhausted => {
+                tracing::info!("Daily search budget spent, skipping discovery");
+                false
+            }
+        }
+    }
+
+    /// Search for a single keyword and process all results.
+    ///
+    /// Tweets are screened one at a time; qualifying candidates are then
+    /// generated in chunks of up to `generation_concurrency` concurrent LLM
+    /// requests and posted in search order.
+    pub(super) async fn search_and_process(
+        &self,
+        keyword: &str,
+        limit: Option<usize>,
+    ) -> Result<(Vec<DiscoveryResult>, DiscoverySummary), LoopError> {
+        tracing::info!(keyword = %keyword, "Searching keyword");
+        let searched = self.searcher.search_tweets(keyword).await;
+        if let Some(pacer) = &self.pacer {
+            pacer.record(PacedEndpoint::Search).await;
+        }
+        let tweets = searched?;
+        let calibrated = self.calibrated_threshold().await;
+
+        let mut summary = DiscoverySummary {
+            tweets_found: tweets.len(),
+            ..Default::default()
+        };
+
+        let to_process = match limit {
+            Some(n) => &tweets[..tweets.len().min(n)],
+            None => &tweets,
+        };
+
+        let mut slots: Vec<Option<DiscoveryResult>> = Vec::with_capacity(to_process.len());
+        let mut batch = Vec::with_capacity(self.generation_concurrency);
+
+        for (index, tweet) in to_process.iter().enumerate() {
+            slots.push(None);
+            match self.screen_tweet(tweet, keyword, calibrated).await {
+                Ok(candidate) => batch.push((index, candidate)),
+                Err(result) => slots[index] = Some(result),
+            }
+            if batch.len() >= self.generation_concurrency {
+                self.generate_and_publish(std::mem::take(&mut batch), &mut slots)
+                    .await;
+            }
+        }
+        self.generate_and_publish(batch, &mut slots).await;
+
+        let mut results: Vec<DiscoveryResult> = slots.into_iter().flatten().collect();
+        summary.tweets_found += self.search_sources(keyword, calibrated, &mut results).await;
+
+        for result in &results {
+            match result {
+                DiscoveryResult::Replied { .. } => {
+                    summary.qualifying += 1;
+                    summary.replied += 1;
+                }
+                DiscoveryResult::Quoted { .. } => {
+                    summary.qualifying += 1;
+                    summary.quoted += 1;
+                }
+                DiscoveryResult::Queued { .. } => {
+                    summary.qualifying += 1;
+                    summary.queued += 1;
+                }
+                DiscoveryResult::Surfaced { .. } => {
+                    summary.qualifying += 1;
+                    summary.surfaced += 1;
+                }
+                DiscoveryResult::BelowThreshold { .. } => {
+                    summary.skipped += 1;
+                }
+                DiscoveryResult::Skipped { .. } => {
+                    summary.skipped += 1;
+                }
+                DiscoveryResult::Failed { .. } => {
+                    summary.failed += 1;
+                }
+            }
+        }
+
+        Ok((results, summary))
+    }
+}
@@ -0,0 +1,70 @@
+//! Non-X discovery sources, scored and surfaced but never replied to.
+
+use super::{DiscoveryLoop, DiscoveryResult};
+use crate::automation::discovery_sources::DiscoverySourceRegistry;
+use crate::automation::loop_helpers::LoopTweet;
+
+impl DiscoveryLoop {
+    /// Also search these non-X sources for each keyword.
+    pub fn with_sources(mut self, sources: DiscoverySourceRegistry) -> Self {
+        self.sources = sources;
+        self
+    }
+
+    /// Search every registered non-X source for `keyword`, appending one
+    /// result per item to `results`. Returns the number of items found.
+    ///
+    /// A failing source is logged and skipped.
+    pub(super) async fn search_sources(
+        &self,
+        keyword: &str,
+        calibrated: Option<f32>,
+        results: &mut Vec<DiscoveryResult>,
+    ) -> usize {
+        let mut found = 0;
+        for source in self.sources.sources() {
+            match source.search(keyword).await {
+                Ok(items) => {
+                    found += items.len();
+                    for item in &items {
+                        results.push(
+                            self.screen_source_item(source.name(), item, keyword, calibrated)
+                                .await,
+                        );
+                    }
+                }
+                Err(e) => {
+                    tracing::warn!(source = source.name(), keyword = %keyword, error = %e, "Discovery source search failed");
+                }
+            }
+        }
+        found
+    }
+
+    /// Screen an item from a non-X source. It goes through the same dedup,
+    /// scoring, and threshold as a tweet but is never replied to.
+    pub(super) async fn screen_source_item(
+        &self,
+        source: &str,
+        item: &LoopTweet,
+        keyword: &str,
+        calibrated: Option<f32>,
+    ) -> DiscoveryResult {
+        match self.score_and_store(item, keyword, calibrated).await {
+            Ok((score, _)) => {
+                tracing::info!(
+                    source,
+                    item_id = %item.id,
+                    score = score.total,
+                    "Discovery source item qualified"
+                );
+                DiscoveryResult::Surfaced {
+                    item_id: item.id.clone(),
+                    source: source.to_string(),
+                    score: score.total,
+                }
+            }
+            Err(result) => result,
+        }
+    }
+}
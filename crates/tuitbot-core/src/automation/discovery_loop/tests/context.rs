@@ -0,0 +1,51 @@
+use super::*;
+
+#[tokio::test]
+async fn context_check_skips_or_queues_flagged_tweets() {
+    let denial = DenialReason::ControversialContext {
+        signal: "tweet quote/like ratio 2.00".to_string(),
+    };
+    for (verdict, queued) in [
+        (ContextVerdict::Skip(denial.clone()), false),
+        (ContextVerdict::RequireApproval(denial.clone()), true),
+    ] {
+        let poster = Arc::new(MockPoster::new());
+        let storage = Arc::new(MockStorage::new());
+        let discovery = DiscoveryLoop::new(
+            Arc::new(MockSearcher {
+                results: vec![test_tweet("100", "alice")],
+            }),
+            Arc::new(MockScorer {
+                score: 85.0,
+                meets_threshold: true,
+            }),
+            Arc::new(MockGenerator {
+                reply: "Great insight!".to_string(),
+            }),
+            Arc::new(MockSafety {
+                context: verdict,
+                ..MockSafety::new(true)
+            }),
+            storage.clone(),
+            poster.clone(),
+            vec!["rust".to_string()],
+            70.0,
+            false,
+        );
+
+        let (results, summary) = discovery.search_and_process("rust", None).await.unwrap();
+        assert_eq!(poster.sent_count(), 0);
+        if queued {
+            assert_eq!(summary.queued, 1);
+            let queued = storage.queued.lock().expect("lock");
+            assert_eq!(queued[0].0, "100");
+            assert!(queued[0].1.starts_with("Controversial context"));
+        } else {
+            assert_eq!(summary.skipped, 1);
+            assert!(matches!(
+                &results[0],
+                DiscoveryResult::Skipped { reason, .. } if reason.starts_with("Controversial context")
+            ));
+        }
+    }
+}
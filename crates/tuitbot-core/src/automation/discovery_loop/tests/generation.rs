@@ -0,0 +1,69 @@
+use super::*;
+
+/// Generator that sleeps and records how many calls overlap.
+struct SlowGenerator {
+    in_flight: std::sync::atomic::AtomicUsize,
+    peak: std::sync::atomic::AtomicUsize,
+}
+
+#[async_trait::async_trait]
+impl ReplyGenerator for SlowGenerator {
+    async fn generate_reply(
+        &self,
+        tweet_text: &str,
+        _author: &str,
+        _mention_product: bool,
+    ) -> Result<String, LoopError> {
+        use std::sync::atomic::Ordering;
+        let now = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+        self.peak.fetch_max(now, Ordering::SeqCst);
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        self.in_flight.fetch_sub(1, Ordering::SeqCst);
+        Ok(format!("re: {tweet_text}"))
+    }
+}
+
+#[tokio::test]
+async fn candidates_are_generated_concurrently_and_posted_in_order() {
+    let tweets: Vec<LoopTweet> = ["100", "101", "102", "103"]
+        .iter()
+        .map(|id| test_tweet(id, &format!("user{id}")))
+        .collect();
+    let generator = Arc::new(SlowGenerator {
+        in_flight: Default::default(),
+        peak: Default::default(),
+    });
+    let poster = Arc::new(MockPoster::new());
+    let discovery = DiscoveryLoop::new(
+        Arc::new(MockSearcher { results: tweets }),
+        Arc::new(MockScorer {
+            score: 85.0,
+            meets_threshold: true,
+        }),
+        generator.clone(),
+        Arc::new(MockSafety::new(true)),
+        Arc::new(MockStorage::new()),
+        poster.clone(),
+        vec!["rust".to_string()],
+        70.0,
+        false,
+    )
+    .with_generation_concurrency(3);
+
+    let (results, summary) = discovery.search_and_process("rust", None).await.unwrap();
+
+    assert_eq!(summary.replied, 4);
+    assert_eq!(generator.peak.load(std::sync::atomic::Ordering::SeqCst), 3);
+    let sent: Vec<String> = poster
+        .sent
+        .lock()
+        .expect("lock")
+        .iter()
+        .map(|(id, _)| id.clone())
+        .collect();
+    assert_eq!(sent, vec!["100", "101", "102", "103"]);
+    assert!(matches!(
+        &results[1],
+        DiscoveryResult::Replied { reply_text, .. } if reply_text.contains("@user101")
+    ));
+}
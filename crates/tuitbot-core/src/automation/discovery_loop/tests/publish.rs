@@ -0,0 +1,105 @@
+use super::*;
+use crate::safety::trusted::TRUSTED_BYPASS_ACTION;
+
+#[tokio::test]
+async fn author_overlap_skips_or_queues_repeated_replies() {
+    let flag = DraftFlag {
+        code: "author_overlap".to_string(),
+        category: "brand".to_string(),
+        severity: "soft".to_string(),
+        message: "Reply overlaps 60% with one of the author's recent tweets.".to_string(),
+        tweet_index: None,
+    };
+    for verdict in [
+        OverlapVerdict::Hard(DenialReason::AuthorOverlap { similarity: 0.9 }),
+        OverlapVerdict::Soft(flag.clone()),
+    ] {
+        let queued = matches!(verdict, OverlapVerdict::Soft(_));
+        let poster = Arc::new(MockPoster::new());
+        let storage = Arc::new(MockStorage::new());
+        let discovery = DiscoveryLoop::new(
+            Arc::new(MockSearcher {
+                results: vec![test_tweet("100", "alice")],
+            }),
+            Arc::new(MockScorer {
+                score: 85.0,
+                meets_threshold: true,
+            }),
+            Arc::new(MockGenerator {
+                reply: "Caching fixed the slow build for me too".to_string(),
+            }),
+            Arc::new(MockSafety {
+                overlap: verdict,
+                ..MockSafety::new(true)
+            }),
+            storage.clone(),
+            poster.clone(),
+            vec!["rust".to_string()],
+            70.0,
+            false,
+        );
+
+        let (results, summary) = discovery.search_and_process("rust", None).await.unwrap();
+        assert_eq!(poster.sent_count(), 0);
+        if queued {
+            assert_eq!(summary.queued, 1);
+            let queued = storage.queued.lock().expect("lock");
+            assert_eq!(queued[0], ("100".to_string(), "author_overlap".to_string()));
+        } else {
+            assert_eq!(summary.skipped, 1);
+            assert!(matches!(
+                &results[0],
+                DiscoveryResult::Skipped { reason, .. } if reason.starts_with("Reply repeats")
+            ));
+        }
+    }
+}
+
+#[tokio::test]
+async fn trusted_author_waives_soft_flag_and_approval() {
+    let flag = DraftFlag {
+        code: "author_overlap".to_string(),
+        category: "brand".to_string(),
+        severity: "soft".to_string(),
+        message: "Reply overlaps 60% with one of the author's recent tweets.".to_string(),
+        tweet_index: None,
+    };
+    let poster = Arc::new(MockPoster::new());
+    let storage = Arc::new(MockStorage::new());
+    let discovery = DiscoveryLoop::new(
+        Arc::new(MockSearcher {
+            results: vec![test_tweet("100", "alice")],
+        }),
+        Arc::new(MockScorer {
+            score: 85.0,
+            meets_threshold: true,
+        }),
+        Arc::new(MockGenerator {
+            reply: "Caching fixed the slow build for me too".to_string(),
+        }),
+        Arc::new(MockSafety {
+            overlap: OverlapVerdict::Soft(flag),
+            trust: TrustBypass {
+                skip_approval: true,
+                skip_soft_qa: true,
+            },
+            ..MockSafety::new(true)
+        }),
+        storage.clone(),
+        poster.clone(),
+        vec!["rust".to_string()],
+        70.0,
+        false,
+    );
+
+    let (_results, summary) = discovery.search_and_process("rust", None).await.unwrap();
+    assert_eq!(summary.replied, 1);
+    assert_eq!(*poster.trusted.lock().expect("lock"), vec!["alice"]);
+    assert!(storage.queued.lock().expect("lock").is_empty());
+    let actions = storage.actions.lock().expect("lock").clone();
+    assert!(actions
+        .iter()
+        .any(|(kind, status, message)| kind == TRUSTED_BYPASS_ACTION
+            && status == "soft_qa"
+            && message.contains("flag author_overlap")));
+}
@@ -0,0 +1,64 @@
+use super::*;
+
+#[async_trait::async_trait]
+impl QuoteGenerator for MockGenerator {
+    async fn generate_quote(&self, _text: &str, _author: &str) -> Result<String, LoopError> {
+        Ok(format!("Quote: {}", self.reply))
+    }
+}
+
+fn quote_config(min_author_followers: u64) -> QuoteTweetConfig {
+    QuoteTweetConfig {
+        enabled: true,
+        min_score: 80.0,
+        min_author_followers,
+        min_keyword_matches: 1,
+    }
+}
+
+#[tokio::test]
+async fn high_reach_on_topic_tweet_is_quoted() {
+    let tweets = vec![test_tweet("100", "alice")];
+    let (discovery, poster, storage) = build_loop(tweets, 85.0, true, false);
+    let discovery = discovery.with_quotes(
+        Arc::new(MockGenerator {
+            reply: "worth a read".to_string(),
+        }),
+        quote_config(1_000),
+    );
+
+    let (results, summary) = discovery.search_and_process("rust", None).await.unwrap();
+
+    assert_eq!(summary.quoted, 1);
+    assert_eq!(summary.replied, 0);
+    assert!(matches!(results[0], DiscoveryResult::Quoted { .. }));
+    assert_eq!(poster.sent_count(), 0);
+    let quoted = poster.quoted.lock().expect("lock").clone();
+    assert_eq!(
+        quoted,
+        vec![("100".to_string(), "Quote: worth a read".to_string())]
+    );
+    let actions = storage.actions.lock().expect("lock").clone();
+    assert!(actions
+        .iter()
+        .any(|(t, s, _)| t == "discovery_quote" && s == "success"));
+}
+
+#[tokio::test]
+async fn small_author_gets_reply_instead_of_quote() {
+    let tweets = vec![test_tweet("100", "alice")];
+    let (discovery, poster, _) = build_loop(tweets, 85.0, true, false);
+    let discovery = discovery.with_quotes(
+        Arc::new(MockGenerator {
+            reply: "worth a read".to_string(),
+        }),
+        quote_config(50_000),
+    );
+
+    let (_, summary) = discovery.search_and_process("rust", None).await.unwrap();
+
+    assert_eq!(summary.quoted, 0);
+    assert_eq!(summary.replied, 1);
+    assert_eq!(poster.sent_count(), 1);
+    assert!(poster.quoted.lock().expect("lock").is_empty());
+}
@@ -0,0 +1,226 @@
+use super::*;
+use crate::automation::pacing::{PaceDecision, PacedEndpoint};
+
+#[tokio::test]
+async fn search_and_process_no_results() {
+    let (discovery, poster, _) = build_loop(Vec::new(), 80.0, true, false);
+    let (results, summary) = discovery.search_and_process("rust", None).await.unwrap();
+    assert_eq!(summary.tweets_found, 0);
+    assert!(results.is_empty());
+    assert_eq!(poster.sent_count(), 0);
+}
+
+#[tokio::test]
+async fn search_and_process_above_threshold() {
+    let tweets = vec![test_tweet("100", "alice"), test_tweet("101", "bob")];
+    let (discovery, poster, storage) = build_loop(tweets, 85.0, true, false);
+
+    let (results, summary) = discovery.search_and_process("rust", None).await.unwrap();
+
+    assert_eq!(summary.tweets_found, 2);
+    assert_eq!(summary.replied, 2);
+    assert_eq!(results.len(), 2);
+    assert_eq!(poster.sent_count(), 2);
+
+    // Both tweets should be stored as discovered
+    let discovered = storage.discovered.lock().expect("lock");
+    assert_eq!(discovered.len(), 2);
+}
+
+#[tokio::test]
+async fn search_and_process_below_threshold() {
+    let tweets = vec![test_tweet("100", "alice")];
+    let (discovery, poster, storage) = build_loop(tweets, 40.0, false, false);
+
+    let (results, summary) = discovery.search_and_process("rust", None).await.unwrap();
+
+    assert_eq!(summary.tweets_found, 1);
+    assert_eq!(summary.skipped, 1);
+    assert_eq!(summary.replied, 0);
+    assert_eq!(results.len(), 1);
+    assert_eq!(poster.sent_count(), 0);
+
+    // Tweet should still be stored as discovered (for analytics)
+    let discovered = storage.discovered.lock().expect("lock");
+    assert_eq!(discovered.len(), 1);
+}
+
+#[tokio::test]
+async fn search_and_process_dry_run() {
+    let tweets = vec![test_tweet("100", "alice")];
+    let (discovery, poster, _) = build_loop(tweets, 85.0, true, true);
+
+    let (_results, summary) = discovery.search_and_process("rust", None).await.unwrap();
+
+    assert_eq!(summary.replied, 1);
+    // Should NOT post in dry-run
+    assert_eq!(poster.sent_count(), 0);
+}
+
+#[tokio::test]
+async fn search_and_process_skips_existing() {
+    let tweets = vec![test_tweet("100", "alice")];
+    let poster = Arc::new(MockPoster::new());
+    let storage = Arc::new(MockStorage::new());
+    // Pre-mark tweet as existing
+    storage
+        .existing_ids
+        .lock()
+        .expect("lock")
+        .push("100".to_string());
+
+    let discovery = DiscoveryLoop::new(
+        Arc::new(MockSearcher { results: tweets }),
+        Arc::new(MockScorer {
+            score: 85.0,
+            meets_threshold: true,
+        }),
+        Arc::new(MockGenerator {
+            reply: "Great!".to_string(),
+        }),
+        Arc::new(MockSafety::new(true)),
+        storage,
+        poster.clone(),
+        vec!["rust".to_string()],
+        70.0,
+        false,
+    );
+
+    let (_results, summary) = discovery.search_and_process("rust", None).await.unwrap();
+    assert_eq!(summary.skipped, 1);
+    assert_eq!(poster.sent_count(), 0);
+}
+
+#[tokio::test]
+async fn search_and_process_respects_limit() {
+    let tweets = vec![
+        test_tweet("100", "alice"),
+        test_tweet("101", "bob"),
+        test_tweet("102", "carol"),
+    ];
+    let (discovery, poster, _) = build_loop(tweets, 85.0, true, false);
+
+    let (results, summary) = discovery.search_and_process("rust", Some(2)).await.unwrap();
+
+    assert_eq!(summary.tweets_found, 3); // found 3, but...
+    assert_eq!(results.len(), 2); // only 2 results returned
+    assert_eq!(poster.sent_count(), 2); // only processed 2
+}
+
+#[tokio::test]
+async fn run_once_searches_all_keywords() {
+    let tweets = vec![test_tweet("100", "alice")];
+    let (discovery, _, _) = build_loop(tweets, 85.0, true, false);
+
+    let (_, summary) = discovery.run_once(None).await.unwrap();
+    // Should search both "rust" and "cli" keywords
+    assert_eq!(summary.tweets_found, 2); // 1 tweet per keyword
+}
+
+#[tokio::test]
+async fn tweet_found_by_two_keywords_is_scored_once() {
+    let tweets = vec![test_tweet("100", "alice")];
+    let (discovery, poster, storage) = build_loop(tweets, 85.0, true, false);
+
+    let (_, summary) = discovery.run_once(None).await.unwrap();
+    assert_eq!(summary.replied, 1);
+    assert_eq!(summary.skipped, 1);
+    assert_eq!(poster.sent_count(), 1);
+    assert_eq!(*storage.discovered.lock().expect("lock"), vec!["100"]);
+    assert_eq!(
+        *storage.keyword_hits.lock().expect("lock"),
+        vec![
+            ("100".to_string(), "rust".to_string()),
+            ("100".to_string(), "cli".to_string()),
+        ]
+    );
+}
+
+/// Allows `allowed` searches, then defers; counts recorded searches.
+struct MockPacer {
+    allowed: usize,
+    recorded: Mutex<usize>,
+}
+
+#[async_trait::async_trait]
+impl RequestPacer for MockPacer {
+    async fn check(&self, _endpoint: PacedEndpoint) -> PaceDecision {
+        if *self.recorded.lock().expect("lock") < self.allowed {
+            PaceDecision::Proceed
+        } else {
+            PaceDecision::Defer {
+                wait: Duration::from_secs(60),
+            }
+        }
+    }
+
+    async fn record(&self, endpoint: PacedEndpoint) {
+        assert_eq!(endpoint, PacedEndpoint::Search);
+        *self.recorded.lock().expect("lock") += 1;
+    }
+}
+
+#[tokio::test]
+async fn run_once_stops_when_ahead_of_pace() {
+    let tweets = vec![test_tweet("100", "alice")];
+    let (discovery, _, _) = build_loop(tweets, 85.0, true, false);
+    let pacer = Arc::new(MockPacer {
+        allowed: 1,
+        recorded: Mutex::new(0),
+    });
+    let discovery = discovery.with_pacer(pacer.clone());
+
+    let (_, summary) = discovery.run_once(None).await.unwrap();
+    assert_eq!(summary.tweets_found, 1, "second keyword deferred");
+    assert_eq!(*pacer.recorded.lock().expect("lock"), 1);
+}
+
+struct FixedCalibrator(f32);
+
+#[async_trait::async_trait]
+impl ThresholdCalibrator for FixedCalibrator {
+    async fn effective_threshold(&self) -> Result<f32, LoopError> {
+        Ok(self.0)
+    }
+}
+
+#[tokio::test]
+async fn calibrated_threshold_overrides_scorer_verdict() {
+    let tweets = vec![test_tweet("100", "alice")];
+    let (discovery, poster, _) = build_loop(tweets.clone(), 85.0, true, false);
+    let discovery = discovery.with_calibrator(Arc::new(FixedCalibrator(90.0)));
+    let (results, _) = discovery.search_and_process("rust", None).await.unwrap();
+    assert!(matches!(results[0], DiscoveryResult::BelowThreshold { .. }));
+    assert_eq!(poster.sent_count(), 0);
+
+    let (discovery, poster, _) = build_loop(tweets, 65.0, false, false);
+    let discovery = discovery.with_calibrator(Arc::new(FixedCalibrator(60.0)));
+    let (results, _) = discovery.search_and_process("rust", None).await.unwrap();
+    assert!(matches!(results[0], DiscoveryResult::Replied { .. }));
+    assert_eq!(poster.sent_count(), 1);
+}
+
+#[tokio::test]
+async fn search_error_returns_loop_error() {
+    let poster = Arc::new(MockPoster::new());
+    let storage = Arc::new(MockStorage::new());
+    let discovery = DiscoveryLoop::new(
+        Arc::new(FailingSearcher),
+        Arc::new(MockScorer {
+            score: 85.0,
+            meets_threshold: true,
+        }),
+        Arc::new(MockGenerator {
+            reply: "test".to_string(),
+        }),
+        Arc::new(MockSafety::new(true)),
+        storage,
+        poster,
+        vec!["rust".to_string()],
+        70.0,
+        false,
+    );
+
+    let result = discovery.search_and_process("rust", None).await;
+    assert!(result.is_err());
+}
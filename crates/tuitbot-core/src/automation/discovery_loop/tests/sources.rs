@@ -0,0 +1,61 @@
+use super::*;
+
+struct MockSource {
+    items: Vec<LoopTweet>,
+    fail: bool,
+}
+
+#[async_trait::async_trait]
+impl crate::automation::DiscoverySource for MockSource {
+    fn name(&self) -> &str {
+        "hn"
+    }
+
+    async fn search(&self, _keyword: &str) -> Result<Vec<LoopTweet>, LoopError> {
+        if self.fail {
+            return Err(LoopError::NetworkError("down".to_string()));
+        }
+        Ok(self.items.clone())
+    }
+}
+
+fn with_source(discovery: DiscoveryLoop, items: Vec<LoopTweet>, fail: bool) -> DiscoveryLoop {
+    let mut sources = DiscoverySourceRegistry::new();
+    sources.register(Arc::new(MockSource { items, fail }));
+    discovery.with_sources(sources)
+}
+
+#[tokio::test]
+async fn source_items_are_scored_and_surfaced_not_replied() {
+    let (discovery, poster, storage) =
+        build_loop(vec![test_tweet("100", "alice")], 85.0, true, false);
+    let discovery = with_source(discovery, vec![test_tweet("hn:1", "pg")], false);
+
+    let (results, summary) = discovery.search_and_process("rust", None).await.unwrap();
+
+    assert_eq!(summary.tweets_found, 2);
+    assert_eq!(summary.replied, 1);
+    assert_eq!(summary.surfaced, 1);
+    assert_eq!(summary.qualifying, 2);
+    assert_eq!(poster.sent_count(), 1, "source items are never posted to");
+    assert!(matches!(
+        &results[1],
+        DiscoveryResult::Surfaced { item_id, source, .. } if item_id == "hn:1" && source == "hn"
+    ));
+    assert_eq!(
+        *storage.discovered.lock().expect("lock"),
+        vec!["100", "hn:1"]
+    );
+}
+
+#[tokio::test]
+async fn failing_source_does_not_fail_iteration() {
+    let (discovery, poster, _) = build_loop(vec![test_tweet("100", "alice")], 85.0, true, false);
+    let discovery = with_source(discovery, Vec::new(), true);
+
+    let (_, summary) = discovery.search_and_process("rust", None).await.unwrap();
+
+    assert_eq!(summary.replied, 1);
+    assert_eq!(summary.surfaced, 0);
+    assert_eq!(poster.sent_count(), 1);
+}
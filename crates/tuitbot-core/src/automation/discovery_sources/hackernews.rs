@@ -0,0 +1,226 @@
+//! Hacker News discovery source, backed by the Algolia HN search API.
+//!
+//! Searches stories by date and maps them to [`LoopTweet`]s: points become
+//! likes, comment count becomes replies, and the story URL is the only URL.
+
+use super::DiscoverySource;
+use crate::automation::loop_helpers::{LoopError, LoopTweet};
+use crate::config::HackerNewsSourceConfig;
+use serde::Deserialize;
+
+const ALGOLIA_BASE_URL: &str = "https://hn.algolia.com/api/v1";
+
+/// Hacker News story search.
+pub struct HackerNewsSource {
+    config: HackerNewsSourceConfig,
+    base_url: String,
+    http_client: reqwest::Client,
+}
+
+#[derive(Debug, Deserialize)]
+struct SearchResponse {
+    hits: Vec<Hit>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Hit {
+    #[serde(rename = "objectID")]
+    object_id: String,
+    title: Option<String>,
+    url: Option<String>,
+    author: Option<String>,
+    points: Option<u64>,
+    num_comments: Option<u64>,
+    created_at: Option<String>,
+    story_text: Option<String>,
+}
+
+impl HackerNewsSource {
+    pub fn new(config: HackerNewsSourceConfig) -> Self {
+        Self {
+            config,
+            base_url: ALGOLIA_BASE_URL.to_string(),
+            http_client: reqwest::Client::new(),
+        }
+    }
+
+    /// Point at a different API host (for testing with wiremock).
+    #[cfg(test)]
+    fn with_base_url(mut self, base_url: String) -> Self {
+        self.base_url = base_url;
+        self
+    }
+
+    fn to_item(&self, hit: Hit) -> Option<LoopTweet> {
+        let title = hit.title.filter(|t| !t.trim().is_empty())?;
+        let text = match hit.story_text.as_deref().map(strip_tags) {
+            Some(body) if !body.is_empty() => format!("{title}\n\n{body}"),
+            _ => title,
+        };
+        let author = hit.author.unwrap_or_default();
+        let discussion = format!("https://news.ycombinator.com/item?id={}", hit.object_id);
+        Some(LoopTweet {
+            id: format!("{}:{}", self.name(), hit.object_id),
+            text,
+            author_id: format!("{}:{author}", self.name()),
+            author_username: author,
+            author_followers: 0,
+            created_at: hit.created_at.unwrap_or_default(),
+            likes: hit.points.unwrap_or(0),
+            retweets: 0,
+            replies: hit.num_comments.unwrap_or(0),
+            quotes: 0,
+            urls: vec![hit.url.unwrap_or(discussion)],
+            has_media: false,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl DiscoverySource for HackerNewsSource {
+    fn name(&self) -> &str {
+        "hn"
+    }
+
+    async fn search(&self, keyword: &str) -> Result<Vec<LoopTweet>, LoopError> {
+        let since = chrono::Utc::now().timestamp() - i64::from(self.config.max_age_hours) * 3600;
+        let mut filters = format!("created_at_i>{since}");
+        if self.config.min_points > 0 {
+            filters.push_str(&format!(",points>={}", self.config.min_points));
+        }
+
+        let response = self
+            .http_client
+            .get(format!("{}/search_by_date", self.base_url))
+            .query(&[
+                ("query", keyword),
+                ("tags", "story"),
+                ("hitsPerPage", &self.config.max_results.to_string()),
+                ("numericFilters", &filters),
+            ])
+            .send()
+            .await
+            .map_err(|e| LoopError::NetworkError(format!("Hacker News search: {e}")))?;
+
+        let status = response.status();
+        if status == reqwest::StatusCode::TOO_MANY_REQUESTS {
+            return Err(LoopError::RateLimited { retry_after: None });
+        }
+        if !status.is_success() {
+            return Err(LoopError::NetworkError(format!(
+                "Hacker News search returned {status}"
+            )));
+        }
+
+        let body: SearchResponse = response
+            .json()
+            .await
+            .map_err(|e| LoopError::Other(format!("Hacker News search: {e}")))?;
+        Ok(body
+            .hits
+            .into_iter()
+            .filter_map(|hit| self.to_item(hit))
+            .collect())
+    }
+}
+
+/// Drop HTML tags from Algolia's `story_text` and collapse whitespace.
+fn strip_tags(html: &str) -> String {
+    let mut out = String::with_capacity(html.len());
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => {
+                in_tag = true;
+                out.push(' ');
+            }
+            '>' => in_tag = false,
+            _ if !in_tag => out.push(c),
+            _ => {}
+        }
+    }
+    out.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn source(server: &MockServer, config: HackerNewsSourceConfig) -> HackerNewsSource {
+        HackerNewsSource::new(config).with_base_url(server.uri())
+    }
+
+    #[tokio::test]
+    async fn search_maps_stories_to_candidates() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/search_by_date"))
+            .and(query_param("query", "rust"))
+            .and(query_param("tags", "story"))
+            .and(query_param("hitsPerPage", "5"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "hits": [
+                    {
+                        "objectID": "41234567",
+                        "title": "Show HN: A Rust CLI",
+                        "url": "https://example.com/cli",
+                        "author": "alice",
+                        "points": 120,
+                        "num_comments": 45,
+                        "created_at": "2026-10-15T12:00:00.000Z"
+                    },
+                    {
+                        "objectID": "41234568",
+                        "title": "Ask HN: Rust for scripting?",
+                        "author": "bob",
+                        "points": 3,
+                        "num_comments": 2,
+                        "created_at": "2026-10-15T13:00:00.000Z",
+                        "story_text": "<p>Anyone using <i>Rust</i> for scripts?</p>"
+                    },
+                    { "objectID": "41234569", "title": null }
+                ]
+            })))
+            .mount(&server)
+            .await;
+
+        let config = HackerNewsSourceConfig {
+            enabled: true,
+            max_results: 5,
+            ..Default::default()
+        };
+        let items = source(&server, config).search("rust").await.unwrap();
+
+        assert_eq!(items.len(), 2, "untitled hits are dropped");
+        assert_eq!(items[0].id, "hn:41234567");
+        assert_eq!(items[0].author_username, "alice");
+        assert_eq!(items[0].likes, 120);
+        assert_eq!(items[0].replies, 45);
+        assert_eq!(items[0].urls, vec!["https://example.com/cli"]);
+        assert_eq!(
+            items[1].text,
+            "Ask HN: Rust for scripting?\n\nAnyone using Rust for scripts?"
+        );
+        assert_eq!(
+            items[1].urls,
+            vec!["https://news.ycombinator.com/item?id=41234568"]
+        );
+    }
+
+    #[tokio::test]
+    async fn search_maps_rate_limit() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(429))
+            .mount(&server)
+            .await;
+
+        let err = source(&server, HackerNewsSourceConfig::default())
+            .search("rust")
+            .await
+            .unwrap_err();
+        assert!(matches!(err, LoopError::RateLimited { .. }));
+    }
+}
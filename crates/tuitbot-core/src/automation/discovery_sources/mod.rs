@@ -0,0 +1,109 @@
+//! Pluggable non-X discovery sources.
+//!
+//! A [`DiscoverySource`] searches somewhere other than X (Hacker News,
+//! forums, ...) for a keyword and returns candidates as [`LoopTweet`]s so
+//! they flow through the discovery loop's scoring and storage. Candidate
+//! ids are prefixed with the source name (`hn:41234567`) to keep them apart
+//! from tweet ids. Source items are never replied to; qualifying ones are
+//! surfaced for review.
+
+mod hackernews;
+
+pub use hackernews::HackerNewsSource;
+
+use super::loop_helpers::{LoopError, LoopTweet};
+use crate::config::DiscoverySourcesConfig;
+use std::sync::Arc;
+
+/// A searchable source of discovery candidates.
+#[async_trait::async_trait]
+pub trait DiscoverySource: Send + Sync {
+    /// Short identifier, also used as the candidate id prefix.
+    fn name(&self) -> &str;
+
+    /// Search for items matching `keyword`, newest first.
+    async fn search(&self, keyword: &str) -> Result<Vec<LoopTweet>, LoopError>;
+}
+
+/// The set of discovery sources enabled for a discovery loop.
+#[derive(Clone, Default)]
+pub struct DiscoverySourceRegistry {
+    sources: Vec<Arc<dyn DiscoverySource>>,
+}
+
+impl DiscoverySourceRegistry {
+    /// Create an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build the registry of sources enabled in config.
+    pub fn from_config(config: &DiscoverySourcesConfig) -> Self {
+        let mut registry = Self::new();
+        if config.hackernews.enabled {
+            registry.register(Arc::new(HackerNewsSource::new(config.hackernews.clone())));
+        }
+        registry
+    }
+
+    /// Add a source. A source with the same name replaces the earlier one.
+    pub fn register(&mut self, source: Arc<dyn DiscoverySource>) {
+        self.sources.retain(|s| s.name() != source.name());
+        self.sources.push(source);
+    }
+
+    /// Registered sources, in registration order.
+    pub fn sources(&self) -> &[Arc<dyn DiscoverySource>] {
+        &self.sources
+    }
+
+    /// Names of the registered sources.
+    pub fn names(&self) -> Vec<&str> {
+        self.sources.iter().map(|s| s.name()).collect()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.sources.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::HackerNewsSourceConfig;
+
+    struct NamedSource(&'static str);
+
+    #[async_trait::async_trait]
+    impl DiscoverySource for NamedSource {
+        fn name(&self) -> &str {
+            self.0
+        }
+
+        async fn search(&self, _keyword: &str) -> Result<Vec<LoopTweet>, LoopError> {
+            Ok(Vec::new())
+        }
+    }
+
+    #[test]
+    fn from_config_registers_enabled_sources() {
+        let mut config = DiscoverySourcesConfig::default();
+        assert!(DiscoverySourceRegistry::from_config(&config).is_empty());
+
+        config.hackernews = HackerNewsSourceConfig {
+            enabled: true,
+            ..Default::default()
+        };
+        let registry = DiscoverySourceRegistry::from_config(&config);
+        assert_eq!(registry.names(), vec!["hn"]);
+    }
+
+    #[test]
+    fn register_replaces_same_name() {
+        let mut registry = DiscoverySourceRegistry::new();
+        registry.register(Arc::new(NamedSource("hn")));
+        registry.register(Arc::new(NamedSource("reddit")));
+        registry.register(Arc::new(NamedSource("hn")));
+        assert_eq!(registry.names(), vec!["reddit", "hn"]);
+    }
+}
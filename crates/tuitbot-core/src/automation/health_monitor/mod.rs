@@ -0,0 +1,320 @@
+//! Account health monitor: periodic visibility self-checks.
+//!
+//! Searches for the account's own recent tweets and replies the way a
+//! logged-out reader would (keyword search scoped to the author) and
+//! compares what was expected against what the search actually returns.
+//! A low hit rate is a common signal of a search ban or reply deboosting.
+//!
+//! When visibility looks degraded the monitor logs a warning, records a
+//! `visibility_check` action for the activity feed, and raises the circuit
+//! breaker's delay multiplier so every posting path slows down until a later
+//! check comes back healthy.
+
+#[cfg(test)]
+mod tests;
+
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use tokio_util::sync::CancellationToken;
+
+use super::circuit_breaker::CircuitBreaker;
+use crate::config::HealthMonitorConfig;
+use crate::error::{StorageError, XApiError};
+use crate::storage::account_health::{self, NewAccountHealthCheck};
+use crate::storage::{self, DbPool};
+use crate::x_api::XApiClient;
+
+/// Posts younger than this may not be indexed by search yet.
+const MIN_POST_AGE: Duration = Duration::from_secs(30 * 60);
+
+/// Recent search only covers the last seven days; stay inside that window.
+const MAX_POST_AGE: Duration = Duration::from_secs(6 * 24 * 60 * 60);
+
+/// Fewer probes than this yields an `unknown` status instead of a verdict.
+const MIN_SAMPLES: usize = 3;
+
+/// Maximum number of keywords used in a probe query.
+const MAX_QUERY_KEYWORDS: usize = 4;
+
+/// Result of a single visibility check.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HealthStatus {
+    Healthy,
+    Degraded,
+    Unknown,
+}
+
+impl HealthStatus {
+    /// Storage / API string for this status.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Healthy => "healthy",
+            Self::Degraded => "degraded",
+            Self::Unknown => "unknown",
+        }
+    }
+}
+
+/// Outcome of looking up one of our own posts via search.
+#[derive(Debug, Clone, Serialize)]
+pub struct VisibilityProbe {
+    /// `tweet` or `reply`.
+    pub kind: &'static str,
+    pub tweet_id: String,
+    pub found: bool,
+}
+
+/// Aggregated result of a visibility check.
+#[derive(Debug, Clone)]
+pub struct HealthReport {
+    pub status: HealthStatus,
+    pub probes: Vec<VisibilityProbe>,
+    /// Posting delay multiplier to apply after this check.
+    pub slowdown_factor: f64,
+}
+
+impl HealthReport {
+    fn count(&self, kind: &str, found_only: bool) -> i64 {
+        self.probes
+            .iter()
+            .filter(|p| p.kind == kind && (!found_only || p.found))
+            .count() as i64
+    }
+}
+
+/// Run the account health monitor loop.
+///
+/// Resolves the authenticated username once, then performs a visibility
+/// check every `config.check_interval_seconds`. The delay multiplier on
+/// `circuit_breaker` is raised to `config.slowdown_factor` while visibility
+/// is degraded and reset once a check comes back healthy.
+pub async fn run_account_health_monitor(
+    pool: DbPool,
+    x_client: Arc<dyn XApiClient>,
+    circuit_breaker: Option<Arc<CircuitBreaker>>,
+    config: HealthMonitorConfig,
+    cancel: CancellationToken,
+) {
+    tracing::info!(
+        interval_secs = config.check_interval_seconds,
+        "Account health monitor started"
+    );
+
+    let interval = Duration::from_secs(config.check_interval_seconds);
+    let mut username: Option<String> = None;
+
+    loop {
+        // Wait a full interval first so freshly posted content has time to index.
+        tokio::select! {
+            biased;
+            () = cancel.cancelled() => {
+                tracing::info!("Account health monitor received cancellation");
+                break;
+            }
+            () = tokio::time::sleep(interval) => {}
+        }
+
+        if username.is_none() {
+            match x_client.get_me().await {
+                Ok(user) => username = Some(user.username),
+                Err(e) => {
+                    tracing::warn!(error = %e, "Health monitor could not resolve account");
+                    continue;
+                }
+            }
+        }
+        let Some(name) = username.as_deref() else {
+            continue;
+        };
+
+        match run_health_check(&pool, &*x_client, name, &config).await {
+            Ok(report) => {
+                // Too few samples to judge: keep whatever slowdown is in effect.
+                if report.status != HealthStatus::Unknown {
+                    if let Some(cb) = &circuit_breaker {
+                        cb.set_delay_multiplier(report.slowdown_factor);
+                    }
+                }
+            }
+            Err(e) => {
+                tracing::warn!(error = %e, "Account health check failed");
+            }
+        }
+    }
+
+    tracing::info!("Account health monitor stopped");
+}
+
+/// Perform one visibility check and record the result.
+///
+/// Search failures on individual probes are skipped; a rate limit ends
+/// probing early and the report is built from whatever was collected.
+pub async fn run_health_check(
+    pool: &DbPool,
+    client: &dyn XApiClient,
+    username: &str,
+    config: &HealthMonitorConfig,
+) -> Result<HealthReport, StorageError> {
+    let now = Utc::now();
+    let limit = config.sample_size as usize;
+    let mut probes = Vec::new();
+
+    // Over-fetch so age filtering still leaves a full sample.
+    let tweets = storage::threads::get_recent_original_tweets(pool, config.sample_size * 4).await?;
+    let tweet_targets = tweets
+        .iter()
+        .filter(|t| t.status == "sent" && in_probe_window(&t.created_at, now))
+        .filter_map(|t| Some((t.tweet_id.clone()?, t.content.as_str())))
+        .take(limit);
+
+    let replies = storage::replies::get_recent_replies(pool, config.sample_size * 4, 0).await?;
+    let reply_targets = replies
+        .iter()
+        .filter(|r| r.status == "sent" && in_probe_window(&r.created_at, now))
+        .filter_map(|r| Some((r.reply_tweet_id.clone()?, r.reply_content.as_str())))
+        .take(limit);
+
+    let targets = tweet_targets
+        .map(|(id, text)| ("tweet", id, text))
+        .chain(reply_targets.map(|(id, text)| ("reply", id, text)));
+
+    for (kind, tweet_id, text) in targets {
+        let Some(query) = build_probe_query(username, text, kind == "reply") else {
+            continue;
+        };
+        match client.search_tweets(&query, 100, None, None).await {
+            Ok(resp) => {
+                let found = resp.data.iter().any(|t| t.id == tweet_id);
+                probes.push(VisibilityProbe {
+                    kind,
+                    tweet_id,
+                    found,
+                });
+            }
+            Err(XApiError::RateLimited { .. }) => {
+                tracing::debug!("Health check rate limited, stopping early");
+                break;
+            }
+            Err(e) => {
+                tracing::debug!(error = %e, tweet_id = %tweet_id, "Visibility probe failed");
+            }
+        }
+    }
+
+    let status = assess_visibility(&probes, config.min_visible_ratio);
+    let slowdown_factor = match status {
+        HealthStatus::Degraded => config.slowdown_factor,
+        HealthStatus::Healthy | HealthStatus::Unknown => 1.0,
+    };
+    let report = HealthReport {
+        status,
+        probes,
+        slowdown_factor,
+    };
+
+    let details = serde_json::to_string(&report.probes).unwrap_or_else(|_| "[]".to_string());
+    let tweets_checked = report.count("tweet", false);
+    let tweets_found = report.count("tweet", true);
+    let replies_checked = report.count("reply", false);
+    let replies_visible = report.count("reply", true);
+
+    account_health::insert_health_check(
+        pool,
+        &NewAccountHealthCheck {
+            status: status.as_str(),
+            tweets_checked,
+            tweets_found,
+            replies_checked,
+            replies_visible,
+            slowdown_factor,
+            details: &details,
+        },
+    )
+    .await?;
+
+    let message = format!(
+        "Visibility {}: {tweets_found}/{tweets_checked} tweets and \
+         {replies_visible}/{replies_checked} replies found in search",
+        status.as_str()
+    );
+    if status == HealthStatus::Degraded {
+        tracing::warn!(
+            tweets_found,
+            tweets_checked,
+            replies_visible,
+            replies_checked,
+            slowdown_factor,
+            "Account visibility appears degraded, slowing down posting"
+        );
+    } else {
+        tracing::info!("{message}");
+    }
+    let _ = storage::action_log::log_action(
+        pool,
+        "visibility_check",
+        status.as_str(),
+        Some(&message),
+        None,
+    )
+    .await;
+
+    Ok(report)
+}
+
+/// Classify a set of probes against the minimum visible ratio.
+pub fn assess_visibility(probes: &[VisibilityProbe], min_visible_ratio: f64) -> HealthStatus {
+    if probes.len() < MIN_SAMPLES {
+        return HealthStatus::Unknown;
+    }
+    let found = probes.iter().filter(|p| p.found).count();
+    let ratio = found as f64 / probes.len() as f64;
+    if ratio < min_visible_ratio {
+        HealthStatus::Degraded
+    } else {
+        HealthStatus::Healthy
+    }
+}
+
+/// Build an author-scoped keyword search for one of our posts.
+///
+/// Picks the longest distinct words (skipping URLs, mentions, and hashtags)
+/// so the query is specific without depending on exact punctuation.
+/// Returns `None` when the text has no usable keywords.
+pub fn build_probe_query(username: &str, text: &str, is_reply: bool) -> Option<String> {
+    let mut seen = HashSet::new();
+    let mut words: Vec<String> = text
+        .split_whitespace()
+        .filter(|w| !w.starts_with("http") && !w.starts_with('@') && !w.starts_with('#'))
+        .map(|w| {
+            w.trim_matches(|c: char| !c.is_alphanumeric())
+                .to_lowercase()
+        })
+        .filter(|w| w.chars().count() >= 3 && w.chars().all(char::is_alphanumeric))
+        .filter(|w| seen.insert(w.clone()))
+        .collect();
+
+    if words.is_empty() {
+        return None;
+    }
+    // Stable sort keeps original order among equal lengths.
+    words.sort_by_key(|w| std::cmp::Reverse(w.chars().count()));
+    words.truncate(MAX_QUERY_KEYWORDS);
+
+    let scope = if is_reply { " is:reply" } else { "" };
+    Some(format!("from:{username}{scope} {}", words.join(" ")))
+}
+
+/// Whether a post timestamp falls inside the window search can reliably see.
+fn in_probe_window(created_at: &str, now: DateTime<Utc>) -> bool {
+    let Ok(ts) = DateTime::parse_from_rfc3339(created_at) else {
+        return false;
+    };
+    let Ok(age) = (now - ts.with_timezone(&Utc)).to_std() else {
+        return false;
+    };
+    age >= MIN_POST_AGE && age <= MAX_POST_AGE
+}
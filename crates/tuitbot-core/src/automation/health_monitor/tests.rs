@@ -0,0 +1,239 @@
+use super::*;
+use crate::error::XApiError;
+use crate::storage::init_test_db;
+use crate::storage::replies::ReplySent;
+use crate::storage::threads::OriginalTweet;
+use crate::x_api::types::*;
+
+/// Returns the given tweet IDs for every search, regardless of query.
+struct SearchMock {
+    visible: Vec<String>,
+}
+
+#[async_trait::async_trait]
+impl XApiClient for SearchMock {
+    async fn search_tweets(
+        &self,
+        _: &str,
+        _: u32,
+        _: Option<&str>,
+        _: Option<&str>,
+    ) -> Result<SearchResponse, XApiError> {
+        Ok(SearchResponse {
+            data: self
+                .visible
+                .iter()
+                .map(|id| Tweet {
+                    id: id.clone(),
+                    text: String::new(),
+                    author_id: "me".into(),
+                    created_at: String::new(),
+                    public_metrics: PublicMetrics::default(),
+                    conversation_id: None,
+                    entities: None,
+                    attachments: None,
+                })
+                .collect(),
+            includes: None,
+            meta: SearchMeta {
+                newest_id: None,
+                oldest_id: None,
+                result_count: self.visible.len() as u32,
+                next_token: None,
+            },
+        })
+    }
+    async fn get_mentions(
+        &self,
+        _: &str,
+        _: Option<&str>,
+        _: Option<&str>,
+    ) -> Result<MentionResponse, XApiError> {
+        unimplemented!()
+    }
+    async fn post_tweet(&self, _: &str) -> Result<PostedTweet, XApiError> {
+        unimplemented!()
+    }
+    async fn reply_to_tweet(&self, _: &str, _: &str) -> Result<PostedTweet, XApiError> {
+        unimplemented!()
+    }
+    async fn get_tweet(&self, _: &str) -> Result<Tweet, XApiError> {
+        unimplemented!()
+    }
+    async fn get_me(&self) -> Result<User, XApiError> {
+        unimplemented!()
+    }
+    async fn get_user_tweets(
+        &self,
+        _: &str,
+        _: u32,
+        _: Option<&str>,
+    ) -> Result<SearchResponse, XApiError> {
+        unimplemented!()
+    }
+    async fn get_user_by_username(&self, _: &str) -> Result<User, XApiError> {
+        unimplemented!()
+    }
+}
+
+fn hours_ago(hours: i64) -> String {
+    (Utc::now() - chrono::Duration::hours(hours))
+        .format("%Y-%m-%dT%H:%M:%SZ")
+        .to_string()
+}
+
+async fn seed_posts(pool: &DbPool) {
+    for i in 0..3 {
+        storage::threads::insert_original_tweet(
+            pool,
+            &OriginalTweet {
+                id: 0,
+                tweet_id: Some(format!("t{i}")),
+                content: format!("Rust ownership explained simply, part {i}"),
+                topic: None,
+                llm_provider: None,
+                created_at: hours_ago(2 + i),
+                status: "sent".into(),
+                error_message: None,
+                inspiration: storage::threads::INSPIRATION_CALENDAR.into(),
+            },
+        )
+        .await
+        .expect("insert tweet");
+    }
+    // Too fresh to be indexed; must not be probed.
+    storage::threads::insert_original_tweet(
+        pool,
+        &OriginalTweet {
+            id: 0,
+            tweet_id: Some("fresh".into()),
+            content: "Brand new tweet about borrowing".into(),
+            topic: None,
+            llm_provider: None,
+            created_at: Utc::now().format("%Y-%m-%dT%H:%M:%SZ").to_string(),
+            status: "sent".into(),
+            error_message: None,
+            inspiration: storage::threads::INSPIRATION_CALENDAR.into(),
+        },
+    )
+    .await
+    .expect("insert tweet");
+    storage::replies::insert_reply(
+        pool,
+        &ReplySent {
+            id: 0,
+            target_tweet_id: "target".into(),
+            reply_tweet_id: Some("r1".into()),
+            reply_content: "Lifetimes become intuitive with practice".into(),
+            llm_provider: None,
+            llm_model: None,
+            created_at: hours_ago(3),
+            status: "sent".into(),
+            error_message: None,
+        },
+    )
+    .await
+    .expect("insert reply");
+}
+
+fn probe(found: bool) -> VisibilityProbe {
+    VisibilityProbe {
+        kind: "tweet",
+        tweet_id: "1".into(),
+        found,
+    }
+}
+
+#[test]
+fn probe_query_uses_longest_keywords() {
+    let q = build_probe_query(
+        "alice",
+        "Check https://x.com/a @bob #rust: ownership, borrowing, and lifetimes rule!",
+        false,
+    )
+    .unwrap();
+    assert_eq!(q, "from:alice ownership borrowing lifetimes check");
+}
+
+#[test]
+fn probe_query_scopes_replies() {
+    let q = build_probe_query("alice", "Totally agree here", true).unwrap();
+    assert_eq!(q, "from:alice is:reply totally agree here");
+}
+
+#[test]
+fn probe_query_none_without_keywords() {
+    assert!(build_probe_query("alice", "@bob ok! #tag", false).is_none());
+}
+
+#[test]
+fn assess_requires_minimum_samples() {
+    assert_eq!(
+        assess_visibility(&[probe(false), probe(false)], 0.5),
+        HealthStatus::Unknown
+    );
+}
+
+#[test]
+fn assess_compares_against_ratio() {
+    let mostly_missing = [probe(true), probe(false), probe(false)];
+    assert_eq!(
+        assess_visibility(&mostly_missing, 0.5),
+        HealthStatus::Degraded
+    );
+    let mostly_found = [probe(true), probe(true), probe(false)];
+    assert_eq!(assess_visibility(&mostly_found, 0.5), HealthStatus::Healthy);
+}
+
+#[test]
+fn probe_window_excludes_fresh_and_stale_posts() {
+    let now = Utc::now();
+    assert!(in_probe_window(&hours_ago(2), now));
+    assert!(!in_probe_window(&hours_ago(0), now));
+    assert!(!in_probe_window(&hours_ago(24 * 8), now));
+    assert!(!in_probe_window("not a date", now));
+}
+
+#[tokio::test]
+async fn health_check_detects_degraded_visibility() {
+    let pool = init_test_db().await.expect("init db");
+    seed_posts(&pool).await;
+    let client = SearchMock {
+        visible: vec!["t0".into()],
+    };
+
+    let report = run_health_check(&pool, &client, "me", &HealthMonitorConfig::default())
+        .await
+        .expect("check");
+
+    assert_eq!(report.status, HealthStatus::Degraded);
+    assert_eq!(report.probes.len(), 4);
+    assert!(report.probes.iter().all(|p| p.tweet_id != "fresh"));
+    assert_eq!(report.slowdown_factor, 3.0);
+
+    let latest = account_health::get_latest_health_check(&pool)
+        .await
+        .expect("latest")
+        .expect("recorded");
+    assert_eq!(latest.status, "degraded");
+    assert_eq!(latest.tweets_checked, 3);
+    assert_eq!(latest.tweets_found, 1);
+    assert_eq!(latest.replies_checked, 1);
+    assert_eq!(latest.replies_visible, 0);
+}
+
+#[tokio::test]
+async fn health_check_healthy_when_posts_found() {
+    let pool = init_test_db().await.expect("init db");
+    seed_posts(&pool).await;
+    let client = SearchMock {
+        visible: vec!["t0".into(), "t1".into(), "t2".into(), "r1".into()],
+    };
+
+    let report = run_health_check(&pool, &client, "me", &HealthMonitorConfig::default())
+        .await
+        .expect("check");
+
+    assert_eq!(report.status, HealthStatus::Healthy);
+    assert_eq!(report.slowdown_factor, 1.0);
+}
@@ -8,6 +8,15 @@
 use std::fmt;
 use std::time::Duration;
 
+use super::trending::TrendTopic;
+use crate::safety::{
+    trusted, ContextVerdict, DenialReason, OverlapVerdict, ReciprocityVerdict, TrustBypass,
+};
+use crate::scoring::EntityType;
+use crate::storage::keyword_hits::KeywordCluster;
+use crate::storage::reply_explanations::{AncestorRef, ReplyExplanation, ScoreSignals};
+use crate::workflow::draft_workspace::DraftFlag;
+
 // ============================================================================
 // WP08 types: Mentions + Discovery loops
 // ============================================================================
@@ -36,6 +45,12 @@ pub struct LoopTweet {
     pub retweets: u64,
     /// Number of replies.
     pub replies: u64,
+    /// Number of quote tweets.
+    pub quotes: u64,
+    /// Expanded URLs from the tweet's entities.
+    pub urls: Vec<String>,
+    /// Whether the tweet has attached media.
+    pub has_media: bool,
 }
 
 /// Result of scoring a tweet for reply-worthiness.
@@ -45,10 +60,45 @@ pub struct ScoreResult {
     pub total: f32,
     /// Whether the score meets the configured threshold.
     pub meets_threshold: bool,
+    /// Whether the tweet is older than the freshness cutoff.
+    pub past_cutoff: bool,
     /// Keywords that matched in the tweet.
     pub matched_keywords: Vec<String>,
+    /// Detected entity type (Spaces, article, media-only, or text).
+    pub entity_type: EntityType,
+    /// Per-signal breakdown, when the scorer provides one.
+    pub signals: Option<ScoreSignals>,
+}
+
+/// A generated reply together with the choices that shaped it.
+#[derive(Debug, Clone, Default)]
+pub struct GeneratedReply {
+    /// Reply text.
+    pub text: String,
+    /// Archetype used to steer generation, if any.
+    pub archetype: Option<String>,
+    /// Winning ancestors injected into the prompt.
+    pub ancestors: Vec<AncestorRef>,
+    /// Content pillar whose override shaped the reply, if any.
+    pub topic_override: Option<String>,
+    /// The pillar override requires approval before the reply is posted.
+    pub require_approval: bool,
+}
+
+impl GeneratedReply {
+    /// Why the reply must go to the approval queue, when its pillar
+    /// override requires approval.
+    pub fn approval_reason(&self) -> Option<String> {
+        match (&self.topic_override, self.require_approval) {
+            (Some(pillar), true) => Some(format!("{TOPIC_APPROVAL_RISK}: {pillar}")),
+            _ => None,
+        }
+    }
 }
 
+/// Approval-queue risk recorded for replies held by a pillar override.
+pub const TOPIC_APPROVAL_RISK: &str = "topic_override";
+
 /// Errors that can occur in mentions/discovery automation loops.
 ///
 /// Wraps specific error categories to enable appropriate handling
@@ -152,6 +202,32 @@ pub trait ReplyGenerator: Send + Sync {
         author: &str,
         mention_product: bool,
     ) -> Result<String, LoopError>;
+
+    /// Generate a reply and report the archetype, ancestors, and pillar
+    /// override behind it.
+    ///
+    /// The default implementation reports none of them.
+    async fn generate_reply_explained(
+        &self,
+        tweet_text: &str,
+        author: &str,
+        mention_product: bool,
+    ) -> Result<GeneratedReply, LoopError> {
+        let text = self
+            .generate_reply(tweet_text, author, mention_product)
+            .await?;
+        Ok(GeneratedReply {
+            text,
+            ..GeneratedReply::default()
+        })
+    }
+}
+
+/// Port for generating quote-tweet commentary via LLM.
+#[async_trait::async_trait]
+pub trait QuoteGenerator: Send + Sync {
+    /// Generate commentary for quote-tweeting the given tweet.
+    async fn generate_quote(&self, tweet_text: &str, author: &str) -> Result<String, LoopError>;
 }
 
 /// Port for safety checks (rate limits and dedup).
@@ -165,6 +241,60 @@ pub trait SafetyChecker: Send + Sync {
 
     /// Record a reply for dedup and rate limit tracking.
     async fn record_reply(&self, tweet_id: &str, reply_content: &str) -> Result<(), LoopError>;
+
+    /// Check whether the tweet's author is a protected entity the agent
+    /// never replies to or quotes.
+    ///
+    /// Required, so no implementation can opt out by omission.
+    fn check_protected_author(&self, tweet: &LoopTweet) -> Result<(), DenialReason>;
+
+    /// What replies to the tweet's author may skip (approval, soft QA).
+    ///
+    /// The default implementation trusts no one.
+    fn trusted_bypass(&self, _tweet: &LoopTweet) -> TrustBypass {
+        TrustBypass::default()
+    }
+
+    /// Check whether the tweet's author is muted or blocked on X, or on
+    /// the local blocklist.
+    ///
+    /// The default implementation never blocks anyone.
+    async fn check_blocked_author(&self, _tweet: &LoopTweet) -> Result<(), DenialReason> {
+        Ok(())
+    }
+
+    /// Check whether replies to the tweet's author have been one-sided.
+    ///
+    /// The default implementation never flags anything.
+    async fn check_reciprocity(&self, _tweet: &LoopTweet) -> ReciprocityVerdict {
+        ReciprocityVerdict::Clear
+    }
+
+    /// Check a reply target for controversy signals.
+    ///
+    /// The default implementation never flags anything.
+    async fn check_context(&self, _tweet: &LoopTweet) -> ContextVerdict {
+        ContextVerdict::Clear
+    }
+
+    /// Compare a drafted reply with the target author's own recent tweets.
+    ///
+    /// The default implementation never flags anything.
+    async fn check_author_overlap(&self, _tweet: &LoopTweet, _reply: &str) -> OverlapVerdict {
+        OverlapVerdict::Clear
+    }
+
+    /// Record a reply along with the explanation of why it was sent.
+    ///
+    /// The default implementation drops the explanation.
+    async fn record_reply_explained(
+        &self,
+        tweet_id: &str,
+        reply_content: &str,
+        _explanation: ReplyExplanation,
+    ) -> Result<(), LoopError> {
+        self.record_reply(tweet_id, reply_content).await
+    }
 }
 
 /// Port for scoring tweets.
@@ -173,6 +303,13 @@ pub trait TweetScorer: Send + Sync {
     fn score(&self, tweet: &LoopTweet) -> ScoreResult;
 }
 
+/// Port for reading the auto-calibrated score threshold.
+#[async_trait::async_trait]
+pub trait ThresholdCalibrator: Send + Sync {
+    /// Effective threshold for today, calibrating first on a new day.
+    async fn effective_threshold(&self) -> Result<f32, LoopError>;
+}
+
 /// Port for persisting loop state (since_id, discovered tweets, action log).
 #[async_trait::async_trait]
 pub trait LoopStorage: Send + Sync {
@@ -185,6 +322,21 @@ pub trait LoopStorage: Send + Sync {
     /// Check if a discovered tweet already exists (dedup by tweet ID).
     async fn tweet_exists(&self, tweet_id: &str) -> Result<bool, LoopError>;
 
+    /// Check if any keyword surfaced the tweet within `window` (shared dedup
+    /// cache). Defaults to the permanent [`tweet_exists`](Self::tweet_exists) check.
+    async fn tweet_seen_within(
+        &self,
+        tweet_id: &str,
+        _window: Duration,
+    ) -> Result<bool, LoopError> {
+        self.tweet_exists(tweet_id).await
+    }
+
+    /// Credit `keyword` with surfacing the tweet, for keyword performance stats.
+    async fn record_keyword_hit(&self, _tweet_id: &str, _keyword: &str) -> Result<(), LoopError> {
+        Ok(())
+    }
+
     /// Store a discovered tweet with its score and matched keyword.
     async fn store_discovered_tweet(
         &self,
@@ -193,6 +345,12 @@ pub trait LoopStorage: Send + Sync {
         keyword: &str,
     ) -> Result<(), LoopError>;
 
+    /// Record a mention or reply the author sent us, for the reciprocity
+    /// guard.
+    async fn record_inbound_engagement(&self, _tweet: &LoopTweet) -> Result<(), LoopError> {
+        Ok(())
+    }
+
     /// Log an action (for audit trail and status reporting).
     async fn log_action(
         &self,
@@ -200,6 +358,32 @@ pub trait LoopStorage: Send + Sync {
         status: &str,
         message: &str,
     ) -> Result<(), LoopError>;
+
+    /// Timestamps and messages of `action_type` entries logged within
+    /// `window`, oldest first. Storage without an action log fails.
+    async fn recent_action_messages(
+        &self,
+        _action_type: &str,
+        _window: Duration,
+    ) -> Result<Vec<(chrono::DateTime<chrono::Utc>, String)>, LoopError> {
+        Err(LoopError::Other("action history not available".to_string()))
+    }
+
+    /// Queue a reply for human approval instead of posting it.
+    ///
+    /// `risk` is a detected risk and `qa_flags` are QA findings that need
+    /// review. Storage without an approval queue rejects the reply.
+    async fn queue_reply_for_approval(
+        &self,
+        _tweet_id: &str,
+        _author: &str,
+        _content: &str,
+        _score: f32,
+        _risk: Option<&str>,
+        _qa_flags: &[DraftFlag],
+    ) -> Result<(), LoopError> {
+        Err(LoopError::Other("approval queue not available".to_string()))
+    }
 }
 
 /// Port for sending post actions to the posting queue.
@@ -207,6 +391,29 @@ pub trait LoopStorage: Send + Sync {
 pub trait PostSender: Send + Sync {
     /// Send a reply to a tweet through the posting queue.
     async fn send_reply(&self, tweet_id: &str, content: &str) -> Result<(), LoopError>;
+
+    /// Send a quote tweet of `tweet_id` through the posting queue.
+    async fn send_quote(&self, tweet_id: &str, content: &str) -> Result<(), LoopError>;
+
+    /// Send a reply to a trusted `author` with `skip_approval`: it is posted
+    /// even in approval mode.
+    ///
+    /// The default implementation sends it like any other reply.
+    async fn send_trusted_reply(
+        &self,
+        tweet_id: &str,
+        _author: &str,
+        content: &str,
+    ) -> Result<(), LoopError> {
+        self.send_reply(tweet_id, content).await
+    }
+}
+
+/// Port for liking tweets on behalf of the authenticated account.
+#[async_trait::async_trait]
+pub trait TweetLiker: Send + Sync {
+    /// Like `tweet_id`.
+    async fn like_tweet(&self, tweet_id: &str) -> Result<(), LoopError>;
 }
 
 // ============================================================================
@@ -220,11 +427,30 @@ pub trait TopicScorer: Send + Sync {
     async fn get_top_topics(&self, limit: u32) -> Result<Vec<String>, ContentLoopError>;
 }
 
+/// Supplies recent discovery clusters for trend-inspired tweets.
+#[async_trait::async_trait]
+pub trait TrendSource: Send + Sync {
+    /// Keyword clusters from recent discovery, largest first.
+    async fn trending_clusters(&self) -> Result<Vec<KeywordCluster>, ContentLoopError>;
+}
+
 /// Generates individual tweets on a given topic.
 #[async_trait::async_trait]
 pub trait TweetGenerator: Send + Sync {
     /// Generate an educational tweet on the given topic.
     async fn generate_tweet(&self, topic: &str) -> Result<String, ContentLoopError>;
+
+    /// Generate an original tweet on `topic` that joins the trending
+    /// conversation in `trend` without replying to it.
+    ///
+    /// Defaults to a plain tweet on `topic`.
+    async fn generate_trend_tweet(
+        &self,
+        topic: &str,
+        _trend: &TrendTopic,
+    ) -> Result<String, ContentLoopError> {
+        self.generate_tweet(topic).await
+    }
 }
 
 /// Checks safety limits for content posting.
@@ -255,7 +481,27 @@ pub trait ContentStorage: Send + Sync {
     ) -> Result<Vec<chrono::DateTime<chrono::Utc>>, ContentLoopError>;
 
     /// Post a tweet (sends to posting queue and records in DB).
-    async fn post_tweet(&self, topic: &str, content: &str) -> Result<(), ContentLoopError>;
+    ///
+    /// `inspiration` records where the topic came from (`calendar` or `trend`).
+    async fn post_tweet(
+        &self,
+        topic: &str,
+        content: &str,
+        inspiration: &str,
+    ) -> Result<(), ContentLoopError>;
+
+    /// Queue a tweet on a content `pillar` that requires approval, instead
+    /// of posting it. Storage without an approval queue rejects the tweet.
+    async fn queue_tweet_for_approval(
+        &self,
+        _topic: &str,
+        _pillar: &str,
+        _content: &str,
+    ) -> Result<(), ContentLoopError> {
+        Err(ContentLoopError::Other(
+            "approval queue not available".to_string(),
+        ))
+    }
 
     /// Create a thread record in the database. Returns the thread ID.
     async fn create_thread(
@@ -399,6 +645,42 @@ pub fn rate_limit_backoff(retry_after: Option<u64>, attempt: u32) -> Duration {
     }
 }
 
+/// Send a reply to `tweet`. Replies to a trusted author with
+/// `skip_approval` are posted even in approval mode.
+pub async fn send_reply_to(
+    poster: &dyn PostSender,
+    safety: &dyn SafetyChecker,
+    tweet: &LoopTweet,
+    content: &str,
+) -> Result<(), LoopError> {
+    if safety.trusted_bypass(tweet).skip_approval {
+        poster
+            .send_trusted_reply(&tweet.id, &tweet.author_username, content)
+            .await
+    } else {
+        poster.send_reply(&tweet.id, content).await
+    }
+}
+
+/// Audit message when a soft QA `flag` on a reply to `tweet` is waived
+/// because the author is trusted with `skip_soft_qa`; `None` otherwise.
+/// Callers log it as a [`trusted::TRUSTED_BYPASS_ACTION`] entry.
+pub fn soft_flag_waiver(
+    safety: &dyn SafetyChecker,
+    tweet: &LoopTweet,
+    flag: &DraftFlag,
+) -> Option<String> {
+    if !safety.trusted_bypass(tweet).skip_soft_qa {
+        return None;
+    }
+    let detail = format!("tweet {}, flag {}", tweet.id, flag.code);
+    Some(trusted::bypass_message(
+        &tweet.author_username,
+        "soft_qa",
+        &detail,
+    ))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -476,6 +758,9 @@ mod tests {
             likes: 10,
             retweets: 2,
             replies: 1,
+            quotes: 0,
+            urls: vec![],
+            has_media: false,
         };
         let debug = format!("{tweet:?}");
         assert!(debug.contains("123"));
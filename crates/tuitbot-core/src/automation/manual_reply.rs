@@ -0,0 +1,421 @@
+//! Manual replies: run the reply pipeline on one tweet picked by hand.
+//!
+//! Backs `tuitbot reply-to <tweet-url>`. The tweet goes through the same
+//! gates as a discovered one (already replied, protected and blocked
+//! authors, context check), is scored, and gets several candidate replies
+//! from the reply generator, each with a draft QA report. The caller then
+//! prints them, queues one for approval with [`queue_candidate`], or posts
+//! one directly.
+
+use serde::Serialize;
+
+use super::loop_helpers::{
+    GeneratedReply, LoopError, LoopTweet, ReplyGenerator, SafetyChecker, ScoreResult, TweetScorer,
+};
+use crate::config::{Config, PillarOverride};
+use crate::error::StorageError;
+use crate::safety::{ContextVerdict, OverlapVerdict};
+use crate::storage::accounts::DEFAULT_ACCOUNT_ID;
+use crate::storage::reply_explanations::ReplyExplanation;
+use crate::storage::{action_log, approval_queue, DbPool};
+use crate::toolkit;
+use crate::workflow::draft_workspace::{
+    evaluate_draft_with, store_approval_check, DraftCheck, DraftFlag,
+};
+use crate::x_api::XApiClient;
+
+/// Fewest and most candidates generated per tweet.
+pub const MIN_CANDIDATES: usize = 2;
+pub const MAX_CANDIDATES: usize = 3;
+
+/// One generated reply and its QA report.
+#[derive(Debug, Clone, Serialize)]
+pub struct ReplyCandidate {
+    pub text: String,
+    pub archetype: Option<String>,
+    pub check: DraftCheck,
+    #[serde(skip)]
+    generated: GeneratedReply,
+}
+
+/// The tweet, its score, and the candidate replies.
+#[derive(Debug, Clone, Serialize)]
+pub struct ManualReply {
+    pub tweet_id: String,
+    pub author: String,
+    pub text: String,
+    pub score: f32,
+    pub meets_threshold: bool,
+    pub matched_keywords: Vec<String>,
+    /// Why replies to this tweet must go through approval, if they must.
+    pub needs_review: Option<String>,
+    pub candidates: Vec<ReplyCandidate>,
+    #[serde(skip)]
+    score_result: Option<ScoreResult>,
+}
+
+impl ManualReply {
+    /// Whether candidate `index` may be posted without review: no hard QA
+    /// flags and no context risk.
+    pub fn can_send(&self, index: usize) -> Result<(), String> {
+        let candidate = self
+            .candidates
+            .get(index)
+            .ok_or_else(|| format!("there is no candidate {}", index + 1))?;
+        if let Some(reason) = &self.needs_review {
+            return Err(format!("{reason}; queue it for approval instead"));
+        }
+        if !candidate.check.passed {
+            let codes: Vec<&str> = candidate
+                .check
+                .hard_flags
+                .iter()
+                .map(|f| f.code.as_str())
+                .collect();
+            return Err(format!("candidate has hard QA flags: {}", codes.join(", ")));
+        }
+        Ok(())
+    }
+
+    /// The explanation recorded with a posted candidate.
+    pub fn explanation(&self, index: usize) -> ReplyExplanation {
+        let candidate = &self.candidates[index];
+        let score = self.score_result.as_ref();
+        ReplyExplanation {
+            source: "manual".to_string(),
+            matched_keywords: self.matched_keywords.clone(),
+            score: score.and_then(|s| s.signals.clone()),
+            threshold: None,
+            archetype: candidate.generated.archetype.clone(),
+            ancestors: candidate.generated.ancestors.clone(),
+            topic_override: candidate.generated.topic_override.clone(),
+            qa_score: Some(candidate.check.score),
+        }
+    }
+}
+
+/// Fetch a tweet and its author as a [`LoopTweet`].
+pub async fn fetch_tweet(client: &dyn XApiClient, tweet_id: &str) -> Result<LoopTweet, LoopError> {
+    let tweet = toolkit::read::get_tweet(client, tweet_id)
+        .await
+        .map_err(|e| LoopError::Other(format!("could not fetch tweet {tweet_id}: {e}")))?;
+    let author = toolkit::read::get_user_by_id(client, &tweet.author_id)
+        .await
+        .map_err(|e| LoopError::Other(format!("could not fetch the tweet's author: {e}")))?;
+    let urls = tweet.expanded_urls();
+    let has_media = tweet.has_media();
+    Ok(LoopTweet {
+        id: tweet.id,
+        text: tweet.text,
+        author_id: tweet.author_id,
+        author_username: author.username,
+        author_followers: author.public_metrics.followers_count,
+        created_at: tweet.created_at,
+        likes: tweet.public_metrics.like_count,
+        retweets: tweet.public_metrics.retweet_count,
+        replies: tweet.public_metrics.reply_count,
+        quotes: tweet.public_metrics.quote_count,
+        urls,
+        has_media,
+    })
+}
+
+/// Gate, score, and draft `count` candidate replies (clamped to 2–3) for
+/// `tweet`. Returns `Err(LoopError::Other)` with the reason when the tweet
+/// must not be replied to at all.
+pub async fn prepare(
+    scorer: &dyn TweetScorer,
+    generator: &dyn ReplyGenerator,
+    safety: &dyn SafetyChecker,
+    config: &Config,
+    tweet: &LoopTweet,
+    count: usize,
+    mention_product: bool,
+) -> Result<ManualReply, LoopError> {
+    if safety.has_replied_to(&tweet.id).await {
+        return Err(LoopError::Other(
+            "already replied to this tweet".to_string(),
+        ));
+    }
+    if let Err(denial) = safety.check_protected_author(tweet) {
+        return Err(LoopError::Other(denial.to_string()));
+    }
+    if let Err(denial) = safety.check_blocked_author(tweet).await {
+        return Err(LoopError::Other(denial.to_string()));
+    }
+    let needs_review = match safety.check_context(tweet).await {
+        ContextVerdict::Clear => None,
+        ContextVerdict::Skip(denial) => return Err(LoopError::Other(denial.to_string())),
+        ContextVerdict::RequireApproval(denial) => Some(denial.to_string()),
+    };
+    let pillar = config.business.pillar_override(&tweet.text);
+    let needs_review = needs_review.or_else(|| match pillar {
+        Some((name, o)) if o.require_approval => {
+            Some(format!("topic \"{name}\" requires approval"))
+        }
+        _ => None,
+    });
+
+    let score = scorer.score(tweet);
+    let mut candidates = Vec::new();
+    for _ in 0..count.clamp(MIN_CANDIDATES, MAX_CANDIDATES) {
+        let generated = generator
+            .generate_reply_explained(&tweet.text, &tweet.author_username, mention_product)
+            .await?;
+        if candidates
+            .iter()
+            .any(|c: &ReplyCandidate| c.text == generated.text)
+        {
+            continue;
+        }
+        let check = check_candidate(
+            safety,
+            config,
+            tweet,
+            &generated.text,
+            pillar.map(|(_, o)| o),
+        )
+        .await;
+        candidates.push(ReplyCandidate {
+            text: generated.text.clone(),
+            archetype: generated.archetype.clone(),
+            check,
+            generated,
+        });
+    }
+
+    Ok(ManualReply {
+        tweet_id: tweet.id.clone(),
+        author: tweet.author_username.clone(),
+        text: tweet.text.clone(),
+        score: score.total,
+        meets_threshold: score.meets_threshold,
+        matched_keywords: score.matched_keywords.clone(),
+        needs_review,
+        candidates,
+        score_result: Some(score),
+    })
+}
+
+/// Draft QA, under the tweet's pillar override, plus the author-overlap
+/// check.
+async fn check_candidate(
+    safety: &dyn SafetyChecker,
+    config: &Config,
+    tweet: &LoopTweet,
+    text: &str,
+    pillar: Option<&PillarOverride>,
+) -> DraftCheck {
+    let check = evaluate_draft_with(config, "reply", text, pillar);
+    let (mut hard, mut soft) = (check.hard_flags, check.soft_flags);
+    match safety.check_author_overlap(tweet, text).await {
+        OverlapVerdict::Clear => {}
+        OverlapVerdict::Soft(flag) => soft.push(flag),
+        OverlapVerdict::Hard(denial) => hard.push(DraftFlag {
+            code: "author_overlap".to_string(),
+            category: "brand".to_string(),
+            severity: "hard".to_string(),
+            message: denial.to_string(),
+            tweet_index: None,
+        }),
+    }
+    DraftCheck::from_flags(hard, soft)
+}
+
+/// Queue candidate `index` for approval with its QA report. Returns the
+/// approval queue ID.
+pub async fn queue_candidate(
+    pool: &DbPool,
+    reply: &ManualReply,
+    index: usize,
+) -> Result<i64, StorageError> {
+    let candidate = &reply.candidates[index];
+    let id = approval_queue::enqueue(
+        pool,
+        "reply",
+        &reply.tweet_id,
+        &reply.author,
+        &candidate.text,
+        reply
+            .matched_keywords
+            .first()
+            .map(String::as_str)
+            .unwrap_or(""),
+        candidate.archetype.as_deref().unwrap_or(""),
+        f64::from(reply.score),
+        "[]",
+    )
+    .await?;
+    store_approval_check(pool, DEFAULT_ACCOUNT_ID, id, &candidate.check).await?;
+    action_log::log_action(
+        pool,
+        "manual_reply",
+        "queued",
+        Some(&format!(
+            "Queued reply to @{} for approval (item {id})",
+            reply.author
+        )),
+        None,
+    )
+    .await?;
+    Ok(id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::safety::DenialReason;
+    use crate::scoring::EntityType;
+    use crate::storage::init_test_db;
+    use std::sync::Mutex;
+
+    struct FixedScorer;
+
+    impl TweetScorer for FixedScorer {
+        fn score(&self, _tweet: &LoopTweet) -> ScoreResult {
+            ScoreResult {
+                total: 72.0,
+                meets_threshold: true,
+                past_cutoff: false,
+                matched_keywords: vec!["rust".to_string()],
+                entity_type: EntityType::Text,
+                signals: None,
+            }
+        }
+    }
+
+    struct Replies(Mutex<Vec<&'static str>>);
+
+    #[async_trait::async_trait]
+    impl ReplyGenerator for Replies {
+        async fn generate_reply(&self, _: &str, _: &str, _: bool) -> Result<String, LoopError> {
+            Ok(self.0.lock().unwrap().remove(0).to_string())
+        }
+    }
+
+    struct Safety {
+        replied: bool,
+        context: ContextVerdict,
+    }
+
+    #[async_trait::async_trait]
+    impl SafetyChecker for Safety {
+        fn check_protected_author(&self, _tweet: &LoopTweet) -> Result<(), DenialReason> {
+            Ok(())
+        }
+        async fn can_reply(&self) -> bool {
+            true
+        }
+        async fn has_replied_to(&self, _tweet_id: &str) -> bool {
+            self.replied
+        }
+        async fn record_reply(&self, _: &str, _: &str) -> Result<(), LoopError> {
+            Ok(())
+        }
+        async fn check_context(&self, _tweet: &LoopTweet) -> ContextVerdict {
+            self.context.clone()
+        }
+    }
+
+    fn tweet() -> LoopTweet {
+        LoopTweet {
+            id: "42".to_string(),
+            text: "How do you speed up Rust builds?".to_string(),
+            author_id: "7".to_string(),
+            author_username: "alice".to_string(),
+            author_followers: 500,
+            created_at: String::new(),
+            likes: 3,
+            retweets: 0,
+            replies: 1,
+            quotes: 0,
+            urls: vec![],
+            has_media: false,
+        }
+    }
+
+    fn safety() -> Safety {
+        Safety {
+            replied: false,
+            context: ContextVerdict::Clear,
+        }
+    }
+
+    #[tokio::test]
+    async fn drafts_distinct_candidates_with_qa() {
+        let mut config = Config::default();
+        config.limits.banned_phrases = vec!["dm me".to_string()];
+        let generator = Replies(Mutex::new(vec![
+            "Try sccache.",
+            "Try sccache.",
+            "Just dm me",
+        ]));
+        let reply = prepare(
+            &FixedScorer,
+            &generator,
+            &safety(),
+            &config,
+            &tweet(),
+            5,
+            false,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(reply.score, 72.0);
+        // Three attempts; the duplicate is dropped.
+        assert_eq!(reply.candidates.len(), 2);
+        assert!(reply.can_send(0).is_ok());
+        assert!(reply.can_send(1).unwrap_err().contains("banned_phrase"));
+        assert!(reply.can_send(2).is_err());
+    }
+
+    #[tokio::test]
+    async fn refuses_replied_and_flags_risky_tweets() {
+        let config = Config::default();
+        let generator = Replies(Mutex::new(vec!["One.", "Two."]));
+        let replied = Safety {
+            replied: true,
+            ..safety()
+        };
+        let err = prepare(
+            &FixedScorer,
+            &generator,
+            &replied,
+            &config,
+            &tweet(),
+            2,
+            false,
+        )
+        .await
+        .unwrap_err();
+        assert!(err.to_string().contains("already replied"));
+
+        let risky = Safety {
+            context: ContextVerdict::RequireApproval(DenialReason::ControversialContext {
+                signal: "quote ratio 3.0".to_string(),
+            }),
+            ..safety()
+        };
+        let reply = prepare(
+            &FixedScorer,
+            &generator,
+            &risky,
+            &config,
+            &tweet(),
+            2,
+            false,
+        )
+        .await
+        .unwrap();
+        assert!(reply.needs_review.is_some());
+        assert!(reply.can_send(0).unwrap_err().contains("queue it"));
+
+        let pool = init_test_db().await.unwrap();
+        let id = queue_candidate(&pool, &reply, 1).await.unwrap();
+        let item = approval_queue::get_by_id(&pool, id).await.unwrap().unwrap();
+        assert_eq!(item.generated_content, "Two.");
+        assert_eq!(item.target_author, "alice");
+        assert_eq!(item.topic, "rust");
+    }
+}
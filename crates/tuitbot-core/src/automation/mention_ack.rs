@@ -0,0 +1,312 @@
+//! Mention triage and auto-acknowledgement of simple praise.
+//!
+//! [`triage_mention`] labels a mention with a rule-based classifier: no LLM
+//! is involved. When `[mention_ack]` is enabled, the mentions loop handles
+//! a mention labelled [`MentionLabel::Praise`] with enough confidence by
+//! liking it and, optionally, replying with a fixed template instead of
+//! generating a reply. [`AckPolicy`] enforces the hourly, daily, and
+//! per-author limits from the `mention_ack` action log entries, so they
+//! hold across restarts.
+
+use std::time::Duration;
+
+use crate::config::MentionAckConfig;
+
+use super::loop_helpers::LoopStorage;
+
+/// Action log type for acknowledgements, kept apart from `mention_reply`.
+pub const MENTION_ACK_ACTION: &str = "mention_ack";
+
+const HOUR: Duration = Duration::from_secs(3600);
+const DAY: Duration = Duration::from_secs(24 * 3600);
+
+/// Words that make a mention praise on their own.
+const PRAISE_WORDS: &[&str] = &[
+    "thanks",
+    "thank",
+    "thx",
+    "ty",
+    "appreciate",
+    "appreciated",
+    "awesome",
+    "amazing",
+    "brilliant",
+    "fantastic",
+    "excellent",
+    "great",
+    "love",
+    "loved",
+    "kudos",
+    "congrats",
+    "congratulations",
+    "legend",
+    "helpful",
+    "superb",
+    "wonderful",
+];
+
+/// Phrases that count as praise.
+const PRAISE_PHRASES: &[&str] = &["well done", "nice work", "nailed it", "so good", "big fan"];
+
+/// Emoji that count as praise.
+const PRAISE_EMOJI: &[&str] = &["🙏", "❤️", "🔥", "👏", "🙌", "💯"];
+
+/// Words that signal a complaint or a mixed message.
+const COMPLAINT_WORDS: &[&str] = &[
+    "but",
+    "however",
+    "unfortunately",
+    "not",
+    "don't",
+    "doesn't",
+    "didn't",
+    "can't",
+    "broken",
+    "bug",
+    "issue",
+    "problem",
+    "error",
+    "refund",
+    "scam",
+    "worst",
+    "hate",
+    "disappointed",
+];
+
+/// Words that open a question or a request.
+const QUESTION_OPENERS: &[&str] = &[
+    "how", "what", "why", "when", "where", "which", "who", "can", "could", "would", "do", "does",
+    "is", "are", "any",
+];
+
+/// Mentions longer than this are never labelled praise.
+const MAX_PRAISE_WORDS: usize = 25;
+
+/// What a mention is about.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MentionLabel {
+    /// Thanks or praise that needs no substantive answer.
+    Praise,
+    /// A question or request.
+    Question,
+    /// A complaint or a mixed message.
+    Complaint,
+    /// Anything else.
+    Other,
+}
+
+/// Triage result for one mention.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MentionTriage {
+    pub label: MentionLabel,
+    /// Confidence in `label`, 0.0-1.0.
+    pub confidence: f64,
+}
+
+/// Label `text`. Handles and links are ignored. Praise confidence grows
+/// with the number of praise signals and shrinks with length; any question
+/// or complaint signal rules praise out.
+pub fn triage_mention(text: &str) -> MentionTriage {
+    let words: Vec<String> = text
+        .split_whitespace()
+        .filter(|w| !w.starts_with('@') && !w.starts_with("http"))
+        .map(|w| {
+            w.trim_matches(|c: char| !c.is_alphanumeric() && c != '\'')
+                .to_lowercase()
+        })
+        .filter(|w| !w.is_empty())
+        .collect();
+    let joined = format!(" {} ", words.join(" "));
+
+    if text.contains('?')
+        || words
+            .first()
+            .is_some_and(|w| QUESTION_OPENERS.contains(&w.as_str()))
+    {
+        return MentionTriage {
+            label: MentionLabel::Question,
+            confidence: 0.8,
+        };
+    }
+    if words.iter().any(|w| COMPLAINT_WORDS.contains(&w.as_str())) {
+        return MentionTriage {
+            label: MentionLabel::Complaint,
+            confidence: 0.7,
+        };
+    }
+
+    let signals = words
+        .iter()
+        .filter(|w| PRAISE_WORDS.contains(&w.as_str()))
+        .count()
+        + PRAISE_PHRASES
+            .iter()
+            .filter(|p| joined.contains(&format!(" {p} ")))
+            .count()
+        + PRAISE_EMOJI.iter().filter(|e| text.contains(*e)).count();
+    if signals == 0 || words.len() > MAX_PRAISE_WORDS {
+        return MentionTriage {
+            label: MentionLabel::Other,
+            confidence: 0.5,
+        };
+    }
+
+    let extra = 0.1 * (signals - 1).min(2) as f64;
+    let brevity = match words.len() {
+        0..=6 => 0.25,
+        7..=12 => 0.15,
+        13..=20 => 0.05,
+        _ => 0.0,
+    };
+    MentionTriage {
+        label: MentionLabel::Praise,
+        confidence: (0.6 + extra + brevity).min(1.0),
+    }
+}
+
+/// How a mention was acknowledged.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Acknowledgement {
+    pub liked: bool,
+    pub reply_text: Option<String>,
+}
+
+impl Acknowledgement {
+    /// Action log message; also what the per-author limit looks for.
+    pub fn log_message(&self, author: &str, tweet_id: &str) -> String {
+        let mut done = Vec::new();
+        if self.liked {
+            done.push("liked".to_string());
+        }
+        if let Some(text) = &self.reply_text {
+            done.push(format!("replied \"{text}\""));
+        }
+        format!(
+            "Acknowledged {} mention {tweet_id}: {}",
+            author_tag(author),
+            done.join(", ")
+        )
+    }
+}
+
+/// Decides whether a mention is acknowledged instead of answered.
+#[derive(Debug, Clone)]
+pub struct AckPolicy {
+    config: MentionAckConfig,
+}
+
+impl AckPolicy {
+    pub fn new(config: MentionAckConfig) -> Self {
+        Self { config }
+    }
+
+    pub fn config(&self) -> &MentionAckConfig {
+        &self.config
+    }
+
+    /// Whether `text` is praise with enough confidence to acknowledge.
+    pub fn qualifies(&self, text: &str) -> bool {
+        let triage = triage_mention(text);
+        triage.label == MentionLabel::Praise && triage.confidence >= self.config.min_confidence
+    }
+
+    /// Why `author` cannot be acknowledged right now, if a limit is hit.
+    ///
+    /// Fails closed: when the action log cannot be read, nothing is
+    /// acknowledged.
+    pub async fn limit_reason(&self, storage: &dyn LoopStorage, author: &str) -> Option<String> {
+        let recent = match storage
+            .recent_action_messages(MENTION_ACK_ACTION, DAY)
+            .await
+        {
+            Ok(recent) => recent,
+            Err(e) => return Some(format!("acknowledgement history unavailable: {e}")),
+        };
+        let tag = format!("Acknowledged {} ", author_tag(author));
+        if recent.iter().any(|(_, message)| message.starts_with(&tag)) {
+            return Some("author already acknowledged today".to_string());
+        }
+        if recent.len() >= self.config.max_per_day as usize {
+            return Some(format!(
+                "daily limit of {} reached",
+                self.config.max_per_day
+            ));
+        }
+        let hour_ago = chrono::Utc::now() - chrono::Duration::from_std(HOUR).unwrap_or_default();
+        let last_hour = recent.iter().filter(|(at, _)| *at >= hour_ago).count();
+        if last_hour >= self.config.max_per_hour as usize {
+            return Some(format!(
+                "hourly limit of {} reached",
+                self.config.max_per_hour
+            ));
+        }
+        None
+    }
+
+    /// The template reply for `tweet_id`, if replies are enabled. The pick
+    /// is stable per tweet so a retry sends the same text.
+    pub fn reply_text(&self, tweet_id: &str) -> Option<String> {
+        let templates = &self.config.templates;
+        if !self.config.reply || templates.is_empty() {
+            return None;
+        }
+        let seed = tweet_id.bytes().fold(0usize, |acc, b| {
+            acc.wrapping_mul(31).wrapping_add(b as usize)
+        });
+        Some(templates[seed % templates.len()].clone())
+    }
+}
+
+fn author_tag(author: &str) -> String {
+    format!("@{}", author.trim_start_matches('@').to_lowercase())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn short_thanks_is_confident_praise() {
+        let triage = triage_mention("@tuitbot thanks, this is awesome! 🙏");
+        assert_eq!(triage.label, MentionLabel::Praise);
+        assert!(triage.confidence >= 0.85, "{triage:?}");
+
+        let policy = AckPolicy::new(MentionAckConfig::default());
+        assert!(policy.qualifies("@tuitbot thank you!"));
+        assert!(!policy.qualifies(
+            "@tuitbot great thread, the section on pacing matched what we saw last year"
+        ));
+    }
+
+    #[test]
+    fn questions_and_complaints_are_not_praise() {
+        assert_eq!(
+            triage_mention("@tuitbot thanks! how do I set this up?").label,
+            MentionLabel::Question
+        );
+        assert_eq!(
+            triage_mention("@tuitbot can you share the config").label,
+            MentionLabel::Question
+        );
+        assert_eq!(
+            triage_mention("@tuitbot thanks but it's broken").label,
+            MentionLabel::Complaint
+        );
+        assert_eq!(
+            triage_mention("@tuitbot shipping the new release today").label,
+            MentionLabel::Other
+        );
+        let long = format!("thanks {}", "word ".repeat(30));
+        assert_eq!(triage_mention(&long).label, MentionLabel::Other);
+    }
+
+    #[test]
+    fn reply_text_is_stable_and_optional() {
+        let mut config = MentionAckConfig::default();
+        assert_eq!(AckPolicy::new(config.clone()).reply_text("1"), None);
+        config.reply = true;
+        let policy = AckPolicy::new(config);
+        let text = policy.reply_text("12345").expect("template");
+        assert_eq!(policy.reply_text("12345"), Some(text));
+    }
+}
@@ -2,14 +2,19 @@
 //!
 //! Fetches new @-mentions from X API, generates contextual replies
 //! via LLM, and posts them through the posting queue. Persists
-//! `since_id` to survive restarts and avoid reprocessing.
+//! `since_id` to survive restarts and avoid reprocessing. Mentions older
+//! than the freshness cutoff are skipped. With `[mention_ack]`, simple
+//! praise is liked and optionally answered from a template instead.
+//! Replies on a content pillar that requires approval are queued.
 
 use super::loop_helpers::{
-    ConsecutiveErrorTracker, LoopError, LoopTweet, MentionsFetcher, PostSender, ReplyGenerator,
-    SafetyChecker,
+    send_reply_to, ConsecutiveErrorTracker, LoopError, LoopTweet, MentionsFetcher, PostSender,
+    ReplyGenerator, SafetyChecker, TweetLiker,
 };
+use super::mention_ack::{AckPolicy, Acknowledgement, MENTION_ACK_ACTION};
 use super::schedule::{schedule_gate, ActiveSchedule};
 use super::scheduler::LoopScheduler;
+use crate::storage::reply_explanations::ReplyExplanation;
 use std::sync::Arc;
 use std::time::Duration;
 use tokio_util::sync::CancellationToken;
@@ -21,6 +26,8 @@ pub struct MentionsLoop {
     safety: Arc<dyn SafetyChecker>,
     poster: Arc<dyn PostSender>,
     dry_run: bool,
+    max_age: Option<chrono::Duration>,
+    ack: Option<(AckPolicy, Arc<dyn TweetLiker>)>,
 }
 
 /// Result of processing a single mention.
@@ -32,6 +39,18 @@ pub enum MentionResult {
         author: String,
         reply_text: String,
     },
+    /// Praise was acknowledged with a like and/or a template reply.
+    Acknowledged {
+        tweet_id: String,
+        author: String,
+        ack: Acknowledgement,
+    },
+    /// Reply was queued for approval because its content pillar requires it.
+    Queued {
+        tweet_id: String,
+        author: String,
+        reply_text: String,
+    },
     /// Mention was skipped (safety check, already replied).
     Skipped { tweet_id: String, reason: String },
     /// Processing failed for this mention.
@@ -53,9 +72,26 @@ impl MentionsLoop {
             safety,
             poster,
             dry_run,
+            max_age: None,
+            ack: None,
         }
     }
 
+    /// Skip mentions older than `hours` (0 keeps every mention).
+    pub fn with_max_age_hours(mut self, hours: u32) -> Self {
+        self.max_age = (hours > 0).then(|| chrono::Duration::hours(i64::from(hours)));
+        self
+    }
+
+    /// Acknowledge praise mentions per `policy` instead of generating
+    /// replies, liking them through `liker`. Ignored unless enabled.
+    pub fn with_auto_ack(mut self, policy: AckPolicy, liker: Arc<dyn TweetLiker>) -> Self {
+        if policy.config().enabled {
+            self.ack = Some((policy, liker));
+        }
+        self
+    }
+
     /// Run the continuous mentions loop until cancellation.
     pub async fn run(
         &self,
@@ -194,28 +230,55 @@ impl MentionsLoop {
 
             let result = self.process_mention(mention, storage).await;
 
-            // Log the action
-            let (status, message) = match &result {
+            // Log the action; acknowledgements are logged apart from replies
+            let dry_run_status = if self.dry_run { "dry_run" } else { "success" };
+            let (action_type, status, message) = match &result {
+                MentionResult::Acknowledged {
+                    tweet_id,
+                    author,
+                    ack,
+                } => (
+                    MENTION_ACK_ACTION,
+                    dry_run_status,
+                    ack.log_message(author, tweet_id),
+                ),
                 MentionResult::Replied {
                     tweet_id,
                     reply_text,
                     ..
                 } => (
-                    if self.dry_run { "dry_run" } else { "success" },
+                    "mention_reply",
+                    dry_run_status,
                     format!(
                         "Replied to mention {tweet_id}: {}",
                         truncate(reply_text, 50)
                     ),
                 ),
-                MentionResult::Skipped { tweet_id, reason } => {
-                    ("skipped", format!("Skipped mention {tweet_id}: {reason}"))
-                }
-                MentionResult::Failed { tweet_id, error } => {
-                    ("failure", format!("Failed on mention {tweet_id}: {error}"))
-                }
+                MentionResult::Queued {
+                    tweet_id,
+                    reply_text,
+                    ..
+                } => (
+                    "mention_reply",
+                    if self.dry_run { "dry_run" } else { "queued" },
+                    format!(
+                        "Queued reply to mention {tweet_id} for approval: {}",
+                        truncate(reply_text, 50)
+                    ),
+                ),
+                MentionResult::Skipped { tweet_id, reason } => (
+                    "mention_reply",
+                    "skipped",
+                    format!("Skipped mention {tweet_id}: {reason}"),
+                ),
+                MentionResult::Failed { tweet_id, error } => (
+                    "mention_reply",
+                    "failure",
+                    format!("Failed on mention {tweet_id}: {error}"),
+                ),
             };
 
-            if let Err(e) = storage.log_action("mention_reply", status, &message).await {
+            if let Err(e) = storage.log_action(action_type, status, &message).await {
                 tracing::warn!(error = %e, "Failed to log action");
             }
 
@@ -231,6 +294,11 @@ impl MentionsLoop {
         mention: &LoopTweet,
         storage: &Arc<dyn super::loop_helpers::LoopStorage>,
     ) -> MentionResult {
+        // A mention is the author engaging us, whether or not we reply
+        if let Err(e) = storage.record_inbound_engagement(mention).await {
+            tracing::warn!(tweet_id = %mention.id, error = %e, "Failed to record inbound engagement");
+        }
+
         // Check if already replied
         if self.safety.has_replied_to(&mention.id).await {
             tracing::debug!(tweet_id = %mention.id, "Already replied to mention, skipping");
@@ -240,6 +308,38 @@ impl MentionsLoop {
             };
         }
 
+        // Protected entities never get a reply, in any mode
+        if let Err(denial) = self.safety.check_protected_author(mention) {
+            tracing::debug!(tweet_id = %mention.id, reason = %denial, "Mention author is protected, skipping");
+            return MentionResult::Skipped {
+                tweet_id: mention.id.clone(),
+                reason: denial.to_string(),
+            };
+        }
+
+        // Muted, blocked, or blocklisted authors get no reply
+        if let Err(denial) = self.safety.check_blocked_author(mention).await {
+            tracing::debug!(tweet_id = %mention.id, reason = %denial, "Mention author on blocklist, skipping");
+            return MentionResult::Skipped {
+                tweet_id: mention.id.clone(),
+                reason: denial.to_string(),
+            };
+        }
+
+        // Stale mentions are past the freshness cutoff
+        if let Some(max_age) = self.max_age {
+            let stale = mention
+                .created_at
+                .parse::<chrono::DateTime<chrono::Utc>>()
+                .is_ok_and(|created| chrono::Utc::now() - created > max_age);
+            if stale {
+                return MentionResult::Skipped {
+                    tweet_id: mention.id.clone(),
+                    reason: format!("older than the {}h freshness cutoff", max_age.num_hours()),
+                };
+            }
+        }
+
         // Check rate limits
         if !self.safety.can_reply().await {
             tracing::warn!(tweet_id = %mention.id, "Reply rate limit reached, skipping");
@@ -249,13 +349,28 @@ impl MentionsLoop {
             };
         }
 
+        // Simple praise gets an acknowledgement instead of a generated reply
+        if let Some((policy, liker)) = &self.ack {
+            if policy.qualifies(&mention.text) {
+                match policy
+                    .limit_reason(storage.as_ref(), &mention.author_username)
+                    .await
+                {
+                    None => return self.acknowledge(mention, policy, liker.as_ref()).await,
+                    Some(reason) => {
+                        tracing::debug!(tweet_id = %mention.id, reason = %reason, "Not acknowledging praise mention");
+                    }
+                }
+            }
+        }
+
         // Generate reply (always mention product for direct mentions)
-        let reply_text = match self
+        let generated = match self
             .generator
-            .generate_reply(&mention.text, &mention.author_username, true)
+            .generate_reply_explained(&mention.text, &mention.author_username, true)
             .await
         {
-            Ok(text) => text,
+            Ok(generated) => generated,
             Err(e) => {
                 tracing::error!(
                     tweet_id = %mention.id,
@@ -268,6 +383,35 @@ impl MentionsLoop {
                 };
             }
         };
+        let review = generated.approval_reason();
+        let reply_text = generated.text;
+
+        // Replies on a pillar that requires approval are queued instead
+        if let Some(reason) = review {
+            if !self.dry_run {
+                if let Err(e) = storage
+                    .queue_reply_for_approval(
+                        &mention.id,
+                        &mention.author_username,
+                        &reply_text,
+                        0.0,
+                        Some(&reason),
+                        &[],
+                    )
+                    .await
+                {
+                    return MentionResult::Failed {
+                        tweet_id: mention.id.clone(),
+                        error: e.to_string(),
+                    };
+                }
+            }
+            return MentionResult::Queued {
+                tweet_id: mention.id.clone(),
+                author: mention.author_username.clone(),
+                reply_text,
+            };
+        }
 
         tracing::info!(
             author = %mention.author_username,
@@ -284,7 +428,14 @@ impl MentionsLoop {
             );
         } else {
             // Send to posting queue
-            if let Err(e) = self.poster.send_reply(&mention.id, &reply_text).await {
+            if let Err(e) = send_reply_to(
+                self.poster.as_ref(),
+                self.safety.as_ref(),
+                mention,
+                &reply_text,
+            )
+            .await
+            {
                 tracing::error!(
                     tweet_id = %mention.id,
                     error = %e,
@@ -297,7 +448,18 @@ impl MentionsLoop {
             }
 
             // Record the reply
-            if let Err(e) = self.safety.record_reply(&mention.id, &reply_text).await {
+            let explanation = ReplyExplanation {
+                source: "mentions".to_string(),
+                archetype: generated.archetype,
+                ancestors: generated.ancestors,
+                topic_override: generated.topic_override,
+                ..ReplyExplanation::default()
+            };
+            if let Err(e) = self
+                .safety
+                .record_reply_explained(&mention.id, &reply_text, explanation)
+                .await
+            {
                 tracing::warn!(
                     tweet_id = %mention.id,
                     error = %e,
@@ -325,6 +487,75 @@ impl MentionsLoop {
             reply_text,
         }
     }
+
+    /// Like `mention` and, if configured, answer it with a template. Runs
+    /// after the reply gates; no LLM is called. A like that went out is
+    /// reported even when the reply fails, so the ack limits count it.
+    async fn acknowledge(
+        &self,
+        mention: &LoopTweet,
+        policy: &AckPolicy,
+        liker: &dyn TweetLiker,
+    ) -> MentionResult {
+        let mut ack = Acknowledgement {
+            liked: policy.config().like,
+            reply_text: policy.reply_text(&mention.id),
+        };
+
+        if self.dry_run {
+            tracing::info!(
+                "DRY RUN: Would acknowledge mention {} by @{}",
+                mention.id,
+                mention.author_username
+            );
+        } else {
+            if ack.liked {
+                if let Err(e) = liker.like_tweet(&mention.id).await {
+                    return MentionResult::Failed {
+                        tweet_id: mention.id.clone(),
+                        error: format!("like failed: {e}"),
+                    };
+                }
+            }
+            if let Some(text) = ack.reply_text.clone() {
+                if let Err(e) =
+                    send_reply_to(self.poster.as_ref(), self.safety.as_ref(), mention, &text).await
+                {
+                    if !ack.liked {
+                        return MentionResult::Failed {
+                            tweet_id: mention.id.clone(),
+                            error: e.to_string(),
+                        };
+                    }
+                    // The like went out: record it so the limits see it
+                    tracing::warn!(tweet_id = %mention.id, error = %e, "Acknowledgement reply failed after like");
+                    ack.reply_text = None;
+                    return MentionResult::Acknowledged {
+                        tweet_id: mention.id.clone(),
+                        author: mention.author_username.clone(),
+                        ack,
+                    };
+                }
+                let explanation = ReplyExplanation {
+                    source: MENTION_ACK_ACTION.to_string(),
+                    ..ReplyExplanation::default()
+                };
+                if let Err(e) = self
+                    .safety
+                    .record_reply_explained(&mention.id, &text, explanation)
+                    .await
+                {
+                    tracing::warn!(tweet_id = %mention.id, error = %e, "Failed to record acknowledgement");
+                }
+            }
+        }
+
+        MentionResult::Acknowledged {
+            tweet_id: mention.id.clone(),
+            author: mention.author_username.clone(),
+            ack,
+        }
+    }
 }
 
 /// Update max_id tracking. Tweet IDs are numeric strings; higher = newer.
@@ -360,7 +591,9 @@ fn truncate(s: &str, max_len: usize) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::automation::loop_helpers::LoopStorage;
+    use crate::automation::loop_helpers::{GeneratedReply, LoopStorage};
+    use crate::safety::DenialReason;
+    use crate::workflow::draft_workspace::DraftFlag;
     use std::sync::Mutex;
 
     // --- Mock implementations ---
@@ -392,6 +625,37 @@ mod tests {
         }
     }
 
+    /// Generator whose replies land on a pillar that requires approval.
+    struct ReviewPillarGenerator;
+
+    #[async_trait::async_trait]
+    impl ReplyGenerator for ReviewPillarGenerator {
+        async fn generate_reply(
+            &self,
+            _tweet_text: &str,
+            author: &str,
+            _mention_product: bool,
+        ) -> Result<String, LoopError> {
+            Ok(format!("Formal reply to @{author}"))
+        }
+
+        async fn generate_reply_explained(
+            &self,
+            tweet_text: &str,
+            author: &str,
+            mention_product: bool,
+        ) -> Result<GeneratedReply, LoopError> {
+            Ok(GeneratedReply {
+                text: self
+                    .generate_reply(tweet_text, author, mention_product)
+                    .await?,
+                topic_override: Some("Tax law".to_string()),
+                require_approval: true,
+                ..GeneratedReply::default()
+            })
+        }
+    }
+
     struct FailingGenerator;
 
     #[async_trait::async_trait]
@@ -422,6 +686,9 @@ mod tests {
 
     #[async_trait::async_trait]
     impl SafetyChecker for MockSafety {
+        fn check_protected_author(&self, _tweet: &LoopTweet) -> Result<(), DenialReason> {
+            Ok(())
+        }
         async fn can_reply(&self) -> bool {
             self.can_reply
         }
@@ -467,6 +734,10 @@ mod tests {
                 .push((tweet_id.to_string(), content.to_string()));
             Ok(())
         }
+
+        async fn send_quote(&self, _tweet_id: &str, _content: &str) -> Result<(), LoopError> {
+            Ok(())
+        }
     }
 
     struct MockStorage {
@@ -523,6 +794,50 @@ mod tests {
             ));
             Ok(())
         }
+
+        async fn recent_action_messages(
+            &self,
+            action_type: &str,
+            _window: Duration,
+        ) -> Result<Vec<(chrono::DateTime<chrono::Utc>, String)>, LoopError> {
+            Ok(self
+                .actions
+                .lock()
+                .expect("lock")
+                .iter()
+                .filter(|(t, _, _)| t == action_type)
+                .map(|(_, _, m)| (chrono::Utc::now(), m.clone()))
+                .collect())
+        }
+
+        async fn queue_reply_for_approval(
+            &self,
+            tweet_id: &str,
+            _author: &str,
+            _content: &str,
+            _score: f32,
+            risk: Option<&str>,
+            _qa_flags: &[DraftFlag],
+        ) -> Result<(), LoopError> {
+            self.actions.lock().expect("lock").push((
+                "approval".to_string(),
+                tweet_id.to_string(),
+                risk.unwrap_or_default().to_string(),
+            ));
+            Ok(())
+        }
+    }
+
+    struct MockLiker {
+        liked: Mutex<Vec<String>>,
+    }
+
+    #[async_trait::async_trait]
+    impl TweetLiker for MockLiker {
+        async fn like_tweet(&self, tweet_id: &str) -> Result<(), LoopError> {
+            self.liked.lock().expect("lock").push(tweet_id.to_string());
+            Ok(())
+        }
     }
 
     fn test_tweet(id: &str, author: &str) -> LoopTweet {
@@ -536,6 +851,9 @@ mod tests {
             likes: 10,
             retweets: 2,
             replies: 1,
+            quotes: 0,
+            urls: vec![],
+            has_media: false,
         }
     }
 
@@ -585,6 +903,132 @@ mod tests {
         assert_eq!(poster.sent_count(), 2);
     }
 
+    #[tokio::test]
+    async fn replies_on_review_pillars_are_queued() {
+        let poster = Arc::new(MockPoster::new());
+        let mentions_loop = MentionsLoop::new(
+            Arc::new(MockFetcher {
+                mentions: vec![test_tweet("100", "alice")],
+            }),
+            Arc::new(ReviewPillarGenerator),
+            Arc::new(MockSafety::new(true)),
+            poster.clone(),
+            false,
+        );
+        let mock = Arc::new(MockStorage::new());
+        let storage: Arc<dyn LoopStorage> = mock.clone();
+
+        let (results, _) = mentions_loop.run_once(None, None, &storage).await.unwrap();
+        assert!(matches!(&results[0], MentionResult::Queued { .. }));
+        assert_eq!(poster.sent_count(), 0);
+        let actions = mock.actions.lock().expect("lock");
+        assert!(actions.iter().any(|(t, id, risk)| t == "approval"
+            && id == "100"
+            && risk == "topic_override: Tax law"));
+        assert!(actions.iter().any(|(_, status, _)| status == "queued"));
+    }
+
+    #[tokio::test]
+    async fn praise_is_acknowledged_once_per_author() {
+        let poster = Arc::new(MockPoster::new());
+        let liker = Arc::new(MockLiker {
+            liked: Mutex::new(Vec::new()),
+        });
+        let mut praise = test_tweet("100", "alice");
+        praise.text = "@me thank you, this is awesome!".to_string();
+        let mut again = test_tweet("101", "alice");
+        again.text = "@me thanks!".to_string();
+        let mut config = crate::config::MentionAckConfig {
+            enabled: true,
+            reply: true,
+            ..Default::default()
+        };
+        config.templates = vec!["Thank you!".to_string()];
+        let mentions_loop = MentionsLoop::new(
+            Arc::new(MockFetcher {
+                mentions: vec![praise, again, test_tweet("102", "bob")],
+            }),
+            Arc::new(MockGenerator {
+                reply_prefix: "Hello".to_string(),
+            }),
+            Arc::new(MockSafety::new(true)),
+            poster.clone(),
+            false,
+        )
+        .with_auto_ack(AckPolicy::new(config), liker.clone());
+        let mock_storage = Arc::new(MockStorage::new());
+        let storage: Arc<dyn LoopStorage> = mock_storage.clone();
+
+        let (results, _) = mentions_loop.run_once(None, None, &storage).await.unwrap();
+        assert!(matches!(&results[0], MentionResult::Acknowledged { .. }));
+        // Same author again, and a non-praise mention: generated replies
+        assert!(matches!(&results[1], MentionResult::Replied { .. }));
+        assert!(matches!(&results[2], MentionResult::Replied { .. }));
+        assert_eq!(*liker.liked.lock().expect("lock"), vec!["100"]);
+        let sent = poster.sent.lock().expect("lock").clone();
+        assert_eq!(sent[0], ("100".to_string(), "Thank you!".to_string()));
+
+        let actions = mock_storage.actions.lock().expect("lock");
+        let acks: Vec<_> = actions
+            .iter()
+            .filter(|(t, _, _)| t == MENTION_ACK_ACTION)
+            .collect();
+        assert_eq!(acks.len(), 1);
+        assert_eq!(
+            acks[0].2,
+            "Acknowledged @alice mention 100: liked, replied \"Thank you!\""
+        );
+    }
+
+    struct FailingPoster;
+
+    #[async_trait::async_trait]
+    impl PostSender for FailingPoster {
+        async fn send_reply(&self, _tweet_id: &str, _content: &str) -> Result<(), LoopError> {
+            Err(LoopError::Other("post failed".to_string()))
+        }
+
+        async fn send_quote(&self, _tweet_id: &str, _content: &str) -> Result<(), LoopError> {
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn like_is_logged_when_ack_reply_fails() {
+        let liker = Arc::new(MockLiker {
+            liked: Mutex::new(Vec::new()),
+        });
+        let mut praise = test_tweet("100", "alice");
+        praise.text = "@me thank you, this is awesome!".to_string();
+        let config = crate::config::MentionAckConfig {
+            enabled: true,
+            reply: true,
+            ..Default::default()
+        };
+        let mentions_loop = MentionsLoop::new(
+            Arc::new(MockFetcher {
+                mentions: vec![praise],
+            }),
+            Arc::new(MockGenerator {
+                reply_prefix: "Hello".to_string(),
+            }),
+            Arc::new(MockSafety::new(true)),
+            Arc::new(FailingPoster),
+            false,
+        )
+        .with_auto_ack(AckPolicy::new(config), liker.clone());
+        let mock_storage = Arc::new(MockStorage::new());
+        let storage: Arc<dyn LoopStorage> = mock_storage.clone();
+
+        let (results, _) = mentions_loop.run_once(None, None, &storage).await.unwrap();
+        assert!(matches!(&results[0], MentionResult::Acknowledged { .. }));
+        let actions = mock_storage.actions.lock().expect("lock");
+        assert!(actions
+            .iter()
+            .any(|(t, _, m)| t == MENTION_ACK_ACTION
+                && m == "Acknowledged @alice mention 100: liked"));
+    }
+
     #[tokio::test]
     async fn run_once_respects_limit() {
         let poster = Arc::new(MockPoster::new());
@@ -640,6 +1084,34 @@ mod tests {
         assert_eq!(poster.sent_count(), 1);
     }
 
+    #[tokio::test]
+    async fn run_once_skips_mentions_past_freshness_cutoff() {
+        let mut fresh = test_tweet("101", "bob");
+        fresh.created_at = chrono::Utc::now().to_rfc3339();
+        let poster = Arc::new(MockPoster::new());
+        let mentions_loop = MentionsLoop::new(
+            Arc::new(MockFetcher {
+                mentions: vec![test_tweet("100", "alice"), fresh],
+            }),
+            Arc::new(MockGenerator {
+                reply_prefix: "Hi".to_string(),
+            }),
+            Arc::new(MockSafety::new(true)),
+            poster.clone(),
+            false,
+        )
+        .with_max_age_hours(72);
+        let storage: Arc<dyn LoopStorage> = Arc::new(MockStorage::new());
+
+        let (results, _) = mentions_loop.run_once(None, None, &storage).await.unwrap();
+        assert!(matches!(
+            &results[0],
+            MentionResult::Skipped { reason, .. } if reason.contains("72h freshness cutoff")
+        ));
+        assert!(matches!(&results[1], MentionResult::Replied { .. }));
+        assert_eq!(poster.sent_count(), 1);
+    }
+
     #[tokio::test]
     async fn run_once_skips_when_rate_limited() {
         let poster = Arc::new(MockPoster::new());
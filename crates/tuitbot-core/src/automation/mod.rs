@@ -7,28 +7,54 @@
 //! Submodules:
 //! - [`scheduler`]: Loop scheduler with configurable interval and jitter.
 //! - [`posting_queue`]: Serialized posting queue for concurrent loops.
+//! - [`posting_lock`]: Cross-process lock so only one process posts at a time.
+//! - [`approval_triage`]: Auto-approves queued items that clear strict rules.
 //! - [`status_reporter`]: Periodic action count summaries.
 //! - [`loop_helpers`]: Shared types, traits, and error handling for loops.
+//! - [`manual_reply`]: Drafts candidate replies to one hand-picked tweet.
 //! - [`mentions_loop`]: Monitors @-mentions and generates replies.
+//! - [`mention_ack`]: Triages mentions and acknowledges simple praise.
 //! - [`discovery_loop`]: Searches tweets by keyword, scores, and replies.
 //! - [`content_loop`]: Generates and posts educational tweets.
 //! - [`thread_loop`]: Generates and posts multi-tweet threads.
+//! - [`health_monitor`]: Periodic visibility self-checks with posting slowdown.
+//! - [`warmup`]: Gradually increasing caps for new or idle accounts.
+//! - [`content_mix`]: Daily split of the posting budget by action type.
+//! - [`safe_mode`]: Starts with posting halted after a crash or failure streak.
+//! - [`blocklist_sync`]: Mirrors X mutes and blocks into the author blocklist.
 
 pub mod adapters;
 pub mod analytics_loop;
 pub mod approval_poster;
+pub mod approval_sla;
+pub mod approval_triage;
+pub mod blocklist_sync;
 pub mod circuit_breaker;
 pub mod content_loop;
+pub mod content_mix;
 pub mod discovery_loop;
+pub mod discovery_sources;
+pub mod health_monitor;
 pub mod loop_helpers;
+pub mod manual_reply;
+pub mod mention_ack;
 pub mod mentions_loop;
+pub mod pacing;
+pub mod posting_lock;
 pub mod posting_queue;
+pub mod reddit_loop;
+pub mod reddit_poster;
+pub mod remote_backup;
+pub mod safe_mode;
 pub mod schedule;
 pub mod scheduler;
 pub mod seed_worker;
 pub mod status_reporter;
 pub mod target_loop;
 pub mod thread_loop;
+pub mod trending;
+pub mod usage_reconciliation;
+pub mod warmup;
 pub mod watchtower;
 
 pub use analytics_loop::{
@@ -36,19 +62,33 @@ pub use analytics_loop::{
     ProfileFetcher, ProfileMetrics, TweetMetrics,
 };
 pub use approval_poster::run_approval_poster;
+pub use approval_triage::run_approval_triage;
+pub use blocklist_sync::{run_blocklist_sync, BlocklistSyncReport};
 pub use content_loop::{ContentLoop, ContentResult};
+pub use content_mix::{run_content_mix_loop, DayPlan, PlanStatus};
 pub use discovery_loop::{DiscoveryLoop, DiscoveryResult, DiscoverySummary};
+pub use discovery_sources::{DiscoverySource, DiscoverySourceRegistry, HackerNewsSource};
+pub use health_monitor::{run_account_health_monitor, HealthReport, HealthStatus};
 pub use loop_helpers::{
-    ConsecutiveErrorTracker, ContentLoopError, ContentSafety, ContentStorage, LoopError,
-    LoopStorage, LoopTweet, MentionsFetcher, PostSender, ReplyGenerator, SafetyChecker,
-    ScoreResult, ThreadPoster, TopicScorer, TweetGenerator, TweetScorer, TweetSearcher,
+    ConsecutiveErrorTracker, ContentLoopError, ContentSafety, ContentStorage, GeneratedReply,
+    LoopError, LoopStorage, LoopTweet, MentionsFetcher, PostSender, QuoteGenerator, ReplyGenerator,
+    SafetyChecker, ScoreResult, ThreadPoster, ThresholdCalibrator, TopicScorer, TrendSource,
+    TweetGenerator, TweetScorer, TweetSearcher,
 };
 pub use mentions_loop::{MentionResult, MentionsLoop};
+pub use pacing::{PaceDecision, PaceState, PaceStatus, PacedEndpoint, PacingPlanner, RequestPacer};
+pub use posting_lock::{post_exclusively, PostingLock, PostingLockError};
 pub use posting_queue::{
     create_posting_queue, run_posting_queue_with_approval, ApprovalQueue, PostAction, PostExecutor,
     QUEUE_CAPACITY,
 };
-pub use schedule::{schedule_gate, ActiveSchedule};
+pub use reddit_loop::{
+    RedditCommentQueue, RedditLoop, RedditPostSearcher, RedditResult, RedditSummary,
+};
+pub use reddit_poster::{run_reddit_poster, REDDIT_COMMENT_ACTION};
+pub use remote_backup::run_remote_backup_loop;
+pub use safe_mode::{SafeModeTrigger, SessionTracker};
+pub use schedule::{schedule_gate, ActiveSchedule, WindowPosition};
 pub use scheduler::{scheduler_from_config, LoopScheduler};
 pub use seed_worker::SeedWorker;
 pub use status_reporter::{ActionCounts, StatusQuerier};
@@ -57,6 +97,9 @@ pub use target_loop::{
     TargetUserManager,
 };
 pub use thread_loop::{ThreadGenerator, ThreadLoop, ThreadResult};
+pub use trending::{select_trend, TrendTopic};
+pub use usage_reconciliation::run_usage_reconciliation_loop;
+pub use warmup::{run_warmup_loop, WarmupCaps, WarmupPhase};
 pub use watchtower::{IngestSummary, WatchtowerError, WatchtowerLoop};
 
 use std::future::Future;
@@ -66,19 +109,14 @@ use tokio::task::JoinHandle;
 use tokio_util::sync::CancellationToken;
 
 use crate::x_api::auth::TokenManager;
-use crate::x_api::XApiHttpClient;
 
 /// Background loop that refreshes the X API OAuth token before it expires.
 ///
 /// Checks every 60 seconds whether the token is within 5 minutes of expiry.
-/// On successful refresh, updates the `XApiHttpClient`'s bearer token.
-/// On `AuthExpired` error (refresh token revoked), cancels the runtime for
-/// graceful shutdown.
-pub async fn run_token_refresh_loop(
-    token_manager: Arc<TokenManager>,
-    x_client: Arc<XApiHttpClient>,
-    cancel: CancellationToken,
-) {
+/// Clients attached to the manager pick up the refreshed token. On
+/// `AuthExpired` error (refresh token revoked), cancels `cancel` so a
+/// runtime sharing it shuts down gracefully.
+pub async fn run_token_refresh_loop(token_manager: Arc<TokenManager>, cancel: CancellationToken) {
     let interval = Duration::from_secs(60);
     loop {
         tokio::select! {
@@ -90,20 +128,11 @@ pub async fn run_token_refresh_loop(
         }
 
         match token_manager.refresh_if_needed().await {
-            Ok(()) => {
-                // Update the HTTP client's bearer token with whatever is current.
-                let token = token_manager
-                    .tokens_lock()
-                    .read()
-                    .await
-                    .access_token
-                    .clone();
-                x_client.set_access_token(token).await;
-            }
+            Ok(()) => {}
             Err(crate::error::XApiError::AuthExpired) => {
                 tracing::error!(
                     "Token refresh failed: authentication expired. \
-                     Run `tuitbot auth` to re-authenticate. Shutting down."
+                     Run `tuitbot auth` to re-authenticate."
                 );
                 cancel.cancel();
                 return;
@@ -0,0 +1,316 @@
+//! Request pacing across the active-hours window.
+//!
+//! Divides each paced endpoint's daily budget evenly across the active
+//! window, so a fresh budget is not burned in the first hour of the day.
+//! Only non-urgent reads are paced (discovery search and target timelines);
+//! mentions and posting are never deferred.
+//!
+//! The pace curve allows `ceil(budget * progress)` requests by the current
+//! point in the window (at least one). A call that would put usage ahead of
+//! the curve is deferred until the curve catches up.
+//!
+//! Budgets can be scaled down by the usage reconciliation job when the local
+//! ledger undercounts what X reports (see [`super::usage_reconciliation`]).
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use super::schedule::{ActiveSchedule, WindowPosition};
+use crate::config::PacingConfig;
+
+/// An X API endpoint whose daily budget is paced.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PacedEndpoint {
+    /// Recent tweet search (discovery loop).
+    Search,
+    /// User timeline reads (target monitoring loop).
+    UserTweets,
+}
+
+impl PacedEndpoint {
+    /// All paced endpoints, in status display order.
+    pub const ALL: [PacedEndpoint; 2] = [PacedEndpoint::Search, PacedEndpoint::UserTweets];
+
+    /// Stable key used for storage and status output.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Search => "search",
+            Self::UserTweets => "user_tweets",
+        }
+    }
+}
+
+/// Whether a paced call may go ahead now.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PaceDecision {
+    /// Usage is at or behind the pace curve.
+    Proceed,
+    /// Usage is ahead of the pace curve; retry after `wait`.
+    Defer { wait: Duration },
+    /// The daily budget is spent.
+    Exhausted,
+}
+
+/// Position of an endpoint relative to its pace curve.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PaceState {
+    /// Another request fits under the curve.
+    OnPace,
+    /// Requests are being deferred until the curve catches up.
+    Ahead,
+    /// The daily budget is spent.
+    Exhausted,
+}
+
+impl PaceState {
+    /// Stable snake_case name used in logs and status output.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::OnPace => "on_pace",
+            Self::Ahead => "ahead",
+            Self::Exhausted => "exhausted",
+        }
+    }
+}
+
+/// Pace snapshot for one endpoint, as shown in status output.
+#[derive(Debug, Clone, Serialize)]
+pub struct PaceStatus {
+    pub endpoint: PacedEndpoint,
+    /// Daily request budget.
+    pub budget: u32,
+    /// Requests made so far in the current window.
+    pub used: u32,
+    /// Requests the pace curve allows by now.
+    pub target: u32,
+    pub state: PaceState,
+}
+
+impl PaceStatus {
+    /// Format a one-line summary, e.g. `search 40/200 (target 52, on_pace)`.
+    pub fn format_line(&self) -> String {
+        format!(
+            "{} {}/{} (target {}, {})",
+            self.endpoint.as_str(),
+            self.used,
+            self.budget,
+            self.target,
+            self.state.as_str()
+        )
+    }
+}
+
+/// Spreads per-endpoint daily budgets across the active window.
+#[derive(Debug, Clone)]
+pub struct PacingPlanner {
+    search_per_day: u32,
+    user_tweets_per_day: u32,
+    schedule: Option<Arc<ActiveSchedule>>,
+    budget_scale: f64,
+}
+
+impl PacingPlanner {
+    /// Build a planner from config. Without a schedule the window is the UTC day.
+    pub fn new(config: &PacingConfig, schedule: Option<Arc<ActiveSchedule>>) -> Self {
+        Self {
+            search_per_day: config.search_per_day,
+            user_tweets_per_day: config.user_tweets_per_day,
+            schedule,
+            budget_scale: 1.0,
+        }
+    }
+
+    /// Scale every daily budget by `scale` (clamped to 0.0-1.0).
+    pub fn with_budget_scale(mut self, scale: f64) -> Self {
+        self.budget_scale = scale.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Daily budget for `endpoint`, after scaling. A configured budget above
+    /// zero never scales below one.
+    pub fn budget(&self, endpoint: PacedEndpoint) -> u32 {
+        let configured = match endpoint {
+            PacedEndpoint::Search => self.search_per_day,
+            PacedEndpoint::UserTweets => self.user_tweets_per_day,
+        };
+        let scaled = (configured as f64 * self.budget_scale).ceil() as u32;
+        scaled.clamp(1.min(configured), configured)
+    }
+
+    /// Locate `now` within the active window.
+    pub fn position(&self, now: DateTime<Utc>) -> WindowPosition {
+        match &self.schedule {
+            Some(schedule) => schedule.window_position(now),
+            None => WindowPosition::utc_day(now),
+        }
+    }
+
+    /// Requests the pace curve allows by `position` (at least one).
+    pub fn target(&self, endpoint: PacedEndpoint, position: &WindowPosition) -> u32 {
+        let budget = self.budget(endpoint);
+        let target = (budget as f64 * position.progress).ceil() as u32;
+        target.clamp(1.min(budget), budget)
+    }
+
+    /// Decide whether one more request may be made given `used` so far.
+    pub fn decide(
+        &self,
+        endpoint: PacedEndpoint,
+        used: u32,
+        position: &WindowPosition,
+    ) -> PaceDecision {
+        let budget = self.budget(endpoint);
+        if used >= budget {
+            return PaceDecision::Exhausted;
+        }
+        if used < self.target(endpoint, position) {
+            return PaceDecision::Proceed;
+        }
+
+        // The curve allows request `used + 1` once progress exceeds used / budget.
+        let catch_up = used as f64 / budget as f64 - position.progress;
+        let wait_secs = (catch_up * position.window_secs as f64).ceil().max(1.0);
+        PaceDecision::Defer {
+            wait: Duration::from_secs(wait_secs as u64),
+        }
+    }
+
+    /// Pace snapshot for `endpoint` given `used` so far.
+    pub fn status(
+        &self,
+        endpoint: PacedEndpoint,
+        used: u32,
+        position: &WindowPosition,
+    ) -> PaceStatus {
+        let state = match self.decide(endpoint, used, position) {
+            PaceDecision::Proceed => PaceState::OnPace,
+            PaceDecision::Defer { .. } => PaceState::Ahead,
+            PaceDecision::Exhausted => PaceState::Exhausted,
+        };
+        PaceStatus {
+            endpoint,
+            budget: self.budget(endpoint),
+            used,
+            target: self.target(endpoint, position),
+            state,
+        }
+    }
+}
+
+/// Port for checking and recording paced requests.
+///
+/// Implementations keep usage counts per window day; errors should be
+/// logged and treated as [`PaceDecision::Proceed`] so pacing never stalls
+/// a loop on its own.
+#[async_trait::async_trait]
+pub trait RequestPacer: Send + Sync {
+    /// Decide whether a request to `endpoint` may be made now.
+    async fn check(&self, endpoint: PacedEndpoint) -> PaceDecision;
+
+    /// Record that a request to `endpoint` was made.
+    async fn record(&self, endpoint: PacedEndpoint);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    fn planner(search_per_day: u32) -> PacingPlanner {
+        let config = PacingConfig {
+            enabled: true,
+            search_per_day,
+            user_tweets_per_day: 10,
+        };
+        PacingPlanner::new(&config, None)
+    }
+
+    fn at(progress: f64) -> WindowPosition {
+        WindowPosition {
+            day: NaiveDate::from_ymd_opt(2026, 3, 2).unwrap(),
+            progress,
+            window_secs: 10_000,
+        }
+    }
+
+    #[test]
+    fn first_request_always_allowed() {
+        let p = planner(100);
+        assert_eq!(
+            p.decide(PacedEndpoint::Search, 0, &at(0.0)),
+            PaceDecision::Proceed
+        );
+        assert_eq!(p.target(PacedEndpoint::Search, &at(0.0)), 1);
+    }
+
+    #[test]
+    fn proceeds_while_behind_curve() {
+        let p = planner(100);
+        assert_eq!(
+            p.decide(PacedEndpoint::Search, 49, &at(0.5)),
+            PaceDecision::Proceed
+        );
+    }
+
+    #[test]
+    fn defers_when_ahead_of_curve() {
+        let p = planner(100);
+        // 60 used at 50% progress: request 61 is allowed once progress > 0.60.
+        let decision = p.decide(PacedEndpoint::Search, 60, &at(0.5));
+        assert_eq!(
+            decision,
+            PaceDecision::Defer {
+                wait: Duration::from_secs(1_000)
+            }
+        );
+        assert_eq!(
+            p.status(PacedEndpoint::Search, 60, &at(0.5)).state,
+            PaceState::Ahead
+        );
+    }
+
+    #[test]
+    fn exhausted_at_budget() {
+        let p = planner(100);
+        assert_eq!(
+            p.decide(PacedEndpoint::Search, 100, &at(1.0)),
+            PaceDecision::Exhausted
+        );
+        assert_eq!(
+            p.decide(PacedEndpoint::UserTweets, 10, &at(0.2)),
+            PaceDecision::Exhausted
+        );
+    }
+
+    #[test]
+    fn budget_scale_shrinks_budgets() {
+        let p = planner(200).with_budget_scale(0.5);
+        assert_eq!(p.budget(PacedEndpoint::Search), 100);
+        assert_eq!(p.budget(PacedEndpoint::UserTweets), 5);
+        assert_eq!(
+            p.decide(PacedEndpoint::Search, 100, &at(0.9)),
+            PaceDecision::Exhausted
+        );
+        let floor = planner(200).with_budget_scale(0.0);
+        assert_eq!(floor.budget(PacedEndpoint::Search), 1);
+        assert_eq!(
+            planner(0)
+                .with_budget_scale(0.5)
+                .budget(PacedEndpoint::Search),
+            0
+        );
+    }
+
+    #[test]
+    fn status_line_format() {
+        let p = planner(200);
+        let status = p.status(PacedEndpoint::Search, 40, &at(0.26));
+        assert_eq!(status.target, 52);
+        assert_eq!(status.format_line(), "search 40/200 (target 52, on_pace)");
+    }
+}
@@ -0,0 +1,259 @@
+//! Cross-process posting lock.
+//!
+//! When `tuitbot run` and another process share a database, both could
+//! pick up the same approved items. Posting loops hold the `posting` lock
+//! row while they work and refresh its heartbeat every round; a process that
+//! stops heartbeating for the stale timeout loses the lock to the next one.
+//! One-off posts (MCP write tools) take the lock only for the duration of
+//! the post via [`post_exclusively`].
+
+use std::future::Future;
+use std::time::Duration;
+
+use crate::error::StorageError;
+use crate::storage::process_lock::{self, LockAttempt, LockHolder};
+use crate::storage::DbPool;
+
+/// Name of the lock row guarding posting paths.
+pub const POSTING_LOCK: &str = "posting";
+
+/// Minimum time without a heartbeat before another process may take over.
+pub const DEFAULT_STALE_AFTER: Duration = Duration::from_secs(300);
+
+/// A process's handle on the posting lock.
+///
+/// Logs when the lock is acquired, taken over, or found held by another
+/// process, without repeating the message every poll.
+pub struct PostingLock {
+    pool: DbPool,
+    holder: String,
+    stale_after: Duration,
+    held: bool,
+    waiting_on: Option<String>,
+}
+
+impl PostingLock {
+    /// Create a handle identified by this process's executable name and PID.
+    pub fn new(pool: DbPool, stale_after: Duration) -> Self {
+        Self::with_holder(pool, process_identity(), stale_after)
+    }
+
+    /// Create a handle with an explicit holder identity.
+    pub fn with_holder(pool: DbPool, holder: String, stale_after: Duration) -> Self {
+        Self {
+            pool,
+            holder,
+            stale_after,
+            held: false,
+            waiting_on: None,
+        }
+    }
+
+    /// This process's identity as recorded in the lock row.
+    pub fn holder(&self) -> &str {
+        &self.holder
+    }
+
+    /// Acquire the lock or refresh its heartbeat. Returns whether this
+    /// process may post. Storage errors fail closed.
+    pub async fn acquire(&mut self) -> bool {
+        let stale_secs = self.stale_after.as_secs() as i64;
+        match process_lock::try_acquire_lock(&self.pool, POSTING_LOCK, &self.holder, stale_secs)
+            .await
+        {
+            Ok(LockAttempt::Acquired { took_over }) => {
+                if let Some(prev) = took_over {
+                    tracing::warn!(
+                        holder = %self.holder,
+                        previous = %prev.holder,
+                        last_heartbeat = %prev.heartbeat_at,
+                        "Took over stale posting lock"
+                    );
+                } else if !self.held {
+                    tracing::info!(holder = %self.holder, "Acquired posting lock");
+                }
+                self.held = true;
+                self.waiting_on = None;
+                true
+            }
+            Ok(LockAttempt::Held(other)) => {
+                if self.held {
+                    tracing::warn!(
+                        holder = %self.holder,
+                        new_holder = %other.holder,
+                        "Lost posting lock to another process"
+                    );
+                }
+                if self.waiting_on.as_deref() != Some(other.holder.as_str()) {
+                    tracing::info!(
+                        holder = %other.holder,
+                        since = %other.acquired_at,
+                        last_heartbeat = %other.heartbeat_at,
+                        "Posting lock held by another process, not posting"
+                    );
+                    self.waiting_on = Some(other.holder);
+                }
+                self.held = false;
+                false
+            }
+            Err(e) => {
+                tracing::warn!(error = %e, "Failed to acquire posting lock");
+                self.held = false;
+                false
+            }
+        }
+    }
+
+    /// Release the lock if this process holds it.
+    pub async fn release(&mut self) {
+        if !self.held {
+            return;
+        }
+        match process_lock::release_lock(&self.pool, POSTING_LOCK, &self.holder).await {
+            Ok(()) => tracing::info!(holder = %self.holder, "Released posting lock"),
+            Err(e) => tracing::warn!(error = %e, "Failed to release posting lock"),
+        }
+        self.held = false;
+    }
+}
+
+/// Why a one-off post could not take the posting lock.
+#[derive(Debug, thiserror::Error)]
+pub enum PostingLockError {
+    /// Another live process is posting.
+    #[error("{} holds the posting lock (last heartbeat {}); not posting", .0.holder, .0.heartbeat_at)]
+    Held(LockHolder),
+
+    /// The lock row could not be read or written.
+    #[error("failed to take the posting lock: {0}")]
+    Storage(#[from] StorageError),
+}
+
+/// Run a single post while holding the posting lock.
+///
+/// Fails without running `post` when another live process holds the lock.
+/// The lock is released afterwards only if this call took it, so a posting
+/// loop in the same process keeps its hold.
+pub async fn post_exclusively<F: Future>(
+    pool: &DbPool,
+    post: F,
+) -> Result<F::Output, PostingLockError> {
+    post_exclusively_as(pool, &process_identity(), post).await
+}
+
+async fn post_exclusively_as<F: Future>(
+    pool: &DbPool,
+    holder: &str,
+    post: F,
+) -> Result<F::Output, PostingLockError> {
+    let already_held = process_lock::get_lock_holder(pool, POSTING_LOCK)
+        .await?
+        .is_some_and(|current| current.holder == holder);
+    let stale_secs = DEFAULT_STALE_AFTER.as_secs() as i64;
+    match process_lock::try_acquire_lock(pool, POSTING_LOCK, holder, stale_secs).await? {
+        LockAttempt::Acquired { took_over } => {
+            if let Some(prev) = took_over {
+                tracing::warn!(
+                    holder,
+                    previous = %prev.holder,
+                    last_heartbeat = %prev.heartbeat_at,
+                    "Took over stale posting lock"
+                );
+            }
+        }
+        LockAttempt::Held(other) => {
+            tracing::info!(
+                holder = %other.holder,
+                last_heartbeat = %other.heartbeat_at,
+                "Posting lock held by another process, refusing post"
+            );
+            return Err(PostingLockError::Held(other));
+        }
+    }
+
+    let output = post.await;
+    if !already_held {
+        if let Err(e) = process_lock::release_lock(pool, POSTING_LOCK, holder).await {
+            tracing::warn!(error = %e, "Failed to release posting lock");
+        }
+    }
+    Ok(output)
+}
+
+/// Identify this process as `<executable> pid <pid>`.
+fn process_identity() -> String {
+    let exe = std::env::current_exe()
+        .ok()
+        .and_then(|p| p.file_stem().map(|s| s.to_string_lossy().into_owned()))
+        .unwrap_or_else(|| "tuitbot".to_string());
+    format!("{exe} pid {}", std::process::id())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::init_test_db;
+
+    #[tokio::test]
+    async fn only_one_process_posts() {
+        let pool = init_test_db().await.expect("init db");
+        let mut run =
+            PostingLock::with_holder(pool.clone(), "run pid 1".into(), DEFAULT_STALE_AFTER);
+        let mut mcp =
+            PostingLock::with_holder(pool.clone(), "mcp pid 2".into(), DEFAULT_STALE_AFTER);
+
+        assert!(run.acquire().await);
+        assert!(!mcp.acquire().await);
+        assert!(run.acquire().await);
+
+        run.release().await;
+        assert!(mcp.acquire().await);
+        assert!(!run.acquire().await);
+    }
+
+    #[tokio::test]
+    async fn one_off_post_refused_while_another_process_posts() {
+        let pool = init_test_db().await.expect("init db");
+        let mut run =
+            PostingLock::with_holder(pool.clone(), "run pid 1".into(), DEFAULT_STALE_AFTER);
+        assert!(run.acquire().await);
+
+        let err = post_exclusively_as(&pool, "mcp pid 2", async { unreachable!() })
+            .await
+            .unwrap_err();
+        assert!(matches!(err, PostingLockError::Held(h) if h.holder == "run pid 1"));
+
+        run.release().await;
+        assert_eq!(
+            post_exclusively_as(&pool, "mcp pid 2", async { 7 })
+                .await
+                .unwrap(),
+            7
+        );
+        // Released after the post, so the loop can take it back.
+        assert!(run.acquire().await);
+    }
+
+    #[tokio::test]
+    async fn one_off_post_keeps_same_process_loop_hold() {
+        let pool = init_test_db().await.expect("init db");
+        let mut loop_lock =
+            PostingLock::with_holder(pool.clone(), "srv pid 3".into(), DEFAULT_STALE_AFTER);
+        assert!(loop_lock.acquire().await);
+
+        post_exclusively_as(&pool, "srv pid 3", async {})
+            .await
+            .unwrap();
+
+        let holder = process_lock::get_lock_holder(&pool, POSTING_LOCK)
+            .await
+            .unwrap()
+            .expect("still held");
+        assert_eq!(holder.holder, "srv pid 3");
+    }
+
+    #[test]
+    fn identity_includes_pid() {
+        assert!(process_identity().ends_with(&format!("pid {}", std::process::id())));
+    }
+}
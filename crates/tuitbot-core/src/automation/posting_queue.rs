@@ -29,6 +29,9 @@ pub enum PostAction {
         content: String,
         /// Media IDs to attach (already uploaded to X API).
         media_ids: Vec<String>,
+        /// Set for replies to a trusted author with `skip_approval`: the
+        /// reply is posted even in approval mode.
+        trusted_author: Option<String>,
         /// Optional channel to receive the result (posted tweet ID or error).
         result_tx: Option<oneshot::Sender<Result<String, String>>>,
     },
@@ -41,6 +44,15 @@ pub enum PostAction {
         /// Optional channel to receive the result.
         result_tx: Option<oneshot::Sender<Result<String, String>>>,
     },
+    /// Quote an existing tweet with our own commentary.
+    Quote {
+        /// The ID of the tweet to quote.
+        tweet_id: String,
+        /// The commentary content.
+        content: String,
+        /// Optional channel to receive the result.
+        result_tx: Option<oneshot::Sender<Result<String, String>>>,
+    },
     /// Post a tweet as part of a thread (reply to previous tweet in thread).
     ThreadTweet {
         /// The tweet content.
@@ -61,12 +73,14 @@ impl std::fmt::Debug for PostAction {
                 tweet_id,
                 content,
                 media_ids,
+                trusted_author,
                 ..
             } => f
                 .debug_struct("Reply")
                 .field("tweet_id", tweet_id)
                 .field("content_len", &content.len())
                 .field("media_count", &media_ids.len())
+                .field("trusted_author", trusted_author)
                 .finish(),
             PostAction::Tweet {
                 content, media_ids, ..
@@ -75,6 +89,13 @@ impl std::fmt::Debug for PostAction {
                 .field("content_len", &content.len())
                 .field("media_count", &media_ids.len())
                 .finish(),
+            PostAction::Quote {
+                tweet_id, content, ..
+            } => f
+                .debug_struct("Quote")
+                .field("tweet_id", tweet_id)
+                .field("content_len", &content.len())
+                .finish(),
             PostAction::ThreadTweet {
                 content,
                 in_reply_to,
@@ -106,6 +127,9 @@ pub trait PostExecutor: Send + Sync {
 
     /// Post a new original tweet. Returns the posted tweet ID.
     async fn execute_tweet(&self, content: &str, media_ids: &[String]) -> Result<String, String>;
+
+    /// Quote an existing tweet. Returns the posted tweet ID.
+    async fn execute_quote(&self, tweet_id: &str, content: &str) -> Result<String, String>;
 }
 
 /// Create a bounded posting queue channel.
@@ -129,6 +153,30 @@ pub trait ApprovalQueue: Send + Sync {
 
     /// Queue a tweet for human review. Returns the queue item ID.
     async fn queue_tweet(&self, content: &str, media_paths: &[String]) -> Result<i64, String>;
+
+    /// Queue a quote tweet for human review. Returns the queue item ID.
+    async fn queue_quote(&self, tweet_id: &str, content: &str) -> Result<i64, String>;
+
+    /// Record that a reply to trusted `author` was posted without review.
+    ///
+    /// The default implementation records nothing.
+    async fn record_trusted_bypass(&self, _author: &str, _tweet_id: &str) {}
+
+    /// Whether actions are reviewed under `approval_mode`. When `false`, the
+    /// queue only receives actions while draft-only mode is on.
+    ///
+    /// The default implementation always reviews.
+    fn approval_mode(&self) -> bool {
+        true
+    }
+
+    /// Whether draft-only mode is on, in which case every action is queued,
+    /// replies to trusted authors included.
+    ///
+    /// The default implementation is never in draft-only mode.
+    async fn draft_only(&self) -> bool {
+        false
+    }
 }
 
 /// Run the posting queue consumer loop.
@@ -136,8 +184,8 @@ pub trait ApprovalQueue: Send + Sync {
 /// Processes actions sequentially with `min_delay` between each post.
 /// On cancellation, drains remaining actions in the channel before exiting.
 ///
-/// When `approval_queue` is `Some`, actions are queued for human review
-/// instead of being posted directly.
+/// Use [`run_posting_queue_with_approval`] to queue actions for human
+/// review (approval mode or draft-only mode) instead of posting directly.
 pub async fn run_posting_queue(
     receiver: mpsc::Receiver<PostAction>,
     executor: Arc<dyn PostExecutor>,
@@ -150,9 +198,12 @@ pub async fn run_posting_queue(
 
 /// Run the posting queue consumer loop with optional approval mode.
 ///
+/// `approval_queue` receives every action under approval mode (except
+/// trusted replies) and every action while draft-only mode is on.
 /// Delay between posts is randomized uniformly in `[min_delay, max_delay]`.
 /// If a `circuit_breaker` is provided, mutations are gated: the queue blocks
-/// while the breaker is Open, and errors/successes are recorded.
+/// while the breaker is Open, and errors/successes are recorded. Its delay
+/// multiplier also stretches the pause between posts.
 pub async fn run_posting_queue_with_approval(
     mut receiver: mpsc::Receiver<PostAction>,
     executor: Arc<dyn PostExecutor>,
@@ -182,8 +233,10 @@ pub async fn run_posting_queue_with_approval(
             }
         };
 
+        let review_queue = review_queue_for(&action, &approval_queue).await;
+
         // Gate on circuit breaker (only for direct execution, not approval queue).
-        if approval_queue.is_none() {
+        if review_queue.is_none() {
             if let Some(ref cb) = circuit_breaker {
                 if !cb.should_allow_mutation().await {
                     tracing::warn!("Circuit breaker open — waiting before posting");
@@ -195,10 +248,10 @@ pub async fn run_posting_queue_with_approval(
             }
         }
 
-        let result = execute_or_queue(action, &executor, &approval_queue).await;
+        let result = execute_or_queue(action, &executor, &review_queue).await;
 
         // Record result in circuit breaker.
-        if approval_queue.is_none() {
+        if review_queue.is_none() {
             if let Some(ref cb) = circuit_breaker {
                 match result {
                     PostResult::Success => {
@@ -212,7 +265,10 @@ pub async fn run_posting_queue_with_approval(
             }
         }
 
-        let delay = randomized_delay(min_delay, max_delay);
+        let mut delay = randomized_delay(min_delay, max_delay);
+        if let Some(ref cb) = circuit_breaker {
+            delay = cb.scale_delay(delay);
+        }
         if !delay.is_zero() {
             tokio::time::sleep(delay).await;
         }
@@ -221,7 +277,8 @@ pub async fn run_posting_queue_with_approval(
     // Drain remaining actions after cancellation or channel close.
     let mut drained = 0u32;
     while let Ok(action) = receiver.try_recv() {
-        execute_or_queue(action, &executor, &approval_queue).await;
+        let review_queue = review_queue_for(&action, &approval_queue).await;
+        execute_or_queue(action, &executor, &review_queue).await;
         drained += 1;
     }
 
@@ -235,6 +292,33 @@ pub async fn run_posting_queue_with_approval(
     tracing::info!("Posting queue consumer stopped");
 }
 
+/// The approval queue `action` goes to, if any. In draft-only mode every
+/// action is queued. Otherwise replies to trusted authors skip review; the
+/// bypass is recorded on the queue.
+async fn review_queue_for(
+    action: &PostAction,
+    approval_queue: &Option<Arc<dyn ApprovalQueue>>,
+) -> Option<Arc<dyn ApprovalQueue>> {
+    let queue = approval_queue.as_ref()?;
+    if queue.draft_only().await {
+        return Some(queue.clone());
+    }
+    if !queue.approval_mode() {
+        return None;
+    }
+    if let PostAction::Reply {
+        tweet_id,
+        trusted_author: Some(author),
+        ..
+    } = action
+    {
+        tracing::info!(tweet_id = %tweet_id, author = %author, "Reply to trusted author skips approval");
+        queue.record_trusted_bypass(author, tweet_id).await;
+        return None;
+    }
+    Some(queue.clone())
+}
+
 /// Whether an error message indicates a rate limit or forbidden response.
 fn is_rate_limit_error(msg: &str) -> bool {
     let lower = msg.to_lowercase();
@@ -273,6 +357,7 @@ async fn queue_for_approval(action: PostAction, queue: &Arc<dyn ApprovalQueue>)
             tweet_id,
             content,
             media_ids: _,
+            trusted_author: _,
             result_tx,
         } => {
             tracing::info!(tweet_id = %tweet_id, "Queuing reply for approval");
@@ -294,6 +379,18 @@ async fn queue_for_approval(action: PostAction, queue: &Arc<dyn ApprovalQueue>)
                 .map(|id| format!("queued:{id}"));
             (r, result_tx)
         }
+        PostAction::Quote {
+            tweet_id,
+            content,
+            result_tx,
+        } => {
+            tracing::info!(tweet_id = %tweet_id, "Queuing quote tweet for approval");
+            let r = queue
+                .queue_quote(&tweet_id, &content)
+                .await
+                .map(|id| format!("queued:{id}"));
+            (r, result_tx)
+        }
         PostAction::ThreadTweet {
             content,
             in_reply_to,
@@ -326,6 +423,7 @@ async fn execute_and_respond(action: PostAction, executor: &Arc<dyn PostExecutor
             tweet_id,
             content,
             media_ids,
+            trusted_author: _,
             result_tx,
         } => {
             tracing::debug!(tweet_id = %tweet_id, "Executing reply action");
@@ -343,6 +441,15 @@ async fn execute_and_respond(action: PostAction, executor: &Arc<dyn PostExecutor
             let r = executor.execute_tweet(&content, &media_ids).await;
             (r, result_tx)
         }
+        PostAction::Quote {
+            tweet_id,
+            content,
+            result_tx,
+        } => {
+            tracing::debug!(tweet_id = %tweet_id, "Executing quote tweet action");
+            let r = executor.execute_quote(&tweet_id, &content).await;
+            (r, result_tx)
+        }
         PostAction::ThreadTweet {
             content,
             in_reply_to,
@@ -455,6 +562,18 @@ mod tests {
                 Ok("tweet-id-456".to_string())
             }
         }
+
+        async fn execute_quote(&self, tweet_id: &str, content: &str) -> Result<String, String> {
+            self.calls
+                .lock()
+                .expect("lock poisoned")
+                .push(("quote".to_string(), format!("{tweet_id}:{content}")));
+            if self.fail {
+                Err("mock error".to_string())
+            } else {
+                Ok("quote-id-789".to_string())
+            }
+        }
     }
 
     #[tokio::test]
@@ -474,6 +593,7 @@ mod tests {
             tweet_id: "t1".to_string(),
             content: "hello".to_string(),
             media_ids: vec![],
+            trusted_author: None,
             result_tx: Some(result_tx),
         })
         .await
@@ -548,6 +668,40 @@ mod tests {
         assert!(calls[0].1.contains("prev-id"));
     }
 
+    #[tokio::test]
+    async fn process_quote_action() {
+        let executor = Arc::new(MockExecutor::new());
+        let (tx, rx) = create_posting_queue();
+        let cancel = CancellationToken::new();
+
+        let cancel_clone = cancel.clone();
+        let exec_clone = executor.clone();
+        let handle = tokio::spawn(async move {
+            run_posting_queue(rx, exec_clone, Duration::ZERO, cancel_clone).await;
+        });
+
+        let (result_tx, result_rx) = oneshot::channel();
+        tx.send(PostAction::Quote {
+            tweet_id: "q1".to_string(),
+            content: "worth reading".to_string(),
+            result_tx: Some(result_tx),
+        })
+        .await
+        .expect("send failed");
+
+        let result = result_rx.await.expect("oneshot recv");
+        assert_eq!(result, Ok("quote-id-789".to_string()));
+
+        cancel.cancel();
+        handle.await.expect("join");
+
+        let calls = executor.calls();
+        assert_eq!(
+            calls[0],
+            ("quote".to_string(), "q1:worth reading".to_string())
+        );
+    }
+
     #[tokio::test]
     async fn result_tx_none_does_not_panic() {
         let executor = Arc::new(MockExecutor::new());
@@ -700,6 +854,7 @@ mod tests {
             tweet_id: "123".to_string(),
             content: "hello world".to_string(),
             media_ids: vec![],
+            trusted_author: None,
             result_tx: None,
         };
         let debug = format!("{action:?}");
@@ -711,12 +866,18 @@ mod tests {
 
     struct MockApprovalQueue {
         items: Mutex<Vec<(String, String, String)>>,
+        bypasses: Mutex<Vec<String>>,
+        approval_mode: bool,
+        draft_only: bool,
     }
 
     impl MockApprovalQueue {
         fn new() -> Self {
             Self {
                 items: Mutex::new(Vec::new()),
+                bypasses: Mutex::new(Vec::new()),
+                approval_mode: true,
+                draft_only: false,
             }
         }
 
@@ -749,6 +910,169 @@ mod tests {
             ));
             Ok(self.item_count() as i64)
         }
+
+        async fn queue_quote(&self, tweet_id: &str, content: &str) -> Result<i64, String> {
+            self.items.lock().expect("lock").push((
+                "quote".to_string(),
+                tweet_id.to_string(),
+                content.to_string(),
+            ));
+            Ok(self.item_count() as i64)
+        }
+
+        async fn record_trusted_bypass(&self, author: &str, tweet_id: &str) {
+            self.bypasses
+                .lock()
+                .expect("lock")
+                .push(format!("{author}:{tweet_id}"));
+        }
+
+        fn approval_mode(&self) -> bool {
+            self.approval_mode
+        }
+
+        async fn draft_only(&self) -> bool {
+            self.draft_only
+        }
+    }
+
+    #[tokio::test]
+    async fn draft_only_queues_everything_without_approval_mode() {
+        let executor = Arc::new(MockExecutor::new());
+        let approval = Arc::new(MockApprovalQueue {
+            approval_mode: false,
+            draft_only: true,
+            ..MockApprovalQueue::new()
+        });
+        let (tx, rx) = create_posting_queue();
+        let cancel = CancellationToken::new();
+
+        let cancel_clone = cancel.clone();
+        let exec_clone = executor.clone();
+        let approval_clone = approval.clone();
+        let handle = tokio::spawn(async move {
+            run_posting_queue_with_approval(
+                rx,
+                exec_clone,
+                Some(approval_clone),
+                Duration::ZERO,
+                Duration::ZERO,
+                None,
+                cancel_clone,
+            )
+            .await;
+        });
+
+        let (result_tx, result_rx) = oneshot::channel();
+        tx.send(PostAction::Reply {
+            tweet_id: "t1".to_string(),
+            content: "hello".to_string(),
+            media_ids: vec![],
+            trusted_author: Some("myalt".to_string()),
+            result_tx: Some(result_tx),
+        })
+        .await
+        .expect("send");
+
+        let result = result_rx.await.expect("recv").expect("queued");
+        assert!(result.starts_with("queued:"));
+        assert_eq!(executor.call_count(), 0);
+        assert_eq!(approval.item_count(), 1);
+        assert!(approval.bypasses.lock().expect("lock").is_empty());
+
+        cancel.cancel();
+        handle.await.expect("join");
+    }
+
+    #[tokio::test]
+    async fn queue_without_approval_mode_posts_directly() {
+        let executor = Arc::new(MockExecutor::new());
+        let approval = Arc::new(MockApprovalQueue {
+            approval_mode: false,
+            ..MockApprovalQueue::new()
+        });
+        let (tx, rx) = create_posting_queue();
+        let cancel = CancellationToken::new();
+
+        let cancel_clone = cancel.clone();
+        let exec_clone = executor.clone();
+        let approval_clone = approval.clone();
+        let handle = tokio::spawn(async move {
+            run_posting_queue_with_approval(
+                rx,
+                exec_clone,
+                Some(approval_clone),
+                Duration::ZERO,
+                Duration::ZERO,
+                None,
+                cancel_clone,
+            )
+            .await;
+        });
+
+        let (result_tx, result_rx) = oneshot::channel();
+        tx.send(PostAction::Tweet {
+            content: "hello".to_string(),
+            media_ids: vec![],
+            result_tx: Some(result_tx),
+        })
+        .await
+        .expect("send");
+
+        let result = result_rx.await.expect("recv").expect("posted");
+        assert!(!result.starts_with("queued:"));
+        assert_eq!(executor.call_count(), 1);
+        assert_eq!(approval.item_count(), 0);
+
+        cancel.cancel();
+        handle.await.expect("join");
+    }
+
+    #[tokio::test]
+    async fn approval_mode_posts_trusted_replies() {
+        let executor = Arc::new(MockExecutor::new());
+        let approval = Arc::new(MockApprovalQueue::new());
+        let (tx, rx) = create_posting_queue();
+        let cancel = CancellationToken::new();
+
+        let cancel_clone = cancel.clone();
+        let exec_clone = executor.clone();
+        let approval_clone = approval.clone();
+        let handle = tokio::spawn(async move {
+            run_posting_queue_with_approval(
+                rx,
+                exec_clone,
+                Some(approval_clone),
+                Duration::ZERO,
+                Duration::ZERO,
+                None,
+                cancel_clone,
+            )
+            .await;
+        });
+
+        let (result_tx, result_rx) = oneshot::channel();
+        tx.send(PostAction::Reply {
+            tweet_id: "t1".to_string(),
+            content: "hello".to_string(),
+            media_ids: vec![],
+            trusted_author: Some("myalt".to_string()),
+            result_tx: Some(result_tx),
+        })
+        .await
+        .expect("send");
+
+        let result = result_rx.await.expect("recv").expect("posted");
+        assert!(!result.starts_with("queued:"));
+        assert_eq!(executor.call_count(), 1);
+        assert_eq!(approval.item_count(), 0);
+        assert_eq!(
+            *approval.bypasses.lock().expect("lock"),
+            vec!["myalt:t1".to_string()]
+        );
+
+        cancel.cancel();
+        handle.await.expect("join");
     }
 
     #[tokio::test]
@@ -779,6 +1103,7 @@ mod tests {
             tweet_id: "t1".to_string(),
             content: "hello".to_string(),
             media_ids: vec![],
+            trusted_author: None,
             result_tx: Some(result_tx),
         })
         .await
@@ -836,4 +1161,50 @@ mod tests {
         cancel.cancel();
         handle.await.expect("join");
     }
+
+    #[tokio::test]
+    async fn approval_mode_queues_quotes() {
+        let executor = Arc::new(MockExecutor::new());
+        let approval = Arc::new(MockApprovalQueue::new());
+        let (tx, rx) = create_posting_queue();
+        let cancel = CancellationToken::new();
+
+        let cancel_clone = cancel.clone();
+        let exec_clone = executor.clone();
+        let approval_clone = approval.clone();
+        let handle = tokio::spawn(async move {
+            run_posting_queue_with_approval(
+                rx,
+                exec_clone,
+                Some(approval_clone),
+                Duration::ZERO,
+                Duration::ZERO,
+                None,
+                cancel_clone,
+            )
+            .await;
+        });
+
+        let (result_tx, result_rx) = oneshot::channel();
+        tx.send(PostAction::Quote {
+            tweet_id: "q1".to_string(),
+            content: "worth reading".to_string(),
+            result_tx: Some(result_tx),
+        })
+        .await
+        .expect("send");
+
+        assert!(result_rx
+            .await
+            .expect("recv")
+            .unwrap()
+            .starts_with("queued:"));
+        assert_eq!(executor.call_count(), 0);
+        let items = approval.items.lock().expect("lock").clone();
+        assert_eq!(items[0].0, "quote");
+        assert_eq!(items[0].1, "q1");
+
+        cancel.cancel();
+        handle.await.expect("join");
+    }
 }
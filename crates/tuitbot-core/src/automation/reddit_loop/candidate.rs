@@ -0,0 +1,171 @@
+//! Per-post candidate handling: dedup, scoring, and queueing a suggested comment.
+
+use super::{RedditLoop, RedditResult};
+use crate::automation::loop_helpers::LoopTweet;
+use crate::reddit::RedditPost;
+use crate::safety::protected;
+use std::time::Duration;
+
+/// Window during which a post is not re-scored. Searches cover a week.
+const DEDUP_WINDOW: Duration = Duration::from_secs(7 * 24 * 3600);
+
+/// Longest post body passed to scoring and generation.
+const MAX_BODY_CHARS: usize = 2_000;
+
+impl RedditLoop {
+    /// Dedup, score, store, and queue a suggested comment for one post.
+    pub(super) async fn process_post(&self, post: &RedditPost) -> RedditResult {
+        let item = to_loop_tweet(post);
+        let keyword = self.matched_keyword(&item.text);
+
+        if !post.accepts_comments() {
+            return RedditResult::Skipped {
+                post_id: post.name.clone(),
+                reason: "locked or archived".to_string(),
+            };
+        }
+        if protected::is_protected(&post.author, &self.protected) {
+            return RedditResult::Skipped {
+                post_id: post.name.clone(),
+                reason: "author is a protected entity".to_string(),
+            };
+        }
+
+        let seen = self.storage.tweet_seen_within(&item.id, DEDUP_WINDOW).await;
+        if let Err(e) = self.storage.record_keyword_hit(&item.id, keyword).await {
+            tracing::warn!(post_id = %post.name, error = %e, "Failed to record keyword hit");
+        }
+        match seen {
+            Ok(true) => {
+                return RedditResult::Skipped {
+                    post_id: post.name.clone(),
+                    reason: "already seen".to_string(),
+                };
+            }
+            Ok(false) => {}
+            Err(e) => {
+                tracing::warn!(post_id = %post.name, error = %e, "Failed to check post existence");
+            }
+        }
+
+        let score = self.scorer.score(&item);
+        if let Err(e) = self
+            .storage
+            .store_discovered_tweet(&item, score.total, keyword)
+            .await
+        {
+            tracing::warn!(post_id = %post.name, error = %e, "Failed to store Reddit post");
+        }
+        if !score.meets_threshold {
+            tracing::debug!(
+                post_id = %post.name,
+                score = score.total,
+                "Reddit post scored below threshold, skipping"
+            );
+            return RedditResult::BelowThreshold {
+                post_id: post.name.clone(),
+                score: score.total,
+            };
+        }
+
+        let comment = match self
+            .generator
+            .generate_reply(&item.text, &post.author, true)
+            .await
+        {
+            Ok(text) => text,
+            Err(e) => {
+                return RedditResult::Failed {
+                    post_id: post.name.clone(),
+                    error: e.to_string(),
+                }
+            }
+        };
+
+        if self.dry_run {
+            tracing::info!(
+                "DRY RUN: Would queue comment on r/{} post \"{}\": \"{}\"",
+                post.subreddit,
+                truncate(&post.title, 60),
+                comment
+            );
+        } else if let Err(e) = self.queue.queue_comment(post, &comment, score.total).await {
+            return RedditResult::Failed {
+                post_id: post.name.clone(),
+                error: e.to_string(),
+            };
+        } else {
+            tracing::info!(
+                subreddit = %post.subreddit,
+                post_id = %post.name,
+                score = score.total,
+                "Queued suggested Reddit comment for approval"
+            );
+            let _ = self
+                .storage
+                .log_action(
+                    "reddit_comment",
+                    "queued",
+                    &format!("Queued comment on r/{}: {}", post.subreddit, post.title),
+                )
+                .await;
+        }
+
+        RedditResult::Queued {
+            post_id: post.name.clone(),
+            subreddit: post.subreddit.clone(),
+            score: score.total,
+            comment,
+        }
+    }
+
+    /// First keyword appearing in `text`, falling back to the first keyword.
+    fn matched_keyword(&self, text: &str) -> &str {
+        let lower = text.to_lowercase();
+        self.keywords
+            .iter()
+            .find(|k| lower.contains(&k.to_lowercase()))
+            .or(self.keywords.first())
+            .map(String::as_str)
+            .unwrap_or("")
+    }
+}
+
+/// Map a Reddit post onto the shared loop representation for scoring.
+pub(super) fn to_loop_tweet(post: &RedditPost) -> LoopTweet {
+    let body = truncate(post.selftext.trim(), MAX_BODY_CHARS);
+    let text = if body.is_empty() {
+        post.title.clone()
+    } else {
+        format!("{}\n\n{body}", post.title)
+    };
+    let created_at = chrono::DateTime::from_timestamp(post.created_utc as i64, 0)
+        .map(|dt| dt.to_rfc3339())
+        .unwrap_or_default();
+    let urls = if post.url.is_empty() || post.url.contains(&post.permalink) {
+        Vec::new()
+    } else {
+        vec![post.url.clone()]
+    };
+    LoopTweet {
+        id: format!("reddit:{}", post.name),
+        text,
+        author_id: format!("reddit:{}", post.author),
+        author_username: post.author.clone(),
+        author_followers: 0,
+        created_at,
+        likes: post.score.max(0) as u64,
+        retweets: 0,
+        replies: post.num_comments,
+        quotes: 0,
+        urls,
+        has_media: false,
+    }
+}
+
+fn truncate(s: &str, max_chars: usize) -> &str {
+    match s.char_indices().nth(max_chars) {
+        Some((idx, _)) => &s[..idx],
+        None => s,
+    }
+}
@@ -0,0 +1,279 @@
+//! Reddit monitoring loop.
+//!
+//! Searches configured subreddits for posts matching the business keywords,
+//! scores each post with the same engine as tweets, and queues a suggested
+//! comment for qualifying posts in the approval queue under the `reddit`
+//! platform tag. Nothing is posted from this loop: approved comments are
+//! sent by [`run_reddit_poster`](super::reddit_poster::run_reddit_poster)
+//! within the daily Reddit comment limit.
+//!
+//! Posts are stored with the discovered tweets under the id
+//! `reddit:<fullname>` and are not re-scored within the dedup window.
+//! Posts by protected entities are skipped.
+
+use super::loop_helpers::{
+    ConsecutiveErrorTracker, LoopError, LoopStorage, ReplyGenerator, TweetScorer,
+};
+use super::schedule::{schedule_gate, ActiveSchedule};
+use super::scheduler::LoopScheduler;
+use crate::reddit::RedditPost;
+use crate::safety::protected;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio_util::sync::CancellationToken;
+
+mod candidate;
+
+#[cfg(test)]
+mod tests;
+
+// ============================================================================
+// Port traits specific to the Reddit loop
+// ============================================================================
+
+/// Searches a subreddit for new posts.
+#[async_trait::async_trait]
+pub trait RedditPostSearcher: Send + Sync {
+    /// Search `subreddit` for posts matching `query`, newest first.
+    async fn search_posts(
+        &self,
+        subreddit: &str,
+        query: &str,
+    ) -> Result<Vec<RedditPost>, LoopError>;
+}
+
+/// Queues suggested comments for human review.
+#[async_trait::async_trait]
+pub trait RedditCommentQueue: Send + Sync {
+    /// Queue `content` as a comment on `post`. Returns the queue item ID.
+    async fn queue_comment(
+        &self,
+        post: &RedditPost,
+        content: &str,
+        score: f32,
+    ) -> Result<i64, LoopError>;
+}
+
+// ============================================================================
+// Reddit loop
+// ============================================================================
+
+/// Monitors subreddits and queues suggested comments.
+pub struct RedditLoop {
+    searcher: Arc<dyn RedditPostSearcher>,
+    scorer: Arc<dyn TweetScorer>,
+    generator: Arc<dyn ReplyGenerator>,
+    storage: Arc<dyn LoopStorage>,
+    queue: Arc<dyn RedditCommentQueue>,
+    subreddits: Vec<String>,
+    keywords: Vec<String>,
+    protected: Vec<String>,
+    dry_run: bool,
+}
+
+/// Result of processing a single Reddit post.
+#[derive(Debug)]
+pub enum RedditResult {
+    /// A suggested comment was queued (or would be in dry-run).
+    Queued {
+        post_id: String,
+        subreddit: String,
+        score: f32,
+        comment: String,
+    },
+    /// Post scored below threshold.
+    BelowThreshold { post_id: String, score: f32 },
+    /// Post was skipped (already seen, locked).
+    Skipped { post_id: String, reason: String },
+    /// Processing failed for this post.
+    Failed { post_id: String, error: String },
+}
+
+/// Summary of a pass over all subreddits.
+#[derive(Debug, Default)]
+pub struct RedditSummary {
+    /// Posts returned by the searches.
+    pub posts_found: usize,
+    /// Suggested comments queued.
+    pub queued: usize,
+    /// Posts skipped or below threshold.
+    pub skipped: usize,
+    /// Posts that failed processing.
+    pub failed: usize,
+}
+
+impl RedditLoop {
+    /// Create a new Reddit loop.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        searcher: Arc<dyn RedditPostSearcher>,
+        scorer: Arc<dyn TweetScorer>,
+        generator: Arc<dyn ReplyGenerator>,
+        storage: Arc<dyn LoopStorage>,
+        queue: Arc<dyn RedditCommentQueue>,
+        subreddits: Vec<String>,
+        keywords: Vec<String>,
+        dry_run: bool,
+    ) -> Self {
+        let subreddits = subreddits
+            .iter()
+            .map(|s| s.trim().trim_start_matches("r/").to_string())
+            .filter(|s| !s.is_empty())
+            .collect();
+        Self {
+            searcher,
+            scorer,
+            generator,
+            storage,
+            queue,
+            subreddits,
+            keywords,
+            protected: Vec::new(),
+            dry_run,
+        }
+    }
+
+    /// Never comment on posts by these protected entities (`blocklist.protected`).
+    pub fn with_protected_entities(mut self, protected: &[String]) -> Self {
+        self.protected = protected::normalize_protected(protected);
+        self
+    }
+
+    /// Run the continuous Reddit loop until cancellation.
+    pub async fn run(
+        &self,
+        cancel: CancellationToken,
+        scheduler: LoopScheduler,
+        schedule: Option<Arc<ActiveSchedule>>,
+    ) {
+        tracing::info!(
+            dry_run = self.dry_run,
+            subreddits = self.subreddits.len(),
+            keywords = self.keywords.len(),
+            "Reddit loop started"
+        );
+
+        if self.subreddits.is_empty() || self.keywords.is_empty() {
+            tracing::warn!("No subreddits or keywords configured, Reddit loop has nothing to do");
+            cancel.cancelled().await;
+            return;
+        }
+
+        let mut error_tracker = ConsecutiveErrorTracker::new(5, Duration::from_secs(900));
+
+        loop {
+            if cancel.is_cancelled() {
+                break;
+            }
+
+            if !schedule_gate(&schedule, &cancel).await {
+                break;
+            }
+
+            match self.run_once().await {
+                Ok((_, summary)) => {
+                    error_tracker.record_success();
+                    if summary.posts_found > 0 {
+                        tracing::info!(
+                            found = summary.posts_found,
+                            queued = summary.queued,
+                            skipped = summary.skipped,
+                            failed = summary.failed,
+                            "Reddit iteration complete"
+                        );
+                    }
+                }
+                Err(e) => {
+                    let should_pause = error_tracker.record_error();
+                    tracing::warn!(
+                        error = %e,
+                        consecutive_errors = error_tracker.count(),
+                        "Reddit iteration failed"
+                    );
+
+                    let backoff = if should_pause {
+                        let pause = error_tracker.pause_duration();
+                        error_tracker.reset();
+                        Some(pause)
+                    } else if let LoopError::RateLimited { retry_after } = &e {
+                        Some(super::loop_helpers::rate_limit_backoff(*retry_after, 0))
+                    } else {
+                        None
+                    };
+                    if let Some(backoff) = backoff {
+                        tokio::select! {
+                            _ = cancel.cancelled() => break,
+                            _ = tokio::time::sleep(backoff) => {},
+                        }
+                        continue;
+                    }
+                }
+            }
+
+            tokio::select! {
+                _ = cancel.cancelled() => break,
+                _ = scheduler.tick() => {},
+            }
+        }
+
+        tracing::info!("Reddit loop stopped");
+    }
+
+    /// Search every subreddit once and process the results.
+    ///
+    /// A failing subreddit is logged and skipped; the error is returned only
+    /// when every subreddit failed.
+    pub async fn run_once(&self) -> Result<(Vec<RedditResult>, RedditSummary), LoopError> {
+        let query = search_query(&self.keywords);
+        let mut results = Vec::new();
+        let mut summary = RedditSummary::default();
+        let mut last_error = None;
+        let mut any_ok = false;
+
+        for subreddit in &self.subreddits {
+            let posts = match self.searcher.search_posts(subreddit, &query).await {
+                Ok(posts) => posts,
+                Err(e) => {
+                    tracing::warn!(subreddit = %subreddit, error = %e, "Subreddit search failed");
+                    last_error = Some(e);
+                    continue;
+                }
+            };
+            any_ok = true;
+            summary.posts_found += posts.len();
+            for post in &posts {
+                let result = self.process_post(post).await;
+                match &result {
+                    RedditResult::Queued { .. } => summary.queued += 1,
+                    RedditResult::BelowThreshold { .. } | RedditResult::Skipped { .. } => {
+                        summary.skipped += 1
+                    }
+                    RedditResult::Failed { .. } => summary.failed += 1,
+                }
+                results.push(result);
+            }
+        }
+
+        match last_error {
+            Some(e) if !any_ok => Err(e),
+            _ => Ok((results, summary)),
+        }
+    }
+}
+
+/// Reddit search query matching any keyword; multi-word keywords are quoted.
+fn search_query(keywords: &[String]) -> String {
+    keywords
+        .iter()
+        .map(|k| k.trim())
+        .filter(|k| !k.is_empty())
+        .map(|k| {
+            if k.contains(char::is_whitespace) {
+                format!("\"{k}\"")
+            } else {
+                k.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" OR ")
+}
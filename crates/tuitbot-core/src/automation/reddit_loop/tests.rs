@@ -0,0 +1,260 @@
+use super::candidate::to_loop_tweet;
+use super::*;
+use crate::automation::{LoopTweet, ScoreResult};
+use crate::scoring::EntityType;
+use std::sync::Mutex;
+
+struct MockSearcher {
+    posts: Vec<RedditPost>,
+    queries: Mutex<Vec<(String, String)>>,
+}
+
+#[async_trait::async_trait]
+impl RedditPostSearcher for MockSearcher {
+    async fn search_posts(
+        &self,
+        subreddit: &str,
+        query: &str,
+    ) -> Result<Vec<RedditPost>, LoopError> {
+        self.queries
+            .lock()
+            .expect("lock")
+            .push((subreddit.to_string(), query.to_string()));
+        if subreddit == "private" {
+            return Err(LoopError::Other("forbidden".to_string()));
+        }
+        Ok(self.posts.clone())
+    }
+}
+
+struct MockScorer {
+    score: f32,
+}
+
+impl TweetScorer for MockScorer {
+    fn score(&self, _tweet: &LoopTweet) -> ScoreResult {
+        ScoreResult {
+            total: self.score,
+            meets_threshold: self.score >= 60.0,
+            past_cutoff: false,
+            matched_keywords: vec![],
+            entity_type: EntityType::Text,
+            signals: None,
+        }
+    }
+}
+
+struct MockGenerator;
+
+#[async_trait::async_trait]
+impl ReplyGenerator for MockGenerator {
+    async fn generate_reply(
+        &self,
+        _tweet_text: &str,
+        author: &str,
+        _mention_product: bool,
+    ) -> Result<String, LoopError> {
+        Ok(format!("Hi {author}, try clap."))
+    }
+}
+
+#[derive(Default)]
+struct MockStorage {
+    seen: Mutex<Vec<String>>,
+    stored: Mutex<Vec<(String, String)>>,
+}
+
+#[async_trait::async_trait]
+impl LoopStorage for MockStorage {
+    async fn get_cursor(&self, _key: &str) -> Result<Option<String>, LoopError> {
+        Ok(None)
+    }
+    async fn set_cursor(&self, _key: &str, _value: &str) -> Result<(), LoopError> {
+        Ok(())
+    }
+    async fn tweet_exists(&self, _tweet_id: &str) -> Result<bool, LoopError> {
+        Ok(false)
+    }
+    async fn tweet_seen_within(
+        &self,
+        tweet_id: &str,
+        _window: Duration,
+    ) -> Result<bool, LoopError> {
+        Ok(self
+            .seen
+            .lock()
+            .expect("lock")
+            .contains(&tweet_id.to_string()))
+    }
+    async fn record_keyword_hit(&self, tweet_id: &str, _keyword: &str) -> Result<(), LoopError> {
+        self.seen.lock().expect("lock").push(tweet_id.to_string());
+        Ok(())
+    }
+    async fn store_discovered_tweet(
+        &self,
+        tweet: &LoopTweet,
+        _score: f32,
+        keyword: &str,
+    ) -> Result<(), LoopError> {
+        self.stored
+            .lock()
+            .expect("lock")
+            .push((tweet.id.clone(), keyword.to_string()));
+        Ok(())
+    }
+    async fn log_action(
+        &self,
+        _action_type: &str,
+        _status: &str,
+        _message: &str,
+    ) -> Result<(), LoopError> {
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+struct MockQueue {
+    queued: Mutex<Vec<(String, String)>>,
+}
+
+#[async_trait::async_trait]
+impl RedditCommentQueue for MockQueue {
+    async fn queue_comment(
+        &self,
+        post: &RedditPost,
+        content: &str,
+        _score: f32,
+    ) -> Result<i64, LoopError> {
+        let mut queued = self.queued.lock().expect("lock");
+        queued.push((post.name.clone(), content.to_string()));
+        Ok(queued.len() as i64)
+    }
+}
+
+fn post(id: &str, title: &str) -> RedditPost {
+    RedditPost {
+        id: id.to_string(),
+        name: format!("t3_{id}"),
+        subreddit: "rust".to_string(),
+        title: title.to_string(),
+        selftext: String::new(),
+        author: "ferris".to_string(),
+        score: 12,
+        num_comments: 4,
+        created_utc: 1_760_000_000.0,
+        permalink: format!("/r/rust/comments/{id}/x/"),
+        url: format!("https://www.reddit.com/r/rust/comments/{id}/x/"),
+        locked: false,
+        archived: false,
+    }
+}
+
+struct Harness {
+    reddit: RedditLoop,
+    searcher: Arc<MockSearcher>,
+    storage: Arc<MockStorage>,
+    queue: Arc<MockQueue>,
+}
+
+fn build(posts: Vec<RedditPost>, score: f32, subreddits: &[&str]) -> Harness {
+    let searcher = Arc::new(MockSearcher {
+        posts,
+        queries: Mutex::new(Vec::new()),
+    });
+    let storage = Arc::new(MockStorage::default());
+    let queue = Arc::new(MockQueue::default());
+    let reddit = RedditLoop::new(
+        searcher.clone(),
+        Arc::new(MockScorer { score }),
+        Arc::new(MockGenerator),
+        storage.clone(),
+        queue.clone(),
+        subreddits.iter().map(|s| s.to_string()).collect(),
+        vec!["cli".to_string(), "arg parsing".to_string()],
+        false,
+    );
+    Harness {
+        reddit,
+        searcher,
+        storage,
+        queue,
+    }
+}
+
+#[tokio::test]
+async fn qualifying_post_is_queued_once() {
+    let h = build(
+        vec![post("a1", "Best arg parsing crate?")],
+        80.0,
+        &["r/rust"],
+    );
+
+    let (_, summary) = h.reddit.run_once().await.unwrap();
+    assert_eq!(summary.posts_found, 1);
+    assert_eq!(summary.queued, 1);
+    assert_eq!(
+        *h.queue.queued.lock().expect("lock"),
+        vec![("t3_a1".to_string(), "Hi ferris, try clap.".to_string())]
+    );
+    assert_eq!(
+        *h.storage.stored.lock().expect("lock"),
+        vec![("reddit:t3_a1".to_string(), "arg parsing".to_string())]
+    );
+    assert_eq!(
+        h.searcher.queries.lock().expect("lock")[0],
+        ("rust".to_string(), "cli OR \"arg parsing\"".to_string())
+    );
+
+    let (_, summary) = h.reddit.run_once().await.unwrap();
+    assert_eq!(summary.queued, 0);
+    assert_eq!(summary.skipped, 1, "seen posts are not re-queued");
+}
+
+#[tokio::test]
+async fn low_score_and_locked_posts_are_not_queued() {
+    let mut locked = post("a2", "Locked cli thread");
+    locked.locked = true;
+    let h = build(vec![post("a1", "cli question"), locked], 30.0, &["rust"]);
+
+    let (results, summary) = h.reddit.run_once().await.unwrap();
+    assert_eq!(summary.skipped, 2);
+    assert!(h.queue.queued.lock().expect("lock").is_empty());
+    assert!(matches!(results[0], RedditResult::BelowThreshold { .. }));
+    assert!(matches!(results[1], RedditResult::Skipped { .. }));
+}
+
+#[tokio::test]
+async fn posts_by_protected_entities_are_skipped() {
+    let mut h = build(vec![post("a1", "cli question")], 80.0, &["rust"]);
+    h.reddit = h.reddit.with_protected_entities(&["Ferris".to_string()]);
+
+    let (results, summary) = h.reddit.run_once().await.unwrap();
+    assert_eq!(summary.skipped, 1);
+    assert!(h.queue.queued.lock().expect("lock").is_empty());
+    assert!(matches!(results[0], RedditResult::Skipped { .. }));
+}
+
+#[tokio::test]
+async fn failing_subreddit_is_skipped_unless_all_fail() {
+    let h = build(vec![post("a1", "cli")], 80.0, &["private", "rust"]);
+    let (_, summary) = h.reddit.run_once().await.unwrap();
+    assert_eq!(summary.queued, 1);
+
+    let h = build(vec![post("a1", "cli")], 80.0, &["private"]);
+    assert!(h.reddit.run_once().await.is_err());
+}
+
+#[test]
+fn to_loop_tweet_maps_fields() {
+    let mut p = post("a1", "Title");
+    p.selftext = "Body".to_string();
+    p.score = -3;
+    p.url = "https://example.com".to_string();
+    let item = to_loop_tweet(&p);
+    assert_eq!(item.id, "reddit:t3_a1");
+    assert_eq!(item.text, "Title\n\nBody");
+    assert_eq!(item.likes, 0);
+    assert_eq!(item.replies, 4);
+    assert_eq!(item.urls, vec!["https://example.com"]);
+    assert!(item.created_at.starts_with("2025-10-09"));
+}
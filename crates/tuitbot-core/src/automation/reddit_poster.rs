@@ -0,0 +1,256 @@
+//! Reddit poster loop: posts approved Reddit comments.
+//!
+//! The counterpart of the approval poster for items tagged with the `reddit`
+//! platform. Each comment is checked against the daily `reddit_comment`
+//! rate limit before it is sent; over the limit, approved comments wait for
+//! the next window. Approved items are held while the kill switch is engaged
+//! or draft-only mode is on.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio_util::sync::CancellationToken;
+
+use super::approval_poster::randomized_delay;
+use crate::reddit::RedditApi;
+use crate::storage::approval_queue::{self, ApprovalItem, PLATFORM_REDDIT};
+use crate::storage::{self, DbPool};
+
+/// Rate limit action type for Reddit comments.
+pub const REDDIT_COMMENT_ACTION: &str = "reddit_comment";
+
+/// Outcome of one poll of the approval queue.
+#[derive(Debug, PartialEq, Eq)]
+enum PollOutcome {
+    /// Nothing approved, or the item changed before it could be claimed.
+    Idle,
+    /// Daily comment limit reached; the item stays approved.
+    LimitReached,
+    /// A comment was posted.
+    Posted,
+    /// Posting failed; the item stays approved for a retry.
+    Failed,
+}
+
+/// Run the Reddit poster loop until cancellation.
+///
+/// Uses a randomized delay between `min_delay` and `max_delay` after each
+/// posted comment.
+pub async fn run_reddit_poster(
+    pool: DbPool,
+    client: Arc<dyn RedditApi>,
+    min_delay: Duration,
+    max_delay: Duration,
+    cancel: CancellationToken,
+) {
+    tracing::info!("Reddit poster loop started");
+
+    let idle_interval = Duration::from_secs(30);
+    let limit_interval = Duration::from_secs(900);
+    let mut wait = idle_interval;
+
+    loop {
+        tokio::select! {
+            biased;
+            () = cancel.cancelled() => break,
+            () = tokio::time::sleep(wait) => {}
+        }
+
+        // Hold approved comments while the kill switch is engaged or
+        // draft-only mode is on (fails closed).
+        let halted = storage::kill_switch::is_kill_switch_active(&pool)
+            .await
+            .unwrap_or(true)
+            || storage::draft_only::is_draft_only_active(&pool)
+                .await
+                .unwrap_or(true);
+        if halted {
+            wait = idle_interval;
+            continue;
+        }
+
+        wait = match poll_once(&pool, &*client).await {
+            PollOutcome::Posted => randomized_delay(min_delay, max_delay),
+            PollOutcome::LimitReached => limit_interval,
+            PollOutcome::Idle | PollOutcome::Failed => idle_interval,
+        };
+    }
+
+    tracing::info!("Reddit poster loop stopped");
+}
+
+/// Post the next approved Reddit comment, if any.
+async fn poll_once(pool: &DbPool, client: &dyn RedditApi) -> PollOutcome {
+    let item = match approval_queue::get_next_approved_on_platform(pool, PLATFORM_REDDIT).await {
+        Ok(Some(item)) => item,
+        Ok(None) => return PollOutcome::Idle,
+        Err(e) => {
+            tracing::warn!(error = %e, "Failed to query approved Reddit comments");
+            return PollOutcome::Idle;
+        }
+    };
+
+    match storage::rate_limits::check_rate_limit(pool, REDDIT_COMMENT_ACTION).await {
+        Ok(true) => {}
+        Ok(false) => {
+            tracing::info!(id = item.id, "Daily Reddit comment limit reached, holding");
+            return PollOutcome::LimitReached;
+        }
+        Err(e) => {
+            tracing::warn!(error = %e, "Failed to check Reddit comment rate limit");
+            return PollOutcome::Failed;
+        }
+    }
+
+    match approval_queue::claim_for_posting(pool, item.id, item.version).await {
+        Ok(true) => {}
+        Ok(false) => return PollOutcome::Idle,
+        Err(e) => {
+            tracing::warn!(id = item.id, error = %e, "Failed to claim approved item");
+            return PollOutcome::Idle;
+        }
+    }
+
+    match post_comment(pool, client, &item).await {
+        Ok(comment_id) => {
+            tracing::info!(id = item.id, comment_id = %comment_id, "Approved Reddit comment posted");
+            if let Err(e) = approval_queue::mark_posted(pool, item.id, &comment_id).await {
+                tracing::warn!(id = item.id, error = %e, "Failed to mark approved item as posted");
+            }
+            let _ = storage::action_log::log_action(
+                pool,
+                "reddit_comment_posted",
+                "success",
+                Some(&format!("Posted approved Reddit comment {}", item.id)),
+                None,
+            )
+            .await;
+            PollOutcome::Posted
+        }
+        Err(e) => {
+            tracing::warn!(id = item.id, error = %e, "Failed to post approved Reddit comment");
+            if let Err(e) = approval_queue::release_posting_claim(pool, item.id).await {
+                tracing::warn!(id = item.id, error = %e, "Failed to release posting claim");
+            }
+            let _ = storage::action_log::log_action(
+                pool,
+                "reddit_comment_posted",
+                "error",
+                Some(&format!(
+                    "Failed to post approved Reddit comment {}: {e}",
+                    item.id
+                )),
+                None,
+            )
+            .await;
+            PollOutcome::Failed
+        }
+    }
+}
+
+/// Submit the comment and count it against the daily limit.
+async fn post_comment(
+    pool: &DbPool,
+    client: &dyn RedditApi,
+    item: &ApprovalItem,
+) -> Result<String, String> {
+    if item.target_tweet_id.is_empty() {
+        return Err("Reddit comment has no parent post".to_string());
+    }
+    let comment_id = client
+        .submit_comment(&item.target_tweet_id, &item.generated_content)
+        .await
+        .map_err(|e| e.to_string())?;
+    if let Err(e) = storage::rate_limits::increment_rate_limit(pool, REDDIT_COMMENT_ACTION).await {
+        tracing::warn!(id = item.id, error = %e, "Failed to record Reddit comment rate limit");
+    }
+    Ok(comment_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::RedditError;
+    use crate::reddit::RedditPost;
+    use crate::storage::init_test_db;
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct MockReddit {
+        comments: Mutex<Vec<(String, String)>>,
+    }
+
+    #[async_trait::async_trait]
+    impl RedditApi for MockReddit {
+        async fn search_subreddit(
+            &self,
+            _subreddit: &str,
+            _query: &str,
+            _limit: u32,
+        ) -> Result<Vec<RedditPost>, RedditError> {
+            Ok(Vec::new())
+        }
+
+        async fn submit_comment(&self, parent: &str, text: &str) -> Result<String, RedditError> {
+            let mut comments = self.comments.lock().expect("lock");
+            comments.push((parent.to_string(), text.to_string()));
+            Ok(format!("t1_{}", comments.len()))
+        }
+    }
+
+    async fn approved_comment(pool: &DbPool, parent: &str) -> i64 {
+        let id = approval_queue::enqueue_on_platform(
+            pool,
+            PLATFORM_REDDIT,
+            REDDIT_COMMENT_ACTION,
+            parent,
+            "ferris",
+            "Try clap.",
+            "r/rust",
+            80.0,
+        )
+        .await
+        .expect("enqueue");
+        approval_queue::update_status(pool, id, "approved")
+            .await
+            .expect("approve");
+        id
+    }
+
+    #[tokio::test]
+    async fn posts_approved_comments_within_daily_limit() {
+        let pool = init_test_db().await.expect("init db");
+        storage::rate_limits::init_reddit_rate_limit(&pool, 1)
+            .await
+            .expect("init rate limit");
+        let client = MockReddit::default();
+
+        let first = approved_comment(&pool, "t3_a").await;
+        let second = approved_comment(&pool, "t3_b").await;
+        // An approved X reply is left for the X poster.
+        let x_id = approval_queue::enqueue(&pool, "reply", "123", "", "Hi", "", "", 0.0, "[]")
+            .await
+            .expect("enqueue x");
+        approval_queue::update_status(&pool, x_id, "approved")
+            .await
+            .expect("approve x");
+
+        assert_eq!(poll_once(&pool, &client).await, PollOutcome::Posted);
+        assert_eq!(poll_once(&pool, &client).await, PollOutcome::LimitReached);
+
+        let posted = approval_queue::get_by_id(&pool, first)
+            .await
+            .expect("get")
+            .expect("item");
+        assert_eq!(posted.status, "posted");
+        let held = approval_queue::get_by_id(&pool, second)
+            .await
+            .expect("get")
+            .expect("item");
+        assert_eq!(held.status, "approved");
+        assert_eq!(
+            *client.comments.lock().expect("lock"),
+            vec![("t3_a".to_string(), "Try clap.".to_string())]
+        );
+    }
+}
@@ -0,0 +1,82 @@
+//! Scheduled uploads to the S3-compatible remote backup target.
+//!
+//! The first upload is due `remote_backup.interval_hours` after the newest
+//! snapshot already in the bucket, so restarts do not upload a fresh copy
+//! each time. A failed upload is retried after an hour at most.
+
+use std::time::Duration;
+
+use chrono::{NaiveDateTime, Utc};
+use tokio_util::sync::CancellationToken;
+
+use crate::config::RemoteBackupConfig;
+use crate::storage::remote_backup::{self, S3Client};
+use crate::storage::DbPool;
+
+/// Longest wait before retrying a failed upload.
+const RETRY_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// Upload a snapshot every `config.interval_hours` until cancelled.
+pub async fn run_remote_backup_loop(
+    pool: DbPool,
+    config: RemoteBackupConfig,
+    cancel: CancellationToken,
+) {
+    tracing::info!(
+        bucket = %config.bucket,
+        interval_hours = config.interval_hours,
+        "Remote backup scheduler started"
+    );
+
+    let interval = Duration::from_secs(config.interval_hours * 60 * 60);
+    let mut wait = initial_wait(&config, interval).await;
+
+    loop {
+        tokio::select! {
+            biased;
+            () = cancel.cancelled() => break,
+            () = tokio::time::sleep(wait) => {}
+        }
+        wait = match remote_backup::upload_backup(&pool, &config).await {
+            Ok(result) => {
+                tracing::info!(
+                    uri = %result.uri,
+                    size_bytes = result.size_bytes,
+                    sha256 = %result.sha256,
+                    pruned = result.pruned,
+                    "Remote backup uploaded"
+                );
+                interval
+            }
+            Err(e) => {
+                tracing::warn!(error = %e, "Remote backup failed");
+                interval.min(RETRY_INTERVAL)
+            }
+        };
+    }
+    tracing::info!("Remote backup scheduler stopped");
+}
+
+/// Time until the next upload is due, based on the newest remote snapshot.
+async fn initial_wait(config: &RemoteBackupConfig, interval: Duration) -> Duration {
+    let client = S3Client::new(config);
+    let newest = match remote_backup::list_remote_backups(&client, config).await {
+        Ok(snapshots) => snapshots.into_iter().next(),
+        Err(e) => {
+            tracing::warn!(error = %e, "Could not list remote backups");
+            None
+        }
+    };
+    let taken_at = newest.and_then(|s| {
+        let name = s.key.rsplit('/').next()?.to_string();
+        let stamp = name.strip_prefix("tuitbot_")?.strip_suffix(".db.gz")?;
+        NaiveDateTime::parse_from_str(stamp, "%Y%m%d_%H%M%S").ok()
+    });
+    let Some(taken_at) = taken_at else {
+        return Duration::ZERO;
+    };
+    let age = (Utc::now().naive_utc() - taken_at)
+        .to_std()
+        .unwrap_or_default();
+    interval.saturating_sub(age)
+}
@@ -0,0 +1,301 @@
+//! Safe-mode startup after a crash or a posting failure streak.
+//!
+//! Each `tuitbot run` session is recorded in `agent_sessions`. At startup
+//! the previous session is checked: if it never shut down cleanly, or its
+//! last `safe_mode.failure_threshold` posts all failed, the agent starts in
+//! safe mode. Safe mode engages the kill switch (source `safe_mode`), so
+//! every mutation path is refused, and mutating loops wait to start until
+//! it is lifted with `tuitbot resume` or `POST /api/admin/resume`.
+
+use std::fmt;
+use std::future::Future;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use tokio_util::sync::CancellationToken;
+
+use crate::config::SafeModeConfig;
+use crate::error::StorageError;
+use crate::storage::agent_sessions::{self, AgentSession};
+use crate::storage::kill_switch::{self, KillSwitchState};
+use crate::storage::{action_log, DbPool};
+
+/// Kill switch source used while in safe mode.
+pub const SAFE_MODE_SOURCE: &str = "safe_mode";
+
+/// How often a running session refreshes its heartbeat.
+const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(60);
+
+/// An open session whose heartbeat is older than this has crashed; a
+/// fresher one belongs to another process that is still running.
+const STALE_AFTER_SECS: i64 = 180;
+
+/// How often waiting loops check whether safe mode was lifted.
+const RESUME_POLL_INTERVAL: Duration = Duration::from_secs(15);
+
+/// Why the agent started in safe mode.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SafeModeTrigger {
+    /// The previous session stopped without shutting down.
+    Crash { last_heartbeat: String },
+    /// The previous session's last posts all failed.
+    PostingFailures {
+        count: i64,
+        last_error: Option<String>,
+    },
+}
+
+impl fmt::Display for SafeModeTrigger {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Crash { last_heartbeat } => write!(
+                f,
+                "previous session crashed (last heartbeat {last_heartbeat})"
+            ),
+            Self::PostingFailures { count, last_error } => {
+                write!(
+                    f,
+                    "previous session ended after {count} consecutive posting failures"
+                )?;
+                match last_error {
+                    Some(error) => write!(f, " (last: {error})"),
+                    None => Ok(()),
+                }
+            }
+        }
+    }
+}
+
+/// Decide whether `previous` should put the next session in safe mode.
+pub fn detect_trigger(
+    previous: &AgentSession,
+    config: &SafeModeConfig,
+    now: DateTime<Utc>,
+) -> Option<SafeModeTrigger> {
+    if previous.ended_at.is_none() {
+        let stale = DateTime::parse_from_rfc3339(&previous.heartbeat_at)
+            .map(|t| (now - t.with_timezone(&Utc)).num_seconds() > STALE_AFTER_SECS)
+            .unwrap_or(true);
+        if stale {
+            return Some(SafeModeTrigger::Crash {
+                last_heartbeat: previous.heartbeat_at.clone(),
+            });
+        }
+    }
+    if previous.consecutive_failures >= i64::from(config.failure_threshold) {
+        return Some(SafeModeTrigger::PostingFailures {
+            count: previous.consecutive_failures,
+            last_error: previous.last_error.clone(),
+        });
+    }
+    None
+}
+
+/// The running process's session. Cheap to clone.
+#[derive(Clone)]
+pub struct SessionTracker {
+    pool: DbPool,
+    id: i64,
+}
+
+impl SessionTracker {
+    /// Open a session for this process and check the previous one. Enters
+    /// safe mode when it calls for it and `config.enabled` is set; returns
+    /// the trigger in that case.
+    pub async fn start(
+        pool: DbPool,
+        config: &SafeModeConfig,
+    ) -> Result<(Self, Option<SafeModeTrigger>), StorageError> {
+        let id = agent_sessions::start_session(&pool, std::process::id()).await?;
+        let previous = agent_sessions::get_previous_session(&pool, id).await?;
+        let trigger = match previous {
+            Some(previous) if config.enabled => detect_trigger(&previous, config, Utc::now()),
+            _ => None,
+        };
+        if let Some(trigger) = &trigger {
+            enter_safe_mode(&pool, trigger).await?;
+        }
+        Ok((Self { pool, id }, trigger))
+    }
+
+    /// Refresh the heartbeat until cancelled, then close the session.
+    pub async fn run_heartbeat(self, cancel: CancellationToken) {
+        loop {
+            tokio::select! {
+                biased;
+                () = cancel.cancelled() => break,
+                () = tokio::time::sleep(HEARTBEAT_INTERVAL) => {}
+            }
+            if let Err(e) = agent_sessions::heartbeat(&self.pool, self.id).await {
+                tracing::warn!(error = %e, "Failed to refresh session heartbeat");
+            }
+        }
+        if let Err(e) = agent_sessions::end_session(&self.pool, self.id).await {
+            tracing::warn!(error = %e, "Failed to close agent session");
+        }
+    }
+}
+
+/// Engage the kill switch for `trigger`, unless it is already engaged.
+async fn enter_safe_mode(pool: &DbPool, trigger: &SafeModeTrigger) -> Result<(), StorageError> {
+    if kill_switch::is_kill_switch_active(pool).await? {
+        tracing::warn!(%trigger, "Kill switch already engaged; not entering safe mode");
+        return Ok(());
+    }
+    kill_switch::engage_kill_switch(pool, &trigger.to_string(), SAFE_MODE_SOURCE).await?;
+    action_log::log_action(
+        pool,
+        "safe_mode",
+        "entered",
+        Some(&format!("Started in safe mode: {trigger}")),
+        None,
+    )
+    .await
+}
+
+/// Why the agent is in safe mode, or `None` when it is not.
+pub async fn safe_mode_reason(pool: &DbPool) -> Result<Option<String>, StorageError> {
+    let state = kill_switch::get_kill_switch(pool).await?;
+    Ok(is_safe_mode(&state).then(|| state.reason.unwrap_or_default()))
+}
+
+/// Whether the kill switch `state` was engaged by safe mode.
+pub fn is_safe_mode(state: &KillSwitchState) -> bool {
+    state.active && state.source.as_deref() == Some(SAFE_MODE_SOURCE)
+}
+
+/// Lift safe mode. Returns `None` when not in safe mode; a kill switch
+/// engaged by hand is left alone.
+pub async fn resume(pool: &DbPool, source: &str) -> Result<Option<KillSwitchState>, StorageError> {
+    if safe_mode_reason(pool).await?.is_none() {
+        return Ok(None);
+    }
+    let state = kill_switch::release_kill_switch(pool, source).await?;
+    action_log::log_action(
+        pool,
+        "safe_mode",
+        "resumed",
+        Some(&format!("Safe mode lifted via {source}")),
+        None,
+    )
+    .await?;
+    Ok(Some(state))
+}
+
+/// Run `task` once safe mode is lifted (right away when not in safe mode).
+/// Nothing runs if `cancel` fires first.
+pub async fn run_after_resume<F>(
+    pool: DbPool,
+    name: &'static str,
+    task: F,
+    cancel: CancellationToken,
+) where
+    F: Future<Output = ()>,
+{
+    let mut waiting = false;
+    loop {
+        match safe_mode_reason(&pool).await {
+            Ok(None) => break,
+            Ok(Some(_)) if !waiting => {
+                tracing::info!(task = name, "Safe mode: waiting for `tuitbot resume`");
+                waiting = true;
+            }
+            Ok(Some(_)) => {}
+            Err(e) => tracing::warn!(error = %e, "Failed to read safe mode state"),
+        }
+        tokio::select! {
+            biased;
+            () = cancel.cancelled() => return,
+            () = tokio::time::sleep(RESUME_POLL_INTERVAL) => {}
+        }
+    }
+    if waiting {
+        tracing::info!(task = name, "Safe mode lifted, starting");
+    }
+    task.await;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::init_test_db;
+
+    fn session(ended: bool, heartbeat_at: &str, failures: i64) -> AgentSession {
+        AgentSession {
+            id: 1,
+            pid: 7,
+            started_at: "2026-03-01T10:00:00Z".to_string(),
+            heartbeat_at: heartbeat_at.to_string(),
+            ended_at: ended.then(|| "2026-03-01T12:00:00Z".to_string()),
+            consecutive_failures: failures,
+            last_error: (failures > 0).then(|| "403 Forbidden".to_string()),
+        }
+    }
+
+    #[test]
+    fn detects_crash_and_failure_streak() {
+        let config = SafeModeConfig::default();
+        let now: DateTime<Utc> = "2026-03-01T12:10:00Z".parse().unwrap();
+
+        assert_eq!(
+            detect_trigger(&session(false, "2026-03-01T12:00:00Z", 0), &config, now),
+            Some(SafeModeTrigger::Crash {
+                last_heartbeat: "2026-03-01T12:00:00Z".to_string()
+            })
+        );
+        // A fresh heartbeat is another process that is still running.
+        assert_eq!(
+            detect_trigger(&session(false, "2026-03-01T12:09:30Z", 0), &config, now),
+            None
+        );
+        assert_eq!(
+            detect_trigger(&session(true, "2026-03-01T12:00:00Z", 4), &config, now),
+            None
+        );
+        let trigger = detect_trigger(&session(true, "2026-03-01T12:00:00Z", 5), &config, now)
+            .expect("trigger");
+        assert_eq!(
+            trigger.to_string(),
+            "previous session ended after 5 consecutive posting failures (last: 403 Forbidden)"
+        );
+    }
+
+    #[tokio::test]
+    async fn crash_starts_in_safe_mode_until_resumed() {
+        let pool = init_test_db().await.expect("init db");
+        let config = SafeModeConfig::default();
+
+        let (first, trigger) = SessionTracker::start(pool.clone(), &config)
+            .await
+            .expect("start");
+        assert!(trigger.is_none());
+        // The first session dies without closing; age its heartbeat.
+        sqlx::query("UPDATE agent_sessions SET heartbeat_at = '2026-01-01T00:00:00Z' WHERE id = ?")
+            .bind(first.id)
+            .execute(&pool)
+            .await
+            .expect("age");
+
+        let (_second, trigger) = SessionTracker::start(pool.clone(), &config)
+            .await
+            .expect("start");
+        assert!(matches!(trigger, Some(SafeModeTrigger::Crash { .. })));
+        let reason = safe_mode_reason(&pool).await.expect("reason").expect("on");
+        assert!(reason.contains("crashed"));
+        assert!(kill_switch::is_kill_switch_active(&pool).await.unwrap());
+
+        let state = resume(&pool, "cli").await.expect("resume").expect("state");
+        assert!(!state.active);
+        assert!(resume(&pool, "cli").await.expect("resume").is_none());
+    }
+
+    #[tokio::test]
+    async fn resume_leaves_manual_kill_switch_alone() {
+        let pool = init_test_db().await.expect("init db");
+        kill_switch::engage_kill_switch(&pool, "legal hold", "cli")
+            .await
+            .expect("engage");
+        assert!(resume(&pool, "api").await.expect("resume").is_none());
+        assert!(kill_switch::is_kill_switch_active(&pool).await.unwrap());
+    }
+}
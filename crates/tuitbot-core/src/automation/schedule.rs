@@ -4,7 +4,7 @@
 //! loops behind a configurable active window. Supports IANA timezones
 //! with automatic DST handling via `chrono-tz`.
 
-use chrono::{DateTime, Datelike, NaiveTime, Timelike, Utc};
+use chrono::{DateTime, Datelike, NaiveDate, NaiveTime, Timelike, Utc};
 use chrono_tz::Tz;
 use rand::Rng;
 use std::collections::HashMap;
@@ -69,6 +69,28 @@ pub fn apply_slot_jitter(wait: Duration) -> Duration {
     Duration::from_secs(wait_secs.max(0) as u64)
 }
 
+/// Where a moment falls within the day's active window.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WindowPosition {
+    /// Local date the window belongs to (the day it starts on).
+    pub day: NaiveDate,
+    /// Fraction of the window already elapsed (0.0-1.0).
+    pub progress: f64,
+    /// Total window length in seconds.
+    pub window_secs: u64,
+}
+
+impl WindowPosition {
+    /// Position within the UTC calendar day, used when no schedule is configured.
+    pub fn utc_day(now: DateTime<Utc>) -> Self {
+        Self {
+            day: now.date_naive(),
+            progress: now.num_seconds_from_midnight() as f64 / 86_400.0,
+            window_secs: 86_400,
+        }
+    }
+}
+
 /// Parsed active schedule with timezone, hours, weekday filtering, and preferred posting times.
 #[derive(Debug, Clone)]
 pub struct ActiveSchedule {
@@ -258,7 +280,12 @@ impl ActiveSchedule {
     ///
     /// Handles wrapping ranges (e.g. start=22, end=6 for night owls).
     pub fn is_active(&self) -> bool {
-        let now = Utc::now().with_timezone(&self.tz);
+        self.is_active_at(Utc::now())
+    }
+
+    /// Check if `now` falls within the active posting window.
+    pub fn is_active_at(&self, now: DateTime<Utc>) -> bool {
+        let now = now.with_timezone(&self.tz);
         let hour = now.hour() as u8;
         let weekday = now.weekday();
 
@@ -277,6 +304,58 @@ impl ActiveSchedule {
         }
     }
 
+    /// Locate `now` within the active window it belongs to.
+    ///
+    /// Wrapping windows (e.g. 22-06) belong to the day they start on, so the
+    /// early-morning hours count toward the previous day's window. Equal start
+    /// and end hours are treated as an all-day window.
+    pub fn window_position(&self, now: DateTime<Utc>) -> WindowPosition {
+        let local = now.with_timezone(&self.tz);
+        let secs_of_day = local.num_seconds_from_midnight() as i64;
+        let start = self.start_hour as i64 * 3600;
+        let end = self.end_hour as i64 * 3600;
+
+        let window_secs = match self.start_hour.cmp(&self.end_hour) {
+            std::cmp::Ordering::Less => end - start,
+            std::cmp::Ordering::Greater => 86_400 - start + end,
+            std::cmp::Ordering::Equal => 86_400,
+        };
+
+        let (day, elapsed) = if self.start_hour > self.end_hour && secs_of_day < end {
+            let yesterday = local.date_naive().pred_opt().unwrap_or(local.date_naive());
+            (yesterday, 86_400 - start + secs_of_day)
+        } else if self.start_hour == self.end_hour {
+            (local.date_naive(), secs_of_day)
+        } else {
+            (local.date_naive(), secs_of_day - start)
+        };
+
+        WindowPosition {
+            day,
+            progress: (elapsed as f64 / window_secs as f64).clamp(0.0, 1.0),
+            window_secs: window_secs as u64,
+        }
+    }
+
+    /// Time between `from` and `to` that falls inside the active window.
+    ///
+    /// Only the last 90 days before `to` are counted.
+    pub fn active_time_between(&self, from: DateTime<Utc>, to: DateTime<Utc>) -> Duration {
+        let mut t = from.max(to - chrono::Duration::days(90));
+        let mut active_secs = 0;
+        while t < to {
+            // Activity only changes on local hour boundaries.
+            let local = t.with_timezone(&self.tz);
+            let into_hour = i64::from(local.minute() * 60 + local.second());
+            let next = (t + chrono::Duration::seconds(3600 - into_hour)).min(to);
+            if self.is_active_at(t) {
+                active_secs += (next - t).num_seconds();
+            }
+            t = next;
+        }
+        Duration::from_secs(active_secs.max(0) as u64)
+    }
+
     /// Compute the duration until the next active window starts.
     ///
     /// Returns `Duration::ZERO` if currently active.
@@ -447,6 +526,71 @@ mod tests {
         assert_eq!(schedule.end_hour, 6);
     }
 
+    fn utc(s: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(s).unwrap().with_timezone(&Utc)
+    }
+
+    #[test]
+    fn active_time_between_counts_only_window_hours() {
+        let schedule = ActiveSchedule::from_config(&default_schedule_config()).unwrap();
+
+        // 20:30 to 09:15 next day: 1.5h before close, 1.25h after open.
+        let active =
+            schedule.active_time_between(utc("2026-03-02T20:30:00Z"), utc("2026-03-03T09:15:00Z"));
+        assert_eq!(active, Duration::from_secs(165 * 60));
+        let active =
+            schedule.active_time_between(utc("2026-03-02T23:00:00Z"), utc("2026-03-02T22:00:00Z"));
+        assert_eq!(active, Duration::ZERO);
+    }
+
+    #[test]
+    fn window_position_normal_range() {
+        let schedule = ActiveSchedule::from_config(&default_schedule_config()).unwrap();
+
+        let pos = schedule.window_position(utc("2026-03-02T15:00:00Z"));
+        assert_eq!(pos.day, NaiveDate::from_ymd_opt(2026, 3, 2).unwrap());
+        assert_eq!(pos.window_secs, 14 * 3600);
+        assert!((pos.progress - 0.5).abs() < 1e-9);
+
+        let before = schedule.window_position(utc("2026-03-02T06:00:00Z"));
+        assert_eq!(before.progress, 0.0);
+        let after = schedule.window_position(utc("2026-03-02T23:00:00Z"));
+        assert_eq!(after.progress, 1.0);
+    }
+
+    #[test]
+    fn window_position_wrapping_range_belongs_to_start_day() {
+        let mut config = default_schedule_config();
+        config.active_hours_start = 22;
+        config.active_hours_end = 6;
+        let schedule = ActiveSchedule::from_config(&config).unwrap();
+
+        let pos = schedule.window_position(utc("2026-03-03T02:00:00Z"));
+        assert_eq!(pos.day, NaiveDate::from_ymd_opt(2026, 3, 2).unwrap());
+        assert_eq!(pos.window_secs, 8 * 3600);
+        assert!((pos.progress - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn window_position_respects_timezone() {
+        let mut config = default_schedule_config();
+        config.timezone = "America/New_York".to_string();
+        let schedule = ActiveSchedule::from_config(&config).unwrap();
+
+        // 03:30 UTC on Mar 3 is 22:30 on Mar 2 in New York (EST).
+        let pos = schedule.window_position(utc("2026-03-03T03:30:00Z"));
+        assert_eq!(pos.day, NaiveDate::from_ymd_opt(2026, 3, 2).unwrap());
+        assert_eq!(pos.progress, 1.0);
+    }
+
+    #[test]
+    fn window_position_utc_day_without_schedule() {
+        let pos = WindowPosition::utc_day(utc("2026-03-02T06:00:00Z"));
+        assert_eq!(pos.day, NaiveDate::from_ymd_opt(2026, 3, 2).unwrap());
+        assert_eq!(pos.window_secs, 86_400);
+        assert!((pos.progress - 0.25).abs() < 1e-9);
+    }
+
     #[test]
     fn time_until_active_when_active_is_zero() {
         // Create schedule with 0-23 range (almost always active)
@@ -4,7 +4,9 @@
 //! human-readable summary. Provides users with a heartbeat showing
 //! what the agent has been doing without requiring verbose logging.
 
+use super::pacing::PaceStatus;
 use super::scheduler::LoopScheduler;
+use super::warmup::WarmupPhase;
 use chrono::{DateTime, Utc};
 use tokio_util::sync::CancellationToken;
 
@@ -53,6 +55,26 @@ pub trait StatusQuerier: Send + Sync {
     /// Query aggregated action counts since the given timestamp.
     async fn query_action_counts_since(&self, since: DateTime<Utc>)
         -> Result<ActionCounts, String>;
+
+    /// Current request pace per paced endpoint. Empty when pacing is off.
+    async fn query_pace_state(&self) -> Result<Vec<PaceStatus>, String> {
+        Ok(Vec::new())
+    }
+
+    /// Current effective score threshold. `None` when calibration is off.
+    async fn query_effective_threshold(&self) -> Result<Option<u32>, String> {
+        Ok(None)
+    }
+
+    /// Current account warm-up phase. `None` when warm-up is off or complete.
+    async fn query_warmup_phase(&self) -> Result<Option<WarmupPhase>, String> {
+        Ok(None)
+    }
+
+    /// Why posting is halted in safe mode. `None` when not in safe mode.
+    async fn query_safe_mode(&self) -> Result<Option<String>, String> {
+        Ok(None)
+    }
 }
 
 /// Run the periodic status reporter loop.
@@ -95,6 +117,49 @@ pub async fn run_status_reporter(
             }
         }
 
+        match querier.query_pace_state().await {
+            Ok(statuses) if !statuses.is_empty() => {
+                let lines: Vec<String> = statuses.iter().map(|s| s.format_line()).collect();
+                tracing::info!("Request pace: {}", lines.join(", "));
+            }
+            Ok(_) => {}
+            Err(e) => {
+                tracing::warn!(error = %e, "Failed to query pace state for status report");
+            }
+        }
+
+        match querier.query_effective_threshold().await {
+            Ok(Some(threshold)) => {
+                tracing::info!("Effective score threshold: {threshold}");
+            }
+            Ok(None) => {}
+            Err(e) => {
+                tracing::warn!(error = %e, "Failed to query effective threshold for status report");
+            }
+        }
+
+        match querier.query_warmup_phase().await {
+            Ok(Some(phase)) => {
+                tracing::info!("Account warm-up: {}", phase.format_line());
+            }
+            Ok(None) => {}
+            Err(e) => {
+                tracing::warn!(error = %e, "Failed to query warm-up phase for status report");
+            }
+        }
+
+        match querier.query_safe_mode().await {
+            Ok(Some(reason)) => {
+                tracing::warn!(
+                    "Safe mode: posting disabled ({reason}). Run `tuitbot resume` to re-enable."
+                );
+            }
+            Ok(None) => {}
+            Err(e) => {
+                tracing::warn!(error = %e, "Failed to query safe mode for status report");
+            }
+        }
+
         last_report = now;
     }
 
@@ -203,8 +268,10 @@ mod tests {
 
     #[test]
     fn has_activity_true_for_any_field() {
-        let mut counts = ActionCounts::default();
-        counts.tweets_scored = 1;
+        let counts = ActionCounts {
+            tweets_scored: 1,
+            ..ActionCounts::default()
+        };
         assert!(counts.has_activity());
     }
 
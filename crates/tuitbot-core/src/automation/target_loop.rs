@@ -5,12 +5,26 @@
 //! generates relationship-based replies. This loop operates independently
 //! from keyword-based discovery to enable genuine engagement with specific
 //! people.
+//!
+//! Each target belongs to a tier whose cadence caps interactions per rolling
+//! week, limits the allowed action types, and can force human approval.
+//! Timelines are watched incrementally: the newest tweet ID seen per target
+//! is stored, and each pass fetches only tweets newer than it. With a
+//! [`RequestPacer`] attached, timeline reads follow the daily pace curve.
 
 use super::loop_helpers::{
-    ConsecutiveErrorTracker, LoopError, LoopTweet, PostSender, ReplyGenerator, SafetyChecker,
+    send_reply_to, soft_flag_waiver, ConsecutiveErrorTracker, LoopError, LoopTweet, PostSender,
+    ReplyGenerator, SafetyChecker,
 };
+use super::pacing::{PaceDecision, PacedEndpoint, RequestPacer};
 use super::schedule::{schedule_gate, ActiveSchedule};
 use super::scheduler::LoopScheduler;
+use crate::config::{TargetTier, TargetTiersConfig};
+use crate::safety::trusted::TRUSTED_BYPASS_ACTION;
+use crate::safety::{ContextVerdict, OverlapVerdict};
+use crate::storage::reply_explanations::ReplyExplanation;
+use crate::workflow::draft_workspace::DraftFlag;
+use crate::x_api::is_newer_tweet_id;
 use std::sync::Arc;
 use std::time::Duration;
 use tokio_util::sync::CancellationToken;
@@ -22,8 +36,12 @@ use tokio_util::sync::CancellationToken;
 /// Fetches tweets from a specific user by user ID.
 #[async_trait::async_trait]
 pub trait TargetTweetFetcher: Send + Sync {
-    /// Fetch recent tweets from the given user.
-    async fn fetch_user_tweets(&self, user_id: &str) -> Result<Vec<LoopTweet>, LoopError>;
+    /// Fetch recent tweets from the given user, newer than `since_id` when given.
+    async fn fetch_user_tweets(
+        &self,
+        user_id: &str,
+        since_id: Option<&str>,
+    ) -> Result<Vec<LoopTweet>, LoopError>;
 }
 
 /// Looks up a user by username.
@@ -68,6 +86,37 @@ pub trait TargetStorage: Send + Sync {
     /// Get count of target replies sent today.
     async fn count_target_replies_today(&self) -> Result<i64, LoopError>;
 
+    /// Get the tier of a target account (general when unknown).
+    async fn get_target_tier(&self, account_id: &str) -> Result<TargetTier, LoopError>;
+
+    /// Count interactions with one target account over the last 7 days.
+    async fn count_target_interactions_this_week(&self, account_id: &str)
+        -> Result<i64, LoopError>;
+
+    /// Record a reply sent or queued (per `status`) in the target's reply history.
+    async fn record_target_interaction(
+        &self,
+        account_id: &str,
+        tweet_id: &str,
+        status: &str,
+    ) -> Result<(), LoopError>;
+
+    /// Get the newest tweet ID seen for a target account.
+    async fn get_target_since_id(&self, account_id: &str) -> Result<Option<String>, LoopError>;
+
+    /// Store the newest tweet ID seen for a target account.
+    async fn set_target_since_id(&self, account_id: &str, since_id: &str) -> Result<(), LoopError>;
+
+    /// Queue a target reply for human approval, with any QA flags raised
+    /// while drafting it.
+    async fn queue_target_reply(
+        &self,
+        tweet_id: &str,
+        username: &str,
+        content: &str,
+        qa_flags: &[DraftFlag],
+    ) -> Result<(), LoopError>;
+
     /// Log an action.
     async fn log_action(
         &self,
@@ -88,6 +137,8 @@ pub struct TargetLoopConfig {
     pub accounts: Vec<String>,
     /// Maximum target replies per day.
     pub max_target_replies_per_day: u32,
+    /// Per-tier engagement cadence.
+    pub tiers: TargetTiersConfig,
     /// Whether this is a dry run.
     pub dry_run: bool,
 }
@@ -105,6 +156,12 @@ pub enum TargetResult {
         account: String,
         reply_text: String,
     },
+    /// Reply was queued for approval because the target's tier requires it.
+    Queued {
+        tweet_id: String,
+        account: String,
+        reply_text: String,
+    },
     /// Tweet was skipped.
     Skipped { tweet_id: String, reason: String },
     /// Processing failed.
@@ -124,6 +181,7 @@ pub struct TargetLoop {
     storage: Arc<dyn TargetStorage>,
     poster: Arc<dyn PostSender>,
     config: TargetLoopConfig,
+    pacer: Option<Arc<dyn RequestPacer>>,
 }
 
 impl TargetLoop {
@@ -146,9 +204,16 @@ impl TargetLoop {
             storage,
             poster,
             config,
+            pacer: None,
         }
     }
 
+    /// Pace timeline reads against the daily user-tweets budget.
+    pub fn with_pacer(mut self, pacer: Arc<dyn RequestPacer>) -> Self {
+        self.pacer = Some(pacer);
+        self
+    }
+
     /// Run the continuous target monitoring loop until cancellation.
     pub async fn run(
         &self,
@@ -187,6 +252,10 @@ impl TargetLoop {
                         .iter()
                         .filter(|r| matches!(r, TargetResult::Replied { .. }))
                         .count();
+                    let queued = results
+                        .iter()
+                        .filter(|r| matches!(r, TargetResult::Queued { .. }))
+                        .count();
                     let skipped = results
                         .iter()
                         .filter(|r| matches!(r, TargetResult::Skipped { .. }))
@@ -195,6 +264,7 @@ impl TargetLoop {
                         tracing::info!(
                             total = results.len(),
                             replied = replied,
+                            queued = queued,
                             skipped = skipped,
                             "Target iteration complete"
                         );
@@ -255,12 +325,26 @@ impl TargetLoop {
                 break;
             }
 
+            if let Some(pacer) = &self.pacer {
+                match pacer.check(PacedEndpoint::UserTweets).await {
+                    PaceDecision::Proceed => {}
+                    PaceDecision::Defer { wait } => {
+                        tracing::debug!(
+                            wait_secs = wait.as_secs(),
+                            "Timeline reads ahead of daily pace, deferring remaining targets"
+                        );
+                        break;
+                    }
+                    PaceDecision::Exhausted => {
+                        tracing::info!("Daily timeline read budget spent, skipping targets");
+                        break;
+                    }
+                }
+            }
+
             match self.process_account(username, remaining_replies).await {
                 Ok(results) => {
-                    let replied_count = results
-                        .iter()
-                        .filter(|r| matches!(r, TargetResult::Replied { .. }))
-                        .count();
+                    let replied_count = results.iter().filter(|r| r.is_engagement()).count();
                     remaining_replies = remaining_replies.saturating_sub(replied_count);
                     all_results.extend(results);
                 }
@@ -287,7 +371,7 @@ impl TargetLoop {
         Ok(all_results)
     }
 
-    /// Process a single target account: resolve, fetch tweets, reply.
+    /// Process a single target account: resolve, check tier cadence, fetch tweets, reply.
     async fn process_account(
         &self,
         username: &str,
@@ -301,8 +385,45 @@ impl TargetLoop {
             .upsert_target_account(&user_id, &resolved_username)
             .await?;
 
-        // Fetch recent tweets
-        let tweets = self.fetcher.fetch_user_tweets(&user_id).await?;
+        // Enforce the tier cadence before spending an API call on the timeline.
+        let tier = self.storage.get_target_tier(&user_id).await?;
+        let cadence = self.config.tiers.cadence(tier);
+        if !cadence.allows("reply") {
+            tracing::debug!(
+                username = %resolved_username,
+                tier = %tier,
+                "Replies not allowed for target tier"
+            );
+            return Ok(Vec::new());
+        }
+        let this_week = self
+            .storage
+            .count_target_interactions_this_week(&user_id)
+            .await?;
+        if this_week >= cadence.max_interactions_per_week as i64 {
+            tracing::debug!(
+                username = %resolved_username,
+                tier = %tier,
+                this_week = this_week,
+                limit = cadence.max_interactions_per_week,
+                "Weekly target cadence reached"
+            );
+            return Ok(Vec::new());
+        }
+
+        // Fetch only tweets newer than the last pass
+        let since_id = self.storage.get_target_since_id(&user_id).await?;
+        let fetched = self
+            .fetcher
+            .fetch_user_tweets(&user_id, since_id.as_deref())
+            .await;
+        if let Some(pacer) = &self.pacer {
+            pacer.record(PacedEndpoint::UserTweets).await;
+        }
+        let tweets = fetched?;
+        if let Some(newest) = newest_tweet_id(&tweets, since_id.as_deref()) {
+            self.storage.set_target_since_id(&user_id, newest).await?;
+        }
         tracing::info!(
             username = %resolved_username,
             count = tweets.len(),
@@ -315,9 +436,14 @@ impl TargetLoop {
 
         for tweet in tweets.iter().take(max_replies) {
             let result = self
-                .process_target_tweet(tweet, &user_id, &resolved_username)
+                .process_target_tweet(
+                    tweet,
+                    &user_id,
+                    &resolved_username,
+                    cadence.require_approval,
+                )
                 .await;
-            if matches!(result, TargetResult::Replied { .. }) {
+            if result.is_engagement() {
                 results.push(result);
                 // Only reply to one tweet per account per iteration
                 break;
@@ -328,12 +454,14 @@ impl TargetLoop {
         Ok(results)
     }
 
-    /// Process a single target tweet: dedup, safety check, generate reply, post.
+    /// Process a single target tweet: dedup, safety check, generate reply, post
+    /// (or queue for approval when the tier requires it).
     async fn process_target_tweet(
         &self,
         tweet: &LoopTweet,
         account_id: &str,
         username: &str,
+        require_approval: bool,
     ) -> TargetResult {
         // Check if already seen
         match self.storage.target_tweet_exists(&tweet.id).await {
@@ -364,6 +492,13 @@ impl TargetLoop {
             .await;
 
         // Safety checks
+        if let Err(denial) = self.safety.check_protected_author(tweet) {
+            return TargetResult::Skipped {
+                tweet_id: tweet.id.clone(),
+                reason: denial.to_string(),
+            };
+        }
+
         if self.safety.has_replied_to(&tweet.id).await {
             return TargetResult::Skipped {
                 tweet_id: tweet.id.clone(),
@@ -378,13 +513,34 @@ impl TargetLoop {
             };
         }
 
+        // Controversy signals skip the tweet or force approval
+        let require_approval = match self.safety.check_context(tweet).await {
+            ContextVerdict::Clear => require_approval,
+            ContextVerdict::Skip(denial) => {
+                let reason = denial.to_string();
+                let _ = self
+                    .storage
+                    .log_action(
+                        "target_reply",
+                        "skipped",
+                        &format!("Skipped @{username}: {reason}"),
+                    )
+                    .await;
+                return TargetResult::Skipped {
+                    tweet_id: tweet.id.clone(),
+                    reason,
+                };
+            }
+            ContextVerdict::RequireApproval(_) => true,
+        };
+
         // Generate reply (no product mention for target accounts — be genuine)
-        let reply_text = match self
+        let generated = match self
             .generator
-            .generate_reply(&tweet.text, username, false)
+            .generate_reply_explained(&tweet.text, username, false)
             .await
         {
-            Ok(text) => text,
+            Ok(generated) => generated,
             Err(e) => {
                 return TargetResult::Failed {
                     tweet_id: tweet.id.clone(),
@@ -392,6 +548,83 @@ impl TargetLoop {
                 };
             }
         };
+        let reply_text = generated.text;
+        let require_approval = require_approval || generated.require_approval;
+
+        // Replies repeating the author's own recent tweets are skipped or
+        // flagged for review
+        let mut qa_flags = Vec::new();
+        let require_approval = match self.safety.check_author_overlap(tweet, &reply_text).await {
+            OverlapVerdict::Clear => require_approval,
+            OverlapVerdict::Soft(flag) => {
+                match soft_flag_waiver(self.safety.as_ref(), tweet, &flag) {
+                    Some(waiver) => {
+                        let _ = self
+                            .storage
+                            .log_action(TRUSTED_BYPASS_ACTION, "soft_qa", &waiver)
+                            .await;
+                        require_approval
+                    }
+                    None => {
+                        qa_flags.push(flag);
+                        true
+                    }
+                }
+            }
+            OverlapVerdict::Hard(denial) => {
+                let reason = denial.to_string();
+                let _ = self
+                    .storage
+                    .log_action(
+                        "target_reply",
+                        "skipped",
+                        &format!("Skipped @{username}: {reason}"),
+                    )
+                    .await;
+                return TargetResult::Skipped {
+                    tweet_id: tweet.id.clone(),
+                    reason,
+                };
+            }
+        };
+
+        if require_approval && !self.config.dry_run {
+            if let Err(e) = self
+                .storage
+                .queue_target_reply(&tweet.id, username, &reply_text, &qa_flags)
+                .await
+            {
+                return TargetResult::Failed {
+                    tweet_id: tweet.id.clone(),
+                    error: e.to_string(),
+                };
+            }
+
+            // Queued replies count toward the cadence right away so the
+            // loop does not keep drafting for the same account.
+            let _ = self
+                .storage
+                .record_target_interaction(account_id, &tweet.id, "queued")
+                .await;
+
+            let _ = self
+                .storage
+                .log_action(
+                    "target_reply",
+                    "queued",
+                    &format!(
+                        "Queued reply to @{username} for approval: {}",
+                        truncate(&reply_text, 50)
+                    ),
+                )
+                .await;
+
+            return TargetResult::Queued {
+                tweet_id: tweet.id.clone(),
+                account: username.to_string(),
+                reply_text,
+            };
+        }
 
         tracing::info!(
             username = %username,
@@ -416,20 +649,42 @@ impl TargetLoop {
                 )
                 .await;
         } else {
-            if let Err(e) = self.poster.send_reply(&tweet.id, &reply_text).await {
+            if let Err(e) = send_reply_to(
+                self.poster.as_ref(),
+                self.safety.as_ref(),
+                tweet,
+                &reply_text,
+            )
+            .await
+            {
                 return TargetResult::Failed {
                     tweet_id: tweet.id.clone(),
                     error: e.to_string(),
                 };
             }
 
-            if let Err(e) = self.safety.record_reply(&tweet.id, &reply_text).await {
+            let explanation = ReplyExplanation {
+                source: "target".to_string(),
+                archetype: generated.archetype,
+                ancestors: generated.ancestors,
+                topic_override: generated.topic_override,
+                ..ReplyExplanation::default()
+            };
+            if let Err(e) = self
+                .safety
+                .record_reply_explained(&tweet.id, &reply_text, explanation)
+                .await
+            {
                 tracing::warn!(tweet_id = %tweet.id, error = %e, "Failed to record reply");
             }
 
             // Mark tweet as replied and update account stats
             let _ = self.storage.mark_target_tweet_replied(&tweet.id).await;
             let _ = self.storage.record_target_reply(account_id).await;
+            let _ = self
+                .storage
+                .record_target_interaction(account_id, &tweet.id, "sent")
+                .await;
 
             let _ = self
                 .storage
@@ -449,6 +704,25 @@ impl TargetLoop {
     }
 }
 
+impl TargetResult {
+    /// Whether this result used up an interaction (replied or queued).
+    pub fn is_engagement(&self) -> bool {
+        matches!(self, Self::Replied { .. } | Self::Queued { .. })
+    }
+}
+
+/// Newest tweet ID in `tweets` if it is newer than `since_id`.
+fn newest_tweet_id<'a>(tweets: &'a [LoopTweet], since_id: Option<&str>) -> Option<&'a str> {
+    tweets
+        .iter()
+        .map(|t| t.id.as_str())
+        .filter(|id| match since_id {
+            Some(since) => is_newer_tweet_id(id, since),
+            None => true,
+        })
+        .max_by(|a, b| (a.len(), a).cmp(&(b.len(), b)))
+}
+
 /// Truncate a string for display.
 fn truncate(s: &str, max_len: usize) -> String {
     if s.len() <= max_len {
@@ -461,6 +735,7 @@ fn truncate(s: &str, max_len: usize) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::safety::DenialReason;
     use std::sync::atomic::{AtomicU32, Ordering};
     use std::sync::Mutex;
 
@@ -472,8 +747,20 @@ mod tests {
 
     #[async_trait::async_trait]
     impl TargetTweetFetcher for MockFetcher {
-        async fn fetch_user_tweets(&self, _user_id: &str) -> Result<Vec<LoopTweet>, LoopError> {
-            Ok(self.tweets.clone())
+        async fn fetch_user_tweets(
+            &self,
+            _user_id: &str,
+            since_id: Option<&str>,
+        ) -> Result<Vec<LoopTweet>, LoopError> {
+            Ok(self
+                .tweets
+                .iter()
+                .filter(|t| match since_id {
+                    Some(since) => is_newer_tweet_id(&t.id, since),
+                    None => true,
+                })
+                .cloned()
+                .collect())
         }
     }
 
@@ -525,6 +812,9 @@ mod tests {
 
     #[async_trait::async_trait]
     impl SafetyChecker for MockSafety {
+        fn check_protected_author(&self, _tweet: &LoopTweet) -> Result<(), DenialReason> {
+            Ok(())
+        }
         async fn can_reply(&self) -> bool {
             self.can_reply
         }
@@ -546,13 +836,27 @@ mod tests {
     struct MockTargetStorage {
         existing_tweets: Mutex<Vec<String>>,
         replies_today: Mutex<i64>,
+        tier: TargetTier,
+        this_week: Mutex<i64>,
+        queued: Mutex<Vec<(String, String)>>,
+        history: Mutex<Vec<(String, String)>>,
+        since_id: Mutex<Option<String>>,
     }
 
     impl MockTargetStorage {
         fn new() -> Self {
+            Self::with_tier(TargetTier::General)
+        }
+
+        fn with_tier(tier: TargetTier) -> Self {
             Self {
                 existing_tweets: Mutex::new(Vec::new()),
                 replies_today: Mutex::new(0),
+                tier,
+                this_week: Mutex::new(0),
+                queued: Mutex::new(Vec::new()),
+                history: Mutex::new(Vec::new()),
+                since_id: Mutex::new(None),
             }
         }
     }
@@ -595,6 +899,54 @@ mod tests {
         async fn count_target_replies_today(&self) -> Result<i64, LoopError> {
             Ok(*self.replies_today.lock().expect("lock"))
         }
+        async fn get_target_tier(&self, _account_id: &str) -> Result<TargetTier, LoopError> {
+            Ok(self.tier)
+        }
+        async fn count_target_interactions_this_week(
+            &self,
+            _account_id: &str,
+        ) -> Result<i64, LoopError> {
+            Ok(*self.this_week.lock().expect("lock"))
+        }
+        async fn record_target_interaction(
+            &self,
+            _account_id: &str,
+            tweet_id: &str,
+            status: &str,
+        ) -> Result<(), LoopError> {
+            self.history
+                .lock()
+                .expect("lock")
+                .push((tweet_id.to_string(), status.to_string()));
+            Ok(())
+        }
+        async fn get_target_since_id(
+            &self,
+            _account_id: &str,
+        ) -> Result<Option<String>, LoopError> {
+            Ok(self.since_id.lock().expect("lock").clone())
+        }
+        async fn set_target_since_id(
+            &self,
+            _account_id: &str,
+            since_id: &str,
+        ) -> Result<(), LoopError> {
+            *self.since_id.lock().expect("lock") = Some(since_id.to_string());
+            Ok(())
+        }
+        async fn queue_target_reply(
+            &self,
+            tweet_id: &str,
+            username: &str,
+            _content: &str,
+            _qa_flags: &[DraftFlag],
+        ) -> Result<(), LoopError> {
+            self.queued
+                .lock()
+                .expect("lock")
+                .push((tweet_id.to_string(), username.to_string()));
+            Ok(())
+        }
         async fn log_action(
             &self,
             _action_type: &str,
@@ -629,6 +981,10 @@ mod tests {
                 .push((tweet_id.to_string(), content.to_string()));
             Ok(())
         }
+
+        async fn send_quote(&self, _tweet_id: &str, _content: &str) -> Result<(), LoopError> {
+            Ok(())
+        }
     }
 
     fn test_tweet(id: &str, author: &str) -> LoopTweet {
@@ -642,6 +998,9 @@ mod tests {
             likes: 10,
             retweets: 2,
             replies: 1,
+            quotes: 0,
+            urls: vec![],
+            has_media: false,
         }
     }
 
@@ -649,6 +1008,7 @@ mod tests {
         TargetLoopConfig {
             accounts: vec!["alice".to_string()],
             max_target_replies_per_day: 3,
+            tiers: TargetTiersConfig::default(),
             dry_run: false,
         }
     }
@@ -749,6 +1109,106 @@ mod tests {
         assert_eq!(poster.sent_count(), 0);
     }
 
+    #[tokio::test]
+    async fn weekly_cadence_skips_account() {
+        let tweets = vec![test_tweet("tw1", "alice")];
+        let storage = Arc::new(MockTargetStorage::new());
+        // General tier allows 2 interactions per week by default.
+        *storage.this_week.lock().expect("lock") = 2;
+        let (target_loop, poster) = build_loop(tweets, default_config(), storage);
+
+        let results = target_loop.run_iteration().await.expect("iteration");
+        assert!(results.is_empty());
+        assert_eq!(poster.sent_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn tier_without_reply_action_is_skipped() {
+        let tweets = vec![test_tweet("tw1", "alice")];
+        let storage = Arc::new(MockTargetStorage::new());
+        let mut config = default_config();
+        config.tiers.general.allowed_actions = vec!["like".to_string()];
+        let (target_loop, poster) = build_loop(tweets, config, storage);
+
+        let results = target_loop.run_iteration().await.expect("iteration");
+        assert!(results.is_empty());
+        assert_eq!(poster.sent_count(), 0);
+    }
+
+    struct ExhaustedPacer;
+
+    #[async_trait::async_trait]
+    impl RequestPacer for ExhaustedPacer {
+        async fn check(&self, _endpoint: PacedEndpoint) -> PaceDecision {
+            PaceDecision::Exhausted
+        }
+
+        async fn record(&self, _endpoint: PacedEndpoint) {
+            panic!("no timeline read expected");
+        }
+    }
+
+    #[tokio::test]
+    async fn exhausted_pace_skips_timeline_reads() {
+        let tweets = vec![test_tweet("tw1", "alice")];
+        let storage = Arc::new(MockTargetStorage::new());
+        let (target_loop, poster) = build_loop(tweets, default_config(), storage);
+        let target_loop = target_loop.with_pacer(Arc::new(ExhaustedPacer));
+
+        let results = target_loop.run_iteration().await.expect("iteration");
+        assert!(results.is_empty());
+        assert_eq!(poster.sent_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn dream_100_tier_queues_for_approval() {
+        let tweets = vec![test_tweet("tw1", "alice")];
+        let storage = Arc::new(MockTargetStorage::with_tier(TargetTier::Dream100));
+        let (target_loop, poster) = build_loop(tweets, default_config(), storage.clone());
+
+        let results = target_loop.run_iteration().await.expect("iteration");
+        assert_eq!(results.len(), 1);
+        assert!(matches!(results[0], TargetResult::Queued { .. }));
+        assert_eq!(poster.sent_count(), 0);
+        assert_eq!(
+            *storage.queued.lock().expect("lock"),
+            vec![("tw1".to_string(), "alice".to_string())]
+        );
+    }
+
+    #[tokio::test]
+    async fn since_id_limits_fetch_to_new_tweets() {
+        let tweets = vec![test_tweet("100", "alice"), test_tweet("105", "alice")];
+        let storage = Arc::new(MockTargetStorage::new());
+        let (target_loop, poster) = build_loop(tweets, default_config(), storage.clone());
+
+        let results = target_loop.run_iteration().await.expect("iteration");
+        assert_eq!(results.len(), 1);
+        assert_eq!(poster.sent_count(), 1);
+        assert_eq!(
+            storage.since_id.lock().expect("lock").as_deref(),
+            Some("105")
+        );
+        assert_eq!(
+            *storage.history.lock().expect("lock"),
+            vec![("100".to_string(), "sent".to_string())]
+        );
+
+        // Second pass: nothing newer than 105, so nothing is fetched.
+        let results = target_loop.run_iteration().await.expect("iteration");
+        assert!(results.is_empty());
+        assert_eq!(poster.sent_count(), 1);
+    }
+
+    #[test]
+    fn newest_tweet_id_respects_since_id() {
+        let tweets = vec![test_tweet("99", "a"), test_tweet("1000", "a")];
+        assert_eq!(newest_tweet_id(&tweets, None), Some("1000"));
+        assert_eq!(newest_tweet_id(&tweets, Some("999")), Some("1000"));
+        assert_eq!(newest_tweet_id(&tweets, Some("1000")), None);
+        assert_eq!(newest_tweet_id(&[], None), None);
+    }
+
     #[test]
     fn truncate_short_string() {
         assert_eq!(truncate("hello", 10), "hello");
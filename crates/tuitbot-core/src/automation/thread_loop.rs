@@ -7,6 +7,8 @@
 use super::loop_helpers::{ContentLoopError, ContentSafety, ContentStorage, ThreadPoster};
 use super::schedule::{apply_slot_jitter, schedule_gate, ActiveSchedule};
 use super::scheduler::LoopScheduler;
+use crate::config::ThreadFormatConfig;
+use crate::content::thread_format::{format_thread, render_segment};
 use rand::seq::SliceRandom;
 use rand::SeedableRng;
 use std::sync::Arc;
@@ -22,6 +24,7 @@ pub struct ThreadLoop {
     topics: Vec<String>,
     thread_interval_secs: u64,
     dry_run: bool,
+    format: ThreadFormatConfig,
 }
 
 /// Trait for generating multi-tweet threads.
@@ -89,9 +92,16 @@ impl ThreadLoop {
             topics,
             thread_interval_secs,
             dry_run,
+            format: ThreadFormatConfig::default(),
         }
     }
 
+    /// Set the numbering, CTA, and re-quote decorations applied before posting.
+    pub fn with_format(mut self, format: ThreadFormatConfig) -> Self {
+        self.format = format;
+        self
+    }
+
     /// Run the continuous thread loop until cancellation.
     pub async fn run(
         &self,
@@ -440,7 +450,18 @@ impl ThreadLoop {
                     <= crate::content::length::MAX_TWEET_CHARS
             });
             if all_valid {
-                return Ok(tweets);
+                // Decorations can push a tweet over the limit; regenerate if so.
+                match format_thread(&self.format, &tweets) {
+                    Ok(segments) => return Ok(segments),
+                    Err(e) => {
+                        tracing::debug!(
+                            attempt = attempt + 1,
+                            error = %e,
+                            "Formatted thread tweet exceeds limit, retrying"
+                        );
+                        continue;
+                    }
+                }
             }
 
             let over_limit: Vec<usize> = tweets
@@ -480,6 +501,10 @@ impl ThreadLoop {
         let mut root_tweet_id: Option<String> = None;
 
         for (i, tweet_content) in tweets.iter().enumerate() {
+            let tweet_content = &match &root_tweet_id {
+                Some(root) => render_segment(tweet_content, root),
+                None => tweet_content.clone(),
+            };
             let post_result = if i == 0 {
                 // First tweet: standalone
                 self.poster.post_tweet(tweet_content).await
@@ -696,7 +721,12 @@ mod tests {
             Ok(Vec::new())
         }
 
-        async fn post_tweet(&self, _topic: &str, _content: &str) -> Result<(), ContentLoopError> {
+        async fn post_tweet(
+            &self,
+            _topic: &str,
+            _content: &str,
+            _inspiration: &str,
+        ) -> Result<(), ContentLoopError> {
             Ok(())
         }
 
@@ -0,0 +1,144 @@
+//! Trend selection for trend-inspired original tweets.
+//!
+//! Discovery clusters recent tweets under the keyword that surfaced them.
+//! A cluster is usable as inspiration when its keyword or sample tweets
+//! share words with one of the account's content pillars; the largest such
+//! cluster wins and its best-matching pillar becomes the tweet topic.
+
+use crate::safety::dedup::tokenize;
+use crate::storage::keyword_hits::KeywordCluster;
+
+/// A trending conversation chosen as inspiration for an original tweet.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrendTopic {
+    /// Content pillar the trend aligns with; used as the tweet topic.
+    pub pillar: String,
+    /// Keyword the conversation clustered under.
+    pub keyword: String,
+    /// Text of the most-engaged tweets in the conversation.
+    pub samples: Vec<String>,
+}
+
+/// Longest sample tweet quoted into the prompt.
+const MAX_SAMPLE_CHARS: usize = 200;
+
+impl TrendTopic {
+    /// Prompt section describing the live conversation the tweet should
+    /// reference.
+    pub fn prompt_context(&self) -> String {
+        let mut context = format!(
+            "Live conversation today about \"{}\". Add your own take on it as an \
+             original post; do not reply to, quote, or name anyone in it:",
+            self.keyword
+        );
+        for sample in &self.samples {
+            let sample: String = sample.chars().take(MAX_SAMPLE_CHARS).collect();
+            context.push_str(&format!("\n- {}", sample.replace('\n', " ")));
+        }
+        context
+    }
+}
+
+/// Words long enough to tell topics apart.
+fn topic_words(text: &str) -> Vec<String> {
+    tokenize(text)
+        .into_iter()
+        .filter(|w| w.chars().count() > 2 && !w.starts_with("http"))
+        .collect()
+}
+
+/// Pick the first cluster aligned with a pillar, pairing it with the pillar
+/// that shares the most words with it. Clusters are expected largest first.
+pub fn select_trend(clusters: &[KeywordCluster], pillars: &[String]) -> Option<TrendTopic> {
+    clusters.iter().find_map(|cluster| {
+        let mut text = cluster.keyword.clone();
+        for sample in &cluster.samples {
+            text.push(' ');
+            text.push_str(sample);
+        }
+        let words = tokenize(&text);
+
+        let (matches, pillar) = pillars
+            .iter()
+            .map(|pillar| {
+                let matches = topic_words(pillar)
+                    .iter()
+                    .filter(|w| words.contains(*w))
+                    .count();
+                (matches, pillar)
+            })
+            .filter(|(matches, _)| *matches > 0)
+            .max_by_key(|(matches, _)| *matches)?;
+
+        tracing::debug!(
+            keyword = %cluster.keyword,
+            pillar = %pillar,
+            matches,
+            "Trending cluster aligned with content pillar"
+        );
+        Some(TrendTopic {
+            pillar: pillar.clone(),
+            keyword: cluster.keyword.clone(),
+            samples: cluster.samples.clone(),
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cluster(keyword: &str, samples: &[&str]) -> KeywordCluster {
+        KeywordCluster {
+            keyword: keyword.to_string(),
+            tweet_count: 5,
+            engagement: 100,
+            samples: samples.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn picks_largest_aligned_cluster() {
+        let pillars = vec![
+            "Developer productivity".to_string(),
+            "Rust tooling".to_string(),
+        ];
+        let clusters = vec![
+            cluster("crypto", &["Token prices are wild today"]),
+            cluster(
+                "cargo",
+                &[
+                    "New cargo release makes rust builds faster",
+                    "Tooling matters",
+                ],
+            ),
+            cluster("productivity", &["Deep work beats meetings"]),
+        ];
+
+        let trend = select_trend(&clusters, &pillars).expect("aligned cluster");
+        assert_eq!(trend.keyword, "cargo");
+        assert_eq!(trend.pillar, "Rust tooling");
+        assert_eq!(trend.samples.len(), 2);
+    }
+
+    #[test]
+    fn prompt_context_lists_samples() {
+        let trend = TrendTopic {
+            pillar: "Rust tooling".to_string(),
+            keyword: "cargo".to_string(),
+            samples: vec!["Cargo got faster\nagain".to_string()],
+        };
+        let context = trend.prompt_context();
+        assert!(context.contains("\"cargo\""));
+        assert!(context.ends_with("\n- Cargo got faster again"));
+    }
+
+    #[test]
+    fn no_alignment_means_no_trend() {
+        let pillars = vec!["Rust tooling".to_string()];
+        let clusters = vec![cluster("crypto", &["Token prices are wild today"])];
+        assert!(select_trend(&clusters, &pillars).is_none());
+        assert!(select_trend(&clusters, &[]).is_none());
+        assert!(select_trend(&[], &pillars).is_none());
+    }
+}
@@ -0,0 +1,287 @@
+//! X usage reconciliation.
+//!
+//! Periodically fetches the project's post usage from X (`GET /2/usage/tweets`)
+//! and compares it with the posts the local `x_api_usage` ledger accounts for
+//! over the same complete UTC days. Every run is stored with its drift.
+//!
+//! X counts every post a read returns, while the ledger counts one post per
+//! read call, so the ledger usually undercounts. When it does, the pacing
+//! budgets are scaled by `ledger / official` (never below
+//! `min_pacing_scale`) until the next run.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::{NaiveDate, Utc};
+use tokio_util::sync::CancellationToken;
+
+use crate::config::UsageReconciliationConfig;
+use crate::error::StorageError;
+use crate::storage::usage_reconciliation::{self, NewUsageReconciliation};
+use crate::storage::{x_api_usage, DbPool};
+use crate::x_api::types::TweetUsage;
+use crate::x_api::XApiClient;
+
+/// Pacing budget scale for the given official and ledger counts.
+///
+/// `1.0` unless the ledger undercounts; otherwise `ledger / official`,
+/// clamped to `min_scale..=1.0`.
+pub fn pacing_scale(official: u64, ledger: u64, min_scale: f64) -> f64 {
+    if official == 0 || official <= ledger {
+        return 1.0;
+    }
+    (ledger as f64 / official as f64).clamp(min_scale.min(1.0), 1.0)
+}
+
+/// Posts X reports for the days in `start..end` (`YYYY-MM-DD`).
+fn official_posts(usage: &TweetUsage, start: &str, end: &str) -> u64 {
+    usage
+        .daily_project_usage
+        .iter()
+        .flat_map(|d| d.usage.iter())
+        .filter(|d| {
+            let day = d.date.get(..10).unwrap_or(&d.date);
+            day >= start && day < end
+        })
+        .map(|d| d.usage)
+        .sum()
+}
+
+/// Reconcile the last `config.lookback_days` complete UTC days before
+/// `today` for a specific account and store the result.
+///
+/// Returns `None` when X's usage could not be fetched (for example on a
+/// tier without access to the usage endpoint); nothing is stored then.
+pub async fn reconcile_usage_for(
+    pool: &DbPool,
+    account_id: &str,
+    client: &dyn XApiClient,
+    config: &UsageReconciliationConfig,
+    today: NaiveDate,
+) -> Result<Option<NewUsageReconciliation>, StorageError> {
+    let days = config.lookback_days.max(1);
+    let usage = match client.get_tweet_usage(days + 1).await {
+        Ok(usage) => usage,
+        Err(e) => {
+            tracing::warn!(error = %e, "Could not fetch X usage; skipping reconciliation");
+            return Ok(None);
+        }
+    };
+
+    let start = (today - chrono::Duration::days(i64::from(days))).to_string();
+    let end = today.to_string();
+    let official = official_posts(&usage, &start, &end);
+    let ledger = x_api_usage::count_post_reads_between_for(pool, account_id, &start, &end).await?;
+    let row = NewUsageReconciliation {
+        window_start: start,
+        window_end: end,
+        official_usage: official,
+        ledger_usage: ledger,
+        pacing_scale: pacing_scale(official, ledger, config.min_pacing_scale),
+        project_usage: Some(usage.project_usage),
+        project_cap: (usage.project_cap > 0).then_some(usage.project_cap),
+    };
+    usage_reconciliation::insert_reconciliation_for(pool, account_id, &row).await?;
+    Ok(Some(row))
+}
+
+/// Reconcile now and then every `config.interval_minutes`.
+pub async fn run_usage_reconciliation_loop(
+    pool: DbPool,
+    account_id: String,
+    x_client: Arc<dyn XApiClient>,
+    config: UsageReconciliationConfig,
+    cancel: CancellationToken,
+) {
+    tracing::info!(
+        interval_minutes = config.interval_minutes,
+        lookback_days = config.lookback_days,
+        "Usage reconciliation started"
+    );
+
+    let interval = Duration::from_secs(config.interval_minutes * 60);
+    loop {
+        let today = Utc::now().date_naive();
+        match reconcile_usage_for(&pool, &account_id, &*x_client, &config, today).await {
+            Ok(Some(row)) => {
+                if row.pacing_scale < 1.0 {
+                    tracing::warn!(
+                        official = row.official_usage,
+                        ledger = row.ledger_usage,
+                        pacing_scale = row.pacing_scale,
+                        "Ledger undercounts X usage; scaling pacing budgets down"
+                    );
+                } else {
+                    tracing::info!(
+                        official = row.official_usage,
+                        ledger = row.ledger_usage,
+                        "X usage reconciled"
+                    );
+                }
+            }
+            Ok(None) => {}
+            Err(e) => tracing::warn!(error = %e, "Failed to store usage reconciliation"),
+        }
+
+        tokio::select! {
+            biased;
+            () = cancel.cancelled() => break,
+            () = tokio::time::sleep(interval) => {}
+        }
+    }
+
+    tracing::info!("Usage reconciliation stopped");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::XApiError;
+    use crate::storage::accounts::DEFAULT_ACCOUNT_ID;
+    use crate::storage::init_test_db;
+    use crate::x_api::types::*;
+
+    /// Reports fixed daily usage, or fails when `usage` is `None`.
+    struct UsageMock {
+        usage: Option<TweetUsage>,
+    }
+
+    #[async_trait::async_trait]
+    impl XApiClient for UsageMock {
+        async fn search_tweets(
+            &self,
+            _: &str,
+            _: u32,
+            _: Option<&str>,
+            _: Option<&str>,
+        ) -> Result<SearchResponse, XApiError> {
+            unimplemented!()
+        }
+        async fn get_mentions(
+            &self,
+            _: &str,
+            _: Option<&str>,
+            _: Option<&str>,
+        ) -> Result<MentionResponse, XApiError> {
+            unimplemented!()
+        }
+        async fn post_tweet(&self, _: &str) -> Result<PostedTweet, XApiError> {
+            unimplemented!()
+        }
+        async fn reply_to_tweet(&self, _: &str, _: &str) -> Result<PostedTweet, XApiError> {
+            unimplemented!()
+        }
+        async fn get_tweet(&self, _: &str) -> Result<Tweet, XApiError> {
+            unimplemented!()
+        }
+        async fn get_me(&self) -> Result<User, XApiError> {
+            unimplemented!()
+        }
+        async fn get_user_tweets(
+            &self,
+            _: &str,
+            _: u32,
+            _: Option<&str>,
+        ) -> Result<SearchResponse, XApiError> {
+            unimplemented!()
+        }
+        async fn get_user_by_username(&self, _: &str) -> Result<User, XApiError> {
+            unimplemented!()
+        }
+        async fn get_tweet_usage(&self, _: u32) -> Result<TweetUsage, XApiError> {
+            self.usage.clone().ok_or(XApiError::ApiError {
+                status: 403,
+                message: "client-forbidden".to_string(),
+            })
+        }
+    }
+
+    fn daily(date: &str, usage: u64) -> DailyUsage {
+        DailyUsage {
+            date: format!("{date}T00:00:00.000Z"),
+            usage,
+        }
+    }
+
+    #[test]
+    fn scale_only_drops_when_ledger_undercounts() {
+        assert_eq!(pacing_scale(0, 0, 0.5), 1.0);
+        assert_eq!(pacing_scale(100, 120, 0.5), 1.0);
+        assert_eq!(pacing_scale(100, 80, 0.5), 0.8);
+        assert_eq!(pacing_scale(1000, 10, 0.5), 0.5);
+    }
+
+    #[tokio::test]
+    async fn reconcile_stores_drift_and_scale() {
+        let pool = init_test_db().await.expect("init db");
+        let today = Utc::now().date_naive();
+        let yesterday = today.pred_opt().unwrap();
+        for _ in 0..3 {
+            sqlx::query(
+                "INSERT INTO x_api_usage (endpoint, method, status_code, cost_usd, created_at) \
+                 VALUES ('/tweets/search/recent', 'GET', 200, 0.005, ?)",
+            )
+            .bind(format!("{yesterday}T12:00:00Z"))
+            .execute(&pool)
+            .await
+            .unwrap();
+        }
+
+        let client = UsageMock {
+            usage: Some(TweetUsage {
+                project_usage: 5_000,
+                project_cap: 10_000,
+                cap_reset_day: Some(1),
+                daily_project_usage: Some(DailyProjectUsage {
+                    usage: vec![
+                        daily(&yesterday.to_string(), 4),
+                        // Today is incomplete and excluded.
+                        daily(&today.to_string(), 50),
+                    ],
+                }),
+            }),
+        };
+        let config = UsageReconciliationConfig {
+            enabled: true,
+            ..Default::default()
+        };
+        let row = reconcile_usage_for(&pool, DEFAULT_ACCOUNT_ID, &client, &config, today)
+            .await
+            .unwrap()
+            .expect("reconciled");
+        assert_eq!(row.official_usage, 4);
+        assert_eq!(row.ledger_usage, 3);
+        assert_eq!(row.pacing_scale, 0.75);
+        assert_eq!(row.window_end, today.to_string());
+
+        let stored = usage_reconciliation::list_recent(&pool, 1).await.unwrap();
+        assert_eq!(stored[0].drift, 1);
+        assert_eq!(stored[0].project_cap, Some(10_000));
+        assert_eq!(
+            usage_reconciliation::current_pacing_scale(&pool)
+                .await
+                .unwrap(),
+            0.75
+        );
+    }
+
+    #[tokio::test]
+    async fn unavailable_usage_endpoint_stores_nothing() {
+        let pool = init_test_db().await.expect("init db");
+        let client = UsageMock { usage: None };
+        let result = reconcile_usage_for(
+            &pool,
+            DEFAULT_ACCOUNT_ID,
+            &client,
+            &UsageReconciliationConfig::default(),
+            Utc::now().date_naive(),
+        )
+        .await
+        .unwrap();
+        assert!(result.is_none());
+        assert!(usage_reconciliation::list_recent(&pool, 10)
+            .await
+            .unwrap()
+            .is_empty());
+    }
+}
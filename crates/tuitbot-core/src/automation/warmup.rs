@@ -0,0 +1,352 @@
+//! Account warm-up: gradually increasing caps for new or idle accounts.
+//!
+//! While a warm-up is in progress, the daily and weekly caps from
+//! `[limits]` are scaled down by phase and written to the rate limit rows.
+//! Progress lives in storage so restarts pick up where they left off. A
+//! pause in posting longer than `warmup.inactive_days` starts the schedule
+//! over.
+
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use tokio_util::sync::CancellationToken;
+
+use super::content_mix;
+use crate::config::{Config, LimitsConfig, WarmupConfig};
+use crate::error::StorageError;
+use crate::storage::{rate_limits, warmup, DbPool};
+
+/// How often the running agent re-applies the schedule.
+const APPLY_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// Caps in effect during a warm-up phase.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct WarmupCaps {
+    pub replies_per_day: u32,
+    pub tweets_per_day: u32,
+    pub threads_per_week: u32,
+    pub likes_per_day: u32,
+    pub follows_per_day: u32,
+}
+
+impl WarmupCaps {
+    /// `percent` of each cap in `limits`, rounded up. Non-zero caps stay at
+    /// least 1.
+    pub fn scaled(limits: &LimitsConfig, percent: u32) -> Self {
+        let scale = |cap: u32| {
+            if cap == 0 {
+                0
+            } else {
+                (cap * percent).div_ceil(100).max(1)
+            }
+        };
+        Self {
+            replies_per_day: scale(limits.max_replies_per_day),
+            tweets_per_day: scale(limits.max_tweets_per_day),
+            threads_per_week: scale(limits.max_threads_per_week),
+            likes_per_day: scale(limits.max_likes_per_day),
+            follows_per_day: scale(limits.max_follows_per_day),
+        }
+    }
+
+    /// `limits` with these caps in place of the configured ones.
+    pub fn apply_to(&self, limits: &LimitsConfig) -> LimitsConfig {
+        LimitsConfig {
+            max_replies_per_day: self.replies_per_day,
+            max_tweets_per_day: self.tweets_per_day,
+            max_threads_per_week: self.threads_per_week,
+            max_likes_per_day: self.likes_per_day,
+            max_follows_per_day: self.follows_per_day,
+            ..limits.clone()
+        }
+    }
+
+    /// Format the caps as a single line.
+    pub fn format_line(&self) -> String {
+        format!(
+            "{} replies/day, {} tweets/day, {} threads/week, {} likes/day, {} follows/day",
+            self.replies_per_day,
+            self.tweets_per_day,
+            self.threads_per_week,
+            self.likes_per_day,
+            self.follows_per_day,
+        )
+    }
+}
+
+/// Where an account is in its warm-up.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct WarmupPhase {
+    /// Current week, starting at 1.
+    pub week: u32,
+    pub total_weeks: u32,
+    /// Percent of the configured caps currently allowed.
+    pub percent: u32,
+    /// When the warm-up started; `None` if it starts on the next run.
+    pub started_at: Option<String>,
+    pub caps: WarmupCaps,
+}
+
+impl WarmupPhase {
+    /// Format the phase and its caps as a single line.
+    pub fn format_line(&self) -> String {
+        format!(
+            "week {} of {} ({}% of limits): {}",
+            self.week,
+            self.total_weeks,
+            self.percent,
+            self.caps.format_line()
+        )
+    }
+}
+
+/// Week (starting at 0) and percent of caps `elapsed` into a warm-up, or
+/// `None` once it is over.
+pub fn phase_percent(config: &WarmupConfig, elapsed: chrono::Duration) -> Option<(u32, u32)> {
+    let week = u32::try_from(elapsed.num_days().max(0) / 7).unwrap_or(u32::MAX);
+    if week >= config.weeks {
+        return None;
+    }
+    let start = config.start_percent.min(100);
+    Some((week, start + (100 - start) * week / config.weeks))
+}
+
+fn phase_at(
+    config: &Config,
+    started_at: Option<String>,
+    now: DateTime<Utc>,
+) -> Option<WarmupPhase> {
+    let start = started_at
+        .as_deref()
+        .and_then(|s| s.parse::<DateTime<Utc>>().ok())
+        .unwrap_or(now);
+    let (week, percent) = phase_percent(&config.warmup, now - start)?;
+    Some(WarmupPhase {
+        week: week + 1,
+        total_weeks: config.warmup.weeks,
+        percent,
+        started_at,
+        caps: WarmupCaps::scaled(&config.limits, percent),
+    })
+}
+
+/// Current warm-up phase for a specific account, without changing anything.
+///
+/// Returns `None` when warm-up is disabled or complete.
+pub async fn warmup_status_for(
+    pool: &DbPool,
+    account_id: &str,
+    config: &Config,
+) -> Result<Option<WarmupPhase>, StorageError> {
+    if !config.warmup.enabled {
+        return Ok(None);
+    }
+    match warmup::get_state_for(pool, account_id).await? {
+        Some(state) if state.completed_at.is_some() => Ok(None),
+        Some(state) => Ok(phase_at(config, Some(state.started_at), Utc::now())),
+        None => Ok(phase_at(config, None, Utc::now())),
+    }
+}
+
+/// Start, restart, or advance the warm-up for a specific account and return
+/// the limits in effect with the current phase.
+///
+/// When warm-up is disabled or complete, the configured limits are returned
+/// unchanged with no phase.
+pub async fn advance_warmup_for(
+    pool: &DbPool,
+    account_id: &str,
+    config: &Config,
+) -> Result<(LimitsConfig, Option<WarmupPhase>), StorageError> {
+    if !config.warmup.enabled {
+        return Ok((config.limits.clone(), None));
+    }
+    let now = Utc::now();
+
+    let state = match warmup::get_state_for(pool, account_id).await? {
+        None => {
+            tracing::info!(account_id, "Account warm-up started");
+            warmup::start_for(pool, account_id).await?
+        }
+        Some(state) if is_idle(pool, account_id, config, &state, now).await? => {
+            tracing::info!(
+                account_id,
+                inactive_days = config.warmup.inactive_days,
+                "Account was inactive; warm-up restarted"
+            );
+            warmup::start_for(pool, account_id).await?
+        }
+        Some(state) => state,
+    };
+    if state.completed_at.is_some() {
+        return Ok((config.limits.clone(), None));
+    }
+
+    match phase_at(config, Some(state.started_at), now) {
+        Some(phase) => Ok((phase.caps.apply_to(&config.limits), Some(phase))),
+        None => {
+            tracing::info!(account_id, "Account warm-up complete");
+            warmup::mark_completed_for(pool, account_id).await?;
+            Ok((config.limits.clone(), None))
+        }
+    }
+}
+
+/// Advance the warm-up for a specific account and write the resulting caps,
+/// further capped by today's content plan, to its rate limit rows. Returns
+/// the phase in effect, if any.
+pub async fn apply_warmup_for(
+    pool: &DbPool,
+    account_id: &str,
+    config: &Config,
+) -> Result<Option<WarmupPhase>, StorageError> {
+    let (limits, phase) = advance_warmup_for(pool, account_id, config).await?;
+    let limits = content_mix::planned_limits_for(pool, account_id, config, limits).await?;
+    rate_limits::reconcile_rate_limits_for(pool, account_id, &limits, &config.intervals).await?;
+    Ok(phase)
+}
+
+/// Whether the account has gone longer than `warmup.inactive_days` without
+/// posting since the later of its last post and the warm-up start.
+async fn is_idle(
+    pool: &DbPool,
+    account_id: &str,
+    config: &Config,
+    state: &warmup::WarmupState,
+    now: DateTime<Utc>,
+) -> Result<bool, StorageError> {
+    if config.warmup.inactive_days == 0 {
+        return Ok(false);
+    }
+    let last_post = warmup::last_post_at_for(pool, account_id).await?;
+    let last_seen = [Some(state.started_at.as_str()), last_post.as_deref()]
+        .into_iter()
+        .flatten()
+        .filter_map(|s| s.parse::<DateTime<Utc>>().ok())
+        .max()
+        .unwrap_or(now);
+    Ok(now - last_seen > chrono::Duration::days(i64::from(config.warmup.inactive_days)))
+}
+
+/// Re-apply the warm-up schedule every hour so caps step up while the agent
+/// runs.
+pub async fn run_warmup_loop(
+    pool: DbPool,
+    account_id: String,
+    config: Config,
+    cancel: CancellationToken,
+) {
+    tracing::info!("Warm-up scheduler started");
+    loop {
+        tokio::select! {
+            biased;
+            () = cancel.cancelled() => break,
+            () = tokio::time::sleep(APPLY_INTERVAL) => {}
+        }
+        match apply_warmup_for(&pool, &account_id, &config).await {
+            Ok(Some(phase)) => tracing::debug!("Warm-up {}", phase.format_line()),
+            Ok(None) => {}
+            Err(e) => tracing::warn!(error = %e, "Failed to apply warm-up schedule"),
+        }
+    }
+    tracing::info!("Warm-up scheduler stopped");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::accounts::DEFAULT_ACCOUNT_ID;
+    use crate::storage::init_test_db;
+
+    fn warmup_config() -> Config {
+        let mut config = Config::default();
+        config.warmup.enabled = true;
+        config.limits.max_replies_per_day = 20;
+        config.limits.max_tweets_per_day = 4;
+        config.limits.max_threads_per_week = 1;
+        config
+    }
+
+    #[test]
+    fn caps_ramp_up_weekly() {
+        let config = WarmupConfig {
+            enabled: true,
+            weeks: 4,
+            start_percent: 25,
+            inactive_days: 14,
+        };
+        let at = |days| phase_percent(&config, chrono::Duration::days(days));
+        assert_eq!(at(0), Some((0, 25)));
+        assert_eq!(at(8), Some((1, 43)));
+        assert_eq!(at(27), Some((3, 81)));
+        assert_eq!(at(28), None);
+
+        let limits = warmup_config().limits;
+        let caps = WarmupCaps::scaled(&limits, 25);
+        assert_eq!(caps.replies_per_day, 5);
+        assert_eq!(caps.tweets_per_day, 1);
+        assert_eq!(caps.threads_per_week, 1);
+    }
+
+    #[tokio::test]
+    async fn apply_writes_phase_caps_and_completes() {
+        let pool = init_test_db().await.expect("init db");
+        let config = warmup_config();
+
+        let phase = apply_warmup_for(&pool, DEFAULT_ACCOUNT_ID, &config)
+            .await
+            .expect("apply")
+            .expect("phase");
+        assert_eq!((phase.week, phase.percent), (1, 25));
+        let reply_max = |limits: Vec<rate_limits::RateLimit>| {
+            limits
+                .into_iter()
+                .find(|l| l.action_type == "reply")
+                .map(|l| l.max_requests)
+        };
+        let limits = rate_limits::get_all_rate_limits(&pool)
+            .await
+            .expect("limits");
+        assert_eq!(reply_max(limits), Some(5));
+
+        // Five weeks in, the warm-up is over and the full caps apply.
+        sqlx::query("UPDATE account_warmup SET started_at = '2020-01-01T00:00:00Z'")
+            .execute(&pool)
+            .await
+            .expect("backdate");
+        let mut config = config;
+        config.warmup.inactive_days = 0;
+        assert!(apply_warmup_for(&pool, DEFAULT_ACCOUNT_ID, &config)
+            .await
+            .expect("apply")
+            .is_none());
+        let limits = rate_limits::get_all_rate_limits(&pool)
+            .await
+            .expect("limits");
+        assert_eq!(reply_max(limits), Some(20));
+        assert!(warmup_status_for(&pool, DEFAULT_ACCOUNT_ID, &config)
+            .await
+            .expect("status")
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn long_inactivity_restarts_warmup() {
+        let pool = init_test_db().await.expect("init db");
+        let config = warmup_config();
+        apply_warmup_for(&pool, DEFAULT_ACCOUNT_ID, &config)
+            .await
+            .expect("apply");
+        sqlx::query("UPDATE account_warmup SET started_at = '2020-01-01T00:00:00Z'")
+            .execute(&pool)
+            .await
+            .expect("backdate");
+
+        let phase = apply_warmup_for(&pool, DEFAULT_ACCOUNT_ID, &config)
+            .await
+            .expect("apply")
+            .expect("restarted");
+        assert_eq!(phase.week, 1);
+    }
+}
@@ -0,0 +1,54 @@
+//! `[approval_sla]`: how long items may wait in the approval queue.
+
+use serde::{Deserialize, Serialize};
+
+use super::types_policy::default_true;
+use crate::error::ConfigError;
+
+/// Review deadline for pending approval items.
+///
+/// An item pending longer than `hours` breaches the SLA: the API server
+/// sends an `approval_sla_breached` notification and raises the item's
+/// priority so it sorts to the top of the queue. Each further `hours`
+/// pending escalates again. With `active_hours_only`, only time inside the
+/// `[schedule]` active window counts.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ApprovalSlaConfig {
+    /// Enable SLA reminders and escalation.
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Hours an item may stay pending before a reminder.
+    #[serde(default = "default_approval_sla_hours")]
+    pub hours: u32,
+
+    /// Count only time inside the active hours window.
+    #[serde(default = "default_true")]
+    pub active_hours_only: bool,
+}
+
+impl Default for ApprovalSlaConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            hours: default_approval_sla_hours(),
+            active_hours_only: true,
+        }
+    }
+}
+
+fn default_approval_sla_hours() -> u32 {
+    4
+}
+
+impl ApprovalSlaConfig {
+    /// Require a positive SLA when enabled.
+    pub(super) fn validate(&self, errors: &mut Vec<ConfigError>) {
+        if self.enabled && self.hours == 0 {
+            errors.push(ConfigError::InvalidValue {
+                field: "approval_sla.hours".to_string(),
+                message: "must be at least 1".to_string(),
+            });
+        }
+    }
+}
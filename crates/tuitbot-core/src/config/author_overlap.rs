@@ -0,0 +1,85 @@
+//! `[author_overlap]`: replies that repeat the author's own recent tweets.
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::ConfigError;
+
+/// Similarity check between a drafted reply and the target author's own
+/// recent tweets, so replies don't repeat a point the author already made.
+///
+/// Overlap is the share of the reply's content words found in a single
+/// author tweet. At `soft_threshold` the reply is queued for approval with a
+/// QA soft flag; at `hard_threshold` the tweet is skipped.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AuthorOverlapConfig {
+    /// Fetch author timelines and run the check.
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Overlap (0.0-1.0) that raises a QA soft flag.
+    #[serde(default = "default_overlap_soft_threshold")]
+    pub soft_threshold: f64,
+
+    /// Overlap (0.0-1.0) at which the tweet is skipped.
+    #[serde(default = "default_overlap_hard_threshold")]
+    pub hard_threshold: f64,
+
+    /// Recent tweets fetched per author (5-100).
+    #[serde(default = "default_overlap_recent_tweets")]
+    pub recent_tweets: u32,
+
+    /// How long a fetched timeline is reused before refetching.
+    #[serde(default = "default_overlap_cache_minutes")]
+    pub cache_minutes: u64,
+}
+
+impl Default for AuthorOverlapConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            soft_threshold: default_overlap_soft_threshold(),
+            hard_threshold: default_overlap_hard_threshold(),
+            recent_tweets: default_overlap_recent_tweets(),
+            cache_minutes: default_overlap_cache_minutes(),
+        }
+    }
+}
+
+fn default_overlap_soft_threshold() -> f64 {
+    0.5
+}
+
+fn default_overlap_hard_threshold() -> f64 {
+    0.75
+}
+
+fn default_overlap_recent_tweets() -> u32 {
+    10
+}
+
+fn default_overlap_cache_minutes() -> u64 {
+    60
+}
+
+impl AuthorOverlapConfig {
+    /// Check the similarity thresholds and sample size when enabled.
+    pub(super) fn validate(&self, errors: &mut Vec<ConfigError>) {
+        if self.enabled {
+            if !(self.soft_threshold > 0.0 && self.soft_threshold <= self.hard_threshold)
+                || self.hard_threshold > 1.0
+            {
+                errors.push(ConfigError::InvalidValue {
+                    field: "author_overlap.soft_threshold".to_string(),
+                    message: "thresholds must satisfy 0 < soft_threshold <= hard_threshold <= 1"
+                        .to_string(),
+                });
+            }
+            if !(5..=100).contains(&self.recent_tweets) {
+                errors.push(ConfigError::InvalidValue {
+                    field: "author_overlap.recent_tweets".to_string(),
+                    message: "must be between 5 and 100".to_string(),
+                });
+            }
+        }
+    }
+}
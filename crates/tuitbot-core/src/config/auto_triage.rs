@@ -0,0 +1,116 @@
+//! `[auto_triage]`: automatic approval of low-risk queue items.
+
+use serde::{Deserialize, Serialize};
+
+use super::types_policy::default_true;
+use crate::error::ConfigError;
+
+/// Automatic approval of queued items that clear strict rules.
+///
+/// A pending item is approved without review only when its action type is
+/// listed, it targets X, its score is at least `min_score`, the QA evaluator
+/// raises no flags, no risks were detected, neither the target account nor
+/// the text is sensitive, and (with `llm_check`) the LLM agrees. Everything
+/// else stays pending. At most `max_per_day` items are approved this way in
+/// any 24 hours.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AutoTriageConfig {
+    /// Enable auto-triage of the approval queue.
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Action types eligible for auto-approval.
+    #[serde(default = "default_auto_triage_action_types")]
+    pub action_types: Vec<String>,
+
+    /// Minimum item score (0-100).
+    #[serde(default = "default_auto_triage_min_score")]
+    pub min_score: f64,
+
+    /// Most items auto-approved in any 24 hours.
+    #[serde(default = "default_auto_triage_max_per_day")]
+    pub max_per_day: u32,
+
+    /// Accounts whose tweets are always left for review (with or without `@`).
+    #[serde(default)]
+    pub sensitive_accounts: Vec<String>,
+
+    /// Words that keep an item pending when they appear in its text or topic.
+    #[serde(default)]
+    pub sensitive_keywords: Vec<String>,
+
+    /// Ask the LLM for a final yes/no before approving.
+    #[serde(default = "default_true")]
+    pub llm_check: bool,
+}
+
+impl AutoTriageConfig {
+    /// Whether `author` is listed in `sensitive_accounts`.
+    pub fn is_sensitive_account(&self, author: &str) -> bool {
+        let author = author.trim_start_matches('@');
+        self.sensitive_accounts
+            .iter()
+            .any(|a| a.trim_start_matches('@').eq_ignore_ascii_case(author))
+    }
+
+    /// The first sensitive keyword found in `text`, if any.
+    pub fn sensitive_keyword_in(&self, text: &str) -> Option<&str> {
+        let text = text.to_lowercase();
+        self.sensitive_keywords
+            .iter()
+            .map(String::as_str)
+            .find(|k| !k.trim().is_empty() && text.contains(&k.to_lowercase()))
+    }
+}
+
+impl Default for AutoTriageConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            action_types: default_auto_triage_action_types(),
+            min_score: default_auto_triage_min_score(),
+            max_per_day: default_auto_triage_max_per_day(),
+            sensitive_accounts: Vec::new(),
+            sensitive_keywords: Vec::new(),
+            llm_check: true,
+        }
+    }
+}
+
+fn default_auto_triage_action_types() -> Vec<String> {
+    vec!["reply".to_string()]
+}
+
+fn default_auto_triage_min_score() -> f64 {
+    85.0
+}
+
+fn default_auto_triage_max_per_day() -> u32 {
+    5
+}
+
+impl AutoTriageConfig {
+    /// Check the triage score, daily cap, and action types when enabled.
+    pub(super) fn validate(&self, errors: &mut Vec<ConfigError>) {
+        if self.enabled {
+            if !(0.0..=100.0).contains(&self.min_score) {
+                errors.push(ConfigError::InvalidValue {
+                    field: "auto_triage.min_score".to_string(),
+                    message: "must be between 0 and 100".to_string(),
+                });
+            }
+            if self.max_per_day == 0 {
+                errors.push(ConfigError::InvalidValue {
+                    field: "auto_triage.max_per_day".to_string(),
+                    message: "must be at least 1".to_string(),
+                });
+            }
+            if self.action_types.is_empty() {
+                errors.push(ConfigError::InvalidValue {
+                    field: "auto_triage.action_types".to_string(),
+                    message: "must list at least one action type".to_string(),
+                });
+            }
+        }
+    }
+}
@@ -0,0 +1,74 @@
+//! `[blocklist]`: authors never engaged with, and protected entities.
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::ConfigError;
+
+/// Authors the agent never replies to or engages with.
+///
+/// Combines the accounts muted and blocked on X, synced periodically, with
+/// usernames listed here.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct BlocklistConfig {
+    /// Sync the account's X mutes and blocks.
+    #[serde(default = "default_blocklist_sync_enabled")]
+    pub sync_enabled: bool,
+
+    /// Seconds between syncs.
+    #[serde(default = "default_blocklist_sync_interval_seconds")]
+    pub sync_interval_seconds: u64,
+
+    /// Usernames to skip in addition to X mutes and blocks.
+    #[serde(default)]
+    pub authors: Vec<String>,
+
+    /// Protected entities (journalists, regulators, competitors'
+    /// executives) the agent never replies to, quotes, or @-mentions, in
+    /// any mode.
+    #[serde(default)]
+    pub protected: Vec<String>,
+}
+
+impl Default for BlocklistConfig {
+    fn default() -> Self {
+        Self {
+            sync_enabled: default_blocklist_sync_enabled(),
+            sync_interval_seconds: default_blocklist_sync_interval_seconds(),
+            authors: Vec::new(),
+            protected: Vec::new(),
+        }
+    }
+}
+
+fn default_blocklist_sync_enabled() -> bool {
+    true
+}
+fn default_blocklist_sync_interval_seconds() -> u64 {
+    21600
+}
+
+impl BlocklistConfig {
+    /// Check the sync interval and that every entry is an X username.
+    pub(super) fn validate(&self, errors: &mut Vec<ConfigError>) {
+        if self.sync_enabled && self.sync_interval_seconds < 900 {
+            errors.push(ConfigError::InvalidValue {
+                field: "blocklist.sync_interval_seconds".to_string(),
+                message: "must be at least 900 (15 minutes)".to_string(),
+            });
+        }
+        for (field, authors) in [
+            ("blocklist.authors", &self.authors),
+            ("blocklist.protected", &self.protected),
+        ] {
+            for author in authors {
+                let name = author.trim().trim_start_matches('@');
+                if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+                    errors.push(ConfigError::InvalidValue {
+                        field: field.to_string(),
+                        message: format!("'{author}' is not a valid X username"),
+                    });
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,96 @@
+//! `[content_mix]`: daily split between replies and original posts.
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::ConfigError;
+
+/// Daily content mix planner configuration.
+///
+/// At the start of each day the planner splits `daily_budget` actions
+/// between replies, original tweets, and threads, and the day's caps
+/// follow the plan. `[limits]` (and any warm-up) still bound each count.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ContentMixConfig {
+    /// Enable the daily planner.
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Replies, tweets, and threads to plan per day, in total.
+    #[serde(default = "default_mix_daily_budget")]
+    pub daily_budget: u32,
+
+    /// Relative share of replies in the budget left after threads.
+    #[serde(default = "default_mix_reply_ratio")]
+    pub reply_ratio: f64,
+
+    /// Relative share of original tweets in the budget left after threads.
+    #[serde(default = "default_mix_original_ratio")]
+    pub original_ratio: f64,
+
+    /// How far recent engagement shifts the reply/original split (0.0-1.0).
+    /// 0 uses the ratios as configured.
+    #[serde(default = "default_mix_performance_weight")]
+    pub performance_weight: f64,
+}
+
+impl Default for ContentMixConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            daily_budget: default_mix_daily_budget(),
+            reply_ratio: default_mix_reply_ratio(),
+            original_ratio: default_mix_original_ratio(),
+            performance_weight: default_mix_performance_weight(),
+        }
+    }
+}
+
+fn default_mix_daily_budget() -> u32 {
+    8
+}
+fn default_mix_reply_ratio() -> f64 {
+    0.7
+}
+fn default_mix_original_ratio() -> f64 {
+    0.3
+}
+fn default_mix_performance_weight() -> f64 {
+    0.5
+}
+
+impl ContentMixConfig {
+    /// Check the budget and ratios when enabled.
+    pub(super) fn validate(&self, errors: &mut Vec<ConfigError>) {
+        if self.enabled {
+            if self.daily_budget == 0 {
+                errors.push(ConfigError::InvalidValue {
+                    field: "content_mix.daily_budget".to_string(),
+                    message: "must be at least 1".to_string(),
+                });
+            }
+            for (field, ratio) in [
+                ("content_mix.reply_ratio", self.reply_ratio),
+                ("content_mix.original_ratio", self.original_ratio),
+            ] {
+                if !(0.0..=1.0).contains(&ratio) {
+                    errors.push(ConfigError::InvalidValue {
+                        field: field.to_string(),
+                        message: "must be between 0.0 and 1.0".to_string(),
+                    });
+                }
+            }
+            if self.reply_ratio + self.original_ratio <= 0.0 {
+                errors.push(ConfigError::InvalidValue {
+                    field: "content_mix.reply_ratio".to_string(),
+                    message: "reply_ratio and original_ratio must not both be 0".to_string(),
+                });
+            }
+            if !(0.0..=1.0).contains(&self.performance_weight) {
+                errors.push(ConfigError::InvalidValue {
+                    field: "content_mix.performance_weight".to_string(),
+                    message: "must be between 0.0 and 1.0".to_string(),
+                });
+            }
+        }
+    }
+}
@@ -0,0 +1,86 @@
+//! `[context_check]`: controversy signals checked before replying.
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::ConfigError;
+
+/// What to do with a reply target that shows controversy signals.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ContextPolicy {
+    /// Do not check.
+    #[default]
+    Off,
+    /// Skip the tweet.
+    Skip,
+    /// Draft the reply but queue it for approval instead of posting.
+    RequireApproval,
+}
+
+/// Controversy check run before replying to discovered and target tweets.
+///
+/// A tweet is flagged when it, or its author's tweets discovered in the last
+/// `author_window_days`, draws at least `min_quotes` quote tweets at a
+/// quote-to-like ratio above `max_quote_ratio` (pile-ons get quoted far more
+/// than they get liked).
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ContextCheckConfig {
+    /// Policy for flagged tweets.
+    #[serde(default)]
+    pub policy: ContextPolicy,
+
+    /// Quote-to-like ratio above which a tweet or author is flagged.
+    #[serde(default = "default_context_max_quote_ratio")]
+    pub max_quote_ratio: f64,
+
+    /// Minimum quote tweets before the ratio is trusted.
+    #[serde(default = "default_context_min_quotes")]
+    pub min_quotes: u64,
+
+    /// How far back the author's discovered tweets count.
+    #[serde(default = "default_context_author_window_days")]
+    pub author_window_days: u32,
+}
+
+impl Default for ContextCheckConfig {
+    fn default() -> Self {
+        Self {
+            policy: ContextPolicy::Off,
+            max_quote_ratio: default_context_max_quote_ratio(),
+            min_quotes: default_context_min_quotes(),
+            author_window_days: default_context_author_window_days(),
+        }
+    }
+}
+
+fn default_context_max_quote_ratio() -> f64 {
+    0.5
+}
+
+fn default_context_min_quotes() -> u64 {
+    10
+}
+
+fn default_context_author_window_days() -> u32 {
+    7
+}
+
+impl ContextCheckConfig {
+    /// Check the quote ratio and author window unless the check is off.
+    pub(super) fn validate(&self, errors: &mut Vec<ConfigError>) {
+        if self.policy != ContextPolicy::Off {
+            if self.max_quote_ratio <= 0.0 {
+                errors.push(ConfigError::InvalidValue {
+                    field: "context_check.max_quote_ratio".to_string(),
+                    message: "must be greater than 0".to_string(),
+                });
+            }
+            if self.author_window_days == 0 {
+                errors.push(ConfigError::InvalidValue {
+                    field: "context_check.author_window_days".to_string(),
+                    message: "must be at least 1".to_string(),
+                });
+            }
+        }
+    }
+}
@@ -3,8 +3,11 @@
 //! These defaults match the values specified in the CLI interface contract.
 //! Users only need to supply credentials and business profile.
 
+use std::collections::HashMap;
+
 use super::{
-    AuthConfig, IntervalsConfig, LimitsConfig, McpPolicyConfig, ScoringConfig, StorageConfig,
+    AuthConfig, EntityAdjustments, FreshnessConfig, IntervalsConfig, LimitsConfig, McpPolicyConfig,
+    ScoringConfig, ScoringPluginConfig, StorageConfig, ThresholdCalibrationConfig,
 };
 
 impl Default for AuthConfig {
@@ -27,6 +30,53 @@ impl Default for ScoringConfig {
             engagement_rate_max: 15.0,
             reply_count_max: 15.0,
             content_type_max: 10.0,
+            entity_adjustments: EntityAdjustments::default(),
+            calibration: ThresholdCalibrationConfig::default(),
+            freshness: FreshnessConfig::default(),
+            plugin: ScoringPluginConfig::default(),
+        }
+    }
+}
+
+impl Default for FreshnessConfig {
+    fn default() -> Self {
+        Self {
+            discovery_decay_after_hours: 12,
+            discovery_max_age_hours: 48,
+            mentions_max_age_hours: 72,
+        }
+    }
+}
+
+impl Default for ThresholdCalibrationConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            target_replies_per_day: 5,
+            floor: 45,
+            ceiling: 85,
+            step: 5,
+        }
+    }
+}
+
+impl Default for EntityAdjustments {
+    fn default() -> Self {
+        Self {
+            spaces: -15.0,
+            article: 0.0,
+            media_only: -10.0,
+        }
+    }
+}
+
+impl Default for ScoringPluginConfig {
+    fn default() -> Self {
+        Self {
+            path: String::new(),
+            fuel: 10_000_000,
+            max_adjustment: 20.0,
+            max_memory_mb: 16,
         }
     }
 }
@@ -37,6 +87,8 @@ impl Default for LimitsConfig {
             max_replies_per_day: 5,
             max_tweets_per_day: 6,
             max_threads_per_week: 1,
+            max_likes_per_day: 50,
+            max_follows_per_day: 20,
             min_action_delay_seconds: 45,
             max_action_delay_seconds: 180,
             max_replies_per_author_per_day: 1,
@@ -47,6 +99,8 @@ impl Default for LimitsConfig {
                 "link in bio".to_string(),
             ],
             product_mention_ratio: 0.2,
+            reset_timezone: String::new(),
+            windows: Default::default(),
         }
     }
 }
@@ -58,6 +112,7 @@ impl Default for IntervalsConfig {
             discovery_search_seconds: 900,
             content_post_window_seconds: 10800,
             thread_interval_seconds: 604800,
+            discovery_dedup_window_seconds: 86400,
         }
     }
 }
@@ -67,6 +122,8 @@ impl Default for StorageConfig {
         Self {
             db_path: "~/.tuitbot/tuitbot.db".to_string(),
             retention_days: 90,
+            slow_query_ms: 1000,
+            record_slow_queries: false,
         }
     }
 }
@@ -83,10 +140,14 @@ impl Default for McpPolicyConfig {
             ],
             blocked_tools: Vec::new(),
             dry_run_mutations: false,
+            confirmation_mode: false,
+            confirmation_ttl_seconds: 300,
             max_mutations_per_hour: 20,
             template: None,
             rules: Vec::new(),
             rate_limits: Vec::new(),
+            tools: HashMap::new(),
+            categories: HashMap::new(),
         }
     }
 }
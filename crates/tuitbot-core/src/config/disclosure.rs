@@ -0,0 +1,82 @@
+//! `[disclosure]`: automation disclosure text appended to posts.
+
+use serde::{Deserialize, Serialize};
+
+use super::types_policy::default_true;
+use crate::content::{tweet_weighted_len, MAX_DISCLOSURE_CHARS};
+use crate::error::ConfigError;
+
+/// Disclaimer or signature appended to generated content, per action type.
+///
+/// Each template may use `{product_name}`. Unset action types get nothing.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct DisclosureConfig {
+    /// Template for replies.
+    #[serde(default)]
+    pub reply: Option<String>,
+
+    /// Template for original tweets.
+    #[serde(default)]
+    pub tweet: Option<String>,
+
+    /// Template for quote tweets.
+    #[serde(default)]
+    pub quote: Option<String>,
+
+    /// Template for threads; added to the first tweet.
+    #[serde(default)]
+    pub thread: Option<String>,
+
+    /// Only add the disclosure when the text mentions the product.
+    #[serde(default = "default_true")]
+    pub product_mentions_only: bool,
+}
+
+impl Default for DisclosureConfig {
+    fn default() -> Self {
+        Self {
+            reply: None,
+            tweet: None,
+            quote: None,
+            thread: None,
+            product_mentions_only: true,
+        }
+    }
+}
+
+impl DisclosureConfig {
+    /// Raw template for `action_type`, if one is set.
+    pub fn template(&self, action_type: &str) -> Option<&str> {
+        match action_type {
+            "reply" => self.reply.as_deref(),
+            "tweet" => self.tweet.as_deref(),
+            "quote" => self.quote.as_deref(),
+            "thread" => self.thread.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Disclosure text for `action_type` with `{product_name}` expanded.
+    pub fn template_for(&self, action_type: &str, product_name: &str) -> Option<String> {
+        let template = self.template(action_type)?.trim();
+        (!template.is_empty()).then(|| template.replace("{product_name}", product_name))
+    }
+}
+
+impl DisclosureConfig {
+    /// Check that each disclosure template fits in a post.
+    pub(super) fn validate(&self, errors: &mut Vec<ConfigError>) {
+        for action_type in ["reply", "tweet", "quote", "thread"] {
+            let Some(template) = self.template(action_type) else {
+                continue;
+            };
+            let length = tweet_weighted_len(template.trim());
+            if length == 0 || length > MAX_DISCLOSURE_CHARS {
+                errors.push(ConfigError::InvalidValue {
+                    field: format!("disclosure.{action_type}"),
+                    message: format!("must be between 1 and {MAX_DISCLOSURE_CHARS} characters"),
+                });
+            }
+        }
+    }
+}
@@ -0,0 +1,73 @@
+//! `[discovery_sources]`: non-X sources searched alongside X.
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::ConfigError;
+
+/// Non-X discovery sources searched with the same keywords as X.
+///
+/// Items from these sources are scored and stored alongside discovered
+/// tweets but never replied to.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct DiscoverySourcesConfig {
+    /// Hacker News stories, via the Algolia search API.
+    #[serde(default)]
+    pub hackernews: HackerNewsSourceConfig,
+}
+
+/// Hacker News discovery source settings.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct HackerNewsSourceConfig {
+    /// Search Hacker News on each discovery iteration.
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Maximum stories fetched per keyword search.
+    #[serde(default = "default_hn_max_results")]
+    pub max_results: u32,
+
+    /// Skip stories with fewer points than this.
+    #[serde(default)]
+    pub min_points: u32,
+
+    /// Only consider stories posted within this many hours.
+    #[serde(default = "default_hn_max_age_hours")]
+    pub max_age_hours: u32,
+}
+
+impl Default for HackerNewsSourceConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_results: default_hn_max_results(),
+            min_points: 0,
+            max_age_hours: default_hn_max_age_hours(),
+        }
+    }
+}
+
+fn default_hn_max_results() -> u32 {
+    20
+}
+fn default_hn_max_age_hours() -> u32 {
+    48
+}
+
+impl DiscoverySourcesConfig {
+    /// Check the Hacker News result and age limits.
+    pub(super) fn validate(&self, errors: &mut Vec<ConfigError>) {
+        let hn = &self.hackernews;
+        if hn.max_results == 0 || hn.max_results > 100 {
+            errors.push(ConfigError::InvalidValue {
+                field: "discovery_sources.hackernews.max_results".to_string(),
+                message: "must be between 1 and 100".to_string(),
+            });
+        }
+        if hn.max_age_hours == 0 {
+            errors.push(ConfigError::InvalidValue {
+                field: "discovery_sources.hackernews.max_age_hours".to_string(),
+                message: "must be greater than 0".to_string(),
+            });
+        }
+    }
+}
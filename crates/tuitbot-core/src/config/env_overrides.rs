@@ -1,7 +1,8 @@
 //! Environment variable overrides for configuration.
 
-use super::{Config, DeploymentMode, OperatingMode};
+use super::{Config, DeploymentMode, OperatingMode, RateLimitWindow};
 use crate::error::ConfigError;
+use std::collections::BTreeMap;
 use std::env;
 
 impl Config {
@@ -52,6 +53,27 @@ impl Config {
         if let Ok(val) = env::var("TUITBOT_X_API__CLIENT_SECRET") {
             self.x_api.client_secret = Some(val);
         }
+        if let Ok(val) = env::var("TUITBOT_X_API__BASE_URL") {
+            self.x_api.base_url = Some(val);
+        }
+
+        // Reddit
+        if let Ok(val) = env::var("TUITBOT_REDDIT__CLIENT_SECRET") {
+            self.reddit.client_secret = Some(val);
+        }
+        if let Ok(val) = env::var("TUITBOT_REDDIT__PASSWORD") {
+            self.reddit.password = Some(val);
+        }
+
+        // Remote backup
+        if let Ok(val) = env::var("TUITBOT_REMOTE_BACKUP__SECRET_ACCESS_KEY") {
+            self.remote_backup.secret_access_key = Some(val);
+        }
+
+        // Email notifications
+        if let Ok(val) = env::var("TUITBOT_NOTIFICATIONS__EMAIL__SMTP_PASSWORD") {
+            self.notifications.email.smtp_password = Some(val);
+        }
 
         // Auth
         if let Ok(val) = env::var("TUITBOT_AUTH__MODE") {
@@ -141,6 +163,12 @@ impl Config {
             self.limits.product_mention_ratio =
                 parse_env_f32("TUITBOT_LIMITS__PRODUCT_MENTION_RATIO", &val)?;
         }
+        if let Ok(val) = env::var("TUITBOT_LIMITS__RESET_TIMEZONE") {
+            self.limits.reset_timezone = val;
+        }
+        if let Ok(val) = env::var("TUITBOT_LIMITS__WINDOWS") {
+            self.limits.windows = parse_env_windows("TUITBOT_LIMITS__WINDOWS", &val)?;
+        }
 
         // Intervals
         if let Ok(val) = env::var("TUITBOT_INTERVALS__MENTIONS_CHECK_SECONDS") {
@@ -159,6 +187,10 @@ impl Config {
             self.intervals.thread_interval_seconds =
                 parse_env_u64("TUITBOT_INTERVALS__THREAD_INTERVAL_SECONDS", &val)?;
         }
+        if let Ok(val) = env::var("TUITBOT_INTERVALS__DISCOVERY_DEDUP_WINDOW_SECONDS") {
+            self.intervals.discovery_dedup_window_seconds =
+                parse_env_u64("TUITBOT_INTERVALS__DISCOVERY_DEDUP_WINDOW_SECONDS", &val)?;
+        }
 
         // Targets
         if let Ok(val) = env::var("TUITBOT_TARGETS__ACCOUNTS") {
@@ -182,6 +214,15 @@ impl Config {
         if let Ok(val) = env::var("TUITBOT_LLM__BASE_URL") {
             self.llm.base_url = Some(val);
         }
+        if let Ok(val) = env::var("TUITBOT_LLM__PROMPT_CACHING") {
+            self.llm.prompt_caching = parse_env_bool("TUITBOT_LLM__PROMPT_CACHING", &val)?;
+        }
+        if let Ok(val) = env::var("TUITBOT_LLM__BATCH_MODE") {
+            self.llm.batch_mode = parse_env_bool("TUITBOT_LLM__BATCH_MODE", &val)?;
+        }
+        if let Ok(val) = env::var("TUITBOT_LLM__MAX_CONCURRENCY") {
+            self.llm.max_concurrency = Some(parse_env_u32("TUITBOT_LLM__MAX_CONCURRENCY", &val)?);
+        }
 
         // Storage
         if let Ok(val) = env::var("TUITBOT_STORAGE__DB_PATH") {
@@ -293,6 +334,26 @@ pub(super) fn parse_env_u32(var_name: &str, val: &str) -> Result<u32, ConfigErro
     })
 }
 
+/// Parse `action=window` pairs such as `thread=week,reply=12h`.
+pub(super) fn parse_env_windows(
+    var_name: &str,
+    val: &str,
+) -> Result<BTreeMap<String, RateLimitWindow>, ConfigError> {
+    split_csv(val)
+        .into_iter()
+        .map(|pair| {
+            pair.split_once('=')
+                .and_then(|(action, window)| {
+                    RateLimitWindow::parse(window).map(|w| (action.trim().to_string(), w))
+                })
+                .ok_or_else(|| ConfigError::InvalidValue {
+                    field: var_name.to_string(),
+                    message: format!("'{pair}' is not a valid action=window pair"),
+                })
+        })
+        .collect()
+}
+
 /// Parse an environment variable value as `f32`.
 pub(super) fn parse_env_f32(var_name: &str, val: &str) -> Result<f32, ConfigError> {
     val.parse::<f32>().map_err(|_| ConfigError::InvalidValue {
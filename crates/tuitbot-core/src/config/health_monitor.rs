@@ -0,0 +1,82 @@
+//! `[health_monitor]`: shadowban and visibility checks.
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::ConfigError;
+
+/// Periodic visibility self-check (shadowban / reply deboosting signals).
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct HealthMonitorConfig {
+    /// Enable the periodic visibility self-check.
+    #[serde(default = "default_health_enabled")]
+    pub enabled: bool,
+
+    /// Seconds between checks.
+    #[serde(default = "default_health_check_interval_seconds")]
+    pub check_interval_seconds: u64,
+
+    /// Maximum recent tweets and replies (each) to look up per check.
+    #[serde(default = "default_health_sample_size")]
+    pub sample_size: u32,
+
+    /// Minimum share of sampled posts that must be findable (0.0-1.0).
+    /// Below this, visibility is considered degraded.
+    #[serde(default = "default_health_min_visible_ratio")]
+    pub min_visible_ratio: f64,
+
+    /// Posting delay multiplier applied while visibility is degraded.
+    #[serde(default = "default_health_slowdown_factor")]
+    pub slowdown_factor: f64,
+}
+
+impl Default for HealthMonitorConfig {
+    fn default() -> Self {
+        Self {
+            enabled: default_health_enabled(),
+            check_interval_seconds: default_health_check_interval_seconds(),
+            sample_size: default_health_sample_size(),
+            min_visible_ratio: default_health_min_visible_ratio(),
+            slowdown_factor: default_health_slowdown_factor(),
+        }
+    }
+}
+
+fn default_health_enabled() -> bool {
+    true
+}
+fn default_health_check_interval_seconds() -> u64 {
+    21600
+}
+fn default_health_sample_size() -> u32 {
+    5
+}
+fn default_health_min_visible_ratio() -> f64 {
+    0.5
+}
+fn default_health_slowdown_factor() -> f64 {
+    3.0
+}
+
+impl HealthMonitorConfig {
+    /// Check the visibility ratio, slowdown factor, and check interval.
+    pub(super) fn validate(&self, errors: &mut Vec<ConfigError>) {
+        if !(0.0..=1.0).contains(&self.min_visible_ratio) {
+            errors.push(ConfigError::InvalidValue {
+                field: "health_monitor.min_visible_ratio".to_string(),
+                message: "must be between 0.0 and 1.0".to_string(),
+            });
+        }
+        if self.slowdown_factor < 1.0 {
+            errors.push(ConfigError::InvalidValue {
+                field: "health_monitor.slowdown_factor".to_string(),
+                message: "must be at least 1.0".to_string(),
+            });
+        }
+        if self.enabled && self.check_interval_seconds < 600 {
+            errors.push(ConfigError::InvalidValue {
+                field: "health_monitor.check_interval_seconds".to_string(),
+                message: "must be at least 600 (10 minutes)".to_string(),
+            });
+        }
+    }
+}
@@ -0,0 +1,429 @@
+//! Snapshot history for the config file.
+//!
+//! Writes that go through [`write_config`] also save the new contents as a
+//! numbered snapshot in `config_history/` next to the config file. The
+//! history can be listed, diffed against the previous version, and any
+//! snapshot restored with [`rollback`], which is itself recorded so a bad
+//! rollback can be undone the same way.
+//!
+//! Snapshots contain the same secrets as the config file, so they are
+//! written with the same `0600` permissions.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Utc};
+
+use super::Config;
+use crate::error::ConfigHistoryError;
+use crate::safety::redact::mask_secret;
+
+/// Directory, next to the config file, that holds snapshots.
+const HISTORY_DIR: &str = "config_history";
+
+/// Snapshots kept; older ones are deleted as new ones are written.
+pub const MAX_SNAPSHOTS: usize = 50;
+
+const HEADER: &str = "# tuitbot config snapshot";
+
+/// A saved version of the config file.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct ConfigSnapshot {
+    /// Sequential snapshot ID, used by `tuitbot settings rollback`.
+    pub id: u64,
+    /// When the version was written.
+    pub created_at: DateTime<Utc>,
+    /// What wrote it, e.g. `settings --set` or `api`.
+    pub source: String,
+}
+
+/// One setting that differs between two config versions.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct ConfigChange {
+    /// Dotted key, e.g. `limits.max_replies_per_day`.
+    pub key: String,
+    /// Previous value, `None` when the key was added.
+    pub old: Option<String>,
+    /// New value, `None` when the key was removed.
+    pub new: Option<String>,
+}
+
+/// Directory holding the snapshots for `config_path`.
+pub fn history_dir(config_path: &Path) -> PathBuf {
+    config_path
+        .parent()
+        .unwrap_or_else(|| Path::new("."))
+        .join(HISTORY_DIR)
+}
+
+/// Write `contents` to the config file and record it as a snapshot.
+///
+/// The first write with an empty history also records the file as it was
+/// before, so the original version can be restored. Writing contents equal
+/// to the latest snapshot returns that snapshot without adding another.
+pub fn write_config(
+    config_path: &Path,
+    contents: &str,
+    source: &str,
+) -> Result<ConfigSnapshot, ConfigHistoryError> {
+    let existing = list_snapshots(config_path)?;
+    if existing.is_empty() {
+        if let Ok(previous) = fs::read_to_string(config_path) {
+            if previous != contents {
+                save_snapshot(config_path, 1, &previous, "initial")?;
+            }
+        }
+    }
+
+    if let Some(parent) = config_path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        fs::create_dir_all(parent).map_err(|e| io_error(parent, e))?;
+    }
+    fs::write(config_path, contents).map_err(|e| io_error(config_path, e))?;
+    restrict_permissions(config_path);
+
+    let snapshots = list_snapshots(config_path)?;
+    if let Some(latest) = snapshots.last() {
+        if read_snapshot(config_path, latest.id)? == contents {
+            return Ok(latest.clone());
+        }
+    }
+    let id = snapshots.last().map_or(1, |s| s.id + 1);
+    let snapshot = save_snapshot(config_path, id, contents, source)?;
+    prune(config_path, snapshots.len() + 1)?;
+    Ok(snapshot)
+}
+
+/// All snapshots for `config_path`, oldest first.
+pub fn list_snapshots(config_path: &Path) -> Result<Vec<ConfigSnapshot>, ConfigHistoryError> {
+    let dir = history_dir(config_path);
+    let entries = match fs::read_dir(&dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(io_error(&dir, e)),
+    };
+
+    let mut snapshots = Vec::new();
+    for entry in entries {
+        let path = entry.map_err(|e| io_error(&dir, e))?.path();
+        let Some(id) = snapshot_id(&path) else {
+            continue;
+        };
+        let raw = fs::read_to_string(&path).map_err(|e| io_error(&path, e))?;
+        snapshots.push(parse_header(id, &raw));
+    }
+    snapshots.sort_by_key(|s| s.id);
+    Ok(snapshots)
+}
+
+/// Config file contents saved in snapshot `id`.
+pub fn read_snapshot(config_path: &Path, id: u64) -> Result<String, ConfigHistoryError> {
+    let path = snapshot_path(config_path, id);
+    match fs::read_to_string(&path) {
+        Ok(raw) => Ok(strip_header(&raw).to_string()),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+            Err(ConfigHistoryError::NotFound { id })
+        }
+        Err(e) => Err(io_error(&path, e)),
+    }
+}
+
+/// Settings changed by snapshot `id` relative to the one before it, or
+/// `None` for the oldest snapshot kept.
+pub fn snapshot_changes(
+    config_path: &Path,
+    id: u64,
+) -> Result<Option<Vec<ConfigChange>>, ConfigHistoryError> {
+    let current = read_snapshot(config_path, id)?;
+    let Some(previous) = list_snapshots(config_path)?
+        .into_iter()
+        .rev()
+        .find(|s| s.id < id)
+    else {
+        return Ok(None);
+    };
+    let previous = read_snapshot(config_path, previous.id)?;
+    Ok(Some(diff_configs(&previous, &current)))
+}
+
+/// Restore the config file to snapshot `id`.
+///
+/// The snapshot must parse as a config. The restored version is recorded as
+/// a new snapshot.
+pub fn rollback(config_path: &Path, id: u64) -> Result<ConfigSnapshot, ConfigHistoryError> {
+    let contents = read_snapshot(config_path, id)?;
+    toml::from_str::<Config>(&contents)
+        .map_err(|source| ConfigHistoryError::Invalid { id, source })?;
+    write_config(config_path, &contents, &format!("rollback to #{id}"))
+}
+
+/// Settings that differ between two config files, sorted by key.
+///
+/// Values of secret settings (API keys, client secrets, tokens) are masked.
+pub fn diff_configs(old: &str, new: &str) -> Vec<ConfigChange> {
+    let mut old_values = Vec::new();
+    let mut new_values = Vec::new();
+    flatten("", &parse_table(old), &mut old_values);
+    flatten("", &parse_table(new), &mut new_values);
+
+    let old_map: std::collections::BTreeMap<_, _> = old_values.into_iter().collect();
+    let new_map: std::collections::BTreeMap<_, _> = new_values.into_iter().collect();
+
+    let mut keys: Vec<&String> = old_map.keys().chain(new_map.keys()).collect();
+    keys.sort();
+    keys.dedup();
+
+    keys.into_iter()
+        .filter_map(|key| {
+            let old = old_map.get(key);
+            let new = new_map.get(key);
+            if old == new {
+                return None;
+            }
+            let show = |value: Option<&String>| {
+                value.map(|v| {
+                    if is_secret_key(key) {
+                        mask_secret(v.trim_matches('"'))
+                    } else {
+                        v.clone()
+                    }
+                })
+            };
+            Some(ConfigChange {
+                key: key.clone(),
+                old: show(old),
+                new: show(new),
+            })
+        })
+        .collect()
+}
+
+fn parse_table(contents: &str) -> toml::Value {
+    contents
+        .parse::<toml::Value>()
+        .unwrap_or_else(|_| toml::Value::Table(toml::map::Map::new()))
+}
+
+/// Flatten nested tables into `(dotted.key, rendered value)` pairs. Arrays
+/// are compared whole.
+fn flatten(prefix: &str, value: &toml::Value, out: &mut Vec<(String, String)>) {
+    match value {
+        toml::Value::Table(table) => {
+            for (key, value) in table {
+                let key = if prefix.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{prefix}.{key}")
+                };
+                flatten(&key, value, out);
+            }
+        }
+        other => out.push((prefix.to_string(), other.to_string())),
+    }
+}
+
+/// Whether a dotted config key holds a credential.
+pub(crate) fn is_secret_key(key: &str) -> bool {
+    let last = key.rsplit('.').next().unwrap_or(key);
+    ["api_key", "secret", "token", "password"]
+        .iter()
+        .any(|marker| last.contains(marker))
+}
+
+fn save_snapshot(
+    config_path: &Path,
+    id: u64,
+    contents: &str,
+    source: &str,
+) -> Result<ConfigSnapshot, ConfigHistoryError> {
+    let dir = history_dir(config_path);
+    fs::create_dir_all(&dir).map_err(|e| io_error(&dir, e))?;
+    restrict_dir_permissions(&dir);
+
+    let snapshot = ConfigSnapshot {
+        id,
+        created_at: Utc::now(),
+        source: source.replace('\n', " "),
+    };
+    let path = snapshot_path(config_path, id);
+    let raw = format!(
+        "{HEADER}\n# created_at: {}\n# source: {}\n\n{contents}",
+        snapshot
+            .created_at
+            .to_rfc3339_opts(chrono::SecondsFormat::Secs, true),
+        snapshot.source,
+    );
+    fs::write(&path, raw).map_err(|e| io_error(&path, e))?;
+    restrict_permissions(&path);
+    Ok(snapshot)
+}
+
+/// Delete the oldest snapshots beyond [`MAX_SNAPSHOTS`].
+fn prune(config_path: &Path, count: usize) -> Result<(), ConfigHistoryError> {
+    if count <= MAX_SNAPSHOTS {
+        return Ok(());
+    }
+    let snapshots = list_snapshots(config_path)?;
+    let excess = snapshots.len().saturating_sub(MAX_SNAPSHOTS);
+    for snapshot in &snapshots[..excess] {
+        let path = snapshot_path(config_path, snapshot.id);
+        fs::remove_file(&path).map_err(|e| io_error(&path, e))?;
+    }
+    Ok(())
+}
+
+fn snapshot_path(config_path: &Path, id: u64) -> PathBuf {
+    history_dir(config_path).join(format!("{id:06}.toml"))
+}
+
+fn snapshot_id(path: &Path) -> Option<u64> {
+    if path.extension()? != "toml" {
+        return None;
+    }
+    path.file_stem()?.to_str()?.parse().ok()
+}
+
+fn parse_header(id: u64, raw: &str) -> ConfigSnapshot {
+    let mut snapshot = ConfigSnapshot {
+        id,
+        created_at: DateTime::<Utc>::UNIX_EPOCH,
+        source: String::new(),
+    };
+    for line in raw.lines().take_while(|l| l.starts_with('#')) {
+        if let Some(value) = line.strip_prefix("# created_at: ") {
+            if let Ok(created_at) = value.parse() {
+                snapshot.created_at = created_at;
+            }
+        } else if let Some(value) = line.strip_prefix("# source: ") {
+            snapshot.source = value.to_string();
+        }
+    }
+    snapshot
+}
+
+fn strip_header(raw: &str) -> &str {
+    if !raw.starts_with(HEADER) {
+        return raw;
+    }
+    raw.split_once("\n\n").map_or("", |(_, body)| body)
+}
+
+fn io_error(path: &Path, source: std::io::Error) -> ConfigHistoryError {
+    ConfigHistoryError::Io {
+        path: path.display().to_string(),
+        source,
+    }
+}
+
+fn restrict_permissions(path: &Path) {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let _ = fs::set_permissions(path, fs::Permissions::from_mode(0o600));
+    }
+    #[cfg(not(unix))]
+    let _ = path;
+}
+
+fn restrict_dir_permissions(path: &Path) {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let _ = fs::set_permissions(path, fs::Permissions::from_mode(0o700));
+    }
+    #[cfg(not(unix))]
+    let _ = path;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const V1: &str =
+        "[business]\nproduct_name = \"Tuitbot\"\n\n[limits]\nmax_replies_per_day = 5\n";
+    const V2: &str = "[business]\nproduct_name = \"Tuitbot\"\n\n[limits]\nmax_replies_per_day = 50\n\n[llm]\napi_key = \"sk-test-1234567890\"\n";
+
+    #[test]
+    fn writes_are_snapshotted_and_rolled_back() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let path = dir.path().join("config.toml");
+        fs::write(&path, V1).expect("seed");
+
+        let snapshot = write_config(&path, V2, "settings --set").expect("write");
+        assert_eq!(snapshot.id, 2);
+        assert_eq!(fs::read_to_string(&path).expect("read"), V2);
+
+        let history = list_snapshots(&path).expect("list");
+        let sources: Vec<&str> = history.iter().map(|s| s.source.as_str()).collect();
+        assert_eq!(sources, ["initial", "settings --set"]);
+        assert_eq!(read_snapshot(&path, 1).expect("read"), V1);
+        assert_eq!(snapshot_changes(&path, 1).expect("changes"), None);
+        assert_eq!(
+            snapshot_changes(&path, 2).expect("changes"),
+            Some(diff_configs(V1, V2))
+        );
+
+        // Writing the same contents again adds nothing.
+        assert_eq!(write_config(&path, V2, "api").expect("write").id, 2);
+
+        let restored = rollback(&path, 1).expect("rollback");
+        assert_eq!(restored.id, 3);
+        assert_eq!(restored.source, "rollback to #1");
+        assert_eq!(fs::read_to_string(&path).expect("read"), V1);
+
+        assert!(matches!(
+            rollback(&path, 9),
+            Err(ConfigHistoryError::NotFound { id: 9 })
+        ));
+    }
+
+    #[test]
+    fn invalid_snapshot_is_not_restored() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let path = dir.path().join("config.toml");
+        write_config(&path, "[limits]\nmax_replies_per_day = \"many\"\n", "test").expect("write");
+        write_config(&path, V1, "test").expect("write");
+
+        assert!(matches!(
+            rollback(&path, 1),
+            Err(ConfigHistoryError::Invalid { id: 1, .. })
+        ));
+        assert_eq!(fs::read_to_string(&path).expect("read"), V1);
+    }
+
+    #[test]
+    fn diff_lists_changed_keys_and_masks_secrets() {
+        let changes = diff_configs(V1, V2);
+        assert_eq!(
+            changes,
+            vec![
+                ConfigChange {
+                    key: "limits.max_replies_per_day".to_string(),
+                    old: Some("5".to_string()),
+                    new: Some("50".to_string()),
+                },
+                ConfigChange {
+                    key: "llm.api_key".to_string(),
+                    old: None,
+                    new: Some("sk-t...7890".to_string()),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn old_snapshots_are_pruned() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let path = dir.path().join("config.toml");
+        for n in 0..MAX_SNAPSHOTS + 3 {
+            write_config(
+                &path,
+                &format!("[limits]\nmax_replies_per_day = {n}\n"),
+                "test",
+            )
+            .expect("write");
+        }
+        let history = list_snapshots(&path).expect("list");
+        assert_eq!(history.len(), MAX_SNAPSHOTS);
+        assert_eq!(history[0].id, 4);
+    }
+}
@@ -0,0 +1,132 @@
+//! `[hooks]`: user scripts run at lifecycle events.
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::ConfigError;
+
+/// Lifecycle events a hook can run on.
+pub const HOOK_EVENTS: &[&str] = &["pre_post", "post_post", "pre_approve", "on_denial"];
+
+/// User-provided executables or WASM modules run at lifecycle events.
+///
+/// Each hook receives the action as JSON on stdin. For `pre_post` and
+/// `pre_approve`, its exit code and optional JSON verdict on stdout decide
+/// whether the action proceeds; `post_post` and `on_denial` hooks are
+/// notified only.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct HooksConfig {
+    /// Command that runs `.wasm` hooks, given the module path and args.
+    #[serde(default = "default_hooks_wasm_runtime")]
+    pub wasm_runtime: String,
+
+    /// Hooks, run in order for their event.
+    #[serde(default)]
+    pub scripts: Vec<HookConfig>,
+}
+
+impl Default for HooksConfig {
+    fn default() -> Self {
+        Self {
+            wasm_runtime: default_hooks_wasm_runtime(),
+            scripts: Vec::new(),
+        }
+    }
+}
+
+impl HooksConfig {
+    /// Hooks registered for `event`, in configuration order.
+    pub fn for_event<'a>(&'a self, event: &'a str) -> impl Iterator<Item = &'a HookConfig> + 'a {
+        self.scripts.iter().filter(move |h| h.event == event)
+    }
+}
+
+/// One hook in `[[hooks.scripts]]`.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct HookConfig {
+    /// Name recorded with each run. Defaults to the command's file name.
+    #[serde(default)]
+    pub name: String,
+
+    /// Event to run on (one of [`HOOK_EVENTS`]).
+    pub event: String,
+
+    /// Executable path, or a `.wasm` module run through `wasm_runtime`.
+    pub command: String,
+
+    /// Extra arguments passed to the command.
+    #[serde(default)]
+    pub args: Vec<String>,
+
+    /// Seconds before the hook is killed and treated as failed.
+    #[serde(default = "default_hook_timeout_secs")]
+    pub timeout_secs: u64,
+
+    /// Let the action proceed when the hook fails, times out, or prints an
+    /// unreadable verdict. By default such failures deny it.
+    #[serde(default)]
+    pub fail_open: bool,
+
+    /// Pass the agent's environment to the hook. Off by default, so API
+    /// keys in the environment are not exposed; only `PATH` is kept.
+    #[serde(default)]
+    pub inherit_env: bool,
+
+    /// Working directory for the hook. Defaults to the system temp dir.
+    #[serde(default)]
+    pub working_dir: Option<String>,
+}
+
+impl HookConfig {
+    /// The name recorded with each run.
+    pub fn display_name(&self) -> &str {
+        if !self.name.trim().is_empty() {
+            return self.name.trim();
+        }
+        std::path::Path::new(&self.command)
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or(&self.command)
+    }
+
+    /// Whether the command is a WASM module.
+    pub fn is_wasm(&self) -> bool {
+        self.command.to_ascii_lowercase().ends_with(".wasm")
+    }
+}
+
+fn default_hooks_wasm_runtime() -> String {
+    "wasmtime run".to_string()
+}
+fn default_hook_timeout_secs() -> u64 {
+    5
+}
+
+impl HooksConfig {
+    /// Check each script's event, command, and timeout.
+    pub(super) fn validate(&self, errors: &mut Vec<ConfigError>) {
+        for (i, hook) in self.scripts.iter().enumerate() {
+            if !HOOK_EVENTS.contains(&hook.event.as_str()) {
+                errors.push(ConfigError::InvalidValue {
+                    field: format!("hooks.scripts[{i}].event"),
+                    message: format!("must be one of: {}", HOOK_EVENTS.join(", ")),
+                });
+            }
+            if hook.command.trim().is_empty() {
+                errors.push(ConfigError::MissingField {
+                    field: format!("hooks.scripts[{i}].command"),
+                });
+            }
+            if hook.timeout_secs == 0 {
+                errors.push(ConfigError::InvalidValue {
+                    field: format!("hooks.scripts[{i}].timeout_secs"),
+                    message: "must be greater than 0".to_string(),
+                });
+            }
+        }
+        if self.scripts.iter().any(|h| h.is_wasm()) && self.wasm_runtime.trim().is_empty() {
+            errors.push(ConfigError::MissingField {
+                field: "hooks.wasm_runtime".to_string(),
+            });
+        }
+    }
+}
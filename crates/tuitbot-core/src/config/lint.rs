@@ -0,0 +1,230 @@
+//! Configuration linting.
+//!
+//! `validate()` rejects configs that cannot run. `lint()` flags configs that
+//! run but are risky or contradict themselves: limits well above what X
+//! tolerates from automated accounts, unattended posting at high volume,
+//! schedules with more posting slots than active hours, and so on. Lint
+//! warnings never block startup.
+
+use std::collections::HashSet;
+use std::fmt;
+
+use serde::Serialize;
+
+use super::Config;
+use crate::automation::schedule::{PostingSlot, AUTO_PREFERRED_TIMES};
+
+/// Daily/weekly limits above which an account is likely to be flagged as spam.
+///
+/// These are conservative heuristics, not published X numbers.
+const SAFE_CEILINGS: &[(&str, u32)] = &[
+    ("limits.max_replies_per_day", 30),
+    ("limits.max_tweets_per_day", 15),
+    ("limits.max_threads_per_week", 7),
+    ("limits.max_likes_per_day", 200),
+    ("limits.max_follows_per_day", 50),
+    ("limits.max_replies_per_author_per_day", 3),
+];
+
+/// Minimum delay between actions that still looks human.
+const SAFE_MIN_ACTION_DELAY_SECONDS: u64 = 15;
+
+/// Reply and tweet volumes above which posting without review is risky.
+const UNATTENDED_MAX_REPLIES_PER_DAY: u32 = 10;
+const UNATTENDED_MAX_TWEETS_PER_DAY: u32 = 6;
+
+/// A risky or contradictory setting.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct LintWarning {
+    /// Dotted config field the warning is about.
+    pub field: String,
+    /// What is wrong and what to change.
+    pub message: String,
+}
+
+impl LintWarning {
+    fn new(field: &str, message: impl Into<String>) -> Self {
+        Self {
+            field: field.to_string(),
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for LintWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.field, self.message)
+    }
+}
+
+impl Config {
+    /// Flag risky or contradictory settings, returning every warning found.
+    pub fn lint(&self) -> Vec<LintWarning> {
+        let mut warnings = Vec::new();
+        self.lint_limits(&mut warnings);
+        self.lint_approval(&mut warnings);
+        self.lint_schedule(&mut warnings);
+        self.lint_keywords(&mut warnings);
+        warnings
+    }
+
+    fn lint_limits(&self, warnings: &mut Vec<LintWarning>) {
+        let limits = &self.limits;
+        let values = [
+            limits.max_replies_per_day,
+            limits.max_tweets_per_day,
+            limits.max_threads_per_week,
+            limits.max_likes_per_day,
+            limits.max_follows_per_day,
+            limits.max_replies_per_author_per_day,
+        ];
+        for (&(field, ceiling), value) in SAFE_CEILINGS.iter().zip(values) {
+            if value > ceiling {
+                warnings.push(LintWarning::new(
+                    field,
+                    format!(
+                        "{value} is above the X-safe heuristic of {ceiling}; \
+                         high volumes get automated accounts restricted"
+                    ),
+                ));
+            }
+        }
+
+        if limits.min_action_delay_seconds < SAFE_MIN_ACTION_DELAY_SECONDS {
+            warnings.push(LintWarning::new(
+                "limits.min_action_delay_seconds",
+                format!(
+                    "{}s between actions looks automated; use at least \
+                     {SAFE_MIN_ACTION_DELAY_SECONDS}s",
+                    limits.min_action_delay_seconds
+                ),
+            ));
+        }
+
+        if limits.banned_phrases.is_empty() {
+            warnings.push(LintWarning::new(
+                "limits.banned_phrases",
+                "empty, so nothing filters spammy phrasing like \"check out\"; \
+                 keep the defaults or add your own",
+            ));
+        }
+    }
+
+    fn lint_approval(&self, warnings: &mut Vec<LintWarning>) {
+        if self.effective_approval_mode() {
+            return;
+        }
+        let limits = &self.limits;
+        if limits.max_replies_per_day > UNATTENDED_MAX_REPLIES_PER_DAY
+            || limits.max_tweets_per_day > UNATTENDED_MAX_TWEETS_PER_DAY
+        {
+            warnings.push(LintWarning::new(
+                "approval_mode",
+                format!(
+                    "off while allowing {} replies and {} tweets a day; enable \
+                     approval_mode or lower the limits to {UNATTENDED_MAX_REPLIES_PER_DAY} \
+                     replies and {UNATTENDED_MAX_TWEETS_PER_DAY} tweets",
+                    limits.max_replies_per_day, limits.max_tweets_per_day
+                ),
+            ));
+        }
+    }
+
+    fn lint_schedule(&self, warnings: &mut Vec<LintWarning>) {
+        let schedule = &self.schedule;
+        let (start, end) = (schedule.active_hours_start, schedule.active_hours_end);
+        if start == end {
+            warnings.push(LintWarning::new(
+                "schedule.active_hours_end",
+                format!("equals active_hours_start ({start}), so the active window is empty"),
+            ));
+            return;
+        }
+        let active_hours = if start < end {
+            end - start
+        } else {
+            24 - start + end
+        };
+        let in_window = |hour: u8| {
+            if start < end {
+                hour >= start && hour < end
+            } else {
+                hour >= start || hour < end
+            }
+        };
+
+        let slots: Vec<PostingSlot> = schedule
+            .preferred_times
+            .iter()
+            .flat_map(|time| {
+                if time == "auto" {
+                    AUTO_PREFERRED_TIMES.to_vec()
+                } else {
+                    vec![time.as_str()]
+                }
+            })
+            .filter_map(PostingSlot::parse)
+            .collect();
+
+        if slots.len() > active_hours as usize {
+            warnings.push(LintWarning::new(
+                "schedule.preferred_times",
+                format!(
+                    "{} posting slots but only {active_hours} active hour(s); widen \
+                     active_hours_start/active_hours_end or drop slots",
+                    slots.len()
+                ),
+            ));
+        }
+        let outside: Vec<String> = slots
+            .iter()
+            .filter(|slot| !in_window((slot.as_minutes() / 60) as u8))
+            .map(PostingSlot::format)
+            .collect();
+        if !outside.is_empty() {
+            warnings.push(LintWarning::new(
+                "schedule.preferred_times",
+                format!(
+                    "{} fall outside active hours {start}:00-{end}:00 and never post",
+                    outside.join(", ")
+                ),
+            ));
+        }
+        if slots.len() > self.limits.max_tweets_per_day as usize {
+            warnings.push(LintWarning::new(
+                "schedule.preferred_times",
+                format!(
+                    "{} posting slots but limits.max_tweets_per_day is {}; the extra \
+                     slots are skipped",
+                    slots.len(),
+                    self.limits.max_tweets_per_day
+                ),
+            ));
+        }
+    }
+
+    fn lint_keywords(&self, warnings: &mut Vec<LintWarning>) {
+        let competitors: HashSet<String> = self
+            .business
+            .competitor_keywords
+            .iter()
+            .map(|k| k.trim().to_lowercase())
+            .collect();
+        let overlap: Vec<&str> = self
+            .business
+            .product_keywords
+            .iter()
+            .filter(|k| competitors.contains(&k.trim().to_lowercase()))
+            .map(String::as_str)
+            .collect();
+        if !overlap.is_empty() {
+            warnings.push(LintWarning::new(
+                "business.competitor_keywords",
+                format!(
+                    "{} also listed in product_keywords; each keyword should be one or the other",
+                    overlap.join(", ")
+                ),
+            ));
+        }
+    }
+}
@@ -0,0 +1,51 @@
+//! `[media]`: upload limits for attached video.
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::ConfigError;
+
+/// Pre-upload media limits, applied on top of X's hard limits.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct MediaConfig {
+    /// Maximum video duration in seconds (X allows 140s for standard accounts).
+    #[serde(default = "default_max_video_duration_seconds")]
+    pub max_video_duration_seconds: u32,
+
+    /// Maximum video file size in MB (X's hard limit is 512).
+    #[serde(default = "default_max_video_size_mb")]
+    pub max_video_size_mb: u32,
+}
+
+impl Default for MediaConfig {
+    fn default() -> Self {
+        Self {
+            max_video_duration_seconds: default_max_video_duration_seconds(),
+            max_video_size_mb: default_max_video_size_mb(),
+        }
+    }
+}
+
+fn default_max_video_duration_seconds() -> u32 {
+    140
+}
+fn default_max_video_size_mb() -> u32 {
+    512
+}
+
+impl MediaConfig {
+    /// Check the video duration and size limits.
+    pub(super) fn validate(&self, errors: &mut Vec<ConfigError>) {
+        if self.max_video_duration_seconds == 0 {
+            errors.push(ConfigError::InvalidValue {
+                field: "media.max_video_duration_seconds".to_string(),
+                message: "must be greater than 0".to_string(),
+            });
+        }
+        if self.max_video_size_mb == 0 || self.max_video_size_mb > 512 {
+            errors.push(ConfigError::InvalidValue {
+                field: "media.max_video_size_mb".to_string(),
+                message: "must be between 1 and 512".to_string(),
+            });
+        }
+    }
+}
@@ -0,0 +1,115 @@
+//! `[mention_ack]`: likes or short replies to positive mentions.
+
+use serde::{Deserialize, Serialize};
+
+use super::types_policy::default_true;
+use crate::error::ConfigError;
+
+/// Like, and optionally answer with a fixed template, simple praise mentions.
+///
+/// A mention is acknowledged instead of getting a generated reply only when
+/// the mention triage labels it praise with at least `min_confidence`. No
+/// LLM is called. At most `max_per_hour` and `max_per_day` mentions are
+/// acknowledged, and each author at most once per day; past a limit the
+/// mention gets the normal generated reply.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct MentionAckConfig {
+    /// Enable auto-acknowledgement of praise mentions.
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Like the mention.
+    #[serde(default = "default_true")]
+    pub like: bool,
+
+    /// Also reply with one of `templates`.
+    #[serde(default)]
+    pub reply: bool,
+
+    /// Acknowledgement replies; one is picked per mention.
+    #[serde(default = "default_mention_ack_templates")]
+    pub templates: Vec<String>,
+
+    /// Minimum triage confidence (0.0-1.0) that the mention is praise.
+    #[serde(default = "default_mention_ack_min_confidence")]
+    pub min_confidence: f64,
+
+    /// Most mentions acknowledged in any hour.
+    #[serde(default = "default_mention_ack_max_per_hour")]
+    pub max_per_hour: u32,
+
+    /// Most mentions acknowledged in any 24 hours.
+    #[serde(default = "default_mention_ack_max_per_day")]
+    pub max_per_day: u32,
+}
+
+impl Default for MentionAckConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            like: true,
+            reply: false,
+            templates: default_mention_ack_templates(),
+            min_confidence: default_mention_ack_min_confidence(),
+            max_per_hour: default_mention_ack_max_per_hour(),
+            max_per_day: default_mention_ack_max_per_day(),
+        }
+    }
+}
+
+fn default_mention_ack_templates() -> Vec<String> {
+    ["Thank you!", "Thanks so much!", "Really appreciate it!"]
+        .iter()
+        .map(|t| t.to_string())
+        .collect()
+}
+
+fn default_mention_ack_min_confidence() -> f64 {
+    0.85
+}
+
+fn default_mention_ack_max_per_hour() -> u32 {
+    3
+}
+
+fn default_mention_ack_max_per_day() -> u32 {
+    10
+}
+
+impl MentionAckConfig {
+    /// Check confidence, caps, and templates when enabled.
+    pub(super) fn validate(&self, errors: &mut Vec<ConfigError>) {
+        if self.enabled {
+            if !self.like && !self.reply {
+                errors.push(ConfigError::InvalidValue {
+                    field: "mention_ack.like".to_string(),
+                    message: "like or reply must be enabled".to_string(),
+                });
+            }
+            if !(0.5..=1.0).contains(&self.min_confidence) {
+                errors.push(ConfigError::InvalidValue {
+                    field: "mention_ack.min_confidence".to_string(),
+                    message: "must be between 0.5 and 1.0".to_string(),
+                });
+            }
+            if self.max_per_hour == 0 || self.max_per_day < self.max_per_hour {
+                errors.push(ConfigError::InvalidValue {
+                    field: "mention_ack.max_per_day".to_string(),
+                    message: "max_per_hour must be at least 1 and at most max_per_day".to_string(),
+                });
+            }
+            if self.reply
+                && (self.templates.is_empty()
+                    || self
+                        .templates
+                        .iter()
+                        .any(|t| t.trim().is_empty() || t.chars().count() > 100))
+            {
+                errors.push(ConfigError::InvalidValue {
+                    field: "mention_ack.templates".to_string(),
+                    message: "must list at least one template of 1-100 characters".to_string(),
+                });
+            }
+        }
+    }
+}
@@ -7,23 +7,82 @@
 //!
 //! CLI flag overrides are applied by the binary crate after loading.
 
+mod approval_sla;
+mod author_overlap;
+mod auto_triage;
+mod blocklist;
+mod content_mix;
+mod context_check;
 mod defaults;
+mod disclosure;
+mod discovery_sources;
 mod enrichment;
 mod env_overrides;
+mod health_monitor;
+pub mod history;
+mod lifecycle_hooks;
+mod lint;
+mod media;
+mod mention_ack;
+mod notifications;
+mod pacing;
+mod presets;
+mod quote_tweets;
+mod reciprocity;
+mod reddit;
+mod remote_backup;
+mod safe_mode;
+mod thread_format;
+mod trending;
+mod trusted_authors;
 mod types;
 mod types_policy;
+mod usage_reconciliation;
 mod validation;
+mod warmup;
 
 #[cfg(test)]
 mod tests;
 
+pub use approval_sla::ApprovalSlaConfig;
+pub use author_overlap::AuthorOverlapConfig;
+pub use auto_triage::AutoTriageConfig;
+pub use blocklist::BlocklistConfig;
+pub use content_mix::ContentMixConfig;
+pub use context_check::{ContextCheckConfig, ContextPolicy};
+pub use disclosure::DisclosureConfig;
+pub use discovery_sources::{DiscoverySourcesConfig, HackerNewsSourceConfig};
 pub use enrichment::{EnrichmentStage, ProfileCompleteness};
+pub use health_monitor::HealthMonitorConfig;
+pub use lifecycle_hooks::{HookConfig, HooksConfig, HOOK_EVENTS};
+pub use lint::LintWarning;
+pub use media::MediaConfig;
+pub use mention_ack::MentionAckConfig;
+pub use notifications::{
+    EmailConfig, NotificationChannelConfig, NotificationsConfig, WebhookConfig,
+    NOTIFICATION_EVENTS, NOTIFICATION_URL_SCHEMES,
+};
+pub use pacing::PacingConfig;
+pub use presets::PolicyPreset;
+pub use quote_tweets::QuoteTweetConfig;
+pub use reciprocity::{ReciprocityConfig, ReciprocityPolicy};
+pub use reddit::RedditConfig;
+pub use remote_backup::RemoteBackupConfig;
+pub use safe_mode::SafeModeConfig;
+pub use thread_format::{ThreadFormatConfig, ThreadNumbering};
+pub use trending::TrendingConfig;
+pub use trusted_authors::TrustedAuthorConfig;
 pub use types::{
-    AuthConfig, BusinessProfile, ContentSourceEntry, ContentSourcesConfig, DeploymentCapabilities,
-    DeploymentMode, IntervalsConfig, LimitsConfig, LlmConfig, LoggingConfig, ScoringConfig,
-    ServerConfig, StorageConfig, TargetsConfig, XApiConfig,
+    ApiRateLimitConfig, AuthConfig, BusinessProfile, ContentSourceEntry, ContentSourcesConfig,
+    DeploymentCapabilities, DeploymentMode, EmojiPolicy, EntityAdjustments, FreshnessConfig,
+    IntervalsConfig, LimitsConfig, LlmConfig, LlmPolicyConfig, LoggingConfig, PillarOverride,
+    PublicFeedConfig, RateLimitWindow, ScoringConfig, ScoringPluginConfig, ServerConfig,
+    StorageConfig, TargetTier, TargetTiersConfig, TargetsConfig, ThresholdCalibrationConfig,
+    TierCadence, XApiConfig, PUBLIC_FEED_FIELDS, TARGET_ACTION_TYPES, WINDOWED_ACTION_TYPES,
 };
 pub use types_policy::{CircuitBreakerConfig, McpPolicyConfig, ScheduleConfig};
+pub use usage_reconciliation::UsageReconciliationConfig;
+pub use warmup::WarmupConfig;
 
 use crate::error::ConfigError;
 use serde::{Deserialize, Serialize};
@@ -134,6 +193,102 @@ pub struct Config {
     #[serde(default)]
     pub circuit_breaker: CircuitBreakerConfig,
 
+    /// Account health monitor (visibility self-check and posting slowdown).
+    #[serde(default)]
+    pub health_monitor: HealthMonitorConfig,
+
+    /// Gradual ramp-up of caps for new or recently inactive accounts.
+    #[serde(default)]
+    pub warmup: WarmupConfig,
+
+    /// Daily split of the posting budget between replies, tweets, and threads.
+    #[serde(default)]
+    pub content_mix: ContentMixConfig,
+
+    /// Start with posting halted after a crash or a posting failure streak.
+    #[serde(default)]
+    pub safe_mode: SafeModeConfig,
+
+    /// Authors to skip: X mutes and blocks plus manual additions.
+    #[serde(default)]
+    pub blocklist: BlocklistConfig,
+
+    /// Trusted accounts whose replies may skip approval or soft QA.
+    #[serde(default)]
+    pub trusted_authors: Vec<TrustedAuthorConfig>,
+
+    /// Scheduled snapshots uploaded to an S3-compatible bucket.
+    #[serde(default)]
+    pub remote_backup: RemoteBackupConfig,
+
+    /// Custom scripts run before and after posts and approvals.
+    #[serde(default)]
+    pub hooks: HooksConfig,
+
+    /// Per-endpoint daily request budgets spread across active hours.
+    #[serde(default)]
+    pub pacing: PacingConfig,
+
+    /// Comparison of X's official post usage with the local ledger.
+    #[serde(default)]
+    pub usage_reconciliation: UsageReconciliationConfig,
+
+    /// Pre-upload media limits (video duration and size).
+    #[serde(default)]
+    pub media: MediaConfig,
+
+    /// Thread numbering, closing CTA, and hook re-quote.
+    #[serde(default)]
+    pub thread_format: ThreadFormatConfig,
+
+    /// Disclaimer or signature appended to generated content.
+    #[serde(default)]
+    pub disclosure: DisclosureConfig,
+
+    /// Quote-tweet routing for high-reach, on-topic discoveries.
+    #[serde(default)]
+    pub quote_tweets: QuoteTweetConfig,
+
+    /// Automatic approval of queued items that clear strict rules.
+    #[serde(default)]
+    pub auto_triage: AutoTriageConfig,
+
+    /// Like and template-acknowledge simple praise mentions.
+    #[serde(default)]
+    pub mention_ack: MentionAckConfig,
+
+    /// Review deadline, reminders, and escalation for pending approvals.
+    #[serde(default)]
+    pub approval_sla: ApprovalSlaConfig,
+
+    /// Controversy check before replying to discovered and target tweets.
+    #[serde(default)]
+    pub context_check: ContextCheckConfig,
+
+    /// De-prioritize or skip authors who rarely engage back.
+    #[serde(default)]
+    pub reciprocity: ReciprocityConfig,
+
+    /// Similarity check against the target author's own recent tweets.
+    #[serde(default)]
+    pub author_overlap: AuthorOverlapConfig,
+
+    /// Trend-inspired original tweets drawn from discovery clusters.
+    #[serde(default)]
+    pub trending: TrendingConfig,
+
+    /// Non-X discovery sources (Hacker News) feeding the scoring pipeline.
+    #[serde(default)]
+    pub discovery_sources: DiscoverySourcesConfig,
+
+    /// Reddit subreddit monitoring and suggested comments.
+    #[serde(default)]
+    pub reddit: RedditConfig,
+
+    /// Outbound webhook notifications.
+    #[serde(default)]
+    pub notifications: NotificationsConfig,
+
     /// Content source configuration for the Watchtower.
     #[serde(default)]
     pub content_sources: ContentSourcesConfig,
@@ -197,6 +352,16 @@ impl Config {
         self.mode == OperatingMode::Composer
     }
 
+    /// Timezone whose midnight resets daily and weekly rate limits:
+    /// `limits.reset_timezone`, falling back to `schedule.timezone`.
+    pub fn rate_limit_timezone(&self) -> &str {
+        if self.limits.reset_timezone.is_empty() {
+            &self.schedule.timezone
+        } else {
+            &self.limits.reset_timezone
+        }
+    }
+
     /// Resolve the config file path from arguments, env vars, or default.
     ///
     /// Returns `(path, explicit)` where `explicit` is true if the path was
@@ -0,0 +1,304 @@
+//! `[notifications]`: webhooks, chat channels, and email.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::error::ConfigError;
+
+/// Event names webhooks can subscribe to, one per dashboard event type.
+pub const NOTIFICATION_EVENTS: &[&str] = &[
+    "action_performed",
+    "approval_queued",
+    "approval_updated",
+    "follower_update",
+    "runtime_status",
+    "tweet_discovered",
+    "action_skipped",
+    "content_scheduled",
+    "circuit_breaker_tripped",
+    "kill_switch_changed",
+    "draft_only_changed",
+    "post_failed",
+    "token_expiring",
+    "approval_sla_breached",
+    "error",
+];
+
+/// Outbound notifications sent by the API server.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct NotificationsConfig {
+    /// Webhooks that receive dashboard events as JSON `POST`s.
+    #[serde(default)]
+    pub webhooks: Vec<WebhookConfig>,
+
+    /// Chat and email channels given as notification URLs.
+    #[serde(default)]
+    pub channels: Vec<NotificationChannelConfig>,
+
+    /// Daily email digest, optionally with approve/reject links.
+    #[serde(default)]
+    pub email: EmailConfig,
+}
+
+/// One outbound webhook, e.g. a Zapier or Make catch hook.
+///
+/// Without a template, an event is sent as a flat JSON object of its fields
+/// plus `event`. A template maps output field names to strings in which
+/// `{{field}}` is replaced by the event's value; a value that is exactly one
+/// placeholder keeps the field's JSON type.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct WebhookConfig {
+    /// URL the payload is `POST`ed to.
+    pub url: String,
+
+    /// Events to send (names from [`NOTIFICATION_EVENTS`]). Empty sends all.
+    #[serde(default)]
+    pub events: Vec<String>,
+
+    /// Per-event payload templates: event name -> output field -> template.
+    #[serde(default)]
+    pub templates: HashMap<String, HashMap<String, String>>,
+}
+
+impl WebhookConfig {
+    /// Whether this webhook subscribes to `event`.
+    pub fn wants(&self, event: &str) -> bool {
+        self.events.is_empty() || self.events.iter().any(|e| e == event)
+    }
+}
+
+/// URL schemes accepted in `[[notifications.channels]]`.
+pub const NOTIFICATION_URL_SCHEMES: &[&str] = &["discord", "slack", "tgram", "mailto", "mailtos"];
+
+/// A notification channel given as an Apprise-style URL, e.g.
+/// `discord://<webhook_id>/<webhook_token>` or `tgram://<bot_token>/<chat_id>`.
+///
+/// Events are sent as short human-readable messages rather than JSON.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct NotificationChannelConfig {
+    /// Channel URL; the scheme selects the service.
+    pub url: String,
+
+    /// Events to send (names from [`NOTIFICATION_EVENTS`]). Empty sends all.
+    #[serde(default)]
+    pub events: Vec<String>,
+
+    /// Extra attempts after a failed delivery, with exponential backoff.
+    #[serde(default = "default_channel_retries")]
+    pub retries: u32,
+}
+
+impl NotificationChannelConfig {
+    /// Whether this channel subscribes to `event`.
+    pub fn wants(&self, event: &str) -> bool {
+        self.events.is_empty() || self.events.iter().any(|e| e == event)
+    }
+
+    /// The URL scheme, e.g. `discord`.
+    pub fn scheme(&self) -> &str {
+        self.url
+            .trim()
+            .split_once("://")
+            .map(|(scheme, _)| scheme)
+            .unwrap_or_default()
+    }
+}
+
+fn default_channel_retries() -> u32 {
+    2
+}
+
+/// Daily email digest of activity and pending approvals, sent over SMTP.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct EmailConfig {
+    /// Send the daily digest.
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// SMTP server host name.
+    #[serde(default)]
+    pub smtp_host: String,
+
+    /// SMTP server port.
+    #[serde(default = "default_smtp_port")]
+    pub smtp_port: u16,
+
+    /// Connection security: `starttls`, `tls` (implicit, usually port 465),
+    /// or `none` (local relays only).
+    #[serde(default = "default_smtp_security")]
+    pub smtp_security: String,
+
+    /// SMTP login; empty sends without authentication.
+    #[serde(default)]
+    pub smtp_username: String,
+
+    /// SMTP password (or `TUITBOT_NOTIFICATIONS__EMAIL__SMTP_PASSWORD`).
+    #[serde(default)]
+    pub smtp_password: Option<String>,
+
+    /// Sender address, e.g. `Tuitbot <bot@example.com>`.
+    #[serde(default)]
+    pub from: String,
+
+    /// Recipient addresses.
+    #[serde(default)]
+    pub to: Vec<String>,
+
+    /// Hour of day (0-23, in `schedule.timezone`) the digest is sent.
+    #[serde(default = "default_digest_hour")]
+    pub digest_hour: u8,
+
+    /// Most pending approvals listed in one digest.
+    #[serde(default = "default_digest_max_items")]
+    pub max_items: u32,
+
+    /// Add signed approve/reject links to each pending item.
+    #[serde(default)]
+    pub approval_links: bool,
+
+    /// Base URL of the API server as reachable from the inbox, e.g.
+    /// `https://tuitbot.example.com`. Required for approval links.
+    #[serde(default)]
+    pub public_url: String,
+
+    /// Hours an approval link stays valid.
+    #[serde(default = "default_link_ttl_hours")]
+    pub link_ttl_hours: u32,
+}
+
+impl Default for EmailConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            smtp_host: String::new(),
+            smtp_port: default_smtp_port(),
+            smtp_security: default_smtp_security(),
+            smtp_username: String::new(),
+            smtp_password: None,
+            from: String::new(),
+            to: Vec::new(),
+            digest_hour: default_digest_hour(),
+            max_items: default_digest_max_items(),
+            approval_links: false,
+            public_url: String::new(),
+            link_ttl_hours: default_link_ttl_hours(),
+        }
+    }
+}
+
+fn default_smtp_port() -> u16 {
+    587
+}
+fn default_smtp_security() -> String {
+    "starttls".to_string()
+}
+fn default_digest_hour() -> u8 {
+    8
+}
+fn default_digest_max_items() -> u32 {
+    20
+}
+fn default_link_ttl_hours() -> u32 {
+    48
+}
+
+impl NotificationsConfig {
+    /// Check webhook URLs, channel schemes, event names, and email settings.
+    pub(super) fn validate(&self, errors: &mut Vec<ConfigError>) {
+        for (i, hook) in self.webhooks.iter().enumerate() {
+            let url = hook.url.trim();
+            if !(url.starts_with("https://") || url.starts_with("http://")) {
+                errors.push(ConfigError::InvalidValue {
+                    field: format!("notifications.webhooks[{i}].url"),
+                    message: "must be an http:// or https:// URL".to_string(),
+                });
+            }
+            let unknown = hook
+                .events
+                .iter()
+                .chain(hook.templates.keys())
+                .filter(|e| !NOTIFICATION_EVENTS.contains(&e.as_str()));
+            for event in unknown {
+                errors.push(ConfigError::InvalidValue {
+                    field: format!("notifications.webhooks[{i}]"),
+                    message: format!(
+                        "unknown event '{event}', expected one of: {}",
+                        NOTIFICATION_EVENTS.join(", ")
+                    ),
+                });
+            }
+        }
+
+        for (i, channel) in self.channels.iter().enumerate() {
+            if !NOTIFICATION_URL_SCHEMES.contains(&channel.scheme()) {
+                errors.push(ConfigError::InvalidValue {
+                    field: format!("notifications.channels[{i}].url"),
+                    message: format!(
+                        "must be a URL with one of these schemes: {}",
+                        NOTIFICATION_URL_SCHEMES.join(", ")
+                    ),
+                });
+            }
+            for event in channel
+                .events
+                .iter()
+                .filter(|e| !NOTIFICATION_EVENTS.contains(&e.as_str()))
+            {
+                errors.push(ConfigError::InvalidValue {
+                    field: format!("notifications.channels[{i}]"),
+                    message: format!(
+                        "unknown event '{event}', expected one of: {}",
+                        NOTIFICATION_EVENTS.join(", ")
+                    ),
+                });
+            }
+        }
+
+        // Validate email notifications
+        let email = &self.email;
+        if email.enabled {
+            for (field, is_missing) in [
+                (
+                    "notifications.email.smtp_host",
+                    email.smtp_host.trim().is_empty(),
+                ),
+                ("notifications.email.from", email.from.trim().is_empty()),
+                ("notifications.email.to", email.to.is_empty()),
+            ] {
+                if is_missing {
+                    errors.push(ConfigError::MissingField {
+                        field: field.to_string(),
+                    });
+                }
+            }
+            if !["starttls", "tls", "none"].contains(&email.smtp_security.as_str()) {
+                errors.push(ConfigError::InvalidValue {
+                    field: "notifications.email.smtp_security".to_string(),
+                    message: "must be starttls, tls, or none".to_string(),
+                });
+            }
+            if email.digest_hour > 23 {
+                errors.push(ConfigError::InvalidValue {
+                    field: "notifications.email.digest_hour".to_string(),
+                    message: "must be between 0 and 23".to_string(),
+                });
+            }
+            if email.approval_links {
+                let url = email.public_url.trim();
+                if !(url.starts_with("https://") || url.starts_with("http://")) {
+                    errors.push(ConfigError::InvalidValue {
+                        field: "notifications.email.public_url".to_string(),
+                        message: "must be an http:// or https:// URL when approval_links is on"
+                            .to_string(),
+                    });
+                }
+                if email.link_ttl_hours == 0 {
+                    errors.push(ConfigError::InvalidValue {
+                        field: "notifications.email.link_ttl_hours".to_string(),
+                        message: "must be greater than 0".to_string(),
+                    });
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,60 @@
+//! `[pacing]`: daily budgets that spread API reads across the day.
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::ConfigError;
+
+/// Spreads per-endpoint daily X API budgets across the active-hours window.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PacingConfig {
+    /// Enable request pacing for discovery search and target timeline reads.
+    #[serde(default = "default_pacing_enabled")]
+    pub enabled: bool,
+
+    /// Daily budget of tweet search requests.
+    #[serde(default = "default_pacing_search_per_day")]
+    pub search_per_day: u32,
+
+    /// Daily budget of user timeline requests (target monitoring).
+    #[serde(default = "default_pacing_user_tweets_per_day")]
+    pub user_tweets_per_day: u32,
+}
+
+impl Default for PacingConfig {
+    fn default() -> Self {
+        Self {
+            enabled: default_pacing_enabled(),
+            search_per_day: default_pacing_search_per_day(),
+            user_tweets_per_day: default_pacing_user_tweets_per_day(),
+        }
+    }
+}
+
+fn default_pacing_enabled() -> bool {
+    true
+}
+fn default_pacing_search_per_day() -> u32 {
+    200
+}
+fn default_pacing_user_tweets_per_day() -> u32 {
+    150
+}
+
+impl PacingConfig {
+    /// Require non-zero budgets when pacing is enabled.
+    pub(super) fn validate(&self, errors: &mut Vec<ConfigError>) {
+        if self.enabled {
+            for (field, budget) in [
+                ("pacing.search_per_day", self.search_per_day),
+                ("pacing.user_tweets_per_day", self.user_tweets_per_day),
+            ] {
+                if budget == 0 {
+                    errors.push(ConfigError::InvalidValue {
+                        field: field.to_string(),
+                        message: "must be greater than 0 when pacing is enabled".to_string(),
+                    });
+                }
+            }
+        }
+    }
+}
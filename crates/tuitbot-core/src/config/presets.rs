@@ -0,0 +1,172 @@
+//! Named policy presets.
+//!
+//! A preset bundles rate limits, the scoring threshold, approval mode, and
+//! loop cadence into a starting point. Applying one overwrites only those
+//! settings; everything can still be tweaked individually afterwards.
+
+use std::fmt;
+use std::str::FromStr;
+
+use serde::Serialize;
+
+use super::Config;
+
+/// A named bundle of safety and cadence settings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PolicyPreset {
+    /// Low volume, high bar, every post reviewed.
+    Conservative,
+    /// The built-in defaults.
+    Balanced,
+    /// High volume, lower bar, posts go out without review.
+    Aggressive,
+}
+
+/// Values a preset sets.
+struct PresetValues {
+    max_replies_per_day: u32,
+    max_tweets_per_day: u32,
+    max_threads_per_week: u32,
+    max_likes_per_day: u32,
+    max_follows_per_day: u32,
+    min_action_delay_seconds: u64,
+    max_action_delay_seconds: u64,
+    max_replies_per_author_per_day: u32,
+    product_mention_ratio: f32,
+    scoring_threshold: u32,
+    approval_mode: bool,
+    mentions_check_seconds: u64,
+    discovery_search_seconds: u64,
+    content_post_window_seconds: u64,
+    thread_interval_seconds: u64,
+    max_target_replies_per_day: u32,
+}
+
+impl PolicyPreset {
+    /// All presets, from most to least cautious.
+    pub const ALL: [PolicyPreset; 3] = [
+        PolicyPreset::Conservative,
+        PolicyPreset::Balanced,
+        PolicyPreset::Aggressive,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            PolicyPreset::Conservative => "conservative",
+            PolicyPreset::Balanced => "balanced",
+            PolicyPreset::Aggressive => "aggressive",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            PolicyPreset::Conservative => {
+                "Few actions per day, long delays, high scoring bar, approval required"
+            }
+            PolicyPreset::Balanced => "The built-in defaults, with approval required",
+            PolicyPreset::Aggressive => {
+                "More replies and posts, shorter intervals, lower scoring bar, no approval queue"
+            }
+        }
+    }
+
+    fn values(&self) -> PresetValues {
+        match self {
+            PolicyPreset::Conservative => PresetValues {
+                max_replies_per_day: 3,
+                max_tweets_per_day: 2,
+                max_threads_per_week: 1,
+                max_likes_per_day: 20,
+                max_follows_per_day: 5,
+                min_action_delay_seconds: 90,
+                max_action_delay_seconds: 300,
+                max_replies_per_author_per_day: 1,
+                product_mention_ratio: 0.1,
+                scoring_threshold: 75,
+                approval_mode: true,
+                mentions_check_seconds: 600,
+                discovery_search_seconds: 1800,
+                content_post_window_seconds: 21600,
+                thread_interval_seconds: 604800,
+                max_target_replies_per_day: 1,
+            },
+            PolicyPreset::Balanced => PresetValues {
+                max_replies_per_day: 5,
+                max_tweets_per_day: 6,
+                max_threads_per_week: 1,
+                max_likes_per_day: 50,
+                max_follows_per_day: 20,
+                min_action_delay_seconds: 45,
+                max_action_delay_seconds: 180,
+                max_replies_per_author_per_day: 1,
+                product_mention_ratio: 0.2,
+                scoring_threshold: 60,
+                approval_mode: true,
+                mentions_check_seconds: 300,
+                discovery_search_seconds: 900,
+                content_post_window_seconds: 10800,
+                thread_interval_seconds: 604800,
+                max_target_replies_per_day: 3,
+            },
+            PolicyPreset::Aggressive => PresetValues {
+                max_replies_per_day: 15,
+                max_tweets_per_day: 10,
+                max_threads_per_week: 3,
+                max_likes_per_day: 100,
+                max_follows_per_day: 40,
+                min_action_delay_seconds: 20,
+                max_action_delay_seconds: 90,
+                max_replies_per_author_per_day: 2,
+                product_mention_ratio: 0.3,
+                scoring_threshold: 45,
+                approval_mode: false,
+                mentions_check_seconds: 120,
+                discovery_search_seconds: 600,
+                content_post_window_seconds: 7200,
+                thread_interval_seconds: 259200,
+                max_target_replies_per_day: 5,
+            },
+        }
+    }
+
+    /// Overwrite the settings this preset covers.
+    pub fn apply(&self, config: &mut Config) {
+        let v = self.values();
+        config.limits.max_replies_per_day = v.max_replies_per_day;
+        config.limits.max_tweets_per_day = v.max_tweets_per_day;
+        config.limits.max_threads_per_week = v.max_threads_per_week;
+        config.limits.max_likes_per_day = v.max_likes_per_day;
+        config.limits.max_follows_per_day = v.max_follows_per_day;
+        config.limits.min_action_delay_seconds = v.min_action_delay_seconds;
+        config.limits.max_action_delay_seconds = v.max_action_delay_seconds;
+        config.limits.max_replies_per_author_per_day = v.max_replies_per_author_per_day;
+        config.limits.product_mention_ratio = v.product_mention_ratio;
+        config.scoring.threshold = v.scoring_threshold;
+        config.approval_mode = v.approval_mode;
+        config.intervals.mentions_check_seconds = v.mentions_check_seconds;
+        config.intervals.discovery_search_seconds = v.discovery_search_seconds;
+        config.intervals.content_post_window_seconds = v.content_post_window_seconds;
+        config.intervals.thread_interval_seconds = v.thread_interval_seconds;
+        config.targets.max_target_replies_per_day = v.max_target_replies_per_day;
+    }
+}
+
+impl fmt::Display for PolicyPreset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for PolicyPreset {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        PolicyPreset::ALL
+            .into_iter()
+            .find(|preset| preset.name().eq_ignore_ascii_case(s.trim()))
+            .ok_or_else(|| {
+                format!("unknown preset '{s}' (expected conservative, balanced, or aggressive)")
+            })
+    }
+}
@@ -0,0 +1,78 @@
+//! `[quote_tweets]`: when discovery quotes a tweet instead of replying.
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::ConfigError;
+
+/// Decision rule routing discovered tweets to a quote tweet instead of a reply.
+///
+/// A candidate is quoted when it scores at least `min_score`, its author has
+/// at least `min_author_followers`, and it matches at least
+/// `min_keyword_matches` of our keywords.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct QuoteTweetConfig {
+    /// Enable quote-tweet routing in the discovery loop.
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Minimum total score (0-100) for a quote.
+    #[serde(default = "default_quote_min_score")]
+    pub min_score: f32,
+
+    /// Minimum author follower count for a quote.
+    #[serde(default = "default_quote_min_author_followers")]
+    pub min_author_followers: u64,
+
+    /// Minimum number of our keywords the tweet must match.
+    #[serde(default = "default_quote_min_keyword_matches")]
+    pub min_keyword_matches: usize,
+}
+
+impl QuoteTweetConfig {
+    /// Whether a candidate should be quoted rather than replied to.
+    pub fn should_quote(&self, score: f32, author_followers: u64, keyword_matches: usize) -> bool {
+        self.enabled
+            && score >= self.min_score
+            && author_followers >= self.min_author_followers
+            && keyword_matches >= self.min_keyword_matches
+    }
+}
+
+impl Default for QuoteTweetConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            min_score: default_quote_min_score(),
+            min_author_followers: default_quote_min_author_followers(),
+            min_keyword_matches: default_quote_min_keyword_matches(),
+        }
+    }
+}
+
+fn default_quote_min_score() -> f32 {
+    75.0
+}
+fn default_quote_min_author_followers() -> u64 {
+    10_000
+}
+fn default_quote_min_keyword_matches() -> usize {
+    2
+}
+
+impl QuoteTweetConfig {
+    /// Check the quote-tweet score and keyword thresholds.
+    pub(super) fn validate(&self, errors: &mut Vec<ConfigError>) {
+        if !(0.0..=100.0).contains(&self.min_score) {
+            errors.push(ConfigError::InvalidValue {
+                field: "quote_tweets.min_score".to_string(),
+                message: "must be between 0 and 100".to_string(),
+            });
+        }
+        if self.min_keyword_matches == 0 {
+            errors.push(ConfigError::InvalidValue {
+                field: "quote_tweets.min_keyword_matches".to_string(),
+                message: "must be at least 1".to_string(),
+            });
+        }
+    }
+}
@@ -0,0 +1,106 @@
+//! `[reciprocity]`: handling of authors who never engage back.
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::ConfigError;
+
+/// What to do with tweets from authors who rarely engage back.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReciprocityPolicy {
+    /// Do not check.
+    #[default]
+    Off,
+    /// Subtract `score_penalty` from the tweet's score.
+    Deprioritize,
+    /// Skip the tweet.
+    Skip,
+}
+
+/// Reciprocity guard for discovered tweets.
+///
+/// An author is one-sided when we replied to them at least `min_outbound`
+/// times in the last `window_days`, and their mentions and replies to us
+/// number less than `min_ratio` of that.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ReciprocityConfig {
+    /// Policy for one-sided authors.
+    #[serde(default)]
+    pub policy: ReciprocityPolicy,
+
+    /// Days of engagement history to compare.
+    #[serde(default = "default_reciprocity_window_days")]
+    pub window_days: u32,
+
+    /// Replies we must have sent before an author is judged.
+    #[serde(default = "default_reciprocity_min_outbound")]
+    pub min_outbound: u32,
+
+    /// Inbound-to-outbound ratio below which an author is one-sided.
+    #[serde(default = "default_reciprocity_min_ratio")]
+    pub min_ratio: f64,
+
+    /// Points subtracted from the score under `deprioritize`.
+    #[serde(default = "default_reciprocity_score_penalty")]
+    pub score_penalty: f32,
+}
+
+impl Default for ReciprocityConfig {
+    fn default() -> Self {
+        Self {
+            policy: ReciprocityPolicy::Off,
+            window_days: default_reciprocity_window_days(),
+            min_outbound: default_reciprocity_min_outbound(),
+            min_ratio: default_reciprocity_min_ratio(),
+            score_penalty: default_reciprocity_score_penalty(),
+        }
+    }
+}
+
+fn default_reciprocity_window_days() -> u32 {
+    30
+}
+
+fn default_reciprocity_min_outbound() -> u32 {
+    3
+}
+
+fn default_reciprocity_min_ratio() -> f64 {
+    0.1
+}
+
+fn default_reciprocity_score_penalty() -> f32 {
+    20.0
+}
+
+impl ReciprocityConfig {
+    /// Check the window, minimums, and penalty unless the policy is off.
+    pub(super) fn validate(&self, errors: &mut Vec<ConfigError>) {
+        if self.policy != ReciprocityPolicy::Off {
+            if self.window_days == 0 {
+                errors.push(ConfigError::InvalidValue {
+                    field: "reciprocity.window_days".to_string(),
+                    message: "must be at least 1".to_string(),
+                });
+            }
+            if self.min_outbound == 0 {
+                errors.push(ConfigError::InvalidValue {
+                    field: "reciprocity.min_outbound".to_string(),
+                    message: "must be at least 1".to_string(),
+                });
+            }
+            if !(self.min_ratio > 0.0 && self.min_ratio <= 1.0) {
+                errors.push(ConfigError::InvalidValue {
+                    field: "reciprocity.min_ratio".to_string(),
+                    message: "must be greater than 0 and at most 1.0".to_string(),
+                });
+            }
+            if !(0.0..=100.0).contains(&self.score_penalty) {
+                errors.push(ConfigError::InvalidValue {
+                    field: "reciprocity.score_penalty".to_string(),
+                    message: "must be between 0 and 100".to_string(),
+                });
+            }
+        }
+    }
+}
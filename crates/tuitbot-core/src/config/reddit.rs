@@ -0,0 +1,134 @@
+//! `[reddit]`: Reddit credentials, subreddits, and comment limits.
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::ConfigError;
+
+/// Reddit monitoring via a "script" app (password-grant OAuth).
+///
+/// Posts in `subreddits` matching the business keywords are scored with the
+/// same engine as tweets. Suggested comments always go to the approval queue
+/// tagged `reddit`; approved ones are posted by a separate Reddit poster.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct RedditConfig {
+    /// Monitor Reddit and queue suggested comments.
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Client ID of the Reddit script app.
+    #[serde(default)]
+    pub client_id: String,
+
+    /// Client secret of the Reddit script app.
+    #[serde(default)]
+    pub client_secret: Option<String>,
+
+    /// Reddit account username the app belongs to.
+    #[serde(default)]
+    pub username: String,
+
+    /// Reddit account password.
+    #[serde(default)]
+    pub password: Option<String>,
+
+    /// User-Agent sent with every request, as Reddit's API rules require.
+    #[serde(default = "default_reddit_user_agent")]
+    pub user_agent: String,
+
+    /// Subreddits to monitor, without the `r/` prefix.
+    #[serde(default)]
+    pub subreddits: Vec<String>,
+
+    /// Maximum posts fetched per subreddit search.
+    #[serde(default = "default_reddit_max_results")]
+    pub max_results: u32,
+
+    /// Maximum comments posted per day.
+    #[serde(default = "default_reddit_max_comments_per_day")]
+    pub max_comments_per_day: u32,
+
+    /// Seconds between subreddit checks.
+    #[serde(default = "default_reddit_check_seconds")]
+    pub check_interval_seconds: u64,
+}
+
+impl Default for RedditConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            client_id: String::new(),
+            client_secret: None,
+            username: String::new(),
+            password: None,
+            user_agent: default_reddit_user_agent(),
+            subreddits: Vec::new(),
+            max_results: default_reddit_max_results(),
+            max_comments_per_day: default_reddit_max_comments_per_day(),
+            check_interval_seconds: default_reddit_check_seconds(),
+        }
+    }
+}
+
+fn default_reddit_user_agent() -> String {
+    format!("tuitbot/{}", env!("CARGO_PKG_VERSION"))
+}
+fn default_reddit_max_results() -> u32 {
+    25
+}
+fn default_reddit_max_comments_per_day() -> u32 {
+    3
+}
+fn default_reddit_check_seconds() -> u64 {
+    1800
+}
+
+impl RedditConfig {
+    /// Check credentials, subreddits, and limits when enabled.
+    pub(super) fn validate(&self, errors: &mut Vec<ConfigError>) {
+        if self.enabled {
+            let missing = [
+                ("reddit.client_id", self.client_id.trim().is_empty()),
+                (
+                    "reddit.client_secret",
+                    self.client_secret.as_deref().unwrap_or("").is_empty(),
+                ),
+                ("reddit.username", self.username.trim().is_empty()),
+                (
+                    "reddit.password",
+                    self.password.as_deref().unwrap_or("").is_empty(),
+                ),
+            ];
+            for (field, is_missing) in missing {
+                if is_missing {
+                    errors.push(ConfigError::MissingField {
+                        field: field.to_string(),
+                    });
+                }
+            }
+            if self.subreddits.iter().all(|s| s.trim().is_empty()) {
+                errors.push(ConfigError::InvalidValue {
+                    field: "reddit.subreddits".to_string(),
+                    message: "must list at least one subreddit when reddit is enabled".to_string(),
+                });
+            }
+            if self.max_results == 0 || self.max_results > 100 {
+                errors.push(ConfigError::InvalidValue {
+                    field: "reddit.max_results".to_string(),
+                    message: "must be between 1 and 100".to_string(),
+                });
+            }
+            if self.max_comments_per_day == 0 {
+                errors.push(ConfigError::InvalidValue {
+                    field: "reddit.max_comments_per_day".to_string(),
+                    message: "must be greater than 0".to_string(),
+                });
+            }
+            if self.check_interval_seconds < 60 {
+                errors.push(ConfigError::InvalidValue {
+                    field: "reddit.check_interval_seconds".to_string(),
+                    message: "must be at least 60".to_string(),
+                });
+            }
+        }
+    }
+}
@@ -0,0 +1,127 @@
+//! `[remote_backup]`: scheduled uploads of backups to S3-compatible storage.
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::ConfigError;
+
+/// Scheduled database snapshots uploaded to an S3-compatible bucket
+/// (AWS S3, Cloudflare R2, Backblaze B2, MinIO).
+///
+/// Requests are path-style (`{endpoint}/{bucket}/{key}`) and signed with
+/// AWS Signature V4.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct RemoteBackupConfig {
+    /// Upload snapshots on a schedule while the agent runs.
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Service endpoint, e.g. `https://<account>.r2.cloudflarestorage.com`.
+    /// Empty means AWS S3 in `region`.
+    #[serde(default)]
+    pub endpoint: String,
+
+    /// Signing region (`auto` for R2).
+    #[serde(default = "default_remote_backup_region")]
+    pub region: String,
+
+    /// Bucket that receives the snapshots.
+    #[serde(default)]
+    pub bucket: String,
+
+    /// Key prefix for snapshots within the bucket.
+    #[serde(default = "default_remote_backup_prefix")]
+    pub prefix: String,
+
+    /// Access key ID.
+    #[serde(default)]
+    pub access_key_id: String,
+
+    /// Secret access key.
+    #[serde(default)]
+    pub secret_access_key: Option<String>,
+
+    /// Hours between uploads.
+    #[serde(default = "default_remote_backup_interval_hours")]
+    pub interval_hours: u64,
+
+    /// Remote snapshots to keep; older ones are deleted after each upload.
+    #[serde(default = "default_remote_backup_keep")]
+    pub keep: u32,
+}
+
+impl Default for RemoteBackupConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            endpoint: String::new(),
+            region: default_remote_backup_region(),
+            bucket: String::new(),
+            prefix: default_remote_backup_prefix(),
+            access_key_id: String::new(),
+            secret_access_key: None,
+            interval_hours: default_remote_backup_interval_hours(),
+            keep: default_remote_backup_keep(),
+        }
+    }
+}
+
+fn default_remote_backup_region() -> String {
+    "us-east-1".to_string()
+}
+fn default_remote_backup_prefix() -> String {
+    "tuitbot/".to_string()
+}
+fn default_remote_backup_interval_hours() -> u64 {
+    24
+}
+fn default_remote_backup_keep() -> u32 {
+    7
+}
+
+impl RemoteBackupConfig {
+    /// Check credentials, endpoint, and schedule when enabled.
+    pub(super) fn validate(&self, errors: &mut Vec<ConfigError>) {
+        if self.enabled {
+            let missing = [
+                ("remote_backup.bucket", self.bucket.trim().is_empty()),
+                (
+                    "remote_backup.access_key_id",
+                    self.access_key_id.trim().is_empty(),
+                ),
+                (
+                    "remote_backup.secret_access_key",
+                    self.secret_access_key.as_deref().unwrap_or("").is_empty(),
+                ),
+            ];
+            for (field, is_missing) in missing {
+                if is_missing {
+                    errors.push(ConfigError::MissingField {
+                        field: field.to_string(),
+                    });
+                }
+            }
+            let endpoint = self.endpoint.trim();
+            if !endpoint.is_empty()
+                && !endpoint.starts_with("https://")
+                && !endpoint.starts_with("http://")
+            {
+                errors.push(ConfigError::InvalidValue {
+                    field: "remote_backup.endpoint".to_string(),
+                    message: "must be an http:// or https:// URL".to_string(),
+                });
+            }
+            if self.interval_hours == 0 {
+                errors.push(ConfigError::InvalidValue {
+                    field: "remote_backup.interval_hours".to_string(),
+                    message: "must be greater than 0".to_string(),
+                });
+            }
+            if self.keep == 0 {
+                errors.push(ConfigError::InvalidValue {
+                    field: "remote_backup.keep".to_string(),
+                    message: "must be greater than 0".to_string(),
+                });
+            }
+        }
+    }
+}
@@ -0,0 +1,47 @@
+//! `[safe_mode]`: starting in draft-only mode after repeated failures.
+
+use serde::{Deserialize, Serialize};
+
+use super::types_policy::default_true;
+use crate::error::ConfigError;
+
+/// Safe-mode startup configuration.
+///
+/// When the previous `tuitbot run` session crashed or ended on a streak of
+/// posting failures, the next one starts with mutations halted and only
+/// read loops running until `tuitbot resume` (or the API) lifts it.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct SafeModeConfig {
+    /// Start in safe mode after a crash or failure streak.
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+
+    /// Consecutive posting failures that trigger safe mode at next startup.
+    #[serde(default = "default_safe_mode_failure_threshold")]
+    pub failure_threshold: u32,
+}
+
+impl Default for SafeModeConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            failure_threshold: default_safe_mode_failure_threshold(),
+        }
+    }
+}
+
+fn default_safe_mode_failure_threshold() -> u32 {
+    5
+}
+
+impl SafeModeConfig {
+    /// Require a positive failure threshold when enabled.
+    pub(super) fn validate(&self, errors: &mut Vec<ConfigError>) {
+        if self.enabled && self.failure_threshold == 0 {
+            errors.push(ConfigError::InvalidValue {
+                field: "safe_mode.failure_threshold".to_string(),
+                message: "must be at least 1".to_string(),
+            });
+        }
+    }
+}
@@ -151,6 +151,21 @@ fn validate_invalid_llm_provider() {
         .any(|e| matches!(e, ConfigError::InvalidValue { field, .. } if field == "llm.provider")));
 }
 
+#[test]
+fn llm_generation_concurrency_defaults_by_provider() {
+    let mut llm = LlmConfig {
+        provider: "ollama".to_string(),
+        ..Default::default()
+    };
+    assert_eq!(llm.generation_concurrency(), 1);
+    llm.provider = "anthropic".to_string();
+    assert_eq!(llm.generation_concurrency(), 4);
+    llm.max_concurrency = Some(0);
+    assert_eq!(llm.generation_concurrency(), 1);
+    llm.max_concurrency = Some(8);
+    assert_eq!(llm.generation_concurrency(), 8);
+}
+
 #[test]
 fn validate_threshold_over_100() {
     let mut config = Config::default();
@@ -165,70 +180,925 @@ fn validate_threshold_over_100() {
 }
 
 #[test]
-fn validate_threshold_boundary_values() {
-    let mut config = Config::default();
-    config.business.product_name = "Test".to_string();
-    config.business.product_keywords = vec!["test".to_string()];
-    config.llm.provider = "ollama".to_string();
+fn validate_threshold_boundary_values() {
+    let mut config = Config::default();
+    config.business.product_name = "Test".to_string();
+    config.business.product_keywords = vec!["test".to_string()];
+    config.llm.provider = "ollama".to_string();
+
+    config.scoring.threshold = 0;
+    assert!(config.validate().is_ok());
+
+    config.scoring.threshold = 100;
+    assert!(config.validate().is_ok());
+}
+
+#[test]
+fn validate_returns_multiple_errors() {
+    let mut config = Config::default();
+    // Missing product_name (default is empty)
+    // Missing keywords (default is empty)
+    config.llm.provider = "invalid".to_string();
+    config.scoring.threshold = 101;
+    config.limits.max_replies_per_day = 0;
+
+    let errors = config.validate().unwrap_err();
+    assert!(
+        errors.len() >= 4,
+        "expected at least 4 errors, got {}: {:?}",
+        errors.len(),
+        errors
+    );
+}
+
+#[test]
+fn validate_valid_config_passes() {
+    let mut config = Config::default();
+    config.business.product_name = "TestProduct".to_string();
+    config.business.product_keywords = vec!["test".to_string()];
+    config.llm.provider = "ollama".to_string();
+    config.llm.model = "llama2".to_string();
+    assert!(config.validate().is_ok());
+}
+
+#[test]
+fn validate_openai_requires_api_key() {
+    let mut config = Config::default();
+    config.business.product_name = "Test".to_string();
+    config.business.product_keywords = vec!["test".to_string()];
+    config.llm.provider = "openai".to_string();
+    config.llm.api_key = None;
+    let errors = config.validate().unwrap_err();
+    assert!(errors.iter().any(
+        |e| matches!(e, ConfigError::MissingField { field } if field.contains("llm.api_key"))
+    ));
+}
+
+#[test]
+fn validate_delay_ordering() {
+    let mut config = Config::default();
+    config.business.product_name = "Test".to_string();
+    config.business.product_keywords = vec!["test".to_string()];
+    config.llm.provider = "ollama".to_string();
+    config.limits.min_action_delay_seconds = 200;
+    config.limits.max_action_delay_seconds = 100;
+    let errors = config.validate().unwrap_err();
+    assert!(errors.iter().any(|e| matches!(e, ConfigError::InvalidValue { field, .. } if field == "limits.min_action_delay_seconds")));
+}
+
+#[test]
+fn validate_media_limits() {
+    let mut config = Config::default();
+    config.business.product_name = "Test".to_string();
+    config.business.product_keywords = vec!["test".to_string()];
+    config.llm.provider = "ollama".to_string();
+    config.media.max_video_duration_seconds = 0;
+    config.media.max_video_size_mb = 1024;
+    let errors = config.validate().unwrap_err();
+    assert!(errors.iter().any(|e| matches!(e, ConfigError::InvalidValue { field, .. } if field == "media.max_video_duration_seconds")));
+    assert!(errors.iter().any(|e| matches!(e, ConfigError::InvalidValue { field, .. } if field == "media.max_video_size_mb")));
+}
+
+#[test]
+fn validate_health_monitor_ranges() {
+    let mut config = Config::default();
+    config.business.product_name = "Test".to_string();
+    config.business.product_keywords = vec!["test".to_string()];
+    config.llm.provider = "ollama".to_string();
+    config.health_monitor.min_visible_ratio = 1.5;
+    config.health_monitor.slowdown_factor = 0.5;
+    config.health_monitor.check_interval_seconds = 60;
+    let errors = config.validate().unwrap_err();
+    for field in [
+        "health_monitor.min_visible_ratio",
+        "health_monitor.slowdown_factor",
+        "health_monitor.check_interval_seconds",
+    ] {
+        assert!(
+            errors
+                .iter()
+                .any(|e| matches!(e, ConfigError::InvalidValue { field: f, .. } if f == field)),
+            "missing error for {field}"
+        );
+    }
+}
+
+#[test]
+fn validate_threshold_calibration() {
+    let mut config = Config::default();
+    config.business.product_name = "Test".to_string();
+    config.business.product_keywords = vec!["test".to_string()];
+    config.llm.provider = "ollama".to_string();
+    config.scoring.calibration.floor = 90;
+    config.scoring.calibration.ceiling = 80;
+    assert!(config.validate().is_ok(), "ignored while disabled");
+
+    config.scoring.calibration.enabled = true;
+    config.scoring.calibration.step = 0;
+    let errors = config.validate().unwrap_err();
+    for field in ["scoring.calibration.floor", "scoring.calibration.step"] {
+        assert!(
+            errors
+                .iter()
+                .any(|e| matches!(e, ConfigError::InvalidValue { field: f, .. } if f == field)),
+            "missing error for {field}"
+        );
+    }
+}
+
+#[test]
+fn validate_pacing_budgets() {
+    let mut config = Config::default();
+    config.business.product_name = "Test".to_string();
+    config.business.product_keywords = vec!["test".to_string()];
+    config.llm.provider = "ollama".to_string();
+    assert!(config.pacing.enabled);
+    config.pacing.search_per_day = 0;
+    let errors = config.validate().unwrap_err();
+    assert!(errors.iter().any(
+        |e| matches!(e, ConfigError::InvalidValue { field, .. } if field == "pacing.search_per_day")
+    ));
+
+    config.pacing.enabled = false;
+    assert!(config.validate().is_ok());
+}
+
+#[test]
+fn validate_usage_reconciliation() {
+    let mut config = Config::default();
+    config.business.product_name = "Test".to_string();
+    config.business.product_keywords = vec!["test".to_string()];
+    config.llm.provider = "ollama".to_string();
+    assert!(!config.usage_reconciliation.enabled);
+    config.usage_reconciliation.min_pacing_scale = 0.0;
+    assert!(config.validate().is_ok(), "ignored while disabled");
+
+    config.usage_reconciliation.enabled = true;
+    config.usage_reconciliation.lookback_days = 0;
+    let errors = config.validate().unwrap_err();
+    for field in [
+        "usage_reconciliation.min_pacing_scale",
+        "usage_reconciliation.lookback_days",
+    ] {
+        assert!(
+            errors
+                .iter()
+                .any(|e| matches!(e, ConfigError::InvalidValue { field: f, .. } if f == field)),
+            "missing error for {field}"
+        );
+    }
+}
+
+#[test]
+fn validate_trusted_proxies() {
+    let mut config = Config::default();
+    config.business.product_name = "Test".to_string();
+    config.business.product_keywords = vec!["test".to_string()];
+    config.llm.provider = "ollama".to_string();
+    config.server.rate_limit.trusted_proxies = vec!["10.0.0.1".to_string(), "::1".to_string()];
+    assert!(config.validate().is_ok());
+
+    config
+        .server
+        .rate_limit
+        .trusted_proxies
+        .push("proxy.local".to_string());
+    let errors = config.validate().unwrap_err();
+    assert!(errors.iter().any(|e| matches!(
+        e,
+        ConfigError::InvalidValue { field, .. } if field == "server.rate_limit.trusted_proxies"
+    )));
+}
+
+#[test]
+fn validate_public_feed() {
+    let mut config = Config::default();
+    config.business.product_name = "Test".to_string();
+    config.business.product_keywords = vec!["test".to_string()];
+    config.llm.provider = "ollama".to_string();
+    assert!(!config.server.public_feed.enabled);
+    config.server.public_feed.fields = vec!["score".to_string()];
+    assert!(config.validate().is_ok(), "ignored while disabled");
+
+    config.server.public_feed.enabled = true;
+    config.server.public_feed.limit = 0;
+    let errors = config.validate().unwrap_err();
+    for field in ["server.public_feed.fields", "server.public_feed.limit"] {
+        assert!(
+            errors
+                .iter()
+                .any(|e| matches!(e, ConfigError::InvalidValue { field: f, .. } if f == field)),
+            "missing error for {field}"
+        );
+    }
+}
+
+#[test]
+fn quote_tweet_rule_requires_all_thresholds() {
+    let mut quotes = QuoteTweetConfig::default();
+    assert!(!quotes.should_quote(90.0, 50_000, 3), "disabled by default");
+
+    quotes.enabled = true;
+    assert!(quotes.should_quote(90.0, 50_000, 3));
+    assert!(!quotes.should_quote(60.0, 50_000, 3));
+    assert!(!quotes.should_quote(90.0, 500, 3));
+    assert!(!quotes.should_quote(90.0, 50_000, 1));
+
+    let mut config = Config::default();
+    config.business.product_name = "Test".to_string();
+    config.business.product_keywords = vec!["test".to_string()];
+    config.llm.provider = "ollama".to_string();
+    config.quote_tweets.min_score = 150.0;
+    config.quote_tweets.min_keyword_matches = 0;
+    let errors = config.validate().unwrap_err();
+    for field in ["quote_tweets.min_score", "quote_tweets.min_keyword_matches"] {
+        assert!(
+            errors
+                .iter()
+                .any(|e| matches!(e, ConfigError::InvalidValue { field: f, .. } if f == field)),
+            "missing error for {field}"
+        );
+    }
+}
+
+#[test]
+fn auto_triage_parses_and_validates() {
+    let config: Config = toml::from_str("").unwrap();
+    assert!(!config.auto_triage.enabled);
+    assert_eq!(config.auto_triage.action_types, vec!["reply".to_string()]);
+
+    let toml_str = r#"
+[auto_triage]
+enabled = true
+min_score = 90
+sensitive_accounts = ["@BigCorp"]
+sensitive_keywords = ["lawsuit"]
+"#;
+    let mut config: Config = toml::from_str(toml_str).unwrap();
+    let triage = &config.auto_triage;
+    assert!(triage.is_sensitive_account("bigcorp"));
+    assert!(!triage.is_sensitive_account("alice"));
+    assert_eq!(
+        triage.sensitive_keyword_in("Re: the LAWSUIT news"),
+        Some("lawsuit")
+    );
+    assert_eq!(triage.sensitive_keyword_in("nothing here"), None);
+
+    config.business.product_name = "Test".to_string();
+    config.business.product_keywords = vec!["test".to_string()];
+    config.llm.provider = "ollama".to_string();
+    config.auto_triage.max_per_day = 0;
+    let errors = config.validate().unwrap_err();
+    assert!(errors.iter().any(|e| matches!(
+        e,
+        ConfigError::InvalidValue { field, .. } if field == "auto_triage.max_per_day"
+    )));
+}
+
+#[test]
+fn approval_sla_parses_and_validates() {
+    let config: Config = toml::from_str("").unwrap();
+    assert!(!config.approval_sla.enabled);
+    assert_eq!(config.approval_sla.hours, 4);
+    assert!(config.approval_sla.active_hours_only);
+
+    let toml_str = r#"
+[approval_sla]
+enabled = true
+hours = 0
+active_hours_only = false
+"#;
+    let mut config: Config = toml::from_str(toml_str).unwrap();
+    assert!(!config.approval_sla.active_hours_only);
+    config.business.product_name = "Test".to_string();
+    config.business.product_keywords = vec!["test".to_string()];
+    config.llm.provider = "ollama".to_string();
+    let errors = config.validate().unwrap_err();
+    assert!(errors.iter().any(|e| matches!(
+        e,
+        ConfigError::InvalidValue { field, .. } if field == "approval_sla.hours"
+    )));
+}
+
+#[test]
+fn trusted_authors_parse_and_validate() {
+    let config: Config = toml::from_str("").unwrap();
+    assert!(config.trusted_authors.is_empty());
+
+    let toml_str = r#"
+[blocklist]
+protected = ["press"]
+
+[[trusted_authors]]
+username = "@my_alt"
+skip_approval = true
+
+[[trusted_authors]]
+username = "teammate"
+
+[[trusted_authors]]
+username = "Press"
+skip_soft_qa = true
+"#;
+    let mut config: Config = toml::from_str(toml_str).unwrap();
+    assert!(config.trusted_authors[0].skip_approval);
+    assert!(!config.trusted_authors[0].skip_soft_qa);
+    config.business.product_name = "Test".to_string();
+    config.business.product_keywords = vec!["test".to_string()];
+    config.llm.provider = "ollama".to_string();
+    let errors = config.validate().unwrap_err();
+    let messages: Vec<String> = errors
+        .iter()
+        .filter_map(|e| match e {
+            ConfigError::InvalidValue { field, message } if field == "trusted_authors" => {
+                Some(message.clone())
+            }
+            _ => None,
+        })
+        .collect();
+    assert_eq!(messages.len(), 2, "{messages:?}");
+    assert!(messages[0].contains("@teammate enables no bypass"));
+    assert!(messages[1].contains("protected"));
+}
+
+#[test]
+fn mention_ack_parses_and_validates() {
+    let config: Config = toml::from_str("").unwrap();
+    assert!(!config.mention_ack.enabled);
+    assert!(config.mention_ack.like);
+
+    let toml_str = r#"
+[mention_ack]
+enabled = true
+like = false
+reply = true
+templates = [""]
+min_confidence = 0.3
+max_per_hour = 20
+"#;
+    let mut config: Config = toml::from_str(toml_str).unwrap();
+    config.business.product_name = "Test".to_string();
+    config.business.product_keywords = vec!["test".to_string()];
+    config.llm.provider = "ollama".to_string();
+    let errors = config.validate().unwrap_err();
+    let fields: Vec<&str> = errors
+        .iter()
+        .filter_map(|e| match e {
+            ConfigError::InvalidValue { field, .. } if field.starts_with("mention_ack") => {
+                Some(field.as_str())
+            }
+            _ => None,
+        })
+        .collect();
+    assert_eq!(
+        fields,
+        vec![
+            "mention_ack.min_confidence",
+            "mention_ack.max_per_day",
+            "mention_ack.templates"
+        ]
+    );
+}
+
+#[test]
+fn context_check_parses_and_validates() {
+    let config: Config = toml::from_str("").unwrap();
+    assert_eq!(config.context_check.policy, ContextPolicy::Off);
+
+    let toml_str = r#"
+[context_check]
+policy = "require_approval"
+max_quote_ratio = 0.8
+"#;
+    let mut config: Config = toml::from_str(toml_str).unwrap();
+    assert_eq!(config.context_check.policy, ContextPolicy::RequireApproval);
+    assert_eq!(config.context_check.max_quote_ratio, 0.8);
+    assert_eq!(config.context_check.min_quotes, 10);
+
+    config.business.product_name = "Test".to_string();
+    config.business.product_keywords = vec!["test".to_string()];
+    config.llm.provider = "ollama".to_string();
+    config.context_check.author_window_days = 0;
+    let errors = config.validate().unwrap_err();
+    assert!(errors.iter().any(|e| matches!(
+        e,
+        ConfigError::InvalidValue { field, .. } if field == "context_check.author_window_days"
+    )));
+}
+
+#[test]
+fn freshness_window_validates_decay_before_cutoff() {
+    let mut config = Config::default();
+    assert_eq!(config.scoring.freshness.discovery_max_age_hours, 48);
+    config.business.product_name = "Test".to_string();
+    config.business.product_keywords = vec!["test".to_string()];
+    config.llm.provider = "ollama".to_string();
+    config.scoring.freshness.discovery_decay_after_hours = 48;
+    let errors = config.validate().unwrap_err();
+    assert!(errors.iter().any(|e| matches!(
+        e,
+        ConfigError::InvalidValue { field, .. }
+            if field == "scoring.freshness.discovery_decay_after_hours"
+    )));
+
+    config.scoring.freshness.discovery_max_age_hours = 0;
+    assert!(config.validate().is_ok());
+}
+
+#[test]
+fn author_overlap_parses_and_validates() {
+    let config: Config = toml::from_str("").unwrap();
+    assert!(!config.author_overlap.enabled);
+
+    let toml_str = r#"
+[author_overlap]
+enabled = true
+soft_threshold = 0.8
+hard_threshold = 0.6
+"#;
+    let mut config: Config = toml::from_str(toml_str).unwrap();
+    assert_eq!(config.author_overlap.recent_tweets, 10);
+    assert_eq!(config.author_overlap.cache_minutes, 60);
+
+    config.business.product_name = "Test".to_string();
+    config.business.product_keywords = vec!["test".to_string()];
+    config.llm.provider = "ollama".to_string();
+    let errors = config.validate().unwrap_err();
+    assert!(errors.iter().any(|e| matches!(
+        e,
+        ConfigError::InvalidValue { field, .. } if field == "author_overlap.soft_threshold"
+    )));
+
+    config.author_overlap.hard_threshold = 0.9;
+    assert!(config.validate().is_ok());
+}
+
+#[test]
+fn reciprocity_parses_and_validates() {
+    let config: Config = toml::from_str("").unwrap();
+    assert_eq!(config.reciprocity.policy, ReciprocityPolicy::Off);
+
+    let toml_str = r#"
+[reciprocity]
+policy = "skip"
+min_ratio = 0.0
+"#;
+    let mut config: Config = toml::from_str(toml_str).unwrap();
+    assert_eq!(config.reciprocity.window_days, 30);
+    assert_eq!(config.reciprocity.min_outbound, 3);
+
+    config.business.product_name = "Test".to_string();
+    config.business.product_keywords = vec!["test".to_string()];
+    config.llm.provider = "ollama".to_string();
+    let errors = config.validate().unwrap_err();
+    assert!(errors.iter().any(|e| matches!(
+        e,
+        ConfigError::InvalidValue { field, .. } if field == "reciprocity.min_ratio"
+    )));
+
+    config.reciprocity.min_ratio = 0.25;
+    assert!(config.validate().is_ok());
+}
+
+#[test]
+fn remote_backup_parses_and_validates() {
+    let toml_str = r#"
+[business]
+product_name = "Test"
+product_keywords = ["test"]
+
+[llm]
+provider = "ollama"
+
+[remote_backup]
+enabled = true
+endpoint = "s3.example.com"
+bucket = "backups"
+"#;
+    let mut config: Config = toml::from_str(toml_str).unwrap();
+    assert_eq!(config.remote_backup.region, "us-east-1");
+    assert_eq!(config.remote_backup.keep, 7);
+    let errors = config.validate().unwrap_err();
+    for field in [
+        "remote_backup.access_key_id",
+        "remote_backup.secret_access_key",
+    ] {
+        assert!(
+            errors
+                .iter()
+                .any(|e| matches!(e, ConfigError::MissingField { field: f } if f == field)),
+            "missing error for {field}"
+        );
+    }
+    assert!(errors.iter().any(
+        |e| matches!(e, ConfigError::InvalidValue { field, .. } if field == "remote_backup.endpoint")
+    ));
+
+    config.remote_backup.endpoint = "https://s3.example.com".to_string();
+    config.remote_backup.access_key_id = "AKID".to_string();
+    config.remote_backup.secret_access_key = Some("secret".to_string());
+    assert!(config.validate().is_ok());
+}
+
+#[test]
+fn hooks_parse_and_validate() {
+    let toml_str = r#"
+[business]
+product_name = "Test"
+product_keywords = ["test"]
+
+[llm]
+provider = "ollama"
+
+[[hooks.scripts]]
+event = "pre_post"
+command = "/opt/hooks/brand-check.sh"
+
+[[hooks.scripts]]
+name = "policy"
+event = "before_post"
+command = "policy.wasm"
+timeout_secs = 0
+"#;
+    let mut config: Config = toml::from_str(toml_str).unwrap();
+    assert_eq!(config.hooks.wasm_runtime, "wasmtime run");
+    assert_eq!(config.hooks.scripts[0].display_name(), "brand-check.sh");
+    assert_eq!(config.hooks.scripts[0].timeout_secs, 5);
+    assert!(!config.hooks.scripts[0].fail_open);
+    assert!(config.hooks.scripts[1].is_wasm());
+    assert_eq!(config.hooks.for_event("pre_post").count(), 1);
+
+    let errors = config.validate().unwrap_err();
+    for field in ["hooks.scripts[1].event", "hooks.scripts[1].timeout_secs"] {
+        assert!(
+            errors
+                .iter()
+                .any(|e| matches!(e, ConfigError::InvalidValue { field: f, .. } if f == field)),
+            "missing error for {field}"
+        );
+    }
+
+    config.hooks.scripts[1].event = "pre_approve".to_string();
+    config.hooks.scripts[1].timeout_secs = 2;
+    assert!(config.validate().is_ok());
+}
+
+#[test]
+fn trending_parses_and_validates() {
+    let config: Config = toml::from_str("").unwrap();
+    assert!(!config.trending.enabled);
+
+    let toml_str = r#"
+[trending]
+enabled = true
+share = 1.5
+"#;
+    let mut config: Config = toml::from_str(toml_str).unwrap();
+    assert_eq!(config.trending.lookback_hours, 24);
+    assert_eq!(config.trending.min_tweets, 3);
+
+    config.business.product_name = "Test".to_string();
+    config.business.product_keywords = vec!["test".to_string()];
+    config.llm.provider = "ollama".to_string();
+    let errors = config.validate().unwrap_err();
+    assert!(errors.iter().any(|e| matches!(
+        e,
+        ConfigError::InvalidValue { field, .. } if field == "trending.share"
+    )));
+
+    config.trending.share = 0.5;
+    assert!(config.validate().is_ok());
+}
+
+#[test]
+fn disclosure_parses_and_validates() {
+    let config: Config = toml::from_str("").unwrap();
+    assert!(config.disclosure.product_mentions_only);
+    assert!(config.disclosure.template_for("reply", "Test").is_none());
+
+    let toml_str = r#"
+[disclosure]
+reply = "(I build {product_name})"
+thread = ""
+"#;
+    let mut config: Config = toml::from_str(toml_str).unwrap();
+    assert_eq!(
+        config.disclosure.template_for("reply", "Test").as_deref(),
+        Some("(I build Test)")
+    );
+
+    config.business.product_name = "Test".to_string();
+    config.business.product_keywords = vec!["test".to_string()];
+    config.llm.provider = "ollama".to_string();
+    let errors = config.validate().unwrap_err();
+    assert!(errors.iter().any(|e| matches!(
+        e,
+        ConfigError::InvalidValue { field, .. } if field == "disclosure.thread"
+    )));
+
+    config.disclosure.thread = None;
+    assert!(config.validate().is_ok());
+}
+
+#[test]
+fn discovery_sources_parse_and_validate() {
+    let config: Config = toml::from_str("").unwrap();
+    assert!(!config.discovery_sources.hackernews.enabled);
+    assert_eq!(config.discovery_sources.hackernews.max_results, 20);
+
+    let toml_str = r#"
+[business]
+product_name = "Test"
+product_keywords = ["test"]
+
+[llm]
+provider = "ollama"
+
+[discovery_sources.hackernews]
+enabled = true
+min_points = 10
+max_results = 0
+"#;
+    let config: Config = toml::from_str(toml_str).unwrap();
+    let hn = &config.discovery_sources.hackernews;
+    assert!(hn.enabled);
+    assert_eq!(hn.min_points, 10);
+    assert_eq!(hn.max_age_hours, 48);
+    let errors = config.validate().unwrap_err();
+    assert!(errors.iter().any(|e| matches!(
+        e,
+        ConfigError::InvalidValue { field, .. } if field == "discovery_sources.hackernews.max_results"
+    )));
+}
+
+#[test]
+fn reddit_requires_credentials_when_enabled() {
+    let toml_str = r#"
+[business]
+product_name = "Test"
+product_keywords = ["test"]
+
+[llm]
+provider = "ollama"
+
+[reddit]
+enabled = true
+client_id = "abc"
+username = "me"
+"#;
+    let config: Config = toml::from_str(toml_str).unwrap();
+    assert_eq!(config.reddit.max_comments_per_day, 3);
+    assert!(config.reddit.user_agent.starts_with("tuitbot/"));
+    let errors = config.validate().unwrap_err();
+    for field in ["reddit.client_secret", "reddit.password"] {
+        assert!(
+            errors
+                .iter()
+                .any(|e| matches!(e, ConfigError::MissingField { field: f } if f == field)),
+            "missing error for {field}"
+        );
+    }
+    assert!(errors.iter().any(
+        |e| matches!(e, ConfigError::InvalidValue { field, .. } if field == "reddit.subreddits")
+    ));
+
+    let mut config = config;
+    config.reddit.client_secret = Some("secret".to_string());
+    config.reddit.password = Some("hunter2".to_string());
+    config.reddit.subreddits = vec!["rust".to_string()];
+    assert!(config.validate().is_ok());
+}
+
+#[test]
+fn notification_webhooks_parse_and_validate() {
+    let toml_str = r#"
+[business]
+product_name = "Test"
+product_keywords = ["test"]
+
+[llm]
+provider = "ollama"
+
+[[notifications.webhooks]]
+url = "https://hooks.zapier.com/hooks/catch/1/abc"
+events = ["approval_queued", "approval_updated"]
+
+[notifications.webhooks.templates.approval_queued]
+title = "New {{action_type}} to review"
+item_id = "{{id}}"
+"#;
+    let config: Config = toml::from_str(toml_str).unwrap();
+    let hook = &config.notifications.webhooks[0];
+    assert!(hook.wants("approval_queued"));
+    assert!(!hook.wants("post_failed"));
+    assert_eq!(hook.templates["approval_queued"]["item_id"], "{{id}}");
+    assert!(config.validate().is_ok());
+
+    let mut config = config;
+    config.notifications.webhooks[0].url = "hooks.zapier.com".to_string();
+    config.notifications.webhooks[0]
+        .events
+        .push("approved".to_string());
+    let errors = config.validate().unwrap_err();
+    assert_eq!(
+        errors
+            .iter()
+            .filter(|e| matches!(e, ConfigError::InvalidValue { field, .. } if field.starts_with("notifications.webhooks[0]")))
+            .count(),
+        2
+    );
+}
+
+#[test]
+fn notification_channels_parse_and_validate() {
+    let toml_str = r#"
+[business]
+product_name = "Test"
+product_keywords = ["test"]
+
+[llm]
+provider = "ollama"
+
+[[notifications.channels]]
+url = "discord://123/abc"
+events = ["post_failed"]
+
+[[notifications.channels]]
+url = "tgram://123:abc/456"
+retries = 0
+"#;
+    let config: Config = toml::from_str(toml_str).unwrap();
+    let channels = &config.notifications.channels;
+    assert_eq!(channels[0].scheme(), "discord");
+    assert_eq!(channels[0].retries, 2);
+    assert!(!channels[0].wants("approval_queued"));
+    assert!(channels[1].wants("approval_queued"));
+    assert!(config.validate().is_ok());
+
+    let mut config = config;
+    config.notifications.channels[0].url = "pagerduty://key".to_string();
+    config.notifications.channels[1].events = vec!["posted".to_string()];
+    let errors = config.validate().unwrap_err();
+    assert!(errors.iter().any(|e| matches!(
+        e,
+        ConfigError::InvalidValue { field, .. } if field == "notifications.channels[0].url"
+    )));
+    assert!(errors.iter().any(|e| matches!(
+        e,
+        ConfigError::InvalidValue { field, .. } if field == "notifications.channels[1]"
+    )));
+}
+
+#[test]
+fn email_notifications_require_smtp_settings() {
+    let toml_str = r#"
+[business]
+product_name = "Test"
+product_keywords = ["test"]
 
-    config.scoring.threshold = 0;
-    assert!(config.validate().is_ok());
+[llm]
+provider = "ollama"
 
-    config.scoring.threshold = 100;
+[notifications.email]
+enabled = true
+smtp_host = "smtp.example.com"
+approval_links = true
+"#;
+    let config: Config = toml::from_str(toml_str).unwrap();
+    assert_eq!(config.notifications.email.smtp_port, 587);
+    assert_eq!(config.notifications.email.digest_hour, 8);
+    let errors = config.validate().unwrap_err();
+    for field in ["notifications.email.from", "notifications.email.to"] {
+        assert!(
+            errors
+                .iter()
+                .any(|e| matches!(e, ConfigError::MissingField { field: f } if f == field)),
+            "missing error for {field}"
+        );
+    }
+    assert!(errors.iter().any(|e| matches!(
+        e,
+        ConfigError::InvalidValue { field, .. } if field == "notifications.email.public_url"
+    )));
+
+    let mut config = config;
+    config.notifications.email.from = "bot@example.com".to_string();
+    config.notifications.email.to = vec!["me@example.com".to_string()];
+    config.notifications.email.public_url = "https://tuitbot.example.com".to_string();
     assert!(config.validate().is_ok());
 }
 
 #[test]
-fn validate_returns_multiple_errors() {
-    let mut config = Config::default();
-    // Missing product_name (default is empty)
-    // Missing keywords (default is empty)
-    config.llm.provider = "invalid".to_string();
-    config.scoring.threshold = 101;
-    config.limits.max_replies_per_day = 0;
+fn target_tiers_parse_and_validate() {
+    let toml_str = r#"
+[business]
+product_name = "Test"
+product_keywords = ["test"]
+
+[llm]
+provider = "ollama"
+
+[targets]
+accounts = ["alice"]
+
+[targets.tiers.dream_100]
+max_interactions_per_week = 7
+allowed_actions = ["reply", "quote"]
+require_approval = true
+"#;
+    let config: Config = toml::from_str(toml_str).expect("valid TOML");
+    let dream = config.targets.tiers.cadence(TargetTier::Dream100);
+    assert_eq!(dream.max_interactions_per_week, 7);
+    assert!(dream.allows("quote"));
+    assert!(!dream.allows("like"));
+    assert!(dream.require_approval);
+    // Unspecified tiers keep their defaults.
+    let general = config.targets.tiers.cadence(TargetTier::General);
+    assert_eq!(general.max_interactions_per_week, 2);
+    assert!(config.validate().is_ok());
 
+    assert_eq!(TargetTier::parse("dream_100"), Some(TargetTier::Dream100));
+    assert_eq!(TargetTier::parse("vip"), None);
+
+    let mut config = config;
+    config.targets.tiers.general.max_interactions_per_week = 0;
+    config.targets.tiers.general.allowed_actions = vec!["dm".to_string()];
     let errors = config.validate().unwrap_err();
-    assert!(
-        errors.len() >= 4,
-        "expected at least 4 errors, got {}: {:?}",
-        errors.len(),
-        errors
-    );
+    for field in [
+        "targets.tiers.general.max_interactions_per_week",
+        "targets.tiers.general.allowed_actions",
+    ] {
+        assert!(
+            errors
+                .iter()
+                .any(|e| matches!(e, ConfigError::InvalidValue { field: f, .. } if f == field)),
+            "missing error for {field}"
+        );
+    }
 }
 
 #[test]
-fn validate_valid_config_passes() {
-    let mut config = Config::default();
-    config.business.product_name = "TestProduct".to_string();
-    config.business.product_keywords = vec!["test".to_string()];
-    config.llm.provider = "ollama".to_string();
-    config.llm.model = "llama2".to_string();
+fn mcp_tool_overrides_parse_and_validate() {
+    let mut config: Config = toml::from_str(
+        r#"
+[business]
+product_name = "Test"
+product_keywords = ["test"]
+
+[llm]
+provider = "ollama"
+
+[mcp_policy.tools.x_delete_tweet]
+enabled = false
+
+[mcp_policy.categories.engage]
+max_per_hour = 10
+"#,
+    )
+    .expect("parse");
+    assert!(!config.mcp_policy.tools["x_delete_tweet"].enabled);
+    assert_eq!(
+        config.mcp_policy.categories["engage"].max_per_hour,
+        Some(10)
+    );
+    assert!(config.mcp_policy.categories["engage"].enabled);
     assert!(config.validate().is_ok());
-}
 
-#[test]
-fn validate_openai_requires_api_key() {
-    let mut config = Config::default();
-    config.business.product_name = "Test".to_string();
-    config.business.product_keywords = vec!["test".to_string()];
-    config.llm.provider = "openai".to_string();
-    config.llm.api_key = None;
+    config
+        .mcp_policy
+        .categories
+        .insert("bogus".into(), Default::default());
+    config
+        .mcp_policy
+        .categories
+        .get_mut("engage")
+        .unwrap()
+        .max_per_hour = Some(0);
     let errors = config.validate().unwrap_err();
-    assert!(errors.iter().any(
-        |e| matches!(e, ConfigError::MissingField { field } if field.contains("llm.api_key"))
-    ));
+    for field in [
+        "mcp_policy.categories.bogus",
+        "mcp_policy.categories.engage.max_per_hour",
+    ] {
+        assert!(
+            errors
+                .iter()
+                .any(|e| matches!(e, ConfigError::InvalidValue { field: f, .. } if f == field)),
+            "missing error for {field}"
+        );
+    }
 }
 
 #[test]
-fn validate_delay_ordering() {
-    let mut config = Config::default();
-    config.business.product_name = "Test".to_string();
-    config.business.product_keywords = vec!["test".to_string()];
-    config.llm.provider = "ollama".to_string();
-    config.limits.min_action_delay_seconds = 200;
-    config.limits.max_action_delay_seconds = 100;
-    let errors = config.validate().unwrap_err();
-    assert!(errors.iter().any(|e| matches!(e, ConfigError::InvalidValue { field, .. } if field == "limits.min_action_delay_seconds")));
+fn media_config_parses_from_toml() {
+    let config: Config = toml::from_str(
+        r#"
+[media]
+max_video_duration_seconds = 600
+"#,
+    )
+    .expect("parse");
+    assert_eq!(config.media.max_video_duration_seconds, 600);
+    assert_eq!(config.media.max_video_size_mb, 512);
 }
 
 #[test]
@@ -399,8 +1269,10 @@ fn parse_env_bool_values() {
 fn env_var_override_approval_mode() {
     with_locked_env(|| {
         let _approval = ScopedEnvVar::set("TUITBOT_APPROVAL_MODE", "true");
-        let mut config = Config::default();
-        config.approval_mode = false;
+        let mut config = Config {
+            approval_mode: false,
+            ..Config::default()
+        };
         config.apply_env_overrides().expect("env override");
         assert!(config.approval_mode);
     });
@@ -420,8 +1292,10 @@ fn env_var_override_approval_mode_false() {
 fn openclaw_env_enables_approval_mode() {
     with_locked_env(|| {
         let _agent_id = ScopedEnvVar::set("OPENCLAW_AGENT_ID", "test");
-        let mut config = Config::default();
-        config.approval_mode = false;
+        let mut config = Config {
+            approval_mode: false,
+            ..Config::default()
+        };
         config.apply_env_overrides().expect("env override");
         assert!(config.approval_mode);
     });
@@ -442,11 +1316,13 @@ fn openclaw_env_respects_explicit_override() {
 
 #[test]
 fn quickstart_minimal_config_validates() {
-    let mut config = Config::default();
-    config.business = BusinessProfile::quickstart(
-        "MyApp".to_string(),
-        vec!["rust cli".to_string(), "developer tools".to_string()],
-    );
+    let mut config = Config {
+        business: BusinessProfile::quickstart(
+            "MyApp".to_string(),
+            vec!["rust cli".to_string(), "developer tools".to_string()],
+        ),
+        ..Config::default()
+    };
     config.llm.provider = "ollama".to_string();
     config.x_api.client_id = "test-client-id".to_string();
     assert!(config.validate().is_ok());
@@ -920,3 +1796,363 @@ fn deployment_mode_display() {
     assert_eq!(DeploymentMode::SelfHost.to_string(), "self_host");
     assert_eq!(DeploymentMode::Cloud.to_string(), "cloud");
 }
+
+#[test]
+fn rate_limit_timezone_falls_back_to_schedule() {
+    let mut config = Config::default();
+    config.schedule.timezone = "America/New_York".to_string();
+    assert_eq!(config.rate_limit_timezone(), "America/New_York");
+
+    config.limits.reset_timezone = "UTC".to_string();
+    assert_eq!(config.rate_limit_timezone(), "UTC");
+
+    config.limits.reset_timezone = "Mars/Olympus".to_string();
+    let errors = config.validate().unwrap_err();
+    assert!(errors.iter().any(|e| matches!(
+        e,
+        ConfigError::InvalidValue { field, .. } if field == "limits.reset_timezone"
+    )));
+}
+
+#[test]
+fn rate_limit_windows_parse_from_toml() {
+    let config: Config = toml::from_str(
+        r#"
+[limits]
+max_threads_per_week = 2
+
+[limits.windows]
+thread = "day"
+reply = "12h"
+"#,
+    )
+    .expect("parse");
+    assert_eq!(config.limits.window("thread"), RateLimitWindow::Day);
+    assert_eq!(
+        config.limits.window("reply"),
+        RateLimitWindow::RollingHours(12)
+    );
+    assert_eq!(config.limits.window("tweet"), RateLimitWindow::Day);
+
+    let err = toml::from_str::<Config>("[limits.windows]\nreply = \"0h\"\n").unwrap_err();
+    assert!(err.to_string().contains("invalid rate limit window"));
+
+    // Defaults stay out of serialized config.
+    let defaults = toml::to_string(&Config::default()).expect("serialize");
+    assert!(!defaults.contains("windows"));
+}
+
+#[test]
+fn rate_limit_windows_env_override_and_validation() {
+    with_locked_env(|| {
+        let _windows = ScopedEnvVar::set("TUITBOT_LIMITS__WINDOWS", "thread=week, like=6h");
+        let mut config = Config::default();
+        config.apply_env_overrides().expect("env override");
+        assert_eq!(config.limits.window("thread"), RateLimitWindow::Week);
+        assert_eq!(
+            config.limits.window("like"),
+            RateLimitWindow::RollingHours(6)
+        );
+    });
+
+    let mut config = Config::default();
+    config
+        .limits
+        .windows
+        .insert("search".to_string(), RateLimitWindow::Day);
+    let errors = config.validate().unwrap_err();
+    assert!(errors.iter().any(|e| matches!(
+        e,
+        ConfigError::InvalidValue { field, .. } if field == "limits.windows.search"
+    )));
+}
+
+#[test]
+fn balanced_preset_matches_defaults() {
+    let defaults: Config = toml::from_str("[targets]").expect("defaults");
+    let mut config = defaults.clone();
+    PolicyPreset::Balanced.apply(&mut config);
+    assert_eq!(
+        toml::to_string(&config).expect("serialize"),
+        toml::to_string(&defaults).expect("serialize")
+    );
+}
+
+#[test]
+fn presets_are_valid_and_ordered() {
+    let mut limits = Vec::new();
+    for preset in PolicyPreset::ALL {
+        let mut config = Config::default();
+        config.business.product_name = "TestProduct".to_string();
+        config.business.product_keywords = vec!["test".to_string()];
+        config.llm.provider = "ollama".to_string();
+        config.llm.model = "llama2".to_string();
+        preset.apply(&mut config);
+        assert!(
+            config.validate().is_ok(),
+            "{preset} preset fails validation"
+        );
+        limits.push(config.limits.max_replies_per_day);
+
+        assert_eq!(preset.name().parse::<PolicyPreset>(), Ok(preset));
+    }
+    assert!(limits.windows(2).all(|w| w[0] < w[1]));
+    assert_eq!("Aggressive".parse(), Ok(PolicyPreset::Aggressive));
+    assert!("yolo".parse::<PolicyPreset>().is_err());
+}
+
+#[test]
+fn validate_reply_archetype_weights() {
+    let mut config = Config::default();
+    config
+        .business
+        .reply_archetype_weights
+        .insert("ask_question".to_string(), 3);
+    assert!(!config.validate().unwrap_err().iter().any(|e| matches!(
+        e,
+        ConfigError::InvalidValue { field, .. } if field.starts_with("business.reply_archetype_weights")
+    )));
+
+    config
+        .business
+        .reply_archetype_weights
+        .insert("rant".to_string(), 1);
+    config
+        .business
+        .reply_archetype_weights
+        .insert("ask_question".to_string(), 0);
+    let errors = config.validate().unwrap_err();
+    assert!(errors.iter().any(|e| matches!(
+        e,
+        ConfigError::InvalidValue { field, .. } if field == "business.reply_archetype_weights.rant"
+    )));
+    assert!(!errors.iter().any(|e| matches!(
+        e,
+        ConfigError::InvalidValue { field, .. } if field == "business.reply_archetype_weights"
+    )));
+}
+
+#[test]
+fn pillar_overrides_parse_and_match_topics() {
+    let toml_str = r#"
+[business]
+product_name = "Test"
+product_keywords = ["test"]
+content_pillars = ["Tax law", "Rust"]
+
+[business.pillar_overrides."Tax law"]
+tone = "Formal and precise."
+language = "German"
+emoji = "forbid"
+require_approval = true
+"#;
+    let config: Config = toml::from_str(toml_str).expect("valid TOML");
+    let (pillar, o) = config
+        .business
+        .pillar_override("New TAX LAW rules for freelancers")
+        .expect("override");
+    assert_eq!(pillar, "Tax law");
+    assert_eq!(o.emoji, Some(EmojiPolicy::Forbid));
+    assert!(o.require_approval);
+    assert!(config.business.pillar_override("Rust tips").is_none());
+    assert!(!config.validate().err().unwrap_or_default().iter().any(|e| matches!(
+        e,
+        ConfigError::InvalidValue { field, .. } if field.starts_with("business.pillar_overrides")
+    )));
+
+    let mut config = config;
+    config
+        .business
+        .pillar_overrides
+        .insert("Crypto".to_string(), PillarOverride::default());
+    assert!(config.validate().unwrap_err().iter().any(|e| matches!(
+        e,
+        ConfigError::InvalidValue { field, .. } if field == "business.pillar_overrides.Crypto"
+    )));
+}
+
+#[test]
+fn warmup_parses_and_validates() {
+    let config: Config = toml::from_str("").unwrap();
+    assert!(!config.warmup.enabled);
+
+    let toml_str = r#"
+[warmup]
+enabled = true
+weeks = 0
+"#;
+    let mut config: Config = toml::from_str(toml_str).unwrap();
+    assert_eq!(config.warmup.start_percent, 25);
+    assert_eq!(config.warmup.inactive_days, 14);
+
+    config.business.product_name = "Test".to_string();
+    config.business.product_keywords = vec!["test".to_string()];
+    config.llm.provider = "ollama".to_string();
+    let errors = config.validate().unwrap_err();
+    assert!(errors.iter().any(|e| matches!(
+        e,
+        ConfigError::InvalidValue { field, .. } if field == "warmup.weeks"
+    )));
+
+    config.warmup.weeks = 6;
+    assert!(config.validate().is_ok());
+}
+
+#[test]
+fn blocklist_parses_and_validates() {
+    let config: Config = toml::from_str("").unwrap();
+    assert!(config.blocklist.sync_enabled);
+    assert!(config.blocklist.authors.is_empty());
+
+    let toml_str = r#"
+[blocklist]
+sync_interval_seconds = 60
+authors = ["@spammer", "not a handle"]
+protected = ["@reporter", "@bad-handle"]
+"#;
+    let mut config: Config = toml::from_str(toml_str).unwrap();
+    config.business.product_name = "Test".to_string();
+    config.business.product_keywords = vec!["test".to_string()];
+    config.llm.provider = "ollama".to_string();
+    let errors = config.validate().unwrap_err();
+    for field in [
+        "blocklist.sync_interval_seconds",
+        "blocklist.authors",
+        "blocklist.protected",
+    ] {
+        assert!(errors.iter().any(|e| matches!(
+            e,
+            ConfigError::InvalidValue { field: f, .. } if f == field
+        )));
+    }
+
+    config.blocklist.sync_interval_seconds = 3600;
+    config.blocklist.authors = vec!["@spammer".to_string()];
+    config.blocklist.protected = vec!["@reporter".to_string()];
+    assert!(config.validate().is_ok());
+}
+
+#[test]
+fn scoring_plugin_parse_and_validate() {
+    let toml_str = r#"
+[business]
+product_name = "Test"
+product_keywords = ["test"]
+
+[llm]
+provider = "ollama"
+
+[scoring.plugin]
+path = "~/plugins/score.wasm"
+fuel = 0
+"#;
+    let mut config: Config = toml::from_str(toml_str).unwrap();
+    assert!(config.scoring.plugin.is_enabled());
+    assert_eq!(config.scoring.plugin.max_adjustment, 20.0);
+    assert_eq!(config.scoring.plugin.max_memory_mb, 16);
+
+    let errors = config.validate().unwrap_err();
+    let has_error = |errors: &[ConfigError], field: &str| {
+        errors
+            .iter()
+            .any(|e| matches!(e, ConfigError::InvalidValue { field: f, .. } if f == field))
+    };
+    assert!(has_error(&errors, "scoring.plugin.fuel"));
+    assert_eq!(
+        has_error(&errors, "scoring.plugin.path"),
+        !cfg!(feature = "wasm-scoring")
+    );
+
+    config.scoring.plugin.path = String::new();
+    assert!(config.validate().is_ok());
+}
+
+#[test]
+fn lint_default_config_is_clean() {
+    assert!(Config::default().lint().is_empty());
+}
+
+#[test]
+fn lint_flags_risky_and_contradictory_settings() {
+    let mut config = Config {
+        approval_mode: false,
+        ..Config::default()
+    };
+    config.limits.max_replies_per_day = 40;
+    config.limits.min_action_delay_seconds = 5;
+    config.limits.banned_phrases.clear();
+    config.schedule.active_hours_start = 9;
+    config.schedule.active_hours_end = 11;
+    config.schedule.preferred_times = vec!["auto".to_string()];
+    config.business.product_keywords = vec!["Rust".to_string(), "cli".to_string()];
+    config.business.competitor_keywords = vec!["rust ".to_string()];
+
+    let warnings = config.lint();
+    let fields: Vec<&str> = warnings.iter().map(|w| w.field.as_str()).collect();
+    assert!(fields.contains(&"limits.max_replies_per_day"));
+    assert!(fields.contains(&"limits.min_action_delay_seconds"));
+    assert!(fields.contains(&"limits.banned_phrases"));
+    assert!(fields.contains(&"approval_mode"));
+    assert!(fields.contains(&"business.competitor_keywords"));
+
+    let schedule: Vec<&str> = warnings
+        .iter()
+        .filter(|w| w.field == "schedule.preferred_times")
+        .map(|w| w.message.as_str())
+        .collect();
+    assert_eq!(schedule.len(), 2);
+    assert!(schedule[0].contains("3 posting slots but only 2 active hour(s)"));
+    assert!(schedule[1].contains("12:30, 17:00 fall outside"));
+}
+
+#[test]
+fn lint_handles_wrapping_and_empty_active_windows() {
+    let mut config = Config::default();
+    config.schedule.active_hours_start = 22;
+    config.schedule.active_hours_end = 6;
+    config.schedule.preferred_times = vec!["23:00".to_string(), "05:30".to_string()];
+    assert!(config.lint().is_empty());
+
+    config.schedule.active_hours_end = 22;
+    let warnings = config.lint();
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].field, "schedule.active_hours_end");
+}
+
+#[test]
+fn llm_policies_parse_and_validate() {
+    let toml_str = r#"
+[business]
+product_name = "Test"
+product_keywords = ["test"]
+
+[llm]
+provider = "ollama"
+
+[llm.policies.ollama]
+timeout_seconds = 300
+max_retries = 0
+
+[llm.policies.gemini]
+timeout_seconds = 0
+"#;
+    let mut config: Config = toml::from_str(toml_str).unwrap();
+    let policy = config.llm.active_policy();
+    assert_eq!(
+        policy.timeout("ollama"),
+        std::time::Duration::from_secs(300)
+    );
+    assert_eq!(policy.max_retries, 0);
+    assert_eq!(policy.breaker_threshold, 5);
+
+    let errors = config.validate().unwrap_err();
+    for field in ["llm.policies.gemini", "llm.policies.gemini.timeout_seconds"] {
+        assert!(errors.iter().any(|e| matches!(
+            e,
+            ConfigError::InvalidValue { field: f, .. } if f == field
+        )));
+    }
+
+    config.llm.policies.remove("gemini");
+    assert!(config.validate().is_ok());
+}
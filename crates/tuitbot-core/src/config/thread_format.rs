@@ -0,0 +1,53 @@
+//! `[thread_format]`: numbering and closing call to action for threads.
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::ConfigError;
+
+/// Where "1/n" numbering goes in each thread tweet.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ThreadNumbering {
+    /// No numbering.
+    #[default]
+    None,
+    /// `1/5 text`
+    Prefix,
+    /// `text 1/5`
+    Suffix,
+}
+
+/// Decorations applied to threads at posting time.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct ThreadFormatConfig {
+    /// Number each tweet "1/n" (prefix or suffix).
+    #[serde(default)]
+    pub numbering: ThreadNumbering,
+
+    /// Template for a closing call-to-action tweet. `{thread_url}` expands
+    /// to the link of the first tweet.
+    #[serde(default)]
+    pub end_cta: Option<String>,
+
+    /// End the thread by quoting its first tweet (the hook).
+    #[serde(default)]
+    pub requote_hook: bool,
+
+    /// Text of the re-quote tweet; the hook's link is appended.
+    #[serde(default)]
+    pub requote_text: Option<String>,
+}
+
+impl ThreadFormatConfig {
+    /// Reject a blank closing call to action.
+    pub(super) fn validate(&self, errors: &mut Vec<ConfigError>) {
+        if let Some(cta) = &self.end_cta {
+            if cta.trim().is_empty() {
+                errors.push(ConfigError::InvalidValue {
+                    field: "thread_format.end_cta".to_string(),
+                    message: "must not be empty when set".to_string(),
+                });
+            }
+        }
+    }
+}
@@ -0,0 +1,73 @@
+//! `[trending]`: original tweets inspired by trending topics.
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::ConfigError;
+
+/// Trend-inspired original tweets in the content loop.
+///
+/// Discovered tweets from the last `lookback_hours` are clustered by the
+/// keyword that surfaced them. On a `share` of content-loop posts, the
+/// largest cluster aligned with a content pillar becomes the topic, and the
+/// tweet references that conversation without replying to it.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct TrendingConfig {
+    /// Draw some content-loop topics from trending discovery clusters.
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Fraction (0.0-1.0) of content-loop posts that try a trend first.
+    #[serde(default = "default_trending_share")]
+    pub share: f64,
+
+    /// How far back discovered tweets count toward a cluster.
+    #[serde(default = "default_trending_lookback_hours")]
+    pub lookback_hours: u32,
+
+    /// Minimum discovered tweets for a keyword to count as trending.
+    #[serde(default = "default_trending_min_tweets")]
+    pub min_tweets: u32,
+}
+
+impl Default for TrendingConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            share: default_trending_share(),
+            lookback_hours: default_trending_lookback_hours(),
+            min_tweets: default_trending_min_tweets(),
+        }
+    }
+}
+
+fn default_trending_share() -> f64 {
+    0.25
+}
+
+fn default_trending_lookback_hours() -> u32 {
+    24
+}
+
+fn default_trending_min_tweets() -> u32 {
+    3
+}
+
+impl TrendingConfig {
+    /// Check the trending share and lookback when enabled.
+    pub(super) fn validate(&self, errors: &mut Vec<ConfigError>) {
+        if self.enabled {
+            if !(0.0..=1.0).contains(&self.share) {
+                errors.push(ConfigError::InvalidValue {
+                    field: "trending.share".to_string(),
+                    message: "must be between 0.0 and 1.0".to_string(),
+                });
+            }
+            if self.lookback_hours == 0 {
+                errors.push(ConfigError::InvalidValue {
+                    field: "trending.lookback_hours".to_string(),
+                    message: "must be at least 1".to_string(),
+                });
+            }
+        }
+    }
+}
@@ -0,0 +1,58 @@
+//! `[[trusted_authors]]`: authors allowed to skip approval or soft QA.
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::ConfigError;
+
+/// A trusted account (your own alt, a teammate) whose replies may skip
+/// review. Listing an account grants nothing by itself: each bypass is
+/// enabled explicitly.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct TrustedAuthorConfig {
+    /// X username, with or without `@`.
+    pub username: String,
+
+    /// Post replies to this account directly, even in approval mode.
+    #[serde(default)]
+    pub skip_approval: bool,
+
+    /// Don't hold replies to this account for soft QA flags, such as
+    /// repeating the author's recent tweets. Hard flags still apply.
+    #[serde(default)]
+    pub skip_soft_qa: bool,
+}
+
+/// Check each trusted author is a valid, unique username that enables a
+/// bypass and is not also protected by the blocklist.
+pub(super) fn validate_trusted_authors(
+    authors: &[TrustedAuthorConfig],
+    protected: &[String],
+    errors: &mut Vec<ConfigError>,
+) {
+    let protected = crate::safety::protected::normalize_protected(protected);
+    let mut trusted = Vec::new();
+    for author in authors {
+        let name = crate::safety::protected::normalize_username(&author.username);
+        let message =
+            if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+                Some(format!("'{}' is not a valid X username", author.username))
+            } else if !author.skip_approval && !author.skip_soft_qa {
+                Some(format!(
+                    "@{name} enables no bypass; set skip_approval or skip_soft_qa"
+                ))
+            } else if protected.contains(&name) {
+                Some(format!("@{name} is also in blocklist.protected"))
+            } else if trusted.contains(&name) {
+                Some(format!("@{name} is listed more than once"))
+            } else {
+                None
+            };
+        if let Some(message) = message {
+            errors.push(ConfigError::InvalidValue {
+                field: "trusted_authors".to_string(),
+                message,
+            });
+        }
+        trusted.push(name);
+    }
+}
@@ -1,5 +1,8 @@
 //! Configuration section structs and their serde default functions.
 
+use std::collections::{BTreeMap, HashMap};
+use std::time::Duration;
+
 use serde::{Deserialize, Serialize};
 
 // ---------------------------------------------------------------------------
@@ -25,6 +28,20 @@ pub struct XApiConfig {
     /// Only meaningful when `provider_backend = "scraper"`. Default: `false`.
     #[serde(default)]
     pub scraper_allow_mutations: bool,
+
+    /// Base URL of the Nitter-style instance the scraper backend reads from,
+    /// e.g. `"https://nitter.example.com"`. Required for scraper reads.
+    #[serde(default)]
+    pub scraper_base_url: Option<String>,
+
+    /// Minimum milliseconds between scraper requests. Unset uses 2000.
+    #[serde(default)]
+    pub scraper_min_interval_ms: Option<u64>,
+
+    /// Override for the X API v2 base URL, e.g. a local mock server or a
+    /// proxy. Media uploads go to the same URL. Unset uses production X.
+    #[serde(default)]
+    pub base_url: Option<String>,
 }
 
 // ---------------------------------------------------------------------------
@@ -122,6 +139,18 @@ pub struct BusinessProfile {
     /// Core content pillars (broad themes the account focuses on).
     #[serde(default)]
     pub content_pillars: Vec<String>,
+
+    /// Relative weights for reply archetypes (`agree_and_expand`,
+    /// `respectful_disagree`, `add_data`, `ask_question`, `share_experience`).
+    /// When set, replies pick an archetype by these weights; archetypes left
+    /// out are not used.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub reply_archetype_weights: BTreeMap<String, u32>,
+
+    /// Per-pillar tone, language, emoji, and approval overrides, keyed by a
+    /// pillar from `content_pillars`. See [`Self::pillar_override`].
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub pillar_overrides: BTreeMap<String, PillarOverride>,
 }
 
 impl BusinessProfile {
@@ -164,6 +193,60 @@ impl BusinessProfile {
             || !self.persona_experiences.is_empty()
             || !self.content_pillars.is_empty()
     }
+
+    /// The override for the first pillar named in `text` (a topic or the
+    /// tweet being answered), matched case-insensitively. Returns the pillar
+    /// with its override.
+    pub fn pillar_override(&self, text: &str) -> Option<(&str, &PillarOverride)> {
+        let text = text.to_lowercase();
+        self.pillar_overrides
+            .iter()
+            .find(|(pillar, _)| text.contains(&pillar.to_lowercase()))
+            .map(|(pillar, o)| (pillar.as_str(), o))
+    }
+
+    /// Pillars whose overrides require approval.
+    pub fn approval_pillars(&self) -> Vec<String> {
+        self.pillar_overrides
+            .iter()
+            .filter(|(_, o)| o.require_approval)
+            .map(|(pillar, _)| pillar.clone())
+            .collect()
+    }
+}
+
+/// How generated content may use emojis.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EmojiPolicy {
+    /// Emojis are fine.
+    Allow,
+    /// Use them sparingly (the global default for replies).
+    #[default]
+    Sparing,
+    /// No emojis at all; QA flags any that slip through.
+    Forbid,
+}
+
+/// Overrides applied to content on one content pillar.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct PillarOverride {
+    /// Replaces `brand_voice` for this pillar.
+    #[serde(default)]
+    pub tone: Option<String>,
+
+    /// Language to write in (e.g. "German"), regardless of the source tweet.
+    #[serde(default)]
+    pub language: Option<String>,
+
+    /// Emoji policy; the global policy applies when unset.
+    #[serde(default)]
+    pub emoji: Option<EmojiPolicy>,
+
+    /// Send replies and tweets on this pillar to the approval queue, and
+    /// keep auto-triage from approving them.
+    #[serde(default)]
+    pub require_approval: bool,
 }
 
 // ---------------------------------------------------------------------------
@@ -200,6 +283,122 @@ pub struct ScoringConfig {
     /// Maximum points for content type signal (text-only originals score highest).
     #[serde(default = "default_content_type_max")]
     pub content_type_max: f32,
+
+    /// Points added to (or subtracted from) the total for detected tweet entity types.
+    #[serde(default)]
+    pub entity_adjustments: EntityAdjustments,
+
+    /// Daily auto-calibration of the effective threshold toward a target reply volume.
+    #[serde(default)]
+    pub calibration: ThresholdCalibrationConfig,
+
+    /// How long tweets stay worth replying to, by source.
+    #[serde(default)]
+    pub freshness: FreshnessConfig,
+
+    /// Custom WASM scoring stage.
+    #[serde(default)]
+    pub plugin: ScoringPluginConfig,
+}
+
+/// Freshness windows for reply targets, by source.
+///
+/// A discovered tweet's total score decays linearly from full value at
+/// `discovery_decay_after_hours` to zero at `discovery_max_age_hours`, past
+/// which the tweet is dropped. Mentions are not scored, so only the
+/// `mentions_max_age_hours` cutoff applies. A max age of 0 turns the window
+/// off for that source.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct FreshnessConfig {
+    /// Age in hours after which a discovered tweet's score starts to decay.
+    #[serde(default = "default_discovery_decay_after_hours")]
+    pub discovery_decay_after_hours: u32,
+
+    /// Age in hours past which discovered tweets are never replied to.
+    #[serde(default = "default_discovery_max_age_hours")]
+    pub discovery_max_age_hours: u32,
+
+    /// Age in hours past which mentions are never replied to.
+    #[serde(default = "default_mentions_max_age_hours")]
+    pub mentions_max_age_hours: u32,
+}
+
+/// Auto-calibration of the discovery score threshold.
+///
+/// Once per UTC day the effective threshold moves by `step` toward the
+/// target reply volume, staying within `floor..=ceiling`.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ThresholdCalibrationConfig {
+    /// Enable daily threshold calibration.
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Discovery replies per day to aim for.
+    #[serde(default = "default_calibration_target_replies_per_day")]
+    pub target_replies_per_day: u32,
+
+    /// Lowest effective threshold calibration may set.
+    #[serde(default = "default_calibration_floor")]
+    pub floor: u32,
+
+    /// Highest effective threshold calibration may set.
+    #[serde(default = "default_calibration_ceiling")]
+    pub ceiling: u32,
+
+    /// Points the threshold moves per daily adjustment.
+    #[serde(default = "default_calibration_step")]
+    pub step: u32,
+}
+
+/// Score adjustments applied by detected tweet entity type.
+///
+/// Positive values boost a tweet, negative values demote it. Plain text
+/// tweets are never adjusted.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct EntityAdjustments {
+    /// X Spaces announcements (links to `x.com/i/spaces/...`).
+    #[serde(default = "default_spaces_adjustment")]
+    pub spaces: f32,
+
+    /// Tweets sharing an external article or news link.
+    #[serde(default)]
+    pub article: f32,
+
+    /// Tweets that are only media with little or no text.
+    #[serde(default = "default_media_only_adjustment")]
+    pub media_only: f32,
+}
+
+/// A compiled WASM module run as an extra scoring stage.
+///
+/// The module receives each candidate tweet as JSON and returns a score
+/// adjustment with a rationale. It runs with no host imports and is stopped
+/// once it uses up `fuel` or grows its memory past `max_memory_mb`. Requires
+/// a build with the `wasm-scoring` feature.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ScoringPluginConfig {
+    /// Path to the `.wasm` module. Empty disables the stage.
+    #[serde(default)]
+    pub path: String,
+
+    /// Instruction budget for scoring one tweet.
+    #[serde(default = "default_plugin_fuel")]
+    pub fuel: u64,
+
+    /// Largest adjustment, in points either way, the plugin may apply.
+    #[serde(default = "default_plugin_max_adjustment")]
+    pub max_adjustment: f32,
+
+    /// Memory limit for the module, in megabytes.
+    #[serde(default = "default_plugin_max_memory_mb")]
+    pub max_memory_mb: u32,
+}
+
+impl ScoringPluginConfig {
+    /// Whether a plugin module is configured.
+    pub fn is_enabled(&self) -> bool {
+        !self.path.trim().is_empty()
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -221,6 +420,14 @@ pub struct LimitsConfig {
     #[serde(default = "default_max_threads_per_week")]
     pub max_threads_per_week: u32,
 
+    /// Maximum likes per day (approved like items).
+    #[serde(default = "default_max_likes_per_day")]
+    pub max_likes_per_day: u32,
+
+    /// Maximum follows per day (approved follow items).
+    #[serde(default = "default_max_follows_per_day")]
+    pub max_follows_per_day: u32,
+
     /// Minimum delay between actions in seconds.
     #[serde(default = "default_min_action_delay_seconds")]
     pub min_action_delay_seconds: u64,
@@ -240,6 +447,117 @@ pub struct LimitsConfig {
     /// Fraction of replies that may mention the product (0.0 - 1.0).
     #[serde(default = "default_product_mention_ratio")]
     pub product_mention_ratio: f32,
+
+    /// IANA timezone whose midnight resets daily and weekly limits.
+    /// Empty uses `schedule.timezone`.
+    #[serde(default)]
+    pub reset_timezone: String,
+
+    /// Window overrides per action type (`reply`, `tweet`, `thread`, `like`,
+    /// `follow`). Unlisted actions use [`RateLimitWindow::default_for`].
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub windows: BTreeMap<String, RateLimitWindow>,
+}
+
+impl LimitsConfig {
+    /// Window the cap for `action_type` applies to.
+    pub fn window(&self, action_type: &str) -> RateLimitWindow {
+        self.windows
+            .get(action_type)
+            .copied()
+            .unwrap_or_else(|| RateLimitWindow::default_for(action_type))
+    }
+}
+
+/// Action types whose window can be set in `limits.windows`.
+pub const WINDOWED_ACTION_TYPES: &[&str] = &["reply", "tweet", "thread", "like", "follow"];
+
+/// Window a posting limit is counted over.
+///
+/// Written in config as `"day"`, `"week"` or a number of hours such as
+/// `"12h"`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum RateLimitWindow {
+    /// Midnight to midnight in the reset timezone.
+    Day,
+    /// Seven days from midnight, keeping the weekday the window started on.
+    Week,
+    /// The given number of hours from the first action after the previous
+    /// window ended.
+    RollingHours(u32),
+}
+
+impl RateLimitWindow {
+    /// Longest rolling window accepted (one year).
+    pub const MAX_ROLLING_HOURS: u32 = 8_760;
+
+    /// Window used when `limits.windows` does not list `action_type`:
+    /// a week for threads, a day for everything else.
+    pub fn default_for(action_type: &str) -> Self {
+        if action_type == "thread" {
+            Self::Week
+        } else {
+            Self::Day
+        }
+    }
+
+    /// Window length in seconds.
+    pub fn seconds(self) -> i64 {
+        match self {
+            Self::Day => 86_400,
+            Self::Week => 604_800,
+            Self::RollingHours(hours) => i64::from(hours) * 3_600,
+        }
+    }
+
+    /// Whether the window starts at local midnight rather than at the first
+    /// action.
+    pub fn is_calendar(self) -> bool {
+        !matches!(self, Self::RollingHours(_))
+    }
+
+    /// Parse `day`, `week` or `<hours>h`.
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.trim() {
+            "day" => Some(Self::Day),
+            "week" => Some(Self::Week),
+            other => other
+                .strip_suffix('h')
+                .and_then(|hours| hours.parse::<u32>().ok())
+                .filter(|hours| (1..=Self::MAX_ROLLING_HOURS).contains(hours))
+                .map(Self::RollingHours),
+        }
+    }
+}
+
+impl std::fmt::Display for RateLimitWindow {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Day => f.write_str("day"),
+            Self::Week => f.write_str("week"),
+            Self::RollingHours(hours) => write!(f, "{hours}h"),
+        }
+    }
+}
+
+impl TryFrom<String> for RateLimitWindow {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::parse(&value).ok_or_else(|| {
+            format!(
+                "invalid rate limit window '{value}': expected \"day\", \"week\" or hours such as \"12h\" (1-{})",
+                Self::MAX_ROLLING_HOURS
+            )
+        })
+    }
+}
+
+impl From<RateLimitWindow> for String {
+    fn from(window: RateLimitWindow) -> Self {
+        window.to_string()
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -264,6 +582,11 @@ pub struct IntervalsConfig {
     /// Seconds between thread posts.
     #[serde(default = "default_thread_interval_seconds")]
     pub thread_interval_seconds: u64,
+
+    /// Seconds a discovered tweet stays in the cross-keyword dedup cache.
+    /// Tweets seen again within this window are not re-scored.
+    #[serde(default = "default_discovery_dedup_window_seconds")]
+    pub discovery_dedup_window_seconds: u64,
 }
 
 // ---------------------------------------------------------------------------
@@ -280,12 +603,123 @@ pub struct TargetsConfig {
     /// Maximum target account replies per day (separate from general limit).
     #[serde(default = "default_max_target_replies_per_day")]
     pub max_target_replies_per_day: u32,
+
+    /// Per-tier engagement cadence.
+    #[serde(default)]
+    pub tiers: TargetTiersConfig,
 }
 
 fn default_max_target_replies_per_day() -> u32 {
     3
 }
 
+/// Priority tier of a target account.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TargetTier {
+    /// High-priority relationship targets ("dream 100").
+    #[serde(rename = "dream_100")]
+    Dream100,
+    /// Everyone else.
+    #[default]
+    General,
+}
+
+impl TargetTier {
+    /// All tiers, highest priority first.
+    pub const ALL: [TargetTier; 2] = [TargetTier::Dream100, TargetTier::General];
+
+    /// Stable name stored in the database and accepted by the CLI/API.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Dream100 => "dream_100",
+            Self::General => "general",
+        }
+    }
+
+    /// Parse a stored or user-supplied tier name.
+    pub fn parse(s: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|t| t.as_str() == s)
+    }
+}
+
+impl std::fmt::Display for TargetTier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Action types a tier cadence may allow.
+pub const TARGET_ACTION_TYPES: &[&str] = &["reply", "like", "quote"];
+
+/// Engagement cadence for one target tier.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct TierCadence {
+    /// Maximum interactions with a single account of this tier per rolling week.
+    pub max_interactions_per_week: u32,
+
+    /// Action types allowed for this tier (`reply`, `like`, `quote`).
+    pub allowed_actions: Vec<String>,
+
+    /// Queue every interaction for human review, even outside approval mode.
+    #[serde(default)]
+    pub require_approval: bool,
+}
+
+impl TierCadence {
+    /// Whether the given action type is allowed for this tier.
+    pub fn allows(&self, action: &str) -> bool {
+        self.allowed_actions.iter().any(|a| a == action)
+    }
+}
+
+/// Cadence settings for each target tier.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct TargetTiersConfig {
+    /// Cadence for dream-100 targets.
+    #[serde(default = "default_dream_100_cadence")]
+    pub dream_100: TierCadence,
+
+    /// Cadence for general targets.
+    #[serde(default = "default_general_cadence")]
+    pub general: TierCadence,
+}
+
+impl TargetTiersConfig {
+    /// Cadence for the given tier.
+    pub fn cadence(&self, tier: TargetTier) -> &TierCadence {
+        match tier {
+            TargetTier::Dream100 => &self.dream_100,
+            TargetTier::General => &self.general,
+        }
+    }
+}
+
+impl Default for TargetTiersConfig {
+    fn default() -> Self {
+        Self {
+            dream_100: default_dream_100_cadence(),
+            general: default_general_cadence(),
+        }
+    }
+}
+
+fn default_dream_100_cadence() -> TierCadence {
+    TierCadence {
+        max_interactions_per_week: 5,
+        allowed_actions: vec!["reply".to_string(), "like".to_string()],
+        require_approval: true,
+    }
+}
+
+fn default_general_cadence() -> TierCadence {
+    TierCadence {
+        max_interactions_per_week: 2,
+        allowed_actions: vec!["reply".to_string()],
+        require_approval: false,
+    }
+}
+
 // ---------------------------------------------------------------------------
 // LLM
 // ---------------------------------------------------------------------------
@@ -308,6 +742,111 @@ pub struct LlmConfig {
     /// Override URL for custom endpoints.
     #[serde(default)]
     pub base_url: Option<String>,
+
+    /// Mark the system prompt as cacheable on providers that support it (Anthropic).
+    #[serde(default = "default_prompt_caching")]
+    pub prompt_caching: bool,
+
+    /// Route non-urgent bulk generation through the OpenAI Batch API (50% cheaper,
+    /// results within 24 hours). Ignored for other providers.
+    #[serde(default)]
+    pub batch_mode: bool,
+
+    /// Maximum concurrent generation requests per discovery batch.
+    /// Defaults to 1 for ollama (a single local model) and 4 otherwise.
+    #[serde(default)]
+    pub max_concurrency: Option<u32>,
+
+    /// Timeout, retry, and circuit-breaker policies keyed by provider name
+    /// (`[llm.policies.openai]`). Providers without an entry use defaults.
+    #[serde(default)]
+    pub policies: HashMap<String, LlmPolicyConfig>,
+}
+
+impl LlmConfig {
+    /// Concurrent generation requests the discovery loop may issue.
+    pub fn generation_concurrency(&self) -> usize {
+        match self.max_concurrency {
+            Some(n) => n.max(1) as usize,
+            None if self.provider == "ollama" => 1,
+            None => 4,
+        }
+    }
+
+    /// Policy for the configured provider.
+    pub fn active_policy(&self) -> LlmPolicyConfig {
+        self.policies
+            .get(&self.provider)
+            .cloned()
+            .unwrap_or_default()
+    }
+}
+
+fn default_prompt_caching() -> bool {
+    true
+}
+
+/// Timeout, retry, and circuit-breaker policy for one LLM provider.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct LlmPolicyConfig {
+    /// Seconds before a single request is abandoned.
+    /// Defaults to 180 for ollama (local models can be slow) and 60 otherwise.
+    #[serde(default)]
+    pub timeout_seconds: Option<u64>,
+
+    /// Retries after a timeout, network error, 5xx, or rate limit.
+    #[serde(default = "default_llm_max_retries")]
+    pub max_retries: u32,
+
+    /// Delay before the first retry, doubled for each later one.
+    #[serde(default = "default_llm_retry_backoff_ms")]
+    pub retry_backoff_ms: u64,
+
+    /// Consecutive failed requests (after retries) that open the breaker
+    /// and disable the provider. `0` disables the breaker.
+    #[serde(default = "default_llm_breaker_threshold")]
+    pub breaker_threshold: u32,
+
+    /// Seconds the provider stays disabled before a probe request.
+    #[serde(default = "default_llm_breaker_cooldown_seconds")]
+    pub breaker_cooldown_seconds: u64,
+}
+
+impl Default for LlmPolicyConfig {
+    fn default() -> Self {
+        Self {
+            timeout_seconds: None,
+            max_retries: default_llm_max_retries(),
+            retry_backoff_ms: default_llm_retry_backoff_ms(),
+            breaker_threshold: default_llm_breaker_threshold(),
+            breaker_cooldown_seconds: default_llm_breaker_cooldown_seconds(),
+        }
+    }
+}
+
+impl LlmPolicyConfig {
+    /// Request timeout for `provider`.
+    pub fn timeout(&self, provider: &str) -> Duration {
+        let seconds = match self.timeout_seconds {
+            Some(seconds) => seconds,
+            None if provider == "ollama" => 180,
+            None => 60,
+        };
+        Duration::from_secs(seconds)
+    }
+}
+
+fn default_llm_max_retries() -> u32 {
+    2
+}
+fn default_llm_retry_backoff_ms() -> u64 {
+    1000
+}
+fn default_llm_breaker_threshold() -> u32 {
+    5
+}
+fn default_llm_breaker_cooldown_seconds() -> u64 {
+    300
 }
 
 // ---------------------------------------------------------------------------
@@ -324,6 +863,15 @@ pub struct StorageConfig {
     /// Number of days to retain data.
     #[serde(default = "default_retention_days")]
     pub retention_days: u32,
+
+    /// Queries at or above this many milliseconds are logged as slow.
+    #[serde(default = "default_slow_query_ms")]
+    pub slow_query_ms: u64,
+
+    /// Also record slow queries in the `slow_queries` table for
+    /// `tuitbot db slow-queries`.
+    #[serde(default)]
+    pub record_slow_queries: bool,
 }
 
 // ---------------------------------------------------------------------------
@@ -340,6 +888,26 @@ pub struct ServerConfig {
     /// Port to listen on.
     #[serde(default = "default_server_port")]
     pub port: u16,
+
+    /// Also serve the write-profile MCP server at `/api/mcp/http`.
+    #[serde(default)]
+    pub mcp_endpoint: bool,
+
+    /// Largest accepted API request body in bytes (media uploads excepted).
+    #[serde(default = "default_max_request_body_bytes")]
+    pub max_request_body_bytes: usize,
+
+    /// Largest JSON response in bytes before clients are asked to paginate.
+    #[serde(default = "default_max_response_bytes")]
+    pub max_response_bytes: usize,
+
+    /// Per-client request rate limiting.
+    #[serde(default)]
+    pub rate_limit: ApiRateLimitConfig,
+
+    /// Unauthenticated recent-activity feed at `/api/public/feed`.
+    #[serde(default)]
+    pub public_feed: PublicFeedConfig,
 }
 
 impl Default for ServerConfig {
@@ -347,6 +915,11 @@ impl Default for ServerConfig {
         Self {
             host: default_server_host(),
             port: default_server_port(),
+            mcp_endpoint: false,
+            max_request_body_bytes: default_max_request_body_bytes(),
+            max_response_bytes: default_max_response_bytes(),
+            rate_limit: ApiRateLimitConfig::default(),
+            public_feed: PublicFeedConfig::default(),
         }
     }
 }
@@ -357,6 +930,118 @@ fn default_server_host() -> String {
 fn default_server_port() -> u16 {
     3001
 }
+fn default_max_request_body_bytes() -> usize {
+    2 * 1024 * 1024
+}
+fn default_max_response_bytes() -> usize {
+    16 * 1024 * 1024
+}
+
+/// Token-bucket rate limit applied to each API client.
+///
+/// Every request counts against its IP address; authenticated requests also
+/// count against their bearer token or session once it has been validated.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ApiRateLimitConfig {
+    /// Enable rate limiting.
+    #[serde(default = "default_rate_limit_enabled")]
+    pub enabled: bool,
+
+    /// Requests a client may make back to back.
+    #[serde(default = "default_rate_limit_burst")]
+    pub burst: u32,
+
+    /// Sustained requests per second once the burst is spent.
+    #[serde(default = "default_rate_limit_per_second")]
+    pub per_second: f64,
+
+    /// Reverse proxy addresses whose `X-Forwarded-For` header is trusted.
+    /// Requests from any other peer are keyed on the peer address.
+    #[serde(default)]
+    pub trusted_proxies: Vec<String>,
+}
+
+impl Default for ApiRateLimitConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            burst: default_rate_limit_burst(),
+            per_second: default_rate_limit_per_second(),
+            trusted_proxies: Vec::new(),
+        }
+    }
+}
+
+fn default_rate_limit_enabled() -> bool {
+    true
+}
+fn default_rate_limit_burst() -> u32 {
+    60
+}
+fn default_rate_limit_per_second() -> f64 {
+    10.0
+}
+
+/// Fields the public feed can expose for each post.
+pub const PUBLIC_FEED_FIELDS: &[&str] = &[
+    "kind",
+    "text",
+    "url",
+    "created_at",
+    "topic",
+    "in_reply_to_url",
+];
+
+/// Public, read-only feed of recently sent posts for embedding on a website.
+///
+/// Off by default. Only the fields listed in `fields` are returned.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PublicFeedConfig {
+    /// Serve the feed without authentication.
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Fields included for each post (see [`PUBLIC_FEED_FIELDS`]).
+    #[serde(default = "default_public_feed_fields")]
+    pub fields: Vec<String>,
+
+    /// Include sent replies as well as tweets and threads.
+    #[serde(default)]
+    pub include_replies: bool,
+
+    /// Number of posts returned.
+    #[serde(default = "default_public_feed_limit")]
+    pub limit: u32,
+
+    /// How long browsers and proxies may cache the feed, in seconds.
+    #[serde(default = "default_public_feed_cache_seconds")]
+    pub cache_seconds: u64,
+}
+
+impl Default for PublicFeedConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            fields: default_public_feed_fields(),
+            include_replies: false,
+            limit: default_public_feed_limit(),
+            cache_seconds: default_public_feed_cache_seconds(),
+        }
+    }
+}
+
+fn default_public_feed_fields() -> Vec<String> {
+    ["kind", "text", "url", "created_at"]
+        .iter()
+        .map(|f| f.to_string())
+        .collect()
+}
+fn default_public_feed_limit() -> u32 {
+    10
+}
+fn default_public_feed_cache_seconds() -> u64 {
+    300
+}
 
 // ---------------------------------------------------------------------------
 // Logging
@@ -404,6 +1089,42 @@ fn default_reply_count_max() -> f32 {
 fn default_content_type_max() -> f32 {
     10.0
 }
+fn default_calibration_target_replies_per_day() -> u32 {
+    5
+}
+fn default_calibration_floor() -> u32 {
+    45
+}
+fn default_calibration_ceiling() -> u32 {
+    85
+}
+fn default_calibration_step() -> u32 {
+    5
+}
+fn default_discovery_decay_after_hours() -> u32 {
+    12
+}
+fn default_discovery_max_age_hours() -> u32 {
+    48
+}
+fn default_mentions_max_age_hours() -> u32 {
+    72
+}
+fn default_spaces_adjustment() -> f32 {
+    -15.0
+}
+fn default_media_only_adjustment() -> f32 {
+    -10.0
+}
+fn default_plugin_fuel() -> u64 {
+    10_000_000
+}
+fn default_plugin_max_adjustment() -> f32 {
+    20.0
+}
+fn default_plugin_max_memory_mb() -> u32 {
+    16
+}
 fn default_max_replies_per_day() -> u32 {
     5
 }
@@ -413,6 +1134,12 @@ fn default_max_tweets_per_day() -> u32 {
 fn default_max_threads_per_week() -> u32 {
     1
 }
+fn default_max_likes_per_day() -> u32 {
+    50
+}
+fn default_max_follows_per_day() -> u32 {
+    20
+}
 fn default_min_action_delay_seconds() -> u64 {
     45
 }
@@ -425,6 +1152,9 @@ fn default_mentions_check_seconds() -> u64 {
 fn default_discovery_search_seconds() -> u64 {
     900
 }
+fn default_discovery_dedup_window_seconds() -> u64 {
+    86400
+}
 fn default_content_post_window_seconds() -> u64 {
     10800
 }
@@ -451,6 +1181,9 @@ fn default_db_path() -> String {
 fn default_retention_days() -> u32 {
     90
 }
+fn default_slow_query_ms() -> u64 {
+    1000
+}
 
 // ---------------------------------------------------------------------------
 // Content Sources
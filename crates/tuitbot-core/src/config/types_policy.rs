@@ -1,8 +1,12 @@
-//! Schedule, MCP policy, and circuit breaker configuration types.
+//! Schedule, MCP policy, circuit breaker, media, thread format, disclosure,
+//! quote tweet, auto-triage, health monitor, warm-up, blocklist, and
+//! trusted author configuration types.
 
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+use crate::error::ConfigError;
+
 // ---------------------------------------------------------------------------
 // Schedule
 // ---------------------------------------------------------------------------
@@ -86,6 +90,123 @@ fn default_thread_preferred_time() -> String {
     "10:00".to_string()
 }
 
+impl ScheduleConfig {
+    /// Check active hours, timezone, day names, and posting times.
+    pub(super) fn validate(&self, errors: &mut Vec<ConfigError>) {
+        if self.active_hours_start > 23 {
+            errors.push(ConfigError::InvalidValue {
+                field: "schedule.active_hours_start".to_string(),
+                message: "must be between 0 and 23".to_string(),
+            });
+        }
+        if self.active_hours_end > 23 {
+            errors.push(ConfigError::InvalidValue {
+                field: "schedule.active_hours_end".to_string(),
+                message: "must be between 0 and 23".to_string(),
+            });
+        }
+        if !self.timezone.is_empty() && self.timezone.parse::<chrono_tz::Tz>().is_err() {
+            errors.push(ConfigError::InvalidValue {
+                field: "schedule.timezone".to_string(),
+                message: format!("'{}' is not a valid IANA timezone name", self.timezone),
+            });
+        }
+
+        let valid_days = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+        for day in &self.active_days {
+            if !valid_days.contains(&day.as_str()) {
+                errors.push(ConfigError::InvalidValue {
+                    field: "schedule.active_days".to_string(),
+                    message: format!(
+                        "'{}' is not a valid day abbreviation (use Mon, Tue, Wed, Thu, Fri, Sat, Sun)",
+                        day
+                    ),
+                });
+                break;
+            }
+        }
+
+        // Validate preferred_times
+        for time_str in &self.preferred_times {
+            if time_str != "auto" && !is_valid_hhmm(time_str) {
+                errors.push(ConfigError::InvalidValue {
+                    field: "schedule.preferred_times".to_string(),
+                    message: format!(
+                        "'{}' is not a valid time (use HH:MM 24h format or \"auto\")",
+                        time_str
+                    ),
+                });
+                break;
+            }
+        }
+
+        // Validate preferred_times_override keys and values
+        for (day, times) in &self.preferred_times_override {
+            if !valid_days.contains(&day.as_str()) {
+                errors.push(ConfigError::InvalidValue {
+                    field: "schedule.preferred_times_override".to_string(),
+                    message: format!(
+                        "'{}' is not a valid day abbreviation (use Mon, Tue, Wed, Thu, Fri, Sat, Sun)",
+                        day
+                    ),
+                });
+                break;
+            }
+            for time_str in times {
+                if !is_valid_hhmm(time_str) {
+                    errors.push(ConfigError::InvalidValue {
+                        field: "schedule.preferred_times_override".to_string(),
+                        message: format!(
+                            "'{}' is not a valid time for {} (use HH:MM 24h format)",
+                            time_str, day
+                        ),
+                    });
+                    break;
+                }
+            }
+        }
+
+        // Validate thread_preferred_day
+        if let Some(day) = &self.thread_preferred_day {
+            if !valid_days.contains(&day.as_str()) {
+                errors.push(ConfigError::InvalidValue {
+                    field: "schedule.thread_preferred_day".to_string(),
+                    message: format!(
+                        "'{}' is not a valid day abbreviation (use Mon, Tue, Wed, Thu, Fri, Sat, Sun)",
+                        day
+                    ),
+                });
+            }
+        }
+
+        // Validate thread_preferred_time
+        if !is_valid_hhmm(&self.thread_preferred_time) {
+            errors.push(ConfigError::InvalidValue {
+                field: "schedule.thread_preferred_time".to_string(),
+                message: format!(
+                    "'{}' is not a valid time (use HH:MM 24h format)",
+                    self.thread_preferred_time
+                ),
+            });
+        }
+    }
+}
+
+/// Check if a string is a valid HH:MM time (24h format).
+fn is_valid_hhmm(s: &str) -> bool {
+    let parts: Vec<&str> = s.split(':').collect();
+    if parts.len() != 2 {
+        return false;
+    }
+    let Ok(hour) = parts[0].parse::<u8>() else {
+        return false;
+    };
+    let Ok(minute) = parts[1].parse::<u8>() else {
+        return false;
+    };
+    hour <= 23 && minute <= 59
+}
+
 // ---------------------------------------------------------------------------
 // MCP Policy
 // ---------------------------------------------------------------------------
@@ -116,6 +237,16 @@ pub struct McpPolicyConfig {
     #[serde(default)]
     pub dry_run_mutations: bool,
 
+    /// When true, allowed mutations are staged and return a confirmation
+    /// token; they execute only once confirmed via `confirm_action` or the
+    /// dashboard within `confirmation_ttl_seconds`.
+    #[serde(default)]
+    pub confirmation_mode: bool,
+
+    /// How long a staged mutation stays confirmable, in seconds.
+    #[serde(default = "default_confirmation_ttl_seconds")]
+    pub confirmation_ttl_seconds: u64,
+
     /// Maximum MCP mutations allowed per hour (aggregate across all tools).
     #[serde(default = "default_max_mutations_per_hour")]
     pub max_mutations_per_hour: u32,
@@ -132,9 +263,30 @@ pub struct McpPolicyConfig {
     /// Per-dimension rate limits (beyond the global `max_mutations_per_hour`).
     #[serde(default)]
     pub rate_limits: Vec<crate::mcp_policy::types::PolicyRateLimit>,
+
+    /// Per-tool overrides keyed by tool name (e.g. `x_delete_tweet`).
+    #[serde(default)]
+    pub tools: HashMap<String, crate::mcp_policy::types::ToolPolicy>,
+
+    /// Per-category overrides keyed by category (e.g. `engage`).
+    #[serde(default)]
+    pub categories: HashMap<String, crate::mcp_policy::types::ToolPolicy>,
 }
 
-fn default_true() -> bool {
+impl McpPolicyConfig {
+    /// Configured rate limits plus the hourly caps derived from
+    /// per-tool and per-category overrides.
+    ///
+    /// Pass this (not `rate_limits`) when recording mutations so the
+    /// override counters advance.
+    pub fn effective_rate_limits(&self) -> Vec<crate::mcp_policy::types::PolicyRateLimit> {
+        let mut limits = self.rate_limits.clone();
+        limits.extend(crate::mcp_policy::overrides::override_rate_limits(self));
+        limits
+    }
+}
+
+pub(super) fn default_true() -> bool {
     true
 }
 
@@ -147,10 +299,71 @@ fn default_require_approval_for() -> Vec<String> {
     ]
 }
 
+fn default_confirmation_ttl_seconds() -> u64 {
+    300
+}
+
 fn default_max_mutations_per_hour() -> u32 {
     20
 }
 
+impl McpPolicyConfig {
+    /// Check tool lists, category names, and per-tool caps.
+    pub(super) fn validate(&self, errors: &mut Vec<ConfigError>) {
+        // Validate MCP policy: tools can't be in both blocked_tools and require_approval_for
+        for tool in &self.blocked_tools {
+            if self.require_approval_for.contains(tool) {
+                errors.push(ConfigError::InvalidValue {
+                    field: "mcp_policy.blocked_tools".to_string(),
+                    message: format!(
+                        "tool '{tool}' cannot be in both blocked_tools and require_approval_for"
+                    ),
+                });
+                break;
+            }
+        }
+
+        if self.confirmation_mode && self.confirmation_ttl_seconds == 0 {
+            errors.push(ConfigError::InvalidValue {
+                field: "mcp_policy.confirmation_ttl_seconds".to_string(),
+                message: "must be greater than 0 when confirmation_mode is enabled".to_string(),
+            });
+        }
+
+        // Validate MCP per-category overrides name real categories
+        for category in self.categories.keys() {
+            if category
+                .parse::<crate::mcp_policy::types::ToolCategory>()
+                .is_err()
+            {
+                errors.push(ConfigError::InvalidValue {
+                    field: format!("mcp_policy.categories.{category}"),
+                    message: format!("unknown tool category '{category}'"),
+                });
+            }
+        }
+
+        // Validate MCP per-tool / per-category caps are non-zero
+        let overrides = self
+            .tools
+            .iter()
+            .map(|(name, p)| (format!("mcp_policy.tools.{name}"), p))
+            .chain(
+                self.categories
+                    .iter()
+                    .map(|(name, p)| (format!("mcp_policy.categories.{name}"), p)),
+            );
+        for (field, policy) in overrides {
+            if policy.max_per_hour == Some(0) {
+                errors.push(ConfigError::InvalidValue {
+                    field: format!("{field}.max_per_hour"),
+                    message: "must be greater than 0 (set enabled = false to disable)".to_string(),
+                });
+            }
+        }
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Circuit Breaker
 // ---------------------------------------------------------------------------
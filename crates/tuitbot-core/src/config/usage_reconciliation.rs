@@ -0,0 +1,73 @@
+//! `[usage_reconciliation]`: matching local counters against X's usage report.
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::ConfigError;
+
+/// Periodic comparison of X's official post usage with the local ledger.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct UsageReconciliationConfig {
+    /// Run the reconciliation job (needs a tier that can read `/2/usage/tweets`).
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Minutes between reconciliation runs.
+    #[serde(default = "default_reconcile_interval_minutes")]
+    pub interval_minutes: u64,
+
+    /// Complete UTC days compared on each run.
+    #[serde(default = "default_reconcile_lookback_days")]
+    pub lookback_days: u32,
+
+    /// Lowest factor the pacing budgets may be scaled down to when the
+    /// ledger undercounts (0.0-1.0).
+    #[serde(default = "default_reconcile_min_pacing_scale")]
+    pub min_pacing_scale: f64,
+}
+
+impl Default for UsageReconciliationConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            interval_minutes: default_reconcile_interval_minutes(),
+            lookback_days: default_reconcile_lookback_days(),
+            min_pacing_scale: default_reconcile_min_pacing_scale(),
+        }
+    }
+}
+
+fn default_reconcile_interval_minutes() -> u64 {
+    360
+}
+fn default_reconcile_lookback_days() -> u32 {
+    7
+}
+fn default_reconcile_min_pacing_scale() -> f64 {
+    0.5
+}
+
+impl UsageReconciliationConfig {
+    /// Check the interval, lookback, and pacing floor when enabled.
+    pub(super) fn validate(&self, errors: &mut Vec<ConfigError>) {
+        if self.enabled {
+            if self.interval_minutes < 15 {
+                errors.push(ConfigError::InvalidValue {
+                    field: "usage_reconciliation.interval_minutes".to_string(),
+                    message: "must be at least 15".to_string(),
+                });
+            }
+            if !(1..=90).contains(&self.lookback_days) {
+                errors.push(ConfigError::InvalidValue {
+                    field: "usage_reconciliation.lookback_days".to_string(),
+                    message: "must be between 1 and 90".to_string(),
+                });
+            }
+            if !(self.min_pacing_scale > 0.0 && self.min_pacing_scale <= 1.0) {
+                errors.push(ConfigError::InvalidValue {
+                    field: "usage_reconciliation.min_pacing_scale".to_string(),
+                    message: "must be greater than 0.0 and at most 1.0".to_string(),
+                });
+            }
+        }
+    }
+}
@@ -1,6 +1,8 @@
 //! Configuration validation logic.
 
-use super::Config;
+use super::trusted_authors::validate_trusted_authors;
+use super::{Config, TargetTier, PUBLIC_FEED_FIELDS, TARGET_ACTION_TYPES, WINDOWED_ACTION_TYPES};
+use crate::content::frameworks::ReplyArchetype;
 use crate::error::ConfigError;
 
 impl Config {
@@ -22,6 +24,67 @@ impl Config {
             });
         }
 
+        let archetype_keys: Vec<&str> = ReplyArchetype::ALL.iter().map(|a| a.key()).collect();
+        for key in self.business.reply_archetype_weights.keys() {
+            if !archetype_keys.contains(&key.as_str()) {
+                errors.push(ConfigError::InvalidValue {
+                    field: format!("business.reply_archetype_weights.{key}"),
+                    message: format!(
+                        "unknown reply archetype; expected one of: {}",
+                        archetype_keys.join(", ")
+                    ),
+                });
+            }
+        }
+        if !self.business.reply_archetype_weights.is_empty()
+            && self
+                .business
+                .reply_archetype_weights
+                .values()
+                .all(|w| *w == 0)
+        {
+            errors.push(ConfigError::InvalidValue {
+                field: "business.reply_archetype_weights".to_string(),
+                message: "at least one weight must be greater than 0".to_string(),
+            });
+        }
+
+        for pillar in self.business.pillar_overrides.keys() {
+            let known = self
+                .business
+                .content_pillars
+                .iter()
+                .any(|p| p.eq_ignore_ascii_case(pillar));
+            if !known || pillar.trim().is_empty() {
+                errors.push(ConfigError::InvalidValue {
+                    field: format!("business.pillar_overrides.{pillar}"),
+                    message: "must name one of business.content_pillars".to_string(),
+                });
+            }
+        }
+
+        // Validate X API base URL override
+        if let Some(url) = &self.x_api.base_url {
+            let url = url.trim();
+            if !url.starts_with("http://") && !url.starts_with("https://") {
+                errors.push(ConfigError::InvalidValue {
+                    field: "x_api.base_url".to_string(),
+                    message: "must be an http:// or https:// URL".to_string(),
+                });
+            }
+        }
+
+        // Validate scraper instance URL
+        if let Some(url) = &self.x_api.scraper_base_url {
+            let url = url.trim();
+            if !url.starts_with("http://") && !url.starts_with("https://") {
+                errors.push(ConfigError::InvalidValue {
+                    field: "x_api.scraper_base_url".to_string(),
+                    message: "must be an http:// or https:// URL".to_string(),
+                });
+            }
+        }
+
         // Validate LLM provider
         if !self.llm.provider.is_empty() {
             match self.llm.provider.as_str() {
@@ -49,6 +112,27 @@ impl Config {
             }
         }
 
+        for (provider, policy) in &self.llm.policies {
+            if !matches!(provider.as_str(), "openai" | "anthropic" | "ollama") {
+                errors.push(ConfigError::InvalidValue {
+                    field: format!("llm.policies.{provider}"),
+                    message: "unknown provider; expected openai, anthropic, or ollama".to_string(),
+                });
+            }
+            if policy.timeout_seconds == Some(0) {
+                errors.push(ConfigError::InvalidValue {
+                    field: format!("llm.policies.{provider}.timeout_seconds"),
+                    message: "must be greater than 0".to_string(),
+                });
+            }
+            if policy.max_retries > 10 {
+                errors.push(ConfigError::InvalidValue {
+                    field: format!("llm.policies.{provider}.max_retries"),
+                    message: "must be at most 10".to_string(),
+                });
+            }
+        }
+
         // Validate auth mode
         if !self.auth.mode.is_empty() {
             match self.auth.mode.as_str() {
@@ -70,6 +154,87 @@ impl Config {
             });
         }
 
+        let adjustments = &self.scoring.entity_adjustments;
+        for (name, value) in [
+            ("spaces", adjustments.spaces),
+            ("article", adjustments.article),
+            ("media_only", adjustments.media_only),
+        ] {
+            if !(-100.0..=100.0).contains(&value) {
+                errors.push(ConfigError::InvalidValue {
+                    field: format!("scoring.entity_adjustments.{name}"),
+                    message: "must be between -100 and 100".to_string(),
+                });
+            }
+        }
+
+        let plugin = &self.scoring.plugin;
+        if plugin.is_enabled() {
+            if !cfg!(feature = "wasm-scoring") {
+                errors.push(ConfigError::InvalidValue {
+                    field: "scoring.plugin.path".to_string(),
+                    message: "this build does not include the wasm-scoring feature".to_string(),
+                });
+            }
+            if plugin.fuel == 0 {
+                errors.push(ConfigError::InvalidValue {
+                    field: "scoring.plugin.fuel".to_string(),
+                    message: "must be greater than 0".to_string(),
+                });
+            }
+            if !(0.0..=100.0).contains(&plugin.max_adjustment) {
+                errors.push(ConfigError::InvalidValue {
+                    field: "scoring.plugin.max_adjustment".to_string(),
+                    message: "must be between 0 and 100".to_string(),
+                });
+            }
+            if plugin.max_memory_mb == 0 {
+                errors.push(ConfigError::InvalidValue {
+                    field: "scoring.plugin.max_memory_mb".to_string(),
+                    message: "must be greater than 0".to_string(),
+                });
+            }
+        }
+
+        let freshness = &self.scoring.freshness;
+        if freshness.discovery_max_age_hours > 0
+            && freshness.discovery_decay_after_hours >= freshness.discovery_max_age_hours
+        {
+            errors.push(ConfigError::InvalidValue {
+                field: "scoring.freshness.discovery_decay_after_hours".to_string(),
+                message: "must be less than scoring.freshness.discovery_max_age_hours".to_string(),
+            });
+        }
+
+        let calibration = &self.scoring.calibration;
+        if calibration.enabled {
+            if calibration.ceiling > 100 {
+                errors.push(ConfigError::InvalidValue {
+                    field: "scoring.calibration.ceiling".to_string(),
+                    message: "must be between 0 and 100".to_string(),
+                });
+            }
+            if calibration.floor > calibration.ceiling {
+                errors.push(ConfigError::InvalidValue {
+                    field: "scoring.calibration.floor".to_string(),
+                    message: "must be less than or equal to scoring.calibration.ceiling"
+                        .to_string(),
+                });
+            }
+            if calibration.step == 0 {
+                errors.push(ConfigError::InvalidValue {
+                    field: "scoring.calibration.step".to_string(),
+                    message: "must be greater than 0".to_string(),
+                });
+            }
+            if calibration.target_replies_per_day == 0 {
+                errors.push(ConfigError::InvalidValue {
+                    field: "scoring.calibration.target_replies_per_day".to_string(),
+                    message: "must be greater than 0".to_string(),
+                });
+            }
+        }
+
         // Validate limits
         if self.limits.max_replies_per_day == 0 {
             errors.push(ConfigError::InvalidValue {
@@ -92,104 +257,51 @@ impl Config {
             });
         }
 
-        if self.limits.min_action_delay_seconds > self.limits.max_action_delay_seconds {
+        if self.limits.max_likes_per_day == 0 {
             errors.push(ConfigError::InvalidValue {
-                field: "limits.min_action_delay_seconds".to_string(),
-                message: "must be less than or equal to max_action_delay_seconds".to_string(),
+                field: "limits.max_likes_per_day".to_string(),
+                message: "must be greater than 0".to_string(),
             });
         }
 
-        // Validate schedule
-        if self.schedule.active_hours_start > 23 {
+        if self.limits.max_follows_per_day == 0 {
             errors.push(ConfigError::InvalidValue {
-                field: "schedule.active_hours_start".to_string(),
-                message: "must be between 0 and 23".to_string(),
+                field: "limits.max_follows_per_day".to_string(),
+                message: "must be greater than 0".to_string(),
             });
         }
-        if self.schedule.active_hours_end > 23 {
+
+        if self.limits.min_action_delay_seconds > self.limits.max_action_delay_seconds {
             errors.push(ConfigError::InvalidValue {
-                field: "schedule.active_hours_end".to_string(),
-                message: "must be between 0 and 23".to_string(),
+                field: "limits.min_action_delay_seconds".to_string(),
+                message: "must be less than or equal to max_action_delay_seconds".to_string(),
             });
         }
-        if !self.schedule.timezone.is_empty()
-            && self.schedule.timezone.parse::<chrono_tz::Tz>().is_err()
+
+        self.schedule.validate(&mut errors);
+
+        if !self.limits.reset_timezone.is_empty()
+            && self.limits.reset_timezone.parse::<chrono_tz::Tz>().is_err()
         {
             errors.push(ConfigError::InvalidValue {
-                field: "schedule.timezone".to_string(),
+                field: "limits.reset_timezone".to_string(),
                 message: format!(
                     "'{}' is not a valid IANA timezone name",
-                    self.schedule.timezone
+                    self.limits.reset_timezone
                 ),
             });
         }
-        let valid_days = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
-        for day in &self.schedule.active_days {
-            if !valid_days.contains(&day.as_str()) {
+        for action_type in self.limits.windows.keys() {
+            if !WINDOWED_ACTION_TYPES.contains(&action_type.as_str()) {
                 errors.push(ConfigError::InvalidValue {
-                    field: "schedule.active_days".to_string(),
+                    field: format!("limits.windows.{action_type}"),
                     message: format!(
-                        "'{}' is not a valid day abbreviation (use Mon, Tue, Wed, Thu, Fri, Sat, Sun)",
-                        day
+                        "unknown action type; expected one of: {}",
+                        WINDOWED_ACTION_TYPES.join(", ")
                     ),
                 });
-                break;
             }
         }
-
-        // Validate preferred_times
-        for time_str in &self.schedule.preferred_times {
-            if time_str != "auto" && !is_valid_hhmm(time_str) {
-                errors.push(ConfigError::InvalidValue {
-                    field: "schedule.preferred_times".to_string(),
-                    message: format!(
-                        "'{}' is not a valid time (use HH:MM 24h format or \"auto\")",
-                        time_str
-                    ),
-                });
-                break;
-            }
-        }
-
-        // Validate preferred_times_override keys and values
-        for (day, times) in &self.schedule.preferred_times_override {
-            if !valid_days.contains(&day.as_str()) {
-                errors.push(ConfigError::InvalidValue {
-                    field: "schedule.preferred_times_override".to_string(),
-                    message: format!(
-                        "'{}' is not a valid day abbreviation (use Mon, Tue, Wed, Thu, Fri, Sat, Sun)",
-                        day
-                    ),
-                });
-                break;
-            }
-            for time_str in times {
-                if !is_valid_hhmm(time_str) {
-                    errors.push(ConfigError::InvalidValue {
-                        field: "schedule.preferred_times_override".to_string(),
-                        message: format!(
-                            "'{}' is not a valid time for {} (use HH:MM 24h format)",
-                            time_str, day
-                        ),
-                    });
-                    break;
-                }
-            }
-        }
-
-        // Validate MCP policy: tools can't be in both blocked_tools and require_approval_for
-        for tool in &self.mcp_policy.blocked_tools {
-            if self.mcp_policy.require_approval_for.contains(tool) {
-                errors.push(ConfigError::InvalidValue {
-                    field: "mcp_policy.blocked_tools".to_string(),
-                    message: format!(
-                        "tool '{tool}' cannot be in both blocked_tools and require_approval_for"
-                    ),
-                });
-                break;
-            }
-        }
-
         // Count effective slots per day vs max_tweets_per_day
         let effective_slots = if self.schedule.preferred_times.is_empty() {
             0
@@ -222,28 +334,88 @@ impl Config {
             });
         }
 
-        // Validate thread_preferred_day
-        if let Some(day) = &self.schedule.thread_preferred_day {
-            if !valid_days.contains(&day.as_str()) {
+        self.mcp_policy.validate(&mut errors);
+
+        self.media.validate(&mut errors);
+        self.thread_format.validate(&mut errors);
+        self.warmup.validate(&mut errors);
+        self.content_mix.validate(&mut errors);
+        self.safe_mode.validate(&mut errors);
+        self.blocklist.validate(&mut errors);
+        validate_trusted_authors(
+            &self.trusted_authors,
+            &self.blocklist.protected,
+            &mut errors,
+        );
+        self.remote_backup.validate(&mut errors);
+        self.hooks.validate(&mut errors);
+        self.disclosure.validate(&mut errors);
+        self.quote_tweets.validate(&mut errors);
+        self.auto_triage.validate(&mut errors);
+        self.mention_ack.validate(&mut errors);
+        self.approval_sla.validate(&mut errors);
+        self.context_check.validate(&mut errors);
+        self.reciprocity.validate(&mut errors);
+        self.author_overlap.validate(&mut errors);
+        self.trending.validate(&mut errors);
+        self.discovery_sources.validate(&mut errors);
+        self.reddit.validate(&mut errors);
+        self.notifications.validate(&mut errors);
+        // Validate target tiers
+        for tier in TargetTier::ALL {
+            let cadence = self.targets.tiers.cadence(tier);
+            if cadence.max_interactions_per_week == 0 {
                 errors.push(ConfigError::InvalidValue {
-                    field: "schedule.thread_preferred_day".to_string(),
-                    message: format!(
-                        "'{}' is not a valid day abbreviation (use Mon, Tue, Wed, Thu, Fri, Sat, Sun)",
-                        day
-                    ),
+                    field: format!("targets.tiers.{tier}.max_interactions_per_week"),
+                    message: "must be greater than 0".to_string(),
                 });
             }
+            for action in &cadence.allowed_actions {
+                if !TARGET_ACTION_TYPES.contains(&action.as_str()) {
+                    errors.push(ConfigError::InvalidValue {
+                        field: format!("targets.tiers.{tier}.allowed_actions"),
+                        message: format!(
+                            "unknown action '{action}', must be one of: {}",
+                            TARGET_ACTION_TYPES.join(", ")
+                        ),
+                    });
+                }
+            }
         }
 
-        // Validate thread_preferred_time
-        if !is_valid_hhmm(&self.schedule.thread_preferred_time) {
-            errors.push(ConfigError::InvalidValue {
-                field: "schedule.thread_preferred_time".to_string(),
-                message: format!(
-                    "'{}' is not a valid time (use HH:MM 24h format)",
-                    self.schedule.thread_preferred_time
-                ),
-            });
+        self.health_monitor.validate(&mut errors);
+        self.pacing.validate(&mut errors);
+        self.usage_reconciliation.validate(&mut errors);
+        // Validate trusted proxy addresses
+        for proxy in &self.server.rate_limit.trusted_proxies {
+            if proxy.parse::<std::net::IpAddr>().is_err() {
+                errors.push(ConfigError::InvalidValue {
+                    field: "server.rate_limit.trusted_proxies".to_string(),
+                    message: format!("'{proxy}' is not an IP address"),
+                });
+            }
+        }
+
+        // Validate the public feed
+        if self.server.public_feed.enabled {
+            let feed = &self.server.public_feed;
+            for field in &feed.fields {
+                if !PUBLIC_FEED_FIELDS.contains(&field.as_str()) {
+                    errors.push(ConfigError::InvalidValue {
+                        field: "server.public_feed.fields".to_string(),
+                        message: format!(
+                            "unknown field '{field}'; expected one of: {}",
+                            PUBLIC_FEED_FIELDS.join(", ")
+                        ),
+                    });
+                }
+            }
+            if !(1..=50).contains(&feed.limit) {
+                errors.push(ConfigError::InvalidValue {
+                    field: "server.public_feed.limit".to_string(),
+                    message: "must be between 1 and 50".to_string(),
+                });
+            }
         }
 
         // Validate content sources against deployment capabilities
@@ -266,18 +438,3 @@ impl Config {
         }
     }
 }
-
-/// Check if a string is a valid HH:MM time (24h format).
-fn is_valid_hhmm(s: &str) -> bool {
-    let parts: Vec<&str> = s.split(':').collect();
-    if parts.len() != 2 {
-        return false;
-    }
-    let Ok(hour) = parts[0].parse::<u8>() else {
-        return false;
-    };
-    let Ok(minute) = parts[1].parse::<u8>() else {
-        return false;
-    };
-    hour <= 23 && minute <= 59
-}
@@ -0,0 +1,69 @@
+//! `[warmup]`: gradual ramp-up of limits for new or idle accounts.
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::ConfigError;
+
+/// Gradual ramp-up of daily and weekly caps for new or idle accounts.
+///
+/// Caps start at `start_percent` of `[limits]` and rise evenly each week
+/// until the full limits apply after `weeks` weeks.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct WarmupConfig {
+    /// Enable the warm-up schedule.
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Weeks until the full limits apply.
+    #[serde(default = "default_warmup_weeks")]
+    pub weeks: u32,
+
+    /// Percent of each cap allowed in the first week (1-100).
+    #[serde(default = "default_warmup_start_percent")]
+    pub start_percent: u32,
+
+    /// Restart the warm-up after this many days without posting. 0 never restarts.
+    #[serde(default = "default_warmup_inactive_days")]
+    pub inactive_days: u32,
+}
+
+impl Default for WarmupConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            weeks: default_warmup_weeks(),
+            start_percent: default_warmup_start_percent(),
+            inactive_days: default_warmup_inactive_days(),
+        }
+    }
+}
+
+fn default_warmup_weeks() -> u32 {
+    4
+}
+fn default_warmup_start_percent() -> u32 {
+    25
+}
+fn default_warmup_inactive_days() -> u32 {
+    14
+}
+
+impl WarmupConfig {
+    /// Check the ramp length and starting percentage when enabled.
+    pub(super) fn validate(&self, errors: &mut Vec<ConfigError>) {
+        if self.enabled {
+            if self.weeks == 0 || self.weeks > 52 {
+                errors.push(ConfigError::InvalidValue {
+                    field: "warmup.weeks".to_string(),
+                    message: "must be between 1 and 52".to_string(),
+                });
+            }
+            if self.start_percent == 0 || self.start_percent > 100 {
+                errors.push(ConfigError::InvalidValue {
+                    field: "warmup.start_percent".to_string(),
+                    message: "must be between 1 and 100".to_string(),
+                });
+            }
+        }
+    }
+}
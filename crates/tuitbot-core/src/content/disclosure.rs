@@ -0,0 +1,122 @@
+//! Disclaimer and signature injection.
+//!
+//! A configured disclosure is appended after a blank line. When the result
+//! would exceed the tweet limit, the body is shortened at a sentence
+//! boundary; the disclosure itself is never cut.
+
+use crate::config::{BusinessProfile, DisclosureConfig};
+use crate::content::length::{truncate_at_sentence, tweet_weighted_len, MAX_TWEET_CHARS};
+
+/// Longest disclosure template accepted by config validation.
+pub const MAX_DISCLOSURE_CHARS: usize = 100;
+
+/// Placed between the body and the disclosure.
+const SEPARATOR: &str = "\n\n";
+
+/// Whether `text` names the product or links to its site.
+pub fn mentions_product(text: &str, business: &BusinessProfile) -> bool {
+    let text = text.to_lowercase();
+    let name = business.product_name.trim().to_lowercase();
+    if !name.is_empty() && text.contains(&name) {
+        return true;
+    }
+    business
+        .product_url
+        .as_deref()
+        .map(product_host)
+        .is_some_and(|host| !host.is_empty() && text.contains(&host))
+}
+
+/// Disclosure that `texts` must carry as `action_type`, if any.
+///
+/// With `product_mentions_only`, a disclosure is required only when one of
+/// the texts mentions the product.
+pub fn required_disclosure<'a>(
+    config: &DisclosureConfig,
+    business: &BusinessProfile,
+    action_type: &str,
+    texts: impl IntoIterator<Item = &'a str>,
+) -> Option<String> {
+    let disclosure = config.template_for(action_type, &business.product_name)?;
+    let applies =
+        !config.product_mentions_only || texts.into_iter().any(|t| mentions_product(t, business));
+    applies.then_some(disclosure)
+}
+
+/// Whether `text` already contains `disclosure` (case-insensitive).
+pub fn has_disclosure(text: &str, disclosure: &str) -> bool {
+    text.to_lowercase().contains(&disclosure.to_lowercase())
+}
+
+/// Append `disclosure` to `text`, shortening the body to stay within the
+/// tweet limit. Text that already carries the disclosure is returned as is.
+pub fn append_disclosure(text: &str, disclosure: &str) -> String {
+    let body = text.trim_end();
+    if has_disclosure(body, disclosure) {
+        return body.to_string();
+    }
+    let suffix = format!("{SEPARATOR}{disclosure}");
+    let budget = MAX_TWEET_CHARS.saturating_sub(tweet_weighted_len(&suffix));
+    let body = truncate_at_sentence(body, budget);
+    format!("{}{suffix}", body.trim_end())
+}
+
+fn product_host(url: &str) -> String {
+    let url = url.trim().to_lowercase();
+    let rest = url.split_once("://").map_or(url.as_str(), |(_, rest)| rest);
+    let host = rest.split(['/', '?', '#']).next().unwrap_or_default();
+    host.strip_prefix("www.").unwrap_or(host).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn business() -> BusinessProfile {
+        BusinessProfile {
+            product_name: "Tuitbot".to_string(),
+            product_url: Some("https://www.tuitbot.dev/pricing".to_string()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn disclosure_survives_truncation() {
+        let body = "Short sentence here. ".repeat(20);
+        let out = append_disclosure(&body, "(I build Tuitbot)");
+        assert!(out.ends_with("\n\n(I build Tuitbot)"));
+        assert!(tweet_weighted_len(&out) <= MAX_TWEET_CHARS);
+        assert!(out.starts_with("Short sentence here."));
+
+        let again = append_disclosure(&out, "(I build Tuitbot)");
+        assert_eq!(again, out);
+    }
+
+    #[test]
+    fn required_only_when_product_is_mentioned() {
+        let config = DisclosureConfig {
+            reply: Some("(I work on {product_name})".to_string()),
+            ..Default::default()
+        };
+        let business = business();
+
+        assert_eq!(
+            required_disclosure(&config, &business, "reply", ["Try tuitbot.dev"]),
+            Some("(I work on Tuitbot)".to_string())
+        );
+        assert_eq!(
+            required_disclosure(&config, &business, "reply", ["Nice thread"]),
+            None
+        );
+        assert_eq!(
+            required_disclosure(&config, &business, "tweet", ["Tuitbot ships"]),
+            None
+        );
+
+        let always = DisclosureConfig {
+            product_mentions_only: false,
+            ..config
+        };
+        assert!(required_disclosure(&always, &business, "reply", ["Nice thread"]).is_some());
+    }
+}
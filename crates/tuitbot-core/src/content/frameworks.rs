@@ -1,10 +1,12 @@
 //! Content frameworks for varied, human-sounding output.
 //!
-//! Provides archetypes for replies, formats for tweets, and structures
-//! for threads. Each variant includes prompt fragment guidance so the
+//! Provides archetypes for replies and quote tweets, formats for tweets,
+//! and structures for threads. Each variant includes prompt fragment guidance so the
 //! LLM produces distinctly different content depending on the chosen
 //! framework.
 
+use std::collections::BTreeMap;
+
 use rand::seq::SliceRandom;
 
 // ============================================================================
@@ -28,21 +30,58 @@ pub enum ReplyArchetype {
 }
 
 impl ReplyArchetype {
-    /// Weighted selection — prefer archetypes that start conversations.
+    /// All archetypes.
+    pub const ALL: [Self; 5] = [
+        Self::AgreeAndExpand,
+        Self::RespectfulDisagree,
+        Self::AddData,
+        Self::AskQuestion,
+        Self::ShareExperience,
+    ];
+
+    /// Key used in `business.reply_archetype_weights`.
+    pub fn key(self) -> &'static str {
+        match self {
+            Self::AgreeAndExpand => "agree_and_expand",
+            Self::RespectfulDisagree => "respectful_disagree",
+            Self::AddData => "add_data",
+            Self::AskQuestion => "ask_question",
+            Self::ShareExperience => "share_experience",
+        }
+    }
+
+    /// Built-in weight — prefer archetypes that start conversations.
+    fn default_weight(self) -> u32 {
+        match self {
+            Self::AgreeAndExpand => 30,
+            Self::AskQuestion => 25,
+            Self::ShareExperience => 20,
+            Self::AddData => 15,
+            Self::RespectfulDisagree => 10,
+        }
+    }
+
+    /// Weighted selection using the built-in weights.
     pub fn select(rng: &mut impl rand::Rng) -> Self {
-        // Weights: AgreeAndExpand 30, AskQuestion 25, ShareExperience 20,
-        //          AddData 15, RespectfulDisagree 10
-        let choices: &[(Self, u32)] = &[
-            (Self::AgreeAndExpand, 30),
-            (Self::AskQuestion, 25),
-            (Self::ShareExperience, 20),
-            (Self::AddData, 15),
-            (Self::RespectfulDisagree, 10),
-        ];
+        Self::select_weighted(&BTreeMap::new(), rng)
+    }
+
+    /// Weighted selection using configured weights, keyed by [`Self::key`].
+    ///
+    /// Archetypes missing from a non-empty map are never picked. An empty
+    /// map, or one whose weights sum to zero, uses the built-in weights.
+    pub fn select_weighted(weights: &BTreeMap<String, u32>, rng: &mut impl rand::Rng) -> Self {
+        let mut choices: Vec<(Self, u32)> = Self::ALL
+            .iter()
+            .map(|a| (*a, weights.get(a.key()).copied().unwrap_or(0)))
+            .collect();
+        if choices.iter().all(|(_, w)| *w == 0) {
+            choices = Self::ALL.iter().map(|a| (*a, a.default_weight())).collect();
+        }
 
         let total: u32 = choices.iter().map(|(_, w)| w).sum();
         let mut roll = rng.gen_range(0..total);
-        for (archetype, weight) in choices {
+        for (archetype, weight) in &choices {
             if roll < *weight {
                 return *archetype;
             }
@@ -91,6 +130,72 @@ impl std::fmt::Display for ReplyArchetype {
     }
 }
 
+// ============================================================================
+// Quote-tweet archetypes
+// ============================================================================
+
+/// How we frame a quote tweet — the commentary our audience sees above
+/// the quoted post.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuoteArchetype {
+    /// Add background the original post leaves out.
+    AddContext,
+    /// Pull out the single most useful takeaway for our audience.
+    KeyTakeaway,
+    /// Relate the post to our own experience or work.
+    PersonalAngle,
+    /// Offer a constructive counterpoint or caveat.
+    Counterpoint,
+}
+
+impl QuoteArchetype {
+    /// All available archetypes.
+    const ALL: &'static [Self] = &[
+        Self::AddContext,
+        Self::KeyTakeaway,
+        Self::PersonalAngle,
+        Self::Counterpoint,
+    ];
+
+    /// Pick a random archetype.
+    pub fn select(rng: &mut impl rand::Rng) -> Self {
+        *Self::ALL.choose(rng).expect("ALL is non-empty")
+    }
+
+    /// Prompt fragment injected into the system prompt.
+    pub fn prompt_fragment(self) -> &'static str {
+        match self {
+            Self::AddContext => {
+                "Approach: Add context the quoted post leaves out — background, \
+                 history, or a related fact that makes it more useful to your followers."
+            }
+            Self::KeyTakeaway => {
+                "Approach: Distill the quoted post into its single most actionable \
+                 takeaway for your audience, in your own words."
+            }
+            Self::PersonalAngle => {
+                "Approach: Connect the quoted post to your own experience. Use 'I' \
+                 language and say what you did or saw that relates to it."
+            }
+            Self::Counterpoint => {
+                "Approach: Offer a constructive counterpoint or caveat. Acknowledge \
+                 what the post gets right before adding your nuance. Never dunk on the author."
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for QuoteArchetype {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::AddContext => write!(f, "add_context"),
+            Self::KeyTakeaway => write!(f, "key_takeaway"),
+            Self::PersonalAngle => write!(f, "personal_angle"),
+            Self::Counterpoint => write!(f, "counterpoint"),
+        }
+    }
+}
+
 // ============================================================================
 // Tweet formats
 // ============================================================================
@@ -269,6 +374,30 @@ mod tests {
         }
     }
 
+    #[test]
+    fn reply_archetype_select_weighted_respects_weights() {
+        let mut rng = rand::thread_rng();
+        let weights =
+            BTreeMap::from([("ask_question".to_string(), 3), ("add_data".to_string(), 1)]);
+        for _ in 0..200 {
+            let archetype = ReplyArchetype::select_weighted(&weights, &mut rng);
+            assert!(matches!(
+                archetype,
+                ReplyArchetype::AskQuestion | ReplyArchetype::AddData
+            ));
+        }
+
+        let zeroed = BTreeMap::from([("add_data".to_string(), 0)]);
+        let picked: Vec<_> = (0..500)
+            .map(|_| ReplyArchetype::select_weighted(&zeroed, &mut rng))
+            .collect();
+        let distinct = ReplyArchetype::ALL
+            .iter()
+            .filter(|a| picked.contains(a))
+            .count();
+        assert!(distinct > 2, "zero weights fall back to defaults");
+    }
+
     #[test]
     fn reply_archetype_select_distribution() {
         let mut rng = rand::thread_rng();
@@ -320,6 +449,24 @@ mod tests {
         assert_eq!(ReplyArchetype::AskQuestion.to_string(), "ask_question");
     }
 
+    #[test]
+    fn quote_archetype_select_returns_valid() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..50 {
+            let archetype = QuoteArchetype::select(&mut rng);
+            assert!(QuoteArchetype::ALL.contains(&archetype));
+        }
+    }
+
+    #[test]
+    fn quote_archetype_prompt_fragments_and_display() {
+        for a in QuoteArchetype::ALL {
+            assert!(!a.prompt_fragment().is_empty());
+        }
+        assert_eq!(QuoteArchetype::AddContext.to_string(), "add_context");
+        assert_eq!(QuoteArchetype::Counterpoint.to_string(), "counterpoint");
+    }
+
     #[test]
     fn tweet_format_select_avoids_recent() {
         let mut rng = rand::thread_rng();
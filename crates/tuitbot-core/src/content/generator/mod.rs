@@ -1,20 +1,25 @@
 //! High-level content generation combining LLM providers with business context.
 //!
-//! Produces replies, tweets, and threads that meet X's format requirements
+//! Produces replies, quote tweets, tweets, and threads that meet X's format requirements
 //! (280 characters per tweet, 5-8 tweets per thread) with retry logic.
+//! Responses are requested as structured JSON and validated before use
+//! (see [`structured`]).
 
 pub(crate) mod parser;
+mod structured;
 
 #[cfg(test)]
 mod tests;
 
-use crate::config::BusinessProfile;
-use crate::content::frameworks::{ReplyArchetype, ThreadStructure, TweetFormat};
-use crate::content::length::{truncate_at_sentence, validate_tweet_length, MAX_TWEET_CHARS};
+use crate::config::{BusinessProfile, DisclosureConfig, EmojiPolicy, PillarOverride};
+use crate::content::disclosure::{append_disclosure, required_disclosure};
+use crate::content::frameworks::{QuoteArchetype, ReplyArchetype, ThreadStructure, TweetFormat};
+use crate::content::length::{validate_tweet_length, MAX_TWEET_CHARS};
 use crate::error::LlmError;
 use crate::llm::{GenerationParams, LlmProvider, TokenUsage};
 
-use parser::parse_thread;
+use parser::extract_thread;
+use structured::{correction, thread_schema, THREAD_FORMAT};
 
 /// Output from a single-text generation (reply or tweet).
 #[derive(Debug, Clone)]
@@ -49,12 +54,28 @@ const MAX_THREAD_RETRIES: u32 = 2;
 pub struct ContentGenerator {
     provider: Box<dyn LlmProvider>,
     business: BusinessProfile,
+    disclosure: DisclosureConfig,
 }
 
 impl ContentGenerator {
     /// Create a new content generator.
     pub fn new(provider: Box<dyn LlmProvider>, business: BusinessProfile) -> Self {
-        Self { provider, business }
+        Self {
+            provider,
+            business,
+            disclosure: DisclosureConfig::default(),
+        }
+    }
+
+    /// Append the configured disclosure to generated content.
+    pub fn with_disclosure(mut self, disclosure: DisclosureConfig) -> Self {
+        self.disclosure = disclosure;
+        self
+    }
+
+    /// Business profile the generator writes for.
+    pub fn business(&self) -> &BusinessProfile {
+        &self.business
     }
 
     // -----------------------------------------------------------------
@@ -112,6 +133,11 @@ impl ContentGenerator {
         archetype: Option<ReplyArchetype>,
         rag_context: Option<&str>,
     ) -> Result<GenerationOutput, LlmError> {
+        let archetype = archetype.or_else(|| {
+            let weights = &self.business.reply_archetype_weights;
+            (!weights.is_empty())
+                .then(|| ReplyArchetype::select_weighted(weights, &mut rand::thread_rng()))
+        });
         tracing::debug!(
             author = %tweet_author,
             archetype = ?archetype,
@@ -120,7 +146,9 @@ impl ContentGenerator {
             "Generating reply",
         );
 
-        let voice_section = self.format_voice_section();
+        let pillar = self.pillar_override(tweet_text);
+        let voice_section = self.format_voice_section(pillar);
+        let override_rules = Self::format_override_rules(pillar, Some(EmojiPolicy::Sparing));
         let reply_section = match &self.business.reply_style {
             Some(s) if !s.is_empty() => format!("\nReply style: {s}"),
             _ => "\nReply style: Be conversational and helpful, not salesy. Sound like a real person, not a bot.".to_string(),
@@ -148,8 +176,8 @@ impl ContentGenerator {
                  - Write a reply to the tweet below.\n\
                  - Maximum 3 sentences.\n\
                  - Only mention {} if it is genuinely relevant to the tweet's topic.\n\
-                 - Do not use hashtags.\n\
-                 - Do not use emojis excessively.",
+                 - Do not use hashtags.\
+                 {override_rules}",
                 self.business.product_name,
                 self.business.product_description,
                 product_url,
@@ -168,8 +196,8 @@ impl ContentGenerator {
                  - Write a reply to the tweet below.\n\
                  - Maximum 3 sentences.\n\
                  - Do NOT mention {} or any product. Just be genuinely helpful.\n\
-                 - Do not use hashtags.\n\
-                 - Do not use emojis excessively.",
+                 - Do not use hashtags.\
+                 {override_rules}",
                 self.business.product_name,
             )
         };
@@ -181,7 +209,76 @@ impl ContentGenerator {
             ..Default::default()
         };
 
-        self.generate_single(&system, &user_message, &params).await
+        let output = self
+            .generate_single(&system, &user_message, &params)
+            .await?;
+        Ok(self.disclose("reply", output))
+    }
+
+    // -----------------------------------------------------------------
+    // Quote-tweet generation
+    // -----------------------------------------------------------------
+
+    /// Generate commentary for quote-tweeting another post.
+    ///
+    /// Unlike a reply, the commentary is read by our own followers, so it
+    /// must stand on its own next to the quoted post.
+    pub async fn generate_quote(
+        &self,
+        tweet_text: &str,
+        tweet_author: &str,
+        archetype: Option<QuoteArchetype>,
+    ) -> Result<GenerationOutput, LlmError> {
+        tracing::debug!(
+            author = %tweet_author,
+            archetype = ?archetype,
+            "Generating quote tweet",
+        );
+
+        let pillar = self.pillar_override(tweet_text);
+        let voice_section = self.format_voice_section(pillar);
+        let override_rules = Self::format_override_rules(pillar, None);
+        let content_section = match &self.business.content_style {
+            Some(s) if !s.is_empty() => format!("\nContent style: {s}"),
+            _ => "\nContent style: Be informative and engaging.".to_string(),
+        };
+        let archetype_section = match archetype {
+            Some(a) => format!("\n{}", a.prompt_fragment()),
+            None => String::new(),
+        };
+        let persona_section = self.format_persona_context();
+        let audience_section = self.format_audience_section();
+
+        let system = format!(
+            "You are {}'s social media voice. {}.\
+             {audience_section}\
+             {voice_section}\
+             {content_section}\
+             {archetype_section}\
+             {persona_section}\n\n\
+             Rules:\n\
+             - Write commentary to quote-tweet the post below for your own followers.\n\
+             - It must make sense on its own; do not address the author directly.\n\
+             - Maximum 2 sentences.\n\
+             - Do not use hashtags.\n\
+             - Do not mention {} unless it is central to the post.\
+             {override_rules}",
+            self.business.product_name,
+            self.business.product_description,
+            self.business.product_name,
+        );
+
+        let user_message = format!("Post by @{tweet_author}: {tweet_text}");
+        let params = GenerationParams {
+            max_tokens: 150,
+            temperature: 0.8,
+            ..Default::default()
+        };
+
+        let output = self
+            .generate_single(&system, &user_message, &params)
+            .await?;
+        Ok(self.disclose("quote", output))
     }
 
     // -----------------------------------------------------------------
@@ -226,7 +323,9 @@ impl ContentGenerator {
             "Generating tweet",
         );
 
-        let voice_section = self.format_voice_section();
+        let pillar = self.pillar_override(topic);
+        let voice_section = self.format_voice_section(pillar);
+        let override_rules = Self::format_override_rules(pillar, None);
         let content_section = match &self.business.content_style {
             Some(s) if !s.is_empty() => format!("\nContent style: {s}"),
             _ => "\nContent style: Be informative and engaging.".to_string(),
@@ -251,7 +350,8 @@ impl ContentGenerator {
              - Write a single educational tweet about the topic below.\n\
              - Maximum 280 characters.\n\
              - Do not use hashtags.\n\
-             - Do not mention {} directly unless it is central to the topic.",
+             - Do not mention {} directly unless it is central to the topic.\
+             {override_rules}",
             self.business.product_name,
             self.business.product_description,
             self.business.product_name,
@@ -264,7 +364,10 @@ impl ContentGenerator {
             ..Default::default()
         };
 
-        self.generate_single(&system, &user_message, &params).await
+        let output = self
+            .generate_single(&system, &user_message, &params)
+            .await?;
+        Ok(self.disclose("tweet", output))
     }
 
     // -----------------------------------------------------------------
@@ -310,7 +413,9 @@ impl ContentGenerator {
             "Generating thread",
         );
 
-        let voice_section = self.format_voice_section();
+        let pillar = self.pillar_override(topic);
+        let voice_section = self.format_voice_section(pillar);
+        let override_rules = Self::format_override_rules(pillar, None);
         let content_section = match &self.business.content_style {
             Some(s) if !s.is_empty() => format!("\nContent style: {s}"),
             _ => "\nContent style: Be informative, not promotional.".to_string(),
@@ -333,11 +438,11 @@ impl ContentGenerator {
              {rag_section}\n\n\
              Rules:\n\
              - Write an educational thread of 5 to 8 tweets about the topic below.\n\
-             - Separate each tweet with a line containing only \"---\".\n\
              - Each tweet must be under 280 characters.\n\
              - The first tweet should hook the reader.\n\
              - The last tweet should include a call to action or summary.\n\
-             - Do not use hashtags.",
+             - Do not use hashtags.\
+             {override_rules}{THREAD_FORMAT}",
             self.business.product_name, self.business.product_description,
         );
 
@@ -345,6 +450,7 @@ impl ContentGenerator {
         let params = GenerationParams {
             max_tokens: 1500,
             temperature: 0.7,
+            response_schema: Some(thread_schema()),
             ..Default::default()
         };
 
@@ -352,26 +458,41 @@ impl ContentGenerator {
         let provider_name = self.provider.name().to_string();
         let mut model = String::new();
 
+        let mut invalid = None;
         for attempt in 0..=MAX_THREAD_RETRIES {
-            let msg = if attempt == 0 {
-                user_message.clone()
-            } else {
-                format!(
+            let msg = match (&invalid, attempt) {
+                (_, 0) => user_message.clone(),
+                (Some(error), _) => correction(&user_message, error),
+                (None, _) => format!(
                     "{user_message}\n\nIMPORTANT: Write exactly 5-8 tweets, \
-                     each under 280 characters, separated by lines containing only \"---\"."
-                )
+                     each under 280 characters."
+                ),
             };
 
             let resp = self.provider.complete(&system, &msg, &params).await?;
             usage.accumulate(&resp.usage);
             model.clone_from(&resp.model);
-            let tweets = parse_thread(&resp.text);
+            let mut tweets = match extract_thread(&resp.text) {
+                Ok(tweets) => tweets,
+                Err(e) => {
+                    tracing::debug!(error = %e, "Generated thread failed validation");
+                    invalid = Some(e);
+                    continue;
+                }
+            };
+            invalid = None;
 
             if (5..=8).contains(&tweets.len())
                 && tweets
                     .iter()
                     .all(|t| validate_tweet_length(t, MAX_TWEET_CHARS))
             {
+                let texts = tweets.iter().map(String::as_str);
+                if let Some(disclosure) =
+                    required_disclosure(&self.disclosure, &self.business, "thread", texts)
+                {
+                    tweets[0] = append_disclosure(&tweets[0], &disclosure);
+                }
                 return Ok(ThreadGenerationOutput {
                     tweets,
                     usage,
@@ -390,63 +511,56 @@ impl ContentGenerator {
     // Shared helpers
     // -----------------------------------------------------------------
 
-    /// Generate a single tweet/reply with retry and truncation fallback.
-    async fn generate_single(
-        &self,
-        system: &str,
-        user_message: &str,
-        params: &GenerationParams,
-    ) -> Result<GenerationOutput, LlmError> {
-        let resp = self.provider.complete(system, user_message, params).await?;
-        let mut usage = resp.usage.clone();
-        let provider_name = self.provider.name().to_string();
-        let model = resp.model.clone();
-        let text = resp.text.trim().to_string();
-
-        tracing::debug!(chars = text.len(), "Generated content");
-
-        if validate_tweet_length(&text, MAX_TWEET_CHARS) {
-            return Ok(GenerationOutput {
-                text,
-                usage,
-                model,
-                provider: provider_name,
-            });
-        }
-
-        // Retry with stricter instruction
-        let retry_msg = format!(
-            "{user_message}\n\nImportant: Your response MUST be under 280 characters. Be more concise."
-        );
-        let resp = self.provider.complete(system, &retry_msg, params).await?;
-        usage.accumulate(&resp.usage);
-        let text = resp.text.trim().to_string();
-
-        if validate_tweet_length(&text, MAX_TWEET_CHARS) {
-            return Ok(GenerationOutput {
-                text,
-                usage,
-                model,
-                provider: provider_name,
-            });
+    /// Append the disclosure configured for `action_type`, if one applies.
+    fn disclose(&self, action_type: &str, mut output: GenerationOutput) -> GenerationOutput {
+        if let Some(disclosure) = required_disclosure(
+            &self.disclosure,
+            &self.business,
+            action_type,
+            [output.text.as_str()],
+        ) {
+            output.text = append_disclosure(&output.text, &disclosure);
         }
+        output
+    }
 
-        // Last resort: truncate at sentence boundary
-        Ok(GenerationOutput {
-            text: truncate_at_sentence(&text, MAX_TWEET_CHARS),
-            usage,
-            model,
-            provider: provider_name,
-        })
+    /// The override for the content pillar named in `text`, if any.
+    fn pillar_override(&self, text: &str) -> Option<&PillarOverride> {
+        let (pillar, o) = self.business.pillar_override(text)?;
+        tracing::debug!(pillar = %pillar, "Applying pillar override");
+        Some(o)
     }
 
-    fn format_voice_section(&self) -> String {
-        match &self.business.brand_voice {
+    /// Brand voice, or the pillar override's tone when it sets one.
+    fn format_voice_section(&self, pillar: Option<&PillarOverride>) -> String {
+        let tone = pillar.and_then(|o| o.tone.as_ref());
+        match tone.or(self.business.brand_voice.as_ref()) {
             Some(v) if !v.is_empty() => format!("\nVoice & personality: {v}"),
             _ => String::new(),
         }
     }
 
+    /// Extra rules from a pillar override: the language to write in, then
+    /// the emoji policy (`default_emoji` when the override sets none).
+    fn format_override_rules(
+        pillar: Option<&PillarOverride>,
+        default_emoji: Option<EmojiPolicy>,
+    ) -> String {
+        let mut rules = String::new();
+        if let Some(language) = pillar.and_then(|o| o.language.as_deref()) {
+            if !language.is_empty() {
+                rules.push_str(&format!("\n- Write in {language}."));
+            }
+        }
+        match pillar.and_then(|o| o.emoji).or(default_emoji) {
+            Some(EmojiPolicy::Allow) => rules.push_str("\n- Emojis are fine where they fit."),
+            Some(EmojiPolicy::Sparing) => rules.push_str("\n- Do not use emojis excessively."),
+            Some(EmojiPolicy::Forbid) => rules.push_str("\n- Do not use emojis."),
+            None => {}
+        }
+        rules
+    }
+
     fn format_audience_section(&self) -> String {
         if self.business.target_audience.is_empty() {
             String::new()
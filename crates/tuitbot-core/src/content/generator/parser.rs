@@ -1,3 +1,195 @@
+//! Response parsing: structured-output extraction with a repair fallback.
+//!
+//! Generators ask for JSON (`{"text": ...}` or `{"tweets": [...]}`). Providers
+//! with native structured output return exactly that; others may wrap it in
+//! code fences, add a preamble, or ignore the format entirely. The extractors
+//! here accept all of those and reject output that would leak into a tweet.
+
+use serde_json::Value;
+
+/// Opening phrases models use to introduce their answer instead of giving it.
+const PREAMBLE_PREFIXES: &[&str] = &[
+    "here's",
+    "here is",
+    "here are",
+    "sure",
+    "certainly",
+    "of course",
+    "absolutely",
+];
+
+/// Labels sometimes prefixed to the content itself.
+const LABEL_PREFIXES: &[&str] = &["tweet:", "reply:", "response:"];
+
+/// Why a response could not be turned into publishable content.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum OutputError {
+    /// Nothing usable remained after extraction.
+    #[error("the response was empty")]
+    Empty,
+    /// Code fences or markdown markup survived extraction.
+    #[error("the response contained markdown or code formatting")]
+    Markdown,
+    /// The response introduced the content instead of being the content.
+    #[error("the response started with a preamble instead of the content itself")]
+    Preamble,
+}
+
+/// Extract a single tweet or reply from a raw response.
+pub fn extract_text(raw: &str) -> Result<String, OutputError> {
+    let body = strip_code_fence(raw.trim());
+    let text = match parse_json_lenient(body) {
+        Some(Value::Object(map)) => match map.get("text") {
+            Some(Value::String(t)) => t.clone(),
+            _ => body.to_string(),
+        },
+        Some(Value::String(t)) => t,
+        _ => body.to_string(),
+    };
+    validate(clean_plain(&text))
+}
+
+/// Extract thread tweets from a raw response.
+///
+/// Accepts `{"tweets": [...]}`, a bare JSON array, or `---` / numbered text.
+pub fn extract_thread(raw: &str) -> Result<Vec<String>, OutputError> {
+    let body = strip_code_fence(raw.trim());
+    let items = match parse_json_lenient(body) {
+        Some(Value::Object(map)) => map.get("tweets").and_then(string_items),
+        Some(value @ Value::Array(_)) => string_items(&value),
+        _ => None,
+    };
+    let tweets = match items {
+        Some(items) => items,
+        None => parse_thread(body),
+    };
+    let tweets = tweets
+        .iter()
+        .map(|t| validate(clean_plain(t)))
+        .collect::<Result<Vec<_>, _>>()?;
+    if tweets.is_empty() {
+        return Err(OutputError::Empty);
+    }
+    Ok(tweets)
+}
+
+fn string_items(value: &Value) -> Option<Vec<String>> {
+    let items: Vec<String> = value
+        .as_array()?
+        .iter()
+        .filter_map(Value::as_str)
+        .map(str::to_string)
+        .collect();
+    (!items.is_empty()).then_some(items)
+}
+
+/// Remove a surrounding ```` ``` ```` / ```` ```json ```` fence, if present.
+fn strip_code_fence(text: &str) -> &str {
+    let Some(rest) = text.strip_prefix("```") else {
+        return text;
+    };
+    let rest = rest.split_once('\n').map_or("", |(_, body)| body);
+    rest.trim_end().strip_suffix("```").unwrap_or(rest).trim()
+}
+
+/// Parse JSON, tolerating surrounding prose and trailing commas.
+fn parse_json_lenient(text: &str) -> Option<Value> {
+    let candidate = match (text.find(['{', '[']), text.rfind(['}', ']'])) {
+        (Some(start), Some(end)) if start < end => &text[start..=end],
+        _ => {
+            return serde_json::from_str::<Value>(text)
+                .ok()
+                .filter(Value::is_string)
+        }
+    };
+    serde_json::from_str(candidate)
+        .or_else(|_| serde_json::from_str(&remove_trailing_commas(candidate)))
+        .ok()
+}
+
+/// Drop commas directly followed (ignoring whitespace) by `}` or `]`.
+fn remove_trailing_commas(text: &str) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let mut out = String::with_capacity(text.len());
+    for (i, &c) in chars.iter().enumerate() {
+        if c == ',' {
+            let next = chars[i + 1..].iter().find(|c| !c.is_whitespace());
+            if matches!(next, Some('}') | Some(']')) {
+                continue;
+            }
+        }
+        out.push(c);
+    }
+    out
+}
+
+/// Strip preambles, labels, wrapping quotes, and inline markdown from plain text.
+fn clean_plain(text: &str) -> String {
+    let mut text = text.trim();
+
+    // "Here's a reply:" on its own line, followed by the content.
+    if let Some((first, rest)) = text.split_once('\n') {
+        if is_preamble(first) && first.trim_end().ends_with(':') && !rest.trim().is_empty() {
+            text = rest.trim();
+        }
+    }
+    // "Here's a reply: Great point!" on a single line.
+    if is_preamble(text) {
+        if let Some((_, rest)) = text.split_once(':') {
+            if !rest.trim().is_empty() {
+                text = rest.trim();
+            }
+        }
+    }
+    for label in LABEL_PREFIXES {
+        if text.len() >= label.len() && text[..label.len()].eq_ignore_ascii_case(label) {
+            text = text[label.len()..].trim();
+        }
+    }
+    for (open, close) in [('"', '"'), ('\u{201c}', '\u{201d}')] {
+        if let Some(inner) = text.strip_prefix(open).and_then(|t| t.strip_suffix(close)) {
+            if !inner.contains(open) {
+                text = inner.trim();
+            }
+        }
+    }
+
+    // Markdown headings ("## Title") and bold markers; hashtags are left alone.
+    text.lines()
+        .map(|line| {
+            let stripped = line.trim_start_matches('#');
+            if stripped.len() < line.len() && stripped.starts_with(' ') {
+                stripped.trim_start()
+            } else {
+                line
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+        .replace("**", "")
+        .trim()
+        .to_string()
+}
+
+fn is_preamble(line: &str) -> bool {
+    let lower = line.trim_start().to_lowercase();
+    PREAMBLE_PREFIXES.iter().any(|p| lower.starts_with(p))
+}
+
+fn validate(text: String) -> Result<String, OutputError> {
+    if text.is_empty() {
+        return Err(OutputError::Empty);
+    }
+    if text.contains("```") {
+        return Err(OutputError::Markdown);
+    }
+    let first_line = text.lines().next().unwrap_or_default();
+    if is_preamble(first_line) && first_line.trim_end().ends_with(':') {
+        return Err(OutputError::Preamble);
+    }
+    Ok(text)
+}
+
 /// Parse a thread response by splitting on `---` delimiters.
 ///
 /// Also tries numbered patterns (e.g., "1/8", "1.") as a fallback.
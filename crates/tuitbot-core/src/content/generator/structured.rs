@@ -0,0 +1,157 @@
+//! Structured-output enforcement for generator calls.
+//!
+//! Every generation asks for JSON via a [`ResponseSchema`] (enforced natively
+//! by OpenAI and Anthropic) plus a format line in the system prompt for
+//! providers without native support. Responses go through the extraction
+//! parser; a validation failure triggers one regeneration that names the
+//! problem, and a second failure fails the generation.
+
+use serde_json::json;
+
+use super::parser::{extract_text, OutputError};
+use super::{ContentGenerator, GenerationOutput};
+use crate::content::length::{truncate_at_sentence, validate_tweet_length, MAX_TWEET_CHARS};
+use crate::error::LlmError;
+use crate::llm::{GenerationParams, ResponseSchema, TokenUsage};
+
+/// Format instruction appended to single-text system prompts.
+const TEXT_FORMAT: &str = "\n\nOutput format: respond with only a JSON object \
+     {\"text\": \"<the tweet>\"}. No preamble, no markdown, no surrounding quotes.";
+
+/// Format instruction appended to thread system prompts.
+pub(super) const THREAD_FORMAT: &str = "\n\nOutput format: respond with only a JSON object \
+     {\"tweets\": [\"<first tweet>\", \"<second tweet>\", ...]}. \
+     No preamble, no markdown, no numbering.";
+
+/// Schema for a single tweet or reply.
+pub(super) fn text_schema() -> ResponseSchema {
+    ResponseSchema {
+        name: "tweet".to_string(),
+        schema: json!({
+            "type": "object",
+            "properties": {
+                "text": {"type": "string", "description": "The tweet text, under 280 characters."}
+            },
+            "required": ["text"],
+            "additionalProperties": false
+        }),
+    }
+}
+
+/// Schema for a thread.
+pub(super) fn thread_schema() -> ResponseSchema {
+    ResponseSchema {
+        name: "thread".to_string(),
+        schema: json!({
+            "type": "object",
+            "properties": {
+                "tweets": {
+                    "type": "array",
+                    "items": {"type": "string"},
+                    "description": "Thread tweets in order, each under 280 characters."
+                }
+            },
+            "required": ["tweets"],
+            "additionalProperties": false
+        }),
+    }
+}
+
+/// Follow-up instruction sent when a response fails validation.
+pub(super) fn correction(user_message: &str, error: &OutputError) -> String {
+    format!(
+        "{user_message}\n\nYour previous response was rejected because {error}. \
+         Respond with only the requested JSON object."
+    )
+}
+
+impl ContentGenerator {
+    /// Complete and extract a single text, regenerating once if validation fails.
+    async fn complete_text(
+        &self,
+        system: &str,
+        user_message: &str,
+        params: &GenerationParams,
+        usage: &mut TokenUsage,
+        model: &mut String,
+    ) -> Result<String, LlmError> {
+        let resp = self.provider.complete(system, user_message, params).await?;
+        usage.accumulate(&resp.usage);
+        model.clone_from(&resp.model);
+
+        let error = match extract_text(&resp.text) {
+            Ok(text) => return Ok(text),
+            Err(e) => e,
+        };
+        tracing::debug!(error = %error, "Generated content failed validation, regenerating");
+
+        let resp = self
+            .provider
+            .complete(system, &correction(user_message, &error), params)
+            .await?;
+        usage.accumulate(&resp.usage);
+        model.clone_from(&resp.model);
+
+        extract_text(&resp.text).map_err(|e| {
+            LlmError::GenerationFailed(format!("invalid response after regeneration: {e}"))
+        })
+    }
+
+    /// Generate a single tweet/reply with format enforcement, a length retry,
+    /// and truncation fallback.
+    pub(super) async fn generate_single(
+        &self,
+        system: &str,
+        user_message: &str,
+        params: &GenerationParams,
+    ) -> Result<GenerationOutput, LlmError> {
+        let system = format!("{system}{TEXT_FORMAT}");
+        let params = GenerationParams {
+            response_schema: Some(text_schema()),
+            ..params.clone()
+        };
+        let provider_name = self.provider.name().to_string();
+        let mut usage = TokenUsage::default();
+        let mut model = String::new();
+
+        let text = self
+            .complete_text(&system, user_message, &params, &mut usage, &mut model)
+            .await?;
+
+        tracing::debug!(chars = text.len(), "Generated content");
+
+        if validate_tweet_length(&text, MAX_TWEET_CHARS) {
+            return Ok(GenerationOutput {
+                text,
+                usage,
+                model,
+                provider: provider_name,
+            });
+        }
+
+        // Retry with stricter instruction
+        let retry_msg = format!(
+            "{user_message}\n\nImportant: Your response MUST be under 280 characters. Be more concise."
+        );
+        let text = self
+            .complete_text(&system, &retry_msg, &params, &mut usage, &mut model)
+            .await?;
+
+        if validate_tweet_length(&text, MAX_TWEET_CHARS) {
+            return Ok(GenerationOutput {
+                text,
+                usage,
+                model,
+                provider: provider_name,
+            });
+        }
+
+        // Last resort: truncate at sentence boundary
+        Ok(GenerationOutput {
+            text: truncate_at_sentence(&text, MAX_TWEET_CHARS),
+            usage,
+            model,
+            provider: provider_name,
+        })
+    }
+}
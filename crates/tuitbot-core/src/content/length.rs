@@ -6,6 +6,7 @@
 
 use regex::Regex;
 use std::sync::OnceLock;
+use unicode_segmentation::UnicodeSegmentation;
 
 /// Length of a t.co shortened URL on X.
 pub const TCO_URL_LENGTH: usize = 23;
@@ -65,7 +66,9 @@ pub fn validate_tweet_length(text: &str, max_chars: usize) -> bool {
 ///
 /// Uses URL-aware length calculation. Looks for the last period, exclamation
 /// mark, or question mark within the limit. Falls back to truncating at the
-/// limit with "..." if no sentence boundary is found.
+/// limit with "..." if no sentence boundary is found. Cuts only ever land on
+/// grapheme cluster boundaries, so emoji sequences and accented letters are
+/// never split.
 pub fn truncate_at_sentence(text: &str, max_chars: usize) -> String {
     if tweet_weighted_len(text) <= max_chars {
         return text.to_string();
@@ -76,7 +79,7 @@ pub fn truncate_at_sentence(text: &str, max_chars: usize) -> String {
     // backwards from byte positions and recheck the weighted length.
 
     // Start from the raw byte limit (may be generous if URLs are present)
-    let byte_limit = text.len().min(max_chars);
+    let byte_limit = floor_grapheme_boundary(text, text.len().min(max_chars));
     let search_area = &text[..byte_limit];
 
     // Find the last sentence-ending punctuation
@@ -86,7 +89,7 @@ pub fn truncate_at_sentence(text: &str, max_chars: usize) -> String {
         .max(search_area.rfind('?'));
 
     if let Some(pos) = last_sentence_end {
-        if pos > 0 {
+        if pos > 0 && floor_grapheme_boundary(text, pos + 1) == pos + 1 {
             let candidate = text[..=pos].trim().to_string();
             if tweet_weighted_len(&candidate) <= max_chars {
                 return candidate;
@@ -96,8 +99,8 @@ pub fn truncate_at_sentence(text: &str, max_chars: usize) -> String {
 
     // No valid sentence boundary; hard truncate with ellipsis.
     // Walk backwards to find a position that fits.
-    let truncate_at = byte_limit.saturating_sub(3);
-    let word_end = text[..truncate_at].rfind(' ').unwrap_or(truncate_at);
+    let truncate_at = floor_grapheme_boundary(text, byte_limit.saturating_sub(3));
+    let word_end = last_space_before(text, truncate_at).unwrap_or(truncate_at);
     let candidate = format!("{}...", &text[..word_end]);
 
     if tweet_weighted_len(&candidate) <= max_chars {
@@ -107,7 +110,7 @@ pub fn truncate_at_sentence(text: &str, max_chars: usize) -> String {
     // If still too long (many URLs), keep shrinking
     let mut end = word_end;
     while end > 0 {
-        end = text[..end].rfind(' ').unwrap_or(0);
+        end = last_space_before(text, end).unwrap_or(0);
         let candidate = if end == 0 {
             "...".to_string()
         } else {
@@ -121,6 +124,29 @@ pub fn truncate_at_sentence(text: &str, max_chars: usize) -> String {
     "...".to_string()
 }
 
+/// Largest grapheme cluster boundary in `text` at or before byte `index`.
+fn floor_grapheme_boundary(text: &str, index: usize) -> usize {
+    if index >= text.len() {
+        return text.len();
+    }
+    text.grapheme_indices(true)
+        .map(|(i, _)| i)
+        .take_while(|&i| i <= index)
+        .last()
+        .unwrap_or(0)
+}
+
+/// Byte offset of the last space that starts a grapheme before `end`.
+///
+/// `end` must be a grapheme boundary.
+fn last_space_before(text: &str, end: usize) -> Option<usize> {
+    text[..end]
+        .grapheme_indices(true)
+        .rev()
+        .find(|(_, g)| *g == " ")
+        .map(|(i, _)| i)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -258,4 +284,97 @@ mod tests {
         let text = "Check out foo.rs for Rust crates";
         assert_eq!(tweet_weighted_len(text), text.len());
     }
+
+    #[test]
+    fn truncate_does_not_split_multibyte_characters() {
+        let text = format!("{} and more", "日本語".repeat(40));
+        let result = truncate_at_sentence(&text, 100);
+        assert!(tweet_weighted_len(&result) <= 100);
+        assert!(result.ends_with("..."));
+    }
+
+    #[test]
+    fn truncate_does_not_split_emoji_sequences() {
+        // Family emoji: four code points joined by ZWJ, 25 bytes.
+        let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}\u{200D}\u{1F466}";
+        let text = format!("ab{}", family.repeat(5));
+        let result = truncate_at_sentence(&text, 40);
+        let body = result.strip_suffix("...").unwrap();
+        assert!(body == "ab" || body.ends_with(family));
+    }
+
+    mod props {
+        use super::*;
+        use proptest::prelude::*;
+
+        /// Text pieces mixing ASCII, sentence punctuation, URLs and
+        /// multi-byte graphemes (combining marks, ZWJ emoji, flags, CJK).
+        fn piece() -> impl Strategy<Value = &'static str> {
+            prop::sample::select(vec![
+                "word",
+                "tweet",
+                " ",
+                " ",
+                ". ",
+                "! ",
+                "? ",
+                ",",
+                "e\u{301}",
+                "\u{1F469}\u{200D}\u{1F4BB}",
+                "\u{1F1EF}\u{1F1F5}",
+                "日本語",
+                "ß",
+                "https://example.com/some/long/path",
+                "docs.example.io",
+            ])
+        }
+
+        fn tweet_text() -> impl Strategy<Value = String> {
+            prop::collection::vec(piece(), 0..120).prop_map(|pieces| pieces.concat())
+        }
+
+        proptest! {
+            #[test]
+            fn truncate_never_exceeds_limit(text in tweet_text(), max in 3usize..=400) {
+                let result = truncate_at_sentence(&text, max);
+                prop_assert!(tweet_weighted_len(&result) <= max);
+            }
+
+            #[test]
+            fn truncate_never_splits_graphemes(text in tweet_text(), max in 3usize..=400) {
+                let result = truncate_at_sentence(&text, max);
+                let body = result.strip_suffix("...").unwrap_or(&result);
+                let text_graphemes: Vec<&str> = text.graphemes(true).collect();
+                let body_graphemes: Vec<&str> = body.graphemes(true).collect();
+                prop_assert!(
+                    body_graphemes.is_empty()
+                        || text_graphemes
+                            .windows(body_graphemes.len())
+                            .any(|w| w == body_graphemes.as_slice()),
+                    "{body:?} is not a run of whole graphemes from {text:?}"
+                );
+            }
+
+            #[test]
+            fn truncate_keeps_text_that_fits(text in tweet_text()) {
+                let max = tweet_weighted_len(&text);
+                prop_assert_eq!(truncate_at_sentence(&text, max), text);
+            }
+
+            #[test]
+            fn truncate_never_panics(text in "\\PC{0,400}", max in 0usize..=400) {
+                let _ = truncate_at_sentence(&text, max);
+            }
+
+            #[test]
+            fn weighted_len_counts_each_url_as_tco(text in tweet_text()) {
+                let urls: Vec<&str> = url_regex().find_iter(&text).map(|m| m.as_str()).collect();
+                let url_bytes: usize = urls.iter().map(|u| u.len()).sum();
+                prop_assert_eq!(
+                    tweet_weighted_len(&text),
+                    text.len() - url_bytes + urls.len() * TCO_URL_LENGTH
+                );
+            }
+        }
+    }
 }
@@ -2,15 +2,20 @@
 //!
 //! Uses an LLM provider to produce content that matches the user's
 //! business profile and adheres to X's format constraints.
-//! The `frameworks` module provides reply archetypes, tweet formats,
-//! and thread structures that shape LLM prompts for varied output.
+//! The `frameworks` module provides reply and quote archetypes, tweet
+//! formats, and thread structures that shape LLM prompts for varied output.
 
+pub mod disclosure;
 pub mod frameworks;
 pub mod generator;
 pub mod length;
 pub mod thread;
+pub mod thread_format;
 
-pub use frameworks::{ReplyArchetype, ThreadStructure, TweetFormat};
+pub use disclosure::{
+    append_disclosure, has_disclosure, required_disclosure, MAX_DISCLOSURE_CHARS,
+};
+pub use frameworks::{QuoteArchetype, ReplyArchetype, ThreadStructure, TweetFormat};
 pub use generator::{ContentGenerator, GenerationOutput, ThreadGenerationOutput};
 pub use length::{
     truncate_at_sentence, tweet_weighted_len, validate_tweet_length, MAX_TWEET_CHARS,
@@ -20,3 +25,4 @@ pub use thread::{
     deserialize_blocks_from_content, serialize_blocks_for_storage, validate_thread_blocks,
     ThreadBlock, ThreadBlockError, ThreadBlocksPayload, MAX_MEDIA_PER_BLOCK,
 };
+pub use thread_format::{format_thread, render_segment, ThreadFormatError, THREAD_URL_PLACEHOLDER};
@@ -0,0 +1,147 @@
+//! Thread decorations applied at posting time.
+//!
+//! Adds "1/n" numbering, an optional closing call-to-action tweet, and an
+//! optional final tweet that re-quotes the hook (the thread's first tweet).
+//! The hook's link is only known once the first tweet is posted, so
+//! segments may contain [`THREAD_URL_PLACEHOLDER`], which the poster expands
+//! with [`render_segment`]. Every segment is revalidated against the weighted
+//! tweet length after decoration.
+
+use crate::config::{ThreadFormatConfig, ThreadNumbering};
+
+use super::length::{tweet_weighted_len, MAX_TWEET_CHARS};
+
+/// Placeholder expanded to the first tweet's URL when a segment is posted.
+pub const THREAD_URL_PLACEHOLDER: &str = "{thread_url}";
+
+/// Re-quote text used when `requote_text` is not configured.
+const DEFAULT_REQUOTE_TEXT: &str = "ICYMI, the thread starts here:";
+
+/// A decorated segment no longer fits in one tweet.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("thread tweet {index} is {length} characters after formatting (max {max})")]
+pub struct ThreadFormatError {
+    /// Zero-based segment index.
+    pub index: usize,
+    /// Weighted length after decoration.
+    pub length: usize,
+    pub max: usize,
+}
+
+/// Decorate `tweets` according to `config`.
+///
+/// Returns the segments to post, in order. The CTA and re-quote tweets are
+/// appended after the content and count toward the numbering total.
+pub fn format_thread(
+    config: &ThreadFormatConfig,
+    tweets: &[String],
+) -> Result<Vec<String>, ThreadFormatError> {
+    let mut segments: Vec<String> = tweets.to_vec();
+    if let Some(cta) = config.end_cta.as_deref().map(str::trim) {
+        if !cta.is_empty() {
+            segments.push(cta.to_string());
+        }
+    }
+    if config.requote_hook && !tweets.is_empty() {
+        let text = config
+            .requote_text
+            .as_deref()
+            .map(str::trim)
+            .filter(|t| !t.is_empty())
+            .unwrap_or(DEFAULT_REQUOTE_TEXT);
+        segments.push(format!("{text} {THREAD_URL_PLACEHOLDER}"));
+    }
+
+    let total = segments.len();
+    let segments: Vec<String> = segments
+        .into_iter()
+        .enumerate()
+        .map(|(i, text)| match config.numbering {
+            ThreadNumbering::None => text,
+            ThreadNumbering::Prefix => format!("{}/{total} {text}", i + 1),
+            ThreadNumbering::Suffix => format!("{text} {}/{total}", i + 1),
+        })
+        .collect();
+
+    // Links always weigh as a t.co URL, so any tweet ID gives the final length.
+    for (index, segment) in segments.iter().enumerate() {
+        let length = tweet_weighted_len(&render_segment(segment, "0"));
+        if length > MAX_TWEET_CHARS {
+            return Err(ThreadFormatError {
+                index,
+                length,
+                max: MAX_TWEET_CHARS,
+            });
+        }
+    }
+
+    Ok(segments)
+}
+
+/// Expand [`THREAD_URL_PLACEHOLDER`] to the URL of the thread's first tweet.
+pub fn render_segment(segment: &str, root_tweet_id: &str) -> String {
+    if segment.contains(THREAD_URL_PLACEHOLDER) {
+        segment.replace(
+            THREAD_URL_PLACEHOLDER,
+            &format!("https://x.com/i/status/{root_tweet_id}"),
+        )
+    } else {
+        segment.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tweets(texts: &[&str]) -> Vec<String> {
+        texts.iter().map(|t| t.to_string()).collect()
+    }
+
+    #[test]
+    fn default_config_is_identity() {
+        let input = tweets(&["Hook", "Body"]);
+        let out = format_thread(&ThreadFormatConfig::default(), &input).unwrap();
+        assert_eq!(out, input);
+    }
+
+    #[test]
+    fn numbering_counts_cta_and_requote() {
+        let config = ThreadFormatConfig {
+            numbering: ThreadNumbering::Suffix,
+            end_cta: Some("Follow for more. Start: {thread_url}".into()),
+            requote_hook: true,
+            requote_text: None,
+        };
+        let out = format_thread(&config, &tweets(&["Hook", "Body"])).unwrap();
+        assert_eq!(out.len(), 4);
+        assert_eq!(out[0], "Hook 1/4");
+        assert_eq!(out[2], "Follow for more. Start: {thread_url} 3/4");
+        assert_eq!(
+            render_segment(&out[3], "42"),
+            "ICYMI, the thread starts here: https://x.com/i/status/42 4/4"
+        );
+    }
+
+    #[test]
+    fn prefix_numbering() {
+        let config = ThreadFormatConfig {
+            numbering: ThreadNumbering::Prefix,
+            ..Default::default()
+        };
+        let out = format_thread(&config, &tweets(&["Hook", "Body"])).unwrap();
+        assert_eq!(out, tweets(&["1/2 Hook", "2/2 Body"]));
+    }
+
+    #[test]
+    fn numbering_that_overflows_is_rejected() {
+        let config = ThreadFormatConfig {
+            numbering: ThreadNumbering::Prefix,
+            ..Default::default()
+        };
+        let full = "a".repeat(MAX_TWEET_CHARS);
+        let err = format_thread(&config, &[String::from("Hook"), full]).unwrap_err();
+        assert_eq!(err.index, 1);
+        assert_eq!(err.length, MAX_TWEET_CHARS + 4);
+    }
+}
@@ -38,6 +38,37 @@ pub enum ConfigError {
     },
 }
 
+/// Errors from the config snapshot history.
+#[derive(Debug, thiserror::Error)]
+pub enum ConfigHistoryError {
+    /// Reading or writing the config file or a snapshot failed.
+    #[error("{path}: {source}")]
+    Io {
+        /// The file or directory involved.
+        path: String,
+        /// The underlying I/O error.
+        #[source]
+        source: std::io::Error,
+    },
+
+    /// No snapshot has the requested ID.
+    #[error("config snapshot #{id} not found")]
+    NotFound {
+        /// The requested snapshot ID.
+        id: u64,
+    },
+
+    /// The snapshot does not parse as a valid config.
+    #[error("config snapshot #{id} is not a valid config: {source}")]
+    Invalid {
+        /// The snapshot ID.
+        id: u64,
+        /// The underlying TOML parse error.
+        #[source]
+        source: toml::de::Error,
+    },
+}
+
 /// Errors from interacting with the X (Twitter) API.
 #[derive(Debug, thiserror::Error)]
 pub enum XApiError {
@@ -106,6 +137,147 @@ pub enum XApiError {
         /// Number of seconds waited before timing out.
         seconds: u64,
     },
+
+    /// The write targets a protected entity and was not sent.
+    #[error("refused to engage protected entity @{username}")]
+    ProtectedEntity {
+        /// Normalized username of the protected account.
+        username: String,
+    },
+}
+
+/// Errors from interacting with the Reddit API.
+#[derive(Debug, thiserror::Error)]
+pub enum RedditError {
+    /// Reddit returned HTTP 429 (rate limited).
+    #[error("Reddit API rate limited{}", match .retry_after {
+        Some(secs) => format!(", retry after {secs}s"),
+        None => String::new(),
+    })]
+    RateLimited {
+        /// Seconds to wait before retrying, if provided by the API.
+        retry_after: Option<u64>,
+    },
+
+    /// The password grant was rejected or the token was refused.
+    #[error("Reddit authentication failed: {message}")]
+    Auth {
+        /// Details from the token endpoint.
+        message: String,
+    },
+
+    /// Network-level failure communicating with Reddit.
+    #[error("Reddit network error: {source}")]
+    Network {
+        /// The underlying HTTP client error.
+        #[source]
+        source: reqwest::Error,
+    },
+
+    /// Any other Reddit error response.
+    #[error("Reddit API error (HTTP {status}): {message}")]
+    ApiError {
+        /// The HTTP status code.
+        status: u16,
+        /// The error message from the API.
+        message: String,
+    },
+}
+
+/// Errors from S3-compatible remote backups.
+#[derive(Debug, thiserror::Error)]
+pub enum RemoteBackupError {
+    /// The remote location is not an `s3://bucket/key` URI.
+    #[error("invalid remote backup URI '{uri}', expected s3://bucket/key")]
+    InvalidUri {
+        /// The URI as given.
+        uri: String,
+    },
+
+    /// Network-level failure communicating with the bucket.
+    #[error("remote backup network error: {source}")]
+    Network {
+        /// The underlying HTTP client error.
+        #[source]
+        source: reqwest::Error,
+    },
+
+    /// The storage service returned an error response.
+    #[error("remote backup API error (HTTP {status}): {message}")]
+    Api {
+        /// The HTTP status code.
+        status: u16,
+        /// The error body returned by the service.
+        message: String,
+    },
+
+    /// A snapshot's SHA-256 does not match the recorded checksum.
+    #[error("checksum mismatch for {key}: expected {expected}, got {actual}")]
+    ChecksumMismatch {
+        /// Object key of the snapshot.
+        key: String,
+        /// Checksum recorded at upload.
+        expected: String,
+        /// Checksum of the bytes received.
+        actual: String,
+    },
+
+    /// Reading, compressing, or writing a local snapshot failed.
+    #[error("{path}: {source}")]
+    Io {
+        /// The file involved.
+        path: String,
+        /// The underlying I/O error.
+        #[source]
+        source: std::io::Error,
+    },
+
+    /// Creating the local snapshot failed.
+    #[error(transparent)]
+    Storage(#[from] StorageError),
+}
+
+/// Errors from encrypted export bundles.
+#[derive(Debug, thiserror::Error)]
+pub enum BundleError {
+    /// Reading or writing a file failed.
+    #[error("{path}: {source}")]
+    Io {
+        /// The file involved.
+        path: String,
+        /// The underlying I/O error.
+        #[source]
+        source: std::io::Error,
+    },
+
+    /// The file is not a bundle or its contents are malformed.
+    #[error("invalid export bundle: {message}")]
+    Invalid {
+        /// What is wrong with the bundle.
+        message: String,
+    },
+
+    /// Decryption failed: the passphrase is wrong or the bundle was modified.
+    #[error("could not decrypt export bundle: wrong passphrase or corrupted file")]
+    Decrypt,
+
+    /// A file in the bundle does not match its recorded checksum.
+    #[error("checksum mismatch for {path} in export bundle")]
+    ChecksumMismatch {
+        /// Path of the file within the bundle.
+        path: String,
+    },
+
+    /// Import would overwrite an existing file.
+    #[error("{path} already exists; use --force to overwrite")]
+    AlreadyExists {
+        /// The existing file.
+        path: String,
+    },
+
+    /// Snapshotting or restoring the database failed.
+    #[error(transparent)]
+    Storage(#[from] StorageError),
 }
 
 /// Errors from interacting with LLM providers (OpenAI, Anthropic, Ollama).
@@ -142,6 +314,22 @@ pub enum LlmError {
     /// Content generation failed after retries.
     #[error("content generation failed: {0}")]
     GenerationFailed(String),
+
+    /// The request did not complete within the provider's timeout.
+    #[error("LLM request timed out after {seconds} seconds")]
+    Timeout {
+        /// The timeout that elapsed.
+        seconds: u64,
+    },
+
+    /// The provider is disabled by its circuit breaker after repeated failures.
+    #[error("LLM provider {provider} disabled after repeated failures, retry in {retry_after_secs} seconds")]
+    CircuitOpen {
+        /// The disabled provider.
+        provider: String,
+        /// Seconds until a probe request is allowed.
+        retry_after_secs: u64,
+    },
 }
 
 /// Errors from SQLite storage operations.
@@ -170,6 +358,13 @@ pub enum StorageError {
         #[source]
         source: sqlx::Error,
     },
+
+    /// A compare-and-swap update lost to a concurrent change.
+    #[error("conflict: {message}")]
+    Conflict {
+        /// What changed underneath the caller.
+        message: String,
+    },
 }
 
 /// Errors from the tweet scoring engine.
@@ -181,6 +376,15 @@ pub enum ScoringError {
         /// Details about what is missing or malformed.
         message: String,
     },
+
+    /// The WASM scoring plugin could not be loaded or run.
+    #[error("scoring plugin {path}: {message}")]
+    Plugin {
+        /// Path of the plugin module.
+        path: String,
+        /// What went wrong.
+        message: String,
+    },
 }
 
 #[cfg(test)]
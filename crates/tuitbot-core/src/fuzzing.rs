@@ -0,0 +1,49 @@
+//! Entry points for the cargo-fuzz targets in `fuzz/`.
+//!
+//! Compiled only with the `fuzzing` feature, so parsers that are private to
+//! their modules can be fuzzed without becoming public API.
+
+use crate::safety::links::{extract_domain, extract_urls, parse_query_keys};
+use crate::source::google_drive::{parse_pkcs8_rsa, pem_to_der};
+
+/// Decode a PEM service-account key and parse the PKCS#8 RSA key inside it,
+/// as the Google Drive provider does before signing its JWT.
+///
+/// Returns the modulus and private exponent lengths in bytes.
+pub fn gdrive_parse_pem_key(pem: &str) -> Option<(usize, usize)> {
+    let der = pem_to_der(pem).ok()?;
+    gdrive_parse_pkcs8_der(&der)
+}
+
+/// Parse a DER-encoded PKCS#8 RSA private key.
+///
+/// Returns the modulus and private exponent lengths in bytes.
+pub fn gdrive_parse_pkcs8_der(der: &[u8]) -> Option<(usize, usize)> {
+    let key = parse_pkcs8_rsa(der).ok()?;
+    Some((key.n_bytes.len(), key.d_bytes.len()))
+}
+
+/// Extract the links from post text and parse each one as the QA link
+/// checks in `safety::qa` do.
+///
+/// Returns each URL with its normalized domain and query parameter names.
+pub fn qa_parse_urls(text: &str) -> Vec<(String, Option<String>, Vec<String>)> {
+    extract_urls(text)
+        .into_iter()
+        .map(|url| {
+            let domain = extract_domain(&url);
+            let keys = parse_query_keys(&url).into_iter().collect();
+            (url, domain, keys)
+        })
+        .collect()
+}
+
+/// Parse the domain of a single URL-like string.
+pub fn qa_extract_domain(url: &str) -> Option<String> {
+    extract_domain(url)
+}
+
+/// Parse the query parameter names of a single URL-like string.
+pub fn qa_parse_query_keys(url: &str) -> Vec<String> {
+    parse_query_keys(url).into_iter().collect()
+}
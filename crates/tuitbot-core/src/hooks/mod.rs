@@ -0,0 +1,124 @@
+//! Lifecycle hooks for custom pre/post action policies.
+//!
+//! Hooks configured in `[[hooks.scripts]]` are user-provided executables, or
+//! WASM modules run through `hooks.wasm_runtime`. Each receives a JSON
+//! [`HookPayload`] on stdin.
+//!
+//! Gate events (`pre_post`, `pre_approve`) decide whether the action goes
+//! ahead. Exit status 0 allows it unless stdout carries a JSON verdict:
+//! `{"verdict": "deny", "reason": "..."}` or
+//! `{"verdict": "modify", "content": "..."}`. Any other exit status denies.
+//! A hook that fails to start, times out, or prints an unreadable verdict
+//! denies the action unless it is marked `fail_open`. Modified content is
+//! passed to the next hook, and a denial skips the remaining hooks and
+//! notifies the `on_denial` hooks.
+//!
+//! Notify events (`post_post`, `on_denial`) ignore the verdict. Every run is
+//! recorded in `hook_runs` when the runner has a database pool.
+
+mod runner;
+#[cfg(all(test, unix))]
+mod tests;
+
+use serde::Serialize;
+
+use crate::storage::approval_queue::ApprovalItem;
+
+pub use runner::HookRunner;
+
+/// Editor recorded in the approval edit history for hook rewrites.
+pub const HOOK_EDITOR: &str = "hook";
+
+/// A lifecycle event hooks can run on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HookEvent {
+    /// Before a post is sent to X. Can allow, deny, or rewrite it.
+    PrePost,
+    /// After a post was sent to X.
+    PostPost,
+    /// Before a queued item is approved. Can allow, deny, or rewrite it.
+    PreApprove,
+    /// After an action was denied by a hook or rejected by a reviewer.
+    OnDenial,
+}
+
+impl HookEvent {
+    /// The event name used in the config and payload.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::PrePost => "pre_post",
+            Self::PostPost => "post_post",
+            Self::PreApprove => "pre_approve",
+            Self::OnDenial => "on_denial",
+        }
+    }
+}
+
+/// The action a hook runs for.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct HookAction {
+    /// `reply`, `tweet`, `quote`, `thread_tweet`, `like`, or `follow`.
+    pub action_type: String,
+    /// Text to be posted (empty for likes and follows).
+    pub content: String,
+    /// Tweet replied to or quoted, or the user followed.
+    pub target_id: Option<String>,
+    /// Approval queue item, when the action came from the queue.
+    pub item_id: Option<i64>,
+    /// ID of the posted tweet (`post_post` only).
+    pub posted_id: Option<String>,
+    /// Why the action was denied (`on_denial` only).
+    pub reason: Option<String>,
+}
+
+impl HookAction {
+    /// An action of `action_type` with `content`.
+    pub fn new(action_type: impl Into<String>, content: impl Into<String>) -> Self {
+        Self {
+            action_type: action_type.into(),
+            content: content.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Set the target tweet or user.
+    pub fn with_target(mut self, target_id: impl Into<String>) -> Self {
+        let target_id = target_id.into();
+        self.target_id = (!target_id.is_empty()).then_some(target_id);
+        self
+    }
+
+    /// Set the approval queue item.
+    pub fn with_item(mut self, item_id: i64) -> Self {
+        self.item_id = Some(item_id);
+        self
+    }
+
+    /// The action a queued approval item performs.
+    pub fn from_item(item: &ApprovalItem) -> Self {
+        Self::new(&item.action_type, &item.generated_content)
+            .with_target(&item.target_tweet_id)
+            .with_item(item.id)
+    }
+}
+
+/// JSON written to a hook's stdin.
+#[derive(Debug, Serialize)]
+pub struct HookPayload<'a> {
+    pub event: &'static str,
+    pub hook: &'a str,
+    pub account_id: &'a str,
+    #[serde(flatten)]
+    pub action: &'a HookAction,
+}
+
+/// Combined verdict of the hooks for a gate event.
+#[derive(Debug, Clone, PartialEq)]
+pub enum HookGate {
+    /// Proceed unchanged.
+    Allow,
+    /// Proceed with the rewritten content.
+    Modify(String),
+    /// Do not proceed.
+    Deny { hook: String, reason: String },
+}
@@ -0,0 +1,321 @@
+//! Running hook executables and recording their verdicts.
+
+use std::path::PathBuf;
+use std::process::Stdio;
+use std::time::{Duration, Instant};
+
+use serde::Deserialize;
+use tokio::io::AsyncWriteExt;
+use tokio::process::Command;
+
+use super::{HookAction, HookEvent, HookGate, HookPayload, HOOK_EDITOR};
+use crate::config::{HookConfig, HooksConfig};
+use crate::error::StorageError;
+use crate::storage::accounts::DEFAULT_ACCOUNT_ID;
+use crate::storage::approval_queue::{self, ApprovalItem};
+use crate::storage::hook_runs::{self, NewHookRun};
+use crate::storage::DbPool;
+
+/// Longest reason kept from a hook's stderr.
+const MAX_REASON_CHARS: usize = 500;
+
+/// Optional JSON verdict a hook prints on stdout.
+#[derive(Debug, Default, Deserialize)]
+struct HookReply {
+    #[serde(default)]
+    verdict: Option<String>,
+    #[serde(default)]
+    content: Option<String>,
+    #[serde(default)]
+    reason: Option<String>,
+}
+
+/// Verdict of a single hook run.
+#[derive(Debug, Clone, PartialEq)]
+enum Verdict {
+    Allow,
+    Modify(String),
+    Deny(String),
+    /// The hook could not be run or its output was unreadable.
+    Error(String),
+}
+
+impl Verdict {
+    fn label(&self) -> &'static str {
+        match self {
+            Self::Allow => "allow",
+            Self::Modify(_) => "modify",
+            Self::Deny(_) => "deny",
+            Self::Error(_) => "error",
+        }
+    }
+}
+
+/// Runs the configured hooks for lifecycle events.
+#[derive(Debug, Clone)]
+pub struct HookRunner {
+    config: HooksConfig,
+    pool: Option<DbPool>,
+    account_id: String,
+}
+
+impl HookRunner {
+    pub fn new(config: HooksConfig) -> Self {
+        Self {
+            config,
+            pool: None,
+            account_id: DEFAULT_ACCOUNT_ID.to_string(),
+        }
+    }
+
+    /// Record each run in `pool`.
+    pub fn with_pool(mut self, pool: DbPool) -> Self {
+        self.pool = Some(pool);
+        self
+    }
+
+    /// Record runs and build payloads for `account_id`.
+    pub fn with_account(mut self, account_id: impl Into<String>) -> Self {
+        self.account_id = account_id.into();
+        self
+    }
+
+    /// Whether any hook is registered for `event`.
+    pub fn has(&self, event: HookEvent) -> bool {
+        self.config.for_event(event.as_str()).next().is_some()
+    }
+
+    /// Run the hooks for a gate event and combine their verdicts.
+    ///
+    /// A denial notifies the `on_denial` hooks before returning.
+    pub async fn gate(&self, event: HookEvent, action: &HookAction) -> HookGate {
+        let mut current = action.clone();
+        let mut modified = false;
+
+        for hook in self.config.for_event(event.as_str()) {
+            let verdict = self.run_one(event, hook, &current).await;
+            let denial = match verdict {
+                Verdict::Allow => None,
+                Verdict::Modify(content) => {
+                    current.content = content;
+                    modified = true;
+                    None
+                }
+                Verdict::Deny(reason) => Some(reason),
+                Verdict::Error(error) if hook.fail_open => {
+                    tracing::warn!(hook = hook.display_name(), %error, "Hook failed, allowing");
+                    None
+                }
+                Verdict::Error(error) => Some(format!("hook failed: {error}")),
+            };
+            if let Some(reason) = denial {
+                let name = hook.display_name().to_string();
+                tracing::info!(event = event.as_str(), hook = %name, %reason, "Hook denied action");
+                let mut denied = current;
+                denied.reason = Some(format!("{name}: {reason}"));
+                self.notify(HookEvent::OnDenial, &denied).await;
+                return HookGate::Deny { hook: name, reason };
+            }
+        }
+
+        if modified && current.content != action.content {
+            HookGate::Modify(current.content)
+        } else {
+            HookGate::Allow
+        }
+    }
+
+    /// Run the hooks for a gate event on a queued item. A rewrite is saved
+    /// to the item, with an edit-history entry, before returning.
+    pub async fn gate_item(
+        &self,
+        event: HookEvent,
+        pool: &DbPool,
+        item: &ApprovalItem,
+    ) -> Result<HookGate, StorageError> {
+        let gate = self.gate(event, &HookAction::from_item(item)).await;
+        if let HookGate::Modify(content) = &gate {
+            approval_queue::update_content_for(pool, &self.account_id, item.id, content).await?;
+            approval_queue::record_edit(
+                pool,
+                item.id,
+                HOOK_EDITOR,
+                "generated_content",
+                &item.generated_content,
+                content,
+            )
+            .await?;
+        }
+        Ok(gate)
+    }
+
+    /// Run the hooks for a notify event. Verdicts are recorded but ignored.
+    pub async fn notify(&self, event: HookEvent, action: &HookAction) {
+        for hook in self.config.for_event(event.as_str()) {
+            if let Verdict::Error(error) = self.run_one(event, hook, action).await {
+                tracing::warn!(
+                    event = event.as_str(),
+                    hook = hook.display_name(),
+                    %error,
+                    "Hook failed"
+                );
+            }
+        }
+    }
+
+    /// Run one hook and record the result.
+    async fn run_one(&self, event: HookEvent, hook: &HookConfig, action: &HookAction) -> Verdict {
+        let started = Instant::now();
+        let payload = HookPayload {
+            event: event.as_str(),
+            hook: hook.display_name(),
+            account_id: &self.account_id,
+            action,
+        };
+        let (verdict, exit_code) = match serde_json::to_vec(&payload) {
+            Ok(input) => self.invoke(hook, &input).await,
+            Err(e) => (Verdict::Error(format!("invalid payload: {e}")), None),
+        };
+
+        if let Some(pool) = &self.pool {
+            let reason = match &verdict {
+                Verdict::Deny(reason) | Verdict::Error(reason) => Some(reason.as_str()),
+                Verdict::Allow | Verdict::Modify(_) => None,
+            };
+            let target_id = match event {
+                HookEvent::PostPost => action.posted_id.as_deref(),
+                _ => action.target_id.as_deref(),
+            };
+            let run = NewHookRun {
+                event: event.as_str(),
+                hook_name: hook.display_name(),
+                action_type: &action.action_type,
+                item_id: action.item_id,
+                target_id,
+                verdict: verdict.label(),
+                reason,
+                exit_code,
+                duration_ms: started.elapsed().as_millis() as u64,
+            };
+            if let Err(e) = hook_runs::record_for(pool, &self.account_id, &run).await {
+                tracing::warn!(error = %e, "Failed to record hook run");
+            }
+        }
+        verdict
+    }
+
+    /// Spawn the hook, feed it `input`, and read its verdict.
+    async fn invoke(&self, hook: &HookConfig, input: &[u8]) -> (Verdict, Option<i32>) {
+        let mut command = match self.command(hook) {
+            Ok(command) => command,
+            Err(e) => return (Verdict::Error(e), None),
+        };
+        let mut child = match command.spawn() {
+            Ok(child) => child,
+            Err(e) => return (Verdict::Error(format!("could not start: {e}")), None),
+        };
+        // Feed stdin while waiting, under one timeout: a hook that never reads
+        // its input would otherwise block the write once the pipe fills.
+        let stdin = child.stdin.take();
+        let write = async move {
+            if let Some(mut stdin) = stdin {
+                // A hook that exits without reading its input is not an error.
+                let _ = stdin.write_all(input).await;
+            }
+        };
+        let run = async {
+            let ((), output) = tokio::join!(write, child.wait_with_output());
+            output
+        };
+
+        let timeout = Duration::from_secs(hook.timeout_secs);
+        let output = match tokio::time::timeout(timeout, run).await {
+            Ok(Ok(output)) => output,
+            Ok(Err(e)) => return (Verdict::Error(format!("could not run: {e}")), None),
+            Err(_) => {
+                return (
+                    Verdict::Error(format!("timed out after {}s", hook.timeout_secs)),
+                    None,
+                )
+            }
+        };
+
+        let exit_code = output.status.code();
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let reply = match stdout.trim() {
+            "" => HookReply::default(),
+            text => match serde_json::from_str::<HookReply>(text) {
+                Ok(reply) => reply,
+                Err(e) => {
+                    return (
+                        Verdict::Error(format!("unreadable verdict on stdout: {e}")),
+                        exit_code,
+                    )
+                }
+            },
+        };
+
+        if !output.status.success() {
+            let reason = reply
+                .reason
+                .or_else(|| last_line(&String::from_utf8_lossy(&output.stderr)))
+                .unwrap_or_else(|| format!("exited with {}", output.status));
+            return (Verdict::Deny(reason), exit_code);
+        }
+
+        let verdict = match reply.verdict.as_deref().unwrap_or("allow") {
+            "allow" => Verdict::Allow,
+            "deny" => Verdict::Deny(reply.reason.unwrap_or_else(|| "denied".to_string())),
+            "modify" => match reply.content {
+                Some(content) if !content.trim().is_empty() => Verdict::Modify(content),
+                _ => Verdict::Error("modify verdict without content".to_string()),
+            },
+            other => Verdict::Error(format!("unknown verdict '{other}'")),
+        };
+        (verdict, exit_code)
+    }
+
+    /// Build the sandboxed command for a hook.
+    fn command(&self, hook: &HookConfig) -> Result<Command, String> {
+        let mut command = if hook.is_wasm() {
+            let mut runtime = self.config.wasm_runtime.split_whitespace();
+            let program = runtime
+                .next()
+                .ok_or_else(|| "hooks.wasm_runtime is empty".to_string())?;
+            let mut command = Command::new(program);
+            command.args(runtime).arg(&hook.command);
+            command
+        } else {
+            Command::new(&hook.command)
+        };
+        command.args(&hook.args);
+
+        if !hook.inherit_env {
+            command.env_clear();
+            if let Some(path) = std::env::var_os("PATH") {
+                command.env("PATH", path);
+            }
+        }
+        let dir = hook
+            .working_dir
+            .as_deref()
+            .map(crate::startup::expand_tilde)
+            .unwrap_or_else(std::env::temp_dir);
+        command
+            .current_dir::<PathBuf>(dir)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .kill_on_drop(true);
+        Ok(command)
+    }
+}
+
+/// Last non-empty line of `text`, truncated.
+fn last_line(text: &str) -> Option<String> {
+    text.lines()
+        .rev()
+        .map(str::trim)
+        .find(|line| !line.is_empty())
+        .map(|line| line.chars().take(MAX_REASON_CHARS).collect())
+}
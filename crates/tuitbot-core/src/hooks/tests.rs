@@ -0,0 +1,152 @@
+use super::*;
+use crate::config::{HookConfig, HooksConfig};
+use crate::storage::hook_runs;
+use crate::storage::init_test_db;
+
+fn shell_hook(event: &str, name: &str, script: &str) -> HookConfig {
+    HookConfig {
+        name: name.to_string(),
+        event: event.to_string(),
+        command: "/bin/sh".to_string(),
+        args: vec!["-c".to_string(), script.to_string()],
+        timeout_secs: 5,
+        fail_open: false,
+        inherit_env: false,
+        working_dir: None,
+    }
+}
+
+fn runner(scripts: Vec<HookConfig>) -> HookRunner {
+    HookRunner::new(HooksConfig {
+        scripts,
+        ..HooksConfig::default()
+    })
+}
+
+#[tokio::test]
+async fn exit_status_and_verdicts_decide_gate() {
+    let action = HookAction::new("reply", "hello").with_target("42");
+
+    let allow = runner(vec![shell_hook("pre_post", "ok", "cat >/dev/null")]);
+    assert_eq!(
+        allow.gate(HookEvent::PrePost, &action).await,
+        HookGate::Allow
+    );
+
+    let deny = runner(vec![shell_hook(
+        "pre_post",
+        "block",
+        "echo 'mentions a competitor' >&2; exit 3",
+    )]);
+    assert_eq!(
+        deny.gate(HookEvent::PrePost, &action).await,
+        HookGate::Deny {
+            hook: "block".to_string(),
+            reason: "mentions a competitor".to_string(),
+        }
+    );
+
+    // The second hook sees the first hook's rewrite in its payload.
+    let chain = runner(vec![
+        shell_hook(
+            "pre_post",
+            "upper",
+            r#"echo '{"verdict":"modify","content":"HELLO"}'"#,
+        ),
+        shell_hook(
+            "pre_post",
+            "suffix",
+            r#"grep -q '"content":"HELLO"' && echo '{"verdict":"modify","content":"HELLO!"}'"#,
+        ),
+        shell_hook("pre_approve", "other-event", "exit 1"),
+    ]);
+    assert_eq!(
+        chain.gate(HookEvent::PrePost, &action).await,
+        HookGate::Modify("HELLO!".to_string())
+    );
+}
+
+#[tokio::test]
+async fn failures_deny_unless_fail_open() {
+    let action = HookAction::new("tweet", "hi");
+    let mut slow = shell_hook("pre_approve", "slow", "sleep 5");
+    slow.timeout_secs = 1;
+    let garbled = shell_hook("pre_approve", "garbled", "echo not-json");
+
+    let closed = runner(vec![slow.clone()]);
+    assert!(matches!(
+        closed.gate(HookEvent::PreApprove, &action).await,
+        HookGate::Deny { reason, .. } if reason.contains("timed out")
+    ));
+    // A payload larger than the pipe buffer must not stall past the timeout.
+    let big = HookAction::new("tweet", "x".repeat(1 << 20));
+    let started = std::time::Instant::now();
+    assert!(matches!(
+        closed.gate(HookEvent::PreApprove, &big).await,
+        HookGate::Deny { reason, .. } if reason.contains("timed out")
+    ));
+    assert!(started.elapsed() < std::time::Duration::from_secs(4));
+    let closed = runner(vec![garbled.clone()]);
+    assert!(matches!(
+        closed.gate(HookEvent::PreApprove, &action).await,
+        HookGate::Deny { reason, .. } if reason.contains("unreadable verdict")
+    ));
+
+    slow.fail_open = true;
+    let mut garbled = garbled;
+    garbled.fail_open = true;
+    let open = runner(vec![slow, garbled]);
+    assert_eq!(
+        open.gate(HookEvent::PreApprove, &action).await,
+        HookGate::Allow
+    );
+}
+
+#[tokio::test]
+async fn runs_are_recorded_and_denials_notify() {
+    let pool = init_test_db().await.expect("init db");
+    let runner = runner(vec![
+        shell_hook(
+            "pre_approve",
+            "policy",
+            r#"echo '{"verdict":"deny","reason":"off-brand"}'"#,
+        ),
+        shell_hook("on_denial", "alert", "cat >/dev/null"),
+    ])
+    .with_pool(pool.clone());
+
+    let action = HookAction::new("reply", "text").with_item(5);
+    assert!(matches!(
+        runner.gate(HookEvent::PreApprove, &action).await,
+        HookGate::Deny { .. }
+    ));
+
+    let runs = hook_runs::list_recent(&pool, Some(5), 10)
+        .await
+        .expect("list");
+    assert_eq!(runs.len(), 2);
+    assert_eq!(runs[0].event, "on_denial");
+    assert_eq!(runs[1].hook_name, "policy");
+    assert_eq!(runs[1].verdict, "deny");
+    assert_eq!(runs[1].reason.as_deref(), Some("off-brand"));
+    assert_eq!(runs[1].exit_code, Some(0));
+}
+
+#[tokio::test]
+async fn environment_is_cleared_by_default() {
+    std::env::set_var("TUITBOT_HOOK_TEST_SECRET", "s3cret");
+    let script = r#"[ -z "$TUITBOT_HOOK_TEST_SECRET" ] || exit 1"#;
+    let mut hook = shell_hook("pre_post", "env", script);
+    let action = HookAction::new("tweet", "hi");
+    assert_eq!(
+        runner(vec![hook.clone()])
+            .gate(HookEvent::PrePost, &action)
+            .await,
+        HookGate::Allow
+    );
+    hook.inherit_env = true;
+    assert!(matches!(
+        runner(vec![hook]).gate(HookEvent::PrePost, &action).await,
+        HookGate::Deny { .. }
+    ));
+}
@@ -8,10 +8,15 @@ pub mod config;
 pub mod content;
 pub mod context;
 pub mod error;
+#[cfg(feature = "fuzzing")]
+#[doc(hidden)]
+pub mod fuzzing;
+pub mod hooks;
 pub mod llm;
 pub mod mcp_policy;
 pub mod mutation_gateway;
 pub mod net;
+pub mod reddit;
 pub mod safety;
 pub mod scoring;
 pub mod source;
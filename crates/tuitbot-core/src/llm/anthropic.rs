@@ -2,6 +2,13 @@
 //!
 //! Uses the Anthropic Messages API which has a distinct request format,
 //! authentication mechanism, and response structure from OpenAI-compatible endpoints.
+//!
+//! With prompt caching enabled, the system prompt is sent as a cacheable
+//! block so repeated generations with the same persona and rules are billed
+//! at the cache-read rate instead of the full input rate.
+//!
+//! A `response_schema` is enforced by forcing a single tool call whose input
+//! schema is the requested schema; the tool input is returned as JSON text.
 
 use super::{GenerationParams, LlmProvider, LlmResponse, TokenUsage};
 use crate::error::LlmError;
@@ -19,6 +26,7 @@ pub struct AnthropicProvider {
     base_url: String,
     api_key: String,
     model: String,
+    prompt_caching: bool,
 }
 
 impl AnthropicProvider {
@@ -29,9 +37,16 @@ impl AnthropicProvider {
             base_url: ANTHROPIC_BASE_URL.to_string(),
             api_key,
             model,
+            prompt_caching: false,
         }
     }
 
+    /// Mark the system prompt as cacheable (`cache_control: ephemeral`).
+    pub fn with_prompt_caching(mut self, enabled: bool) -> Self {
+        self.prompt_caching = enabled;
+        self
+    }
+
     /// Create a new Anthropic provider with a custom base URL (for testing).
     pub fn with_base_url(api_key: String, model: String, base_url: String) -> Self {
         Self {
@@ -39,6 +54,7 @@ impl AnthropicProvider {
             base_url,
             api_key,
             model,
+            prompt_caching: false,
         }
     }
 }
@@ -69,14 +85,33 @@ impl LlmProvider for AnthropicProvider {
             max_tokens: params.max_tokens,
             system: if system_prompt.is_empty() {
                 None
+            } else if self.prompt_caching {
+                Some(SystemPrompt::Blocks(vec![SystemBlock {
+                    block_type: "text",
+                    text: system_prompt,
+                    cache_control: CacheControl {
+                        control_type: "ephemeral",
+                    },
+                }]))
             } else {
-                Some(system_prompt)
+                Some(SystemPrompt::Text(system_prompt))
             },
             messages: vec![AnthropicMessage {
                 role: "user",
                 content: user_message,
             }],
             temperature: params.temperature,
+            tools: params.response_schema.as_ref().map(|s| {
+                vec![AnthropicTool {
+                    name: &s.name,
+                    description: "Return the response in this exact structure.",
+                    input_schema: &s.schema,
+                }]
+            }),
+            tool_choice: params.response_schema.as_ref().map(|s| ToolChoice {
+                choice_type: "tool",
+                name: &s.name,
+            }),
         };
 
         let response = self
@@ -121,22 +156,40 @@ impl LlmProvider for AnthropicProvider {
             .await
             .map_err(|e| LlmError::Parse(format!("failed to parse Anthropic response: {e}")))?;
 
-        let text = body
+        // A forced tool call carries the structured response as its input.
+        let tool_input = body
             .content
-            .into_iter()
-            .filter(|b| b.block_type == "text")
-            .map(|b| b.text)
-            .collect::<Vec<_>>()
-            .join("");
-
-        let usage = body.usage.map_or_else(TokenUsage::default, |u| TokenUsage {
-            input_tokens: u.input_tokens.unwrap_or(0),
-            output_tokens: u.output_tokens.unwrap_or(0),
+            .iter()
+            .find(|b| b.block_type == "tool_use")
+            .and_then(|b| b.input.as_ref())
+            .map(|input| input.to_string());
+        let text = tool_input.unwrap_or_else(|| {
+            body.content
+                .iter()
+                .filter(|b| b.block_type == "text")
+                .map(|b| b.text.as_str())
+                .collect::<Vec<_>>()
+                .join("")
+        });
+
+        // Anthropic reports cached tokens separately from `input_tokens`;
+        // fold them in so `input_tokens` is always the full prompt size.
+        let usage = body.usage.map_or_else(TokenUsage::default, |u| {
+            let cache_read = u.cache_read_input_tokens.unwrap_or(0);
+            let cache_write = u.cache_creation_input_tokens.unwrap_or(0);
+            TokenUsage {
+                input_tokens: u.input_tokens.unwrap_or(0) + cache_read + cache_write,
+                output_tokens: u.output_tokens.unwrap_or(0),
+                cache_read_tokens: cache_read,
+                cache_write_tokens: cache_write,
+            }
         });
 
         tracing::debug!(
             input_tokens = usage.input_tokens,
             output_tokens = usage.output_tokens,
+            cache_read_tokens = usage.cache_read_tokens,
+            cache_write_tokens = usage.cache_write_tokens,
             chars = text.len(),
             "LLM response",
         );
@@ -169,9 +222,48 @@ struct AnthropicRequest<'a> {
     model: &'a str,
     max_tokens: u32,
     #[serde(skip_serializing_if = "Option::is_none")]
-    system: Option<&'a str>,
+    system: Option<SystemPrompt<'a>>,
     messages: Vec<AnthropicMessage<'a>>,
     temperature: f32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tools: Option<Vec<AnthropicTool<'a>>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tool_choice: Option<ToolChoice<'a>>,
+}
+
+#[derive(Serialize)]
+struct AnthropicTool<'a> {
+    name: &'a str,
+    description: &'a str,
+    input_schema: &'a serde_json::Value,
+}
+
+#[derive(Serialize)]
+struct ToolChoice<'a> {
+    #[serde(rename = "type")]
+    choice_type: &'static str,
+    name: &'a str,
+}
+
+#[derive(Serialize)]
+#[serde(untagged)]
+enum SystemPrompt<'a> {
+    Text(&'a str),
+    Blocks(Vec<SystemBlock<'a>>),
+}
+
+#[derive(Serialize)]
+struct SystemBlock<'a> {
+    #[serde(rename = "type")]
+    block_type: &'static str,
+    text: &'a str,
+    cache_control: CacheControl,
+}
+
+#[derive(Serialize)]
+struct CacheControl {
+    #[serde(rename = "type")]
+    control_type: &'static str,
 }
 
 #[derive(Serialize)]
@@ -196,6 +288,8 @@ struct ContentBlock {
     block_type: String,
     #[serde(default)]
     text: String,
+    #[serde(default)]
+    input: Option<serde_json::Value>,
 }
 
 #[derive(Deserialize)]
@@ -204,6 +298,10 @@ struct AnthropicUsage {
     input_tokens: Option<u32>,
     #[serde(default)]
     output_tokens: Option<u32>,
+    #[serde(default)]
+    cache_creation_input_tokens: Option<u32>,
+    #[serde(default)]
+    cache_read_input_tokens: Option<u32>,
 }
 
 #[derive(Deserialize)]
@@ -220,7 +318,7 @@ struct AnthropicErrorDetail {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use wiremock::matchers::{header, method, path};
+    use wiremock::matchers::{body_partial_json, header, method, path};
     use wiremock::{Mock, MockServer, ResponseTemplate};
 
     #[tokio::test]
@@ -366,6 +464,87 @@ mod tests {
         assert_eq!(resp.text, "OK");
     }
 
+    #[tokio::test]
+    async fn prompt_caching_marks_system_block_and_reports_cache_tokens() {
+        let server = MockServer::start().await;
+
+        let body = serde_json::json!({
+            "content": [{"type": "text", "text": "cached"}],
+            "model": "claude-sonnet-4-5-20250514",
+            "usage": {
+                "input_tokens": 20,
+                "output_tokens": 8,
+                "cache_creation_input_tokens": 0,
+                "cache_read_input_tokens": 1500
+            }
+        });
+
+        Mock::given(method("POST"))
+            .and(path("/messages"))
+            .and(body_partial_json(serde_json::json!({
+                "system": [{
+                    "type": "text",
+                    "text": "long persona prompt",
+                    "cache_control": {"type": "ephemeral"}
+                }]
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(&body))
+            .mount(&server)
+            .await;
+
+        let provider = AnthropicProvider::with_base_url("key".into(), "model".into(), server.uri())
+            .with_prompt_caching(true);
+
+        let resp = provider
+            .complete("long persona prompt", "hello", &GenerationParams::default())
+            .await
+            .expect("complete");
+
+        assert_eq!(resp.usage.input_tokens, 1520);
+        assert_eq!(resp.usage.cache_read_tokens, 1500);
+        assert_eq!(resp.usage.cache_write_tokens, 0);
+    }
+
+    #[tokio::test]
+    async fn response_schema_forces_tool_call() {
+        let server = MockServer::start().await;
+
+        let body = serde_json::json!({
+            "content": [{
+                "type": "tool_use",
+                "name": "tweet",
+                "input": {"text": "Structured hello"}
+            }],
+            "model": "claude-sonnet-4-5-20250514"
+        });
+
+        Mock::given(method("POST"))
+            .and(path("/messages"))
+            .and(body_partial_json(serde_json::json!({
+                "tool_choice": {"type": "tool", "name": "tweet"},
+                "tools": [{"name": "tweet"}]
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(&body))
+            .mount(&server)
+            .await;
+
+        let provider = AnthropicProvider::with_base_url("key".into(), "model".into(), server.uri());
+        let params = GenerationParams {
+            response_schema: Some(crate::llm::ResponseSchema {
+                name: "tweet".into(),
+                schema: serde_json::json!({"type": "object"}),
+            }),
+            ..Default::default()
+        };
+
+        let resp = provider
+            .complete("system", "hello", &params)
+            .await
+            .expect("complete");
+
+        assert_eq!(resp.text, r#"{"text":"Structured hello"}"#);
+    }
+
     #[test]
     fn provider_name() {
         let provider = AnthropicProvider::new("key".into(), "model".into());
@@ -4,7 +4,9 @@
 //! abstracting away provider-specific construction details.
 
 use super::anthropic::AnthropicProvider;
+use super::openai_batch::OpenAiBatchClient;
 use super::openai_compat::OpenAiCompatProvider;
+use super::resilience::ResilientProvider;
 use super::LlmProvider;
 use crate::config::LlmConfig;
 use crate::error::LlmError;
@@ -12,8 +14,19 @@ use crate::error::LlmError;
 /// Create an LLM provider from configuration.
 ///
 /// Returns `Box<dyn LlmProvider>` so callers are decoupled from the concrete type.
+/// The provider is wrapped with the timeout, retry, and circuit-breaker
+/// policy configured for it in `llm.policies`.
 /// Logs the constructed provider at info level (without the API key).
 pub fn create_provider(config: &LlmConfig) -> Result<Box<dyn LlmProvider>, LlmError> {
+    let provider = create_base_provider(config)?;
+    Ok(Box::new(ResilientProvider::new(
+        provider,
+        config.active_policy(),
+    )))
+}
+
+/// Construct the bare provider for `config`.
+fn create_base_provider(config: &LlmConfig) -> Result<Box<dyn LlmProvider>, LlmError> {
     match config.provider.as_str() {
         "openai" => {
             let api_key = config
@@ -82,23 +95,58 @@ pub fn create_provider(config: &LlmConfig) -> Result<Box<dyn LlmProvider>, LlmEr
                 config.model.clone()
             };
 
-            tracing::info!(provider = "anthropic", model = %model, "Creating LLM provider");
+            tracing::info!(
+                provider = "anthropic",
+                model = %model,
+                prompt_caching = config.prompt_caching,
+                "Creating LLM provider",
+            );
 
-            if let Some(base_url) = config.base_url.as_deref().filter(|u| !u.is_empty()) {
-                Ok(Box::new(AnthropicProvider::with_base_url(
-                    api_key,
-                    model,
-                    base_url.to_string(),
-                )))
-            } else {
-                Ok(Box::new(AnthropicProvider::new(api_key, model)))
-            }
+            let provider =
+                if let Some(base_url) = config.base_url.as_deref().filter(|u| !u.is_empty()) {
+                    AnthropicProvider::with_base_url(api_key, model, base_url.to_string())
+                } else {
+                    AnthropicProvider::new(api_key, model)
+                };
+            Ok(Box::new(
+                provider.with_prompt_caching(config.prompt_caching),
+            ))
         }
         "" => Err(LlmError::NotConfigured),
         _other => Err(LlmError::NotConfigured),
     }
 }
 
+/// Create an OpenAI Batch API client when `batch_mode` is enabled.
+///
+/// Returns `Ok(None)` for other providers or when batch mode is off, so
+/// callers can fall back to synchronous generation.
+pub fn create_batch_client(config: &LlmConfig) -> Result<Option<OpenAiBatchClient>, LlmError> {
+    if !config.batch_mode || config.provider != "openai" {
+        return Ok(None);
+    }
+
+    let api_key = config
+        .api_key
+        .as_deref()
+        .filter(|k| !k.is_empty())
+        .ok_or(LlmError::NotConfigured)?
+        .to_string();
+    let base_url = config
+        .base_url
+        .as_deref()
+        .filter(|u| !u.is_empty())
+        .unwrap_or("https://api.openai.com/v1")
+        .to_string();
+    let model = if config.model.is_empty() {
+        "gpt-4o-mini".to_string()
+    } else {
+        config.model.clone()
+    };
+
+    Ok(Some(OpenAiBatchClient::new(base_url, api_key, model)))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -111,6 +159,7 @@ mod tests {
             api_key: Some("sk-test".to_string()),
             model: "gpt-4o".to_string(),
             base_url: None,
+            ..Default::default()
         };
         let provider = create_provider(&config).expect("create");
         assert_eq!(provider.name(), "openai");
@@ -123,6 +172,7 @@ mod tests {
             api_key: None,
             model: String::new(),
             base_url: None,
+            ..Default::default()
         };
         assert!(matches!(
             create_provider(&config),
@@ -137,6 +187,7 @@ mod tests {
             api_key: None,
             model: String::new(),
             base_url: None,
+            ..Default::default()
         };
         let provider = create_provider(&config).expect("create");
         assert_eq!(provider.name(), "ollama");
@@ -149,6 +200,7 @@ mod tests {
             api_key: Some("sk-ant-test".to_string()),
             model: String::new(),
             base_url: None,
+            ..Default::default()
         };
         let provider = create_provider(&config).expect("create");
         assert_eq!(provider.name(), "anthropic");
@@ -161,6 +213,7 @@ mod tests {
             api_key: None,
             model: String::new(),
             base_url: None,
+            ..Default::default()
         };
         assert!(matches!(
             create_provider(&config),
@@ -175,6 +228,7 @@ mod tests {
             api_key: None,
             model: String::new(),
             base_url: None,
+            ..Default::default()
         };
         assert!(matches!(
             create_provider(&config),
@@ -198,6 +252,7 @@ mod tests {
             api_key: Some("key".to_string()),
             model: String::new(),
             base_url: Some("https://custom.api.com/v1".to_string()),
+            ..Default::default()
         };
         let provider = create_provider(&config).expect("create");
         assert_eq!(provider.name(), "openai");
@@ -210,8 +265,25 @@ mod tests {
             api_key: None,
             model: "custom-model".to_string(),
             base_url: Some("http://remote:11434/v1".to_string()),
+            ..Default::default()
         };
         let provider = create_provider(&config).expect("create");
         assert_eq!(provider.name(), "ollama");
     }
+
+    #[test]
+    fn batch_client_only_for_openai_with_batch_mode() {
+        let mut config = LlmConfig {
+            provider: "openai".to_string(),
+            api_key: Some("sk-test".to_string()),
+            ..Default::default()
+        };
+        assert!(create_batch_client(&config).expect("ok").is_none());
+
+        config.batch_mode = true;
+        assert!(create_batch_client(&config).expect("ok").is_some());
+
+        config.provider = "anthropic".to_string();
+        assert!(create_batch_client(&config).expect("ok").is_none());
+    }
 }
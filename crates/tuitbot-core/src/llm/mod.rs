@@ -1,22 +1,33 @@
 //! LLM provider abstraction and implementations.
 //!
 //! Provides a trait-based abstraction for LLM providers (OpenAI, Anthropic, Ollama)
-//! with typed responses, token usage tracking, and health checking.
+//! with typed responses, token usage tracking, and health checking. Providers
+//! built by the factory are wrapped with timeouts, retries, and a circuit
+//! breaker (see [`resilience`]).
 
 pub mod anthropic;
 pub mod factory;
+pub mod ollama;
+pub mod openai_batch;
 pub mod openai_compat;
 pub mod pricing;
+pub mod resilience;
 
 use crate::error::LlmError;
 
 /// Token usage information from an LLM completion.
 #[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
 pub struct TokenUsage {
-    /// Number of tokens in the input/prompt.
+    /// Number of tokens in the input/prompt, including cached tokens.
     pub input_tokens: u32,
     /// Number of tokens in the output/completion.
     pub output_tokens: u32,
+    /// Input tokens served from the provider's prompt cache.
+    #[serde(default)]
+    pub cache_read_tokens: u32,
+    /// Input tokens written to the provider's prompt cache.
+    #[serde(default)]
+    pub cache_write_tokens: u32,
 }
 
 impl TokenUsage {
@@ -24,6 +35,8 @@ impl TokenUsage {
     pub fn accumulate(&mut self, other: &TokenUsage) {
         self.input_tokens += other.input_tokens;
         self.output_tokens += other.output_tokens;
+        self.cache_read_tokens += other.cache_read_tokens;
+        self.cache_write_tokens += other.cache_write_tokens;
     }
 }
 
@@ -47,6 +60,19 @@ pub struct GenerationParams {
     pub temperature: f32,
     /// Optional system prompt override. If `Some`, replaces the caller's system prompt.
     pub system_prompt: Option<String>,
+    /// Optional JSON schema the response must follow. Providers with native
+    /// structured output (OpenAI response format, Anthropic tool use) enforce
+    /// it and return the JSON as `text`; others ignore it.
+    pub response_schema: Option<ResponseSchema>,
+}
+
+/// A named JSON schema for structured output.
+#[derive(Debug, Clone)]
+pub struct ResponseSchema {
+    /// Schema name (used as the Anthropic tool name / OpenAI schema name).
+    pub name: String,
+    /// JSON schema describing a single object.
+    pub schema: serde_json::Value,
 }
 
 impl Default for GenerationParams {
@@ -55,6 +81,7 @@ impl Default for GenerationParams {
             max_tokens: 512,
             temperature: 0.7,
             system_prompt: None,
+            response_schema: None,
         }
     }
 }
@@ -0,0 +1,151 @@
+//! Ollama local model discovery.
+//!
+//! Generation goes through Ollama's OpenAI-compatible `/v1` endpoint, but the
+//! list of pulled models is only exposed by the native `/api/tags` endpoint.
+//! Used by `tuitbot test` and the init wizard to validate and offer models.
+
+use std::time::Duration;
+
+use serde::Deserialize;
+
+use crate::error::LlmError;
+
+/// Default Ollama OpenAI-compatible base URL.
+pub const DEFAULT_BASE_URL: &str = "http://localhost:11434/v1";
+
+/// How long to wait for Ollama before treating it as not running.
+const DISCOVERY_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// A model pulled into the local Ollama instance.
+#[derive(Debug, Clone, Deserialize)]
+pub struct OllamaModel {
+    /// Model name including tag (e.g. `llama3.2:latest`).
+    pub name: String,
+    /// Size on disk in bytes.
+    #[serde(default)]
+    pub size: u64,
+}
+
+impl OllamaModel {
+    /// Human-readable size hint (e.g. `2.0 GB`).
+    pub fn size_label(&self) -> String {
+        const GB: f64 = 1_000_000_000.0;
+        const MB: f64 = 1_000_000.0;
+        let bytes = self.size as f64;
+        if bytes >= GB {
+            format!("{:.1} GB", bytes / GB)
+        } else {
+            format!("{:.0} MB", bytes / MB)
+        }
+    }
+
+    /// Whether this model satisfies a configured model name.
+    ///
+    /// Ollama treats an untagged name as `:latest`, so `llama3.2` matches
+    /// `llama3.2:latest`.
+    pub fn matches(&self, wanted: &str) -> bool {
+        self.name == wanted || (!wanted.contains(':') && self.name == format!("{wanted}:latest"))
+    }
+}
+
+/// Derive the native API root from a configured base URL.
+///
+/// Strips a trailing `/v1` so both `http://host:11434/v1` and
+/// `http://host:11434` resolve to `http://host:11434`.
+pub fn api_root(base_url: Option<&str>) -> String {
+    let url = base_url
+        .filter(|u| !u.is_empty())
+        .unwrap_or(DEFAULT_BASE_URL)
+        .trim_end_matches('/');
+    url.strip_suffix("/v1").unwrap_or(url).to_string()
+}
+
+/// List models pulled into the Ollama instance at `base_url`.
+pub async fn list_models(base_url: Option<&str>) -> Result<Vec<OllamaModel>, LlmError> {
+    let client = reqwest::Client::builder()
+        .timeout(DISCOVERY_TIMEOUT)
+        .build()?;
+    let response = client
+        .get(format!("{}/api/tags", api_root(base_url)))
+        .send()
+        .await?;
+
+    if !response.status().is_success() {
+        let status = response.status().as_u16();
+        let message = response.text().await.unwrap_or_default();
+        return Err(LlmError::Api { status, message });
+    }
+
+    let body: TagsResponse = response
+        .json()
+        .await
+        .map_err(|e| LlmError::Parse(format!("failed to parse Ollama tags: {e}")))?;
+    Ok(body.models)
+}
+
+/// Find the pulled model matching `wanted`, if any.
+pub fn find_model<'a>(models: &'a [OllamaModel], wanted: &str) -> Option<&'a OllamaModel> {
+    models.iter().find(|m| m.matches(wanted))
+}
+
+#[derive(Deserialize)]
+struct TagsResponse {
+    #[serde(default)]
+    models: Vec<OllamaModel>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn model(name: &str, size: u64) -> OllamaModel {
+        OllamaModel {
+            name: name.to_string(),
+            size,
+        }
+    }
+
+    #[test]
+    fn api_root_strips_v1() {
+        assert_eq!(api_root(None), "http://localhost:11434");
+        assert_eq!(api_root(Some("http://box:11434/v1/")), "http://box:11434");
+        assert_eq!(api_root(Some("http://box:11434")), "http://box:11434");
+    }
+
+    #[test]
+    fn untagged_name_matches_latest() {
+        let models = vec![model("llama3.2:latest", 0), model("qwen2.5:7b", 0)];
+        assert!(find_model(&models, "llama3.2").is_some());
+        assert!(find_model(&models, "qwen2.5:7b").is_some());
+        assert!(find_model(&models, "qwen2.5").is_none());
+        assert!(find_model(&models, "mistral").is_none());
+    }
+
+    #[test]
+    fn size_labels() {
+        assert_eq!(model("a", 2_019_393_189).size_label(), "2.0 GB");
+        assert_eq!(model("b", 274_302_450).size_label(), "274 MB");
+    }
+
+    #[tokio::test]
+    async fn lists_models_from_tags_endpoint() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/tags"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "models": [
+                    {"name": "llama3.2:latest", "size": 2019393189u64},
+                    {"name": "nomic-embed-text:latest", "size": 274302450u64}
+                ]
+            })))
+            .mount(&server)
+            .await;
+
+        let base = format!("{}/v1", server.uri());
+        let models = list_models(Some(&base)).await.expect("list");
+        assert_eq!(models.len(), 2);
+        assert_eq!(models[0].name, "llama3.2:latest");
+    }
+}
@@ -0,0 +1,356 @@
+//! OpenAI Batch API client for non-urgent bulk generation.
+//!
+//! Batch requests are uploaded as a JSONL file, processed asynchronously
+//! within a 24 hour window, and billed at half the synchronous price.
+//! Suitable for work that does not need an immediate answer, such as
+//! drafting a week of content ahead of time.
+
+use super::openai_compat::{ChatCompletionRequest, ChatCompletionResponse};
+use super::{GenerationParams, LlmResponse};
+use crate::error::LlmError;
+use serde::{Deserialize, Serialize};
+
+/// The only completion window the Batch API currently accepts.
+const COMPLETION_WINDOW: &str = "24h";
+
+/// Endpoint each batch line is executed against.
+const BATCH_ENDPOINT: &str = "/v1/chat/completions";
+
+/// A single generation request within a batch.
+#[derive(Debug, Clone)]
+pub struct BatchRequest {
+    /// Caller-chosen identifier used to match results back to requests.
+    pub custom_id: String,
+    /// System prompt (overridden by `params.system_prompt` if set).
+    pub system: String,
+    /// User message.
+    pub user_message: String,
+    /// Generation parameters.
+    pub params: GenerationParams,
+}
+
+/// Lifecycle state of a submitted batch.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct BatchStatus {
+    /// Batch identifier.
+    pub id: String,
+    /// One of `validating`, `in_progress`, `finalizing`, `completed`,
+    /// `failed`, `expired`, `cancelling`, `cancelled`.
+    pub status: String,
+    /// File holding successful results, once completed.
+    #[serde(default)]
+    pub output_file_id: Option<String>,
+    /// File holding per-request errors, if any.
+    #[serde(default)]
+    pub error_file_id: Option<String>,
+}
+
+impl BatchStatus {
+    /// Whether the batch has finished and results can be fetched.
+    pub fn is_completed(&self) -> bool {
+        self.status == "completed"
+    }
+
+    /// Whether the batch ended without producing results.
+    pub fn is_terminal_failure(&self) -> bool {
+        matches!(
+            self.status.as_str(),
+            "failed" | "expired" | "cancelled" | "cancelling"
+        )
+    }
+}
+
+/// Outcome of one request in a completed batch.
+#[derive(Debug, Clone)]
+pub struct BatchResult {
+    /// The `custom_id` of the originating [`BatchRequest`].
+    pub custom_id: String,
+    /// The generated response, or the per-request error message.
+    pub response: Result<LlmResponse, String>,
+}
+
+/// Client for the OpenAI Batch API.
+pub struct OpenAiBatchClient {
+    client: reqwest::Client,
+    base_url: String,
+    api_key: String,
+    model: String,
+}
+
+impl OpenAiBatchClient {
+    /// Create a new batch client.
+    pub fn new(base_url: String, api_key: String, model: String) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            base_url,
+            api_key,
+            model,
+        }
+    }
+
+    /// Upload `requests` and start a batch. Returns the batch status.
+    pub async fn submit(&self, requests: &[BatchRequest]) -> Result<BatchStatus, LlmError> {
+        let jsonl = self.encode(requests)?;
+
+        let part = reqwest::multipart::Part::bytes(jsonl.into_bytes())
+            .file_name("batch.jsonl")
+            .mime_str("application/jsonl")?;
+        let form = reqwest::multipart::Form::new()
+            .text("purpose", "batch")
+            .part("file", part);
+
+        let response = self
+            .client
+            .post(format!("{}/files", self.base_url))
+            .bearer_auth(&self.api_key)
+            .multipart(form)
+            .send()
+            .await?;
+        let file: FileObject = parse_json(response).await?;
+
+        let response = self
+            .client
+            .post(format!("{}/batches", self.base_url))
+            .bearer_auth(&self.api_key)
+            .json(&CreateBatch {
+                input_file_id: &file.id,
+                endpoint: BATCH_ENDPOINT,
+                completion_window: COMPLETION_WINDOW,
+            })
+            .send()
+            .await?;
+        let status: BatchStatus = parse_json(response).await?;
+
+        tracing::info!(
+            batch_id = %status.id,
+            requests = requests.len(),
+            model = %self.model,
+            "Submitted OpenAI batch",
+        );
+        Ok(status)
+    }
+
+    /// Fetch the current status of a batch.
+    pub async fn status(&self, batch_id: &str) -> Result<BatchStatus, LlmError> {
+        let response = self
+            .client
+            .get(format!("{}/batches/{batch_id}", self.base_url))
+            .bearer_auth(&self.api_key)
+            .send()
+            .await?;
+        parse_json(response).await
+    }
+
+    /// Download and parse the results of a completed batch.
+    ///
+    /// Returns an empty list while the batch has no output file yet.
+    pub async fn results(&self, status: &BatchStatus) -> Result<Vec<BatchResult>, LlmError> {
+        let mut results = Vec::new();
+        for file_id in [&status.output_file_id, &status.error_file_id]
+            .into_iter()
+            .flatten()
+        {
+            let response = self
+                .client
+                .get(format!("{}/files/{file_id}/content", self.base_url))
+                .bearer_auth(&self.api_key)
+                .send()
+                .await?;
+            let body = ensure_success(response).await?.text().await?;
+            for line in body.lines().filter(|l| !l.trim().is_empty()) {
+                results.push(decode_line(line)?);
+            }
+        }
+        Ok(results)
+    }
+
+    /// Encode requests as Batch API JSONL.
+    fn encode(&self, requests: &[BatchRequest]) -> Result<String, LlmError> {
+        let mut out = String::new();
+        for req in requests {
+            let system = req.params.system_prompt.as_deref().unwrap_or(&req.system);
+            let line = BatchLine {
+                custom_id: &req.custom_id,
+                method: "POST",
+                url: BATCH_ENDPOINT,
+                body: ChatCompletionRequest::new(
+                    &self.model,
+                    system,
+                    &req.user_message,
+                    &req.params,
+                )
+                .with_schema(req.params.response_schema.as_ref()),
+            };
+            let encoded = serde_json::to_string(&line)
+                .map_err(|e| LlmError::Parse(format!("failed to encode batch line: {e}")))?;
+            out.push_str(&encoded);
+            out.push('\n');
+        }
+        Ok(out)
+    }
+}
+
+/// Decode one line of a batch output or error file.
+fn decode_line(line: &str) -> Result<BatchResult, LlmError> {
+    let parsed: BatchOutputLine = serde_json::from_str(line)
+        .map_err(|e| LlmError::Parse(format!("failed to parse batch result: {e}")))?;
+
+    let response = match (parsed.response, parsed.error) {
+        (_, Some(err)) => Err(err.message),
+        (Some(resp), None) if resp.status_code == 200 => {
+            serde_json::from_value::<ChatCompletionResponse>(resp.body)
+                .map(ChatCompletionResponse::into_response)
+                .map_err(|e| format!("failed to parse batch response body: {e}"))
+        }
+        (Some(resp), None) => Err(format!("request failed with status {}", resp.status_code)),
+        (None, None) => Err("batch line had neither response nor error".to_string()),
+    };
+
+    Ok(BatchResult {
+        custom_id: parsed.custom_id,
+        response,
+    })
+}
+
+async fn ensure_success(response: reqwest::Response) -> Result<reqwest::Response, LlmError> {
+    if response.status().is_success() {
+        return Ok(response);
+    }
+    let status = response.status().as_u16();
+    let message = response.text().await.unwrap_or_default();
+    Err(LlmError::Api { status, message })
+}
+
+async fn parse_json<T: for<'de> Deserialize<'de>>(
+    response: reqwest::Response,
+) -> Result<T, LlmError> {
+    ensure_success(response)
+        .await?
+        .json()
+        .await
+        .map_err(|e| LlmError::Parse(format!("failed to parse batch API response: {e}")))
+}
+
+// --- Internal Serde types ---
+
+#[derive(Serialize)]
+struct BatchLine<'a> {
+    custom_id: &'a str,
+    method: &'a str,
+    url: &'a str,
+    body: ChatCompletionRequest<'a>,
+}
+
+#[derive(Serialize)]
+struct CreateBatch<'a> {
+    input_file_id: &'a str,
+    endpoint: &'a str,
+    completion_window: &'a str,
+}
+
+#[derive(Deserialize)]
+struct FileObject {
+    id: String,
+}
+
+#[derive(Deserialize)]
+struct BatchOutputLine {
+    custom_id: String,
+    #[serde(default)]
+    response: Option<BatchResponse>,
+    #[serde(default)]
+    error: Option<BatchLineError>,
+}
+
+#[derive(Deserialize)]
+struct BatchResponse {
+    status_code: u16,
+    #[serde(default)]
+    body: serde_json::Value,
+}
+
+#[derive(Deserialize)]
+struct BatchLineError {
+    #[serde(default)]
+    message: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn request(id: &str) -> BatchRequest {
+        BatchRequest {
+            custom_id: id.to_string(),
+            system: "persona".to_string(),
+            user_message: "draft a tweet".to_string(),
+            params: GenerationParams::default(),
+        }
+    }
+
+    #[test]
+    fn encodes_one_line_per_request() {
+        let client = OpenAiBatchClient::new("http://x".into(), "k".into(), "gpt-4o-mini".into());
+        let jsonl = client
+            .encode(&[request("a"), request("b")])
+            .expect("encode");
+        let lines: Vec<serde_json::Value> = jsonl
+            .lines()
+            .map(|l| serde_json::from_str(l).expect("json"))
+            .collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0]["custom_id"], "a");
+        assert_eq!(lines[0]["url"], BATCH_ENDPOINT);
+        assert_eq!(lines[0]["body"]["model"], "gpt-4o-mini");
+        assert_eq!(lines[0]["body"]["messages"][0]["content"], "persona");
+    }
+
+    #[test]
+    fn decodes_success_and_error_lines() {
+        let ok = decode_line(
+            r#"{"custom_id":"a","response":{"status_code":200,"body":{"choices":[{"message":{"content":"hi"}}],"model":"gpt-4o-mini","usage":{"prompt_tokens":10,"completion_tokens":2}}}}"#,
+        )
+        .expect("decode");
+        assert_eq!(ok.custom_id, "a");
+        let resp = ok.response.expect("success");
+        assert_eq!(resp.text, "hi");
+        assert_eq!(resp.usage.input_tokens, 10);
+
+        let failed = decode_line(
+            r#"{"custom_id":"b","response":null,"error":{"code":"bad","message":"boom"}}"#,
+        )
+        .expect("decode");
+        assert_eq!(failed.response.unwrap_err(), "boom");
+    }
+
+    #[tokio::test]
+    async fn submit_uploads_file_and_creates_batch() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/files"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!({"id": "file-1"})),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/batches"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({"id": "batch-1", "status": "validating"})),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = OpenAiBatchClient::new(server.uri(), "k".into(), "gpt-4o-mini".into());
+        let status = client.submit(&[request("a")]).await.expect("submit");
+        assert_eq!(status.id, "batch-1");
+        assert!(!status.is_completed());
+        assert!(!status.is_terminal_failure());
+    }
+}
@@ -3,7 +3,7 @@
 //! Works with both OpenAI (cloud) and Ollama (local) since they share
 //! the same chat completions request/response format.
 
-use super::{GenerationParams, LlmProvider, LlmResponse, TokenUsage};
+use super::{GenerationParams, LlmProvider, LlmResponse, ResponseSchema, TokenUsage};
 use crate::error::LlmError;
 use serde::{Deserialize, Serialize};
 
@@ -52,21 +52,12 @@ impl LlmProvider for OpenAiCompatProvider {
             "LLM request",
         );
 
-        let request = ChatCompletionRequest {
-            model: &self.model,
-            messages: vec![
-                ChatMessage {
-                    role: "system",
-                    content: system_prompt,
-                },
-                ChatMessage {
-                    role: "user",
-                    content: user_message,
-                },
-            ],
-            max_tokens: params.max_tokens,
-            temperature: params.temperature,
-        };
+        let mut request =
+            ChatCompletionRequest::new(&self.model, system_prompt, user_message, params);
+        // Only OpenAI itself is known to honour `json_schema` response formats.
+        if self.provider_name == "openai" {
+            request = request.with_schema(params.response_schema.as_ref());
+        }
 
         let response = self
             .client
@@ -103,30 +94,17 @@ impl LlmProvider for OpenAiCompatProvider {
             .await
             .map_err(|e| LlmError::Parse(format!("failed to parse response: {e}")))?;
 
-        let text = body
-            .choices
-            .into_iter()
-            .next()
-            .map(|c| c.message.content)
-            .unwrap_or_default();
-
-        let usage = body.usage.map_or_else(TokenUsage::default, |u| TokenUsage {
-            input_tokens: u.prompt_tokens.unwrap_or(0),
-            output_tokens: u.completion_tokens.unwrap_or(0),
-        });
+        let response = body.into_response();
 
         tracing::debug!(
-            input_tokens = usage.input_tokens,
-            output_tokens = usage.output_tokens,
-            chars = text.len(),
+            input_tokens = response.usage.input_tokens,
+            output_tokens = response.usage.output_tokens,
+            cache_read_tokens = response.usage.cache_read_tokens,
+            chars = response.text.len(),
             "LLM response",
         );
 
-        Ok(LlmResponse {
-            text,
-            usage,
-            model: body.model,
-        })
+        Ok(response)
     }
 
     async fn health_check(&self) -> Result<(), LlmError> {
@@ -143,14 +121,69 @@ impl LlmProvider for OpenAiCompatProvider {
     }
 }
 
-// --- Internal Serde types ---
+// --- Internal Serde types (shared with the batch client) ---
 
 #[derive(Serialize)]
-struct ChatCompletionRequest<'a> {
+pub(super) struct ChatCompletionRequest<'a> {
     model: &'a str,
     messages: Vec<ChatMessage<'a>>,
     max_tokens: u32,
     temperature: f32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    response_format: Option<ResponseFormat<'a>>,
+}
+
+#[derive(Serialize)]
+struct ResponseFormat<'a> {
+    #[serde(rename = "type")]
+    format_type: &'static str,
+    json_schema: JsonSchemaFormat<'a>,
+}
+
+#[derive(Serialize)]
+struct JsonSchemaFormat<'a> {
+    name: &'a str,
+    schema: &'a serde_json::Value,
+    strict: bool,
+}
+
+impl<'a> ChatCompletionRequest<'a> {
+    pub(super) fn new(
+        model: &'a str,
+        system_prompt: &'a str,
+        user_message: &'a str,
+        params: &GenerationParams,
+    ) -> Self {
+        Self {
+            model,
+            messages: vec![
+                ChatMessage {
+                    role: "system",
+                    content: system_prompt,
+                },
+                ChatMessage {
+                    role: "user",
+                    content: user_message,
+                },
+            ],
+            max_tokens: params.max_tokens,
+            temperature: params.temperature,
+            response_format: None,
+        }
+    }
+
+    /// Constrain the response to `schema` via a strict `json_schema` response format.
+    pub(super) fn with_schema(mut self, schema: Option<&'a ResponseSchema>) -> Self {
+        self.response_format = schema.map(|s| ResponseFormat {
+            format_type: "json_schema",
+            json_schema: JsonSchemaFormat {
+                name: &s.name,
+                schema: &s.schema,
+                strict: true,
+            },
+        });
+        self
+    }
 }
 
 #[derive(Serialize)]
@@ -160,7 +193,7 @@ struct ChatMessage<'a> {
 }
 
 #[derive(Deserialize)]
-struct ChatCompletionResponse {
+pub(super) struct ChatCompletionResponse {
     #[serde(default)]
     choices: Vec<Choice>,
     #[serde(default)]
@@ -186,12 +219,50 @@ struct Usage {
     prompt_tokens: Option<u32>,
     #[serde(default)]
     completion_tokens: Option<u32>,
+    #[serde(default)]
+    prompt_tokens_details: Option<PromptTokensDetails>,
+}
+
+#[derive(Deserialize)]
+struct PromptTokensDetails {
+    #[serde(default)]
+    cached_tokens: Option<u32>,
+}
+
+impl ChatCompletionResponse {
+    /// Convert to an [`LlmResponse`], taking the first choice's content.
+    pub(super) fn into_response(self) -> LlmResponse {
+        let text = self
+            .choices
+            .into_iter()
+            .next()
+            .map(|c| c.message.content)
+            .unwrap_or_default();
+
+        // OpenAI caches long prompt prefixes automatically; `prompt_tokens`
+        // already includes the cached portion.
+        let usage = self.usage.map_or_else(TokenUsage::default, |u| TokenUsage {
+            input_tokens: u.prompt_tokens.unwrap_or(0),
+            output_tokens: u.completion_tokens.unwrap_or(0),
+            cache_read_tokens: u
+                .prompt_tokens_details
+                .and_then(|d| d.cached_tokens)
+                .unwrap_or(0),
+            cache_write_tokens: 0,
+        });
+
+        LlmResponse {
+            text,
+            usage,
+            model: self.model,
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use wiremock::matchers::{header, method, path};
+    use wiremock::matchers::{body_partial_json, header, method, path};
     use wiremock::{Mock, MockServer, ResponseTemplate};
 
     #[tokio::test]
@@ -227,6 +298,83 @@ mod tests {
         assert_eq!(resp.model, "gpt-4o-mini");
         assert_eq!(resp.usage.input_tokens, 10);
         assert_eq!(resp.usage.output_tokens, 5);
+        assert_eq!(resp.usage.cache_read_tokens, 0);
+    }
+
+    #[tokio::test]
+    async fn sends_json_schema_response_format() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/chat/completions"))
+            .and(body_partial_json(serde_json::json!({
+                "response_format": {
+                    "type": "json_schema",
+                    "json_schema": {"name": "tweet", "strict": true}
+                }
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "choices": [{"message": {"content": "{\"text\":\"hi\"}"}}],
+                "model": "gpt-4o-mini"
+            })))
+            .mount(&server)
+            .await;
+
+        let provider = OpenAiCompatProvider::new(
+            server.uri(),
+            "key".into(),
+            "gpt-4o-mini".into(),
+            "openai".into(),
+        );
+        let params = GenerationParams {
+            response_schema: Some(ResponseSchema {
+                name: "tweet".into(),
+                schema: serde_json::json!({"type": "object"}),
+            }),
+            ..Default::default()
+        };
+
+        let resp = provider
+            .complete("system", "hello", &params)
+            .await
+            .expect("complete");
+        assert_eq!(resp.text, r#"{"text":"hi"}"#);
+    }
+
+    #[tokio::test]
+    async fn reports_cached_prompt_tokens() {
+        let server = MockServer::start().await;
+
+        let body = serde_json::json!({
+            "choices": [{"message": {"content": "hi"}}],
+            "model": "gpt-4o-mini",
+            "usage": {
+                "prompt_tokens": 2000,
+                "completion_tokens": 5,
+                "prompt_tokens_details": {"cached_tokens": 1792}
+            }
+        });
+
+        Mock::given(method("POST"))
+            .and(path("/chat/completions"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(&body))
+            .mount(&server)
+            .await;
+
+        let provider = OpenAiCompatProvider::new(
+            server.uri(),
+            "key".into(),
+            "gpt-4o-mini".into(),
+            "openai".into(),
+        );
+
+        let resp = provider
+            .complete("system", "hello", &GenerationParams::default())
+            .await
+            .expect("complete");
+
+        assert_eq!(resp.usage.input_tokens, 2000);
+        assert_eq!(resp.usage.cache_read_tokens, 1792);
     }
 
     #[tokio::test]
@@ -2,6 +2,12 @@
 //!
 //! Provides per-token pricing for known models and computes estimated costs.
 //! Prices are in USD per million tokens; Ollama / unknown models default to $0.
+//! [`usage_cost`] applies prompt-cache and batch discounts on top of list price.
+
+use super::TokenUsage;
+
+/// Discount applied to requests sent through the OpenAI Batch API.
+pub const BATCH_DISCOUNT: f64 = 0.5;
 
 /// Per-token pricing for a model.
 #[derive(Debug, Clone, Copy)]
@@ -21,6 +27,50 @@ impl ModelPricing {
     }
 }
 
+/// Cost of a single call after discounts, and how much was saved versus list price.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct UsageCost {
+    /// Estimated billed cost in USD.
+    pub cost_usd: f64,
+    /// List price minus billed cost. Negative when cache writes were not yet reused.
+    pub savings_usd: f64,
+}
+
+/// Compute the billed cost for `usage`, pricing cached input tokens at the
+/// provider's cache rates and halving the total for batch requests.
+pub fn usage_cost(provider: &str, model: &str, usage: &TokenUsage, batch: bool) -> UsageCost {
+    let pricing = lookup(provider, model);
+    let list = pricing.compute_cost(usage.input_tokens, usage.output_tokens);
+
+    let (read_multiplier, write_multiplier) = cache_multipliers(provider);
+    let input_rate = pricing.input_per_million / 1_000_000.0;
+    let uncached = usage
+        .input_tokens
+        .saturating_sub(usage.cache_read_tokens + usage.cache_write_tokens);
+    let input_cost = uncached as f64 * input_rate
+        + usage.cache_read_tokens as f64 * input_rate * read_multiplier
+        + usage.cache_write_tokens as f64 * input_rate * write_multiplier;
+    let output_cost = (usage.output_tokens as f64 / 1_000_000.0) * pricing.output_per_million;
+
+    let mut cost = input_cost + output_cost;
+    if batch {
+        cost *= 1.0 - BATCH_DISCOUNT;
+    }
+    UsageCost {
+        cost_usd: cost,
+        savings_usd: list - cost,
+    }
+}
+
+/// Input-price multipliers for `(cache reads, cache writes)`.
+fn cache_multipliers(provider: &str) -> (f64, f64) {
+    match provider {
+        "anthropic" => (0.1, 1.25),
+        "openai" => (0.5, 1.0),
+        _ => (1.0, 1.0),
+    }
+}
+
 /// Look up pricing for a provider + model combination.
 ///
 /// Falls back to zero-cost for Ollama and unknown models.
@@ -190,6 +240,58 @@ mod tests {
         assert!((cost - expected).abs() < 1e-10);
     }
 
+    #[test]
+    fn usage_cost_without_discounts_matches_list_price() {
+        let usage = TokenUsage {
+            input_tokens: 1000,
+            output_tokens: 500,
+            ..Default::default()
+        };
+        let cost = usage_cost("openai", "gpt-4o", &usage, false);
+        let list = lookup("openai", "gpt-4o").compute_cost(1000, 500);
+        assert!((cost.cost_usd - list).abs() < 1e-12);
+        assert!(cost.savings_usd.abs() < 1e-12);
+    }
+
+    #[test]
+    fn anthropic_cache_reads_are_discounted() {
+        let usage = TokenUsage {
+            input_tokens: 1_000_000,
+            output_tokens: 0,
+            cache_read_tokens: 1_000_000,
+            cache_write_tokens: 0,
+        };
+        let cost = usage_cost("anthropic", "claude-sonnet-4-5", &usage, false);
+        // Sonnet input is $3/M; cache reads bill at 10%.
+        assert!((cost.cost_usd - 0.3).abs() < 1e-9);
+        assert!((cost.savings_usd - 2.7).abs() < 1e-9);
+    }
+
+    #[test]
+    fn anthropic_cache_writes_cost_extra() {
+        let usage = TokenUsage {
+            input_tokens: 1_000_000,
+            output_tokens: 0,
+            cache_read_tokens: 0,
+            cache_write_tokens: 1_000_000,
+        };
+        let cost = usage_cost("anthropic", "claude-sonnet-4-5", &usage, false);
+        assert!((cost.cost_usd - 3.75).abs() < 1e-9);
+        assert!(cost.savings_usd < 0.0);
+    }
+
+    #[test]
+    fn batch_halves_cost() {
+        let usage = TokenUsage {
+            input_tokens: 1_000_000,
+            output_tokens: 1_000_000,
+            ..Default::default()
+        };
+        let cost = usage_cost("openai", "gpt-4o-mini", &usage, true);
+        assert!((cost.cost_usd - 0.375).abs() < 1e-9);
+        assert!((cost.savings_usd - 0.375).abs() < 1e-9);
+    }
+
     #[test]
     fn gemini_2_5_pro_pricing() {
         let p = lookup("gemini", "gemini-2.5-pro");
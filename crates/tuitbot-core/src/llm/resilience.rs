@@ -0,0 +1,370 @@
+//! Timeout, retry, and circuit-breaker wrapper for LLM providers.
+//!
+//! Every provider built by the factory is wrapped in a [`ResilientProvider`].
+//! Each request gets a timeout, transient failures (timeouts, network
+//! errors, 5xx, rate limits) are retried with exponential backoff, and a
+//! per-provider breaker disables a provider after repeated failed requests.
+//! While the breaker is open, requests fail fast with
+//! [`LlmError::CircuitOpen`] and callers skip generation. After the
+//! cooldown one probe request is let through; its outcome closes or
+//! re-opens the breaker.
+//!
+//! Breakers are shared process-wide by provider name so that every
+//! generator using a failing provider backs off together, and so that
+//! [`provider_health`] can report them in health output.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+
+use super::{GenerationParams, LlmProvider, LlmResponse};
+use crate::automation::circuit_breaker::BreakerState;
+use crate::config::LlmPolicyConfig;
+use crate::error::LlmError;
+
+/// Longest `retry-after` the wrapper waits out before giving up on a retry.
+const MAX_RETRY_WAIT: Duration = Duration::from_secs(30);
+
+static BREAKERS: OnceLock<Mutex<HashMap<String, Arc<ProviderBreaker>>>> = OnceLock::new();
+
+/// Breaker and request counters for one provider.
+pub struct ProviderBreaker {
+    provider: String,
+    inner: Mutex<BreakerInner>,
+    requests: AtomicU64,
+    failures: AtomicU64,
+    timeouts: AtomicU64,
+    retries: AtomicU64,
+    trips: AtomicU64,
+    rejected: AtomicU64,
+}
+
+struct BreakerInner {
+    state: BreakerState,
+    threshold: u32,
+    cooldown: Duration,
+    consecutive_failures: u32,
+    opened_at: Option<Instant>,
+    probe_in_flight: bool,
+}
+
+/// Breaker state and counters for health output.
+#[derive(Debug, Clone, Serialize)]
+pub struct ProviderHealth {
+    /// Provider name.
+    pub provider: String,
+    /// `closed`, `open`, or `half_open`.
+    pub state: String,
+    /// Failed requests since the last success.
+    pub consecutive_failures: u32,
+    /// Seconds until a probe is allowed while open.
+    pub cooldown_remaining_seconds: u64,
+    /// Requests admitted since startup.
+    pub requests: u64,
+    /// Requests that failed after retries.
+    pub failures: u64,
+    /// Attempts that hit the timeout.
+    pub timeouts: u64,
+    /// Retry attempts made.
+    pub retries: u64,
+    /// Times the breaker opened.
+    pub trips: u64,
+    /// Requests rejected while the breaker was open.
+    pub rejected: u64,
+}
+
+impl ProviderBreaker {
+    /// Create a standalone breaker. `threshold` of `0` never opens.
+    pub fn new(provider: &str, threshold: u32, cooldown: Duration) -> Arc<Self> {
+        Arc::new(Self {
+            provider: provider.to_string(),
+            inner: Mutex::new(BreakerInner {
+                state: BreakerState::Closed,
+                threshold,
+                cooldown,
+                consecutive_failures: 0,
+                opened_at: None,
+                probe_in_flight: false,
+            }),
+            requests: AtomicU64::new(0),
+            failures: AtomicU64::new(0),
+            timeouts: AtomicU64::new(0),
+            retries: AtomicU64::new(0),
+            trips: AtomicU64::new(0),
+            rejected: AtomicU64::new(0),
+        })
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, BreakerInner> {
+        self.inner.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Admit a request, or fail fast while the breaker is open.
+    fn admit(&self) -> Result<(), LlmError> {
+        let mut inner = self.lock();
+        if inner.state == BreakerState::Open {
+            let elapsed = inner.opened_at.map_or(inner.cooldown, |at| at.elapsed());
+            if elapsed >= inner.cooldown {
+                inner.state = BreakerState::HalfOpen;
+                tracing::info!(provider = %self.provider, "LLM circuit breaker half-open, probing");
+            }
+        }
+        let admitted = match inner.state {
+            BreakerState::Closed => true,
+            BreakerState::HalfOpen if !inner.probe_in_flight => {
+                inner.probe_in_flight = true;
+                true
+            }
+            _ => false,
+        };
+        if admitted {
+            self.requests.fetch_add(1, Ordering::Relaxed);
+            return Ok(());
+        }
+        self.rejected.fetch_add(1, Ordering::Relaxed);
+        Err(LlmError::CircuitOpen {
+            provider: self.provider.clone(),
+            retry_after_secs: cooldown_remaining(&inner).as_secs().max(1),
+        })
+    }
+
+    /// Record a request that reached the provider and got an answer.
+    fn record_success(&self) {
+        let mut inner = self.lock();
+        inner.consecutive_failures = 0;
+        inner.probe_in_flight = false;
+        if inner.state != BreakerState::Closed {
+            inner.state = BreakerState::Closed;
+            inner.opened_at = None;
+            tracing::info!(provider = %self.provider, "LLM circuit breaker closed");
+        }
+    }
+
+    /// Record a request that failed after retries. Only transient failures
+    /// count toward opening the breaker.
+    fn record_failure(&self, transient: bool) {
+        self.failures.fetch_add(1, Ordering::Relaxed);
+        if !transient {
+            self.record_success();
+            return;
+        }
+        let mut inner = self.lock();
+        inner.consecutive_failures += 1;
+        inner.probe_in_flight = false;
+        let trip = match inner.state {
+            BreakerState::HalfOpen => true,
+            BreakerState::Closed => {
+                inner.threshold > 0 && inner.consecutive_failures >= inner.threshold
+            }
+            BreakerState::Open => false,
+        };
+        if trip {
+            inner.state = BreakerState::Open;
+            inner.opened_at = Some(Instant::now());
+            self.trips.fetch_add(1, Ordering::Relaxed);
+            tracing::warn!(
+                provider = %self.provider,
+                failures = inner.consecutive_failures,
+                cooldown_seconds = inner.cooldown.as_secs(),
+                "LLM circuit breaker opened, provider disabled"
+            );
+        }
+    }
+
+    /// Current state and counters.
+    pub fn health(&self) -> ProviderHealth {
+        let inner = self.lock();
+        let state = if inner.state == BreakerState::Open && cooldown_remaining(&inner).is_zero() {
+            BreakerState::HalfOpen
+        } else {
+            inner.state
+        };
+        ProviderHealth {
+            provider: self.provider.clone(),
+            state: state.to_string(),
+            consecutive_failures: inner.consecutive_failures,
+            cooldown_remaining_seconds: cooldown_remaining(&inner).as_secs(),
+            requests: self.requests.load(Ordering::Relaxed),
+            failures: self.failures.load(Ordering::Relaxed),
+            timeouts: self.timeouts.load(Ordering::Relaxed),
+            retries: self.retries.load(Ordering::Relaxed),
+            trips: self.trips.load(Ordering::Relaxed),
+            rejected: self.rejected.load(Ordering::Relaxed),
+        }
+    }
+}
+
+fn cooldown_remaining(inner: &BreakerInner) -> Duration {
+    match (inner.state, inner.opened_at) {
+        (BreakerState::Open, Some(at)) => inner.cooldown.saturating_sub(at.elapsed()),
+        _ => Duration::ZERO,
+    }
+}
+
+/// The shared breaker for `provider`, created on first use. Later calls
+/// apply the latest `policy` thresholds.
+pub fn shared_breaker(provider: &str, policy: &LlmPolicyConfig) -> Arc<ProviderBreaker> {
+    let cooldown = Duration::from_secs(policy.breaker_cooldown_seconds);
+    let mut breakers = BREAKERS
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap_or_else(|e| e.into_inner());
+    let breaker = breakers
+        .entry(provider.to_string())
+        .or_insert_with(|| ProviderBreaker::new(provider, policy.breaker_threshold, cooldown))
+        .clone();
+    {
+        let mut inner = breaker.lock();
+        inner.threshold = policy.breaker_threshold;
+        inner.cooldown = cooldown;
+    }
+    breaker
+}
+
+/// Breaker state for every provider used in this process, by name.
+pub fn provider_health() -> Vec<ProviderHealth> {
+    let Some(breakers) = BREAKERS.get() else {
+        return Vec::new();
+    };
+    let breakers = breakers.lock().unwrap_or_else(|e| e.into_inner());
+    let mut health: Vec<ProviderHealth> = breakers.values().map(|b| b.health()).collect();
+    health.sort_by(|a, b| a.provider.cmp(&b.provider));
+    health
+}
+
+/// Whether an error is worth retrying and counts against provider health.
+fn is_transient(error: &LlmError) -> bool {
+    match error {
+        LlmError::Request(_) | LlmError::Timeout { .. } | LlmError::RateLimited { .. } => true,
+        LlmError::Api { status, .. } => *status >= 500 || *status == 408 || *status == 429,
+        _ => false,
+    }
+}
+
+/// Provider wrapper applying a timeout, retries, and a circuit breaker.
+pub struct ResilientProvider {
+    inner: Box<dyn LlmProvider>,
+    policy: LlmPolicyConfig,
+    timeout: Duration,
+    breaker: Arc<ProviderBreaker>,
+}
+
+impl ResilientProvider {
+    /// Wrap `inner` using the process-wide breaker for its provider name.
+    pub fn new(inner: Box<dyn LlmProvider>, policy: LlmPolicyConfig) -> Self {
+        let breaker = shared_breaker(inner.name(), &policy);
+        Self::with_breaker(inner, policy, breaker)
+    }
+
+    /// Wrap `inner` with a specific breaker.
+    pub fn with_breaker(
+        inner: Box<dyn LlmProvider>,
+        policy: LlmPolicyConfig,
+        breaker: Arc<ProviderBreaker>,
+    ) -> Self {
+        let timeout = policy.timeout(inner.name());
+        Self {
+            inner,
+            policy,
+            timeout,
+            breaker,
+        }
+    }
+
+    /// Delay before retry number `attempt` (0-based) after `error`, or
+    /// `None` when the provider asked for a longer wait than we allow.
+    fn retry_delay(&self, attempt: u32, error: &LlmError) -> Option<Duration> {
+        let backoff = Duration::from_millis(
+            self.policy
+                .retry_backoff_ms
+                .saturating_mul(1 << attempt.min(10)),
+        );
+        match error {
+            LlmError::RateLimited { retry_after_secs } => {
+                let wait = Duration::from_secs(*retry_after_secs);
+                (wait <= MAX_RETRY_WAIT).then(|| wait.max(backoff))
+            }
+            _ => Some(backoff),
+        }
+    }
+
+    async fn attempt(
+        &self,
+        system: &str,
+        user_message: &str,
+        params: &GenerationParams,
+    ) -> Result<LlmResponse, LlmError> {
+        match tokio::time::timeout(
+            self.timeout,
+            self.inner.complete(system, user_message, params),
+        )
+        .await
+        {
+            Ok(result) => result,
+            Err(_) => {
+                self.breaker.timeouts.fetch_add(1, Ordering::Relaxed);
+                Err(LlmError::Timeout {
+                    seconds: self.timeout.as_secs(),
+                })
+            }
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl LlmProvider for ResilientProvider {
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    async fn complete(
+        &self,
+        system: &str,
+        user_message: &str,
+        params: &GenerationParams,
+    ) -> Result<LlmResponse, LlmError> {
+        self.breaker.admit()?;
+        let mut attempt = 0;
+        loop {
+            let error = match self.attempt(system, user_message, params).await {
+                Ok(response) => {
+                    self.breaker.record_success();
+                    return Ok(response);
+                }
+                Err(e) => e,
+            };
+            let transient = is_transient(&error);
+            let delay = (transient && attempt < self.policy.max_retries)
+                .then(|| self.retry_delay(attempt, &error))
+                .flatten();
+            let Some(delay) = delay else {
+                self.breaker.record_failure(transient);
+                return Err(error);
+            };
+            tracing::debug!(
+                provider = self.inner.name(),
+                attempt = attempt + 1,
+                delay_ms = delay.as_millis() as u64,
+                error = %error,
+                "Retrying LLM request"
+            );
+            self.breaker.retries.fetch_add(1, Ordering::Relaxed);
+            tokio::time::sleep(delay).await;
+            attempt += 1;
+        }
+    }
+
+    async fn health_check(&self) -> Result<(), LlmError> {
+        tokio::time::timeout(self.timeout, self.inner.health_check())
+            .await
+            .unwrap_or(Err(LlmError::Timeout {
+                seconds: self.timeout.as_secs(),
+            }))
+    }
+}
+
+#[cfg(test)]
+mod tests;
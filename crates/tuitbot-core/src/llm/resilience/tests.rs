@@ -0,0 +1,187 @@
+use std::collections::VecDeque;
+
+use super::*;
+use crate::llm::TokenUsage;
+
+/// Scripted provider: pops one outcome per call; `None` hangs forever.
+struct Scripted {
+    outcomes: Mutex<VecDeque<Option<Result<(), LlmError>>>>,
+    calls: AtomicU64,
+}
+
+impl Scripted {
+    fn new(outcomes: Vec<Option<Result<(), LlmError>>>) -> Self {
+        Self {
+            outcomes: Mutex::new(outcomes.into()),
+            calls: AtomicU64::new(0),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl LlmProvider for Scripted {
+    fn name(&self) -> &str {
+        "scripted"
+    }
+
+    async fn complete(
+        &self,
+        _system: &str,
+        _user_message: &str,
+        _params: &GenerationParams,
+    ) -> Result<LlmResponse, LlmError> {
+        self.calls.fetch_add(1, Ordering::Relaxed);
+        let outcome = self
+            .outcomes
+            .lock()
+            .unwrap()
+            .pop_front()
+            .unwrap_or(Some(Ok(())));
+        match outcome {
+            Some(Ok(())) => Ok(LlmResponse {
+                text: "ok".to_string(),
+                usage: TokenUsage::default(),
+                model: "scripted".to_string(),
+            }),
+            Some(Err(e)) => Err(e),
+            None => std::future::pending().await,
+        }
+    }
+
+    async fn health_check(&self) -> Result<(), LlmError> {
+        Ok(())
+    }
+}
+
+fn server_error() -> Option<Result<(), LlmError>> {
+    Some(Err(LlmError::Api {
+        status: 503,
+        message: "overloaded".to_string(),
+    }))
+}
+
+fn policy(max_retries: u32, breaker_threshold: u32) -> LlmPolicyConfig {
+    LlmPolicyConfig {
+        timeout_seconds: Some(1),
+        max_retries,
+        retry_backoff_ms: 1,
+        breaker_threshold,
+        breaker_cooldown_seconds: 300,
+    }
+}
+
+fn wrap(
+    outcomes: Vec<Option<Result<(), LlmError>>>,
+    policy: LlmPolicyConfig,
+) -> (ResilientProvider, Arc<ProviderBreaker>) {
+    let breaker = ProviderBreaker::new(
+        "scripted",
+        policy.breaker_threshold,
+        Duration::from_secs(policy.breaker_cooldown_seconds),
+    );
+    let provider =
+        ResilientProvider::with_breaker(Box::new(Scripted::new(outcomes)), policy, breaker.clone());
+    (provider, breaker)
+}
+
+async fn complete(provider: &ResilientProvider) -> Result<LlmResponse, LlmError> {
+    provider
+        .complete("system", "user", &GenerationParams::default())
+        .await
+}
+
+#[tokio::test]
+async fn retries_transient_errors_until_success() {
+    let (provider, breaker) = wrap(vec![server_error(), server_error()], policy(2, 5));
+
+    let response = complete(&provider).await.expect("third attempt succeeds");
+    assert_eq!(response.text, "ok");
+
+    let health = breaker.health();
+    assert_eq!(health.retries, 2);
+    assert_eq!(health.failures, 0);
+    assert_eq!(health.consecutive_failures, 0);
+}
+
+#[tokio::test]
+async fn does_not_retry_permanent_errors() {
+    let (provider, breaker) = wrap(
+        vec![Some(Err(LlmError::Api {
+            status: 401,
+            message: "bad key".to_string(),
+        }))],
+        policy(3, 1),
+    );
+
+    let err = complete(&provider).await.unwrap_err();
+    assert!(matches!(err, LlmError::Api { status: 401, .. }));
+    let health = breaker.health();
+    assert_eq!(health.retries, 0);
+    assert_eq!(health.state, "closed");
+}
+
+#[tokio::test]
+async fn hanging_request_times_out() {
+    let (provider, breaker) = wrap(vec![None], policy(0, 5));
+
+    let err = complete(&provider).await.unwrap_err();
+    assert!(matches!(err, LlmError::Timeout { seconds: 1 }));
+    let health = breaker.health();
+    assert_eq!(health.timeouts, 1);
+    assert_eq!(health.consecutive_failures, 1);
+}
+
+#[tokio::test]
+async fn breaker_opens_after_threshold_and_fails_fast() {
+    let (provider, breaker) = wrap(vec![server_error(), server_error()], policy(0, 2));
+
+    assert!(complete(&provider).await.is_err());
+    assert!(complete(&provider).await.is_err());
+    let err = complete(&provider).await.unwrap_err();
+    assert!(matches!(
+        err,
+        LlmError::CircuitOpen { ref provider, .. } if provider == "scripted"
+    ));
+
+    let health = breaker.health();
+    assert_eq!(health.state, "open");
+    assert_eq!(health.trips, 1);
+    assert_eq!(health.rejected, 1);
+    assert_eq!(health.requests, 2);
+    assert!(health.cooldown_remaining_seconds > 0);
+}
+
+#[tokio::test]
+async fn half_open_probe_success_closes_breaker() {
+    let mut config = policy(0, 1);
+    config.breaker_cooldown_seconds = 0;
+    let (provider, breaker) = wrap(vec![server_error()], config);
+
+    assert!(complete(&provider).await.is_err());
+    assert_eq!(breaker.health().trips, 1);
+
+    complete(&provider).await.expect("probe succeeds");
+    let health = breaker.health();
+    assert_eq!(health.state, "closed");
+    assert_eq!(health.consecutive_failures, 0);
+}
+
+#[test]
+fn shared_breaker_is_reused_and_reported() {
+    let first = shared_breaker("resilience-test", &policy(0, 3));
+    let second = shared_breaker("resilience-test", &policy(0, 7));
+    assert!(Arc::ptr_eq(&first, &second));
+    assert_eq!(first.lock().threshold, 7);
+
+    assert!(provider_health()
+        .iter()
+        .any(|h| h.provider == "resilience-test" && h.state == "closed"));
+}
+
+#[test]
+fn timeout_defaults_depend_on_provider() {
+    let config = LlmPolicyConfig::default();
+    assert_eq!(config.timeout("ollama"), Duration::from_secs(180));
+    assert_eq!(config.timeout("openai"), Duration::from_secs(60));
+    assert_eq!(policy(0, 0).timeout("ollama"), Duration::from_secs(1));
+}
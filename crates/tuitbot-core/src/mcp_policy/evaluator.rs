@@ -1,22 +1,27 @@
 //! Core policy evaluator and audit types.
 //!
 //! v2 evaluation order:
+//! 0. Compliance kill switch engaged → Deny (applies even when enforcement is off)
+//!    Draft-only mode on and the tool posts content → RouteToApproval (likewise)
 //! 1. `enforce_for_mutations` disabled → Allow (master kill switch)
-//! 2. Build effective rule set via `build_effective_rules()`
-//! 3. Walk rules by priority: first match → mapped PolicyDecision
-//! 4. Check per-dimension rate limits from `config.rate_limits`
-//! 5. Check legacy global rate limit (`mcp_mutation`)
-//! 6. Default → Allow
+//! 2. Tool or category disabled via `[mcp_policy.tools]` / `[mcp_policy.categories]` → Deny
+//! 3. Build effective rule set via `build_effective_rules()`
+//! 4. Walk rules by priority: first match → mapped PolicyDecision
+//! 5. Check per-tool / per-category override caps, then per-dimension
+//!    rate limits from `config.rate_limits`
+//! 6. Check legacy global rate limit (`mcp_mutation`)
+//! 7. Default → Allow
 
 use serde::Serialize;
 
 use crate::config::{McpPolicyConfig, OperatingMode};
 use crate::error::StorageError;
-use crate::storage::rate_limits;
 use crate::storage::DbPool;
+use crate::storage::{draft_only, kill_switch, rate_limits};
 
+use super::overrides;
 use super::rules::{build_effective_rules, find_matching_rule, make_eval_context};
-use super::types::{tool_category, PolicyAction, PolicyAuditRecordV2};
+use super::types::{tool_category, PolicyAction, PolicyAuditRecordV2, ToolCategory};
 
 /// The outcome of a policy evaluation.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -48,6 +53,8 @@ pub enum PolicyDenialReason {
     HardRule,
     /// A user-defined rule denied the request.
     UserRule,
+    /// The compliance kill switch is engaged.
+    KillSwitch,
 }
 
 impl std::fmt::Display for PolicyDenialReason {
@@ -57,10 +64,23 @@ impl std::fmt::Display for PolicyDenialReason {
             PolicyDenialReason::RateLimited => write!(f, "rate_limited"),
             PolicyDenialReason::HardRule => write!(f, "hard_rule"),
             PolicyDenialReason::UserRule => write!(f, "user_rule"),
+            PolicyDenialReason::KillSwitch => write!(f, "kill_switch"),
         }
     }
 }
 
+/// Rule ID reported when draft-only mode routes a post to approval.
+pub const DRAFT_ONLY_RULE_ID: &str = "draft_only";
+
+/// Whether `tool_name` publishes content to X (as opposed to reading,
+/// engaging, uploading media, or deleting).
+fn posts_content(tool_name: &str) -> bool {
+    matches!(
+        tool_category(tool_name),
+        ToolCategory::Write | ToolCategory::Thread | ToolCategory::UniversalRequest
+    ) || tool_name == "post_tweet_with_media"
+}
+
 /// Audit record logged for every policy evaluation.
 #[derive(Debug, Clone, Serialize)]
 pub struct PolicyAuditRecord {
@@ -86,16 +106,41 @@ impl McpPolicyEvaluator {
         mode: &OperatingMode,
         tool_name: &str,
     ) -> Result<PolicyDecision, StorageError> {
+        // 0. Compliance kill switch halts every mutation
+        let switch = kill_switch::get_kill_switch(pool).await?;
+        if switch.active {
+            return Ok(PolicyDecision::Deny {
+                reason: PolicyDenialReason::KillSwitch,
+                rule_id: switch.reason,
+            });
+        }
+
+        // Draft-only mode queues every post instead of sending it
+        if posts_content(tool_name) && draft_only::is_draft_only_active(pool).await? {
+            return Ok(PolicyDecision::RouteToApproval {
+                reason: "Draft-only mode is on; posting is disabled".to_string(),
+                rule_id: Some(DRAFT_ONLY_RULE_ID.to_string()),
+            });
+        }
+
         // 1. Enforcement disabled → allow all
         if !config.enforce_for_mutations {
             return Ok(PolicyDecision::Allow);
         }
 
-        // 2. Build effective rule set
+        // 2. Per-tool / per-category disable overrides
+        if let Some(source) = overrides::disabled_by(config, tool_name) {
+            return Ok(PolicyDecision::Deny {
+                reason: PolicyDenialReason::UserRule,
+                rule_id: Some(format!("{source} (disabled)")),
+            });
+        }
+
+        // 3. Build effective rule set
         let rules = build_effective_rules(config, mode);
         let ctx = make_eval_context(tool_name, mode);
 
-        // 3. Walk rules by priority: first match wins
+        // 4. Walk rules by priority: first match wins
         if let Some(rule) = find_matching_rule(&rules, &ctx) {
             let rule_id = Some(rule.id.clone());
             match &rule.action {
@@ -127,14 +172,26 @@ impl McpPolicyEvaluator {
             }
         }
 
-        // 4. Check per-dimension rate limits
-        if let Some(exceeded_key) = rate_limits::check_policy_rate_limits(
-            pool,
-            tool_name,
-            &ctx.category.to_string(),
-            &config.rate_limits,
-        )
-        .await?
+        // 5. Check override caps, then per-dimension rate limits
+        let category = ctx.category.to_string();
+        let override_limits = overrides::override_rate_limits(config);
+        if let Some(exceeded_key) =
+            rate_limits::check_policy_rate_limits(pool, tool_name, &category, &override_limits)
+                .await?
+        {
+            let max = override_limits
+                .iter()
+                .find(|l| l.key == exceeded_key)
+                .map_or(0, |l| l.max_count);
+            return Ok(PolicyDecision::Deny {
+                reason: PolicyDenialReason::UserRule,
+                rule_id: Some(format!("{exceeded_key} (max {max}/hour)")),
+            });
+        }
+
+        if let Some(exceeded_key) =
+            rate_limits::check_policy_rate_limits(pool, tool_name, &category, &config.rate_limits)
+                .await?
         {
             return Ok(PolicyDecision::Deny {
                 reason: PolicyDenialReason::RateLimited,
@@ -142,7 +199,7 @@ impl McpPolicyEvaluator {
             });
         }
 
-        // 5. Legacy global rate limit
+        // 6. Legacy global rate limit
         let allowed = rate_limits::check_rate_limit(pool, "mcp_mutation").await?;
         if !allowed {
             return Ok(PolicyDecision::Deny {
@@ -151,7 +208,7 @@ impl McpPolicyEvaluator {
             });
         }
 
-        // 6. Default: allow
+        // 7. Default: allow
         Ok(PolicyDecision::Allow)
     }
 
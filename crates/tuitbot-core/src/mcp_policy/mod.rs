@@ -4,10 +4,12 @@
 //! side effects, enforcing allow/deny/route-to-approval decisions based
 //! on configuration, rate limits, and operating mode.
 //!
-//! v2 adds multi-dimensional rules, templates, and per-dimension rate limits.
+//! v2 adds multi-dimensional rules, templates, and per-dimension rate limits,
+//! plus per-tool and per-category overrides.
 
 mod evaluator;
 pub mod migration;
+pub mod overrides;
 pub mod rules;
 pub mod templates;
 pub mod types;
@@ -15,4 +17,6 @@ pub mod types;
 #[cfg(test)]
 mod tests;
 
-pub use evaluator::{McpPolicyEvaluator, PolicyAuditRecord, PolicyDecision, PolicyDenialReason};
+pub use evaluator::{
+    McpPolicyEvaluator, PolicyAuditRecord, PolicyDecision, PolicyDenialReason, DRAFT_ONLY_RULE_ID,
+};
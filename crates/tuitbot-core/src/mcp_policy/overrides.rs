@@ -0,0 +1,187 @@
+//! Per-tool and per-category policy overrides.
+//!
+//! Resolves the `[mcp_policy.tools.*]` and `[mcp_policy.categories.*]`
+//! config tables into disable checks and hourly rate limits. A tool entry
+//! is more specific than its category entry, so its `enabled` flag wins;
+//! hourly caps from both apply independently.
+
+use serde::Serialize;
+
+use crate::config::McpPolicyConfig;
+
+use super::types::{tool_category, PolicyRateLimit, RateLimitDimension, ToolCategory};
+
+/// Rate limit period for override caps.
+const HOUR_SECONDS: u64 = 3600;
+
+/// Effective override policy for a single tool.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct EffectiveToolPolicy {
+    pub tool: String,
+    pub category: ToolCategory,
+    pub enabled: bool,
+    /// Override that disabled the tool, e.g. `tool:x_delete_tweet`.
+    pub disabled_by: Option<String>,
+    /// Tightest hourly cap from the tool and category overrides.
+    pub max_per_hour: Option<u32>,
+    /// Rate limit keys whose counters apply to this tool.
+    pub rate_limit_keys: Vec<String>,
+}
+
+/// Rate limit key for a per-tool override cap.
+pub fn tool_limit_key(tool: &str) -> String {
+    format!("mcp:tool:{tool}:hourly")
+}
+
+/// Rate limit key for a per-category override cap.
+pub fn category_limit_key(category: &str) -> String {
+    format!("mcp:category:{category}:hourly")
+}
+
+/// Hourly rate limits derived from overrides with a `max_per_hour`, sorted by key.
+pub fn override_rate_limits(config: &McpPolicyConfig) -> Vec<PolicyRateLimit> {
+    let tools = config.tools.iter().filter_map(|(name, policy)| {
+        policy.max_per_hour.map(|max| PolicyRateLimit {
+            key: tool_limit_key(name),
+            dimension: RateLimitDimension::Tool,
+            match_value: name.clone(),
+            max_count: max,
+            period_seconds: HOUR_SECONDS,
+        })
+    });
+    let categories = config.categories.iter().filter_map(|(name, policy)| {
+        policy.max_per_hour.map(|max| PolicyRateLimit {
+            key: category_limit_key(name),
+            dimension: RateLimitDimension::Category,
+            match_value: name.clone(),
+            max_count: max,
+            period_seconds: HOUR_SECONDS,
+        })
+    });
+
+    let mut limits: Vec<_> = tools.chain(categories).collect();
+    limits.sort_by(|a, b| a.key.cmp(&b.key));
+    limits
+}
+
+/// Return the override that disables `tool_name`, if any.
+pub fn disabled_by(config: &McpPolicyConfig, tool_name: &str) -> Option<String> {
+    if let Some(policy) = config.tools.get(tool_name) {
+        return (!policy.enabled).then(|| format!("tool:{tool_name}"));
+    }
+    let category = tool_category(tool_name).to_string();
+    match config.categories.get(&category) {
+        Some(policy) if !policy.enabled => Some(format!("category:{category}")),
+        _ => None,
+    }
+}
+
+/// Resolve the effective override policy for `tool_name`.
+pub fn resolve_tool_policy(config: &McpPolicyConfig, tool_name: &str) -> EffectiveToolPolicy {
+    let category = tool_category(tool_name);
+    let disabled = disabled_by(config, tool_name);
+
+    let mut max_per_hour: Option<u32> = None;
+    let mut rate_limit_keys = Vec::new();
+    if let Some(max) = config.tools.get(tool_name).and_then(|p| p.max_per_hour) {
+        max_per_hour = Some(max);
+        rate_limit_keys.push(tool_limit_key(tool_name));
+    }
+    let category_name = category.to_string();
+    if let Some(max) = config
+        .categories
+        .get(&category_name)
+        .and_then(|p| p.max_per_hour)
+    {
+        max_per_hour = Some(max_per_hour.map_or(max, |m| m.min(max)));
+        rate_limit_keys.push(category_limit_key(&category_name));
+    }
+
+    EffectiveToolPolicy {
+        tool: tool_name.to_string(),
+        category,
+        enabled: disabled.is_none(),
+        disabled_by: disabled,
+        max_per_hour,
+        rate_limit_keys,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mcp_policy::types::ToolPolicy;
+
+    fn config() -> McpPolicyConfig {
+        let mut config = McpPolicyConfig::default();
+        config.tools.insert(
+            "x_delete_tweet".into(),
+            ToolPolicy {
+                enabled: false,
+                max_per_hour: None,
+            },
+        );
+        config.tools.insert(
+            "x_like_tweet".into(),
+            ToolPolicy {
+                enabled: true,
+                max_per_hour: Some(5),
+            },
+        );
+        config.categories.insert(
+            "engage".into(),
+            ToolPolicy {
+                enabled: true,
+                max_per_hour: Some(10),
+            },
+        );
+        config
+    }
+
+    #[test]
+    fn disabled_tool_and_category() {
+        let mut config = config();
+        assert_eq!(
+            disabled_by(&config, "x_delete_tweet").as_deref(),
+            Some("tool:x_delete_tweet")
+        );
+        assert!(disabled_by(&config, "x_like_tweet").is_none());
+
+        config.categories.get_mut("engage").unwrap().enabled = false;
+        assert_eq!(
+            disabled_by(&config, "x_follow_user").as_deref(),
+            Some("category:engage")
+        );
+        // An explicit tool entry takes precedence over its category.
+        assert!(disabled_by(&config, "x_like_tweet").is_none());
+    }
+
+    #[test]
+    fn override_limits_sorted_by_key() {
+        let keys: Vec<_> = override_rate_limits(&config())
+            .into_iter()
+            .map(|l| l.key)
+            .collect();
+        assert_eq!(
+            keys,
+            vec!["mcp:category:engage:hourly", "mcp:tool:x_like_tweet:hourly"]
+        );
+    }
+
+    #[test]
+    fn resolve_uses_tightest_cap() {
+        let config = config();
+        let like = resolve_tool_policy(&config, "x_like_tweet");
+        assert!(like.enabled);
+        assert_eq!(like.max_per_hour, Some(5));
+        assert_eq!(like.rate_limit_keys.len(), 2);
+
+        let follow = resolve_tool_policy(&config, "x_follow_user");
+        assert_eq!(follow.max_per_hour, Some(10));
+
+        let post = resolve_tool_policy(&config, "x_post_tweet");
+        assert!(post.enabled);
+        assert!(post.max_per_hour.is_none());
+        assert!(post.rate_limit_keys.is_empty());
+    }
+}
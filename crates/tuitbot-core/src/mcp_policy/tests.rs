@@ -1,11 +1,13 @@
 //! Tests for the MCP policy evaluator.
 
-use super::evaluator::{McpPolicyEvaluator, PolicyDecision, PolicyDenialReason};
+use super::evaluator::{
+    McpPolicyEvaluator, PolicyDecision, PolicyDenialReason, DRAFT_ONLY_RULE_ID,
+};
 use super::rules::{build_effective_rules, conditions_match, make_eval_context};
 use super::templates::{get_template, list_templates};
 use super::types::{
     PolicyAction, PolicyRateLimit, PolicyRule, PolicyTemplateName, RateLimitDimension,
-    RuleConditions, ToolCategory,
+    RuleConditions, ToolCategory, ToolPolicy,
 };
 use crate::config::{McpPolicyConfig, OperatingMode};
 use crate::storage::{self, rate_limits};
@@ -62,6 +64,67 @@ async fn enforcement_disabled_allows_all() {
     assert_eq!(decision, PolicyDecision::Allow);
 }
 
+#[tokio::test]
+async fn kill_switch_denies_even_when_enforcement_disabled() {
+    let pool = storage::init_test_db().await.expect("init db");
+    storage::kill_switch::engage_kill_switch(&pool, "legal hold", "test")
+        .await
+        .expect("engage");
+
+    let decision = McpPolicyEvaluator::evaluate(
+        &pool,
+        &enforcement_disabled(),
+        &OperatingMode::Autopilot,
+        "post_tweet",
+    )
+    .await
+    .expect("evaluate");
+
+    assert_eq!(
+        decision,
+        PolicyDecision::Deny {
+            reason: PolicyDenialReason::KillSwitch,
+            rule_id: Some("legal hold".to_string()),
+        }
+    );
+}
+
+#[tokio::test]
+async fn draft_only_routes_posts_but_not_engagement() {
+    let pool = storage::init_test_db().await.expect("init db");
+    storage::draft_only::enable_draft_only(&pool, None, "test")
+        .await
+        .expect("enable");
+
+    for tool in ["post_tweet", "x_reply_to_tweet", "post_thread"] {
+        let decision = McpPolicyEvaluator::evaluate(
+            &pool,
+            &enforcement_disabled(),
+            &OperatingMode::Autopilot,
+            tool,
+        )
+        .await
+        .expect("evaluate");
+        assert!(
+            matches!(
+                &decision,
+                PolicyDecision::RouteToApproval { rule_id: Some(id), .. } if id == DRAFT_ONLY_RULE_ID
+            ),
+            "{tool}: {decision:?}"
+        );
+    }
+
+    let decision = McpPolicyEvaluator::evaluate(
+        &pool,
+        &enforcement_disabled(),
+        &OperatingMode::Autopilot,
+        "like_tweet",
+    )
+    .await
+    .expect("evaluate");
+    assert_eq!(decision, PolicyDecision::Allow);
+}
+
 #[tokio::test]
 async fn blocked_tool_denied() {
     let pool = storage::init_test_db().await.expect("init db");
@@ -807,3 +870,84 @@ async fn record_mutation_increments_per_dimension_counters() {
         .expect("per-tool row");
     assert_eq!(per_tool.request_count, 1);
 }
+
+// =========================================================================
+// Per-tool / per-category override tests
+// =========================================================================
+
+#[tokio::test]
+async fn disabled_tool_override_denies_as_user_rule() {
+    let pool = storage::init_test_db().await.expect("init db");
+    let mut config = no_approval_policy();
+    config.tools.insert(
+        "x_delete_tweet".into(),
+        ToolPolicy {
+            enabled: false,
+            max_per_hour: None,
+        },
+    );
+
+    // Takes precedence over the hard delete-approval rule.
+    let decision =
+        McpPolicyEvaluator::evaluate(&pool, &config, &OperatingMode::Autopilot, "x_delete_tweet")
+            .await
+            .expect("evaluate");
+    assert_eq!(
+        decision,
+        PolicyDecision::Deny {
+            reason: PolicyDenialReason::UserRule,
+            rule_id: Some("tool:x_delete_tweet (disabled)".into()),
+        }
+    );
+}
+
+#[tokio::test]
+async fn category_cap_override_denies_as_user_rule() {
+    let pool = storage::init_test_db().await.expect("init db");
+    rate_limits::init_mcp_rate_limit(&pool, 100)
+        .await
+        .expect("init rate limit");
+
+    let mut config = no_approval_policy();
+    config.categories.insert(
+        "engage".into(),
+        ToolPolicy {
+            enabled: true,
+            max_per_hour: Some(2),
+        },
+    );
+    let limits = config.effective_rate_limits();
+    rate_limits::init_policy_rate_limits(&pool, &limits)
+        .await
+        .expect("init policy rate limits");
+
+    for tool in ["x_like_tweet", "x_follow_user"] {
+        let decision =
+            McpPolicyEvaluator::evaluate(&pool, &config, &OperatingMode::Autopilot, tool)
+                .await
+                .expect("evaluate");
+        assert_eq!(decision, PolicyDecision::Allow);
+        McpPolicyEvaluator::record_mutation(&pool, tool, &limits)
+            .await
+            .expect("record");
+    }
+
+    let decision =
+        McpPolicyEvaluator::evaluate(&pool, &config, &OperatingMode::Autopilot, "x_retweet")
+            .await
+            .expect("evaluate");
+    assert_eq!(
+        decision,
+        PolicyDecision::Deny {
+            reason: PolicyDenialReason::UserRule,
+            rule_id: Some("mcp:category:engage:hourly (max 2/hour)".into()),
+        }
+    );
+
+    // Other categories are unaffected.
+    let decision =
+        McpPolicyEvaluator::evaluate(&pool, &config, &OperatingMode::Autopilot, "x_post_tweet")
+            .await
+            .expect("evaluate");
+    assert_eq!(decision, PolicyDecision::Allow);
+}
@@ -38,6 +38,24 @@ impl std::fmt::Display for ToolCategory {
     }
 }
 
+impl std::str::FromStr for ToolCategory {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "read" => Ok(ToolCategory::Read),
+            "write" => Ok(ToolCategory::Write),
+            "engage" => Ok(ToolCategory::Engage),
+            "media" => Ok(ToolCategory::Media),
+            "thread" => Ok(ToolCategory::Thread),
+            "delete" => Ok(ToolCategory::Delete),
+            "universal_request" => Ok(ToolCategory::UniversalRequest),
+            "enterprise_admin" => Ok(ToolCategory::EnterpriseAdmin),
+            _ => Err(format!("unknown tool category: {s}")),
+        }
+    }
+}
+
 /// Map a tool name to its canonical category.
 pub fn tool_category(name: &str) -> ToolCategory {
     match name {
@@ -142,6 +160,33 @@ pub enum PolicyAction {
     DryRun,
 }
 
+/// Per-tool or per-category policy override.
+///
+/// Configured under `[mcp_policy.tools.<name>]` or
+/// `[mcp_policy.categories.<category>]`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ToolPolicy {
+    /// When false, the tool (or every tool in the category) is denied.
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+    /// Maximum successful mutations per hour. `None` = no dedicated cap.
+    #[serde(default)]
+    pub max_per_hour: Option<u32>,
+}
+
+impl Default for ToolPolicy {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            max_per_hour: None,
+        }
+    }
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
 /// Per-dimension rate limit configuration.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PolicyRateLimit {
@@ -271,6 +316,18 @@ mod tests {
         assert_eq!(ToolCategory::Write.to_string(), "write");
     }
 
+    #[test]
+    fn tool_category_from_str_roundtrips_display() {
+        for cat in [
+            ToolCategory::Engage,
+            ToolCategory::Delete,
+            ToolCategory::UniversalRequest,
+        ] {
+            assert_eq!(cat.to_string().parse::<ToolCategory>(), Ok(cat));
+        }
+        assert!("bogus".parse::<ToolCategory>().is_err());
+    }
+
     #[test]
     fn tool_category_serde_roundtrip() {
         let json = serde_json::to_string(&ToolCategory::UniversalRequest).unwrap();
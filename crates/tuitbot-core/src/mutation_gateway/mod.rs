@@ -5,9 +5,11 @@
 //!
 //! The gateway enforces a strict sequence:
 //! 1. **Policy evaluation** — block rules, rate limits, approval routing, dry-run
-//! 2. **Idempotency** — DB-backed dedup within a 5-minute window
-//! 3. **Audit record** — pending entry before execution
-//! 4. **Post-execution recording** — rate-limit increment + audit completion
+//! 2. **Confirmation** — in confirmation mode, stage the mutation unless a
+//!    matching staged action has already been confirmed
+//! 3. **Idempotency** — DB-backed dedup within a 5-minute window
+//! 4. **Audit record** — pending entry before execution
+//! 5. **Post-execution recording** — rate-limit increment + audit completion
 //!
 //! This single path replaces the scattered policy/idempotency/audit logic
 //! that was previously duplicated across MCP tool handlers.
@@ -20,11 +22,43 @@ use crate::error::StorageError;
 use crate::mcp_policy::types::PolicyRateLimit;
 use crate::mcp_policy::{McpPolicyEvaluator, PolicyDecision, PolicyDenialReason};
 use crate::storage::mutation_audit;
+use crate::storage::staged_actions::{self, StagedAction};
+use crate::storage::workflow_origins::{self, WorkflowOrigin};
 use crate::storage::DbPool;
 
 /// DB-backed idempotency window in seconds (5 minutes).
 const IDEMPOTENCY_WINDOW_SECS: u32 = 300;
 
+/// Map a like/follow tool call to its approval action type and target.
+fn engagement_target(tool_name: &str, params_json: &str) -> Option<(&'static str, String)> {
+    let (action_type, field) = match tool_name {
+        "like_tweet" | "x_like_tweet" => ("like", "tweet_id"),
+        "follow_user" | "x_follow_user" => ("follow", "target_user_id"),
+        _ => return None,
+    };
+    let params: serde_json::Value = serde_json::from_str(params_json).ok()?;
+    let target = params.get(field)?.as_str()?;
+    Some((action_type, target.to_string()))
+}
+
+/// Map a tweet, reply, or quote tool call to its approval action type,
+/// target tweet, and text, so the approval poster can post it as-is.
+fn post_target(tool_name: &str, params_json: &str) -> Option<(&'static str, String, String)> {
+    let (action_type, field) = match tool_name {
+        "post_tweet" | "x_post_tweet" => ("tweet", None),
+        "reply_to_tweet" | "x_reply_to_tweet" => ("reply", Some("in_reply_to_id")),
+        "quote_tweet" | "x_quote_tweet" => ("quote", Some("quoted_tweet_id")),
+        _ => return None,
+    };
+    let params: serde_json::Value = serde_json::from_str(params_json).ok()?;
+    let text = params.get("text")?.as_str()?;
+    let target = match field {
+        Some(field) => params.get(field)?.as_str()?,
+        None => "",
+    };
+    Some((action_type, target.to_string(), text.to_string()))
+}
+
 /// Unified mutation governance gateway.
 ///
 /// Stateless: all dependencies are passed per-call via [`MutationRequest`].
@@ -38,6 +72,9 @@ pub struct MutationRequest<'a> {
     pub mode: &'a OperatingMode,
     pub tool_name: &'a str,
     pub params_json: &'a str,
+    /// Agent / session / goal the mutation was requested under. Linked to
+    /// the approval item or audit record the gateway creates.
+    pub origin: Option<&'a WorkflowOrigin>,
 }
 
 /// The gateway's decision for a mutation request.
@@ -61,6 +98,10 @@ pub enum GatewayDecision {
     /// Dry-run mode: the mutation would have executed but was intercepted.
     DryRun { rule_id: Option<String> },
 
+    /// Confirmation mode: the mutation was staged and must be confirmed
+    /// with the returned token before it executes.
+    Staged(StagedAction),
+
     /// Idempotency hit: an identical recent mutation was already successful.
     Duplicate(DuplicateInfo),
 }
@@ -96,11 +137,12 @@ impl MutationGateway {
     ///
     /// Sequence:
     /// 1. Policy evaluation (block rules, rate limits, approval routing)
-    /// 2. DB-backed idempotency check (5-minute window)
-    /// 3. Pending audit record creation
+    /// 2. Confirmation mode staging (consumes a confirmed staged action)
+    /// 3. DB-backed idempotency check (5-minute window)
+    /// 4. Pending audit record creation
     ///
     /// Returns a [`GatewayDecision`] indicating whether the mutation may
-    /// proceed, was denied, routed to approval, or is a duplicate.
+    /// proceed, was denied, routed to approval, staged, or is a duplicate.
     pub async fn evaluate(req: &MutationRequest<'_>) -> Result<GatewayDecision, StorageError> {
         // ── Step 1: Policy evaluation ──────────────────────────────────
         let decision =
@@ -115,13 +157,24 @@ impl MutationGateway {
                 return Ok(GatewayDecision::Denied(GatewayDenial { reason, rule_id }));
             }
             PolicyDecision::RouteToApproval { reason, rule_id } => {
-                // Enqueue into approval queue.
+                // Enqueue into approval queue. Likes and follows become
+                // target-only engagement items, and tweets, replies, and
+                // quotes become regular posts the approval poster executes.
+                let (action_type, target, content) = if let Some((action_type, target)) =
+                    engagement_target(req.tool_name, req.params_json)
+                {
+                    (action_type, target, String::new())
+                } else if let Some(post) = post_target(req.tool_name, req.params_json) {
+                    post
+                } else {
+                    (req.tool_name, String::new(), req.params_json.to_string())
+                };
                 let queue_id = crate::storage::approval_queue::enqueue_with_context(
                     req.pool,
-                    req.tool_name,
-                    "",
+                    action_type,
+                    &target,
                     "",
-                    req.params_json,
+                    &content,
                     "mcp_policy",
                     req.tool_name,
                     0.0,
@@ -136,6 +189,7 @@ impl MutationGateway {
                 .map_err(|e| StorageError::Query {
                     source: sqlx::Error::Protocol(format!("Failed to enqueue for approval: {e}")),
                 })?;
+                Self::link_origin(req, workflow_origins::APPROVAL_QUEUE, queue_id).await;
 
                 return Ok(GatewayDecision::RoutedToApproval {
                     queue_id,
@@ -149,8 +203,26 @@ impl MutationGateway {
             PolicyDecision::Allow => { /* continue to idempotency check */ }
         }
 
-        // ── Step 2: DB-backed idempotency (5-minute window) ────────────
         let params_hash = mutation_audit::compute_params_hash(req.tool_name, req.params_json);
+
+        // ── Step 2: Confirmation mode (two-phase commit) ───────────────
+        if req.policy_config.confirmation_mode
+            && staged_actions::take_confirmed(req.pool, req.tool_name, &params_hash)
+                .await?
+                .is_none()
+        {
+            let staged = staged_actions::stage_action(
+                req.pool,
+                req.tool_name,
+                req.params_json,
+                &params_hash,
+                req.policy_config.confirmation_ttl_seconds,
+            )
+            .await?;
+            return Ok(GatewayDecision::Staged(staged));
+        }
+
+        // ── Step 3: DB-backed idempotency (5-minute window) ────────────
         let params_summary = mutation_audit::truncate_summary(req.params_json, 500);
 
         if let Some(existing) = mutation_audit::find_recent_duplicate(
@@ -174,6 +246,7 @@ impl MutationGateway {
             .await?;
             let _ =
                 mutation_audit::mark_duplicate(req.pool, dup_id, &existing.correlation_id).await;
+            Self::link_origin(req, workflow_origins::MUTATION_AUDIT, dup_id).await;
 
             return Ok(GatewayDecision::Duplicate(DuplicateInfo {
                 original_correlation_id: existing.correlation_id,
@@ -182,7 +255,7 @@ impl MutationGateway {
             }));
         }
 
-        // ── Step 3: Create pending audit record ────────────────────────
+        // ── Step 4: Create pending audit record ────────────────────────
         let correlation_id = generate_correlation_id();
         let audit_id = mutation_audit::insert_pending(
             req.pool,
@@ -193,6 +266,7 @@ impl MutationGateway {
             &params_summary,
         )
         .await?;
+        Self::link_origin(req, workflow_origins::MUTATION_AUDIT, audit_id).await;
 
         Ok(GatewayDecision::Proceed(MutationTicket {
             audit_id,
@@ -201,6 +275,13 @@ impl MutationGateway {
         }))
     }
 
+    /// Link the request's workflow origin to a created record (best-effort).
+    async fn link_origin(req: &MutationRequest<'_>, record_type: &str, record_id: i64) {
+        if let Some(origin) = req.origin {
+            let _ = workflow_origins::record_origin(req.pool, record_type, record_id, origin).await;
+        }
+    }
+
     /// Record a successful mutation: complete audit + increment rate counters.
     pub async fn complete_success(
         pool: &DbPool,
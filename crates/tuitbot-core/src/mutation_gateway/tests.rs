@@ -18,9 +18,13 @@ fn default_policy_config() -> McpPolicyConfig {
         blocked_tools: vec![],
         require_approval_for: vec![],
         dry_run_mutations: false,
+        confirmation_mode: false,
+        confirmation_ttl_seconds: 300,
         template: None,
         rules: vec![],
         rate_limits: vec![],
+        tools: Default::default(),
+        categories: Default::default(),
     }
 }
 
@@ -37,6 +41,7 @@ fn make_request<'a>(
         mode,
         tool_name,
         params_json,
+        origin: None,
     }
 }
 
@@ -151,7 +156,7 @@ async fn gateway_denies_per_dimension_rate_limit() {
         period_seconds: 3600,
     };
 
-    rate_limits::init_policy_rate_limits(&pool, &[rl.clone()])
+    rate_limits::init_policy_rate_limits(&pool, std::slice::from_ref(&rl))
         .await
         .expect("init policy rl");
     rate_limits::increment_rate_limit(&pool, "mcp:like_tweet:hourly")
@@ -215,6 +220,86 @@ async fn gateway_routes_to_approval() {
     }
 }
 
+#[tokio::test]
+async fn gateway_routes_likes_as_engagement_items() {
+    let pool = init_test_db().await.expect("init db");
+    rate_limits::init_mcp_rate_limit(&pool, 10)
+        .await
+        .expect("init rl");
+
+    let mut config = default_policy_config();
+    config.require_approval_for = vec!["like_tweet".to_string(), "follow_user".to_string()];
+    let mode = OperatingMode::Autopilot;
+
+    let req = make_request(&pool, &config, &mode, "like_tweet", r#"{"tweet_id":"t42"}"#);
+    let like_id = match MutationGateway::evaluate(&req).await.expect("evaluate") {
+        GatewayDecision::RoutedToApproval { queue_id, .. } => queue_id,
+        other => panic!("expected RoutedToApproval, got {other:?}"),
+    };
+    let req = make_request(
+        &pool,
+        &config,
+        &mode,
+        "follow_user",
+        r#"{"target_user_id":"u7"}"#,
+    );
+    let follow_id = match MutationGateway::evaluate(&req).await.expect("evaluate") {
+        GatewayDecision::RoutedToApproval { queue_id, .. } => queue_id,
+        other => panic!("expected RoutedToApproval, got {other:?}"),
+    };
+
+    let like = crate::storage::approval_queue::get_by_id(&pool, like_id)
+        .await
+        .expect("get")
+        .expect("like item");
+    assert_eq!(like.action_type, "like");
+    assert_eq!(like.target_tweet_id, "t42");
+    assert!(like.generated_content.is_empty());
+    assert!(like.is_engagement());
+
+    let follow = crate::storage::approval_queue::get_by_id(&pool, follow_id)
+        .await
+        .expect("get")
+        .expect("follow item");
+    assert_eq!(follow.action_type, "follow");
+    assert_eq!(follow.target_tweet_id, "u7");
+}
+
+#[tokio::test]
+async fn gateway_routes_posts_as_postable_items_in_draft_only_mode() {
+    let pool = init_test_db().await.expect("init db");
+    crate::storage::draft_only::enable_draft_only(&pool, None, "test")
+        .await
+        .expect("enable");
+    let config = default_policy_config();
+    let mode = OperatingMode::Autopilot;
+
+    let req = make_request(
+        &pool,
+        &config,
+        &mode,
+        "reply_to_tweet",
+        r#"{"text":"Nice!","in_reply_to_id":"t9"}"#,
+    );
+    let queue_id = match MutationGateway::evaluate(&req).await.expect("evaluate") {
+        GatewayDecision::RoutedToApproval {
+            queue_id, rule_id, ..
+        } => {
+            assert_eq!(rule_id.as_deref(), Some("draft_only"));
+            queue_id
+        }
+        other => panic!("expected RoutedToApproval, got {other:?}"),
+    };
+
+    let reply = crate::storage::approval_queue::get_by_id(&pool, queue_id)
+        .await
+        .expect("get")
+        .expect("reply item");
+    assert_eq!(reply.action_type, "reply");
+    assert_eq!(reply.target_tweet_id, "t9");
+    assert_eq!(reply.generated_content, "Nice!");
+}
+
 // ── Dry-run ────────────────────────────────────────────────────────────
 
 #[tokio::test]
@@ -283,6 +368,44 @@ async fn gateway_detects_duplicate() {
     }
 }
 
+// ── Confirmation mode (two-phase commit) ───────────────────────────────
+
+#[tokio::test]
+async fn gateway_stages_until_confirmed() {
+    let pool = init_test_db().await.expect("init db");
+    rate_limits::init_mcp_rate_limit(&pool, 100)
+        .await
+        .expect("init rl");
+    let config = McpPolicyConfig {
+        confirmation_mode: true,
+        ..default_policy_config()
+    };
+    let mode = OperatingMode::Autopilot;
+    let params = r#"{"tweet_id":"42"}"#;
+
+    let req = make_request(&pool, &config, &mode, "like_tweet", params);
+    let staged = match MutationGateway::evaluate(&req).await.expect("eval 1") {
+        GatewayDecision::Staged(s) => s,
+        other => panic!("expected Staged, got {other:?}"),
+    };
+    assert_eq!(staged.tool_name, "like_tweet");
+    assert_eq!(staged.params_json, params);
+
+    // Unconfirmed retries stage again rather than executing.
+    assert!(matches!(
+        MutationGateway::evaluate(&req).await.expect("eval 2"),
+        GatewayDecision::Staged(_)
+    ));
+
+    crate::storage::staged_actions::confirm(&pool, staged.id, "mcp")
+        .await
+        .expect("confirm");
+    assert!(matches!(
+        MutationGateway::evaluate(&req).await.expect("eval 3"),
+        GatewayDecision::Proceed(_)
+    ));
+}
+
 // ── Post-execution: success recording ──────────────────────────────────
 
 #[tokio::test]
@@ -463,3 +586,35 @@ fn correlation_ids_are_unique() {
     let unique: std::collections::HashSet<&str> = ids.iter().map(|s| s.as_str()).collect();
     assert_eq!(ids.len(), unique.len());
 }
+
+// ── Workflow origin ────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn gateway_links_workflow_origin() {
+    let pool = init_test_db().await.expect("init db");
+    rate_limits::init_mcp_rate_limit(&pool, 10)
+        .await
+        .expect("init rl");
+
+    let config = default_policy_config();
+    let mode = OperatingMode::Autopilot;
+    let origin = WorkflowOrigin {
+        agent: Some("claude-code".to_string()),
+        session_id: Some("s1".to_string()),
+        goal: None,
+    };
+    let mut req = make_request(&pool, &config, &mode, "like_tweet", r#"{"tweet_id":"1"}"#);
+    req.origin = Some(&origin);
+
+    let ticket = match MutationGateway::evaluate(&req).await.expect("evaluate") {
+        GatewayDecision::Proceed(ticket) => ticket,
+        other => panic!("expected Proceed, got {other:?}"),
+    };
+    let linked =
+        workflow_origins::get_origin(&pool, workflow_origins::MUTATION_AUDIT, ticket.audit_id)
+            .await
+            .expect("get origin")
+            .expect("origin linked");
+    assert_eq!(linked.agent.as_deref(), Some("claude-code"));
+    assert_eq!(linked.session_id.as_deref(), Some("s1"));
+}
@@ -0,0 +1,444 @@
+//! HTTP implementation of [`RedditApi`].
+//!
+//! Tokens come from the password grant and are cached until a minute before
+//! they expire. A 401 from the API drops the cached token so the next call
+//! fetches a fresh one.
+
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use serde::Deserialize;
+
+use super::{RedditApi, RedditPost};
+use crate::config::RedditConfig;
+use crate::error::RedditError;
+
+const TOKEN_URL: &str = "https://www.reddit.com/api/v1/access_token";
+const API_BASE_URL: &str = "https://oauth.reddit.com";
+
+/// Reddit API client authenticated as a script app.
+pub struct RedditHttpClient {
+    client_id: String,
+    client_secret: String,
+    username: String,
+    password: String,
+    user_agent: String,
+    token_url: String,
+    api_base_url: String,
+    http_client: reqwest::Client,
+    token_cache: Mutex<Option<CachedToken>>,
+}
+
+struct CachedToken {
+    access_token: String,
+    expires_at: Instant,
+}
+
+#[derive(Deserialize)]
+struct TokenResponse {
+    access_token: Option<String>,
+    expires_in: Option<u64>,
+    error: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct Listing {
+    data: ListingData,
+}
+
+#[derive(Deserialize)]
+struct ListingData {
+    children: Vec<ListingChild>,
+}
+
+#[derive(Deserialize)]
+struct ListingChild {
+    data: RedditPost,
+}
+
+#[derive(Deserialize)]
+struct CommentResponse {
+    json: CommentJson,
+}
+
+#[derive(Deserialize)]
+struct CommentJson {
+    #[serde(default)]
+    errors: Vec<Vec<serde_json::Value>>,
+    data: Option<CommentData>,
+}
+
+#[derive(Deserialize)]
+struct CommentData {
+    things: Vec<CommentThing>,
+}
+
+#[derive(Deserialize)]
+struct CommentThing {
+    data: CommentThingData,
+}
+
+#[derive(Deserialize)]
+struct CommentThingData {
+    name: String,
+}
+
+impl RedditHttpClient {
+    /// Build a client from the `[reddit]` config section.
+    pub fn new(config: &RedditConfig) -> Self {
+        Self {
+            client_id: config.client_id.clone(),
+            client_secret: config.client_secret.clone().unwrap_or_default(),
+            username: config.username.clone(),
+            password: config.password.clone().unwrap_or_default(),
+            user_agent: config.user_agent.clone(),
+            token_url: TOKEN_URL.to_string(),
+            api_base_url: API_BASE_URL.to_string(),
+            http_client: reqwest::Client::new(),
+            token_cache: Mutex::new(None),
+        }
+    }
+
+    /// Point at a different host for both endpoints (for testing with wiremock).
+    #[cfg(test)]
+    fn with_base_url(mut self, base_url: &str) -> Self {
+        self.token_url = format!("{base_url}/api/v1/access_token");
+        self.api_base_url = base_url.to_string();
+        self
+    }
+
+    /// Obtain a valid access token, fetching a new one if expired.
+    async fn access_token(&self) -> Result<String, RedditError> {
+        if let Ok(cache) = self.token_cache.lock() {
+            if let Some(ref tok) = *cache {
+                if tok.expires_at > Instant::now() + Duration::from_secs(60) {
+                    return Ok(tok.access_token.clone());
+                }
+            }
+        }
+
+        let response = self
+            .http_client
+            .post(&self.token_url)
+            .basic_auth(&self.client_id, Some(&self.client_secret))
+            .header(reqwest::header::USER_AGENT, &self.user_agent)
+            .form(&[
+                ("grant_type", "password"),
+                ("username", self.username.as_str()),
+                ("password", self.password.as_str()),
+            ])
+            .send()
+            .await
+            .map_err(|source| RedditError::Network { source })?;
+
+        let status = response.status();
+        if status == reqwest::StatusCode::TOO_MANY_REQUESTS {
+            return Err(RedditError::RateLimited {
+                retry_after: retry_after(&response),
+            });
+        }
+        if !status.is_success() {
+            return Err(RedditError::Auth {
+                message: format!("token endpoint returned HTTP {}", status.as_u16()),
+            });
+        }
+
+        // Reddit reports a bad grant as HTTP 200 with an `error` field.
+        let body: TokenResponse = response
+            .json()
+            .await
+            .map_err(|source| RedditError::Network { source })?;
+        let access_token = match (body.access_token, body.error) {
+            (Some(token), None) => token,
+            (_, error) => {
+                return Err(RedditError::Auth {
+                    message: error.unwrap_or_else(|| "no access token returned".to_string()),
+                })
+            }
+        };
+
+        if let Ok(mut cache) = self.token_cache.lock() {
+            *cache = Some(CachedToken {
+                access_token: access_token.clone(),
+                expires_at: Instant::now() + Duration::from_secs(body.expires_in.unwrap_or(3600)),
+            });
+        }
+        Ok(access_token)
+    }
+
+    fn clear_token(&self) {
+        if let Ok(mut cache) = self.token_cache.lock() {
+            *cache = None;
+        }
+    }
+
+    /// Map a non-success API response to an error.
+    async fn error_for(&self, response: reqwest::Response) -> RedditError {
+        let status = response.status();
+        if status == reqwest::StatusCode::TOO_MANY_REQUESTS {
+            return RedditError::RateLimited {
+                retry_after: retry_after(&response),
+            };
+        }
+        if status == reqwest::StatusCode::UNAUTHORIZED {
+            self.clear_token();
+            return RedditError::Auth {
+                message: "access token rejected".to_string(),
+            };
+        }
+        let message = response.text().await.unwrap_or_default();
+        RedditError::ApiError {
+            status: status.as_u16(),
+            message,
+        }
+    }
+}
+
+/// Seconds until the rate limit window resets, from `x-ratelimit-reset`.
+fn retry_after(response: &reqwest::Response) -> Option<u64> {
+    response
+        .headers()
+        .get("x-ratelimit-reset")
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.trim().parse::<f64>().ok())
+        .map(|secs| secs.ceil() as u64)
+}
+
+#[async_trait::async_trait]
+impl RedditApi for RedditHttpClient {
+    async fn search_subreddit(
+        &self,
+        subreddit: &str,
+        query: &str,
+        limit: u32,
+    ) -> Result<Vec<RedditPost>, RedditError> {
+        let token = self.access_token().await?;
+        let response = self
+            .http_client
+            .get(format!("{}/r/{subreddit}/search", self.api_base_url))
+            .bearer_auth(token)
+            .header(reqwest::header::USER_AGENT, &self.user_agent)
+            .query(&[
+                ("q", query),
+                ("restrict_sr", "1"),
+                ("sort", "new"),
+                ("t", "week"),
+                ("limit", &limit.to_string()),
+                ("raw_json", "1"),
+            ])
+            .send()
+            .await
+            .map_err(|source| RedditError::Network { source })?;
+
+        if !response.status().is_success() {
+            return Err(self.error_for(response).await);
+        }
+        let listing: Listing = response
+            .json()
+            .await
+            .map_err(|source| RedditError::Network { source })?;
+        Ok(listing
+            .data
+            .children
+            .into_iter()
+            .map(|child| child.data)
+            .collect())
+    }
+
+    async fn submit_comment(&self, parent: &str, text: &str) -> Result<String, RedditError> {
+        let token = self.access_token().await?;
+        let response = self
+            .http_client
+            .post(format!("{}/api/comment", self.api_base_url))
+            .bearer_auth(token)
+            .header(reqwest::header::USER_AGENT, &self.user_agent)
+            .form(&[("api_type", "json"), ("thing_id", parent), ("text", text)])
+            .send()
+            .await
+            .map_err(|source| RedditError::Network { source })?;
+
+        if !response.status().is_success() {
+            return Err(self.error_for(response).await);
+        }
+        let body: CommentResponse = response
+            .json()
+            .await
+            .map_err(|source| RedditError::Network { source })?;
+        if let Some(error) = body.json.errors.first() {
+            let message = error
+                .iter()
+                .filter_map(|v| v.as_str())
+                .collect::<Vec<_>>()
+                .join(": ");
+            return Err(RedditError::ApiError {
+                status: 200,
+                message,
+            });
+        }
+        body.json
+            .data
+            .and_then(|data| data.things.into_iter().next())
+            .map(|thing| thing.data.name)
+            .ok_or_else(|| RedditError::ApiError {
+                status: 200,
+                message: "comment response had no comment".to_string(),
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{body_string_contains, header, method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn config() -> RedditConfig {
+        RedditConfig {
+            enabled: true,
+            client_id: "app".to_string(),
+            client_secret: Some("secret".to_string()),
+            username: "me".to_string(),
+            password: Some("hunter2".to_string()),
+            subreddits: vec!["rust".to_string()],
+            ..Default::default()
+        }
+    }
+
+    async fn mount_token(server: &MockServer) {
+        Mock::given(method("POST"))
+            .and(path("/api/v1/access_token"))
+            .and(body_string_contains("grant_type=password"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "access_token": "tok",
+                "token_type": "bearer",
+                "expires_in": 3600
+            })))
+            .expect(1)
+            .mount(server)
+            .await;
+    }
+
+    #[tokio::test]
+    async fn search_returns_posts_and_caches_token() {
+        let server = MockServer::start().await;
+        mount_token(&server).await;
+        Mock::given(method("GET"))
+            .and(path("/r/rust/search"))
+            .and(query_param("q", "cli"))
+            .and(query_param("restrict_sr", "1"))
+            .and(header("authorization", "Bearer tok"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "kind": "Listing",
+                "data": { "children": [{
+                    "kind": "t3",
+                    "data": {
+                        "id": "1abcd2",
+                        "name": "t3_1abcd2",
+                        "subreddit": "rust",
+                        "title": "Best CLI crates?",
+                        "selftext": "Looking for arg parsing.",
+                        "author": "ferris",
+                        "score": 42,
+                        "num_comments": 7,
+                        "created_utc": 1760000000.0,
+                        "permalink": "/r/rust/comments/1abcd2/best_cli_crates/",
+                        "url": "https://www.reddit.com/r/rust/comments/1abcd2/best_cli_crates/",
+                        "locked": false,
+                        "archived": false
+                    }
+                }]}
+            })))
+            .expect(2)
+            .mount(&server)
+            .await;
+
+        let client = RedditHttpClient::new(&config()).with_base_url(&server.uri());
+        let posts = client.search_subreddit("rust", "cli", 10).await.unwrap();
+        assert_eq!(posts.len(), 1);
+        assert_eq!(posts[0].name, "t3_1abcd2");
+        assert_eq!(posts[0].score, 42);
+        assert!(posts[0].accepts_comments());
+        client.search_subreddit("rust", "cli", 10).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn bad_grant_is_auth_error() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/api/v1/access_token"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({ "error": "invalid_grant" })),
+            )
+            .mount(&server)
+            .await;
+
+        let client = RedditHttpClient::new(&config()).with_base_url(&server.uri());
+        let err = client
+            .search_subreddit("rust", "cli", 10)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, RedditError::Auth { message } if message == "invalid_grant"));
+    }
+
+    #[tokio::test]
+    async fn submit_comment_returns_fullname_or_api_error() {
+        let server = MockServer::start().await;
+        mount_token(&server).await;
+        Mock::given(method("POST"))
+            .and(path("/api/comment"))
+            .and(body_string_contains("thing_id=t3_1abcd2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "json": { "errors": [], "data": { "things": [
+                    { "kind": "t1", "data": { "name": "t1_xyz", "id": "xyz" } }
+                ]}}
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/api/comment"))
+            .and(body_string_contains("thing_id=t3_locked"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "json": { "errors": [["THREAD_LOCKED", "that thread is locked", "parent"]] }
+            })))
+            .mount(&server)
+            .await;
+
+        let client = RedditHttpClient::new(&config()).with_base_url(&server.uri());
+        let name = client
+            .submit_comment("t3_1abcd2", "Try clap")
+            .await
+            .unwrap();
+        assert_eq!(name, "t1_xyz");
+
+        let err = client
+            .submit_comment("t3_locked", "Try clap")
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(err, RedditError::ApiError { message, .. } if message.starts_with("THREAD_LOCKED"))
+        );
+    }
+
+    #[tokio::test]
+    async fn rate_limit_reads_reset_header() {
+        let server = MockServer::start().await;
+        mount_token(&server).await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(429).insert_header("x-ratelimit-reset", "42.0"))
+            .mount(&server)
+            .await;
+
+        let client = RedditHttpClient::new(&config()).with_base_url(&server.uri());
+        let err = client
+            .search_subreddit("rust", "cli", 10)
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            RedditError::RateLimited {
+                retry_after: Some(42)
+            }
+        ));
+    }
+}
@@ -0,0 +1,75 @@
+//! Reddit API client for subreddit monitoring and commenting.
+//!
+//! Authenticates as a Reddit "script" app with the password grant, searches
+//! subreddits for new posts, and submits comments. Used by the Reddit loop
+//! (monitoring) and the Reddit poster (approved comments).
+
+pub mod client;
+
+pub use client::RedditHttpClient;
+
+use crate::error::RedditError;
+use serde::{Deserialize, Serialize};
+
+/// A Reddit submission (link or self post).
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct RedditPost {
+    /// Base-36 post ID, e.g. `1abcd2`.
+    pub id: String,
+    /// Fullname used as the comment parent, e.g. `t3_1abcd2`.
+    pub name: String,
+    /// Subreddit name without the `r/` prefix.
+    pub subreddit: String,
+    pub title: String,
+    /// Self-post body (empty for link posts).
+    #[serde(default)]
+    pub selftext: String,
+    pub author: String,
+    /// Net upvotes.
+    #[serde(default)]
+    pub score: i64,
+    #[serde(default)]
+    pub num_comments: u64,
+    /// Creation time as Unix seconds.
+    #[serde(default)]
+    pub created_utc: f64,
+    /// Path of the post on reddit.com, e.g. `/r/rust/comments/1abcd2/...`.
+    #[serde(default)]
+    pub permalink: String,
+    /// Link target (the permalink for self posts).
+    #[serde(default)]
+    pub url: String,
+    /// Locked or archived posts cannot be commented on.
+    #[serde(default)]
+    pub locked: bool,
+    #[serde(default)]
+    pub archived: bool,
+}
+
+impl RedditPost {
+    /// Whether new comments are accepted on this post.
+    pub fn accepts_comments(&self) -> bool {
+        !self.locked && !self.archived
+    }
+
+    /// Full URL of the post's comment page.
+    pub fn comments_url(&self) -> String {
+        format!("https://www.reddit.com{}", self.permalink)
+    }
+}
+
+/// Trait abstracting the Reddit API operations Tuitbot uses.
+#[async_trait::async_trait]
+pub trait RedditApi: Send + Sync {
+    /// Search `subreddit` for posts matching `query`, newest first.
+    async fn search_subreddit(
+        &self,
+        subreddit: &str,
+        query: &str,
+        limit: u32,
+    ) -> Result<Vec<RedditPost>, RedditError>;
+
+    /// Comment on the thing with fullname `parent` (a post or comment).
+    /// Returns the new comment's fullname.
+    async fn submit_comment(&self, parent: &str, text: &str) -> Result<String, RedditError>;
+}
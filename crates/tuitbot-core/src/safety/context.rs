@@ -0,0 +1,154 @@
+//! Controversy check for reply targets.
+//!
+//! Flags tweets that draw far more quote tweets than likes, either on the
+//! tweet itself or across the author's recently discovered tweets, and maps
+//! the configured [`ContextPolicy`] onto a verdict.
+//!
+//! X API v2 does not expose Community Notes on tweet lookups, so a noted
+//! tweet is caught only through the quote pile-on it usually draws.
+
+use chrono::{Duration, Utc};
+
+use super::{DenialReason, SafetyGuard};
+use crate::config::{ContextCheckConfig, ContextPolicy};
+use crate::error::StorageError;
+use crate::storage::tweets;
+
+/// Outcome of the controversy check.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ContextVerdict {
+    /// No signal, or the check is off.
+    Clear,
+    /// Do not reply.
+    Skip(DenialReason),
+    /// Reply only after human approval.
+    RequireApproval(DenialReason),
+}
+
+/// Quote-to-like ratio of `quotes` and `likes`, if it trips `config`.
+pub fn flagged_quote_ratio(config: &ContextCheckConfig, quotes: u64, likes: u64) -> Option<f64> {
+    if quotes < config.min_quotes {
+        return None;
+    }
+    let ratio = quotes as f64 / likes.max(1) as f64;
+    (ratio > config.max_quote_ratio).then_some(ratio)
+}
+
+impl SafetyGuard {
+    /// Check a reply target for controversy signals under `config`.
+    ///
+    /// The tweet's own metrics are checked first, then the author's tweets
+    /// discovered within `author_window_days`.
+    pub async fn check_context(
+        &self,
+        config: &ContextCheckConfig,
+        author_id: &str,
+        quotes: u64,
+        likes: u64,
+    ) -> Result<ContextVerdict, StorageError> {
+        if config.policy == ContextPolicy::Off {
+            return Ok(ContextVerdict::Clear);
+        }
+
+        let signal = match flagged_quote_ratio(config, quotes, likes) {
+            Some(ratio) => Some(format!("tweet quote/like ratio {ratio:.2}")),
+            None => {
+                let since = (Utc::now() - Duration::days(i64::from(config.author_window_days)))
+                    .format("%Y-%m-%dT%H:%M:%SZ")
+                    .to_string();
+                let (author_quotes, author_likes) =
+                    tweets::author_quote_totals(&self.pool, author_id, &since).await?;
+                flagged_quote_ratio(config, author_quotes as u64, author_likes as u64).map(
+                    |ratio| {
+                        format!(
+                            "author quote/like ratio {ratio:.2} over {} days",
+                            config.author_window_days
+                        )
+                    },
+                )
+            }
+        };
+
+        let Some(signal) = signal else {
+            return Ok(ContextVerdict::Clear);
+        };
+        tracing::debug!(author_id, signal = %signal, "Controversy signal on reply target");
+        let denial = DenialReason::ControversialContext { signal };
+        Ok(match config.policy {
+            ContextPolicy::Off => ContextVerdict::Clear,
+            ContextPolicy::Skip => ContextVerdict::Skip(denial),
+            ContextPolicy::RequireApproval => ContextVerdict::RequireApproval(denial),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::init_test_db;
+    use crate::storage::tweets::{insert_discovered_tweet, DiscoveredTweet};
+
+    fn config(policy: ContextPolicy) -> ContextCheckConfig {
+        ContextCheckConfig {
+            policy,
+            ..ContextCheckConfig::default()
+        }
+    }
+
+    #[test]
+    fn ratio_needs_enough_quotes() {
+        let config = config(ContextPolicy::Skip);
+        assert_eq!(flagged_quote_ratio(&config, 9, 0), None);
+        assert_eq!(flagged_quote_ratio(&config, 10, 40), None);
+        assert_eq!(flagged_quote_ratio(&config, 30, 20), Some(1.5));
+    }
+
+    #[tokio::test]
+    async fn author_history_trips_the_check() {
+        let pool = init_test_db().await.expect("init db");
+        let guard = SafetyGuard::new(pool.clone());
+        let piled_on = DiscoveredTweet {
+            id: "old".to_string(),
+            author_id: "a1".to_string(),
+            author_username: "loud".to_string(),
+            content: "hot take".to_string(),
+            like_count: 10,
+            retweet_count: 0,
+            reply_count: 0,
+            quote_count: 50,
+            impression_count: None,
+            relevance_score: None,
+            matched_keyword: None,
+            discovered_at: Utc::now().format("%Y-%m-%dT%H:%M:%SZ").to_string(),
+            replied_to: 0,
+        };
+        insert_discovered_tweet(&pool, &piled_on).await.unwrap();
+
+        let off = guard
+            .check_context(&config(ContextPolicy::Off), "a1", 0, 100)
+            .await
+            .unwrap();
+        assert_eq!(off, ContextVerdict::Clear);
+
+        let verdict = guard
+            .check_context(&config(ContextPolicy::RequireApproval), "a1", 0, 100)
+            .await
+            .unwrap();
+        let ContextVerdict::RequireApproval(denial) = verdict else {
+            panic!("expected approval verdict, got {verdict:?}");
+        };
+        assert!(denial.to_string().contains("author quote/like ratio"));
+
+        let clean = guard
+            .check_context(&config(ContextPolicy::Skip), "a2", 0, 100)
+            .await
+            .unwrap();
+        assert_eq!(clean, ContextVerdict::Clear);
+
+        let tweet = guard
+            .check_context(&config(ContextPolicy::Skip), "a2", 40, 10)
+            .await
+            .unwrap();
+        assert!(matches!(tweet, ContextVerdict::Skip(_)));
+    }
+}
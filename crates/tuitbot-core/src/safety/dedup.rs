@@ -69,7 +69,7 @@ impl DedupChecker {
 }
 
 /// Tokenize text into a set of lowercase alphanumeric words.
-fn tokenize(text: &str) -> HashSet<String> {
+pub(crate) fn tokenize(text: &str) -> HashSet<String> {
     text.to_lowercase()
         .split_whitespace()
         .map(|w| w.trim_matches(|c: char| !c.is_alphanumeric()).to_string())
@@ -81,7 +81,7 @@ fn tokenize(text: &str) -> HashSet<String> {
 ///
 /// Returns a value between 0.0 (no overlap) and 1.0 (identical sets).
 /// Two empty sets are considered identical (returns 1.0).
-fn jaccard_similarity(a: &HashSet<String>, b: &HashSet<String>) -> f64 {
+pub(crate) fn jaccard_similarity(a: &HashSet<String>, b: &HashSet<String>) -> f64 {
     if a.is_empty() && b.is_empty() {
         return 1.0;
     }
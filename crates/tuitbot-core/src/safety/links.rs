@@ -0,0 +1,111 @@
+//! Link parsing shared by the QA link checks.
+
+use std::collections::HashSet;
+use std::sync::OnceLock;
+
+use regex::Regex;
+
+/// Lowercase and trim a domain for comparison.
+pub(crate) fn normalize_domain(domain: &str) -> String {
+    domain.trim().to_lowercase()
+}
+
+fn url_regex() -> &'static Regex {
+    static URL_RE: OnceLock<Regex> = OnceLock::new();
+    URL_RE.get_or_init(|| Regex::new(r"https?://[^\s<>()]+").expect("valid URL regex"))
+}
+
+/// Extract the `http(s)` links in `text`, without trailing punctuation.
+pub(crate) fn extract_urls(text: &str) -> Vec<String> {
+    url_regex()
+        .find_iter(text)
+        .map(|m| {
+            m.as_str()
+                .trim_end_matches(['.', ',', ';', '!', '?'])
+                .to_string()
+        })
+        .collect()
+}
+
+/// The lowercase host of `url`, without scheme, credentials, or port.
+pub(crate) fn extract_domain(url: &str) -> Option<String> {
+    let without_scheme = url
+        .strip_prefix("https://")
+        .or_else(|| url.strip_prefix("http://"))
+        .unwrap_or(url);
+
+    let host_port = without_scheme
+        .split('/')
+        .next()?
+        .split('?')
+        .next()?
+        .split('#')
+        .next()?
+        .trim();
+    if host_port.is_empty() {
+        return None;
+    }
+
+    let host = host_port
+        .split('@')
+        .next_back()
+        .unwrap_or(host_port)
+        .split(':')
+        .next()
+        .unwrap_or(host_port);
+    let normalized = normalize_domain(host);
+    if normalized.is_empty() {
+        None
+    } else {
+        Some(normalized)
+    }
+}
+
+/// The names of the query parameters in `url`.
+pub(crate) fn parse_query_keys(url: &str) -> HashSet<String> {
+    let Some(query) = url.split('?').nth(1) else {
+        return HashSet::new();
+    };
+    let query = query.split('#').next().unwrap_or(query);
+
+    query
+        .split('&')
+        .filter_map(|kv| {
+            let key = kv.split('=').next()?.trim();
+            if key.is_empty() {
+                None
+            } else {
+                Some(key.to_string())
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extracts_urls_without_trailing_punctuation() {
+        let urls = extract_urls("See https://example.com/a?b=1, and http://x.io.");
+        assert_eq!(urls, vec!["https://example.com/a?b=1", "http://x.io"]);
+    }
+
+    #[test]
+    fn domain_drops_credentials_and_port() {
+        assert_eq!(
+            extract_domain("https://user@Example.COM:8080/path?q=1").as_deref(),
+            Some("example.com")
+        );
+        assert_eq!(extract_domain("https://"), None);
+    }
+
+    #[test]
+    fn query_keys_ignore_fragment_and_empty_keys() {
+        let keys = parse_query_keys("https://x.io/?utm_source=a&=b&ref#frag=1");
+        assert_eq!(
+            keys,
+            HashSet::from(["utm_source".to_string(), "ref".to_string()])
+        );
+    }
+}
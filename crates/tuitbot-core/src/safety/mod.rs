@@ -4,14 +4,27 @@
 //! for all automation loops. Combines rate limiting with deduplication
 //! to prevent API abuse and duplicate content.
 
+pub mod context;
 pub mod dedup;
+#[cfg(any(test, feature = "fuzzing"))]
+pub(crate) mod links;
+pub mod overlap;
+pub mod protected;
+pub mod reciprocity;
 pub mod redact;
+pub mod trusted;
+
+use chrono::{DateTime, Utc};
 
 use crate::error::StorageError;
 use crate::storage::rate_limits;
-use crate::storage::{author_interactions, DbPool};
+use crate::storage::{author_interactions, blocklist, draft_only, kill_switch, DbPool};
 
+pub use context::ContextVerdict;
 pub use dedup::DedupChecker;
+pub use overlap::OverlapVerdict;
+pub use reciprocity::ReciprocityVerdict;
+pub use trusted::{TrustBypass, TrustedAuthors};
 
 /// Wraps rate limit database operations with a clean API.
 pub struct RateLimiter {
@@ -85,6 +98,8 @@ pub enum DenialReason {
         current: i64,
         /// Maximum allowed requests.
         max: i64,
+        /// When the action's window ends and the count resets.
+        resets_at: Option<DateTime<Utc>>,
     },
     /// Already replied to this tweet.
     AlreadyReplied {
@@ -102,6 +117,42 @@ pub enum DenialReason {
     AuthorLimitReached,
     /// Replying to own tweet.
     SelfReply,
+    /// The compliance kill switch is engaged.
+    KillSwitch {
+        /// Reason given when the switch was engaged.
+        reason: String,
+    },
+    /// Draft-only mode is on: content is queued, never posted.
+    DraftOnly,
+    /// The tweet or its author shows controversy signals.
+    ControversialContext {
+        /// The signal that tripped the check.
+        signal: String,
+    },
+    /// The reply repeats one of the author's own recent tweets.
+    AuthorOverlap {
+        /// Overlap (0.0-1.0) with the closest author tweet.
+        similarity: f64,
+    },
+    /// We keep replying to the author, who rarely engages back.
+    OneSidedEngagement {
+        /// Replies we sent the author in the window.
+        outbound: i64,
+        /// Mentions and replies the author sent us in the window.
+        inbound: i64,
+    },
+    /// The author is muted or blocked on X, or on the local blocklist.
+    BlockedAuthor {
+        /// The author's username.
+        username: String,
+        /// Blocklist source: `x_mute`, `x_block`, or `manual`.
+        source: String,
+    },
+    /// The author is a protected entity the agent never engages with.
+    ProtectedEntity {
+        /// The author's username.
+        username: String,
+    },
 }
 
 impl std::fmt::Display for DenialReason {
@@ -111,7 +162,14 @@ impl std::fmt::Display for DenialReason {
                 action_type,
                 current,
                 max,
-            } => write!(f, "Rate limited: {action_type} ({current}/{max})"),
+                resets_at,
+            } => {
+                write!(f, "Rate limited: {action_type} ({current}/{max})")?;
+                if let Some(resets_at) = resets_at {
+                    write!(f, ", resets {}", resets_at.format("%Y-%m-%d %H:%M UTC"))?;
+                }
+                Ok(())
+            }
             Self::AlreadyReplied { tweet_id } => {
                 write!(f, "Already replied to tweet {tweet_id}")
             }
@@ -127,6 +185,38 @@ impl std::fmt::Display for DenialReason {
             Self::SelfReply => {
                 write!(f, "Cannot reply to own tweets")
             }
+            Self::KillSwitch { reason } => {
+                write!(f, "Kill switch engaged: {reason}")
+            }
+            Self::DraftOnly => {
+                write!(f, "Draft-only mode is on")
+            }
+            Self::ControversialContext { signal } => {
+                write!(f, "Controversial context: {signal}")
+            }
+            Self::AuthorOverlap { similarity } => {
+                write!(
+                    f,
+                    "Reply repeats the author's recent tweet (overlap {similarity:.2})"
+                )
+            }
+            Self::OneSidedEngagement { outbound, inbound } => {
+                write!(
+                    f,
+                    "One-sided engagement: {outbound} replies sent, {inbound} received"
+                )
+            }
+            Self::BlockedAuthor { username, source } => {
+                let listed = match source.as_str() {
+                    blocklist::SOURCE_X_MUTE => "muted on X",
+                    blocklist::SOURCE_X_BLOCK => "blocked on X",
+                    _ => "on the local blocklist",
+                };
+                write!(f, "Author @{username} is {listed}")
+            }
+            Self::ProtectedEntity { username } => {
+                write!(f, "Author @{username} is a protected entity")
+            }
         }
     }
 }
@@ -157,6 +247,8 @@ pub struct SafetyGuard {
     rate_limiter: RateLimiter,
     dedup_checker: DedupChecker,
     pool: DbPool,
+    protected: Vec<String>,
+    trusted: TrustedAuthors,
 }
 
 impl SafetyGuard {
@@ -166,12 +258,27 @@ impl SafetyGuard {
             rate_limiter: RateLimiter::new(pool.clone()),
             dedup_checker: DedupChecker::new(pool.clone()),
             pool,
+            protected: Vec::new(),
+            trusted: TrustedAuthors::default(),
         }
     }
 
+    /// Never engage with the usernames in `protected`.
+    pub fn with_protected_entities(mut self, protected: &[String]) -> Self {
+        self.protected = protected::normalize_protected(protected);
+        self
+    }
+
+    /// Let replies to the `trusted` authors skip what each opted into.
+    pub fn with_trusted_authors(mut self, trusted: &[crate::config::TrustedAuthorConfig]) -> Self {
+        self.trusted = TrustedAuthors::from_config(trusted);
+        self
+    }
+
     /// Check whether replying to a tweet is permitted.
     ///
-    /// Checks rate limits, exact dedup, and optionally phrasing similarity.
+    /// Checks the kill switch, rate limits, exact dedup, and optionally
+    /// phrasing similarity.
     /// Returns `Ok(Ok(()))` if allowed, `Ok(Err(DenialReason))` if blocked,
     /// or `Err(StorageError)` on infrastructure failure.
     pub async fn can_reply_to(
@@ -179,26 +286,13 @@ impl SafetyGuard {
         tweet_id: &str,
         proposed_reply: Option<&str>,
     ) -> Result<Result<(), DenialReason>, StorageError> {
+        if let Some(denial) = self.check_kill_switch().await? {
+            return Ok(Err(denial));
+        }
+
         // Check rate limit
         if !self.rate_limiter.can_reply().await? {
-            let limits = rate_limits::get_all_rate_limits(&self.rate_limiter.pool).await?;
-            let reply_limit = limits.iter().find(|l| l.action_type == "reply");
-            let (current, max) = reply_limit
-                .map(|l| (l.request_count, l.max_requests))
-                .unwrap_or((0, 0));
-
-            tracing::debug!(
-                action = "reply",
-                current,
-                max,
-                "Action denied: rate limited"
-            );
-
-            return Ok(Err(DenialReason::RateLimited {
-                action_type: "reply".to_string(),
-                current,
-                max,
-            }));
+            return Ok(Err(self.rate_limited("reply").await?));
         }
 
         // Check exact dedup
@@ -226,27 +320,14 @@ impl SafetyGuard {
 
     /// Check whether posting an original tweet is permitted.
     ///
-    /// Only checks rate limits (no dedup for original tweets).
+    /// Checks the kill switch and rate limits (no dedup for original tweets).
     pub async fn can_post_tweet(&self) -> Result<Result<(), DenialReason>, StorageError> {
-        if !self.rate_limiter.can_tweet().await? {
-            let limits = rate_limits::get_all_rate_limits(&self.rate_limiter.pool).await?;
-            let tweet_limit = limits.iter().find(|l| l.action_type == "tweet");
-            let (current, max) = tweet_limit
-                .map(|l| (l.request_count, l.max_requests))
-                .unwrap_or((0, 0));
-
-            tracing::debug!(
-                action = "tweet",
-                current,
-                max,
-                "Action denied: rate limited"
-            );
+        if let Some(denial) = self.check_kill_switch().await? {
+            return Ok(Err(denial));
+        }
 
-            return Ok(Err(DenialReason::RateLimited {
-                action_type: "tweet".to_string(),
-                current,
-                max,
-            }));
+        if !self.rate_limiter.can_tweet().await? {
+            return Ok(Err(self.rate_limited("tweet").await?));
         }
 
         Ok(Ok(()))
@@ -254,32 +335,63 @@ impl SafetyGuard {
 
     /// Check whether posting a thread is permitted.
     ///
-    /// Only checks rate limits (no dedup for threads).
+    /// Checks the kill switch and rate limits (no dedup for threads).
     pub async fn can_post_thread(&self) -> Result<Result<(), DenialReason>, StorageError> {
-        if !self.rate_limiter.can_thread().await? {
-            let limits = rate_limits::get_all_rate_limits(&self.rate_limiter.pool).await?;
-            let thread_limit = limits.iter().find(|l| l.action_type == "thread");
-            let (current, max) = thread_limit
-                .map(|l| (l.request_count, l.max_requests))
-                .unwrap_or((0, 0));
+        if let Some(denial) = self.check_kill_switch().await? {
+            return Ok(Err(denial));
+        }
 
-            tracing::debug!(
-                action = "thread",
-                current,
-                max,
-                "Action denied: rate limited"
-            );
+        // Threads post straight to X rather than through the approval queue,
+        // so draft-only mode skips them instead of holding them.
+        if draft_only::is_draft_only_active(&self.pool).await? {
+            tracing::debug!("Thread denied: draft-only mode is on");
+            return Ok(Err(DenialReason::DraftOnly));
+        }
 
-            return Ok(Err(DenialReason::RateLimited {
-                action_type: "thread".to_string(),
-                current,
-                max,
-            }));
+        if !self.rate_limiter.can_thread().await? {
+            return Ok(Err(self.rate_limited("thread").await?));
         }
 
         Ok(Ok(()))
     }
 
+    /// Denial for an action type that has used up its window.
+    async fn rate_limited(&self, action_type: &str) -> Result<DenialReason, StorageError> {
+        let limits = rate_limits::get_all_rate_limits(&self.rate_limiter.pool).await?;
+        let limit = limits.iter().find(|l| l.action_type == action_type);
+        let (current, max) = limit
+            .map(|l| (l.request_count, l.max_requests))
+            .unwrap_or((0, 0));
+        let resets_at = limit.map(|l| l.resets_at());
+
+        tracing::debug!(
+            action = action_type,
+            current,
+            max,
+            resets_at = ?resets_at,
+            "Action denied: rate limited"
+        );
+
+        Ok(DenialReason::RateLimited {
+            action_type: action_type.to_string(),
+            current,
+            max,
+            resets_at,
+        })
+    }
+
+    /// Deny every action while the compliance kill switch is engaged.
+    async fn check_kill_switch(&self) -> Result<Option<DenialReason>, StorageError> {
+        let state = kill_switch::get_kill_switch(&self.pool).await?;
+        if !state.active {
+            return Ok(None);
+        }
+        tracing::debug!("Action denied: kill switch engaged");
+        Ok(Some(DenialReason::KillSwitch {
+            reason: state.reason.unwrap_or_default(),
+        }))
+    }
+
     /// Check if replying to this author is permitted (per-author daily limit).
     pub async fn check_author_limit(
         &self,
@@ -300,6 +412,44 @@ impl SafetyGuard {
         Ok(Ok(()))
     }
 
+    /// Check if the author is muted or blocked on X, or on the local
+    /// blocklist.
+    pub async fn check_blocked_author(
+        &self,
+        author_id: &str,
+        author_username: &str,
+    ) -> Result<Result<(), DenialReason>, StorageError> {
+        let Some(entry) = blocklist::find(&self.pool, author_id, author_username).await? else {
+            return Ok(Ok(()));
+        };
+        tracing::debug!(
+            author_id,
+            author = %entry.author_username,
+            source = %entry.source,
+            "Action denied: author on blocklist"
+        );
+        Ok(Err(DenialReason::BlockedAuthor {
+            username: entry.author_username,
+            source: entry.source,
+        }))
+    }
+
+    /// Check if the author is a protected entity.
+    pub fn check_protected_author(&self, author_username: &str) -> Result<(), DenialReason> {
+        if protected::is_protected(author_username, &self.protected) {
+            tracing::debug!(author = author_username, "Action denied: protected entity");
+            return Err(DenialReason::ProtectedEntity {
+                username: protected::normalize_username(author_username),
+            });
+        }
+        Ok(())
+    }
+
+    /// What replies to the author may skip.
+    pub fn trusted_bypass(&self, author_username: &str) -> TrustBypass {
+        self.trusted.bypass_for(author_username)
+    }
+
     /// Check if a generated reply contains a banned phrase.
     pub fn check_banned_phrases(reply_text: &str, banned: &[String]) -> Result<(), DenialReason> {
         if let Some(phrase) = contains_banned_phrase(reply_text, banned) {
@@ -357,11 +507,15 @@ mod tests {
             max_replies_per_day: 3,
             max_tweets_per_day: 2,
             max_threads_per_week: 1,
+            max_likes_per_day: 50,
+            max_follows_per_day: 20,
             min_action_delay_seconds: 30,
             max_action_delay_seconds: 120,
             max_replies_per_author_per_day: 1,
             banned_phrases: vec!["check out".to_string(), "you should try".to_string()],
             product_mention_ratio: 0.2,
+            reset_timezone: String::new(),
+            windows: Default::default(),
         }
     }
 
@@ -371,6 +525,7 @@ mod tests {
             discovery_search_seconds: 600,
             content_post_window_seconds: 14400,
             thread_interval_seconds: 604800,
+            discovery_dedup_window_seconds: 86400,
         }
     }
 
@@ -466,10 +621,14 @@ mod tests {
                 action_type,
                 current,
                 max,
+                resets_at,
             }) => {
                 assert_eq!(action_type, "reply");
                 assert_eq!(current, 3);
                 assert_eq!(max, 3);
+                let resets_at = resets_at.expect("reset time");
+                assert!(resets_at > Utc::now());
+                assert!(resets_at <= Utc::now() + chrono::Duration::days(1));
             }
             other => panic!("expected RateLimited, got: {other:?}"),
         }
@@ -562,9 +721,21 @@ mod tests {
             action_type: "reply".to_string(),
             current: 20,
             max: 20,
+            resets_at: None,
         };
         assert_eq!(rate.to_string(), "Rate limited: reply (20/20)");
 
+        let weekly = DenialReason::RateLimited {
+            action_type: "thread".to_string(),
+            current: 1,
+            max: 1,
+            resets_at: Some("2026-03-16T04:00:00Z".parse().expect("timestamp")),
+        };
+        assert_eq!(
+            weekly.to_string(),
+            "Rate limited: thread (1/1), resets 2026-03-16 04:00 UTC"
+        );
+
         let replied = DenialReason::AlreadyReplied {
             tweet_id: "abc123".to_string(),
         };
@@ -638,6 +809,115 @@ mod tests {
         assert!(!is_self_reply("", ""));
     }
 
+    #[tokio::test]
+    async fn safety_guard_flags_one_sided_author() {
+        use crate::config::{ReciprocityConfig, ReciprocityPolicy};
+        use crate::storage::tweets::{insert_discovered_tweet, DiscoveredTweet};
+
+        let (pool, guard) = setup_guard().await;
+        let mut config = ReciprocityConfig {
+            policy: ReciprocityPolicy::Skip,
+            min_outbound: 2,
+            ..Default::default()
+        };
+        for id in ["t1", "t2"] {
+            insert_discovered_tweet(
+                &pool,
+                &DiscoveredTweet {
+                    id: id.to_string(),
+                    author_id: "author_1".to_string(),
+                    author_username: "alice".to_string(),
+                    content: String::new(),
+                    like_count: 0,
+                    retweet_count: 0,
+                    reply_count: 0,
+                    quote_count: 0,
+                    impression_count: None,
+                    relevance_score: None,
+                    matched_keyword: None,
+                    discovered_at: chrono::Utc::now().to_rfc3339(),
+                    replied_to: 1,
+                },
+            )
+            .await
+            .expect("tweet");
+            insert_reply(&pool, &sample_reply(id, "Great point"))
+                .await
+                .expect("reply");
+        }
+        // The range ends at the current second, exclusive.
+        sqlx::query("UPDATE replies_sent SET created_at = datetime('now', '-1 minute')")
+            .execute(&pool)
+            .await
+            .expect("backdate");
+
+        let verdict = guard
+            .check_reciprocity(&config, "author_1")
+            .await
+            .expect("check");
+        assert_eq!(
+            verdict,
+            ReciprocityVerdict::Skip(DenialReason::OneSidedEngagement {
+                outbound: 2,
+                inbound: 0
+            })
+        );
+
+        config.policy = ReciprocityPolicy::Deprioritize;
+        assert!(matches!(
+            guard.check_reciprocity(&config, "author_1").await.expect("check"),
+            ReciprocityVerdict::Deprioritize { penalty, .. } if penalty == 20.0
+        ));
+        config.min_outbound = 3;
+        assert_eq!(
+            guard
+                .check_reciprocity(&config, "author_1")
+                .await
+                .expect("check"),
+            ReciprocityVerdict::Clear
+        );
+    }
+
+    #[tokio::test]
+    async fn safety_guard_denies_blocklisted_author() {
+        let (pool, guard) = setup_guard().await;
+        assert!(guard
+            .check_blocked_author("u1", "alice")
+            .await
+            .expect("check")
+            .is_ok());
+
+        blocklist::replace_source(
+            &pool,
+            blocklist::SOURCE_X_BLOCK,
+            &[(Some("u1".to_string()), "alice".to_string())],
+        )
+        .await
+        .expect("sync");
+        let denial = guard
+            .check_blocked_author("u1", "alice_renamed")
+            .await
+            .expect("check")
+            .unwrap_err();
+        assert_eq!(denial.to_string(), "Author @alice is blocked on X");
+    }
+
+    #[tokio::test]
+    async fn safety_guard_denies_protected_entities() {
+        let (pool, _guard) = setup_guard().await;
+        let guard = SafetyGuard::new(pool).with_protected_entities(&["@Reporter".to_string()]);
+
+        let denial = guard.check_protected_author("REPORTER").unwrap_err();
+        assert_eq!(
+            denial,
+            DenialReason::ProtectedEntity {
+                username: "reporter".to_string()
+            }
+        );
+        assert_eq!(denial.to_string(), "Author @reporter is a protected entity");
+        assert!(guard.check_protected_author("someone_else").is_ok());
+    }
+
     #[tokio::test]
     async fn safety_guard_check_author_limit_allows_first() {
         let (_pool, guard) = setup_guard().await;
@@ -695,4 +975,39 @@ mod tests {
             .expect("phrases");
         assert!(phrases.is_empty());
     }
+
+    #[tokio::test]
+    async fn kill_switch_denies_all_actions() {
+        let (pool, guard) = setup_guard().await;
+        kill_switch::engage_kill_switch(&pool, "compliance review", "test")
+            .await
+            .expect("engage");
+
+        let expected = Err(DenialReason::KillSwitch {
+            reason: "compliance review".to_string(),
+        });
+        assert_eq!(guard.can_reply_to("t1", None).await.unwrap(), expected);
+        assert_eq!(guard.can_post_tweet().await.unwrap(), expected);
+        assert_eq!(guard.can_post_thread().await.unwrap(), expected);
+
+        kill_switch::release_kill_switch(&pool, "test")
+            .await
+            .expect("release");
+        assert!(guard.can_post_tweet().await.unwrap().is_ok());
+    }
+
+    #[tokio::test]
+    async fn draft_only_skips_threads_but_not_replies() {
+        let (pool, guard) = setup_guard().await;
+        draft_only::enable_draft_only(&pool, None, "test")
+            .await
+            .expect("enable");
+
+        assert_eq!(
+            guard.can_post_thread().await.unwrap(),
+            Err(DenialReason::DraftOnly)
+        );
+        assert!(guard.can_reply_to("t1", None).await.unwrap().is_ok());
+        assert!(guard.can_post_tweet().await.unwrap().is_ok());
+    }
 }
@@ -0,0 +1,193 @@
+//! Overlap check between a drafted reply and the target author's own tweets.
+//!
+//! A reply that restates what the author already said in a follow-up tweet
+//! adds nothing to the conversation. Overlap is the share of the reply's
+//! content words found in a single recent author tweet; the tweet being
+//! replied to is excluded, since a reply naturally echoes it.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use super::dedup::tokenize;
+use super::DenialReason;
+use crate::config::AuthorOverlapConfig;
+use crate::workflow::draft_workspace::DraftFlag;
+
+/// An author's recent tweets as `(tweet_id, text)` pairs.
+pub type AuthorTweets = Vec<(String, String)>;
+
+/// Replies with fewer content words than this are never flagged.
+const MIN_CONTENT_WORDS: usize = 5;
+
+/// Outcome of the author overlap check.
+#[derive(Debug, Clone, PartialEq)]
+pub enum OverlapVerdict {
+    /// No meaningful overlap, or the check is off.
+    Clear,
+    /// High overlap; the reply needs review.
+    Soft(DraftFlag),
+    /// The reply repeats the author; do not send it.
+    Hard(DenialReason),
+}
+
+/// Words long enough to carry meaning, so filler like "the" or "you"
+/// doesn't count as overlap.
+fn content_words(text: &str) -> HashSet<String> {
+    tokenize(text)
+        .into_iter()
+        .filter(|w| w.chars().count() > 3 && !w.starts_with("http"))
+        .collect()
+}
+
+/// Share (0.0-1.0) of the reply's content words found in `tweet`.
+pub fn overlap(reply: &str, tweet: &str) -> f64 {
+    let reply_words = content_words(reply);
+    if reply_words.len() < MIN_CONTENT_WORDS {
+        return 0.0;
+    }
+    let tweet_words = content_words(tweet);
+    reply_words.intersection(&tweet_words).count() as f64 / reply_words.len() as f64
+}
+
+/// Compare `reply` with the author's recent `(tweet_id, text)` pairs,
+/// ignoring the tweet being replied to.
+pub fn check_author_overlap(
+    config: &AuthorOverlapConfig,
+    reply: &str,
+    replied_tweet_id: &str,
+    author_tweets: &[(String, String)],
+) -> OverlapVerdict {
+    if !config.enabled {
+        return OverlapVerdict::Clear;
+    }
+    let highest = author_tweets
+        .iter()
+        .filter(|(id, _)| id != replied_tweet_id)
+        .map(|(_, text)| overlap(reply, text))
+        .fold(0.0, f64::max);
+
+    if highest >= config.hard_threshold {
+        OverlapVerdict::Hard(DenialReason::AuthorOverlap {
+            similarity: highest,
+        })
+    } else if highest >= config.soft_threshold {
+        OverlapVerdict::Soft(DraftFlag {
+            code: "author_overlap".to_string(),
+            category: "brand".to_string(),
+            severity: "soft".to_string(),
+            message: format!(
+                "Reply overlaps {:.0}% with one of the author's recent tweets.",
+                highest * 100.0
+            ),
+            tweet_index: None,
+        })
+    } else {
+        OverlapVerdict::Clear
+    }
+}
+
+/// Recent tweets per author, kept for a fixed time so repeated replies to
+/// the same author cost one timeline fetch.
+pub struct AuthorTweetCache {
+    ttl: Duration,
+    entries: Mutex<HashMap<String, (Instant, AuthorTweets)>>,
+}
+
+impl AuthorTweetCache {
+    /// Create a cache whose entries expire after `ttl`.
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Cached `(tweet_id, text)` pairs for `author_id`, if still fresh.
+    pub fn get(&self, author_id: &str) -> Option<AuthorTweets> {
+        let entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        entries
+            .get(author_id)
+            .filter(|(fetched, _)| fetched.elapsed() < self.ttl)
+            .map(|(_, tweets)| tweets.clone())
+    }
+
+    /// Store `tweets` for `author_id`, dropping expired entries.
+    pub fn insert(&self, author_id: &str, tweets: AuthorTweets) {
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        entries.retain(|_, (fetched, _)| fetched.elapsed() < self.ttl);
+        entries.insert(author_id.to_string(), (Instant::now(), tweets));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn enabled() -> AuthorOverlapConfig {
+        AuthorOverlapConfig {
+            enabled: true,
+            ..AuthorOverlapConfig::default()
+        }
+    }
+
+    fn timeline() -> AuthorTweets {
+        vec![
+            (
+                "t1".to_string(),
+                "Why does my build take ten minutes?".to_string(),
+            ),
+            (
+                "t2".to_string(),
+                "Update: caching the dependency downloads fixed the slow build completely"
+                    .to_string(),
+            ),
+        ]
+    }
+
+    #[test]
+    fn repeated_point_is_skipped_or_flagged() {
+        let config = enabled();
+        let echo = "Caching the dependency downloads fixes the slow build";
+        let verdict = check_author_overlap(&config, echo, "t1", &timeline());
+        assert!(matches!(verdict, OverlapVerdict::Hard(_)), "{verdict:?}");
+
+        let partial = "Caching the dependency downloads should help that slow build";
+        let verdict = check_author_overlap(&config, partial, "t1", &timeline());
+        let OverlapVerdict::Soft(flag) = verdict else {
+            panic!("expected soft flag, got {verdict:?}");
+        };
+        assert_eq!(flag.code, "author_overlap");
+
+        let fresh = "Have you tried profiling which crate dominates compile times?";
+        assert_eq!(
+            check_author_overlap(&config, fresh, "t1", &timeline()),
+            OverlapVerdict::Clear
+        );
+    }
+
+    #[test]
+    fn replied_tweet_and_disabled_config_are_ignored() {
+        let echo = "Caching the dependency downloads fixes the slow build";
+        assert_eq!(
+            check_author_overlap(&enabled(), echo, "t2", &timeline()),
+            OverlapVerdict::Clear
+        );
+        assert_eq!(
+            check_author_overlap(&AuthorOverlapConfig::default(), echo, "t1", &timeline()),
+            OverlapVerdict::Clear
+        );
+    }
+
+    #[test]
+    fn cache_entries_expire() {
+        let cache = AuthorTweetCache::new(Duration::from_secs(60));
+        assert!(cache.get("a1").is_none());
+        cache.insert("a1", timeline());
+        assert_eq!(cache.get("a1").unwrap().len(), 2);
+
+        let expired = AuthorTweetCache::new(Duration::ZERO);
+        expired.insert("a1", timeline());
+        assert!(expired.get("a1").is_none());
+    }
+}
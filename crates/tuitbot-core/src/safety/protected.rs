@@ -0,0 +1,77 @@
+//! Protected entities: accounts the agent never engages with.
+//!
+//! Journalists, regulators, or competitors' executives listed in
+//! `blocklist.protected` are never replied to or quoted, and generated
+//! content must not @-mention them. Matching is by username, ignoring case
+//! and a leading `@`.
+
+/// Normalize a username for comparison: trimmed, no `@`, lowercase.
+pub fn normalize_username(username: &str) -> String {
+    username.trim().trim_start_matches('@').to_lowercase()
+}
+
+/// Normalize a configured protected list, dropping empty entries.
+pub fn normalize_protected(protected: &[String]) -> Vec<String> {
+    protected
+        .iter()
+        .map(|name| normalize_username(name))
+        .filter(|name| !name.is_empty())
+        .collect()
+}
+
+/// Check whether `username` is on the (normalized) protected list.
+pub fn is_protected(username: &str, protected: &[String]) -> bool {
+    let name = normalize_username(username);
+    !name.is_empty() && protected.contains(&name)
+}
+
+/// Protected usernames @-mentioned in `text`, in order of appearance.
+///
+/// `protected` must already be normalized. An `@` preceded by a word
+/// character (as in an email address) is not a mention.
+pub fn protected_mentions(text: &str, protected: &[String]) -> Vec<String> {
+    let is_handle_char = |c: char| c.is_ascii_alphanumeric() || c == '_';
+    let mut found = Vec::new();
+    let mut prev: Option<char> = None;
+    for (i, c) in text.char_indices() {
+        if c == '@' && !prev.is_some_and(is_handle_char) {
+            let rest = &text[i + 1..];
+            let end = rest
+                .find(|c: char| !is_handle_char(c))
+                .unwrap_or(rest.len());
+            let name = rest[..end].to_lowercase();
+            if !name.is_empty() && protected.contains(&name) && !found.contains(&name) {
+                found.push(name);
+            }
+        }
+        prev = Some(c);
+    }
+    found
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn protected() -> Vec<String> {
+        normalize_protected(&["@Reporter".to_string(), " regulator_1 ".to_string()])
+    }
+
+    #[test]
+    fn matches_usernames_ignoring_case_and_at() {
+        assert!(is_protected("reporter", &protected()));
+        assert!(is_protected("@REGULATOR_1", &protected()));
+        assert!(!is_protected("reporter2", &protected()));
+        assert!(!is_protected("", &protected()));
+    }
+
+    #[test]
+    fn finds_mentions_but_not_emails_or_prefixes() {
+        let text = "cc @Reporter and @reporter_fan, mail tips@reporter, (@regulator_1)";
+        assert_eq!(
+            protected_mentions(text, &protected()),
+            vec!["reporter".to_string(), "regulator_1".to_string()]
+        );
+        assert!(protected_mentions("no mentions here", &protected()).is_empty());
+    }
+}
@@ -5,11 +5,11 @@
 //! so behavior is predictable and testable.
 
 use std::collections::{HashMap, HashSet};
-use std::sync::OnceLock;
 
-use regex::Regex;
 use serde::{Deserialize, Serialize};
 
+use super::links::{extract_domain, extract_urls, normalize_domain, parse_query_keys};
+use super::protected::{normalize_protected, protected_mentions};
 use crate::config::{Config, EmojiPolicy, LanguagePolicyMode};
 
 /// Severity used for QA flags.
@@ -172,6 +172,7 @@ impl<'a> QaEvaluator<'a> {
         );
         self.evaluate_forbidden_terms(generated_text, &mut hard_flags);
         self.evaluate_claims(generated_text, &mut hard_flags);
+        self.evaluate_protected_mentions(generated_text, &mut hard_flags);
         self.evaluate_links(generated_text, &mut hard_flags, &mut soft_flags);
         self.evaluate_length_and_emoji(generated_text, &mut soft_flags);
         self.evaluate_similarity(generated_text, recent_outputs, &mut soft_flags);
@@ -403,6 +404,20 @@ impl<'a> QaEvaluator<'a> {
         }
     }
 
+    fn evaluate_protected_mentions(&self, generated_text: &str, hard_flags: &mut Vec<QaFlag>) {
+        let protected = normalize_protected(&self.config.blocklist.protected);
+        for username in protected_mentions(generated_text, &protected) {
+            hard_flags.push(QaFlag {
+                code: "protected_mention".to_string(),
+                severity: QaSeverity::Hard,
+                category: QaCategory::Compliance,
+                message: "Content @-mentions a protected entity".to_string(),
+                evidence: Some(format!("@{username}")),
+                suggestion: Some("Remove the mention; protected accounts are never engaged".to_string()),
+            });
+        }
+    }
+
     fn evaluate_claims(&self, generated_text: &str, hard_flags: &mut Vec<QaFlag>) {
         let generated_lower = generated_text.to_lowercase();
         for claim in &self.config.brand_voice_profile.disallowed_claims {
@@ -588,10 +603,6 @@ fn normalize_language_code(code: &str) -> String {
     code.trim().to_lowercase()
 }
 
-fn normalize_domain(domain: &str) -> String {
-    domain.trim().to_lowercase()
-}
-
 fn detect_language(text: &str) -> Option<LanguageDetection> {
     let cleaned = text.trim();
     if cleaned.is_empty() {
@@ -655,74 +666,6 @@ fn detect_language(text: &str) -> Option<LanguageDetection> {
     })
 }
 
-fn url_regex() -> &'static Regex {
-    static URL_RE: OnceLock<Regex> = OnceLock::new();
-    URL_RE.get_or_init(|| Regex::new(r"https?://[^\s<>()]+").expect("valid URL regex"))
-}
-
-fn extract_urls(text: &str) -> Vec<String> {
-    url_regex()
-        .find_iter(text)
-        .map(|m| {
-            m.as_str()
-                .trim_end_matches(|c: char| matches!(c, '.' | ',' | ';' | '!' | '?'))
-                .to_string()
-        })
-        .collect()
-}
-
-fn extract_domain(url: &str) -> Option<String> {
-    let without_scheme = url
-        .strip_prefix("https://")
-        .or_else(|| url.strip_prefix("http://"))
-        .unwrap_or(url);
-
-    let host_port = without_scheme
-        .split('/')
-        .next()?
-        .split('?')
-        .next()?
-        .split('#')
-        .next()?
-        .trim();
-    if host_port.is_empty() {
-        return None;
-    }
-
-    let host = host_port
-        .split('@')
-        .next_back()
-        .unwrap_or(host_port)
-        .split(':')
-        .next()
-        .unwrap_or(host_port);
-    let normalized = normalize_domain(host);
-    if normalized.is_empty() {
-        None
-    } else {
-        Some(normalized)
-    }
-}
-
-fn parse_query_keys(url: &str) -> HashSet<String> {
-    let Some(query) = url.split('?').nth(1) else {
-        return HashSet::new();
-    };
-    let query = query.split('#').next().unwrap_or(query);
-
-    query
-        .split('&')
-        .filter_map(|kv| {
-            let key = kv.split('=').next()?.trim();
-            if key.is_empty() {
-                None
-            } else {
-                Some(key.to_string())
-            }
-        })
-        .collect()
-}
-
 fn count_emoji(text: &str) -> usize {
     text.chars().filter(|ch| is_emoji(*ch)).count()
 }
@@ -910,6 +853,27 @@ mod tests {
             .any(|flag| flag.code == "missing_required_utm"));
     }
 
+    #[test]
+    fn protected_mention_is_hard_flag() {
+        let mut config = base_config();
+        config.blocklist.protected = vec!["@Reporter".to_string()];
+
+        let qa = QaEvaluator::new(&config);
+        let report = qa.evaluate(
+            "Who covered this?",
+            "Great point, @reporter wrote about it last week.",
+            &[],
+        );
+
+        let flag = report
+            .hard_flags
+            .iter()
+            .find(|flag| flag.code == "protected_mention")
+            .expect("protected mention flagged");
+        assert_eq!(flag.evidence.as_deref(), Some("@reporter"));
+        assert!(report.requires_override);
+    }
+
     #[test]
     fn hard_and_soft_flags_are_classified() {
         let mut config = base_config();
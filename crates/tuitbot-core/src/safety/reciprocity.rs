@@ -0,0 +1,63 @@
+//! Engagement reciprocity check for reply targets.
+//!
+//! Authors we keep replying to who never mention or reply to us back are
+//! de-prioritized or skipped under the configured [`ReciprocityPolicy`].
+
+use super::{DenialReason, SafetyGuard};
+use crate::config::{ReciprocityConfig, ReciprocityPolicy};
+use crate::error::StorageError;
+use crate::storage::analytics_range::AnalyticsRange;
+use crate::storage::reciprocity;
+
+/// Outcome of the reciprocity check.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ReciprocityVerdict {
+    /// Reciprocal enough, too little history, or the check is off.
+    Clear,
+    /// Lower the tweet's score by `penalty` points.
+    Deprioritize { penalty: f32, reason: DenialReason },
+    /// Do not reply.
+    Skip(DenialReason),
+}
+
+impl SafetyGuard {
+    /// Check whether replies to `author_id` have been one-sided under `config`.
+    pub async fn check_reciprocity(
+        &self,
+        config: &ReciprocityConfig,
+        author_id: &str,
+    ) -> Result<ReciprocityVerdict, StorageError> {
+        if config.policy == ReciprocityPolicy::Off || author_id.is_empty() {
+            return Ok(ReciprocityVerdict::Clear);
+        }
+
+        let range = AnalyticsRange::last_days(config.window_days);
+        let Some(stats) =
+            reciprocity::get_author_reciprocity(&self.pool, author_id, &range).await?
+        else {
+            return Ok(ReciprocityVerdict::Clear);
+        };
+        if stats.outbound < i64::from(config.min_outbound) || stats.ratio >= config.min_ratio {
+            return Ok(ReciprocityVerdict::Clear);
+        }
+
+        tracing::debug!(
+            author_id,
+            outbound = stats.outbound,
+            inbound = stats.inbound,
+            "One-sided engagement with reply target"
+        );
+        let reason = DenialReason::OneSidedEngagement {
+            outbound: stats.outbound,
+            inbound: stats.inbound,
+        };
+        Ok(match config.policy {
+            ReciprocityPolicy::Off => ReciprocityVerdict::Clear,
+            ReciprocityPolicy::Deprioritize => ReciprocityVerdict::Deprioritize {
+                penalty: config.score_penalty,
+                reason,
+            },
+            ReciprocityPolicy::Skip => ReciprocityVerdict::Skip(reason),
+        })
+    }
+}
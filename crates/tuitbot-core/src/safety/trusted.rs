@@ -0,0 +1,145 @@
+//! Trusted authors: accounts whose replies may skip review.
+//!
+//! Accounts listed in `[[trusted_authors]]` (your own alt, teammates) opt
+//! into bypasses one by one: `skip_approval` posts replies to them even in
+//! approval mode, and `skip_soft_qa` keeps soft QA flags from holding those
+//! replies for review. Hard QA flags, rate limits, and the kill switch
+//! still apply. Every bypass is written to the action log as a
+//! `trusted_author_bypass` entry naming the author and what was skipped.
+
+use super::protected::normalize_username;
+use crate::config::TrustedAuthorConfig;
+use crate::error::StorageError;
+use crate::storage::{action_log, DbPool};
+
+/// Action log type for bypass audit entries.
+pub const TRUSTED_BYPASS_ACTION: &str = "trusted_author_bypass";
+
+/// What replies to an author may skip.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TrustBypass {
+    /// Post directly even in approval mode.
+    pub skip_approval: bool,
+    /// Ignore soft QA flags.
+    pub skip_soft_qa: bool,
+}
+
+/// Configured trusted authors, matched by username ignoring case and `@`.
+#[derive(Debug, Clone, Default)]
+pub struct TrustedAuthors {
+    entries: Vec<(String, TrustBypass)>,
+}
+
+impl TrustedAuthors {
+    /// Build from `[[trusted_authors]]`, dropping empty usernames.
+    pub fn from_config(authors: &[TrustedAuthorConfig]) -> Self {
+        let entries = authors
+            .iter()
+            .map(|author| {
+                (
+                    normalize_username(&author.username),
+                    TrustBypass {
+                        skip_approval: author.skip_approval,
+                        skip_soft_qa: author.skip_soft_qa,
+                    },
+                )
+            })
+            .filter(|(name, _)| !name.is_empty())
+            .collect();
+        Self { entries }
+    }
+
+    /// Bypasses granted to `username`; none if it is not trusted.
+    pub fn bypass_for(&self, username: &str) -> TrustBypass {
+        let name = normalize_username(username);
+        self.entries
+            .iter()
+            .find(|(trusted, _)| *trusted == name)
+            .map(|(_, bypass)| *bypass)
+            .unwrap_or_default()
+    }
+}
+
+/// Record that a reply to `username` skipped `skipped` (`approval` or
+/// `soft_qa`). `detail` says which item or flags were involved.
+pub async fn log_bypass(
+    pool: &DbPool,
+    username: &str,
+    skipped: &str,
+    detail: &str,
+) -> Result<(), StorageError> {
+    let message = bypass_message(username, skipped, detail);
+    action_log::log_action(pool, TRUSTED_BYPASS_ACTION, skipped, Some(&message), None).await
+}
+
+/// Audit message for a bypass, shared by callers that log through their
+/// own storage port.
+pub fn bypass_message(username: &str, skipped: &str, detail: &str) -> String {
+    let what = match skipped {
+        "approval" => "skipped approval",
+        "soft_qa" => "skipped soft QA",
+        other => other,
+    };
+    format!(
+        "Reply to trusted @{} {what}: {detail}",
+        normalize_username(username)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::init_test_db;
+
+    fn trusted() -> TrustedAuthors {
+        TrustedAuthors::from_config(&[
+            TrustedAuthorConfig {
+                username: "@MyAlt".to_string(),
+                skip_approval: true,
+                skip_soft_qa: true,
+            },
+            TrustedAuthorConfig {
+                username: "teammate".to_string(),
+                skip_approval: false,
+                skip_soft_qa: true,
+            },
+        ])
+    }
+
+    #[test]
+    fn bypasses_are_per_author() {
+        let trusted = trusted();
+        assert_eq!(
+            trusted.bypass_for("myalt"),
+            TrustBypass {
+                skip_approval: true,
+                skip_soft_qa: true
+            }
+        );
+        let teammate = trusted.bypass_for("@Teammate");
+        assert!(!teammate.skip_approval);
+        assert!(teammate.skip_soft_qa);
+        assert_eq!(trusted.bypass_for("stranger"), TrustBypass::default());
+    }
+
+    #[tokio::test]
+    async fn bypass_is_logged() {
+        let pool = init_test_db().await.expect("init db");
+        log_bypass(&pool, "@MyAlt", "approval", "tweet 42")
+            .await
+            .expect("log");
+        let log = action_log::get_actions_since(
+            &pool,
+            "1970-01-01T00:00:00Z",
+            Some(TRUSTED_BYPASS_ACTION),
+        )
+        .await
+        .expect("log");
+        assert_eq!(log.len(), 1);
+        assert_eq!(log[0].status, "approval");
+        assert_eq!(
+            log[0].message.as_deref(),
+            Some("Reply to trusted @myalt skipped approval: tweet 42")
+        );
+    }
+}
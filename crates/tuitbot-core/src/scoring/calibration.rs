@@ -0,0 +1,67 @@
+//! Daily score-threshold calibration.
+//!
+//! Moves the effective threshold one step toward a target reply volume:
+//! down after a quiet day, up after a busy one. Days within
+//! [`TOLERANCE`] of the target leave the threshold unchanged, so normal
+//! day-to-day noise does not cause oscillation.
+
+use crate::config::ThresholdCalibrationConfig;
+
+/// Fraction of the target reply volume treated as "on target".
+pub const TOLERANCE: f64 = 0.2;
+
+/// Compute the next effective threshold from yesterday's reply count.
+///
+/// The result is always clamped to `floor..=ceiling`, even when no step is
+/// taken, so tightening the bounds takes effect on the next calibration.
+pub fn next_threshold(current: u32, replies: u32, config: &ThresholdCalibrationConfig) -> u32 {
+    let target = config.target_replies_per_day as f64;
+    let replies = replies as f64;
+    let next = if replies < target * (1.0 - TOLERANCE) {
+        current.saturating_sub(config.step)
+    } else if replies > target * (1.0 + TOLERANCE) {
+        current.saturating_add(config.step)
+    } else {
+        current
+    };
+    next.clamp(config.floor, config.ceiling.max(config.floor))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> ThresholdCalibrationConfig {
+        ThresholdCalibrationConfig {
+            enabled: true,
+            target_replies_per_day: 10,
+            floor: 50,
+            ceiling: 80,
+            step: 5,
+        }
+    }
+
+    #[test]
+    fn quiet_day_lowers_threshold() {
+        assert_eq!(next_threshold(70, 0, &config()), 65);
+        assert_eq!(next_threshold(70, 7, &config()), 65);
+    }
+
+    #[test]
+    fn busy_day_raises_threshold() {
+        assert_eq!(next_threshold(70, 13, &config()), 75);
+    }
+
+    #[test]
+    fn near_target_keeps_threshold() {
+        assert_eq!(next_threshold(70, 8, &config()), 70);
+        assert_eq!(next_threshold(70, 12, &config()), 70);
+    }
+
+    #[test]
+    fn stays_within_floor_and_ceiling() {
+        assert_eq!(next_threshold(52, 0, &config()), 50);
+        assert_eq!(next_threshold(78, 50, &config()), 80);
+        assert_eq!(next_threshold(95, 10, &config()), 80);
+    }
+}
@@ -0,0 +1,208 @@
+//! Tweet entity-type detection.
+//!
+//! Classifies a tweet as a Spaces announcement, an article/news link share,
+//! a media-only post, or plain text. The detected type drives a configurable
+//! score adjustment and is surfaced in scoring output so decisions can be
+//! traced back to it.
+
+use serde::Serialize;
+
+use crate::config::EntityAdjustments;
+
+/// Media tweets with at most this many words (ignoring links, mentions, and
+/// hashtags) count as media-only.
+const MEDIA_ONLY_MAX_WORDS: usize = 3;
+
+/// Hosts that belong to X itself and are never treated as articles.
+const X_HOSTS: &[&str] = &[
+    "x.com",
+    "twitter.com",
+    "t.co",
+    "pic.x.com",
+    "pic.twitter.com",
+];
+
+/// Detected entity type of a tweet.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EntityType {
+    /// Plain text (possibly with mentions, hashtags, or links to other tweets).
+    #[default]
+    Text,
+    /// Announcement of an X Space.
+    Spaces,
+    /// Share of an external article or news URL.
+    Article,
+    /// Media with little or no accompanying text.
+    MediaOnly,
+}
+
+impl EntityType {
+    /// Stable snake_case name used in logs and API output.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Text => "text",
+            Self::Spaces => "spaces",
+            Self::Article => "article",
+            Self::MediaOnly => "media_only",
+        }
+    }
+
+    /// Configured score adjustment for this entity type.
+    pub fn adjustment(self, adjustments: &EntityAdjustments) -> f32 {
+        match self {
+            Self::Text => 0.0,
+            Self::Spaces => adjustments.spaces,
+            Self::Article => adjustments.article,
+            Self::MediaOnly => adjustments.media_only,
+        }
+    }
+}
+
+impl std::fmt::Display for EntityType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Detect a tweet's entity type from its text, expanded URLs, and media flag.
+///
+/// Spaces links win over everything else, then media-only posts, then
+/// external links. URLs found only in the text are considered too, so
+/// detection still works when entities were not requested.
+pub fn detect_entity_type(text: &str, urls: &[String], has_media: bool) -> EntityType {
+    let text_urls: Vec<&str> = text
+        .split_whitespace()
+        .filter(|w| w.starts_with("http://") || w.starts_with("https://"))
+        .collect();
+    let all_urls = urls.iter().map(String::as_str).chain(text_urls);
+
+    let mut has_article = false;
+    for url in all_urls {
+        let (host, path) = split_url(url);
+        let is_x_host = X_HOSTS.contains(&host.as_str());
+        if is_x_host && path.starts_with("i/spaces/") {
+            return EntityType::Spaces;
+        }
+        if !is_x_host && !host.is_empty() {
+            has_article = true;
+        }
+    }
+
+    if has_media && prose_word_count(text) <= MEDIA_ONLY_MAX_WORDS {
+        return EntityType::MediaOnly;
+    }
+    if has_article {
+        return EntityType::Article;
+    }
+    EntityType::Text
+}
+
+/// Split a URL into a lowercase host (without `www.`) and the path after `/`.
+fn split_url(url: &str) -> (String, &str) {
+    let rest = url
+        .strip_prefix("https://")
+        .or_else(|| url.strip_prefix("http://"))
+        .unwrap_or(url);
+    let (host, path) = rest.split_once('/').unwrap_or((rest, ""));
+    let host = host.to_ascii_lowercase();
+    let host = host
+        .strip_prefix("www.")
+        .map(str::to_string)
+        .unwrap_or(host);
+    (host, path)
+}
+
+/// Count words that are not links, mentions, or hashtags.
+fn prose_word_count(text: &str) -> usize {
+    text.split_whitespace()
+        .filter(|w| {
+            !(w.starts_with("http://")
+                || w.starts_with("https://")
+                || w.starts_with('@')
+                || w.starts_with('#'))
+        })
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn urls(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn plain_text_is_text() {
+        assert_eq!(
+            detect_entity_type("Rust makes CLIs pleasant", &[], false),
+            EntityType::Text
+        );
+    }
+
+    #[test]
+    fn spaces_link_detected_from_entities() {
+        let t = detect_entity_type(
+            "Join us tonight https://t.co/abc",
+            &urls(&["https://x.com/i/spaces/1YqKDqWqdPLGV"]),
+            false,
+        );
+        assert_eq!(t, EntityType::Spaces);
+    }
+
+    #[test]
+    fn spaces_link_detected_from_text() {
+        let t = detect_entity_type(
+            "Live now https://twitter.com/i/spaces/1YqKDqWqdPLGV",
+            &[],
+            false,
+        );
+        assert_eq!(t, EntityType::Spaces);
+    }
+
+    #[test]
+    fn external_link_is_article() {
+        let t = detect_entity_type(
+            "Great write-up on async Rust https://t.co/xyz",
+            &urls(&["https://www.nytimes.com/2026/01/01/tech/rust.html"]),
+            false,
+        );
+        assert_eq!(t, EntityType::Article);
+    }
+
+    #[test]
+    fn link_to_another_tweet_is_text() {
+        let t = detect_entity_type(
+            "This thread is great",
+            &urls(&["https://x.com/someone/status/123"]),
+            false,
+        );
+        assert_eq!(t, EntityType::Text);
+    }
+
+    #[test]
+    fn media_with_little_text_is_media_only() {
+        assert_eq!(
+            detect_entity_type("wow https://t.co/pic", &[], true),
+            EntityType::MediaOnly
+        );
+        assert_eq!(
+            detect_entity_type("Here is how our new deploy pipeline works", &[], true),
+            EntityType::Text
+        );
+    }
+
+    #[test]
+    fn adjustment_uses_config() {
+        let adjustments = EntityAdjustments {
+            spaces: -20.0,
+            article: 5.0,
+            media_only: -8.0,
+        };
+        assert_eq!(EntityType::Text.adjustment(&adjustments), 0.0);
+        assert_eq!(EntityType::Spaces.adjustment(&adjustments), -20.0);
+        assert_eq!(EntityType::Article.adjustment(&adjustments), 5.0);
+        assert_eq!(EntityType::MediaOnly.adjustment(&adjustments), -8.0);
+    }
+}
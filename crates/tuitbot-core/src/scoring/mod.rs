@@ -4,10 +4,24 @@
 //! recency, engagement rate) into a total score (0-100) with a configurable
 //! threshold for the REPLY/SKIP verdict.
 //!
+//! A configurable adjustment for the detected entity type (Spaces
+//! announcement, article link, media-only post) is added on top. Tweets
+//! older than the discovery freshness window then lose part of their score,
+//! and tweets past its cutoff never meet the threshold.
+//!
+//! An optional WASM plugin (see [`plugin`]) can add its own adjustment
+//! alongside the entity adjustment, before freshness decay.
+//!
 //! All scoring is purely heuristic -- no LLM calls.
 
+pub mod calibration;
+pub mod entity;
+pub mod plugin;
 pub mod signals;
 
+pub use entity::{detect_entity_type, EntityType};
+pub use plugin::ScoringPlugin;
+
 use crate::config::ScoringConfig;
 use chrono::{DateTime, Utc};
 
@@ -37,6 +51,8 @@ pub struct TweetData {
     /// Whether the tweet is a quote tweet.
     #[allow(dead_code)]
     pub is_quote_tweet: bool,
+    /// Expanded URLs from the tweet's entities.
+    pub urls: Vec<String>,
 }
 
 /// Per-signal score breakdown for a tweet.
@@ -56,6 +72,18 @@ pub struct TweetScore {
     pub reply_count: f32,
     /// Content type signal score (text-only = max).
     pub content_type: f32,
+    /// Detected entity type (Spaces, article, media-only, or text).
+    pub entity_type: EntityType,
+    /// Configured adjustment applied for `entity_type`.
+    pub entity_adjustment: f32,
+    /// Adjustment returned by the scoring plugin (0 without one).
+    pub plugin_adjustment: f32,
+    /// The scoring plugin's rationale for its adjustment.
+    pub plugin_rationale: Option<String>,
+    /// Points removed because the tweet is older than the decay age.
+    pub freshness_penalty: f32,
+    /// Whether the tweet is older than the freshness cutoff.
+    pub past_cutoff: bool,
     /// Whether the total score meets the configured threshold.
     pub meets_threshold: bool,
 }
@@ -64,6 +92,7 @@ pub struct TweetScore {
 pub struct ScoringEngine {
     config: ScoringConfig,
     keywords: Vec<String>,
+    plugin: Option<ScoringPlugin>,
 }
 
 impl ScoringEngine {
@@ -72,7 +101,17 @@ impl ScoringEngine {
     /// Keywords should be the combined list of `product_keywords` and
     /// `competitor_keywords` from the business profile.
     pub fn new(config: ScoringConfig, keywords: Vec<String>) -> Self {
-        Self { config, keywords }
+        Self {
+            config,
+            keywords,
+            plugin: None,
+        }
+    }
+
+    /// Run `plugin` as an extra scoring stage.
+    pub fn with_plugin(mut self, plugin: Option<ScoringPlugin>) -> Self {
+        self.plugin = plugin;
+        self
     }
 
     /// Score a tweet using all four signals.
@@ -115,10 +154,44 @@ impl ScoringEngine {
             self.config.content_type_max,
         );
 
-        let total =
-            (keyword_relevance + follower + recency + engagement + reply_count + content_type)
-                .clamp(0.0, 100.0);
-        let meets_threshold = total >= self.config.threshold as f32;
+        let entity_type = detect_entity_type(&tweet.text, &tweet.urls, tweet.has_media);
+        let entity_adjustment = entity_type.adjustment(&self.config.entity_adjustments);
+
+        let base_total = (keyword_relevance
+            + follower
+            + recency
+            + engagement
+            + reply_count
+            + content_type
+            + entity_adjustment)
+            .clamp(0.0, 100.0);
+
+        let (plugin_adjustment, plugin_rationale) = match &self.plugin {
+            Some(plugin) => match plugin.score(tweet, base_total) {
+                Ok(output) => (
+                    output.adjustment,
+                    Some(output.rationale).filter(|r| !r.is_empty()),
+                ),
+                Err(e) => {
+                    tracing::warn!(error = %e, "Scoring plugin failed, no adjustment applied");
+                    (0.0, None)
+                }
+            },
+            None => (0.0, None),
+        };
+        let raw_total = (base_total + plugin_adjustment).clamp(0.0, 100.0);
+
+        let freshness = &self.config.freshness;
+        let factor = signals::freshness_factor_at(
+            &tweet.created_at,
+            freshness.discovery_decay_after_hours,
+            freshness.discovery_max_age_hours,
+            now,
+        );
+        let past_cutoff = factor.is_none();
+        let freshness_penalty = raw_total * (1.0 - factor.unwrap_or(0.0));
+        let total = raw_total - freshness_penalty;
+        let meets_threshold = !past_cutoff && total >= self.config.threshold as f32;
 
         tracing::debug!(
             author = %tweet.author_username,
@@ -129,6 +202,11 @@ impl ScoringEngine {
             engagement = format!("{:.0}", engagement),
             reply = format!("{:.0}", reply_count),
             content = format!("{:.0}", content_type),
+            entity = entity_type.as_str(),
+            entity_adj = format!("{:.0}", entity_adjustment),
+            plugin_adj = format!("{:.0}", plugin_adjustment),
+            freshness = format!("{:.0}", -freshness_penalty),
+            past_cutoff,
             meets = meets_threshold,
             "Scored tweet",
         );
@@ -141,6 +219,12 @@ impl ScoringEngine {
             engagement,
             reply_count,
             content_type,
+            entity_type,
+            entity_adjustment,
+            plugin_adjustment,
+            plugin_rationale,
+            freshness_penalty,
+            past_cutoff,
             meets_threshold,
         }
     }
@@ -252,8 +336,31 @@ impl TweetScore {
         } else {
             "SKIP"
         };
+        let freshness = if self.past_cutoff {
+            format!(
+                "past the {}h cutoff",
+                config.freshness.discovery_max_age_hours
+            )
+        } else if self.freshness_penalty > 0.0 {
+            format!(
+                "decaying after {}h, cutoff {}h",
+                config.freshness.discovery_decay_after_hours,
+                config.freshness.discovery_max_age_hours
+            )
+        } else {
+            "fresh".to_string()
+        };
 
         let reply_count_display = tweet.replies;
+        let plugin_line = if self.plugin_adjustment != 0.0 || self.plugin_rationale.is_some() {
+            format!(
+                "\x20 Plugin:             {:+.0}  ({})\n",
+                self.plugin_adjustment,
+                self.plugin_rationale.as_deref().unwrap_or("no rationale")
+            )
+        } else {
+            String::new()
+        };
 
         format!(
             "Tweet: \"{}\" by @{} ({} followers)\n\
@@ -264,6 +371,9 @@ impl TweetScore {
              \x20 Engagement rate:    {:.0}/{}  ({:.1}% engagement vs 1.5% baseline)\n\
              \x20 Reply count:        {:.0}/{}  ({} existing replies)\n\
              \x20 Content type:       {:.0}/{}  ({})\n\
+             \x20 Entity type:        {:+.0}  ({})\n\
+             {}\
+             \x20 Freshness:          {:+.0}  ({})\n\
              Verdict: {} (threshold: {})",
             truncated,
             tweet.author_username,
@@ -291,6 +401,11 @@ impl TweetScore {
             } else {
                 "text-only"
             },
+            self.entity_adjustment,
+            self.entity_type,
+            plugin_line,
+            -self.freshness_penalty,
+            freshness,
             verdict,
             config.threshold,
         )
@@ -301,7 +416,7 @@ impl std::fmt::Display for TweetScore {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "Score: {:.0}/100 [kw:{:.0} fol:{:.0} rec:{:.0} eng:{:.0} rep:{:.0} ct:{:.0}] {}",
+            "Score: {:.0}/100 [kw:{:.0} fol:{:.0} rec:{:.0} eng:{:.0} rep:{:.0} ct:{:.0} {}:{:+.0} fr:{:+.0}] {}",
             self.total,
             self.keyword_relevance,
             self.follower,
@@ -309,6 +424,9 @@ impl std::fmt::Display for TweetScore {
             self.engagement,
             self.reply_count,
             self.content_type,
+            self.entity_type,
+            self.entity_adjustment,
+            -self.freshness_penalty,
             if self.meets_threshold {
                 "REPLY"
             } else {
@@ -333,6 +451,10 @@ mod tests {
             engagement_rate_max: 15.0,
             reply_count_max: 15.0,
             content_type_max: 10.0,
+            entity_adjustments: Default::default(),
+            calibration: Default::default(),
+            freshness: Default::default(),
+            plugin: Default::default(),
         }
     }
 
@@ -347,6 +469,7 @@ mod tests {
             author_followers: 5000,
             has_media: false,
             is_quote_tweet: false,
+            urls: vec![],
         }
     }
 
@@ -381,6 +504,10 @@ mod tests {
             engagement_rate_max: 80.0,
             reply_count_max: 80.0,
             content_type_max: 80.0,
+            entity_adjustments: Default::default(),
+            calibration: Default::default(),
+            freshness: Default::default(),
+            plugin: Default::default(),
         };
         let keywords = vec!["rust".to_string()];
         let engine = ScoringEngine::new(config, keywords);
@@ -505,6 +632,23 @@ mod tests {
         assert_eq!(score.keyword_relevance, 0.0);
     }
 
+    #[test]
+    fn score_applies_entity_adjustment() {
+        let config = default_scoring_config();
+        let engine = ScoringEngine::new(config, vec!["rust".to_string()]);
+        let now = Utc::now();
+
+        let plain = engine.score_tweet_at(&test_tweet(now), now);
+        let mut spaces = test_tweet(now);
+        spaces.urls = vec!["https://x.com/i/spaces/1abc".to_string()];
+        let spaces = engine.score_tweet_at(&spaces, now);
+
+        assert_eq!(plain.entity_type, EntityType::Text);
+        assert_eq!(spaces.entity_type, EntityType::Spaces);
+        assert_eq!(spaces.entity_adjustment, -15.0);
+        assert!((plain.total - spaces.total - 15.0).abs() < 0.01);
+    }
+
     // --- find_matched_keywords tests ---
 
     #[test]
@@ -580,6 +724,28 @@ mod tests {
         assert_eq!(format_tweet_age_at("bad", Utc::now()), "unknown");
     }
 
+    #[test]
+    fn stale_tweets_decay_and_hit_the_cutoff() {
+        let engine = ScoringEngine::new(default_scoring_config(), vec!["rust".to_string()]);
+        let now = Utc::now();
+        let fresh = engine.score_tweet_at(&test_tweet(now), now);
+        assert_eq!(fresh.freshness_penalty, 0.0);
+
+        let mut tweet = test_tweet(now);
+        tweet.created_at = (now - Duration::hours(30)).to_rfc3339();
+        let decayed = engine.score_tweet_at(&tweet, now);
+        assert!(decayed.freshness_penalty > 0.0);
+        assert!(!decayed.past_cutoff);
+
+        tweet.created_at = (now - Duration::days(3)).to_rfc3339();
+        let stale = engine.score_tweet_at(&tweet, now);
+        assert!(stale.past_cutoff);
+        assert_eq!(stale.total, 0.0);
+        assert!(!stale.meets_threshold);
+        let breakdown = stale.format_breakdown(engine.config(), &tweet, &[]);
+        assert!(breakdown.contains("past the 48h cutoff"));
+    }
+
     // --- truncate_text tests ---
 
     #[test]
@@ -610,6 +776,12 @@ mod tests {
             engagement: 10.0,
             reply_count: 15.0,
             content_type: 10.0,
+            entity_type: EntityType::Text,
+            entity_adjustment: 0.0,
+            plugin_adjustment: 0.0,
+            plugin_rationale: None,
+            freshness_penalty: 0.0,
+            past_cutoff: false,
             meets_threshold: true,
         };
 
@@ -621,6 +793,22 @@ mod tests {
         assert!(output.contains("Content type"));
     }
 
+    #[test]
+    fn format_breakdown_shows_plugin_adjustment() {
+        let config = default_scoring_config();
+        let now = Utc::now();
+        let tweet = test_tweet(now);
+        let mut score = ScoringEngine::new(config.clone(), vec![]).score_tweet_at(&tweet, now);
+        assert!(!score
+            .format_breakdown(&config, &tweet, &[])
+            .contains("Plugin"));
+
+        score.plugin_adjustment = 8.0;
+        score.plugin_rationale = Some("founder account".to_string());
+        let output = score.format_breakdown(&config, &tweet, &[]);
+        assert!(output.contains("Plugin:             +8  (founder account)"));
+    }
+
     #[test]
     fn format_breakdown_skip_verdict() {
         let config = default_scoring_config();
@@ -634,6 +822,12 @@ mod tests {
             engagement: 7.0,
             reply_count: 5.0,
             content_type: 5.0,
+            entity_type: EntityType::Text,
+            entity_adjustment: 0.0,
+            plugin_adjustment: 0.0,
+            plugin_rationale: None,
+            freshness_penalty: 0.0,
+            past_cutoff: false,
             meets_threshold: false,
         };
 
@@ -654,6 +848,12 @@ mod tests {
             engagement: 10.0,
             reply_count: 15.0,
             content_type: 10.0,
+            entity_type: EntityType::Text,
+            entity_adjustment: 0.0,
+            plugin_adjustment: 0.0,
+            plugin_rationale: None,
+            freshness_penalty: 0.0,
+            past_cutoff: false,
             meets_threshold: true,
         };
         let display = format!("{score}");
@@ -0,0 +1,356 @@
+//! Custom WASM scoring stage.
+//!
+//! A plugin is a compiled WASM module configured at `[scoring.plugin]`. For
+//! each candidate tweet the engine writes a JSON [`PluginInput`] into the
+//! module's memory and calls its `score` export, which answers with a JSON
+//! [`PluginOutput`]: a score adjustment and the rationale for it.
+//!
+//! The module must export:
+//!
+//! - `memory`: its linear memory.
+//! - `alloc(len: i32) -> i32`: a buffer of `len` bytes for the input.
+//! - `score(ptr: i32, len: i32) -> i64`: scores the input at `ptr` and
+//!   returns the output's pointer in the high 32 bits and its length in the
+//!   low 32 bits.
+//!
+//! Modules get no host imports, so they cannot reach the network, the
+//! filesystem, or the clock. Each call runs in a fresh instance with the
+//! configured fuel budget and memory limit.
+
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use super::TweetData;
+use crate::config::ScoringPluginConfig;
+use crate::error::ScoringError;
+
+/// Longest rationale kept from a plugin.
+const MAX_RATIONALE_CHARS: usize = 280;
+
+/// Largest output a plugin may return, in bytes.
+#[cfg(feature = "wasm-scoring")]
+const MAX_OUTPUT_BYTES: usize = 64 * 1024;
+
+/// JSON passed to the plugin for each candidate tweet.
+#[derive(Debug, Serialize)]
+pub struct PluginInput<'a> {
+    pub text: &'a str,
+    pub created_at: &'a str,
+    pub likes: u64,
+    pub retweets: u64,
+    pub replies: u64,
+    pub author_username: &'a str,
+    pub author_followers: u64,
+    pub has_media: bool,
+    pub is_quote_tweet: bool,
+    pub urls: &'a [String],
+    /// Built-in score (0-100) before the plugin and freshness decay.
+    pub base_score: f32,
+}
+
+/// JSON returned by the plugin.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct PluginOutput {
+    /// Points added to (or subtracted from) the score.
+    #[serde(default)]
+    pub adjustment: f32,
+    /// Why the plugin adjusted the score.
+    #[serde(default)]
+    pub rationale: String,
+}
+
+/// A compiled scoring plugin.
+pub struct ScoringPlugin {
+    path: String,
+    config: ScoringPluginConfig,
+    #[cfg(feature = "wasm-scoring")]
+    engine: wasmtime::Engine,
+    #[cfg(feature = "wasm-scoring")]
+    module: wasmtime::Module,
+}
+
+impl ScoringPlugin {
+    /// Load and compile the configured module.
+    ///
+    /// Returns `None` when no plugin path is configured.
+    pub fn load(config: &ScoringPluginConfig) -> Result<Option<Self>, ScoringError> {
+        if !config.is_enabled() {
+            return Ok(None);
+        }
+        let path = crate::startup::expand_tilde(config.path.trim());
+        Self::compile(&path, config).map(Some)
+    }
+
+    /// Like [`load`](Self::load), but logs a load failure and returns `None`.
+    pub fn load_or_warn(config: &ScoringPluginConfig) -> Option<Self> {
+        Self::load(config).unwrap_or_else(|e| {
+            tracing::warn!(error = %e, "Scoring plugin not loaded");
+            None
+        })
+    }
+
+    #[cfg(not(feature = "wasm-scoring"))]
+    fn compile(path: &Path, _config: &ScoringPluginConfig) -> Result<Self, ScoringError> {
+        Err(plugin_error(
+            path,
+            "this build does not include the wasm-scoring feature",
+        ))
+    }
+
+    #[cfg(feature = "wasm-scoring")]
+    fn compile(path: &Path, config: &ScoringPluginConfig) -> Result<Self, ScoringError> {
+        let mut wasm_config = wasmtime::Config::new();
+        wasm_config.consume_fuel(true);
+        let engine = wasmtime::Engine::new(&wasm_config)
+            .map_err(|e| plugin_error(path, format!("{e:#}")))?;
+        let bytes = std::fs::read(path).map_err(|e| plugin_error(path, e))?;
+        let module = wasmtime::Module::new(&engine, bytes)
+            .map_err(|e| plugin_error(path, format!("{e:#}")))?;
+        if let Some(import) = module.imports().next() {
+            return Err(plugin_error(
+                path,
+                format!(
+                    "imports {}::{}, but plugins get no host functions",
+                    import.module(),
+                    import.name()
+                ),
+            ));
+        }
+        Ok(Self {
+            path: path.display().to_string(),
+            config: config.clone(),
+            engine,
+            module,
+        })
+    }
+
+    /// Score one tweet. The adjustment is clamped to `max_adjustment`.
+    pub fn score(&self, tweet: &TweetData, base_score: f32) -> Result<PluginOutput, ScoringError> {
+        let input = PluginInput {
+            text: &tweet.text,
+            created_at: &tweet.created_at,
+            likes: tweet.likes,
+            retweets: tweet.retweets,
+            replies: tweet.replies,
+            author_username: &tweet.author_username,
+            author_followers: tweet.author_followers,
+            has_media: tweet.has_media,
+            is_quote_tweet: tweet.is_quote_tweet,
+            urls: &tweet.urls,
+            base_score,
+        };
+        let input = serde_json::to_vec(&input).map_err(|e| self.error(e))?;
+        let output = self.call(&input)?;
+
+        let mut output: PluginOutput = serde_json::from_slice(&output)
+            .map_err(|e| self.error(format!("unreadable output: {e}")))?;
+        if !output.adjustment.is_finite() {
+            return Err(self.error("adjustment is not a finite number"));
+        }
+        let max = self.config.max_adjustment;
+        output.adjustment = output.adjustment.clamp(-max, max);
+        output.rationale = output
+            .rationale
+            .trim()
+            .chars()
+            .take(MAX_RATIONALE_CHARS)
+            .collect();
+        Ok(output)
+    }
+
+    #[cfg(not(feature = "wasm-scoring"))]
+    fn call(&self, _input: &[u8]) -> Result<Vec<u8>, ScoringError> {
+        Err(self.error("this build does not include the wasm-scoring feature"))
+    }
+
+    /// Run the module's `score` export on `input` in a fresh instance.
+    #[cfg(feature = "wasm-scoring")]
+    fn call(&self, input: &[u8]) -> Result<Vec<u8>, ScoringError> {
+        use wasmtime::{Instance, Store, StoreLimitsBuilder};
+
+        let wasm_error = |e: wasmtime::Error| self.error(format!("{e:#}"));
+        let memory_bytes = usize::try_from(self.config.max_memory_mb)
+            .unwrap_or(usize::MAX)
+            .saturating_mul(1024 * 1024);
+        let limits = StoreLimitsBuilder::new()
+            .memory_size(memory_bytes)
+            .instances(1)
+            .build();
+        let mut store = Store::new(&self.engine, limits);
+        store.limiter(|limits| limits);
+        store.set_fuel(self.config.fuel).map_err(wasm_error)?;
+
+        let instance = Instance::new(&mut store, &self.module, &[]).map_err(wasm_error)?;
+        let memory = instance
+            .get_memory(&mut store, "memory")
+            .ok_or_else(|| self.error("missing `memory` export"))?;
+        let alloc = instance
+            .get_typed_func::<i32, i32>(&mut store, "alloc")
+            .map_err(wasm_error)?;
+        let score = instance
+            .get_typed_func::<(i32, i32), i64>(&mut store, "score")
+            .map_err(wasm_error)?;
+
+        let len = i32::try_from(input.len()).map_err(|_| self.error("input too large"))?;
+        let ptr = alloc.call(&mut store, len).map_err(wasm_error)?;
+        memory
+            .write(&mut store, ptr as u32 as usize, input)
+            .map_err(|e| self.error(e))?;
+        let packed = score.call(&mut store, (ptr, len)).map_err(wasm_error)? as u64;
+
+        let out_ptr = (packed >> 32) as usize;
+        let out_len = (packed & 0xffff_ffff) as usize;
+        if out_len > MAX_OUTPUT_BYTES {
+            return Err(self.error(format!(
+                "output of {out_len} bytes exceeds {MAX_OUTPUT_BYTES}"
+            )));
+        }
+        let mut output = vec![0; out_len];
+        memory
+            .read(&store, out_ptr, &mut output)
+            .map_err(|e| self.error(e))?;
+        Ok(output)
+    }
+
+    fn error(&self, message: impl std::fmt::Display) -> ScoringError {
+        ScoringError::Plugin {
+            path: self.path.clone(),
+            message: message.to_string(),
+        }
+    }
+}
+
+fn plugin_error(path: &Path, message: impl std::fmt::Display) -> ScoringError {
+    ScoringError::Plugin {
+        path: path.display().to_string(),
+        message: message.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn plugin_config(path: &str) -> ScoringPluginConfig {
+        ScoringPluginConfig {
+            path: path.to_string(),
+            ..ScoringPluginConfig::default()
+        }
+    }
+
+    #[test]
+    fn empty_path_disables_plugin() {
+        assert!(ScoringPlugin::load(&plugin_config("  ")).unwrap().is_none());
+    }
+
+    #[cfg(not(feature = "wasm-scoring"))]
+    #[test]
+    fn load_fails_without_feature() {
+        let err = ScoringPlugin::load(&plugin_config("/tmp/plugin.wasm"))
+            .err()
+            .expect("load should fail");
+        assert!(err.to_string().contains("wasm-scoring feature"));
+    }
+
+    #[cfg(feature = "wasm-scoring")]
+    mod wasm {
+        use super::*;
+        use crate::config::ScoringConfig;
+        use crate::scoring::ScoringEngine;
+
+        /// A module whose `score` export always returns `output`.
+        fn fixed_output_module(output: &str) -> String {
+            format!(
+                r#"(module
+                  (memory (export "memory") 1)
+                  (data (i32.const 1024) "{escaped}")
+                  (func (export "alloc") (param i32) (result i32) i32.const 4096)
+                  (func (export "score") (param i32 i32) (result i64)
+                    (i64.or (i64.shl (i64.const 1024) (i64.const 32)) (i64.const {len}))))"#,
+                escaped = output.replace('"', "\\\""),
+                len = output.len(),
+            )
+        }
+
+        fn write_module(dir: &tempfile::TempDir, wat: &str) -> String {
+            let path = dir.path().join("plugin.wasm");
+            std::fs::write(&path, wat::parse_str(wat).expect("valid wat")).expect("write");
+            path.display().to_string()
+        }
+
+        fn tweet() -> TweetData {
+            TweetData {
+                text: "Shipping a Rust CLI today".to_string(),
+                created_at: chrono::Utc::now().to_rfc3339(),
+                likes: 10,
+                retweets: 2,
+                replies: 1,
+                author_username: "dev".to_string(),
+                author_followers: 2000,
+                has_media: false,
+                is_quote_tweet: false,
+                urls: vec![],
+            }
+        }
+
+        #[test]
+        fn adjustment_is_clamped_and_merged_into_score() {
+            let dir = tempfile::tempdir().expect("tempdir");
+            let path = write_module(
+                &dir,
+                &fixed_output_module(r#"{"adjustment": 50, "rationale": "ships Rust"}"#),
+            );
+            let plugin = ScoringPlugin::load(&plugin_config(&path))
+                .expect("load")
+                .expect("enabled");
+            let output = plugin.score(&tweet(), 40.0).expect("score");
+            assert_eq!(output.adjustment, 20.0);
+            assert_eq!(output.rationale, "ships Rust");
+
+            let config = ScoringConfig::default();
+            let keywords = vec!["rust".to_string()];
+            let plain = ScoringEngine::new(config.clone(), keywords.clone());
+            let engine = ScoringEngine::new(config, keywords).with_plugin(Some(plugin));
+            let now = chrono::Utc::now();
+            let base = plain.score_tweet_at(&tweet(), now);
+            let score = engine.score_tweet_at(&tweet(), now);
+            assert_eq!(score.plugin_adjustment, 20.0);
+            assert_eq!(score.plugin_rationale.as_deref(), Some("ships Rust"));
+            assert!((score.total - (base.total + 20.0).min(100.0)).abs() < 0.01);
+        }
+
+        #[test]
+        fn runaway_and_importing_modules_are_stopped() {
+            let dir = tempfile::tempdir().expect("tempdir");
+            let looping = write_module(
+                &dir,
+                r#"(module
+                  (memory (export "memory") 1)
+                  (func (export "alloc") (param i32) (result i32) i32.const 0)
+                  (func (export "score") (param i32 i32) (result i64)
+                    (loop $spin (br $spin))
+                    i64.const 0))"#,
+            );
+            let plugin = ScoringPlugin::load(&plugin_config(&looping))
+                .expect("load")
+                .expect("enabled");
+            let err = plugin.score(&tweet(), 40.0).expect_err("out of fuel");
+            assert!(err.to_string().contains("fuel"), "{err}");
+
+            // A failing plugin leaves the built-in score unchanged.
+            let config = ScoringConfig::default();
+            let engine = ScoringEngine::new(config, vec![]).with_plugin(Some(plugin));
+            let score = engine.score_tweet(&tweet());
+            assert_eq!(score.plugin_adjustment, 0.0);
+            assert!(score.plugin_rationale.is_none());
+
+            let importing =
+                write_module(&dir, r#"(module (import "env" "now" (func (result i64))))"#);
+            let err = ScoringPlugin::load(&plugin_config(&importing))
+                .err()
+                .expect("imports rejected");
+            assert!(err.to_string().contains("env::now"), "{err}");
+        }
+    }
+}
@@ -106,6 +106,36 @@ pub fn recency_score_at(tweet_created_at: &str, max_score: f32, now: DateTime<Ut
     (fraction as f32 * max_score).clamp(0.0, max_score)
 }
 
+/// Share of a tweet's score kept at its age, or `None` past the cutoff.
+///
+/// The factor is 1.0 up to `decay_after_hours` and falls linearly to 0.0 at
+/// `max_age_hours`. A `max_age_hours` of 0 disables the window, as does an
+/// unparseable timestamp.
+pub fn freshness_factor_at(
+    tweet_created_at: &str,
+    decay_after_hours: u32,
+    max_age_hours: u32,
+    now: DateTime<Utc>,
+) -> Option<f32> {
+    let Ok(created_at) = tweet_created_at.parse::<DateTime<Utc>>() else {
+        return Some(1.0);
+    };
+    if max_age_hours == 0 {
+        return Some(1.0);
+    }
+
+    let age_hours = (now - created_at).num_minutes().max(0) as f64 / 60.0;
+    let decay_after = f64::from(decay_after_hours);
+    let max_age = f64::from(max_age_hours);
+    if age_hours > max_age {
+        None
+    } else if age_hours <= decay_after {
+        Some(1.0)
+    } else {
+        Some((1.0 - (age_hours - decay_after) / (max_age - decay_after).max(1.0)) as f32)
+    }
+}
+
 /// Convenience wrapper for `recency_score_at` using the current time.
 pub fn recency_score(tweet_created_at: &str, max_score: f32) -> f32 {
     recency_score_at(tweet_created_at, max_score, Utc::now())
@@ -487,4 +517,16 @@ mod tests {
     fn content_type_media_and_quote_zero() {
         assert!((content_type_score(true, true, 10.0) - 0.0).abs() < 0.01);
     }
+
+    #[test]
+    fn freshness_decays_then_cuts_off() {
+        let now = Utc::now();
+        let at = |hours: i64| (now - chrono::Duration::hours(hours)).to_rfc3339();
+        assert_eq!(freshness_factor_at(&at(2), 12, 48, now), Some(1.0));
+        let half = freshness_factor_at(&at(30), 12, 48, now).unwrap();
+        assert!((half - 0.5).abs() < 0.01);
+        assert_eq!(freshness_factor_at(&at(49), 12, 48, now), None);
+        assert_eq!(freshness_factor_at(&at(500), 12, 0, now), Some(1.0));
+        assert_eq!(freshness_factor_at("not a date", 12, 48, now), Some(1.0));
+    }
 }
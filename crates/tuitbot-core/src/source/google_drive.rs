@@ -331,7 +331,7 @@ fn rsa_sign_sha256(data: &[u8], pem: &str) -> Result<Vec<u8>, SourceError> {
 }
 
 /// Decode a PEM-encoded RSA private key to DER bytes.
-fn pem_to_der(pem: &str) -> Result<Vec<u8>, SourceError> {
+pub(crate) fn pem_to_der(pem: &str) -> Result<Vec<u8>, SourceError> {
     let pem = pem.trim();
     let body: String = pem
         .lines()
@@ -552,13 +552,13 @@ fn mod_pow(base: &BigUint, exp: &BigUint, modulus: &BigUint) -> BigUint {
 // ASN.1/DER parsing for PKCS#8 RSA keys
 // ---------------------------------------------------------------------------
 
-struct RsaKeyParts {
-    n_bytes: Vec<u8>,
-    d_bytes: Vec<u8>,
+pub(crate) struct RsaKeyParts {
+    pub(crate) n_bytes: Vec<u8>,
+    pub(crate) d_bytes: Vec<u8>,
 }
 
 /// Parse a PKCS#8 DER-encoded RSA private key and extract (n, d).
-fn parse_pkcs8_rsa(der: &[u8]) -> Result<RsaKeyParts, SourceError> {
+pub(crate) fn parse_pkcs8_rsa(der: &[u8]) -> Result<RsaKeyParts, SourceError> {
     // PKCS#8 is a SEQUENCE containing:
     //   INTEGER version
     //   SEQUENCE { OID rsaEncryption, NULL }
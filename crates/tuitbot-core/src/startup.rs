@@ -255,25 +255,12 @@ pub fn load_tokens_from_file() -> Result<StoredTokens, StartupError> {
 
 /// Save OAuth tokens to the default file path with secure permissions.
 ///
-/// Creates the `~/.tuitbot/` directory if it does not exist.
-/// On Unix, sets file permissions to 0600 (owner read/write only).
+/// Creates the `~/.tuitbot/` directory if it does not exist and replaces
+/// the file atomically. On Unix, the file is owner read/write only (0600).
 pub fn save_tokens_to_file(tokens: &StoredTokens) -> Result<(), StartupError> {
-    let dir = data_dir();
-    std::fs::create_dir_all(&dir)?;
-
-    let path = token_file_path();
     let json = serde_json::to_string_pretty(tokens)
         .map_err(|e| StartupError::Other(format!("failed to serialize tokens: {e}")))?;
-    std::fs::write(&path, json)?;
-
-    // Set file permissions to 0600 on Unix (owner read/write only).
-    #[cfg(unix)]
-    {
-        use std::os::unix::fs::PermissionsExt;
-        let perms = std::fs::Permissions::from_mode(0o600);
-        std::fs::set_permissions(&path, perms)?;
-    }
-
+    crate::x_api::auth::write_private_file(&token_file_path(), json.as_bytes())?;
     Ok(())
 }
 
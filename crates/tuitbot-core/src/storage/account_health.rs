@@ -0,0 +1,184 @@
+//! Storage for account health (visibility) self-check results.
+//!
+//! The account health monitor records one row per check so the dashboard
+//! and CLI can show the latest status and its history.
+
+use super::accounts::DEFAULT_ACCOUNT_ID;
+use super::DbPool;
+use crate::error::StorageError;
+
+/// A recorded visibility self-check.
+#[derive(Debug, Clone, sqlx::FromRow, serde::Serialize)]
+pub struct AccountHealthCheck {
+    pub id: i64,
+    /// `healthy`, `degraded`, or `unknown` (not enough samples).
+    pub status: String,
+    pub tweets_checked: i64,
+    pub tweets_found: i64,
+    pub replies_checked: i64,
+    pub replies_visible: i64,
+    /// Posting delay multiplier applied after this check.
+    pub slowdown_factor: f64,
+    /// JSON array of per-post probe results.
+    pub details: String,
+    pub checked_at: String,
+}
+
+/// Input for recording a new health check.
+#[derive(Debug, Clone)]
+pub struct NewAccountHealthCheck<'a> {
+    pub status: &'a str,
+    pub tweets_checked: i64,
+    pub tweets_found: i64,
+    pub replies_checked: i64,
+    pub replies_visible: i64,
+    pub slowdown_factor: f64,
+    pub details: &'a str,
+}
+
+/// Record a health check for a specific account. Returns the row ID.
+pub async fn insert_health_check_for(
+    pool: &DbPool,
+    account_id: &str,
+    check: &NewAccountHealthCheck<'_>,
+) -> Result<i64, StorageError> {
+    let result = sqlx::query(
+        "INSERT INTO account_health_checks \
+         (account_id, status, tweets_checked, tweets_found, replies_checked, \
+          replies_visible, slowdown_factor, details) \
+         VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+    )
+    .bind(account_id)
+    .bind(check.status)
+    .bind(check.tweets_checked)
+    .bind(check.tweets_found)
+    .bind(check.replies_checked)
+    .bind(check.replies_visible)
+    .bind(check.slowdown_factor)
+    .bind(check.details)
+    .execute(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+
+    Ok(result.last_insert_rowid())
+}
+
+/// Record a health check for the default account.
+pub async fn insert_health_check(
+    pool: &DbPool,
+    check: &NewAccountHealthCheck<'_>,
+) -> Result<i64, StorageError> {
+    insert_health_check_for(pool, DEFAULT_ACCOUNT_ID, check).await
+}
+
+/// Get the most recent health checks for a specific account, newest first.
+pub async fn get_recent_health_checks_for(
+    pool: &DbPool,
+    account_id: &str,
+    limit: u32,
+) -> Result<Vec<AccountHealthCheck>, StorageError> {
+    sqlx::query_as::<_, AccountHealthCheck>(
+        "SELECT id, status, tweets_checked, tweets_found, replies_checked, \
+                replies_visible, slowdown_factor, details, checked_at \
+         FROM account_health_checks \
+         WHERE account_id = ? \
+         ORDER BY checked_at DESC, id DESC \
+         LIMIT ?",
+    )
+    .bind(account_id)
+    .bind(limit)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })
+}
+
+/// Get the most recent health checks for the default account, newest first.
+pub async fn get_recent_health_checks(
+    pool: &DbPool,
+    limit: u32,
+) -> Result<Vec<AccountHealthCheck>, StorageError> {
+    get_recent_health_checks_for(pool, DEFAULT_ACCOUNT_ID, limit).await
+}
+
+/// Get the latest health check for a specific account, if any.
+pub async fn get_latest_health_check_for(
+    pool: &DbPool,
+    account_id: &str,
+) -> Result<Option<AccountHealthCheck>, StorageError> {
+    Ok(get_recent_health_checks_for(pool, account_id, 1)
+        .await?
+        .into_iter()
+        .next())
+}
+
+/// Get the latest health check for the default account, if any.
+pub async fn get_latest_health_check(
+    pool: &DbPool,
+) -> Result<Option<AccountHealthCheck>, StorageError> {
+    get_latest_health_check_for(pool, DEFAULT_ACCOUNT_ID).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::init_test_db;
+
+    fn sample(status: &str) -> NewAccountHealthCheck<'_> {
+        NewAccountHealthCheck {
+            status,
+            tweets_checked: 4,
+            tweets_found: 1,
+            replies_checked: 3,
+            replies_visible: 0,
+            slowdown_factor: 3.0,
+            details: "[]",
+        }
+    }
+
+    #[tokio::test]
+    async fn insert_and_get_latest() {
+        let pool = init_test_db().await.expect("init db");
+
+        assert!(get_latest_health_check(&pool)
+            .await
+            .expect("latest")
+            .is_none());
+
+        insert_health_check(&pool, &sample("healthy"))
+            .await
+            .expect("insert");
+        insert_health_check(&pool, &sample("degraded"))
+            .await
+            .expect("insert");
+
+        let latest = get_latest_health_check(&pool)
+            .await
+            .expect("latest")
+            .expect("some");
+        assert_eq!(latest.status, "degraded");
+        assert_eq!(latest.tweets_checked, 4);
+        assert_eq!(latest.slowdown_factor, 3.0);
+
+        let recent = get_recent_health_checks(&pool, 10).await.expect("recent");
+        assert_eq!(recent.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn checks_are_scoped_per_account() {
+        let pool = init_test_db().await.expect("init db");
+
+        insert_health_check_for(&pool, "other-account", &sample("degraded"))
+            .await
+            .expect("insert");
+
+        assert!(get_latest_health_check(&pool)
+            .await
+            .expect("latest")
+            .is_none());
+        let other = get_latest_health_check_for(&pool, "other-account")
+            .await
+            .expect("latest")
+            .expect("some");
+        assert_eq!(other.status, "degraded");
+    }
+}
@@ -4,6 +4,7 @@
 //! status, and optional metadata in JSON format.
 
 use super::accounts::DEFAULT_ACCOUNT_ID;
+use super::pagination::{fetch_page, Conditions, Page, PageRequest};
 use super::DbPool;
 use crate::error::StorageError;
 use std::collections::HashMap;
@@ -211,6 +212,50 @@ pub async fn get_actions_paginated_for(
         .map_err(|e| StorageError::Query { source: e })
 }
 
+/// Sortable fields for [`get_actions_page_for`], as (field, column).
+pub const ACTION_SORT_FIELDS: &[(&str, &str)] = &[
+    ("created_at", "created_at"),
+    ("type", "action_type"),
+    ("status", "status"),
+];
+
+/// Filters for [`get_actions_page_for`]; `None` matches everything.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ActionFilter<'a> {
+    pub action_type: Option<&'a str>,
+    pub status: Option<&'a str>,
+    /// Logged at or after this ISO-8601 timestamp.
+    pub since: Option<&'a str>,
+    /// Logged before this ISO-8601 timestamp.
+    pub until: Option<&'a str>,
+}
+
+/// One page of action log entries for a specific account, newest first by
+/// default.
+pub async fn get_actions_page_for(
+    pool: &DbPool,
+    account_id: &str,
+    filter: &ActionFilter<'_>,
+    page: &PageRequest,
+) -> Result<Page<ActionLogEntry>, StorageError> {
+    let conditions = Conditions::new()
+        .eq("account_id", Some(account_id))
+        .eq("action_type", filter.action_type)
+        .eq("status", filter.status)
+        .since("created_at", filter.since)
+        .until("created_at", filter.until);
+    fetch_page(
+        pool,
+        "action_log",
+        "*",
+        &conditions,
+        page,
+        "ORDER BY created_at DESC",
+        |e: &ActionLogEntry| e.id,
+    )
+    .await
+}
+
 /// Fetch paginated action log entries with optional type and status filters.
 ///
 /// Results are ordered by `created_at` descending (newest first).
@@ -265,6 +310,58 @@ pub async fn get_actions_count(
     get_actions_count_for(pool, DEFAULT_ACCOUNT_ID, action_type, status).await
 }
 
+/// Highest action log ID for a specific account, or 0 when the log is empty.
+pub async fn latest_action_id_for(pool: &DbPool, account_id: &str) -> Result<i64, StorageError> {
+    let (id,): (i64,) =
+        sqlx::query_as("SELECT COALESCE(MAX(id), 0) FROM action_log WHERE account_id = ?")
+            .bind(account_id)
+            .fetch_one(pool)
+            .await
+            .map_err(|e| StorageError::Query { source: e })?;
+    Ok(id)
+}
+
+/// Highest action log ID, or 0 when the log is empty.
+pub async fn latest_action_id(pool: &DbPool) -> Result<i64, StorageError> {
+    latest_action_id_for(pool, DEFAULT_ACCOUNT_ID).await
+}
+
+/// Failed attempts to post to X logged after `after_id` for a specific account,
+/// oldest first.
+///
+/// Covers tweets, threads, and replies from every loop, plus approved items
+/// posted from the queue (`*_posted`).
+pub async fn get_posting_failures_after_for(
+    pool: &DbPool,
+    account_id: &str,
+    after_id: i64,
+    limit: u32,
+) -> Result<Vec<ActionLogEntry>, StorageError> {
+    sqlx::query_as::<_, ActionLogEntry>(
+        "SELECT id, action_type, status, message, metadata, created_at FROM action_log \
+         WHERE account_id = ? AND id > ? AND status IN ('failure', 'error') \
+           AND (action_type IN ('tweet', 'thread', 'reply', 'mention_reply', \
+                                'discovery_reply', 'discovery_quote', 'target_reply') \
+                OR action_type LIKE '%_posted') \
+         ORDER BY id ASC LIMIT ?",
+    )
+    .bind(account_id)
+    .bind(after_id)
+    .bind(limit)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })
+}
+
+/// Failed attempts to post to X logged after `after_id`, oldest first.
+pub async fn get_posting_failures_after(
+    pool: &DbPool,
+    after_id: i64,
+    limit: u32,
+) -> Result<Vec<ActionLogEntry>, StorageError> {
+    get_posting_failures_after_for(pool, DEFAULT_ACCOUNT_ID, after_id, limit).await
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -288,6 +385,32 @@ mod tests {
         assert_eq!(actions[0].message.as_deref(), Some("Found 10 tweets"));
     }
 
+    #[tokio::test]
+    async fn posting_failures_after_watermark() {
+        let pool = init_test_db().await.expect("init db");
+
+        log_action(&pool, "tweet", "failure", Some("old"), None)
+            .await
+            .expect("log");
+        let watermark = latest_action_id(&pool).await.expect("latest");
+
+        log_action(&pool, "search", "failure", None, None)
+            .await
+            .expect("log");
+        log_action(&pool, "reply_posted", "error", Some("Failed"), None)
+            .await
+            .expect("log");
+        log_action(&pool, "tweet", "success", None, None)
+            .await
+            .expect("log");
+
+        let failures = get_posting_failures_after(&pool, watermark, 10)
+            .await
+            .expect("failures");
+        assert_eq!(failures.len(), 1);
+        assert_eq!(failures[0].action_type, "reply_posted");
+    }
+
     #[tokio::test]
     async fn filter_by_action_type() {
         let pool = init_test_db().await.expect("init db");
@@ -0,0 +1,273 @@
+//! Normalized activity feed built from action log rows.
+//!
+//! Action log rows carry a free-form `action_type` and optional JSON
+//! metadata. An [`ActivityEvent`] adds a typed kind, an icon hint, links to
+//! related entities found in the metadata, and a compact summary, so feed
+//! renderers need no extra lookups.
+
+use serde::Serialize;
+
+use super::action_log::ActionLogEntry;
+
+/// Longest log message carried into a summary.
+const MAX_SUMMARY_MESSAGE_CHARS: usize = 120;
+
+/// Broad category of an activity event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ActivityKind {
+    Reply,
+    Tweet,
+    Thread,
+    Discovery,
+    Mentions,
+    Approval,
+    Analytics,
+    Safety,
+    Policy,
+    Maintenance,
+    Other,
+}
+
+impl ActivityKind {
+    /// Icon hint for renderers.
+    pub fn icon(self) -> &'static str {
+        match self {
+            Self::Reply => "message",
+            Self::Tweet => "file",
+            Self::Thread => "book",
+            Self::Discovery => "search",
+            Self::Mentions => "bell",
+            Self::Approval => "check",
+            Self::Analytics => "chart",
+            Self::Safety => "shield",
+            Self::Policy => "lock",
+            Self::Maintenance => "refresh",
+            Self::Other => "activity",
+        }
+    }
+}
+
+/// Entities an activity event refers to. Fields are set when the row's
+/// metadata names them.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct ActivityLinks {
+    /// Approval queue item ID.
+    pub approval_id: Option<i64>,
+    /// `replies_sent` row ID.
+    pub reply_id: Option<i64>,
+    /// X tweet ID of the posted content.
+    pub tweet_id: Option<String>,
+    /// Public URL of `tweet_id`.
+    pub tweet_url: Option<String>,
+    /// Public URL of the tweet that was replied to.
+    pub in_reply_to_url: Option<String>,
+}
+
+/// An action log row with its kind, icon, links, and summary.
+#[derive(Debug, Clone, Serialize)]
+pub struct ActivityEvent {
+    #[serde(flatten)]
+    pub entry: ActionLogEntry,
+    pub kind: ActivityKind,
+    pub icon: &'static str,
+    pub summary: String,
+    pub links: ActivityLinks,
+}
+
+impl From<ActionLogEntry> for ActivityEvent {
+    fn from(entry: ActionLogEntry) -> Self {
+        let kind = kind_of(&entry.action_type);
+        let links = links_from_metadata(entry.metadata.as_deref());
+        let summary = summarize(&entry);
+        Self {
+            kind,
+            icon: kind.icon(),
+            summary,
+            links,
+            entry,
+        }
+    }
+}
+
+/// Public URL of a tweet.
+pub fn tweet_url(tweet_id: &str) -> String {
+    format!("https://x.com/i/status/{tweet_id}")
+}
+
+/// Classify an action type.
+pub fn kind_of(action_type: &str) -> ActivityKind {
+    match action_type {
+        t if t.starts_with("approval_") => ActivityKind::Approval,
+        t if t.ends_with("_posted") => ActivityKind::Approval,
+        "reply" | "discovery_reply" | "mention_reply" | "target_reply" | "reddit_comment" => {
+            ActivityKind::Reply
+        }
+        "tweet" => ActivityKind::Tweet,
+        "thread" => ActivityKind::Thread,
+        "search" | "ingest_candidates" | "target_follow" => ActivityKind::Discovery,
+        "mention_check" => ActivityKind::Mentions,
+        "analytics" | "threshold_calibration" | "visibility_check" => ActivityKind::Analytics,
+        "kill_switch" | "post_dedup" => ActivityKind::Safety,
+        "mcp_policy" => ActivityKind::Policy,
+        "cleanup" | "auth_refresh" => ActivityKind::Maintenance,
+        _ => ActivityKind::Other,
+    }
+}
+
+/// Human-readable name of an action type.
+fn label(action_type: &str) -> String {
+    let known = match action_type {
+        "reply" => "Reply",
+        "discovery_reply" => "Discovery reply",
+        "mention_reply" => "Mention reply",
+        "target_reply" => "Target reply",
+        "reddit_comment" => "Reddit comment",
+        "mention_check" => "Mention check",
+        "approval_approved" => "Approved",
+        "approval_rejected" => "Rejected",
+        "approval_edited" => "Edited",
+        "approval_deleted" => "Deleted",
+        "approval_restored" => "Restored",
+        "approval_batch_approved" => "Batch approved",
+        "threshold_calibration" => "Threshold calibration",
+        "post_dedup" => "Repeated post",
+        "mcp_policy" => "MCP policy",
+        "auth_refresh" => "Token refresh",
+        _ => "",
+    };
+    if !known.is_empty() {
+        return known.to_string();
+    }
+    if let Some(posted) = action_type.strip_suffix("_posted") {
+        return format!("Posted {}", posted.replace('_', " "));
+    }
+    let spaced = action_type.replace('_', " ");
+    let mut chars = spaced.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// One-line summary: label, status qualifier, and the (truncated) message.
+fn summarize(entry: &ActionLogEntry) -> String {
+    let label = label(&entry.action_type);
+    let head = match entry.status.as_str() {
+        "success" => label,
+        "failure" | "error" => format!("{label} failed"),
+        "skipped" => format!("{label} skipped"),
+        "dry_run" => format!("{label} (dry run)"),
+        "queued" => format!("{label} queued for approval"),
+        other => format!("{label} ({})", other.replace('_', " ")),
+    };
+    match entry.message.as_deref().map(str::trim) {
+        Some(message) if !message.is_empty() => {
+            let mut short: String = message.chars().take(MAX_SUMMARY_MESSAGE_CHARS).collect();
+            if short.len() < message.len() {
+                short.push('…');
+            }
+            format!("{head}: {short}")
+        }
+        _ => head,
+    }
+}
+
+/// Pull entity references out of a row's JSON metadata.
+fn links_from_metadata(metadata: Option<&str>) -> ActivityLinks {
+    let Some(value) = metadata.and_then(|m| serde_json::from_str::<serde_json::Value>(m).ok())
+    else {
+        return ActivityLinks::default();
+    };
+    let text = |key: &str| {
+        value
+            .get(key)
+            .and_then(|v| v.as_str())
+            .filter(|s| !s.is_empty())
+            .map(str::to_string)
+    };
+    let tweet_id = text("tweet_id");
+    ActivityLinks {
+        approval_id: value.get("approval_id").and_then(|v| v.as_i64()),
+        reply_id: value.get("reply_id").and_then(|v| v.as_i64()),
+        tweet_url: tweet_id.as_deref().map(tweet_url),
+        tweet_id,
+        in_reply_to_url: text("in_reply_to_id").as_deref().map(tweet_url),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(
+        action_type: &str,
+        status: &str,
+        message: &str,
+        metadata: Option<&str>,
+    ) -> ActionLogEntry {
+        ActionLogEntry {
+            id: 1,
+            action_type: action_type.to_string(),
+            status: status.to_string(),
+            message: Some(message.to_string()),
+            metadata: metadata.map(str::to_string),
+            created_at: "2026-03-01T12:00:00Z".to_string(),
+        }
+    }
+
+    #[test]
+    fn posted_approval_links_item_and_tweet() {
+        let event = ActivityEvent::from(entry(
+            "reply_posted",
+            "success",
+            "Posted approved item 7",
+            Some(r#"{"approval_id":7,"tweet_id":"123","in_reply_to_id":"99"}"#),
+        ));
+        assert_eq!(event.kind, ActivityKind::Approval);
+        assert_eq!(event.icon, "check");
+        assert_eq!(event.summary, "Posted reply: Posted approved item 7");
+        assert_eq!(event.links.approval_id, Some(7));
+        assert_eq!(
+            event.links.tweet_url.as_deref(),
+            Some("https://x.com/i/status/123")
+        );
+        assert_eq!(
+            event.links.in_reply_to_url.as_deref(),
+            Some("https://x.com/i/status/99")
+        );
+    }
+
+    #[test]
+    fn summaries_qualify_status_and_truncate() {
+        let skipped = ActivityEvent::from(entry(
+            "discovery_reply",
+            "skipped",
+            "Skipped @dev: already replied",
+            None,
+        ));
+        assert_eq!(skipped.kind, ActivityKind::Reply);
+        assert_eq!(
+            skipped.summary,
+            "Discovery reply skipped: Skipped @dev: already replied"
+        );
+        assert_eq!(skipped.links, ActivityLinks::default());
+
+        let long = "x".repeat(200);
+        let failed =
+            ActivityEvent::from(entry("some_new_action", "failure", &long, Some("not json")));
+        assert_eq!(failed.kind, ActivityKind::Other);
+        assert!(failed.summary.starts_with("Some new action failed: xxx"));
+        assert!(failed.summary.ends_with('…'));
+    }
+
+    #[test]
+    fn serializes_flat_with_raw_fields() {
+        let event = ActivityEvent::from(entry("tweet", "success", "Posted", None));
+        let json = serde_json::to_value(&event).unwrap();
+        assert_eq!(json["action_type"], "tweet");
+        assert_eq!(json["kind"], "tweet");
+        assert_eq!(json["icon"], "file");
+        assert!(json["links"]["tweet_url"].is_null());
+    }
+}
@@ -0,0 +1,139 @@
+//! Agent session records for safe-mode startup.
+//!
+//! `tuitbot run` opens a session row at startup, refreshes its heartbeat
+//! while running, and closes it on clean shutdown. Posting outcomes are
+//! counted on the open session of the posting process, so the next startup
+//! can tell whether the previous session crashed or kept failing to post.
+
+use super::DbPool;
+use crate::error::StorageError;
+
+/// One `tuitbot run` session.
+#[derive(Debug, Clone, PartialEq, Eq, sqlx::FromRow, serde::Serialize)]
+pub struct AgentSession {
+    pub id: i64,
+    pub pid: i64,
+    pub started_at: String,
+    pub heartbeat_at: String,
+    /// Set on clean shutdown; `None` while running or after a crash.
+    pub ended_at: Option<String>,
+    /// Posting failures since the last successful post.
+    pub consecutive_failures: i64,
+    pub last_error: Option<String>,
+}
+
+const SESSION_COLS: &str =
+    "id, pid, started_at, heartbeat_at, ended_at, consecutive_failures, last_error";
+
+/// Open a session for process `pid`. Returns the new session id.
+pub async fn start_session(pool: &DbPool, pid: u32) -> Result<i64, StorageError> {
+    let result = sqlx::query("INSERT INTO agent_sessions (pid) VALUES (?)")
+        .bind(i64::from(pid))
+        .execute(pool)
+        .await
+        .map_err(|e| StorageError::Query { source: e })?;
+    Ok(result.last_insert_rowid())
+}
+
+/// The most recent session started before session `id`.
+pub async fn get_previous_session(
+    pool: &DbPool,
+    id: i64,
+) -> Result<Option<AgentSession>, StorageError> {
+    sqlx::query_as::<_, AgentSession>(&format!(
+        "SELECT {SESSION_COLS} FROM agent_sessions WHERE id < ? ORDER BY id DESC LIMIT 1"
+    ))
+    .bind(id)
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })
+}
+
+/// Refresh the heartbeat of session `id`.
+pub async fn heartbeat(pool: &DbPool, id: i64) -> Result<(), StorageError> {
+    sqlx::query(
+        "UPDATE agent_sessions SET heartbeat_at = strftime('%Y-%m-%dT%H:%M:%SZ', 'now') \
+         WHERE id = ?",
+    )
+    .bind(id)
+    .execute(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+    Ok(())
+}
+
+/// Mark session `id` as cleanly ended.
+pub async fn end_session(pool: &DbPool, id: i64) -> Result<(), StorageError> {
+    sqlx::query(
+        "UPDATE agent_sessions SET ended_at = strftime('%Y-%m-%dT%H:%M:%SZ', 'now'), \
+             heartbeat_at = strftime('%Y-%m-%dT%H:%M:%SZ', 'now') \
+         WHERE id = ?",
+    )
+    .bind(id)
+    .execute(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+    Ok(())
+}
+
+/// Count a post on the open session of process `pid`: `error` is `None`
+/// for a successful post, which resets the failure streak. A no-op when the
+/// process has no open session (e.g. the API server or MCP).
+pub async fn record_post_outcome(
+    pool: &DbPool,
+    pid: u32,
+    error: Option<&str>,
+) -> Result<(), StorageError> {
+    sqlx::query(
+        "UPDATE agent_sessions SET \
+             consecutive_failures = CASE WHEN ?1 IS NULL THEN 0 \
+                                         ELSE consecutive_failures + 1 END, \
+             last_error = COALESCE(?1, last_error) \
+         WHERE id = (SELECT MAX(id) FROM agent_sessions WHERE pid = ?2 AND ended_at IS NULL)",
+    )
+    .bind(error)
+    .bind(i64::from(pid))
+    .execute(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::init_test_db;
+
+    #[tokio::test]
+    async fn failures_count_until_a_post_succeeds() {
+        let pool = init_test_db().await.expect("init db");
+        let first = start_session(&pool, 41).await.expect("start");
+        record_post_outcome(&pool, 41, Some("403 Forbidden"))
+            .await
+            .expect("record");
+        record_post_outcome(&pool, 41, Some("503"))
+            .await
+            .expect("record");
+        // Another process's posts leave this session alone.
+        record_post_outcome(&pool, 99, None).await.expect("record");
+
+        let second = start_session(&pool, 42).await.expect("start");
+        let previous = get_previous_session(&pool, second)
+            .await
+            .expect("previous")
+            .expect("session");
+        assert_eq!(previous.id, first);
+        assert_eq!(previous.consecutive_failures, 2);
+        assert_eq!(previous.last_error.as_deref(), Some("503"));
+        assert!(previous.ended_at.is_none());
+
+        record_post_outcome(&pool, 41, None).await.expect("record");
+        end_session(&pool, first).await.expect("end");
+        let previous = get_previous_session(&pool, second)
+            .await
+            .expect("previous")
+            .expect("session");
+        assert_eq!(previous.consecutive_failures, 0);
+        assert!(previous.ended_at.is_some());
+    }
+}
@@ -4,10 +4,13 @@
 //! and content score running averages.
 
 use super::accounts::DEFAULT_ACCOUNT_ID;
+use super::analytics_range::AnalyticsRange;
 use super::DbPool;
 use crate::error::StorageError;
 use chrono::{NaiveDate, Utc};
 
+pub use tuitbot_types::analytics::InspirationPerformance;
+
 // ============================================================================
 // Follower snapshots
 // ============================================================================
@@ -645,6 +648,61 @@ pub async fn get_optimal_posting_times(
     get_optimal_posting_times_for(pool, DEFAULT_ACCOUNT_ID).await
 }
 
+// ============================================================================
+// Inspiration comparison (trend-driven vs calendar-driven tweets)
+// ============================================================================
+
+/// Compare original tweets posted in `range` by inspiration for a specific account.
+pub async fn get_inspiration_performance_for(
+    pool: &DbPool,
+    account_id: &str,
+    range: &AnalyticsRange,
+) -> Result<Vec<InspirationPerformance>, StorageError> {
+    let (from, to) = range.bounds();
+    let rows: Vec<(String, i64, i64, f64, f64)> = sqlx::query_as(
+        "SELECT ot.inspiration, \
+                COUNT(*), \
+                COUNT(tp.tweet_id), \
+                COALESCE(AVG(tp.performance_score), 0.0), \
+                COALESCE(AVG(tp.impressions), 0.0) \
+         FROM original_tweets ot \
+         LEFT JOIN tweet_performance tp ON tp.tweet_id = ot.tweet_id \
+         WHERE ot.account_id = ? AND ot.status = 'sent' \
+           AND datetime(ot.created_at) >= ? AND datetime(ot.created_at) < ? \
+         GROUP BY ot.inspiration \
+         ORDER BY ot.inspiration",
+    )
+    .bind(account_id)
+    .bind(&from)
+    .bind(&to)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+
+    Ok(rows
+        .into_iter()
+        .map(
+            |(inspiration, post_count, measured_count, avg_performance, avg_impressions)| {
+                InspirationPerformance {
+                    inspiration,
+                    post_count,
+                    measured_count,
+                    avg_performance,
+                    avg_impressions,
+                }
+            },
+        )
+        .collect())
+}
+
+/// Compare original tweets posted in `range` by inspiration.
+pub async fn get_inspiration_performance(
+    pool: &DbPool,
+    range: &AnalyticsRange,
+) -> Result<Vec<InspirationPerformance>, StorageError> {
+    get_inspiration_performance_for(pool, DEFAULT_ACCOUNT_ID, range).await
+}
+
 // ============================================================================
 // Archetype & engagement score updates (Winning DNA pipeline)
 // ============================================================================
@@ -1110,6 +1168,50 @@ mod tests {
         assert_eq!(items[0].likes, 10);
     }
 
+    #[tokio::test]
+    async fn inspiration_performance_splits_trend_and_calendar() {
+        use crate::storage::threads::{
+            insert_original_tweet, OriginalTweet, INSPIRATION_CALENDAR, INSPIRATION_TREND,
+        };
+
+        let pool = init_test_db().await.expect("init db");
+        for (id, inspiration) in [
+            ("c1", INSPIRATION_CALENDAR),
+            ("c2", INSPIRATION_CALENDAR),
+            ("tr1", INSPIRATION_TREND),
+        ] {
+            let tweet = OriginalTweet {
+                id: 0,
+                tweet_id: Some(id.to_string()),
+                content: format!("tweet {id}"),
+                topic: Some("rust".to_string()),
+                llm_provider: None,
+                created_at: (Utc::now() - chrono::Duration::hours(1)).to_rfc3339(),
+                status: "sent".to_string(),
+                error_message: None,
+                inspiration: inspiration.to_string(),
+            };
+            insert_original_tweet(&pool, &tweet).await.expect("insert");
+        }
+        upsert_tweet_performance(&pool, "c1", 5, 0, 0, 500, 20.0)
+            .await
+            .expect("perf");
+        upsert_tweet_performance(&pool, "tr1", 20, 2, 3, 2000, 60.0)
+            .await
+            .expect("perf");
+
+        let rows = get_inspiration_performance(&pool, &AnalyticsRange::last_days(7))
+            .await
+            .expect("get");
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].inspiration, "calendar");
+        assert_eq!(rows[0].post_count, 2);
+        assert_eq!(rows[0].measured_count, 1);
+        assert_eq!(rows[1].inspiration, "trend");
+        assert_eq!(rows[1].avg_performance, 60.0);
+        assert_eq!(rows[1].avg_impressions, 2000.0);
+    }
+
     // ============================================================================
     // Winning DNA storage tests
     // ============================================================================
@@ -0,0 +1,413 @@
+//! Date-range and granularity queries over analytics tables.
+//!
+//! Buckets follower snapshots and performance measurements by hour, day,
+//! or ISO week in SQL, and compares each range against the preceding
+//! period of equal length so the dashboard can render delta cards.
+
+#[cfg(test)]
+mod tests;
+
+use chrono::{Duration, NaiveDate, NaiveDateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tuitbot_types::analytics::DateRange;
+
+use super::accounts::DEFAULT_ACCOUNT_ID;
+use super::DbPool;
+use crate::error::StorageError;
+
+/// Timestamp format matching SQLite's `datetime()` output.
+const SQL_DATETIME: &str = "%Y-%m-%d %H:%M:%S";
+
+/// Bucket size for time-series queries.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Granularity {
+    Hour,
+    #[default]
+    Day,
+    Week,
+}
+
+impl Granularity {
+    /// SQL expression bucketing `column` (weeks start on Monday).
+    fn bucket_expr(self, column: &str) -> String {
+        match self {
+            Self::Hour => format!("strftime('%Y-%m-%dT%H:00:00', {column})"),
+            Self::Day => format!("date({column})"),
+            Self::Week => format!("date({column}, 'weekday 0', '-6 days')"),
+        }
+    }
+}
+
+/// Invalid `from` / `to` parameters.
+#[derive(Debug, thiserror::Error)]
+pub enum RangeError {
+    #[error("invalid {field} '{value}': expected YYYY-MM-DD or an RFC 3339 timestamp")]
+    InvalidTimestamp { field: &'static str, value: String },
+    #[error("'from' must be before 'to'")]
+    Empty,
+}
+
+/// A half-open UTC time range `[from, to)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct AnalyticsRange {
+    pub from: NaiveDateTime,
+    pub to: NaiveDateTime,
+}
+
+impl AnalyticsRange {
+    /// The last `days` days up to now.
+    pub fn last_days(days: u32) -> Self {
+        let to = Utc::now().naive_utc();
+        Self {
+            from: to - Duration::days(i64::from(days)),
+            to,
+        }
+    }
+
+    /// Parse optional `from` / `to` parameters.
+    ///
+    /// Dates are inclusive: `to=2026-03-07` covers all of March 7th. A missing
+    /// `to` means now; a missing `from` means `default_days` before `to`.
+    pub fn parse(
+        from: Option<&str>,
+        to: Option<&str>,
+        default_days: u32,
+    ) -> Result<Self, RangeError> {
+        let to = match to {
+            Some(v) => parse_bound("to", v, true)?,
+            None => Utc::now().naive_utc(),
+        };
+        let from = match from {
+            Some(v) => parse_bound("from", v, false)?,
+            None => to - Duration::days(i64::from(default_days)),
+        };
+        if from >= to {
+            return Err(RangeError::Empty);
+        }
+        Ok(Self { from, to })
+    }
+
+    /// The preceding period of equal length.
+    pub fn previous(&self) -> Self {
+        Self {
+            from: self.from - (self.to - self.from),
+            to: self.from,
+        }
+    }
+
+    pub(crate) fn bounds(&self) -> (String, String) {
+        (
+            self.from.format(SQL_DATETIME).to_string(),
+            self.to.format(SQL_DATETIME).to_string(),
+        )
+    }
+}
+
+impl From<AnalyticsRange> for DateRange {
+    /// Formats bounds the way `AnalyticsRange` serializes them.
+    fn from(range: AnalyticsRange) -> Self {
+        const ISO: &str = "%Y-%m-%dT%H:%M:%S%.f";
+        Self {
+            from: range.from.format(ISO).to_string(),
+            to: range.to.format(ISO).to_string(),
+        }
+    }
+}
+
+fn parse_bound(
+    field: &'static str,
+    value: &str,
+    end_of_day: bool,
+) -> Result<NaiveDateTime, RangeError> {
+    if let Ok(ts) = chrono::DateTime::parse_from_rfc3339(value) {
+        return Ok(ts.naive_utc());
+    }
+    let date =
+        NaiveDate::parse_from_str(value, "%Y-%m-%d").map_err(|_| RangeError::InvalidTimestamp {
+            field,
+            value: value.to_string(),
+        })?;
+    let date = if end_of_day {
+        date + Duration::days(1)
+    } else {
+        date
+    };
+    Ok(date.and_hms_opt(0, 0, 0).expect("midnight is valid"))
+}
+
+/// A metric in the current period compared with the previous one.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct PeriodDelta {
+    pub current: f64,
+    pub previous: f64,
+    pub delta: f64,
+    /// Relative change; `None` when the previous value is zero.
+    pub delta_pct: Option<f64>,
+}
+
+impl PeriodDelta {
+    pub fn new(current: f64, previous: f64) -> Self {
+        let delta = current - previous;
+        Self {
+            current,
+            previous,
+            delta,
+            delta_pct: (previous != 0.0).then(|| delta / previous.abs() * 100.0),
+        }
+    }
+}
+
+// ============================================================================
+// Followers
+// ============================================================================
+
+/// Follower counts at the end of a bucket.
+#[derive(Debug, Clone, Serialize)]
+pub struct FollowerBucket {
+    pub bucket: String,
+    pub follower_count: i64,
+    pub following_count: i64,
+    pub tweet_count: i64,
+}
+
+/// Follower totals and growth versus the previous period.
+#[derive(Debug, Clone, Serialize)]
+pub struct FollowerRangeSummary {
+    /// Follower count at the end of each period.
+    pub followers: PeriodDelta,
+    /// Net follower change within each period.
+    pub net_change: PeriodDelta,
+}
+
+/// Follower snapshots in `range`, one per bucket (the last snapshot in it), oldest first.
+pub async fn get_follower_series_for(
+    pool: &DbPool,
+    account_id: &str,
+    range: &AnalyticsRange,
+    granularity: Granularity,
+) -> Result<Vec<FollowerBucket>, StorageError> {
+    let (from, to) = range.bounds();
+    let bucket = granularity.bucket_expr("snapshot_date");
+    let sql = format!(
+        "SELECT bucket, follower_count, following_count, tweet_count FROM ( \
+             SELECT {bucket} AS bucket, follower_count, following_count, tweet_count, \
+                    ROW_NUMBER() OVER (PARTITION BY {bucket} ORDER BY snapshot_date DESC) AS rn \
+             FROM follower_snapshots \
+             WHERE account_id = ? AND datetime(snapshot_date) >= ? AND datetime(snapshot_date) < ? \
+         ) WHERE rn = 1 ORDER BY bucket"
+    );
+    let rows: Vec<(String, i64, i64, i64)> = sqlx::query_as(&sql)
+        .bind(account_id)
+        .bind(&from)
+        .bind(&to)
+        .fetch_all(pool)
+        .await
+        .map_err(|e| StorageError::Query { source: e })?;
+
+    Ok(rows
+        .into_iter()
+        .map(|r| FollowerBucket {
+            bucket: r.0,
+            follower_count: r.1,
+            following_count: r.2,
+            tweet_count: r.3,
+        })
+        .collect())
+}
+
+/// Follower snapshots in `range`, bucketed by `granularity`.
+pub async fn get_follower_series(
+    pool: &DbPool,
+    range: &AnalyticsRange,
+    granularity: Granularity,
+) -> Result<Vec<FollowerBucket>, StorageError> {
+    get_follower_series_for(pool, DEFAULT_ACCOUNT_ID, range, granularity).await
+}
+
+/// Follower count and net change in `range` versus the previous period.
+pub async fn get_follower_range_summary_for(
+    pool: &DbPool,
+    account_id: &str,
+    range: &AnalyticsRange,
+) -> Result<FollowerRangeSummary, StorageError> {
+    let previous = range.previous();
+    let (start, end) = follower_endpoints(pool, account_id, range).await?;
+    let (prev_start, prev_end) = follower_endpoints(pool, account_id, &previous).await?;
+
+    Ok(FollowerRangeSummary {
+        followers: PeriodDelta::new(end as f64, prev_end as f64),
+        net_change: PeriodDelta::new((end - start) as f64, (prev_end - prev_start) as f64),
+    })
+}
+
+/// Follower count and net change in `range` versus the previous period.
+pub async fn get_follower_range_summary(
+    pool: &DbPool,
+    range: &AnalyticsRange,
+) -> Result<FollowerRangeSummary, StorageError> {
+    get_follower_range_summary_for(pool, DEFAULT_ACCOUNT_ID, range).await
+}
+
+/// Follower counts at the start and end of `range`.
+///
+/// The start is the last snapshot before the range, falling back to the
+/// first snapshot inside it; the end is the last snapshot inside it.
+/// Periods without snapshots report zero.
+async fn follower_endpoints(
+    pool: &DbPool,
+    account_id: &str,
+    range: &AnalyticsRange,
+) -> Result<(i64, i64), StorageError> {
+    let (from, to) = range.bounds();
+    let row: (Option<i64>, Option<i64>, Option<i64>) = sqlx::query_as(
+        "SELECT \
+           (SELECT follower_count FROM follower_snapshots \
+             WHERE account_id = ?1 AND datetime(snapshot_date) < ?2 \
+             ORDER BY snapshot_date DESC LIMIT 1), \
+           (SELECT follower_count FROM follower_snapshots \
+             WHERE account_id = ?1 AND datetime(snapshot_date) >= ?2 AND datetime(snapshot_date) < ?3 \
+             ORDER BY snapshot_date ASC LIMIT 1), \
+           (SELECT follower_count FROM follower_snapshots \
+             WHERE account_id = ?1 AND datetime(snapshot_date) >= ?2 AND datetime(snapshot_date) < ?3 \
+             ORDER BY snapshot_date DESC LIMIT 1)",
+    )
+    .bind(account_id)
+    .bind(&from)
+    .bind(&to)
+    .fetch_one(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+
+    let end = row.2.unwrap_or(0);
+    let start = row.0.or(row.1).unwrap_or(end);
+    Ok((start, end))
+}
+
+// ============================================================================
+// Performance
+// ============================================================================
+
+/// Reply and tweet performance measured within a bucket.
+#[derive(Debug, Clone, Serialize)]
+pub struct PerformanceBucket {
+    pub bucket: String,
+    pub replies: i64,
+    pub tweets: i64,
+    pub avg_reply_score: f64,
+    pub avg_tweet_score: f64,
+    pub likes: i64,
+    pub impressions: i64,
+}
+
+/// Performance totals in a range versus the previous period.
+#[derive(Debug, Clone, Serialize)]
+pub struct PerformanceRangeSummary {
+    pub measured_replies: PeriodDelta,
+    pub measured_tweets: PeriodDelta,
+    pub avg_reply_engagement: PeriodDelta,
+    pub avg_tweet_engagement: PeriodDelta,
+}
+
+/// Union of reply and tweet measurements in `[?2, ?3)` for account `?1`.
+const MEASUREMENTS: &str = "\
+    SELECT 'reply' AS kind, measured_at, performance_score, likes_received, impressions \
+      FROM reply_performance WHERE account_id = ?1 AND measured_at >= ?2 AND measured_at < ?3 \
+    UNION ALL \
+    SELECT 'tweet', measured_at, performance_score, likes_received, impressions \
+      FROM tweet_performance WHERE account_id = ?1 AND measured_at >= ?2 AND measured_at < ?3";
+
+/// Reply and tweet performance in `range`, bucketed by `granularity`, oldest first.
+pub async fn get_performance_series_for(
+    pool: &DbPool,
+    account_id: &str,
+    range: &AnalyticsRange,
+    granularity: Granularity,
+) -> Result<Vec<PerformanceBucket>, StorageError> {
+    let (from, to) = range.bounds();
+    let bucket = granularity.bucket_expr("measured_at");
+    let sql = format!(
+        "SELECT {bucket} AS bucket, \
+                SUM(kind = 'reply'), SUM(kind = 'tweet'), \
+                COALESCE(AVG(CASE WHEN kind = 'reply' THEN performance_score END), 0.0), \
+                COALESCE(AVG(CASE WHEN kind = 'tweet' THEN performance_score END), 0.0), \
+                COALESCE(SUM(likes_received), 0), COALESCE(SUM(impressions), 0) \
+         FROM ({MEASUREMENTS}) GROUP BY bucket ORDER BY bucket"
+    );
+    let rows: Vec<(String, i64, i64, f64, f64, i64, i64)> = sqlx::query_as(&sql)
+        .bind(account_id)
+        .bind(&from)
+        .bind(&to)
+        .fetch_all(pool)
+        .await
+        .map_err(|e| StorageError::Query { source: e })?;
+
+    Ok(rows
+        .into_iter()
+        .map(|r| PerformanceBucket {
+            bucket: r.0,
+            replies: r.1,
+            tweets: r.2,
+            avg_reply_score: r.3,
+            avg_tweet_score: r.4,
+            likes: r.5,
+            impressions: r.6,
+        })
+        .collect())
+}
+
+/// Reply and tweet performance in `range`, bucketed by `granularity`.
+pub async fn get_performance_series(
+    pool: &DbPool,
+    range: &AnalyticsRange,
+    granularity: Granularity,
+) -> Result<Vec<PerformanceBucket>, StorageError> {
+    get_performance_series_for(pool, DEFAULT_ACCOUNT_ID, range, granularity).await
+}
+
+/// Performance totals in `range` versus the previous period.
+pub async fn get_performance_range_summary_for(
+    pool: &DbPool,
+    account_id: &str,
+    range: &AnalyticsRange,
+) -> Result<PerformanceRangeSummary, StorageError> {
+    let current = performance_totals(pool, account_id, range).await?;
+    let previous = performance_totals(pool, account_id, &range.previous()).await?;
+
+    Ok(PerformanceRangeSummary {
+        measured_replies: PeriodDelta::new(current.0 as f64, previous.0 as f64),
+        measured_tweets: PeriodDelta::new(current.1 as f64, previous.1 as f64),
+        avg_reply_engagement: PeriodDelta::new(current.2, previous.2),
+        avg_tweet_engagement: PeriodDelta::new(current.3, previous.3),
+    })
+}
+
+/// Performance totals in `range` versus the previous period.
+pub async fn get_performance_range_summary(
+    pool: &DbPool,
+    range: &AnalyticsRange,
+) -> Result<PerformanceRangeSummary, StorageError> {
+    get_performance_range_summary_for(pool, DEFAULT_ACCOUNT_ID, range).await
+}
+
+/// `(replies, tweets, avg reply score, avg tweet score)` measured in `range`.
+async fn performance_totals(
+    pool: &DbPool,
+    account_id: &str,
+    range: &AnalyticsRange,
+) -> Result<(i64, i64, f64, f64), StorageError> {
+    let (from, to) = range.bounds();
+    let sql = format!(
+        "SELECT COALESCE(SUM(kind = 'reply'), 0), COALESCE(SUM(kind = 'tweet'), 0), \
+                COALESCE(AVG(CASE WHEN kind = 'reply' THEN performance_score END), 0.0), \
+                COALESCE(AVG(CASE WHEN kind = 'tweet' THEN performance_score END), 0.0) \
+         FROM ({MEASUREMENTS})"
+    );
+    sqlx::query_as(&sql)
+        .bind(account_id)
+        .bind(&from)
+        .bind(&to)
+        .fetch_one(pool)
+        .await
+        .map_err(|e| StorageError::Query { source: e })
+}
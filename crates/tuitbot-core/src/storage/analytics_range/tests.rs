@@ -0,0 +1,138 @@
+use super::*;
+use crate::storage::init_test_db;
+
+fn at(value: &str) -> NaiveDateTime {
+    NaiveDateTime::parse_from_str(value, SQL_DATETIME).unwrap()
+}
+
+async fn snapshot(pool: &DbPool, date: &str, followers: i64) {
+    sqlx::query(
+        "INSERT INTO follower_snapshots (snapshot_date, follower_count, following_count, tweet_count) \
+         VALUES (?, ?, 10, 100)",
+    )
+    .bind(date)
+    .bind(followers)
+    .execute(pool)
+    .await
+    .unwrap();
+}
+
+async fn reply_measured(pool: &DbPool, id: &str, at: &str, score: f64) {
+    sqlx::query(
+        "INSERT INTO reply_performance (reply_id, likes_received, impressions, performance_score, measured_at) \
+         VALUES (?, 2, 100, ?, ?)",
+    )
+    .bind(id)
+    .bind(score)
+    .bind(at)
+    .execute(pool)
+    .await
+    .unwrap();
+}
+
+#[test]
+fn parse_range_dates_are_inclusive() {
+    let range = AnalyticsRange::parse(Some("2026-03-01"), Some("2026-03-07"), 7).unwrap();
+    assert_eq!(range.from, at("2026-03-01 00:00:00"));
+    assert_eq!(range.to, at("2026-03-08 00:00:00"));
+    assert_eq!(range.previous().from, at("2026-02-22 00:00:00"));
+    assert_eq!(range.previous().to, range.from);
+
+    let rfc = AnalyticsRange::parse(
+        Some("2026-03-01T12:00:00Z"),
+        Some("2026-03-02T00:00:00Z"),
+        7,
+    )
+    .unwrap();
+    assert_eq!(rfc.from, at("2026-03-01 12:00:00"));
+
+    assert!(matches!(
+        AnalyticsRange::parse(Some("yesterday"), None, 7),
+        Err(RangeError::InvalidTimestamp { field: "from", .. })
+    ));
+    assert!(matches!(
+        AnalyticsRange::parse(Some("2026-03-07"), Some("2026-03-01"), 7),
+        Err(RangeError::Empty)
+    ));
+}
+
+#[test]
+fn date_range_matches_serialized_range() {
+    let range = AnalyticsRange {
+        from: at("2026-03-01 12:00:00"),
+        to: Utc::now().naive_utc(),
+    };
+    let serialized = serde_json::to_value(range).unwrap();
+    let body = DateRange::from(range);
+    assert_eq!(body.from, "2026-03-01T12:00:00");
+    assert_eq!(serialized["from"], body.from);
+    assert_eq!(serialized["to"], body.to);
+}
+
+#[test]
+fn period_delta_percent() {
+    let d = PeriodDelta::new(150.0, 100.0);
+    assert_eq!(d.delta, 50.0);
+    assert_eq!(d.delta_pct, Some(50.0));
+    assert_eq!(PeriodDelta::new(5.0, 0.0).delta_pct, None);
+}
+
+#[tokio::test]
+async fn follower_series_and_summary() {
+    let pool = init_test_db().await.expect("init db");
+    // Previous period: Feb 22 - Feb 28. Current: Mar 1 - Mar 7.
+    snapshot(&pool, "2026-02-22", 90).await;
+    snapshot(&pool, "2026-02-28", 100).await;
+    snapshot(&pool, "2026-03-02", 110).await; // Monday
+    snapshot(&pool, "2026-03-03", 115).await;
+    snapshot(&pool, "2026-03-07", 130).await;
+
+    let range = AnalyticsRange::parse(Some("2026-03-01"), Some("2026-03-07"), 7).unwrap();
+
+    let daily = get_follower_series(&pool, &range, Granularity::Day)
+        .await
+        .unwrap();
+    assert_eq!(daily.len(), 3);
+    assert_eq!(daily[0].bucket, "2026-03-02");
+
+    let weekly = get_follower_series(&pool, &range, Granularity::Week)
+        .await
+        .unwrap();
+    assert_eq!(weekly.len(), 1);
+    assert_eq!(weekly[0].bucket, "2026-03-02");
+    assert_eq!(weekly[0].follower_count, 130, "last snapshot in bucket");
+
+    let summary = get_follower_range_summary(&pool, &range).await.unwrap();
+    assert_eq!(summary.followers.current, 130.0);
+    assert_eq!(summary.followers.previous, 100.0);
+    assert_eq!(summary.net_change.current, 30.0);
+    assert_eq!(summary.net_change.previous, 10.0);
+    assert_eq!(summary.net_change.delta, 20.0);
+}
+
+#[tokio::test]
+async fn performance_series_and_summary() {
+    let pool = init_test_db().await.expect("init db");
+    reply_measured(&pool, "old", "2026-02-25 09:00:00", 10.0).await;
+    reply_measured(&pool, "a", "2026-03-02 09:15:00", 20.0).await;
+    reply_measured(&pool, "b", "2026-03-02 09:45:00", 40.0).await;
+    reply_measured(&pool, "c", "2026-03-02 14:00:00", 60.0).await;
+
+    let range = AnalyticsRange::parse(Some("2026-03-01"), Some("2026-03-07"), 7).unwrap();
+
+    let hourly = get_performance_series(&pool, &range, Granularity::Hour)
+        .await
+        .unwrap();
+    assert_eq!(hourly.len(), 2);
+    assert_eq!(hourly[0].bucket, "2026-03-02T09:00:00");
+    assert_eq!(hourly[0].replies, 2);
+    assert_eq!(hourly[0].avg_reply_score, 30.0);
+    assert_eq!(hourly[0].likes, 4);
+
+    let summary = get_performance_range_summary(&pool, &range).await.unwrap();
+    assert_eq!(summary.measured_replies.current, 3.0);
+    assert_eq!(summary.measured_replies.previous, 1.0);
+    assert_eq!(summary.avg_reply_engagement.current, 40.0);
+    assert_eq!(summary.avg_reply_engagement.previous, 10.0);
+    assert_eq!(summary.measured_tweets.current, 0.0);
+}
@@ -0,0 +1,339 @@
+//! Computed metrics over the approval queue.
+//!
+//! Summarizes how the review loop performs for items enqueued within a
+//! range: time from enqueue to review, approval versus rejection rates by
+//! action type, topic, and ingestion source, per-reviewer throughput, and how
+//! much of the queue expired without a decision.
+
+use serde::Serialize;
+
+use super::accounts::DEFAULT_ACCOUNT_ID;
+use super::analytics_range::AnalyticsRange;
+use super::DbPool;
+use crate::error::StorageError;
+
+/// Statuses that represent a human decision. Approved items become `posted`
+/// once published, so both count as approvals.
+const DECIDED: &str = "status IN ('approved', 'posted', 'rejected')";
+
+/// Filter for items enqueued in `[?2, ?3)` for account `?1`.
+const IN_RANGE: &str =
+    "account_id = ?1 AND datetime(created_at) >= ?2 AND datetime(created_at) < ?3";
+
+/// Approval queue metrics for a range.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ApprovalMetrics {
+    pub enqueued: i64,
+    pub pending: i64,
+    pub approved: i64,
+    pub rejected: i64,
+    pub expired: i64,
+    /// Approved share of decided items (0.0–1.0).
+    pub approval_rate: f64,
+    /// Expired share of items that left the queue (0.0–1.0).
+    pub expired_share: f64,
+    /// Median seconds from enqueue to review; `None` when nothing was reviewed.
+    pub median_review_secs: Option<f64>,
+    pub by_action_type: Vec<ApprovalBreakdown>,
+    pub by_topic: Vec<ApprovalBreakdown>,
+    /// Decisions by ingestion source; loop-generated items are `(none)`.
+    pub by_source: Vec<ApprovalBreakdown>,
+    pub reviewers: Vec<ReviewerThroughput>,
+}
+
+/// Decision counts for one action type, topic, or source.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct ApprovalBreakdown {
+    pub key: String,
+    pub approved: i64,
+    pub rejected: i64,
+    pub approval_rate: f64,
+}
+
+/// Decisions made by one reviewer.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct ReviewerThroughput {
+    pub reviewer: String,
+    pub reviewed: i64,
+    pub approved: i64,
+    pub rejected: i64,
+    pub avg_review_secs: f64,
+}
+
+/// Compute approval queue metrics for items enqueued in `range` for a specific account.
+pub async fn get_approval_metrics_for(
+    pool: &DbPool,
+    account_id: &str,
+    range: &AnalyticsRange,
+) -> Result<ApprovalMetrics, StorageError> {
+    let (from, to) = range.bounds();
+
+    let counts: (i64, i64, i64, i64, i64) = sqlx::query_as(&format!(
+        "SELECT COUNT(*), \
+            COALESCE(SUM(status = 'pending'), 0), \
+            COALESCE(SUM(status IN ('approved', 'posted')), 0), \
+            COALESCE(SUM(status = 'rejected'), 0), \
+            COALESCE(SUM(status = 'expired'), 0) \
+         FROM approval_queue WHERE {IN_RANGE}"
+    ))
+    .bind(account_id)
+    .bind(&from)
+    .bind(&to)
+    .fetch_one(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+    let (enqueued, pending, approved, rejected, expired) = counts;
+
+    let durations: Vec<(f64,)> = sqlx::query_as(&format!(
+        "SELECT (julianday(reviewed_at) - julianday(created_at)) * 86400.0 \
+         FROM approval_queue \
+         WHERE {IN_RANGE} AND {DECIDED} AND reviewed_at IS NOT NULL \
+         ORDER BY 1"
+    ))
+    .bind(account_id)
+    .bind(&from)
+    .bind(&to)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+
+    Ok(ApprovalMetrics {
+        enqueued,
+        pending,
+        approved,
+        rejected,
+        expired,
+        approval_rate: ratio(approved, approved + rejected),
+        expired_share: ratio(expired, approved + rejected + expired),
+        median_review_secs: median(&durations.into_iter().map(|d| d.0).collect::<Vec<_>>()),
+        by_action_type: breakdown(pool, account_id, &from, &to, "action_type").await?,
+        by_topic: breakdown(pool, account_id, &from, &to, "topic").await?,
+        by_source: breakdown(pool, account_id, &from, &to, "source").await?,
+        reviewers: reviewers(pool, account_id, &from, &to).await?,
+    })
+}
+
+/// Compute approval queue metrics for items enqueued in `range`.
+pub async fn get_approval_metrics(
+    pool: &DbPool,
+    range: &AnalyticsRange,
+) -> Result<ApprovalMetrics, StorageError> {
+    get_approval_metrics_for(pool, DEFAULT_ACCOUNT_ID, range).await
+}
+
+/// Decision counts grouped by `column`, most decisions first.
+async fn breakdown(
+    pool: &DbPool,
+    account_id: &str,
+    from: &str,
+    to: &str,
+    column: &str,
+) -> Result<Vec<ApprovalBreakdown>, StorageError> {
+    let rows: Vec<(String, i64, i64)> = sqlx::query_as(&format!(
+        "SELECT COALESCE(NULLIF({column}, ''), '(none)') AS key, \
+            SUM(status IN ('approved', 'posted')) AS approved, \
+            SUM(status = 'rejected') AS rejected \
+         FROM approval_queue WHERE {IN_RANGE} AND {DECIDED} \
+         GROUP BY key ORDER BY approved + rejected DESC, key"
+    ))
+    .bind(account_id)
+    .bind(from)
+    .bind(to)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+
+    Ok(rows
+        .into_iter()
+        .map(|(key, approved, rejected)| ApprovalBreakdown {
+            key,
+            approved,
+            rejected,
+            approval_rate: ratio(approved, approved + rejected),
+        })
+        .collect())
+}
+
+/// Decisions grouped by reviewer, busiest first.
+async fn reviewers(
+    pool: &DbPool,
+    account_id: &str,
+    from: &str,
+    to: &str,
+) -> Result<Vec<ReviewerThroughput>, StorageError> {
+    let rows: Vec<(String, i64, i64, i64, f64)> = sqlx::query_as(&format!(
+        "SELECT COALESCE(NULLIF(reviewed_by, ''), 'unknown') AS reviewer, \
+            COUNT(*), \
+            SUM(status IN ('approved', 'posted')), \
+            SUM(status = 'rejected'), \
+            COALESCE(AVG((julianday(reviewed_at) - julianday(created_at)) * 86400.0), 0.0) \
+         FROM approval_queue WHERE {IN_RANGE} AND {DECIDED} \
+         GROUP BY reviewer ORDER BY COUNT(*) DESC, reviewer"
+    ))
+    .bind(account_id)
+    .bind(from)
+    .bind(to)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+
+    Ok(rows
+        .into_iter()
+        .map(
+            |(reviewer, reviewed, approved, rejected, avg_review_secs)| ReviewerThroughput {
+                reviewer,
+                reviewed,
+                approved,
+                rejected,
+                avg_review_secs,
+            },
+        )
+        .collect())
+}
+
+fn ratio(part: i64, whole: i64) -> f64 {
+    if whole > 0 {
+        part as f64 / whole as f64
+    } else {
+        0.0
+    }
+}
+
+/// Median of an ascending slice.
+fn median(sorted: &[f64]) -> Option<f64> {
+    let n = sorted.len();
+    match n {
+        0 => None,
+        _ if n % 2 == 1 => Some(sorted[n / 2]),
+        _ => Some((sorted[n / 2 - 1] + sorted[n / 2]) / 2.0),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::init_test_db;
+
+    async fn item(
+        pool: &DbPool,
+        action_type: &str,
+        topic: &str,
+        status: &str,
+        created_at: &str,
+        reviewed: Option<(&str, &str)>,
+    ) {
+        sqlx::query(
+            "INSERT INTO approval_queue \
+             (action_type, generated_content, topic, status, created_at, reviewed_at, reviewed_by) \
+             VALUES (?, 'text', ?, ?, ?, ?, ?)",
+        )
+        .bind(action_type)
+        .bind(topic)
+        .bind(status)
+        .bind(created_at)
+        .bind(reviewed.map(|r| r.0))
+        .bind(reviewed.map(|r| r.1))
+        .execute(pool)
+        .await
+        .unwrap();
+    }
+
+    #[test]
+    fn median_of_odd_and_even() {
+        assert_eq!(median(&[]), None);
+        assert_eq!(median(&[1.0, 5.0, 9.0]), Some(5.0));
+        assert_eq!(median(&[1.0, 3.0, 5.0, 9.0]), Some(4.0));
+    }
+
+    #[tokio::test]
+    async fn empty_queue_has_zero_metrics() {
+        let pool = init_test_db().await.expect("init db");
+        let range = AnalyticsRange::last_days(7);
+        let m = get_approval_metrics(&pool, &range).await.unwrap();
+        assert_eq!(m.enqueued, 0);
+        assert_eq!(m.approval_rate, 0.0);
+        assert!(m.median_review_secs.is_none());
+        assert!(m.reviewers.is_empty());
+    }
+
+    #[tokio::test]
+    async fn computes_rates_medians_and_breakdowns() {
+        let pool = init_test_db().await.expect("init db");
+        let t = "2026-03-02T10:00:00Z";
+        item(
+            &pool,
+            "reply",
+            "rust",
+            "approved",
+            t,
+            Some(("2026-03-02T10:01:00Z", "ana")),
+        )
+        .await;
+        item(
+            &pool,
+            "reply",
+            "rust",
+            "posted",
+            t,
+            Some(("2026-03-02T10:03:00Z", "ana")),
+        )
+        .await;
+        item(
+            &pool,
+            "reply",
+            "go",
+            "rejected",
+            t,
+            Some(("2026-03-02T10:10:00Z", "bo")),
+        )
+        .await;
+        item(
+            &pool,
+            "tweet",
+            "",
+            "expired",
+            t,
+            Some(("2026-03-04T10:00:00Z", "")),
+        )
+        .await;
+        item(&pool, "tweet", "rust", "pending", t, None).await;
+        // Outside the range.
+        item(
+            &pool,
+            "reply",
+            "rust",
+            "rejected",
+            "2026-02-01T10:00:00Z",
+            None,
+        )
+        .await;
+
+        let range = AnalyticsRange::parse(Some("2026-03-01"), Some("2026-03-07"), 7).unwrap();
+        let m = get_approval_metrics(&pool, &range).await.unwrap();
+
+        assert_eq!(m.enqueued, 5);
+        assert_eq!(m.pending, 1);
+        assert_eq!(m.approved, 2);
+        assert_eq!(m.rejected, 1);
+        assert_eq!(m.expired, 1);
+        assert!((m.approval_rate - 2.0 / 3.0).abs() < 1e-9);
+        assert!((m.expired_share - 0.25).abs() < 1e-9);
+        // Review times 60s, 180s, 600s; expired items are excluded.
+        let median = m.median_review_secs.unwrap();
+        assert!((median - 180.0).abs() < 0.01, "median was {median}");
+
+        assert_eq!(m.by_action_type.len(), 1);
+        assert_eq!(m.by_action_type[0].key, "reply");
+        assert_eq!(m.by_topic[0].key, "rust");
+        assert_eq!(m.by_topic[0].approval_rate, 1.0);
+        assert_eq!(m.by_topic[1].key, "go");
+        assert_eq!(m.by_source.len(), 1);
+        assert_eq!(m.by_source[0].key, "(none)");
+
+        assert_eq!(m.reviewers[0].reviewer, "ana");
+        assert_eq!(m.reviewers[0].reviewed, 2);
+        assert!((m.reviewers[0].avg_review_secs - 120.0).abs() < 0.01);
+        assert_eq!(m.reviewers[1].reviewer, "bo");
+        assert_eq!(m.reviewers[1].rejected, 1);
+    }
+}
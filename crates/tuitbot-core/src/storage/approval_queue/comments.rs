@@ -0,0 +1,185 @@
+//! Reviewer comment threads on approval items.
+//!
+//! Any number of comments can be left on an item; a comment with a
+//! `parent_id` replies to another comment on the same item. Item payloads
+//! carry the most recent few via [`attach_latest_comments`].
+
+use std::collections::HashMap;
+
+use super::ApprovalItem;
+use crate::error::StorageError;
+use crate::storage::DbPool;
+
+/// Comments included with each item in list payloads.
+pub const LATEST_COMMENTS: usize = 3;
+
+pub use tuitbot_types::approval::ApprovalComment;
+
+/// `id, approval_id, parent_id, author, body, created_at`
+type CommentRow = (i64, i64, Option<i64>, String, String, String);
+
+fn comment_from_row(
+    (id, approval_id, parent_id, author, body, created_at): CommentRow,
+) -> ApprovalComment {
+    ApprovalComment {
+        id,
+        approval_id,
+        parent_id,
+        author,
+        body,
+        created_at,
+    }
+}
+
+/// Add a comment to an approval item.
+///
+/// Returns `None` when `parent_id` does not name a comment on the same item.
+pub async fn add_comment(
+    pool: &DbPool,
+    approval_id: i64,
+    parent_id: Option<i64>,
+    author: &str,
+    body: &str,
+) -> Result<Option<ApprovalComment>, StorageError> {
+    let row = sqlx::query_as::<_, CommentRow>(
+        "INSERT INTO approval_comments (approval_id, parent_id, author, body) \
+         SELECT ?1, ?2, ?3, ?4 \
+         WHERE ?2 IS NULL OR EXISTS \
+             (SELECT 1 FROM approval_comments WHERE id = ?2 AND approval_id = ?1) \
+         RETURNING id, approval_id, parent_id, author, body, created_at",
+    )
+    .bind(approval_id)
+    .bind(parent_id)
+    .bind(author)
+    .bind(body)
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+    Ok(row.map(comment_from_row))
+}
+
+/// Get all comments on an approval item, oldest first.
+pub async fn get_comments(
+    pool: &DbPool,
+    approval_id: i64,
+) -> Result<Vec<ApprovalComment>, StorageError> {
+    let rows = sqlx::query_as::<_, CommentRow>(
+        "SELECT id, approval_id, parent_id, author, body, created_at \
+         FROM approval_comments WHERE approval_id = ? ORDER BY created_at ASC, id ASC",
+    )
+    .bind(approval_id)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+    Ok(rows.into_iter().map(comment_from_row).collect())
+}
+
+/// Fill `latest_comments` on each item with its [`LATEST_COMMENTS`] most
+/// recent comments, oldest first.
+pub async fn attach_latest_comments(
+    pool: &DbPool,
+    items: &mut [ApprovalItem],
+) -> Result<(), StorageError> {
+    if items.is_empty() {
+        return Ok(());
+    }
+
+    let placeholders = vec!["?"; items.len()].join(", ");
+    let sql = format!(
+        "SELECT id, approval_id, parent_id, author, body, created_at FROM ( \
+             SELECT *, ROW_NUMBER() OVER ( \
+                 PARTITION BY approval_id ORDER BY created_at DESC, id DESC) AS rank \
+             FROM approval_comments WHERE approval_id IN ({placeholders})) \
+         WHERE rank <= ? ORDER BY created_at ASC, id ASC"
+    );
+    let mut query = sqlx::query_as::<_, CommentRow>(&sql);
+    for item in items.iter() {
+        query = query.bind(item.id);
+    }
+    let comments = query
+        .bind(LATEST_COMMENTS as i64)
+        .fetch_all(pool)
+        .await
+        .map_err(|e| StorageError::Query { source: e })?;
+
+    let mut by_item: HashMap<i64, Vec<ApprovalComment>> = HashMap::new();
+    for comment in comments.into_iter().map(comment_from_row) {
+        by_item
+            .entry(comment.approval_id)
+            .or_default()
+            .push(comment);
+    }
+    for item in items.iter_mut() {
+        item.latest_comments = by_item.remove(&item.id).unwrap_or_default();
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::approval_queue::{enqueue, get_pending};
+    use crate::storage::init_test_db;
+
+    async fn enqueue_tweet(pool: &DbPool, content: &str) -> i64 {
+        enqueue(pool, "tweet", "", "", content, "Rust", "", 0.0, "[]")
+            .await
+            .expect("enqueue")
+    }
+
+    #[tokio::test]
+    async fn replies_must_stay_on_the_same_item() {
+        let pool = init_test_db().await.expect("init db");
+        let first = enqueue_tweet(&pool, "Ownership makes refactors safe").await;
+        let second = enqueue_tweet(&pool, "Async closures finally landed").await;
+
+        let root = add_comment(&pool, first, None, "alice", "Tone is too salesy")
+            .await
+            .expect("add")
+            .expect("root comment");
+        let reply = add_comment(&pool, first, Some(root.id), "bob", "Agreed, trimming")
+            .await
+            .expect("add")
+            .expect("reply");
+        assert_eq!(reply.parent_id, Some(root.id));
+
+        let stray = add_comment(&pool, second, Some(root.id), "bob", "Wrong item")
+            .await
+            .expect("add");
+        assert!(stray.is_none());
+
+        let thread = get_comments(&pool, first).await.expect("comments");
+        assert_eq!(thread, vec![root, reply]);
+        assert!(get_comments(&pool, second)
+            .await
+            .expect("comments")
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn items_carry_their_latest_comments() {
+        let pool = init_test_db().await.expect("init db");
+        let busy = enqueue_tweet(&pool, "Ownership makes refactors safe").await;
+        let quiet = enqueue_tweet(&pool, "Async closures finally landed").await;
+        for n in 0..5 {
+            add_comment(&pool, busy, None, "alice", &format!("note {n}"))
+                .await
+                .expect("add");
+        }
+
+        let mut items = get_pending(&pool).await.expect("pending");
+        attach_latest_comments(&pool, &mut items)
+            .await
+            .expect("attach");
+
+        let busy_item = items.iter().find(|i| i.id == busy).expect("busy");
+        let bodies: Vec<&str> = busy_item
+            .latest_comments
+            .iter()
+            .map(|c| c.body.as_str())
+            .collect();
+        assert_eq!(bodies, ["note 2", "note 3", "note 4"]);
+        let quiet_item = items.iter().find(|i| i.id == quiet).expect("quiet");
+        assert!(quiet_item.latest_comments.is_empty());
+    }
+}
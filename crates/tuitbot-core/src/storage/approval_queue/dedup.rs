@@ -0,0 +1,228 @@
+//! Duplicate collapse at enqueue time.
+//!
+//! The discovery loop can queue two near-identical replies to the same
+//! conversation when a tweet matches several keywords. A newly enqueued item
+//! is compared against pending items of the same action type; on a match the
+//! lower-scored item is marked `collapsed` and points at the one kept.
+
+use crate::error::StorageError;
+use crate::safety::dedup::{jaccard_similarity, tokenize};
+use crate::storage::DbPool;
+
+/// Word-set similarity at or above which two items are duplicates.
+const DUPLICATE_SIMILARITY: f64 = 0.8;
+
+/// Texts shorter than this many words only collapse on an exact match.
+const MIN_SIMILARITY_WORDS: usize = 5;
+
+/// Result of collapsing a duplicate pair.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) struct Collapse {
+    /// The item kept pending.
+    pub kept: i64,
+    /// The item marked `collapsed`.
+    pub collapsed: i64,
+}
+
+/// Collapse item `id` with a pending duplicate, if one exists.
+///
+/// Items are duplicates when they share a non-empty `target_tweet_id` or
+/// their content is identical or highly similar. The higher-scored item is
+/// kept; ties keep the older item.
+pub(super) async fn collapse_duplicates_for(
+    pool: &DbPool,
+    account_id: &str,
+    id: i64,
+) -> Result<Option<Collapse>, StorageError> {
+    let (action_type, target, content, score): (String, String, String, f64) = sqlx::query_as(
+        "SELECT action_type, COALESCE(target_tweet_id, ''), generated_content, score \
+         FROM approval_queue WHERE id = ? AND account_id = ?",
+    )
+    .bind(id)
+    .bind(account_id)
+    .fetch_one(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+
+    let candidates: Vec<(i64, String, String, f64)> = sqlx::query_as(
+        "SELECT id, COALESCE(target_tweet_id, ''), generated_content, score \
+         FROM approval_queue \
+         WHERE account_id = ? AND status = 'pending' AND action_type = ? AND id != ? \
+         AND deleted_at IS NULL \
+         ORDER BY created_at ASC, id ASC",
+    )
+    .bind(account_id)
+    .bind(&action_type)
+    .bind(id)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+
+    let tokens = tokenize(&content);
+    let Some((existing, _, _, existing_score)) = candidates
+        .into_iter()
+        .find(|c| (!target.is_empty() && c.1 == target) || is_similar(&content, &tokens, &c.2))
+    else {
+        return Ok(None);
+    };
+
+    let collapse = if score > existing_score {
+        Collapse {
+            kept: id,
+            collapsed: existing,
+        }
+    } else {
+        Collapse {
+            kept: existing,
+            collapsed: id,
+        }
+    };
+
+    sqlx::query(
+        "UPDATE approval_queue SET status = 'collapsed', collapsed_into = ?1 \
+         WHERE id = ?2 AND account_id = ?3",
+    )
+    .bind(collapse.kept)
+    .bind(collapse.collapsed)
+    .bind(account_id)
+    .execute(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+
+    // Items previously collapsed into the loser now point at the kept item.
+    sqlx::query(
+        "UPDATE approval_queue SET collapsed_into = ?1 \
+         WHERE collapsed_into = ?2 AND account_id = ?3",
+    )
+    .bind(collapse.kept)
+    .bind(collapse.collapsed)
+    .bind(account_id)
+    .execute(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+
+    tracing::info!(
+        kept = collapse.kept,
+        collapsed = collapse.collapsed,
+        "Collapsed duplicate approval item"
+    );
+    Ok(Some(collapse))
+}
+
+fn is_similar(content: &str, tokens: &std::collections::HashSet<String>, other: &str) -> bool {
+    if content == other {
+        return true;
+    }
+    if tokens.len() < MIN_SIMILARITY_WORDS {
+        return false;
+    }
+    jaccard_similarity(tokens, &tokenize(other)) >= DUPLICATE_SIMILARITY
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{enqueue, get_by_id, get_pending};
+    use crate::storage::init_test_db;
+
+    #[tokio::test]
+    async fn same_target_keeps_higher_score() {
+        let pool = init_test_db().await.expect("init db");
+        let low = enqueue(
+            &pool,
+            "reply",
+            "t1",
+            "@u",
+            "First take",
+            "Rust",
+            "",
+            40.0,
+            "[]",
+        )
+        .await
+        .expect("enqueue");
+        let high = enqueue(
+            &pool,
+            "reply",
+            "t1",
+            "@u",
+            "Second take",
+            "Go",
+            "",
+            70.0,
+            "[]",
+        )
+        .await
+        .expect("enqueue");
+
+        let pending = get_pending(&pool).await.expect("pending");
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].id, high);
+
+        let collapsed = get_by_id(&pool, low).await.expect("get").expect("found");
+        assert_eq!(collapsed.status, "collapsed");
+        assert_eq!(collapsed.collapsed_into, Some(high));
+    }
+
+    #[tokio::test]
+    async fn similar_content_keeps_existing_on_tie() {
+        let pool = init_test_db().await.expect("init db");
+        let text = "Rust ownership makes concurrent code far easier to reason about";
+        let first = enqueue(&pool, "reply", "t1", "@a", text, "Rust", "", 50.0, "[]")
+            .await
+            .expect("enqueue");
+        let second = enqueue(
+            &pool,
+            "reply",
+            "t2",
+            "@b",
+            "Rust ownership makes concurrent code far easier to reason about!",
+            "Rust",
+            "",
+            50.0,
+            "[]",
+        )
+        .await
+        .expect("enqueue");
+
+        let item = get_by_id(&pool, second).await.expect("get").expect("found");
+        assert_eq!(item.status, "collapsed");
+        assert_eq!(item.collapsed_into, Some(first));
+    }
+
+    #[tokio::test]
+    async fn distinct_items_are_kept() {
+        let pool = init_test_db().await.expect("init db");
+        enqueue(&pool, "reply", "t1", "@a", "Nice!", "Rust", "", 50.0, "[]")
+            .await
+            .expect("enqueue");
+        enqueue(&pool, "reply", "t2", "@b", "Great!", "Rust", "", 50.0, "[]")
+            .await
+            .expect("enqueue");
+        // Same target but a different action type is not a duplicate.
+        enqueue(&pool, "tweet", "t1", "", "Nice!", "Rust", "", 50.0, "[]")
+            .await
+            .expect("enqueue");
+
+        assert_eq!(get_pending(&pool).await.expect("pending").len(), 3);
+    }
+
+    #[tokio::test]
+    async fn chained_collapse_repoints_to_kept_item() {
+        let pool = init_test_db().await.expect("init db");
+        let a = enqueue(&pool, "reply", "t1", "@u", "A", "", "", 10.0, "[]")
+            .await
+            .expect("enqueue");
+        let b = enqueue(&pool, "reply", "t1", "@u", "B", "", "", 5.0, "[]")
+            .await
+            .expect("enqueue");
+        let c = enqueue(&pool, "reply", "t1", "@u", "C", "", "", 90.0, "[]")
+            .await
+            .expect("enqueue");
+
+        let a_item = get_by_id(&pool, a).await.expect("get").expect("found");
+        let b_item = get_by_id(&pool, b).await.expect("get").expect("found");
+        assert_eq!(a_item.collapsed_into, Some(c));
+        assert_eq!(b_item.collapsed_into, Some(c));
+        assert_eq!(get_pending(&pool).await.expect("pending")[0].id, c);
+    }
+}
@@ -1,15 +1,29 @@
 //! Storage operations for the approval queue.
 //!
-//! Provides CRUD operations for queuing posts for human review
-//! when `approval_mode` is enabled.
+//! Provides CRUD operations for queuing posts, and engagement actions
+//! such as likes and follows, for human review when `approval_mode` is
+//! enabled.
 
+mod comments;
+mod dedup;
 mod edit_history;
 mod queries;
+pub mod sla;
+mod soft_delete;
 #[cfg(test)]
 mod tests;
+mod transitions;
 
+pub use comments::{
+    add_comment, attach_latest_comments, get_comments, ApprovalComment, LATEST_COMMENTS,
+};
 pub use edit_history::{get_edit_history, record_edit, EditHistoryEntry};
 pub use queries::*;
+pub(crate) use soft_delete::purge_deleted_for;
+pub use soft_delete::{
+    get_deleted, get_deleted_for, restore, restore_for, soft_delete, soft_delete_for,
+};
+pub use transitions::*;
 
 /// Row type for approval queue queries (expanded with review and QA metadata).
 #[derive(Debug, Clone, sqlx::FromRow)]
@@ -38,6 +52,12 @@ struct ApprovalRow {
     qa_override_by: Option<String>,
     qa_override_note: Option<String>,
     qa_override_at: Option<String>,
+    media_validation: String,
+    collapsed_into: Option<i64>,
+    version: i64,
+    platform: String,
+    source: String,
+    priority: i64,
 }
 
 /// A pending item in the approval queue.
@@ -84,6 +104,48 @@ pub struct ApprovalItem {
     pub qa_override_note: Option<String>,
     /// Timestamp of override action.
     pub qa_override_at: Option<String>,
+    /// JSON-encoded per-file media validation results (recorded before upload).
+    #[serde(serialize_with = "serialize_json_string")]
+    pub media_validation: String,
+    /// For `collapsed` items, the duplicate that was kept instead.
+    pub collapsed_into: Option<i64>,
+    /// Incremented on every change; pass as `expected_version` to review
+    /// only the revision that was displayed.
+    pub version: i64,
+    /// Platform the item is posted to (`x`, `reddit`, or `external`).
+    pub platform: String,
+    /// External system that supplied the candidate, e.g. `zapier`. Empty for
+    /// items produced by Tuitbot's own loops.
+    pub source: String,
+    /// Review priority; raised each time a pending item breaches the
+    /// approval SLA. Higher sorts first.
+    pub priority: i64,
+    /// Most recent reviewer comments, oldest first. Empty unless filled by
+    /// [`attach_latest_comments`].
+    pub latest_comments: Vec<ApprovalComment>,
+}
+
+/// Platform tag for items posted to X.
+pub const PLATFORM_X: &str = "x";
+
+/// Platform tag for suggested Reddit comments.
+pub const PLATFORM_REDDIT: &str = "reddit";
+
+/// Platform tag for replies to ingested items that are not X posts. No poster
+/// picks these up; approved items are posted by hand.
+pub const PLATFORM_EXTERNAL: &str = "external";
+
+/// Action types that carry only a target and no generated content.
+///
+/// For `like` the target is a tweet ID; for `follow` it is a user ID. Both are
+/// stored in `target_tweet_id`.
+pub const ENGAGEMENT_ACTION_TYPES: &[&str] = &["like", "follow"];
+
+impl ApprovalItem {
+    /// Whether this item is a like or follow rather than content to post.
+    pub fn is_engagement(&self) -> bool {
+        ENGAGEMENT_ACTION_TYPES.contains(&self.action_type.as_str())
+    }
 }
 
 /// Serialize a JSON-encoded string as a raw JSON value.
@@ -127,6 +189,13 @@ impl From<ApprovalRow> for ApprovalItem {
             qa_override_by: r.qa_override_by,
             qa_override_note: r.qa_override_note,
             qa_override_at: r.qa_override_at,
+            media_validation: r.media_validation,
+            collapsed_into: r.collapsed_into,
+            version: r.version,
+            platform: r.platform,
+            source: r.source,
+            priority: r.priority,
+            latest_comments: Vec::new(),
         }
     }
 }
@@ -140,8 +209,4 @@ pub struct ApprovalStats {
 }
 
 /// Optional review metadata for approve/reject actions.
-#[derive(Debug, Clone, Default, serde::Deserialize)]
-pub struct ReviewAction {
-    pub actor: Option<String>,
-    pub notes: Option<String>,
-}
+pub use tuitbot_types::approval::ReviewAction;
@@ -1,18 +1,26 @@
 //! Query functions for the approval queue.
 
-use super::{ApprovalItem, ApprovalRow, ApprovalStats, ReviewAction};
+use super::{ApprovalItem, ApprovalRow, ApprovalStats, ReviewAction, PLATFORM_X};
 use crate::error::StorageError;
 use crate::storage::accounts::DEFAULT_ACCOUNT_ID;
+use crate::storage::pagination::{fetch_page, Conditions, Page, PageRequest};
 use crate::storage::DbPool;
 
 /// Standard SELECT columns for approval queue queries.
-const SELECT_COLS: &str = "id, action_type, target_tweet_id, target_author, \
+pub(super) const SELECT_COLS: &str = "id, action_type, target_tweet_id, target_author, \
     generated_content, topic, archetype, score, status, created_at, \
     COALESCE(media_paths, '[]') AS media_paths, reviewed_by, review_notes, reason, \
     COALESCE(detected_risks, '[]') AS detected_risks, COALESCE(qa_report, '{}') AS qa_report, \
     COALESCE(qa_hard_flags, '[]') AS qa_hard_flags, COALESCE(qa_soft_flags, '[]') AS qa_soft_flags, \
     COALESCE(qa_recommendations, '[]') AS qa_recommendations, COALESCE(qa_score, 0) AS qa_score, \
-    COALESCE(qa_requires_override, 0) AS qa_requires_override, qa_override_by, qa_override_note, qa_override_at";
+    COALESCE(qa_requires_override, 0) AS qa_requires_override, qa_override_by, qa_override_note, qa_override_at, \
+    COALESCE(media_validation, '[]') AS media_validation, collapsed_into, version, platform, source, \
+    priority";
+
+/// Review order for queue listings: escalated pending items first (see
+/// `approval_sla`), then oldest first.
+pub(super) const QUEUE_ORDER: &str =
+    "ORDER BY (CASE WHEN status = 'pending' THEN priority ELSE 0 END) DESC, created_at ASC";
 
 /// Insert a new item into the approval queue for a specific account.
 #[allow(clippy::too_many_arguments)]
@@ -74,6 +82,10 @@ pub async fn enqueue(
 }
 
 /// Insert a new item into the approval queue with optional reason and risks for a specific account.
+///
+/// If a pending item of the same action type targets the same tweet or has
+/// near-identical content, the lower-scored of the two is collapsed into the
+/// other. The returned id is always the newly inserted row.
 #[allow(clippy::too_many_arguments)]
 pub async fn enqueue_with_context_for(
     pool: &DbPool,
@@ -109,7 +121,72 @@ pub async fn enqueue_with_context_for(
     .await
     .map_err(|e| StorageError::Query { source: e })?;
 
-    Ok(result.last_insert_rowid())
+    let id = result.last_insert_rowid();
+    super::dedup::collapse_duplicates_for(pool, account_id, id).await?;
+    Ok(id)
+}
+
+/// Insert an item to be posted on `platform` for a specific account.
+///
+/// Used for non-X items such as suggested Reddit comments; `target_id` is
+/// the platform's ID of the thing being replied to.
+#[allow(clippy::too_many_arguments)]
+pub async fn enqueue_on_platform_for(
+    pool: &DbPool,
+    account_id: &str,
+    platform: &str,
+    action_type: &str,
+    target_id: &str,
+    target_author: &str,
+    generated_content: &str,
+    topic: &str,
+    score: f64,
+) -> Result<i64, StorageError> {
+    let result = sqlx::query(
+        "INSERT INTO approval_queue (account_id, platform, action_type, target_tweet_id, \
+         target_author, generated_content, topic, score) VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+    )
+    .bind(account_id)
+    .bind(platform)
+    .bind(action_type)
+    .bind(target_id)
+    .bind(target_author)
+    .bind(generated_content)
+    .bind(topic)
+    .bind(score)
+    .execute(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+
+    let id = result.last_insert_rowid();
+    super::dedup::collapse_duplicates_for(pool, account_id, id).await?;
+    Ok(id)
+}
+
+/// Insert an item to be posted on `platform`.
+#[allow(clippy::too_many_arguments)]
+pub async fn enqueue_on_platform(
+    pool: &DbPool,
+    platform: &str,
+    action_type: &str,
+    target_id: &str,
+    target_author: &str,
+    generated_content: &str,
+    topic: &str,
+    score: f64,
+) -> Result<i64, StorageError> {
+    enqueue_on_platform_for(
+        pool,
+        DEFAULT_ACCOUNT_ID,
+        platform,
+        action_type,
+        target_id,
+        target_author,
+        generated_content,
+        topic,
+        score,
+    )
+    .await
 }
 
 /// Insert a new item into the approval queue with optional reason and risks.
@@ -144,14 +221,15 @@ pub async fn enqueue_with_context(
     .await
 }
 
-/// Get all pending approval items for a specific account, ordered by creation time (oldest first).
+/// Get all pending approval items for a specific account, escalated items
+/// first, then by creation time (oldest first).
 pub async fn get_pending_for(
     pool: &DbPool,
     account_id: &str,
 ) -> Result<Vec<ApprovalItem>, StorageError> {
     let sql = format!(
         "SELECT {SELECT_COLS} FROM approval_queue \
-         WHERE status = 'pending' AND account_id = ? ORDER BY created_at ASC"
+         WHERE status = 'pending' AND account_id = ? AND deleted_at IS NULL {QUEUE_ORDER}"
     );
     let rows: Vec<ApprovalRow> = sqlx::query_as(&sql)
         .bind(account_id)
@@ -162,7 +240,7 @@ pub async fn get_pending_for(
     Ok(rows.into_iter().map(ApprovalItem::from).collect())
 }
 
-/// Get all pending approval items, ordered by creation time (oldest first).
+/// Get all pending approval items, escalated items first, then oldest first.
 pub async fn get_pending(pool: &DbPool) -> Result<Vec<ApprovalItem>, StorageError> {
     get_pending_for(pool, DEFAULT_ACCOUNT_ID).await
 }
@@ -170,7 +248,8 @@ pub async fn get_pending(pool: &DbPool) -> Result<Vec<ApprovalItem>, StorageErro
 /// Get the count of pending items for a specific account.
 pub async fn pending_count_for(pool: &DbPool, account_id: &str) -> Result<i64, StorageError> {
     let row: (i64,) = sqlx::query_as(
-        "SELECT COUNT(*) FROM approval_queue WHERE status = 'pending' AND account_id = ?",
+        "SELECT COUNT(*) FROM approval_queue \
+         WHERE status = 'pending' AND account_id = ? AND deleted_at IS NULL",
     )
     .bind(account_id)
     .fetch_one(pool)
@@ -193,7 +272,7 @@ pub async fn update_status_for(
     status: &str,
 ) -> Result<(), StorageError> {
     sqlx::query(
-        "UPDATE approval_queue SET status = ?, \
+        "UPDATE approval_queue SET status = ?, version = version + 1, \
          reviewed_at = strftime('%Y-%m-%dT%H:%M:%SZ', 'now') WHERE id = ? AND account_id = ?",
     )
     .bind(status)
@@ -211,78 +290,16 @@ pub async fn update_status(pool: &DbPool, id: i64, status: &str) -> Result<(), S
     update_status_for(pool, DEFAULT_ACCOUNT_ID, id, status).await
 }
 
-/// Update the status of an approval item with review metadata for a specific account.
-pub async fn update_status_with_review_for(
-    pool: &DbPool,
-    account_id: &str,
-    id: i64,
-    status: &str,
-    review: &ReviewAction,
-) -> Result<(), StorageError> {
-    sqlx::query(
-        "UPDATE approval_queue SET status = ?, \
-         reviewed_at = strftime('%Y-%m-%dT%H:%M:%SZ', 'now'), \
-         reviewed_by = ?, review_notes = ? WHERE id = ? AND account_id = ?",
-    )
-    .bind(status)
-    .bind(&review.actor)
-    .bind(&review.notes)
-    .bind(id)
-    .bind(account_id)
-    .execute(pool)
-    .await
-    .map_err(|e| StorageError::Query { source: e })?;
-
-    Ok(())
-}
-
-/// Update the status of an approval item with review metadata.
-pub async fn update_status_with_review(
-    pool: &DbPool,
-    id: i64,
-    status: &str,
-    review: &ReviewAction,
-) -> Result<(), StorageError> {
-    update_status_with_review_for(pool, DEFAULT_ACCOUNT_ID, id, status, review).await
-}
-
-/// Update the content and status of an approval item for a specific account (for edit-then-approve).
-pub async fn update_content_and_approve_for(
-    pool: &DbPool,
-    account_id: &str,
-    id: i64,
-    new_content: &str,
-) -> Result<(), StorageError> {
-    sqlx::query(
-        "UPDATE approval_queue SET generated_content = ?, status = 'approved', \
-         reviewed_at = strftime('%Y-%m-%dT%H:%M:%SZ', 'now') WHERE id = ? AND account_id = ?",
-    )
-    .bind(new_content)
-    .bind(id)
-    .bind(account_id)
-    .execute(pool)
-    .await
-    .map_err(|e| StorageError::Query { source: e })?;
-
-    Ok(())
-}
-
-/// Update the content and status of an approval item (for edit-then-approve).
-pub async fn update_content_and_approve(
-    pool: &DbPool,
-    id: i64,
-    new_content: &str,
-) -> Result<(), StorageError> {
-    update_content_and_approve_for(pool, DEFAULT_ACCOUNT_ID, id, new_content).await
-}
-
 /// Get a single approval item by ID for a specific account.
 pub async fn get_by_id_for(
     pool: &DbPool,
     account_id: &str,
     id: i64,
 ) -> Result<Option<ApprovalItem>, StorageError> {
-    let sql = format!("SELECT {SELECT_COLS} FROM approval_queue WHERE id = ? AND account_id = ?");
+    let sql = format!(
+        "SELECT {SELECT_COLS} FROM approval_queue \
+         WHERE id = ? AND account_id = ? AND deleted_at IS NULL"
+    );
     let row: Option<ApprovalRow> = sqlx::query_as(&sql)
         .bind(id)
         .bind(account_id)
@@ -305,7 +322,7 @@ pub async fn get_stats_for(pool: &DbPool, account_id: &str) -> Result<ApprovalSt
             COALESCE(SUM(CASE WHEN status = 'pending' THEN 1 ELSE 0 END), 0), \
             COALESCE(SUM(CASE WHEN status = 'approved' THEN 1 ELSE 0 END), 0), \
             COALESCE(SUM(CASE WHEN status = 'rejected' THEN 1 ELSE 0 END), 0) \
-         FROM approval_queue WHERE account_id = ?",
+         FROM approval_queue WHERE account_id = ? AND deleted_at IS NULL",
     )
     .bind(account_id)
     .fetch_one(pool)
@@ -343,7 +360,7 @@ pub async fn get_by_statuses_for(
         let sql = format!(
             "SELECT {SELECT_COLS} FROM approval_queue \
              WHERE account_id = ? AND status IN ({in_clause}) AND action_type = ? \
-             ORDER BY created_at ASC"
+             AND deleted_at IS NULL {QUEUE_ORDER}"
         );
         let mut q = sqlx::query_as::<_, ApprovalRow>(&sql);
         q = q.bind(account_id);
@@ -355,8 +372,8 @@ pub async fn get_by_statuses_for(
     } else {
         let sql = format!(
             "SELECT {SELECT_COLS} FROM approval_queue \
-             WHERE account_id = ? AND status IN ({in_clause}) \
-             ORDER BY created_at ASC"
+             WHERE account_id = ? AND status IN ({in_clause}) AND deleted_at IS NULL \
+             {QUEUE_ORDER}"
         );
         let mut q = sqlx::query_as::<_, ApprovalRow>(&sql);
         q = q.bind(account_id);
@@ -397,7 +414,7 @@ pub async fn get_filtered_for(
 
     let mut sql = format!(
         "SELECT {SELECT_COLS} FROM approval_queue \
-         WHERE account_id = ? AND status IN ({in_clause})"
+         WHERE account_id = ? AND status IN ({in_clause}) AND deleted_at IS NULL"
     );
     if action_type.is_some() {
         sql.push_str(" AND action_type = ?");
@@ -408,7 +425,8 @@ pub async fn get_filtered_for(
     if since.is_some() {
         sql.push_str(" AND created_at >= ?");
     }
-    sql.push_str(" ORDER BY created_at ASC");
+    sql.push(' ');
+    sql.push_str(QUEUE_ORDER);
 
     let mut q = sqlx::query_as::<_, ApprovalRow>(&sql);
     q = q.bind(account_id);
@@ -432,6 +450,58 @@ pub async fn get_filtered_for(
     Ok(rows.into_iter().map(ApprovalItem::from).collect())
 }
 
+/// Sortable fields for [`get_filtered_page_for`], as (field, column).
+pub const APPROVAL_SORT_FIELDS: &[(&str, &str)] = &[
+    ("created_at", "created_at"),
+    ("score", "score"),
+    ("priority", "priority"),
+    ("author", "target_author"),
+];
+
+/// Filters for [`get_filtered_page_for`]; `None` matches everything.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ApprovalFilter<'a> {
+    /// Statuses to include; an empty list matches nothing.
+    pub statuses: &'a [&'a str],
+    pub action_type: Option<&'a str>,
+    pub reviewed_by: Option<&'a str>,
+    pub author: Option<&'a str>,
+    /// Created at or after this ISO-8601 timestamp.
+    pub since: Option<&'a str>,
+    /// Created before this ISO-8601 timestamp.
+    pub until: Option<&'a str>,
+}
+
+/// One page of approval items for a specific account, in queue order
+/// (escalated pending items first, then oldest) unless sorted otherwise.
+pub async fn get_filtered_page_for(
+    pool: &DbPool,
+    account_id: &str,
+    filter: &ApprovalFilter<'_>,
+    page: &PageRequest,
+) -> Result<Page<ApprovalItem>, StorageError> {
+    let conditions = Conditions::new()
+        .eq("account_id", Some(account_id))
+        .any_of("status", filter.statuses)
+        .clause("deleted_at IS NULL")
+        .eq("action_type", filter.action_type)
+        .eq("reviewed_by", filter.reviewed_by)
+        .eq("target_author", filter.author)
+        .since("created_at", filter.since)
+        .until("created_at", filter.until);
+    let page = fetch_page(
+        pool,
+        "approval_queue",
+        SELECT_COLS,
+        &conditions,
+        page,
+        QUEUE_ORDER,
+        |r: &ApprovalRow| r.id,
+    )
+    .await?;
+    Ok(page.map(ApprovalItem::from))
+}
+
 /// Get approval items with optional filters for reviewer, date range, statuses, and action type.
 pub async fn get_filtered(
     pool: &DbPool,
@@ -458,13 +528,16 @@ pub async fn update_content_for(
     id: i64,
     new_content: &str,
 ) -> Result<(), StorageError> {
-    sqlx::query("UPDATE approval_queue SET generated_content = ? WHERE id = ? AND account_id = ?")
-        .bind(new_content)
-        .bind(id)
-        .bind(account_id)
-        .execute(pool)
-        .await
-        .map_err(|e| StorageError::Query { source: e })?;
+    sqlx::query(
+        "UPDATE approval_queue SET generated_content = ?, version = version + 1 \
+         WHERE id = ? AND account_id = ?",
+    )
+    .bind(new_content)
+    .bind(id)
+    .bind(account_id)
+    .execute(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
 
     Ok(())
 }
@@ -481,8 +554,38 @@ pub async fn update_media_paths_for(
     id: i64,
     media_paths: &str,
 ) -> Result<(), StorageError> {
-    sqlx::query("UPDATE approval_queue SET media_paths = ? WHERE id = ? AND account_id = ?")
-        .bind(media_paths)
+    sqlx::query(
+        "UPDATE approval_queue SET media_paths = ?, version = version + 1 \
+         WHERE id = ? AND account_id = ?",
+    )
+    .bind(media_paths)
+    .bind(id)
+    .bind(account_id)
+    .execute(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+
+    Ok(())
+}
+
+/// Update the media paths of an approval item.
+pub async fn update_media_paths(
+    pool: &DbPool,
+    id: i64,
+    media_paths: &str,
+) -> Result<(), StorageError> {
+    update_media_paths_for(pool, DEFAULT_ACCOUNT_ID, id, media_paths).await
+}
+
+/// Tag an approval item with the external source that supplied it, for a specific account.
+pub async fn set_source_for(
+    pool: &DbPool,
+    account_id: &str,
+    id: i64,
+    source: &str,
+) -> Result<(), StorageError> {
+    sqlx::query("UPDATE approval_queue SET source = ? WHERE id = ? AND account_id = ?")
+        .bind(source)
         .bind(id)
         .bind(account_id)
         .execute(pool)
@@ -492,16 +595,41 @@ pub async fn update_media_paths_for(
     Ok(())
 }
 
-/// Update the media paths of an approval item.
-pub async fn update_media_paths(
+/// Tag an approval item with the external source that supplied it.
+pub async fn set_source(pool: &DbPool, id: i64, source: &str) -> Result<(), StorageError> {
+    set_source_for(pool, DEFAULT_ACCOUNT_ID, id, source).await
+}
+
+/// Record media validation results on an approval item for a specific account.
+pub async fn update_media_validation_for(
     pool: &DbPool,
+    account_id: &str,
     id: i64,
-    media_paths: &str,
+    media_validation: &str,
 ) -> Result<(), StorageError> {
-    update_media_paths_for(pool, DEFAULT_ACCOUNT_ID, id, media_paths).await
+    sqlx::query("UPDATE approval_queue SET media_validation = ? WHERE id = ? AND account_id = ?")
+        .bind(media_validation)
+        .bind(id)
+        .bind(account_id)
+        .execute(pool)
+        .await
+        .map_err(|e| StorageError::Query { source: e })?;
+
+    Ok(())
+}
+
+/// Record media validation results on an approval item.
+pub async fn update_media_validation(
+    pool: &DbPool,
+    id: i64,
+    media_validation: &str,
+) -> Result<(), StorageError> {
+    update_media_validation_for(pool, DEFAULT_ACCOUNT_ID, id, media_validation).await
 }
 
 /// Update QA fields for an approval item for a specific account.
+///
+/// Also increments the daily QA flag counters used for trend analytics.
 #[allow(clippy::too_many_arguments)]
 pub async fn update_qa_fields_for(
     pool: &DbPool,
@@ -531,6 +659,9 @@ pub async fn update_qa_fields_for(
     .await
     .map_err(|e| StorageError::Query { source: e })?;
 
+    crate::storage::qa_flags::record_flags_json_for(pool, account_id, qa_hard_flags, qa_soft_flags)
+        .await?;
+
     Ok(())
 }
 
@@ -618,17 +749,33 @@ pub async fn clear_qa_override(pool: &DbPool, id: i64) -> Result<(), StorageErro
     clear_qa_override_for(pool, DEFAULT_ACCOUNT_ID, id).await
 }
 
-/// Fetch the next approved item ready for posting for a specific account.
+/// Fetch the next approved X item ready for posting for a specific account.
 pub async fn get_next_approved_for(
     pool: &DbPool,
     account_id: &str,
+) -> Result<Option<ApprovalItem>, StorageError> {
+    get_next_approved_on_platform_for(pool, account_id, PLATFORM_X).await
+}
+
+/// Fetch the next approved X item ready for posting.
+pub async fn get_next_approved(pool: &DbPool) -> Result<Option<ApprovalItem>, StorageError> {
+    get_next_approved_for(pool, DEFAULT_ACCOUNT_ID).await
+}
+
+/// Fetch the next approved item for `platform` for a specific account.
+pub async fn get_next_approved_on_platform_for(
+    pool: &DbPool,
+    account_id: &str,
+    platform: &str,
 ) -> Result<Option<ApprovalItem>, StorageError> {
     let sql = format!(
         "SELECT {SELECT_COLS} FROM approval_queue \
-         WHERE status = 'approved' AND account_id = ? ORDER BY reviewed_at ASC LIMIT 1"
+         WHERE status = 'approved' AND account_id = ? AND platform = ? AND deleted_at IS NULL \
+         ORDER BY reviewed_at ASC LIMIT 1"
     );
     let row: Option<ApprovalRow> = sqlx::query_as(&sql)
         .bind(account_id)
+        .bind(platform)
         .fetch_optional(pool)
         .await
         .map_err(|e| StorageError::Query { source: e })?;
@@ -636,35 +783,12 @@ pub async fn get_next_approved_for(
     Ok(row.map(ApprovalItem::from))
 }
 
-/// Fetch the next approved item ready for posting.
-pub async fn get_next_approved(pool: &DbPool) -> Result<Option<ApprovalItem>, StorageError> {
-    get_next_approved_for(pool, DEFAULT_ACCOUNT_ID).await
-}
-
-/// Mark an approved item as posted for a specific account, storing the returned tweet ID.
-pub async fn mark_posted_for(
+/// Fetch the next approved item for `platform`.
+pub async fn get_next_approved_on_platform(
     pool: &DbPool,
-    account_id: &str,
-    id: i64,
-    tweet_id: &str,
-) -> Result<(), StorageError> {
-    sqlx::query(
-        "UPDATE approval_queue SET status = 'posted', posted_tweet_id = ? \
-         WHERE id = ? AND account_id = ?",
-    )
-    .bind(tweet_id)
-    .bind(id)
-    .bind(account_id)
-    .execute(pool)
-    .await
-    .map_err(|e| StorageError::Query { source: e })?;
-
-    Ok(())
-}
-
-/// Mark an approved item as posted, storing the returned tweet ID.
-pub async fn mark_posted(pool: &DbPool, id: i64, tweet_id: &str) -> Result<(), StorageError> {
-    mark_posted_for(pool, DEFAULT_ACCOUNT_ID, id, tweet_id).await
+    platform: &str,
+) -> Result<Option<ApprovalItem>, StorageError> {
+    get_next_approved_on_platform_for(pool, DEFAULT_ACCOUNT_ID, platform).await
 }
 
 /// Expire old pending items for a specific account (older than the specified hours).
@@ -676,7 +800,7 @@ pub async fn expire_old_items_for(
     let result = sqlx::query(
         "UPDATE approval_queue SET status = 'expired', \
          reviewed_at = strftime('%Y-%m-%dT%H:%M:%SZ', 'now') \
-         WHERE status = 'pending' AND account_id = ? \
+         WHERE status = 'pending' AND account_id = ? AND deleted_at IS NULL \
          AND created_at < strftime('%Y-%m-%dT%H:%M:%SZ', 'now', ?)",
     )
     .bind(account_id)
@@ -703,10 +827,20 @@ pub async fn batch_approve_for(
     let pending = get_pending_for(pool, account_id).await?;
     let to_approve: Vec<&ApprovalItem> = pending.iter().take(max_batch).collect();
     let mut approved_ids = Vec::with_capacity(to_approve.len());
+    let review = ReviewAction {
+        expected_version: None,
+        ..review.clone()
+    };
 
     for item in to_approve {
-        update_status_with_review_for(pool, account_id, item.id, "approved", review).await?;
-        approved_ids.push(item.id);
+        // Items reviewed concurrently since the pending list was read are skipped.
+        match super::update_status_with_review_for(pool, account_id, item.id, "approved", &review)
+            .await
+        {
+            Ok(()) => approved_ids.push(item.id),
+            Err(StorageError::Conflict { .. }) => continue,
+            Err(e) => return Err(e),
+        }
     }
 
     Ok(approved_ids)
@@ -0,0 +1,82 @@
+//! Queries behind the approval SLA: pending item ages, priority bumps, and
+//! review turnaround for compliance stats.
+
+use crate::error::StorageError;
+use crate::storage::analytics_range::AnalyticsRange;
+use crate::storage::DbPool;
+
+/// A pending item with the fields the SLA check needs.
+#[derive(Debug, Clone, PartialEq, sqlx::FromRow)]
+pub struct PendingAge {
+    pub id: i64,
+    pub account_id: String,
+    pub action_type: String,
+    /// Enqueue time, Unix seconds.
+    pub created_epoch: i64,
+    pub priority: i64,
+}
+
+/// When one item was enqueued and reviewed, and by whom.
+#[derive(Debug, Clone, PartialEq, sqlx::FromRow)]
+pub struct ReviewTime {
+    pub reviewer: String,
+    /// Enqueue time, Unix seconds.
+    pub created_epoch: i64,
+    /// Review time, Unix seconds.
+    pub reviewed_epoch: i64,
+}
+
+/// Every pending item across accounts, oldest first.
+pub async fn get_pending_ages(pool: &DbPool) -> Result<Vec<PendingAge>, StorageError> {
+    sqlx::query_as(
+        "SELECT id, account_id, action_type, \
+            CAST(strftime('%s', created_at) AS INTEGER) AS created_epoch, priority \
+         FROM approval_queue \
+         WHERE status = 'pending' AND deleted_at IS NULL \
+         ORDER BY created_at ASC",
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })
+}
+
+/// Raise a pending item's priority. Returns `false` if the item is no longer
+/// pending or already has at least this priority.
+pub async fn raise_priority(pool: &DbPool, id: i64, priority: i64) -> Result<bool, StorageError> {
+    let result = sqlx::query(
+        "UPDATE approval_queue SET priority = ? \
+         WHERE id = ? AND status = 'pending' AND priority < ?",
+    )
+    .bind(priority)
+    .bind(id)
+    .bind(priority)
+    .execute(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+    Ok(result.rows_affected() > 0)
+}
+
+/// Decided items enqueued in `range` for an account, with reviewer and
+/// timestamps.
+pub async fn get_review_times_for(
+    pool: &DbPool,
+    account_id: &str,
+    range: &AnalyticsRange,
+) -> Result<Vec<ReviewTime>, StorageError> {
+    let (from, to) = range.bounds();
+    sqlx::query_as(
+        "SELECT COALESCE(NULLIF(reviewed_by, ''), 'unknown') AS reviewer, \
+            CAST(strftime('%s', created_at) AS INTEGER) AS created_epoch, \
+            CAST(strftime('%s', reviewed_at) AS INTEGER) AS reviewed_epoch \
+         FROM approval_queue \
+         WHERE account_id = ? AND datetime(created_at) >= ? AND datetime(created_at) < ? \
+           AND status IN ('approved', 'posted', 'rejected') AND reviewed_at IS NOT NULL \
+         ORDER BY created_at ASC",
+    )
+    .bind(account_id)
+    .bind(&from)
+    .bind(&to)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })
+}
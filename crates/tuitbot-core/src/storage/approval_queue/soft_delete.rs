@@ -0,0 +1,223 @@
+//! Soft delete and restore for approval items.
+//!
+//! Deleting stamps `deleted_at` instead of removing the row; every standard
+//! query skips stamped rows. Restoring clears the stamp and returns a
+//! rejected item to `pending`, which also undoes an accidental rejection.
+//! The retention cleanup purges rows deleted longer ago than
+//! `storage.retention_days`.
+
+use super::{ApprovalItem, ApprovalRow};
+use crate::error::StorageError;
+use crate::storage::accounts::DEFAULT_ACCOUNT_ID;
+use crate::storage::DbPool;
+
+/// Soft-delete an approval item for a specific account.
+///
+/// Items being posted cannot be deleted. Returns `false` when the item does
+/// not exist, is already deleted, or is being posted.
+pub async fn soft_delete_for(
+    pool: &DbPool,
+    account_id: &str,
+    id: i64,
+) -> Result<bool, StorageError> {
+    let result = sqlx::query(
+        "UPDATE approval_queue SET deleted_at = strftime('%Y-%m-%dT%H:%M:%SZ', 'now'), \
+         version = version + 1 \
+         WHERE id = ? AND account_id = ? AND deleted_at IS NULL AND status != 'posting'",
+    )
+    .bind(id)
+    .bind(account_id)
+    .execute(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+    Ok(result.rows_affected() > 0)
+}
+
+/// Soft-delete an approval item.
+pub async fn soft_delete(pool: &DbPool, id: i64) -> Result<bool, StorageError> {
+    soft_delete_for(pool, DEFAULT_ACCOUNT_ID, id).await
+}
+
+/// Restore a deleted or rejected approval item for a specific account.
+///
+/// Rejected items go back to `pending` with their review cleared; other
+/// items keep their status. Returns `false` when there is nothing to restore.
+pub async fn restore_for(pool: &DbPool, account_id: &str, id: i64) -> Result<bool, StorageError> {
+    let result = sqlx::query(
+        "UPDATE approval_queue SET deleted_at = NULL, version = version + 1, \
+             reviewed_by = CASE WHEN status = 'rejected' THEN NULL ELSE reviewed_by END, \
+             review_notes = CASE WHEN status = 'rejected' THEN NULL ELSE review_notes END, \
+             reviewed_at = CASE WHEN status = 'rejected' THEN NULL ELSE reviewed_at END, \
+             status = CASE WHEN status = 'rejected' THEN 'pending' ELSE status END \
+         WHERE id = ? AND account_id = ? AND (deleted_at IS NOT NULL OR status = 'rejected')",
+    )
+    .bind(id)
+    .bind(account_id)
+    .execute(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+    Ok(result.rows_affected() > 0)
+}
+
+/// Restore a deleted or rejected approval item.
+pub async fn restore(pool: &DbPool, id: i64) -> Result<bool, StorageError> {
+    restore_for(pool, DEFAULT_ACCOUNT_ID, id).await
+}
+
+/// Get deleted approval items for a specific account, most recently deleted first.
+pub async fn get_deleted_for(
+    pool: &DbPool,
+    account_id: &str,
+) -> Result<Vec<ApprovalItem>, StorageError> {
+    let sql = format!(
+        "SELECT {} FROM approval_queue \
+         WHERE account_id = ? AND deleted_at IS NOT NULL ORDER BY deleted_at DESC, id DESC",
+        super::queries::SELECT_COLS
+    );
+    let rows: Vec<ApprovalRow> = sqlx::query_as(&sql)
+        .bind(account_id)
+        .fetch_all(pool)
+        .await
+        .map_err(|e| StorageError::Query { source: e })?;
+
+    Ok(rows.into_iter().map(ApprovalItem::from).collect())
+}
+
+/// Get deleted approval items, most recently deleted first.
+pub async fn get_deleted(pool: &DbPool) -> Result<Vec<ApprovalItem>, StorageError> {
+    get_deleted_for(pool, DEFAULT_ACCOUNT_ID).await
+}
+
+/// Permanently remove approval items deleted before `cutoff` for a specific
+/// account, with their edit history and comments.
+pub(crate) async fn purge_deleted_for(
+    pool: &DbPool,
+    account_id: &str,
+    cutoff: &str,
+) -> Result<u64, StorageError> {
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|e| StorageError::Query { source: e })?;
+
+    const PURGED: &str = "SELECT id FROM approval_queue WHERE account_id = ? AND deleted_at < ?";
+
+    for child in ["approval_edit_history", "approval_comments"] {
+        sqlx::query(&format!(
+            "DELETE FROM {child} WHERE approval_id IN ({PURGED})"
+        ))
+        .bind(account_id)
+        .bind(cutoff)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| StorageError::Query { source: e })?;
+    }
+
+    sqlx::query(&format!(
+        "UPDATE approval_queue SET collapsed_into = NULL WHERE collapsed_into IN ({PURGED})"
+    ))
+    .bind(account_id)
+    .bind(cutoff)
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+
+    let deleted = sqlx::query("DELETE FROM approval_queue WHERE account_id = ? AND deleted_at < ?")
+        .bind(account_id)
+        .bind(cutoff)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| StorageError::Query { source: e })?
+        .rows_affected();
+
+    tx.commit()
+        .await
+        .map_err(|e| StorageError::Query { source: e })?;
+    Ok(deleted)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::approval_queue::{
+        enqueue, get_by_id, get_pending, pending_count, update_status_with_review, ReviewAction,
+    };
+    use crate::storage::init_test_db;
+
+    async fn enqueue_reply(pool: &DbPool, tweet_id: &str) -> i64 {
+        enqueue(
+            pool,
+            "reply",
+            tweet_id,
+            "@dev",
+            "Nice work",
+            "Rust",
+            "",
+            50.0,
+            "[]",
+        )
+        .await
+        .expect("enqueue")
+    }
+
+    #[tokio::test]
+    async fn deleted_items_are_hidden_until_restored() {
+        let pool = init_test_db().await.expect("init db");
+        let id = enqueue_reply(&pool, "t1").await;
+
+        assert!(soft_delete(&pool, id).await.expect("delete"));
+        assert!(!soft_delete(&pool, id).await.expect("delete twice"));
+        assert!(get_pending(&pool).await.expect("pending").is_empty());
+        assert_eq!(pending_count(&pool).await.expect("count"), 0);
+        assert!(get_by_id(&pool, id).await.expect("get").is_none());
+        assert_eq!(get_deleted(&pool).await.expect("deleted").len(), 1);
+
+        assert!(restore(&pool, id).await.expect("restore"));
+        let item = get_by_id(&pool, id).await.expect("get").expect("item");
+        assert_eq!(item.status, "pending");
+        assert!(get_deleted(&pool).await.expect("deleted").is_empty());
+        assert!(!restore(&pool, id).await.expect("nothing to restore"));
+    }
+
+    #[tokio::test]
+    async fn restore_undoes_rejection() {
+        let pool = init_test_db().await.expect("init db");
+        let id = enqueue_reply(&pool, "t1").await;
+        let review = ReviewAction {
+            actor: Some("alice".to_string()),
+            notes: Some("off topic".to_string()),
+            expected_version: None,
+        };
+        update_status_with_review(&pool, id, "rejected", &review)
+            .await
+            .expect("reject");
+
+        assert!(restore(&pool, id).await.expect("restore"));
+        let item = get_by_id(&pool, id).await.expect("get").expect("item");
+        assert_eq!(item.status, "pending");
+        assert!(item.reviewed_by.is_none());
+        assert!(item.review_notes.is_none());
+    }
+
+    #[tokio::test]
+    async fn purge_removes_only_old_deletions() {
+        let pool = init_test_db().await.expect("init db");
+        let old = enqueue_reply(&pool, "t1").await;
+        let recent = enqueue_reply(&pool, "t2").await;
+        soft_delete(&pool, old).await.expect("delete");
+        soft_delete(&pool, recent).await.expect("delete");
+        sqlx::query("UPDATE approval_queue SET deleted_at = '2020-01-01T00:00:00Z' WHERE id = ?")
+            .bind(old)
+            .execute(&pool)
+            .await
+            .expect("backdate");
+
+        let purged = purge_deleted_for(&pool, DEFAULT_ACCOUNT_ID, "2025-01-01T00:00:00Z")
+            .await
+            .expect("purge");
+        assert_eq!(purged, 1);
+        let remaining = get_deleted(&pool).await.expect("deleted");
+        assert_eq!(remaining.len(), 1);
+        assert_eq!(remaining[0].id, recent);
+    }
+}
@@ -270,6 +270,7 @@ async fn update_status_with_review_stores_metadata() {
     let review = ReviewAction {
         actor: Some("dashboard_user".to_string()),
         notes: Some("Looks good!".to_string()),
+        expected_version: None,
     };
     update_status_with_review(&pool, id, "approved", &review)
         .await
@@ -377,6 +378,7 @@ async fn batch_approve_respects_max() {
     let review = ReviewAction {
         actor: Some("batch_user".to_string()),
         notes: None,
+        expected_version: None,
     };
     let ids = batch_approve(&pool, 3, &review).await.expect("batch");
     assert_eq!(ids.len(), 3);
@@ -415,3 +417,96 @@ async fn edit_history_roundtrip() {
     assert_eq!(history[0].old_value, "Original");
     assert_eq!(history[0].new_value, "Edited");
 }
+
+#[tokio::test]
+async fn media_validation_roundtrip() {
+    let pool = init_test_db().await.expect("init db");
+
+    let id = enqueue(
+        &pool,
+        "tweet",
+        "",
+        "",
+        "With media",
+        "General",
+        "",
+        0.0,
+        "[]",
+    )
+    .await
+    .expect("enqueue");
+
+    let item = get_by_id(&pool, id).await.expect("get").expect("found");
+    assert_eq!(item.media_validation, "[]");
+
+    let results = r#"[{"media_type":"image/jpeg","original_size":10,"final_size":8,"width":4,"height":4,"transcoded":true,"warnings":[]}]"#;
+    update_media_validation(&pool, id, results)
+        .await
+        .expect("update");
+
+    let item = get_by_id(&pool, id).await.expect("get").expect("found");
+    assert_eq!(item.media_validation, results);
+
+    let json = serde_json::to_value(&item).expect("serialize");
+    assert_eq!(json["media_validation"][0]["transcoded"], true);
+}
+
+#[tokio::test]
+async fn next_approved_is_per_platform() {
+    let pool = init_test_db().await.expect("init db");
+
+    let reddit_id = enqueue_on_platform(
+        &pool,
+        PLATFORM_REDDIT,
+        "reddit_comment",
+        "t3_abc",
+        "ferris",
+        "Try clap.",
+        "r/rust",
+        72.0,
+    )
+    .await
+    .expect("enqueue reddit");
+    update_status(&pool, reddit_id, "approved")
+        .await
+        .expect("approve");
+
+    assert!(get_next_approved(&pool).await.expect("next x").is_none());
+
+    let item = get_next_approved_on_platform(&pool, PLATFORM_REDDIT)
+        .await
+        .expect("next reddit")
+        .expect("found");
+    assert_eq!(item.id, reddit_id);
+    assert_eq!(item.platform, "reddit");
+    assert_eq!(item.target_tweet_id, "t3_abc");
+
+    let x_id = enqueue(&pool, "tweet", "", "", "Hello", "General", "", 0.0, "[]")
+        .await
+        .expect("enqueue x");
+    update_status(&pool, x_id, "approved")
+        .await
+        .expect("approve");
+    let item = get_next_approved(&pool)
+        .await
+        .expect("next x")
+        .expect("found");
+    assert_eq!(item.id, x_id);
+    assert_eq!(item.platform, PLATFORM_X);
+}
+
+#[tokio::test]
+async fn source_tag_round_trips() {
+    let pool = init_test_db().await.expect("init db");
+
+    let id = enqueue(&pool, "reply", "123", "alice", "Nice", "", "", 70.0, "[]")
+        .await
+        .expect("enqueue");
+    let item = get_by_id(&pool, id).await.expect("get").expect("found");
+    assert_eq!(item.source, "");
+
+    set_source(&pool, id, "zapier").await.expect("set source");
+    let item = get_by_id(&pool, id).await.expect("get").expect("found");
+    assert_eq!(item.source, "zapier");
+    assert_eq!(item.version, 0, "tagging is not an edit");
+}
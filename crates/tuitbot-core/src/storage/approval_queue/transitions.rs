@@ -0,0 +1,313 @@
+//! Status transitions with optimistic concurrency.
+//!
+//! The dashboard and MCP agents can review the same item at the same time.
+//! Every transition is a compare-and-swap on the item's status (and, when
+//! the caller supplies one, its `version`), so exactly one reviewer wins and
+//! the loser gets [`StorageError::Conflict`]. Posting claims an approved item
+//! the same way before anything is sent to X.
+
+use super::ReviewAction;
+use crate::error::StorageError;
+use crate::storage::accounts::DEFAULT_ACCOUNT_ID;
+use crate::storage::DbPool;
+
+/// Statuses a review decision may move an item out of.
+///
+/// Approving requires a pending item; rejecting also cancels an approved
+/// item that has not been claimed for posting yet.
+fn review_sources(status: &str) -> &'static [&'static str] {
+    match status {
+        "rejected" => &["pending", "approved"],
+        _ => &["pending"],
+    }
+}
+
+/// Update the status of an approval item with review metadata for a specific account.
+///
+/// Fails with [`StorageError::Conflict`] when the item is no longer in a
+/// reviewable state or `review.expected_version` does not match.
+pub async fn update_status_with_review_for(
+    pool: &DbPool,
+    account_id: &str,
+    id: i64,
+    status: &str,
+    review: &ReviewAction,
+) -> Result<(), StorageError> {
+    let sources = review_sources(status);
+    let placeholders = vec!["?"; sources.len()].join(", ");
+    let sql = format!(
+        "UPDATE approval_queue SET status = ?, version = version + 1, \
+         reviewed_at = strftime('%Y-%m-%dT%H:%M:%SZ', 'now'), \
+         reviewed_by = ?, review_notes = ? \
+         WHERE id = ? AND account_id = ? AND status IN ({placeholders}) AND deleted_at IS NULL \
+         AND (? IS NULL OR version = ?)"
+    );
+    let mut query = sqlx::query(&sql)
+        .bind(status)
+        .bind(&review.actor)
+        .bind(&review.notes)
+        .bind(id)
+        .bind(account_id);
+    for source in sources {
+        query = query.bind(*source);
+    }
+    let result = query
+        .bind(review.expected_version)
+        .bind(review.expected_version)
+        .execute(pool)
+        .await
+        .map_err(|e| StorageError::Query { source: e })?;
+
+    if result.rows_affected() == 0 {
+        if let Some(err) = conflict(pool, account_id, id, status, review.expected_version).await? {
+            return Err(err);
+        }
+    }
+    Ok(())
+}
+
+/// Update the status of an approval item with review metadata.
+pub async fn update_status_with_review(
+    pool: &DbPool,
+    id: i64,
+    status: &str,
+    review: &ReviewAction,
+) -> Result<(), StorageError> {
+    update_status_with_review_for(pool, DEFAULT_ACCOUNT_ID, id, status, review).await
+}
+
+/// Update the content and approve a pending item for a specific account (for edit-then-approve).
+pub async fn update_content_and_approve_for(
+    pool: &DbPool,
+    account_id: &str,
+    id: i64,
+    new_content: &str,
+) -> Result<(), StorageError> {
+    let result = sqlx::query(
+        "UPDATE approval_queue SET generated_content = ?, status = 'approved', \
+         version = version + 1, reviewed_at = strftime('%Y-%m-%dT%H:%M:%SZ', 'now') \
+         WHERE id = ? AND account_id = ? AND status = 'pending' AND deleted_at IS NULL",
+    )
+    .bind(new_content)
+    .bind(id)
+    .bind(account_id)
+    .execute(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+
+    if result.rows_affected() == 0 {
+        if let Some(err) = conflict(pool, account_id, id, "approved", None).await? {
+            return Err(err);
+        }
+    }
+    Ok(())
+}
+
+/// Update the content and approve a pending item (for edit-then-approve).
+pub async fn update_content_and_approve(
+    pool: &DbPool,
+    id: i64,
+    new_content: &str,
+) -> Result<(), StorageError> {
+    update_content_and_approve_for(pool, DEFAULT_ACCOUNT_ID, id, new_content).await
+}
+
+/// Claim an approved item for posting for a specific account.
+///
+/// Moves the item from `approved` to `posting` if it is still at `version`.
+/// Returns `false` when another poster or reviewer got there first, in which
+/// case the caller must not post it.
+pub async fn claim_for_posting_for(
+    pool: &DbPool,
+    account_id: &str,
+    id: i64,
+    version: i64,
+) -> Result<bool, StorageError> {
+    let result = sqlx::query(
+        "UPDATE approval_queue SET status = 'posting', version = version + 1 \
+         WHERE id = ? AND account_id = ? AND status = 'approved' AND version = ? \
+         AND deleted_at IS NULL",
+    )
+    .bind(id)
+    .bind(account_id)
+    .bind(version)
+    .execute(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+
+    Ok(result.rows_affected() == 1)
+}
+
+/// Claim an approved item for posting.
+pub async fn claim_for_posting(pool: &DbPool, id: i64, version: i64) -> Result<bool, StorageError> {
+    claim_for_posting_for(pool, DEFAULT_ACCOUNT_ID, id, version).await
+}
+
+/// Return a claimed item to `approved` after a failed post for a specific account.
+pub async fn release_posting_claim_for(
+    pool: &DbPool,
+    account_id: &str,
+    id: i64,
+) -> Result<(), StorageError> {
+    sqlx::query(
+        "UPDATE approval_queue SET status = 'approved', version = version + 1 \
+         WHERE id = ? AND account_id = ? AND status = 'posting'",
+    )
+    .bind(id)
+    .bind(account_id)
+    .execute(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+
+    Ok(())
+}
+
+/// Return a claimed item to `approved` after a failed post.
+pub async fn release_posting_claim(pool: &DbPool, id: i64) -> Result<(), StorageError> {
+    release_posting_claim_for(pool, DEFAULT_ACCOUNT_ID, id).await
+}
+
+/// Mark an approved item as posted for a specific account, storing the returned tweet ID.
+pub async fn mark_posted_for(
+    pool: &DbPool,
+    account_id: &str,
+    id: i64,
+    tweet_id: &str,
+) -> Result<(), StorageError> {
+    sqlx::query(
+        "UPDATE approval_queue SET status = 'posted', posted_tweet_id = ?, \
+         version = version + 1 WHERE id = ? AND account_id = ?",
+    )
+    .bind(tweet_id)
+    .bind(id)
+    .bind(account_id)
+    .execute(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+
+    Ok(())
+}
+
+/// Mark an approved item as posted, storing the returned tweet ID.
+pub async fn mark_posted(pool: &DbPool, id: i64, tweet_id: &str) -> Result<(), StorageError> {
+    mark_posted_for(pool, DEFAULT_ACCOUNT_ID, id, tweet_id).await
+}
+
+/// Explain why a transition matched no rows.
+///
+/// Returns `None` when the item does not exist or is deleted; updates to
+/// missing items are no-ops.
+async fn conflict(
+    pool: &DbPool,
+    account_id: &str,
+    id: i64,
+    target: &str,
+    expected_version: Option<i64>,
+) -> Result<Option<StorageError>, StorageError> {
+    let current: Option<(String, i64)> = sqlx::query_as(
+        "SELECT status, version FROM approval_queue \
+         WHERE id = ? AND account_id = ? AND deleted_at IS NULL",
+    )
+    .bind(id)
+    .bind(account_id)
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+
+    Ok(current.map(|(status, version)| {
+        let message = match expected_version {
+            Some(expected) if expected != version => {
+                format!("approval item {id} was modified (version {version}, expected {expected})")
+            }
+            _ => format!("approval item {id} is already {status} and cannot be {target}"),
+        };
+        StorageError::Conflict { message }
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::approval_queue::{enqueue, get_by_id};
+    use crate::storage::init_test_db;
+
+    fn review(actor: &str, expected_version: Option<i64>) -> ReviewAction {
+        ReviewAction {
+            actor: Some(actor.to_string()),
+            notes: None,
+            expected_version,
+        }
+    }
+
+    #[tokio::test]
+    async fn second_reviewer_gets_conflict() {
+        let pool = init_test_db().await.expect("init db");
+        let id = enqueue(&pool, "tweet", "", "", "Hello", "", "", 0.0, "[]")
+            .await
+            .expect("enqueue");
+
+        update_status_with_review(&pool, id, "approved", &review("dashboard", Some(0)))
+            .await
+            .expect("first approve");
+        let err = update_status_with_review(&pool, id, "approved", &review("mcp", None))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, StorageError::Conflict { .. }));
+
+        let item = get_by_id(&pool, id).await.expect("get").expect("found");
+        assert_eq!(item.status, "approved");
+        assert_eq!(item.reviewed_by.as_deref(), Some("dashboard"));
+        assert_eq!(item.version, 1);
+    }
+
+    #[tokio::test]
+    async fn stale_version_is_rejected() {
+        let pool = init_test_db().await.expect("init db");
+        let id = enqueue(&pool, "tweet", "", "", "Hello", "", "", 0.0, "[]")
+            .await
+            .expect("enqueue");
+
+        let err = update_status_with_review(&pool, id, "rejected", &review("mcp", Some(3)))
+            .await
+            .unwrap_err();
+        match err {
+            StorageError::Conflict { message } => assert!(message.contains("expected 3")),
+            other => panic!("expected conflict, got {other:?}"),
+        }
+
+        // Missing items stay a no-op.
+        update_status_with_review(&pool, 9999, "approved", &review("mcp", None))
+            .await
+            .expect("missing item");
+    }
+
+    #[tokio::test]
+    async fn only_one_claim_wins_and_reject_blocks_posting() {
+        let pool = init_test_db().await.expect("init db");
+        let id = enqueue(&pool, "tweet", "", "", "Hello", "", "", 0.0, "[]")
+            .await
+            .expect("enqueue");
+        update_status_with_review(&pool, id, "approved", &review("a", None))
+            .await
+            .expect("approve");
+
+        assert!(claim_for_posting(&pool, id, 1).await.expect("claim"));
+        assert!(!claim_for_posting(&pool, id, 1).await.expect("claim"));
+
+        // A claimed item can no longer be rejected.
+        let err = update_status_with_review(&pool, id, "rejected", &review("b", None))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, StorageError::Conflict { .. }));
+
+        // Released after a failed post, then rejected before the retry.
+        release_posting_claim(&pool, id).await.expect("release");
+        update_status_with_review(&pool, id, "rejected", &review("b", None))
+            .await
+            .expect("reject approved item");
+        let item = get_by_id(&pool, id).await.expect("get").expect("found");
+        assert!(!claim_for_posting(&pool, id, item.version)
+            .await
+            .expect("claim"));
+    }
+}
@@ -0,0 +1,148 @@
+//! Archived installs.
+//!
+//! `tuitbot archive` retires a bot without deleting its history: it
+//! compacts the database and sets a persisted flag. While the flag is set
+//! the API server runs read-only (analytics and history, no mutations or
+//! background tasks) and `tuitbot run`/`tick` refuse to start. The change
+//! is recorded in the action log.
+
+use serde::Serialize;
+
+use super::DbPool;
+use crate::error::StorageError;
+
+/// Current archive state.
+#[derive(Debug, Clone, Default, PartialEq, sqlx::FromRow, Serialize)]
+pub struct ArchiveState {
+    /// Whether the install is archived.
+    pub archived: bool,
+    /// Who last changed the flag: `cli`, ...
+    pub source: Option<String>,
+    /// ISO-8601 UTC timestamp of the last change.
+    pub changed_at: Option<String>,
+}
+
+/// Database size before and after [`compact`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct CompactStats {
+    /// Bytes used by the main database file before compaction.
+    pub bytes_before: u64,
+    /// Bytes used after compaction.
+    pub bytes_after: u64,
+}
+
+/// Read the archive state. A missing row means the install was never archived.
+pub async fn get_archive_state(pool: &DbPool) -> Result<ArchiveState, StorageError> {
+    let row = sqlx::query_as::<_, ArchiveState>(
+        "SELECT archived, source, changed_at FROM archive_state WHERE id = 1",
+    )
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+
+    Ok(row.unwrap_or_default())
+}
+
+/// Whether the install is archived.
+pub async fn is_archived(pool: &DbPool) -> Result<bool, StorageError> {
+    Ok(get_archive_state(pool).await?.archived)
+}
+
+/// Mark the install archived (`true`) or active again (`false`).
+pub async fn set_archived(
+    pool: &DbPool,
+    archived: bool,
+    source: &str,
+) -> Result<ArchiveState, StorageError> {
+    sqlx::query(
+        "INSERT INTO archive_state (id, archived, source, changed_at) \
+         VALUES (1, ?, ?, strftime('%Y-%m-%dT%H:%M:%SZ', 'now')) \
+         ON CONFLICT(id) DO UPDATE SET \
+            archived = excluded.archived, \
+            source = excluded.source, \
+            changed_at = excluded.changed_at",
+    )
+    .bind(archived)
+    .bind(source)
+    .execute(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+
+    let (status, message) = if archived {
+        ("archived", format!("Install archived via {source}"))
+    } else {
+        ("unarchived", format!("Install unarchived via {source}"))
+    };
+    let _ = super::action_log::log_action(pool, "archive", status, Some(&message), None).await;
+    tracing::info!(source, archived, "Archive state changed");
+    get_archive_state(pool).await
+}
+
+/// Checkpoint the WAL, rebuild the database file, and refresh planner
+/// statistics.
+pub async fn compact(pool: &DbPool) -> Result<CompactStats, StorageError> {
+    let bytes_before = database_size(pool).await?;
+    for statement in [
+        "PRAGMA wal_checkpoint(TRUNCATE)",
+        "VACUUM",
+        "PRAGMA optimize",
+    ] {
+        sqlx::query(statement)
+            .execute(pool)
+            .await
+            .map_err(|e| StorageError::Query { source: e })?;
+    }
+    let bytes_after = database_size(pool).await?;
+    Ok(CompactStats {
+        bytes_before,
+        bytes_after,
+    })
+}
+
+async fn database_size(pool: &DbPool) -> Result<u64, StorageError> {
+    let (size,): (i64,) = sqlx::query_as(
+        "SELECT page_count * page_size FROM pragma_page_count(), pragma_page_size()",
+    )
+    .fetch_one(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+    Ok(size.max(0) as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::init_test_db;
+
+    #[tokio::test]
+    async fn archive_round_trip_is_logged() {
+        let pool = init_test_db().await.expect("init db");
+        assert!(!is_archived(&pool).await.expect("state"));
+
+        let state = set_archived(&pool, true, "cli").await.expect("archive");
+        assert!(state.archived);
+        assert_eq!(state.source.as_deref(), Some("cli"));
+        assert!(is_archived(&pool).await.expect("state"));
+
+        let state = set_archived(&pool, false, "cli").await.expect("unarchive");
+        assert!(!state.archived);
+
+        let log = crate::storage::action_log::get_actions_since(
+            &pool,
+            "1970-01-01T00:00:00Z",
+            Some("archive"),
+        )
+        .await
+        .expect("log");
+        let statuses: Vec<_> = log.iter().map(|e| e.status.as_str()).collect();
+        assert_eq!(statuses, vec!["archived", "unarchived"]);
+    }
+
+    #[tokio::test]
+    async fn compact_reports_sizes() {
+        let pool = init_test_db().await.expect("init db");
+        let stats = compact(&pool).await.expect("compact");
+        assert!(stats.bytes_before > 0);
+        assert!(stats.bytes_after > 0);
+    }
+}
@@ -0,0 +1,188 @@
+//! Storage for the author blocklist.
+//!
+//! Holds the accounts muted or blocked on X, refreshed by the blocklist
+//! sync, alongside usernames added by hand in `[blocklist].authors`. Each
+//! source is replaced as a whole so removals on X carry over.
+
+use super::accounts::DEFAULT_ACCOUNT_ID;
+use super::DbPool;
+use crate::error::StorageError;
+
+/// Accounts muted on X.
+pub const SOURCE_X_MUTE: &str = "x_mute";
+/// Accounts blocked on X.
+pub const SOURCE_X_BLOCK: &str = "x_block";
+/// Usernames listed in the config.
+pub const SOURCE_MANUAL: &str = "manual";
+
+/// An author on the blocklist.
+#[derive(Debug, Clone, PartialEq, sqlx::FromRow, serde::Serialize)]
+pub struct BlockedAuthor {
+    pub source: String,
+    pub author_username: String,
+    pub author_id: Option<String>,
+    pub synced_at: String,
+}
+
+/// Replace every entry from `source` for a specific account with `authors`
+/// (`(author_id, username)` pairs; manual entries have no ID).
+pub async fn replace_source_for(
+    pool: &DbPool,
+    account_id: &str,
+    source: &str,
+    authors: &[(Option<String>, String)],
+) -> Result<(), StorageError> {
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|e| StorageError::Connection { source: e })?;
+
+    sqlx::query("DELETE FROM author_blocklist WHERE account_id = ? AND source = ?")
+        .bind(account_id)
+        .bind(source)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| StorageError::Query { source: e })?;
+
+    for (author_id, username) in authors {
+        let username = username.trim().trim_start_matches('@');
+        if username.is_empty() {
+            continue;
+        }
+        sqlx::query(
+            "INSERT INTO author_blocklist (account_id, source, author_username, author_id) \
+             VALUES (?, ?, ?, ?) \
+             ON CONFLICT(account_id, source, author_username) DO UPDATE SET \
+                 author_id = excluded.author_id",
+        )
+        .bind(account_id)
+        .bind(source)
+        .bind(username)
+        .bind(author_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| StorageError::Query { source: e })?;
+    }
+
+    tx.commit()
+        .await
+        .map_err(|e| StorageError::Connection { source: e })
+}
+
+/// Replace every entry from `source` for the default account.
+pub async fn replace_source(
+    pool: &DbPool,
+    source: &str,
+    authors: &[(Option<String>, String)],
+) -> Result<(), StorageError> {
+    replace_source_for(pool, DEFAULT_ACCOUNT_ID, source, authors).await
+}
+
+/// Find the blocklist entry matching an author for a specific account, by
+/// ID or case-insensitive username. Blocks take precedence over mutes and
+/// manual entries.
+pub async fn find_for(
+    pool: &DbPool,
+    account_id: &str,
+    author_id: &str,
+    author_username: &str,
+) -> Result<Option<BlockedAuthor>, StorageError> {
+    sqlx::query_as::<_, BlockedAuthor>(
+        "SELECT source, author_username, author_id, synced_at FROM author_blocklist \
+         WHERE account_id = ? \
+           AND ((author_id IS NOT NULL AND author_id = ?) OR author_username = ?) \
+         ORDER BY CASE source WHEN 'x_block' THEN 0 WHEN 'x_mute' THEN 1 ELSE 2 END \
+         LIMIT 1",
+    )
+    .bind(account_id)
+    .bind(author_id)
+    .bind(author_username.trim_start_matches('@'))
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })
+}
+
+/// Find the blocklist entry matching an author for the default account.
+pub async fn find(
+    pool: &DbPool,
+    author_id: &str,
+    author_username: &str,
+) -> Result<Option<BlockedAuthor>, StorageError> {
+    find_for(pool, DEFAULT_ACCOUNT_ID, author_id, author_username).await
+}
+
+/// List every blocklist entry for a specific account, by source and username.
+pub async fn list_for(pool: &DbPool, account_id: &str) -> Result<Vec<BlockedAuthor>, StorageError> {
+    sqlx::query_as::<_, BlockedAuthor>(
+        "SELECT source, author_username, author_id, synced_at FROM author_blocklist \
+         WHERE account_id = ? ORDER BY source, author_username",
+    )
+    .bind(account_id)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })
+}
+
+/// Count entries per source for a specific account.
+pub async fn count_by_source_for(
+    pool: &DbPool,
+    account_id: &str,
+) -> Result<Vec<(String, i64)>, StorageError> {
+    sqlx::query_as(
+        "SELECT source, COUNT(*) FROM author_blocklist WHERE account_id = ? \
+         GROUP BY source ORDER BY source",
+    )
+    .bind(account_id)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::init_test_db;
+
+    #[tokio::test]
+    async fn sync_replaces_source_and_matches_by_id_or_username() {
+        let pool = init_test_db().await.expect("init db");
+        replace_source(
+            &pool,
+            SOURCE_X_MUTE,
+            &[
+                (Some("1".to_string()), "alice".to_string()),
+                (Some("2".to_string()), "bob".to_string()),
+            ],
+        )
+        .await
+        .expect("sync mutes");
+        replace_source(&pool, SOURCE_MANUAL, &[(None, "@Carol".to_string())])
+            .await
+            .expect("manual");
+
+        let hit = find(&pool, "1", "renamed")
+            .await
+            .expect("find")
+            .expect("hit");
+        assert_eq!(hit.source, SOURCE_X_MUTE);
+        let hit = find(&pool, "9", "carol").await.expect("find").expect("hit");
+        assert_eq!(hit.source, SOURCE_MANUAL);
+
+        // A later sync drops accounts that were unmuted on X.
+        replace_source(
+            &pool,
+            SOURCE_X_MUTE,
+            &[(Some("2".to_string()), "bob".to_string())],
+        )
+        .await
+        .expect("resync");
+        assert!(find(&pool, "1", "alice").await.expect("find").is_none());
+        assert_eq!(
+            list_for(&pool, DEFAULT_ACCOUNT_ID)
+                .await
+                .expect("list")
+                .len(),
+            2
+        );
+    }
+}
@@ -21,6 +21,14 @@ pub struct CleanupStats {
     pub threads_deleted: u64,
     /// Number of action log entries deleted.
     pub action_log_deleted: u64,
+    /// Number of keyword attribution rows deleted.
+    pub keyword_hits_deleted: u64,
+    /// Number of recorded slow queries deleted.
+    pub slow_queries_deleted: u64,
+    /// Number of soft-deleted approval items purged.
+    pub approval_items_purged: u64,
+    /// Number of removed target accounts purged (with their tweets).
+    pub target_accounts_purged: u64,
     /// Total records deleted across all tables.
     pub total_deleted: u64,
     /// Whether VACUUM was run to reclaim disk space.
@@ -36,6 +44,9 @@ pub struct CleanupStats {
 /// - Original tweets: `retention_days`.
 /// - Threads: `retention_days` (CASCADE deletes thread_tweets).
 /// - Action log: 14 days (fixed).
+/// - Keyword hits: `retention_days`.
+/// - Slow queries: 14 days (fixed, shared by all accounts).
+/// - Deleted approval items and removed targets: `retention_days` after deletion.
 /// - Rate limits: NEVER deleted.
 ///
 /// Runs VACUUM if more than 1000 total rows were deleted.
@@ -120,11 +131,39 @@ pub async fn run_cleanup_for(
             .map_err(|e| StorageError::Query { source: e })?;
     let action_log_deleted = action_log_result.rows_affected();
 
+    // 7. Delete old keyword attribution rows.
+    let keyword_hits_result = sqlx::query(
+        "DELETE FROM discovery_keyword_hits WHERE first_seen_at < ? AND account_id = ?",
+    )
+    .bind(&replied_cutoff)
+    .bind(account_id)
+    .execute(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+    let keyword_hits_deleted = keyword_hits_result.rows_affected();
+
+    // 8. Delete old slow-query records.
+    let slow_queries_deleted = super::query_log::prune_slow_queries(pool, 14).await?;
+
+    // 9. Purge soft-deleted approval items and target accounts.
+    let approval_items_purged =
+        super::approval_queue::purge_deleted_for(pool, account_id, &replied_cutoff).await?;
+    let target_accounts_purged = super::target_accounts::purge_deleted_target_accounts_for(
+        pool,
+        account_id,
+        &replied_cutoff,
+    )
+    .await?;
+
     let total_deleted = discovered_tweets_deleted
         + replies_deleted
         + original_tweets_deleted
         + threads_deleted
-        + action_log_deleted;
+        + action_log_deleted
+        + keyword_hits_deleted
+        + slow_queries_deleted
+        + approval_items_purged
+        + target_accounts_purged;
 
     let vacuum_run = if total_deleted > 1000 {
         sqlx::query("VACUUM")
@@ -142,6 +181,10 @@ pub async fn run_cleanup_for(
         original_tweets_deleted,
         threads_deleted,
         action_log_deleted,
+        keyword_hits_deleted,
+        slow_queries_deleted,
+        approval_items_purged,
+        target_accounts_purged,
         total_deleted,
         vacuum_run,
     };
@@ -152,6 +195,10 @@ pub async fn run_cleanup_for(
         original_tweets = stats.original_tweets_deleted,
         threads = stats.threads_deleted,
         action_log = stats.action_log_deleted,
+        keyword_hits = stats.keyword_hits_deleted,
+        slow_queries = stats.slow_queries_deleted,
+        approval_items = stats.approval_items_purged,
+        target_accounts = stats.target_accounts_purged,
         total = stats.total_deleted,
         vacuum = stats.vacuum_run,
         "Cleanup completed"
@@ -169,6 +216,9 @@ pub async fn run_cleanup_for(
 /// - Original tweets: `retention_days`.
 /// - Threads: `retention_days` (CASCADE deletes thread_tweets).
 /// - Action log: 14 days (fixed).
+/// - Keyword hits: `retention_days`.
+/// - Slow queries: 14 days (fixed, shared by all accounts).
+/// - Deleted approval items and removed targets: `retention_days` after deletion.
 /// - Rate limits: NEVER deleted.
 ///
 /// Runs VACUUM if more than 1000 total rows were deleted.
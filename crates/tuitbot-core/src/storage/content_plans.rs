@@ -0,0 +1,212 @@
+//! Storage for daily content mix plans.
+//!
+//! One row per account and local day (`YYYY-MM-DD` in the rate limit
+//! timezone) records the replies, original tweets, and threads planned at
+//! day start, and, once the day is over, how many were actually posted.
+
+use super::accounts::DEFAULT_ACCOUNT_ID;
+use super::DbPool;
+use crate::error::StorageError;
+
+/// Planned actions for one day.
+#[derive(Debug, Clone, PartialEq, Eq, sqlx::FromRow, serde::Serialize)]
+pub struct ContentPlanRow {
+    pub day: String,
+    pub replies: i64,
+    pub originals: i64,
+    pub threads: i64,
+    /// How the allocation was reached, for status output.
+    pub rationale: String,
+    pub created_at: String,
+    pub actual_replies: Option<i64>,
+    pub actual_originals: Option<i64>,
+    pub actual_threads: Option<i64>,
+    /// When the plan-vs-actual report was written; `None` until the day ends.
+    pub reported_at: Option<String>,
+}
+
+const PLAN_COLS: &str = "day, replies, originals, threads, rationale, created_at, \
+     actual_replies, actual_originals, actual_threads, reported_at";
+
+/// Get the plan for `day` for a specific account.
+pub async fn get_plan_for(
+    pool: &DbPool,
+    account_id: &str,
+    day: &str,
+) -> Result<Option<ContentPlanRow>, StorageError> {
+    sqlx::query_as::<_, ContentPlanRow>(&format!(
+        "SELECT {PLAN_COLS} FROM content_plans WHERE account_id = ? AND day = ?"
+    ))
+    .bind(account_id)
+    .bind(day)
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })
+}
+
+/// Get the plan for `day` for the default account.
+pub async fn get_plan(pool: &DbPool, day: &str) -> Result<Option<ContentPlanRow>, StorageError> {
+    get_plan_for(pool, DEFAULT_ACCOUNT_ID, day).await
+}
+
+/// Store the plan for `day` for a specific account. A plan already stored
+/// for that day is kept, so restarts do not re-plan mid-day. Returns the
+/// plan in effect.
+pub async fn insert_plan_for(
+    pool: &DbPool,
+    account_id: &str,
+    day: &str,
+    replies: u32,
+    originals: u32,
+    threads: u32,
+    rationale: &str,
+) -> Result<ContentPlanRow, StorageError> {
+    sqlx::query(
+        "INSERT INTO content_plans (account_id, day, replies, originals, threads, rationale) \
+         VALUES (?, ?, ?, ?, ?, ?) ON CONFLICT(account_id, day) DO NOTHING",
+    )
+    .bind(account_id)
+    .bind(day)
+    .bind(replies)
+    .bind(originals)
+    .bind(threads)
+    .bind(rationale)
+    .execute(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+
+    sqlx::query_as::<_, ContentPlanRow>(&format!(
+        "SELECT {PLAN_COLS} FROM content_plans WHERE account_id = ? AND day = ?"
+    ))
+    .bind(account_id)
+    .bind(day)
+    .fetch_one(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })
+}
+
+/// Plans of a specific account from days before `day` that have no
+/// plan-vs-actual report yet, oldest first.
+pub async fn get_unreported_before_for(
+    pool: &DbPool,
+    account_id: &str,
+    day: &str,
+) -> Result<Vec<ContentPlanRow>, StorageError> {
+    sqlx::query_as::<_, ContentPlanRow>(&format!(
+        "SELECT {PLAN_COLS} FROM content_plans \
+         WHERE account_id = ? AND day < ? AND reported_at IS NULL ORDER BY day"
+    ))
+    .bind(account_id)
+    .bind(day)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })
+}
+
+/// Record what was actually posted on `day` for a specific account.
+pub async fn record_actuals_for(
+    pool: &DbPool,
+    account_id: &str,
+    day: &str,
+    actual: &PostCounts,
+) -> Result<(), StorageError> {
+    sqlx::query(
+        "UPDATE content_plans SET actual_replies = ?, actual_originals = ?, actual_threads = ?, \
+             reported_at = strftime('%Y-%m-%dT%H:%M:%SZ', 'now') \
+         WHERE account_id = ? AND day = ?",
+    )
+    .bind(actual.replies)
+    .bind(actual.originals)
+    .bind(actual.threads)
+    .bind(account_id)
+    .bind(day)
+    .execute(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+    Ok(())
+}
+
+/// Replies, original tweets, and threads posted in a time range.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize)]
+pub struct PostCounts {
+    pub replies: i64,
+    pub originals: i64,
+    pub threads: i64,
+}
+
+/// Count what a specific account posted with `created_at` in
+/// `[from, to)` (ISO-8601 UTC).
+pub async fn count_posts_between_for(
+    pool: &DbPool,
+    account_id: &str,
+    from: &str,
+    to: &str,
+) -> Result<PostCounts, StorageError> {
+    let (replies, originals, threads): (i64, i64, i64) = sqlx::query_as(
+        "SELECT \
+             (SELECT COUNT(*) FROM replies_sent WHERE account_id = ?1 AND status = 'sent' \
+                 AND created_at >= ?2 AND created_at < ?3), \
+             (SELECT COUNT(*) FROM original_tweets WHERE account_id = ?1 AND status = 'sent' \
+                 AND created_at >= ?2 AND created_at < ?3), \
+             (SELECT COUNT(*) FROM threads WHERE account_id = ?1 AND status = 'sent' \
+                 AND created_at >= ?2 AND created_at < ?3)",
+    )
+    .bind(account_id)
+    .bind(from)
+    .bind(to)
+    .fetch_one(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+    Ok(PostCounts {
+        replies,
+        originals,
+        threads,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::init_test_db;
+
+    #[tokio::test]
+    async fn plan_is_kept_and_reported_once() {
+        let pool = init_test_db().await.expect("init db");
+        let plan = insert_plan_for(&pool, DEFAULT_ACCOUNT_ID, "2026-03-02", 5, 2, 1, "ratios")
+            .await
+            .expect("insert");
+        assert_eq!((plan.replies, plan.originals, plan.threads), (5, 2, 1));
+
+        // A second plan for the same day does not replace the first.
+        let again = insert_plan_for(&pool, DEFAULT_ACCOUNT_ID, "2026-03-02", 9, 9, 9, "")
+            .await
+            .expect("insert");
+        assert_eq!(again.replies, 5);
+
+        let pending = get_unreported_before_for(&pool, DEFAULT_ACCOUNT_ID, "2026-03-03")
+            .await
+            .expect("pending");
+        assert_eq!(pending.len(), 1);
+
+        let actual = PostCounts {
+            replies: 4,
+            originals: 2,
+            threads: 0,
+        };
+        record_actuals_for(&pool, DEFAULT_ACCOUNT_ID, "2026-03-02", &actual)
+            .await
+            .expect("record");
+        let plan = get_plan(&pool, "2026-03-02")
+            .await
+            .expect("get")
+            .expect("plan");
+        assert_eq!(plan.actual_replies, Some(4));
+        assert!(plan.reported_at.is_some());
+        assert!(
+            get_unreported_before_for(&pool, DEFAULT_ACCOUNT_ID, "2026-03-03")
+                .await
+                .expect("pending")
+                .is_empty()
+        );
+    }
+}
@@ -0,0 +1,203 @@
+//! Aggregated home-screen dashboard queries.
+//!
+//! Gathers everything the dashboard home view needs from the database in
+//! two queries: one row of scalar aggregates and the next scheduled posts.
+
+use serde::Serialize;
+
+use super::accounts::DEFAULT_ACCOUNT_ID;
+use super::rate_limits::{ActionUsage, DailyUsage};
+use super::DbPool;
+use crate::error::StorageError;
+
+/// Latest follower count and the change since the previous snapshot.
+#[derive(Debug, Clone, Serialize)]
+pub struct FollowerDelta {
+    /// Follower count from the newest snapshot, if any.
+    pub count: Option<i64>,
+    /// Change since the snapshot before it; `None` with fewer than two snapshots.
+    pub delta: Option<i64>,
+}
+
+/// A scheduled post coming up next.
+#[derive(Debug, Clone, Serialize, sqlx::FromRow)]
+pub struct UpcomingSlot {
+    pub id: i64,
+    /// Content type: "tweet" or "thread".
+    pub content_type: String,
+    /// ISO-8601 time the post is scheduled for.
+    pub scheduled_for: String,
+}
+
+/// Database-backed part of the dashboard summary.
+#[derive(Debug, Clone, Serialize)]
+pub struct DashboardSummary {
+    /// Today's reply, tweet, and thread counts against their limits.
+    pub usage: DailyUsage,
+    /// Approval items waiting for review.
+    pub pending_approvals: i64,
+    pub followers: FollowerDelta,
+    /// Status of the latest visibility self-check, if any.
+    pub visibility_status: Option<String>,
+    /// Whether the compliance kill switch is engaged.
+    pub kill_switch_active: bool,
+    /// Whether draft-only mode is holding posts for review.
+    pub draft_only_active: bool,
+    /// Next scheduled posts, soonest first.
+    pub upcoming: Vec<UpcomingSlot>,
+}
+
+#[derive(sqlx::FromRow)]
+struct DashboardRow {
+    replies_used: i64,
+    replies_max: i64,
+    tweets_used: i64,
+    tweets_max: i64,
+    threads_used: i64,
+    threads_max: i64,
+    pending_approvals: i64,
+    follower_count: Option<i64>,
+    previous_follower_count: Option<i64>,
+    visibility_status: Option<String>,
+    kill_switch_active: bool,
+    draft_only_active: bool,
+}
+
+/// Build the dashboard summary for a specific account.
+///
+/// Returns at most `upcoming_limit` upcoming scheduled posts.
+pub async fn get_dashboard_summary_for(
+    pool: &DbPool,
+    account_id: &str,
+    upcoming_limit: u32,
+) -> Result<DashboardSummary, StorageError> {
+    let row: DashboardRow = sqlx::query_as(
+        "SELECT \
+           COALESCE((SELECT request_count FROM rate_limits \
+                     WHERE account_id = ?1 AND action_type = 'reply'), 0) AS replies_used, \
+           COALESCE((SELECT max_requests FROM rate_limits \
+                     WHERE account_id = ?1 AND action_type = 'reply'), 0) AS replies_max, \
+           COALESCE((SELECT request_count FROM rate_limits \
+                     WHERE account_id = ?1 AND action_type = 'tweet'), 0) AS tweets_used, \
+           COALESCE((SELECT max_requests FROM rate_limits \
+                     WHERE account_id = ?1 AND action_type = 'tweet'), 0) AS tweets_max, \
+           COALESCE((SELECT request_count FROM rate_limits \
+                     WHERE account_id = ?1 AND action_type = 'thread'), 0) AS threads_used, \
+           COALESCE((SELECT max_requests FROM rate_limits \
+                     WHERE account_id = ?1 AND action_type = 'thread'), 0) AS threads_max, \
+           (SELECT COUNT(*) FROM approval_queue \
+            WHERE account_id = ?1 AND status = 'pending' AND deleted_at IS NULL) \
+            AS pending_approvals, \
+           (SELECT follower_count FROM follower_snapshots WHERE account_id = ?1 \
+            ORDER BY snapshot_date DESC LIMIT 1) AS follower_count, \
+           (SELECT follower_count FROM follower_snapshots WHERE account_id = ?1 \
+            ORDER BY snapshot_date DESC LIMIT 1 OFFSET 1) AS previous_follower_count, \
+           (SELECT status FROM account_health_checks WHERE account_id = ?1 \
+            ORDER BY checked_at DESC, id DESC LIMIT 1) AS visibility_status, \
+           COALESCE((SELECT active FROM kill_switch WHERE id = 1), 0) AS kill_switch_active, \
+           COALESCE((SELECT active FROM draft_only_mode WHERE id = 1), 0) AS draft_only_active",
+    )
+    .bind(account_id)
+    .fetch_one(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+
+    let upcoming: Vec<UpcomingSlot> = sqlx::query_as(
+        "SELECT id, content_type, scheduled_for FROM scheduled_content \
+         WHERE account_id = ? AND status = 'scheduled' AND scheduled_for IS NOT NULL \
+           AND scheduled_for > datetime('now') \
+         ORDER BY scheduled_for ASC LIMIT ?",
+    )
+    .bind(account_id)
+    .bind(upcoming_limit)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+
+    let delta = row
+        .follower_count
+        .zip(row.previous_follower_count)
+        .map(|(count, previous)| count - previous);
+
+    Ok(DashboardSummary {
+        usage: DailyUsage {
+            replies: ActionUsage {
+                used: row.replies_used,
+                max: row.replies_max,
+            },
+            tweets: ActionUsage {
+                used: row.tweets_used,
+                max: row.tweets_max,
+            },
+            threads: ActionUsage {
+                used: row.threads_used,
+                max: row.threads_max,
+            },
+        },
+        pending_approvals: row.pending_approvals,
+        followers: FollowerDelta {
+            count: row.follower_count,
+            delta,
+        },
+        visibility_status: row.visibility_status,
+        kill_switch_active: row.kill_switch_active,
+        draft_only_active: row.draft_only_active,
+        upcoming,
+    })
+}
+
+/// Build the dashboard summary.
+pub async fn get_dashboard_summary(
+    pool: &DbPool,
+    upcoming_limit: u32,
+) -> Result<DashboardSummary, StorageError> {
+    get_dashboard_summary_for(pool, DEFAULT_ACCOUNT_ID, upcoming_limit).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::{approval_queue, init_test_db, scheduled_content};
+
+    #[tokio::test]
+    async fn empty_database_summary() {
+        let pool = init_test_db().await.expect("init db");
+        let summary = get_dashboard_summary(&pool, 3).await.expect("summary");
+        assert_eq!(summary.pending_approvals, 0);
+        assert_eq!(summary.usage.replies.used, 0);
+        assert!(summary.followers.count.is_none());
+        assert!(summary.followers.delta.is_none());
+        assert!(summary.visibility_status.is_none());
+        assert!(!summary.kill_switch_active);
+        assert!(summary.upcoming.is_empty());
+    }
+
+    #[tokio::test]
+    async fn summary_aggregates_counts() {
+        let pool = init_test_db().await.expect("init db");
+
+        sqlx::query(
+            "INSERT INTO follower_snapshots (snapshot_date, follower_count, following_count, tweet_count) \
+             VALUES ('2026-03-01', 100, 10, 5), ('2026-03-02', 112, 10, 6)",
+        )
+        .execute(&pool)
+        .await
+        .expect("insert snapshots");
+        approval_queue::enqueue(&pool, "reply", "t1", "alice", "Hello", "", "", 80.0, "[]")
+            .await
+            .expect("enqueue");
+        scheduled_content::insert(&pool, "tweet", "Later", Some("2099-01-01T09:00:00Z"))
+            .await
+            .expect("insert scheduled");
+        scheduled_content::insert(&pool, "tweet", "Past", Some("2020-01-01T09:00:00Z"))
+            .await
+            .expect("insert scheduled");
+
+        let summary = get_dashboard_summary(&pool, 3).await.expect("summary");
+        assert_eq!(summary.pending_approvals, 1);
+        assert_eq!(summary.followers.count, Some(112));
+        assert_eq!(summary.followers.delta, Some(12));
+        assert_eq!(summary.upcoming.len(), 1);
+        assert_eq!(summary.upcoming[0].scheduled_for, "2099-01-01T09:00:00Z");
+    }
+}
@@ -0,0 +1,144 @@
+//! Persisted draft-only mode.
+//!
+//! A single global flag that, while on, keeps the pipeline producing drafts
+//! and approval items but disables posting to X everywhere: the posting
+//! queue routes every post to the approval queue, the approval and Reddit
+//! posters hold approved items, and MCP post tools queue instead of posting.
+//! Unlike dry-run or shadow modes, nothing is thrown away; held items are
+//! posted once the mode is lifted. Like the kill switch, it survives
+//! restarts and can be flipped from another process, and every change is
+//! recorded in the action log.
+
+use super::DbPool;
+use crate::error::StorageError;
+
+/// Current draft-only state.
+#[derive(Debug, Clone, Default, PartialEq, sqlx::FromRow, serde::Serialize)]
+pub struct DraftOnlyState {
+    /// Whether posting is currently disabled.
+    pub active: bool,
+    /// Reason given when the mode was turned on.
+    pub reason: Option<String>,
+    /// Who last changed the mode: `cli`, `api`, ...
+    pub source: Option<String>,
+    /// ISO-8601 UTC timestamp of the last change.
+    pub changed_at: Option<String>,
+}
+
+/// Read the draft-only state. A missing row means the mode was never on.
+pub async fn get_draft_only(pool: &DbPool) -> Result<DraftOnlyState, StorageError> {
+    let row = sqlx::query_as::<_, DraftOnlyState>(
+        "SELECT active, reason, source, changed_at FROM draft_only_mode WHERE id = 1",
+    )
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+
+    Ok(row.unwrap_or_default())
+}
+
+/// Whether draft-only mode is currently on.
+pub async fn is_draft_only_active(pool: &DbPool) -> Result<bool, StorageError> {
+    Ok(get_draft_only(pool).await?.active)
+}
+
+/// Turn draft-only mode on, holding every post until it is lifted.
+pub async fn enable_draft_only(
+    pool: &DbPool,
+    reason: Option<&str>,
+    source: &str,
+) -> Result<DraftOnlyState, StorageError> {
+    set_draft_only(pool, true, reason, source).await?;
+    let message = match reason {
+        Some(reason) => format!("Draft-only mode enabled via {source}: {reason}"),
+        None => format!("Draft-only mode enabled via {source}"),
+    };
+    let _ =
+        super::action_log::log_action(pool, "draft_only", "enabled", Some(&message), None).await;
+    tracing::warn!(source, reason, "Draft-only mode enabled, posting disabled");
+    get_draft_only(pool).await
+}
+
+/// Lift draft-only mode; held approved items are posted again.
+pub async fn disable_draft_only(
+    pool: &DbPool,
+    source: &str,
+) -> Result<DraftOnlyState, StorageError> {
+    set_draft_only(pool, false, None, source).await?;
+    let _ = super::action_log::log_action(
+        pool,
+        "draft_only",
+        "disabled",
+        Some(&format!("Draft-only mode lifted via {source}")),
+        None,
+    )
+    .await;
+    tracing::info!(source, "Draft-only mode lifted, posting re-enabled");
+    get_draft_only(pool).await
+}
+
+async fn set_draft_only(
+    pool: &DbPool,
+    active: bool,
+    reason: Option<&str>,
+    source: &str,
+) -> Result<(), StorageError> {
+    sqlx::query(
+        "INSERT INTO draft_only_mode (id, active, reason, source, changed_at) \
+         VALUES (1, ?, ?, ?, strftime('%Y-%m-%dT%H:%M:%SZ', 'now')) \
+         ON CONFLICT(id) DO UPDATE SET \
+            active = excluded.active, \
+            reason = excluded.reason, \
+            source = excluded.source, \
+            changed_at = excluded.changed_at",
+    )
+    .bind(active)
+    .bind(reason)
+    .bind(source)
+    .execute(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::init_test_db;
+
+    #[tokio::test]
+    async fn defaults_to_off() {
+        let pool = init_test_db().await.expect("init db");
+        let state = get_draft_only(&pool).await.expect("get");
+        assert!(!state.active);
+        assert!(state.reason.is_none());
+    }
+
+    #[tokio::test]
+    async fn enable_and_disable_round_trip() {
+        let pool = init_test_db().await.expect("init db");
+
+        let state = enable_draft_only(&pool, Some("launch review"), "cli")
+            .await
+            .expect("enable");
+        assert!(state.active);
+        assert_eq!(state.reason.as_deref(), Some("launch review"));
+        assert!(is_draft_only_active(&pool).await.expect("active"));
+
+        let state = disable_draft_only(&pool, "api").await.expect("disable");
+        assert!(!state.active);
+        assert!(state.reason.is_none());
+        assert_eq!(state.source.as_deref(), Some("api"));
+
+        let log = crate::storage::action_log::get_actions_since(
+            &pool,
+            "1970-01-01T00:00:00Z",
+            Some("draft_only"),
+        )
+        .await
+        .expect("log");
+        let statuses: Vec<_> = log.iter().map(|e| e.status.as_str()).collect();
+        assert_eq!(statuses, vec!["enabled", "disabled"]);
+    }
+}
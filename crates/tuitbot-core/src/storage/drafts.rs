@@ -0,0 +1,151 @@
+//! Draft workspace queries.
+//!
+//! Drafts are `scheduled_content` rows in status `draft`. They can be edited
+//! and QA-checked freely, given a planned calendar slot, and then either
+//! scheduled or promoted into the approval queue. The basic create, edit,
+//! delete, and schedule operations live in [`super::scheduled_content`].
+
+use super::accounts::DEFAULT_ACCOUNT_ID;
+use super::scheduled_content::ScheduledContent;
+use super::DbPool;
+use crate::error::StorageError;
+
+/// Fetch a draft by ID for a specific account. Returns `None` if the item
+/// does not exist or is no longer a draft.
+pub async fn get_draft_for(
+    pool: &DbPool,
+    account_id: &str,
+    id: i64,
+) -> Result<Option<ScheduledContent>, StorageError> {
+    sqlx::query_as::<_, ScheduledContent>(
+        "SELECT * FROM scheduled_content WHERE id = ? AND account_id = ? AND status = 'draft'",
+    )
+    .bind(id)
+    .bind(account_id)
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })
+}
+
+/// Fetch a draft by ID.
+pub async fn get_draft(pool: &DbPool, id: i64) -> Result<Option<ScheduledContent>, StorageError> {
+    get_draft_for(pool, DEFAULT_ACCOUNT_ID, id).await
+}
+
+/// Set or clear a draft's planned calendar slot for a specific account.
+///
+/// The slot is stored in `scheduled_for` so the draft shows up on the
+/// content calendar, but nothing is posted until the draft is scheduled.
+/// Returns `false` if the item is not a draft.
+pub async fn plan_draft_for(
+    pool: &DbPool,
+    account_id: &str,
+    id: i64,
+    planned_for: Option<&str>,
+) -> Result<bool, StorageError> {
+    let result = sqlx::query(
+        "UPDATE scheduled_content SET scheduled_for = ?, updated_at = datetime('now') \
+         WHERE id = ? AND account_id = ? AND status = 'draft'",
+    )
+    .bind(planned_for)
+    .bind(id)
+    .bind(account_id)
+    .execute(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// Set or clear a draft's planned calendar slot.
+pub async fn plan_draft(
+    pool: &DbPool,
+    id: i64,
+    planned_for: Option<&str>,
+) -> Result<bool, StorageError> {
+    plan_draft_for(pool, DEFAULT_ACCOUNT_ID, id, planned_for).await
+}
+
+/// Mark a draft as promoted into the approval queue for a specific account.
+///
+/// Returns `false` if the item was no longer a draft, e.g. because a
+/// concurrent request promoted or deleted it first.
+pub async fn mark_promoted_for(
+    pool: &DbPool,
+    account_id: &str,
+    id: i64,
+    approval_queue_id: i64,
+) -> Result<bool, StorageError> {
+    let result = sqlx::query(
+        "UPDATE scheduled_content SET status = 'promoted', approval_queue_id = ?, \
+         updated_at = datetime('now') \
+         WHERE id = ? AND account_id = ? AND status = 'draft'",
+    )
+    .bind(approval_queue_id)
+    .bind(id)
+    .bind(account_id)
+    .execute(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// Mark a draft as promoted into the approval queue.
+pub async fn mark_promoted(
+    pool: &DbPool,
+    id: i64,
+    approval_queue_id: i64,
+) -> Result<bool, StorageError> {
+    mark_promoted_for(pool, DEFAULT_ACCOUNT_ID, id, approval_queue_id).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::init_test_db;
+    use crate::storage::scheduled_content;
+
+    #[tokio::test]
+    async fn planned_draft_appears_on_calendar_but_is_not_due() {
+        let pool = init_test_db().await.expect("init db");
+        let id = scheduled_content::insert_draft(&pool, "tweet", "Idea", "manual")
+            .await
+            .unwrap();
+
+        assert!(plan_draft(&pool, id, Some("2020-01-01T09:00:00Z"))
+            .await
+            .unwrap());
+
+        let in_range =
+            scheduled_content::get_in_range(&pool, "2020-01-01T00:00:00Z", "2020-01-02T00:00:00Z")
+                .await
+                .unwrap();
+        assert_eq!(in_range.len(), 1);
+        assert_eq!(in_range[0].status, "draft");
+        assert!(scheduled_content::get_due_items(&pool)
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn promoted_draft_leaves_workspace() {
+        let pool = init_test_db().await.expect("init db");
+        let id = scheduled_content::insert_draft(&pool, "tweet", "Idea", "manual")
+            .await
+            .unwrap();
+
+        assert!(mark_promoted(&pool, id, 7).await.unwrap());
+        assert!(!mark_promoted(&pool, id, 8).await.unwrap());
+        assert!(get_draft(&pool, id).await.unwrap().is_none());
+
+        let item = scheduled_content::get_by_id(&pool, id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(item.status, "promoted");
+        assert_eq!(item.approval_queue_id, Some(7));
+        assert!(!plan_draft(&pool, id, None).await.unwrap());
+    }
+}
@@ -0,0 +1,125 @@
+//! Encrypted export bundles for moving an installation to a new machine.
+//!
+//! A bundle is a gzip-compressed tar archive holding the config file, a
+//! `VACUUM INTO` snapshot of the database, the OAuth token file, and stored
+//! media, plus a manifest with the SHA-256 of each file. The archive is
+//! encrypted with AES-256-GCM under a key derived from a passphrase with
+//! PBKDF2-HMAC-SHA256. The bundle header is authenticated along with the
+//! ciphertext, so any modification fails decryption.
+
+mod reader;
+mod writer;
+
+#[cfg(test)]
+mod tests;
+
+use std::num::NonZeroU32;
+use std::path::Path;
+
+use ring::aead::{LessSafeKey, UnboundKey, AES_256_GCM, NONCE_LEN};
+use ring::pbkdf2;
+use serde::{Deserialize, Serialize};
+
+use crate::error::BundleError;
+
+pub use reader::{import_bundle, read_bundle};
+pub use writer::export_bundle;
+
+/// Leading bytes of every bundle.
+const MAGIC: &[u8; 8] = b"TUITBUND";
+
+/// Bundle format version.
+const FORMAT_VERSION: u8 = 1;
+
+/// PBKDF2 iterations for new bundles.
+const KDF_ITERATIONS: u32 = 600_000;
+
+const SALT_LEN: usize = 16;
+
+/// Magic, version, iterations, salt, and nonce.
+const HEADER_LEN: usize = MAGIC.len() + 1 + 4 + SALT_LEN + NONCE_LEN;
+
+/// Paths of the fixed files within a bundle.
+const MANIFEST_FILE: &str = "manifest.json";
+const CONFIG_FILE: &str = "config.toml";
+const DB_FILE: &str = "tuitbot.db";
+const TOKENS_FILE: &str = "tokens.json";
+const MEDIA_DIR: &str = "media/";
+
+/// A file stored in a bundle.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BundleFile {
+    pub path: String,
+    pub size_bytes: u64,
+    /// SHA-256 of the file contents (hex).
+    pub sha256: String,
+}
+
+/// Describes a bundle's contents.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BundleManifest {
+    pub version: u8,
+    pub created_at: String,
+    /// Version of Tuitbot that wrote the bundle.
+    pub tuitbot_version: String,
+    /// Whether API keys, secrets, and passwords were kept in the config.
+    pub secrets_included: bool,
+    /// Data directory on the exporting machine, used to rewrite media paths.
+    pub data_dir: String,
+    pub files: Vec<BundleFile>,
+}
+
+impl BundleManifest {
+    /// Number of media files in the bundle.
+    pub fn media_count(&self) -> usize {
+        self.files
+            .iter()
+            .filter(|f| f.path.starts_with(MEDIA_DIR))
+            .count()
+    }
+
+    /// Whether the bundle contains `path`.
+    pub fn contains(&self, path: &str) -> bool {
+        self.files.iter().any(|f| f.path == path)
+    }
+}
+
+fn derive_key(passphrase: &str, salt: &[u8], iterations: u32) -> Result<LessSafeKey, BundleError> {
+    let iterations = NonZeroU32::new(iterations).ok_or_else(|| invalid("zero KDF iterations"))?;
+    let mut key = [0u8; 32];
+    pbkdf2::derive(
+        pbkdf2::PBKDF2_HMAC_SHA256,
+        iterations,
+        salt,
+        passphrase.as_bytes(),
+        &mut key,
+    );
+    let key = UnboundKey::new(&AES_256_GCM, &key).map_err(|_| invalid("bad key length"))?;
+    Ok(LessSafeKey::new(key))
+}
+
+fn read_file(path: &Path) -> Result<Vec<u8>, BundleError> {
+    std::fs::read(path).map_err(|e| io_error(path, e))
+}
+
+fn io_error(path: &Path, source: std::io::Error) -> BundleError {
+    BundleError::Io {
+        path: path.display().to_string(),
+        source,
+    }
+}
+
+fn invalid(message: impl Into<String>) -> BundleError {
+    BundleError::Invalid {
+        message: message.into(),
+    }
+}
+
+#[cfg(unix)]
+fn restrict_permissions(path: &Path) {
+    use std::os::unix::fs::PermissionsExt;
+    let _ = std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o600));
+}
+
+#[cfg(not(unix))]
+fn restrict_permissions(_path: &Path) {}
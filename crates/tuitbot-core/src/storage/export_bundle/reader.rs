@@ -0,0 +1,214 @@
+//! Bundle import: decrypt, verify, and restore an installation.
+
+use std::collections::BTreeMap;
+use std::io::Read;
+use std::path::Path;
+
+use chrono::Utc;
+use flate2::read::GzDecoder;
+use ring::aead::{Aad, Nonce, NONCE_LEN};
+use sha2::{Digest, Sha256};
+use sqlx::sqlite::{SqliteConnectOptions, SqlitePoolOptions};
+
+use super::{
+    derive_key, invalid, io_error, read_file, restrict_permissions, BundleManifest, CONFIG_FILE,
+    DB_FILE, FORMAT_VERSION, HEADER_LEN, MAGIC, MANIFEST_FILE, MEDIA_DIR, SALT_LEN, TOKENS_FILE,
+};
+use crate::error::BundleError;
+use crate::storage::backup;
+
+/// Decrypt a bundle and verify every file against the manifest.
+pub fn read_bundle(
+    path: &Path,
+    passphrase: &str,
+) -> Result<(BundleManifest, BTreeMap<String, Vec<u8>>), BundleError> {
+    let sealed = read_file(path)?;
+    let archive = open(&sealed, passphrase)?;
+    let mut entries = read_archive(&archive).map_err(|e| invalid(e.to_string()))?;
+
+    let manifest_json = entries
+        .remove(MANIFEST_FILE)
+        .ok_or_else(|| invalid("missing manifest"))?;
+    let manifest: BundleManifest =
+        serde_json::from_slice(&manifest_json).map_err(|e| invalid(e.to_string()))?;
+    if manifest.version != FORMAT_VERSION {
+        return Err(invalid(format!(
+            "unsupported manifest version {}",
+            manifest.version
+        )));
+    }
+    if !manifest.contains(CONFIG_FILE) || !manifest.contains(DB_FILE) {
+        return Err(invalid("config or database missing"));
+    }
+
+    for file in &manifest.files {
+        if !is_allowed_path(&file.path) {
+            return Err(invalid(format!("unexpected file {}", file.path)));
+        }
+        let data = entries
+            .get(&file.path)
+            .ok_or_else(|| invalid(format!("missing file {}", file.path)))?;
+        if data.len() as u64 != file.size_bytes || hex::encode(Sha256::digest(data)) != file.sha256
+        {
+            return Err(BundleError::ChecksumMismatch {
+                path: file.path.clone(),
+            });
+        }
+    }
+    entries.retain(|path, _| manifest.contains(path));
+
+    Ok((manifest, entries))
+}
+
+/// Restore a bundle into `config_path` and `data_dir`.
+///
+/// Refuses to replace an existing config or database unless `force`. The
+/// database is restored through [`backup::restore_from_backup`], so it is
+/// validated and the current one is kept as a safety backup. Media paths in
+/// the approval queue are rewritten when `data_dir` differs from the
+/// exporting machine's.
+pub async fn import_bundle(
+    bundle_path: &Path,
+    passphrase: &str,
+    config_path: &Path,
+    data_dir: &Path,
+    force: bool,
+) -> Result<BundleManifest, BundleError> {
+    let (manifest, mut entries) = read_bundle(bundle_path, passphrase)?;
+
+    let db_path = data_dir.join(DB_FILE);
+    if !force {
+        for existing in [config_path, db_path.as_path()] {
+            if existing.exists() {
+                return Err(BundleError::AlreadyExists {
+                    path: existing.display().to_string(),
+                });
+            }
+        }
+    }
+    std::fs::create_dir_all(data_dir).map_err(|e| io_error(data_dir, e))?;
+
+    // Database first: it is validated before anything else is replaced.
+    let db = entries.remove(DB_FILE).unwrap_or_default();
+    let staged = data_dir.join(format!(
+        ".tuitbot_import_{}.db",
+        Utc::now().timestamp_millis()
+    ));
+    std::fs::write(&staged, db).map_err(|e| io_error(&staged, e))?;
+    let restored = backup::restore_from_backup(&staged, &db_path).await;
+    let _ = std::fs::remove_file(&staged);
+    restored?;
+
+    if let Some(config) = entries.remove(CONFIG_FILE) {
+        if config_path.exists() {
+            let saved = config_path.with_extension("toml.bak");
+            std::fs::copy(config_path, &saved).map_err(|e| io_error(&saved, e))?;
+        }
+        if let Some(parent) = config_path.parent() {
+            std::fs::create_dir_all(parent).map_err(|e| io_error(parent, e))?;
+        }
+        std::fs::write(config_path, config).map_err(|e| io_error(config_path, e))?;
+        restrict_permissions(config_path);
+    }
+
+    if let Some(tokens) = entries.remove(TOKENS_FILE) {
+        let path = data_dir.join(TOKENS_FILE);
+        std::fs::write(&path, tokens).map_err(|e| io_error(&path, e))?;
+        restrict_permissions(&path);
+    }
+
+    let media_dir = data_dir.join("media");
+    for (path, data) in &entries {
+        let Some(name) = path.strip_prefix(MEDIA_DIR) else {
+            continue;
+        };
+        std::fs::create_dir_all(&media_dir).map_err(|e| io_error(&media_dir, e))?;
+        let dest = media_dir.join(name);
+        std::fs::write(&dest, data).map_err(|e| io_error(&dest, e))?;
+    }
+
+    let old_media = Path::new(&manifest.data_dir).join("media");
+    if manifest.media_count() > 0 && old_media != media_dir {
+        rewrite_media_paths(&db_path, &old_media, &media_dir).await?;
+    }
+
+    Ok(manifest)
+}
+
+/// Point approval queue media at the new media directory.
+async fn rewrite_media_paths(db_path: &Path, from: &Path, to: &Path) -> Result<(), BundleError> {
+    let options = SqliteConnectOptions::new().filename(db_path);
+    let pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .connect_with(options)
+        .await
+        .map_err(|e| crate::error::StorageError::Connection { source: e })?;
+    let result = sqlx::query(
+        "UPDATE approval_queue SET media_paths = REPLACE(media_paths, ?, ?) \
+         WHERE media_paths LIKE '%' || ? || '%'",
+    )
+    .bind(from.display().to_string())
+    .bind(to.display().to_string())
+    .bind(from.display().to_string())
+    .execute(&pool)
+    .await;
+    pool.close().await;
+    result.map_err(|e| crate::error::StorageError::Query { source: e })?;
+    Ok(())
+}
+
+/// Only the fixed files and flat `media/<name>` entries are accepted.
+pub(super) fn is_allowed_path(path: &str) -> bool {
+    match path.strip_prefix(MEDIA_DIR) {
+        Some(name) => {
+            !name.is_empty()
+                && !name.starts_with('.')
+                && !name.contains(['/', '\\'])
+                && name != ".."
+        }
+        None => [CONFIG_FILE, DB_FILE, TOKENS_FILE].contains(&path),
+    }
+}
+
+fn read_archive(archive: &[u8]) -> std::io::Result<BTreeMap<String, Vec<u8>>> {
+    let mut entries = BTreeMap::new();
+    let mut reader = tar::Archive::new(GzDecoder::new(archive));
+    for entry in reader.entries()? {
+        let mut entry = entry?;
+        let path = entry.path()?.to_string_lossy().to_string();
+        let mut data = Vec::new();
+        entry.read_to_end(&mut data)?;
+        entries.insert(path, data);
+    }
+    Ok(entries)
+}
+
+/// Decrypt a bundle produced by [`seal`](super::writer::seal).
+pub(super) fn open(sealed: &[u8], passphrase: &str) -> Result<Vec<u8>, BundleError> {
+    if sealed.len() < HEADER_LEN || &sealed[..MAGIC.len()] != MAGIC {
+        return Err(invalid("not a Tuitbot export bundle"));
+    }
+    let (header, body) = sealed.split_at(HEADER_LEN);
+    let version = header[MAGIC.len()];
+    if version != FORMAT_VERSION {
+        return Err(invalid(format!("unsupported bundle version {version}")));
+    }
+    let mut offset = MAGIC.len() + 1;
+    let iterations = u32::from_be_bytes(
+        header[offset..offset + 4]
+            .try_into()
+            .map_err(|_| invalid("truncated header"))?,
+    );
+    offset += 4;
+    let salt = &header[offset..offset + SALT_LEN];
+    offset += SALT_LEN;
+    let nonce = Nonce::try_assume_unique_for_key(&header[offset..offset + NONCE_LEN])
+        .map_err(|_| invalid("truncated header"))?;
+
+    let key = derive_key(passphrase, salt, iterations)?;
+    let mut body = body.to_vec();
+    let plaintext = key
+        .open_in_place(nonce, Aad::from(header), &mut body)
+        .map_err(|_| BundleError::Decrypt)?;
+    Ok(plaintext.to_vec())
+}
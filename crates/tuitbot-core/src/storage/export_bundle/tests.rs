@@ -0,0 +1,100 @@
+use super::reader::{is_allowed_path, open};
+use super::writer::{seal, strip_secrets};
+use super::*;
+use crate::storage::init_db;
+
+#[test]
+fn sealed_bundle_rejects_wrong_passphrase_and_tampering() {
+    let sealed = seal(b"payload", "correct horse", 1_000).expect("seal");
+    assert_eq!(open(&sealed, "correct horse").expect("open"), b"payload");
+    assert!(matches!(
+        open(&sealed, "wrong").unwrap_err(),
+        BundleError::Decrypt
+    ));
+
+    let mut tampered = sealed.clone();
+    let last = tampered.len() - 1;
+    tampered[last] ^= 1;
+    assert!(matches!(
+        open(&tampered, "correct horse").unwrap_err(),
+        BundleError::Decrypt
+    ));
+    assert!(!is_allowed_path("media/../tokens.json"));
+    assert!(!is_allowed_path("other.txt"));
+}
+
+#[test]
+fn strips_secret_keys_from_config() {
+    let config = br#"
+[llm]
+provider = "openai"
+api_key = "sk-123"
+
+[x_api]
+client_id = "abc"
+client_secret = "shh"
+"#;
+    let stripped = strip_secrets(config).expect("strip");
+    assert!(stripped.contains("client_id"));
+    assert!(!stripped.contains("sk-123"));
+    assert!(!stripped.contains("shh"));
+}
+
+#[tokio::test]
+async fn export_then_import_round_trips() {
+    let old = tempfile::tempdir().expect("old dir");
+    let old_data = old.path().join(".tuitbot");
+    std::fs::create_dir_all(old_data.join("media")).expect("media dir");
+    let config_path = old_data.join("config.toml");
+    std::fs::write(&config_path, "[llm]\napi_key = \"sk-1\"\n").expect("config");
+    std::fs::write(old_data.join("tokens.json"), "{}").expect("tokens");
+    let old_media = old_data.join("media").join("a.png");
+    std::fs::write(&old_media, b"png").expect("media");
+
+    let pool = init_db(&old_data.join("tuitbot.db").to_string_lossy())
+        .await
+        .expect("init db");
+    sqlx::query(
+        "INSERT INTO approval_queue (action_type, generated_content, media_paths) \
+         VALUES ('tweet', 'hi', ?)",
+    )
+    .bind(format!("[\"{}\"]", old_media.display()))
+    .execute(&pool)
+    .await
+    .expect("queue");
+    let bundle = old.path().join("export.tuitbot");
+    let manifest = export_bundle(&pool, &config_path, &old_data, &bundle, "pass", true)
+        .await
+        .expect("export");
+    pool.close().await;
+    assert_eq!(manifest.media_count(), 1);
+
+    let new = tempfile::tempdir().expect("new dir");
+    let new_data = new.path().join("data");
+    let new_config = new_data.join("config.toml");
+    import_bundle(&bundle, "pass", &new_config, &new_data, false)
+        .await
+        .expect("import");
+    assert!(std::fs::read_to_string(&new_config)
+        .unwrap()
+        .contains("sk-1"));
+    assert_eq!(
+        std::fs::read(new_data.join("media").join("a.png")).unwrap(),
+        b"png"
+    );
+
+    let pool = init_db(&new_data.join("tuitbot.db").to_string_lossy())
+        .await
+        .expect("open imported");
+    let (paths,): (String,) = sqlx::query_as("SELECT media_paths FROM approval_queue")
+        .fetch_one(&pool)
+        .await
+        .expect("paths");
+    assert!(paths.contains(&new_data.join("media").display().to_string()));
+    pool.close().await;
+
+    let err = import_bundle(&bundle, "pass", &new_config, &new_data, false)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, BundleError::AlreadyExists { .. }));
+}
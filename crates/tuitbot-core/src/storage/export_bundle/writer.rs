@@ -0,0 +1,186 @@
+//! Bundle export: snapshot, archive, and encrypt an installation.
+
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use chrono::Utc;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use ring::aead::{Aad, Nonce, AES_256_GCM, NONCE_LEN};
+use ring::rand::{SecureRandom, SystemRandom};
+use sha2::{Digest, Sha256};
+
+use super::{
+    derive_key, invalid, io_error, read_file, restrict_permissions, BundleFile, BundleManifest,
+    CONFIG_FILE, DB_FILE, FORMAT_VERSION, HEADER_LEN, KDF_ITERATIONS, MAGIC, MANIFEST_FILE,
+    MEDIA_DIR, SALT_LEN, TOKENS_FILE,
+};
+use crate::config::history::is_secret_key;
+use crate::error::BundleError;
+use crate::storage::{backup, DbPool};
+
+/// Write an encrypted bundle of the installation to `dest`.
+///
+/// The database is snapshotted from `pool`; the token file and media are
+/// read from `data_dir`. Without `include_secrets`, credential keys are
+/// removed from the config (comments and formatting are not preserved then).
+pub async fn export_bundle(
+    pool: &DbPool,
+    config_path: &Path,
+    data_dir: &Path,
+    dest: &Path,
+    passphrase: &str,
+    include_secrets: bool,
+) -> Result<BundleManifest, BundleError> {
+    let mut files: Vec<(String, Vec<u8>)> = Vec::new();
+
+    let config = read_file(config_path)?;
+    let config = if include_secrets {
+        config
+    } else {
+        strip_secrets(&config)?.into_bytes()
+    };
+    files.push((CONFIG_FILE.to_string(), config));
+
+    let staging =
+        std::env::temp_dir().join(format!("tuitbot_export_{}", Utc::now().timestamp_millis()));
+    let snapshot = backup::create_backup(pool, &staging).await;
+    let db = snapshot
+        .map_err(BundleError::from)
+        .and_then(|s| read_file(&s.path));
+    let _ = std::fs::remove_dir_all(&staging);
+    files.push((DB_FILE.to_string(), db?));
+
+    let tokens = data_dir.join(TOKENS_FILE);
+    if tokens.is_file() {
+        files.push((TOKENS_FILE.to_string(), read_file(&tokens)?));
+    }
+
+    let media_dir = data_dir.join("media");
+    if let Ok(entries) = std::fs::read_dir(&media_dir) {
+        let mut media: Vec<PathBuf> = entries
+            .flatten()
+            .map(|e| e.path())
+            .filter(|p| p.is_file())
+            .collect();
+        media.sort();
+        for path in media {
+            let Some(name) = path.file_name().and_then(|n| n.to_str()) else {
+                continue;
+            };
+            files.push((format!("{MEDIA_DIR}{name}"), read_file(&path)?));
+        }
+    }
+
+    let manifest = BundleManifest {
+        version: FORMAT_VERSION,
+        created_at: Utc::now().to_rfc3339(),
+        tuitbot_version: env!("CARGO_PKG_VERSION").to_string(),
+        secrets_included: include_secrets,
+        data_dir: data_dir.display().to_string(),
+        files: files
+            .iter()
+            .map(|(path, data)| BundleFile {
+                path: path.clone(),
+                size_bytes: data.len() as u64,
+                sha256: hex::encode(Sha256::digest(data)),
+            })
+            .collect(),
+    };
+
+    let manifest_json = serde_json::to_vec_pretty(&manifest).map_err(|e| invalid(e.to_string()))?;
+    let archive = write_archive(
+        std::iter::once((MANIFEST_FILE, manifest_json.as_slice()))
+            .chain(files.iter().map(|(p, d)| (p.as_str(), d.as_slice()))),
+    )
+    .map_err(|e| io_error(dest, e))?;
+    let sealed = seal(&archive, passphrase, KDF_ITERATIONS)?;
+
+    if let Some(parent) = dest.parent().filter(|p| !p.as_os_str().is_empty()) {
+        std::fs::create_dir_all(parent).map_err(|e| io_error(parent, e))?;
+    }
+    let temp = dest.with_extension("tmp");
+    std::fs::write(&temp, sealed).map_err(|e| io_error(&temp, e))?;
+    restrict_permissions(&temp);
+    std::fs::rename(&temp, dest).map_err(|e| io_error(dest, e))?;
+
+    Ok(manifest)
+}
+
+/// Remove credential keys from a config file.
+pub(super) fn strip_secrets(config: &[u8]) -> Result<String, BundleError> {
+    let text = std::str::from_utf8(config).map_err(|e| invalid(e.to_string()))?;
+    let mut value: toml::Value = toml::from_str(text).map_err(|e| invalid(e.to_string()))?;
+    if let toml::Value::Table(table) = &mut value {
+        strip_table(table);
+    }
+    toml::to_string_pretty(&value).map_err(|e| invalid(e.to_string()))
+}
+
+fn strip_table(table: &mut toml::map::Map<String, toml::Value>) {
+    table.retain(|key, _| !is_secret_key(key));
+    for (_, value) in table.iter_mut() {
+        match value {
+            toml::Value::Table(inner) => strip_table(inner),
+            toml::Value::Array(items) => {
+                for item in items {
+                    if let toml::Value::Table(inner) = item {
+                        strip_table(inner);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn write_archive<'a>(
+    files: impl IntoIterator<Item = (&'a str, &'a [u8])>,
+) -> std::io::Result<Vec<u8>> {
+    let encoder = GzEncoder::new(Vec::new(), Compression::default());
+    let mut builder = tar::Builder::new(encoder);
+    let mtime = Utc::now().timestamp().max(0) as u64;
+    for (path, data) in files {
+        let mut header = tar::Header::new_gnu();
+        header.set_size(data.len() as u64);
+        header.set_mode(0o600);
+        header.set_mtime(mtime);
+        header.set_cksum();
+        builder.append_data(&mut header, path, data)?;
+    }
+    let mut encoder = builder.into_inner()?;
+    encoder.flush()?;
+    encoder.finish()
+}
+
+/// Encrypt `plaintext` into a complete bundle (header + ciphertext + tag).
+pub(super) fn seal(
+    plaintext: &[u8],
+    passphrase: &str,
+    iterations: u32,
+) -> Result<Vec<u8>, BundleError> {
+    let rng = SystemRandom::new();
+    let mut salt = [0u8; SALT_LEN];
+    let mut nonce = [0u8; NONCE_LEN];
+    rng.fill(&mut salt)
+        .and_then(|()| rng.fill(&mut nonce))
+        .map_err(|_| invalid("random number generator failed"))?;
+
+    let mut out = Vec::with_capacity(HEADER_LEN + plaintext.len() + AES_256_GCM.tag_len());
+    out.extend_from_slice(MAGIC);
+    out.push(FORMAT_VERSION);
+    out.extend_from_slice(&iterations.to_be_bytes());
+    out.extend_from_slice(&salt);
+    out.extend_from_slice(&nonce);
+
+    let key = derive_key(passphrase, &salt, iterations)?;
+    let mut body = plaintext.to_vec();
+    key.seal_in_place_append_tag(
+        Nonce::assume_unique_for_key(nonce),
+        Aad::from(&out[..HEADER_LEN]),
+        &mut body,
+    )
+    .map_err(|_| invalid("encryption failed"))?;
+    out.extend_from_slice(&body);
+    Ok(out)
+}
@@ -0,0 +1,173 @@
+//! Storage for lifecycle hook results.
+//!
+//! Every hook invocation is recorded with its event, verdict, and timing so
+//! a denied or rewritten post can be traced back to the hook that decided it.
+
+use super::accounts::DEFAULT_ACCOUNT_ID;
+use super::DbPool;
+use crate::error::StorageError;
+
+pub use tuitbot_types::hooks::HookRun;
+
+#[derive(sqlx::FromRow)]
+struct HookRunRow {
+    id: i64,
+    event: String,
+    hook_name: String,
+    action_type: String,
+    item_id: Option<i64>,
+    target_id: Option<String>,
+    verdict: String,
+    reason: Option<String>,
+    exit_code: Option<i64>,
+    duration_ms: i64,
+    created_at: String,
+}
+
+impl From<HookRunRow> for HookRun {
+    fn from(row: HookRunRow) -> Self {
+        Self {
+            id: row.id,
+            event: row.event,
+            hook_name: row.hook_name,
+            action_type: row.action_type,
+            item_id: row.item_id,
+            target_id: row.target_id,
+            verdict: row.verdict,
+            reason: row.reason,
+            exit_code: row.exit_code,
+            duration_ms: row.duration_ms,
+            created_at: row.created_at,
+        }
+    }
+}
+
+/// A hook invocation to record.
+#[derive(Debug, Clone, Default)]
+pub struct NewHookRun<'a> {
+    pub event: &'a str,
+    pub hook_name: &'a str,
+    pub action_type: &'a str,
+    pub item_id: Option<i64>,
+    pub target_id: Option<&'a str>,
+    pub verdict: &'a str,
+    pub reason: Option<&'a str>,
+    pub exit_code: Option<i32>,
+    pub duration_ms: u64,
+}
+
+/// Record a hook invocation for a specific account.
+pub async fn record_for(
+    pool: &DbPool,
+    account_id: &str,
+    run: &NewHookRun<'_>,
+) -> Result<i64, StorageError> {
+    let result = sqlx::query(
+        "INSERT INTO hook_runs \
+             (account_id, event, hook_name, action_type, item_id, target_id, \
+              verdict, reason, exit_code, duration_ms) \
+         VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+    )
+    .bind(account_id)
+    .bind(run.event)
+    .bind(run.hook_name)
+    .bind(run.action_type)
+    .bind(run.item_id)
+    .bind(run.target_id)
+    .bind(run.verdict)
+    .bind(run.reason)
+    .bind(run.exit_code)
+    .bind(i64::try_from(run.duration_ms).unwrap_or(i64::MAX))
+    .execute(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+    Ok(result.last_insert_rowid())
+}
+
+/// Record a hook invocation for the default account.
+pub async fn record(pool: &DbPool, run: &NewHookRun<'_>) -> Result<i64, StorageError> {
+    record_for(pool, DEFAULT_ACCOUNT_ID, run).await
+}
+
+/// Most recent hook runs for a specific account, newest first, optionally
+/// limited to one approval item.
+pub async fn list_recent_for(
+    pool: &DbPool,
+    account_id: &str,
+    item_id: Option<i64>,
+    limit: u32,
+) -> Result<Vec<HookRun>, StorageError> {
+    let rows = sqlx::query_as::<_, HookRunRow>(
+        "SELECT id, event, hook_name, action_type, item_id, target_id, verdict, \
+                reason, exit_code, duration_ms, created_at \
+         FROM hook_runs \
+         WHERE account_id = ? AND (?2 IS NULL OR item_id = ?2) \
+         ORDER BY id DESC LIMIT ?3",
+    )
+    .bind(account_id)
+    .bind(item_id)
+    .bind(i64::from(limit))
+    .fetch_all(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+    Ok(rows.into_iter().map(HookRun::from).collect())
+}
+
+/// Most recent hook runs for the default account.
+pub async fn list_recent(
+    pool: &DbPool,
+    item_id: Option<i64>,
+    limit: u32,
+) -> Result<Vec<HookRun>, StorageError> {
+    list_recent_for(pool, DEFAULT_ACCOUNT_ID, item_id, limit).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::init_test_db;
+
+    #[tokio::test]
+    async fn records_and_filters_by_item() {
+        let pool = init_test_db().await.expect("init db");
+        record(
+            &pool,
+            &NewHookRun {
+                event: "pre_approve",
+                hook_name: "brand",
+                action_type: "reply",
+                item_id: Some(7),
+                verdict: "deny",
+                reason: Some("mentions a competitor"),
+                exit_code: Some(1),
+                duration_ms: 12,
+                ..Default::default()
+            },
+        )
+        .await
+        .expect("record");
+        record(
+            &pool,
+            &NewHookRun {
+                event: "post_post",
+                hook_name: "notify",
+                action_type: "tweet",
+                target_id: Some("99"),
+                verdict: "allow",
+                exit_code: Some(0),
+                ..Default::default()
+            },
+        )
+        .await
+        .expect("record");
+
+        let all = list_recent(&pool, None, 10).await.expect("list");
+        assert_eq!(all.len(), 2);
+        assert_eq!(all[0].hook_name, "notify");
+
+        let for_item = list_recent(&pool, Some(7), 10).await.expect("list");
+        assert_eq!(for_item.len(), 1);
+        assert_eq!(for_item[0].verdict, "deny");
+        assert_eq!(for_item[0].reason.as_deref(), Some("mentions a competitor"));
+    }
+}
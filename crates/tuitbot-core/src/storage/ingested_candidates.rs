@@ -0,0 +1,114 @@
+//! Externally pushed candidates that are not X posts.
+//!
+//! `POST /api/ingest/candidates` accepts raw text from other systems. Such
+//! items have no X tweet or author ID, so they are stored here instead of
+//! with the discovered tweets, which only hold posts fetched from X.
+
+use super::DbPool;
+use crate::error::StorageError;
+
+/// A raw-text candidate as ingested.
+#[derive(Debug, Clone, PartialEq, sqlx::FromRow, serde::Serialize)]
+pub struct IngestedCandidate {
+    /// `ingest:<source>:<text hash>`.
+    pub id: String,
+    /// System the candidate came from.
+    pub source: String,
+    /// Author handle as given by the source, if any.
+    pub author_username: String,
+    /// Candidate text.
+    pub content: String,
+    /// Link to the original item, if any.
+    pub url: Option<String>,
+    /// Score from the scoring engine.
+    pub relevance_score: Option<f64>,
+    /// First configured keyword found in the text.
+    pub matched_keyword: Option<String>,
+    /// ISO-8601 UTC timestamp when the candidate was ingested.
+    pub ingested_at: String,
+}
+
+/// Store a candidate for a specific account. Re-ingesting the same
+/// candidate keeps the first copy.
+pub async fn insert_candidate_for(
+    pool: &DbPool,
+    account_id: &str,
+    candidate: &IngestedCandidate,
+) -> Result<(), StorageError> {
+    sqlx::query(
+        "INSERT OR IGNORE INTO ingested_candidates \
+         (id, account_id, source, author_username, content, url, relevance_score, \
+          matched_keyword, ingested_at) \
+         VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)",
+    )
+    .bind(&candidate.id)
+    .bind(account_id)
+    .bind(&candidate.source)
+    .bind(&candidate.author_username)
+    .bind(&candidate.content)
+    .bind(&candidate.url)
+    .bind(candidate.relevance_score)
+    .bind(&candidate.matched_keyword)
+    .bind(&candidate.ingested_at)
+    .execute(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+    Ok(())
+}
+
+/// Get a candidate by ID for a specific account.
+pub async fn get_candidate_for(
+    pool: &DbPool,
+    account_id: &str,
+    id: &str,
+) -> Result<Option<IngestedCandidate>, StorageError> {
+    sqlx::query_as::<_, IngestedCandidate>(
+        "SELECT id, source, author_username, content, url, relevance_score, \
+                matched_keyword, ingested_at \
+         FROM ingested_candidates WHERE account_id = ? AND id = ?",
+    )
+    .bind(account_id)
+    .bind(id)
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::accounts::DEFAULT_ACCOUNT_ID;
+    use crate::storage::init_test_db;
+
+    #[tokio::test]
+    async fn insert_keeps_first_copy() {
+        let pool = init_test_db().await.expect("init db");
+        let mut candidate = IngestedCandidate {
+            id: "ingest:forum:abc".to_string(),
+            source: "forum".to_string(),
+            author_username: "bob".to_string(),
+            content: "Is Rust hard?".to_string(),
+            url: Some("https://forum.example.com/t/1".to_string()),
+            relevance_score: Some(42.0),
+            matched_keyword: Some("rust".to_string()),
+            ingested_at: "2026-03-01T10:00:00Z".to_string(),
+        };
+        insert_candidate_for(&pool, DEFAULT_ACCOUNT_ID, &candidate)
+            .await
+            .expect("insert");
+        let first = candidate.clone();
+        candidate.content = "Changed".to_string();
+        insert_candidate_for(&pool, DEFAULT_ACCOUNT_ID, &candidate)
+            .await
+            .expect("insert again");
+
+        let stored = get_candidate_for(&pool, DEFAULT_ACCOUNT_ID, "ingest:forum:abc")
+            .await
+            .expect("get");
+        assert_eq!(stored, Some(first));
+        assert!(get_candidate_for(&pool, "other", "ingest:forum:abc")
+            .await
+            .expect("get")
+            .is_none());
+    }
+}
@@ -0,0 +1,329 @@
+//! Per-keyword attribution for discovered tweets.
+//!
+//! Each row records that a keyword surfaced a tweet. Rows double as the
+//! cross-keyword dedup cache: a tweet with a hit inside the dedup window is
+//! not re-scored, but every keyword that found it is still credited.
+
+use chrono::Utc;
+use serde::Serialize;
+
+use super::accounts::DEFAULT_ACCOUNT_ID;
+use super::DbPool;
+use crate::error::StorageError;
+
+/// Discovery performance of a single keyword.
+#[derive(Debug, Clone, Serialize)]
+pub struct KeywordStats {
+    pub keyword: String,
+    /// Distinct tweets this keyword surfaced.
+    pub tweets_found: i64,
+    /// Tweets no other keyword surfaced.
+    pub exclusive_tweets: i64,
+    /// Average relevance score of the surfaced tweets.
+    pub avg_score: f64,
+    /// Surfaced tweets that were replied to.
+    pub replied: i64,
+}
+
+/// Tweets a keyword surfaced recently, grouped as one conversation.
+#[derive(Debug, Clone, Serialize)]
+pub struct KeywordCluster {
+    pub keyword: String,
+    /// Distinct tweets the keyword surfaced in the window.
+    pub tweet_count: i64,
+    /// Likes, retweets, replies, and quotes across those tweets.
+    pub engagement: i64,
+    /// Text of the most-engaged tweets, highest first.
+    pub samples: Vec<String>,
+}
+
+/// Number of sample tweets attached to each cluster.
+const CLUSTER_SAMPLES: u32 = 3;
+
+fn cutoff(seconds_ago: i64) -> String {
+    (Utc::now() - chrono::Duration::seconds(seconds_ago))
+        .format("%Y-%m-%dT%H:%M:%SZ")
+        .to_string()
+}
+
+/// Record that `keyword` surfaced `tweet_id` for a specific account.
+/// Repeat hits keep the first-seen time.
+pub async fn record_keyword_hit_for(
+    pool: &DbPool,
+    account_id: &str,
+    tweet_id: &str,
+    keyword: &str,
+) -> Result<(), StorageError> {
+    sqlx::query(
+        "INSERT OR IGNORE INTO discovery_keyword_hits \
+         (account_id, tweet_id, keyword, first_seen_at) VALUES (?, ?, ?, ?)",
+    )
+    .bind(account_id)
+    .bind(tweet_id)
+    .bind(keyword)
+    .bind(cutoff(0))
+    .execute(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+    Ok(())
+}
+
+/// Record that `keyword` surfaced `tweet_id`.
+pub async fn record_keyword_hit(
+    pool: &DbPool,
+    tweet_id: &str,
+    keyword: &str,
+) -> Result<(), StorageError> {
+    record_keyword_hit_for(pool, DEFAULT_ACCOUNT_ID, tweet_id, keyword).await
+}
+
+/// Check whether any keyword surfaced `tweet_id` within the last `window_secs`
+/// for a specific account.
+pub async fn tweet_seen_within_for(
+    pool: &DbPool,
+    account_id: &str,
+    tweet_id: &str,
+    window_secs: u64,
+) -> Result<bool, StorageError> {
+    let row: (i64,) = sqlx::query_as(
+        "SELECT EXISTS(SELECT 1 FROM discovery_keyword_hits \
+         WHERE account_id = ? AND tweet_id = ? AND first_seen_at >= ?)",
+    )
+    .bind(account_id)
+    .bind(tweet_id)
+    .bind(cutoff(window_secs as i64))
+    .fetch_one(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+    Ok(row.0 == 1)
+}
+
+/// Check whether any keyword surfaced `tweet_id` within the last `window_secs`.
+pub async fn tweet_seen_within(
+    pool: &DbPool,
+    tweet_id: &str,
+    window_secs: u64,
+) -> Result<bool, StorageError> {
+    tweet_seen_within_for(pool, DEFAULT_ACCOUNT_ID, tweet_id, window_secs).await
+}
+
+/// Per-keyword discovery stats over the last `days` for a specific account,
+/// ordered by tweets found.
+pub async fn get_keyword_stats_for(
+    pool: &DbPool,
+    account_id: &str,
+    days: u32,
+) -> Result<Vec<KeywordStats>, StorageError> {
+    let rows: Vec<(String, i64, i64, f64, i64)> = sqlx::query_as(
+        "SELECT h.keyword, \
+                COUNT(*), \
+                SUM(CASE WHEN (SELECT COUNT(*) FROM discovery_keyword_hits o \
+                               WHERE o.account_id = h.account_id AND o.tweet_id = h.tweet_id) = 1 \
+                         THEN 1 ELSE 0 END), \
+                COALESCE(AVG(d.relevance_score), 0.0), \
+                COALESCE(SUM(CASE WHEN d.replied_to = 1 THEN 1 ELSE 0 END), 0) \
+         FROM discovery_keyword_hits h \
+         LEFT JOIN discovered_tweets d ON d.account_id = h.account_id AND d.id = h.tweet_id \
+         WHERE h.account_id = ? AND h.first_seen_at >= ? \
+         GROUP BY h.keyword \
+         ORDER BY COUNT(*) DESC, h.keyword",
+    )
+    .bind(account_id)
+    .bind(cutoff(i64::from(days) * 86_400))
+    .fetch_all(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+
+    Ok(rows
+        .into_iter()
+        .map(
+            |(keyword, tweets_found, exclusive_tweets, avg_score, replied)| KeywordStats {
+                keyword,
+                tweets_found,
+                exclusive_tweets,
+                avg_score,
+                replied,
+            },
+        )
+        .collect())
+}
+
+/// Per-keyword discovery stats over the last `days`.
+pub async fn get_keyword_stats(
+    pool: &DbPool,
+    days: u32,
+) -> Result<Vec<KeywordStats>, StorageError> {
+    get_keyword_stats_for(pool, DEFAULT_ACCOUNT_ID, days).await
+}
+
+/// Keyword clusters of at least `min_tweets` tweets surfaced in the last
+/// `hours` for a specific account, largest and most engaged first.
+pub async fn get_keyword_clusters_for(
+    pool: &DbPool,
+    account_id: &str,
+    hours: u32,
+    min_tweets: u32,
+    limit: u32,
+) -> Result<Vec<KeywordCluster>, StorageError> {
+    let since = cutoff(i64::from(hours) * 3600);
+    let rows: Vec<(String, i64, i64)> = sqlx::query_as(
+        "SELECT h.keyword, \
+                COUNT(*), \
+                COALESCE(SUM(d.like_count + d.retweet_count + d.reply_count + d.quote_count), 0) \
+         FROM discovery_keyword_hits h \
+         JOIN discovered_tweets d ON d.account_id = h.account_id AND d.id = h.tweet_id \
+         WHERE h.account_id = ? AND h.first_seen_at >= ? \
+         GROUP BY h.keyword \
+         HAVING COUNT(*) >= ? \
+         ORDER BY COUNT(*) DESC, 3 DESC, h.keyword \
+         LIMIT ?",
+    )
+    .bind(account_id)
+    .bind(&since)
+    .bind(min_tweets)
+    .bind(limit)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+
+    let mut clusters = Vec::with_capacity(rows.len());
+    for (keyword, tweet_count, engagement) in rows {
+        let samples: Vec<(String,)> = sqlx::query_as(
+            "SELECT d.content \
+             FROM discovery_keyword_hits h \
+             JOIN discovered_tweets d ON d.account_id = h.account_id AND d.id = h.tweet_id \
+             WHERE h.account_id = ? AND h.keyword = ? AND h.first_seen_at >= ? \
+             ORDER BY d.like_count + d.retweet_count + d.reply_count + d.quote_count DESC \
+             LIMIT ?",
+        )
+        .bind(account_id)
+        .bind(&keyword)
+        .bind(&since)
+        .bind(CLUSTER_SAMPLES)
+        .fetch_all(pool)
+        .await
+        .map_err(|e| StorageError::Query { source: e })?;
+        clusters.push(KeywordCluster {
+            keyword,
+            tweet_count,
+            engagement,
+            samples: samples.into_iter().map(|(text,)| text).collect(),
+        });
+    }
+    Ok(clusters)
+}
+
+/// Keyword clusters of at least `min_tweets` tweets surfaced in the last `hours`.
+pub async fn get_keyword_clusters(
+    pool: &DbPool,
+    hours: u32,
+    min_tweets: u32,
+    limit: u32,
+) -> Result<Vec<KeywordCluster>, StorageError> {
+    get_keyword_clusters_for(pool, DEFAULT_ACCOUNT_ID, hours, min_tweets, limit).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::init_test_db;
+    use crate::storage::tweets::{insert_discovered_tweet, mark_tweet_replied, DiscoveredTweet};
+
+    fn tweet(id: &str, score: f64) -> DiscoveredTweet {
+        DiscoveredTweet {
+            id: id.to_string(),
+            author_id: "a1".to_string(),
+            author_username: "alice".to_string(),
+            content: "rust cli".to_string(),
+            like_count: 0,
+            retweet_count: 0,
+            reply_count: 0,
+            quote_count: 0,
+            impression_count: None,
+            relevance_score: Some(score),
+            matched_keyword: Some("rust".to_string()),
+            discovered_at: "2026-03-01T00:00:00Z".to_string(),
+            replied_to: 0,
+        }
+    }
+
+    #[tokio::test]
+    async fn hit_marks_tweet_seen_within_window() {
+        let pool = init_test_db().await.expect("init db");
+        assert!(!tweet_seen_within(&pool, "t1", 3600).await.unwrap());
+
+        record_keyword_hit(&pool, "t1", "rust").await.unwrap();
+        record_keyword_hit(&pool, "t1", "rust").await.unwrap();
+        assert!(tweet_seen_within(&pool, "t1", 3600).await.unwrap());
+
+        sqlx::query("UPDATE discovery_keyword_hits SET first_seen_at = '2020-01-01T00:00:00Z'")
+            .execute(&pool)
+            .await
+            .unwrap();
+        assert!(!tweet_seen_within(&pool, "t1", 3600).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn stats_credit_every_keyword() {
+        let pool = init_test_db().await.expect("init db");
+        insert_discovered_tweet(&pool, &tweet("t1", 80.0))
+            .await
+            .unwrap();
+        insert_discovered_tweet(&pool, &tweet("t2", 40.0))
+            .await
+            .unwrap();
+        mark_tweet_replied(&pool, "t1").await.unwrap();
+
+        record_keyword_hit(&pool, "t1", "rust").await.unwrap();
+        record_keyword_hit(&pool, "t1", "cli").await.unwrap();
+        record_keyword_hit(&pool, "t2", "rust").await.unwrap();
+
+        let stats = get_keyword_stats(&pool, 7).await.unwrap();
+        assert_eq!(stats.len(), 2);
+
+        let rust = &stats[0];
+        assert_eq!(rust.keyword, "rust");
+        assert_eq!(rust.tweets_found, 2);
+        assert_eq!(rust.exclusive_tweets, 1);
+        assert!((rust.avg_score - 60.0).abs() < 1e-9);
+        assert_eq!(rust.replied, 1);
+
+        let cli = &stats[1];
+        assert_eq!(cli.keyword, "cli");
+        assert_eq!(cli.tweets_found, 1);
+        assert_eq!(cli.exclusive_tweets, 0);
+        assert_eq!(cli.replied, 1);
+    }
+
+    #[tokio::test]
+    async fn clusters_need_enough_recent_tweets() {
+        let pool = init_test_db().await.expect("init db");
+        for (id, likes) in [("t1", 5), ("t2", 50), ("t3", 1)] {
+            let mut t = tweet(id, 60.0);
+            t.content = format!("tweet {id}");
+            t.like_count = likes;
+            insert_discovered_tweet(&pool, &t).await.unwrap();
+            record_keyword_hit(&pool, id, "rust").await.unwrap();
+        }
+        record_keyword_hit(&pool, "t1", "cli").await.unwrap();
+
+        let clusters = get_keyword_clusters(&pool, 24, 2, 5).await.unwrap();
+        assert_eq!(clusters.len(), 1);
+        assert_eq!(clusters[0].keyword, "rust");
+        assert_eq!(clusters[0].tweet_count, 3);
+        assert_eq!(clusters[0].engagement, 56);
+        assert_eq!(
+            clusters[0].samples,
+            vec!["tweet t2", "tweet t1", "tweet t3"]
+        );
+
+        sqlx::query("UPDATE discovery_keyword_hits SET first_seen_at = '2020-01-01T00:00:00Z'")
+            .execute(&pool)
+            .await
+            .unwrap();
+        assert!(get_keyword_clusters(&pool, 24, 2, 5)
+            .await
+            .unwrap()
+            .is_empty());
+    }
+}
@@ -0,0 +1,144 @@
+//! Persisted compliance kill switch.
+//!
+//! A single global flag that, while engaged, halts every mutation path:
+//! automation loops, the posting queue, approval posting, and MCP mutation
+//! tools. It survives restarts and can be flipped from another process
+//! (CLI or HTTP API), so consumers read it from the database rather than
+//! caching it. Every engage/release is recorded in the action log.
+
+use super::DbPool;
+use crate::error::StorageError;
+
+/// Current kill switch state.
+#[derive(Debug, Clone, Default, PartialEq, sqlx::FromRow, serde::Serialize)]
+pub struct KillSwitchState {
+    /// Whether mutations are currently halted.
+    pub active: bool,
+    /// Reason given when the switch was engaged.
+    pub reason: Option<String>,
+    /// Who last changed the switch: `cli`, `api`, ...
+    pub source: Option<String>,
+    /// ISO-8601 UTC timestamp of the last change.
+    pub changed_at: Option<String>,
+}
+
+/// Read the kill switch state. A missing row means the switch was never engaged.
+pub async fn get_kill_switch(pool: &DbPool) -> Result<KillSwitchState, StorageError> {
+    let row = sqlx::query_as::<_, KillSwitchState>(
+        "SELECT active, reason, source, changed_at FROM kill_switch WHERE id = 1",
+    )
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+
+    Ok(row.unwrap_or_default())
+}
+
+/// Whether the kill switch is currently engaged.
+pub async fn is_kill_switch_active(pool: &DbPool) -> Result<bool, StorageError> {
+    Ok(get_kill_switch(pool).await?.active)
+}
+
+/// Engage the kill switch, halting all mutations until released.
+pub async fn engage_kill_switch(
+    pool: &DbPool,
+    reason: &str,
+    source: &str,
+) -> Result<KillSwitchState, StorageError> {
+    set_kill_switch(pool, true, Some(reason), source).await?;
+    let _ = super::action_log::log_action(
+        pool,
+        "kill_switch",
+        "engaged",
+        Some(&format!("Kill switch engaged via {source}: {reason}")),
+        None,
+    )
+    .await;
+    tracing::warn!(source, reason, "Kill switch engaged, all mutations halted");
+    get_kill_switch(pool).await
+}
+
+/// Release the kill switch, re-enabling mutations.
+pub async fn release_kill_switch(
+    pool: &DbPool,
+    source: &str,
+) -> Result<KillSwitchState, StorageError> {
+    set_kill_switch(pool, false, None, source).await?;
+    let _ = super::action_log::log_action(
+        pool,
+        "kill_switch",
+        "released",
+        Some(&format!("Kill switch released via {source}")),
+        None,
+    )
+    .await;
+    tracing::info!(source, "Kill switch released, mutations re-enabled");
+    get_kill_switch(pool).await
+}
+
+async fn set_kill_switch(
+    pool: &DbPool,
+    active: bool,
+    reason: Option<&str>,
+    source: &str,
+) -> Result<(), StorageError> {
+    sqlx::query(
+        "INSERT INTO kill_switch (id, active, reason, source, changed_at) \
+         VALUES (1, ?, ?, ?, strftime('%Y-%m-%dT%H:%M:%SZ', 'now')) \
+         ON CONFLICT(id) DO UPDATE SET \
+            active = excluded.active, \
+            reason = excluded.reason, \
+            source = excluded.source, \
+            changed_at = excluded.changed_at",
+    )
+    .bind(active)
+    .bind(reason)
+    .bind(source)
+    .execute(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::init_test_db;
+
+    #[tokio::test]
+    async fn defaults_to_inactive() {
+        let pool = init_test_db().await.expect("init db");
+        let state = get_kill_switch(&pool).await.expect("get");
+        assert!(!state.active);
+        assert!(state.reason.is_none());
+    }
+
+    #[tokio::test]
+    async fn engage_and_release_round_trip() {
+        let pool = init_test_db().await.expect("init db");
+
+        let state = engage_kill_switch(&pool, "legal hold", "cli")
+            .await
+            .expect("engage");
+        assert!(state.active);
+        assert_eq!(state.reason.as_deref(), Some("legal hold"));
+        assert_eq!(state.source.as_deref(), Some("cli"));
+        assert!(is_kill_switch_active(&pool).await.expect("active"));
+
+        let state = release_kill_switch(&pool, "api").await.expect("release");
+        assert!(!state.active);
+        assert!(state.reason.is_none());
+        assert_eq!(state.source.as_deref(), Some("api"));
+
+        let log = crate::storage::action_log::get_actions_since(
+            &pool,
+            "1970-01-01T00:00:00Z",
+            Some("kill_switch"),
+        )
+        .await
+        .expect("log");
+        let statuses: Vec<_> = log.iter().map(|e| e.status.as_str()).collect();
+        assert_eq!(statuses, vec!["engaged", "released"]);
+    }
+}
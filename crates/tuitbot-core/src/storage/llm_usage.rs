@@ -1,6 +1,8 @@
 //! LLM usage tracking — stores per-call token counts and costs.
 
 use crate::error::StorageError;
+use crate::llm::pricing::{self, UsageCost};
+use crate::llm::TokenUsage;
 
 use super::accounts::DEFAULT_ACCOUNT_ID;
 use super::DbPool;
@@ -16,6 +18,9 @@ pub struct CostSummary {
     pub calls_7d: i64,
     pub calls_30d: i64,
     pub calls_all_time: i64,
+    /// Saved versus list price by prompt caching and batch discounts.
+    pub savings_30d: f64,
+    pub savings_all_time: f64,
 }
 
 /// Daily cost aggregation for chart data.
@@ -37,6 +42,8 @@ pub struct ModelCostBreakdown {
     pub calls: i64,
     pub input_tokens: i64,
     pub output_tokens: i64,
+    pub cache_read_tokens: i64,
+    pub savings: f64,
 }
 
 /// Cost breakdown by generation type (reply/tweet/thread).
@@ -100,12 +107,69 @@ pub async fn insert_llm_usage(
     .await
 }
 
+/// Record a call's usage for a specific account, pricing cached tokens and
+/// batch requests at their discounted rates. Returns the computed cost.
+#[allow(clippy::too_many_arguments)]
+pub async fn record_usage_for(
+    pool: &DbPool,
+    account_id: &str,
+    generation_type: &str,
+    provider: &str,
+    model: &str,
+    usage: &TokenUsage,
+    batch: bool,
+) -> Result<UsageCost, StorageError> {
+    let cost = pricing::usage_cost(provider, model, usage, batch);
+    sqlx::query(
+        "INSERT INTO llm_usage (account_id, generation_type, provider, model, input_tokens,
+                                output_tokens, cache_read_tokens, cache_write_tokens, batch,
+                                cost_usd, savings_usd)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)",
+    )
+    .bind(account_id)
+    .bind(generation_type)
+    .bind(provider)
+    .bind(model)
+    .bind(usage.input_tokens)
+    .bind(usage.output_tokens)
+    .bind(usage.cache_read_tokens)
+    .bind(usage.cache_write_tokens)
+    .bind(batch)
+    .bind(cost.cost_usd)
+    .bind(cost.savings_usd)
+    .execute(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+    Ok(cost)
+}
+
+/// Record a call's usage with cache and batch discounts applied.
+pub async fn record_usage(
+    pool: &DbPool,
+    generation_type: &str,
+    provider: &str,
+    model: &str,
+    usage: &TokenUsage,
+    batch: bool,
+) -> Result<UsageCost, StorageError> {
+    record_usage_for(
+        pool,
+        DEFAULT_ACCOUNT_ID,
+        generation_type,
+        provider,
+        model,
+        usage,
+        batch,
+    )
+    .await
+}
+
 /// Get cost summary across time windows for a specific account.
 pub async fn get_cost_summary_for(
     pool: &DbPool,
     account_id: &str,
 ) -> Result<CostSummary, StorageError> {
-    let row: (f64, i64, f64, i64, f64, i64, f64, i64) = sqlx::query_as(
+    let row: (f64, i64, f64, i64, f64, i64, f64, i64, f64, f64) = sqlx::query_as(
         "SELECT
             COALESCE(SUM(CASE WHEN created_at >= date('now') THEN cost_usd ELSE 0.0 END), 0.0),
             COALESCE(SUM(CASE WHEN created_at >= date('now') THEN 1 ELSE 0 END), 0),
@@ -114,7 +178,9 @@ pub async fn get_cost_summary_for(
             COALESCE(SUM(CASE WHEN created_at >= date('now', '-30 days') THEN cost_usd ELSE 0.0 END), 0.0),
             COALESCE(SUM(CASE WHEN created_at >= date('now', '-30 days') THEN 1 ELSE 0 END), 0),
             COALESCE(SUM(cost_usd), 0.0),
-            COUNT(*)
+            COUNT(*),
+            COALESCE(SUM(CASE WHEN created_at >= date('now', '-30 days') THEN savings_usd ELSE 0.0 END), 0.0),
+            COALESCE(SUM(savings_usd), 0.0)
         FROM llm_usage
         WHERE account_id = ?",
     )
@@ -132,6 +198,8 @@ pub async fn get_cost_summary_for(
         calls_30d: row.5,
         cost_all_time: row.6,
         calls_all_time: row.7,
+        savings_30d: row.8,
+        savings_all_time: row.9,
     })
 }
 
@@ -186,20 +254,25 @@ pub async fn get_daily_costs(
     get_daily_costs_for(pool, DEFAULT_ACCOUNT_ID, days).await
 }
 
+/// provider, model, cost, calls, input, output, cache_read, savings.
+type ModelBreakdownRow = (String, String, f64, i64, i64, i64, i64, f64);
+
 /// Get cost breakdown by provider + model for a specific account.
 pub async fn get_model_breakdown_for(
     pool: &DbPool,
     account_id: &str,
     days: u32,
 ) -> Result<Vec<ModelCostBreakdown>, StorageError> {
-    let rows: Vec<(String, String, f64, i64, i64, i64)> = sqlx::query_as(
+    let rows: Vec<ModelBreakdownRow> = sqlx::query_as(
         "SELECT
             provider,
             model,
             COALESCE(SUM(cost_usd), 0.0),
             COUNT(*),
             COALESCE(SUM(input_tokens), 0),
-            COALESCE(SUM(output_tokens), 0)
+            COALESCE(SUM(output_tokens), 0),
+            COALESCE(SUM(cache_read_tokens), 0),
+            COALESCE(SUM(savings_usd), 0.0)
         FROM llm_usage
         WHERE account_id = ? AND created_at >= date('now', '-' || ? || ' days')
         GROUP BY provider, model
@@ -214,13 +287,24 @@ pub async fn get_model_breakdown_for(
     Ok(rows
         .into_iter()
         .map(
-            |(provider, model, cost, calls, input_tokens, output_tokens)| ModelCostBreakdown {
+            |(
+                provider,
+                model,
+                cost,
+                calls,
+                input_tokens,
+                output_tokens,
+                cache_read_tokens,
+                savings,
+            )| ModelCostBreakdown {
                 provider,
                 model,
                 cost,
                 calls,
                 input_tokens,
                 output_tokens,
+                cache_read_tokens,
+                savings,
             },
         )
         .collect())
@@ -300,6 +384,43 @@ mod tests {
         assert!(summary.cost_all_time > 0.0);
     }
 
+    #[tokio::test]
+    async fn record_usage_tracks_cache_and_batch_savings() {
+        let pool = init_test_db().await.expect("init db");
+
+        let cached = TokenUsage {
+            input_tokens: 1_000_000,
+            output_tokens: 0,
+            cache_read_tokens: 1_000_000,
+            cache_write_tokens: 0,
+        };
+        let cost = record_usage(&pool, "tweet", "anthropic", "claude-sonnet", &cached, false)
+            .await
+            .expect("record");
+        assert!((cost.savings_usd - 2.7).abs() < 1e-9);
+
+        let bulk = TokenUsage {
+            input_tokens: 1_000_000,
+            output_tokens: 1_000_000,
+            ..Default::default()
+        };
+        record_usage(&pool, "tweet", "openai", "gpt-4o-mini", &bulk, true)
+            .await
+            .expect("record");
+
+        let summary = get_cost_summary(&pool).await.expect("summary");
+        assert_eq!(summary.calls_all_time, 2);
+        assert!((summary.savings_all_time - 3.075).abs() < 1e-9);
+        assert!((summary.savings_30d - summary.savings_all_time).abs() < 1e-9);
+
+        let breakdown = get_model_breakdown(&pool, 30).await.expect("breakdown");
+        let sonnet = breakdown
+            .iter()
+            .find(|b| b.provider == "anthropic")
+            .expect("anthropic row");
+        assert_eq!(sonnet.cache_read_tokens, 1_000_000);
+    }
+
     #[tokio::test]
     async fn model_breakdown_groups_correctly() {
         let pool = init_test_db().await.expect("init db");
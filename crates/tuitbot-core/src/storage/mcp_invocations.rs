@@ -0,0 +1,202 @@
+//! MCP tool invocation log.
+//!
+//! Records every tool call made to a DB-backed MCP server (write / admin
+//! profiles) with its redacted arguments, outcome, and latency, so an
+//! agent's session can be inspected after the fact and read-only calls
+//! re-executed with `tuitbot mcp replay`.
+
+use super::accounts::DEFAULT_ACCOUNT_ID;
+use super::DbPool;
+use crate::error::StorageError;
+use serde::Serialize;
+
+/// A recorded tool invocation.
+#[derive(Debug, Clone, sqlx::FromRow, Serialize)]
+pub struct McpInvocation {
+    pub id: i64,
+    pub session_id: String,
+    pub tool_name: String,
+    pub args_hash: String,
+    pub args_json: String,
+    pub read_only: bool,
+    pub success: bool,
+    pub error_code: Option<String>,
+    pub latency_ms: i64,
+    pub created_at: String,
+}
+
+/// Parameters for recording an invocation.
+pub struct InvocationParams<'a> {
+    pub session_id: &'a str,
+    pub tool_name: &'a str,
+    pub args_hash: &'a str,
+    pub args_json: &'a str,
+    pub read_only: bool,
+    pub success: bool,
+    pub error_code: Option<&'a str>,
+    pub latency_ms: u64,
+}
+
+/// Filters for listing invocations. `None` fields match everything.
+#[derive(Debug, Clone, Default)]
+pub struct InvocationFilter {
+    pub session_id: Option<String>,
+    pub tool_name: Option<String>,
+    pub error_code: Option<String>,
+    pub limit: u32,
+}
+
+/// Record an invocation for a specific account.
+pub async fn log_invocation_for(
+    pool: &DbPool,
+    account_id: &str,
+    params: &InvocationParams<'_>,
+) -> Result<i64, StorageError> {
+    let result = sqlx::query(
+        "INSERT INTO mcp_invocations \
+         (account_id, session_id, tool_name, args_hash, args_json, read_only, success, \
+          error_code, latency_ms) \
+         VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)",
+    )
+    .bind(account_id)
+    .bind(params.session_id)
+    .bind(params.tool_name)
+    .bind(params.args_hash)
+    .bind(params.args_json)
+    .bind(params.read_only)
+    .bind(params.success)
+    .bind(params.error_code)
+    .bind(params.latency_ms as i64)
+    .execute(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+    Ok(result.last_insert_rowid())
+}
+
+/// Record an invocation.
+pub async fn log_invocation(
+    pool: &DbPool,
+    params: &InvocationParams<'_>,
+) -> Result<i64, StorageError> {
+    log_invocation_for(pool, DEFAULT_ACCOUNT_ID, params).await
+}
+
+/// Fetch a single invocation by ID.
+pub async fn get_invocation(pool: &DbPool, id: i64) -> Result<Option<McpInvocation>, StorageError> {
+    sqlx::query_as::<_, McpInvocation>(
+        "SELECT id, session_id, tool_name, args_hash, args_json, read_only, success, \
+                error_code, latency_ms, created_at \
+         FROM mcp_invocations WHERE id = ?",
+    )
+    .bind(id)
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })
+}
+
+/// List invocations matching `filter` for a specific account, newest first.
+pub async fn list_invocations_for(
+    pool: &DbPool,
+    account_id: &str,
+    filter: &InvocationFilter,
+) -> Result<Vec<McpInvocation>, StorageError> {
+    sqlx::query_as::<_, McpInvocation>(
+        "SELECT id, session_id, tool_name, args_hash, args_json, read_only, success, \
+                error_code, latency_ms, created_at \
+         FROM mcp_invocations \
+         WHERE account_id = ? \
+           AND (? IS NULL OR session_id = ?) \
+           AND (? IS NULL OR tool_name = ?) \
+           AND (? IS NULL OR error_code = ?) \
+         ORDER BY id DESC LIMIT ?",
+    )
+    .bind(account_id)
+    .bind(&filter.session_id)
+    .bind(&filter.session_id)
+    .bind(&filter.tool_name)
+    .bind(&filter.tool_name)
+    .bind(&filter.error_code)
+    .bind(&filter.error_code)
+    .bind(i64::from(filter.limit))
+    .fetch_all(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })
+}
+
+/// List invocations matching `filter`, newest first.
+pub async fn list_invocations(
+    pool: &DbPool,
+    filter: &InvocationFilter,
+) -> Result<Vec<McpInvocation>, StorageError> {
+    list_invocations_for(pool, DEFAULT_ACCOUNT_ID, filter).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::init_test_db;
+
+    fn params<'a>(session: &'a str, tool: &'a str, error: Option<&'a str>) -> InvocationParams<'a> {
+        InvocationParams {
+            session_id: session,
+            tool_name: tool,
+            args_hash: "h",
+            args_json: r#"{"tweet_id":"1"}"#,
+            read_only: error.is_none(),
+            success: error.is_none(),
+            error_code: error,
+            latency_ms: 12,
+        }
+    }
+
+    #[tokio::test]
+    async fn log_get_and_filter() {
+        let pool = init_test_db().await.expect("init db");
+        let id = log_invocation(&pool, &params("s1", "get_tweet_by_id", None))
+            .await
+            .expect("log");
+        log_invocation(&pool, &params("s1", "like_tweet", Some("x_api_error")))
+            .await
+            .expect("log");
+        log_invocation(&pool, &params("s2", "get_tweet_by_id", None))
+            .await
+            .expect("log");
+
+        let inv = get_invocation(&pool, id)
+            .await
+            .expect("get")
+            .expect("exists");
+        assert_eq!(inv.tool_name, "get_tweet_by_id");
+        assert!(inv.read_only);
+        assert_eq!(inv.latency_ms, 12);
+
+        let all = InvocationFilter {
+            limit: 10,
+            ..Default::default()
+        };
+        let rows = list_invocations(&pool, &all).await.expect("list");
+        assert_eq!(rows.len(), 3);
+        assert!(rows[0].id > rows[2].id, "newest first");
+
+        let by_session = InvocationFilter {
+            session_id: Some("s1".into()),
+            ..all.clone()
+        };
+        assert_eq!(list_invocations(&pool, &by_session).await.unwrap().len(), 2);
+
+        let by_error = InvocationFilter {
+            error_code: Some("x_api_error".into()),
+            ..all.clone()
+        };
+        let rows = list_invocations(&pool, &by_error).await.unwrap();
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].tool_name, "like_tweet");
+
+        let by_tool = InvocationFilter {
+            tool_name: Some("get_tweet_by_id".into()),
+            limit: 1,
+            ..Default::default()
+        };
+        assert_eq!(list_invocations(&pool, &by_tool).await.unwrap().len(), 1);
+    }
+}
@@ -232,6 +232,21 @@ pub async fn finalize_media_upload(
     Ok(())
 }
 
+/// Update the status of an in-flight media upload (e.g. `uploading` → `processing`).
+pub async fn update_media_upload_status(
+    pool: &DbPool,
+    id: i64,
+    status: &str,
+) -> Result<(), StorageError> {
+    sqlx::query("UPDATE media_uploads SET status = ? WHERE id = ?")
+        .bind(status)
+        .bind(id)
+        .execute(pool)
+        .await
+        .map_err(|e| StorageError::Query { source: e })?;
+    Ok(())
+}
+
 /// Mark a media upload as failed.
 pub async fn fail_media_upload(
     pool: &DbPool,
@@ -257,6 +272,43 @@ pub fn is_safe_media_path(path: &str, data_dir: &Path) -> bool {
     }
 }
 
+/// Derive the media ID (`{uuid}.{ext}`) for a path produced by [`store_media`].
+///
+/// The ID is the stored file name, which lets HTTP clients reference a
+/// file without ever sending a filesystem path.
+pub fn media_id_for_path(path: &str) -> Option<String> {
+    let name = Path::new(path).file_name()?.to_str()?;
+    is_valid_media_id(name).then(|| name.to_string())
+}
+
+/// Check that a media ID has the exact shape produced by [`store_media`].
+///
+/// Only lowercase hex, dashes, and a known extension are accepted, so an
+/// ID can never contain separators or `..` components.
+pub fn is_valid_media_id(id: &str) -> bool {
+    let Some((stem, ext)) = id.split_once('.') else {
+        return false;
+    };
+    stem.len() == 36
+        && stem
+            .chars()
+            .all(|c| c.is_ascii_digit() || ('a'..='f').contains(&c) || c == '-')
+        && matches!(ext, "jpg" | "png" | "webp" | "gif" | "mp4")
+}
+
+/// Resolve a media ID to an existing file under `{data_dir}/media/`.
+///
+/// Returns `None` for malformed IDs, missing files, and files that resolve
+/// outside the media directory (e.g. through a symlink).
+pub fn resolve_media_id(data_dir: &Path, id: &str) -> Option<PathBuf> {
+    if !is_valid_media_id(id) {
+        return None;
+    }
+    let media_dir = data_dir.join("media").canonicalize().ok()?;
+    let canonical = media_dir.join(id).canonicalize().ok()?;
+    (canonical.starts_with(&media_dir) && canonical.is_file()).then_some(canonical)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -336,7 +388,7 @@ mod tests {
         .expect("store");
 
         assert!(Path::new(&media.path).exists());
-        cleanup_media(&[media.path.clone()]).await;
+        cleanup_media(std::slice::from_ref(&media.path)).await;
         assert!(!Path::new(&media.path).exists());
     }
 
@@ -394,4 +446,47 @@ mod tests {
         let found = find_ready_upload_by_hash(&pool, &hash).await.expect("find");
         assert!(found.is_none());
     }
+
+    #[test]
+    fn media_id_round_trips_from_stored_path() {
+        let id = "0123abcd-4567-89ef-0123-456789abcdef.jpg";
+        let path = format!("/home/u/.tuitbot/media/{id}");
+        assert_eq!(media_id_for_path(&path).as_deref(), Some(id));
+        assert!(media_id_for_path("/etc/passwd").is_none());
+    }
+
+    #[test]
+    fn is_valid_media_id_rejects_traversal() {
+        assert!(is_valid_media_id(
+            "0123abcd-4567-89ef-0123-456789abcdef.mp4"
+        ));
+        assert!(!is_valid_media_id("../../etc/passwd"));
+        assert!(!is_valid_media_id(
+            "0123abcd-4567-89ef-0123-456789abcdef.exe"
+        ));
+        assert!(!is_valid_media_id(
+            "0123ABCD-4567-89ef-0123-456789abcdef.jpg"
+        ));
+        assert!(!is_valid_media_id(".jpg"));
+    }
+
+    #[tokio::test]
+    async fn resolve_media_id_finds_stored_file() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let media = store_media(
+            dir.path(),
+            b"img",
+            "a.png",
+            MediaType::Image(ImageFormat::Png),
+        )
+        .await
+        .expect("store");
+        let id = media_id_for_path(&media.path).expect("id");
+
+        let resolved = resolve_media_id(dir.path(), &id).expect("resolve");
+        assert_eq!(resolved, Path::new(&media.path).canonicalize().unwrap());
+
+        let missing = "00000000-0000-0000-0000-000000000000.png";
+        assert!(resolve_media_id(dir.path(), missing).is_none());
+    }
 }
@@ -1,33 +1,70 @@
 //! SQLite storage layer for Tuitbot.
 //!
 //! Provides database initialization, connection pooling, and CRUD operations
-//! for all persistent entities. Uses SQLx with WAL mode for concurrent access.
+//! for all persistent entities. Uses SQLx with WAL mode for concurrent access;
+//! in-process writers share a [`write_queue::WriteQueue`].
 
+pub mod account_health;
 pub mod accounts;
 pub mod action_log;
+pub mod activity;
+pub mod agent_sessions;
 pub mod analytics;
+pub mod analytics_range;
+pub mod approval_metrics;
 pub mod approval_queue;
+pub mod archive;
 pub mod author_interactions;
 pub mod backup;
+pub mod blocklist;
 pub mod cleanup;
+pub mod content_plans;
 pub mod cursors;
+pub mod dashboard;
+pub mod draft_only;
+pub mod drafts;
+pub mod export_bundle;
 pub mod health;
+pub mod hook_runs;
+pub mod ingested_candidates;
+pub mod keyword_hits;
+pub mod kill_switch;
 pub mod llm_usage;
+pub mod mcp_invocations;
 pub mod mcp_telemetry;
 pub mod media;
 pub mod mutation_audit;
+pub mod pacing;
+pub mod pagination;
+pub mod post_intents;
+pub mod privacy;
+pub mod process_lock;
+pub mod public_feed;
+pub mod qa_flags;
+pub mod query_log;
 pub mod rate_limits;
+pub mod reciprocity;
+pub mod remote_backup;
 pub mod replies;
+pub mod reply_explanations;
 pub mod scheduled_content;
+pub mod staged_actions;
 pub mod strategy;
 pub mod target_accounts;
+pub mod thread_performance;
 pub mod threads;
+pub mod threshold_calibrations;
 pub mod tweets;
+pub mod usage_reconciliation;
+pub mod warmup;
 pub mod watchtower;
+pub mod workflow_origins;
+pub mod write_queue;
 pub mod x_api_usage;
 
 use crate::error::StorageError;
 use sqlx::sqlite::{SqliteConnectOptions, SqliteJournalMode, SqlitePoolOptions, SqliteSynchronous};
+use sqlx::ConnectOptions;
 use std::str::FromStr;
 use std::time::Duration;
 
@@ -76,7 +113,8 @@ pub async fn init_db(db_path: &str) -> Result<DbPool, StorageError> {
         .synchronous(SqliteSynchronous::Normal)
         .busy_timeout(Duration::from_secs(5))
         .optimize_on_close(true, None)
-        .foreign_keys(true);
+        .foreign_keys(true)
+        .log_slow_statements(log::LevelFilter::Warn, query_log::slow_threshold());
 
     let pool = SqlitePoolOptions::new()
         .max_connections(4)
@@ -91,6 +129,7 @@ pub async fn init_db(db_path: &str) -> Result<DbPool, StorageError> {
         .await
         .map_err(|e| StorageError::Migration { source: e })?;
 
+    query_log::start_recorder(&pool);
     Ok(pool)
 }
 
@@ -156,6 +195,8 @@ mod tests {
         assert!(table_names.contains(&"original_tweets"));
         assert!(table_names.contains(&"threads"));
         assert!(table_names.contains(&"thread_tweets"));
+        assert!(table_names.contains(&"thread_tweet_performance"));
+        assert!(table_names.contains(&"slow_queries"));
         assert!(table_names.contains(&"rate_limits"));
         assert!(table_names.contains(&"action_log"));
         assert!(table_names.contains(&"target_accounts"));
@@ -175,6 +216,59 @@ mod tests {
         assert!(table_names.contains(&"source_contexts"));
         assert!(table_names.contains(&"content_nodes"));
         assert!(table_names.contains(&"draft_seeds"));
+        assert!(table_names.contains(&"archive_state"));
+    }
+
+    /// `EXPLAIN QUERY PLAN` details for `sql`, with every `?` bound to `x`.
+    async fn query_plan(pool: &DbPool, sql: &str) -> String {
+        let mut query = sqlx::query_as::<_, (i64, i64, i64, String)>(sql);
+        for _ in 0..sql.matches('?').count() {
+            query = query.bind("x");
+        }
+        let rows = query.fetch_all(pool).await.expect("explain");
+        rows.into_iter()
+            .map(|(_, _, _, detail)| detail)
+            .collect::<Vec<_>>()
+            .join("; ")
+    }
+
+    #[tokio::test]
+    async fn hot_queries_use_indexes() {
+        let pool = init_test_db().await.expect("init db");
+        let cases = [
+            (
+                "SELECT id FROM approval_queue \
+                 WHERE status = 'pending' AND account_id = ? ORDER BY created_at ASC",
+                "idx_approval_queue_status_account_created",
+            ),
+            (
+                "SELECT EXISTS(SELECT 1 FROM replies_sent WHERE account_id = ? AND target_tweet_id = ?)",
+                "idx_replies_sent_account_target",
+            ),
+            (
+                "SELECT reply_content FROM replies_sent WHERE account_id = ? \
+                 ORDER BY created_at DESC LIMIT 20",
+                "idx_replies_sent_account_created",
+            ),
+            (
+                "SELECT request_count FROM rate_limits WHERE account_id = ? AND action_type = ?",
+                "sqlite_autoindex_rate_limits_1",
+            ),
+            (
+                "SELECT id FROM discovered_tweets WHERE account_id = ? \
+                 ORDER BY discovered_at DESC LIMIT 50",
+                "idx_discovered_tweets_account_discovered",
+            ),
+        ];
+
+        for (sql, index) in cases {
+            let plan = query_plan(&pool, &format!("EXPLAIN QUERY PLAN {sql}")).await;
+            assert!(
+                plan.contains(index),
+                "{sql}\n  expected {index}, got: {plan}"
+            );
+            assert!(!plan.contains("TEMP B-TREE"), "{sql}\n  sorts rows: {plan}");
+        }
     }
 
     #[tokio::test]
@@ -0,0 +1,115 @@
+//! Per-day request counters for the pacing planner.
+//!
+//! Each row counts requests to one paced endpoint on one local day
+//! (`YYYY-MM-DD` in the schedule timezone).
+
+use super::accounts::DEFAULT_ACCOUNT_ID;
+use super::DbPool;
+use crate::error::StorageError;
+
+/// Record one request to `endpoint` on `day` for a specific account.
+pub async fn increment_pacing_usage_for(
+    pool: &DbPool,
+    account_id: &str,
+    endpoint: &str,
+    day: &str,
+) -> Result<(), StorageError> {
+    sqlx::query(
+        "INSERT INTO pacing_usage (account_id, endpoint, day, request_count) VALUES (?1, ?2, ?3, 1) \
+         ON CONFLICT(account_id, endpoint, day) DO UPDATE SET request_count = request_count + 1",
+    )
+    .bind(account_id)
+    .bind(endpoint)
+    .bind(day)
+    .execute(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+    Ok(())
+}
+
+/// Record one request to `endpoint` on `day`.
+pub async fn increment_pacing_usage(
+    pool: &DbPool,
+    endpoint: &str,
+    day: &str,
+) -> Result<(), StorageError> {
+    increment_pacing_usage_for(pool, DEFAULT_ACCOUNT_ID, endpoint, day).await
+}
+
+/// Number of requests made to `endpoint` on `day` for a specific account.
+pub async fn get_pacing_usage_for(
+    pool: &DbPool,
+    account_id: &str,
+    endpoint: &str,
+    day: &str,
+) -> Result<u32, StorageError> {
+    let row: Option<(i64,)> = sqlx::query_as(
+        "SELECT request_count FROM pacing_usage \
+         WHERE account_id = ?1 AND endpoint = ?2 AND day = ?3",
+    )
+    .bind(account_id)
+    .bind(endpoint)
+    .bind(day)
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+    Ok(row.map_or(0, |(n,)| n.max(0) as u32))
+}
+
+/// Number of requests made to `endpoint` on `day`.
+pub async fn get_pacing_usage(
+    pool: &DbPool,
+    endpoint: &str,
+    day: &str,
+) -> Result<u32, StorageError> {
+    get_pacing_usage_for(pool, DEFAULT_ACCOUNT_ID, endpoint, day).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::init_test_db;
+
+    #[tokio::test]
+    async fn usage_counts_per_endpoint_and_day() {
+        let pool = init_test_db().await.expect("init db");
+        assert_eq!(
+            get_pacing_usage(&pool, "search", "2026-03-01")
+                .await
+                .unwrap(),
+            0
+        );
+
+        increment_pacing_usage(&pool, "search", "2026-03-01")
+            .await
+            .unwrap();
+        increment_pacing_usage(&pool, "search", "2026-03-01")
+            .await
+            .unwrap();
+        increment_pacing_usage(&pool, "user_tweets", "2026-03-01")
+            .await
+            .unwrap();
+        increment_pacing_usage(&pool, "search", "2026-03-02")
+            .await
+            .unwrap();
+
+        assert_eq!(
+            get_pacing_usage(&pool, "search", "2026-03-01")
+                .await
+                .unwrap(),
+            2
+        );
+        assert_eq!(
+            get_pacing_usage(&pool, "user_tweets", "2026-03-01")
+                .await
+                .unwrap(),
+            1
+        );
+        assert_eq!(
+            get_pacing_usage(&pool, "search", "2026-03-02")
+                .await
+                .unwrap(),
+            1
+        );
+    }
+}
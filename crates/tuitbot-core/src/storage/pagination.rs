@@ -0,0 +1,289 @@
+//! Paged list queries shared by the list endpoints.
+//!
+//! A [`PageRequest`] carries a limit and offset, an optional keyset cursor,
+//! and an optional [`Sort`]. [`Conditions`] collects `WHERE` clauses with
+//! their bound values, and [`fetch_page`] runs the page query plus a
+//! matching `COUNT(*)`. A cursor is the `id` of the last row of the
+//! previous page and only applies to chronological sorts, where `id`
+//! follows `created_at`.
+
+use sqlx::sqlite::SqliteRow;
+use sqlx::FromRow;
+
+use super::DbPool;
+use crate::error::StorageError;
+
+/// Largest page a list query returns.
+pub const MAX_PAGE_LIMIT: u32 = 500;
+
+/// Sort order for a list query.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Sort {
+    /// Column to sort by.
+    pub column: &'static str,
+    pub descending: bool,
+}
+
+impl Sort {
+    /// Newest or largest first.
+    pub const fn desc(column: &'static str) -> Self {
+        Self {
+            column,
+            descending: true,
+        }
+    }
+
+    /// Oldest or smallest first.
+    pub const fn asc(column: &'static str) -> Self {
+        Self {
+            column,
+            descending: false,
+        }
+    }
+
+    /// Parse `field` (ascending) or `-field` (descending). `fields` maps
+    /// the accepted field names to their columns.
+    pub fn parse(spec: &str, fields: &[(&str, &'static str)]) -> Result<Self, String> {
+        let (name, descending) = match spec.strip_prefix('-') {
+            Some(name) => (name, true),
+            None => (spec, false),
+        };
+        fields
+            .iter()
+            .find(|(field, _)| *field == name)
+            .map(|(_, column)| Self { column, descending })
+            .ok_or_else(|| {
+                let names: Vec<&str> = fields.iter().map(|(field, _)| *field).collect();
+                format!(
+                    "cannot sort by '{name}'; sortable fields: {}",
+                    names.join(", ")
+                )
+            })
+    }
+
+    /// Whether rows in this order follow insertion order, so an `id`
+    /// cursor applies.
+    pub fn is_chronological(&self) -> bool {
+        matches!(self.column, "id" | "created_at")
+    }
+
+    fn order_by(&self) -> String {
+        let dir = if self.descending { "DESC" } else { "ASC" };
+        format!("ORDER BY {} {dir}, id {dir}", self.column)
+    }
+}
+
+/// Which page of a list to fetch.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PageRequest {
+    pub limit: u32,
+    pub offset: u32,
+    /// `id` of the last row already seen; chronological sorts only.
+    pub cursor: Option<i64>,
+    /// `None` keeps the list's default order.
+    pub sort: Option<Sort>,
+}
+
+impl PageRequest {
+    /// The first `limit` rows in `sort` order.
+    pub fn first(limit: u32, sort: Option<Sort>) -> Self {
+        Self {
+            limit,
+            offset: 0,
+            cursor: None,
+            sort,
+        }
+    }
+
+    fn chronological(&self) -> Option<Sort> {
+        self.sort.filter(Sort::is_chronological)
+    }
+}
+
+/// One page of rows.
+#[derive(Debug, Clone)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    /// Rows matching the filters across all pages.
+    pub total: i64,
+    /// Cursor for the next page; `None` on the last page or when the sort
+    /// does not support cursors.
+    pub next_cursor: Option<i64>,
+}
+
+impl<T> Page<T> {
+    /// Convert each row, keeping the totals and cursor.
+    pub fn map<U>(self, f: impl FnMut(T) -> U) -> Page<U> {
+        Page {
+            items: self.items.into_iter().map(f).collect(),
+            total: self.total,
+            next_cursor: self.next_cursor,
+        }
+    }
+}
+
+/// `WHERE` clauses and their bound values, joined with `AND`.
+#[derive(Debug, Clone, Default)]
+pub struct Conditions {
+    clauses: Vec<String>,
+    binds: Vec<String>,
+}
+
+impl Conditions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A clause with no bound values, e.g. `deleted_at IS NULL`.
+    pub fn clause(mut self, sql: &str) -> Self {
+        self.clauses.push(sql.to_string());
+        self
+    }
+
+    /// `column = value`, skipped when `value` is `None`.
+    pub fn eq(self, column: &str, value: Option<&str>) -> Self {
+        self.compare(column, "=", value)
+    }
+
+    /// `column >= value`, skipped when `value` is `None`.
+    pub fn since(self, column: &str, value: Option<&str>) -> Self {
+        self.compare(column, ">=", value)
+    }
+
+    /// `column < value`, skipped when `value` is `None`.
+    pub fn until(self, column: &str, value: Option<&str>) -> Self {
+        self.compare(column, "<", value)
+    }
+
+    /// `column IN (values)`. An empty list matches nothing.
+    pub fn any_of(mut self, column: &str, values: &[&str]) -> Self {
+        if values.is_empty() {
+            self.clauses.push("0".to_string());
+        } else {
+            let placeholders = vec!["?"; values.len()].join(", ");
+            self.clauses.push(format!("{column} IN ({placeholders})"));
+            self.binds.extend(values.iter().map(|v| v.to_string()));
+        }
+        self
+    }
+
+    fn compare(mut self, column: &str, op: &str, value: Option<&str>) -> Self {
+        if let Some(value) = value {
+            self.clauses.push(format!("{column} {op} ?"));
+            self.binds.push(value.to_string());
+        }
+        self
+    }
+
+    fn where_sql(&self, extra: Option<&str>) -> String {
+        let clauses: Vec<&str> = self
+            .clauses
+            .iter()
+            .map(String::as_str)
+            .chain(extra)
+            .collect();
+        if clauses.is_empty() {
+            String::new()
+        } else {
+            format!(" WHERE {}", clauses.join(" AND "))
+        }
+    }
+}
+
+/// Fetch one page of `SELECT {columns} FROM {table}` rows matching
+/// `conditions`, with the total count. Without a sort, rows come in
+/// `default_order` (a full `ORDER BY` clause). `id_of` reads the row ID
+/// for the next cursor.
+pub async fn fetch_page<R>(
+    pool: &DbPool,
+    table: &str,
+    columns: &str,
+    conditions: &Conditions,
+    page: &PageRequest,
+    default_order: &str,
+    id_of: fn(&R) -> i64,
+) -> Result<Page<R>, StorageError>
+where
+    R: for<'r> FromRow<'r, SqliteRow> + Send + Unpin,
+{
+    let keyset = page.chronological().zip(page.cursor);
+    let cursor_clause = keyset.map(|(sort, _)| if sort.descending { "id < ?" } else { "id > ?" });
+    let order = page
+        .sort
+        .map(|s| s.order_by())
+        .unwrap_or_else(|| default_order.to_string());
+    let sql = format!(
+        "SELECT {columns} FROM {table}{} {order} LIMIT ? OFFSET ?",
+        conditions.where_sql(cursor_clause)
+    );
+
+    let mut query = sqlx::query_as::<_, R>(&sql);
+    for value in &conditions.binds {
+        query = query.bind(value);
+    }
+    if let Some((_, cursor)) = keyset {
+        query = query.bind(cursor);
+    }
+    let items = query
+        .bind(page.limit)
+        .bind(page.offset)
+        .fetch_all(pool)
+        .await
+        .map_err(|e| StorageError::Query { source: e })?;
+
+    let count_sql = format!("SELECT COUNT(*) FROM {table}{}", conditions.where_sql(None));
+    let mut count = sqlx::query_as::<_, (i64,)>(&count_sql);
+    for value in &conditions.binds {
+        count = count.bind(value);
+    }
+    let (total,) = count
+        .fetch_one(pool)
+        .await
+        .map_err(|e| StorageError::Query { source: e })?;
+
+    let full = page.limit > 0 && items.len() as u32 == page.limit;
+    let next_cursor = if full && page.chronological().is_some() {
+        items.last().map(id_of)
+    } else {
+        None
+    };
+    Ok(Page {
+        items,
+        total,
+        next_cursor,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FIELDS: &[(&str, &str)] = &[("created_at", "created_at"), ("type", "action_type")];
+
+    #[test]
+    fn sort_parses_direction_and_rejects_unknown_fields() {
+        assert_eq!(
+            Sort::parse("-created_at", FIELDS),
+            Ok(Sort::desc("created_at"))
+        );
+        assert_eq!(Sort::parse("type", FIELDS), Ok(Sort::asc("action_type")));
+        let err = Sort::parse("secret", FIELDS).unwrap_err();
+        assert!(err.contains("created_at, type"), "{err}");
+        assert!(!Sort::asc("action_type").is_chronological());
+    }
+
+    #[test]
+    fn conditions_build_where_clause() {
+        let conditions = Conditions::new()
+            .eq("account_id", Some("a"))
+            .eq("status", None)
+            .any_of("status", &["pending", "approved"])
+            .clause("deleted_at IS NULL");
+        assert_eq!(
+            conditions.where_sql(Some("id < ?")),
+            " WHERE account_id = ? AND status IN (?, ?) AND deleted_at IS NULL AND id < ?"
+        );
+        assert_eq!(conditions.binds, vec!["a", "pending", "approved"]);
+        assert_eq!(Conditions::new().where_sql(None), "");
+    }
+}
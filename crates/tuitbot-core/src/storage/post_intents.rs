@@ -0,0 +1,258 @@
+//! Persisted intents for outbound posts.
+//!
+//! Each outbound tweet or reply gets a dedup key derived from what is being
+//! posted. The intent is recorded before the X API call and completed with
+//! the resulting tweet ID afterwards, so a retry can tell whether an earlier
+//! attempt with an unknown outcome may already have gone out.
+
+use sha2::{Digest, Sha256};
+
+use super::accounts::DEFAULT_ACCOUNT_ID;
+use super::DbPool;
+use crate::error::StorageError;
+
+/// How long an intent identifies "the same post". Posting identical text
+/// again after this window starts a fresh intent.
+const INTENT_WINDOW_HOURS: u32 = 24;
+
+/// A recorded outbound post intent.
+#[derive(Debug, Clone, sqlx::FromRow, serde::Serialize)]
+pub struct PostIntent {
+    pub id: i64,
+    pub dedup_key: String,
+    /// `tweet` or `reply`.
+    pub action_type: String,
+    /// Tweet being replied to (empty for original tweets).
+    pub in_reply_to_id: String,
+    pub content: String,
+    /// `pending`, `posted`, or `failed`.
+    pub status: String,
+    pub tweet_id: Option<String>,
+    /// Number of times posting was attempted, including the current one.
+    pub attempts: i64,
+    pub last_error: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl PostIntent {
+    /// Whether an earlier attempt may have reached X without being confirmed.
+    pub fn is_retry(&self) -> bool {
+        self.status != "posted" && self.attempts > 1
+    }
+}
+
+/// Compute the dedup key for an outbound post.
+///
+/// `scope` distinguishes callers that may legitimately post the same text,
+/// e.g. `approval:42`. Whitespace differences do not change the key.
+pub fn compute_dedup_key(
+    scope: &str,
+    action_type: &str,
+    in_reply_to_id: &str,
+    text: &str,
+) -> String {
+    let normalized = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    let mut hasher = Sha256::new();
+    for part in [scope, action_type, in_reply_to_id, normalized.as_str()] {
+        hasher.update(part.as_bytes());
+        hasher.update(b"|");
+    }
+    format!("{:x}", hasher.finalize())
+}
+
+/// Record an attempt to post for a specific account, returning the intent.
+///
+/// Creates the intent on the first attempt and bumps `attempts` on later
+/// ones. Intents older than the dedup window are replaced.
+pub async fn begin_intent_for(
+    pool: &DbPool,
+    account_id: &str,
+    dedup_key: &str,
+    action_type: &str,
+    in_reply_to_id: &str,
+    content: &str,
+) -> Result<PostIntent, StorageError> {
+    sqlx::query(
+        "DELETE FROM post_intents WHERE account_id = ? AND dedup_key = ? \
+         AND datetime(created_at) < datetime('now', ?)",
+    )
+    .bind(account_id)
+    .bind(dedup_key)
+    .bind(format!("-{INTENT_WINDOW_HOURS} hours"))
+    .execute(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+
+    sqlx::query_as::<_, PostIntent>(
+        "INSERT INTO post_intents \
+         (account_id, dedup_key, action_type, in_reply_to_id, content, attempts) \
+         VALUES (?, ?, ?, ?, ?, 1) \
+         ON CONFLICT(account_id, dedup_key) DO UPDATE SET \
+            attempts = attempts + 1, \
+            updated_at = strftime('%Y-%m-%dT%H:%M:%SZ', 'now') \
+         RETURNING id, dedup_key, action_type, in_reply_to_id, content, status, tweet_id, \
+            attempts, last_error, created_at, updated_at",
+    )
+    .bind(account_id)
+    .bind(dedup_key)
+    .bind(action_type)
+    .bind(in_reply_to_id)
+    .bind(content)
+    .fetch_one(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })
+}
+
+/// Record an attempt to post, returning the intent.
+pub async fn begin_intent(
+    pool: &DbPool,
+    dedup_key: &str,
+    action_type: &str,
+    in_reply_to_id: &str,
+    content: &str,
+) -> Result<PostIntent, StorageError> {
+    begin_intent_for(
+        pool,
+        DEFAULT_ACCOUNT_ID,
+        dedup_key,
+        action_type,
+        in_reply_to_id,
+        content,
+    )
+    .await
+}
+
+/// Mark an intent as posted with the resulting tweet ID for a specific account.
+pub async fn mark_intent_posted_for(
+    pool: &DbPool,
+    account_id: &str,
+    dedup_key: &str,
+    tweet_id: &str,
+) -> Result<(), StorageError> {
+    sqlx::query(
+        "UPDATE post_intents SET status = 'posted', tweet_id = ?, last_error = NULL, \
+         updated_at = strftime('%Y-%m-%dT%H:%M:%SZ', 'now') \
+         WHERE account_id = ? AND dedup_key = ?",
+    )
+    .bind(tweet_id)
+    .bind(account_id)
+    .bind(dedup_key)
+    .execute(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+
+    Ok(())
+}
+
+/// Mark an intent as posted with the resulting tweet ID.
+pub async fn mark_intent_posted(
+    pool: &DbPool,
+    dedup_key: &str,
+    tweet_id: &str,
+) -> Result<(), StorageError> {
+    mark_intent_posted_for(pool, DEFAULT_ACCOUNT_ID, dedup_key, tweet_id).await
+}
+
+/// Record a failed attempt for a specific account.
+///
+/// The failure may have been ambiguous (the post could still have gone
+/// out), so the next attempt checks the timeline before re-sending.
+pub async fn mark_intent_failed_for(
+    pool: &DbPool,
+    account_id: &str,
+    dedup_key: &str,
+    error: &str,
+) -> Result<(), StorageError> {
+    sqlx::query(
+        "UPDATE post_intents SET status = 'failed', last_error = ?, \
+         updated_at = strftime('%Y-%m-%dT%H:%M:%SZ', 'now') \
+         WHERE account_id = ? AND dedup_key = ? AND status != 'posted'",
+    )
+    .bind(error)
+    .bind(account_id)
+    .bind(dedup_key)
+    .execute(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+
+    Ok(())
+}
+
+/// Record a failed attempt.
+pub async fn mark_intent_failed(
+    pool: &DbPool,
+    dedup_key: &str,
+    error: &str,
+) -> Result<(), StorageError> {
+    mark_intent_failed_for(pool, DEFAULT_ACCOUNT_ID, dedup_key, error).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::init_test_db;
+
+    #[test]
+    fn dedup_key_ignores_whitespace_but_not_scope() {
+        let a = compute_dedup_key("mcp", "tweet", "", "Hello  world\n");
+        let b = compute_dedup_key("mcp", "tweet", "", "Hello world");
+        let c = compute_dedup_key("approval:1", "tweet", "", "Hello world");
+        let d = compute_dedup_key("mcp", "reply", "123", "Hello world");
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+        assert_ne!(a, d);
+    }
+
+    #[tokio::test]
+    async fn intent_lifecycle() {
+        let pool = init_test_db().await.expect("init db");
+        let key = compute_dedup_key("mcp", "tweet", "", "Hello");
+
+        let first = begin_intent(&pool, &key, "tweet", "", "Hello")
+            .await
+            .unwrap();
+        assert_eq!(first.attempts, 1);
+        assert!(!first.is_retry());
+
+        mark_intent_failed(&pool, &key, "timeout").await.unwrap();
+        let retry = begin_intent(&pool, &key, "tweet", "", "Hello")
+            .await
+            .unwrap();
+        assert_eq!(retry.attempts, 2);
+        assert_eq!(retry.status, "failed");
+        assert_eq!(retry.last_error.as_deref(), Some("timeout"));
+        assert!(retry.is_retry());
+
+        mark_intent_posted(&pool, &key, "999").await.unwrap();
+        mark_intent_failed(&pool, &key, "late error").await.unwrap();
+        let done = begin_intent(&pool, &key, "tweet", "", "Hello")
+            .await
+            .unwrap();
+        assert_eq!(done.status, "posted");
+        assert_eq!(done.tweet_id.as_deref(), Some("999"));
+        assert!(!done.is_retry());
+    }
+
+    #[tokio::test]
+    async fn expired_intent_starts_fresh() {
+        let pool = init_test_db().await.expect("init db");
+        let key = compute_dedup_key("mcp", "tweet", "", "Hello");
+        begin_intent(&pool, &key, "tweet", "", "Hello")
+            .await
+            .unwrap();
+        mark_intent_posted(&pool, &key, "999").await.unwrap();
+        sqlx::query(
+            "UPDATE post_intents SET created_at = strftime('%Y-%m-%dT%H:%M:%SZ', 'now', '-2 days')",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let fresh = begin_intent(&pool, &key, "tweet", "", "Hello")
+            .await
+            .unwrap();
+        assert_eq!(fresh.attempts, 1);
+        assert_eq!(fresh.status, "pending");
+    }
+}
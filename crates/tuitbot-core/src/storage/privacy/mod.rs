@@ -0,0 +1,484 @@
+//! Targeted data wipe for a single external X user.
+//!
+//! Implements "forget me" requests: every stored row that describes or
+//! references a specific X user is deleted or anonymized, across all
+//! agent accounts. The agent's own published content (reply text, tweets)
+//! is kept so dedup keeps working, but its link to the user is severed.
+
+use super::DbPool;
+use crate::error::StorageError;
+
+/// Placeholder written into text columns that referenced a forgotten user.
+pub const REDACTED: &str = "[redacted]";
+
+/// Replacement for recorded MCP arguments and staged action parameters that
+/// referenced a forgotten user.
+///
+/// Valid JSON carrying the MCP recorder's `[REDACTED]` marker, so replay
+/// refuses the invocation instead of re-running it with missing arguments.
+const REDACTED_ARGS: &str = r#"{"args":"[REDACTED]"}"#;
+
+/// Identity of the user being forgotten, resolved from stored rows.
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize)]
+pub struct ForgetTarget {
+    /// Numeric X user ID, if known.
+    pub user_id: Option<String>,
+    /// X username without the leading `@`, if known.
+    pub username: Option<String>,
+}
+
+/// Report of everything removed or anonymized by [`forget_user`].
+#[derive(Debug, Clone, Default, serde::Serialize)]
+pub struct ForgetReport {
+    /// The resolved identity that was wiped.
+    pub target: ForgetTarget,
+    /// Discovered tweets authored by the user (deleted).
+    pub discovered_tweets_deleted: u64,
+    /// Target tweets authored by the user (deleted).
+    pub target_tweets_deleted: u64,
+    /// Reply history entries for the user as a target account (deleted).
+    pub target_reply_history_deleted: u64,
+    /// Target account rows for the user (deleted).
+    pub target_accounts_deleted: u64,
+    /// Per-day author interaction counters (deleted).
+    pub author_interactions_deleted: u64,
+    /// Mentions and replies received from the user (deleted).
+    pub inbound_engagements_deleted: u64,
+    /// Sent replies whose author fields were cleared.
+    pub replies_anonymized: u64,
+    /// Pending approval items aimed at the user (deleted, with edit history).
+    pub approval_items_deleted: u64,
+    /// Reviewed approval items whose target author was cleared.
+    pub approval_items_anonymized: u64,
+    /// Mutation audit entries whose params/result summaries were redacted.
+    pub mutation_audit_redacted: u64,
+    /// MCP telemetry entries whose metadata was cleared.
+    pub mcp_telemetry_redacted: u64,
+    /// Recorded MCP invocations whose arguments were redacted.
+    pub mcp_invocations_redacted: u64,
+    /// Hook runs whose target and reason were cleared.
+    pub hook_runs_redacted: u64,
+    /// Ingested candidate posts authored by the user (deleted).
+    pub ingested_candidates_deleted: u64,
+    /// Staged MCP actions whose parameters were redacted (pending ones are rejected).
+    pub staged_actions_redacted: u64,
+    /// Post intents whose text and reply target were cleared.
+    pub post_intents_redacted: u64,
+    /// Action log entries whose message/metadata were redacted.
+    pub action_log_redacted: u64,
+    /// Total rows touched across all tables.
+    pub total_affected: u64,
+}
+
+/// Resolve a `@username`, `username`, or numeric user ID to a full identity.
+///
+/// Looks the counterpart up in discovered tweets, target accounts, author
+/// interactions, and sent replies. A user with no stored identity rows
+/// resolves to the identifier alone so free-form traces can still be
+/// scrubbed. Returns `None` only for an empty identifier.
+pub async fn resolve_target(
+    pool: &DbPool,
+    identifier: &str,
+) -> Result<Option<ForgetTarget>, StorageError> {
+    let ident = identifier.trim().trim_start_matches('@');
+    if ident.is_empty() {
+        return Ok(None);
+    }
+
+    let is_numeric_id = ident.chars().all(|c| c.is_ascii_digit());
+
+    // (author_id, author_username) pairs from every table that stores both.
+    let row: Option<(String, String)> = sqlx::query_as(
+        "SELECT author_id, author_username FROM ( \
+             SELECT author_id, author_username FROM discovered_tweets \
+             UNION ALL SELECT account_id, username FROM target_accounts \
+             UNION ALL SELECT author_id, author_username FROM author_interactions \
+             UNION ALL SELECT COALESCE(author_id, ''), COALESCE(author_username, '') FROM replies_sent \
+         ) WHERE (author_id = ?1 AND ?2 = 1) OR (lower(author_username) = lower(?1) AND ?2 = 0) \
+         ORDER BY author_id = '', author_username = '' \
+         LIMIT 1",
+    )
+    .bind(ident)
+    .bind(i64::from(is_numeric_id))
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+
+    let non_empty = |s: String| if s.is_empty() { None } else { Some(s) };
+
+    Ok(match row {
+        Some((id, username)) => Some(ForgetTarget {
+            user_id: non_empty(id),
+            username: non_empty(username),
+        }),
+        None if is_numeric_id => Some(ForgetTarget {
+            user_id: Some(ident.to_string()),
+            username: None,
+        }),
+        None => Some(ForgetTarget {
+            user_id: None,
+            username: Some(ident.to_string()),
+        }),
+    })
+}
+
+/// Build lowercase substring needles used to find the user inside free-form
+/// JSON/text columns (audit params, telemetry metadata, MCP arguments, hook
+/// reasons, action log).
+///
+/// Values are matched as JSON strings (`"value"`) or mentions (`@username`)
+/// so that a short username does not match unrelated longer ones.
+fn text_needles(target: &ForgetTarget) -> Vec<String> {
+    let mut needles = Vec::new();
+    if let Some(id) = &target.user_id {
+        needles.push(format!("\"{id}\""));
+    }
+    if let Some(username) = &target.username {
+        let lower = username.to_lowercase();
+        needles.push(format!("\"{lower}\""));
+        needles.push(format!("\"@{lower}\""));
+        needles.push(format!("@{lower}"));
+    }
+    needles
+}
+
+/// Build a `(instr(lower(col), ?) > 0 OR ...)` clause for the given column.
+fn needle_clause(column: &str, count: usize) -> String {
+    let parts: Vec<String> = (0..count)
+        .map(|_| format!("instr(lower(COALESCE({column}, '')), ?) > 0"))
+        .collect();
+    format!("({})", parts.join(" OR "))
+}
+
+/// Delete or anonymize all stored data about a specific external X user.
+///
+/// `identifier` may be a username (with or without `@`) or a numeric user ID.
+/// Runs in a single transaction across every agent account; either the whole
+/// wipe applies or nothing does.
+pub async fn forget_user(pool: &DbPool, identifier: &str) -> Result<ForgetReport, StorageError> {
+    let Some(target) = resolve_target(pool, identifier).await? else {
+        return Ok(ForgetReport::default());
+    };
+
+    // Sentinels that can never match a real row when a half of the identity is unknown.
+    let user_id = target
+        .user_id
+        .clone()
+        .unwrap_or_else(|| "\u{0}".to_string());
+    let username = target
+        .username
+        .clone()
+        .unwrap_or_else(|| "\u{0}".to_string());
+    let mention = format!("@{username}");
+
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|e| StorageError::Connection { source: e })?;
+
+    let mut report = ForgetReport {
+        target: target.clone(),
+        ..Default::default()
+    };
+
+    // Approval items aimed at the user: by author handle or by a tweet they wrote.
+    let approval_match = "(lower(COALESCE(target_author, '')) IN (lower(?1), lower(?2)) \
+         OR target_tweet_id IN (SELECT id FROM discovered_tweets \
+             WHERE author_id = ?3 OR lower(author_username) = lower(?1)))";
+
+    // 1. Pending approval items would still engage the user — drop them entirely.
+    for child in ["approval_edit_history", "approval_comments"] {
+        sqlx::query(&format!(
+            "DELETE FROM {child} WHERE approval_id IN \
+             (SELECT id FROM approval_queue WHERE status = 'pending' AND {approval_match})"
+        ))
+        .bind(&username)
+        .bind(&mention)
+        .bind(&user_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| StorageError::Query { source: e })?;
+    }
+
+    report.approval_items_deleted = sqlx::query(&format!(
+        "DELETE FROM approval_queue WHERE status = 'pending' AND {approval_match}"
+    ))
+    .bind(&username)
+    .bind(&mention)
+    .bind(&user_id)
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?
+    .rows_affected();
+
+    // 2. Reviewed approval items are history — keep them, drop the link to the user.
+    report.approval_items_anonymized = sqlx::query(&format!(
+        "UPDATE approval_queue SET target_author = '' \
+         WHERE COALESCE(target_author, '') != '' AND {approval_match}"
+    ))
+    .bind(&username)
+    .bind(&mention)
+    .bind(&user_id)
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?
+    .rows_affected();
+
+    // 3. Sent replies: keep our content for dedup, clear who it was aimed at.
+    report.replies_anonymized = sqlx::query(
+        "UPDATE replies_sent SET author_id = '', author_username = '' \
+         WHERE author_id = ?1 OR lower(author_username) = lower(?2) \
+            OR target_tweet_id IN (SELECT id FROM discovered_tweets \
+                WHERE author_id = ?1 OR lower(author_username) = lower(?2))",
+    )
+    .bind(&user_id)
+    .bind(&username)
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?
+    .rows_affected();
+
+    // Needles for free-form columns, used by the hook run and trace steps.
+    let needles = text_needles(&target);
+
+    // Hook runs aimed at the user or at a tweet they wrote; hook output may
+    // also name them. Runs before their discovered tweets are deleted.
+    let mut hook_where = "target_id = ? OR target_id IN (SELECT id FROM discovered_tweets \
+         WHERE author_id = ? OR lower(author_username) = lower(?))"
+        .to_string();
+    if !needles.is_empty() {
+        hook_where = format!("{hook_where} OR {}", needle_clause("reason", needles.len()));
+    }
+    let sql = format!(
+        "UPDATE hook_runs SET target_id = NULL, \
+         reason = CASE WHEN reason IS NULL THEN NULL ELSE '{REDACTED}' END \
+         WHERE {hook_where}"
+    );
+    let mut query = sqlx::query(&sql)
+        .bind(&user_id)
+        .bind(&user_id)
+        .bind(&username);
+    for needle in &needles {
+        query = query.bind(needle);
+    }
+    report.hook_runs_redacted = query
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| StorageError::Query { source: e })?
+        .rows_affected();
+
+    // Post intents replying to the user's tweets or naming them in the text.
+    // The dedup key is a hash, so retries still match without the text.
+    let mut intent_where = "in_reply_to_id IN (SELECT id FROM discovered_tweets \
+         WHERE author_id = ? OR lower(author_username) = lower(?))"
+        .to_string();
+    if !needles.is_empty() {
+        intent_where = format!(
+            "{intent_where} OR {}",
+            needle_clause("content", needles.len())
+        );
+    }
+    let sql = format!(
+        "UPDATE post_intents SET content = '{REDACTED}', in_reply_to_id = '' WHERE {intent_where}"
+    );
+    let mut query = sqlx::query(&sql).bind(&user_id).bind(&username);
+    for needle in &needles {
+        query = query.bind(needle);
+    }
+    report.post_intents_redacted = query
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| StorageError::Query { source: e })?
+        .rows_affected();
+
+    // 4. Tweets the user authored, discovered or ingested.
+    report.discovered_tweets_deleted = sqlx::query(
+        "DELETE FROM discovered_tweets WHERE author_id = ? OR lower(author_username) = lower(?)",
+    )
+    .bind(&user_id)
+    .bind(&username)
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?
+    .rows_affected();
+
+    report.ingested_candidates_deleted =
+        sqlx::query("DELETE FROM ingested_candidates WHERE lower(author_username) = lower(?)")
+            .bind(&username)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| StorageError::Query { source: e })?
+            .rows_affected();
+
+    // 5. Target tweets before their parent target account (FK).
+    report.target_tweets_deleted = sqlx::query(
+        "DELETE FROM target_tweets WHERE account_id IN \
+         (SELECT account_id FROM target_accounts WHERE account_id = ? OR lower(username) = lower(?))",
+    )
+    .bind(&user_id)
+    .bind(&username)
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?
+    .rows_affected();
+
+    report.target_reply_history_deleted = sqlx::query(
+        "DELETE FROM target_reply_history WHERE account_id IN \
+         (SELECT account_id FROM target_accounts WHERE account_id = ? OR lower(username) = lower(?))",
+    )
+    .bind(&user_id)
+    .bind(&username)
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?
+    .rows_affected();
+
+    report.target_accounts_deleted = sqlx::query(
+        "DELETE FROM target_accounts WHERE account_id = ? OR lower(username) = lower(?)",
+    )
+    .bind(&user_id)
+    .bind(&username)
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?
+    .rows_affected();
+
+    // 6. Relationship counters.
+    report.author_interactions_deleted = sqlx::query(
+        "DELETE FROM author_interactions WHERE author_id = ? OR lower(author_username) = lower(?)",
+    )
+    .bind(&user_id)
+    .bind(&username)
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?
+    .rows_affected();
+
+    report.inbound_engagements_deleted = sqlx::query(
+        "DELETE FROM inbound_engagements \
+         WHERE author_id = ? OR lower(author_username) = lower(?)",
+    )
+    .bind(&user_id)
+    .bind(&username)
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?
+    .rows_affected();
+
+    // 7. Decision traces: free-form JSON/text that mentions the user.
+    if !needles.is_empty() {
+        let audit_where = format!(
+            "{} OR {}",
+            needle_clause("params_summary", needles.len()),
+            needle_clause("result_summary", needles.len())
+        );
+        let sql = format!(
+            "UPDATE mutation_audit SET params_summary = '{REDACTED}', \
+             result_summary = NULL, rollback_action = NULL WHERE {audit_where}"
+        );
+        let mut query = sqlx::query(&sql);
+        for needle in needles.iter().chain(needles.iter()) {
+            query = query.bind(needle);
+        }
+        report.mutation_audit_redacted = query
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| StorageError::Query { source: e })?
+            .rows_affected();
+
+        let sql = format!(
+            "UPDATE mcp_telemetry SET metadata = NULL WHERE {}",
+            needle_clause("metadata", needles.len())
+        );
+        let mut query = sqlx::query(&sql);
+        for needle in &needles {
+            query = query.bind(needle);
+        }
+        report.mcp_telemetry_redacted = query
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| StorageError::Query { source: e })?
+            .rows_affected();
+
+        let sql = format!(
+            "UPDATE mcp_invocations SET args_json = '{REDACTED_ARGS}' WHERE {}",
+            needle_clause("args_json", needles.len())
+        );
+        let mut query = sqlx::query(&sql);
+        for needle in &needles {
+            query = query.bind(needle);
+        }
+        report.mcp_invocations_redacted = query
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| StorageError::Query { source: e })?
+            .rows_affected();
+
+        // A staged action aimed at the user must not run after the wipe.
+        let sql = format!(
+            "UPDATE staged_actions SET params_json = '{REDACTED_ARGS}', \
+             status = CASE WHEN status IN ('pending', 'confirmed') THEN 'rejected' ELSE status END, \
+             resolved_at = COALESCE(resolved_at, strftime('%Y-%m-%dT%H:%M:%SZ', 'now')), \
+             resolved_by = COALESCE(resolved_by, 'privacy') WHERE {}",
+            needle_clause("params_json", needles.len())
+        );
+        let mut query = sqlx::query(&sql);
+        for needle in &needles {
+            query = query.bind(needle);
+        }
+        report.staged_actions_redacted = query
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| StorageError::Query { source: e })?
+            .rows_affected();
+
+        let sql = format!(
+            "UPDATE action_log SET message = '{REDACTED}', metadata = NULL WHERE {} OR {}",
+            needle_clause("message", needles.len()),
+            needle_clause("metadata", needles.len())
+        );
+        let mut query = sqlx::query(&sql);
+        for needle in needles.iter().chain(needles.iter()) {
+            query = query.bind(needle);
+        }
+        report.action_log_redacted = query
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| StorageError::Query { source: e })?
+            .rows_affected();
+    }
+
+    tx.commit()
+        .await
+        .map_err(|e| StorageError::Connection { source: e })?;
+
+    report.total_affected = report.discovered_tweets_deleted
+        + report.target_tweets_deleted
+        + report.target_reply_history_deleted
+        + report.target_accounts_deleted
+        + report.author_interactions_deleted
+        + report.inbound_engagements_deleted
+        + report.replies_anonymized
+        + report.approval_items_deleted
+        + report.approval_items_anonymized
+        + report.mutation_audit_redacted
+        + report.mcp_telemetry_redacted
+        + report.mcp_invocations_redacted
+        + report.hook_runs_redacted
+        + report.ingested_candidates_deleted
+        + report.staged_actions_redacted
+        + report.post_intents_redacted
+        + report.action_log_redacted;
+
+    tracing::info!(
+        user_id = ?report.target.user_id,
+        username = ?report.target.username,
+        total = report.total_affected,
+        "Forgot external user"
+    );
+
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests;
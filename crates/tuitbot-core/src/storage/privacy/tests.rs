@@ -0,0 +1,315 @@
+//! Tests for the targeted user wipe.
+
+use super::*;
+use crate::storage::init_test_db;
+
+async fn insert_discovered(pool: &DbPool, id: &str, author_id: &str, username: &str) {
+    sqlx::query(
+        "INSERT INTO discovered_tweets (id, author_id, author_username, content) \
+         VALUES (?, ?, ?, 'hello')",
+    )
+    .bind(id)
+    .bind(author_id)
+    .bind(username)
+    .execute(pool)
+    .await
+    .expect("insert tweet");
+}
+
+async fn count(pool: &DbPool, sql: &str) -> i64 {
+    let row: (i64,) = sqlx::query_as(sql).fetch_one(pool).await.expect("count");
+    row.0
+}
+
+#[tokio::test]
+async fn resolve_by_username_finds_user_id() {
+    let pool = init_test_db().await.expect("init db");
+    insert_discovered(&pool, "t1", "111", "Alice").await;
+
+    let target = resolve_target(&pool, "@alice").await.expect("resolve");
+    assert_eq!(
+        target,
+        Some(ForgetTarget {
+            user_id: Some("111".to_string()),
+            username: Some("Alice".to_string()),
+        })
+    );
+}
+
+#[tokio::test]
+async fn resolve_unknown_numeric_id_keeps_identifier() {
+    let pool = init_test_db().await.expect("init db");
+
+    let target = resolve_target(&pool, "999").await.expect("resolve");
+    assert_eq!(target.unwrap().user_id.as_deref(), Some("999"));
+    assert!(resolve_target(&pool, " @ ")
+        .await
+        .expect("resolve")
+        .is_none());
+}
+
+#[tokio::test]
+async fn forget_removes_user_rows_and_keeps_others() {
+    let pool = init_test_db().await.expect("init db");
+    insert_discovered(&pool, "t1", "111", "alice").await;
+    insert_discovered(&pool, "t2", "222", "bob").await;
+
+    sqlx::query("INSERT INTO target_accounts (account_id, username) VALUES ('111', 'alice')")
+        .execute(&pool)
+        .await
+        .expect("insert target");
+    sqlx::query("INSERT INTO target_tweets (id, account_id) VALUES ('tt1', '111')")
+        .execute(&pool)
+        .await
+        .expect("insert target tweet");
+    sqlx::query(
+        "INSERT INTO author_interactions (author_id, author_username, interaction_date, reply_count) \
+         VALUES ('111', 'alice', '2026-01-01', 2), ('222', 'bob', '2026-01-01', 1)",
+    )
+    .execute(&pool)
+    .await
+    .expect("insert interactions");
+    sqlx::query(
+        "INSERT INTO replies_sent (target_tweet_id, reply_content, author_id, author_username) \
+         VALUES ('t1', 'nice', '111', 'alice')",
+    )
+    .execute(&pool)
+    .await
+    .expect("insert reply");
+
+    let report = forget_user(&pool, "alice").await.expect("forget");
+    assert_eq!(report.target.user_id.as_deref(), Some("111"));
+    assert_eq!(report.discovered_tweets_deleted, 1);
+    assert_eq!(report.target_tweets_deleted, 1);
+    assert_eq!(report.target_accounts_deleted, 1);
+    assert_eq!(report.author_interactions_deleted, 1);
+    assert_eq!(report.replies_anonymized, 1);
+    assert_eq!(report.total_affected, 5);
+
+    assert_eq!(
+        count(&pool, "SELECT COUNT(*) FROM discovered_tweets").await,
+        1
+    );
+    assert_eq!(
+        count(&pool, "SELECT COUNT(*) FROM author_interactions").await,
+        1
+    );
+    assert_eq!(
+        count(
+            &pool,
+            "SELECT COUNT(*) FROM replies_sent WHERE author_id = '' AND reply_content = 'nice'"
+        )
+        .await,
+        1
+    );
+}
+
+#[tokio::test]
+async fn forget_drops_pending_and_anonymizes_reviewed_approvals() {
+    let pool = init_test_db().await.expect("init db");
+    sqlx::query(
+        "INSERT INTO approval_queue (action_type, target_author, generated_content, status) \
+         VALUES ('reply', '@alice', 'pending one', 'pending'), \
+                ('reply', 'alice', 'approved one', 'approved'), \
+                ('reply', 'bob', 'unrelated', 'pending')",
+    )
+    .execute(&pool)
+    .await
+    .expect("insert approvals");
+
+    let report = forget_user(&pool, "@alice").await.expect("forget");
+    assert_eq!(report.approval_items_deleted, 1);
+    assert_eq!(report.approval_items_anonymized, 1);
+    assert_eq!(count(&pool, "SELECT COUNT(*) FROM approval_queue").await, 2);
+    assert_eq!(
+        count(
+            &pool,
+            "SELECT COUNT(*) FROM approval_queue WHERE target_author = 'bob'"
+        )
+        .await,
+        1
+    );
+}
+
+#[tokio::test]
+async fn forget_redacts_decision_traces() {
+    let pool = init_test_db().await.expect("init db");
+    sqlx::query(
+        "INSERT INTO mutation_audit (correlation_id, tool_name, params_hash, params_summary) \
+         VALUES ('c1', 'x_follow_user', 'h1', '{\"user_id\":\"111\"}'), \
+                ('c2', 'x_follow_user', 'h2', '{\"user_id\":\"1112\"}')",
+    )
+    .execute(&pool)
+    .await
+    .expect("insert audit");
+    sqlx::query(
+        "INSERT INTO action_log (action_type, message) \
+         VALUES ('reply', 'Replied to @Alice'), ('reply', 'Replied to @bob')",
+    )
+    .execute(&pool)
+    .await
+    .expect("insert action log");
+    sqlx::query(
+        "INSERT INTO mcp_invocations \
+         (session_id, tool_name, args_hash, args_json, success, latency_ms) \
+         VALUES ('s1', 'x_get_user_by_id', 'h1', '{\"user_id\":\"111\"}', 1, 5), \
+                ('s1', 'x_get_user_by_id', 'h2', '{\"user_id\":\"222\"}', 1, 5)",
+    )
+    .execute(&pool)
+    .await
+    .expect("insert mcp invocations");
+    sqlx::query(
+        "INSERT INTO hook_runs (event, hook_name, action_type, target_id, verdict, reason) \
+         VALUES ('pre_action', 'gate', 'reply', 't1', 'deny', 'blocked'), \
+                ('pre_action', 'gate', 'follow', NULL, 'deny', 'do not follow @alice'), \
+                ('pre_action', 'gate', 'reply', 't2', 'allow', 'fine')",
+    )
+    .execute(&pool)
+    .await
+    .expect("insert hook runs");
+    insert_discovered(&pool, "t1", "111", "alice").await;
+
+    let report = forget_user(&pool, "111").await.expect("forget");
+    assert_eq!(report.mutation_audit_redacted, 1);
+    assert_eq!(
+        count(
+            &pool,
+            "SELECT COUNT(*) FROM mutation_audit WHERE params_summary = '[redacted]'"
+        )
+        .await,
+        1
+    );
+    assert_eq!(report.action_log_redacted, 1);
+
+    assert_eq!(report.mcp_invocations_redacted, 1);
+    assert_eq!(
+        count(
+            &pool,
+            "SELECT COUNT(*) FROM mcp_invocations WHERE instr(args_json, '111') > 0"
+        )
+        .await,
+        0
+    );
+    assert_eq!(
+        count(
+            &pool,
+            "SELECT COUNT(*) FROM mcp_invocations WHERE instr(args_json, '222') > 0"
+        )
+        .await,
+        1
+    );
+
+    assert_eq!(report.hook_runs_redacted, 2);
+    assert_eq!(
+        count(
+            &pool,
+            "SELECT COUNT(*) FROM hook_runs WHERE target_id = 't1' \
+             OR instr(lower(reason), 'alice') > 0"
+        )
+        .await,
+        0
+    );
+    assert_eq!(
+        count(
+            &pool,
+            "SELECT COUNT(*) FROM hook_runs WHERE target_id = 't2' AND reason = 'fine'"
+        )
+        .await,
+        1
+    );
+}
+
+#[tokio::test]
+async fn forget_deletes_ingested_candidates_by_author() {
+    let pool = init_test_db().await.expect("init db");
+    sqlx::query(
+        "INSERT INTO ingested_candidates (id, source, author_username, content) \
+         VALUES ('i1', 'rss', 'Alice', 'alice wrote this'), \
+                ('i2', 'rss', 'bob', 'bob wrote this')",
+    )
+    .execute(&pool)
+    .await
+    .expect("insert candidates");
+
+    let report = forget_user(&pool, "@alice").await.expect("forget");
+    assert_eq!(report.ingested_candidates_deleted, 1);
+    assert_eq!(
+        count(
+            &pool,
+            "SELECT COUNT(*) FROM ingested_candidates WHERE author_username = 'bob'"
+        )
+        .await,
+        1
+    );
+    assert_eq!(
+        count(&pool, "SELECT COUNT(*) FROM ingested_candidates").await,
+        1
+    );
+}
+
+#[tokio::test]
+async fn forget_redacts_and_rejects_staged_actions() {
+    let pool = init_test_db().await.expect("init db");
+    sqlx::query(
+        "INSERT INTO staged_actions (token, tool_name, params_json, params_hash, expires_at) \
+         VALUES ('k1', 'reply_to_tweet', '{\"text\":\"hi @alice\"}', 'h1', '2099-01-01T00:00:00Z'), \
+                ('k2', 'reply_to_tweet', '{\"text\":\"hi @bob\"}', 'h2', '2099-01-01T00:00:00Z')",
+    )
+    .execute(&pool)
+    .await
+    .expect("insert staged actions");
+
+    let report = forget_user(&pool, "alice").await.expect("forget");
+    assert_eq!(report.staged_actions_redacted, 1);
+    assert_eq!(
+        count(
+            &pool,
+            "SELECT COUNT(*) FROM staged_actions WHERE token = 'k1' AND status = 'rejected' \
+             AND instr(lower(params_json), 'alice') = 0"
+        )
+        .await,
+        1
+    );
+    assert_eq!(
+        count(
+            &pool,
+            "SELECT COUNT(*) FROM staged_actions WHERE token = 'k2' AND status = 'pending'"
+        )
+        .await,
+        1
+    );
+}
+
+#[tokio::test]
+async fn forget_redacts_post_intents() {
+    let pool = init_test_db().await.expect("init db");
+    insert_discovered(&pool, "t1", "111", "alice").await;
+    sqlx::query(
+        "INSERT INTO post_intents (dedup_key, action_type, in_reply_to_id, content, status) \
+         VALUES ('d1', 'reply', 't1', 'great point', 'posted'), \
+                ('d2', 'tweet', '', 'thanks @alice!', 'posted'), \
+                ('d3', 'tweet', '', 'unrelated', 'posted')",
+    )
+    .execute(&pool)
+    .await
+    .expect("insert intents");
+
+    let report = forget_user(&pool, "alice").await.expect("forget");
+    assert_eq!(report.post_intents_redacted, 2);
+    assert_eq!(
+        count(
+            &pool,
+            "SELECT COUNT(*) FROM post_intents WHERE content = '[redacted]' AND in_reply_to_id = ''"
+        )
+        .await,
+        2
+    );
+    assert_eq!(
+        count(
+            &pool,
+            "SELECT COUNT(*) FROM post_intents WHERE content = 'unrelated'"
+        )
+        .await,
+        1
+    );
+}
@@ -0,0 +1,168 @@
+//! Cross-process advisory locks backed by a SQLite row.
+//!
+//! `tuitbot run`, the API server, and the MCP server can share one database.
+//! A lock row records which process holds a named lock and when it last
+//! proved it was alive. Holders refresh the heartbeat by re-acquiring; once
+//! the heartbeat is older than the caller's timeout, another process may
+//! take the lock over.
+
+use serde::Serialize;
+
+use super::DbPool;
+use crate::error::StorageError;
+
+/// The process currently holding a lock.
+#[derive(Debug, Clone, PartialEq, Eq, sqlx::FromRow, Serialize)]
+pub struct LockHolder {
+    /// Process identity, e.g. `tuitbot pid 4242`.
+    pub holder: String,
+    /// ISO-8601 UTC timestamp when this holder acquired the lock.
+    pub acquired_at: String,
+    /// ISO-8601 UTC timestamp of the holder's last heartbeat.
+    pub heartbeat_at: String,
+}
+
+/// Outcome of [`try_acquire_lock`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LockAttempt {
+    /// The caller holds the lock. `took_over` is the previous holder when
+    /// its heartbeat had gone stale.
+    Acquired { took_over: Option<LockHolder> },
+    /// Another live process holds the lock.
+    Held(LockHolder),
+}
+
+/// Acquire `name` for `holder`, or refresh the heartbeat if already held.
+///
+/// Succeeds when the lock is free, already held by `holder`, or its current
+/// holder has not sent a heartbeat for `stale_after_secs` seconds.
+pub async fn try_acquire_lock(
+    pool: &DbPool,
+    name: &str,
+    holder: &str,
+    stale_after_secs: i64,
+) -> Result<LockAttempt, StorageError> {
+    loop {
+        let previous = get_lock_holder(pool, name).await?;
+
+        sqlx::query(
+            "INSERT INTO process_locks (name, holder) VALUES (?1, ?2) \
+             ON CONFLICT(name) DO UPDATE SET \
+                acquired_at = CASE WHEN holder = excluded.holder THEN acquired_at \
+                                   ELSE excluded.acquired_at END, \
+                holder = excluded.holder, \
+                heartbeat_at = excluded.heartbeat_at \
+             WHERE holder = excluded.holder \
+                OR datetime(heartbeat_at) < datetime('now', ?3)",
+        )
+        .bind(name)
+        .bind(holder)
+        .bind(format!("-{stale_after_secs} seconds"))
+        .execute(pool)
+        .await
+        .map_err(|e| StorageError::Query { source: e })?;
+
+        match get_lock_holder(pool, name).await? {
+            Some(current) if current.holder == holder => {
+                return Ok(LockAttempt::Acquired {
+                    took_over: previous.filter(|p| p.holder != holder),
+                })
+            }
+            Some(current) => return Ok(LockAttempt::Held(current)),
+            // The other holder released between the upsert and the read.
+            None => continue,
+        }
+    }
+}
+
+/// Release `name` if it is still held by `holder`.
+pub async fn release_lock(pool: &DbPool, name: &str, holder: &str) -> Result<(), StorageError> {
+    sqlx::query("DELETE FROM process_locks WHERE name = ? AND holder = ?")
+        .bind(name)
+        .bind(holder)
+        .execute(pool)
+        .await
+        .map_err(|e| StorageError::Query { source: e })?;
+
+    Ok(())
+}
+
+/// The current holder of `name`, if any.
+pub async fn get_lock_holder(
+    pool: &DbPool,
+    name: &str,
+) -> Result<Option<LockHolder>, StorageError> {
+    sqlx::query_as::<_, LockHolder>(
+        "SELECT holder, acquired_at, heartbeat_at FROM process_locks WHERE name = ?",
+    )
+    .bind(name)
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::init_test_db;
+
+    #[tokio::test]
+    async fn second_process_waits_until_release() {
+        let pool = init_test_db().await.expect("init db");
+
+        let first = try_acquire_lock(&pool, "posting", "run pid 1", 60)
+            .await
+            .unwrap();
+        assert_eq!(first, LockAttempt::Acquired { took_over: None });
+
+        match try_acquire_lock(&pool, "posting", "mcp pid 2", 60)
+            .await
+            .unwrap()
+        {
+            LockAttempt::Held(holder) => assert_eq!(holder.holder, "run pid 1"),
+            other => panic!("expected held, got {other:?}"),
+        }
+
+        // Re-acquiring refreshes the holder's own lock.
+        let again = try_acquire_lock(&pool, "posting", "run pid 1", 60)
+            .await
+            .unwrap();
+        assert_eq!(again, LockAttempt::Acquired { took_over: None });
+
+        // Releasing someone else's lock is a no-op.
+        release_lock(&pool, "posting", "mcp pid 2").await.unwrap();
+        assert!(get_lock_holder(&pool, "posting").await.unwrap().is_some());
+
+        release_lock(&pool, "posting", "run pid 1").await.unwrap();
+        let second = try_acquire_lock(&pool, "posting", "mcp pid 2", 60)
+            .await
+            .unwrap();
+        assert_eq!(second, LockAttempt::Acquired { took_over: None });
+    }
+
+    #[tokio::test]
+    async fn stale_lock_is_taken_over() {
+        let pool = init_test_db().await.expect("init db");
+        try_acquire_lock(&pool, "posting", "run pid 1", 60)
+            .await
+            .unwrap();
+        sqlx::query(
+            "UPDATE process_locks SET heartbeat_at = strftime('%Y-%m-%dT%H:%M:%SZ', 'now', '-5 minutes')",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        match try_acquire_lock(&pool, "posting", "mcp pid 2", 60)
+            .await
+            .unwrap()
+        {
+            LockAttempt::Acquired {
+                took_over: Some(prev),
+            } => assert_eq!(prev.holder, "run pid 1"),
+            other => panic!("expected takeover, got {other:?}"),
+        }
+        let holder = get_lock_holder(&pool, "posting").await.unwrap().unwrap();
+        assert_eq!(holder.holder, "mcp pid 2");
+    }
+}
@@ -0,0 +1,125 @@
+//! Recent posts for the public activity feed.
+//!
+//! Reads only what was actually published to X: sent tweets, the opening
+//! tweet of sent threads, and optionally sent replies. Rows carry the
+//! posted text and public identifiers, never scores, QA results, or LLM
+//! details, so callers can expose them without further scrubbing.
+
+use super::DbPool;
+use crate::error::StorageError;
+
+/// A published post as shown in the public feed.
+#[derive(Debug, Clone, PartialEq, sqlx::FromRow, serde::Serialize)]
+pub struct PublicPost {
+    /// `tweet`, `thread`, or `reply`.
+    pub kind: String,
+    /// X tweet ID of the post (the root tweet for threads).
+    pub tweet_id: String,
+    /// Posted text (the opening tweet for threads).
+    pub text: String,
+    /// Content topic, for tweets and threads.
+    pub topic: Option<String>,
+    /// X tweet ID a reply answered.
+    pub in_reply_to: Option<String>,
+    /// ISO-8601 UTC timestamp when the post was sent.
+    pub created_at: String,
+}
+
+/// Most recent published posts for an account, newest first.
+///
+/// Failed and unposted rows are skipped. Replies are included only when
+/// `include_replies` is set, since they quote other people's conversations.
+pub async fn get_recent_public_posts_for(
+    pool: &DbPool,
+    account_id: &str,
+    include_replies: bool,
+    limit: u32,
+) -> Result<Vec<PublicPost>, StorageError> {
+    sqlx::query_as::<_, PublicPost>(
+        "SELECT 'tweet' AS kind, tweet_id, content AS text, topic, \
+                NULL AS in_reply_to, created_at \
+         FROM original_tweets \
+         WHERE account_id = ?1 AND status = 'sent' AND tweet_id IS NOT NULL \
+         UNION ALL \
+         SELECT 'thread' AS kind, t.root_tweet_id AS tweet_id, \
+                COALESCE(tt.content, '') AS text, t.topic, \
+                NULL AS in_reply_to, t.created_at \
+         FROM threads t \
+         LEFT JOIN thread_tweets tt ON tt.thread_id = t.id AND tt.position = 0 \
+         WHERE t.account_id = ?1 AND t.status = 'sent' AND t.root_tweet_id IS NOT NULL \
+         UNION ALL \
+         SELECT 'reply' AS kind, reply_tweet_id AS tweet_id, reply_content AS text, \
+                NULL AS topic, target_tweet_id AS in_reply_to, created_at \
+         FROM replies_sent \
+         WHERE ?2 AND account_id = ?1 AND status = 'sent' AND reply_tweet_id IS NOT NULL \
+         ORDER BY created_at DESC \
+         LIMIT ?3",
+    )
+    .bind(account_id)
+    .bind(include_replies)
+    .bind(limit)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::accounts::DEFAULT_ACCOUNT_ID;
+    use crate::storage::init_test_db;
+
+    async fn seed(pool: &DbPool) {
+        sqlx::query(
+            "INSERT INTO original_tweets (tweet_id, content, topic, created_at, status) VALUES \
+             ('t1', 'First tweet', 'rust', '2026-03-01T10:00:00Z', 'sent'), \
+             (NULL, 'Never posted', 'rust', '2026-03-01T11:00:00Z', 'failed')",
+        )
+        .execute(pool)
+        .await
+        .expect("tweets");
+        sqlx::query(
+            "INSERT INTO threads (id, topic, tweet_count, root_tweet_id, created_at, status) \
+             VALUES (1, 'async', 2, 'th1', '2026-03-01T12:00:00Z', 'sent')",
+        )
+        .execute(pool)
+        .await
+        .expect("thread");
+        sqlx::query(
+            "INSERT INTO thread_tweets (thread_id, position, tweet_id, content) VALUES \
+             (1, 0, 'th1', 'Thread opener'), (1, 1, 'th2', 'Second part')",
+        )
+        .execute(pool)
+        .await
+        .expect("thread tweets");
+        sqlx::query(
+            "INSERT INTO replies_sent \
+             (target_tweet_id, reply_tweet_id, reply_content, created_at, status) \
+             VALUES ('x9', 'r1', 'A reply', '2026-03-01T13:00:00Z', 'sent')",
+        )
+        .execute(pool)
+        .await
+        .expect("reply");
+    }
+
+    #[tokio::test]
+    async fn lists_sent_posts_newest_first() {
+        let pool = init_test_db().await.expect("init db");
+        seed(&pool).await;
+
+        let posts = get_recent_public_posts_for(&pool, DEFAULT_ACCOUNT_ID, false, 10)
+            .await
+            .expect("posts");
+        let ids: Vec<&str> = posts.iter().map(|p| p.tweet_id.as_str()).collect();
+        assert_eq!(ids, ["th1", "t1"]);
+        assert_eq!(posts[0].kind, "thread");
+        assert_eq!(posts[0].text, "Thread opener");
+
+        let posts = get_recent_public_posts_for(&pool, DEFAULT_ACCOUNT_ID, true, 2)
+            .await
+            .expect("posts");
+        assert_eq!(posts.len(), 2);
+        assert_eq!(posts[0].kind, "reply");
+        assert_eq!(posts[0].in_reply_to.as_deref(), Some("x9"));
+    }
+}
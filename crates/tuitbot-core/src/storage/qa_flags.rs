@@ -0,0 +1,268 @@
+//! Daily QA flag counts for trend analytics.
+//!
+//! Every persisted QA evaluation increments a per-day counter for each flag
+//! it raised, keyed by rule code and severity. Trend queries compare a range
+//! against the preceding period so regressions after a prompt change show up
+//! as rising counts on specific rules.
+
+use serde::{Deserialize, Serialize};
+
+use super::accounts::DEFAULT_ACCOUNT_ID;
+use super::analytics_range::{AnalyticsRange, PeriodDelta};
+use super::DbPool;
+use crate::error::StorageError;
+
+/// The parts of a persisted QA flag that trends are keyed on.
+///
+/// Deserializes from the flag objects stored in `qa_hard_flags` /
+/// `qa_soft_flags`; other fields (message, evidence) are ignored.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+pub struct QaFlagKey {
+    pub code: String,
+    #[serde(default)]
+    pub category: String,
+    #[serde(default)]
+    pub severity: String,
+}
+
+/// Flags raised on one day, grouped by category and severity.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct QaFlagDay {
+    pub day: String,
+    pub category: String,
+    pub severity: String,
+    pub count: i64,
+}
+
+/// A QA rule's flag count in the range versus the previous period.
+#[derive(Debug, Clone, Serialize)]
+pub struct QaRuleTrend {
+    pub code: String,
+    pub category: String,
+    pub severity: String,
+    pub count: PeriodDelta,
+}
+
+/// QA flag trends for a range.
+#[derive(Debug, Clone, Serialize)]
+pub struct QaFlagTrends {
+    pub total: PeriodDelta,
+    pub hard: PeriodDelta,
+    pub series: Vec<QaFlagDay>,
+    /// Rules with the most flags in the range, most frequent first.
+    pub top_rules: Vec<QaRuleTrend>,
+}
+
+/// Increment today's counters for each flag in `flags` for a specific account.
+pub async fn record_flags_for(
+    pool: &DbPool,
+    account_id: &str,
+    flags: &[QaFlagKey],
+) -> Result<(), StorageError> {
+    for flag in flags {
+        sqlx::query(
+            "INSERT INTO qa_flag_daily (account_id, day, code, category, severity, count) \
+             VALUES (?, date('now'), ?, ?, ?, 1) \
+             ON CONFLICT(account_id, day, code, severity) DO UPDATE SET \
+             count = count + 1, category = excluded.category",
+        )
+        .bind(account_id)
+        .bind(&flag.code)
+        .bind(&flag.category)
+        .bind(&flag.severity)
+        .execute(pool)
+        .await
+        .map_err(|e| StorageError::Query { source: e })?;
+    }
+    Ok(())
+}
+
+/// Increment today's counters for each flag in `flags`.
+pub async fn record_flags(pool: &DbPool, flags: &[QaFlagKey]) -> Result<(), StorageError> {
+    record_flags_for(pool, DEFAULT_ACCOUNT_ID, flags).await
+}
+
+/// Record the flags from persisted `qa_hard_flags` / `qa_soft_flags` JSON columns.
+///
+/// Malformed JSON is ignored so trend bookkeeping never blocks a QA update.
+pub(crate) async fn record_flags_json_for(
+    pool: &DbPool,
+    account_id: &str,
+    hard_flags: &str,
+    soft_flags: &str,
+) -> Result<(), StorageError> {
+    let mut flags: Vec<QaFlagKey> = serde_json::from_str(hard_flags).unwrap_or_default();
+    flags.extend(serde_json::from_str::<Vec<QaFlagKey>>(soft_flags).unwrap_or_default());
+    record_flags_for(pool, account_id, &flags).await
+}
+
+/// QA flag trends in `range` versus the previous period for a specific account.
+pub async fn get_qa_trends_for(
+    pool: &DbPool,
+    account_id: &str,
+    range: &AnalyticsRange,
+    top_limit: u32,
+) -> Result<QaFlagTrends, StorageError> {
+    let (from, to) = range.bounds();
+    let (prev_from, _) = range.previous().bounds();
+
+    let series: Vec<(String, String, String, i64)> = sqlx::query_as(
+        "SELECT day, category, severity, SUM(count) FROM qa_flag_daily \
+         WHERE account_id = ? AND datetime(day) >= ? AND datetime(day) < ? \
+         GROUP BY day, category, severity ORDER BY day, category, severity",
+    )
+    .bind(account_id)
+    .bind(&from)
+    .bind(&to)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+
+    // ?2 = previous period start, ?3 = range start, ?4 = range end.
+    let totals: (i64, i64, i64, i64) = sqlx::query_as(
+        "SELECT \
+            COALESCE(SUM(CASE WHEN datetime(day) >= ?3 THEN count END), 0), \
+            COALESCE(SUM(CASE WHEN datetime(day) < ?3 THEN count END), 0), \
+            COALESCE(SUM(CASE WHEN datetime(day) >= ?3 AND severity = 'hard' THEN count END), 0), \
+            COALESCE(SUM(CASE WHEN datetime(day) < ?3 AND severity = 'hard' THEN count END), 0) \
+         FROM qa_flag_daily \
+         WHERE account_id = ?1 AND datetime(day) >= ?2 AND datetime(day) < ?4",
+    )
+    .bind(account_id)
+    .bind(&prev_from)
+    .bind(&from)
+    .bind(&to)
+    .fetch_one(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+
+    let rules: Vec<(String, String, String, i64, i64)> = sqlx::query_as(
+        "SELECT code, MAX(category), severity, \
+            COALESCE(SUM(CASE WHEN datetime(day) >= ?3 THEN count END), 0) AS current, \
+            COALESCE(SUM(CASE WHEN datetime(day) < ?3 THEN count END), 0) \
+         FROM qa_flag_daily \
+         WHERE account_id = ?1 AND datetime(day) >= ?2 AND datetime(day) < ?4 \
+         GROUP BY code, severity HAVING current > 0 \
+         ORDER BY current DESC, code LIMIT ?5",
+    )
+    .bind(account_id)
+    .bind(&prev_from)
+    .bind(&from)
+    .bind(&to)
+    .bind(top_limit)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+
+    Ok(QaFlagTrends {
+        total: PeriodDelta::new(totals.0 as f64, totals.1 as f64),
+        hard: PeriodDelta::new(totals.2 as f64, totals.3 as f64),
+        series: series
+            .into_iter()
+            .map(|(day, category, severity, count)| QaFlagDay {
+                day,
+                category,
+                severity,
+                count,
+            })
+            .collect(),
+        top_rules: rules
+            .into_iter()
+            .map(
+                |(code, category, severity, current, previous)| QaRuleTrend {
+                    code,
+                    category,
+                    severity,
+                    count: PeriodDelta::new(current as f64, previous as f64),
+                },
+            )
+            .collect(),
+    })
+}
+
+/// QA flag trends in `range` versus the previous period.
+pub async fn get_qa_trends(
+    pool: &DbPool,
+    range: &AnalyticsRange,
+    top_limit: u32,
+) -> Result<QaFlagTrends, StorageError> {
+    get_qa_trends_for(pool, DEFAULT_ACCOUNT_ID, range, top_limit).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::init_test_db;
+
+    fn flag(code: &str, category: &str, severity: &str) -> QaFlagKey {
+        QaFlagKey {
+            code: code.to_string(),
+            category: category.to_string(),
+            severity: severity.to_string(),
+        }
+    }
+
+    async fn backdate(pool: &DbPool, days_ago: i64) {
+        sqlx::query("UPDATE qa_flag_daily SET day = date('now', ?) WHERE day = date('now')")
+            .bind(format!("-{days_ago} days"))
+            .execute(pool)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn records_and_trends_flags() {
+        let pool = init_test_db().await.expect("init db");
+        let banned = flag("banned_phrase", "brand", "hard");
+        let mismatch = flag("language_mismatch", "language", "hard");
+        let length = flag("length_near_limit", "compliance", "soft");
+
+        // Previous week: one banned phrase.
+        record_flags(&pool, std::slice::from_ref(&banned))
+            .await
+            .unwrap();
+        backdate(&pool, 10).await;
+
+        // This week: banned phrase trending up.
+        record_flags(&pool, &[banned.clone(), mismatch])
+            .await
+            .unwrap();
+        record_flags(&pool, &[banned, length]).await.unwrap();
+
+        let trends = get_qa_trends(&pool, &AnalyticsRange::last_days(7), 10)
+            .await
+            .unwrap();
+        assert_eq!(trends.total.current, 4.0);
+        assert_eq!(trends.total.previous, 1.0);
+        assert_eq!(trends.hard.current, 3.0);
+
+        assert_eq!(trends.top_rules[0].code, "banned_phrase");
+        assert_eq!(trends.top_rules[0].category, "brand");
+        assert_eq!(trends.top_rules[0].count.current, 2.0);
+        assert_eq!(trends.top_rules[0].count.delta, 1.0);
+        assert_eq!(trends.top_rules.len(), 3);
+
+        let brand_hard = trends
+            .series
+            .iter()
+            .find(|d| d.category == "brand" && d.severity == "hard")
+            .unwrap();
+        assert_eq!(brand_hard.count, 2);
+    }
+
+    #[tokio::test]
+    async fn record_flags_json_ignores_malformed() {
+        let pool = init_test_db().await.expect("init db");
+        let hard = r#"[{"code": "unsupported_claim", "severity": "hard",
+            "category": "compliance", "message": "Unverified claim"}]"#;
+        record_flags_json_for(&pool, DEFAULT_ACCOUNT_ID, hard, "not json")
+            .await
+            .unwrap();
+
+        let trends = get_qa_trends(&pool, &AnalyticsRange::last_days(1), 10)
+            .await
+            .unwrap();
+        assert_eq!(trends.total.current, 1.0);
+        assert_eq!(trends.top_rules[0].severity, "hard");
+    }
+}
@@ -0,0 +1,379 @@
+//! Query instrumentation and the slow-query log.
+//!
+//! Every statement sqlx runs is traced on the `sqlx::query` target with its
+//! summary, duration, and row counts. [`configure`] sets the duration at
+//! which a statement is logged as slow (at WARN). When recording is enabled,
+//! the tracing [`layer`] forwards slow statements to a background task that
+//! stores them in `slow_queries`, which `tuitbot db slow-queries` reports on.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, OnceLock};
+use std::time::Duration;
+
+use serde::Serialize;
+use tokio::sync::mpsc;
+use tracing::field::{Field, Visit};
+use tracing_subscriber::filter::filter_fn;
+use tracing_subscriber::layer::{Context, Layer};
+use tracing_subscriber::registry::LookupSpan;
+
+use super::DbPool;
+use crate::config::StorageConfig;
+use crate::error::StorageError;
+
+/// Tracing target sqlx logs statements under.
+const QUERY_TARGET: &str = "sqlx::query";
+
+/// Slow statements buffered for the recorder before new ones are dropped.
+const RECORDER_BUFFER: usize = 256;
+
+/// Threshold used when [`configure`] was never called (sqlx's default).
+const DEFAULT_SLOW_THRESHOLD: Duration = Duration::from_secs(1);
+
+static SETTINGS: OnceLock<StorageConfig> = OnceLock::new();
+static RECORDING: AtomicBool = AtomicBool::new(false);
+static CHANNEL: OnceLock<RecorderChannel> = OnceLock::new();
+
+/// Sender shared by every layer, and the receiver the recorder takes once.
+type RecorderChannel = (
+    mpsc::Sender<SlowQuery>,
+    Mutex<Option<mpsc::Receiver<SlowQuery>>>,
+);
+
+/// A statement that ran at or above the slow-query threshold.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SlowQuery {
+    /// Statement kind and main table, e.g. `select action_log`.
+    pub name: String,
+    /// SQL text.
+    pub statement: String,
+    pub elapsed_ms: f64,
+    pub rows_returned: i64,
+    pub rows_affected: i64,
+}
+
+/// Slow-query occurrences grouped by query name.
+#[derive(Debug, Clone, Serialize, sqlx::FromRow)]
+pub struct SlowQueryStat {
+    pub name: String,
+    pub occurrences: i64,
+    pub avg_ms: f64,
+    pub max_ms: f64,
+    pub avg_rows_returned: f64,
+    /// Most recent statement recorded under this name.
+    pub statement: String,
+    pub last_seen: String,
+}
+
+/// Apply the storage config's slow-query settings to databases opened after
+/// this call. Only the first call takes effect.
+pub fn configure(config: &StorageConfig) {
+    let _ = SETTINGS.set(config.clone());
+}
+
+/// Duration at which statements are logged as slow.
+pub(crate) fn slow_threshold() -> Duration {
+    SETTINGS
+        .get()
+        .map(|s| Duration::from_millis(s.slow_query_ms))
+        .unwrap_or(DEFAULT_SLOW_THRESHOLD)
+}
+
+/// Start storing slow statements in `pool`, if recording is configured.
+/// Only the first pool is recorded to.
+pub(crate) fn start_recorder(pool: &DbPool) {
+    if !SETTINGS.get().is_some_and(|s| s.record_slow_queries) {
+        return;
+    }
+    let Some(mut rx) = channel().1.lock().ok().and_then(|mut slot| slot.take()) else {
+        return;
+    };
+    let pool = pool.clone();
+    RECORDING.store(true, Ordering::Relaxed);
+    tokio::spawn(async move {
+        while let Some(query) = rx.recv().await {
+            if let Err(e) = insert_slow_query(&pool, &query).await {
+                tracing::debug!(error = %e, "Failed to record slow query");
+            }
+        }
+    });
+}
+
+fn channel() -> &'static RecorderChannel {
+    CHANNEL.get_or_init(|| {
+        let (tx, rx) = mpsc::channel(RECORDER_BUFFER);
+        (tx, Mutex::new(Some(rx)))
+    })
+}
+
+/// Tracing layer that forwards slow sqlx statements to the recorder. Add it
+/// next to the formatting layer when installing the global subscriber.
+pub fn layer<S>() -> impl Layer<S>
+where
+    S: tracing::Subscriber + for<'a> LookupSpan<'a>,
+{
+    SlowQueryLayer {
+        sender: channel().0.clone(),
+        gate: Some(&RECORDING),
+    }
+    .with_filter(filter_fn(|meta| {
+        meta.target() == QUERY_TARGET && *meta.level() <= tracing::Level::WARN
+    }))
+}
+
+/// Captures sqlx slow-statement events. See [`layer`].
+pub struct SlowQueryLayer {
+    sender: mpsc::Sender<SlowQuery>,
+    /// Events are dropped while this is false; `None` always forwards.
+    gate: Option<&'static AtomicBool>,
+}
+
+impl<S: tracing::Subscriber> Layer<S> for SlowQueryLayer {
+    fn on_event(&self, event: &tracing::Event<'_>, _ctx: Context<'_, S>) {
+        if self.gate.is_some_and(|g| !g.load(Ordering::Relaxed)) {
+            return;
+        }
+        let mut fields = QueryFields::default();
+        event.record(&mut fields);
+        if !fields.slow {
+            return;
+        }
+        let statement = if fields.statement.trim().is_empty() {
+            fields.summary
+        } else {
+            fields.statement.trim().to_string()
+        };
+        let name = query_name(&statement);
+        // Never record the recorder's own inserts.
+        if name == "insert slow_queries" {
+            return;
+        }
+        let _ = self.sender.try_send(SlowQuery {
+            name,
+            statement,
+            elapsed_ms: fields.elapsed_secs * 1000.0,
+            rows_returned: fields.rows_returned,
+            rows_affected: fields.rows_affected,
+        });
+    }
+}
+
+#[derive(Default)]
+struct QueryFields {
+    summary: String,
+    statement: String,
+    elapsed_secs: f64,
+    rows_returned: i64,
+    rows_affected: i64,
+    slow: bool,
+}
+
+impl Visit for QueryFields {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        match field.name() {
+            "summary" => self.summary = value.to_string(),
+            "db.statement" => self.statement = value.to_string(),
+            _ => {}
+        }
+    }
+
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        let value = i64::try_from(value).unwrap_or(i64::MAX);
+        match field.name() {
+            "rows_returned" => self.rows_returned = value,
+            "rows_affected" => self.rows_affected = value,
+            _ => {}
+        }
+    }
+
+    fn record_f64(&mut self, field: &Field, value: f64) {
+        if field.name() == "elapsed_secs" {
+            self.elapsed_secs = value;
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, _value: &dyn std::fmt::Debug) {
+        // sqlx only attaches the threshold to slow-statement events.
+        if field.name() == "slow_threshold" {
+            self.slow = true;
+        }
+    }
+}
+
+/// Short name for a statement: its kind and the table it reads or writes,
+/// e.g. `select action_log` or `update approval_queue`.
+pub fn query_name(sql: &str) -> String {
+    let words: Vec<String> = sql
+        .split_whitespace()
+        .map(|w| {
+            w.trim_matches(|c: char| !c.is_alphanumeric() && c != '_')
+                .to_lowercase()
+        })
+        .collect();
+    let Some(kind) = words.first().cloned() else {
+        return String::new();
+    };
+    let table_after = |keyword: &str| {
+        words
+            .iter()
+            .position(|w| w == keyword)
+            .and_then(|i| words.get(i + 1))
+            .filter(|w| !w.is_empty() && *w != "select")
+    };
+    let table = match kind.as_str() {
+        "insert" | "replace" => table_after("into"),
+        "update" => words.get(1).filter(|w| *w != "or").or_else(|| words.get(3)),
+        _ => table_after("from"),
+    };
+    match table {
+        Some(table) => format!("{kind} {table}"),
+        None => kind,
+    }
+}
+
+/// Store a slow statement.
+pub async fn insert_slow_query(pool: &DbPool, query: &SlowQuery) -> Result<(), StorageError> {
+    sqlx::query(
+        "INSERT INTO slow_queries (name, statement, elapsed_ms, rows_returned, rows_affected) \
+         VALUES (?, ?, ?, ?, ?)",
+    )
+    .bind(&query.name)
+    .bind(&query.statement)
+    .bind(query.elapsed_ms)
+    .bind(query.rows_returned)
+    .bind(query.rows_affected)
+    .execute(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+    Ok(())
+}
+
+/// Slow statements from the last `hours`, grouped by name, most total time first.
+pub async fn get_slow_query_report(
+    pool: &DbPool,
+    hours: u32,
+    limit: u32,
+) -> Result<Vec<SlowQueryStat>, StorageError> {
+    sqlx::query_as(
+        "SELECT name, COUNT(*) AS occurrences, AVG(elapsed_ms) AS avg_ms, \
+                MAX(elapsed_ms) AS max_ms, AVG(rows_returned) AS avg_rows_returned, \
+                (SELECT s2.statement FROM slow_queries s2 WHERE s2.name = s.name \
+                 ORDER BY s2.id DESC LIMIT 1) AS statement, \
+                MAX(created_at) AS last_seen \
+         FROM slow_queries s \
+         WHERE datetime(created_at) >= datetime('now', '-' || ? || ' hours') \
+         GROUP BY name \
+         ORDER BY SUM(elapsed_ms) DESC \
+         LIMIT ?",
+    )
+    .bind(hours)
+    .bind(limit)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })
+}
+
+/// Delete slow-query rows older than `retention_days`.
+pub async fn prune_slow_queries(pool: &DbPool, retention_days: u32) -> Result<u64, StorageError> {
+    let result = sqlx::query(
+        "DELETE FROM slow_queries WHERE datetime(created_at) < datetime('now', '-' || ? || ' days')",
+    )
+    .bind(retention_days)
+    .execute(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+    Ok(result.rows_affected())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::init_test_db;
+    use tracing_subscriber::layer::SubscriberExt;
+
+    #[test]
+    fn names_statements_by_kind_and_table() {
+        assert_eq!(
+            query_name("SELECT * FROM action_log WHERE account_id = ?"),
+            "select action_log"
+        );
+        assert_eq!(
+            query_name("INSERT INTO replies_sent (id) VALUES (?)"),
+            "insert replies_sent"
+        );
+        assert_eq!(
+            query_name("UPDATE approval_queue SET status = ?"),
+            "update approval_queue"
+        );
+        assert_eq!(query_name("SELECT COUNT(*) FROM (SELECT 1)"), "select");
+        assert_eq!(query_name("PRAGMA optimize"), "pragma");
+    }
+
+    #[test]
+    fn layer_forwards_only_slow_statements() {
+        let (tx, mut rx) = mpsc::channel(8);
+        let subscriber = tracing_subscriber::registry().with(SlowQueryLayer {
+            sender: tx,
+            gate: None,
+        });
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::warn!(
+                target: "sqlx::query",
+                summary = "SELECT * FROM tweets …",
+                db.statement = "\n\nSELECT * FROM tweets WHERE score > ?\n",
+                rows_affected = 0u64,
+                rows_returned = 12u64,
+                elapsed_secs = 1.5,
+                slow_threshold = ?Duration::from_secs(1),
+                "slow statement"
+            );
+            tracing::debug!(
+                target: "sqlx::query",
+                summary = "SELECT 1",
+                db.statement = "",
+                rows_affected = 0u64,
+                rows_returned = 1u64,
+                elapsed_secs = 0.001,
+            );
+        });
+
+        let query = rx.try_recv().expect("slow statement forwarded");
+        assert_eq!(query.name, "select tweets");
+        assert_eq!(query.statement, "SELECT * FROM tweets WHERE score > ?");
+        assert_eq!(query.rows_returned, 12);
+        assert!((query.elapsed_ms - 1500.0).abs() < 1e-6);
+        assert!(rx.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn report_groups_by_name() {
+        let pool = init_test_db().await.expect("init db");
+        for (name, ms) in [
+            ("select tweets", 1200.0),
+            ("select tweets", 1800.0),
+            ("update x", 1100.0),
+        ] {
+            insert_slow_query(
+                &pool,
+                &SlowQuery {
+                    name: name.to_string(),
+                    statement: format!("{name} ..."),
+                    elapsed_ms: ms,
+                    rows_returned: 10,
+                    rows_affected: 0,
+                },
+            )
+            .await
+            .unwrap();
+        }
+
+        let report = get_slow_query_report(&pool, 24, 10).await.unwrap();
+        assert_eq!(report.len(), 2);
+        assert_eq!(report[0].name, "select tweets");
+        assert_eq!(report[0].occurrences, 2);
+        assert!((report[0].avg_ms - 1500.0).abs() < 1e-6);
+        assert!((report[0].max_ms - 1800.0).abs() < 1e-6);
+
+        assert_eq!(prune_slow_queries(&pool, 1).await.unwrap(), 0);
+    }
+}
@@ -0,0 +1,344 @@
+//! CRUD operations for rate limit state tracking.
+//!
+//! Rate limits are stored in SQLite so they persist across restarts.
+//! The check and reset logic uses transactions for atomicity.
+//!
+//! Each row's `window_kind` says how its period is aligned. `calendar`
+//! periods (daily and weekly limits) run from midnight to midnight in the
+//! row's `reset_timezone`; `rolling` periods start at the first request after
+//! the previous one ended.
+
+mod policy;
+mod reconcile;
+#[cfg(test)]
+mod tests;
+mod windows;
+
+use super::accounts::DEFAULT_ACCOUNT_ID;
+use super::DbPool;
+use crate::error::StorageError;
+use chrono::{DateTime, Utc};
+
+pub use policy::{
+    check_policy_rate_limits, check_policy_rate_limits_for, init_policy_rate_limits,
+    init_policy_rate_limits_for, record_policy_rate_limits, record_policy_rate_limits_for,
+};
+pub use reconcile::{
+    init_mcp_rate_limit, init_mcp_rate_limit_for, init_rate_limits, init_rate_limits_for,
+    init_reddit_rate_limit, init_reddit_rate_limit_for, reconcile_rate_limits,
+    reconcile_rate_limits_for, set_reset_timezone, set_reset_timezone_for, RateLimitAdjustment,
+};
+use windows::{expired_period_start, format_period_start, period_end};
+
+/// A rate limit entry tracking usage for a specific action type.
+#[derive(Debug, Clone, sqlx::FromRow, serde::Serialize)]
+pub struct RateLimit {
+    /// Action type: reply, tweet, thread, like, follow, search, mention_check.
+    pub action_type: String,
+    /// Number of requests made in the current period.
+    pub request_count: i64,
+    /// ISO-8601 UTC timestamp when the current period started.
+    pub period_start: String,
+    /// Maximum requests allowed per period.
+    pub max_requests: i64,
+    /// Period length in seconds.
+    pub period_seconds: i64,
+    /// IANA timezone whose midnight starts day-based periods.
+    pub reset_timezone: String,
+    /// How the period is aligned: [`WINDOW_CALENDAR`] or [`WINDOW_ROLLING`].
+    pub window_kind: String,
+}
+
+impl RateLimit {
+    /// When the current period ends and the counter resets.
+    pub fn resets_at(&self) -> DateTime<Utc> {
+        let period_start = self
+            .period_start
+            .parse::<DateTime<Utc>>()
+            .unwrap_or_else(|_| Utc::now());
+        period_end(self, period_start)
+    }
+
+    /// Requests still allowed at `now`, counting an elapsed period as reset.
+    pub fn remaining(&self, now: DateTime<Utc>) -> i64 {
+        if expired_period_start(self, now).is_some() {
+            return self.max_requests;
+        }
+        (self.max_requests - self.request_count).max(0)
+    }
+}
+
+/// Window kind for periods that start at local midnight.
+pub const WINDOW_CALENDAR: &str = "calendar";
+
+/// Window kind for periods that start at the first request after the
+/// previous period ended.
+pub const WINDOW_ROLLING: &str = "rolling";
+
+/// Check whether the rate limit for an action type allows another request for a specific account.
+///
+/// Within a single transaction:
+/// 1. Fetches the rate limit row.
+/// 2. Resets the counter if the period has expired.
+/// 3. Returns `true` if under the limit, `false` if at or over.
+///
+/// Does NOT increment the counter -- call `increment_rate_limit` after the action succeeds.
+pub async fn check_rate_limit_for(
+    pool: &DbPool,
+    account_id: &str,
+    action_type: &str,
+) -> Result<bool, StorageError> {
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|e| StorageError::Connection { source: e })?;
+
+    let row = sqlx::query_as::<_, RateLimit>(
+        "SELECT action_type, request_count, period_start, max_requests, period_seconds, \
+         reset_timezone, window_kind FROM rate_limits WHERE account_id = ? AND action_type = ?",
+    )
+    .bind(account_id)
+    .bind(action_type)
+    .fetch_optional(&mut *tx)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+
+    let limit = match row {
+        Some(l) => l,
+        None => {
+            tx.commit()
+                .await
+                .map_err(|e| StorageError::Connection { source: e })?;
+            return Ok(true);
+        }
+    };
+
+    if let Some(period_start) = expired_period_start(&limit, Utc::now()) {
+        sqlx::query(
+            "UPDATE rate_limits SET request_count = 0, period_start = ? \
+             WHERE account_id = ? AND action_type = ?",
+        )
+        .bind(format_period_start(period_start))
+        .bind(account_id)
+        .bind(action_type)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| StorageError::Query { source: e })?;
+
+        tx.commit()
+            .await
+            .map_err(|e| StorageError::Connection { source: e })?;
+        return Ok(true);
+    }
+
+    let allowed = limit.request_count < limit.max_requests;
+
+    tx.commit()
+        .await
+        .map_err(|e| StorageError::Connection { source: e })?;
+
+    Ok(allowed)
+}
+
+/// Check whether the rate limit for an action type allows another request.
+///
+/// Within a single transaction:
+/// 1. Fetches the rate limit row.
+/// 2. Resets the counter if the period has expired.
+/// 3. Returns `true` if under the limit, `false` if at or over.
+///
+/// Does NOT increment the counter -- call `increment_rate_limit` after the action succeeds.
+pub async fn check_rate_limit(pool: &DbPool, action_type: &str) -> Result<bool, StorageError> {
+    check_rate_limit_for(pool, DEFAULT_ACCOUNT_ID, action_type).await
+}
+
+/// Atomically check and increment the rate limit counter for a specific account within a single transaction.
+///
+/// Returns `Ok(true)` if the action was permitted and the counter was incremented.
+/// Returns `Ok(false)` if the rate limit was reached.
+/// Resets the period if expired before checking.
+pub async fn check_and_increment_rate_limit_for(
+    pool: &DbPool,
+    account_id: &str,
+    action_type: &str,
+) -> Result<bool, StorageError> {
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|e| StorageError::Connection { source: e })?;
+
+    let row = sqlx::query_as::<_, RateLimit>(
+        "SELECT action_type, request_count, period_start, max_requests, period_seconds, \
+         reset_timezone, window_kind FROM rate_limits WHERE account_id = ? AND action_type = ?",
+    )
+    .bind(account_id)
+    .bind(action_type)
+    .fetch_optional(&mut *tx)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+
+    let limit = match row {
+        Some(l) => l,
+        None => {
+            tx.commit()
+                .await
+                .map_err(|e| StorageError::Connection { source: e })?;
+            return Ok(true);
+        }
+    };
+
+    let current_count = if let Some(period_start) = expired_period_start(&limit, Utc::now()) {
+        sqlx::query(
+            "UPDATE rate_limits SET request_count = 0, period_start = ? \
+             WHERE account_id = ? AND action_type = ?",
+        )
+        .bind(format_period_start(period_start))
+        .bind(account_id)
+        .bind(action_type)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| StorageError::Query { source: e })?;
+        0
+    } else {
+        limit.request_count
+    };
+
+    if current_count < limit.max_requests {
+        sqlx::query(
+            "UPDATE rate_limits SET request_count = request_count + 1 \
+             WHERE account_id = ? AND action_type = ?",
+        )
+        .bind(account_id)
+        .bind(action_type)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| StorageError::Query { source: e })?;
+
+        tx.commit()
+            .await
+            .map_err(|e| StorageError::Connection { source: e })?;
+        Ok(true)
+    } else {
+        tx.commit()
+            .await
+            .map_err(|e| StorageError::Connection { source: e })?;
+        Ok(false)
+    }
+}
+
+/// Atomically check and increment the rate limit counter within a single transaction.
+///
+/// Returns `Ok(true)` if the action was permitted and the counter was incremented.
+/// Returns `Ok(false)` if the rate limit was reached.
+/// Resets the period if expired before checking.
+pub async fn check_and_increment_rate_limit(
+    pool: &DbPool,
+    action_type: &str,
+) -> Result<bool, StorageError> {
+    check_and_increment_rate_limit_for(pool, DEFAULT_ACCOUNT_ID, action_type).await
+}
+
+/// Increment the request counter for an action type for a specific account.
+///
+/// Called after a successful action to record usage.
+pub async fn increment_rate_limit_for(
+    pool: &DbPool,
+    account_id: &str,
+    action_type: &str,
+) -> Result<(), StorageError> {
+    sqlx::query(
+        "UPDATE rate_limits SET request_count = request_count + 1 \
+         WHERE account_id = ? AND action_type = ?",
+    )
+    .bind(account_id)
+    .bind(action_type)
+    .execute(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+
+    Ok(())
+}
+
+/// Increment the request counter for an action type.
+///
+/// Called after a successful action to record usage.
+pub async fn increment_rate_limit(pool: &DbPool, action_type: &str) -> Result<(), StorageError> {
+    increment_rate_limit_for(pool, DEFAULT_ACCOUNT_ID, action_type).await
+}
+
+/// Usage count for a single action type.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ActionUsage {
+    pub used: i64,
+    pub max: i64,
+}
+
+/// Daily action usage summary for the activity feed rate limit display.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct DailyUsage {
+    pub replies: ActionUsage,
+    pub tweets: ActionUsage,
+    pub threads: ActionUsage,
+}
+
+/// Get daily usage counts for reply, tweet, and thread actions for a specific account.
+///
+/// Reads from the rate limits table and extracts only the three
+/// user-facing action types.
+pub async fn get_daily_usage_for(
+    pool: &DbPool,
+    account_id: &str,
+) -> Result<DailyUsage, StorageError> {
+    let limits = get_all_rate_limits_for(pool, account_id).await?;
+
+    let mut usage = DailyUsage {
+        replies: ActionUsage { used: 0, max: 0 },
+        tweets: ActionUsage { used: 0, max: 0 },
+        threads: ActionUsage { used: 0, max: 0 },
+    };
+
+    for limit in limits {
+        let target = match limit.action_type.as_str() {
+            "reply" => &mut usage.replies,
+            "tweet" => &mut usage.tweets,
+            "thread" => &mut usage.threads,
+            _ => continue,
+        };
+        target.used = limit.request_count;
+        target.max = limit.max_requests;
+    }
+
+    Ok(usage)
+}
+
+/// Get daily usage counts for reply, tweet, and thread actions.
+///
+/// Reads from the rate limits table and extracts only the three
+/// user-facing action types.
+pub async fn get_daily_usage(pool: &DbPool) -> Result<DailyUsage, StorageError> {
+    get_daily_usage_for(pool, DEFAULT_ACCOUNT_ID).await
+}
+
+/// Fetch all rate limit entries for a specific account, ordered by action type.
+///
+/// Used for status reporting and debugging.
+pub async fn get_all_rate_limits_for(
+    pool: &DbPool,
+    account_id: &str,
+) -> Result<Vec<RateLimit>, StorageError> {
+    sqlx::query_as::<_, RateLimit>(
+        "SELECT action_type, request_count, period_start, max_requests, period_seconds, \
+         reset_timezone, window_kind FROM rate_limits WHERE account_id = ? ORDER BY action_type",
+    )
+    .bind(account_id)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })
+}
+
+/// Fetch all rate limit entries, ordered by action type.
+///
+/// Used for status reporting and debugging.
+pub async fn get_all_rate_limits(pool: &DbPool) -> Result<Vec<RateLimit>, StorageError> {
+    get_all_rate_limits_for(pool, DEFAULT_ACCOUNT_ID).await
+}
@@ -0,0 +1,126 @@
+//! Per-dimension rate limits for the v2 policy engine.
+
+use super::reconcile::{reconcile_rows, DesiredLimit};
+use super::{check_rate_limit_for, increment_rate_limit_for};
+use crate::error::StorageError;
+use crate::mcp_policy::types::{PolicyRateLimit, RateLimitDimension};
+use crate::storage::accounts::DEFAULT_ACCOUNT_ID;
+use crate::storage::DbPool;
+
+/// Initialize rate limit rows for v2 policy rate limits for a specific account.
+///
+/// Existing counters are preserved; changed limits are applied.
+pub async fn init_policy_rate_limits_for(
+    pool: &DbPool,
+    account_id: &str,
+    limits: &[PolicyRateLimit],
+) -> Result<(), StorageError> {
+    let desired: Vec<DesiredLimit> = limits
+        .iter()
+        .map(|limit| {
+            DesiredLimit::rolling(
+                limit.key.clone(),
+                i64::from(limit.max_count),
+                limit.period_seconds as i64,
+            )
+        })
+        .collect();
+    for adjustment in reconcile_rows(pool, account_id, &desired).await? {
+        if adjustment.previous.is_some() {
+            tracing::info!(
+                account_id,
+                key = %adjustment.action_type,
+                max = adjustment.max_requests,
+                period = adjustment.period_seconds,
+                "Policy rate limit updated to match config"
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Initialize rate limit rows for v2 policy rate limits.
+///
+/// Existing counters are preserved; changed limits are applied.
+pub async fn init_policy_rate_limits(
+    pool: &DbPool,
+    limits: &[PolicyRateLimit],
+) -> Result<(), StorageError> {
+    init_policy_rate_limits_for(pool, DEFAULT_ACCOUNT_ID, limits).await
+}
+
+/// Check all applicable rate limits for a tool invocation for a specific account.
+///
+/// Returns the key of the first exceeded limit, or `None` if all pass.
+pub async fn check_policy_rate_limits_for(
+    pool: &DbPool,
+    account_id: &str,
+    tool_name: &str,
+    category: &str,
+    limits: &[PolicyRateLimit],
+) -> Result<Option<String>, StorageError> {
+    for limit in limits {
+        let matches = match limit.dimension {
+            RateLimitDimension::Tool => limit.match_value == tool_name,
+            RateLimitDimension::Category => limit.match_value == category,
+            RateLimitDimension::EngagementType => limit.match_value == tool_name,
+            RateLimitDimension::Global => true,
+        };
+
+        if !matches {
+            continue;
+        }
+
+        let allowed = check_rate_limit_for(pool, account_id, &limit.key).await?;
+        if !allowed {
+            return Ok(Some(limit.key.clone()));
+        }
+    }
+    Ok(None)
+}
+
+/// Check all applicable rate limits for a tool invocation.
+///
+/// Returns the key of the first exceeded limit, or `None` if all pass.
+pub async fn check_policy_rate_limits(
+    pool: &DbPool,
+    tool_name: &str,
+    category: &str,
+    limits: &[PolicyRateLimit],
+) -> Result<Option<String>, StorageError> {
+    check_policy_rate_limits_for(pool, DEFAULT_ACCOUNT_ID, tool_name, category, limits).await
+}
+
+/// Increment all applicable rate limit counters for a specific account after a successful mutation.
+pub async fn record_policy_rate_limits_for(
+    pool: &DbPool,
+    account_id: &str,
+    tool_name: &str,
+    category: &str,
+    limits: &[PolicyRateLimit],
+) -> Result<(), StorageError> {
+    for limit in limits {
+        let matches = match limit.dimension {
+            RateLimitDimension::Tool => limit.match_value == tool_name,
+            RateLimitDimension::Category => limit.match_value == category,
+            RateLimitDimension::EngagementType => limit.match_value == tool_name,
+            RateLimitDimension::Global => true,
+        };
+
+        if matches {
+            // Best-effort: if the row doesn't exist yet, skip it
+            let _ = increment_rate_limit_for(pool, account_id, &limit.key).await;
+        }
+    }
+    Ok(())
+}
+
+/// Increment all applicable rate limit counters after a successful mutation.
+pub async fn record_policy_rate_limits(
+    pool: &DbPool,
+    tool_name: &str,
+    category: &str,
+    limits: &[PolicyRateLimit],
+) -> Result<(), StorageError> {
+    record_policy_rate_limits_for(pool, DEFAULT_ACCOUNT_ID, tool_name, category, limits).await
+}
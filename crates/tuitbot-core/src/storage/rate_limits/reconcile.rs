@@ -0,0 +1,361 @@
+//! Bringing stored rate limit rows in line with the configuration.
+
+use super::{WINDOW_CALENDAR, WINDOW_ROLLING};
+use crate::config::{IntervalsConfig, LimitsConfig, RateLimitWindow};
+use crate::error::StorageError;
+use crate::storage::accounts::DEFAULT_ACCOUNT_ID;
+use crate::storage::DbPool;
+
+/// A rate limit row added or changed to match the current configuration.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct RateLimitAdjustment {
+    /// Action type of the row.
+    pub action_type: String,
+    /// Previous `(max_requests, period_seconds)`, or `None` for a new row.
+    pub previous: Option<(i64, i64)>,
+    /// Maximum requests per period now in effect.
+    pub max_requests: i64,
+    /// Period length in seconds now in effect.
+    pub period_seconds: i64,
+    /// Window kind now in effect.
+    pub window_kind: String,
+}
+
+/// A rate limit row as the configuration wants it.
+pub(super) struct DesiredLimit {
+    action_type: String,
+    max_requests: i64,
+    period_seconds: i64,
+    window_kind: &'static str,
+}
+
+impl DesiredLimit {
+    pub(super) fn rolling(
+        action_type: impl Into<String>,
+        max_requests: i64,
+        period_seconds: i64,
+    ) -> Self {
+        Self {
+            action_type: action_type.into(),
+            max_requests,
+            period_seconds,
+            window_kind: WINDOW_ROLLING,
+        }
+    }
+
+    fn windowed(action_type: &str, max_requests: u32, window: RateLimitWindow) -> Self {
+        Self {
+            action_type: action_type.to_string(),
+            max_requests: i64::from(max_requests),
+            period_seconds: window.seconds(),
+            window_kind: if window.is_calendar() {
+                WINDOW_CALENDAR
+            } else {
+                WINDOW_ROLLING
+            },
+        }
+    }
+}
+
+/// Insert missing rows and update limits that differ from `desired`,
+/// preserving counters.
+pub(super) async fn reconcile_rows(
+    pool: &DbPool,
+    account_id: &str,
+    desired: &[DesiredLimit],
+) -> Result<Vec<RateLimitAdjustment>, StorageError> {
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|e| StorageError::Connection { source: e })?;
+    let mut adjustments = Vec::new();
+
+    for limit in desired {
+        let DesiredLimit {
+            action_type,
+            max_requests,
+            period_seconds,
+            window_kind,
+        } = limit;
+        let existing: Option<(i64, i64, String)> = sqlx::query_as(
+            "SELECT max_requests, period_seconds, window_kind FROM rate_limits \
+             WHERE account_id = ? AND action_type = ?",
+        )
+        .bind(account_id)
+        .bind(action_type)
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| StorageError::Query { source: e })?;
+
+        match &existing {
+            Some((max, period, kind))
+                if (*max, *period, kind.as_str())
+                    == (*max_requests, *period_seconds, *window_kind) =>
+            {
+                continue
+            }
+            Some(_) => {
+                sqlx::query(
+                    "UPDATE rate_limits SET max_requests = ?, period_seconds = ?, window_kind = ? \
+                     WHERE account_id = ? AND action_type = ?",
+                )
+                .bind(max_requests)
+                .bind(period_seconds)
+                .bind(window_kind)
+                .bind(account_id)
+                .bind(action_type)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| StorageError::Query { source: e })?;
+            }
+            None => {
+                sqlx::query(
+                    "INSERT INTO rate_limits \
+                     (account_id, action_type, request_count, period_start, max_requests, \
+                      period_seconds, window_kind) \
+                     VALUES (?, ?, 0, strftime('%Y-%m-%dT%H:%M:%SZ', 'now'), ?, ?, ?)",
+                )
+                .bind(account_id)
+                .bind(action_type)
+                .bind(max_requests)
+                .bind(period_seconds)
+                .bind(window_kind)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| StorageError::Query { source: e })?;
+            }
+        }
+
+        adjustments.push(RateLimitAdjustment {
+            action_type: action_type.clone(),
+            previous: existing.map(|(max, period, _)| (max, period)),
+            max_requests: *max_requests,
+            period_seconds: *period_seconds,
+            window_kind: window_kind.to_string(),
+        });
+    }
+
+    tx.commit()
+        .await
+        .map_err(|e| StorageError::Connection { source: e })?;
+    Ok(adjustments)
+}
+
+/// Log limits changed since the last start. New rows are only worth an
+/// info line when the account already had limits (a newly added action type).
+pub(super) fn log_adjustments(account_id: &str, adjustments: &[RateLimitAdjustment]) {
+    let upgrade =
+        adjustments.iter().any(|a| a.previous.is_some()) || adjustments.len() < LIMIT_ACTION_TYPES;
+    for adjustment in adjustments {
+        match adjustment.previous {
+            Some((old_max, old_period)) => tracing::info!(
+                account_id,
+                action_type = %adjustment.action_type,
+                old_max,
+                new_max = adjustment.max_requests,
+                old_period,
+                new_period = adjustment.period_seconds,
+                window = %adjustment.window_kind,
+                "Rate limit updated to match config"
+            ),
+            None if upgrade => tracing::info!(
+                account_id,
+                action_type = %adjustment.action_type,
+                max = adjustment.max_requests,
+                period = adjustment.period_seconds,
+                window = %adjustment.window_kind,
+                "Rate limit added for new action type"
+            ),
+            None => tracing::debug!(
+                account_id,
+                action_type = %adjustment.action_type,
+                "Rate limit row created"
+            ),
+        }
+    }
+}
+
+/// Number of action types [`reconcile_rate_limits_for`] manages.
+pub(super) const LIMIT_ACTION_TYPES: usize = 7;
+
+/// Bring rate limit rows for a specific account in line with configuration.
+///
+/// Adds rows for action types that have none and updates `max_requests`,
+/// `period_seconds` and `window_kind` where the config changed. Counters and
+/// period starts are kept. Returns the rows that changed.
+pub async fn reconcile_rate_limits_for(
+    pool: &DbPool,
+    account_id: &str,
+    config: &LimitsConfig,
+    intervals: &IntervalsConfig,
+) -> Result<Vec<RateLimitAdjustment>, StorageError> {
+    // Suppress unused variable warning -- intervals is reserved for future per-interval limits
+    let _ = intervals;
+
+    let desired: [DesiredLimit; LIMIT_ACTION_TYPES] = [
+        DesiredLimit::windowed("reply", config.max_replies_per_day, config.window("reply")),
+        DesiredLimit::windowed("tweet", config.max_tweets_per_day, config.window("tweet")),
+        DesiredLimit::windowed(
+            "thread",
+            config.max_threads_per_week,
+            config.window("thread"),
+        ),
+        DesiredLimit::windowed("like", config.max_likes_per_day, config.window("like")),
+        DesiredLimit::windowed(
+            "follow",
+            config.max_follows_per_day,
+            config.window("follow"),
+        ),
+        DesiredLimit::rolling("search", 300, 900),
+        DesiredLimit::rolling("mention_check", 180, 900),
+    ];
+
+    reconcile_rows(pool, account_id, &desired).await
+}
+
+/// Bring rate limit rows in line with configuration.
+///
+/// See [`reconcile_rate_limits_for`].
+pub async fn reconcile_rate_limits(
+    pool: &DbPool,
+    config: &LimitsConfig,
+    intervals: &IntervalsConfig,
+) -> Result<Vec<RateLimitAdjustment>, StorageError> {
+    reconcile_rate_limits_for(pool, DEFAULT_ACCOUNT_ID, config, intervals).await
+}
+
+/// Initialize rate limit rows from configuration for a specific account.
+///
+/// Existing counters are preserved across restarts; limits changed in the
+/// config since the last start are applied and logged.
+pub async fn init_rate_limits_for(
+    pool: &DbPool,
+    account_id: &str,
+    config: &LimitsConfig,
+    intervals: &IntervalsConfig,
+) -> Result<(), StorageError> {
+    let adjustments = reconcile_rate_limits_for(pool, account_id, config, intervals).await?;
+    log_adjustments(account_id, &adjustments);
+    Ok(())
+}
+
+/// Initialize rate limit rows from configuration.
+///
+/// Existing counters are preserved across restarts; limits changed in the
+/// config since the last start are applied and logged.
+pub async fn init_rate_limits(
+    pool: &DbPool,
+    config: &LimitsConfig,
+    intervals: &IntervalsConfig,
+) -> Result<(), StorageError> {
+    init_rate_limits_for(pool, DEFAULT_ACCOUNT_ID, config, intervals).await
+}
+
+/// Initialize the MCP mutation rate limit row for a specific account.
+///
+/// An existing counter is preserved across restarts; a changed
+/// `max_mutations_per_hour` is applied.
+pub async fn init_mcp_rate_limit_for(
+    pool: &DbPool,
+    account_id: &str,
+    max_per_hour: u32,
+) -> Result<(), StorageError> {
+    let desired = [DesiredLimit::rolling(
+        "mcp_mutation",
+        i64::from(max_per_hour),
+        3600,
+    )];
+    for adjustment in reconcile_rows(pool, account_id, &desired).await? {
+        if adjustment.previous.is_some() {
+            tracing::info!(
+                account_id,
+                max = adjustment.max_requests,
+                "MCP mutation rate limit updated to match config"
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Initialize the MCP mutation rate limit row.
+///
+/// An existing counter is preserved across restarts; a changed
+/// `max_mutations_per_hour` is applied.
+pub async fn init_mcp_rate_limit(pool: &DbPool, max_per_hour: u32) -> Result<(), StorageError> {
+    init_mcp_rate_limit_for(pool, DEFAULT_ACCOUNT_ID, max_per_hour).await
+}
+
+/// Initialize the Reddit comment rate limit row for a specific account.
+///
+/// Counted per calendar day in the reset timezone, like X replies. An
+/// existing counter is preserved; a changed `max_per_day` is applied.
+pub async fn init_reddit_rate_limit_for(
+    pool: &DbPool,
+    account_id: &str,
+    max_per_day: u32,
+) -> Result<(), StorageError> {
+    let desired = [DesiredLimit::windowed(
+        "reddit_comment",
+        max_per_day,
+        RateLimitWindow::Day,
+    )];
+    for adjustment in reconcile_rows(pool, account_id, &desired).await? {
+        if adjustment.previous.is_some() {
+            tracing::info!(
+                account_id,
+                max = adjustment.max_requests,
+                "Reddit comment rate limit updated to match config"
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Initialize the Reddit comment rate limit row.
+pub async fn init_reddit_rate_limit(pool: &DbPool, max_per_day: u32) -> Result<(), StorageError> {
+    init_reddit_rate_limit_for(pool, DEFAULT_ACCOUNT_ID, max_per_day).await
+}
+
+/// Set the timezone whose midnight resets day-based limits for a specific
+/// account. Returns the action types whose timezone changed.
+///
+/// A window already open when the timezone changes is re-read in the new
+/// timezone, so it may end up to a day early or late once.
+pub async fn set_reset_timezone_for(
+    pool: &DbPool,
+    account_id: &str,
+    timezone: &str,
+) -> Result<Vec<String>, StorageError> {
+    let changed: Vec<(String,)> = sqlx::query_as(
+        "UPDATE rate_limits SET reset_timezone = ? \
+         WHERE account_id = ? AND reset_timezone != ? RETURNING action_type",
+    )
+    .bind(timezone)
+    .bind(account_id)
+    .bind(timezone)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+
+    let mut changed: Vec<String> = changed.into_iter().map(|(a,)| a).collect();
+    changed.sort();
+    if !changed.is_empty() {
+        tracing::info!(
+            account_id,
+            timezone,
+            action_types = %changed.join(", "),
+            "Rate limit reset timezone updated"
+        );
+    }
+    Ok(changed)
+}
+
+/// Set the timezone whose midnight resets day-based limits.
+///
+/// See [`set_reset_timezone_for`].
+pub async fn set_reset_timezone(
+    pool: &DbPool,
+    timezone: &str,
+) -> Result<Vec<String>, StorageError> {
+    set_reset_timezone_for(pool, DEFAULT_ACCOUNT_ID, timezone).await
+}
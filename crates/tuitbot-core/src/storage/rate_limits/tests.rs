@@ -0,0 +1,404 @@
+use super::*;
+use crate::config::{IntervalsConfig, LimitsConfig, RateLimitWindow};
+use crate::storage::init_test_db;
+
+fn test_limits_config() -> LimitsConfig {
+    LimitsConfig {
+        max_replies_per_day: 3,
+        max_tweets_per_day: 2,
+        max_threads_per_week: 1,
+        max_likes_per_day: 50,
+        max_follows_per_day: 20,
+        min_action_delay_seconds: 30,
+        max_action_delay_seconds: 120,
+        max_replies_per_author_per_day: 1,
+        banned_phrases: vec![],
+        product_mention_ratio: 0.2,
+        reset_timezone: String::new(),
+        windows: Default::default(),
+    }
+}
+
+fn test_intervals_config() -> IntervalsConfig {
+    IntervalsConfig {
+        mentions_check_seconds: 300,
+        discovery_search_seconds: 600,
+        content_post_window_seconds: 14400,
+        thread_interval_seconds: 604800,
+        discovery_dedup_window_seconds: 86400,
+    }
+}
+
+#[tokio::test]
+async fn init_creates_all_rate_limit_rows() {
+    let pool = init_test_db().await.expect("init db");
+    init_rate_limits(&pool, &test_limits_config(), &test_intervals_config())
+        .await
+        .expect("init rate limits");
+
+    let limits = get_all_rate_limits(&pool).await.expect("get limits");
+    assert_eq!(limits.len(), 7);
+
+    let reply = limits
+        .iter()
+        .find(|l| l.action_type == "reply")
+        .expect("reply");
+    assert_eq!(reply.max_requests, 3);
+    assert_eq!(reply.period_seconds, 86400);
+    assert_eq!(reply.request_count, 0);
+
+    let thread = limits
+        .iter()
+        .find(|l| l.action_type == "thread")
+        .expect("thread");
+    assert_eq!(thread.max_requests, 1);
+    assert_eq!(thread.period_seconds, 604800);
+
+    let like = limits
+        .iter()
+        .find(|l| l.action_type == "like")
+        .expect("like");
+    assert_eq!(like.max_requests, 50);
+    assert_eq!(like.period_seconds, 86400);
+}
+
+#[tokio::test]
+async fn init_preserves_existing_counters() {
+    let pool = init_test_db().await.expect("init db");
+    init_rate_limits(&pool, &test_limits_config(), &test_intervals_config())
+        .await
+        .expect("first init");
+
+    // Increment reply counter
+    increment_rate_limit(&pool, "reply")
+        .await
+        .expect("increment");
+
+    // Re-init should preserve the counter
+    init_rate_limits(&pool, &test_limits_config(), &test_intervals_config())
+        .await
+        .expect("second init");
+
+    let limits = get_all_rate_limits(&pool).await.expect("get limits");
+    let reply = limits
+        .iter()
+        .find(|l| l.action_type == "reply")
+        .expect("reply");
+    assert_eq!(reply.request_count, 1, "counter should be preserved");
+}
+
+#[tokio::test]
+async fn reddit_rate_limit_is_daily_and_reconciled() {
+    let pool = init_test_db().await.expect("init db");
+    init_reddit_rate_limit(&pool, 3).await.expect("init");
+    increment_rate_limit(&pool, "reddit_comment")
+        .await
+        .expect("increment");
+    init_reddit_rate_limit(&pool, 5).await.expect("re-init");
+
+    let limits = get_all_rate_limits(&pool).await.expect("get limits");
+    let reddit = limits
+        .iter()
+        .find(|l| l.action_type == "reddit_comment")
+        .expect("reddit_comment");
+    assert_eq!(reddit.max_requests, 5);
+    assert_eq!(reddit.period_seconds, 86_400);
+    assert_eq!(reddit.request_count, 1);
+}
+
+#[tokio::test]
+async fn reconcile_applies_changed_limits_and_keeps_counts() {
+    let pool = init_test_db().await.expect("init db");
+    init_rate_limits(&pool, &test_limits_config(), &test_intervals_config())
+        .await
+        .expect("first init");
+    increment_rate_limit(&pool, "reply")
+        .await
+        .expect("increment");
+    sqlx::query("DELETE FROM rate_limits WHERE action_type = 'mention_check'")
+        .execute(&pool)
+        .await
+        .expect("delete");
+
+    let mut config = test_limits_config();
+    config.max_replies_per_day = 10;
+    let adjustments = reconcile_rate_limits(&pool, &config, &test_intervals_config())
+        .await
+        .expect("reconcile");
+
+    assert_eq!(
+        adjustments,
+        vec![
+            RateLimitAdjustment {
+                action_type: "reply".to_string(),
+                previous: Some((3, 86400)),
+                max_requests: 10,
+                period_seconds: 86400,
+                window_kind: WINDOW_CALENDAR.to_string(),
+            },
+            RateLimitAdjustment {
+                action_type: "mention_check".to_string(),
+                previous: None,
+                max_requests: 180,
+                period_seconds: 900,
+                window_kind: WINDOW_ROLLING.to_string(),
+            },
+        ]
+    );
+    let limits = get_all_rate_limits(&pool).await.expect("get limits");
+    assert_eq!(limits.len(), 7);
+    let reply = limits
+        .iter()
+        .find(|l| l.action_type == "reply")
+        .expect("reply");
+    assert_eq!(reply.max_requests, 10);
+    assert_eq!(reply.request_count, 1, "counter should be preserved");
+
+    // Nothing left to change.
+    let again = reconcile_rate_limits(&pool, &config, &test_intervals_config())
+        .await
+        .expect("reconcile again");
+    assert!(again.is_empty());
+}
+
+#[tokio::test]
+async fn reset_timezone_moves_existing_rows() {
+    let pool = init_test_db().await.expect("init db");
+    init_rate_limits(&pool, &test_limits_config(), &test_intervals_config())
+        .await
+        .expect("init");
+
+    let changed = set_reset_timezone(&pool, "Europe/Berlin")
+        .await
+        .expect("set timezone");
+    assert_eq!(changed.len(), 7);
+    assert!(set_reset_timezone(&pool, "Europe/Berlin")
+        .await
+        .expect("set again")
+        .is_empty());
+
+    // Exhaust replies, then move the window back past local midnight.
+    for _ in 0..3 {
+        increment_rate_limit(&pool, "reply").await.expect("inc");
+    }
+    assert!(!check_rate_limit(&pool, "reply").await.expect("check"));
+    sqlx::query(
+        "UPDATE rate_limits SET period_start = strftime('%Y-%m-%dT%H:%M:%SZ', 'now', '-2 days') \
+         WHERE action_type = 'reply'",
+    )
+    .execute(&pool)
+    .await
+    .expect("backdate");
+
+    assert!(check_rate_limit(&pool, "reply").await.expect("check"));
+    let reply = get_all_rate_limits(&pool)
+        .await
+        .expect("limits")
+        .into_iter()
+        .find(|l| l.action_type == "reply")
+        .expect("reply");
+    assert_eq!(reply.request_count, 0);
+    let start: DateTime<Utc> = reply.period_start.parse().expect("start");
+    let local = start.with_timezone(&chrono_tz::Europe::Berlin);
+    assert_eq!(local.format("%H:%M").to_string(), "00:00");
+}
+
+#[tokio::test]
+async fn configured_windows_are_stored_per_action() {
+    let pool = init_test_db().await.expect("init db");
+    let mut config = test_limits_config();
+    config
+        .windows
+        .insert("reply".to_string(), RateLimitWindow::RollingHours(12));
+    config
+        .windows
+        .insert("thread".to_string(), RateLimitWindow::Day);
+    init_rate_limits(&pool, &config, &test_intervals_config())
+        .await
+        .expect("init");
+
+    let limits = get_all_rate_limits(&pool).await.expect("limits");
+    let window = |action: &str| {
+        let limit = limits
+            .iter()
+            .find(|l| l.action_type == action)
+            .expect("row");
+        (limit.period_seconds, limit.window_kind.as_str())
+    };
+    assert_eq!(window("reply"), (43200, WINDOW_ROLLING));
+    assert_eq!(window("thread"), (86400, WINDOW_CALENDAR));
+    assert_eq!(window("tweet"), (86400, WINDOW_CALENDAR));
+    assert_eq!(window("search"), (900, WINDOW_ROLLING));
+
+    // Switching back to the default keeps the count and changes the kind.
+    increment_rate_limit(&pool, "reply").await.expect("inc");
+    let adjustments = reconcile_rate_limits(&pool, &test_limits_config(), &test_intervals_config())
+        .await
+        .expect("reconcile");
+    let reply = adjustments
+        .iter()
+        .find(|a| a.action_type == "reply")
+        .expect("reply adjusted");
+    assert_eq!(reply.previous, Some((3, 43200)));
+    assert_eq!(reply.window_kind, WINDOW_CALENDAR);
+    let usage = get_daily_usage(&pool).await.expect("usage");
+    assert_eq!(usage.replies.used, 1);
+}
+
+#[tokio::test]
+async fn mcp_rate_limit_follows_config() {
+    let pool = init_test_db().await.expect("init db");
+    init_mcp_rate_limit(&pool, 20).await.expect("init");
+    init_mcp_rate_limit(&pool, 50).await.expect("re-init");
+
+    let limits = get_all_rate_limits(&pool).await.expect("get limits");
+    let mcp = limits
+        .iter()
+        .find(|l| l.action_type == "mcp_mutation")
+        .expect("mcp");
+    assert_eq!(mcp.max_requests, 50);
+}
+
+#[tokio::test]
+async fn check_rate_limit_allows_under_max() {
+    let pool = init_test_db().await.expect("init db");
+    init_rate_limits(&pool, &test_limits_config(), &test_intervals_config())
+        .await
+        .expect("init");
+
+    assert!(check_rate_limit(&pool, "reply").await.expect("check"));
+}
+
+#[tokio::test]
+async fn check_rate_limit_blocks_at_max() {
+    let pool = init_test_db().await.expect("init db");
+    init_rate_limits(&pool, &test_limits_config(), &test_intervals_config())
+        .await
+        .expect("init");
+
+    // Fill up the reply limit (max = 3)
+    for _ in 0..3 {
+        increment_rate_limit(&pool, "reply").await.expect("inc");
+    }
+
+    assert!(!check_rate_limit(&pool, "reply").await.expect("check"));
+}
+
+#[tokio::test]
+async fn check_rate_limit_resets_expired_period() {
+    let pool = init_test_db().await.expect("init db");
+    init_rate_limits(&pool, &test_limits_config(), &test_intervals_config())
+        .await
+        .expect("init");
+
+    // Fill up and set period_start to 25 hours ago
+    for _ in 0..3 {
+        increment_rate_limit(&pool, "reply").await.expect("inc");
+    }
+    sqlx::query(
+        "UPDATE rate_limits SET period_start = strftime('%Y-%m-%dT%H:%M:%SZ', 'now', '-25 hours') \
+         WHERE action_type = 'reply'",
+    )
+    .execute(&pool)
+    .await
+    .expect("backdate");
+
+    // Should reset and allow
+    assert!(check_rate_limit(&pool, "reply").await.expect("check"));
+
+    // Verify counter was reset
+    let limits = get_all_rate_limits(&pool).await.expect("get");
+    let reply = limits
+        .iter()
+        .find(|l| l.action_type == "reply")
+        .expect("reply");
+    assert_eq!(reply.request_count, 0);
+}
+
+#[tokio::test]
+async fn check_rate_limit_unknown_type_allows() {
+    let pool = init_test_db().await.expect("init db");
+    assert!(check_rate_limit(&pool, "nonexistent").await.expect("check"));
+}
+
+#[tokio::test]
+async fn check_and_increment_works() {
+    let pool = init_test_db().await.expect("init db");
+    init_rate_limits(&pool, &test_limits_config(), &test_intervals_config())
+        .await
+        .expect("init");
+
+    // Should succeed 3 times (max_replies = 3) then fail
+    assert!(check_and_increment_rate_limit(&pool, "reply")
+        .await
+        .expect("1"));
+    assert!(check_and_increment_rate_limit(&pool, "reply")
+        .await
+        .expect("2"));
+    assert!(check_and_increment_rate_limit(&pool, "reply")
+        .await
+        .expect("3"));
+    assert!(!check_and_increment_rate_limit(&pool, "reply")
+        .await
+        .expect("4"));
+
+    let limits = get_all_rate_limits(&pool).await.expect("get");
+    let reply = limits
+        .iter()
+        .find(|l| l.action_type == "reply")
+        .expect("reply");
+    assert_eq!(reply.request_count, 3);
+}
+
+#[tokio::test]
+async fn increment_rate_limit_works() {
+    let pool = init_test_db().await.expect("init db");
+    init_rate_limits(&pool, &test_limits_config(), &test_intervals_config())
+        .await
+        .expect("init");
+
+    increment_rate_limit(&pool, "tweet").await.expect("inc");
+    increment_rate_limit(&pool, "tweet").await.expect("inc");
+
+    let limits = get_all_rate_limits(&pool).await.expect("get");
+    let tweet = limits
+        .iter()
+        .find(|l| l.action_type == "tweet")
+        .expect("tweet");
+    assert_eq!(tweet.request_count, 2);
+}
+
+#[tokio::test]
+async fn get_all_rate_limits_ordered() {
+    let pool = init_test_db().await.expect("init db");
+    init_rate_limits(&pool, &test_limits_config(), &test_intervals_config())
+        .await
+        .expect("init");
+
+    let limits = get_all_rate_limits(&pool).await.expect("get");
+    let types: Vec<&str> = limits.iter().map(|l| l.action_type.as_str()).collect();
+    let mut sorted = types.clone();
+    sorted.sort();
+    assert_eq!(types, sorted, "should be sorted by action_type");
+}
+
+#[tokio::test]
+async fn daily_usage_returns_correct_counts() {
+    let pool = init_test_db().await.expect("init db");
+    init_rate_limits(&pool, &test_limits_config(), &test_intervals_config())
+        .await
+        .expect("init");
+
+    increment_rate_limit(&pool, "reply").await.expect("inc");
+    increment_rate_limit(&pool, "reply").await.expect("inc");
+    increment_rate_limit(&pool, "tweet").await.expect("inc");
+
+    let usage = get_daily_usage(&pool).await.expect("get usage");
+
+    assert_eq!(usage.replies.used, 2);
+    assert_eq!(usage.replies.max, 3);
+    assert_eq!(usage.tweets.used, 1);
+    assert_eq!(usage.tweets.max, 2);
+    assert_eq!(usage.threads.used, 0);
+    assert_eq!(usage.threads.max, 1);
+}
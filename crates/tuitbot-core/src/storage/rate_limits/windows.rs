@@ -0,0 +1,200 @@
+//! Period arithmetic for calendar and rolling rate limit windows.
+
+use chrono::{DateTime, Days, NaiveDate, TimeZone, Utc};
+use chrono_tz::Tz;
+
+use super::{RateLimit, WINDOW_ROLLING};
+
+const SECONDS_PER_DAY: i64 = 86_400;
+
+/// UTC instant of midnight at the start of `date` in `tz`. When a DST jump
+/// skips midnight, the first valid local time after it.
+fn local_midnight(date: NaiveDate, tz: Tz) -> DateTime<Utc> {
+    let mut time = date.and_hms_opt(0, 0, 0).expect("midnight is valid");
+    for _ in 0..4 {
+        if let Some(local) = tz.from_local_datetime(&time).earliest() {
+            return local.with_timezone(&Utc);
+        }
+        time += chrono::Duration::minutes(30);
+    }
+    date.and_hms_opt(0, 0, 0)
+        .expect("midnight is valid")
+        .and_utc()
+}
+
+/// Whether `limit` starts its periods at local midnight.
+fn is_calendar(limit: &RateLimit) -> bool {
+    limit.window_kind != WINDOW_ROLLING
+        && limit.period_seconds > 0
+        && limit.period_seconds % SECONDS_PER_DAY == 0
+}
+
+/// End of the period that started at `period_start`.
+pub(super) fn period_end(limit: &RateLimit, period_start: DateTime<Utc>) -> DateTime<Utc> {
+    if !is_calendar(limit) {
+        return period_start + chrono::Duration::seconds(limit.period_seconds.max(0));
+    }
+    let tz: Tz = limit.reset_timezone.parse().unwrap_or(Tz::UTC);
+    let days = (limit.period_seconds / SECONDS_PER_DAY) as u64;
+    let start_day = period_start.with_timezone(&tz).date_naive();
+    local_midnight(start_day + Days::new(days), tz)
+}
+
+/// Start of the period that should be in effect at `now`, or `None` when the
+/// stored period has not ended yet.
+pub(super) fn expired_period_start(limit: &RateLimit, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+    let period_start = limit.period_start.parse::<DateTime<Utc>>().unwrap_or(now);
+
+    if now < period_end(limit, period_start) {
+        return None;
+    }
+    if !is_calendar(limit) {
+        return Some(now);
+    }
+
+    let tz: Tz = limit.reset_timezone.parse().unwrap_or(Tz::UTC);
+    let days = (limit.period_seconds / SECONDS_PER_DAY) as u64;
+    let start_day = period_start.with_timezone(&tz).date_naive();
+
+    // Whole periods keep their phase (a weekly limit stays on its weekday).
+    let today = now.with_timezone(&tz).date_naive();
+    let elapsed_days = (today - start_day).num_days() as u64;
+    Some(local_midnight(
+        start_day + Days::new(elapsed_days - elapsed_days % days),
+        tz,
+    ))
+}
+
+pub(super) fn format_period_start(start: DateTime<Utc>) -> String {
+    start.format("%Y-%m-%dT%H:%M:%SZ").to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::rate_limits::WINDOW_CALENDAR;
+
+    fn daily_limit(period_start: &str, period_seconds: i64, timezone: &str) -> RateLimit {
+        RateLimit {
+            action_type: "reply".to_string(),
+            request_count: 3,
+            period_start: period_start.to_string(),
+            max_requests: 3,
+            period_seconds,
+            reset_timezone: timezone.to_string(),
+            window_kind: WINDOW_CALENDAR.to_string(),
+        }
+    }
+
+    fn at(s: &str) -> DateTime<Utc> {
+        s.parse().expect("timestamp")
+    }
+
+    #[test]
+    fn utc_day_resets_at_utc_midnight() {
+        let limit = daily_limit("2026-03-10T00:00:00Z", 86400, "UTC");
+        assert_eq!(
+            expired_period_start(&limit, at("2026-03-10T23:59:59Z")),
+            None
+        );
+        assert_eq!(
+            expired_period_start(&limit, at("2026-03-11T00:00:00Z")),
+            Some(at("2026-03-11T00:00:00Z"))
+        );
+    }
+
+    #[test]
+    fn remaining_counts_reset_period_as_full() {
+        let mut limit = daily_limit("2026-03-10T00:00:00Z", 86400, "UTC");
+        limit.request_count = 2;
+        assert_eq!(limit.remaining(at("2026-03-10T12:00:00Z")), 1);
+        assert_eq!(limit.remaining(at("2026-03-11T00:00:01Z")), 3);
+    }
+
+    #[test]
+    fn local_day_spans_utc_midnight() {
+        // Local midnight in New York on 10 March 2026 (EDT, UTC-4).
+        let limit = daily_limit("2026-03-10T04:00:00Z", 86400, "America/New_York");
+        // 8 PM and 11 PM local are still the same day.
+        assert_eq!(
+            expired_period_start(&limit, at("2026-03-11T00:30:00Z")),
+            None
+        );
+        assert_eq!(
+            expired_period_start(&limit, at("2026-03-11T03:59:59Z")),
+            None
+        );
+        assert_eq!(
+            expired_period_start(&limit, at("2026-03-11T04:00:00Z")),
+            Some(at("2026-03-11T04:00:00Z"))
+        );
+        // Idle for several days: the new window starts at today's midnight.
+        assert_eq!(
+            expired_period_start(&limit, at("2026-03-14T15:00:00Z")),
+            Some(at("2026-03-14T04:00:00Z"))
+        );
+    }
+
+    #[test]
+    fn local_day_follows_dst_change() {
+        // 8 March 2026 is 23 hours long in New York (clocks go forward).
+        let limit = daily_limit("2026-03-08T05:00:00Z", 86400, "America/New_York");
+        assert_eq!(
+            expired_period_start(&limit, at("2026-03-09T03:59:59Z")),
+            None
+        );
+        assert_eq!(
+            expired_period_start(&limit, at("2026-03-09T04:00:00Z")),
+            Some(at("2026-03-09T04:00:00Z"))
+        );
+    }
+
+    #[test]
+    fn weekly_limit_keeps_its_weekday() {
+        // Starts Monday 2 March 2026, local midnight in Tokyo (UTC+9).
+        let limit = daily_limit("2026-03-01T15:00:00Z", 604800, "Asia/Tokyo");
+        assert_eq!(
+            expired_period_start(&limit, at("2026-03-08T14:59:59Z")),
+            None
+        );
+        // Eleven days later we are in the second week, which began Monday 9 March.
+        assert_eq!(
+            expired_period_start(&limit, at("2026-03-13T03:00:00Z")),
+            Some(at("2026-03-08T15:00:00Z"))
+        );
+    }
+
+    #[test]
+    fn short_periods_keep_rolling() {
+        let limit = daily_limit("2026-03-10T10:00:00Z", 900, "America/New_York");
+        assert_eq!(
+            expired_period_start(&limit, at("2026-03-10T10:14:59Z")),
+            None
+        );
+        assert_eq!(
+            expired_period_start(&limit, at("2026-03-10T10:20:00Z")),
+            Some(at("2026-03-10T10:20:00Z"))
+        );
+    }
+
+    #[test]
+    fn rolling_day_ignores_midnight() {
+        let mut limit = daily_limit("2026-03-10T20:00:00Z", 86400, "UTC");
+        limit.window_kind = WINDOW_ROLLING.to_string();
+        assert_eq!(
+            expired_period_start(&limit, at("2026-03-11T19:59:59Z")),
+            None
+        );
+        assert_eq!(limit.resets_at(), at("2026-03-11T20:00:00Z"));
+        assert_eq!(
+            expired_period_start(&limit, at("2026-03-11T20:00:00Z")),
+            Some(at("2026-03-11T20:00:00Z"))
+        );
+    }
+
+    #[test]
+    fn weekly_limit_reports_local_reset() {
+        let limit = daily_limit("2026-03-09T04:00:00Z", 604800, "America/New_York");
+        assert_eq!(limit.resets_at(), at("2026-03-16T04:00:00Z"));
+    }
+}
@@ -0,0 +1,253 @@
+//! Engagement reciprocity per author.
+//!
+//! Compares the replies we sent to an author's tweets with the mentions and
+//! replies that author sent us over the same range. Our side comes from
+//! `replies_sent`, resolved to an author through the stored tweet; their
+//! side comes from `inbound_engagements`.
+
+use super::accounts::DEFAULT_ACCOUNT_ID;
+use super::analytics_range::AnalyticsRange;
+use super::DbPool;
+use crate::error::StorageError;
+
+pub use tuitbot_types::analytics::AuthorReciprocity;
+
+fn reciprocity_from_row(
+    (author_id, author_username, outbound, inbound): (String, String, i64, i64),
+) -> AuthorReciprocity {
+    let ratio = if outbound > 0 {
+        inbound as f64 / outbound as f64
+    } else {
+        0.0
+    };
+    AuthorReciprocity {
+        author_id,
+        author_username,
+        outbound,
+        inbound,
+        ratio,
+    }
+}
+
+/// Per-author outbound and inbound counts within `[?2, ?3)`.
+///
+/// ?1 = account ID, ?2/?3 = range bounds, ?4 = minimum outbound count,
+/// ?5 = author ID filter (NULL for all authors), ?6 = row limit.
+const RECIPROCITY_SQL: &str = "\
+    WITH outbound AS ( \
+        SELECT COALESCE(NULLIF(r.author_id, ''), d.author_id, t.account_id) AS author_id, \
+               COALESCE(NULLIF(r.author_username, ''), d.author_username, ta.username) \
+                   AS author_username \
+        FROM replies_sent r \
+        LEFT JOIN discovered_tweets d ON d.id = r.target_tweet_id \
+        LEFT JOIN target_tweets t ON t.id = r.target_tweet_id \
+        LEFT JOIN target_accounts ta ON ta.account_id = t.account_id \
+        WHERE r.account_id = ?1 AND r.status != 'failed' \
+          AND datetime(r.created_at) >= ?2 AND datetime(r.created_at) < ?3 \
+    ), out_counts AS ( \
+        SELECT author_id, MAX(author_username) AS author_username, COUNT(*) AS outbound \
+        FROM outbound \
+        WHERE COALESCE(author_id, '') != '' AND (?5 IS NULL OR author_id = ?5) \
+        GROUP BY author_id \
+    ), in_counts AS ( \
+        SELECT author_id, COUNT(*) AS inbound FROM inbound_engagements \
+        WHERE account_id = ?1 AND datetime(created_at) >= ?2 AND datetime(created_at) < ?3 \
+        GROUP BY author_id \
+    ) \
+    SELECT o.author_id, COALESCE(o.author_username, ''), o.outbound, COALESCE(i.inbound, 0) \
+    FROM out_counts o LEFT JOIN in_counts i ON i.author_id = o.author_id \
+    WHERE o.outbound >= ?4 \
+    ORDER BY CAST(COALESCE(i.inbound, 0) AS REAL) / o.outbound ASC, o.outbound DESC \
+    LIMIT ?6";
+
+/// Record a mention or reply an author sent to a specific account.
+/// Recording the same tweet twice is a no-op.
+pub async fn record_inbound_for(
+    pool: &DbPool,
+    account_id: &str,
+    tweet_id: &str,
+    author_id: &str,
+    author_username: &str,
+    created_at: &str,
+) -> Result<(), StorageError> {
+    sqlx::query(
+        "INSERT INTO inbound_engagements \
+             (account_id, tweet_id, author_id, author_username, created_at) \
+         VALUES (?, ?, ?, ?, COALESCE(NULLIF(?, ''), strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))) \
+         ON CONFLICT(account_id, tweet_id) DO NOTHING",
+    )
+    .bind(account_id)
+    .bind(tweet_id)
+    .bind(author_id)
+    .bind(author_username)
+    .bind(created_at)
+    .execute(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+    Ok(())
+}
+
+/// Record a mention or reply an author sent to the default account.
+pub async fn record_inbound(
+    pool: &DbPool,
+    tweet_id: &str,
+    author_id: &str,
+    author_username: &str,
+    created_at: &str,
+) -> Result<(), StorageError> {
+    record_inbound_for(
+        pool,
+        DEFAULT_ACCOUNT_ID,
+        tweet_id,
+        author_id,
+        author_username,
+        created_at,
+    )
+    .await
+}
+
+/// Reciprocity with one author for a specific account, or `None` if we
+/// sent them nothing in `range`.
+pub async fn get_author_reciprocity_for(
+    pool: &DbPool,
+    account_id: &str,
+    author_id: &str,
+    range: &AnalyticsRange,
+) -> Result<Option<AuthorReciprocity>, StorageError> {
+    let (from, to) = range.bounds();
+    let row: Option<(String, String, i64, i64)> = sqlx::query_as(RECIPROCITY_SQL)
+        .bind(account_id)
+        .bind(&from)
+        .bind(&to)
+        .bind(1_i64)
+        .bind(author_id)
+        .bind(1_i64)
+        .fetch_optional(pool)
+        .await
+        .map_err(|e| StorageError::Query { source: e })?;
+    Ok(row.map(reciprocity_from_row))
+}
+
+/// Reciprocity with one author for the default account.
+pub async fn get_author_reciprocity(
+    pool: &DbPool,
+    author_id: &str,
+    range: &AnalyticsRange,
+) -> Result<Option<AuthorReciprocity>, StorageError> {
+    get_author_reciprocity_for(pool, DEFAULT_ACCOUNT_ID, author_id, range).await
+}
+
+/// The most one-sided relationships for a specific account: authors we
+/// engaged at least `min_outbound` times in `range`, lowest ratio first.
+pub async fn get_one_sided_for(
+    pool: &DbPool,
+    account_id: &str,
+    range: &AnalyticsRange,
+    min_outbound: u32,
+    limit: u32,
+) -> Result<Vec<AuthorReciprocity>, StorageError> {
+    let (from, to) = range.bounds();
+    let rows: Vec<(String, String, i64, i64)> = sqlx::query_as(RECIPROCITY_SQL)
+        .bind(account_id)
+        .bind(&from)
+        .bind(&to)
+        .bind(i64::from(min_outbound.max(1)))
+        .bind(None::<String>)
+        .bind(i64::from(limit))
+        .fetch_all(pool)
+        .await
+        .map_err(|e| StorageError::Query { source: e })?;
+    Ok(rows.into_iter().map(reciprocity_from_row).collect())
+}
+
+/// The most one-sided relationships for the default account.
+pub async fn get_one_sided(
+    pool: &DbPool,
+    range: &AnalyticsRange,
+    min_outbound: u32,
+    limit: u32,
+) -> Result<Vec<AuthorReciprocity>, StorageError> {
+    get_one_sided_for(pool, DEFAULT_ACCOUNT_ID, range, min_outbound, limit).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::init_test_db;
+    use crate::storage::replies::{insert_reply, ReplySent};
+    use crate::storage::tweets::{insert_discovered_tweet, DiscoveredTweet};
+
+    async fn reply_to(pool: &DbPool, tweet_id: &str, author_id: &str, username: &str) {
+        insert_discovered_tweet(
+            pool,
+            &DiscoveredTweet {
+                id: tweet_id.to_string(),
+                author_id: author_id.to_string(),
+                author_username: username.to_string(),
+                content: "Rust tip".to_string(),
+                like_count: 0,
+                retweet_count: 0,
+                reply_count: 0,
+                quote_count: 0,
+                impression_count: None,
+                relevance_score: Some(80.0),
+                matched_keyword: Some("rust".to_string()),
+                discovered_at: chrono::Utc::now().to_rfc3339(),
+                replied_to: 1,
+            },
+        )
+        .await
+        .expect("tweet");
+        insert_reply(
+            pool,
+            &ReplySent {
+                id: 0,
+                target_tweet_id: tweet_id.to_string(),
+                reply_tweet_id: None,
+                reply_content: "Nice".to_string(),
+                llm_provider: None,
+                llm_model: None,
+                created_at: (chrono::Utc::now() - chrono::Duration::minutes(1)).to_rfc3339(),
+                status: "sent".to_string(),
+                error_message: None,
+            },
+        )
+        .await
+        .expect("reply");
+    }
+
+    #[tokio::test]
+    async fn one_sided_authors_rank_first() {
+        let pool = init_test_db().await.expect("init db");
+        for i in 0..3 {
+            reply_to(&pool, &format!("a{i}"), "alice_id", "alice").await;
+            reply_to(&pool, &format!("b{i}"), "bob_id", "bob").await;
+        }
+        let mentioned_at = (chrono::Utc::now() - chrono::Duration::minutes(1)).to_rfc3339();
+        record_inbound(&pool, "m1", "bob_id", "bob", &mentioned_at)
+            .await
+            .expect("inbound");
+        record_inbound(&pool, "m1", "bob_id", "bob", "")
+            .await
+            .expect("duplicate");
+
+        let range = AnalyticsRange::last_days(30);
+        let report = get_one_sided(&pool, &range, 3, 10).await.expect("report");
+        assert_eq!(report.len(), 2);
+        assert_eq!(report[0].author_username, "alice");
+        assert_eq!((report[0].outbound, report[0].inbound), (3, 0));
+        assert_eq!(report[1].inbound, 1);
+
+        let bob = get_author_reciprocity_for(&pool, DEFAULT_ACCOUNT_ID, "bob_id", &range)
+            .await
+            .expect("bob")
+            .expect("engaged");
+        assert!((bob.ratio - 1.0 / 3.0).abs() < 1e-9);
+        assert!(
+            get_author_reciprocity_for(&pool, DEFAULT_ACCOUNT_ID, "carol_id", &range)
+                .await
+                .expect("carol")
+                .is_none()
+        );
+    }
+}
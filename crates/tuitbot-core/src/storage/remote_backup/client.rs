@@ -0,0 +1,328 @@
+//! Minimal S3 client with AWS Signature V4 signing.
+
+use chrono::Utc;
+use hmac::{Hmac, Mac};
+use regex::Regex;
+use sha2::{Digest, Sha256};
+
+use super::{RemoteObject, CHECKSUM_HEADER};
+use crate::config::RemoteBackupConfig;
+use crate::error::RemoteBackupError;
+
+/// Minimal S3 client covering the calls backups need.
+pub struct S3Client {
+    http: reqwest::Client,
+    endpoint: String,
+    region: String,
+    access_key_id: String,
+    secret_access_key: String,
+}
+
+impl S3Client {
+    /// Create a client from `[remote_backup]` settings.
+    pub fn new(config: &RemoteBackupConfig) -> Self {
+        let endpoint = match config.endpoint.trim() {
+            "" => format!("https://s3.{}.amazonaws.com", config.region),
+            endpoint => endpoint.trim_end_matches('/').to_string(),
+        };
+        Self {
+            http: reqwest::Client::new(),
+            endpoint,
+            region: config.region.clone(),
+            access_key_id: config.access_key_id.clone(),
+            secret_access_key: config.secret_access_key.clone().unwrap_or_default(),
+        }
+    }
+
+    /// Upload `body`, recording `sha256` as object metadata.
+    pub async fn put_object(
+        &self,
+        bucket: &str,
+        key: &str,
+        body: Vec<u8>,
+        sha256: &str,
+    ) -> Result<(), RemoteBackupError> {
+        self.send(reqwest::Method::PUT, bucket, key, &[], body, Some(sha256))
+            .await?;
+        Ok(())
+    }
+
+    /// Size and recorded checksum of an object.
+    pub async fn head_object(
+        &self,
+        bucket: &str,
+        key: &str,
+    ) -> Result<(u64, Option<String>), RemoteBackupError> {
+        let response = self
+            .send(reqwest::Method::HEAD, bucket, key, &[], Vec::new(), None)
+            .await?;
+        let size = response
+            .headers()
+            .get(reqwest::header::CONTENT_LENGTH)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(0);
+        Ok((size, checksum_of(&response)))
+    }
+
+    /// Download an object with its recorded checksum.
+    pub async fn get_object(
+        &self,
+        bucket: &str,
+        key: &str,
+    ) -> Result<(Vec<u8>, Option<String>), RemoteBackupError> {
+        let response = self
+            .send(reqwest::Method::GET, bucket, key, &[], Vec::new(), None)
+            .await?;
+        let checksum = checksum_of(&response);
+        let body = response
+            .bytes()
+            .await
+            .map_err(|e| RemoteBackupError::Network { source: e })?;
+        Ok((body.to_vec(), checksum))
+    }
+
+    /// All objects under `prefix`, following continuation tokens.
+    pub async fn list_objects(
+        &self,
+        bucket: &str,
+        prefix: &str,
+    ) -> Result<Vec<RemoteObject>, RemoteBackupError> {
+        let mut objects = Vec::new();
+        let mut token: Option<String> = None;
+        loop {
+            let mut query = vec![
+                ("list-type", "2".to_string()),
+                ("prefix", prefix.to_string()),
+            ];
+            if let Some(token) = token.take() {
+                query.push(("continuation-token", token));
+            }
+            let response = self
+                .send(reqwest::Method::GET, bucket, "", &query, Vec::new(), None)
+                .await?;
+            let xml = response
+                .text()
+                .await
+                .map_err(|e| RemoteBackupError::Network { source: e })?;
+            let (page, next) = parse_list_response(&xml);
+            objects.extend(page);
+            match next {
+                Some(next) => token = Some(next),
+                None => break,
+            }
+        }
+        Ok(objects)
+    }
+
+    /// Delete an object.
+    pub async fn delete_object(&self, bucket: &str, key: &str) -> Result<(), RemoteBackupError> {
+        self.send(reqwest::Method::DELETE, bucket, key, &[], Vec::new(), None)
+            .await?;
+        Ok(())
+    }
+
+    async fn send(
+        &self,
+        method: reqwest::Method,
+        bucket: &str,
+        key: &str,
+        query: &[(&str, String)],
+        body: Vec<u8>,
+        checksum: Option<&str>,
+    ) -> Result<reqwest::Response, RemoteBackupError> {
+        let path = if key.is_empty() {
+            format!("/{}", uri_encode(bucket, false))
+        } else {
+            format!("/{}/{}", uri_encode(bucket, false), uri_encode(key, true))
+        };
+        let mut query: Vec<(String, String)> = query
+            .iter()
+            .map(|(k, v)| (uri_encode(k, false), uri_encode(v, false)))
+            .collect();
+        query.sort();
+        let query = query
+            .iter()
+            .map(|(k, v)| format!("{k}={v}"))
+            .collect::<Vec<_>>()
+            .join("&");
+        let url = if query.is_empty() {
+            format!("{}{path}", self.endpoint)
+        } else {
+            format!("{}{path}?{query}", self.endpoint)
+        };
+
+        let mut headers = vec![
+            ("host".to_string(), host_of(&self.endpoint)),
+            (
+                "x-amz-content-sha256".to_string(),
+                hex::encode(Sha256::digest(&body)),
+            ),
+            (
+                "x-amz-date".to_string(),
+                Utc::now().format("%Y%m%dT%H%M%SZ").to_string(),
+            ),
+        ];
+        if let Some(checksum) = checksum {
+            headers.push((CHECKSUM_HEADER.to_string(), checksum.to_string()));
+        }
+        let authorization = self.authorization(method.as_str(), &path, &query, &headers);
+
+        let mut request = self
+            .http
+            .request(method, &url)
+            .header(reqwest::header::AUTHORIZATION, authorization);
+        for (name, value) in headers.iter().filter(|(name, _)| name != "host") {
+            request = request.header(name.as_str(), value.as_str());
+        }
+        let response = request
+            .body(body)
+            .send()
+            .await
+            .map_err(|e| RemoteBackupError::Network { source: e })?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let message = response.text().await.unwrap_or_default();
+            return Err(RemoteBackupError::Api {
+                status: status.as_u16(),
+                message: message.chars().take(500).collect(),
+            });
+        }
+        Ok(response)
+    }
+
+    /// `Authorization` header value for AWS Signature V4.
+    ///
+    /// `headers` must include `host`, `x-amz-date`, and
+    /// `x-amz-content-sha256`, with lowercase names.
+    pub(super) fn authorization(
+        &self,
+        method: &str,
+        path: &str,
+        query: &str,
+        headers: &[(String, String)],
+    ) -> String {
+        let mut headers = headers.to_vec();
+        headers.sort();
+        let canonical_headers: String = headers
+            .iter()
+            .map(|(name, value)| format!("{name}:{}\n", value.trim()))
+            .collect();
+        let signed_headers = headers
+            .iter()
+            .map(|(name, _)| name.as_str())
+            .collect::<Vec<_>>()
+            .join(";");
+        let header = |name: &str| {
+            headers
+                .iter()
+                .find(|(n, _)| n == name)
+                .map(|(_, v)| v.as_str())
+                .unwrap_or_default()
+        };
+        let amz_date = header("x-amz-date");
+        let payload_hash = header("x-amz-content-sha256");
+
+        let canonical_request = format!(
+            "{method}\n{path}\n{query}\n{canonical_headers}\n{signed_headers}\n{payload_hash}"
+        );
+        let date = &amz_date[..amz_date.len().min(8)];
+        let scope = format!("{date}/{}/s3/aws4_request", self.region);
+        let string_to_sign = format!(
+            "AWS4-HMAC-SHA256\n{amz_date}\n{scope}\n{}",
+            hex::encode(Sha256::digest(canonical_request.as_bytes()))
+        );
+
+        let key = hmac_sha256(format!("AWS4{}", self.secret_access_key).as_bytes(), date);
+        let key = hmac_sha256(&key, &self.region);
+        let key = hmac_sha256(&key, "s3");
+        let key = hmac_sha256(&key, "aws4_request");
+        let signature = hex::encode(hmac_sha256(&key, &string_to_sign));
+
+        format!(
+            "AWS4-HMAC-SHA256 Credential={}/{scope}, SignedHeaders={signed_headers}, Signature={signature}",
+            self.access_key_id
+        )
+    }
+}
+
+fn checksum_of(response: &reqwest::Response) -> Option<String> {
+    response
+        .headers()
+        .get(CHECKSUM_HEADER)
+        .and_then(|v| v.to_str().ok())
+        .map(str::to_string)
+}
+
+fn host_of(endpoint: &str) -> String {
+    let rest = endpoint
+        .split_once("://")
+        .map_or(endpoint, |(_, rest)| rest);
+    rest.split('/').next().unwrap_or_default().to_string()
+}
+
+fn hmac_sha256(key: &[u8], data: &str) -> Vec<u8> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC accepts keys of any length");
+    mac.update(data.as_bytes());
+    mac.finalize().into_bytes().to_vec()
+}
+
+/// Percent-encode everything but unreserved characters (and `/` when
+/// `keep_slash`), as Signature V4 requires.
+pub(super) fn uri_encode(value: &str, keep_slash: bool) -> String {
+    let mut out = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                out.push(byte as char);
+            }
+            b'/' if keep_slash => out.push('/'),
+            _ => out.push_str(&format!("%{byte:02X}")),
+        }
+    }
+    out
+}
+
+/// Objects and the continuation token from a ListObjectsV2 response.
+fn parse_list_response(xml: &str) -> (Vec<RemoteObject>, Option<String>) {
+    let contents = Regex::new(r"(?s)<Contents>(.*?)</Contents>").expect("valid regex");
+    let field = |block: &str, tag: &str| {
+        let start = format!("<{tag}>");
+        let end = format!("</{tag}>");
+        let from = block.find(&start)? + start.len();
+        let to = block[from..].find(&end)? + from;
+        Some(xml_unescape(&block[from..to]))
+    };
+
+    let objects = contents
+        .captures_iter(xml)
+        .filter_map(|c| {
+            let block = c.get(1)?.as_str();
+            Some(RemoteObject {
+                key: field(block, "Key")?,
+                size_bytes: field(block, "Size")
+                    .and_then(|s| s.parse().ok())
+                    .unwrap_or(0),
+                last_modified: field(block, "LastModified"),
+            })
+        })
+        .collect();
+
+    let truncated = field(xml, "IsTruncated").is_some_and(|v| v == "true");
+    let next = if truncated {
+        field(xml, "NextContinuationToken")
+    } else {
+        None
+    };
+    (objects, next)
+}
+
+fn xml_unescape(value: &str) -> String {
+    value
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
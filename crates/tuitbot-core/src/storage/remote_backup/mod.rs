@@ -0,0 +1,60 @@
+//! Remote backups to S3-compatible storage (AWS S3, Cloudflare R2,
+//! Backblaze B2, MinIO).
+//!
+//! Snapshots from [`backup::create_backup`](super::backup::create_backup) are gzip-compressed and
+//! uploaded with path-style requests signed with AWS Signature V4. The
+//! SHA-256 of each upload is stored as object metadata, checked right after
+//! the upload, and checked again before a restore. Snapshots beyond
+//! `remote_backup.keep` are pruned after each upload.
+
+mod client;
+mod upload;
+
+#[cfg(test)]
+mod tests;
+
+use crate::error::RemoteBackupError;
+
+pub use client::S3Client;
+pub use upload::{download_backup, list_remote_backups, prune_remote_backups, upload_backup};
+
+/// Filename prefix and suffix of uploaded snapshots.
+const SNAPSHOT_PREFIX: &str = "tuitbot_";
+const SNAPSHOT_SUFFIX: &str = ".db.gz";
+
+/// Object metadata header holding the snapshot's SHA-256 (hex).
+const CHECKSUM_HEADER: &str = "x-amz-meta-sha256";
+
+/// An object in the backup bucket.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RemoteObject {
+    pub key: String,
+    pub size_bytes: u64,
+    pub last_modified: Option<String>,
+}
+
+/// Result of a successful remote upload.
+#[derive(Debug, Clone)]
+pub struct RemoteBackupResult {
+    /// `s3://bucket/key` of the uploaded snapshot.
+    pub uri: String,
+    /// Compressed size in bytes.
+    pub size_bytes: u64,
+    /// SHA-256 of the compressed snapshot (hex).
+    pub sha256: String,
+    /// Older snapshots deleted by retention.
+    pub pruned: u32,
+}
+
+/// Split `s3://bucket/key` into bucket and key.
+pub fn parse_s3_uri(uri: &str) -> Result<(String, String), RemoteBackupError> {
+    let invalid = || RemoteBackupError::InvalidUri {
+        uri: uri.to_string(),
+    };
+    let rest = uri.strip_prefix("s3://").ok_or_else(invalid)?;
+    let (bucket, key) = rest.split_once('/').ok_or_else(invalid)?;
+    if bucket.is_empty() || key.is_empty() || key.ends_with('/') {
+        return Err(invalid());
+    }
+    Ok((bucket.to_string(), key.to_string()))
+}
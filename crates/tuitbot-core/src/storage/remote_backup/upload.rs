@@ -0,0 +1,181 @@
+//! Snapshot upload, listing, retention, and download.
+
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Utc};
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use sha2::{Digest, Sha256};
+
+use super::{
+    parse_s3_uri, RemoteBackupResult, RemoteObject, S3Client, SNAPSHOT_PREFIX, SNAPSHOT_SUFFIX,
+};
+use crate::config::RemoteBackupConfig;
+use crate::error::RemoteBackupError;
+use crate::storage::{backup, DbPool};
+
+/// Take a snapshot of `pool`, compress it, upload it, verify the upload,
+/// and prune remote snapshots beyond `config.keep`.
+pub async fn upload_backup(
+    pool: &DbPool,
+    config: &RemoteBackupConfig,
+) -> Result<RemoteBackupResult, RemoteBackupError> {
+    let staging = std::env::temp_dir().join(format!(
+        "tuitbot_remote_backup_{}",
+        Utc::now().timestamp_millis()
+    ));
+    let snapshot = backup::create_backup(pool, &staging).await;
+    let body = snapshot.map_err(RemoteBackupError::from).and_then(|s| {
+        let bytes = std::fs::read(&s.path).map_err(|e| io_error(&s.path, e))?;
+        gzip(&bytes).map_err(|e| io_error(&s.path, e))
+    });
+    let _ = std::fs::remove_dir_all(&staging);
+    let body = body?;
+
+    let sha256 = hex::encode(Sha256::digest(&body));
+    let size_bytes = body.len() as u64;
+    let key = snapshot_key(&config.prefix, Utc::now());
+    let client = S3Client::new(config);
+    client
+        .put_object(&config.bucket, &key, body, &sha256)
+        .await?;
+
+    let (remote_size, remote_sha256) = client.head_object(&config.bucket, &key).await?;
+    if remote_size != size_bytes || remote_sha256.as_deref() != Some(sha256.as_str()) {
+        return Err(RemoteBackupError::ChecksumMismatch {
+            key,
+            expected: sha256,
+            actual: remote_sha256.unwrap_or_else(|| format!("{remote_size} bytes")),
+        });
+    }
+
+    let pruned = match prune_remote_backups(&client, config).await {
+        Ok(pruned) => pruned,
+        Err(e) => {
+            tracing::warn!(error = %e, "Failed to prune remote backups");
+            0
+        }
+    };
+
+    Ok(RemoteBackupResult {
+        uri: format!("s3://{}/{key}", config.bucket),
+        size_bytes,
+        sha256,
+        pruned,
+    })
+}
+
+/// Snapshots under `config.prefix`, newest first.
+pub async fn list_remote_backups(
+    client: &S3Client,
+    config: &RemoteBackupConfig,
+) -> Result<Vec<RemoteObject>, RemoteBackupError> {
+    let prefix = normalize_prefix(&config.prefix);
+    let mut snapshots: Vec<RemoteObject> = client
+        .list_objects(&config.bucket, &prefix)
+        .await?
+        .into_iter()
+        .filter(|o| {
+            o.key.strip_prefix(prefix.as_str()).is_some_and(|name| {
+                !name.contains('/')
+                    && name.starts_with(SNAPSHOT_PREFIX)
+                    && name.ends_with(SNAPSHOT_SUFFIX)
+            })
+        })
+        .collect();
+    snapshots.sort_by(|a, b| b.key.cmp(&a.key));
+    Ok(snapshots)
+}
+
+/// Delete remote snapshots beyond the newest `config.keep`.
+///
+/// Returns the number of snapshots deleted.
+pub async fn prune_remote_backups(
+    client: &S3Client,
+    config: &RemoteBackupConfig,
+) -> Result<u32, RemoteBackupError> {
+    let snapshots = list_remote_backups(client, config).await?;
+    let mut deleted = 0u32;
+    for snapshot in snapshots.iter().skip(config.keep as usize) {
+        client.delete_object(&config.bucket, &snapshot.key).await?;
+        deleted += 1;
+    }
+    Ok(deleted)
+}
+
+/// Download the snapshot at `uri` into `dest_dir`, verify its checksum, and
+/// decompress it. Returns the path of the database file.
+pub async fn download_backup(
+    config: &RemoteBackupConfig,
+    uri: &str,
+    dest_dir: &Path,
+) -> Result<PathBuf, RemoteBackupError> {
+    let (bucket, key) = parse_s3_uri(uri)?;
+    let client = S3Client::new(config);
+    let (body, expected) = client.get_object(&bucket, &key).await?;
+
+    let actual = hex::encode(Sha256::digest(&body));
+    match expected {
+        Some(expected) if expected != actual => {
+            return Err(RemoteBackupError::ChecksumMismatch {
+                key,
+                expected,
+                actual,
+            });
+        }
+        Some(_) => {}
+        None => tracing::warn!(key = %key, "Remote backup has no checksum; skipping verification"),
+    }
+
+    let name = key.rsplit('/').next().unwrap_or_default();
+    let (name, data) = match name.strip_suffix(".gz") {
+        Some(stem) => {
+            let mut data = Vec::new();
+            GzDecoder::new(body.as_slice())
+                .read_to_end(&mut data)
+                .map_err(|e| io_error(Path::new(&key), e))?;
+            (stem, data)
+        }
+        None => (name, body),
+    };
+
+    std::fs::create_dir_all(dest_dir).map_err(|e| io_error(dest_dir, e))?;
+    let path = dest_dir.join(format!("remote_{name}"));
+    std::fs::write(&path, data).map_err(|e| io_error(&path, e))?;
+    Ok(path)
+}
+
+/// Key for a snapshot taken at `now` under `prefix`.
+fn snapshot_key(prefix: &str, now: DateTime<Utc>) -> String {
+    format!(
+        "{}{SNAPSHOT_PREFIX}{}{SNAPSHOT_SUFFIX}",
+        normalize_prefix(prefix),
+        now.format("%Y%m%d_%H%M%S")
+    )
+}
+
+/// `prefix` without leading slashes and with exactly one trailing slash
+/// (empty stays empty).
+pub(super) fn normalize_prefix(prefix: &str) -> String {
+    let prefix = prefix.trim().trim_matches('/');
+    if prefix.is_empty() {
+        String::new()
+    } else {
+        format!("{prefix}/")
+    }
+}
+
+pub(super) fn gzip(bytes: &[u8]) -> std::io::Result<Vec<u8>> {
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(bytes)?;
+    encoder.finish()
+}
+
+fn io_error(path: &Path, source: std::io::Error) -> RemoteBackupError {
+    RemoteBackupError::Io {
+        path: path.display().to_string(),
+        source,
+    }
+}
@@ -4,6 +4,7 @@
 //! count daily usage, and retrieve recent reply content.
 
 use super::accounts::DEFAULT_ACCOUNT_ID;
+use super::pagination::{fetch_page, Conditions, Page, PageRequest};
 use super::DbPool;
 use crate::error::StorageError;
 
@@ -198,6 +199,44 @@ pub async fn get_recent_replies_for(
     .map_err(|e| StorageError::Query { source: e })
 }
 
+/// Sortable fields for [`get_replies_page_for`], as (field, column).
+pub const REPLY_SORT_FIELDS: &[(&str, &str)] =
+    &[("created_at", "created_at"), ("status", "status")];
+
+/// Filters for [`get_replies_page_for`]; `None` matches everything.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ReplyFilter<'a> {
+    pub status: Option<&'a str>,
+    /// Sent at or after this ISO-8601 timestamp.
+    pub since: Option<&'a str>,
+    /// Sent before this ISO-8601 timestamp.
+    pub until: Option<&'a str>,
+}
+
+/// One page of replies for a specific account, newest first by default.
+pub async fn get_replies_page_for(
+    pool: &DbPool,
+    account_id: &str,
+    filter: &ReplyFilter<'_>,
+    page: &PageRequest,
+) -> Result<Page<ReplySent>, StorageError> {
+    let conditions = Conditions::new()
+        .eq("account_id", Some(account_id))
+        .eq("status", filter.status)
+        .since("created_at", filter.since)
+        .until("created_at", filter.until);
+    fetch_page(
+        pool,
+        "replies_sent",
+        "*",
+        &conditions,
+        page,
+        "ORDER BY created_at DESC",
+        |r: &ReplySent| r.id,
+    )
+    .await
+}
+
 /// Get the most recent replies, newest first, with pagination.
 pub async fn get_recent_replies(
     pool: &DbPool,
@@ -207,6 +246,25 @@ pub async fn get_recent_replies(
     get_recent_replies_for(pool, DEFAULT_ACCOUNT_ID, limit, offset).await
 }
 
+/// Fetch a single reply by ID for a specific account.
+pub async fn get_reply_for(
+    pool: &DbPool,
+    account_id: &str,
+    id: i64,
+) -> Result<Option<ReplySent>, StorageError> {
+    sqlx::query_as::<_, ReplySent>("SELECT * FROM replies_sent WHERE account_id = ? AND id = ?")
+        .bind(account_id)
+        .bind(id)
+        .fetch_optional(pool)
+        .await
+        .map_err(|e| StorageError::Query { source: e })
+}
+
+/// Fetch a single reply by ID.
+pub async fn get_reply(pool: &DbPool, id: i64) -> Result<Option<ReplySent>, StorageError> {
+    get_reply_for(pool, DEFAULT_ACCOUNT_ID, id).await
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
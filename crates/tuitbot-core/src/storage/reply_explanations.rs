@@ -0,0 +1,132 @@
+//! "Why this reply" explanations stored alongside sent replies.
+//!
+//! Each reply the automation loops post can carry a compact record of the
+//! signals that produced it: matched keywords, the score breakdown, the
+//! archetype chosen, the winning ancestors fed to the prompt, the content
+//! pillar override applied, and the QA score. The record is stored as JSON keyed by `replies_sent.id` and is
+//! removed with the reply.
+
+use super::accounts::DEFAULT_ACCOUNT_ID;
+use super::DbPool;
+use crate::error::StorageError;
+
+pub use tuitbot_types::replies::{AncestorRef, ReplyExplanation, ScoreSignals};
+
+/// Store the explanation for a reply for a specific account, replacing any existing one.
+pub async fn save_for(
+    pool: &DbPool,
+    account_id: &str,
+    reply_id: i64,
+    explanation: &ReplyExplanation,
+) -> Result<(), StorageError> {
+    let json = serde_json::to_string(explanation).unwrap_or_else(|_| "{}".to_string());
+    sqlx::query(
+        "INSERT INTO reply_explanations (reply_id, account_id, explanation) VALUES (?, ?, ?) \
+         ON CONFLICT(reply_id) DO UPDATE SET explanation = excluded.explanation",
+    )
+    .bind(reply_id)
+    .bind(account_id)
+    .bind(json)
+    .execute(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+    Ok(())
+}
+
+/// Store the explanation for a reply, replacing any existing one.
+pub async fn save(
+    pool: &DbPool,
+    reply_id: i64,
+    explanation: &ReplyExplanation,
+) -> Result<(), StorageError> {
+    save_for(pool, DEFAULT_ACCOUNT_ID, reply_id, explanation).await
+}
+
+/// Fetch the explanation for a reply for a specific account.
+///
+/// Rows that no longer deserialize are treated as missing.
+pub async fn get_for(
+    pool: &DbPool,
+    account_id: &str,
+    reply_id: i64,
+) -> Result<Option<ReplyExplanation>, StorageError> {
+    let row: Option<(String,)> = sqlx::query_as(
+        "SELECT explanation FROM reply_explanations WHERE account_id = ? AND reply_id = ?",
+    )
+    .bind(account_id)
+    .bind(reply_id)
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+    Ok(row.and_then(|(json,)| serde_json::from_str(&json).ok()))
+}
+
+/// Fetch the explanation for a reply.
+pub async fn get(pool: &DbPool, reply_id: i64) -> Result<Option<ReplyExplanation>, StorageError> {
+    get_for(pool, DEFAULT_ACCOUNT_ID, reply_id).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::init_test_db;
+    use crate::storage::replies::{insert_reply, ReplySent};
+
+    fn sample_reply() -> ReplySent {
+        ReplySent {
+            id: 0,
+            target_tweet_id: "t1".to_string(),
+            reply_tweet_id: Some("r1".to_string()),
+            reply_content: "Have you tried profiling first?".to_string(),
+            llm_provider: None,
+            llm_model: None,
+            created_at: chrono::Utc::now().format("%Y-%m-%dT%H:%M:%SZ").to_string(),
+            status: "sent".to_string(),
+            error_message: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn save_and_get_round_trip() {
+        let pool = init_test_db().await.expect("init db");
+        let id = insert_reply(&pool, &sample_reply()).await.expect("insert");
+        assert!(get(&pool, id).await.unwrap().is_none());
+
+        let explanation = ReplyExplanation {
+            source: "discovery".to_string(),
+            matched_keywords: vec!["rust".to_string()],
+            score: Some(ScoreSignals {
+                total: 82.0,
+                keyword_relevance: 20.0,
+                ..ScoreSignals::default()
+            }),
+            threshold: Some(70.0),
+            archetype: Some("ask_question".to_string()),
+            ancestors: vec![AncestorRef {
+                tweet_id: "a1".to_string(),
+                content_preview: "Profile before you optimize".to_string(),
+                engagement_score: 0.9,
+            }],
+            topic_override: Some("Tax law".to_string()),
+            qa_score: Some(100.0),
+        };
+        save(&pool, id, &explanation).await.unwrap();
+        assert_eq!(get(&pool, id).await.unwrap(), Some(explanation.clone()));
+
+        let updated = ReplyExplanation {
+            qa_score: Some(80.0),
+            ..explanation
+        };
+        save(&pool, id, &updated).await.unwrap();
+        assert_eq!(get(&pool, id).await.unwrap().unwrap().qa_score, Some(80.0));
+        assert!(get_for(&pool, "other", id).await.unwrap().is_none());
+    }
+
+    #[test]
+    fn missing_fields_deserialize_as_defaults() {
+        let parsed: ReplyExplanation = serde_json::from_str(r#"{"source":"mentions"}"#).unwrap();
+        assert_eq!(parsed.source, "mentions");
+        assert!(parsed.matched_keywords.is_empty());
+        assert!(parsed.score.is_none());
+    }
+}
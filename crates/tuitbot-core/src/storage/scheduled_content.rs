@@ -18,7 +18,7 @@ pub struct ScheduledContent {
     pub content: String,
     /// Optional ISO-8601 scheduled time. NULL = next available slot.
     pub scheduled_for: Option<String>,
-    /// Status: scheduled, posted, or cancelled.
+    /// Status: draft, scheduled, promoted, posted, or cancelled.
     pub status: String,
     /// X tweet ID after posting (filled when posted).
     pub posted_tweet_id: Option<String>,
@@ -40,6 +40,8 @@ pub struct ScheduledContent {
     pub qa_recommendations: String,
     /// QA score summary (0-100).
     pub qa_score: f64,
+    /// Approval queue item created when a draft was promoted for review.
+    pub approval_queue_id: Option<i64>,
 }
 
 /// Serialize a JSON-encoded string as a raw JSON value.
@@ -271,6 +273,8 @@ pub async fn update_content(
 }
 
 /// Update QA fields for a content item for a specific account.
+///
+/// Also increments the daily QA flag counters used for trend analytics.
 #[allow(clippy::too_many_arguments)]
 pub async fn update_qa_fields_for(
     pool: &DbPool,
@@ -298,6 +302,8 @@ pub async fn update_qa_fields_for(
     .await
     .map_err(|e| StorageError::Query { source: e })?;
 
+    super::qa_flags::record_flags_json_for(pool, account_id, qa_hard_flags, qa_soft_flags).await?;
+
     Ok(())
 }
 
@@ -0,0 +1,271 @@
+//! Staged mutations for MCP confirmation mode (two-phase commit).
+//!
+//! When `mcp_policy.confirmation_mode` is on, a mutation tool call is stored
+//! here instead of executing and the caller receives a token. The action
+//! runs only after it is confirmed (via `confirm_action` or the dashboard)
+//! and before `expires_at`. Status moves `pending` → `confirmed` →
+//! `executed`, or `pending` → `rejected`; expiry is derived from `expires_at`.
+
+use chrono::{Duration, Utc};
+use rand::RngCore;
+
+use super::accounts::DEFAULT_ACCOUNT_ID;
+use super::DbPool;
+use crate::error::StorageError;
+
+/// SQL expression for "now" in the stored timestamp format.
+const NOW: &str = "strftime('%Y-%m-%dT%H:%M:%SZ', 'now')";
+
+/// A staged mutation awaiting confirmation.
+#[derive(Debug, Clone, PartialEq, sqlx::FromRow, serde::Serialize)]
+pub struct StagedAction {
+    pub id: i64,
+    pub token: String,
+    pub tool_name: String,
+    pub params_json: String,
+    pub params_hash: String,
+    pub status: String,
+    pub created_at: String,
+    pub expires_at: String,
+    pub resolved_at: Option<String>,
+    pub resolved_by: Option<String>,
+}
+
+impl StagedAction {
+    /// Whether the TTL has elapsed.
+    pub fn is_expired(&self) -> bool {
+        self.expires_at <= Utc::now().format("%Y-%m-%dT%H:%M:%SZ").to_string()
+    }
+}
+
+/// Stage a mutation for a specific account. Returns the stored row.
+pub async fn stage_action_for(
+    pool: &DbPool,
+    account_id: &str,
+    tool_name: &str,
+    params_json: &str,
+    params_hash: &str,
+    ttl_seconds: u64,
+) -> Result<StagedAction, StorageError> {
+    let mut buf = [0u8; 16];
+    rand::thread_rng().fill_bytes(&mut buf);
+    let token = hex::encode(buf);
+    let expires_at = (Utc::now() + Duration::seconds(ttl_seconds as i64))
+        .format("%Y-%m-%dT%H:%M:%SZ")
+        .to_string();
+
+    sqlx::query_as::<_, StagedAction>(
+        "INSERT INTO staged_actions \
+         (account_id, token, tool_name, params_json, params_hash, expires_at) \
+         VALUES (?, ?, ?, ?, ?, ?) \
+         RETURNING id, token, tool_name, params_json, params_hash, status, \
+                   created_at, expires_at, resolved_at, resolved_by",
+    )
+    .bind(account_id)
+    .bind(&token)
+    .bind(tool_name)
+    .bind(params_json)
+    .bind(params_hash)
+    .bind(&expires_at)
+    .fetch_one(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })
+}
+
+/// Stage a mutation for the default account.
+pub async fn stage_action(
+    pool: &DbPool,
+    tool_name: &str,
+    params_json: &str,
+    params_hash: &str,
+    ttl_seconds: u64,
+) -> Result<StagedAction, StorageError> {
+    stage_action_for(
+        pool,
+        DEFAULT_ACCOUNT_ID,
+        tool_name,
+        params_json,
+        params_hash,
+        ttl_seconds,
+    )
+    .await
+}
+
+/// Look up a staged action by its confirmation token.
+pub async fn get_by_token(
+    pool: &DbPool,
+    token: &str,
+) -> Result<Option<StagedAction>, StorageError> {
+    sqlx::query_as::<_, StagedAction>(
+        "SELECT id, token, tool_name, params_json, params_hash, status, \
+                created_at, expires_at, resolved_at, resolved_by \
+         FROM staged_actions WHERE token = ?",
+    )
+    .bind(token)
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })
+}
+
+/// Look up a staged action by ID.
+pub async fn get_by_id(pool: &DbPool, id: i64) -> Result<Option<StagedAction>, StorageError> {
+    sqlx::query_as::<_, StagedAction>(
+        "SELECT id, token, tool_name, params_json, params_hash, status, \
+                created_at, expires_at, resolved_at, resolved_by \
+         FROM staged_actions WHERE id = ?",
+    )
+    .bind(id)
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })
+}
+
+/// Unexpired actions still awaiting execution for a specific account, oldest first.
+pub async fn list_open_for(
+    pool: &DbPool,
+    account_id: &str,
+) -> Result<Vec<StagedAction>, StorageError> {
+    sqlx::query_as::<_, StagedAction>(&format!(
+        "SELECT id, token, tool_name, params_json, params_hash, status, \
+                created_at, expires_at, resolved_at, resolved_by \
+         FROM staged_actions \
+         WHERE account_id = ? AND status IN ('pending', 'confirmed') AND expires_at > {NOW} \
+         ORDER BY id"
+    ))
+    .bind(account_id)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })
+}
+
+/// Unexpired actions still awaiting execution for the default account.
+pub async fn list_open(pool: &DbPool) -> Result<Vec<StagedAction>, StorageError> {
+    list_open_for(pool, DEFAULT_ACCOUNT_ID).await
+}
+
+/// Confirm an open, unexpired action. Returns `false` if it can't be confirmed.
+pub async fn confirm(pool: &DbPool, id: i64, resolved_by: &str) -> Result<bool, StorageError> {
+    resolve(pool, id, "confirmed", resolved_by).await
+}
+
+/// Reject an open, unexpired action. Returns `false` if it can't be rejected.
+pub async fn reject(pool: &DbPool, id: i64, resolved_by: &str) -> Result<bool, StorageError> {
+    resolve(pool, id, "rejected", resolved_by).await
+}
+
+async fn resolve(
+    pool: &DbPool,
+    id: i64,
+    status: &str,
+    resolved_by: &str,
+) -> Result<bool, StorageError> {
+    let result = sqlx::query(&format!(
+        "UPDATE staged_actions SET status = ?, resolved_at = {NOW}, resolved_by = ? \
+         WHERE id = ? AND status IN ('pending', 'confirmed') AND expires_at > {NOW}"
+    ))
+    .bind(status)
+    .bind(resolved_by)
+    .bind(id)
+    .execute(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// Atomically claim a confirmed, unexpired action matching this mutation
+/// for a specific account, marking it `executed`.
+pub async fn take_confirmed_for(
+    pool: &DbPool,
+    account_id: &str,
+    tool_name: &str,
+    params_hash: &str,
+) -> Result<Option<StagedAction>, StorageError> {
+    sqlx::query_as::<_, StagedAction>(&format!(
+        "UPDATE staged_actions SET status = 'executed' \
+         WHERE id = ( \
+             SELECT id FROM staged_actions \
+             WHERE account_id = ? AND tool_name = ? AND params_hash = ? \
+               AND status = 'confirmed' AND expires_at > {NOW} \
+             ORDER BY id LIMIT 1) \
+         RETURNING id, token, tool_name, params_json, params_hash, status, \
+                   created_at, expires_at, resolved_at, resolved_by"
+    ))
+    .bind(account_id)
+    .bind(tool_name)
+    .bind(params_hash)
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })
+}
+
+/// Atomically claim a confirmed action for the default account.
+pub async fn take_confirmed(
+    pool: &DbPool,
+    tool_name: &str,
+    params_hash: &str,
+) -> Result<Option<StagedAction>, StorageError> {
+    take_confirmed_for(pool, DEFAULT_ACCOUNT_ID, tool_name, params_hash).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::init_test_db;
+
+    #[tokio::test]
+    async fn stage_confirm_and_take() {
+        let pool = init_test_db().await.expect("init db");
+        let staged = stage_action(&pool, "like_tweet", r#"{"tweet_id":"1"}"#, "h1", 300)
+            .await
+            .expect("stage");
+        assert_eq!(staged.status, "pending");
+        assert!(!staged.is_expired());
+        assert_eq!(list_open(&pool).await.expect("list").len(), 1);
+
+        // Not claimable until confirmed.
+        assert!(take_confirmed(&pool, "like_tweet", "h1")
+            .await
+            .expect("take")
+            .is_none());
+
+        assert!(confirm(&pool, staged.id, "mcp").await.expect("confirm"));
+        let taken = take_confirmed(&pool, "like_tweet", "h1")
+            .await
+            .expect("take")
+            .expect("claimed");
+        assert_eq!(taken.token, staged.token);
+        assert_eq!(taken.status, "executed");
+
+        // Claimed exactly once and no longer open.
+        assert!(take_confirmed(&pool, "like_tweet", "h1")
+            .await
+            .expect("take")
+            .is_none());
+        assert!(list_open(&pool).await.expect("list").is_empty());
+        assert!(!confirm(&pool, staged.id, "mcp").await.expect("confirm"));
+    }
+
+    #[tokio::test]
+    async fn rejected_and_expired_cannot_be_confirmed() {
+        let pool = init_test_db().await.expect("init db");
+        let rejected = stage_action(&pool, "follow_user", "{}", "h2", 300)
+            .await
+            .expect("stage");
+        assert!(reject(&pool, rejected.id, "dashboard")
+            .await
+            .expect("reject"));
+        assert!(!confirm(&pool, rejected.id, "mcp").await.expect("confirm"));
+
+        let expired = stage_action(&pool, "follow_user", "{}", "h3", 0)
+            .await
+            .expect("stage");
+        let expired = get_by_token(&pool, &expired.token)
+            .await
+            .expect("get")
+            .expect("exists");
+        assert!(expired.is_expired());
+        assert!(!confirm(&pool, expired.id, "mcp").await.expect("confirm"));
+        assert!(list_open(&pool).await.expect("list").is_empty());
+    }
+}
@@ -32,6 +32,7 @@ pub struct StrategyReportRow {
     pub bottom_topics_json: String,
     pub top_content_json: String,
     pub recommendations_json: String,
+    pub approval_metrics_json: String,
     // Metadata
     pub created_at: String,
 }
@@ -49,8 +50,8 @@ pub async fn insert_strategy_report_for(
          (account_id, week_start, week_end, replies_sent, tweets_posted, threads_posted, target_replies, \
           follower_start, follower_end, follower_delta, \
           avg_reply_score, avg_tweet_score, reply_acceptance_rate, estimated_follow_conversion, \
-          top_topics_json, bottom_topics_json, top_content_json, recommendations_json) \
-         VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?) \
+          top_topics_json, bottom_topics_json, top_content_json, recommendations_json, approval_metrics_json) \
+         VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?) \
          ON CONFLICT(week_start) DO UPDATE SET \
          week_end = excluded.week_end, \
          replies_sent = excluded.replies_sent, \
@@ -67,7 +68,8 @@ pub async fn insert_strategy_report_for(
          top_topics_json = excluded.top_topics_json, \
          bottom_topics_json = excluded.bottom_topics_json, \
          top_content_json = excluded.top_content_json, \
-         recommendations_json = excluded.recommendations_json",
+         recommendations_json = excluded.recommendations_json, \
+         approval_metrics_json = excluded.approval_metrics_json",
     )
     .bind(account_id)
     .bind(&report.week_start)
@@ -87,6 +89,7 @@ pub async fn insert_strategy_report_for(
     .bind(&report.bottom_topics_json)
     .bind(&report.top_content_json)
     .bind(&report.recommendations_json)
+    .bind(&report.approval_metrics_json)
     .execute(pool)
     .await
     .map_err(|e| StorageError::Query { source: e })?;
@@ -197,6 +200,7 @@ mod tests {
             bottom_topics_json: "[]".to_string(),
             top_content_json: "[]".to_string(),
             recommendations_json: "[]".to_string(),
+            approval_metrics_json: "{}".to_string(),
             created_at: String::new(),
         }
     }
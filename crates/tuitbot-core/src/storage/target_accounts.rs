@@ -17,9 +17,13 @@ pub struct TargetAccount {
     pub total_replies_sent: i64,
     pub last_reply_at: Option<String>,
     pub status: String,
+    pub tier: String,
 }
 
 /// Upsert a target account (insert or update username if exists) for a specific owner account.
+///
+/// A new row inherits the tier of any existing row with the same username, so
+/// a tier set on a placeholder record survives resolution to the real user ID.
 pub async fn upsert_target_account_for(
     pool: &DbPool,
     owner_account_id: &str,
@@ -27,13 +31,17 @@ pub async fn upsert_target_account_for(
     username: &str,
 ) -> Result<(), StorageError> {
     sqlx::query(
-        "INSERT INTO target_accounts (owner_account_id, account_id, username) \
-         VALUES (?, ?, ?) \
+        "INSERT INTO target_accounts (owner_account_id, account_id, username, tier) \
+         VALUES (?, ?, ?, COALESCE( \
+             (SELECT tier FROM target_accounts \
+              WHERE username = ? AND owner_account_id = ? LIMIT 1), 'general')) \
          ON CONFLICT(account_id) DO UPDATE SET username = excluded.username",
     )
     .bind(owner_account_id)
     .bind(account_id)
     .bind(username)
+    .bind(username)
+    .bind(owner_account_id)
     .execute(pool)
     .await
     .map_err(|e| StorageError::Query { source: e })?;
@@ -57,6 +65,7 @@ type TargetAccountRow = (
     i64,
     Option<String>,
     String,
+    String,
 );
 
 /// Get a target account by ID for a specific owner account.
@@ -67,7 +76,7 @@ pub async fn get_target_account_for(
 ) -> Result<Option<TargetAccount>, StorageError> {
     let row: Option<TargetAccountRow> = sqlx::query_as(
         "SELECT account_id, username, followed_at, first_engagement_at, \
-             total_replies_sent, last_reply_at, status \
+             total_replies_sent, last_reply_at, status, tier \
              FROM target_accounts WHERE account_id = ? AND owner_account_id = ?",
     )
     .bind(account_id)
@@ -84,6 +93,7 @@ pub async fn get_target_account_for(
         total_replies_sent: r.4,
         last_reply_at: r.5,
         status: r.6,
+        tier: r.7,
     }))
 }
 
@@ -102,7 +112,7 @@ pub async fn get_active_target_accounts_for(
 ) -> Result<Vec<TargetAccount>, StorageError> {
     let rows: Vec<TargetAccountRow> = sqlx::query_as(
         "SELECT account_id, username, followed_at, first_engagement_at, \
-             total_replies_sent, last_reply_at, status \
+             total_replies_sent, last_reply_at, status, tier \
              FROM target_accounts WHERE status = 'active' AND owner_account_id = ?",
     )
     .bind(owner_account_id)
@@ -120,6 +130,7 @@ pub async fn get_active_target_accounts_for(
             total_replies_sent: r.4,
             last_reply_at: r.5,
             status: r.6,
+            tier: r.7,
         })
         .collect())
 }
@@ -176,6 +187,151 @@ pub async fn count_target_replies_today(pool: &DbPool) -> Result<i64, StorageErr
     count_target_replies_today_for(pool, DEFAULT_ACCOUNT_ID).await
 }
 
+/// Set the tier of an active target account by username for a specific owner account.
+///
+/// Returns `false` when no active target has that username.
+pub async fn set_target_tier_for(
+    pool: &DbPool,
+    owner_account_id: &str,
+    username: &str,
+    tier: &str,
+) -> Result<bool, StorageError> {
+    let result = sqlx::query(
+        "UPDATE target_accounts SET tier = ? \
+         WHERE username = ? AND status = 'active' AND owner_account_id = ?",
+    )
+    .bind(tier)
+    .bind(username)
+    .bind(owner_account_id)
+    .execute(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+    Ok(result.rows_affected() > 0)
+}
+
+/// Set the tier of an active target account by username.
+pub async fn set_target_tier(
+    pool: &DbPool,
+    username: &str,
+    tier: &str,
+) -> Result<bool, StorageError> {
+    set_target_tier_for(pool, DEFAULT_ACCOUNT_ID, username, tier).await
+}
+
+/// Count replies sent or queued to one target account over the last 7 days
+/// for a specific owner account.
+pub async fn count_target_interactions_this_week_for(
+    pool: &DbPool,
+    owner_account_id: &str,
+    account_id: &str,
+) -> Result<i64, StorageError> {
+    let row: (i64,) = sqlx::query_as(
+        "SELECT COUNT(*) FROM target_reply_history \
+         WHERE account_id = ? AND created_at >= datetime('now', '-7 days') \
+           AND owner_account_id = ?",
+    )
+    .bind(account_id)
+    .bind(owner_account_id)
+    .fetch_one(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+    Ok(row.0)
+}
+
+/// Count replies sent or queued to one target account over the last 7 days.
+pub async fn count_target_interactions_this_week(
+    pool: &DbPool,
+    account_id: &str,
+) -> Result<i64, StorageError> {
+    count_target_interactions_this_week_for(pool, DEFAULT_ACCOUNT_ID, account_id).await
+}
+
+/// Record a reply sent (or queued, per `status`) to a target account for a
+/// specific owner account.
+pub async fn record_target_reply_history_for(
+    pool: &DbPool,
+    owner_account_id: &str,
+    account_id: &str,
+    tweet_id: &str,
+    status: &str,
+) -> Result<(), StorageError> {
+    sqlx::query(
+        "INSERT INTO target_reply_history (owner_account_id, account_id, tweet_id, status) \
+         VALUES (?, ?, ?, ?)",
+    )
+    .bind(owner_account_id)
+    .bind(account_id)
+    .bind(tweet_id)
+    .bind(status)
+    .execute(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+    Ok(())
+}
+
+/// Record a reply sent (or queued, per `status`) to a target account.
+pub async fn record_target_reply_history(
+    pool: &DbPool,
+    account_id: &str,
+    tweet_id: &str,
+    status: &str,
+) -> Result<(), StorageError> {
+    record_target_reply_history_for(pool, DEFAULT_ACCOUNT_ID, account_id, tweet_id, status).await
+}
+
+/// Get the newest tweet ID seen for a target account for a specific owner account.
+pub async fn get_target_since_id_for(
+    pool: &DbPool,
+    owner_account_id: &str,
+    account_id: &str,
+) -> Result<Option<String>, StorageError> {
+    let row: Option<(Option<String>,)> = sqlx::query_as(
+        "SELECT since_id FROM target_accounts WHERE account_id = ? AND owner_account_id = ?",
+    )
+    .bind(account_id)
+    .bind(owner_account_id)
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+    Ok(row.and_then(|r| r.0))
+}
+
+/// Get the newest tweet ID seen for a target account.
+pub async fn get_target_since_id(
+    pool: &DbPool,
+    account_id: &str,
+) -> Result<Option<String>, StorageError> {
+    get_target_since_id_for(pool, DEFAULT_ACCOUNT_ID, account_id).await
+}
+
+/// Store the newest tweet ID seen for a target account for a specific owner account.
+pub async fn set_target_since_id_for(
+    pool: &DbPool,
+    owner_account_id: &str,
+    account_id: &str,
+    since_id: &str,
+) -> Result<(), StorageError> {
+    sqlx::query(
+        "UPDATE target_accounts SET since_id = ? WHERE account_id = ? AND owner_account_id = ?",
+    )
+    .bind(since_id)
+    .bind(account_id)
+    .bind(owner_account_id)
+    .execute(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+    Ok(())
+}
+
+/// Store the newest tweet ID seen for a target account.
+pub async fn set_target_since_id(
+    pool: &DbPool,
+    account_id: &str,
+    since_id: &str,
+) -> Result<(), StorageError> {
+    set_target_since_id_for(pool, DEFAULT_ACCOUNT_ID, account_id, since_id).await
+}
+
 /// Check if a target tweet exists for a specific owner account.
 pub async fn target_tweet_exists_for(
     pool: &DbPool,
@@ -283,7 +439,7 @@ pub async fn get_target_account_by_username_for(
 ) -> Result<Option<TargetAccount>, StorageError> {
     let row: Option<TargetAccountRow> = sqlx::query_as(
         "SELECT account_id, username, followed_at, first_engagement_at, \
-             total_replies_sent, last_reply_at, status \
+             total_replies_sent, last_reply_at, status, tier \
              FROM target_accounts WHERE username = ? AND owner_account_id = ?",
     )
     .bind(username)
@@ -300,6 +456,7 @@ pub async fn get_target_account_by_username_for(
         total_replies_sent: r.4,
         last_reply_at: r.5,
         status: r.6,
+        tier: r.7,
     }))
 }
 
@@ -312,13 +469,17 @@ pub async fn get_target_account_by_username(
 }
 
 /// Deactivate a target account by username (soft delete) for a specific owner account.
+///
+/// The row is kept until the retention cleanup purges it, so
+/// [`restore_target_account_for`] can bring it back with its history.
 pub async fn deactivate_target_account_for(
     pool: &DbPool,
     owner_account_id: &str,
     username: &str,
 ) -> Result<bool, StorageError> {
     let result = sqlx::query(
-        "UPDATE target_accounts SET status = 'inactive' \
+        "UPDATE target_accounts SET status = 'inactive', \
+             deleted_at = strftime('%Y-%m-%dT%H:%M:%SZ', 'now') \
          WHERE username = ? AND status = 'active' AND owner_account_id = ?",
     )
     .bind(username)
@@ -337,6 +498,113 @@ pub async fn deactivate_target_account(
     deactivate_target_account_for(pool, DEFAULT_ACCOUNT_ID, username).await
 }
 
+/// Restore a deactivated target account by username for a specific owner account.
+///
+/// Returns `false` when there is no deactivated target with that username.
+pub async fn restore_target_account_for(
+    pool: &DbPool,
+    owner_account_id: &str,
+    username: &str,
+) -> Result<bool, StorageError> {
+    let result = sqlx::query(
+        "UPDATE target_accounts SET status = 'active', deleted_at = NULL \
+         WHERE username = ? AND status = 'inactive' AND owner_account_id = ?",
+    )
+    .bind(username)
+    .bind(owner_account_id)
+    .execute(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+    Ok(result.rows_affected() > 0)
+}
+
+/// Restore a deactivated target account by username.
+pub async fn restore_target_account(pool: &DbPool, username: &str) -> Result<bool, StorageError> {
+    restore_target_account_for(pool, DEFAULT_ACCOUNT_ID, username).await
+}
+
+/// Get deactivated target accounts for a specific owner account, most
+/// recently removed first.
+pub async fn get_deleted_target_accounts_for(
+    pool: &DbPool,
+    owner_account_id: &str,
+) -> Result<Vec<TargetAccount>, StorageError> {
+    let rows: Vec<TargetAccountRow> = sqlx::query_as(
+        "SELECT account_id, username, followed_at, first_engagement_at, \
+             total_replies_sent, last_reply_at, status, tier \
+             FROM target_accounts WHERE status = 'inactive' AND owner_account_id = ? \
+             ORDER BY deleted_at DESC",
+    )
+    .bind(owner_account_id)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+
+    Ok(rows
+        .into_iter()
+        .map(|r| TargetAccount {
+            account_id: r.0,
+            username: r.1,
+            followed_at: r.2,
+            first_engagement_at: r.3,
+            total_replies_sent: r.4,
+            last_reply_at: r.5,
+            status: r.6,
+            tier: r.7,
+        })
+        .collect())
+}
+
+/// Get deactivated target accounts, most recently removed first.
+pub async fn get_deleted_target_accounts(
+    pool: &DbPool,
+) -> Result<Vec<TargetAccount>, StorageError> {
+    get_deleted_target_accounts_for(pool, DEFAULT_ACCOUNT_ID).await
+}
+
+/// Permanently remove target accounts deactivated before `cutoff` for a
+/// specific owner account, with their tweets and reply history.
+pub(crate) async fn purge_deleted_target_accounts_for(
+    pool: &DbPool,
+    owner_account_id: &str,
+    cutoff: &str,
+) -> Result<u64, StorageError> {
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|e| StorageError::Query { source: e })?;
+
+    const PURGED: &str = "SELECT account_id FROM target_accounts \
+         WHERE status = 'inactive' AND owner_account_id = ? AND deleted_at < ?";
+
+    for child in ["target_tweets", "target_reply_history"] {
+        sqlx::query(&format!(
+            "DELETE FROM {child} WHERE account_id IN ({PURGED})"
+        ))
+        .bind(owner_account_id)
+        .bind(cutoff)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| StorageError::Query { source: e })?;
+    }
+
+    let deleted = sqlx::query(
+        "DELETE FROM target_accounts \
+         WHERE status = 'inactive' AND owner_account_id = ? AND deleted_at < ?",
+    )
+    .bind(owner_account_id)
+    .bind(cutoff)
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?
+    .rows_affected();
+
+    tx.commit()
+        .await
+        .map_err(|e| StorageError::Query { source: e })?;
+    Ok(deleted)
+}
+
 // --- Enriched queries for the dashboard ---
 
 /// A target account with today's interaction count.
@@ -349,6 +617,7 @@ pub struct EnrichedTargetAccount {
     pub total_replies_sent: i64,
     pub last_reply_at: Option<String>,
     pub status: String,
+    pub tier: String,
     pub interactions_today: i64,
 }
 
@@ -360,6 +629,7 @@ type EnrichedRow = (
     i64,
     Option<String>,
     String,
+    String,
     i64,
 );
 
@@ -370,7 +640,7 @@ pub async fn get_enriched_target_accounts_for(
 ) -> Result<Vec<EnrichedTargetAccount>, StorageError> {
     let rows: Vec<EnrichedRow> = sqlx::query_as(
         "SELECT ta.account_id, ta.username, ta.followed_at, ta.first_engagement_at, \
-                ta.total_replies_sent, ta.last_reply_at, ta.status, \
+                ta.total_replies_sent, ta.last_reply_at, ta.status, ta.tier, \
                 COALESCE(SUM(CASE WHEN tt.replied_to = 1 \
                     AND date(tt.discovered_at) = date('now') THEN 1 ELSE 0 END), 0) \
          FROM target_accounts ta \
@@ -393,7 +663,8 @@ pub async fn get_enriched_target_accounts_for(
             total_replies_sent: r.4,
             last_reply_at: r.5,
             status: r.6,
-            interactions_today: r.7,
+            tier: r.7,
+            interactions_today: r.8,
         })
         .collect())
 }
@@ -830,6 +1101,147 @@ mod tests {
         assert!((stats.best_reply_score.unwrap() - 90.0).abs() < 0.01);
     }
 
+    #[tokio::test]
+    async fn set_tier_carries_over_to_resolved_account() {
+        let pool = init_test_db().await.expect("init db");
+
+        // Placeholder row as created by the API (account_id = username).
+        upsert_target_account(&pool, "alice", "alice")
+            .await
+            .expect("upsert");
+        assert!(set_target_tier(&pool, "alice", "dream_100")
+            .await
+            .expect("set tier"));
+        assert!(!set_target_tier(&pool, "nobody", "dream_100")
+            .await
+            .expect("set tier"));
+
+        // The loop later resolves the real user ID.
+        upsert_target_account(&pool, "uid_alice", "alice")
+            .await
+            .expect("upsert");
+        let account = get_target_account(&pool, "uid_alice")
+            .await
+            .expect("get")
+            .expect("found");
+        assert_eq!(account.tier, "dream_100");
+
+        upsert_target_account(&pool, "uid_bob", "bob")
+            .await
+            .expect("upsert");
+        let bob = get_target_account(&pool, "uid_bob")
+            .await
+            .expect("get")
+            .expect("found");
+        assert_eq!(bob.tier, "general");
+    }
+
+    #[tokio::test]
+    async fn weekly_interactions_count_reply_history() {
+        let pool = init_test_db().await.expect("init db");
+
+        upsert_target_account(&pool, "acc_1", "alice")
+            .await
+            .expect("upsert");
+        record_target_reply_history(&pool, "acc_1", "tw_1", "sent")
+            .await
+            .expect("record");
+        record_target_reply_history(&pool, "acc_1", "tw_2", "queued")
+            .await
+            .expect("record");
+        record_target_reply_history(&pool, "acc_2", "tw_3", "sent")
+            .await
+            .expect("record");
+        // Older than a week: ignored.
+        sqlx::query(
+            "INSERT INTO target_reply_history (account_id, tweet_id, status, created_at) \
+             VALUES ('acc_1', 'tw_0', 'sent', datetime('now', '-8 days'))",
+        )
+        .execute(&pool)
+        .await
+        .expect("insert");
+
+        let count = count_target_interactions_this_week(&pool, "acc_1")
+            .await
+            .expect("count");
+        assert_eq!(count, 2);
+    }
+
+    #[tokio::test]
+    async fn since_id_round_trip() {
+        let pool = init_test_db().await.expect("init db");
+
+        upsert_target_account(&pool, "acc_1", "alice")
+            .await
+            .expect("upsert");
+        assert!(get_target_since_id(&pool, "acc_1")
+            .await
+            .expect("get")
+            .is_none());
+
+        set_target_since_id(&pool, "acc_1", "1900")
+            .await
+            .expect("set");
+        assert_eq!(
+            get_target_since_id(&pool, "acc_1").await.expect("get"),
+            Some("1900".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn removed_target_can_be_restored_until_purged() {
+        let pool = init_test_db().await.expect("init db");
+
+        upsert_target_account(&pool, "acc_1", "alice")
+            .await
+            .expect("upsert");
+        store_target_tweet(&pool, "tw1", "acc_1", "hello", "2026-01-01", 0, 0, 0.5)
+            .await
+            .expect("store");
+        assert!(deactivate_target_account(&pool, "alice")
+            .await
+            .expect("deactivate"));
+        assert!(get_active_target_accounts(&pool)
+            .await
+            .expect("active")
+            .is_empty());
+        assert_eq!(
+            get_deleted_target_accounts(&pool).await.expect("deleted")[0].username,
+            "alice"
+        );
+
+        assert!(restore_target_account(&pool, "alice")
+            .await
+            .expect("restore"));
+        assert!(!restore_target_account(&pool, "alice").await.expect("again"));
+        assert_eq!(
+            get_active_target_accounts(&pool)
+                .await
+                .expect("active")
+                .len(),
+            1
+        );
+
+        deactivate_target_account(&pool, "alice")
+            .await
+            .expect("deactivate");
+        let purged =
+            purge_deleted_target_accounts_for(&pool, DEFAULT_ACCOUNT_ID, "2020-01-01T00:00:00Z")
+                .await
+                .expect("purge");
+        assert_eq!(purged, 0, "recent removals are kept");
+        let purged =
+            purge_deleted_target_accounts_for(&pool, DEFAULT_ACCOUNT_ID, "2999-01-01T00:00:00Z")
+                .await
+                .expect("purge");
+        assert_eq!(purged, 1);
+        assert!(get_target_account(&pool, "acc_1")
+            .await
+            .expect("get")
+            .is_none());
+        assert!(!target_tweet_exists(&pool, "tw1").await.expect("exists"));
+    }
+
     #[tokio::test]
     async fn get_target_stats_returns_none_for_missing() {
         let pool = init_test_db().await.expect("init db");
@@ -0,0 +1,425 @@
+//! Per-position performance of posted threads.
+//!
+//! The analytics loop measures every tweet in a thread, not just the hook.
+//! Each row stores the tweet's metrics and its retention (impressions
+//! relative to the hook), so threads can be compared by role (hook, body,
+//! closing CTA) and by how quickly readers drop off.
+
+use super::accounts::DEFAULT_ACCOUNT_ID;
+use super::analytics_range::AnalyticsRange;
+use super::DbPool;
+use crate::error::StorageError;
+
+pub use tuitbot_types::analytics::{
+    PositionRetention, RolePerformance, ThreadInsight, ThreadPositionReport,
+};
+
+/// Tweets per role needed before a role is compared in insights.
+const MIN_ROLE_TWEETS: i64 = 3;
+
+/// A posted thread tweet awaiting measurement.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ThreadTweetRef {
+    pub thread_id: i64,
+    /// 0-indexed position in the thread.
+    pub position: i64,
+    pub tweet_id: String,
+}
+
+/// Thread tweets posted at least a day ago (and within the last week) that
+/// have not been measured, for a specific account.
+pub async fn get_tweets_needing_measurement_for(
+    pool: &DbPool,
+    account_id: &str,
+) -> Result<Vec<ThreadTweetRef>, StorageError> {
+    let rows: Vec<(i64, i64, String)> = sqlx::query_as(
+        "SELECT tt.thread_id, tt.position, tt.tweet_id \
+         FROM thread_tweets tt \
+         JOIN threads t ON t.id = tt.thread_id \
+         WHERE tt.account_id = ? AND t.status = 'sent' \
+           AND tt.tweet_id IS NOT NULL \
+           AND datetime(tt.created_at) <= datetime('now', '-23 hours') \
+           AND datetime(tt.created_at) >= datetime('now', '-7 days') \
+           AND NOT EXISTS ( \
+               SELECT 1 FROM thread_tweet_performance p WHERE p.tweet_id = tt.tweet_id \
+           ) \
+         ORDER BY tt.thread_id, tt.position",
+    )
+    .bind(account_id)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+
+    Ok(rows
+        .into_iter()
+        .map(|(thread_id, position, tweet_id)| ThreadTweetRef {
+            thread_id,
+            position,
+            tweet_id,
+        })
+        .collect())
+}
+
+/// Thread tweets posted at least a day ago that have not been measured.
+pub async fn get_tweets_needing_measurement(
+    pool: &DbPool,
+) -> Result<Vec<ThreadTweetRef>, StorageError> {
+    get_tweets_needing_measurement_for(pool, DEFAULT_ACCOUNT_ID).await
+}
+
+/// Store metrics for a thread tweet for a specific account and refresh the
+/// thread's retention curve.
+#[allow(clippy::too_many_arguments)]
+pub async fn upsert_tweet_performance_for(
+    pool: &DbPool,
+    account_id: &str,
+    tweet: &ThreadTweetRef,
+    likes: i64,
+    retweets: i64,
+    replies: i64,
+    impressions: i64,
+    score: f64,
+) -> Result<(), StorageError> {
+    sqlx::query(
+        "INSERT INTO thread_tweet_performance \
+         (tweet_id, account_id, thread_id, position, likes_received, retweets_received, \
+          replies_received, impressions, performance_score) \
+         VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?) \
+         ON CONFLICT(tweet_id) DO UPDATE SET \
+         likes_received = excluded.likes_received, \
+         retweets_received = excluded.retweets_received, \
+         replies_received = excluded.replies_received, \
+         impressions = excluded.impressions, \
+         performance_score = excluded.performance_score, \
+         measured_at = datetime('now')",
+    )
+    .bind(&tweet.tweet_id)
+    .bind(account_id)
+    .bind(tweet.thread_id)
+    .bind(tweet.position)
+    .bind(likes)
+    .bind(retweets)
+    .bind(replies)
+    .bind(impressions)
+    .bind(score)
+    .execute(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+
+    // Retention is relative to the hook, so it is only known once the hook
+    // has been measured.
+    sqlx::query(
+        "UPDATE thread_tweet_performance \
+         SET retention = ( \
+             SELECT CAST(thread_tweet_performance.impressions AS REAL) / MAX(h.impressions, 1) \
+             FROM thread_tweet_performance h \
+             WHERE h.thread_id = thread_tweet_performance.thread_id AND h.position = 0 \
+         ) \
+         WHERE account_id = ? AND thread_id = ?",
+    )
+    .bind(account_id)
+    .bind(tweet.thread_id)
+    .execute(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+    Ok(())
+}
+
+/// Store metrics for a thread tweet and refresh the thread's retention curve.
+pub async fn upsert_tweet_performance(
+    pool: &DbPool,
+    tweet: &ThreadTweetRef,
+    likes: i64,
+    retweets: i64,
+    replies: i64,
+    impressions: i64,
+    score: f64,
+) -> Result<(), StorageError> {
+    upsert_tweet_performance_for(
+        pool,
+        DEFAULT_ACCOUNT_ID,
+        tweet,
+        likes,
+        retweets,
+        replies,
+        impressions,
+        score,
+    )
+    .await
+}
+
+/// Role and position breakdown of threads posted in `range` for a specific account.
+///
+/// The first tweet is the hook. The last tweet of a thread with three or more
+/// tweets is the CTA. Everything else is body.
+pub async fn get_position_report_for(
+    pool: &DbPool,
+    account_id: &str,
+    range: &AnalyticsRange,
+) -> Result<ThreadPositionReport, StorageError> {
+    let (from, to) = range.bounds();
+
+    let (threads_measured,): (i64,) = sqlx::query_as(
+        "SELECT COUNT(DISTINCT p.thread_id) \
+         FROM thread_tweet_performance p JOIN threads t ON t.id = p.thread_id \
+         WHERE p.account_id = ?1 AND datetime(t.created_at) >= ?2 AND datetime(t.created_at) < ?3",
+    )
+    .bind(account_id)
+    .bind(&from)
+    .bind(&to)
+    .fetch_one(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+
+    let role_rows: Vec<(String, i64, f64, f64, f64)> = sqlx::query_as(
+        "SELECT CASE WHEN p.position = 0 THEN 'hook' \
+                     WHEN t.tweet_count >= 3 AND p.position = t.tweet_count - 1 THEN 'cta' \
+                     ELSE 'body' END AS role, \
+                COUNT(*), \
+                AVG(p.impressions), \
+                AVG(CAST(p.likes_received + p.retweets_received + p.replies_received AS REAL) \
+                    / MAX(p.impressions, 1)), \
+                AVG(p.performance_score) \
+         FROM thread_tweet_performance p JOIN threads t ON t.id = p.thread_id \
+         WHERE p.account_id = ?1 AND datetime(t.created_at) >= ?2 AND datetime(t.created_at) < ?3 \
+         GROUP BY role",
+    )
+    .bind(account_id)
+    .bind(&from)
+    .bind(&to)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+
+    let mut roles: Vec<RolePerformance> = role_rows
+        .into_iter()
+        .map(
+            |(role, tweets, avg_impressions, avg_engagement_rate, avg_performance)| {
+                RolePerformance {
+                    role,
+                    tweets,
+                    avg_impressions,
+                    avg_engagement_rate,
+                    avg_performance,
+                }
+            },
+        )
+        .collect();
+    roles.sort_by_key(|r| match r.role.as_str() {
+        "hook" => 0,
+        "body" => 1,
+        _ => 2,
+    });
+
+    let curve_rows: Vec<(i64, i64, f64, f64)> = sqlx::query_as(
+        "SELECT p.position, COUNT(*), AVG(p.impressions), COALESCE(AVG(p.retention), 0.0) \
+         FROM thread_tweet_performance p JOIN threads t ON t.id = p.thread_id \
+         WHERE p.account_id = ?1 AND datetime(t.created_at) >= ?2 AND datetime(t.created_at) < ?3 \
+         GROUP BY p.position \
+         ORDER BY p.position",
+    )
+    .bind(account_id)
+    .bind(&from)
+    .bind(&to)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+
+    let curve: Vec<PositionRetention> = curve_rows
+        .into_iter()
+        .map(
+            |(position, threads, avg_impressions, avg_retention)| PositionRetention {
+                position,
+                threads,
+                avg_impressions,
+                avg_retention,
+            },
+        )
+        .collect();
+
+    let insights = thread_insights(&roles, &curve);
+    Ok(ThreadPositionReport {
+        threads_measured,
+        roles,
+        curve,
+        insights,
+    })
+}
+
+/// Role and position breakdown of threads posted in `range`.
+pub async fn get_position_report(
+    pool: &DbPool,
+    range: &AnalyticsRange,
+) -> Result<ThreadPositionReport, StorageError> {
+    get_position_report_for(pool, DEFAULT_ACCOUNT_ID, range).await
+}
+
+/// Findings from the role breakdown and drop-off curve. Roles and positions
+/// with fewer than three measured tweets are not compared.
+pub fn thread_insights(
+    roles: &[RolePerformance],
+    curve: &[PositionRetention],
+) -> Vec<ThreadInsight> {
+    let role = |name: &str| {
+        roles
+            .iter()
+            .find(|r| r.role == name && r.tweets >= MIN_ROLE_TWEETS)
+    };
+    let mut insights = Vec::new();
+
+    if let (Some(hook), Some(body)) = (role("hook"), role("body")) {
+        if hook.avg_engagement_rate < body.avg_engagement_rate * 0.8 {
+            insights.push(ThreadInsight {
+                title: "Your hooks underperform relative to bodies".to_string(),
+                description: format!(
+                    "Hooks average {:.1}% engagement versus {:.1}% for body tweets. \
+                     Try opening with the strongest point from the body.",
+                    hook.avg_engagement_rate * 100.0,
+                    body.avg_engagement_rate * 100.0
+                ),
+            });
+        }
+    }
+
+    if let Some(second) = curve
+        .iter()
+        .find(|p| p.position == 1 && p.threads >= MIN_ROLE_TWEETS)
+    {
+        if second.avg_retention < 0.3 {
+            insights.push(ThreadInsight {
+                title: "Readers drop off right after the hook".to_string(),
+                description: format!(
+                    "Only {:.0}% of hook impressions reach the second tweet. \
+                     Make the hook promise something the thread delivers.",
+                    second.avg_retention * 100.0
+                ),
+            });
+        }
+    }
+
+    if let (Some(cta), Some(body)) = (role("cta"), role("body")) {
+        if cta.avg_engagement_rate < body.avg_engagement_rate * 0.5 {
+            insights.push(ThreadInsight {
+                title: "Closing CTAs get little engagement".to_string(),
+                description: format!(
+                    "CTAs average {:.1}% engagement versus {:.1}% for body tweets. \
+                     Try a question or a lighter ask.",
+                    cta.avg_engagement_rate * 100.0,
+                    body.avg_engagement_rate * 100.0
+                ),
+            });
+        }
+    }
+
+    insights
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::init_test_db;
+    use crate::storage::threads::{insert_thread, insert_thread_tweets, Thread, ThreadTweet};
+
+    async fn seed_thread(pool: &DbPool, root: &str, hours_ago: i64) -> i64 {
+        let created_at = (chrono::Utc::now() - chrono::Duration::hours(hours_ago))
+            .format("%Y-%m-%dT%H:%M:%SZ")
+            .to_string();
+        let thread_id = insert_thread(
+            pool,
+            &Thread {
+                id: 0,
+                topic: "Rust".to_string(),
+                tweet_count: 3,
+                root_tweet_id: Some(format!("{root}0")),
+                created_at: created_at.clone(),
+                status: "sent".to_string(),
+            },
+        )
+        .await
+        .expect("thread");
+        let tweets: Vec<ThreadTweet> = (0..3)
+            .map(|i| ThreadTweet {
+                id: 0,
+                thread_id,
+                position: i,
+                tweet_id: Some(format!("{root}{i}")),
+                content: format!("part {i}"),
+                created_at: created_at.clone(),
+            })
+            .collect();
+        insert_thread_tweets(pool, thread_id, &tweets)
+            .await
+            .expect("thread tweets");
+        thread_id
+    }
+
+    #[tokio::test]
+    async fn measures_positions_and_builds_curve() {
+        let pool = init_test_db().await.expect("init db");
+        seed_thread(&pool, "old", 30).await;
+        seed_thread(&pool, "new", 1).await;
+
+        let pending = get_tweets_needing_measurement(&pool).await.unwrap();
+        let ids: Vec<&str> = pending.iter().map(|t| t.tweet_id.as_str()).collect();
+        assert_eq!(ids, vec!["old0", "old1", "old2"]);
+
+        // Body measured before the hook gets its retention once the hook lands.
+        upsert_tweet_performance(&pool, &pending[1], 10, 0, 0, 400, 50.0)
+            .await
+            .unwrap();
+        upsert_tweet_performance(&pool, &pending[0], 5, 0, 0, 1000, 10.0)
+            .await
+            .unwrap();
+        upsert_tweet_performance(&pool, &pending[2], 1, 0, 0, 200, 5.0)
+            .await
+            .unwrap();
+        assert!(get_tweets_needing_measurement(&pool)
+            .await
+            .unwrap()
+            .is_empty());
+
+        let report = get_position_report(&pool, &AnalyticsRange::last_days(7))
+            .await
+            .unwrap();
+        assert_eq!(report.threads_measured, 1);
+        let roles: Vec<&str> = report.roles.iter().map(|r| r.role.as_str()).collect();
+        assert_eq!(roles, vec!["hook", "body", "cta"]);
+        assert!((report.roles[1].avg_engagement_rate - 0.025).abs() < 1e-9);
+        let retention: Vec<f64> = report.curve.iter().map(|p| p.avg_retention).collect();
+        assert_eq!(retention, vec![1.0, 0.4, 0.2]);
+    }
+
+    #[test]
+    fn insights_flag_weak_hooks_drop_off_and_ctas() {
+        let role = |name: &str, rate: f64| RolePerformance {
+            role: name.to_string(),
+            tweets: 5,
+            avg_impressions: 100.0,
+            avg_engagement_rate: rate,
+            avg_performance: 10.0,
+        };
+        let curve = vec![PositionRetention {
+            position: 1,
+            threads: 5,
+            avg_impressions: 20.0,
+            avg_retention: 0.2,
+        }];
+
+        let insights = thread_insights(
+            &[role("hook", 0.01), role("body", 0.03), role("cta", 0.005)],
+            &curve,
+        );
+        let titles: Vec<&str> = insights.iter().map(|i| i.title.as_str()).collect();
+        assert_eq!(
+            titles,
+            vec![
+                "Your hooks underperform relative to bodies",
+                "Readers drop off right after the hook",
+                "Closing CTAs get little engagement",
+            ]
+        );
+
+        let healthy = thread_insights(&[role("hook", 0.03), role("body", 0.03)], &[]);
+        assert!(healthy.is_empty());
+    }
+}
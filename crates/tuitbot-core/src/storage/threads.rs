@@ -4,9 +4,15 @@
 //! supporting the content and thread automation loops.
 
 use super::accounts::DEFAULT_ACCOUNT_ID;
+use super::pagination::{fetch_page, Conditions, Page, PageRequest};
 use super::DbPool;
 use crate::error::StorageError;
 
+/// Inspiration of a tweet whose topic came from the configured topic rotation.
+pub const INSPIRATION_CALENDAR: &str = "calendar";
+/// Inspiration of a tweet whose topic came from a trending discovery cluster.
+pub const INSPIRATION_TREND: &str = "trend";
+
 /// An educational tweet generated and posted by the agent.
 #[derive(Debug, Clone, sqlx::FromRow, serde::Serialize)]
 pub struct OriginalTweet {
@@ -26,6 +32,8 @@ pub struct OriginalTweet {
     pub status: String,
     /// Error details if failed.
     pub error_message: Option<String>,
+    /// Where the topic came from: `calendar` or `trend`.
+    pub inspiration: String,
 }
 
 /// A series of connected tweets posted as a thread.
@@ -70,8 +78,9 @@ pub async fn insert_original_tweet_for(
 ) -> Result<i64, StorageError> {
     let result = sqlx::query(
         "INSERT INTO original_tweets \
-         (account_id, tweet_id, content, topic, llm_provider, created_at, status, error_message) \
-         VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+         (account_id, tweet_id, content, topic, llm_provider, created_at, status, error_message, \
+          inspiration) \
+         VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)",
     )
     .bind(account_id)
     .bind(&tweet.tweet_id)
@@ -81,6 +90,7 @@ pub async fn insert_original_tweet_for(
     .bind(&tweet.created_at)
     .bind(&tweet.status)
     .bind(&tweet.error_message)
+    .bind(&tweet.inspiration)
     .execute(pool)
     .await
     .map_err(|e| StorageError::Query { source: e })?;
@@ -354,6 +364,50 @@ pub async fn get_recent_original_tweets_for(
     .map_err(|e| StorageError::Query { source: e })
 }
 
+/// Sortable fields for [`get_original_tweets_page_for`], as (field, column).
+pub const TWEET_SORT_FIELDS: &[(&str, &str)] = &[
+    ("created_at", "created_at"),
+    ("topic", "topic"),
+    ("status", "status"),
+];
+
+/// Filters for [`get_original_tweets_page_for`]; `None` matches everything.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TweetFilter<'a> {
+    pub status: Option<&'a str>,
+    pub topic: Option<&'a str>,
+    /// Posted at or after this ISO-8601 timestamp.
+    pub since: Option<&'a str>,
+    /// Posted before this ISO-8601 timestamp.
+    pub until: Option<&'a str>,
+}
+
+/// One page of original tweets for a specific account, newest first by
+/// default.
+pub async fn get_original_tweets_page_for(
+    pool: &DbPool,
+    account_id: &str,
+    filter: &TweetFilter<'_>,
+    page: &PageRequest,
+) -> Result<Page<OriginalTweet>, StorageError> {
+    let conditions = Conditions::new()
+        .eq("account_id", Some(account_id))
+        .eq("status", filter.status)
+        .eq("topic", filter.topic)
+        .since("created_at", filter.since)
+        .until("created_at", filter.until);
+    fetch_page(
+        pool,
+        "original_tweets",
+        "*",
+        &conditions,
+        page,
+        "ORDER BY created_at DESC",
+        |t: &OriginalTweet| t.id,
+    )
+    .await
+}
+
 /// Get the most recent original tweets, newest first.
 pub async fn get_recent_original_tweets(
     pool: &DbPool,
@@ -402,6 +456,7 @@ mod tests {
             created_at: now_iso(),
             status: "sent".to_string(),
             error_message: None,
+            inspiration: INSPIRATION_CALENDAR.to_string(),
         }
     }
 
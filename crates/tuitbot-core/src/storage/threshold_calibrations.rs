@@ -0,0 +1,388 @@
+//! Daily history of the auto-calibrated discovery score threshold.
+//!
+//! One row per UTC day records the effective threshold for that day, the
+//! threshold it replaced, and the previous day's reply volume that drove
+//! the change.
+
+use chrono::NaiveDate;
+
+use super::accounts::DEFAULT_ACCOUNT_ID;
+use super::DbPool;
+use crate::config::ScoringConfig;
+use crate::error::StorageError;
+use crate::scoring::calibration::next_threshold;
+
+/// Effective threshold for one UTC day.
+#[derive(Debug, Clone, sqlx::FromRow, serde::Serialize)]
+pub struct ThresholdCalibration {
+    /// UTC day (`YYYY-MM-DD`) the threshold applies to.
+    pub day: String,
+    /// Effective threshold before this calibration.
+    pub previous_threshold: i64,
+    /// Effective threshold for `day`.
+    pub threshold: i64,
+    /// Discovery replies on the previous day.
+    pub replies: i64,
+    /// Target replies per day at calibration time.
+    pub target_replies: i64,
+    /// ISO-8601 UTC timestamp.
+    pub created_at: String,
+}
+
+/// Record the effective threshold for `day` for a specific account.
+///
+/// Returns `false` if the day was already calibrated (the first writer wins,
+/// so concurrent `run` and `tick` processes agree on one value).
+pub async fn insert_calibration_for(
+    pool: &DbPool,
+    account_id: &str,
+    day: &str,
+    previous_threshold: u32,
+    threshold: u32,
+    replies: u32,
+    target_replies: u32,
+) -> Result<bool, StorageError> {
+    let result = sqlx::query(
+        "INSERT OR IGNORE INTO threshold_calibrations \
+         (account_id, day, previous_threshold, threshold, replies, target_replies) \
+         VALUES (?, ?, ?, ?, ?, ?)",
+    )
+    .bind(account_id)
+    .bind(day)
+    .bind(previous_threshold)
+    .bind(threshold)
+    .bind(replies)
+    .bind(target_replies)
+    .execute(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+    Ok(result.rows_affected() > 0)
+}
+
+/// Record the effective threshold for `day`.
+pub async fn insert_calibration(
+    pool: &DbPool,
+    day: &str,
+    previous_threshold: u32,
+    threshold: u32,
+    replies: u32,
+    target_replies: u32,
+) -> Result<bool, StorageError> {
+    insert_calibration_for(
+        pool,
+        DEFAULT_ACCOUNT_ID,
+        day,
+        previous_threshold,
+        threshold,
+        replies,
+        target_replies,
+    )
+    .await
+}
+
+/// Fetch the calibration for `day` for a specific account.
+pub async fn get_calibration_for(
+    pool: &DbPool,
+    account_id: &str,
+    day: &str,
+) -> Result<Option<ThresholdCalibration>, StorageError> {
+    sqlx::query_as::<_, ThresholdCalibration>(
+        "SELECT day, previous_threshold, threshold, replies, target_replies, created_at \
+         FROM threshold_calibrations WHERE account_id = ? AND day = ?",
+    )
+    .bind(account_id)
+    .bind(day)
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })
+}
+
+/// Fetch the calibration for `day`.
+pub async fn get_calibration(
+    pool: &DbPool,
+    day: &str,
+) -> Result<Option<ThresholdCalibration>, StorageError> {
+    get_calibration_for(pool, DEFAULT_ACCOUNT_ID, day).await
+}
+
+/// Fetch the most recent calibration for a specific account.
+pub async fn get_latest_calibration_for(
+    pool: &DbPool,
+    account_id: &str,
+) -> Result<Option<ThresholdCalibration>, StorageError> {
+    sqlx::query_as::<_, ThresholdCalibration>(
+        "SELECT day, previous_threshold, threshold, replies, target_replies, created_at \
+         FROM threshold_calibrations WHERE account_id = ? ORDER BY day DESC LIMIT 1",
+    )
+    .bind(account_id)
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })
+}
+
+/// Fetch the most recent calibration.
+pub async fn get_latest_calibration(
+    pool: &DbPool,
+) -> Result<Option<ThresholdCalibration>, StorageError> {
+    get_latest_calibration_for(pool, DEFAULT_ACCOUNT_ID).await
+}
+
+/// Count discovery replies and quotes (sent or dry-run) logged on a UTC `day`
+/// for a specific account.
+pub async fn count_discovery_replies_on_for(
+    pool: &DbPool,
+    account_id: &str,
+    day: &str,
+) -> Result<u32, StorageError> {
+    let row: (i64,) = sqlx::query_as(
+        "SELECT COUNT(*) FROM action_log \
+         WHERE account_id = ? AND substr(created_at, 1, 10) = ? \
+           AND action_type IN ('discovery_reply', 'discovery_quote') \
+           AND status IN ('success', 'dry_run')",
+    )
+    .bind(account_id)
+    .bind(day)
+    .fetch_one(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+    Ok(row.0.max(0) as u32)
+}
+
+/// Count discovery replies and quotes (sent or dry-run) logged on a UTC `day`.
+pub async fn count_discovery_replies_on(pool: &DbPool, day: &str) -> Result<u32, StorageError> {
+    count_discovery_replies_on_for(pool, DEFAULT_ACCOUNT_ID, day).await
+}
+
+/// Effective threshold without calibrating: the latest calibrated value when
+/// calibration is enabled, otherwise the configured threshold.
+pub async fn current_threshold_for(
+    pool: &DbPool,
+    account_id: &str,
+    config: &ScoringConfig,
+) -> Result<u32, StorageError> {
+    if !config.calibration.enabled {
+        return Ok(config.threshold);
+    }
+    let latest = get_latest_calibration_for(pool, account_id).await?;
+    Ok(latest.map_or(config.threshold, |c| c.threshold.max(0) as u32))
+}
+
+/// Effective threshold without calibrating.
+pub async fn current_threshold(pool: &DbPool, config: &ScoringConfig) -> Result<u32, StorageError> {
+    current_threshold_for(pool, DEFAULT_ACCOUNT_ID, config).await
+}
+
+/// Ensure `today` has a calibrated threshold for a specific account and return it.
+///
+/// The first call on a new day steps the threshold from yesterday's value
+/// based on yesterday's discovery replies. If the agent did not run
+/// yesterday, the last value carries over unchanged (a day offline is not
+/// a quiet day). Each change is written to the action log.
+pub async fn calibrate_for(
+    pool: &DbPool,
+    account_id: &str,
+    config: &ScoringConfig,
+    today: NaiveDate,
+) -> Result<ThresholdCalibration, StorageError> {
+    let day = today.to_string();
+    if let Some(existing) = get_calibration_for(pool, account_id, &day).await? {
+        return Ok(existing);
+    }
+
+    let calibration = &config.calibration;
+    let bounds = |t: u32| {
+        t.clamp(
+            calibration.floor,
+            calibration.ceiling.max(calibration.floor),
+        )
+    };
+    let yesterday = today.pred_opt().unwrap_or(today).to_string();
+
+    let (previous, threshold, replies) = match get_latest_calibration_for(pool, account_id).await? {
+        Some(latest) if latest.day == yesterday => {
+            let previous = latest.threshold.max(0) as u32;
+            let replies = count_discovery_replies_on_for(pool, account_id, &yesterday).await?;
+            (
+                previous,
+                next_threshold(previous, replies, calibration),
+                replies,
+            )
+        }
+        Some(latest) => {
+            let previous = latest.threshold.max(0) as u32;
+            (previous, bounds(previous), 0)
+        }
+        None => (config.threshold, bounds(config.threshold), 0),
+    };
+
+    let inserted = insert_calibration_for(
+        pool,
+        account_id,
+        &day,
+        previous,
+        threshold,
+        replies,
+        calibration.target_replies_per_day,
+    )
+    .await?;
+
+    if inserted && threshold != previous {
+        let message = format!(
+            "Score threshold {previous} -> {threshold} ({replies} discovery replies yesterday, target {})",
+            calibration.target_replies_per_day
+        );
+        tracing::info!(
+            previous,
+            threshold,
+            replies,
+            target = calibration.target_replies_per_day,
+            "Calibrated score threshold"
+        );
+        super::action_log::log_action_for(
+            pool,
+            account_id,
+            "threshold_calibration",
+            "success",
+            Some(&message),
+            None,
+        )
+        .await?;
+    }
+
+    get_calibration_for(pool, account_id, &day)
+        .await?
+        .ok_or_else(|| StorageError::Query {
+            source: sqlx::Error::RowNotFound,
+        })
+}
+
+/// Ensure `today` has a calibrated threshold and return it.
+pub async fn calibrate(
+    pool: &DbPool,
+    config: &ScoringConfig,
+    today: NaiveDate,
+) -> Result<ThresholdCalibration, StorageError> {
+    calibrate_for(pool, DEFAULT_ACCOUNT_ID, config, today).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::action_log::log_action;
+    use crate::storage::init_test_db;
+
+    #[tokio::test]
+    async fn first_calibration_per_day_wins() {
+        let pool = init_test_db().await.expect("init db");
+        assert!(get_latest_calibration(&pool).await.unwrap().is_none());
+
+        assert!(insert_calibration(&pool, "2026-03-01", 60, 60, 0, 5)
+            .await
+            .unwrap());
+        assert!(insert_calibration(&pool, "2026-03-02", 60, 55, 1, 5)
+            .await
+            .unwrap());
+        assert!(!insert_calibration(&pool, "2026-03-02", 60, 65, 9, 5)
+            .await
+            .unwrap());
+
+        let latest = get_latest_calibration(&pool).await.unwrap().unwrap();
+        assert_eq!(latest.day, "2026-03-02");
+        assert_eq!(latest.threshold, 55);
+        assert_eq!(latest.previous_threshold, 60);
+        assert!(get_calibration(&pool, "2026-02-28")
+            .await
+            .unwrap()
+            .is_none());
+    }
+
+    fn calibrated_config() -> ScoringConfig {
+        let mut config = ScoringConfig {
+            threshold: 60,
+            ..Default::default()
+        };
+        config.calibration.enabled = true;
+        config.calibration.target_replies_per_day = 5;
+        config
+    }
+
+    fn day(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    #[tokio::test]
+    async fn quiet_yesterday_lowers_threshold_once() {
+        let pool = init_test_db().await.expect("init db");
+        let config = calibrated_config();
+
+        let first = calibrate(&pool, &config, day("2026-03-01")).await.unwrap();
+        assert_eq!(first.threshold, 60);
+
+        // No discovery replies logged on 2026-03-01.
+        let second = calibrate(&pool, &config, day("2026-03-02")).await.unwrap();
+        assert_eq!(second.previous_threshold, 60);
+        assert_eq!(second.threshold, 55);
+        assert_eq!(second.replies, 0);
+
+        let again = calibrate(&pool, &config, day("2026-03-02")).await.unwrap();
+        assert_eq!(again.threshold, 55);
+        assert_eq!(current_threshold(&pool, &config).await.unwrap(), 55);
+
+        let logged: (i64,) = sqlx::query_as(
+            "SELECT COUNT(*) FROM action_log WHERE action_type = 'threshold_calibration'",
+        )
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        assert_eq!(logged.0, 1);
+    }
+
+    #[tokio::test]
+    async fn gap_day_carries_threshold_over() {
+        let pool = init_test_db().await.expect("init db");
+        let config = calibrated_config();
+        insert_calibration(&pool, "2026-03-01", 60, 70, 9, 5)
+            .await
+            .unwrap();
+
+        let next = calibrate(&pool, &config, day("2026-03-05")).await.unwrap();
+        assert_eq!(next.threshold, 70);
+    }
+
+    #[tokio::test]
+    async fn disabled_calibration_uses_config_threshold() {
+        let pool = init_test_db().await.expect("init db");
+        let mut config = calibrated_config();
+        insert_calibration(&pool, "2026-03-01", 60, 70, 9, 5)
+            .await
+            .unwrap();
+        config.calibration.enabled = false;
+        assert_eq!(current_threshold(&pool, &config).await.unwrap(), 60);
+    }
+
+    #[tokio::test]
+    async fn counts_sent_and_dry_run_discovery_replies() {
+        let pool = init_test_db().await.expect("init db");
+        log_action(&pool, "discovery_reply", "success", None, None)
+            .await
+            .unwrap();
+        log_action(&pool, "discovery_quote", "dry_run", None, None)
+            .await
+            .unwrap();
+        log_action(&pool, "discovery_reply", "failure", None, None)
+            .await
+            .unwrap();
+        log_action(&pool, "mention_reply", "success", None, None)
+            .await
+            .unwrap();
+
+        let today = chrono::Utc::now().format("%Y-%m-%d").to_string();
+        assert_eq!(count_discovery_replies_on(&pool, &today).await.unwrap(), 2);
+        assert_eq!(
+            count_discovery_replies_on(&pool, "2000-01-01")
+                .await
+                .unwrap(),
+            0
+        );
+    }
+}
@@ -24,6 +24,8 @@ pub struct DiscoveredTweet {
     pub retweet_count: i64,
     /// Replies at discovery time.
     pub reply_count: i64,
+    /// Quote tweets at discovery time.
+    pub quote_count: i64,
     /// Impressions if available.
     pub impression_count: Option<i64>,
     /// Computed relevance score (0-100).
@@ -45,9 +47,9 @@ pub async fn insert_discovered_tweet_for(
     sqlx::query(
         "INSERT OR IGNORE INTO discovered_tweets \
          (account_id, id, author_id, author_username, content, like_count, retweet_count, \
-          reply_count, impression_count, relevance_score, matched_keyword, \
+          reply_count, quote_count, impression_count, relevance_score, matched_keyword, \
           discovered_at, replied_to) \
-         VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+         VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
     )
     .bind(account_id)
     .bind(&tweet.id)
@@ -57,6 +59,7 @@ pub async fn insert_discovered_tweet_for(
     .bind(tweet.like_count)
     .bind(tweet.retweet_count)
     .bind(tweet.reply_count)
+    .bind(tweet.quote_count)
     .bind(tweet.impression_count)
     .bind(tweet.relevance_score)
     .bind(&tweet.matched_keyword)
@@ -282,6 +285,36 @@ pub async fn tweet_exists(pool: &DbPool, tweet_id: &str) -> Result<bool, Storage
     tweet_exists_for(pool, DEFAULT_ACCOUNT_ID, tweet_id).await
 }
 
+/// Total quote tweets and likes on an author's tweets discovered since `since`
+/// for a specific account.
+pub async fn author_quote_totals_for(
+    pool: &DbPool,
+    account_id: &str,
+    author_id: &str,
+    since: &str,
+) -> Result<(i64, i64), StorageError> {
+    sqlx::query_as(
+        "SELECT COALESCE(SUM(quote_count), 0), COALESCE(SUM(like_count), 0) \
+         FROM discovered_tweets \
+         WHERE account_id = ? AND author_id = ? AND discovered_at >= ?",
+    )
+    .bind(account_id)
+    .bind(author_id)
+    .bind(since)
+    .fetch_one(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })
+}
+
+/// Total quote tweets and likes on an author's tweets discovered since `since`.
+pub async fn author_quote_totals(
+    pool: &DbPool,
+    author_id: &str,
+    since: &str,
+) -> Result<(i64, i64), StorageError> {
+    author_quote_totals_for(pool, DEFAULT_ACCOUNT_ID, author_id, since).await
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -296,6 +329,7 @@ mod tests {
             like_count: 10,
             retweet_count: 2,
             reply_count: 1,
+            quote_count: 0,
             impression_count: Some(500),
             relevance_score: score,
             matched_keyword: Some("rust".to_string()),
@@ -370,4 +404,29 @@ mod tests {
         insert_discovered_tweet(&pool, &tweet).await.expect("ins");
         assert!(tweet_exists(&pool, "exists_test").await.expect("check"));
     }
+
+    #[tokio::test]
+    async fn author_quote_totals_sum_recent_tweets() {
+        let pool = init_test_db().await.expect("init db");
+        let mut first = sample_tweet("q1", None);
+        first.quote_count = 12;
+        let mut second = sample_tweet("q2", None);
+        second.quote_count = 3;
+        second.like_count = 5;
+        let mut old = sample_tweet("q3", None);
+        old.quote_count = 100;
+        old.discovered_at = "2026-01-01T00:00:00Z".to_string();
+        for tweet in [&first, &second, &old] {
+            insert_discovered_tweet(&pool, tweet).await.expect("ins");
+        }
+
+        let totals = author_quote_totals(&pool, "user123", "2026-02-01T00:00:00Z")
+            .await
+            .expect("totals");
+        assert_eq!(totals, (15, 15));
+        let none = author_quote_totals(&pool, "nobody", "2026-02-01T00:00:00Z")
+            .await
+            .expect("totals");
+        assert_eq!(none, (0, 0));
+    }
 }
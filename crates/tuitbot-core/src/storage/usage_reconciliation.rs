@@ -0,0 +1,168 @@
+//! History of X usage reconciliations.
+//!
+//! Each row compares the posts X reports the project consumed with the
+//! posts the local `x_api_usage` ledger accounts for over the same window,
+//! and records the pacing budget scale derived from the gap.
+
+use super::accounts::DEFAULT_ACCOUNT_ID;
+use super::DbPool;
+use crate::error::StorageError;
+
+/// One reconciliation run.
+#[derive(Debug, Clone, PartialEq, sqlx::FromRow, serde::Serialize)]
+pub struct UsageReconciliation {
+    pub id: i64,
+    /// First UTC day (`YYYY-MM-DD`) of the compared window.
+    pub window_start: String,
+    /// Day after the last compared day (exclusive).
+    pub window_end: String,
+    /// Posts consumed according to X.
+    pub official_usage: i64,
+    /// Posts consumed according to the local ledger.
+    pub ledger_usage: i64,
+    /// `official_usage - ledger_usage`; positive when the ledger undercounts.
+    pub drift: i64,
+    /// Factor applied to the pacing budgets until the next run.
+    pub pacing_scale: f64,
+    /// Posts consumed in the current billing cycle, if reported.
+    pub project_usage: Option<i64>,
+    /// Posts allowed per billing cycle, if reported.
+    pub project_cap: Option<i64>,
+    /// ISO-8601 UTC timestamp.
+    pub created_at: String,
+}
+
+/// Values for a new reconciliation row.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NewUsageReconciliation {
+    pub window_start: String,
+    pub window_end: String,
+    pub official_usage: u64,
+    pub ledger_usage: u64,
+    pub pacing_scale: f64,
+    pub project_usage: Option<u64>,
+    pub project_cap: Option<u64>,
+}
+
+/// Record a reconciliation run for a specific account.
+pub async fn insert_reconciliation_for(
+    pool: &DbPool,
+    account_id: &str,
+    row: &NewUsageReconciliation,
+) -> Result<i64, StorageError> {
+    let drift = row.official_usage as i64 - row.ledger_usage as i64;
+    let result = sqlx::query(
+        "INSERT INTO usage_reconciliations \
+         (account_id, window_start, window_end, official_usage, ledger_usage, drift, \
+          pacing_scale, project_usage, project_cap) \
+         VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)",
+    )
+    .bind(account_id)
+    .bind(&row.window_start)
+    .bind(&row.window_end)
+    .bind(row.official_usage as i64)
+    .bind(row.ledger_usage as i64)
+    .bind(drift)
+    .bind(row.pacing_scale)
+    .bind(row.project_usage.map(|v| v as i64))
+    .bind(row.project_cap.map(|v| v as i64))
+    .execute(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+    Ok(result.last_insert_rowid())
+}
+
+/// Record a reconciliation run.
+pub async fn insert_reconciliation(
+    pool: &DbPool,
+    row: &NewUsageReconciliation,
+) -> Result<i64, StorageError> {
+    insert_reconciliation_for(pool, DEFAULT_ACCOUNT_ID, row).await
+}
+
+/// Most recent reconciliation runs for a specific account, newest first.
+pub async fn list_recent_for(
+    pool: &DbPool,
+    account_id: &str,
+    limit: u32,
+) -> Result<Vec<UsageReconciliation>, StorageError> {
+    sqlx::query_as::<_, UsageReconciliation>(
+        "SELECT id, window_start, window_end, official_usage, ledger_usage, drift, \
+         pacing_scale, project_usage, project_cap, created_at \
+         FROM usage_reconciliations WHERE account_id = ? \
+         ORDER BY id DESC LIMIT ?",
+    )
+    .bind(account_id)
+    .bind(limit)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })
+}
+
+/// Most recent reconciliation runs, newest first.
+pub async fn list_recent(
+    pool: &DbPool,
+    limit: u32,
+) -> Result<Vec<UsageReconciliation>, StorageError> {
+    list_recent_for(pool, DEFAULT_ACCOUNT_ID, limit).await
+}
+
+/// Pacing scale from the latest run for a specific account (1.0 if none).
+pub async fn current_pacing_scale_for(
+    pool: &DbPool,
+    account_id: &str,
+) -> Result<f64, StorageError> {
+    let row: Option<(f64,)> = sqlx::query_as(
+        "SELECT pacing_scale FROM usage_reconciliations WHERE account_id = ? \
+         ORDER BY id DESC LIMIT 1",
+    )
+    .bind(account_id)
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+    Ok(row.map_or(1.0, |(scale,)| scale))
+}
+
+/// Pacing scale from the latest run (1.0 if none).
+pub async fn current_pacing_scale(pool: &DbPool) -> Result<f64, StorageError> {
+    current_pacing_scale_for(pool, DEFAULT_ACCOUNT_ID).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::init_test_db;
+
+    fn run(official: u64, ledger: u64, scale: f64) -> NewUsageReconciliation {
+        NewUsageReconciliation {
+            window_start: "2026-03-01".to_string(),
+            window_end: "2026-03-08".to_string(),
+            official_usage: official,
+            ledger_usage: ledger,
+            pacing_scale: scale,
+            project_usage: Some(official),
+            project_cap: Some(10_000),
+        }
+    }
+
+    #[tokio::test]
+    async fn latest_run_sets_pacing_scale() {
+        let pool = init_test_db().await.expect("init db");
+        assert_eq!(current_pacing_scale(&pool).await.unwrap(), 1.0);
+
+        insert_reconciliation(&pool, &run(100, 100, 1.0))
+            .await
+            .unwrap();
+        insert_reconciliation(&pool, &run(400, 300, 0.75))
+            .await
+            .unwrap();
+        assert_eq!(current_pacing_scale(&pool).await.unwrap(), 0.75);
+        assert_eq!(current_pacing_scale_for(&pool, "other").await.unwrap(), 1.0);
+
+        let recent = list_recent(&pool, 10).await.unwrap();
+        assert_eq!(recent.len(), 2);
+        assert_eq!(recent[0].drift, 100);
+        assert_eq!(recent[0].project_cap, Some(10_000));
+        assert_eq!(recent[1].drift, 0);
+    }
+}
@@ -0,0 +1,112 @@
+//! Storage for account warm-up progress.
+//!
+//! One row per account records when the current warm-up started and, once
+//! the schedule has run its course, when it completed.
+
+use super::accounts::DEFAULT_ACCOUNT_ID;
+use super::DbPool;
+use crate::error::StorageError;
+
+/// Warm-up progress for an account.
+#[derive(Debug, Clone, PartialEq, sqlx::FromRow, serde::Serialize)]
+pub struct WarmupState {
+    pub started_at: String,
+    pub completed_at: Option<String>,
+}
+
+/// Get warm-up progress for a specific account, if a warm-up was ever started.
+pub async fn get_state_for(
+    pool: &DbPool,
+    account_id: &str,
+) -> Result<Option<WarmupState>, StorageError> {
+    sqlx::query_as::<_, WarmupState>(
+        "SELECT started_at, completed_at FROM account_warmup WHERE account_id = ?",
+    )
+    .bind(account_id)
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })
+}
+
+/// Get warm-up progress for the default account.
+pub async fn get_state(pool: &DbPool) -> Result<Option<WarmupState>, StorageError> {
+    get_state_for(pool, DEFAULT_ACCOUNT_ID).await
+}
+
+/// Start (or restart) a warm-up now for a specific account.
+pub async fn start_for(pool: &DbPool, account_id: &str) -> Result<WarmupState, StorageError> {
+    sqlx::query_as::<_, WarmupState>(
+        "INSERT INTO account_warmup (account_id) VALUES (?) \
+         ON CONFLICT(account_id) DO UPDATE SET \
+             started_at = strftime('%Y-%m-%dT%H:%M:%SZ', 'now'), completed_at = NULL \
+         RETURNING started_at, completed_at",
+    )
+    .bind(account_id)
+    .fetch_one(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })
+}
+
+/// Start (or restart) a warm-up now for the default account.
+pub async fn start(pool: &DbPool) -> Result<WarmupState, StorageError> {
+    start_for(pool, DEFAULT_ACCOUNT_ID).await
+}
+
+/// Mark the warm-up of a specific account as completed.
+pub async fn mark_completed_for(pool: &DbPool, account_id: &str) -> Result<(), StorageError> {
+    sqlx::query(
+        "UPDATE account_warmup SET completed_at = strftime('%Y-%m-%dT%H:%M:%SZ', 'now') \
+         WHERE account_id = ? AND completed_at IS NULL",
+    )
+    .bind(account_id)
+    .execute(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+    Ok(())
+}
+
+/// When a specific account last posted a reply, tweet, or thread.
+pub async fn last_post_at_for(
+    pool: &DbPool,
+    account_id: &str,
+) -> Result<Option<String>, StorageError> {
+    let row: (Option<String>,) = sqlx::query_as(
+        "SELECT MAX(created_at) FROM ( \
+             SELECT MAX(created_at) AS created_at FROM replies_sent WHERE account_id = ?1 \
+             UNION ALL \
+             SELECT MAX(created_at) FROM original_tweets WHERE account_id = ?1 \
+             UNION ALL \
+             SELECT MAX(created_at) FROM threads WHERE account_id = ?1)",
+    )
+    .bind(account_id)
+    .fetch_one(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+    Ok(row.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::init_test_db;
+
+    #[tokio::test]
+    async fn restart_clears_completion() {
+        let pool = init_test_db().await.expect("init db");
+        assert!(get_state(&pool).await.expect("get").is_none());
+
+        start(&pool).await.expect("start");
+        mark_completed_for(&pool, DEFAULT_ACCOUNT_ID)
+            .await
+            .expect("complete");
+        let state = get_state(&pool).await.expect("get").expect("state");
+        assert!(state.completed_at.is_some());
+
+        let restarted = start(&pool).await.expect("restart");
+        assert!(restarted.completed_at.is_none());
+        assert!(last_post_at_for(&pool, DEFAULT_ACCOUNT_ID)
+            .await
+            .expect("last post")
+            .is_none());
+    }
+}
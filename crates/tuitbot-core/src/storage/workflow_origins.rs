@@ -0,0 +1,209 @@
+//! Workflow origin tracking for rows created by MCP tools.
+//!
+//! MCP tool calls carry a workflow context (agent, session, goal). Rows
+//! they create — approval items, scheduled content, mutation audit records —
+//! get a `workflow_origins` entry so operators can later answer "which agent
+//! session queued this reply?" without widening every table.
+
+use serde::{Deserialize, Serialize};
+
+use super::accounts::DEFAULT_ACCOUNT_ID;
+use super::DbPool;
+use crate::error::StorageError;
+
+/// Record type for approval queue items.
+pub const APPROVAL_QUEUE: &str = "approval_queue";
+/// Record type for scheduled content and drafts.
+pub const SCHEDULED_CONTENT: &str = "scheduled_content";
+/// Record type for mutation audit entries.
+pub const MUTATION_AUDIT: &str = "mutation_audit";
+
+/// The agent, session, and goal a tool call was made under.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WorkflowOrigin {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub agent: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub session_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub goal: Option<String>,
+}
+
+impl WorkflowOrigin {
+    /// Whether no origin field is set.
+    pub fn is_empty(&self) -> bool {
+        self.agent.is_none() && self.session_id.is_none() && self.goal.is_none()
+    }
+}
+
+/// A stored origin linked to a record.
+#[derive(Debug, Clone, sqlx::FromRow, Serialize)]
+pub struct OriginRecord {
+    pub id: i64,
+    pub record_type: String,
+    pub record_id: i64,
+    pub agent: Option<String>,
+    pub session_id: Option<String>,
+    pub goal: Option<String>,
+    pub created_at: String,
+}
+
+/// Filters for listing origins. `None` fields match everything.
+#[derive(Debug, Clone, Default)]
+pub struct OriginFilter {
+    pub session_id: Option<String>,
+    pub agent: Option<String>,
+    pub record_type: Option<String>,
+    pub limit: u32,
+}
+
+/// Link `origin` to a record for a specific account. Empty origins are not stored.
+pub async fn record_origin_for(
+    pool: &DbPool,
+    account_id: &str,
+    record_type: &str,
+    record_id: i64,
+    origin: &WorkflowOrigin,
+) -> Result<(), StorageError> {
+    if origin.is_empty() {
+        return Ok(());
+    }
+    sqlx::query(
+        "INSERT INTO workflow_origins \
+         (account_id, record_type, record_id, agent, session_id, goal) \
+         VALUES (?, ?, ?, ?, ?, ?)",
+    )
+    .bind(account_id)
+    .bind(record_type)
+    .bind(record_id)
+    .bind(&origin.agent)
+    .bind(&origin.session_id)
+    .bind(&origin.goal)
+    .execute(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+    Ok(())
+}
+
+/// Link `origin` to a record. Empty origins are not stored.
+pub async fn record_origin(
+    pool: &DbPool,
+    record_type: &str,
+    record_id: i64,
+    origin: &WorkflowOrigin,
+) -> Result<(), StorageError> {
+    record_origin_for(pool, DEFAULT_ACCOUNT_ID, record_type, record_id, origin).await
+}
+
+/// Get the origin of a record, if one was stored.
+pub async fn get_origin(
+    pool: &DbPool,
+    record_type: &str,
+    record_id: i64,
+) -> Result<Option<OriginRecord>, StorageError> {
+    sqlx::query_as::<_, OriginRecord>(
+        "SELECT id, record_type, record_id, agent, session_id, goal, created_at \
+         FROM workflow_origins WHERE record_type = ? AND record_id = ? \
+         ORDER BY id DESC LIMIT 1",
+    )
+    .bind(record_type)
+    .bind(record_id)
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })
+}
+
+/// List origins matching `filter` for a specific account, newest first.
+pub async fn list_origins_for(
+    pool: &DbPool,
+    account_id: &str,
+    filter: &OriginFilter,
+) -> Result<Vec<OriginRecord>, StorageError> {
+    sqlx::query_as::<_, OriginRecord>(
+        "SELECT id, record_type, record_id, agent, session_id, goal, created_at \
+         FROM workflow_origins \
+         WHERE account_id = ? \
+           AND (? IS NULL OR session_id = ?) \
+           AND (? IS NULL OR agent = ?) \
+           AND (? IS NULL OR record_type = ?) \
+         ORDER BY id DESC LIMIT ?",
+    )
+    .bind(account_id)
+    .bind(&filter.session_id)
+    .bind(&filter.session_id)
+    .bind(&filter.agent)
+    .bind(&filter.agent)
+    .bind(&filter.record_type)
+    .bind(&filter.record_type)
+    .bind(i64::from(filter.limit))
+    .fetch_all(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })
+}
+
+/// List origins matching `filter`, newest first.
+pub async fn list_origins(
+    pool: &DbPool,
+    filter: &OriginFilter,
+) -> Result<Vec<OriginRecord>, StorageError> {
+    list_origins_for(pool, DEFAULT_ACCOUNT_ID, filter).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::init_test_db;
+
+    fn origin(session: &str) -> WorkflowOrigin {
+        WorkflowOrigin {
+            agent: Some("claude-code".into()),
+            session_id: Some(session.into()),
+            goal: Some("grow audience".into()),
+        }
+    }
+
+    #[tokio::test]
+    async fn record_get_and_list() {
+        let pool = init_test_db().await.expect("init db");
+        record_origin(&pool, APPROVAL_QUEUE, 7, &origin("s1"))
+            .await
+            .expect("record");
+        record_origin(&pool, SCHEDULED_CONTENT, 3, &origin("s1"))
+            .await
+            .expect("record");
+        record_origin(&pool, APPROVAL_QUEUE, 8, &origin("s2"))
+            .await
+            .expect("record");
+        // Empty origins are skipped.
+        record_origin(&pool, APPROVAL_QUEUE, 9, &WorkflowOrigin::default())
+            .await
+            .expect("record");
+
+        let found = get_origin(&pool, APPROVAL_QUEUE, 7)
+            .await
+            .expect("get")
+            .expect("exists");
+        assert_eq!(found.session_id.as_deref(), Some("s1"));
+        assert_eq!(found.agent.as_deref(), Some("claude-code"));
+        assert!(get_origin(&pool, APPROVAL_QUEUE, 9)
+            .await
+            .expect("get")
+            .is_none());
+
+        let s1 = OriginFilter {
+            session_id: Some("s1".into()),
+            limit: 10,
+            ..Default::default()
+        };
+        assert_eq!(list_origins(&pool, &s1).await.expect("list").len(), 2);
+
+        let queued = OriginFilter {
+            record_type: Some(APPROVAL_QUEUE.into()),
+            limit: 10,
+            ..Default::default()
+        };
+        let rows = list_origins(&pool, &queued).await.expect("list");
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].record_id, 8, "newest first");
+    }
+}
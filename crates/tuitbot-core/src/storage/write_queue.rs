@@ -0,0 +1,377 @@
+//! Single-writer queue for SQLite mutations.
+//!
+//! SQLite allows one writer at a time. Concurrent writers sharing a pool can
+//! still hit `SQLITE_BUSY` when a deferred transaction is upgraded to a write,
+//! even with a busy timeout. A [`WriteQueue`] hands out write turns in FIFO
+//! order so the writers holding it never race each other; reads stay on the
+//! pool.
+//!
+//! Holders today are the automation loops' storage, safety, approval-queue
+//! and pacing adapters, the approval poster, API server approval and content
+//! writes and action logs, and MCP queue, draft and mutation-audit writes.
+//! Each process has its own queue, so the server, MCP and CLI still contend
+//! with each other through the busy timeout.
+//!
+//! Activity-log inserts go through a background actor instead: entries that
+//! queue up while a write is in progress are committed together in a single
+//! transaction.
+
+use std::future::Future;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use serde::Serialize;
+use tokio::sync::{mpsc, oneshot, Mutex, MutexGuard};
+
+use super::accounts::DEFAULT_ACCOUNT_ID;
+use super::{action_log, DbPool};
+use crate::error::StorageError;
+
+/// Maximum activity-log entries committed in one transaction.
+const MAX_LOG_BATCH: usize = 64;
+
+/// An activity-log insert waiting for the actor.
+struct LogEntry {
+    account_id: String,
+    action_type: String,
+    status: String,
+    message: Option<String>,
+    metadata: Option<String>,
+    result_tx: oneshot::Sender<Result<(), StorageError>>,
+}
+
+#[derive(Default)]
+struct Metrics {
+    waiting: AtomicUsize,
+    queued_logs: AtomicUsize,
+    peak_depth: AtomicUsize,
+    writes_total: AtomicU64,
+    log_batches: AtomicU64,
+    log_rows: AtomicU64,
+}
+
+impl Metrics {
+    fn depth(&self) -> usize {
+        self.waiting.load(Ordering::Relaxed) + self.queued_logs.load(Ordering::Relaxed)
+    }
+
+    fn record_peak(&self) {
+        self.peak_depth.fetch_max(self.depth(), Ordering::Relaxed);
+    }
+}
+
+/// Write queue counters, as reported by `/api/health/detailed`.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct WriteQueueStats {
+    /// Writers waiting for a turn plus activity-log entries not yet committed.
+    pub depth: usize,
+    /// Highest depth seen since startup.
+    pub peak_depth: usize,
+    /// Write turns granted since startup.
+    pub writes_total: u64,
+    /// Activity-log transactions committed since startup.
+    pub log_batches: u64,
+    /// Activity-log rows committed since startup.
+    pub log_rows: u64,
+}
+
+/// Handle to a database's write queue. Cheap to clone; all clones share
+/// one queue.
+#[derive(Clone)]
+pub struct WriteQueue {
+    pool: DbPool,
+    gate: Arc<Mutex<()>>,
+    log_tx: mpsc::UnboundedSender<LogEntry>,
+    metrics: Arc<Metrics>,
+}
+
+/// Exclusive write access, held until dropped.
+pub struct WriteTurn<'a> {
+    _guard: MutexGuard<'a, ()>,
+}
+
+impl WriteQueue {
+    /// Create a write queue for `pool` and spawn its activity-log actor.
+    ///
+    /// The actor stops once every handle has been dropped.
+    pub fn spawn(pool: DbPool) -> Self {
+        let gate = Arc::new(Mutex::new(()));
+        let metrics = Arc::new(Metrics::default());
+        let (log_tx, log_rx) = mpsc::unbounded_channel();
+        tokio::spawn(run_log_actor(
+            pool.clone(),
+            gate.clone(),
+            metrics.clone(),
+            log_rx,
+        ));
+        Self {
+            pool,
+            gate,
+            log_tx,
+            metrics,
+        }
+    }
+
+    /// The pool writes are issued against.
+    pub fn pool(&self) -> &DbPool {
+        &self.pool
+    }
+
+    /// Wait for this caller's turn to write. Turns are granted in FIFO order.
+    pub async fn turn(&self) -> WriteTurn<'_> {
+        self.metrics.waiting.fetch_add(1, Ordering::Relaxed);
+        self.metrics.record_peak();
+        let guard = self.gate.lock().await;
+        self.metrics.waiting.fetch_sub(1, Ordering::Relaxed);
+        self.metrics.writes_total.fetch_add(1, Ordering::Relaxed);
+        WriteTurn { _guard: guard }
+    }
+
+    /// Run a single storage write in its own turn.
+    ///
+    /// `write` must not log through this queue, since the log actor waits
+    /// for the same turn.
+    pub async fn write<F: Future>(&self, write: F) -> F::Output {
+        let _turn = self.turn().await;
+        write.await
+    }
+
+    /// Queue an activity-log entry for a specific account and wait until it
+    /// is committed.
+    pub async fn log_action_for(
+        &self,
+        account_id: &str,
+        action_type: &str,
+        status: &str,
+        message: Option<&str>,
+        metadata: Option<&str>,
+    ) -> Result<(), StorageError> {
+        let (result_tx, result_rx) = oneshot::channel();
+        let entry = LogEntry {
+            account_id: account_id.to_string(),
+            action_type: action_type.to_string(),
+            status: status.to_string(),
+            message: message.map(str::to_string),
+            metadata: metadata.map(str::to_string),
+            result_tx,
+        };
+        self.metrics.queued_logs.fetch_add(1, Ordering::Relaxed);
+        self.metrics.record_peak();
+        if self.log_tx.send(entry).is_err() {
+            // Actor gone (runtime shutting down): write directly.
+            self.metrics.queued_logs.fetch_sub(1, Ordering::Relaxed);
+            let _turn = self.turn().await;
+            return action_log::log_action_for(
+                &self.pool,
+                account_id,
+                action_type,
+                status,
+                message,
+                metadata,
+            )
+            .await;
+        }
+        result_rx.await.map_err(|_| StorageError::Query {
+            source: sqlx::Error::WorkerCrashed,
+        })?
+    }
+
+    /// Queue an activity-log entry and wait until it is committed.
+    pub async fn log_action(
+        &self,
+        action_type: &str,
+        status: &str,
+        message: Option<&str>,
+        metadata: Option<&str>,
+    ) -> Result<(), StorageError> {
+        self.log_action_for(DEFAULT_ACCOUNT_ID, action_type, status, message, metadata)
+            .await
+    }
+
+    pub fn stats(&self) -> WriteQueueStats {
+        WriteQueueStats {
+            depth: self.metrics.depth(),
+            peak_depth: self.metrics.peak_depth.load(Ordering::Relaxed),
+            writes_total: self.metrics.writes_total.load(Ordering::Relaxed),
+            log_batches: self.metrics.log_batches.load(Ordering::Relaxed),
+            log_rows: self.metrics.log_rows.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// Wait for a write turn when a write queue is attached.
+pub async fn write_turn(writes: Option<&WriteQueue>) -> Option<WriteTurn<'_>> {
+    match writes {
+        Some(writes) => Some(writes.turn().await),
+        None => None,
+    }
+}
+
+/// Run a single storage write in a turn of `writes` when attached.
+pub async fn write_via<F: Future>(writes: Option<&WriteQueue>, write: F) -> F::Output {
+    let _turn = write_turn(writes).await;
+    write.await
+}
+
+/// Insert an action log entry, batched through `writes` when attached.
+pub async fn log_action_via(
+    pool: &DbPool,
+    writes: Option<&WriteQueue>,
+    action_type: &str,
+    status: &str,
+    message: Option<&str>,
+    metadata: Option<&str>,
+) -> Result<(), StorageError> {
+    match writes {
+        Some(writes) => {
+            writes
+                .log_action(action_type, status, message, metadata)
+                .await
+        }
+        None => action_log::log_action(pool, action_type, status, message, metadata).await,
+    }
+}
+
+/// Commit queued activity-log entries, batching whatever has accumulated.
+async fn run_log_actor(
+    pool: DbPool,
+    gate: Arc<Mutex<()>>,
+    metrics: Arc<Metrics>,
+    mut log_rx: mpsc::UnboundedReceiver<LogEntry>,
+) {
+    while let Some(first) = log_rx.recv().await {
+        let _guard = gate.lock().await;
+        metrics.writes_total.fetch_add(1, Ordering::Relaxed);
+
+        // Collect entries queued while waiting for the gate.
+        let mut batch = vec![first];
+        while batch.len() < MAX_LOG_BATCH {
+            match log_rx.try_recv() {
+                Ok(entry) => batch.push(entry),
+                Err(_) => break,
+            }
+        }
+
+        let count = batch.len();
+        match insert_batch(&pool, &batch).await {
+            Ok(()) => {
+                for entry in batch {
+                    let _ = entry.result_tx.send(Ok(()));
+                }
+            }
+            Err(e) => {
+                // Retry one by one so each caller gets its own result.
+                tracing::warn!(error = %e, count, "Activity log batch failed, retrying rows");
+                for entry in batch {
+                    let result = action_log::log_action_for(
+                        &pool,
+                        &entry.account_id,
+                        &entry.action_type,
+                        &entry.status,
+                        entry.message.as_deref(),
+                        entry.metadata.as_deref(),
+                    )
+                    .await;
+                    let _ = entry.result_tx.send(result);
+                }
+            }
+        }
+
+        metrics.queued_logs.fetch_sub(count, Ordering::Relaxed);
+        metrics.log_batches.fetch_add(1, Ordering::Relaxed);
+        metrics.log_rows.fetch_add(count as u64, Ordering::Relaxed);
+    }
+}
+
+async fn insert_batch(pool: &DbPool, batch: &[LogEntry]) -> Result<(), StorageError> {
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|e| StorageError::Query { source: e })?;
+    for entry in batch {
+        sqlx::query(
+            "INSERT INTO action_log (account_id, action_type, status, message, metadata) \
+             VALUES (?, ?, ?, ?, ?)",
+        )
+        .bind(&entry.account_id)
+        .bind(&entry.action_type)
+        .bind(&entry.status)
+        .bind(&entry.message)
+        .bind(&entry.metadata)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| StorageError::Query { source: e })?;
+    }
+    tx.commit()
+        .await
+        .map_err(|e| StorageError::Query { source: e })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::init_test_db;
+
+    async fn log_count(pool: &DbPool) -> i64 {
+        let (count,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM action_log")
+            .fetch_one(pool)
+            .await
+            .expect("count");
+        count
+    }
+
+    #[tokio::test]
+    async fn queued_log_entries_are_batched() {
+        let pool = init_test_db().await.expect("init db");
+        let queue = WriteQueue::spawn(pool.clone());
+
+        // Hold a turn so entries pile up behind it.
+        let turn = queue.turn().await;
+        let mut tasks = tokio::task::JoinSet::new();
+        for i in 0..10 {
+            let queue = queue.clone();
+            tasks.spawn(async move {
+                queue
+                    .log_action("search", "success", Some(&format!("entry {i}")), None)
+                    .await
+            });
+        }
+        while queue.stats().depth < 10 {
+            tokio::task::yield_now().await;
+        }
+        assert_eq!(queue.stats().peak_depth, 10);
+        drop(turn);
+
+        while let Some(result) = tasks.join_next().await {
+            result.expect("join").expect("log");
+        }
+        assert_eq!(log_count(&pool).await, 10);
+
+        let stats = queue.stats();
+        assert_eq!(stats.depth, 0);
+        assert_eq!(stats.log_rows, 10);
+        assert!(stats.log_batches < 10, "expected batching: {stats:?}");
+    }
+
+    #[tokio::test]
+    async fn turns_are_exclusive() {
+        let pool = init_test_db().await.expect("init db");
+        let queue = WriteQueue::spawn(pool);
+
+        let first = queue.turn().await;
+        let waiter = {
+            let queue = queue.clone();
+            tokio::spawn(async move {
+                let _turn = queue.turn().await;
+            })
+        };
+        while queue.stats().depth == 0 {
+            tokio::task::yield_now().await;
+        }
+        assert!(!waiter.is_finished());
+
+        drop(first);
+        waiter.await.expect("waiter");
+        assert_eq!(queue.stats().writes_total, 2);
+    }
+}
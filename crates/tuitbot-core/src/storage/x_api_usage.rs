@@ -58,6 +58,18 @@ pub fn estimate_cost(endpoint: &str, method: &str) -> f64 {
     }
 }
 
+/// Whether a call reads posts, i.e. counts toward X's post usage cap.
+///
+/// The ledger counts one post per such call; X counts every post returned.
+pub fn is_post_read(endpoint: &str, method: &str) -> bool {
+    method == "GET"
+        && (endpoint.starts_with("/tweets")
+            || endpoint.contains("/mentions")
+            || (endpoint.starts_with("/users/") && endpoint.contains("/tweets"))
+            || endpoint.contains("/bookmarks")
+            || endpoint.contains("/liked_tweets"))
+}
+
 /// Summary of X API usage across multiple time windows.
 #[derive(Debug, serde::Serialize)]
 pub struct XApiUsageSummary {
@@ -254,6 +266,33 @@ pub async fn get_endpoint_breakdown(
     get_endpoint_breakdown_for(pool, DEFAULT_ACCOUNT_ID, days).await
 }
 
+/// Successful post-read calls from `start` (inclusive) to `end` (exclusive),
+/// both `YYYY-MM-DD` UTC days, for a specific account.
+pub async fn count_post_reads_between_for(
+    pool: &DbPool,
+    account_id: &str,
+    start: &str,
+    end: &str,
+) -> Result<u64, StorageError> {
+    let rows: Vec<(String, String, i64)> = sqlx::query_as(
+        "SELECT endpoint, method, COUNT(*) FROM x_api_usage \
+         WHERE account_id = ? AND created_at >= ? AND created_at < ? AND status_code < 400 \
+         GROUP BY endpoint, method",
+    )
+    .bind(account_id)
+    .bind(start)
+    .bind(end)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+
+    Ok(rows
+        .into_iter()
+        .filter(|(endpoint, method, _)| is_post_read(endpoint, method))
+        .map(|(_, _, calls)| calls.max(0) as u64)
+        .sum())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -308,6 +347,37 @@ mod tests {
         assert_eq!(post.error_count, 0);
     }
 
+    #[tokio::test]
+    async fn post_reads_count_successful_read_calls_in_window() {
+        let pool = init_test_db().await.expect("init db");
+        for (endpoint, method, status) in [
+            ("/tweets/search/recent", "GET", 200),
+            ("/users/u1/tweets", "GET", 200),
+            ("/users/u1/mentions", "GET", 200),
+            ("/tweets/search/recent", "GET", 429),
+            ("/tweets", "POST", 201),
+            ("/users/me", "GET", 200),
+        ] {
+            insert_x_api_usage(&pool, endpoint, method, status, 0.0)
+                .await
+                .expect("insert");
+        }
+
+        let today = chrono::Utc::now().date_naive();
+        let tomorrow = today.succ_opt().unwrap().to_string();
+        let reads =
+            count_post_reads_between_for(&pool, DEFAULT_ACCOUNT_ID, &today.to_string(), &tomorrow)
+                .await
+                .unwrap();
+        assert_eq!(reads, 3);
+
+        let before =
+            count_post_reads_between_for(&pool, DEFAULT_ACCOUNT_ID, "2020-01-01", "2020-01-02")
+                .await
+                .unwrap();
+        assert_eq!(before, 0);
+    }
+
     #[tokio::test]
     async fn empty_table_returns_zero_summary() {
         let pool = init_test_db().await.expect("init db");
@@ -3,6 +3,7 @@
 
 use super::metrics::TopicPerformance;
 use crate::storage::strategy::StrategyReportRow;
+use crate::storage::thread_performance::ThreadInsight;
 
 /// An actionable recommendation generated by the rule engine.
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -34,6 +35,8 @@ pub struct WeekMetrics {
     /// Configured capacity (max per day * 7).
     pub max_replies_per_week: i64,
     pub max_tweets_per_week: i64,
+    /// Findings from the per-position breakdown of this week's threads.
+    pub thread_insights: Vec<ThreadInsight>,
 }
 
 /// Generate recommendations from the current week's metrics and an optional previous report.
@@ -180,8 +183,18 @@ pub fn generate(
         });
     }
 
+    // Rule 9: Thread Structure — weak hooks, early drop-off, or flat CTAs
+    for insight in &metrics.thread_insights {
+        recs.push(Recommendation {
+            category: "experiment".to_string(),
+            priority: "medium".to_string(),
+            title: insight.title.clone(),
+            description: insight.description.clone(),
+        });
+    }
+
     // Sort by priority (high first)
-    recs.sort_by(|a, b| priority_rank(&a.priority).cmp(&priority_rank(&b.priority)));
+    recs.sort_by_key(|a| priority_rank(&a.priority));
 
     recs
 }
@@ -223,9 +236,23 @@ mod tests {
             distinct_topic_count: 5,
             max_replies_per_week: 70,
             max_tweets_per_week: 21,
+            thread_insights: vec![],
         }
     }
 
+    #[test]
+    fn thread_insights_become_experiments() {
+        let mut metrics = base_metrics();
+        metrics.thread_insights = vec![ThreadInsight {
+            title: "Your hooks underperform relative to bodies".to_string(),
+            description: "Hooks average 1.0% engagement versus 3.0% for body tweets.".to_string(),
+        }];
+        let recs = generate(&metrics, None);
+        assert_eq!(recs.len(), 1);
+        assert_eq!(recs[0].category, "experiment");
+        assert_eq!(recs[0].title, "Your hooks underperform relative to bodies");
+    }
+
     #[test]
     fn no_recommendations_for_healthy_metrics() {
         let metrics = base_metrics();
@@ -307,6 +334,7 @@ mod tests {
             bottom_topics_json: "[]".to_string(),
             top_content_json: "[]".to_string(),
             recommendations_json: "[]".to_string(),
+            approval_metrics_json: "{}".to_string(),
             created_at: String::new(),
         };
         // Current avg = 65, prev avg = 100, drop = 35% > 20%
@@ -5,7 +5,10 @@ use chrono::{Datelike, NaiveDate, Utc};
 
 use crate::config::Config;
 use crate::error::StorageError;
+use crate::storage::analytics_range::AnalyticsRange;
+use crate::storage::approval_metrics;
 use crate::storage::strategy::StrategyReportRow;
+use crate::storage::thread_performance;
 use crate::storage::DbPool;
 
 use super::metrics;
@@ -39,6 +42,12 @@ pub async fn compute_report(
     let bottom_topics = metrics::bottom_topics_in_range(pool, &start, &end, 5).await?;
     let top_content = metrics::top_content_in_range(pool, &start, &end, 5).await?;
     let distinct_topic_count = metrics::distinct_topic_count(pool, &start, &end).await?;
+    let week = AnalyticsRange {
+        from: monday.and_hms_opt(0, 0, 0).expect("midnight is valid"),
+        to: end_date.and_hms_opt(0, 0, 0).expect("midnight is valid"),
+    };
+    let approvals = approval_metrics::get_approval_metrics(pool, &week).await?;
+    let threads = thread_performance::get_position_report(pool, &week).await?;
 
     // --- Estimated follow conversion ---
     let total_output = actions.replies + actions.tweets + actions.threads + actions.target_replies;
@@ -68,6 +77,7 @@ pub async fn compute_report(
         distinct_topic_count,
         max_replies_per_week: i64::from(config.limits.max_replies_per_day) * 7,
         max_tweets_per_week: i64::from(config.limits.max_tweets_per_day) * 7,
+        thread_insights: threads.insights,
     };
     let recs = recommendations::generate(&week_metrics, previous.as_ref());
 
@@ -77,6 +87,8 @@ pub async fn compute_report(
         serde_json::to_string(&bottom_topics).unwrap_or_else(|_| "[]".to_string());
     let top_content_json = serde_json::to_string(&top_content).unwrap_or_else(|_| "[]".to_string());
     let recommendations_json = serde_json::to_string(&recs).unwrap_or_else(|_| "[]".to_string());
+    let approval_metrics_json =
+        serde_json::to_string(&approvals).unwrap_or_else(|_| "{}".to_string());
 
     Ok(StrategyReportRow {
         id: 0,
@@ -97,6 +109,7 @@ pub async fn compute_report(
         bottom_topics_json,
         top_content_json,
         recommendations_json,
+        approval_metrics_json,
         created_at: String::new(),
     })
 }
@@ -179,5 +192,8 @@ mod tests {
         assert_eq!(report.week_end, "2026-03-01");
         assert_eq!(report.replies_sent, 0);
         assert_eq!(report.follower_delta, 0);
+        let approvals: serde_json::Value =
+            serde_json::from_str(&report.approval_metrics_json).expect("json");
+        assert_eq!(approvals["enqueued"], 0);
     }
 }
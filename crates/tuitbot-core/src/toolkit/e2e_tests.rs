@@ -106,6 +106,8 @@ mod tests {
                 created_at: "2026-02-24T12:00:00Z".to_string(),
                 public_metrics: PublicMetrics::default(),
                 conversation_id: None,
+                entities: None,
+                attachments: None,
             })
         }
 
@@ -192,6 +194,8 @@ mod tests {
                 ..Default::default()
             },
             conversation_id: None,
+            entities: None,
+            attachments: None,
         }
     }
 
@@ -236,7 +240,10 @@ mod tests {
 
         // Step 2: Score each tweet (stateless, no DB)
         let keywords = vec!["rust".to_string(), "async".to_string()];
-        let engine = ScoringEngine::new(ScoringConfig::default(), keywords.clone());
+        // Fixture tweets have fixed timestamps, so keep them out of the freshness cutoff.
+        let mut config = ScoringConfig::default();
+        config.freshness.discovery_max_age_hours = 0;
+        let engine = ScoringEngine::new(config, keywords.clone());
         let user_map: std::collections::HashMap<String, &User> = results
             .includes
             .as_ref()
@@ -261,6 +268,7 @@ mod tests {
                     author_followers: user.map(|u| u.public_metrics.followers_count).unwrap_or(0),
                     has_media: false,
                     is_quote_tweet: false,
+                    urls: vec![],
                 };
                 (tweet.id.clone(), engine.score_tweet(&data).total)
             })
@@ -3,7 +3,18 @@
 //! Media type inference, size validation, and raw upload.
 //! File I/O, hashing, DB tracking, and idempotency belong in the workflow layer.
 
+mod validate;
+mod video;
+
+pub use validate::{
+    prepare_media, prepare_media_with_limits, MediaLimits, MediaValidation, PreparedMedia,
+    DEFAULT_MAX_VIDEO_DURATION_SECS, GIF_MAX_HEIGHT, GIF_MAX_WIDTH, IMAGE_MAX_DIMENSION,
+    IMAGE_MIN_DIMENSION,
+};
+pub use video::mp4_duration_secs;
+
 use super::ToolkitError;
+use crate::x_api::media::ProgressCallback;
 use crate::x_api::types::{ImageFormat, MediaId, MediaType};
 use crate::x_api::XApiClient;
 
@@ -20,6 +31,21 @@ pub async fn upload_media(
     Ok(client.upload_media(data, media_type).await?)
 }
 
+/// Upload media bytes to X API, reporting chunked-upload progress.
+///
+/// Chunk failures are retried by the client, resuming from the failed segment.
+pub async fn upload_media_with_progress(
+    client: &dyn XApiClient,
+    data: &[u8],
+    media_type: MediaType,
+    progress: ProgressCallback<'_>,
+) -> Result<MediaId, ToolkitError> {
+    validate_media_size(data.len() as u64, media_type)?;
+    Ok(client
+        .upload_media_with_progress(data, media_type, progress)
+        .await?)
+}
+
 /// Infer `MediaType` from a file path extension.
 ///
 /// Supports: jpg, jpeg, png, webp, gif, mp4.
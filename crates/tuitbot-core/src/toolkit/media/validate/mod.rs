@@ -0,0 +1,211 @@
+//! Pre-upload media validation and auto-transcoding.
+//!
+//! Sniffs the real format from the bytes, checks dimensions and file size
+//! against X's limits, and re-encodes oversized still images so they fit.
+//! Pure byte-in/byte-out: no file I/O and no network.
+
+mod probe;
+#[cfg(test)]
+mod tests;
+mod transcode;
+
+use serde::{Deserialize, Serialize};
+
+use super::ToolkitError;
+use crate::x_api::types::MediaType;
+use probe::{check_min_dimensions, detect_media_type, read_dimensions};
+use transcode::prepare_image;
+
+/// Maximum width or height (pixels) X accepts for still images.
+pub const IMAGE_MAX_DIMENSION: u32 = 8192;
+
+/// Minimum width or height (pixels) X accepts for still images and GIFs.
+pub const IMAGE_MIN_DIMENSION: u32 = 4;
+
+/// Maximum GIF width (pixels).
+pub const GIF_MAX_WIDTH: u32 = 1280;
+
+/// Maximum GIF height (pixels).
+pub const GIF_MAX_HEIGHT: u32 = 1080;
+
+/// X's default maximum video duration (seconds) for standard accounts.
+pub const DEFAULT_MAX_VIDEO_DURATION_SECS: u32 = 140;
+
+/// Operator-configurable media limits, layered on top of X's hard limits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MediaLimits {
+    /// Maximum video duration in seconds.
+    pub max_video_duration_secs: u32,
+    /// Maximum video size in bytes (never above X's 512 MB limit).
+    pub max_video_size_bytes: u64,
+}
+
+impl Default for MediaLimits {
+    fn default() -> Self {
+        Self {
+            max_video_duration_secs: DEFAULT_MAX_VIDEO_DURATION_SECS,
+            max_video_size_bytes: MediaType::Video.max_size(),
+        }
+    }
+}
+
+impl From<&crate::config::MediaConfig> for MediaLimits {
+    fn from(config: &crate::config::MediaConfig) -> Self {
+        Self {
+            max_video_duration_secs: config.max_video_duration_seconds,
+            max_video_size_bytes: (config.max_video_size_mb as u64 * 1024 * 1024)
+                .min(MediaType::Video.max_size()),
+        }
+    }
+}
+
+/// Outcome of validating one media file, suitable for persisting as JSON.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MediaValidation {
+    /// MIME type of the bytes that will actually be uploaded.
+    pub media_type: String,
+    /// Size of the input bytes.
+    pub original_size: u64,
+    /// Size of the bytes that will be uploaded.
+    pub final_size: u64,
+    /// Pixel width (images and GIFs only).
+    pub width: Option<u32>,
+    /// Pixel height (images and GIFs only).
+    pub height: Option<u32>,
+    /// Duration in seconds (videos only, when readable from the container).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub duration_secs: Option<f64>,
+    /// Whether the image was resized and/or re-encoded.
+    pub transcoded: bool,
+    /// Non-fatal observations (format mismatch, transcode details).
+    #[serde(default)]
+    pub warnings: Vec<String>,
+}
+
+/// Media bytes that passed validation and are ready to upload.
+#[derive(Debug, Clone)]
+pub struct PreparedMedia {
+    pub data: Vec<u8>,
+    pub media_type: MediaType,
+    pub validation: MediaValidation,
+}
+
+/// Validate media bytes against X's limits, transcoding still images if needed.
+///
+/// `declared` is the type inferred from the file name. The bytes are sniffed
+/// and the detected format wins when the two disagree (recorded as a warning).
+/// JPEG/PNG/WebP images that exceed the size or dimension limits are resized
+/// and re-encoded as JPEG. GIFs and videos are never transcoded; they are
+/// rejected when they exceed their limits.
+pub fn prepare_media(data: &[u8], declared: MediaType) -> Result<PreparedMedia, ToolkitError> {
+    prepare_media_with_limits(data, declared, &MediaLimits::default())
+}
+
+/// Like [`prepare_media`], with operator-configured video limits.
+pub fn prepare_media_with_limits(
+    data: &[u8],
+    declared: MediaType,
+    limits: &MediaLimits,
+) -> Result<PreparedMedia, ToolkitError> {
+    if data.is_empty() {
+        return Err(ToolkitError::UnsupportedMediaFormat {
+            detected: "empty file".to_string(),
+        });
+    }
+
+    if declared == MediaType::Video {
+        return prepare_video(data, limits);
+    }
+
+    let detected = detect_media_type(data)?;
+    let mut warnings = Vec::new();
+    if detected != declared {
+        warnings.push(format!(
+            "file extension suggests {} but content is {}",
+            declared.mime_type(),
+            detected.mime_type()
+        ));
+    }
+
+    match detected {
+        MediaType::Gif => prepare_gif(data, warnings),
+        MediaType::Image(_) => prepare_image(data, detected, warnings),
+        MediaType::Video => unreachable!("detect_media_type never returns video"),
+    }
+}
+
+fn prepare_video(data: &[u8], limits: &MediaLimits) -> Result<PreparedMedia, ToolkitError> {
+    if !super::video::is_mp4(data) {
+        return Err(ToolkitError::UnsupportedMediaFormat {
+            detected: "not an MP4 container".to_string(),
+        });
+    }
+
+    let size = data.len() as u64;
+    let max = limits.max_video_size_bytes.min(MediaType::Video.max_size());
+    if size > max {
+        return Err(ToolkitError::MediaTooLarge {
+            size,
+            max,
+            media_type: MediaType::Video.mime_type().to_string(),
+        });
+    }
+
+    let mut warnings = Vec::new();
+    let duration = super::video::mp4_duration_secs(data);
+    match duration {
+        Some(secs) if secs > limits.max_video_duration_secs as f64 => {
+            return Err(ToolkitError::VideoTooLong {
+                duration_secs: secs.ceil() as u64,
+                max_secs: limits.max_video_duration_secs,
+            });
+        }
+        Some(_) => {}
+        None => warnings.push("could not read video duration from container".to_string()),
+    }
+
+    let mut prepared = passthrough(data, MediaType::Video, None, warnings);
+    prepared.validation.duration_secs = duration;
+    Ok(prepared)
+}
+
+fn prepare_gif(data: &[u8], warnings: Vec<String>) -> Result<PreparedMedia, ToolkitError> {
+    let (width, height) = read_dimensions(data)?;
+    check_min_dimensions(width, height)?;
+    if width > GIF_MAX_WIDTH || height > GIF_MAX_HEIGHT {
+        return Err(ToolkitError::InvalidMediaDimensions {
+            width,
+            height,
+            message: format!("GIFs must be at most {GIF_MAX_WIDTH}x{GIF_MAX_HEIGHT}"),
+        });
+    }
+    super::validate_media_size(data.len() as u64, MediaType::Gif)?;
+    Ok(passthrough(
+        data,
+        MediaType::Gif,
+        Some((width, height)),
+        warnings,
+    ))
+}
+
+fn passthrough(
+    data: &[u8],
+    media_type: MediaType,
+    dims: Option<(u32, u32)>,
+    warnings: Vec<String>,
+) -> PreparedMedia {
+    PreparedMedia {
+        data: data.to_vec(),
+        media_type,
+        validation: MediaValidation {
+            media_type: media_type.mime_type().to_string(),
+            original_size: data.len() as u64,
+            final_size: data.len() as u64,
+            width: dims.map(|d| d.0),
+            height: dims.map(|d| d.1),
+            duration_secs: None,
+            transcoded: false,
+            warnings,
+        },
+    }
+}
@@ -0,0 +1,50 @@
+//! Format sniffing and dimension checks.
+
+use std::io::Cursor;
+
+use image::ImageReader;
+
+use super::IMAGE_MIN_DIMENSION;
+use crate::toolkit::ToolkitError;
+use crate::x_api::types::{ImageFormat, MediaType};
+
+/// Sniff the image format from magic bytes.
+pub(super) fn detect_media_type(data: &[u8]) -> Result<MediaType, ToolkitError> {
+    match image::guess_format(data) {
+        Ok(image::ImageFormat::Jpeg) => Ok(MediaType::Image(ImageFormat::Jpeg)),
+        Ok(image::ImageFormat::Png) => Ok(MediaType::Image(ImageFormat::Png)),
+        Ok(image::ImageFormat::WebP) => Ok(MediaType::Image(ImageFormat::Webp)),
+        Ok(image::ImageFormat::Gif) => Ok(MediaType::Gif),
+        Ok(other) => Err(ToolkitError::UnsupportedMediaFormat {
+            detected: format!("{other:?}").to_lowercase(),
+        }),
+        Err(_) => Err(ToolkitError::UnsupportedMediaFormat {
+            detected: "unrecognized".to_string(),
+        }),
+    }
+}
+
+pub(super) fn read_dimensions(data: &[u8]) -> Result<(u32, u32), ToolkitError> {
+    ImageReader::new(Cursor::new(data))
+        .with_guessed_format()
+        .map_err(|e| decode_error(&e))?
+        .into_dimensions()
+        .map_err(|e| decode_error(&e))
+}
+
+pub(super) fn check_min_dimensions(width: u32, height: u32) -> Result<(), ToolkitError> {
+    if width < IMAGE_MIN_DIMENSION || height < IMAGE_MIN_DIMENSION {
+        return Err(ToolkitError::InvalidMediaDimensions {
+            width,
+            height,
+            message: format!("media must be at least {IMAGE_MIN_DIMENSION}x{IMAGE_MIN_DIMENSION}"),
+        });
+    }
+    Ok(())
+}
+
+pub(super) fn decode_error(e: &dyn std::fmt::Display) -> ToolkitError {
+    ToolkitError::UnsupportedMediaFormat {
+        detected: format!("undecodable image: {e}"),
+    }
+}
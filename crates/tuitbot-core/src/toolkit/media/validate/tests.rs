@@ -0,0 +1,186 @@
+use super::*;
+
+use std::io::Cursor;
+
+use image::{DynamicImage, Rgb, RgbImage};
+
+use crate::x_api::types::ImageFormat;
+
+fn png_bytes(width: u32, height: u32) -> Vec<u8> {
+    let img = RgbImage::from_pixel(width, height, Rgb([10, 120, 200]));
+    let mut out = Vec::new();
+    DynamicImage::ImageRgb8(img)
+        .write_to(&mut Cursor::new(&mut out), image::ImageFormat::Png)
+        .unwrap();
+    out
+}
+
+fn gif_bytes(width: u32, height: u32) -> Vec<u8> {
+    let img = RgbImage::from_pixel(width, height, Rgb([0, 0, 0]));
+    let mut out = Vec::new();
+    DynamicImage::ImageRgb8(img)
+        .write_to(&mut Cursor::new(&mut out), image::ImageFormat::Gif)
+        .unwrap();
+    out
+}
+
+/// High-entropy pixels so PNG compression cannot shrink the file much.
+fn noisy_png(width: u32, height: u32) -> Vec<u8> {
+    let mut seed: u32 = 0x1234_5678;
+    let img = RgbImage::from_fn(width, height, |_, _| {
+        seed ^= seed << 13;
+        seed ^= seed >> 17;
+        seed ^= seed << 5;
+        let [a, b, c, _] = seed.to_le_bytes();
+        Rgb([a, b, c])
+    });
+    let mut out = Vec::new();
+    DynamicImage::ImageRgb8(img)
+        .write_to(&mut Cursor::new(&mut out), image::ImageFormat::Png)
+        .unwrap();
+    out
+}
+
+const PNG: MediaType = MediaType::Image(ImageFormat::Png);
+
+#[test]
+fn valid_png_passes_through_unchanged() {
+    let data = png_bytes(64, 32);
+    let prepared = prepare_media(&data, PNG).unwrap();
+    assert_eq!(prepared.data, data);
+    assert_eq!(prepared.media_type, PNG);
+    assert!(!prepared.validation.transcoded);
+    assert_eq!(prepared.validation.width, Some(64));
+    assert_eq!(prepared.validation.height, Some(32));
+    assert!(prepared.validation.warnings.is_empty());
+}
+
+#[test]
+fn extension_mismatch_uses_detected_format() {
+    let data = png_bytes(16, 16);
+    let prepared = prepare_media(&data, MediaType::Image(ImageFormat::Jpeg)).unwrap();
+    assert_eq!(prepared.media_type, PNG);
+    assert_eq!(prepared.validation.warnings.len(), 1);
+    assert!(prepared.validation.warnings[0].contains("image/png"));
+}
+
+#[test]
+fn unsupported_format_rejected() {
+    let err = prepare_media(b"not an image at all", PNG).unwrap_err();
+    assert!(matches!(err, ToolkitError::UnsupportedMediaFormat { .. }));
+
+    // BMP is a real image format but not one X accepts.
+    let bmp = b"BM\x00\x00\x00\x00\x00\x00\x00\x00\x36\x00\x00\x00";
+    let err = prepare_media(bmp, PNG).unwrap_err();
+    match err {
+        ToolkitError::UnsupportedMediaFormat { detected } => assert_eq!(detected, "bmp"),
+        other => panic!("unexpected error: {other}"),
+    }
+}
+
+#[test]
+fn empty_file_rejected() {
+    let err = prepare_media(&[], PNG).unwrap_err();
+    assert!(matches!(err, ToolkitError::UnsupportedMediaFormat { .. }));
+}
+
+#[test]
+fn tiny_image_rejected() {
+    let err = prepare_media(&png_bytes(2, 100), PNG).unwrap_err();
+    assert!(matches!(
+        err,
+        ToolkitError::InvalidMediaDimensions {
+            width: 2,
+            height: 100,
+            ..
+        }
+    ));
+}
+
+#[test]
+fn oversized_gif_dimensions_rejected() {
+    let err = prepare_media(&gif_bytes(1300, 10), MediaType::Gif).unwrap_err();
+    assert!(matches!(
+        err,
+        ToolkitError::InvalidMediaDimensions { width: 1300, .. }
+    ));
+}
+
+#[test]
+fn gif_within_limits_passes() {
+    let data = gif_bytes(40, 30);
+    let prepared = prepare_media(&data, MediaType::Gif).unwrap();
+    assert_eq!(prepared.media_type, MediaType::Gif);
+    assert_eq!(prepared.data, data);
+    assert_eq!(prepared.validation.width, Some(40));
+}
+
+#[test]
+fn oversized_image_is_transcoded_to_jpeg() {
+    // ~1500x1500 noisy RGB PNG is well over 5 MB.
+    let data = noisy_png(1500, 1500);
+    assert!(data.len() as u64 > PNG.max_size());
+
+    let prepared = prepare_media(&data, PNG).unwrap();
+    assert_eq!(prepared.media_type, MediaType::Image(ImageFormat::Jpeg));
+    assert!(prepared.validation.transcoded);
+    assert!(prepared.validation.final_size <= PNG.max_size());
+    assert_eq!(prepared.validation.original_size, data.len() as u64);
+    assert_eq!(
+        image::guess_format(&prepared.data).unwrap(),
+        image::ImageFormat::Jpeg
+    );
+}
+
+#[test]
+fn video_within_limits_passes() {
+    let data = crate::toolkit::media::video::test_mp4(1000, 30_000);
+    let prepared = prepare_media(&data, MediaType::Video).unwrap();
+    assert_eq!(prepared.media_type, MediaType::Video);
+    assert_eq!(prepared.validation.width, None);
+    assert_eq!(prepared.validation.duration_secs, Some(30.0));
+}
+
+#[test]
+fn video_must_be_mp4() {
+    let err = prepare_media(&[0u8; 128], MediaType::Video).unwrap_err();
+    assert!(matches!(err, ToolkitError::UnsupportedMediaFormat { .. }));
+}
+
+#[test]
+fn video_duration_limit_enforced() {
+    let data = crate::toolkit::media::video::test_mp4(1000, 200_000);
+    let err = prepare_media(&data, MediaType::Video).unwrap_err();
+    assert!(matches!(
+        err,
+        ToolkitError::VideoTooLong {
+            duration_secs: 200,
+            max_secs: 140
+        }
+    ));
+
+    let limits = MediaLimits {
+        max_video_duration_secs: 300,
+        ..MediaLimits::default()
+    };
+    assert!(prepare_media_with_limits(&data, MediaType::Video, &limits).is_ok());
+}
+
+#[test]
+fn video_size_limit_is_configurable() {
+    let data = crate::toolkit::media::video::test_mp4(1000, 1000);
+    let limits = MediaLimits {
+        max_video_size_bytes: 16,
+        ..MediaLimits::default()
+    };
+    let err = prepare_media_with_limits(&data, MediaType::Video, &limits).unwrap_err();
+    assert!(matches!(err, ToolkitError::MediaTooLarge { max: 16, .. }));
+}
+
+#[test]
+fn validation_serializes_round_trip() {
+    let prepared = prepare_media(&png_bytes(8, 8), PNG).unwrap();
+    let json = serde_json::to_string(&prepared.validation).unwrap();
+    let back: MediaValidation = serde_json::from_str(&json).unwrap();
+    assert_eq!(back, prepared.validation);
+}
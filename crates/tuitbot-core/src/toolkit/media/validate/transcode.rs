@@ -0,0 +1,116 @@
+//! Resizing and JPEG re-encoding of still images that exceed X's limits.
+
+use std::io::Cursor;
+
+use image::codecs::jpeg::JpegEncoder;
+use image::imageops::FilterType;
+use image::{DynamicImage, GenericImageView, ImageReader};
+
+use super::probe::{check_min_dimensions, decode_error, read_dimensions};
+use super::{
+    passthrough, MediaValidation, PreparedMedia, IMAGE_MAX_DIMENSION, IMAGE_MIN_DIMENSION,
+};
+use crate::toolkit::ToolkitError;
+use crate::x_api::types::{ImageFormat, MediaType};
+
+/// JPEG quality steps tried, in order, when re-encoding an oversized image.
+const JPEG_QUALITY_STEPS: [u8; 4] = [85, 75, 65, 50];
+
+/// Scale factor applied per downscale pass when quality alone is not enough.
+const DOWNSCALE_FACTOR: f64 = 0.75;
+
+/// Upper bound on downscale passes before giving up.
+const MAX_DOWNSCALE_PASSES: u32 = 6;
+
+pub(super) fn prepare_image(
+    data: &[u8],
+    media_type: MediaType,
+    mut warnings: Vec<String>,
+) -> Result<PreparedMedia, ToolkitError> {
+    let (width, height) = read_dimensions(data)?;
+    check_min_dimensions(width, height)?;
+
+    let max_size = media_type.max_size();
+    let fits = width <= IMAGE_MAX_DIMENSION
+        && height <= IMAGE_MAX_DIMENSION
+        && data.len() as u64 <= max_size;
+    if fits {
+        return Ok(passthrough(
+            data,
+            media_type,
+            Some((width, height)),
+            warnings,
+        ));
+    }
+
+    let img = ImageReader::new(Cursor::new(data))
+        .with_guessed_format()
+        .map_err(|e| decode_error(&e))?
+        .decode()
+        .map_err(|e| decode_error(&e))?;
+
+    let mut img = if width > IMAGE_MAX_DIMENSION || height > IMAGE_MAX_DIMENSION {
+        warnings.push(format!(
+            "resized from {width}x{height} to fit {IMAGE_MAX_DIMENSION}x{IMAGE_MAX_DIMENSION}"
+        ));
+        img.resize(
+            IMAGE_MAX_DIMENSION,
+            IMAGE_MAX_DIMENSION,
+            FilterType::Lanczos3,
+        )
+    } else {
+        img
+    };
+
+    for _ in 0..=MAX_DOWNSCALE_PASSES {
+        for quality in JPEG_QUALITY_STEPS {
+            let encoded = encode_jpeg(&img, quality)?;
+            if encoded.len() as u64 <= max_size {
+                let (w, h) = img.dimensions();
+                warnings.push(format!(
+                    "re-encoded as JPEG (quality {quality}, {w}x{h}) to fit {max_size} bytes"
+                ));
+                let final_type = MediaType::Image(ImageFormat::Jpeg);
+                return Ok(PreparedMedia {
+                    validation: MediaValidation {
+                        media_type: final_type.mime_type().to_string(),
+                        original_size: data.len() as u64,
+                        final_size: encoded.len() as u64,
+                        width: Some(w),
+                        height: Some(h),
+                        duration_secs: None,
+                        transcoded: true,
+                        warnings,
+                    },
+                    data: encoded,
+                    media_type: final_type,
+                });
+            }
+        }
+        let (w, h) = img.dimensions();
+        let next_w = ((w as f64) * DOWNSCALE_FACTOR) as u32;
+        let next_h = ((h as f64) * DOWNSCALE_FACTOR) as u32;
+        if next_w < IMAGE_MIN_DIMENSION || next_h < IMAGE_MIN_DIMENSION {
+            break;
+        }
+        img = img.resize_exact(next_w, next_h, FilterType::Triangle);
+    }
+
+    Err(ToolkitError::MediaTooLarge {
+        size: data.len() as u64,
+        max: max_size,
+        media_type: media_type.mime_type().to_string(),
+    })
+}
+
+pub(super) fn encode_jpeg(img: &DynamicImage, quality: u8) -> Result<Vec<u8>, ToolkitError> {
+    let mut out = Vec::new();
+    // JPEG has no alpha channel; flatten to RGB first.
+    let rgb = img.to_rgb8();
+    JpegEncoder::new_with_quality(&mut out, quality)
+        .encode_image(&rgb)
+        .map_err(|e| ToolkitError::UnsupportedMediaFormat {
+            detected: format!("re-encode failed: {e}"),
+        })?;
+    Ok(out)
+}
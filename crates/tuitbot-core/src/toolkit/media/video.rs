@@ -0,0 +1,142 @@
+//! MP4 container inspection for pre-upload video checks.
+//!
+//! Reads just enough of the ISO base media file format to confirm the
+//! container type and extract the movie duration from the `mvhd` box.
+
+/// Whether the bytes look like an MP4 (ISO BMFF) container.
+///
+/// MP4 files start with an `ftyp` box: 4-byte size followed by `ftyp`.
+pub fn is_mp4(data: &[u8]) -> bool {
+    data.len() >= 8 && &data[4..8] == b"ftyp"
+}
+
+/// Movie duration in seconds, read from `moov/mvhd`.
+///
+/// Returns `None` if the container is truncated, has no `moov` box
+/// (e.g. fragmented streams), or reports a zero timescale.
+pub fn mp4_duration_secs(data: &[u8]) -> Option<f64> {
+    let moov = find_box(data, b"moov")?;
+    let mvhd = find_box(moov, b"mvhd")?;
+
+    let version = *mvhd.first()?;
+    // Skip version (1) + flags (3).
+    let body = mvhd.get(4..)?;
+    let (timescale, duration) = if version == 1 {
+        // creation (8) + modification (8) + timescale (4) + duration (8)
+        let timescale = read_u32(body, 16)?;
+        let duration = read_u64(body, 20)?;
+        (timescale, duration)
+    } else {
+        // creation (4) + modification (4) + timescale (4) + duration (4)
+        let timescale = read_u32(body, 8)?;
+        let duration = read_u32(body, 12)? as u64;
+        (timescale, duration)
+    };
+
+    if timescale == 0 {
+        return None;
+    }
+    Some(duration as f64 / timescale as f64)
+}
+
+/// Find the payload of the first box with the given type at this nesting level.
+fn find_box<'a>(mut data: &'a [u8], kind: &[u8; 4]) -> Option<&'a [u8]> {
+    while data.len() >= 8 {
+        let size32 = read_u32(data, 0)? as u64;
+        let (header_len, size) = match size32 {
+            // Box extends to end of data.
+            0 => (8, data.len() as u64),
+            // 64-bit size follows the type.
+            1 => (16, read_u64(data, 8)?),
+            n => (8, n),
+        };
+        if size < header_len || size > data.len() as u64 {
+            return None;
+        }
+        let size = size as usize;
+        if &data[4..8] == kind {
+            return data.get(header_len as usize..size);
+        }
+        data = &data[size..];
+    }
+    None
+}
+
+fn read_u32(data: &[u8], offset: usize) -> Option<u32> {
+    let bytes = data.get(offset..offset + 4)?;
+    Some(u32::from_be_bytes(bytes.try_into().ok()?))
+}
+
+fn read_u64(data: &[u8], offset: usize) -> Option<u64> {
+    let bytes = data.get(offset..offset + 8)?;
+    Some(u64::from_be_bytes(bytes.try_into().ok()?))
+}
+
+/// Build a minimal MP4 (`ftyp` + `moov/mvhd` v0) for tests.
+#[cfg(test)]
+pub(crate) fn test_mp4(timescale: u32, duration: u32) -> Vec<u8> {
+    fn boxed(kind: &[u8; 4], payload: &[u8]) -> Vec<u8> {
+        let mut out = ((payload.len() + 8) as u32).to_be_bytes().to_vec();
+        out.extend_from_slice(kind);
+        out.extend_from_slice(payload);
+        out
+    }
+
+    let mut mvhd = vec![0u8; 4]; // version 0 + flags
+    mvhd.extend_from_slice(&0u32.to_be_bytes()); // creation
+    mvhd.extend_from_slice(&0u32.to_be_bytes()); // modification
+    mvhd.extend_from_slice(&timescale.to_be_bytes());
+    mvhd.extend_from_slice(&duration.to_be_bytes());
+    mvhd.extend_from_slice(&[0u8; 80]); // rate, volume, matrix, etc.
+
+    let mut out = boxed(b"ftyp", b"isom\0\0\x02\0isomiso2mp41");
+    out.extend(boxed(b"free", &[]));
+    out.extend(boxed(b"moov", &boxed(b"mvhd", &mvhd)));
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_mp4_container() {
+        assert!(is_mp4(&test_mp4(1000, 5000)));
+        assert!(!is_mp4(b"GIF89a......"));
+        assert!(!is_mp4(b"short"));
+    }
+
+    #[test]
+    fn reads_v0_duration() {
+        let data = test_mp4(600, 600 * 42);
+        assert_eq!(mp4_duration_secs(&data), Some(42.0));
+    }
+
+    #[test]
+    fn reads_v1_duration() {
+        let mut mvhd = vec![1u8, 0, 0, 0];
+        mvhd.extend_from_slice(&[0u8; 16]); // creation + modification
+        mvhd.extend_from_slice(&1000u32.to_be_bytes());
+        mvhd.extend_from_slice(&90_500u64.to_be_bytes());
+        let mut mvhd_box = ((mvhd.len() + 8) as u32).to_be_bytes().to_vec();
+        mvhd_box.extend_from_slice(b"mvhd");
+        mvhd_box.extend(mvhd);
+        let mut moov = ((mvhd_box.len() + 8) as u32).to_be_bytes().to_vec();
+        moov.extend_from_slice(b"moov");
+        moov.extend(mvhd_box);
+
+        assert_eq!(mp4_duration_secs(&moov), Some(90.5));
+    }
+
+    #[test]
+    fn missing_moov_returns_none() {
+        let data = &test_mp4(1000, 1000)[..20];
+        assert_eq!(mp4_duration_secs(data), None);
+        assert_eq!(mp4_duration_secs(&[]), None);
+    }
+
+    #[test]
+    fn zero_timescale_returns_none() {
+        assert_eq!(mp4_duration_secs(&test_mp4(0, 1000)), None);
+    }
+}
@@ -50,6 +50,22 @@ pub enum ToolkitError {
         media_type: String,
     },
 
+    /// Media bytes are not in a format X accepts (or could not be decoded).
+    #[error("unsupported media format: {detected}")]
+    UnsupportedMediaFormat { detected: String },
+
+    /// Video runs longer than the configured maximum duration.
+    #[error("video too long: {duration_secs}s (max {max_secs}s)")]
+    VideoTooLong { duration_secs: u64, max_secs: u32 },
+
+    /// Media dimensions fall outside X's limits and cannot be fixed automatically.
+    #[error("invalid media dimensions {width}x{height}: {message}")]
+    InvalidMediaDimensions {
+        width: u32,
+        height: u32,
+        message: String,
+    },
+
     /// Thread posting failed partway through.
     #[error("thread failed at tweet {failed_index}: posted {posted}/{total} tweets")]
     ThreadPartialFailure {
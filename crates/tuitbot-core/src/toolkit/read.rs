@@ -77,6 +77,19 @@ pub async fn get_user_tweets(
         .await?)
 }
 
+/// Get recent tweets from a specific user that are newer than `since_id`.
+pub async fn get_user_tweets_since(
+    client: &dyn XApiClient,
+    user_id: &str,
+    max_results: u32,
+    since_id: Option<&str>,
+) -> Result<SearchResponse, ToolkitError> {
+    super::validate_id(user_id, "user_id")?;
+    Ok(client
+        .get_user_tweets_since(user_id, max_results, since_id)
+        .await?)
+}
+
 /// Get the authenticated user's home timeline.
 pub async fn get_home_timeline(
     client: &dyn XApiClient,
@@ -155,6 +168,22 @@ pub async fn get_users_by_ids(
     Ok(client.get_users_by_ids(user_ids).await?)
 }
 
+/// Look up users by username (1-100). Unknown usernames are left out.
+pub async fn get_users_by_usernames(
+    client: &dyn XApiClient,
+    usernames: &[&str],
+) -> Result<Vec<User>, ToolkitError> {
+    if usernames.is_empty() || usernames.len() > 100 {
+        return Err(ToolkitError::InvalidInput {
+            message: format!(
+                "usernames must contain 1-100 names, got {}",
+                usernames.len()
+            ),
+        });
+    }
+    Ok(client.get_users_by_usernames(usernames).await?)
+}
+
 /// Get users who liked a specific tweet.
 pub async fn get_tweet_liking_users(
     client: &dyn XApiClient,
@@ -215,6 +244,8 @@ mod tests {
                 created_at: String::new(),
                 public_metrics: PublicMetrics::default(),
                 conversation_id: None,
+                entities: None,
+                attachments: None,
             })
         }
         async fn get_me(&self) -> Result<User, XApiError> {
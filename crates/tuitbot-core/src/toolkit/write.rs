@@ -5,6 +5,7 @@
 //! Those concerns belong in the workflow layer (AD-04).
 
 use super::ToolkitError;
+use crate::content::thread_format::render_segment;
 use crate::x_api::types::PostedTweet;
 use crate::x_api::XApiClient;
 
@@ -58,6 +59,8 @@ pub async fn delete_tweet(client: &dyn XApiClient, tweet_id: &str) -> Result<boo
 /// Post a thread (ordered sequence of tweets).
 ///
 /// Validates all tweet lengths up front. Chains replies sequentially.
+/// `{thread_url}` in tweets after the first expands to the first tweet's
+/// link (see [`crate::content::thread_format`]).
 /// On partial failure, returns `ToolkitError::ThreadPartialFailure`
 /// with the IDs of successfully posted tweets.
 pub async fn post_thread(
@@ -94,6 +97,7 @@ pub async fn post_thread(
             }
         } else {
             let prev = &posted_ids[i - 1];
+            let text = &render_segment(text, &posted_ids[0]);
             match tweet_media {
                 Some(ids) if !ids.is_empty() => {
                     client.reply_to_tweet_with_media(text, prev, ids).await
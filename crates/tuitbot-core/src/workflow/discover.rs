@@ -1,12 +1,13 @@
 //! Discover step: search tweets via toolkit, score, persist to DB.
 //!
 //! This is the first step in the reply pipeline: find tweets worth replying to.
-//! Routes all X API calls through `toolkit::read::search_tweets`.
+//! Routes all X API calls through `toolkit::read::search_tweets`. Tweets from
+//! active target accounts are ranked ahead of keyword matches.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use crate::config::Config;
-use crate::scoring::{find_matched_keywords, ScoringEngine, TweetData};
+use crate::scoring::{find_matched_keywords, ScoringEngine, ScoringPlugin, TweetData};
 use crate::storage;
 use crate::storage::tweets::DiscoveredTweet;
 use crate::storage::DbPool;
@@ -20,7 +21,9 @@ use super::{ScoreBreakdown, ScoredCandidate, WorkflowError};
 pub struct DiscoverInput {
     /// Search query. If `None`, uses product keywords from config.
     pub query: Option<String>,
-    /// Minimum score threshold. If `None`, uses `config.scoring.threshold`.
+    /// Minimum score threshold. If `None`, uses the effective threshold
+    /// (the latest calibrated value when calibration is enabled, otherwise
+    /// `config.scoring.threshold`).
     pub min_score: Option<f64>,
     /// Maximum number of results (clamped to 1..100).
     pub limit: Option<u32>,
@@ -63,7 +66,12 @@ pub async fn execute(
     };
 
     let max_results = input.limit.unwrap_or(10).clamp(1, 100);
-    let threshold = input.min_score.unwrap_or(config.scoring.threshold as f64);
+    let threshold = match input.min_score {
+        Some(min_score) => min_score,
+        None => {
+            storage::threshold_calibrations::current_threshold(db, &config.scoring).await? as f64
+        }
+    };
 
     // Search tweets via toolkit (not direct XApiClient)
     let search_response = toolkit::read::search_tweets(
@@ -99,7 +107,17 @@ pub async fn execute(
         .chain(config.business.effective_industry_topics().iter())
         .cloned()
         .collect();
-    let engine = ScoringEngine::new(config.scoring.clone(), keywords.clone());
+    let engine = ScoringEngine::new(config.scoring.clone(), keywords.clone())
+        .with_plugin(ScoringPlugin::load_or_warn(&config.scoring.plugin));
+
+    // Target accounts take priority over keyword discovery (best-effort).
+    let target_usernames: HashSet<String> =
+        storage::target_accounts::get_active_target_accounts(db)
+            .await
+            .unwrap_or_default()
+            .into_iter()
+            .map(|t| t.username.to_lowercase())
+            .collect();
 
     let mut candidates = Vec::new();
 
@@ -116,8 +134,9 @@ pub async fn execute(
             replies: tweet.public_metrics.reply_count,
             author_username: author_username.to_string(),
             author_followers,
-            has_media: false,
+            has_media: tweet.has_media(),
             is_quote_tweet: false,
+            urls: tweet.expanded_urls(),
         };
 
         let score = engine.score_tweet(&tweet_data);
@@ -132,6 +151,7 @@ pub async fn execute(
             like_count: tweet.public_metrics.like_count as i64,
             retweet_count: tweet.public_metrics.retweet_count as i64,
             reply_count: tweet.public_metrics.reply_count as i64,
+            quote_count: tweet.public_metrics.quote_count as i64,
             impression_count: Some(tweet.public_metrics.impression_count as i64),
             relevance_score: Some(score.total as f64),
             matched_keyword: matched.first().cloned(),
@@ -139,6 +159,10 @@ pub async fn execute(
             replied_to: 0,
         };
         let _ = storage::tweets::insert_discovered_tweet(db, &discovered).await;
+        // Credit every matched keyword and share the sighting with the loops' dedup cache.
+        for keyword in &matched {
+            let _ = storage::keyword_hits::record_keyword_hit(db, &tweet.id, keyword).await;
+        }
 
         // Check if already replied
         let already_replied = storage::replies::has_replied_to(db, &tweet.id)
@@ -168,19 +192,26 @@ pub async fn execute(
                 engagement: score.engagement,
                 reply_count: score.reply_count,
                 content_type: score.content_type,
+                entity_adjustment: score.entity_adjustment,
+                plugin_adjustment: score.plugin_adjustment,
+                plugin_rationale: score.plugin_rationale.clone(),
             },
+            entity_type: score.entity_type,
             matched_keywords: matched,
             recommended_action: recommended_action.to_string(),
             already_replied,
+            is_target: target_usernames.contains(&author_username.to_lowercase()),
         });
     }
 
-    // Filter by threshold, sort desc, take limit
+    // Filter by threshold, sort targets first then by score desc, take limit
     candidates.retain(|c| (c.score_total as f64) >= threshold);
     candidates.sort_by(|a, b| {
-        b.score_total
-            .partial_cmp(&a.score_total)
-            .unwrap_or(std::cmp::Ordering::Equal)
+        b.is_target.cmp(&a.is_target).then_with(|| {
+            b.score_total
+                .partial_cmp(&a.score_total)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        })
     });
     candidates.truncate(max_results as usize);
 
@@ -44,7 +44,7 @@ pub async fn execute(
     let archetype_override: Option<ReplyArchetype> =
         input.archetype.as_deref().and_then(parse_archetype);
 
-    let gen = make_content_gen(llm, &config.business);
+    let gen = make_content_gen(llm, config);
     let dedup = DedupChecker::new(db.clone());
     let banned = &config.limits.banned_phrases;
 
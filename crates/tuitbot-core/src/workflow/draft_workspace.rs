@@ -0,0 +1,494 @@
+//! Draft workspace steps: QA-check a draft and promote it for review.
+//!
+//! Drafts are iterated on outside the approval queue. A check runs the
+//! deterministic content rules (length, banned phrases, disclosure) over
+//! every tweet in the draft and stores the result in the draft's QA fields.
+//! Promotion re-runs the check and, if nothing hard-fails, enqueues the
+//! draft as a pending approval item.
+
+use serde::Serialize;
+
+use crate::config::{Config, EmojiPolicy, PillarOverride};
+use crate::content::{
+    deserialize_blocks_from_content, has_disclosure, required_disclosure, tweet_weighted_len,
+    MAX_TWEET_CHARS,
+};
+use crate::error::StorageError;
+use crate::safety::contains_banned_phrase;
+use crate::storage::scheduled_content::ScheduledContent;
+use crate::storage::{approval_queue, drafts, scheduled_content, DbPool};
+
+use super::WorkflowError;
+
+/// Tweets within this many characters of the limit get a soft flag.
+const NEAR_LIMIT_MARGIN: usize = 20;
+
+/// Emojis a tweet may hold under [`EmojiPolicy::Sparing`].
+const MAX_SPARING_EMOJIS: usize = 2;
+
+/// A single QA finding on a draft.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct DraftFlag {
+    pub code: String,
+    pub category: String,
+    /// `hard` blocks promotion; `soft` is advisory.
+    pub severity: String,
+    pub message: String,
+    /// Zero-based tweet index within a thread draft.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tweet_index: Option<usize>,
+}
+
+/// Result of checking a draft.
+#[derive(Debug, Clone, Serialize)]
+pub struct DraftCheck {
+    /// True when there are no hard flags.
+    pub passed: bool,
+    /// 0-100; each hard flag costs 50 and each soft flag 10.
+    pub score: f64,
+    pub hard_flags: Vec<DraftFlag>,
+    pub soft_flags: Vec<DraftFlag>,
+    pub recommendations: Vec<String>,
+}
+
+impl DraftCheck {
+    /// Build a check result, with recommendations and score, from `flags`.
+    pub fn from_flags(hard_flags: Vec<DraftFlag>, soft_flags: Vec<DraftFlag>) -> Self {
+        let recommendations = hard_flags
+            .iter()
+            .chain(&soft_flags)
+            .map(|f| match f.code.as_str() {
+                "tweet_too_long" | "length_near_limit" => "Shorten the tweet.",
+                "banned_phrase" => "Rephrase to avoid banned phrases.",
+                "empty_content" => "Add text or remove the empty tweet.",
+                "missing_disclosure" => "Add the configured disclosure.",
+                "author_overlap" => "Add something the author hasn't already said.",
+                "emoji_forbidden" | "emoji_heavy" => "Remove emojis.",
+                _ => "Post it as a single tweet instead.",
+            })
+            .fold(Vec::<String>::new(), |mut acc, r| {
+                if !acc.iter().any(|a| a == r) {
+                    acc.push(r.to_string());
+                }
+                acc
+            });
+
+        let penalty = 50.0 * hard_flags.len() as f64 + 10.0 * soft_flags.len() as f64;
+        DraftCheck {
+            passed: hard_flags.is_empty(),
+            score: (100.0 - penalty).max(0.0),
+            hard_flags,
+            soft_flags,
+            recommendations,
+        }
+    }
+}
+
+/// Result of promoting a draft into the approval queue.
+#[derive(Debug, Clone, Serialize)]
+pub struct PromotedDraft {
+    pub draft_id: i64,
+    pub approval_queue_id: i64,
+    pub check: DraftCheck,
+}
+
+/// Split stored draft content into the tweets that would be posted.
+///
+/// Thread drafts may hold a versioned blocks payload or a legacy JSON array
+/// of strings; anything else is treated as a single tweet.
+pub fn draft_segments(content_type: &str, content: &str) -> Vec<String> {
+    if content_type == "thread" {
+        if let Some(mut blocks) = deserialize_blocks_from_content(content) {
+            blocks.sort_by_key(|b| b.order);
+            return blocks.into_iter().map(|b| b.text).collect();
+        }
+        if let Ok(tweets) = serde_json::from_str::<Vec<String>>(content) {
+            return tweets;
+        }
+    }
+    vec![content.to_string()]
+}
+
+/// Run the draft QA rules without touching storage, under the override of
+/// the content pillar the draft names, if any.
+pub fn evaluate_draft(config: &Config, content_type: &str, content: &str) -> DraftCheck {
+    let pillar = config.business.pillar_override(content).map(|(_, o)| o);
+    evaluate_draft_with(config, content_type, content, pillar)
+}
+
+/// Run the draft QA rules under an already resolved pillar override (e.g.
+/// the one for the tweet a reply answers).
+pub fn evaluate_draft_with(
+    config: &Config,
+    content_type: &str,
+    content: &str,
+    pillar: Option<&PillarOverride>,
+) -> DraftCheck {
+    let segments = draft_segments(content_type, content);
+    let is_thread = segments.len() > 1;
+    let mut hard_flags = Vec::new();
+    let mut soft_flags = Vec::new();
+
+    for (i, text) in segments.iter().enumerate() {
+        let tweet_index = is_thread.then_some(i);
+        if text.trim().is_empty() {
+            hard_flags.push(flag(
+                "empty_content",
+                "format",
+                "hard",
+                "Tweet is empty.".to_string(),
+                tweet_index,
+            ));
+            continue;
+        }
+
+        let length = tweet_weighted_len(text);
+        if length > MAX_TWEET_CHARS {
+            hard_flags.push(flag(
+                "tweet_too_long",
+                "compliance",
+                "hard",
+                format!("Tweet is {length} characters (max {MAX_TWEET_CHARS})."),
+                tweet_index,
+            ));
+        } else if length > MAX_TWEET_CHARS - NEAR_LIMIT_MARGIN {
+            soft_flags.push(flag(
+                "length_near_limit",
+                "compliance",
+                "soft",
+                format!("Tweet is {length} characters, close to the {MAX_TWEET_CHARS} limit."),
+                tweet_index,
+            ));
+        }
+
+        if let Some(phrase) = contains_banned_phrase(text, &config.limits.banned_phrases) {
+            hard_flags.push(flag(
+                "banned_phrase",
+                "compliance",
+                "hard",
+                format!("Contains banned phrase \"{phrase}\"."),
+                tweet_index,
+            ));
+        }
+
+        let emojis = text.chars().filter(|c| is_emoji(*c)).count();
+        match pillar.and_then(|o| o.emoji) {
+            Some(EmojiPolicy::Forbid) if emojis > 0 => hard_flags.push(flag(
+                "emoji_forbidden",
+                "brand",
+                "hard",
+                format!("Contains {emojis} emoji; this topic forbids them."),
+                tweet_index,
+            )),
+            Some(EmojiPolicy::Sparing) if emojis > MAX_SPARING_EMOJIS => soft_flags.push(flag(
+                "emoji_heavy",
+                "brand",
+                "soft",
+                format!("Contains {emojis} emojis; this topic keeps them sparing."),
+                tweet_index,
+            )),
+            _ => {}
+        }
+    }
+
+    if content_type == "thread" && segments.len() < 2 {
+        soft_flags.push(flag(
+            "thread_too_short",
+            "format",
+            "soft",
+            "Thread has a single tweet.".to_string(),
+            None,
+        ));
+    }
+
+    let texts = segments.iter().map(String::as_str);
+    if let Some(disclosure) =
+        required_disclosure(&config.disclosure, &config.business, content_type, texts)
+    {
+        if !segments.iter().any(|t| has_disclosure(t, &disclosure)) {
+            hard_flags.push(flag(
+                "missing_disclosure",
+                "compliance",
+                "hard",
+                format!("Mentions the product without the disclosure \"{disclosure}\"."),
+                None,
+            ));
+        }
+    }
+
+    DraftCheck::from_flags(hard_flags, soft_flags)
+}
+
+/// Check a draft and store the result in its QA fields.
+pub async fn check_draft(
+    db: &DbPool,
+    account_id: &str,
+    config: &Config,
+    draft: &ScheduledContent,
+) -> Result<DraftCheck, WorkflowError> {
+    let check = evaluate_draft(config, &draft.content_type, &draft.content);
+    scheduled_content::update_qa_fields_for(
+        db,
+        account_id,
+        draft.id,
+        &serde_json::to_string(&check).unwrap_or_default(),
+        &serde_json::to_string(&check.hard_flags).unwrap_or_default(),
+        &serde_json::to_string(&check.soft_flags).unwrap_or_default(),
+        &serde_json::to_string(&check.recommendations).unwrap_or_default(),
+        check.score,
+    )
+    .await?;
+    Ok(check)
+}
+
+/// Store `check` in the QA fields of approval item `id`.
+pub async fn store_approval_check(
+    db: &DbPool,
+    account_id: &str,
+    id: i64,
+    check: &DraftCheck,
+) -> Result<(), StorageError> {
+    approval_queue::update_qa_fields_for(
+        db,
+        account_id,
+        id,
+        &serde_json::to_string(check).unwrap_or_default(),
+        &serde_json::to_string(&check.hard_flags).unwrap_or_default(),
+        &serde_json::to_string(&check.soft_flags).unwrap_or_default(),
+        &serde_json::to_string(&check.recommendations).unwrap_or_default(),
+        check.score,
+        !check.passed,
+    )
+    .await
+}
+
+/// Promote a draft into the approval queue as a pending item.
+///
+/// Fails with `InvalidInput` if the draft has hard QA flags, and with a
+/// storage conflict if the draft was promoted or removed concurrently.
+/// Thread drafts must be scheduled instead, since approval items post as a
+/// single tweet.
+pub async fn promote_draft(
+    db: &DbPool,
+    account_id: &str,
+    config: &Config,
+    draft: &ScheduledContent,
+) -> Result<PromotedDraft, WorkflowError> {
+    if draft.status != "draft" {
+        return Err(conflict(draft.id, &draft.status));
+    }
+    if draft.content_type != "tweet" {
+        return Err(WorkflowError::InvalidInput(format!(
+            "Only tweet drafts can be promoted for approval; schedule {} drafts instead.",
+            draft.content_type
+        )));
+    }
+
+    let check = check_draft(db, account_id, config, draft).await?;
+    if !check.passed {
+        let reasons: Vec<&str> = check
+            .hard_flags
+            .iter()
+            .map(|f| f.message.as_str())
+            .collect();
+        return Err(WorkflowError::InvalidInput(format!(
+            "Draft {} failed QA: {}",
+            draft.id,
+            reasons.join(" ")
+        )));
+    }
+
+    let reason = format!("Promoted from draft {}", draft.id);
+    let approval_queue_id = approval_queue::enqueue_with_context_for(
+        db,
+        account_id,
+        "tweet",
+        "",
+        "",
+        &draft.content,
+        "",
+        "",
+        check.score,
+        "[]",
+        Some(&reason),
+        None,
+    )
+    .await?;
+
+    if !drafts::mark_promoted_for(db, account_id, draft.id, approval_queue_id).await? {
+        return Err(conflict(draft.id, "changed"));
+    }
+
+    Ok(PromotedDraft {
+        draft_id: draft.id,
+        approval_queue_id,
+        check,
+    })
+}
+
+fn flag(
+    code: &str,
+    category: &str,
+    severity: &str,
+    message: String,
+    tweet_index: Option<usize>,
+) -> DraftFlag {
+    DraftFlag {
+        code: code.to_string(),
+        category: category.to_string(),
+        severity: severity.to_string(),
+        message,
+        tweet_index,
+    }
+}
+
+/// Whether `c` is an emoji, pictograph, or regional indicator (flag) letter.
+fn is_emoji(c: char) -> bool {
+    matches!(u32::from(c), 0x1F1E6..=0x1F1FF | 0x1F300..=0x1FAFF | 0x2600..=0x27BF)
+}
+
+fn conflict(id: i64, status: &str) -> WorkflowError {
+    WorkflowError::Storage(StorageError::Conflict {
+        message: format!("draft {id} is no longer a draft (status: {status})"),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::accounts::DEFAULT_ACCOUNT_ID;
+    use crate::storage::init_test_db;
+
+    #[test]
+    fn evaluates_each_thread_tweet() {
+        let config = Config::default();
+        let content = serde_json::to_string(&["Hook", "You should check out my tool"]).unwrap();
+        let check = evaluate_draft(&config, "thread", &content);
+        assert!(!check.passed);
+        assert_eq!(check.hard_flags.len(), 1);
+        assert_eq!(check.hard_flags[0].code, "banned_phrase");
+        assert_eq!(check.hard_flags[0].tweet_index, Some(1));
+        assert_eq!(check.score, 50.0);
+    }
+
+    #[test]
+    fn long_and_near_limit_tweets() {
+        let config = Config::default();
+        let too_long = evaluate_draft(&config, "tweet", &"a".repeat(MAX_TWEET_CHARS + 1));
+        assert_eq!(too_long.hard_flags[0].code, "tweet_too_long");
+
+        let near = evaluate_draft(&config, "tweet", &"a".repeat(MAX_TWEET_CHARS - 5));
+        assert!(near.passed);
+        assert_eq!(near.soft_flags[0].code, "length_near_limit");
+        assert_eq!(near.score, 90.0);
+    }
+
+    #[test]
+    fn pillar_emoji_policy_flags_emojis() {
+        let mut config = Config::default();
+        config.business.pillar_overrides.insert(
+            "Tax".to_string(),
+            PillarOverride {
+                emoji: Some(EmojiPolicy::Forbid),
+                ..PillarOverride::default()
+            },
+        );
+        let check = evaluate_draft(&config, "tweet", "Tax season is here \u{1F4B8}");
+        assert_eq!(check.hard_flags[0].code, "emoji_forbidden");
+        assert!(evaluate_draft(&config, "tweet", "Rust ships \u{1F680}").passed);
+
+        let sparing = PillarOverride {
+            emoji: Some(EmojiPolicy::Sparing),
+            ..PillarOverride::default()
+        };
+        let check = evaluate_draft_with(
+            &config,
+            "reply",
+            "Yes \u{1F389}\u{1F389}\u{1F389}",
+            Some(&sparing),
+        );
+        assert!(check.passed);
+        assert_eq!(check.soft_flags[0].code, "emoji_heavy");
+    }
+
+    #[test]
+    fn product_mentions_need_the_disclosure() {
+        let mut config = Config::default();
+        config.business.product_name = "Tuitbot".to_string();
+        config.disclosure.reply = Some("(I build {product_name})".to_string());
+
+        let missing = evaluate_draft(&config, "reply", "Tuitbot handles this for you");
+        assert_eq!(missing.hard_flags[0].code, "missing_disclosure");
+
+        let present = evaluate_draft(
+            &config,
+            "reply",
+            "Tuitbot handles this for you\n\n(I build Tuitbot)",
+        );
+        assert!(present.passed);
+        assert!(evaluate_draft(&config, "reply", "Good point").passed);
+        assert!(evaluate_draft(&config, "tweet", "Tuitbot ships today").passed);
+    }
+
+    #[tokio::test]
+    async fn promote_enqueues_pending_item_once() {
+        let pool = init_test_db().await.expect("init db");
+        let config = Config::default();
+        let id = scheduled_content::insert_draft(&pool, "tweet", "Ship small PRs", "manual")
+            .await
+            .unwrap();
+        let draft = drafts::get_draft(&pool, id).await.unwrap().unwrap();
+
+        let promoted = promote_draft(&pool, DEFAULT_ACCOUNT_ID, &config, &draft)
+            .await
+            .unwrap();
+        let item = approval_queue::get_by_id(&pool, promoted.approval_queue_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(item.status, "pending");
+        assert_eq!(item.generated_content, "Ship small PRs");
+
+        let stored = scheduled_content::get_by_id(&pool, id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(stored.status, "promoted");
+        assert_eq!(stored.approval_queue_id, Some(promoted.approval_queue_id));
+
+        // The stale snapshot still says "draft"; the storage CAS catches it.
+        let again = promote_draft(&pool, DEFAULT_ACCOUNT_ID, &config, &draft).await;
+        assert!(matches!(
+            again,
+            Err(WorkflowError::Storage(StorageError::Conflict { .. }))
+        ));
+    }
+
+    #[tokio::test]
+    async fn promote_rejects_failing_and_thread_drafts() {
+        let pool = init_test_db().await.expect("init db");
+        let config = Config::default();
+        let id = scheduled_content::insert_draft(&pool, "tweet", "Check out my app", "manual")
+            .await
+            .unwrap();
+        let draft = drafts::get_draft(&pool, id).await.unwrap().unwrap();
+        let err = promote_draft(&pool, DEFAULT_ACCOUNT_ID, &config, &draft)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, WorkflowError::InvalidInput(_)));
+
+        // The failed check is still recorded on the draft.
+        let stored = drafts::get_draft(&pool, id).await.unwrap().unwrap();
+        assert_eq!(stored.qa_score, 50.0);
+
+        let thread = r#"["One","Two"]"#;
+        let id = scheduled_content::insert_draft(&pool, "thread", thread, "manual")
+            .await
+            .unwrap();
+        let draft = drafts::get_draft(&pool, id).await.unwrap().unwrap();
+        let err = promote_draft(&pool, DEFAULT_ACCOUNT_ID, &config, &draft)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, WorkflowError::InvalidInput(_)));
+    }
+}
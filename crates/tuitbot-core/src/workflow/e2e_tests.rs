@@ -173,6 +173,7 @@ mod tests {
                 usage: crate::llm::TokenUsage {
                     input_tokens: 10,
                     output_tokens: 20,
+                    ..Default::default()
                 },
             })
         }
@@ -186,6 +187,8 @@ mod tests {
         config.business.product_name = "TestProduct".to_string();
         config.business.product_keywords = vec!["rust".to_string(), "programming".to_string()];
         config.scoring.threshold = 10;
+        // Fixture tweets have fixed timestamps, so keep them out of the freshness cutoff.
+        config.scoring.freshness.discovery_max_age_hours = 0;
         config.approval_mode = true;
         config
     }
@@ -204,6 +207,8 @@ mod tests {
                 ..Default::default()
             },
             conversation_id: None,
+            entities: None,
+            attachments: None,
         }
     }
 
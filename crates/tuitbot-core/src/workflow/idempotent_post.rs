@@ -0,0 +1,406 @@
+//! Idempotent publish: post a tweet, reply, or quote at most once across retries.
+//!
+//! A network timeout after X accepted a post looks like a failure, and the
+//! retry would publish a duplicate. Before each attempt an intent keyed by
+//! the post's dedup key is persisted. When an earlier attempt for the same
+//! key did not confirm, the user's recent timeline is searched for the post
+//! before anything is re-sent. Every retry resolution is written to the
+//! action log under `post_dedup`.
+
+use chrono::{DateTime, Duration, Utc};
+use serde::Serialize;
+
+use crate::storage::post_intents::{self, PostIntent};
+use crate::storage::{action_log, DbPool};
+use crate::toolkit;
+use crate::x_api::XApiClient;
+
+use super::WorkflowError;
+
+/// Recent own tweets scanned for an earlier attempt.
+const TIMELINE_LOOKBACK: u32 = 20;
+
+/// Clock skew tolerated between our intent timestamp and X's `created_at`.
+const CLOCK_SKEW_SECS: i64 = 120;
+
+/// An outbound tweet, reply, or quote tweet.
+#[derive(Debug, Clone, Copy)]
+pub struct OutboundPost<'a> {
+    /// Distinguishes callers that may legitimately post the same text,
+    /// e.g. `approval:42` or `mcp`.
+    pub scope: &'a str,
+    pub text: &'a str,
+    /// Tweet to reply to; `None` for an original tweet.
+    pub in_reply_to_id: Option<&'a str>,
+    /// Tweet to quote. Quotes carry no media and ignore `in_reply_to_id`.
+    pub quote_tweet_id: Option<&'a str>,
+    pub media_ids: Option<&'a [String]>,
+}
+
+impl OutboundPost<'_> {
+    fn action_type(&self) -> &'static str {
+        if self.quote_tweet_id.is_some() {
+            "quote"
+        } else if self.in_reply_to_id.is_some() {
+            "reply"
+        } else {
+            "tweet"
+        }
+    }
+
+    fn target(&self) -> &str {
+        self.quote_tweet_id.or(self.in_reply_to_id).unwrap_or("")
+    }
+}
+
+/// How a post request was resolved.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PostResolution {
+    /// Sent to X by this call.
+    Posted,
+    /// An earlier attempt was already confirmed; nothing was sent.
+    AlreadyPosted,
+    /// An unconfirmed earlier attempt was found on the timeline; nothing was sent.
+    FoundOnTimeline,
+}
+
+/// Output of [`post_once`].
+#[derive(Debug, Clone, Serialize)]
+pub struct IdempotentPostOutput {
+    pub tweet_id: String,
+    pub resolution: PostResolution,
+    pub dedup_key: String,
+    /// Attempts recorded for this dedup key, including this one.
+    pub attempts: i64,
+}
+
+/// Post `post` unless an earlier attempt for the same dedup key already went out.
+///
+/// `own_user_id` is the authenticated user's ID; it is looked up with
+/// `get_me` when `None` and a timeline check is needed. If the timeline
+/// cannot be read the post is re-sent, since X rejects exact duplicates.
+pub async fn post_once(
+    pool: &DbPool,
+    client: &dyn XApiClient,
+    own_user_id: Option<&str>,
+    post: OutboundPost<'_>,
+) -> Result<IdempotentPostOutput, WorkflowError> {
+    let action_type = post.action_type();
+    let target = post.target();
+    let key = post_intents::compute_dedup_key(post.scope, action_type, target, post.text);
+    let intent = post_intents::begin_intent(pool, &key, action_type, target, post.text).await?;
+
+    if let (Some(tweet_id), "posted") = (&intent.tweet_id, intent.status.as_str()) {
+        record(pool, &intent, "already_posted", Some(tweet_id)).await;
+        return Ok(output(
+            &intent,
+            tweet_id.clone(),
+            PostResolution::AlreadyPosted,
+        ));
+    }
+
+    if intent.is_retry() {
+        match find_on_timeline(client, own_user_id, &intent).await {
+            Ok(Some(tweet_id)) => {
+                post_intents::mark_intent_posted(pool, &key, &tweet_id).await?;
+                record(pool, &intent, "found_on_timeline", Some(&tweet_id)).await;
+                return Ok(output(&intent, tweet_id, PostResolution::FoundOnTimeline));
+            }
+            Ok(None) => record(pool, &intent, "resent", None).await,
+            Err(e) => {
+                tracing::warn!(
+                    dedup_key = %key,
+                    error = %e,
+                    "Could not check timeline for earlier post attempt, re-sending"
+                );
+                record(pool, &intent, "resent_unverified", None).await;
+            }
+        }
+    }
+
+    let result = match (post.quote_tweet_id, post.in_reply_to_id) {
+        (Some(quoted), _) => toolkit::write::quote_tweet(client, post.text, quoted).await,
+        (None, Some(reply_to)) => {
+            toolkit::write::reply_to_tweet(client, post.text, reply_to, post.media_ids).await
+        }
+        (None, None) => toolkit::write::post_tweet(client, post.text, post.media_ids).await,
+    };
+
+    match result {
+        Ok(posted) => {
+            post_intents::mark_intent_posted(pool, &key, &posted.id).await?;
+            Ok(output(&intent, posted.id, PostResolution::Posted))
+        }
+        Err(e) => {
+            let _ = post_intents::mark_intent_failed(pool, &key, &e.to_string()).await;
+            Err(e.into())
+        }
+    }
+}
+
+fn output(
+    intent: &PostIntent,
+    tweet_id: String,
+    resolution: PostResolution,
+) -> IdempotentPostOutput {
+    IdempotentPostOutput {
+        tweet_id,
+        resolution,
+        dedup_key: intent.dedup_key.clone(),
+        attempts: intent.attempts,
+    }
+}
+
+/// Search the user's recent tweets for one matching the intent.
+async fn find_on_timeline(
+    client: &dyn XApiClient,
+    own_user_id: Option<&str>,
+    intent: &PostIntent,
+) -> Result<Option<String>, WorkflowError> {
+    let user_id = match own_user_id {
+        Some(id) => id.to_string(),
+        None => toolkit::read::get_me(client).await?.id,
+    };
+    let recent = toolkit::read::get_user_tweets(client, &user_id, TIMELINE_LOOKBACK, None).await?;
+
+    let since = DateTime::parse_from_rfc3339(&intent.created_at)
+        .ok()
+        .map(|t| t.with_timezone(&Utc) - Duration::seconds(CLOCK_SKEW_SECS));
+    let wanted = comparable_text(&intent.content);
+
+    Ok(recent
+        .data
+        .into_iter()
+        .find(|t| {
+            let recent_enough = match (since, DateTime::parse_from_rfc3339(&t.created_at)) {
+                (Some(since), Ok(created)) => created.with_timezone(&Utc) >= since,
+                _ => true,
+            };
+            recent_enough && comparable_text(&t.text) == wanted
+        })
+        .map(|t| t.id))
+}
+
+/// Normalize text for matching against what X returns: collapse whitespace,
+/// drop links (X rewrites them to t.co), and undo X's HTML escaping.
+fn comparable_text(text: &str) -> String {
+    text.replace("&amp;", "&")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .split_whitespace()
+        .filter(|w| !w.starts_with("http://") && !w.starts_with("https://"))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Record a retry resolution in the action log (best-effort).
+async fn record(pool: &DbPool, intent: &PostIntent, resolution: &str, tweet_id: Option<&str>) {
+    tracing::info!(
+        dedup_key = %intent.dedup_key,
+        attempts = intent.attempts,
+        resolution,
+        tweet_id = tweet_id.unwrap_or(""),
+        "Resolved repeated post attempt"
+    );
+    let metadata = serde_json::json!({
+        "dedup_key": intent.dedup_key,
+        "action_type": intent.action_type,
+        "in_reply_to_id": intent.in_reply_to_id,
+        "attempts": intent.attempts,
+        "last_error": intent.last_error,
+        "tweet_id": tweet_id,
+    });
+    let message = match tweet_id {
+        Some(id) => format!(
+            "Repeated {} attempt resolved to existing tweet {id}",
+            intent.action_type
+        ),
+        None => format!("Repeated {} attempt re-sent", intent.action_type),
+    };
+    let _ = action_log::log_action(
+        pool,
+        "post_dedup",
+        resolution,
+        Some(&message),
+        Some(&metadata.to_string()),
+    )
+    .await;
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Mutex;
+
+    use super::*;
+    use crate::error::XApiError;
+    use crate::storage::init_test_db;
+    use crate::x_api::types::*;
+
+    /// Counts posts; optionally times out after X accepted the post.
+    #[derive(Default)]
+    struct FlakyClient {
+        posts: AtomicUsize,
+        timeline: Mutex<Vec<Tweet>>,
+        timeout_after_post: bool,
+    }
+
+    fn empty_response(data: Vec<Tweet>) -> SearchResponse {
+        SearchResponse {
+            data,
+            includes: None,
+            meta: SearchMeta {
+                newest_id: None,
+                oldest_id: None,
+                result_count: 0,
+                next_token: None,
+            },
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl XApiClient for FlakyClient {
+        async fn search_tweets(
+            &self,
+            _: &str,
+            _: u32,
+            _: Option<&str>,
+            _: Option<&str>,
+        ) -> Result<SearchResponse, XApiError> {
+            Ok(empty_response(vec![]))
+        }
+
+        async fn get_mentions(
+            &self,
+            _: &str,
+            _: Option<&str>,
+            _: Option<&str>,
+        ) -> Result<MentionResponse, XApiError> {
+            Ok(empty_response(vec![]))
+        }
+
+        async fn post_tweet(&self, text: &str) -> Result<PostedTweet, XApiError> {
+            let n = self.posts.fetch_add(1, Ordering::SeqCst) + 1;
+            let id = format!("t{n}");
+            self.timeline.lock().unwrap().push(Tweet {
+                id: id.clone(),
+                text: text.to_string(),
+                author_id: "me".to_string(),
+                created_at: Utc::now().to_rfc3339(),
+                public_metrics: PublicMetrics::default(),
+                conversation_id: None,
+                entities: None,
+                attachments: None,
+            });
+            if self.timeout_after_post {
+                return Err(XApiError::ApiError {
+                    status: 504,
+                    message: "gateway timeout".to_string(),
+                });
+            }
+            Ok(PostedTweet {
+                id,
+                text: text.to_string(),
+            })
+        }
+
+        async fn reply_to_tweet(&self, text: &str, _: &str) -> Result<PostedTweet, XApiError> {
+            self.post_tweet(text).await
+        }
+
+        async fn get_tweet(&self, _: &str) -> Result<Tweet, XApiError> {
+            Err(XApiError::ApiError {
+                status: 404,
+                message: "not found".to_string(),
+            })
+        }
+
+        async fn get_me(&self) -> Result<User, XApiError> {
+            Ok(User {
+                id: "me".to_string(),
+                username: "me".to_string(),
+                name: "Me".to_string(),
+                public_metrics: UserMetrics::default(),
+            })
+        }
+
+        async fn get_user_tweets(
+            &self,
+            _: &str,
+            _: u32,
+            _: Option<&str>,
+        ) -> Result<SearchResponse, XApiError> {
+            Ok(empty_response(self.timeline.lock().unwrap().clone()))
+        }
+
+        async fn get_user_by_username(&self, _: &str) -> Result<User, XApiError> {
+            self.get_me().await
+        }
+    }
+
+    fn tweet(text: &str) -> OutboundPost<'_> {
+        OutboundPost {
+            scope: "test",
+            text,
+            in_reply_to_id: None,
+            quote_tweet_id: None,
+            media_ids: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn timeout_after_success_is_not_reposted() {
+        let pool = init_test_db().await.expect("init db");
+        let flaky = FlakyClient {
+            timeout_after_post: true,
+            ..Default::default()
+        };
+
+        assert!(post_once(&pool, &flaky, None, tweet("Ship it & see"))
+            .await
+            .is_err());
+        assert_eq!(flaky.posts.load(Ordering::SeqCst), 1);
+
+        let out = post_once(&pool, &flaky, None, tweet("Ship it & see"))
+            .await
+            .unwrap();
+        assert_eq!(out.resolution, PostResolution::FoundOnTimeline);
+        assert_eq!(out.tweet_id, "t1");
+        assert_eq!(out.attempts, 2);
+        assert_eq!(flaky.posts.load(Ordering::SeqCst), 1);
+
+        let logged =
+            action_log::get_actions_since(&pool, "2000-01-01T00:00:00Z", Some("post_dedup"))
+                .await
+                .unwrap();
+        assert_eq!(logged.len(), 1);
+        assert_eq!(logged[0].status, "found_on_timeline");
+    }
+
+    #[tokio::test]
+    async fn confirmed_post_is_not_resent() {
+        let pool = init_test_db().await.expect("init db");
+        let client = FlakyClient::default();
+
+        let first = post_once(&pool, &client, Some("me"), tweet("Hello"))
+            .await
+            .unwrap();
+        assert_eq!(first.resolution, PostResolution::Posted);
+
+        let again = post_once(&pool, &client, Some("me"), tweet("Hello"))
+            .await
+            .unwrap();
+        assert_eq!(again.resolution, PostResolution::AlreadyPosted);
+        assert_eq!(again.tweet_id, first.tweet_id);
+        assert_eq!(client.posts.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn comparable_text_ignores_links_and_escaping() {
+        assert_eq!(
+            comparable_text("Read  this &amp; that https://t.co/abc"),
+            comparable_text("Read this & that\nhttps://example.com/post")
+        );
+    }
+}
@@ -0,0 +1,393 @@
+//! Ingest step: score externally pushed candidates, draft replies, and queue them.
+//!
+//! Backs `POST /api/ingest/candidates`. Each candidate is a tweet URL or raw
+//! text from another system. Tweets are fetched from X, so what is scored
+//! and stored with the discovered tweets is X's text and real author, never
+//! the caller's claims. Raw text is stored in its own table. Blocked and
+//! protected authors are skipped before anything is stored. Candidates that
+//! meet the threshold get a generated reply in the approval queue, whatever
+//! `approval_mode` says, tagged with the candidate's source. Replies to
+//! tweets are posted by the approval poster; replies to raw text are queued
+//! on the `external` platform and posted by hand.
+
+use sha2::{Digest, Sha256};
+
+use crate::config::Config;
+use crate::content::ContentGenerator;
+use crate::safety::{contains_banned_phrase, protected};
+use crate::scoring::{find_matched_keywords, ScoringEngine, ScoringPlugin, TweetData};
+use crate::storage;
+use crate::storage::approval_queue::PLATFORM_EXTERNAL;
+use crate::storage::ingested_candidates::IngestedCandidate;
+use crate::storage::tweets::DiscoveredTweet;
+use crate::storage::DbPool;
+use crate::toolkit;
+use crate::x_api::XApiClient;
+
+use super::WorkflowError;
+
+pub use tuitbot_types::ingest::{CandidateInput, CandidateResult};
+
+/// Maximum number of candidates accepted per call.
+pub const MAX_CANDIDATES: usize = 50;
+
+/// Input for the ingest step.
+#[derive(Debug, Clone)]
+pub struct IngestInput {
+    pub candidates: Vec<CandidateInput>,
+    /// Whether generated replies may mention the product.
+    pub mention_product: bool,
+}
+
+/// Execute the ingest step for `account_id`.
+///
+/// Returns one `CandidateResult` per candidate, in order. Individual failures
+/// don't abort the batch. Tweet candidates are skipped when `x_client` is
+/// `None`, since they cannot be fetched.
+pub async fn execute(
+    db: &DbPool,
+    account_id: &str,
+    x_client: Option<&dyn XApiClient>,
+    gen: &ContentGenerator,
+    config: &Config,
+    input: IngestInput,
+) -> Result<Vec<CandidateResult>, WorkflowError> {
+    if input.candidates.is_empty() {
+        return Err(WorkflowError::InvalidInput(
+            "candidates must not be empty.".to_string(),
+        ));
+    }
+    if input.candidates.len() > MAX_CANDIDATES {
+        return Err(WorkflowError::InvalidInput(format!(
+            "at most {MAX_CANDIDATES} candidates per request."
+        )));
+    }
+
+    let keywords: Vec<String> = config
+        .business
+        .product_keywords
+        .iter()
+        .chain(config.business.competitor_keywords.iter())
+        .chain(config.business.effective_industry_topics().iter())
+        .cloned()
+        .collect();
+    let engine = ScoringEngine::new(config.scoring.clone(), keywords.clone())
+        .with_plugin(ScoringPlugin::load_or_warn(&config.scoring.plugin));
+    let threshold =
+        storage::threshold_calibrations::current_threshold_for(db, account_id, &config.scoring)
+            .await? as f32;
+
+    let mut results = Vec::with_capacity(input.candidates.len());
+    for candidate in &input.candidates {
+        let result = match ingest_one(
+            db,
+            account_id,
+            x_client,
+            gen,
+            config,
+            &engine,
+            &keywords,
+            threshold,
+            candidate,
+            input.mention_product,
+        )
+        .await
+        {
+            Ok(result) => result,
+            Err(e) => outcome(&candidate_id(candidate), "error", None, Some(e.to_string())),
+        };
+        results.push(result);
+    }
+    Ok(results)
+}
+
+/// A candidate as scored and stored: the tweet as fetched from X, or the
+/// caller's raw text.
+struct Resolved {
+    author_id: String,
+    author: String,
+    text: String,
+    author_followers: u64,
+    likes: u64,
+    retweets: u64,
+    replies: u64,
+    quotes: u64,
+    impressions: Option<i64>,
+    created_at: String,
+}
+
+impl Resolved {
+    /// Fetch a tweet and its author from X.
+    async fn fetch(client: &dyn XApiClient, tweet_id: &str) -> Result<Self, WorkflowError> {
+        let tweet = toolkit::read::get_tweet(client, tweet_id).await?;
+        let author = toolkit::read::get_user_by_id(client, &tweet.author_id).await?;
+        let metrics = tweet.public_metrics;
+        Ok(Self {
+            author_id: tweet.author_id,
+            author: author.username,
+            text: tweet.text,
+            author_followers: author.public_metrics.followers_count,
+            likes: metrics.like_count,
+            retweets: metrics.retweet_count,
+            replies: metrics.reply_count,
+            quotes: metrics.quote_count,
+            impressions: Some(metrics.impression_count as i64),
+            created_at: tweet.created_at,
+        })
+    }
+
+    /// Take a raw-text candidate as given.
+    fn raw(candidate: &CandidateInput) -> Self {
+        Self {
+            author_id: String::new(),
+            author: candidate.author_username.clone().unwrap_or_default(),
+            text: candidate.text.trim().to_string(),
+            author_followers: candidate.author_followers,
+            likes: candidate.likes,
+            retweets: candidate.retweets,
+            replies: candidate.replies,
+            quotes: 0,
+            impressions: None,
+            created_at: candidate
+                .created_at
+                .clone()
+                .unwrap_or_else(|| chrono::Utc::now().to_rfc3339()),
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn ingest_one(
+    db: &DbPool,
+    account_id: &str,
+    x_client: Option<&dyn XApiClient>,
+    gen: &ContentGenerator,
+    config: &Config,
+    engine: &ScoringEngine,
+    keywords: &[String],
+    threshold: f32,
+    candidate: &CandidateInput,
+    mention_product: bool,
+) -> Result<CandidateResult, WorkflowError> {
+    let id = candidate_id(candidate);
+    let skip = |reason: &str| Ok(outcome(&id, "skipped", None, Some(reason.to_string())));
+
+    if !valid_source(&candidate.source) {
+        return skip("source must be 1-64 letters, digits, '-' or '_'");
+    }
+    let tweet_id = match candidate.tweet_url.as_deref() {
+        Some(url) => match parse_tweet_url(url) {
+            Some((tweet_id, _)) => Some(tweet_id),
+            None => return skip("tweet_url is not an X post URL"),
+        },
+        None => None,
+    };
+    if tweet_id.is_none() && candidate.text.trim().is_empty() {
+        return skip("text must not be empty");
+    }
+
+    let seen = match &tweet_id {
+        Some(_) => storage::tweets::get_tweet_by_id_for(db, account_id, &id)
+            .await?
+            .is_some(),
+        None => storage::ingested_candidates::get_candidate_for(db, account_id, &id)
+            .await?
+            .is_some(),
+    };
+    if seen {
+        return skip("already discovered");
+    }
+    if storage::replies::has_replied_to_for(db, account_id, &id).await? {
+        return skip("already replied");
+    }
+
+    let post = match (&tweet_id, x_client) {
+        (Some(tweet_id), Some(client)) => Resolved::fetch(client, tweet_id).await?,
+        (Some(_), None) => return skip("X API is not configured, so the tweet cannot be fetched"),
+        (None, _) => Resolved::raw(candidate),
+    };
+    let author = post.author.clone();
+
+    if !author.is_empty() {
+        if let Some(entry) =
+            storage::blocklist::find_for(db, account_id, &post.author_id, &author).await?
+        {
+            return skip(&format!(
+                "author @{} is on the blocklist",
+                entry.author_username
+            ));
+        }
+    }
+    let protected_list = protected::normalize_protected(&config.blocklist.protected);
+    if protected::is_protected(&author, &protected_list) {
+        return skip("author is a protected entity");
+    }
+
+    let score = engine.score_tweet(&TweetData {
+        text: post.text.clone(),
+        created_at: post.created_at.clone(),
+        likes: post.likes,
+        retweets: post.retweets,
+        replies: post.replies,
+        author_username: author.clone(),
+        author_followers: post.author_followers,
+        has_media: false,
+        is_quote_tweet: false,
+        urls: candidate.url.iter().cloned().collect(),
+    });
+    let matched = find_matched_keywords(&post.text, keywords);
+    let now = chrono::Utc::now().format("%Y-%m-%dT%H:%M:%SZ").to_string();
+
+    if tweet_id.is_some() {
+        storage::tweets::insert_discovered_tweet_for(
+            db,
+            account_id,
+            &DiscoveredTweet {
+                id: id.clone(),
+                author_id: post.author_id.clone(),
+                author_username: author.clone(),
+                content: post.text.clone(),
+                like_count: post.likes as i64,
+                retweet_count: post.retweets as i64,
+                reply_count: post.replies as i64,
+                quote_count: post.quotes as i64,
+                impression_count: post.impressions,
+                relevance_score: Some(score.total as f64),
+                matched_keyword: matched.first().cloned(),
+                discovered_at: now,
+                replied_to: 0,
+            },
+        )
+        .await?;
+    } else {
+        storage::ingested_candidates::insert_candidate_for(
+            db,
+            account_id,
+            &IngestedCandidate {
+                id: id.clone(),
+                source: candidate.source.clone(),
+                author_username: author.clone(),
+                content: post.text.clone(),
+                url: candidate.url.clone(),
+                relevance_score: Some(score.total as f64),
+                matched_keyword: matched.first().cloned(),
+                ingested_at: now,
+            },
+        )
+        .await?;
+    }
+
+    let total = Some(score.total as f64);
+    if score.total < threshold {
+        return Ok(outcome(&id, "below_threshold", total, None));
+    }
+
+    let reply = gen
+        .generate_reply(&post.text, &author, mention_product)
+        .await?
+        .text;
+    if let Some(phrase) = contains_banned_phrase(&reply, &config.limits.banned_phrases) {
+        return Ok(outcome(
+            &id,
+            "skipped",
+            total,
+            Some(format!("reply contains banned phrase: {phrase}")),
+        ));
+    }
+
+    let topic = matched.first().map(String::as_str).unwrap_or("");
+    let queue_id = match &tweet_id {
+        Some(tweet_id) => {
+            storage::approval_queue::enqueue_for(
+                db,
+                account_id,
+                "reply",
+                tweet_id,
+                &author,
+                &reply,
+                topic,
+                "",
+                score.total as f64,
+                "[]",
+            )
+            .await?
+        }
+        None => {
+            storage::approval_queue::enqueue_on_platform_for(
+                db,
+                account_id,
+                PLATFORM_EXTERNAL,
+                "reply",
+                candidate.url.as_deref().unwrap_or(""),
+                &author,
+                &reply,
+                topic,
+                score.total as f64,
+            )
+            .await?
+        }
+    };
+    storage::approval_queue::set_source_for(db, account_id, queue_id, &candidate.source).await?;
+
+    let mut result = outcome(&id, "queued", total, None);
+    result.approval_queue_id = Some(queue_id);
+    Ok(result)
+}
+
+fn outcome(id: &str, status: &str, score: Option<f64>, reason: Option<String>) -> CandidateResult {
+    CandidateResult {
+        candidate_id: id.to_string(),
+        status: status.to_string(),
+        score,
+        approval_queue_id: None,
+        reason,
+    }
+}
+
+/// The tweet ID for tweet URLs, otherwise `ingest:<source>:<text hash>`.
+fn candidate_id(candidate: &CandidateInput) -> String {
+    if let Some((tweet_id, _)) = candidate.tweet_url.as_deref().and_then(parse_tweet_url) {
+        return tweet_id;
+    }
+    let digest = Sha256::digest(candidate.text.trim().as_bytes());
+    format!("ingest:{}:{}", candidate.source, &hex::encode(digest)[..16])
+}
+
+fn valid_source(source: &str) -> bool {
+    !source.is_empty()
+        && source.len() <= 64
+        && source
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
+/// Parse an X post URL into its tweet ID and, when present, the author handle.
+///
+/// Accepts `x.com` and `twitter.com` hosts (with `www.` or `mobile.`), and
+/// both `/<user>/status/<id>` and `/i/web/status/<id>` paths.
+pub fn parse_tweet_url(url: &str) -> Option<(String, Option<String>)> {
+    let rest = url
+        .trim()
+        .strip_prefix("https://")
+        .or_else(|| url.trim().strip_prefix("http://"))?;
+    let (host, path) = rest.split_once('/')?;
+    let host = host
+        .trim_start_matches("www.")
+        .trim_start_matches("mobile.");
+    if host != "x.com" && host != "twitter.com" {
+        return None;
+    }
+
+    let path = path.split(['?', '#']).next().unwrap_or_default();
+    let segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+    let status_at = segments.iter().position(|s| *s == "status")?;
+    let tweet_id = segments.get(status_at + 1)?;
+    if tweet_id.is_empty() || !tweet_id.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let author = match segments[..status_at] {
+        [user] if user != "i" => Some(user.to_string()),
+        _ => None,
+    };
+    Some((tweet_id.to_string(), author))
+}
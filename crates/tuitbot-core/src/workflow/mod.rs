@@ -11,9 +11,14 @@
 
 pub mod discover;
 pub mod draft;
+pub mod draft_workspace;
+pub mod idempotent_post;
+pub mod ingest;
 pub mod orchestrate;
 pub mod publish;
 pub mod queue;
+pub mod session_plan;
+pub mod target_import;
 pub mod thread_plan;
 
 #[cfg(test)]
@@ -28,6 +33,7 @@ use serde::Serialize;
 use crate::content::frameworks::ReplyArchetype;
 use crate::error::XApiError;
 use crate::llm::{GenerationParams, LlmProvider, LlmResponse};
+use crate::scoring::EntityType;
 use crate::toolkit::ToolkitError;
 use crate::LlmError;
 
@@ -108,9 +114,12 @@ pub struct ScoredCandidate {
     pub created_at: String,
     pub score_total: f32,
     pub score_breakdown: ScoreBreakdown,
+    pub entity_type: EntityType,
     pub matched_keywords: Vec<String>,
     pub recommended_action: String,
     pub already_replied: bool,
+    /// Author is an active target account; such candidates rank first.
+    pub is_target: bool,
 }
 
 /// Per-signal score breakdown.
@@ -122,6 +131,10 @@ pub struct ScoreBreakdown {
     pub engagement: f32,
     pub reply_count: f32,
     pub content_type: f32,
+    pub entity_adjustment: f32,
+    pub plugin_adjustment: f32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub plugin_rationale: Option<String>,
 }
 
 /// Result of drafting a reply for a single candidate.
@@ -194,10 +207,11 @@ pub fn parse_archetype(s: &str) -> Option<ReplyArchetype> {
 /// Build a `ContentGenerator` from a shared LLM provider.
 pub(crate) fn make_content_gen(
     llm: &Arc<dyn LlmProvider>,
-    business: &crate::config::BusinessProfile,
+    config: &crate::config::Config,
 ) -> crate::content::ContentGenerator {
     let provider = Box::new(SharedProvider(Arc::clone(llm)));
-    crate::content::ContentGenerator::new(provider, business.clone())
+    crate::content::ContentGenerator::new(provider, config.business.clone())
+        .with_disclosure(config.disclosure.clone())
 }
 
 // ── Helper: convert XApiError fields ────────────────────────────────
@@ -213,7 +227,9 @@ impl WorkflowError {
 
 pub use discover::{DiscoverInput, DiscoverOutput};
 pub use draft::DraftInput;
+pub use ingest::IngestInput;
 pub use orchestrate::{CycleInput, CycleReport};
 pub use publish::PublishOutput;
 pub use queue::QueueInput;
+pub use session_plan::{SessionPlan, SessionPlanInput};
 pub use thread_plan::{ThreadPlanInput, ThreadPlanOutput};
@@ -8,7 +8,7 @@ use std::sync::Arc;
 
 use crate::config::Config;
 use crate::llm::LlmProvider;
-use crate::safety::{contains_banned_phrase, DedupChecker};
+use crate::safety::{contains_banned_phrase, protected, trusted, DedupChecker, TrustedAuthors};
 use crate::storage;
 use crate::storage::DbPool;
 use crate::toolkit;
@@ -28,7 +28,10 @@ pub struct QueueInput {
 /// Execute the queue step: validate, safety-check, route or execute.
 ///
 /// When `approval_mode` is true, replies are queued for human review.
-/// When false, replies are executed immediately via toolkit.
+/// When false, replies are executed immediately via toolkit. Replies to
+/// trusted authors may skip approval or the phrasing-similarity check,
+/// per `[[trusted_authors]]`; each bypass is logged. While draft-only mode
+/// is on, every reply is queued. Replies to protected entities are blocked.
 ///
 /// All X API writes go through `toolkit::write::reply_to_tweet`.
 pub async fn execute(
@@ -45,11 +48,17 @@ pub async fn execute(
     }
 
     let approval_mode = config.effective_approval_mode();
+    // Fails closed: if the flag cannot be read, replies are queued.
+    let draft_only = storage::draft_only::is_draft_only_active(db)
+        .await
+        .unwrap_or(true);
     let dedup = DedupChecker::new(db.clone());
     let banned = &config.limits.banned_phrases;
+    let trusted_authors = TrustedAuthors::from_config(&config.trusted_authors);
+    let protected = protected::normalize_protected(&config.blocklist.protected);
 
     // Build content generator if LLM is available (needed for auto-generation)
-    let gen = llm.map(|l| make_content_gen(l, &config.business));
+    let gen = llm.map(|l| make_content_gen(l, config));
 
     let mut results = Vec::with_capacity(input.items.len());
 
@@ -73,6 +82,17 @@ pub async fn execute(
             }
         };
 
+        if protected::is_protected(&tweet.author_username, &protected) {
+            results.push(ProposeResult::Blocked {
+                candidate_id: item.candidate_id.clone(),
+                reason: format!(
+                    "@{} is a protected entity.",
+                    protected::normalize_username(&tweet.author_username)
+                ),
+            });
+            continue;
+        }
+
         // Determine reply text: pre-drafted or auto-generate
         let reply_text = if let Some(text) = &item.pre_drafted_text {
             text.clone()
@@ -106,6 +126,8 @@ pub async fn execute(
             }
         };
 
+        let bypass = trusted_authors.bypass_for(&tweet.author_username);
+
         // Safety checks
         if let Ok(true) = dedup.has_replied_to(&item.candidate_id).await {
             results.push(ProposeResult::Blocked {
@@ -124,15 +146,20 @@ pub async fn execute(
         }
 
         if let Ok(true) = dedup.is_phrasing_similar(&reply_text, 20).await {
-            results.push(ProposeResult::Blocked {
-                candidate_id: item.candidate_id.clone(),
-                reason: "Reply too similar to a recent reply.".to_string(),
-            });
-            continue;
+            if bypass.skip_soft_qa {
+                let detail = format!("tweet {}, similar phrasing", item.candidate_id);
+                let _ = trusted::log_bypass(db, &tweet.author_username, "soft_qa", &detail).await;
+            } else {
+                results.push(ProposeResult::Blocked {
+                    candidate_id: item.candidate_id.clone(),
+                    reason: "Reply too similar to a recent reply.".to_string(),
+                });
+                continue;
+            }
         }
 
         // Route: approval queue or direct execution
-        if approval_mode {
+        if draft_only || (approval_mode && !bypass.skip_approval) {
             match storage::approval_queue::enqueue(
                 db,
                 "reply",
@@ -178,6 +205,12 @@ pub async fn execute(
             {
                 Ok(posted) => {
                     let _ = storage::tweets::mark_tweet_replied(db, &item.candidate_id).await;
+                    if approval_mode {
+                        let detail = format!("tweet {}", item.candidate_id);
+                        let _ =
+                            trusted::log_bypass(db, &tweet.author_username, "approval", &detail)
+                                .await;
+                    }
                     results.push(ProposeResult::Executed {
                         candidate_id: item.candidate_id.clone(),
                         reply_tweet_id: posted.id,
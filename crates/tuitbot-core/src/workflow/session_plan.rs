@@ -0,0 +1,444 @@
+//! Session plan step: turn a time budget into a ranked engagement plan.
+//!
+//! Given the minutes someone (or an agent) has for a session, assemble the
+//! actions worth doing in that time: scheduled content that is due before
+//! the session ends, the best reply opportunities with drafts, one quote
+//! tweet suggestion, and likes for the next-best tweets. Each action is
+//! bounded by the remaining rate limit for its type and by a fixed time
+//! estimate. Nothing is posted; the plan is executed step by step with the
+//! tool named on each step.
+
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::config::Config;
+use crate::llm::LlmProvider;
+use crate::storage::{self, DbPool};
+use crate::x_api::XApiClient;
+
+use super::discover::{self, DiscoverInput};
+use super::draft::{self, DraftInput};
+use super::{make_content_gen, DraftResult, ScoredCandidate, WorkflowError};
+
+/// Shortest and longest session budgets accepted, in minutes.
+pub const MIN_SESSION_MINUTES: u32 = 5;
+pub const MAX_SESSION_MINUTES: u32 = 240;
+
+/// Estimated minutes per action, including review.
+const SCHEDULED_MINUTES: f64 = 1.0;
+const REPLY_MINUTES: f64 = 2.0;
+const QUOTE_MINUTES: f64 = 3.0;
+const LIKE_MINUTES: f64 = 0.25;
+
+/// Most likes planned in one session.
+const MAX_LIKES: usize = 10;
+
+/// Candidates requested from discovery.
+const DISCOVER_LIMIT: u32 = 30;
+
+/// Input for the session plan step.
+#[derive(Debug, Clone)]
+pub struct SessionPlanInput {
+    /// Session length in minutes.
+    pub minutes: u32,
+    /// Search query. If `None`, uses product keywords from config.
+    pub query: Option<String>,
+    /// Whether drafted replies may mention the product.
+    pub mention_product: bool,
+}
+
+/// A ranked, time-boxed action plan.
+#[derive(Debug, Clone, Serialize)]
+pub struct SessionPlan {
+    pub minutes_budget: u32,
+    pub minutes_planned: f64,
+    /// Remaining rate limit per action type when the plan was made.
+    pub remaining: RemainingLimits,
+    pub query_used: String,
+    pub steps: Vec<PlanStep>,
+    /// Why planned counts are lower than they could be.
+    pub notes: Vec<String>,
+}
+
+/// Remaining allowance for the action types a plan uses. `None` means no
+/// limit is configured.
+#[derive(Debug, Clone, Copy, Default, Serialize)]
+pub struct RemainingLimits {
+    pub reply: Option<i64>,
+    pub like: Option<i64>,
+    pub tweet: Option<i64>,
+    pub thread: Option<i64>,
+}
+
+/// One action in a session plan.
+#[derive(Debug, Clone, Serialize)]
+pub struct PlanStep {
+    /// 1-based position in the plan.
+    pub step: usize,
+    /// `publish_scheduled`, `reply`, `quote`, or `like`.
+    pub action: String,
+    /// Tool that executes the step.
+    pub tool: String,
+    pub minutes: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tweet_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub score: Option<f32>,
+    /// The tweet being engaged with, or the scheduled content.
+    pub text: String,
+    /// Drafted reply or quote text, when an LLM is available.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub draft: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scheduled_content_id: Option<i64>,
+    pub reason: String,
+}
+
+/// How many of each action fit in a budget.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Allocation {
+    pub scheduled: usize,
+    pub replies: usize,
+    pub quote: bool,
+    pub likes: usize,
+}
+
+/// Split `minutes` across actions in priority order: due scheduled content,
+/// one quote tweet (when there is time left to reply too), replies, then
+/// likes with what remains. A fifth of the post-quote time is held back for
+/// likes. Counts never exceed what is available or the remaining rate limit.
+pub fn allocate(
+    minutes: u32,
+    scheduled_available: usize,
+    candidates: usize,
+    limits: RemainingLimits,
+) -> Allocation {
+    let cap = |limit: Option<i64>, n: usize| match limit {
+        Some(left) => n.min(left.max(0) as usize),
+        None => n,
+    };
+    let mut left = f64::from(minutes);
+
+    let scheduled = scheduled_available.min((left / SCHEDULED_MINUTES) as usize);
+    left -= scheduled as f64 * SCHEDULED_MINUTES;
+
+    let quote =
+        candidates > 1 && cap(limits.tweet, 1) == 1 && left >= QUOTE_MINUTES + REPLY_MINUTES;
+    if quote {
+        left -= QUOTE_MINUTES;
+    }
+    let pool = candidates - usize::from(quote);
+
+    let replies = cap(limits.reply, pool).min((left * 0.8 / REPLY_MINUTES) as usize);
+    left -= replies as f64 * REPLY_MINUTES;
+
+    let likes = cap(
+        limits.like,
+        (pool - replies)
+            .min(MAX_LIKES)
+            .min((left / LIKE_MINUTES) as usize),
+    );
+
+    Allocation {
+        scheduled,
+        replies,
+        quote,
+        likes,
+    }
+}
+
+/// Execute the session plan step.
+///
+/// Searches and scores tweets like the discover step (persisting them), and
+/// drafts replies and the quote when `llm` is given. Nothing is posted.
+pub async fn execute(
+    db: &DbPool,
+    x_client: &dyn XApiClient,
+    llm: Option<&Arc<dyn LlmProvider>>,
+    config: &Config,
+    input: SessionPlanInput,
+) -> Result<SessionPlan, WorkflowError> {
+    if !(MIN_SESSION_MINUTES..=MAX_SESSION_MINUTES).contains(&input.minutes) {
+        return Err(WorkflowError::InvalidInput(format!(
+            "minutes must be between {MIN_SESSION_MINUTES} and {MAX_SESSION_MINUTES}."
+        )));
+    }
+
+    let now = Utc::now();
+    let remaining = remaining_limits(db, now).await?;
+    let mut notes = Vec::new();
+
+    let session_end = now + chrono::Duration::minutes(i64::from(input.minutes));
+    let scheduled = due_scheduled(db, session_end, remaining).await?;
+
+    let discovered = discover::execute(
+        db,
+        x_client,
+        config,
+        DiscoverInput {
+            query: input.query,
+            min_score: None,
+            limit: Some(DISCOVER_LIMIT),
+            since_id: None,
+        },
+    )
+    .await?;
+    let candidates: Vec<&ScoredCandidate> = discovered
+        .candidates
+        .iter()
+        .filter(|c| !c.already_replied && c.recommended_action != "skip")
+        .collect();
+
+    // Scheduled tweets come out of the same allowance as the quote.
+    let mut after_scheduled = remaining;
+    let scheduled_tweets = scheduled
+        .iter()
+        .filter(|i| i.content_type != "thread")
+        .count();
+    after_scheduled.tweet = remaining.tweet.map(|t| t - scheduled_tweets as i64);
+    let plan = allocate(
+        input.minutes,
+        scheduled.len(),
+        candidates.len(),
+        after_scheduled,
+    );
+    if remaining.reply == Some(0) {
+        notes.push("Daily reply limit reached; no replies planned.".to_string());
+    }
+    if remaining.like == Some(0) {
+        notes.push("Daily like limit reached; no likes planned.".to_string());
+    }
+    if candidates.is_empty() {
+        notes.push("No tweets above the score threshold were found.".to_string());
+    }
+    if llm.is_none() && (plan.replies > 0 || plan.quote) {
+        notes.push("No LLM provider configured; replies and the quote have no drafts.".to_string());
+    }
+
+    // Replies take the top-ranked tweets; the quote takes the most engaged of
+    // the rest, and likes the next ones down.
+    let (reply_targets, rest) = candidates.split_at(plan.replies.min(candidates.len()));
+    let mut rest: Vec<&ScoredCandidate> = rest.to_vec();
+    let quote_target = if plan.quote {
+        let best = rest
+            .iter()
+            .enumerate()
+            .max_by(|(_, a), (_, b)| {
+                a.score_breakdown
+                    .engagement
+                    .total_cmp(&b.score_breakdown.engagement)
+            })
+            .map(|(i, _)| i);
+        best.map(|i| rest.remove(i))
+    } else {
+        None
+    };
+    rest.truncate(plan.likes);
+    let like_targets = rest;
+
+    let reply_drafts = match (llm, reply_targets.is_empty()) {
+        (Some(llm), false) => draft_replies(db, llm, config, reply_targets, input.mention_product)
+            .await
+            .unwrap_or_else(|e| {
+                notes.push(format!("Reply drafting failed: {e}"));
+                Vec::new()
+            }),
+        _ => Vec::new(),
+    };
+    let quote_draft = match (llm, quote_target) {
+        (Some(llm), Some(target)) => make_content_gen(llm, config)
+            .generate_quote(&target.text, &target.author_username, None)
+            .await
+            .map(|out| out.text)
+            .map_err(|e| notes.push(format!("Quote drafting failed: {e}")))
+            .ok(),
+        _ => None,
+    };
+
+    let mut steps = Vec::new();
+    for item in scheduled.iter().take(plan.scheduled) {
+        let due = item.scheduled_for.as_deref().unwrap_or_default();
+        steps.push(PlanStep {
+            step: 0,
+            action: "publish_scheduled".to_string(),
+            tool: if item.content_type == "thread" {
+                "x_post_thread"
+            } else {
+                "x_post_tweet"
+            }
+            .to_string(),
+            minutes: SCHEDULED_MINUTES,
+            tweet_id: None,
+            author: None,
+            score: None,
+            text: item.content.clone(),
+            draft: None,
+            scheduled_content_id: Some(item.id),
+            reason: format!("Scheduled {} due {due}", item.content_type),
+        });
+    }
+    for candidate in reply_targets {
+        let draft = reply_drafts.iter().find_map(|d| match d {
+            DraftResult::Success {
+                candidate_id,
+                draft_text,
+                ..
+            } if candidate_id == &candidate.tweet_id => Some(draft_text.clone()),
+            _ => None,
+        });
+        steps.push(candidate_step(
+            "reply",
+            "propose_and_queue_replies",
+            REPLY_MINUTES,
+            candidate,
+            draft,
+            reply_reason(candidate),
+        ));
+    }
+    if let Some(candidate) = quote_target {
+        steps.push(candidate_step(
+            "quote",
+            "x_quote_tweet",
+            QUOTE_MINUTES,
+            candidate,
+            quote_draft,
+            "Most engaged remaining tweet; worth amplifying".to_string(),
+        ));
+    }
+    for candidate in like_targets {
+        steps.push(candidate_step(
+            "like",
+            "x_like_tweet",
+            LIKE_MINUTES,
+            candidate,
+            None,
+            format!(
+                "Relevant (score {:.0}) but lower priority",
+                candidate.score_total
+            ),
+        ));
+    }
+    for (i, step) in steps.iter_mut().enumerate() {
+        step.step = i + 1;
+    }
+
+    Ok(SessionPlan {
+        minutes_budget: input.minutes,
+        minutes_planned: steps.iter().map(|s| s.minutes).sum(),
+        remaining,
+        query_used: discovered.query_used,
+        steps,
+        notes,
+    })
+}
+
+async fn remaining_limits(
+    db: &DbPool,
+    now: DateTime<Utc>,
+) -> Result<RemainingLimits, WorkflowError> {
+    let mut remaining = RemainingLimits::default();
+    for limit in storage::rate_limits::get_all_rate_limits(db).await? {
+        let slot = match limit.action_type.as_str() {
+            "reply" => &mut remaining.reply,
+            "like" => &mut remaining.like,
+            "tweet" => &mut remaining.tweet,
+            "thread" => &mut remaining.thread,
+            _ => continue,
+        };
+        *slot = Some(limit.remaining(now));
+    }
+    Ok(remaining)
+}
+
+/// Scheduled items due before `session_end`, oldest first, within the
+/// remaining tweet and thread limits.
+async fn due_scheduled(
+    db: &DbPool,
+    session_end: DateTime<Utc>,
+    remaining: RemainingLimits,
+) -> Result<Vec<storage::scheduled_content::ScheduledContent>, WorkflowError> {
+    let items = storage::scheduled_content::get_in_range(
+        db,
+        "0000-01-01T00:00:00Z",
+        &session_end.format("%Y-%m-%dT%H:%M:%SZ").to_string(),
+    )
+    .await?;
+    let (mut tweets, mut threads) = (remaining.tweet, remaining.thread);
+    Ok(items
+        .into_iter()
+        .filter(|item| item.status == "scheduled" && item.scheduled_for.is_some())
+        .filter(|item| {
+            let left = if item.content_type == "thread" {
+                &mut threads
+            } else {
+                &mut tweets
+            };
+            match left {
+                Some(0) => false,
+                Some(n) => {
+                    *n -= 1;
+                    true
+                }
+                None => true,
+            }
+        })
+        .collect())
+}
+
+async fn draft_replies(
+    db: &DbPool,
+    llm: &Arc<dyn LlmProvider>,
+    config: &Config,
+    targets: &[&ScoredCandidate],
+    mention_product: bool,
+) -> Result<Vec<DraftResult>, WorkflowError> {
+    draft::execute(
+        db,
+        llm,
+        config,
+        DraftInput {
+            candidate_ids: targets.iter().map(|c| c.tweet_id.clone()).collect(),
+            archetype: None,
+            mention_product,
+        },
+    )
+    .await
+}
+
+fn reply_reason(candidate: &ScoredCandidate) -> String {
+    let mut reason = format!("Score {:.0}", candidate.score_total);
+    if candidate.is_target {
+        reason.push_str(", target account");
+    }
+    if let Some(keyword) = candidate.matched_keywords.first() {
+        reason.push_str(&format!(", matches \"{keyword}\""));
+    }
+    reason
+}
+
+fn candidate_step(
+    action: &str,
+    tool: &str,
+    minutes: f64,
+    candidate: &ScoredCandidate,
+    draft: Option<String>,
+    reason: String,
+) -> PlanStep {
+    PlanStep {
+        step: 0,
+        action: action.to_string(),
+        tool: tool.to_string(),
+        minutes,
+        tweet_id: Some(candidate.tweet_id.clone()),
+        author: Some(candidate.author_username.clone()),
+        score: Some(candidate.score_total),
+        text: candidate.text.clone(),
+        draft,
+        scheduled_content_id: None,
+        reason,
+    }
+}
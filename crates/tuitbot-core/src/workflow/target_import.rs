@@ -0,0 +1,222 @@
+//! Target import step: seed many target accounts at once.
+//!
+//! Backs `tuitbot targets import`. Handles come from a CSV file (first
+//! column, optional `username` header, `@handles` and profile URLs
+//! accepted) or from the accounts a user follows. Handles are checked
+//! locally, deduplicated against the input and the existing targets, and
+//! then resolved on X in batches of 100 so unknown or suspended accounts
+//! are reported instead of imported. Resolved accounts are stored with
+//! their X user ID and the requested tier; the caller adds them to
+//! `targets.accounts` so the target loop monitors them.
+
+use std::collections::HashSet;
+
+use serde::Serialize;
+
+use crate::config::TargetTier;
+use crate::storage::{target_accounts, DbPool};
+use crate::toolkit;
+use crate::x_api::XApiClient;
+
+use super::WorkflowError;
+
+/// Usernames per lookup request (X API maximum).
+const LOOKUP_BATCH: usize = 100;
+
+/// Accounts per following page (X API maximum).
+const FOLLOWING_PAGE: u32 = 1000;
+
+/// An entry that was not imported, and why.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SkippedEntry {
+    pub entry: String,
+    pub reason: String,
+}
+
+/// Outcome of an import.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ImportReport {
+    /// Usernames imported, as spelled on X.
+    pub added: Vec<String>,
+    /// Valid handles left out: duplicates and existing targets.
+    pub skipped: Vec<SkippedEntry>,
+    /// Malformed handles and accounts that do not exist on X.
+    pub invalid: Vec<SkippedEntry>,
+}
+
+/// Handles from CSV `content`: the first column of each non-empty row,
+/// skipping a `username` or `handle` header.
+pub fn parse_csv(content: &str) -> Vec<String> {
+    content
+        .lines()
+        .filter_map(|line| line.split(',').next())
+        .map(|cell| cell.trim().trim_matches('"').trim().to_string())
+        .filter(|cell| !cell.is_empty())
+        .enumerate()
+        .filter(|(i, cell)| {
+            *i > 0 || !matches!(cell.to_lowercase().as_str(), "username" | "handle")
+        })
+        .map(|(_, cell)| cell)
+        .collect()
+}
+
+/// The username in `entry`: a handle with or without `@`, or an
+/// `x.com`/`twitter.com` profile URL. `None` if it is not a valid handle.
+pub fn normalize_handle(entry: &str) -> Option<String> {
+    let entry = entry.trim();
+    let handle = match entry
+        .strip_prefix("https://")
+        .or_else(|| entry.strip_prefix("http://"))
+    {
+        Some(rest) => {
+            let (host, path) = rest.split_once('/')?;
+            let host = host.trim_start_matches("www.");
+            if host != "x.com" && host != "twitter.com" {
+                return None;
+            }
+            path.split(['/', '?', '#']).next()?
+        }
+        None => entry.trim_start_matches('@'),
+    };
+    let valid = (1..=15).contains(&handle.len())
+        && handle
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_');
+    valid.then(|| handle.to_string())
+}
+
+/// Validate `entries` and import the accounts that exist on X and are not
+/// in `existing` (compared ignoring case). With `dry_run`, nothing is
+/// stored and `added` lists what would be imported.
+pub async fn import_handles(
+    db: &DbPool,
+    client: &dyn XApiClient,
+    entries: &[String],
+    existing: &[String],
+    tier: TargetTier,
+    dry_run: bool,
+) -> Result<ImportReport, WorkflowError> {
+    let mut report = ImportReport::default();
+    let seen = known_targets(db, existing).await?;
+
+    let mut pending = Vec::new();
+    let mut in_input = HashSet::new();
+    for entry in entries {
+        let Some(handle) = normalize_handle(entry) else {
+            report
+                .invalid
+                .push(skipped(entry, "not a valid X username"));
+            continue;
+        };
+        let key = handle.to_lowercase();
+        if !in_input.insert(key.clone()) {
+            report.skipped.push(skipped(entry, "duplicate in input"));
+        } else if seen.contains(&key) {
+            report.skipped.push(skipped(entry, "already a target"));
+        } else {
+            pending.push(handle);
+        }
+    }
+
+    for batch in pending.chunks(LOOKUP_BATCH) {
+        let names: Vec<&str> = batch.iter().map(String::as_str).collect();
+        let users = toolkit::read::get_users_by_usernames(client, &names).await?;
+        for handle in batch {
+            match users
+                .iter()
+                .find(|u| u.username.eq_ignore_ascii_case(handle))
+            {
+                Some(user) => {
+                    if !dry_run {
+                        store(db, &user.id, &user.username, tier).await?;
+                    }
+                    report.added.push(user.username.clone());
+                }
+                None => report
+                    .invalid
+                    .push(skipped(handle, "not found on X or suspended")),
+            }
+        }
+    }
+    Ok(report)
+}
+
+/// Import up to `limit` accounts that `username` follows, skipping
+/// existing targets. They need no lookup: X returned them.
+pub async fn import_following(
+    db: &DbPool,
+    client: &dyn XApiClient,
+    username: &str,
+    limit: usize,
+    existing: &[String],
+    tier: TargetTier,
+    dry_run: bool,
+) -> Result<ImportReport, WorkflowError> {
+    let Some(handle) = normalize_handle(username) else {
+        return Err(WorkflowError::InvalidInput(format!(
+            "'{username}' is not a valid X username."
+        )));
+    };
+    let owner = toolkit::read::get_user_by_username(client, &handle).await?;
+
+    let mut report = ImportReport::default();
+    let mut seen = known_targets(db, existing).await?;
+
+    let mut token: Option<String> = None;
+    let mut fetched = 0;
+    while fetched < limit {
+        let page =
+            toolkit::read::get_following(client, &owner.id, FOLLOWING_PAGE, token.as_deref())
+                .await?;
+        for user in page.data {
+            if fetched >= limit {
+                break;
+            }
+            fetched += 1;
+            if !seen.insert(user.username.to_lowercase()) {
+                report
+                    .skipped
+                    .push(skipped(&user.username, "already a target"));
+                continue;
+            }
+            if !dry_run {
+                store(db, &user.id, &user.username, tier).await?;
+            }
+            report.added.push(user.username);
+        }
+        token = page.meta.next_token;
+        if token.is_none() {
+            break;
+        }
+    }
+    Ok(report)
+}
+
+/// Lowercased usernames of configured and stored active targets.
+async fn known_targets(db: &DbPool, existing: &[String]) -> Result<HashSet<String>, WorkflowError> {
+    let mut seen: HashSet<String> = existing.iter().map(|u| u.to_lowercase()).collect();
+    for target in target_accounts::get_active_target_accounts(db).await? {
+        seen.insert(target.username.to_lowercase());
+    }
+    Ok(seen)
+}
+
+async fn store(
+    db: &DbPool,
+    user_id: &str,
+    username: &str,
+    tier: TargetTier,
+) -> Result<(), WorkflowError> {
+    target_accounts::upsert_target_account(db, user_id, username).await?;
+    // Re-activates an account removed earlier.
+    target_accounts::restore_target_account(db, username).await?;
+    target_accounts::set_target_tier(db, username, tier.as_str()).await?;
+    Ok(())
+}
+
+fn skipped(entry: &str, reason: &str) -> SkippedEntry {
+    SkippedEntry {
+        entry: entry.to_string(),
+        reason: reason.to_string(),
+    }
+}
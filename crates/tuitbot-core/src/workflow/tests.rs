@@ -104,9 +104,22 @@ impl XApiClient for MockXApiClient {
             created_at: "2026-02-24T00:00:00Z".to_string(),
             public_metrics: PublicMetrics::default(),
             conversation_id: None,
+            entities: None,
+            attachments: None,
         })
     }
 
+    async fn get_user_by_id(&self, user_id: &str) -> Result<User, XApiError> {
+        self.users
+            .iter()
+            .find(|u| u.id == user_id)
+            .cloned()
+            .ok_or(XApiError::ApiError {
+                status: 404,
+                message: "user not found".to_string(),
+            })
+    }
+
     async fn get_me(&self) -> Result<User, XApiError> {
         Ok(User {
             id: "u1".to_string(),
@@ -169,6 +182,34 @@ impl XApiClient for MockXApiClient {
     ) -> Result<bool, XApiError> {
         Ok(false)
     }
+
+    async fn get_users_by_usernames(&self, usernames: &[&str]) -> Result<Vec<User>, XApiError> {
+        Ok(self
+            .users
+            .iter()
+            .filter(|u| {
+                usernames
+                    .iter()
+                    .any(|n| n.eq_ignore_ascii_case(&u.username))
+            })
+            .cloned()
+            .collect())
+    }
+
+    async fn get_following(
+        &self,
+        _user_id: &str,
+        _max_results: u32,
+        _pagination_token: Option<&str>,
+    ) -> Result<UsersResponse, XApiError> {
+        Ok(UsersResponse {
+            data: self.users.clone(),
+            meta: UsersMeta {
+                result_count: self.users.len() as u32,
+                next_token: None,
+            },
+        })
+    }
 }
 
 struct MockLlmProvider {
@@ -200,6 +241,7 @@ impl LlmProvider for MockLlmProvider {
             usage: crate::llm::TokenUsage {
                 input_tokens: 10,
                 output_tokens: 5,
+                ..Default::default()
             },
             model: "mock-model".to_string(),
         })
@@ -211,14 +253,16 @@ impl LlmProvider for MockLlmProvider {
 }
 
 fn test_config() -> Config {
-    let mut config = Config::default();
-    config.mcp_policy = McpPolicyConfig {
-        enforce_for_mutations: false,
-        blocked_tools: Vec::new(),
-        require_approval_for: Vec::new(),
-        dry_run_mutations: false,
-        max_mutations_per_hour: 20,
-        ..McpPolicyConfig::default()
+    let mut config = Config {
+        mcp_policy: McpPolicyConfig {
+            enforce_for_mutations: false,
+            blocked_tools: Vec::new(),
+            require_approval_for: Vec::new(),
+            dry_run_mutations: false,
+            max_mutations_per_hour: 20,
+            ..McpPolicyConfig::default()
+        },
+        ..Config::default()
     };
     config.business.product_keywords = vec!["rust".to_string(), "async".to_string()];
     config.business.industry_topics = vec!["software engineering".to_string()];
@@ -241,6 +285,8 @@ fn sample_tweet(id: &str, text: &str, author_id: &str) -> Tweet {
             bookmark_count: 0,
         },
         conversation_id: None,
+        entities: None,
+        attachments: None,
     }
 }
 
@@ -266,6 +312,7 @@ async fn seed_discovered_tweet(db: &storage::DbPool, id: &str, text: &str, autho
         like_count: 10,
         retweet_count: 2,
         reply_count: 1,
+        quote_count: 0,
         impression_count: Some(500),
         relevance_score: Some(75.0),
         matched_keyword: Some("rust".to_string()),
@@ -314,6 +361,43 @@ mod discover_tests {
         assert_eq!(output.query_used, "rust");
     }
 
+    #[tokio::test]
+    async fn target_authors_rank_first() {
+        let db = storage::init_test_db().await.unwrap();
+        storage::target_accounts::upsert_target_account(&db, "a2", "CoffeeLover")
+            .await
+            .unwrap();
+        let tweets = vec![
+            sample_tweet("t1", "Learning rust async programming today", "a1"),
+            sample_tweet("t2", "Just had coffee", "a2"),
+        ];
+        let users = vec![
+            sample_user("a1", "rustdev", 5000),
+            sample_user("a2", "coffeelover", 200),
+        ];
+        let client = MockXApiClient::with_results(tweets, users);
+        let config = test_config();
+
+        let output = discover::execute(
+            &db,
+            &client,
+            &config,
+            DiscoverInput {
+                query: Some("rust".to_string()),
+                min_score: Some(0.0),
+                limit: Some(10),
+                since_id: None,
+            },
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(output.candidates.len(), 2);
+        assert_eq!(output.candidates[0].tweet_id, "t2");
+        assert!(output.candidates[0].is_target);
+        assert!(!output.candidates[1].is_target);
+    }
+
     #[tokio::test]
     async fn empty_results() {
         let db = storage::init_test_db().await.unwrap();
@@ -551,6 +635,113 @@ mod queue_tests {
         }
     }
 
+    #[tokio::test]
+    async fn queues_in_draft_only_mode_without_approval_mode() {
+        let db = storage::init_test_db().await.unwrap();
+        seed_discovered_tweet(&db, "t1", "Rust topic", "dev").await;
+        storage::draft_only::enable_draft_only(&db, None, "test")
+            .await
+            .unwrap();
+
+        let client = MockXApiClient::empty();
+        let mut config = test_config();
+        config.approval_mode = false;
+
+        let results = queue::execute(
+            &db,
+            Some(&client as &dyn XApiClient),
+            None,
+            &config,
+            QueueInput {
+                items: vec![QueueItem {
+                    candidate_id: "t1".to_string(),
+                    pre_drafted_text: Some("Held reply!".to_string()),
+                }],
+                mention_product: false,
+            },
+        )
+        .await
+        .unwrap();
+
+        assert!(matches!(&results[0], ProposeResult::Queued { .. }));
+    }
+
+    #[tokio::test]
+    async fn blocks_replies_to_protected_entities() {
+        let db = storage::init_test_db().await.unwrap();
+        seed_discovered_tweet(&db, "t1", "Rust topic", "dev").await;
+
+        let client = MockXApiClient::empty();
+        let mut config = test_config();
+        config.blocklist.protected = vec!["@Dev".to_string()];
+
+        let results = queue::execute(
+            &db,
+            Some(&client as &dyn XApiClient),
+            None,
+            &config,
+            QueueInput {
+                items: vec![QueueItem {
+                    candidate_id: "t1".to_string(),
+                    pre_drafted_text: Some("Hello!".to_string()),
+                }],
+                mention_product: false,
+            },
+        )
+        .await
+        .unwrap();
+
+        match &results[0] {
+            ProposeResult::Blocked { reason, .. } => assert!(reason.contains("@dev")),
+            other => panic!("Expected Blocked, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn trusted_author_skips_approval_with_audit() {
+        let db = storage::init_test_db().await.unwrap();
+        seed_discovered_tweet(&db, "t1", "Rust topic", "dev").await;
+
+        let client = MockXApiClient::empty();
+        let mut config = test_config();
+        config.approval_mode = true;
+        config.trusted_authors = vec![crate::config::TrustedAuthorConfig {
+            username: "@Dev".to_string(),
+            skip_approval: true,
+            skip_soft_qa: false,
+        }];
+
+        let results = queue::execute(
+            &db,
+            Some(&client as &dyn XApiClient),
+            None,
+            &config,
+            QueueInput {
+                items: vec![QueueItem {
+                    candidate_id: "t1".to_string(),
+                    pre_drafted_text: Some("Direct reply!".to_string()),
+                }],
+                mention_product: false,
+            },
+        )
+        .await
+        .unwrap();
+
+        assert!(matches!(&results[0], ProposeResult::Executed { .. }));
+        let log = storage::action_log::get_actions_since(
+            &db,
+            "1970-01-01T00:00:00Z",
+            Some(crate::safety::trusted::TRUSTED_BYPASS_ACTION),
+        )
+        .await
+        .unwrap();
+        assert_eq!(log.len(), 1);
+        assert_eq!(
+            log[0].message.as_deref(),
+            Some("Reply to trusted @dev skipped approval: tweet t1")
+        );
+    }
+
     #[tokio::test]
     async fn tweet_not_found_blocked() {
         let db = storage::init_test_db().await.unwrap();
@@ -598,6 +789,241 @@ mod queue_tests {
     }
 }
 
+// ── Ingest step tests ────────────────────────────────────────────────
+
+mod ingest_tests {
+    use super::*;
+    use crate::storage::approval_queue::PLATFORM_EXTERNAL;
+    use crate::workflow::ingest::{self, parse_tweet_url, CandidateInput};
+
+    const ACCOUNT: &str = storage::accounts::DEFAULT_ACCOUNT_ID;
+
+    fn candidate(source: &str, text: &str) -> CandidateInput {
+        CandidateInput {
+            source: source.to_string(),
+            text: text.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn parses_tweet_urls() {
+        assert_eq!(
+            parse_tweet_url("https://x.com/alice/status/123?s=20"),
+            Some(("123".to_string(), Some("alice".to_string())))
+        );
+        assert_eq!(
+            parse_tweet_url("https://mobile.twitter.com/i/web/status/456"),
+            Some(("456".to_string(), None))
+        );
+        assert_eq!(parse_tweet_url("https://example.com/alice/status/1"), None);
+        assert_eq!(parse_tweet_url("https://x.com/alice"), None);
+        assert_eq!(parse_tweet_url("https://x.com/alice/status/abc"), None);
+    }
+
+    #[tokio::test]
+    async fn queues_tweets_and_raw_text_with_source() {
+        let db = storage::init_test_db().await.unwrap();
+        let llm: Arc<dyn LlmProvider> = Arc::new(MockLlmProvider::new("Try tokio."));
+        let config = test_config();
+        let gen = make_content_gen(&llm, &config);
+
+        let tweet = CandidateInput {
+            tweet_url: Some("https://x.com/alice/status/777".to_string()),
+            ..candidate("zapier", "Which async runtime for Rust?")
+        };
+        let raw = CandidateInput {
+            url: Some("https://forum.example.com/t/42".to_string()),
+            author_username: Some("bob".to_string()),
+            ..candidate("forum", "Is Rust async hard to learn?")
+        };
+
+        let client = MockXApiClient::with_results(vec![], vec![sample_user("a1", "alice", 500)]);
+
+        let results = ingest::execute(
+            &db,
+            ACCOUNT,
+            Some(&client as &dyn XApiClient),
+            &gen,
+            &config,
+            IngestInput {
+                candidates: vec![tweet, raw],
+                mention_product: false,
+            },
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(results[0].candidate_id, "777");
+        assert_eq!(results[0].status, "queued");
+        let item = storage::approval_queue::get_by_id(&db, results[0].approval_queue_id.unwrap())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(item.platform, "x");
+        assert_eq!(item.target_tweet_id, "777");
+        assert_eq!(item.target_author, "alice");
+        assert_eq!(item.source, "zapier");
+        assert_eq!(item.generated_content, "Try tokio.");
+
+        assert!(results[1].candidate_id.starts_with("ingest:forum:"));
+        let item = storage::approval_queue::get_by_id(&db, results[1].approval_queue_id.unwrap())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(item.platform, PLATFORM_EXTERNAL);
+        assert_eq!(item.target_tweet_id, "https://forum.example.com/t/42");
+        assert_eq!(item.source, "forum");
+
+        let stored = storage::tweets::get_tweet_by_id(&db, "777")
+            .await
+            .unwrap()
+            .expect("tweets are stored with discovered tweets");
+        assert_eq!(stored.author_id, "a1", "the real author ID from X");
+        assert_eq!(stored.content, "Test tweet", "the text as fetched from X");
+
+        let raw_id = &results[1].candidate_id;
+        assert!(storage::tweets::get_tweet_by_id(&db, raw_id)
+            .await
+            .unwrap()
+            .is_none());
+        let raw = storage::ingested_candidates::get_candidate_for(&db, ACCOUNT, raw_id)
+            .await
+            .unwrap()
+            .expect("raw text is stored apart");
+        assert_eq!(raw.author_username, "bob");
+    }
+
+    #[tokio::test]
+    async fn skips_unverifiable_blocked_and_protected_candidates() {
+        let db = storage::init_test_db().await.unwrap();
+        let llm: Arc<dyn LlmProvider> = Arc::new(MockLlmProvider::new("Reply"));
+        let mut config = test_config();
+        config.blocklist.protected = vec!["@Carol".to_string()];
+        let gen = make_content_gen(&llm, &config);
+        storage::blocklist::replace_source(
+            &db,
+            storage::blocklist::SOURCE_X_BLOCK,
+            &[(Some("a1".to_string()), "alice_renamed".to_string())],
+        )
+        .await
+        .unwrap();
+
+        let tweet = CandidateInput {
+            tweet_url: Some("https://x.com/whoever/status/777".to_string()),
+            ..candidate("zapier", "Rust?")
+        };
+        let protected = CandidateInput {
+            author_username: Some("carol".to_string()),
+            ..candidate("forum", "Rust async?")
+        };
+        let input = || IngestInput {
+            candidates: vec![tweet.clone(), protected.clone()],
+            mention_product: false,
+        };
+
+        let results = ingest::execute(&db, ACCOUNT, None, &gen, &config, input())
+            .await
+            .unwrap();
+        assert_eq!(results[0].status, "skipped");
+        assert!(results[0].reason.as_deref().unwrap().contains("X API"));
+        assert_eq!(
+            results[1].reason.as_deref(),
+            Some("author is a protected entity")
+        );
+
+        let client = MockXApiClient::with_results(vec![], vec![sample_user("a1", "alice", 500)]);
+        let results = ingest::execute(
+            &db,
+            ACCOUNT,
+            Some(&client as &dyn XApiClient),
+            &gen,
+            &config,
+            input(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(results[0].status, "skipped");
+        assert!(results[0].reason.as_deref().unwrap().contains("blocklist"));
+
+        assert!(storage::tweets::get_tweet_by_id(&db, "777")
+            .await
+            .unwrap()
+            .is_none());
+        assert!(storage::ingested_candidates::get_candidate_for(
+            &db,
+            ACCOUNT,
+            &results[1].candidate_id
+        )
+        .await
+        .unwrap()
+        .is_none());
+    }
+
+    #[tokio::test]
+    async fn skips_invalid_duplicate_and_low_scoring_candidates() {
+        let db = storage::init_test_db().await.unwrap();
+        let llm: Arc<dyn LlmProvider> = Arc::new(MockLlmProvider::new("Reply"));
+        let mut config = test_config();
+        config.scoring.threshold = 100;
+        let gen = make_content_gen(&llm, &config);
+
+        let input = || IngestInput {
+            candidates: vec![
+                candidate("bad source!", "Rust"),
+                CandidateInput {
+                    tweet_url: Some("https://example.com/post".to_string()),
+                    ..candidate("zapier", "Rust")
+                },
+                candidate("zapier", "Nothing relevant"),
+            ],
+            mention_product: false,
+        };
+
+        let results = ingest::execute(&db, ACCOUNT, None, &gen, &config, input())
+            .await
+            .unwrap();
+        let statuses: Vec<&str> = results.iter().map(|r| r.status.as_str()).collect();
+        assert_eq!(statuses, ["skipped", "skipped", "below_threshold"]);
+        assert!(results[2].score.is_some());
+
+        let again = ingest::execute(&db, ACCOUNT, None, &gen, &config, input())
+            .await
+            .unwrap();
+        assert_eq!(again[2].status, "skipped");
+        assert_eq!(again[2].reason.as_deref(), Some("already discovered"));
+        assert_eq!(
+            storage::approval_queue::pending_count(&db).await.unwrap(),
+            0
+        );
+    }
+
+    #[tokio::test]
+    async fn rejects_empty_and_oversized_batches() {
+        let db = storage::init_test_db().await.unwrap();
+        let llm: Arc<dyn LlmProvider> = Arc::new(MockLlmProvider::new("Reply"));
+        let config = test_config();
+        let gen = make_content_gen(&llm, &config);
+
+        let empty = IngestInput {
+            candidates: vec![],
+            mention_product: false,
+        };
+        assert!(ingest::execute(&db, ACCOUNT, None, &gen, &config, empty)
+            .await
+            .is_err());
+
+        let oversized = IngestInput {
+            candidates: vec![candidate("zapier", "Rust"); ingest::MAX_CANDIDATES + 1],
+            mention_product: false,
+        };
+        assert!(matches!(
+            ingest::execute(&db, ACCOUNT, None, &gen, &config, oversized).await,
+            Err(WorkflowError::InvalidInput(_))
+        ));
+    }
+}
+
 // ── Thread plan step tests ───────────────────────────────────────────
 
 mod thread_plan_tests {
@@ -726,6 +1152,262 @@ mod orchestrate_tests {
     }
 }
 
+// ── Session plan step tests ──────────────────────────────────────────
+
+mod session_plan_tests {
+    use super::*;
+    use session_plan::{allocate, Allocation, RemainingLimits};
+
+    fn rust_tweets(n: usize) -> (Vec<Tweet>, Vec<User>) {
+        (1..=n)
+            .map(|i| {
+                (
+                    sample_tweet(
+                        &format!("t{i}"),
+                        "Learning rust async programming",
+                        &format!("a{i}"),
+                    ),
+                    sample_user(&format!("a{i}"), &format!("dev{i}"), 1000 * i as u64),
+                )
+            })
+            .unzip()
+    }
+
+    #[test]
+    fn allocate_fits_budget_and_limits() {
+        let unlimited = RemainingLimits::default();
+        assert_eq!(
+            allocate(20, 2, 30, unlimited),
+            Allocation {
+                scheduled: 2,
+                replies: 6,
+                quote: true,
+                likes: 10,
+            }
+        );
+
+        let capped = RemainingLimits {
+            reply: Some(1),
+            like: Some(0),
+            tweet: Some(0),
+            thread: None,
+        };
+        assert_eq!(
+            allocate(20, 0, 30, capped),
+            Allocation {
+                scheduled: 0,
+                replies: 1,
+                quote: false,
+                likes: 0,
+            }
+        );
+    }
+
+    #[test]
+    fn allocate_skips_quote_for_short_sessions() {
+        let plan = allocate(5, 3, 10, RemainingLimits::default());
+        assert_eq!(plan.scheduled, 3);
+        assert!(!plan.quote);
+        assert!(plan.replies <= 1);
+    }
+
+    #[tokio::test]
+    async fn plan_orders_replies_quote_then_likes() {
+        let db = storage::init_test_db().await.unwrap();
+        let (tweets, users) = rust_tweets(8);
+        let client = MockXApiClient::with_results(tweets, users);
+        let llm: Arc<dyn LlmProvider> = Arc::new(MockLlmProvider::new("Great point about Rust!"));
+        let config = test_config();
+
+        let plan = session_plan::execute(
+            &db,
+            &client,
+            Some(&llm),
+            &config,
+            SessionPlanInput {
+                minutes: 15,
+                query: Some("rust".to_string()),
+                mention_product: false,
+            },
+        )
+        .await
+        .unwrap();
+
+        assert!(plan.minutes_planned <= 15.0);
+        let actions: Vec<&str> = plan.steps.iter().map(|s| s.action.as_str()).collect();
+        let first_quote = actions.iter().position(|a| *a == "quote").unwrap();
+        assert!(actions[..first_quote].iter().all(|a| *a == "reply"));
+        assert!(actions[first_quote + 1..].iter().all(|a| *a == "like"));
+        assert!(plan.steps.iter().enumerate().all(|(i, s)| s.step == i + 1));
+        let reply = &plan.steps[0];
+        assert_eq!(reply.tool, "propose_and_queue_replies");
+        assert!(reply.draft.is_some());
+    }
+
+    #[tokio::test]
+    async fn plan_without_llm_notes_missing_drafts() {
+        let db = storage::init_test_db().await.unwrap();
+        let (tweets, users) = rust_tweets(3);
+        let client = MockXApiClient::with_results(tweets, users);
+        let config = test_config();
+
+        let plan = session_plan::execute(
+            &db,
+            &client,
+            None,
+            &config,
+            SessionPlanInput {
+                minutes: 10,
+                query: Some("rust".to_string()),
+                mention_product: false,
+            },
+        )
+        .await
+        .unwrap();
+
+        assert!(plan.steps.iter().all(|s| s.draft.is_none()));
+        assert!(plan.notes.iter().any(|n| n.contains("No LLM provider")));
+    }
+
+    #[tokio::test]
+    async fn plan_rejects_out_of_range_budget() {
+        let db = storage::init_test_db().await.unwrap();
+        let client = MockXApiClient::empty();
+        let config = test_config();
+
+        let err = session_plan::execute(
+            &db,
+            &client,
+            None,
+            &config,
+            SessionPlanInput {
+                minutes: 1,
+                query: None,
+                mention_product: false,
+            },
+        )
+        .await
+        .unwrap_err();
+        assert!(matches!(err, WorkflowError::InvalidInput(_)));
+    }
+}
+
+// ── Target import tests ──────────────────────────────────────────────
+
+mod target_import_tests {
+    use super::*;
+    use crate::config::TargetTier;
+    use crate::workflow::target_import::{self, normalize_handle, parse_csv};
+
+    #[test]
+    fn csv_takes_first_column_and_skips_header() {
+        let csv = "username,note\n@alice,friend\n\n\"bob\"\nhttps://x.com/carol/status/1\n";
+        assert_eq!(
+            parse_csv(csv),
+            vec!["@alice", "bob", "https://x.com/carol/status/1"]
+        );
+        assert_eq!(normalize_handle("@alice").as_deref(), Some("alice"));
+        assert_eq!(
+            normalize_handle("https://x.com/carol/status/1").as_deref(),
+            Some("carol")
+        );
+        assert_eq!(normalize_handle("https://example.com/carol"), None);
+        assert_eq!(normalize_handle("way_too_long_handle_x"), None);
+        assert_eq!(normalize_handle("bad-name"), None);
+    }
+
+    #[tokio::test]
+    async fn import_dedups_and_reports_invalid() {
+        let db = storage::init_test_db().await.expect("init db");
+        storage::target_accounts::upsert_target_account(&db, "9", "existing")
+            .await
+            .expect("seed");
+        let client = MockXApiClient::with_results(
+            vec![],
+            vec![sample_user("1", "Alice", 10), sample_user("2", "bob", 10)],
+        );
+        let entries: Vec<String> = [
+            "@alice",
+            "ALICE",
+            "bob",
+            "Existing",
+            "configured",
+            "ghost",
+            "no-good",
+        ]
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+
+        let report = target_import::import_handles(
+            &db,
+            &client,
+            &entries,
+            &["Configured".to_string()],
+            TargetTier::Dream100,
+            false,
+        )
+        .await
+        .expect("import");
+
+        assert_eq!(report.added, vec!["Alice", "bob"]);
+        let skipped: Vec<_> = report.skipped.iter().map(|s| s.reason.as_str()).collect();
+        assert_eq!(
+            skipped,
+            vec!["duplicate in input", "already a target", "already a target"]
+        );
+        let invalid: Vec<_> = report.invalid.iter().map(|s| s.entry.as_str()).collect();
+        assert_eq!(invalid, vec!["no-good", "ghost"]);
+
+        let active = storage::target_accounts::get_active_target_accounts(&db)
+            .await
+            .expect("targets");
+        let alice = active
+            .iter()
+            .find(|t| t.username == "Alice")
+            .expect("alice");
+        assert_eq!(alice.account_id, "1");
+        assert_eq!(alice.tier, "dream_100");
+    }
+
+    #[tokio::test]
+    async fn dry_run_and_following_import() {
+        let db = storage::init_test_db().await.expect("init db");
+        let client = MockXApiClient::with_results(
+            vec![],
+            vec![
+                sample_user("1", "alice", 10),
+                sample_user("2", "bob", 10),
+                sample_user("3", "carol", 10),
+            ],
+        );
+
+        let dry =
+            target_import::import_following(&db, &client, "@me", 2, &[], TargetTier::General, true)
+                .await
+                .expect("dry run");
+        assert_eq!(dry.added, vec!["alice", "bob"]);
+        assert!(storage::target_accounts::get_active_target_accounts(&db)
+            .await
+            .expect("targets")
+            .is_empty());
+
+        let report = target_import::import_following(
+            &db,
+            &client,
+            "me",
+            10,
+            &["bob".to_string()],
+            TargetTier::General,
+            false,
+        )
+        .await
+        .expect("import");
+        assert_eq!(report.added, vec!["alice", "carol"]);
+        assert_eq!(report.skipped[0].entry, "bob");
+    }
+}
+
 // ── Publish step tests ───────────────────────────────────────────────
 
 mod publish_tests {
@@ -82,7 +82,7 @@ pub async fn execute(
 ) -> Result<ThreadPlanOutput, WorkflowError> {
     let structure_override = input.structure.as_deref().and_then(parse_structure);
 
-    let gen = make_content_gen(llm, &config.business);
+    let gen = make_content_gen(llm, config);
 
     let thread = gen
         .generate_thread_with_structure(&input.topic, structure_override)
@@ -5,11 +5,12 @@
 //! - **Local callback**: CLI starts a temporary HTTP server to capture the code.
 //!
 //! Token management handles persistent storage, loading, and automatic
-//! refresh before expiry.
+//! refresh before expiry (see [`TokenManager`]).
+
+mod token_manager;
 
 use std::io::Write;
 use std::path::Path;
-use std::sync::Arc;
 
 use chrono::{DateTime, Utc};
 use oauth2::basic::BasicClient;
@@ -18,21 +19,19 @@ use oauth2::{
     TokenResponse, TokenUrl,
 };
 use serde::{Deserialize, Serialize};
-use tokio::sync::RwLock;
 
 use crate::error::XApiError;
 
 use super::scopes::REQUIRED_SCOPES;
 
+pub use token_manager::TokenManager;
+
 /// X API OAuth 2.0 authorization endpoint.
 const AUTH_URL: &str = "https://x.com/i/oauth2/authorize";
 
 /// X API OAuth 2.0 token endpoint.
 const TOKEN_URL: &str = "https://api.x.com/2/oauth2/token";
 
-/// Pre-expiry refresh window in seconds.
-const REFRESH_WINDOW_SECS: i64 = 300;
-
 /// Stored OAuth tokens with expiration tracking.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Tokens {
@@ -46,168 +45,44 @@ pub struct Tokens {
     pub scopes: Vec<String>,
 }
 
-/// Manages token persistence, loading, and automatic refresh.
-pub struct TokenManager {
-    tokens: Arc<RwLock<Tokens>>,
-    client_id: String,
-    http_client: reqwest::Client,
-    token_path: std::path::PathBuf,
-}
-
-impl TokenManager {
-    /// Create a new token manager with the given tokens and client configuration.
-    pub fn new(tokens: Tokens, client_id: String, token_path: std::path::PathBuf) -> Self {
-        Self {
-            tokens: Arc::new(RwLock::new(tokens)),
-            client_id,
-            http_client: reqwest::Client::new(),
-            token_path,
-        }
-    }
-
-    /// Get the current access token, refreshing if needed.
-    pub async fn get_access_token(&self) -> Result<String, XApiError> {
-        self.refresh_if_needed().await?;
-        let tokens = self.tokens.read().await;
-        Ok(tokens.access_token.clone())
-    }
-
-    /// Get a shared reference to the tokens lock for direct access.
-    pub fn tokens_lock(&self) -> Arc<RwLock<Tokens>> {
-        self.tokens.clone()
-    }
-
-    /// Refresh the access token if it is within 5 minutes of expiring.
-    pub async fn refresh_if_needed(&self) -> Result<(), XApiError> {
-        let should_refresh = {
-            let tokens = self.tokens.read().await;
-            let now = Utc::now();
-            let seconds_until_expiry = tokens.expires_at.signed_duration_since(now).num_seconds();
-            seconds_until_expiry < REFRESH_WINDOW_SECS
-        };
-
-        if should_refresh {
-            self.do_refresh().await?;
-        }
-
-        Ok(())
-    }
-
-    /// Perform the token refresh.
-    async fn do_refresh(&self) -> Result<(), XApiError> {
-        let refresh_token = {
-            let tokens = self.tokens.read().await;
-            tokens.refresh_token.clone()
-        };
-
-        tracing::info!("Refreshing X API access token");
-
-        let params = [
-            ("grant_type", "refresh_token"),
-            ("refresh_token", &refresh_token),
-            ("client_id", &self.client_id),
-        ];
-
-        let response = self
-            .http_client
-            .post(TOKEN_URL)
-            .form(&params)
-            .send()
-            .await
-            .map_err(|e| XApiError::Network { source: e })?;
-
-        if !response.status().is_success() {
-            let status = response.status().as_u16();
-            let body = response.text().await.unwrap_or_default();
-            tracing::error!(
-                status,
-                body_len = body.len(),
-                "Token refresh failed (response body redacted)"
-            );
-            return Err(XApiError::AuthExpired);
-        }
-
-        let body: TokenRefreshResponse = response
-            .json()
-            .await
-            .map_err(|e| XApiError::Network { source: e })?;
-
-        let new_tokens = Tokens {
-            access_token: body.access_token,
-            refresh_token: body.refresh_token,
-            expires_at: Utc::now() + chrono::Duration::seconds(body.expires_in),
-            scopes: body
-                .scope
-                .split_whitespace()
-                .map(|s| s.to_string())
-                .collect(),
-        };
-
-        tracing::info!(
-            expires_at = %new_tokens.expires_at,
-            "Token refreshed successfully"
-        );
-
-        // Update in memory
-        {
-            let mut tokens = self.tokens.write().await;
-            *tokens = new_tokens.clone();
-        }
-
-        // Persist to disk
-        save_tokens(&new_tokens, &self.token_path).map_err(|e| {
-            tracing::error!(error = %e, "Failed to save refreshed tokens");
-            XApiError::ApiError {
-                status: 0,
-                message: format!("Failed to save tokens: {e}"),
-            }
-        })?;
-
-        Ok(())
-    }
-}
-
-/// Response from the OAuth 2.0 token refresh endpoint.
-#[derive(Debug, Deserialize)]
-struct TokenRefreshResponse {
-    access_token: String,
-    refresh_token: String,
-    expires_in: i64,
-    scope: String,
-}
-
 /// Save tokens to disk as JSON with restricted permissions.
+///
+/// The file is replaced atomically, so a reader never sees a half-written
+/// token pair.
 pub fn save_tokens(tokens: &Tokens, path: &Path) -> Result<(), String> {
-    if let Some(parent) = path.parent() {
-        std::fs::create_dir_all(parent).map_err(|e| format!("Failed to create directory: {e}"))?;
-    }
-
     let json = serde_json::to_string_pretty(tokens)
         .map_err(|e| format!("Failed to serialize tokens: {e}"))?;
+    write_private_file(path, json.as_bytes()).map_err(|e| format!("Failed to write tokens: {e}"))
+}
 
-    // Write token file with restricted permissions from the start (no TOCTOU window)
+/// Atomically replace `path` with `contents`, readable only by the owner.
+///
+/// Writes a sibling temp file (mode 0600 from the start, so there is no
+/// window with wider permissions), syncs it, and renames it over `path`.
+/// Creates missing parent directories.
+pub(crate) fn write_private_file(path: &Path, contents: &[u8]) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let mut tmp_name = path.file_name().unwrap_or_default().to_os_string();
+    tmp_name.push(".tmp");
+    let tmp_path = path.with_file_name(tmp_name);
+
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
     #[cfg(unix)]
     {
-        use std::io::Write;
         use std::os::unix::fs::OpenOptionsExt;
-        let mut file = std::fs::OpenOptions::new()
-            .write(true)
-            .create(true)
-            .truncate(true)
-            .mode(0o600)
-            .open(path)
-            .map_err(|e| format!("Failed to create token file: {e}"))?;
-        file.write_all(json.as_bytes())
-            .map_err(|e| format!("Failed to write tokens: {e}"))?;
+        options.mode(0o600);
     }
-
     #[cfg(not(unix))]
-    {
-        std::fs::write(path, &json).map_err(|e| format!("Failed to write tokens: {e}"))?;
-        tracing::warn!("Cannot set restrictive file permissions on non-Unix platform");
-    }
+    tracing::warn!("Cannot set restrictive file permissions on non-Unix platform");
 
-    Ok(())
+    let mut file = options.open(&tmp_path)?;
+    file.write_all(contents)?;
+    file.sync_all()?;
+    drop(file);
+    std::fs::rename(&tmp_path, path)
 }
 
 /// Load tokens from disk. Returns `None` if the file does not exist.
@@ -590,82 +465,23 @@ mod tests {
         assert!(path.exists());
     }
 
-    #[tokio::test]
-    async fn token_manager_refresh_detects_expiry() {
-        // Create tokens that are about to expire
-        let tokens = Tokens {
-            access_token: "old_token".to_string(),
-            refresh_token: "refresh".to_string(),
-            expires_at: Utc::now() + chrono::Duration::seconds(60), // within 5 min window
-            scopes: vec![],
-        };
-
-        let dir = tempfile::tempdir().expect("temp dir");
-        let path = dir.path().join("tokens.json");
-
-        let manager = TokenManager::new(tokens, "client_id".to_string(), path);
-
-        // The refresh will fail (no real server) but we can verify it attempts
-        let result = manager.refresh_if_needed().await;
-        // Should fail with Network error since TOKEN_URL is not reachable in test
-        assert!(result.is_err());
-    }
-
-    #[tokio::test]
-    async fn token_manager_no_refresh_when_fresh() {
-        let tokens = Tokens {
-            access_token: "fresh_token".to_string(),
-            refresh_token: "refresh".to_string(),
-            expires_at: Utc::now() + chrono::Duration::hours(2), // far from expiry
-            scopes: vec![],
-        };
-
+    #[test]
+    fn save_tokens_replaces_file_without_leaving_temp() {
         let dir = tempfile::tempdir().expect("temp dir");
         let path = dir.path().join("tokens.json");
 
-        let manager = TokenManager::new(tokens, "client_id".to_string(), path);
-
-        // Should not attempt refresh and succeed
-        let result = manager.refresh_if_needed().await;
-        assert!(result.is_ok());
-
-        let token = manager.get_access_token().await.expect("get token");
-        assert_eq!(token, "fresh_token");
-    }
-
-    #[tokio::test]
-    async fn token_manager_refresh_with_mock() {
-        use wiremock::matchers::{body_string_contains, method};
-        use wiremock::{Mock, MockServer, ResponseTemplate};
-
-        let server = MockServer::start().await;
-
-        Mock::given(method("POST"))
-            .and(body_string_contains("grant_type=refresh_token"))
-            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
-                "access_token": "new_access",
-                "refresh_token": "new_refresh",
-                "expires_in": 7200,
-                "scope": "tweet.read tweet.write"
-            })))
-            .mount(&server)
-            .await;
-
-        // Create a custom TokenManager that points to the mock server
-        let tokens = Tokens {
-            access_token: "old_token".to_string(),
-            refresh_token: "old_refresh".to_string(),
-            expires_at: Utc::now() + chrono::Duration::seconds(60),
+        let mut tokens = Tokens {
+            access_token: "first".to_string(),
+            refresh_token: "r1".to_string(),
+            expires_at: Utc::now(),
             scopes: vec![],
         };
+        save_tokens(&tokens, &path).expect("save");
+        tokens.access_token = "second".to_string();
+        save_tokens(&tokens, &path).expect("save again");
 
-        let dir = tempfile::tempdir().expect("temp dir");
-        let path = dir.path().join("tokens.json");
-
-        // We need to override TOKEN_URL for the test, which the current implementation
-        // doesn't support directly. Instead, verify the token manager structure works.
-        let manager = TokenManager::new(tokens, "client_id".to_string(), path);
-        let token = manager.tokens.read().await;
-        assert_eq!(token.access_token, "old_token");
+        let loaded = load_tokens(&path).expect("load").expect("present");
+        assert_eq!(loaded.access_token, "second");
+        assert!(!dir.path().join("tokens.json.tmp").exists());
     }
 }
@@ -0,0 +1,388 @@
+//! Shared OAuth token refresh.
+//!
+//! A [`TokenManager`] owns a process's X API tokens. It refreshes the
+//! access token shortly before expiry using the stored refresh token,
+//! persists the rotated pair atomically, and pushes the new access token
+//! into every attached [`XApiHttpClient`]. The CLI run loop, the server,
+//! and the MCP profiles all build it through [`TokenManager::load`].
+//!
+//! X rotates the refresh token on every use, so two refreshers must never
+//! spend the same one. Refreshes are serialized within a manager, and a
+//! manager adopts tokens another process has already refreshed on disk
+//! before (and, if its own refresh is rejected, after) refreshing itself.
+
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use chrono::Utc;
+use serde::Deserialize;
+use tokio::sync::{Mutex, RwLock};
+
+use super::{load_tokens, save_tokens, Tokens, TOKEN_URL};
+use crate::error::XApiError;
+use crate::startup::{self, StartupError, StoredTokens};
+use crate::x_api::XApiHttpClient;
+
+/// Pre-expiry refresh window in seconds.
+const REFRESH_WINDOW_SECS: i64 = 300;
+
+/// Manages token persistence, loading, and automatic refresh.
+pub struct TokenManager {
+    tokens: Arc<RwLock<Tokens>>,
+    client_id: String,
+    http_client: reqwest::Client,
+    token_path: PathBuf,
+    token_url: String,
+    /// Held for a whole refresh so concurrent callers never spend the same
+    /// refresh token twice.
+    refresh_lock: Mutex<()>,
+    /// Access-token slots of attached clients, updated after each refresh.
+    clients: std::sync::Mutex<Vec<Arc<RwLock<String>>>>,
+}
+
+impl TokenManager {
+    /// Create a new token manager with the given tokens and client configuration.
+    pub fn new(tokens: Tokens, client_id: String, token_path: PathBuf) -> Self {
+        Self {
+            tokens: Arc::new(RwLock::new(tokens)),
+            client_id,
+            http_client: reqwest::Client::new(),
+            token_path,
+            token_url: TOKEN_URL.to_string(),
+            refresh_lock: Mutex::new(()),
+            clients: std::sync::Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Create a manager from tokens in the `~/.tuitbot/tokens.json` format.
+    pub fn from_stored(stored: &StoredTokens, client_id: String, token_path: PathBuf) -> Self {
+        let tokens = Tokens {
+            access_token: stored.access_token.clone(),
+            refresh_token: stored.refresh_token.clone().unwrap_or_default(),
+            expires_at: stored.expires_at.unwrap_or_else(Utc::now),
+            scopes: stored.scopes.clone(),
+        };
+        Self::new(tokens, client_id, token_path)
+    }
+
+    /// Load the token file written by `tuitbot auth` and refresh it if it is
+    /// expired or about to expire.
+    ///
+    /// Fails when there is no token file, or when the access token has
+    /// expired and cannot be refreshed. A failed refresh of a still-valid
+    /// token is logged and the current token is kept.
+    pub async fn load(client_id: &str) -> Result<Self, StartupError> {
+        let stored = startup::load_tokens_from_file()?;
+        let manager = Self::from_stored(&stored, client_id.to_string(), startup::token_file_path());
+
+        if let Err(e) = manager.refresh_if_needed().await {
+            if manager.is_expired().await {
+                return Err(StartupError::TokenRefreshFailed(format!(
+                    "{e}; run `tuitbot auth` to re-authenticate"
+                )));
+            }
+            tracing::warn!(error = %e, "Token refresh attempt failed, continuing with current token");
+        }
+
+        Ok(manager)
+    }
+
+    /// Get the current access token, refreshing if needed.
+    pub async fn get_access_token(&self) -> Result<String, XApiError> {
+        self.refresh_if_needed().await?;
+        Ok(self.access_token().await)
+    }
+
+    /// The current access token, without refreshing.
+    pub async fn access_token(&self) -> String {
+        self.tokens.read().await.access_token.clone()
+    }
+
+    /// Whether the current access token has expired.
+    pub async fn is_expired(&self) -> bool {
+        self.tokens.read().await.expires_at <= Utc::now()
+    }
+
+    /// Get a shared reference to the tokens lock for direct access.
+    pub fn tokens_lock(&self) -> Arc<RwLock<Tokens>> {
+        self.tokens.clone()
+    }
+
+    /// Keep `client`'s bearer token in step with this manager.
+    ///
+    /// The client is switched to the current access token now and to each
+    /// refreshed token afterwards.
+    pub async fn attach(&self, client: &XApiHttpClient) {
+        client.set_access_token(self.access_token().await).await;
+        self.lock_clients().push(client.access_token.clone());
+    }
+
+    /// Refresh the access token if it is within 5 minutes of expiring.
+    pub async fn refresh_if_needed(&self) -> Result<(), XApiError> {
+        let _guard = self.refresh_lock.lock().await;
+        if !self.needs_refresh().await {
+            return Ok(());
+        }
+        if self.adopt_newer_from_disk().await && !self.needs_refresh().await {
+            return Ok(());
+        }
+
+        match self.do_refresh().await {
+            // Another process spent the refresh token first; use its result.
+            Err(XApiError::AuthExpired) if self.adopt_newer_from_disk().await => Ok(()),
+            result => result,
+        }
+    }
+
+    async fn needs_refresh(&self) -> bool {
+        let tokens = self.tokens.read().await;
+        let seconds_until_expiry = tokens
+            .expires_at
+            .signed_duration_since(Utc::now())
+            .num_seconds();
+        seconds_until_expiry < REFRESH_WINDOW_SECS
+    }
+
+    /// Switch to the token file's contents if they expire later than the
+    /// tokens in memory. Returns whether anything changed.
+    async fn adopt_newer_from_disk(&self) -> bool {
+        let Ok(Some(on_disk)) = load_tokens(&self.token_path) else {
+            return false;
+        };
+        {
+            let mut tokens = self.tokens.write().await;
+            if on_disk.expires_at <= tokens.expires_at {
+                return false;
+            }
+            *tokens = on_disk.clone();
+        }
+        tracing::info!(
+            expires_at = %on_disk.expires_at,
+            "Using X API tokens refreshed by another process"
+        );
+        self.update_clients(&on_disk.access_token).await;
+        true
+    }
+
+    /// Perform the token refresh.
+    async fn do_refresh(&self) -> Result<(), XApiError> {
+        let refresh_token = {
+            let tokens = self.tokens.read().await;
+            tokens.refresh_token.clone()
+        };
+        if refresh_token.is_empty() {
+            return Err(XApiError::AuthExpired);
+        }
+
+        tracing::info!("Refreshing X API access token");
+
+        let params = [
+            ("grant_type", "refresh_token"),
+            ("refresh_token", &refresh_token),
+            ("client_id", &self.client_id),
+        ];
+
+        let response = self
+            .http_client
+            .post(&self.token_url)
+            .form(&params)
+            .send()
+            .await
+            .map_err(|e| XApiError::Network { source: e })?;
+
+        if !response.status().is_success() {
+            let status = response.status().as_u16();
+            let body = response.text().await.unwrap_or_default();
+            tracing::error!(
+                status,
+                body_len = body.len(),
+                "Token refresh failed (response body redacted)"
+            );
+            return Err(XApiError::AuthExpired);
+        }
+
+        let body: TokenRefreshResponse = response
+            .json()
+            .await
+            .map_err(|e| XApiError::Network { source: e })?;
+
+        let new_tokens = Tokens {
+            access_token: body.access_token,
+            refresh_token: body.refresh_token,
+            expires_at: Utc::now() + chrono::Duration::seconds(body.expires_in),
+            scopes: body
+                .scope
+                .split_whitespace()
+                .map(|s| s.to_string())
+                .collect(),
+        };
+
+        tracing::info!(
+            expires_at = %new_tokens.expires_at,
+            "Token refreshed successfully"
+        );
+
+        // The old refresh token is spent, so keep the new pair in memory
+        // even if it cannot be written to disk.
+        {
+            let mut tokens = self.tokens.write().await;
+            *tokens = new_tokens.clone();
+        }
+        self.update_clients(&new_tokens.access_token).await;
+
+        save_tokens(&new_tokens, &self.token_path).map_err(|e| {
+            tracing::error!(error = %e, "Failed to save refreshed tokens");
+            XApiError::ApiError {
+                status: 0,
+                message: format!("Failed to save tokens: {e}"),
+            }
+        })?;
+
+        Ok(())
+    }
+
+    async fn update_clients(&self, access_token: &str) {
+        let clients = self.lock_clients().clone();
+        for slot in clients {
+            *slot.write().await = access_token.to_string();
+        }
+    }
+
+    fn lock_clients(&self) -> std::sync::MutexGuard<'_, Vec<Arc<RwLock<String>>>> {
+        self.clients.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// Response from the OAuth 2.0 token refresh endpoint.
+#[derive(Debug, Deserialize)]
+struct TokenRefreshResponse {
+    access_token: String,
+    refresh_token: String,
+    expires_in: i64,
+    scope: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{body_string_contains, method};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn expiring_tokens() -> Tokens {
+        Tokens {
+            access_token: "old_token".to_string(),
+            refresh_token: "old_refresh".to_string(),
+            expires_at: Utc::now() + chrono::Duration::seconds(60), // within 5 min window
+            scopes: vec![],
+        }
+    }
+
+    #[tokio::test]
+    async fn token_manager_refresh_detects_expiry() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let path = dir.path().join("tokens.json");
+
+        let mut manager = TokenManager::new(expiring_tokens(), "client_id".to_string(), path);
+        manager.token_url = "http://127.0.0.1:9/2/oauth2/token".to_string();
+
+        // Nothing listens there, so the attempted refresh fails.
+        let result = manager.refresh_if_needed().await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn token_manager_no_refresh_when_fresh() {
+        let tokens = Tokens {
+            access_token: "fresh_token".to_string(),
+            refresh_token: "refresh".to_string(),
+            expires_at: Utc::now() + chrono::Duration::hours(2), // far from expiry
+            scopes: vec![],
+        };
+
+        let dir = tempfile::tempdir().expect("temp dir");
+        let path = dir.path().join("tokens.json");
+
+        let manager = TokenManager::new(tokens, "client_id".to_string(), path);
+
+        // Should not attempt refresh and succeed
+        let result = manager.refresh_if_needed().await;
+        assert!(result.is_ok());
+
+        let token = manager.get_access_token().await.expect("get token");
+        assert_eq!(token, "fresh_token");
+    }
+
+    #[tokio::test]
+    async fn token_manager_refresh_with_mock() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(body_string_contains("grant_type=refresh_token"))
+            .and(body_string_contains("refresh_token=old_refresh"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "access_token": "new_access",
+                "refresh_token": "new_refresh",
+                "expires_in": 7200,
+                "scope": "tweet.read tweet.write"
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let dir = tempfile::tempdir().expect("temp dir");
+        let path = dir.path().join("tokens.json");
+
+        let mut manager =
+            TokenManager::new(expiring_tokens(), "client_id".to_string(), path.clone());
+        manager.token_url = format!("{}/2/oauth2/token", server.uri());
+        let client = XApiHttpClient::new("stale".to_string());
+        manager.attach(&client).await;
+        assert_eq!(*client.access_token.read().await, "old_token");
+
+        manager.refresh_if_needed().await.expect("refresh");
+        // A second call finds the token fresh and does not hit the endpoint.
+        manager.refresh_if_needed().await.expect("no-op");
+
+        assert_eq!(manager.access_token().await, "new_access");
+        assert_eq!(*client.access_token.read().await, "new_access");
+        let saved = load_tokens(&path).expect("load").expect("saved");
+        assert_eq!(saved.refresh_token, "new_refresh");
+        assert_eq!(saved.scopes, ["tweet.read", "tweet.write"]);
+    }
+
+    #[tokio::test]
+    async fn token_manager_adopts_tokens_refreshed_elsewhere() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(400))
+            .expect(0)
+            .mount(&server)
+            .await;
+
+        let dir = tempfile::tempdir().expect("temp dir");
+        let path = dir.path().join("tokens.json");
+        let newer = Tokens {
+            access_token: "from_other_process".to_string(),
+            refresh_token: "rotated".to_string(),
+            expires_at: Utc::now() + chrono::Duration::hours(2),
+            scopes: vec![],
+        };
+        save_tokens(&newer, &path).expect("save");
+
+        let mut manager = TokenManager::new(expiring_tokens(), "client_id".to_string(), path);
+        manager.token_url = format!("{}/2/oauth2/token", server.uri());
+
+        manager.refresh_if_needed().await.expect("adopt");
+        assert_eq!(manager.access_token().await, "from_other_process");
+    }
+
+    #[tokio::test]
+    async fn token_manager_without_refresh_token_reports_expired() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let mut tokens = expiring_tokens();
+        tokens.refresh_token.clear();
+
+        let manager = TokenManager::new(tokens, "client_id".to_string(), dir.path().join("t"));
+        let result = manager.refresh_if_needed().await;
+        assert!(matches!(result, Err(XApiError::AuthExpired)));
+    }
+}
@@ -6,17 +6,21 @@
 
 mod trait_impl;
 
+#[cfg(test)]
+mod protected_tests;
 #[cfg(test)]
 mod tests;
 
 use std::sync::Arc;
 use tokio::sync::RwLock;
 
+use crate::config::XApiConfig;
 use crate::error::XApiError;
+use crate::safety::protected;
 use crate::safety::redact::redact_secrets;
 use crate::storage::{self, DbPool};
 
-use super::types::{RateLimitInfo, XApiErrorResponse};
+use super::types::{RateLimitInfo, SingleTweetResponse, UserResponse, XApiErrorResponse};
 
 /// Default X API v2 base URL.
 const DEFAULT_BASE_URL: &str = "https://api.x.com/2";
@@ -24,8 +28,17 @@ const DEFAULT_BASE_URL: &str = "https://api.x.com/2";
 /// Default X API v1.1 media upload base URL.
 const DEFAULT_UPLOAD_BASE_URL: &str = "https://upload.twitter.com/1.1";
 
+/// Hosts serving the production X API.
+const PRODUCTION_HOSTS: &[&str] = &[
+    "api.x.com",
+    "api.twitter.com",
+    "upload.x.com",
+    "upload.twitter.com",
+];
+
 /// Standard tweet fields requested on every query.
-pub(crate) const TWEET_FIELDS: &str = "public_metrics,created_at,author_id,conversation_id";
+pub(crate) const TWEET_FIELDS: &str =
+    "public_metrics,created_at,author_id,conversation_id,entities,attachments";
 
 /// Standard expansions requested on every query.
 pub(crate) const EXPANSIONS: &str = "author_id";
@@ -44,6 +57,7 @@ pub struct XApiHttpClient {
     pub(crate) upload_base_url: String,
     pub(crate) access_token: Arc<RwLock<String>>,
     pool: Arc<RwLock<Option<DbPool>>>,
+    protected: Vec<String>,
 }
 
 impl XApiHttpClient {
@@ -55,6 +69,7 @@ impl XApiHttpClient {
             upload_base_url: DEFAULT_UPLOAD_BASE_URL.to_string(),
             access_token: Arc::new(RwLock::new(access_token)),
             pool: Arc::new(RwLock::new(None)),
+            protected: Vec::new(),
         }
     }
 
@@ -67,9 +82,35 @@ impl XApiHttpClient {
             upload_base_url,
             access_token: Arc::new(RwLock::new(access_token)),
             pool: Arc::new(RwLock::new(None)),
+            protected: Vec::new(),
+        }
+    }
+
+    /// Create a client for `config`, honoring its `base_url` override.
+    ///
+    /// Logs a warning when the override is not a production X host, since
+    /// nothing sent through the client will reach X.
+    pub fn from_config(access_token: String, config: &XApiConfig) -> Self {
+        match config.base_url.as_deref().map(str::trim) {
+            Some(url) if !url.is_empty() && !is_production_url(url) => {
+                let url = url.trim_end_matches('/');
+                tracing::warn!(
+                    base_url = %url,
+                    "X API base URL is not a production X host; nothing will be posted to X"
+                );
+                Self::with_base_url(access_token, url.to_string())
+            }
+            _ => Self::new(access_token),
         }
     }
 
+    /// Refuse replies, quotes, likes, retweets, and follows aimed at these
+    /// protected entities (`blocklist.protected`).
+    pub fn with_protected_entities(mut self, protected: &[String]) -> Self {
+        self.protected = protected::normalize_protected(protected);
+        self
+    }
+
     /// Set the database pool for usage tracking.
     ///
     /// Called after DB initialization to enable fire-and-forget recording
@@ -90,6 +131,62 @@ impl XApiHttpClient {
         *lock = token;
     }
 
+    /// Refuse to engage with `tweet_id` if its author is protected.
+    ///
+    /// Costs one lookup per write while protected entities are configured.
+    /// Fails closed: if the author cannot be looked up, the write fails.
+    pub(crate) async fn check_protected_tweet(&self, tweet_id: &str) -> Result<(), XApiError> {
+        if self.protected.is_empty() {
+            return Ok(());
+        }
+        let path = format!("/tweets/{tweet_id}");
+        let params = [("expansions", "author_id"), ("user.fields", "username")];
+        let response = self.get(&path, &params).await?;
+        let resp: SingleTweetResponse = response
+            .json()
+            .await
+            .map_err(|e| XApiError::Network { source: e })?;
+        let author = resp
+            .includes
+            .and_then(|includes| {
+                includes
+                    .users
+                    .into_iter()
+                    .find(|user| user.id == resp.data.author_id)
+            })
+            .map(|user| user.username);
+        match author {
+            Some(username) => self.refuse_if_protected(&username),
+            None => self.check_protected_user(&resp.data.author_id).await,
+        }
+    }
+
+    /// Refuse to engage with the user `user_id` if they are protected.
+    ///
+    /// Fails closed like [`Self::check_protected_tweet`].
+    pub(crate) async fn check_protected_user(&self, user_id: &str) -> Result<(), XApiError> {
+        if self.protected.is_empty() {
+            return Ok(());
+        }
+        let path = format!("/users/{user_id}");
+        let response = self.get(&path, &[("user.fields", "username")]).await?;
+        let resp: UserResponse = response
+            .json()
+            .await
+            .map_err(|e| XApiError::Network { source: e })?;
+        self.refuse_if_protected(&resp.data.username)
+    }
+
+    fn refuse_if_protected(&self, username: &str) -> Result<(), XApiError> {
+        if protected::is_protected(username, &self.protected) {
+            tracing::warn!(author = username, "Refused write to protected entity");
+            return Err(XApiError::ProtectedEntity {
+                username: protected::normalize_username(username),
+            });
+        }
+        Ok(())
+    }
+
     /// Parse rate limit headers from an X API response.
     pub(crate) fn parse_rate_limit_headers(headers: &reqwest::header::HeaderMap) -> RateLimitInfo {
         let remaining = headers
@@ -277,3 +374,15 @@ impl XApiHttpClient {
         }
     }
 }
+
+/// Whether `url` points at a production X API host.
+pub fn is_production_url(url: &str) -> bool {
+    let rest = url
+        .trim()
+        .split_once("://")
+        .map_or(url.trim(), |(_, rest)| rest);
+    let host = rest.split(['/', ':', '?', '#']).next().unwrap_or_default();
+    PRODUCTION_HOSTS
+        .iter()
+        .any(|h| host.eq_ignore_ascii_case(h))
+}
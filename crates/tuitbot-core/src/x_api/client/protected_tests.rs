@@ -0,0 +1,80 @@
+use super::*;
+use crate::error::XApiError;
+use crate::x_api::XApiClient;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+async fn protected_client(server: &MockServer) -> XApiHttpClient {
+    XApiHttpClient::with_base_url("test-token".to_string(), server.uri())
+        .with_protected_entities(&["@Reporter".to_string()])
+}
+
+async fn mount_tweet(server: &MockServer, tweet_id: &str, username: &str) {
+    Mock::given(method("GET"))
+        .and(path(format!("/tweets/{tweet_id}")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "data": {"id": tweet_id, "text": "hi", "author_id": "a1"},
+            "includes": {"users": [{"id": "a1", "username": username, "name": "A"}]}
+        })))
+        .mount(server)
+        .await;
+}
+
+#[tokio::test]
+async fn refuses_reply_and_like_to_protected_author() {
+    let server = MockServer::start().await;
+    let client = protected_client(&server).await;
+    mount_tweet(&server, "t1", "reporter").await;
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(201))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    let err = client.reply_to_tweet("Hi", "t1").await.unwrap_err();
+    assert!(
+        matches!(err, XApiError::ProtectedEntity { ref username } if username == "reporter"),
+        "got {err:?}"
+    );
+    let err = client.like_tweet("me", "t1").await.unwrap_err();
+    assert!(matches!(err, XApiError::ProtectedEntity { .. }));
+}
+
+#[tokio::test]
+async fn refuses_follow_of_protected_user() {
+    let server = MockServer::start().await;
+    let client = protected_client(&server).await;
+    Mock::given(method("GET"))
+        .and(path("/users/a1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "data": {"id": "a1", "username": "Reporter", "name": "A"}
+        })))
+        .mount(&server)
+        .await;
+
+    let err = client.follow_user("me", "a1").await.unwrap_err();
+    assert!(matches!(err, XApiError::ProtectedEntity { .. }));
+}
+
+#[tokio::test]
+async fn allows_other_authors_and_fails_closed_on_lookup_error() {
+    let server = MockServer::start().await;
+    let client = protected_client(&server).await;
+    mount_tweet(&server, "t2", "someone_else").await;
+    Mock::given(method("GET"))
+        .and(path("/tweets/t3"))
+        .respond_with(ResponseTemplate::new(503))
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/tweets"))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+            "data": {"id": "r1", "text": "Hi"}
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    client.reply_to_tweet("Hi", "t2").await.expect("reply");
+    assert!(client.quote_tweet("Hi", "t3").await.is_err());
+}
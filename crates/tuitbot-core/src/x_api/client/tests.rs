@@ -8,6 +8,41 @@ async fn setup_client(server: &MockServer) -> XApiHttpClient {
     XApiHttpClient::with_base_url("test-token".to_string(), server.uri())
 }
 
+#[tokio::test]
+async fn from_config_posts_to_base_url_override() {
+    let server = MockServer::start().await;
+    let config = XApiConfig {
+        base_url: Some(format!("{}/", server.uri())),
+        ..Default::default()
+    };
+    let client = XApiHttpClient::from_config("test-token".to_string(), &config);
+    assert_eq!(client.base_url, server.uri());
+
+    Mock::given(method("POST"))
+        .and(path("/tweets"))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+            "data": {"id": "1", "text": "Hello from the sandbox"}
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    client
+        .post_tweet("Hello from the sandbox")
+        .await
+        .expect("post");
+
+    let production = XApiHttpClient::from_config("t".to_string(), &XApiConfig::default());
+    assert_eq!(production.base_url, DEFAULT_BASE_URL);
+}
+
+#[test]
+fn production_urls_are_recognized() {
+    assert!(is_production_url("https://api.x.com/2"));
+    assert!(is_production_url("https://API.twitter.com/2/"));
+    assert!(!is_production_url("http://localhost:8080/2"));
+    assert!(!is_production_url("https://api.x.com.example.net/2"));
+}
+
 #[tokio::test]
 async fn search_tweets_success() {
     let server = MockServer::start().await;
@@ -554,6 +589,40 @@ async fn get_following_success() {
     assert_eq!(resp.data[0].username, "following1");
 }
 
+#[tokio::test]
+async fn get_muted_and_blocked_users_success() {
+    let server = MockServer::start().await;
+    let client = setup_client(&server).await;
+
+    Mock::given(method("GET"))
+        .and(path("/users/u1/muting"))
+        .and(query_param("pagination_token", "p2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "data": [{"id": "m1", "username": "muted1", "name": "Muted One"}],
+            "meta": {"result_count": 1}
+        })))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/users/u1/blocking"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "meta": {"result_count": 0}
+        })))
+        .mount(&server)
+        .await;
+
+    let muted = client
+        .get_muted_users("u1", 1000, Some("p2"))
+        .await
+        .expect("muting");
+    assert_eq!(muted.data[0].username, "muted1");
+    let blocked = client
+        .get_blocked_users("u1", 1000, None)
+        .await
+        .expect("blocking");
+    assert!(blocked.data.is_empty());
+}
+
 #[tokio::test]
 async fn get_user_by_id_success() {
     let server = MockServer::start().await;
@@ -735,3 +804,32 @@ async fn search_tweets_with_pagination_token() {
     assert_eq!(resp.data.len(), 1);
     assert_eq!(resp.data[0].id, "p1");
 }
+
+#[tokio::test]
+async fn get_tweet_usage_success() {
+    let server = MockServer::start().await;
+    let client = setup_client(&server).await;
+
+    Mock::given(method("GET"))
+        .and(path("/usage/tweets"))
+        .and(query_param("days", "8"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "data": {
+                "project_usage": "1234",
+                "project_cap": 10000,
+                "cap_reset_day": 5,
+                "daily_project_usage": {
+                    "project_id": "p1",
+                    "usage": [{"date": "2026-03-01T00:00:00.000Z", "usage": 40}]
+                }
+            }
+        })))
+        .mount(&server)
+        .await;
+
+    let usage = client.get_tweet_usage(8).await.expect("usage");
+    assert_eq!(usage.project_usage, 1234);
+    assert_eq!(usage.project_cap, 10_000);
+    assert_eq!(usage.cap_reset_day, Some(5));
+    assert_eq!(usage.daily_project_usage.unwrap().usage[0].usage, 40);
+}
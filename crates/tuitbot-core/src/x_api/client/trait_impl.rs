@@ -4,11 +4,13 @@
 //! delegates to the HTTP helpers in `mod.rs` (`get`, `post_json`, `delete`).
 
 use crate::error::XApiError;
+use crate::x_api::media::ProgressCallback;
 use crate::x_api::types::{
     ActionResultResponse, BookmarkTweetRequest, DeleteTweetResponse, FollowUserRequest,
     LikeTweetRequest, MediaId, MediaPayload, MediaType, MentionResponse, PostTweetRequest,
     PostTweetResponse, PostedTweet, RawApiResponse, ReplyTo, RetweetRequest, SearchResponse,
-    SingleTweetResponse, Tweet, User, UserResponse, UsersResponse,
+    SingleTweetResponse, Tweet, TweetUsage, TweetUsageResponse, User, UserResponse,
+    UsersLookupResponse, UsersResponse,
 };
 use crate::x_api::XApiClient;
 
@@ -113,6 +115,7 @@ impl XApiClient for XApiHttpClient {
         in_reply_to_id: &str,
     ) -> Result<PostedTweet, XApiError> {
         tracing::debug!(in_reply_to = %in_reply_to_id, chars = text.len(), "Posting reply");
+        self.check_protected_tweet(in_reply_to_id).await?;
         let body = PostTweetRequest {
             text: text.to_string(),
             reply: Some(ReplyTo {
@@ -141,6 +144,23 @@ impl XApiClient for XApiHttpClient {
         .await
     }
 
+    async fn upload_media_with_progress(
+        &self,
+        data: &[u8],
+        media_type: MediaType,
+        progress: ProgressCallback<'_>,
+    ) -> Result<MediaId, XApiError> {
+        super::super::media::upload_media_with_progress(
+            &self.client,
+            &self.upload_base_url,
+            &self.access_token.read().await,
+            data,
+            media_type,
+            progress,
+        )
+        .await
+    }
+
     async fn post_tweet_with_media(
         &self,
         text: &str,
@@ -175,6 +195,7 @@ impl XApiClient for XApiHttpClient {
         media_ids: &[String],
     ) -> Result<PostedTweet, XApiError> {
         tracing::debug!(in_reply_to = %in_reply_to_id, chars = text.len(), media_count = media_ids.len(), "Posting reply with media");
+        self.check_protected_tweet(in_reply_to_id).await?;
         let body = PostTweetRequest {
             text: text.to_string(),
             reply: Some(ReplyTo {
@@ -249,6 +270,34 @@ impl XApiClient for XApiHttpClient {
             .map_err(|e| XApiError::Network { source: e })
     }
 
+    async fn get_user_tweets_since(
+        &self,
+        user_id: &str,
+        max_results: u32,
+        since_id: Option<&str>,
+    ) -> Result<SearchResponse, XApiError> {
+        let path = format!("/users/{user_id}/tweets");
+        let max_str = max_results.to_string();
+        let mut params = vec![
+            ("max_results", max_str.as_str()),
+            ("tweet.fields", TWEET_FIELDS),
+            ("expansions", EXPANSIONS),
+            ("user.fields", USER_FIELDS),
+        ];
+
+        let since_id_owned;
+        if let Some(sid) = since_id {
+            since_id_owned = sid.to_string();
+            params.push(("since_id", &since_id_owned));
+        }
+
+        let response = self.get(&path, &params).await?;
+        response
+            .json::<SearchResponse>()
+            .await
+            .map_err(|e| XApiError::Network { source: e })
+    }
+
     async fn get_user_by_username(&self, username: &str) -> Result<User, XApiError> {
         let path = format!("/users/by/username/{username}");
         let params = [("user.fields", USER_FIELDS)];
@@ -267,6 +316,7 @@ impl XApiClient for XApiHttpClient {
         quoted_tweet_id: &str,
     ) -> Result<PostedTweet, XApiError> {
         tracing::debug!(chars = text.len(), quoted = %quoted_tweet_id, "Posting quote tweet");
+        self.check_protected_tweet(quoted_tweet_id).await?;
         let body = PostTweetRequest {
             text: text.to_string(),
             reply: None,
@@ -284,6 +334,7 @@ impl XApiClient for XApiHttpClient {
 
     async fn like_tweet(&self, user_id: &str, tweet_id: &str) -> Result<bool, XApiError> {
         tracing::debug!(user_id = %user_id, tweet_id = %tweet_id, "Liking tweet");
+        self.check_protected_tweet(tweet_id).await?;
         let path = format!("/users/{user_id}/likes");
         let body = LikeTweetRequest {
             tweet_id: tweet_id.to_string(),
@@ -299,6 +350,7 @@ impl XApiClient for XApiHttpClient {
 
     async fn follow_user(&self, user_id: &str, target_user_id: &str) -> Result<bool, XApiError> {
         tracing::debug!(user_id = %user_id, target = %target_user_id, "Following user");
+        self.check_protected_user(target_user_id).await?;
         let path = format!("/users/{user_id}/following");
         let body = FollowUserRequest {
             target_user_id: target_user_id.to_string(),
@@ -326,6 +378,7 @@ impl XApiClient for XApiHttpClient {
 
     async fn retweet(&self, user_id: &str, tweet_id: &str) -> Result<bool, XApiError> {
         tracing::debug!(user_id = %user_id, tweet_id = %tweet_id, "Retweeting");
+        self.check_protected_tweet(tweet_id).await?;
         let path = format!("/users/{user_id}/retweets");
         let body = RetweetRequest {
             tweet_id: tweet_id.to_string(),
@@ -458,6 +511,78 @@ impl XApiClient for XApiHttpClient {
             .map_err(|e| XApiError::Network { source: e })
     }
 
+    async fn get_muted_users(
+        &self,
+        user_id: &str,
+        max_results: u32,
+        pagination_token: Option<&str>,
+    ) -> Result<UsersResponse, XApiError> {
+        tracing::debug!(user_id = %user_id, max_results = max_results, "Getting muted users");
+        let path = format!("/users/{user_id}/muting");
+        let max_str = max_results.to_string();
+        let mut params = vec![
+            ("max_results", max_str.as_str()),
+            ("user.fields", USER_FIELDS),
+        ];
+
+        let pagination_token_owned;
+        if let Some(pt) = pagination_token {
+            pagination_token_owned = pt.to_string();
+            params.push(("pagination_token", &pagination_token_owned));
+        }
+
+        let response = self.get(&path, &params).await?;
+        response
+            .json::<UsersResponse>()
+            .await
+            .map_err(|e| XApiError::Network { source: e })
+    }
+
+    async fn get_blocked_users(
+        &self,
+        user_id: &str,
+        max_results: u32,
+        pagination_token: Option<&str>,
+    ) -> Result<UsersResponse, XApiError> {
+        tracing::debug!(user_id = %user_id, max_results = max_results, "Getting blocked users");
+        let path = format!("/users/{user_id}/blocking");
+        let max_str = max_results.to_string();
+        let mut params = vec![
+            ("max_results", max_str.as_str()),
+            ("user.fields", USER_FIELDS),
+        ];
+
+        let pagination_token_owned;
+        if let Some(pt) = pagination_token {
+            pagination_token_owned = pt.to_string();
+            params.push(("pagination_token", &pagination_token_owned));
+        }
+
+        let response = self.get(&path, &params).await?;
+        response
+            .json::<UsersResponse>()
+            .await
+            .map_err(|e| XApiError::Network { source: e })
+    }
+
+    async fn get_tweet_usage(&self, days: u32) -> Result<TweetUsage, XApiError> {
+        tracing::debug!(days = days, "Getting post usage");
+        let days_str = days.to_string();
+        let params = [
+            ("days", days_str.as_str()),
+            (
+                "usage.fields",
+                "cap_reset_day,daily_project_usage,project_cap,project_usage",
+            ),
+        ];
+        let response = self.get("/usage/tweets", &params).await?;
+        let resp: TweetUsageResponse = response
+            .json()
+            .await
+            .map_err(|e| XApiError::Network { source: e })?;
+        Ok(resp.data)
+    }
+
     async fn get_user_by_id(&self, user_id: &str) -> Result<User, XApiError> {
         tracing::debug!(user_id = %user_id, "Getting user by ID");
         let path = format!("/users/{user_id}");
@@ -568,6 +693,19 @@ impl XApiClient for XApiHttpClient {
             .map_err(|e| XApiError::Network { source: e })
     }
 
+    async fn get_users_by_usernames(&self, usernames: &[&str]) -> Result<Vec<User>, XApiError> {
+        tracing::debug!(count = usernames.len(), "Getting users by username");
+        let names = usernames.join(",");
+        let params = [("usernames", names.as_str()), ("user.fields", USER_FIELDS)];
+
+        let response = self.get("/users/by", &params).await?;
+        let resp: UsersLookupResponse = response
+            .json()
+            .await
+            .map_err(|e| XApiError::Network { source: e })?;
+        Ok(resp.data)
+    }
+
     async fn get_tweet_liking_users(
         &self,
         tweet_id: &str,
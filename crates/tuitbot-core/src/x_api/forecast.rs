@@ -0,0 +1,285 @@
+//! X API usage forecasting.
+//!
+//! Projects the posts the automation loops would read and write in a
+//! 30-day month from the configured intervals, limits, targets, and
+//! active schedule, and compares them with the tier's monthly caps. Reads
+//! assume every request returns a full page, and writes assume every daily
+//! limit is used, so the forecast is an upper bound. `tuitbot test` prints
+//! it with the arithmetic so users can tune intervals before hitting a cap.
+
+use serde::Serialize;
+
+use super::tier::{ApiTier, TierQuota};
+use crate::config::Config;
+
+/// Days in a forecast month.
+pub const DAYS_PER_MONTH: f64 = 30.0;
+
+/// Posts per discovery search (`max_results` used by the discovery loop).
+const SEARCH_PAGE: u32 = 20;
+/// Posts per mentions request (X default page size).
+const MENTIONS_PAGE: u32 = 10;
+/// Posts per target timeline request.
+const TARGET_PAGE: u32 = 10;
+/// Longest generated thread, in tweets.
+const THREAD_TWEETS: u32 = 8;
+
+/// One source of reads or writes.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ForecastLine {
+    /// What consumes the quota (`mentions`, `replies`, ...).
+    pub source: &'static str,
+    /// Projected posts per month.
+    pub per_month: f64,
+    /// How `per_month` was computed.
+    pub math: String,
+}
+
+/// Projected monthly X API consumption for a config and tier.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct UsageForecast {
+    pub tier: String,
+    pub quota: TierQuota,
+    /// Hours per active day the loops run.
+    pub active_hours_per_day: u32,
+    /// Active days per week.
+    pub active_days_per_week: u32,
+    pub reads: Vec<ForecastLine>,
+    pub writes: Vec<ForecastLine>,
+    pub reads_per_month: f64,
+    pub writes_per_month: f64,
+}
+
+impl UsageForecast {
+    /// Forecast usage for `config` on `tier`. Loops the tier cannot run
+    /// (search and mentions on Free) are left out.
+    pub fn for_config(config: &Config, tier: ApiTier) -> Self {
+        let caps = tier.capabilities();
+        let hours = active_hours_per_day(
+            config.schedule.active_hours_start,
+            config.schedule.active_hours_end,
+        );
+        let days = active_days_per_week(&config.schedule.active_days);
+        let active_days = DAYS_PER_MONTH * f64::from(days) / 7.0;
+        let active_secs = u64::from(hours) * 3600;
+
+        let window = format!("{hours}h/day");
+        let month = format!("{active_days:.1} active days");
+        let polled = |source, interval: u64, per_run: u32, page: u32| {
+            let runs = active_secs as f64 / interval.max(1) as f64;
+            let per_month = runs * f64::from(per_run) * f64::from(page) * active_days;
+            let per_run_text = if per_run == 1 {
+                String::new()
+            } else {
+                format!(" x {per_run} accounts")
+            };
+            ForecastLine {
+                source,
+                per_month,
+                math: format!(
+                    "{window} / {interval}s = {runs:.0} runs{per_run_text} x {page} posts x {month} = {per_month:.0}"
+                ),
+            }
+        };
+
+        let mut reads = Vec::new();
+        if caps.mentions_available {
+            reads.push(polled(
+                "mentions",
+                config.intervals.mentions_check_seconds,
+                1,
+                MENTIONS_PAGE,
+            ));
+            let targets = config.targets.accounts.len() as u32;
+            if targets > 0 {
+                reads.push(polled(
+                    "targets",
+                    config.intervals.mentions_check_seconds,
+                    targets,
+                    TARGET_PAGE,
+                ));
+            }
+        }
+        if caps.discovery_loop_enabled {
+            reads.push(polled(
+                "discovery",
+                config.intervals.discovery_search_seconds,
+                1,
+                SEARCH_PAGE,
+            ));
+        }
+
+        let daily = |source, limit: u32| {
+            let per_month = f64::from(limit) * active_days;
+            ForecastLine {
+                source,
+                per_month,
+                math: format!("{limit}/day x {month} = {per_month:.0}"),
+            }
+        };
+        let threads = config.limits.max_threads_per_week;
+        let thread_posts = f64::from(threads * THREAD_TWEETS) * DAYS_PER_MONTH / 7.0;
+        let writes = vec![
+            daily("replies", config.limits.max_replies_per_day),
+            daily("tweets", config.limits.max_tweets_per_day),
+            ForecastLine {
+                source: "threads",
+                per_month: thread_posts,
+                math: format!(
+                    "{threads}/week x {THREAD_TWEETS} tweets x {DAYS_PER_MONTH:.0}/7 weeks = {thread_posts:.0}"
+                ),
+            },
+        ];
+
+        Self {
+            tier: tier.to_string(),
+            quota: tier.monthly_quota(),
+            active_hours_per_day: hours,
+            active_days_per_week: days,
+            reads_per_month: reads.iter().map(|l| l.per_month).sum(),
+            writes_per_month: writes.iter().map(|l| l.per_month).sum(),
+            reads,
+            writes,
+        }
+    }
+
+    /// Day of the month the read cap runs out, if it does.
+    pub fn reads_exhausted_on(&self) -> Option<u32> {
+        exhausted_on(self.reads_per_month, self.quota.reads_per_month)
+    }
+
+    /// Day of the month the write cap runs out, if it does.
+    pub fn writes_exhausted_on(&self) -> Option<u32> {
+        exhausted_on(self.writes_per_month, self.quota.writes_per_month)
+    }
+
+    /// One line per exceeded cap, with the settings to change.
+    pub fn warnings(&self) -> Vec<String> {
+        let mut warnings = Vec::new();
+        if let Some(day) = self.reads_exhausted_on() {
+            warnings.push(format!(
+                "reads {:.0}/month exceed the {} cap of {} around day {day}; \
+                 raise intervals.mentions_check_seconds or intervals.discovery_search_seconds",
+                self.reads_per_month, self.tier, self.quota.reads_per_month
+            ));
+        }
+        if let Some(day) = self.writes_exhausted_on() {
+            warnings.push(format!(
+                "writes {:.0}/month exceed the {} cap of {} around day {day}; \
+                 lower the [limits] daily caps",
+                self.writes_per_month, self.tier, self.quota.writes_per_month
+            ));
+        }
+        warnings
+    }
+
+    /// Short summary: projected use against each cap.
+    pub fn summary(&self) -> String {
+        format!(
+            "{} tier: {:.0}/{} reads, {:.0}/{} writes per month",
+            self.tier,
+            self.reads_per_month,
+            self.quota.reads_per_month,
+            self.writes_per_month,
+            self.quota.writes_per_month
+        )
+    }
+}
+
+/// Hours per day in the active window. Equal hours mean all day.
+fn active_hours_per_day(start: u8, end: u8) -> u32 {
+    let (start, end) = (u32::from(start), u32::from(end));
+    match start.cmp(&end) {
+        std::cmp::Ordering::Equal => 24,
+        std::cmp::Ordering::Less => end - start,
+        std::cmp::Ordering::Greater => 24 - start + end,
+    }
+}
+
+/// Distinct active weekdays. An empty or unparseable list means every day.
+fn active_days_per_week(days: &[String]) -> u32 {
+    let mut parsed: Vec<chrono::Weekday> = days.iter().filter_map(|d| d.parse().ok()).collect();
+    parsed.sort_by_key(|d| d.num_days_from_monday());
+    parsed.dedup();
+    if parsed.is_empty() {
+        7
+    } else {
+        parsed.len() as u32
+    }
+}
+
+fn exhausted_on(per_month: f64, quota: u64) -> Option<u32> {
+    if per_month <= quota as f64 {
+        return None;
+    }
+    let per_day = per_month / DAYS_PER_MONTH;
+    Some((quota as f64 / per_day).floor() as u32 + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> Config {
+        let mut config = Config::default();
+        config.schedule.active_hours_start = 8;
+        config.schedule.active_hours_end = 22;
+        config.schedule.active_days = vec!["Mon".into(), "Tue".into(), "Wed".into()];
+        config.intervals.mentions_check_seconds = 3600;
+        config.intervals.discovery_search_seconds = 1800;
+        config.targets.accounts = vec!["alice".into(), "bob".into()];
+        config.limits.max_replies_per_day = 7;
+        config.limits.max_tweets_per_day = 0;
+        config.limits.max_threads_per_week = 1;
+        config
+    }
+
+    #[test]
+    fn forecast_counts_active_hours_and_pages() {
+        let forecast = UsageForecast::for_config(&config(), ApiTier::Basic);
+        assert_eq!(forecast.active_hours_per_day, 14);
+        assert_eq!(forecast.active_days_per_week, 3);
+
+        // 14 runs/day; 30 * 3/7 active days.
+        let active_days = 30.0 * 3.0 / 7.0;
+        let sources: Vec<_> = forecast.reads.iter().map(|l| l.source).collect();
+        assert_eq!(sources, vec!["mentions", "targets", "discovery"]);
+        assert!((forecast.reads[0].per_month - 14.0 * 10.0 * active_days).abs() < 1e-6);
+        assert!((forecast.reads[1].per_month - 14.0 * 2.0 * 10.0 * active_days).abs() < 1e-6);
+        assert!((forecast.reads[2].per_month - 28.0 * 20.0 * active_days).abs() < 1e-6);
+        assert!(forecast.reads[1].math.contains("x 2 accounts"));
+
+        // 14 * 10 * (1 + 2 + 4) * 12.86 = 12,600 reads: within Basic.
+        assert!(forecast.reads_exhausted_on().is_none());
+        assert!(forecast.warnings().is_empty());
+    }
+
+    #[test]
+    fn forecast_warns_when_cap_runs_out() {
+        let mut config = config();
+        config.intervals.discovery_search_seconds = 600;
+        let forecast = UsageForecast::for_config(&config, ApiTier::Basic);
+        let day = forecast.reads_exhausted_on().expect("over cap");
+        assert!(day < 30);
+        let warnings = forecast.warnings();
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains("discovery_search_seconds"));
+    }
+
+    #[test]
+    fn free_tier_skips_read_loops() {
+        let forecast = UsageForecast::for_config(&config(), ApiTier::Free);
+        assert!(forecast.reads.is_empty());
+        assert_eq!(forecast.reads_per_month, 0.0);
+        // 7 replies on 12.9 days plus ~34 thread tweets fit in 500.
+        assert!(forecast.writes_exhausted_on().is_none());
+    }
+
+    #[test]
+    fn wrapping_and_all_day_windows() {
+        assert_eq!(active_hours_per_day(22, 6), 8);
+        assert_eq!(active_hours_per_day(9, 9), 24);
+        assert_eq!(active_days_per_week(&[]), 7);
+        assert_eq!(active_days_per_week(&["Mon".into(), "mon".into()]), 1);
+    }
+}
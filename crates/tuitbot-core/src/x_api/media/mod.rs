@@ -3,16 +3,65 @@
 //! Supports simple upload for small images and chunked upload for
 //! large images, GIFs, and videos. Chunked upload follows the
 //! INIT → APPEND → FINALIZE → STATUS polling state machine.
+//!
+//! APPEND segments are retried individually on transient failures, so a
+//! flaky connection resumes from the failed segment instead of restarting
+//! the whole upload. Progress is reported through an optional callback.
+
+use std::time::Duration;
+
+use serde::Serialize;
 
 use crate::error::XApiError;
 use crate::x_api::types::{MediaId, MediaType};
 
 /// Chunk size for chunked uploads (5 MB).
-const CHUNK_SIZE: usize = 5 * 1024 * 1024;
+pub const CHUNK_SIZE: usize = 5 * 1024 * 1024;
 
 /// Maximum time to wait for media processing (300 seconds).
 const MAX_PROCESSING_WAIT_SECS: u64 = 300;
 
+/// Retries per APPEND segment before the upload is abandoned.
+const MAX_SEGMENT_RETRIES: u32 = 3;
+
+/// Initial backoff between segment retries (doubles per attempt).
+const SEGMENT_RETRY_BASE_MS: u64 = 500;
+
+/// Phase of an in-flight media upload.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UploadPhase {
+    /// INIT accepted; a media ID has been allocated.
+    Initialized,
+    /// APPEND segments are being sent.
+    Uploading,
+    /// All bytes sent; FINALIZE issued.
+    Finalizing,
+    /// X is processing the media asynchronously (GIF/video).
+    Processing,
+    /// Media is ready to attach to a tweet.
+    Completed,
+}
+
+/// Snapshot of upload progress, emitted at every phase change and segment.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct UploadProgress {
+    pub phase: UploadPhase,
+    /// X media ID, once INIT has succeeded.
+    pub media_id: Option<String>,
+    pub bytes_sent: u64,
+    pub total_bytes: u64,
+    pub segments_sent: u32,
+    pub segment_count: u32,
+    /// Total segment retries performed so far.
+    pub retries: u32,
+    /// Server-reported processing progress (0-100), when available.
+    pub processing_percent: Option<u8>,
+}
+
+/// Callback receiving upload progress snapshots.
+pub type ProgressCallback<'a> = &'a (dyn Fn(&UploadProgress) + Send + Sync);
+
 /// Response from the media upload endpoint.
 #[derive(Debug, serde::Deserialize)]
 struct MediaUploadResponse {
@@ -28,6 +77,8 @@ struct ProcessingInfo {
     #[serde(default)]
     check_after_secs: Option<u64>,
     #[serde(default)]
+    progress_percent: Option<u8>,
+    #[serde(default)]
     error: Option<ProcessingError>,
 }
 
@@ -45,6 +96,26 @@ pub async fn upload_media(
     access_token: &str,
     data: &[u8],
     media_type: MediaType,
+) -> Result<MediaId, XApiError> {
+    upload_media_with_progress(
+        client,
+        upload_base_url,
+        access_token,
+        data,
+        media_type,
+        &|_| {},
+    )
+    .await
+}
+
+/// Upload media to X API, reporting progress through `progress`.
+pub async fn upload_media_with_progress(
+    client: &reqwest::Client,
+    upload_base_url: &str,
+    access_token: &str,
+    data: &[u8],
+    media_type: MediaType,
+    progress: ProgressCallback<'_>,
 ) -> Result<MediaId, XApiError> {
     let size = data.len() as u64;
 
@@ -61,9 +132,29 @@ pub async fn upload_media(
     }
 
     if media_type.requires_chunked(size) {
-        chunked_upload(client, upload_base_url, access_token, data, media_type).await
+        chunked_upload(
+            client,
+            upload_base_url,
+            access_token,
+            data,
+            media_type,
+            progress,
+        )
+        .await
     } else {
-        simple_upload(client, upload_base_url, access_token, data, media_type).await
+        let media_id =
+            simple_upload(client, upload_base_url, access_token, data, media_type).await?;
+        progress(&UploadProgress {
+            phase: UploadPhase::Completed,
+            media_id: Some(media_id.0.clone()),
+            bytes_sent: size,
+            total_bytes: size,
+            segments_sent: 1,
+            segment_count: 1,
+            retries: 0,
+            processing_percent: None,
+        });
+        Ok(media_id)
     }
 }
 
@@ -123,6 +214,7 @@ async fn chunked_upload(
     access_token: &str,
     data: &[u8],
     media_type: MediaType,
+    progress: ProgressCallback<'_>,
 ) -> Result<MediaId, XApiError> {
     let url = format!("{}/media/upload.json", upload_base_url);
     let total_bytes = data.len();
@@ -160,34 +252,32 @@ async fn chunked_upload(
 
     let media_id = &init_resp.media_id_string;
 
-    // APPEND — send data in chunks
+    let mut state = UploadProgress {
+        phase: UploadPhase::Initialized,
+        media_id: Some(media_id.clone()),
+        bytes_sent: 0,
+        total_bytes: total_bytes as u64,
+        segments_sent: 0,
+        segment_count: total_bytes.div_ceil(CHUNK_SIZE).max(1) as u32,
+        retries: 0,
+        processing_percent: None,
+    };
+    progress(&state);
+
+    // APPEND — send data in chunks, retrying each segment independently.
+    state.phase = UploadPhase::Uploading;
     for (segment_index, chunk) in data.chunks(CHUNK_SIZE).enumerate() {
-        let part = reqwest::multipart::Part::bytes(chunk.to_vec());
-
-        let append_form = reqwest::multipart::Form::new()
-            .text("command", "APPEND")
-            .text("media_id", media_id.clone())
-            .text("segment_index", segment_index.to_string())
-            .part("media_data", part);
-
-        let response = client
-            .post(&url)
-            .bearer_auth(access_token)
-            .multipart(append_form)
-            .send()
-            .await
-            .map_err(|e| XApiError::Network { source: e })?;
-
-        if !response.status().is_success() {
-            let status = response.status().as_u16();
-            let body = response.text().await.unwrap_or_default();
-            return Err(XApiError::MediaUploadError {
-                message: format!("APPEND segment {segment_index} failed (HTTP {status}): {body}"),
-            });
-        }
+        state.retries +=
+            append_segment(client, &url, access_token, media_id, segment_index, chunk).await?;
+        state.segments_sent += 1;
+        state.bytes_sent += chunk.len() as u64;
+        progress(&state);
     }
 
     // FINALIZE
+    state.phase = UploadPhase::Finalizing;
+    progress(&state);
+
     let finalize_form = reqwest::multipart::Form::new()
         .text("command", "FINALIZE")
         .text("media_id", media_id.clone());
@@ -218,20 +308,92 @@ async fn chunked_upload(
 
     // STATUS polling — only needed if processing_info is present
     if let Some(info) = finalize_resp.processing_info {
-        poll_processing_status(client, &url, access_token, media_id, info).await?;
+        poll_processing_status(client, &url, access_token, info, &mut state, progress).await?;
     }
 
+    state.phase = UploadPhase::Completed;
+    progress(&state);
+
     Ok(MediaId(media_id.clone()))
 }
 
+/// Send one APPEND segment, retrying transient failures with exponential backoff.
+///
+/// Returns the number of retries that were needed. Network errors, HTTP 429,
+/// and 5xx responses are retried; other client errors fail immediately.
+async fn append_segment(
+    client: &reqwest::Client,
+    url: &str,
+    access_token: &str,
+    media_id: &str,
+    segment_index: usize,
+    chunk: &[u8],
+) -> Result<u32, XApiError> {
+    let mut attempt = 0;
+    loop {
+        let append_form = reqwest::multipart::Form::new()
+            .text("command", "APPEND")
+            .text("media_id", media_id.to_string())
+            .text("segment_index", segment_index.to_string())
+            .part(
+                "media_data",
+                reqwest::multipart::Part::bytes(chunk.to_vec()),
+            );
+
+        let result = client
+            .post(url)
+            .bearer_auth(access_token)
+            .multipart(append_form)
+            .send()
+            .await;
+
+        let error = match result {
+            Ok(response) if response.status().is_success() => return Ok(attempt),
+            Ok(response) => {
+                let status = response.status();
+                let body = response.text().await.unwrap_or_default();
+                let error = XApiError::MediaUploadError {
+                    message: format!(
+                        "APPEND segment {segment_index} failed (HTTP {}): {body}",
+                        status.as_u16()
+                    ),
+                };
+                if !(status.is_server_error() || status.as_u16() == 429) {
+                    return Err(error);
+                }
+                error
+            }
+            Err(e) => XApiError::Network { source: e },
+        };
+
+        if attempt >= MAX_SEGMENT_RETRIES {
+            return Err(error);
+        }
+        attempt += 1;
+        tracing::warn!(
+            media_id,
+            segment_index,
+            attempt,
+            error = %error,
+            "Retrying media APPEND segment"
+        );
+        tokio::time::sleep(Duration::from_millis(
+            SEGMENT_RETRY_BASE_MS * 2u64.pow(attempt - 1),
+        ))
+        .await;
+    }
+}
+
 /// Poll the STATUS endpoint with exponential backoff until processing completes.
 async fn poll_processing_status(
     client: &reqwest::Client,
     url: &str,
     access_token: &str,
-    media_id: &str,
     initial_info: ProcessingInfo,
+    state: &mut UploadProgress,
+    progress: ProgressCallback<'_>,
 ) -> Result<(), XApiError> {
+    let media_id = state.media_id.clone().unwrap_or_default();
     let mut total_waited: u64 = 0;
     let mut wait_secs = initial_info.check_after_secs.unwrap_or(5);
 
@@ -247,6 +409,10 @@ async fn poll_processing_status(
         return Err(XApiError::MediaUploadError { message: msg });
     }
 
+    state.phase = UploadPhase::Processing;
+    state.processing_percent = initial_info.progress_percent;
+    progress(state);
+
     loop {
         if total_waited >= MAX_PROCESSING_WAIT_SECS {
             return Err(XApiError::MediaProcessingTimeout {
@@ -254,13 +420,13 @@ async fn poll_processing_status(
             });
         }
 
-        tokio::time::sleep(std::time::Duration::from_secs(wait_secs)).await;
+        tokio::time::sleep(Duration::from_secs(wait_secs)).await;
         total_waited += wait_secs;
 
         let response = client
             .get(url)
             .bearer_auth(access_token)
-            .query(&[("command", "STATUS"), ("media_id", media_id)])
+            .query(&[("command", "STATUS"), ("media_id", media_id.as_str())])
             .send()
             .await
             .map_err(|e| XApiError::Network { source: e })?;
@@ -291,6 +457,10 @@ async fn poll_processing_status(
                 return Err(XApiError::MediaUploadError { message: msg });
             }
             Some(info) => {
+                if info.progress_percent.is_some() {
+                    state.processing_percent = info.progress_percent;
+                    progress(state);
+                }
                 wait_secs = info.check_after_secs.unwrap_or(wait_secs * 2).min(30);
             }
             None => return Ok(()),
@@ -299,128 +469,4 @@ async fn poll_processing_status(
 }
 
 #[cfg(test)]
-mod tests {
-    use super::*;
-    use wiremock::matchers::{method, path};
-    use wiremock::{Mock, MockServer, ResponseTemplate};
-
-    #[tokio::test]
-    async fn simple_upload_success() {
-        let server = MockServer::start().await;
-        let client = reqwest::Client::new();
-
-        Mock::given(method("POST"))
-            .and(path("/media/upload.json"))
-            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
-                "media_id_string": "123456"
-            })))
-            .mount(&server)
-            .await;
-
-        let data = vec![0u8; 1024]; // 1KB image
-        let result = upload_media(
-            &client,
-            &server.uri(),
-            "test-token",
-            &data,
-            MediaType::Image(crate::x_api::types::ImageFormat::Jpeg),
-        )
-        .await;
-
-        let media_id = result.expect("upload should succeed");
-        assert_eq!(media_id.0, "123456");
-    }
-
-    #[tokio::test]
-    async fn size_validation_rejects_oversized_file() {
-        let client = reqwest::Client::new();
-        let data = vec![0u8; 6 * 1024 * 1024]; // 6MB > 5MB limit for images
-
-        let result = upload_media(
-            &client,
-            "http://unused",
-            "test-token",
-            &data,
-            MediaType::Image(crate::x_api::types::ImageFormat::Jpeg),
-        )
-        .await;
-
-        match result {
-            Err(XApiError::MediaUploadError { message }) => {
-                assert!(message.contains("exceeds maximum"));
-            }
-            other => panic!("expected MediaUploadError, got: {other:?}"),
-        }
-    }
-
-    #[tokio::test]
-    async fn chunked_upload_init_append_finalize() {
-        let server = MockServer::start().await;
-        let client = reqwest::Client::new();
-
-        // The upload endpoint handles INIT, APPEND, and FINALIZE via multipart
-        Mock::given(method("POST"))
-            .and(path("/media/upload.json"))
-            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
-                "media_id_string": "chunked_789"
-            })))
-            .expect(3) // INIT + APPEND + FINALIZE
-            .mount(&server)
-            .await;
-
-        let data = vec![0u8; 1024]; // Small data but GIF forces chunked
-        let result =
-            upload_media(&client, &server.uri(), "test-token", &data, MediaType::Gif).await;
-
-        let media_id = result.expect("chunked upload should succeed");
-        assert_eq!(media_id.0, "chunked_789");
-    }
-
-    #[tokio::test]
-    async fn upload_error_response() {
-        let server = MockServer::start().await;
-        let client = reqwest::Client::new();
-
-        Mock::given(method("POST"))
-            .and(path("/media/upload.json"))
-            .respond_with(ResponseTemplate::new(413).set_body_string("Request Entity Too Large"))
-            .mount(&server)
-            .await;
-
-        let data = vec![0u8; 1024];
-        let result = upload_media(
-            &client,
-            &server.uri(),
-            "test-token",
-            &data,
-            MediaType::Image(crate::x_api::types::ImageFormat::Png),
-        )
-        .await;
-
-        assert!(matches!(result, Err(XApiError::MediaUploadError { .. })));
-    }
-
-    #[tokio::test]
-    async fn tweet_with_media_request_body() {
-        use crate::x_api::types::{MediaPayload, PostTweetRequest};
-
-        let req = PostTweetRequest {
-            text: "Hello with media".to_string(),
-            reply: None,
-            media: Some(MediaPayload {
-                media_ids: vec!["111".to_string(), "222".to_string()],
-            }),
-            quote_tweet_id: None,
-        };
-
-        let json = serde_json::to_string(&req).expect("serialize");
-        assert!(json.contains("media_ids"));
-        assert!(json.contains("111"));
-        assert!(json.contains("222"));
-
-        // Verify round-trip
-        let parsed: PostTweetRequest = serde_json::from_str(&json).expect("deserialize");
-        let media = parsed.media.expect("media should be present");
-        assert_eq!(media.media_ids.len(), 2);
-    }
-}
+mod tests;
@@ -0,0 +1,306 @@
+//! Tests for X API media upload.
+
+use super::*;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn simple_upload_success() {
+    let server = MockServer::start().await;
+    let client = reqwest::Client::new();
+
+    Mock::given(method("POST"))
+        .and(path("/media/upload.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "media_id_string": "123456"
+        })))
+        .mount(&server)
+        .await;
+
+    let data = vec![0u8; 1024]; // 1KB image
+    let result = upload_media(
+        &client,
+        &server.uri(),
+        "test-token",
+        &data,
+        MediaType::Image(crate::x_api::types::ImageFormat::Jpeg),
+    )
+    .await;
+
+    let media_id = result.expect("upload should succeed");
+    assert_eq!(media_id.0, "123456");
+}
+
+#[tokio::test]
+async fn size_validation_rejects_oversized_file() {
+    let client = reqwest::Client::new();
+    let data = vec![0u8; 6 * 1024 * 1024]; // 6MB > 5MB limit for images
+
+    let result = upload_media(
+        &client,
+        "http://unused",
+        "test-token",
+        &data,
+        MediaType::Image(crate::x_api::types::ImageFormat::Jpeg),
+    )
+    .await;
+
+    match result {
+        Err(XApiError::MediaUploadError { message }) => {
+            assert!(message.contains("exceeds maximum"));
+        }
+        other => panic!("expected MediaUploadError, got: {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn chunked_upload_init_append_finalize() {
+    let server = MockServer::start().await;
+    let client = reqwest::Client::new();
+
+    // The upload endpoint handles INIT, APPEND, and FINALIZE via multipart
+    Mock::given(method("POST"))
+        .and(path("/media/upload.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "media_id_string": "chunked_789"
+        })))
+        .expect(3) // INIT + APPEND + FINALIZE
+        .mount(&server)
+        .await;
+
+    let data = vec![0u8; 1024]; // Small data but GIF forces chunked
+    let result = upload_media(&client, &server.uri(), "test-token", &data, MediaType::Gif).await;
+
+    let media_id = result.expect("chunked upload should succeed");
+    assert_eq!(media_id.0, "chunked_789");
+}
+
+#[tokio::test]
+async fn upload_error_response() {
+    let server = MockServer::start().await;
+    let client = reqwest::Client::new();
+
+    Mock::given(method("POST"))
+        .and(path("/media/upload.json"))
+        .respond_with(ResponseTemplate::new(413).set_body_string("Request Entity Too Large"))
+        .mount(&server)
+        .await;
+
+    let data = vec![0u8; 1024];
+    let result = upload_media(
+        &client,
+        &server.uri(),
+        "test-token",
+        &data,
+        MediaType::Image(crate::x_api::types::ImageFormat::Png),
+    )
+    .await;
+
+    assert!(matches!(result, Err(XApiError::MediaUploadError { .. })));
+}
+
+#[tokio::test]
+async fn tweet_with_media_request_body() {
+    use crate::x_api::types::{MediaPayload, PostTweetRequest};
+
+    let req = PostTweetRequest {
+        text: "Hello with media".to_string(),
+        reply: None,
+        media: Some(MediaPayload {
+            media_ids: vec!["111".to_string(), "222".to_string()],
+        }),
+        quote_tweet_id: None,
+    };
+
+    let json = serde_json::to_string(&req).expect("serialize");
+    assert!(json.contains("media_ids"));
+    assert!(json.contains("111"));
+    assert!(json.contains("222"));
+
+    // Verify round-trip
+    let parsed: PostTweetRequest = serde_json::from_str(&json).expect("deserialize");
+    let media = parsed.media.expect("media should be present");
+    assert_eq!(media.media_ids.len(), 2);
+}
+
+fn collect_progress() -> (
+    std::sync::Arc<std::sync::Mutex<Vec<UploadProgress>>>,
+    impl Fn(&UploadProgress) + Send + Sync,
+) {
+    let events = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+    let sink = events.clone();
+    (events, move |p: &UploadProgress| {
+        sink.lock().unwrap().push(p.clone())
+    })
+}
+
+#[tokio::test]
+async fn chunked_upload_reports_progress() {
+    let server = MockServer::start().await;
+    let client = reqwest::Client::new();
+
+    Mock::given(method("POST"))
+        .and(path("/media/upload.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "media_id_string": "vid_1"
+        })))
+        .mount(&server)
+        .await;
+
+    // Two segments: one full chunk plus a partial one.
+    let data = vec![0u8; CHUNK_SIZE + 10];
+    let (events, callback) = collect_progress();
+    upload_media_with_progress(
+        &client,
+        &server.uri(),
+        "test-token",
+        &data,
+        MediaType::Video,
+        &callback,
+    )
+    .await
+    .expect("upload should succeed");
+
+    let events = events.lock().unwrap();
+    let phases: Vec<UploadPhase> = events.iter().map(|e| e.phase).collect();
+    assert_eq!(
+        phases,
+        vec![
+            UploadPhase::Initialized,
+            UploadPhase::Uploading,
+            UploadPhase::Uploading,
+            UploadPhase::Finalizing,
+            UploadPhase::Completed,
+        ]
+    );
+    let last = events.last().unwrap();
+    assert_eq!(last.segment_count, 2);
+    assert_eq!(last.segments_sent, 2);
+    assert_eq!(last.bytes_sent, data.len() as u64);
+    assert_eq!(last.media_id.as_deref(), Some("vid_1"));
+    assert_eq!(last.retries, 0);
+}
+
+#[tokio::test]
+async fn append_segment_retried_after_server_error() {
+    use wiremock::matchers::body_string_contains;
+
+    let server = MockServer::start().await;
+    let client = reqwest::Client::new();
+
+    // First APPEND attempt fails with 503, then succeeds.
+    Mock::given(method("POST"))
+        .and(path("/media/upload.json"))
+        .and(body_string_contains("APPEND"))
+        .respond_with(ResponseTemplate::new(503).set_body_string("busy"))
+        .up_to_n_times(1)
+        .with_priority(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/media/upload.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "media_id_string": "retry_1"
+        })))
+        .expect(3) // INIT + retried APPEND + FINALIZE
+        .mount(&server)
+        .await;
+
+    let (events, callback) = collect_progress();
+    let media_id = upload_media_with_progress(
+        &client,
+        &server.uri(),
+        "test-token",
+        &[0u8; 64],
+        MediaType::Gif,
+        &callback,
+    )
+    .await
+    .expect("upload should succeed after retry");
+
+    assert_eq!(media_id.0, "retry_1");
+    assert_eq!(events.lock().unwrap().last().unwrap().retries, 1);
+}
+
+#[tokio::test]
+async fn append_client_error_not_retried() {
+    use wiremock::matchers::body_string_contains;
+
+    let server = MockServer::start().await;
+    let client = reqwest::Client::new();
+
+    Mock::given(method("POST"))
+        .and(path("/media/upload.json"))
+        .and(body_string_contains("APPEND"))
+        .respond_with(ResponseTemplate::new(400).set_body_string("bad segment"))
+        .expect(1)
+        .with_priority(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/media/upload.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "media_id_string": "bad_1"
+        })))
+        .mount(&server)
+        .await;
+
+    let result = upload_media(
+        &client,
+        &server.uri(),
+        "test-token",
+        &[0u8; 64],
+        MediaType::Gif,
+    )
+    .await;
+
+    match result {
+        Err(XApiError::MediaUploadError { message }) => {
+            assert!(message.contains("APPEND segment 0"));
+        }
+        other => panic!("expected MediaUploadError, got: {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn processing_progress_is_reported() {
+    let server = MockServer::start().await;
+    let client = reqwest::Client::new();
+
+    Mock::given(method("POST"))
+        .and(path("/media/upload.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "media_id_string": "proc_1",
+            "processing_info": { "state": "pending", "check_after_secs": 0, "progress_percent": 10 }
+        })))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/media/upload.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "media_id_string": "proc_1",
+            "processing_info": { "state": "succeeded", "progress_percent": 100 }
+        })))
+        .mount(&server)
+        .await;
+
+    let (events, callback) = collect_progress();
+    upload_media_with_progress(
+        &client,
+        &server.uri(),
+        "test-token",
+        &[0u8; 64],
+        MediaType::Video,
+        &callback,
+    )
+    .await
+    .expect("upload should succeed");
+
+    let events = events.lock().unwrap();
+    let processing = events
+        .iter()
+        .find(|e| e.phase == UploadPhase::Processing)
+        .expect("processing phase reported");
+    assert_eq!(processing.processing_percent, Some(10));
+    assert_eq!(events.last().unwrap().phase, UploadPhase::Completed);
+}
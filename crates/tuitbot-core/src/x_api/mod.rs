@@ -6,6 +6,7 @@
 
 pub mod auth;
 pub mod client;
+pub mod forecast;
 pub mod media;
 pub mod scopes;
 pub mod tier;
@@ -68,6 +69,24 @@ pub trait XApiClient: Send + Sync {
         pagination_token: Option<&str>,
     ) -> Result<SearchResponse, XApiError>;
 
+    /// Get recent tweets from a user that are newer than `since_id`.
+    ///
+    /// The default implementation fetches the latest page and filters it
+    /// locally; the HTTP client passes `since_id` to the API instead.
+    async fn get_user_tweets_since(
+        &self,
+        user_id: &str,
+        max_results: u32,
+        since_id: Option<&str>,
+    ) -> Result<SearchResponse, XApiError> {
+        let mut response = self.get_user_tweets(user_id, max_results, None).await?;
+        if let Some(since_id) = since_id {
+            response.data.retain(|t| is_newer_tweet_id(&t.id, since_id));
+            response.meta.result_count = response.data.len() as u32;
+        }
+        Ok(response)
+    }
+
     /// Look up a user by their username.
     async fn get_user_by_username(&self, username: &str) -> Result<User, XApiError>;
 
@@ -84,6 +103,29 @@ pub trait XApiClient: Send + Sync {
         })
     }
 
+    /// Upload media, reporting progress snapshots through `progress`.
+    ///
+    /// Default delegates to `upload_media` and reports a single completion event.
+    async fn upload_media_with_progress(
+        &self,
+        data: &[u8],
+        media_type: MediaType,
+        progress: media::ProgressCallback<'_>,
+    ) -> Result<MediaId, XApiError> {
+        let media_id = self.upload_media(data, media_type).await?;
+        progress(&media::UploadProgress {
+            phase: media::UploadPhase::Completed,
+            media_id: Some(media_id.0.clone()),
+            bytes_sent: data.len() as u64,
+            total_bytes: data.len() as u64,
+            segments_sent: 1,
+            segment_count: 1,
+            retries: 0,
+            processing_percent: None,
+        });
+        Ok(media_id)
+    }
+
     /// Post a new tweet with media attachments.
     ///
     /// Default delegates to `post_tweet` (ignoring media) for backward compat.
@@ -218,6 +260,43 @@ pub trait XApiClient: Send + Sync {
         })
     }
 
+    /// Get accounts a user has muted.
+    async fn get_muted_users(
+        &self,
+        _user_id: &str,
+        _max_results: u32,
+        _pagination_token: Option<&str>,
+    ) -> Result<UsersResponse, XApiError> {
+        Err(XApiError::ApiError {
+            status: 0,
+            message: "not implemented".to_string(),
+        })
+    }
+
+    /// Get accounts a user has blocked.
+    async fn get_blocked_users(
+        &self,
+        _user_id: &str,
+        _max_results: u32,
+        _pagination_token: Option<&str>,
+    ) -> Result<UsersResponse, XApiError> {
+        Err(XApiError::ApiError {
+            status: 0,
+            message: "not implemented".to_string(),
+        })
+    }
+
+    /// Get the project's post usage over the last `days` days.
+    ///
+    /// Not every tier may call the usage endpoint; callers should treat an
+    /// error as "no official numbers available".
+    async fn get_tweet_usage(&self, _days: u32) -> Result<TweetUsage, XApiError> {
+        Err(XApiError::ApiError {
+            status: 0,
+            message: "not implemented".to_string(),
+        })
+    }
+
     /// Get a user by their ID.
     async fn get_user_by_id(&self, _user_id: &str) -> Result<User, XApiError> {
         Err(XApiError::ApiError {
@@ -276,6 +355,15 @@ pub trait XApiClient: Send + Sync {
         })
     }
 
+    /// Look up users by username, up to 100 at a time. Usernames that do
+    /// not resolve are left out of the result.
+    async fn get_users_by_usernames(&self, _usernames: &[&str]) -> Result<Vec<User>, XApiError> {
+        Err(XApiError::ApiError {
+            status: 0,
+            message: "not implemented".to_string(),
+        })
+    }
+
     /// Get users who liked a specific tweet.
     async fn get_tweet_liking_users(
         &self,
@@ -310,3 +398,24 @@ pub trait XApiClient: Send + Sync {
         })
     }
 }
+
+/// Whether tweet ID `id` is newer than `since_id`.
+///
+/// Tweet IDs are snowflakes that grow over time, so they compare numerically
+/// (by length first, then digit by digit).
+pub fn is_newer_tweet_id(id: &str, since_id: &str) -> bool {
+    (id.len(), id) > (since_id.len(), since_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn newer_tweet_id_compares_numerically() {
+        assert!(is_newer_tweet_id("1001", "999"));
+        assert!(is_newer_tweet_id("1900", "1899"));
+        assert!(!is_newer_tweet_id("1899", "1900"));
+        assert!(!is_newer_tweet_id("1900", "1900"));
+    }
+}
@@ -20,6 +20,8 @@ pub const REQUIRED_SCOPES: &[&str] = &[
     "bookmark.write",
     "dm.read",
     "dm.write",
+    "mute.read",
+    "block.read",
     "offline.access",
 ];
 
@@ -81,6 +83,11 @@ pub const FEATURE_SCOPE_MAP: &[FeatureScopeMapping] = &[
         description: "Send direct messages and create group conversations.",
         required_scopes: &["dm.write", "dm.read", "users.read"],
     },
+    FeatureScopeMapping {
+        feature: "Mute/block sync",
+        description: "Skip authors muted or blocked on X.",
+        required_scopes: &["mute.read", "block.read", "users.read"],
+    },
 ];
 
 /// A degraded feature caused by missing scopes.
@@ -205,12 +212,12 @@ mod tests {
     #[test]
     fn extra_scopes_are_reported_without_error() {
         let mut scopes = all_required_scopes();
-        scopes.push("mute.read".to_string());
+        scopes.push("list.read".to_string());
 
         let analysis = analyze_scopes(&scopes);
 
         assert!(analysis.all_required_present);
         assert!(analysis.missing.is_empty());
-        assert_eq!(analysis.extra, vec!["mute.read".to_string()]);
+        assert_eq!(analysis.extra, vec!["list.read".to_string()]);
     }
 }
@@ -31,7 +31,34 @@ pub struct TierCapabilities {
     pub discovery_loop_enabled: bool,
 }
 
+/// Monthly X API caps for a tier, per authenticated user.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+pub struct TierQuota {
+    /// Posts that may be read (returned by search, timelines, mentions).
+    pub reads_per_month: u64,
+    /// Posts that may be created, replies and thread tweets included.
+    pub writes_per_month: u64,
+}
+
 impl ApiTier {
+    /// Published monthly caps for this tier.
+    pub fn monthly_quota(&self) -> TierQuota {
+        match self {
+            ApiTier::Free => TierQuota {
+                reads_per_month: 100,
+                writes_per_month: 500,
+            },
+            ApiTier::Basic => TierQuota {
+                reads_per_month: 15_000,
+                writes_per_month: 3_000,
+            },
+            ApiTier::Pro => TierQuota {
+                reads_per_month: 1_000_000,
+                writes_per_month: 300_000,
+            },
+        }
+    }
+
     /// Get the capabilities available at this tier.
     pub fn capabilities(&self) -> TierCapabilities {
         match self {
@@ -61,6 +88,21 @@ impl std::fmt::Display for ApiTier {
     }
 }
 
+impl std::str::FromStr for ApiTier {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "free" => Ok(ApiTier::Free),
+            "basic" => Ok(ApiTier::Basic),
+            "pro" => Ok(ApiTier::Pro),
+            other => Err(format!(
+                "unknown X API tier '{other}' (expected free, basic, or pro)"
+            )),
+        }
+    }
+}
+
 /// Detect the user's X API tier by probing the search endpoint.
 ///
 /// Uses a minimal test query to avoid wasting rate limit quota.
@@ -24,6 +24,60 @@ pub struct Tweet {
     /// Conversation thread ID (matches the root tweet's ID).
     #[serde(default)]
     pub conversation_id: Option<String>,
+    /// Parsed entities (URLs) in the tweet text.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub entities: Option<TweetEntities>,
+    /// Attached media keys, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub attachments: Option<TweetAttachments>,
+}
+
+/// Entities parsed from a tweet's text.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TweetEntities {
+    /// URLs in the tweet, with their expanded targets.
+    #[serde(default)]
+    pub urls: Vec<UrlEntity>,
+}
+
+/// A single URL entity. `url` is the t.co short link that appears in the text.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UrlEntity {
+    /// The t.co short link as it appears in the tweet text.
+    pub url: String,
+    /// The fully expanded destination URL.
+    #[serde(default)]
+    pub expanded_url: Option<String>,
+}
+
+/// Media and poll attachments on a tweet.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TweetAttachments {
+    /// Keys of attached media (images, video, GIFs).
+    #[serde(default)]
+    pub media_keys: Vec<String>,
+}
+
+impl Tweet {
+    /// Expanded URLs from the tweet's entities, falling back to the short link.
+    pub fn expanded_urls(&self) -> Vec<String> {
+        self.entities
+            .as_ref()
+            .map(|e| {
+                e.urls
+                    .iter()
+                    .map(|u| u.expanded_url.clone().unwrap_or_else(|| u.url.clone()))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Whether the tweet has attached media.
+    pub fn has_media(&self) -> bool {
+        self.attachments
+            .as_ref()
+            .is_some_and(|a| !a.media_keys.is_empty())
+    }
 }
 
 /// Public engagement metrics for a tweet.
@@ -282,6 +336,15 @@ pub struct UserResponse {
     pub data: User,
 }
 
+/// Response from the username batch lookup. Unknown or suspended usernames
+/// are reported in `errors`, which is not kept, and are absent from `data`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UsersLookupResponse {
+    /// The users that were found.
+    #[serde(default)]
+    pub data: Vec<User>,
+}
+
 /// Response from endpoints returning a list of users (followers, following, batch lookup).
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UsersResponse {
@@ -365,6 +428,61 @@ pub struct DeleteTweetData {
     pub deleted: bool,
 }
 
+/// Response from the X API v2 post usage endpoint (`GET /2/usage/tweets`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TweetUsageResponse {
+    /// Project-level usage for the current billing cycle.
+    pub data: TweetUsage,
+}
+
+/// Posts consumed by the project, as counted by X.
+///
+/// X returns the counts as strings; they are parsed into integers.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TweetUsage {
+    /// Posts consumed in the current billing cycle.
+    #[serde(default, deserialize_with = "de_count")]
+    pub project_usage: u64,
+    /// Posts allowed per billing cycle.
+    #[serde(default, deserialize_with = "de_count")]
+    pub project_cap: u64,
+    /// Day of the month the cycle resets.
+    #[serde(default)]
+    pub cap_reset_day: Option<u32>,
+    /// Per-day usage for the requested window.
+    #[serde(default)]
+    pub daily_project_usage: Option<DailyProjectUsage>,
+}
+
+/// Per-day post usage for a project.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DailyProjectUsage {
+    #[serde(default)]
+    pub usage: Vec<DailyUsage>,
+}
+
+/// Posts consumed on one UTC day.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DailyUsage {
+    /// Start of the day, e.g. `2026-03-01T00:00:00.000Z`.
+    pub date: String,
+    /// Posts consumed that day.
+    #[serde(default, deserialize_with = "de_count")]
+    pub usage: u64,
+}
+
+/// Accept a count sent either as a JSON number or as a numeric string.
+fn de_count<'de, D>(deserializer: D) -> Result<u64, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    match serde_json::Value::deserialize(deserializer)? {
+        serde_json::Value::Number(n) => Ok(n.as_u64().unwrap_or(0)),
+        serde_json::Value::String(s) => s.trim().parse().map_err(serde::de::Error::custom),
+        _ => Ok(0),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
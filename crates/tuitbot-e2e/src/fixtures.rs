@@ -0,0 +1,116 @@
+//! Deterministic fixture data for the mock X API.
+//!
+//! Tweet IDs, authors and metrics are fixed. Timestamps are relative to the
+//! moment the search response is built so recency scoring does not drift
+//! as the fixtures age.
+
+use chrono::{Duration, Utc};
+use serde_json::{json, Value};
+
+/// Keyword the harness configures as its only product keyword.
+pub const KEYWORD: &str = "rust async";
+
+/// Reply text returned by the stub LLM.
+pub const REPLY_TEXT: &str =
+    "Pinning the runtime version in CI saved us from exactly this. Worth a try.";
+
+/// Tweet that clears the scoring threshold.
+pub const STRONG_TWEET_ID: &str = "1900000000000000001";
+
+/// Tweet that scores below the threshold.
+pub const WEAK_TWEET_ID: &str = "1900000000000000002";
+
+/// A tweet as returned by `GET /tweets/search/recent`.
+#[derive(Debug, Clone)]
+pub struct FixtureTweet {
+    pub id: &'static str,
+    pub text: &'static str,
+    pub author_id: &'static str,
+    pub username: &'static str,
+    pub followers: u64,
+    pub likes: u64,
+    pub retweets: u64,
+    pub replies: u64,
+    /// How long before "now" the tweet was created.
+    pub age_minutes: i64,
+}
+
+/// One tweet worth replying to and one that is not.
+pub fn discovery_tweets() -> Vec<FixtureTweet> {
+    vec![
+        FixtureTweet {
+            id: STRONG_TWEET_ID,
+            text: "Anyone else fighting rust async runtime deadlocks in CI? \
+                   Tests pass locally and hang on the runner.",
+            author_id: "2000000000000000001",
+            username: "ferris_dev",
+            followers: 4_200,
+            likes: 12,
+            retweets: 2,
+            replies: 1,
+            age_minutes: 10,
+        },
+        FixtureTweet {
+            id: WEAK_TWEET_ID,
+            text: "rust async",
+            author_id: "2000000000000000002",
+            username: "quiet_account",
+            followers: 3,
+            likes: 0,
+            retweets: 0,
+            replies: 40,
+            age_minutes: 60 * 24 * 6,
+        },
+    ]
+}
+
+/// Build a search response body with `includes.users` for `tweets`.
+pub fn search_response(tweets: &[FixtureTweet]) -> Value {
+    let now = Utc::now();
+    let data: Vec<Value> = tweets
+        .iter()
+        .map(|t| {
+            json!({
+                "id": t.id,
+                "text": t.text,
+                "author_id": t.author_id,
+                "created_at": (now - Duration::minutes(t.age_minutes))
+                    .to_rfc3339_opts(chrono::SecondsFormat::Millis, true),
+                "conversation_id": t.id,
+                "public_metrics": {
+                    "like_count": t.likes,
+                    "retweet_count": t.retweets,
+                    "reply_count": t.replies,
+                    "quote_count": 0,
+                    "impression_count": 0,
+                    "bookmark_count": 0,
+                },
+            })
+        })
+        .collect();
+    let users: Vec<Value> = tweets
+        .iter()
+        .map(|t| {
+            json!({
+                "id": t.author_id,
+                "username": t.username,
+                "name": t.username,
+                "public_metrics": {
+                    "followers_count": t.followers,
+                    "following_count": 100,
+                    "tweet_count": 1_000,
+                },
+            })
+        })
+        .collect();
+
+    json!({
+        "data": data,
+        "includes": { "users": users },
+        "meta": {
+            "result_count": tweets.len(),
+            "newest_id": tweets.first().map(|t| t.id),
+            "oldest_id": tweets.last().map(|t| t.id),
+        },
+    })
+}
@@ -0,0 +1,129 @@
+//! Pipeline wiring for end-to-end runs.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio_util::sync::CancellationToken;
+use tuitbot_core::automation::adapters::{
+    ApprovalQueueAdapter, LlmReplyAdapter, PostSenderAdapter, SafetyAdapter, ScoringAdapter,
+    StorageAdapter, XApiPostExecutorAdapter, XApiSearchAdapter,
+};
+use tuitbot_core::automation::{
+    create_posting_queue, run_posting_queue_with_approval, ApprovalQueue, DiscoveryLoop,
+    DiscoverySummary, PostExecutor,
+};
+use tuitbot_core::config::Config;
+use tuitbot_core::content::ContentGenerator;
+use tuitbot_core::safety::SafetyGuard;
+use tuitbot_core::scoring::ScoringEngine;
+use tuitbot_core::storage::write_queue::WriteQueue;
+use tuitbot_core::storage::{self, DbPool};
+use tuitbot_core::x_api::{XApiClient, XApiHttpClient};
+
+use crate::fixtures::{KEYWORD, REPLY_TEXT};
+use crate::llm::StubLlm;
+use crate::mock_x::MockXApi;
+
+/// Scoring threshold used by the harness; the strong fixture clears it and
+/// the weak one does not.
+const THRESHOLD: u32 = 60;
+
+/// A fresh database, mock X API and stub LLM, wired like `tuitbot run`.
+pub struct Harness {
+    pub pool: DbPool,
+    pub x_api: MockXApi,
+    pub llm: StubLlm,
+    pub config: Config,
+}
+
+impl Harness {
+    pub async fn start() -> Self {
+        let pool = storage::init_test_db().await.expect("init test db");
+        let x_api = MockXApi::start().await;
+
+        let mut config = Config::default();
+        config.business.product_name = "Tuitbot".to_string();
+        config.business.product_description = "Grows your X account on autopilot".to_string();
+        config.business.product_keywords = vec![KEYWORD.to_string()];
+        config.scoring.threshold = THRESHOLD;
+        config.approval_mode = false;
+
+        storage::rate_limits::init_rate_limits(&pool, &config.limits, &config.intervals)
+            .await
+            .expect("init rate limits");
+
+        Self {
+            pool,
+            x_api,
+            llm: StubLlm::new(REPLY_TEXT),
+            config,
+        }
+    }
+
+    /// Queue replies for review instead of posting them.
+    pub fn with_approval_mode(mut self, enabled: bool) -> Self {
+        self.config.approval_mode = enabled;
+        self
+    }
+
+    /// Run one discovery iteration (search, score, generate, queue, post)
+    /// and wait for the posting queue to drain.
+    pub async fn run_discovery_once(&self) -> DiscoverySummary {
+        let client: Arc<dyn XApiClient> = Arc::new(XApiHttpClient::with_base_url(
+            "e2e-token".to_string(),
+            self.x_api.uri(),
+        ));
+        let keywords = self.config.business.product_keywords.clone();
+        let content_gen = Arc::new(
+            ContentGenerator::new(Box::new(self.llm.clone()), self.config.business.clone())
+                .with_disclosure(self.config.disclosure.clone()),
+        );
+        let scoring_engine = Arc::new(ScoringEngine::new(
+            self.config.scoring.clone(),
+            keywords.clone(),
+        ));
+        let safety_guard = Arc::new(SafetyGuard::new(self.pool.clone()));
+        let writes = WriteQueue::spawn(self.pool.clone());
+
+        let (post_tx, post_rx) = create_posting_queue();
+        let executor: Arc<dyn PostExecutor> = Arc::new(
+            XApiPostExecutorAdapter::new(client.clone()).with_kill_switch(self.pool.clone()),
+        );
+        let approval_queue = self.config.effective_approval_mode().then(|| {
+            Arc::new(ApprovalQueueAdapter::new(self.pool.clone()).with_write_queue(writes.clone()))
+                as Arc<dyn ApprovalQueue>
+        });
+        let cancel = CancellationToken::new();
+        let queue = tokio::spawn(run_posting_queue_with_approval(
+            post_rx,
+            executor,
+            approval_queue,
+            Duration::ZERO,
+            Duration::ZERO,
+            None,
+            cancel.clone(),
+        ));
+
+        let discovery = DiscoveryLoop::new(
+            Arc::new(XApiSearchAdapter::new(client)),
+            Arc::new(ScoringAdapter::new(scoring_engine)),
+            Arc::new(LlmReplyAdapter::new(content_gen, self.pool.clone())),
+            Arc::new(
+                SafetyAdapter::new(safety_guard, self.pool.clone())
+                    .with_write_queue(writes.clone()),
+            ),
+            Arc::new(StorageAdapter::new(self.pool.clone()).with_write_queue(writes)),
+            Arc::new(PostSenderAdapter::new(post_tx)),
+            keywords,
+            THRESHOLD as f32,
+            false,
+        );
+        let (_, summary) = discovery.run_once(None).await.expect("discovery run");
+
+        // Dropping the loop closes the channel; the queue drains and exits.
+        drop(discovery);
+        queue.await.expect("posting queue task");
+        drop(cancel);
+        summary
+    }
+}
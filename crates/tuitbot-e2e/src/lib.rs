@@ -0,0 +1,29 @@
+//! End-to-end test harness for Tuitbot.
+//!
+//! Runs the real automation pipeline against a [`MockXApi`] (a `wiremock`
+//! server implementing the X API v2 endpoints `XApiHttpClient` calls), a
+//! [`StubLlm`] with canned output, and an in-memory database. Everything
+//! between those edges — scoring, safety checks, the posting queue, the
+//! approval queue and storage — is the production code.
+//!
+//! ```no_run
+//! # async fn run() {
+//! use tuitbot_e2e::{fixtures, Harness};
+//!
+//! let harness = Harness::start().await;
+//! harness.x_api.mount_search(&fixtures::discovery_tweets()).await;
+//! let summary = harness.run_discovery_once().await;
+//! assert_eq!(summary.replied, 1);
+//! # }
+//! ```
+//!
+//! Run the suite with `cargo test -p tuitbot-e2e`.
+
+pub mod fixtures;
+mod harness;
+mod llm;
+mod mock_x;
+
+pub use harness::Harness;
+pub use llm::StubLlm;
+pub use mock_x::{MockXApi, OWN_USER_ID, POSTED_TWEET_ID};
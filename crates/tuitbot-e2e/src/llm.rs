@@ -0,0 +1,60 @@
+//! Stub LLM provider with canned output.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use tuitbot_core::error::LlmError;
+use tuitbot_core::llm::{GenerationParams, LlmProvider, LlmResponse, TokenUsage};
+
+/// LLM provider that always answers with the same text.
+///
+/// Clones share a call counter, so a test can keep one handle while the
+/// content generator owns another.
+#[derive(Debug, Clone)]
+pub struct StubLlm {
+    text: String,
+    calls: Arc<AtomicUsize>,
+}
+
+impl StubLlm {
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            calls: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+
+    /// Number of completions served so far.
+    pub fn calls(&self) -> usize {
+        self.calls.load(Ordering::SeqCst)
+    }
+}
+
+#[async_trait::async_trait]
+impl LlmProvider for StubLlm {
+    fn name(&self) -> &str {
+        "stub"
+    }
+
+    async fn complete(
+        &self,
+        _system: &str,
+        _user_message: &str,
+        _params: &GenerationParams,
+    ) -> Result<LlmResponse, LlmError> {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        Ok(LlmResponse {
+            text: self.text.clone(),
+            usage: TokenUsage {
+                input_tokens: 100,
+                output_tokens: 20,
+                ..Default::default()
+            },
+            model: "stub-model".to_string(),
+        })
+    }
+
+    async fn health_check(&self) -> Result<(), LlmError> {
+        Ok(())
+    }
+}
@@ -0,0 +1,102 @@
+//! Fake X API v2 server backed by `wiremock`.
+
+use serde_json::{json, Value};
+use wiremock::matchers::{method, path, path_regex};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use crate::fixtures::{self, FixtureTweet};
+
+/// User ID of the authenticated account.
+pub const OWN_USER_ID: &str = "1000000000000000001";
+
+/// ID the mock assigns to every tweet posted through `POST /tweets`.
+pub const POSTED_TWEET_ID: &str = "1950000000000000001";
+
+/// A running fake X API.
+///
+/// [`MockXApi::start`] mounts the endpoints every run needs (the
+/// authenticated user, empty mentions, and `POST /tweets`); tests add
+/// search results with [`MockXApi::mount_search`].
+pub struct MockXApi {
+    server: MockServer,
+}
+
+impl MockXApi {
+    pub async fn start() -> Self {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/users/me"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "data": {
+                    "id": OWN_USER_ID,
+                    "username": "tuitbot_e2e",
+                    "name": "Tuitbot E2E",
+                    "public_metrics": {
+                        "followers_count": 250,
+                        "following_count": 180,
+                        "tweet_count": 900,
+                    },
+                }
+            })))
+            .mount(&server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path_regex(r"^/users/\d+/mentions$"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "meta": { "result_count": 0 }
+            })))
+            .mount(&server)
+            .await;
+
+        Mock::given(method("POST"))
+            .and(path("/tweets"))
+            .respond_with(ResponseTemplate::new(201).set_body_json(json!({
+                "data": { "id": POSTED_TWEET_ID, "text": "" }
+            })))
+            .mount(&server)
+            .await;
+
+        Self { server }
+    }
+
+    /// Base URL to pass to `XApiHttpClient::with_base_url`.
+    pub fn uri(&self) -> String {
+        self.server.uri()
+    }
+
+    /// Answer every `GET /tweets/search/recent` with `tweets`.
+    pub async fn mount_search(&self, tweets: &[FixtureTweet]) {
+        Mock::given(method("GET"))
+            .and(path("/tweets/search/recent"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(fixtures::search_response(tweets)),
+            )
+            .mount(&self.server)
+            .await;
+    }
+
+    /// JSON bodies of every `POST /tweets` received, in order.
+    pub async fn posted_tweets(&self) -> Vec<Value> {
+        self.server
+            .received_requests()
+            .await
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|r| r.method.as_str() == "POST" && r.url.path() == "/tweets")
+            .filter_map(|r| serde_json::from_slice(&r.body).ok())
+            .collect()
+    }
+
+    /// Number of `GET /tweets/search/recent` calls received.
+    pub async fn search_count(&self) -> usize {
+        self.server
+            .received_requests()
+            .await
+            .unwrap_or_default()
+            .iter()
+            .filter(|r| r.url.path() == "/tweets/search/recent")
+            .count()
+    }
+}
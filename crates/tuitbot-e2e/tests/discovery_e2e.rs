@@ -0,0 +1,97 @@
+//! Discovery pipeline end to end: mock X API search → scoring → stub LLM →
+//! posting queue → mock X API post, asserting what lands in the database.
+
+use tuitbot_core::storage;
+use tuitbot_e2e::fixtures::{self, REPLY_TEXT, STRONG_TWEET_ID, WEAK_TWEET_ID};
+use tuitbot_e2e::Harness;
+
+#[tokio::test]
+async fn discovery_replies_to_qualifying_tweet() {
+    let harness = Harness::start().await;
+    harness
+        .x_api
+        .mount_search(&fixtures::discovery_tweets())
+        .await;
+
+    let summary = harness.run_discovery_once().await;
+    assert_eq!(summary.tweets_found, 2);
+    assert_eq!(summary.replied, 1);
+    assert_eq!(summary.skipped, 1);
+    assert_eq!(summary.failed, 0);
+    assert_eq!(harness.llm.calls(), 1);
+
+    // Both tweets are stored with their score; only one clears the threshold.
+    let strong = storage::tweets::get_tweet_by_id(&harness.pool, STRONG_TWEET_ID)
+        .await
+        .unwrap()
+        .expect("strong tweet stored");
+    let weak = storage::tweets::get_tweet_by_id(&harness.pool, WEAK_TWEET_ID)
+        .await
+        .unwrap()
+        .expect("weak tweet stored");
+    assert!(strong.relevance_score.unwrap_or_default() >= harness.config.scoring.threshold as f64);
+    assert!(weak.relevance_score.unwrap_or_default() < harness.config.scoring.threshold as f64);
+
+    // The reply went out through POST /tweets, threaded under the strong tweet.
+    let posted = harness.x_api.posted_tweets().await;
+    assert_eq!(posted.len(), 1);
+    assert_eq!(posted[0]["text"], REPLY_TEXT);
+    assert_eq!(posted[0]["reply"]["in_reply_to_tweet_id"], STRONG_TWEET_ID);
+
+    assert!(
+        storage::replies::has_replied_to(&harness.pool, STRONG_TWEET_ID)
+            .await
+            .unwrap()
+    );
+    assert!(
+        !storage::replies::has_replied_to(&harness.pool, WEAK_TWEET_ID)
+            .await
+            .unwrap()
+    );
+    assert_eq!(
+        storage::replies::count_replies_today(&harness.pool)
+            .await
+            .unwrap(),
+        1
+    );
+}
+
+#[tokio::test]
+async fn approval_mode_queues_reply_without_posting() {
+    let harness = Harness::start().await.with_approval_mode(true);
+    harness
+        .x_api
+        .mount_search(&fixtures::discovery_tweets())
+        .await;
+
+    let summary = harness.run_discovery_once().await;
+    assert_eq!(summary.replied, 1);
+
+    assert!(harness.x_api.posted_tweets().await.is_empty());
+
+    let pending = storage::approval_queue::get_pending(&harness.pool)
+        .await
+        .unwrap();
+    assert_eq!(pending.len(), 1);
+    assert_eq!(pending[0].action_type, "reply");
+    assert_eq!(pending[0].target_tweet_id, STRONG_TWEET_ID);
+    assert_eq!(pending[0].generated_content, REPLY_TEXT);
+}
+
+#[tokio::test]
+async fn second_iteration_skips_already_discovered_tweets() {
+    let harness = Harness::start().await;
+    harness
+        .x_api
+        .mount_search(&fixtures::discovery_tweets())
+        .await;
+
+    harness.run_discovery_once().await;
+    let summary = harness.run_discovery_once().await;
+
+    assert_eq!(harness.x_api.search_count().await, 2);
+    assert_eq!(summary.replied, 0);
+    assert_eq!(summary.skipped, 2);
+    assert_eq!(harness.x_api.posted_tweets().await.len(), 1);
+    assert_eq!(harness.llm.calls(), 1);
+}
@@ -54,6 +54,15 @@ pub struct WorkflowContext {
     pub mode: String,
     /// Effective approval mode flag.
     pub approval_mode: bool,
+    /// Agent that made the call (e.g. the MCP client name).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub agent: Option<String>,
+    /// Agent session the call belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub session_id: Option<String>,
+    /// Goal the agent declared for the session.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub goal: Option<String>,
 }
 
 /// Normalized pagination metadata extracted from API responses.
@@ -254,9 +263,29 @@ impl ToolMeta {
         self.workflow = Some(WorkflowContext {
             mode: mode.into(),
             approval_mode,
+            agent: None,
+            session_id: None,
+            goal: None,
         });
         self
     }
+
+    /// Attach the originating agent / session / goal to the workflow context
+    /// (builder pattern). No-op unless [`with_workflow`](Self::with_workflow)
+    /// was called first.
+    pub fn with_origin(
+        mut self,
+        agent: Option<String>,
+        session_id: Option<String>,
+        goal: Option<String>,
+    ) -> Self {
+        if let Some(wf) = self.workflow.as_mut() {
+            wf.agent = agent;
+            wf.session_id = session_id;
+            wf.goal = goal;
+        }
+        self
+    }
 }
 
 #[cfg(test)]
@@ -323,6 +352,22 @@ mod tests {
         assert!(parsed.get("meta").is_none());
     }
 
+    #[test]
+    fn meta_workflow_origin_flattened_when_set() {
+        let meta = ToolMeta::new(1)
+            .with_workflow("autopilot", false)
+            .with_origin(Some("agent-a".into()), Some("s1".into()), None);
+        let json = serde_json::to_string(&meta).unwrap();
+        let parsed: Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed["agent"], "agent-a");
+        assert_eq!(parsed["session_id"], "s1");
+        assert!(parsed.get("goal").is_none());
+
+        let plain =
+            serde_json::to_value(ToolMeta::new(1).with_workflow("autopilot", false)).unwrap();
+        assert!(plain.get("agent").is_none());
+    }
+
     #[test]
     fn meta_without_workflow_omits_mode() {
         let meta = ToolMeta::new(10);
@@ -75,6 +75,8 @@ pub enum ErrorCode {
     PolicyDeniedHardRule,
     #[serde(rename = "policy_denied_user_rule")]
     PolicyDeniedUserRule,
+    #[serde(rename = "policy_denied_kill_switch")]
+    PolicyDeniedKillSwitch,
 
     // ── Context ─────────────────────────────────────────────────────
     #[serde(rename = "context_error")]
@@ -87,6 +89,8 @@ pub enum ErrorCode {
     // ── Resource ────────────────────────────────────────────────────
     #[serde(rename = "not_found")]
     NotFound,
+    #[serde(rename = "conflict")]
+    Conflict,
 
     // ── Universal Request ──────────────────────────────────────────
     #[serde(rename = "x_request_blocked")]
@@ -123,10 +127,12 @@ impl ErrorCode {
         Self::PolicyDeniedRateLimited,
         Self::PolicyDeniedHardRule,
         Self::PolicyDeniedUserRule,
+        Self::PolicyDeniedKillSwitch,
         Self::ContextError,
         Self::RecommendationError,
         Self::TopicError,
         Self::NotFound,
+        Self::Conflict,
         Self::XRequestBlocked,
         Self::SerializationError,
     ];
@@ -178,10 +184,12 @@ impl ErrorCode {
             Self::PolicyDeniedRateLimited => "policy_denied_rate_limited",
             Self::PolicyDeniedHardRule => "policy_denied_hard_rule",
             Self::PolicyDeniedUserRule => "policy_denied_user_rule",
+            Self::PolicyDeniedKillSwitch => "policy_denied_kill_switch",
             Self::ContextError => "context_error",
             Self::RecommendationError => "recommendation_error",
             Self::TopicError => "topic_error",
             Self::NotFound => "not_found",
+            Self::Conflict => "conflict",
             Self::XRequestBlocked => "x_request_blocked",
             Self::SerializationError => "serialization_error",
         }
@@ -200,7 +208,7 @@ mod tests {
 
     #[test]
     fn all_constant_has_correct_count() {
-        assert_eq!(ErrorCode::ALL.len(), 29);
+        assert_eq!(ErrorCode::ALL.len(), 31);
     }
 
     #[test]
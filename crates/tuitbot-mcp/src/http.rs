@@ -0,0 +1,41 @@
+//! Streamable HTTP transport for hosting the MCP server in another process.
+//!
+//! `tuitbot-server` mounts [`McpHttpService`] next to its REST routes so a
+//! hosted deployment serves dashboard and agent tooling from one process,
+//! one database pool, and one MCP policy.
+
+use std::sync::Arc;
+
+use rmcp::transport::streamable_http_server::session::local::LocalSessionManager;
+use rmcp::transport::streamable_http_server::{StreamableHttpServerConfig, StreamableHttpService};
+use tokio_util::sync::CancellationToken;
+use tuitbot_core::config::Config;
+use tuitbot_core::storage::DbPool;
+
+use crate::server::WriteMcpServer;
+
+/// Tower service speaking MCP over streamable HTTP (write profile).
+pub type McpHttpService = StreamableHttpService<WriteMcpServer, LocalSessionManager>;
+
+/// Build the write-profile MCP server as an HTTP service sharing `pool`.
+///
+/// Every session gets its own server handle over the same state, so policy
+/// gating, rate limits, and the invocation log are shared across clients.
+/// Cancelling `cancel` closes all open sessions.
+pub async fn write_http_service(
+    config: Config,
+    pool: DbPool,
+    cancel: CancellationToken,
+) -> anyhow::Result<McpHttpService> {
+    let state = crate::init_write_state_with_pool(config, pool).await?;
+    tracing::info!("MCP server ready on streamable HTTP (write profile)");
+
+    Ok(StreamableHttpService::new(
+        move || Ok(WriteMcpServer::new(state.clone())),
+        Arc::new(LocalSessionManager::default()),
+        StreamableHttpServerConfig {
+            cancellation_token: cancel,
+            ..Default::default()
+        },
+    ))
+}
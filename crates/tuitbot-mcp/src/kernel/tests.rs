@@ -25,6 +25,8 @@ impl SocialReadProvider for MockProvider {
             created_at: "2026-02-25T00:00:00Z".to_string(),
             public_metrics: PublicMetrics::default(),
             conversation_id: None,
+            entities: None,
+            attachments: None,
         })
     }
 
@@ -52,6 +54,8 @@ impl SocialReadProvider for MockProvider {
                 created_at: String::new(),
                 public_metrics: PublicMetrics::default(),
                 conversation_id: None,
+                entities: None,
+                attachments: None,
             }],
             includes: None,
             meta: SearchMeta {
@@ -77,6 +81,8 @@ impl SocialReadProvider for MockProvider {
                 created_at: String::new(),
                 public_metrics: PublicMetrics::default(),
                 conversation_id: None,
+                entities: None,
+                attachments: None,
             }],
             includes: None,
             meta: SearchMeta {
@@ -102,6 +108,8 @@ impl SocialReadProvider for MockProvider {
                 created_at: String::new(),
                 public_metrics: PublicMetrics::default(),
                 conversation_id: None,
+                entities: None,
+                attachments: None,
             }],
             includes: None,
             meta: SearchMeta {
@@ -127,6 +135,8 @@ impl SocialReadProvider for MockProvider {
                 created_at: String::new(),
                 public_metrics: PublicMetrics::default(),
                 conversation_id: None,
+                entities: None,
+                attachments: None,
             }],
             includes: None,
             meta: SearchMeta {
@@ -210,6 +220,8 @@ impl SocialReadProvider for MockProvider {
                 created_at: String::new(),
                 public_metrics: PublicMetrics::default(),
                 conversation_id: None,
+                entities: None,
+                attachments: None,
             }],
             includes: None,
             meta: SearchMeta {
@@ -235,6 +247,8 @@ impl SocialReadProvider for MockProvider {
                 created_at: String::new(),
                 public_metrics: PublicMetrics::default(),
                 conversation_id: None,
+                entities: None,
+                attachments: None,
             }],
             includes: None,
             meta: SearchMeta {
@@ -13,8 +13,11 @@
 //! - **`utility-write`**: flat toolkit surface — reads + writes + engages. No workflow, no policy gate.
 
 pub mod contract;
+#[cfg(feature = "http")]
+mod http;
 mod kernel;
 mod provider;
+mod replay;
 mod requests;
 mod server;
 pub mod spec;
@@ -26,12 +29,18 @@ use std::sync::Arc;
 use rmcp::transport::stdio;
 use rmcp::ServiceExt;
 
+use tokio_util::sync::CancellationToken;
+
+use tuitbot_core::automation::run_token_refresh_loop;
 use tuitbot_core::config::Config;
 use tuitbot_core::llm;
-use tuitbot_core::startup;
+use tuitbot_core::startup::StartupError;
 use tuitbot_core::storage;
+use tuitbot_core::storage::write_queue::WriteQueue;
+use tuitbot_core::x_api::auth::TokenManager;
 use tuitbot_core::x_api::{XApiClient, XApiHttpClient};
 
+use provider::scraper::ScraperReadProvider;
 use server::{
     AdminMcpServer, ApiReadonlyMcpServer, ReadonlyMcpServer, UtilityReadonlyMcpServer,
     UtilityWriteMcpServer, WriteMcpServer,
@@ -39,6 +48,8 @@ use server::{
 use state::{AppState, ReadonlyState, SharedReadonlyState};
 use tools::idempotency::IdempotencyStore;
 
+#[cfg(feature = "http")]
+pub use http::{write_http_service, McpHttpService};
 pub use state::Profile;
 pub use tools::manifest::{generate_profile_manifest, ProfileManifest};
 
@@ -56,16 +67,44 @@ pub async fn run_server(config: Config, profile: Profile) -> anyhow::Result<()>
     }
 }
 
+/// Re-execute a recorded read-only tool invocation and return its response.
+///
+/// Initializes the same state as the write / admin profiles, then replays
+/// invocation `id` from the `mcp_invocations` log.
+pub async fn replay_invocation(config: Config, id: i64) -> anyhow::Result<String> {
+    let state = init_write_state(config).await?;
+    let pool = state.pool.clone();
+    let result = match replay::load_replayable(&state, id).await {
+        Ok(invocation) => replay::replay(state, &invocation).await,
+        Err(e) => Err(e),
+    };
+    pool.close().await;
+    result
+}
+
 // ── Shared init for write/admin profiles ────────────────────────────────
 
 /// Initialize shared state for write / admin profiles: DB, LLM, X client.
 async fn init_write_state(config: Config) -> anyhow::Result<Arc<AppState>> {
     // Initialize database
+    storage::query_log::configure(&config.storage);
     let pool = storage::init_db(&config.storage.db_path).await?;
+    init_write_state_with_pool(config, pool).await
+}
 
+/// Initialize write / admin state on top of an existing database pool.
+async fn init_write_state_with_pool(
+    config: Config,
+    pool: storage::DbPool,
+) -> anyhow::Result<Arc<AppState>> {
     // Initialize MCP mutation rate limit
     storage::rate_limits::init_mcp_rate_limit(&pool, config.mcp_policy.max_mutations_per_hour)
         .await?;
+    storage::rate_limits::init_policy_rate_limits(
+        &pool,
+        &config.mcp_policy.effective_rate_limits(),
+    )
+    .await?;
 
     // Try to create LLM provider (optional — content tools won't work without it)
     let llm_provider = match llm::factory::create_provider(&config.llm) {
@@ -86,10 +125,12 @@ async fn init_write_state(config: Config) -> anyhow::Result<Arc<AppState>> {
         Option<Box<dyn XApiClient>>,
         Option<String>,
         Vec<String>,
-    ) = match startup::load_tokens_from_file() {
-        Ok(tokens) if !tokens.is_expired() => {
-            let scopes = tokens.scopes.clone();
-            let client = XApiHttpClient::new(tokens.access_token);
+    ) = match start_token_manager(&config).await {
+        Ok(tokens) => {
+            let scopes = tokens.tokens_lock().read().await.scopes.clone();
+            let client = XApiHttpClient::from_config(tokens.access_token().await, &config.x_api)
+                .with_protected_entities(&config.blocklist.protected);
+            tokens.attach(&client).await;
             client.set_pool(pool.clone()).await;
             match client.get_me().await {
                 Ok(user) => {
@@ -110,10 +151,10 @@ async fn init_write_state(config: Config) -> anyhow::Result<Arc<AppState>> {
                 }
             }
         }
-        Ok(_) => {
+        Err(e @ StartupError::TokenRefreshFailed(_)) => {
             tracing::warn!(
-                "X API tokens expired. Direct X tools will be disabled. \
-                 Run `tuitbot auth` to re-authenticate."
+                "X API tokens expired and could not be refreshed ({e}). \
+                 Direct X tools will be disabled."
             );
             (None, None, vec![])
         }
@@ -123,22 +164,10 @@ async fn init_write_state(config: Config) -> anyhow::Result<Arc<AppState>> {
         }
     };
 
-    // Log provider backend selection.
-    let backend = provider::parse_backend(&config.x_api.provider_backend);
-    match backend {
-        provider::ProviderBackend::XApi => {
-            tracing::info!(backend = "x_api", "Provider backend: official X API");
-        }
-        provider::ProviderBackend::Scraper => {
-            tracing::warn!(
-                backend = "scraper",
-                allow_mutations = config.x_api.scraper_allow_mutations,
-                "Provider backend: scraper (elevated risk)"
-            );
-        }
-    }
+    let scraper = select_scraper(&config);
 
     Ok(Arc::new(AppState {
+        write_queue: WriteQueue::spawn(pool.clone()),
         pool,
         config,
         llm_provider,
@@ -146,6 +175,7 @@ async fn init_write_state(config: Config) -> anyhow::Result<Arc<AppState>> {
         authenticated_user_id,
         granted_scopes,
         idempotency: Arc::new(IdempotencyStore::new()),
+        scraper,
     }))
 }
 
@@ -191,6 +221,40 @@ async fn run_admin_server(config: Config) -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Load X API tokens through the shared token manager and keep them
+/// refreshed for the life of the process.
+async fn start_token_manager(config: &Config) -> Result<Arc<TokenManager>, StartupError> {
+    let tokens = Arc::new(TokenManager::load(&config.x_api.client_id).await?);
+    tokio::spawn(run_token_refresh_loop(
+        tokens.clone(),
+        CancellationToken::new(),
+    ));
+    Ok(tokens)
+}
+
+/// Log the provider backend selection and build the scraper when selected.
+fn select_scraper(config: &Config) -> Option<ScraperReadProvider> {
+    match provider::parse_backend(&config.x_api.provider_backend) {
+        provider::ProviderBackend::XApi => {
+            tracing::info!(backend = "x_api", "Provider backend: official X API");
+            None
+        }
+        provider::ProviderBackend::Scraper => {
+            tracing::warn!(
+                backend = "scraper",
+                base_url = config
+                    .x_api
+                    .scraper_base_url
+                    .as_deref()
+                    .unwrap_or("(unset)"),
+                allow_mutations = config.x_api.scraper_allow_mutations,
+                "Provider backend: scraper (elevated risk)"
+            );
+            Some(ScraperReadProvider::from_config(&config.x_api))
+        }
+    }
+}
+
 // ── Shared init for read-only profiles ──────────────────────────────────
 
 /// Initialize shared readonly state: load tokens, create X client, verify get_me.
@@ -199,21 +263,16 @@ async fn init_readonly_state(
     profile: Profile,
 ) -> anyhow::Result<SharedReadonlyState> {
     // Load X API tokens (required for readonly profiles)
-    let tokens = startup::load_tokens_from_file().map_err(|e| {
+    let tokens = start_token_manager(&config).await.map_err(|e| {
         anyhow::anyhow!(
-            "{profile} profile requires X API tokens but they are not available: {e}. \
+            "{profile} profile requires valid X API tokens but they are not available: {e}. \
              Run `tuitbot auth` to authenticate."
         )
     })?;
 
-    if tokens.is_expired() {
-        anyhow::bail!(
-            "{profile} profile requires valid X API tokens but they are expired. \
-             Run `tuitbot auth` to re-authenticate."
-        );
-    }
-
-    let client = XApiHttpClient::new(tokens.access_token);
+    let client = XApiHttpClient::from_config(tokens.access_token().await, &config.x_api)
+        .with_protected_entities(&config.blocklist.protected);
+    tokens.attach(&client).await;
 
     // Verify connectivity and get authenticated user ID
     let user = client.get_me().await.map_err(|e| {
@@ -230,25 +289,13 @@ async fn init_readonly_state(
         "X API client initialized ({profile} profile)"
     );
 
-    // Log provider backend selection.
-    let backend = provider::parse_backend(&config.x_api.provider_backend);
-    match backend {
-        provider::ProviderBackend::XApi => {
-            tracing::info!(backend = "x_api", "Provider backend: official X API");
-        }
-        provider::ProviderBackend::Scraper => {
-            tracing::warn!(
-                backend = "scraper",
-                allow_mutations = config.x_api.scraper_allow_mutations,
-                "Provider backend: scraper (elevated risk)"
-            );
-        }
-    }
+    let scraper = select_scraper(&config);
 
     Ok(Arc::new(ReadonlyState {
         config,
         x_client: Box::new(client),
         authenticated_user_id: user.id,
+        scraper,
     }))
 }
 
@@ -14,6 +14,10 @@ use serde::{Deserialize, Serialize};
 
 use crate::contract::ProviderError;
 use tuitbot_core::x_api::types::{MentionResponse, SearchResponse, Tweet, User, UsersResponse};
+use tuitbot_core::x_api::XApiClient;
+
+use retry::{RetryPolicy, RetryingProvider};
+use scraper::ScraperReadProvider;
 
 /// Backend used for social platform operations.
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
@@ -47,7 +51,6 @@ pub fn parse_backend(s: &str) -> ProviderBackend {
 
 /// Post-process a tool response JSON string to inject `provider_backend`
 /// into the `meta` object (if present) or add a minimal `meta` object.
-#[allow(dead_code)]
 pub fn inject_provider_backend(json: &str, backend: &str) -> String {
     match serde_json::from_str::<serde_json::Value>(json) {
         Ok(mut v) => {
@@ -65,6 +68,40 @@ pub fn inject_provider_backend(json: &str, backend: &str) -> String {
     }
 }
 
+/// Provider for public-data reads: the scraper when one is configured,
+/// otherwise the official X API with retries.
+pub fn public_reader<'a>(
+    scraper: Option<&ScraperReadProvider>,
+    client: &'a dyn XApiClient,
+) -> Box<dyn SocialReadProvider + 'a> {
+    match scraper {
+        Some(scraper) => Box::new(scraper.clone()),
+        None => Box::new(RetryingProvider::new(
+            x_api::XApiProvider::new(client),
+            RetryPolicy::default(),
+        )),
+    }
+}
+
+/// Tag a public-read tool response with the backend that served it.
+///
+/// Scraper responses also get `meta.risk_level = "elevated"` and the
+/// scraper's `meta.risk_warnings`; official API responses pass through.
+pub fn annotate_public_read(scraper: Option<&ScraperReadProvider>, json: String) -> String {
+    if scraper.is_none() {
+        return json;
+    }
+    let tagged = inject_provider_backend(&json, "scraper");
+    match serde_json::from_str::<serde_json::Value>(&tagged) {
+        Ok(mut v) => {
+            v["meta"]["risk_level"] = serde_json::json!("elevated");
+            v["meta"]["risk_warnings"] = serde_json::json!(scraper::RISK_WARNINGS);
+            serde_json::to_string_pretty(&v).unwrap_or(tagged)
+        }
+        Err(_) => tagged,
+    }
+}
+
 /// Read-only social platform operations.
 ///
 /// Kernel tools program against this trait, allowing the backend to be
@@ -257,6 +294,22 @@ mod tests {
         assert_eq!(parsed["meta"]["provider_backend"], "scraper");
     }
 
+    #[test]
+    fn annotate_public_read_adds_risk_warnings_for_scraper() {
+        let input = r#"{"success":true,"data":{},"meta":{"tool_version":"1.0"}}"#;
+        assert_eq!(annotate_public_read(None, input.to_string()), input);
+
+        let scraper = ScraperReadProvider::default();
+        let result = annotate_public_read(Some(&scraper), input.to_string());
+        let v: serde_json::Value = serde_json::from_str(&result).unwrap();
+        assert_eq!(v["meta"]["provider_backend"], "scraper");
+        assert_eq!(v["meta"]["risk_level"], "elevated");
+        assert_eq!(
+            v["meta"]["risk_warnings"].as_array().unwrap().len(),
+            scraper::RISK_WARNINGS.len()
+        );
+    }
+
     #[test]
     fn inject_provider_backend_invalid_json() {
         let input = "not json";
@@ -342,6 +342,8 @@ mod tests {
                     created_at: String::new(),
                     public_metrics: PublicMetrics::default(),
                     conversation_id: None,
+                    entities: None,
+                    attachments: None,
                 })
             }
         }
@@ -473,6 +475,8 @@ mod tests {
                         created_at: String::new(),
                         public_metrics: PublicMetrics::default(),
                         conversation_id: None,
+                        entities: None,
+                        attachments: None,
                     })
                 }
             }
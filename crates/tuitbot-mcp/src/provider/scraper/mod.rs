@@ -0,0 +1,400 @@
+//! Scraper-backed [`SocialReadProvider`] over a Nitter-style front-end.
+//!
+//! Public reads go to the instance at `x_api.scraper_base_url` instead of the
+//! official API: tweet pages for `get_tweet`, RSS feeds for search, user
+//! timelines, and profile lookups. Requests are spaced at least
+//! `x_api.scraper_min_interval_ms` apart so the instance is not hammered.
+//!
+//! Nitter exposes no numeric user IDs or public metrics, so user `id` and
+//! tweet `author_id` carry the handle and metrics are zero. Auth-gated
+//! methods (mentions, timeline, me, bookmarks) return
+//! [`ProviderError::NotConfigured`]; graph and engagement reads that Nitter
+//! does not serve return [`ProviderError::Other`].
+
+mod nitter;
+
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use tokio::sync::Mutex;
+use tuitbot_core::config::XApiConfig;
+use tuitbot_core::x_api::types::{
+    Includes, MentionResponse, SearchMeta, SearchResponse, Tweet, User, UserMetrics, UsersResponse,
+};
+
+use crate::contract::ProviderError;
+use crate::provider::SocialReadProvider;
+
+/// Minimum spacing between scraper requests when none is configured.
+pub const DEFAULT_MIN_INTERVAL_MS: u64 = 2_000;
+
+/// Risk warnings attached to the `meta` of every scraper-backed tool response.
+pub const RISK_WARNINGS: &[&str] = &[
+    "Data was scraped from a third-party front-end, not the official X API.",
+    "Scraping may violate the X Terms of Service; use it at your own risk.",
+    "Metrics are unavailable and user IDs are handles, not numeric X IDs.",
+];
+
+/// Scraper-based read provider.
+///
+/// Cheap to clone; clones share the HTTP client and request throttle.
+#[derive(Clone)]
+pub struct ScraperReadProvider {
+    inner: Arc<Inner>,
+}
+
+struct Inner {
+    client: reqwest::Client,
+    base_url: Option<String>,
+    min_interval: Duration,
+    last_request: Mutex<Option<Instant>>,
+}
+
+impl ScraperReadProvider {
+    /// Create a provider for the instance at `base_url`.
+    ///
+    /// With no base URL every public read returns
+    /// [`ProviderError::NotConfigured`].
+    pub fn new(base_url: Option<String>, min_interval: Duration) -> Self {
+        Self {
+            inner: Arc::new(Inner {
+                client: reqwest::Client::builder()
+                    .user_agent(concat!("tuitbot/", env!("CARGO_PKG_VERSION")))
+                    .timeout(Duration::from_secs(20))
+                    .build()
+                    .unwrap_or_default(),
+                base_url: base_url.map(|u| u.trim_end_matches('/').to_string()),
+                min_interval,
+                last_request: Mutex::new(None),
+            }),
+        }
+    }
+
+    /// Create a provider from the `[x_api]` scraper settings.
+    pub fn from_config(config: &XApiConfig) -> Self {
+        Self::new(
+            config.scraper_base_url.clone(),
+            Duration::from_millis(
+                config
+                    .scraper_min_interval_ms
+                    .unwrap_or(DEFAULT_MIN_INTERVAL_MS),
+            ),
+        )
+    }
+
+    /// Fetch `path` from the instance, waiting out the throttle first.
+    ///
+    /// Returns the body, the `Min-Id` pagination cursor Nitter sends with
+    /// feeds, and the final URL after redirects.
+    async fn fetch(
+        &self,
+        path: &str,
+        query: &[(&str, &str)],
+    ) -> Result<(String, Option<String>, reqwest::Url), ProviderError> {
+        let base = self
+            .inner
+            .base_url
+            .as_deref()
+            .ok_or_else(|| ProviderError::NotConfigured {
+                message: NO_BASE_URL_MSG.to_string(),
+            })?;
+        self.throttle().await;
+
+        let resp = self
+            .inner
+            .client
+            .get(format!("{base}{path}"))
+            .query(query)
+            .send()
+            .await
+            .map_err(|e| ProviderError::Network {
+                message: e.to_string(),
+            })?;
+        let status = resp.status().as_u16();
+        match status {
+            200..=299 => {}
+            404 => {
+                return Err(ProviderError::Other {
+                    message: format!("scraper: {path} not found"),
+                })
+            }
+            429 => {
+                let retry_after = resp
+                    .headers()
+                    .get("retry-after")
+                    .and_then(|v| v.to_str().ok())
+                    .and_then(|v| v.parse().ok());
+                return Err(ProviderError::RateLimited { retry_after });
+            }
+            500..=599 => {
+                return Err(ProviderError::ServerError {
+                    status,
+                    message: format!("scraper instance returned {status}"),
+                })
+            }
+            _ => {
+                return Err(ProviderError::Other {
+                    message: format!("scraper instance returned {status} for {path}"),
+                })
+            }
+        }
+        let cursor = resp
+            .headers()
+            .get("min-id")
+            .and_then(|v| v.to_str().ok())
+            .filter(|v| !v.is_empty())
+            .map(str::to_string);
+        let url = resp.url().clone();
+        let body = resp.text().await.map_err(|e| ProviderError::Network {
+            message: e.to_string(),
+        })?;
+        Ok((body, cursor, url))
+    }
+
+    /// Sleep until `min_interval` has passed since the previous request.
+    ///
+    /// The lock is held while sleeping so concurrent calls queue up.
+    async fn throttle(&self) {
+        let mut last = self.inner.last_request.lock().await;
+        if let Some(prev) = *last {
+            let wait = self.inner.min_interval.saturating_sub(prev.elapsed());
+            if !wait.is_zero() {
+                tokio::time::sleep(wait).await;
+            }
+        }
+        *last = Some(Instant::now());
+    }
+
+    /// Resolve a user ID to a handle.
+    ///
+    /// IDs this provider returns are already handles; numeric X IDs are
+    /// resolved through the instance's `/i/user/{id}` redirect.
+    async fn resolve_handle(&self, user_id: &str) -> Result<String, ProviderError> {
+        if !user_id.chars().all(|c| c.is_ascii_digit()) {
+            return Ok(user_id.trim_start_matches('@').to_string());
+        }
+        let (_, _, url) = self.fetch(&format!("/i/user/{user_id}"), &[]).await?;
+        url.path_segments()
+            .and_then(|mut s| s.next())
+            .filter(|h| !h.is_empty() && *h != "i")
+            .map(str::to_string)
+            .ok_or_else(|| ProviderError::Other {
+                message: format!("scraper: could not resolve user {user_id}"),
+            })
+    }
+
+    /// Fetch an RSS feed and map it to a search response.
+    async fn feed(
+        &self,
+        path: &str,
+        query: &[(&str, &str)],
+        max_results: u32,
+        since_id: Option<&str>,
+    ) -> Result<SearchResponse, ProviderError> {
+        let (body, cursor, _) = self.fetch(path, query).await?;
+        let feed = nitter::parse_feed(&body);
+        let data: Vec<Tweet> = feed
+            .items
+            .iter()
+            .filter_map(nitter::FeedItem::to_tweet)
+            .filter(|t| since_id.map_or(true, |s| nitter::is_newer(&t.id, s)))
+            .take(max_results as usize)
+            .collect();
+
+        let mut users: Vec<User> = Vec::new();
+        for tweet in &data {
+            if !users.iter().any(|u| u.id == tweet.author_id) {
+                users.push(handle_user(&tweet.author_id, &tweet.author_id));
+            }
+        }
+        Ok(SearchResponse {
+            meta: SearchMeta {
+                newest_id: data.first().map(|t| t.id.clone()),
+                oldest_id: data.last().map(|t| t.id.clone()),
+                result_count: data.len() as u32,
+                next_token: cursor,
+            },
+            includes: Some(Includes { users }),
+            data,
+        })
+    }
+}
+
+impl Default for ScraperReadProvider {
+    fn default() -> Self {
+        Self::new(None, Duration::from_millis(DEFAULT_MIN_INTERVAL_MS))
+    }
+}
+
+/// A [`User`] known only by handle and display name.
+fn handle_user(handle: &str, name: &str) -> User {
+    User {
+        id: handle.to_string(),
+        username: handle.to_string(),
+        name: name.to_string(),
+        public_metrics: UserMetrics::default(),
+    }
+}
+
+/// Message for methods that require authentication and cannot work with a scraper.
+const AUTH_GATED_MSG: &str =
+    "This method requires authentication and is not available via the scraper backend. \
+     Switch to provider_backend = \"x_api\" in config.toml to use this feature.";
+
+/// Message for public-data methods the scraper front-end does not serve.
+const UNSUPPORTED_MSG: &str = "Scraper backend: method not supported by the scraper front-end.";
+
+/// Message when no scraper instance is configured.
+const NO_BASE_URL_MSG: &str =
+    "Scraper backend needs x_api.scraper_base_url (a Nitter-style instance URL) in config.toml.";
+
+#[async_trait::async_trait]
+impl SocialReadProvider for ScraperReadProvider {
+    // ── Scraped public reads ────────────────────────────────────────
+
+    async fn get_tweet(&self, tweet_id: &str) -> Result<Tweet, ProviderError> {
+        let (body, _, _) = self.fetch(&format!("/i/status/{tweet_id}"), &[]).await?;
+        nitter::parse_status_page(&body, tweet_id).ok_or_else(|| ProviderError::Other {
+            message: format!("scraper: could not parse tweet {tweet_id}"),
+        })
+    }
+
+    async fn get_user_by_username(&self, username: &str) -> Result<User, ProviderError> {
+        let handle = username.trim_start_matches('@');
+        let (body, _, _) = self.fetch(&format!("/{handle}/rss"), &[]).await?;
+        let feed = nitter::parse_feed(&body);
+        let (name, handle) = nitter::split_channel_title(&feed.title)
+            .unwrap_or_else(|| (handle.to_string(), handle.to_string()));
+        Ok(handle_user(&handle, &name))
+    }
+
+    async fn search_tweets(
+        &self,
+        query: &str,
+        max_results: u32,
+        since_id: Option<&str>,
+        pagination_token: Option<&str>,
+    ) -> Result<SearchResponse, ProviderError> {
+        let mut params = vec![("f", "tweets"), ("q", query)];
+        if let Some(cursor) = pagination_token {
+            params.push(("cursor", cursor));
+        }
+        self.feed("/search/rss", &params, max_results, since_id)
+            .await
+    }
+
+    async fn get_user_tweets(
+        &self,
+        user_id: &str,
+        max_results: u32,
+        pagination_token: Option<&str>,
+    ) -> Result<SearchResponse, ProviderError> {
+        let handle = self.resolve_handle(user_id).await?;
+        let params: Vec<(&str, &str)> = pagination_token
+            .map(|cursor| vec![("cursor", cursor)])
+            .unwrap_or_default();
+        self.feed(&format!("/{handle}/rss"), &params, max_results, None)
+            .await
+    }
+
+    async fn get_user_by_id(&self, user_id: &str) -> Result<User, ProviderError> {
+        let handle = self.resolve_handle(user_id).await?;
+        self.get_user_by_username(&handle).await
+    }
+
+    // ── Not served by the front-end ─────────────────────────────────
+
+    async fn get_followers(
+        &self,
+        _user_id: &str,
+        _max_results: u32,
+        _pagination_token: Option<&str>,
+    ) -> Result<UsersResponse, ProviderError> {
+        Err(ProviderError::Other {
+            message: UNSUPPORTED_MSG.to_string(),
+        })
+    }
+
+    async fn get_following(
+        &self,
+        _user_id: &str,
+        _max_results: u32,
+        _pagination_token: Option<&str>,
+    ) -> Result<UsersResponse, ProviderError> {
+        Err(ProviderError::Other {
+            message: UNSUPPORTED_MSG.to_string(),
+        })
+    }
+
+    async fn get_liked_tweets(
+        &self,
+        _user_id: &str,
+        _max_results: u32,
+        _pagination_token: Option<&str>,
+    ) -> Result<SearchResponse, ProviderError> {
+        Err(ProviderError::Other {
+            message: UNSUPPORTED_MSG.to_string(),
+        })
+    }
+
+    async fn get_users_by_ids(&self, _user_ids: &[&str]) -> Result<UsersResponse, ProviderError> {
+        Err(ProviderError::Other {
+            message: UNSUPPORTED_MSG.to_string(),
+        })
+    }
+
+    async fn get_tweet_liking_users(
+        &self,
+        _tweet_id: &str,
+        _max_results: u32,
+        _pagination_token: Option<&str>,
+    ) -> Result<UsersResponse, ProviderError> {
+        Err(ProviderError::Other {
+            message: UNSUPPORTED_MSG.to_string(),
+        })
+    }
+
+    // ── Auth-gated (rejected) ───────────────────────────────────────
+
+    async fn get_user_mentions(
+        &self,
+        _user_id: &str,
+        _since_id: Option<&str>,
+        _pagination_token: Option<&str>,
+    ) -> Result<MentionResponse, ProviderError> {
+        Err(ProviderError::NotConfigured {
+            message: AUTH_GATED_MSG.to_string(),
+        })
+    }
+
+    async fn get_home_timeline(
+        &self,
+        _user_id: &str,
+        _max_results: u32,
+        _pagination_token: Option<&str>,
+    ) -> Result<SearchResponse, ProviderError> {
+        Err(ProviderError::NotConfigured {
+            message: AUTH_GATED_MSG.to_string(),
+        })
+    }
+
+    async fn get_me(&self) -> Result<User, ProviderError> {
+        Err(ProviderError::NotConfigured {
+            message: AUTH_GATED_MSG.to_string(),
+        })
+    }
+
+    async fn get_bookmarks(
+        &self,
+        _user_id: &str,
+        _max_results: u32,
+        _pagination_token: Option<&str>,
+    ) -> Result<SearchResponse, ProviderError> {
+        Err(ProviderError::NotConfigured {
+            message: AUTH_GATED_MSG.to_string(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,287 @@
+//! Parsers for Nitter RSS feeds and tweet pages.
+//!
+//! Nitter serves user timelines and search results as RSS 2.0 and tweet
+//! pages as HTML with Open Graph tags. The parsers here are deliberately
+//! small string scanners: they read only the fields the provider maps onto
+//! X API types and ignore everything else.
+
+use tuitbot_core::x_api::types::{PublicMetrics, Tweet};
+
+/// A parsed RSS feed.
+#[derive(Debug, Default)]
+pub struct Feed {
+    /// Channel title, `"Display Name / @username"` for profile feeds.
+    pub title: String,
+    /// Feed items in document order (newest first).
+    pub items: Vec<FeedItem>,
+}
+
+/// A single RSS `<item>`.
+#[derive(Debug, Default)]
+pub struct FeedItem {
+    /// Status URL of the tweet.
+    pub link: String,
+    /// Author handle from `<dc:creator>`, without the `@`.
+    pub creator: String,
+    /// Tweet body as HTML from `<description>`.
+    pub description: String,
+    /// Plain-text title, used when the description is empty.
+    pub title: String,
+    /// RFC 2822 publication date.
+    pub pub_date: String,
+}
+
+impl FeedItem {
+    /// Convert the item into a [`Tweet`].
+    ///
+    /// Returns `None` when the link carries no status ID (pinned-profile
+    /// items and malformed entries). Nitter does not expose numeric user
+    /// IDs or metrics, so `author_id` carries the handle and metrics are zero.
+    pub fn to_tweet(&self) -> Option<Tweet> {
+        let id = status_id(&self.link)?;
+        let text = match html_to_text(&self.description) {
+            t if t.is_empty() => self.title.clone(),
+            t => t,
+        };
+        Some(Tweet {
+            id,
+            text,
+            author_id: self.creator.clone(),
+            created_at: rfc2822_to_iso(&self.pub_date),
+            public_metrics: PublicMetrics::default(),
+            conversation_id: None,
+            entities: None,
+            attachments: None,
+        })
+    }
+}
+
+/// Parse an RSS document into a [`Feed`].
+pub fn parse_feed(xml: &str) -> Feed {
+    let (head, _) = xml.split_once("<item>").unwrap_or((xml, ""));
+    let title = tag_text(head, "title").unwrap_or_default();
+    let items = blocks(xml, "item")
+        .into_iter()
+        .map(|block| FeedItem {
+            link: tag_text(block, "link").unwrap_or_default(),
+            creator: tag_text(block, "dc:creator")
+                .unwrap_or_default()
+                .trim_start_matches('@')
+                .to_string(),
+            description: tag_text(block, "description").unwrap_or_default(),
+            title: tag_text(block, "title").unwrap_or_default(),
+            pub_date: tag_text(block, "pubDate").unwrap_or_default(),
+        })
+        .collect();
+    Feed { title, items }
+}
+
+/// Split a profile feed title (`"Name / @handle"`) into name and handle.
+pub fn split_channel_title(title: &str) -> Option<(String, String)> {
+    let (name, handle) = title.rsplit_once(" / @")?;
+    Some((name.trim().to_string(), handle.trim().to_string()))
+}
+
+/// Parse a tweet page into a [`Tweet`] using its Open Graph tags.
+///
+/// `og:title` is `"Name (@handle)"` and `og:description` holds the text.
+pub fn parse_status_page(html: &str, tweet_id: &str) -> Option<Tweet> {
+    let text = meta_content(html, "og:description")?;
+    let handle = meta_content(html, "og:title")
+        .and_then(|t| {
+            let start = t.rfind("(@")? + 2;
+            let end = t[start..].find(')')? + start;
+            Some(t[start..end].to_string())
+        })
+        .unwrap_or_default();
+    Some(Tweet {
+        id: tweet_id.to_string(),
+        text,
+        author_id: handle,
+        created_at: String::new(),
+        public_metrics: PublicMetrics::default(),
+        conversation_id: None,
+        entities: None,
+        attachments: None,
+    })
+}
+
+/// Extract the numeric status ID from a Nitter or X status URL.
+pub fn status_id(link: &str) -> Option<String> {
+    let rest = &link[link.find("/status/")? + "/status/".len()..];
+    let id: String = rest.chars().take_while(char::is_ascii_digit).collect();
+    (!id.is_empty()).then_some(id)
+}
+
+/// Whether tweet ID `a` is newer than `b` (snowflake IDs grow with time).
+pub fn is_newer(a: &str, b: &str) -> bool {
+    (a.len(), a) > (b.len(), b)
+}
+
+/// Text of the first `<tag>` element in `src`, with CDATA unwrapped and
+/// XML entities decoded.
+fn tag_text(src: &str, tag: &str) -> Option<String> {
+    let open = format!("<{tag}");
+    let close = format!("</{tag}>");
+    let mut from = 0;
+    let start = loop {
+        let at = src[from..].find(&open)? + from;
+        let after = at + open.len();
+        match src[after..].chars().next() {
+            Some('>') | Some(' ') => break src[after..].find('>')? + after + 1,
+            _ => from = after,
+        }
+    };
+    let end = src[start..].find(&close)? + start;
+    let raw = src[start..end].trim();
+    let raw = raw
+        .strip_prefix("<![CDATA[")
+        .and_then(|r| r.strip_suffix("]]>"))
+        .map(str::to_string)
+        .unwrap_or_else(|| decode_entities(raw));
+    Some(raw.trim().to_string())
+}
+
+/// Inner text of every `<tag>...</tag>` block in `src`.
+fn blocks<'a>(src: &'a str, tag: &str) -> Vec<&'a str> {
+    let open = format!("<{tag}>");
+    let close = format!("</{tag}>");
+    let mut out = Vec::new();
+    let mut rest = src;
+    while let Some(start) = rest.find(&open) {
+        let body = &rest[start + open.len()..];
+        let Some(end) = body.find(&close) else { break };
+        out.push(&body[..end]);
+        rest = &body[end + close.len()..];
+    }
+    out
+}
+
+/// Value of the `content` attribute of `<meta property="{property}">`.
+fn meta_content(html: &str, property: &str) -> Option<String> {
+    let marker = format!("property=\"{property}\"");
+    let at = html.find(&marker)?;
+    let tag_start = html[..at].rfind('<')?;
+    let tag_end = html[at..].find('>')? + at;
+    let tag = &html[tag_start..tag_end];
+    let value_start = tag.find("content=\"")? + "content=\"".len();
+    let value_end = tag[value_start..].find('"')? + value_start;
+    Some(decode_entities(&tag[value_start..value_end]))
+}
+
+/// Strip tags from a tweet body, keeping line breaks.
+fn html_to_text(html: &str) -> String {
+    let html = html.replace("<br>", "\n").replace("<br/>", "\n");
+    let mut out = String::with_capacity(html.len());
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            c if !in_tag => out.push(c),
+            _ => {}
+        }
+    }
+    decode_entities(out.trim())
+}
+
+/// Decode the XML/HTML entities Nitter emits.
+fn decode_entities(s: &str) -> String {
+    s.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&#x27;", "'")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+/// Convert an RFC 2822 date to the ISO-8601 form the X API returns.
+fn rfc2822_to_iso(date: &str) -> String {
+    chrono::DateTime::parse_from_rfc2822(date)
+        .map(|d| {
+            d.with_timezone(&chrono::Utc)
+                .format("%Y-%m-%dT%H:%M:%S.000Z")
+                .to_string()
+        })
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FEED: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<rss xmlns:atom="http://www.w3.org/2005/Atom" xmlns:dc="http://purl.org/dc/elements/1.1/" version="2.0">
+  <channel>
+    <atom:link href="https://nitter.example/rustlang/rss" rel="self" type="application/rss+xml" />
+    <title>Rust Language / @rustlang</title>
+    <link>https://nitter.example/rustlang</link>
+    <item>
+      <title>Rust 1.90 is out &amp; ready</title>
+      <dc:creator>@rustlang</dc:creator>
+      <description><![CDATA[<p>Rust 1.90 is out &amp; ready<br>Read the <a href="https://blog.rust-lang.org">post</a></p>]]></description>
+      <pubDate>Thu, 18 Sep 2025 14:03:00 GMT</pubDate>
+      <guid>https://nitter.example/rustlang/status/1968678901234567890#m</guid>
+      <link>https://nitter.example/rustlang/status/1968678901234567890#m</link>
+    </item>
+    <item>
+      <title>Older post</title>
+      <dc:creator>@ferris</dc:creator>
+      <description><![CDATA[]]></description>
+      <pubDate>Wed, 17 Sep 2025 09:00:00 GMT</pubDate>
+      <link>https://nitter.example/ferris/status/1968300000000000000#m</link>
+    </item>
+  </channel>
+</rss>"#;
+
+    #[test]
+    fn parses_feed_items_into_tweets() {
+        let feed = parse_feed(FEED);
+        assert_eq!(feed.title, "Rust Language / @rustlang");
+        assert_eq!(feed.items.len(), 2);
+
+        let tweet = feed.items[0].to_tweet().unwrap();
+        assert_eq!(tweet.id, "1968678901234567890");
+        assert_eq!(tweet.author_id, "rustlang");
+        assert_eq!(tweet.text, "Rust 1.90 is out & ready\nRead the post");
+        assert_eq!(tweet.created_at, "2025-09-18T14:03:00.000Z");
+
+        let older = feed.items[1].to_tweet().unwrap();
+        assert_eq!(older.author_id, "ferris");
+        assert_eq!(older.text, "Older post");
+    }
+
+    #[test]
+    fn splits_profile_channel_title() {
+        assert_eq!(
+            split_channel_title("Rust Language / @rustlang"),
+            Some(("Rust Language".to_string(), "rustlang".to_string()))
+        );
+        assert_eq!(split_channel_title("Search results"), None);
+    }
+
+    #[test]
+    fn parses_status_page_open_graph_tags() {
+        let html = r#"<html><head>
+<meta property="og:title" content="Ferris (@ferris)" />
+<meta property="og:description" content="Crabs &amp; borrow checkers" />
+</head></html>"#;
+        let tweet = parse_status_page(html, "42").unwrap();
+        assert_eq!(tweet.id, "42");
+        assert_eq!(tweet.author_id, "ferris");
+        assert_eq!(tweet.text, "Crabs & borrow checkers");
+        assert!(parse_status_page("<html></html>", "42").is_none());
+    }
+
+    #[test]
+    fn status_ids_and_ordering() {
+        assert_eq!(
+            status_id("https://nitter.example/a/status/123#m").as_deref(),
+            Some("123")
+        );
+        assert_eq!(status_id("https://nitter.example/a"), None);
+        assert!(is_newer("1000", "999"));
+        assert!(!is_newer("999", "999"));
+    }
+}
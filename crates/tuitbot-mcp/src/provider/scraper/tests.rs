@@ -0,0 +1,202 @@
+use std::time::Duration;
+
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use super::*;
+
+const SEARCH_FEED: &str = r#"<rss version="2.0"><channel>
+<title>Search results for "rust"</title>
+<item>
+  <title>Newest</title>
+  <dc:creator>@ferris</dc:creator>
+  <description><![CDATA[<p>Newest</p>]]></description>
+  <pubDate>Thu, 18 Sep 2025 14:03:00 GMT</pubDate>
+  <link>https://nitter.example/ferris/status/300#m</link>
+</item>
+<item>
+  <title>Middle</title>
+  <dc:creator>@rustlang</dc:creator>
+  <description><![CDATA[<p>Middle</p>]]></description>
+  <pubDate>Thu, 18 Sep 2025 13:00:00 GMT</pubDate>
+  <link>https://nitter.example/rustlang/status/200#m</link>
+</item>
+<item>
+  <title>Oldest</title>
+  <dc:creator>@ferris</dc:creator>
+  <description><![CDATA[<p>Oldest</p>]]></description>
+  <pubDate>Thu, 18 Sep 2025 12:00:00 GMT</pubDate>
+  <link>https://nitter.example/ferris/status/100#m</link>
+</item>
+</channel></rss>"#;
+
+fn provider(server: &MockServer, min_interval_ms: u64) -> ScraperReadProvider {
+    ScraperReadProvider::new(
+        Some(format!("{}/", server.uri())),
+        Duration::from_millis(min_interval_ms),
+    )
+}
+
+#[tokio::test]
+async fn search_maps_feed_and_filters_since_id() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/search/rss"))
+        .and(query_param("q", "rust"))
+        .and(query_param("f", "tweets"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("Min-Id", "cursor-2")
+                .set_body_string(SEARCH_FEED),
+        )
+        .mount(&server)
+        .await;
+
+    let resp = provider(&server, 0)
+        .search_tweets("rust", 10, Some("100"), None)
+        .await
+        .unwrap();
+    let ids: Vec<&str> = resp.data.iter().map(|t| t.id.as_str()).collect();
+    assert_eq!(ids, ["300", "200"]);
+    assert_eq!(resp.meta.newest_id.as_deref(), Some("300"));
+    assert_eq!(resp.meta.oldest_id.as_deref(), Some("200"));
+    assert_eq!(resp.meta.next_token.as_deref(), Some("cursor-2"));
+    let users = resp.includes.unwrap().users;
+    assert_eq!(users.len(), 2);
+    assert_eq!(users[0].username, "ferris");
+}
+
+#[tokio::test]
+async fn user_lookup_and_timeline_use_profile_feed() {
+    let server = MockServer::start().await;
+    let profile = SEARCH_FEED.replace(r#"Search results for "rust""#, "Ferris / @ferris");
+    Mock::given(method("GET"))
+        .and(path("/ferris/rss"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(profile))
+        .mount(&server)
+        .await;
+
+    let scraper = provider(&server, 0);
+    let user = scraper.get_user_by_username("@ferris").await.unwrap();
+    assert_eq!(user.id, "ferris");
+    assert_eq!(user.name, "Ferris");
+
+    let timeline = scraper.get_user_tweets(&user.id, 1, None).await.unwrap();
+    assert_eq!(timeline.data.len(), 1);
+    assert_eq!(timeline.data[0].author_id, "ferris");
+}
+
+#[tokio::test]
+async fn get_tweet_reads_status_page() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/i/status/42"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(
+            r#"<meta property="og:title" content="Ferris (@ferris)">
+<meta property="og:description" content="Hello crabs">"#,
+        ))
+        .mount(&server)
+        .await;
+
+    let tweet = provider(&server, 0).get_tweet("42").await.unwrap();
+    assert_eq!(tweet.text, "Hello crabs");
+    assert_eq!(tweet.author_id, "ferris");
+}
+
+#[tokio::test]
+async fn upstream_status_codes_map_to_provider_errors() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/i/status/1"))
+        .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "30"))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/i/status/2"))
+        .respond_with(ResponseTemplate::new(503))
+        .mount(&server)
+        .await;
+
+    let scraper = provider(&server, 0);
+    let err = scraper.get_tweet("1").await.unwrap_err();
+    assert!(matches!(
+        err,
+        ProviderError::RateLimited {
+            retry_after: Some(30)
+        }
+    ));
+    let err = scraper.get_tweet("2").await.unwrap_err();
+    assert!(matches!(
+        err,
+        ProviderError::ServerError { status: 503, .. }
+    ));
+}
+
+#[tokio::test]
+async fn requests_are_spaced_by_min_interval() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(SEARCH_FEED))
+        .mount(&server)
+        .await;
+
+    let scraper = provider(&server, 150);
+    let start = Instant::now();
+    scraper.search_tweets("a", 10, None, None).await.unwrap();
+    scraper
+        .clone()
+        .search_tweets("b", 10, None, None)
+        .await
+        .unwrap();
+    assert!(start.elapsed() >= Duration::from_millis(150));
+}
+
+#[tokio::test]
+async fn missing_base_url_returns_not_configured() {
+    let scraper = ScraperReadProvider::default();
+    let err = scraper.get_tweet("123").await.unwrap_err();
+    assert!(
+        matches!(err, ProviderError::NotConfigured { .. }),
+        "expected NotConfigured, got {err:?}"
+    );
+    let err = scraper
+        .search_tweets("q", 10, None, None)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, ProviderError::NotConfigured { .. }));
+}
+
+#[tokio::test]
+async fn unsupported_methods_return_other_error() {
+    let scraper = ScraperReadProvider::default();
+    let err = scraper.get_followers("u1", 10, None).await.unwrap_err();
+    assert!(matches!(err, ProviderError::Other { .. }));
+    let err = scraper.get_users_by_ids(&["u1"]).await.unwrap_err();
+    assert!(matches!(err, ProviderError::Other { .. }));
+}
+
+#[tokio::test]
+async fn auth_gated_methods_return_not_configured() {
+    let provider = ScraperReadProvider::default();
+
+    let err = provider
+        .get_user_mentions("u1", None, None)
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(err, ProviderError::NotConfigured { .. }),
+        "expected NotConfigured, got {err:?}"
+    );
+
+    let err = provider
+        .get_home_timeline("u1", 10, None)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, ProviderError::NotConfigured { .. }));
+
+    let err = provider.get_me().await.unwrap_err();
+    assert!(matches!(err, ProviderError::NotConfigured { .. }));
+
+    let err = provider.get_bookmarks("u1", 10, None).await.unwrap_err();
+    assert!(matches!(err, ProviderError::NotConfigured { .. }));
+}
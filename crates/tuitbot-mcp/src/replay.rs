@@ -0,0 +1,167 @@
+//! Replay of recorded MCP tool invocations.
+//!
+//! Re-executes a read-only invocation from `mcp_invocations` against an
+//! in-process admin-profile server, so the call goes through the same
+//! routing and argument parsing as the original. Mutating invocations and
+//! invocations whose arguments were redacted are refused.
+
+use rmcp::model::CallToolRequestParams;
+use rmcp::ServiceExt;
+use serde_json::Value;
+use tuitbot_core::storage::mcp_invocations::{self, McpInvocation};
+
+use crate::server::recorder::REDACTED;
+use crate::server::AdminMcpServer;
+use crate::state::SharedState;
+
+/// Buffer size for the in-process client/server pipe.
+const PIPE_CAPACITY: usize = 64 * 1024;
+
+/// Load invocation `id` and check that it can be replayed.
+pub(crate) async fn load_replayable(state: &SharedState, id: i64) -> anyhow::Result<McpInvocation> {
+    let invocation = mcp_invocations::get_invocation(&state.pool, id)
+        .await?
+        .ok_or_else(|| anyhow::anyhow!("No recorded invocation with id {id}"))?;
+
+    if !invocation.read_only {
+        anyhow::bail!(
+            "Invocation {id} ({}) is a mutation; only read-only invocations can be replayed",
+            invocation.tool_name
+        );
+    }
+    if invocation.args_json.contains(REDACTED) {
+        anyhow::bail!(
+            "Invocation {id} ({}) has redacted arguments and cannot be replayed faithfully",
+            invocation.tool_name
+        );
+    }
+    Ok(invocation)
+}
+
+/// Re-execute a recorded invocation and return the tool's response text.
+pub(crate) async fn replay(
+    state: SharedState,
+    invocation: &McpInvocation,
+) -> anyhow::Result<String> {
+    let arguments = match serde_json::from_str::<Value>(&invocation.args_json)? {
+        Value::Object(map) => Some(map),
+        _ => None,
+    };
+
+    let (server_io, client_io) = tokio::io::duplex(PIPE_CAPACITY);
+    let server = tokio::spawn(async move {
+        let service = AdminMcpServer::new(state).serve(server_io).await?;
+        service.waiting().await?;
+        anyhow::Ok(())
+    });
+
+    let client = ()
+        .serve(client_io)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to connect replay client: {e}"))?;
+    let result = client
+        .call_tool(CallToolRequestParams {
+            meta: None,
+            name: invocation.tool_name.clone().into(),
+            arguments,
+            task: None,
+        })
+        .await
+        .map_err(|e| anyhow::anyhow!("Replay of {} failed: {e}", invocation.tool_name));
+    let _ = client.cancel().await;
+    let _ = server.await;
+
+    let text = result?
+        .content
+        .iter()
+        .filter_map(|c| c.as_text().map(|t| t.text.clone()))
+        .collect::<Vec<_>>()
+        .join("\n");
+    Ok(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use tuitbot_core::config::Config;
+    use tuitbot_core::storage;
+    use tuitbot_core::storage::mcp_invocations::{InvocationFilter, InvocationParams};
+    use tuitbot_core::storage::write_queue::WriteQueue;
+
+    use super::*;
+    use crate::state::AppState;
+    use crate::tools::idempotency::IdempotencyStore;
+
+    async fn test_state() -> SharedState {
+        let pool = storage::init_test_db().await.expect("init db");
+        Arc::new(AppState {
+            write_queue: WriteQueue::spawn(pool.clone()),
+            pool,
+            config: Config::default(),
+            llm_provider: None,
+            x_client: None,
+            authenticated_user_id: None,
+            granted_scopes: vec![],
+            idempotency: Arc::new(IdempotencyStore::new()),
+            scraper: None,
+        })
+    }
+
+    async fn record(state: &SharedState, tool: &str, args: &str, read_only: bool) -> i64 {
+        mcp_invocations::log_invocation(
+            &state.pool,
+            &InvocationParams {
+                session_id: "s1",
+                tool_name: tool,
+                args_hash: "h",
+                args_json: args,
+                read_only,
+                success: true,
+                error_code: None,
+                latency_ms: 1,
+            },
+        )
+        .await
+        .expect("log")
+    }
+
+    #[tokio::test]
+    async fn replays_read_only_invocation() {
+        let state = test_state().await;
+        let id = record(&state, "get_stats", r#"{"days":3}"#, true).await;
+
+        let invocation = load_replayable(&state, id).await.expect("replayable");
+        let text = replay(state.clone(), &invocation).await.expect("replay");
+        let response: Value = serde_json::from_str(&text).expect("json response");
+        assert_eq!(response["success"], true);
+
+        // The replayed call is itself recorded.
+        let rows = mcp_invocations::list_invocations(
+            &state.pool,
+            &InvocationFilter {
+                tool_name: Some("get_stats".into()),
+                limit: 10,
+                ..Default::default()
+            },
+        )
+        .await
+        .expect("list");
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].args_json, r#"{"days":3}"#);
+    }
+
+    #[tokio::test]
+    async fn refuses_mutations_and_redacted_args() {
+        let state = test_state().await;
+        let mutation = record(&state, "x_like_tweet", r#"{"tweet_id":"1"}"#, false).await;
+        let err = load_replayable(&state, mutation).await.unwrap_err();
+        assert!(err.to_string().contains("mutation"));
+
+        let redacted = record(&state, "x_get", r#"{"token":"[REDACTED]"}"#, true).await;
+        let err = load_replayable(&state, redacted).await.unwrap_err();
+        assert!(err.to_string().contains("redacted"));
+
+        assert!(load_replayable(&state, 999).await.is_err());
+    }
+}
@@ -86,6 +86,12 @@ pub struct ScoreTweetRequest {
     pub replies: u64,
     /// Tweet creation timestamp (ISO 8601)
     pub created_at: String,
+    /// Expanded URLs in the tweet, used to detect Spaces and article links
+    #[serde(default)]
+    pub urls: Vec<String>,
+    /// Whether the tweet has attached media
+    #[serde(default)]
+    pub has_media: bool,
 }
 
 // --- Approval ---
@@ -96,6 +102,12 @@ pub struct ApprovalIdRequest {
     pub id: i64,
 }
 
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct ConfirmActionRequest {
+    /// Confirmation token returned by a staged mutation
+    pub token: String,
+}
+
 // --- Content Generation ---
 
 #[derive(Debug, Deserialize, JsonSchema)]
@@ -124,6 +136,30 @@ pub struct ComposeTweetRequest {
     pub scheduled_for: Option<String>,
 }
 
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct DraftIdRequest {
+    /// Draft ID
+    pub id: i64,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct EditDraftRequest {
+    /// Draft ID
+    pub id: i64,
+    /// New content (text for a tweet, JSON array for a thread).
+    pub content: Option<String>,
+    /// Planned ISO-8601 calendar slot. Does not schedule the draft; an empty string clears it.
+    pub planned_for: Option<String>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct ScheduleDraftRequest {
+    /// Draft ID
+    pub id: i64,
+    /// ISO-8601 datetime to post at.
+    pub scheduled_for: String,
+}
+
 // --- Direct X API ---
 
 #[derive(Debug, Deserialize, JsonSchema)]
@@ -415,6 +451,16 @@ pub struct FindReplyOpportunitiesRequest {
     pub since_id: Option<String>,
 }
 
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct PlanEngagementSessionRequest {
+    /// Session length in minutes (5-240).
+    pub minutes: u32,
+    /// Search query (defaults to product keywords joined with OR).
+    pub query: Option<String>,
+    /// Whether drafted replies may mention the product (default: false).
+    pub mention_product: Option<bool>,
+}
+
 #[derive(Debug, Deserialize, JsonSchema)]
 pub struct DraftRepliesRequest {
     /// Tweet IDs of previously discovered candidates.
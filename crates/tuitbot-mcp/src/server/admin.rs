@@ -8,6 +8,7 @@ use rmcp::handler::server::wrapper::Parameters;
 use rmcp::model::*;
 use rmcp::{tool, tool_handler, tool_router, ServerHandler};
 
+use super::recorder::RecordingRouter;
 use crate::requests::*;
 use crate::state::SharedState;
 use crate::tools;
@@ -29,6 +30,11 @@ impl AdminMcpServer {
             tool_router: Self::tool_router(),
         }
     }
+
+    /// Tool router that records each call to the invocation log.
+    fn recorder(&self) -> RecordingRouter<'_, Self> {
+        RecordingRouter::new(&self.tool_router, &self.state.pool)
+    }
 }
 
 #[tool_router]
@@ -202,6 +208,8 @@ impl AdminMcpServer {
             retweets: req.retweets,
             replies: req.replies,
             created_at: &req.created_at,
+            urls: &req.urls,
+            has_media: req.has_media,
         };
         let result = tools::scoring::score_tweet(&self.state.config, &input);
         Ok(CallToolResult::success(vec![Content::text(result)]))
@@ -230,8 +238,15 @@ impl AdminMcpServer {
         &self,
         Parameters(req): Parameters<ApprovalIdRequest>,
     ) -> Result<CallToolResult, rmcp::ErrorData> {
-        let result =
-            workflow::approval::approve_item(&self.state.pool, req.id, &self.state.config).await;
+        let result = self
+            .state
+            .write_queue
+            .write(workflow::approval::approve_item(
+                &self.state.pool,
+                req.id,
+                &self.state.config,
+            ))
+            .await;
         Ok(CallToolResult::success(vec![Content::text(result)]))
     }
 
@@ -241,15 +256,29 @@ impl AdminMcpServer {
         &self,
         Parameters(req): Parameters<ApprovalIdRequest>,
     ) -> Result<CallToolResult, rmcp::ErrorData> {
-        let result =
-            workflow::approval::reject_item(&self.state.pool, req.id, &self.state.config).await;
+        let result = self
+            .state
+            .write_queue
+            .write(workflow::approval::reject_item(
+                &self.state.pool,
+                req.id,
+                &self.state.config,
+            ))
+            .await;
         Ok(CallToolResult::success(vec![Content::text(result)]))
     }
 
     /// Approve all pending items in the approval queue.
     #[tool]
     async fn approve_all(&self) -> Result<CallToolResult, rmcp::ErrorData> {
-        let result = workflow::approval::approve_all(&self.state.pool, &self.state.config).await;
+        let result = self
+            .state
+            .write_queue
+            .write(workflow::approval::approve_all(
+                &self.state.pool,
+                &self.state.config,
+            ))
+            .await;
         Ok(CallToolResult::success(vec![Content::text(result)]))
     }
 
@@ -411,6 +440,116 @@ impl AdminMcpServer {
         Ok(CallToolResult::success(vec![Content::text(result)]))
     }
 
+    /// Confirm and execute a mutation staged by confirmation mode. Pass the confirmation_token returned by the mutation tool; tokens expire after mcp_policy.confirmation_ttl_seconds.
+    #[tool]
+    async fn confirm_action(
+        &self,
+        Parameters(req): Parameters<ConfirmActionRequest>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let result = workflow::confirm::confirm_action(&self.state, &req.token).await;
+        Ok(CallToolResult::success(vec![Content::text(result)]))
+    }
+
+    /// List drafts in the draft workspace (content not yet scheduled or submitted for approval).
+    #[tool]
+    async fn list_drafts(&self) -> Result<CallToolResult, rmcp::ErrorData> {
+        let result = workflow::drafts::list_drafts(&self.state.pool, &self.state.config).await;
+        Ok(CallToolResult::success(vec![Content::text(result)]))
+    }
+
+    /// Edit a draft's content and/or its planned calendar slot (planned_for does not schedule it).
+    #[tool]
+    async fn edit_draft(
+        &self,
+        Parameters(req): Parameters<EditDraftRequest>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let result = self
+            .state
+            .write_queue
+            .write(workflow::drafts::edit_draft(
+                &self.state.pool,
+                &self.state.config,
+                req.id,
+                req.content.as_deref(),
+                req.planned_for.as_deref(),
+            ))
+            .await;
+        Ok(CallToolResult::success(vec![Content::text(result)]))
+    }
+
+    /// Run QA checks (length, banned phrases) on a draft and store the report on it.
+    #[tool]
+    async fn check_draft(
+        &self,
+        Parameters(req): Parameters<DraftIdRequest>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let result = self
+            .state
+            .write_queue
+            .write(workflow::drafts::check_draft(
+                &self.state.pool,
+                &self.state.config,
+                req.id,
+            ))
+            .await;
+        Ok(CallToolResult::success(vec![Content::text(result)]))
+    }
+
+    /// Promote a tweet draft into the approval queue as a pending item. Fails if QA finds hard issues.
+    #[tool]
+    async fn promote_draft(
+        &self,
+        Parameters(req): Parameters<DraftIdRequest>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let result = self
+            .state
+            .write_queue
+            .write(workflow::drafts::promote_draft(
+                &self.state.pool,
+                &self.state.config,
+                req.id,
+            ))
+            .await;
+        Ok(CallToolResult::success(vec![Content::text(result)]))
+    }
+
+    /// Schedule a draft to be posted at a specific time.
+    #[tool]
+    async fn schedule_draft(
+        &self,
+        Parameters(req): Parameters<ScheduleDraftRequest>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let result = self
+            .state
+            .write_queue
+            .write(workflow::drafts::schedule_draft(
+                &self.state.pool,
+                &self.state.config,
+                req.id,
+                &req.scheduled_for,
+            ))
+            .await;
+        Ok(CallToolResult::success(vec![Content::text(result)]))
+    }
+
+    /// Delete a draft from the draft workspace.
+    #[tool]
+    async fn delete_draft(
+        &self,
+        Parameters(req): Parameters<DraftIdRequest>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let result = self
+            .state
+            .write_queue
+            .write(workflow::drafts::delete_draft(
+                &self.state.pool,
+                &self.state.config,
+                req.id,
+            ))
+            .await;
+        Ok(CallToolResult::success(vec![Content::text(result)]))
+    }
+
     /// Create a new draft or scheduled tweet/thread. In composer mode, this is the primary way to queue content.
     #[tool]
     async fn compose_tweet(
@@ -435,24 +574,36 @@ impl AdminMcpServer {
         let content_type = req.content_type.as_deref().unwrap_or("tweet");
         let config = &self.state.config;
         let result = if let Some(scheduled_for) = &req.scheduled_for {
-            match tuitbot_core::storage::scheduled_content::insert(
-                &self.state.pool,
-                content_type,
-                &req.content,
-                Some(scheduled_for),
-            )
-            .await
+            match self
+                .state
+                .write_queue
+                .write(tuitbot_core::storage::scheduled_content::insert(
+                    &self.state.pool,
+                    content_type,
+                    &req.content,
+                    Some(scheduled_for),
+                ))
+                .await
             {
                 Ok(id) => {
                     let _ = tuitbot_core::mcp_policy::McpPolicyEvaluator::record_mutation(
                         &self.state.pool,
                         "compose_tweet",
-                        &self.state.config.mcp_policy.rate_limits,
+                        &self.state.config.mcp_policy.effective_rate_limits(),
+                    )
+                    .await;
+                    workflow::origin::link(
+                        &self.state.pool,
+                        tuitbot_core::storage::workflow_origins::SCHEDULED_CONTENT,
+                        id,
                     )
                     .await;
                     let elapsed = start.elapsed().as_millis() as u64;
-                    let meta = ToolMeta::new(elapsed)
-                        .with_workflow(config.mode.to_string(), config.effective_approval_mode());
+                    let meta =
+                        workflow::origin::attach(ToolMeta::new(elapsed).with_workflow(
+                            config.mode.to_string(),
+                            config.effective_approval_mode(),
+                        ));
                     ToolResponse::success(serde_json::json!({
                         "scheduled_item_id": id,
                         "content_type": content_type,
@@ -471,24 +622,36 @@ impl AdminMcpServer {
                 }
             }
         } else {
-            match tuitbot_core::storage::scheduled_content::insert_draft(
-                &self.state.pool,
-                content_type,
-                &req.content,
-                "mcp",
-            )
-            .await
+            match self
+                .state
+                .write_queue
+                .write(tuitbot_core::storage::scheduled_content::insert_draft(
+                    &self.state.pool,
+                    content_type,
+                    &req.content,
+                    "mcp",
+                ))
+                .await
             {
                 Ok(id) => {
                     let _ = tuitbot_core::mcp_policy::McpPolicyEvaluator::record_mutation(
                         &self.state.pool,
                         "compose_tweet",
-                        &self.state.config.mcp_policy.rate_limits,
+                        &self.state.config.mcp_policy.effective_rate_limits(),
+                    )
+                    .await;
+                    workflow::origin::link(
+                        &self.state.pool,
+                        tuitbot_core::storage::workflow_origins::SCHEDULED_CONTENT,
+                        id,
                     )
                     .await;
                     let elapsed = start.elapsed().as_millis() as u64;
-                    let meta = ToolMeta::new(elapsed)
-                        .with_workflow(config.mode.to_string(), config.effective_approval_mode());
+                    let meta =
+                        workflow::origin::attach(ToolMeta::new(elapsed).with_workflow(
+                            config.mode.to_string(),
+                            config.effective_approval_mode(),
+                        ));
                     ToolResponse::success(serde_json::json!({
                         "draft_id": id,
                         "content_type": content_type,
@@ -1009,6 +1172,22 @@ impl AdminMcpServer {
         Ok(CallToolResult::success(vec![Content::text(result)]))
     }
 
+    /// Plan an engagement session for a time budget: due scheduled content, replies with drafts, a quote tweet, and likes within remaining rate limits. Read-only (no posts made).
+    #[tool]
+    async fn plan_engagement_session(
+        &self,
+        Parameters(req): Parameters<PlanEngagementSessionRequest>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let result = workflow::composite::engagement_plan::execute(
+            &self.state,
+            req.minutes,
+            req.query.as_deref(),
+            req.mention_product.unwrap_or(false),
+        )
+        .await;
+        Ok(CallToolResult::success(vec![Content::text(result)]))
+    }
+
     /// Generate reply drafts for previously discovered tweet candidates. Read-only. Requires LLM provider.
     #[tool]
     async fn draft_replies_for_candidates(
@@ -1158,7 +1337,7 @@ fn kv_to_tuples(kv: Option<&[crate::requests::KeyValue]>) -> Option<Vec<(String,
     })
 }
 
-#[tool_handler(router = self.tool_router)]
+#[tool_handler(router = self.recorder())]
 impl ServerHandler for AdminMcpServer {
     fn get_info(&self) -> ServerInfo {
         ServerInfo {
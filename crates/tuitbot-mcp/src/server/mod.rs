@@ -10,6 +10,7 @@
 pub mod admin;
 pub mod api_readonly;
 pub mod readonly;
+pub(crate) mod recorder;
 mod toolkit_response;
 pub mod utility_readonly;
 pub mod utility_write;
@@ -11,6 +11,7 @@ use rmcp::{tool, tool_handler, tool_router, ServerHandler};
 use crate::kernel;
 use crate::provider::retry::{RetryPolicy, RetryingProvider};
 use crate::provider::x_api::XApiProvider;
+use crate::provider::{annotate_public_read, public_reader};
 use crate::requests::*;
 use crate::state::SharedReadonlyState;
 use crate::tools::scoring;
@@ -42,11 +43,9 @@ impl ReadonlyMcpServer {
         &self,
         Parameters(req): Parameters<TweetIdRequest>,
     ) -> Result<CallToolResult, rmcp::ErrorData> {
-        let provider = RetryingProvider::new(
-            XApiProvider::new(self.state.x_client.as_ref()),
-            RetryPolicy::default(),
-        );
-        let result = kernel::read::get_tweet(&provider, &req.tweet_id).await;
+        let provider = public_reader(self.state.scraper.as_ref(), self.state.x_client.as_ref());
+        let result = kernel::read::get_tweet(provider.as_ref(), &req.tweet_id).await;
+        let result = annotate_public_read(self.state.scraper.as_ref(), result);
         Ok(CallToolResult::success(vec![Content::text(result)]))
     }
 
@@ -56,11 +55,9 @@ impl ReadonlyMcpServer {
         &self,
         Parameters(req): Parameters<UsernameRequest>,
     ) -> Result<CallToolResult, rmcp::ErrorData> {
-        let provider = RetryingProvider::new(
-            XApiProvider::new(self.state.x_client.as_ref()),
-            RetryPolicy::default(),
-        );
-        let result = kernel::read::get_user_by_username(&provider, &req.username).await;
+        let provider = public_reader(self.state.scraper.as_ref(), self.state.x_client.as_ref());
+        let result = kernel::read::get_user_by_username(provider.as_ref(), &req.username).await;
+        let result = annotate_public_read(self.state.scraper.as_ref(), result);
         Ok(CallToolResult::success(vec![Content::text(result)]))
     }
 
@@ -71,18 +68,16 @@ impl ReadonlyMcpServer {
         Parameters(req): Parameters<SearchTweetsRequest>,
     ) -> Result<CallToolResult, rmcp::ErrorData> {
         let max = req.max_results.unwrap_or(10).clamp(10, 100);
-        let provider = RetryingProvider::new(
-            XApiProvider::new(self.state.x_client.as_ref()),
-            RetryPolicy::default(),
-        );
+        let provider = public_reader(self.state.scraper.as_ref(), self.state.x_client.as_ref());
         let result = kernel::read::search_tweets(
-            &provider,
+            provider.as_ref(),
             &req.query,
             max,
             req.since_id.as_deref(),
             req.pagination_token.as_deref(),
         )
         .await;
+        let result = annotate_public_read(self.state.scraper.as_ref(), result);
         Ok(CallToolResult::success(vec![Content::text(result)]))
     }
 
@@ -113,17 +108,15 @@ impl ReadonlyMcpServer {
         Parameters(req): Parameters<GetUserTweetsRequest>,
     ) -> Result<CallToolResult, rmcp::ErrorData> {
         let max = req.max_results.unwrap_or(10).clamp(5, 100);
-        let provider = RetryingProvider::new(
-            XApiProvider::new(self.state.x_client.as_ref()),
-            RetryPolicy::default(),
-        );
+        let provider = public_reader(self.state.scraper.as_ref(), self.state.x_client.as_ref());
         let result = kernel::read::get_user_tweets(
-            &provider,
+            provider.as_ref(),
             &req.user_id,
             max,
             req.pagination_token.as_deref(),
         )
         .await;
+        let result = annotate_public_read(self.state.scraper.as_ref(), result);
         Ok(CallToolResult::success(vec![Content::text(result)]))
     }
 
@@ -154,11 +147,9 @@ impl ReadonlyMcpServer {
         &self,
         Parameters(req): Parameters<GetUserByIdRequest>,
     ) -> Result<CallToolResult, rmcp::ErrorData> {
-        let provider = RetryingProvider::new(
-            XApiProvider::new(self.state.x_client.as_ref()),
-            RetryPolicy::default(),
-        );
-        let result = kernel::read::get_user_by_id(&provider, &req.user_id).await;
+        let provider = public_reader(self.state.scraper.as_ref(), self.state.x_client.as_ref());
+        let result = kernel::read::get_user_by_id(provider.as_ref(), &req.user_id).await;
+        let result = annotate_public_read(self.state.scraper.as_ref(), result);
         Ok(CallToolResult::success(vec![Content::text(result)]))
     }
 
@@ -185,6 +176,8 @@ impl ReadonlyMcpServer {
             retweets: req.retweets,
             replies: req.replies,
             created_at: &req.created_at,
+            urls: &req.urls,
+            has_media: req.has_media,
         };
         let result = scoring::score_tweet(&self.state.config, &input);
         Ok(CallToolResult::success(vec![Content::text(result)]))
@@ -0,0 +1,223 @@
+//! Invocation recording for DB-backed MCP servers.
+//!
+//! [`RecordingRouter`] wraps a server's [`ToolRouter`] and is plugged into
+//! `#[tool_handler(router = ...)]`, so every tool call on the write and
+//! admin profiles is written to `mcp_invocations` with redacted arguments,
+//! outcome, and latency. Recording is best-effort and never fails the call.
+//! Each call runs inside its workflow origin scope (see
+//! [`crate::tools::workflow::origin`]).
+
+use std::collections::HashSet;
+use std::sync::OnceLock;
+use std::time::Instant;
+
+use rmcp::handler::server::router::tool::ToolRouter;
+use rmcp::handler::server::tool::ToolCallContext;
+use rmcp::model::{CallToolResult, Tool};
+use serde_json::Value;
+use tuitbot_core::storage::mcp_invocations::{self, InvocationParams};
+use tuitbot_core::storage::mutation_audit::compute_params_hash;
+use tuitbot_core::storage::DbPool;
+
+use crate::tools::manifest::all_tools;
+use crate::tools::workflow::origin;
+
+/// Placeholder stored in place of redacted argument values.
+pub const REDACTED: &str = "[REDACTED]";
+
+/// Argument keys whose values are never persisted.
+const SENSITIVE_KEYS: &[&str] = &[
+    "token",
+    "secret",
+    "password",
+    "api_key",
+    "authorization",
+    "cookie",
+];
+
+/// Identifier for this server process, shared by all its invocations.
+pub fn session_id() -> &'static str {
+    static SESSION: OnceLock<String> = OnceLock::new();
+    SESSION.get_or_init(|| {
+        format!(
+            "{}-{}",
+            chrono::Utc::now().format("%Y%m%dT%H%M%S"),
+            std::process::id()
+        )
+    })
+}
+
+/// Whether `tool_name` is a non-mutating tool per the manifest.
+///
+/// Unknown tools are treated as mutating so they are never replayed.
+pub fn is_read_only(tool_name: &str) -> bool {
+    static READ_ONLY: OnceLock<HashSet<String>> = OnceLock::new();
+    READ_ONLY
+        .get_or_init(|| {
+            all_tools()
+                .into_iter()
+                .filter(|t| !t.mutation)
+                .map(|t| t.name)
+                .collect()
+        })
+        .contains(tool_name)
+}
+
+/// Replace values under sensitive keys with [`REDACTED`], recursively.
+pub fn redact_args(value: &Value) -> Value {
+    match value {
+        Value::Object(map) => Value::Object(
+            map.iter()
+                .map(|(k, v)| {
+                    let key = k.to_ascii_lowercase();
+                    if SENSITIVE_KEYS.iter().any(|s| key.contains(s)) {
+                        (k.clone(), Value::String(REDACTED.to_string()))
+                    } else {
+                        (k.clone(), redact_args(v))
+                    }
+                })
+                .collect(),
+        ),
+        Value::Array(items) => Value::Array(items.iter().map(redact_args).collect()),
+        other => other.clone(),
+    }
+}
+
+/// Extract `(success, error_code)` from a tool result's response envelope.
+fn outcome(result: &Result<CallToolResult, rmcp::ErrorData>) -> (bool, Option<String>) {
+    let Ok(result) = result else {
+        return (false, Some("protocol_error".to_string()));
+    };
+    let envelope = result
+        .content
+        .first()
+        .and_then(|c| c.as_text())
+        .and_then(|t| serde_json::from_str::<Value>(&t.text).ok());
+    match envelope {
+        Some(v) => {
+            let success = v
+                .get("success")
+                .and_then(Value::as_bool)
+                .unwrap_or(result.is_error != Some(true));
+            let code = v
+                .pointer("/error/code")
+                .and_then(Value::as_str)
+                .map(str::to_string);
+            (success, code)
+        }
+        None => (result.is_error != Some(true), None),
+    }
+}
+
+/// [`ToolRouter`] wrapper that records each call to `mcp_invocations`.
+pub struct RecordingRouter<'a, S> {
+    router: &'a ToolRouter<S>,
+    pool: &'a DbPool,
+}
+
+impl<'a, S> RecordingRouter<'a, S>
+where
+    S: Send + Sync + 'static,
+{
+    pub fn new(router: &'a ToolRouter<S>, pool: &'a DbPool) -> Self {
+        Self { router, pool }
+    }
+
+    pub async fn call(
+        &self,
+        context: ToolCallContext<'_, S>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let tool_name = context.name.to_string();
+        let args = Value::Object(context.arguments.clone().unwrap_or_default());
+        let client_name = context
+            .request_context
+            .peer
+            .peer_info()
+            .map(|info| info.client_info.name.clone());
+        let call_origin = origin::resolve(
+            &context.request_context.meta.0,
+            client_name.as_deref(),
+            session_id(),
+        );
+        let session = call_origin
+            .session_id
+            .clone()
+            .unwrap_or_else(|| session_id().to_string());
+        let start = Instant::now();
+
+        let result = origin::scope(call_origin, self.router.call(context)).await;
+
+        let latency_ms = start.elapsed().as_millis() as u64;
+        let (success, error_code) = outcome(&result);
+        let args_hash = compute_params_hash(&tool_name, &args.to_string());
+        let args_json = redact_args(&args).to_string();
+        let _ = mcp_invocations::log_invocation(
+            self.pool,
+            &InvocationParams {
+                session_id: &session,
+                tool_name: &tool_name,
+                args_hash: &args_hash,
+                args_json: &args_json,
+                read_only: is_read_only(&tool_name),
+                success,
+                error_code: error_code.as_deref(),
+                latency_ms,
+            },
+        )
+        .await;
+
+        result
+    }
+
+    pub fn list_all(&self) -> Vec<Tool> {
+        self.router.list_all()
+    }
+
+    pub fn get(&self, name: &str) -> Option<&'a Tool> {
+        self.router.get(name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rmcp::model::Content;
+
+    #[test]
+    fn redacts_sensitive_keys_recursively() {
+        let args = serde_json::json!({
+            "tweet_id": "1",
+            "access_token": "abc",
+            "headers": [{"Authorization": "Bearer x", "accept": "json"}],
+        });
+        let redacted = redact_args(&args);
+        assert_eq!(redacted["tweet_id"], "1");
+        assert_eq!(redacted["access_token"], REDACTED);
+        assert_eq!(redacted["headers"][0]["Authorization"], REDACTED);
+        assert_eq!(redacted["headers"][0]["accept"], "json");
+    }
+
+    #[test]
+    fn outcome_reads_envelope() {
+        let ok = CallToolResult::success(vec![Content::text(r#"{"success":true,"data":{}}"#)]);
+        assert_eq!(outcome(&Ok(ok)), (true, None));
+
+        let err = CallToolResult::success(vec![Content::text(
+            r#"{"success":false,"error":{"code":"x_not_configured"}}"#,
+        )]);
+        assert_eq!(
+            outcome(&Ok(err)),
+            (false, Some("x_not_configured".to_string()))
+        );
+
+        let plain = CallToolResult::success(vec![Content::text("not json")]);
+        assert_eq!(outcome(&Ok(plain)), (true, None));
+    }
+
+    #[test]
+    fn read_only_classification() {
+        assert!(is_read_only("get_tweet_by_id"));
+        assert!(!is_read_only("x_post_tweet"));
+        assert!(!is_read_only("no_such_tool"));
+    }
+}
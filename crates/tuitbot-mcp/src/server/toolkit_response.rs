@@ -75,6 +75,15 @@ fn toolkit_error_to_envelope(err: ToolkitError) -> CallToolResult {
         ToolkitError::MediaTooLarge { .. } => {
             ToolResponse::error(ErrorCode::MediaUploadError, err.to_string())
         }
+        ToolkitError::UnsupportedMediaFormat { .. } => {
+            ToolResponse::error(ErrorCode::UnsupportedMediaType, err.to_string())
+        }
+        ToolkitError::InvalidMediaDimensions { .. } => {
+            ToolResponse::error(ErrorCode::MediaUploadError, err.to_string())
+        }
+        ToolkitError::VideoTooLong { .. } => {
+            ToolResponse::error(ErrorCode::MediaUploadError, err.to_string())
+        }
         ToolkitError::ThreadPartialFailure { .. } => {
             ToolResponse::error(ErrorCode::ThreadPartialFailure, err.to_string())
         }
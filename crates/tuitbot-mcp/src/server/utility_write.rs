@@ -460,6 +460,8 @@ impl UtilityWriteMcpServer {
             retweets: req.retweets,
             replies: req.replies,
             created_at: &req.created_at,
+            urls: &req.urls,
+            has_media: req.has_media,
         };
         let result = scoring::score_tweet(&self.state.config, &input);
         Ok(CallToolResult::success(vec![Content::text(result)]))
@@ -56,6 +56,7 @@ const X_WRITE_ERR: &[ErrorCode] = &[
     ErrorCode::PolicyDeniedRateLimited,
     ErrorCode::PolicyDeniedHardRule,
     ErrorCode::PolicyDeniedUserRule,
+    ErrorCode::PolicyDeniedKillSwitch,
     ErrorCode::PolicyError,
 ];
 
@@ -14,9 +14,11 @@ use std::sync::Arc;
 
 use tuitbot_core::config::Config;
 use tuitbot_core::llm::LlmProvider;
+use tuitbot_core::storage::write_queue::WriteQueue;
 use tuitbot_core::storage::DbPool;
 use tuitbot_core::x_api::XApiClient;
 
+use crate::provider::scraper::ScraperReadProvider;
 use crate::tools::idempotency::IdempotencyStore;
 
 // ── Runtime profile ─────────────────────────────────────────────────
@@ -78,6 +80,8 @@ impl FromStr for Profile {
 pub struct AppState {
     /// SQLite connection pool.
     pub pool: DbPool,
+    /// Write queue for queue, draft, and mutation-audit writes.
+    pub write_queue: WriteQueue,
     /// Loaded and validated configuration.
     pub config: Config,
     /// Optional LLM provider (None if not configured or creation failed).
@@ -90,6 +94,8 @@ pub struct AppState {
     pub granted_scopes: Vec<String>,
     /// Idempotency guard for mutation dedup.
     pub idempotency: Arc<IdempotencyStore>,
+    /// Scraper serving public reads when `x_api.provider_backend = "scraper"`.
+    pub scraper: Option<ScraperReadProvider>,
 }
 
 /// Thread-safe reference to shared full-profile state.
@@ -109,6 +115,8 @@ pub struct ReadonlyState {
     pub x_client: Box<dyn XApiClient>,
     /// Authenticated user ID from X API (from get_me on startup).
     pub authenticated_user_id: String,
+    /// Scraper serving public reads when `x_api.provider_backend = "scraper"`.
+    pub scraper: Option<ScraperReadProvider>,
 }
 
 /// Thread-safe reference to shared readonly-profile state.
@@ -165,9 +165,7 @@ async fn generate_baseline_benchmark() {
         "\n**Aggregate** — P50: {global_p50:.3} ms, P95: {global_p95:.3} ms, \
          Min: {global_min:.3} ms, Max: {global_max:.3} ms\n"
     ));
-    md.push_str(&format!(
-        "\nMigrated: 5 / 27 tools — Schema pass rate: 100%\n"
-    ));
+    md.push_str("\nMigrated: 5 / 27 tools — Schema pass rate: 100%\n");
 
     let md_path = artifacts.join("task-01-summary.md");
     std::fs::write(md_path, md).expect("write benchmark summary");
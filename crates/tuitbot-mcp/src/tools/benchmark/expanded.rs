@@ -67,6 +67,8 @@ async fn session09_expanded_benchmark() {
                     retweets: 2,
                     replies: 1,
                     created_at: "2026-02-24T12:00:00Z",
+                    urls: &[],
+                    has_media: false,
                 };
                 crate::tools::scoring::score_tweet(c, &input)
             }
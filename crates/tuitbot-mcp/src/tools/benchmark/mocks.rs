@@ -21,6 +21,8 @@ impl SocialReadProvider for BenchMockProvider {
             created_at: "2026-02-25T00:00:00Z".to_string(),
             public_metrics: PublicMetrics::default(),
             conversation_id: None,
+            entities: None,
+            attachments: None,
         })
     }
     async fn get_user_by_username(&self, u: &str) -> Result<User, ProviderError> {
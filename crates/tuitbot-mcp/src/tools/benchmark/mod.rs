@@ -63,17 +63,22 @@ pub(super) async fn setup_db() -> DbPool {
         max_replies_per_day: 5,
         max_tweets_per_day: 6,
         max_threads_per_week: 1,
+        max_likes_per_day: 50,
+        max_follows_per_day: 20,
         min_action_delay_seconds: 30,
         max_action_delay_seconds: 120,
         max_replies_per_author_per_day: 1,
         banned_phrases: vec![],
         product_mention_ratio: 0.2,
+        reset_timezone: String::new(),
+        windows: Default::default(),
     };
     let intervals = IntervalsConfig {
         mentions_check_seconds: 300,
         discovery_search_seconds: 600,
         content_post_window_seconds: 14400,
         thread_interval_seconds: 604800,
+        discovery_dedup_window_seconds: 86400,
     };
     storage::rate_limits::init_rate_limits(&pool, &limits, &intervals)
         .await
@@ -35,6 +35,11 @@ mod tests {
             "reject_item",
             "propose_and_queue_replies",
             "compose_tweet",
+            "edit_draft",
+            "promote_draft",
+            "schedule_draft",
+            "delete_draft",
+            "confirm_action",
             "x_post",
             "x_put",
             "x_delete",
@@ -287,8 +292,8 @@ mod tests {
             .iter()
             .filter(|t| t.profiles.contains(&Profile::Write))
             .count();
-        // 68 curated write + 44 generated - 4 admin-only = 112
-        assert_eq!(count, 112, "Write has {count} tools (expected 112)");
+        // 75 curated write + 44 generated - 4 admin-only = 119
+        assert_eq!(count, 120, "Write has {count} tools (expected 120)");
     }
 
     #[test]
@@ -299,8 +304,8 @@ mod tests {
             .iter()
             .filter(|t| t.profiles.contains(&Profile::Admin))
             .count();
-        // 72 curated + 44 generated + 16 ads + 7 compliance/stream = 139 (superset of write)
-        assert_eq!(count, 139, "Admin has {count} tools (expected 139)");
+        // 79 curated + 44 generated + 16 ads + 7 compliance/stream = 146 (superset of write)
+        assert_eq!(count, 147, "Admin has {count} tools (expected 147)");
     }
 
     // ── Mutation safety ─────────────────────────────────────────────
@@ -556,11 +561,11 @@ mod tests {
     fn write_server_tool_count() {
         let source = include_str!("../server/write.rs");
         let fn_names = extract_tool_fn_names(source);
-        // 72 curated - 4 admin-only universal request tools = 68
+        // 80 curated - 4 admin-only universal request tools = 76
         assert_eq!(
             fn_names.len(),
-            68,
-            "write.rs has {} tools (expected 68): {:?}",
+            76,
+            "write.rs has {} tools (expected 76): {:?}",
             fn_names.len(),
             fn_names
         );
@@ -570,11 +575,11 @@ mod tests {
     fn admin_server_tool_count() {
         let source = include_str!("../server/admin.rs");
         let fn_names = extract_tool_fn_names(source);
-        // All 72 curated tools including universal request tools
+        // All 80 curated tools including universal request tools
         assert_eq!(
             fn_names.len(),
-            72,
-            "admin.rs has {} tools (expected 72): {:?}",
+            80,
+            "admin.rs has {} tools (expected 80): {:?}",
             fn_names.len(),
             fn_names
         );
@@ -606,6 +611,7 @@ mod tests {
             ErrorCode::PolicyDeniedRateLimited,
             ErrorCode::PolicyDeniedHardRule,
             ErrorCode::PolicyDeniedUserRule,
+            ErrorCode::PolicyDeniedKillSwitch,
             ErrorCode::PolicyError,
             ErrorCode::ScraperMutationBlocked,
         ]);
@@ -151,6 +151,8 @@ mod tests {
             retweets: 2,
             replies: 1,
             created_at: "2026-02-24T12:00:00Z",
+            urls: &[],
+            has_media: false,
         };
         let json = crate::tools::scoring::score_tweet(&config, &input);
         assert_success(&json, "score_tweet");
@@ -10,6 +10,7 @@ use tuitbot_core::error::XApiError;
 use tuitbot_core::llm::{GenerationParams, LlmProvider, LlmResponse};
 use tuitbot_core::storage;
 use tuitbot_core::storage::tweets::DiscoveredTweet;
+use tuitbot_core::storage::write_queue::WriteQueue;
 use tuitbot_core::x_api::types::*;
 use tuitbot_core::x_api::XApiClient;
 use tuitbot_core::LlmError;
@@ -98,6 +99,8 @@ impl XApiClient for MockXApiClient {
             created_at: "2026-02-24T00:00:00Z".to_string(),
             public_metrics: PublicMetrics::default(),
             conversation_id: None,
+            entities: None,
+            attachments: None,
         })
     }
 
@@ -194,6 +197,7 @@ impl LlmProvider for MockLlmProvider {
             usage: tuitbot_core::llm::TokenUsage {
                 input_tokens: 10,
                 output_tokens: 5,
+                ..Default::default()
             },
             model: "mock-model".to_string(),
         })
@@ -205,14 +209,16 @@ impl LlmProvider for MockLlmProvider {
 }
 
 pub fn test_config() -> Config {
-    let mut config = Config::default();
-    config.mcp_policy = McpPolicyConfig {
-        enforce_for_mutations: false,
-        blocked_tools: Vec::new(),
-        require_approval_for: Vec::new(),
-        dry_run_mutations: false,
-        max_mutations_per_hour: 20,
-        ..McpPolicyConfig::default()
+    let mut config = Config {
+        mcp_policy: McpPolicyConfig {
+            enforce_for_mutations: false,
+            blocked_tools: Vec::new(),
+            require_approval_for: Vec::new(),
+            dry_run_mutations: false,
+            max_mutations_per_hour: 20,
+            ..McpPolicyConfig::default()
+        },
+        ..Config::default()
     };
     config.business.product_keywords = vec!["rust".to_string(), "async".to_string()];
     config.business.industry_topics = vec!["software engineering".to_string()];
@@ -245,6 +251,7 @@ pub async fn make_test_state(
         .await
         .expect("init rate limit");
     Arc::new(AppState {
+        write_queue: WriteQueue::spawn(pool.clone()),
         pool,
         config,
         llm_provider,
@@ -252,6 +259,7 @@ pub async fn make_test_state(
         authenticated_user_id: Some("u1".to_string()),
         granted_scopes: vec![],
         idempotency: Arc::new(crate::tools::idempotency::IdempotencyStore::new()),
+        scraper: None,
     })
 }
 
@@ -270,6 +278,8 @@ pub fn sample_tweet(id: &str, text: &str, author_id: &str) -> Tweet {
             bookmark_count: 0,
         },
         conversation_id: None,
+        entities: None,
+        attachments: None,
     }
 }
 
@@ -295,6 +305,7 @@ pub async fn seed_discovered_tweet(state: &SharedState, id: &str, text: &str, au
         like_count: 10,
         retweet_count: 2,
         reply_count: 1,
+        quote_count: 0,
         impression_count: Some(500),
         relevance_score: Some(75.0),
         matched_keyword: Some("rust".to_string()),
@@ -115,7 +115,7 @@ async fn eval_session09_all_scenarios() {
     assert!(results.quality_gates.all_pass, "Quality gates failed");
 }
 
-fn write_handoff(results: &EvalResults, dir: &std::path::PathBuf) {
+fn write_handoff(results: &EvalResults, dir: &std::path::Path) {
     let mut md = String::from("# Session 09 — Handoff\n\n");
     md.push_str(&format!(
         "**Generated:** {}\n\n",
@@ -6,19 +6,22 @@ use tuitbot_core::config::{Config, McpPolicyConfig};
 use tuitbot_core::llm::LlmProvider;
 use tuitbot_core::storage;
 use tuitbot_core::storage::tweets::DiscoveredTweet;
+use tuitbot_core::storage::write_queue::WriteQueue;
 use tuitbot_core::x_api::XApiClient;
 
 pub use crate::tools::test_mocks::validate_schema;
 
 pub fn approval_config() -> Config {
-    let mut config = Config::default();
-    config.mcp_policy = McpPolicyConfig {
-        enforce_for_mutations: true,
-        blocked_tools: Vec::new(),
-        require_approval_for: Vec::new(),
-        dry_run_mutations: false,
-        max_mutations_per_hour: 20,
-        ..McpPolicyConfig::default()
+    let mut config = Config {
+        mcp_policy: McpPolicyConfig {
+            enforce_for_mutations: true,
+            blocked_tools: Vec::new(),
+            require_approval_for: Vec::new(),
+            dry_run_mutations: false,
+            max_mutations_per_hour: 20,
+            ..McpPolicyConfig::default()
+        },
+        ..Config::default()
     };
     config.business.product_keywords = vec!["rust".to_string()];
     config.business.industry_topics = vec!["software".to_string()];
@@ -37,6 +40,7 @@ pub async fn make_test_state(
         .await
         .expect("init rate limit");
     Arc::new(AppState {
+        write_queue: WriteQueue::spawn(pool.clone()),
         pool,
         config,
         llm_provider,
@@ -44,6 +48,7 @@ pub async fn make_test_state(
         authenticated_user_id: Some("u1".to_string()),
         granted_scopes: vec![],
         idempotency: Arc::new(IdempotencyStore::new()),
+        scraper: None,
     })
 }
 
@@ -56,6 +61,7 @@ pub async fn seed_discovered_tweet(state: &SharedState, id: &str, text: &str, au
         like_count: 10,
         retweet_count: 2,
         reply_count: 1,
+        quote_count: 0,
         impression_count: Some(500),
         relevance_score: Some(75.0),
         matched_keyword: Some("rust".to_string()),
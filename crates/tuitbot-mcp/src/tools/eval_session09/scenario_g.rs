@@ -8,7 +8,7 @@ use crate::tools::test_mocks::MockProvider;
 
 pub async fn run_scenario_g() -> ScenarioResult {
     let mut steps = Vec::new();
-    let scraper = ScraperReadProvider::new();
+    let scraper = ScraperReadProvider::default();
 
     // Step 1: MockProvider get_tweet -> success
     let start = std::time::Instant::now();
@@ -25,14 +25,14 @@ pub async fn run_scenario_g() -> ScenarioResult {
         error_code: None,
     });
 
-    // Step 2: ScraperReadProvider get_tweet -> error (stub)
+    // Step 2: ScraperReadProvider get_tweet -> NotConfigured (no instance URL)
     let start = std::time::Instant::now();
     let json = read::get_tweet(&scraper, "t1").await;
     let elapsed = start.elapsed().as_millis() as u64;
     let valid = validate_schema(&json);
     let parsed: Value = serde_json::from_str(&json).unwrap_or_default();
     let code = parsed["error"]["code"].as_str().map(String::from);
-    let expected_scraper_error = code.as_deref() == Some("x_api_error");
+    let expected_scraper_error = code.as_deref() == Some("x_not_configured");
     steps.push(StepResult {
         tool_name: "get_tweet_scraper_provider".to_string(),
         latency_ms: elapsed,
@@ -75,8 +75,8 @@ async fn scenario_g_provider_switching() {
     assert!(result.schema_valid, "Scenario G schema validation failed");
     assert_eq!(
         result.steps[1].error_code.as_deref(),
-        Some("x_api_error"),
-        "Scraper stub should return x_api_error"
+        Some("x_not_configured"),
+        "Scraper without an instance URL should return x_not_configured"
     );
     assert_eq!(
         result.steps[2].error_code.as_deref(),
@@ -30,6 +30,8 @@ impl SocialReadProvider for GoldenMockProvider {
                 bookmark_count: 0,
             },
             conversation_id: None,
+            entities: None,
+            attachments: None,
         })
     }
 
@@ -61,6 +63,8 @@ impl SocialReadProvider for GoldenMockProvider {
                 created_at: "2026-02-25T00:00:00Z".to_string(),
                 public_metrics: PublicMetrics::default(),
                 conversation_id: None,
+                entities: None,
+                attachments: None,
             }],
             includes: Some(Includes {
                 users: vec![User {
@@ -111,6 +115,8 @@ impl SocialReadProvider for GoldenMockProvider {
                 created_at: String::new(),
                 public_metrics: PublicMetrics::default(),
                 conversation_id: None,
+                entities: None,
+                attachments: None,
             }],
             includes: None,
             meta: SearchMeta {
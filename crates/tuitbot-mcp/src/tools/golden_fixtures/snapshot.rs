@@ -1,4 +1,4 @@
-use std::path::PathBuf;
+use std::path::Path;
 
 use super::generation::generate_fixtures;
 use super::GoldenFixtures;
@@ -54,7 +54,7 @@ async fn golden_snapshot_matches() {
     write_golden_report(&fixtures, &dir);
 }
 
-fn write_golden_report(fixtures: &GoldenFixtures, dir: &PathBuf) {
+fn write_golden_report(fixtures: &GoldenFixtures, dir: &Path) {
     let mut md = String::from("# Session 09 — Schema Golden Report\n\n");
     md.push_str(&format!(
         "**Generated:** {}\n\n",
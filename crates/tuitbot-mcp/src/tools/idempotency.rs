@@ -70,6 +70,16 @@ impl IdempotencyStore {
         None
     }
 
+    /// Drop a recorded fingerprint so an identical call may proceed.
+    ///
+    /// Used when a call was staged rather than executed, so confirming it
+    /// within the dedup window isn't rejected as a duplicate.
+    pub fn forget(&self, tool_name: &str, params_json: &str) {
+        let fingerprint = Self::compute_fingerprint(tool_name, params_json);
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        entries.remove(&fingerprint);
+    }
+
     fn compute_fingerprint(tool_name: &str, params_json: &str) -> u64 {
         let mut hasher = DefaultHasher::new();
         tool_name.hash(&mut hasher);
@@ -302,6 +302,7 @@ const X_WRITE_ERR: &[ErrorCode] = &[
     ErrorCode::PolicyDeniedRateLimited,
     ErrorCode::PolicyDeniedHardRule,
     ErrorCode::PolicyDeniedUserRule,
+    ErrorCode::PolicyDeniedKillSwitch,
     ErrorCode::PolicyError,
 ];
 
@@ -318,6 +319,7 @@ const X_ENGAGE_ERR: &[ErrorCode] = &[
     ErrorCode::PolicyDeniedRateLimited,
     ErrorCode::PolicyDeniedHardRule,
     ErrorCode::PolicyDeniedUserRule,
+    ErrorCode::PolicyDeniedKillSwitch,
     ErrorCode::PolicyError,
 ];
 
@@ -346,6 +348,7 @@ const X_REQUEST_MUTATION_ERR: &[ErrorCode] = &[
     ErrorCode::PolicyDeniedRateLimited,
     ErrorCode::PolicyDeniedHardRule,
     ErrorCode::PolicyDeniedUserRule,
+    ErrorCode::PolicyDeniedKillSwitch,
     ErrorCode::PolicyError,
 ];
 
@@ -541,6 +544,7 @@ fn all_curated_tools() -> Vec<ToolEntry> {
             &[
                 ErrorCode::DbError,
                 ErrorCode::NotFound,
+                ErrorCode::Conflict,
                 ErrorCode::XNotConfigured,
                 ErrorCode::XApiError,
             ],
@@ -554,7 +558,7 @@ fn all_curated_tools() -> Vec<ToolEntry> {
             false,
             true,
             WRITE_UP,
-            &[ErrorCode::DbError, ErrorCode::NotFound],
+            &[ErrorCode::DbError, ErrorCode::NotFound, ErrorCode::Conflict],
         ),
         tool(
             "approve_all",
@@ -680,6 +684,29 @@ fn all_curated_tools() -> Vec<ToolEntry> {
             WRITE_UP,
             DB_ERR,
         ),
+        tool(
+            "confirm_action",
+            ToolCategory::Policy,
+            Lane::Workflow,
+            true,
+            true,
+            false,
+            true,
+            WRITE_UP,
+            &[
+                ErrorCode::NotFound,
+                ErrorCode::InvalidInput,
+                ErrorCode::DbError,
+                ErrorCode::XNotConfigured,
+                ErrorCode::XApiError,
+                ErrorCode::PolicyDeniedBlocked,
+                ErrorCode::PolicyDeniedRateLimited,
+                ErrorCode::PolicyDeniedHardRule,
+                ErrorCode::PolicyDeniedUserRule,
+                ErrorCode::PolicyDeniedKillSwitch,
+                ErrorCode::PolicyError,
+            ],
+        ),
         tool(
             "compose_tweet",
             ToolCategory::Write,
@@ -699,9 +726,89 @@ fn all_curated_tools() -> Vec<ToolEntry> {
                 ErrorCode::PolicyDeniedRateLimited,
                 ErrorCode::PolicyDeniedHardRule,
                 ErrorCode::PolicyDeniedUserRule,
+                ErrorCode::PolicyDeniedKillSwitch,
                 ErrorCode::PolicyError,
             ],
         ),
+        tool(
+            "list_drafts",
+            ToolCategory::Content,
+            Lane::Workflow,
+            false,
+            false,
+            false,
+            true,
+            WRITE_UP,
+            DB_ERR,
+        ),
+        tool(
+            "edit_draft",
+            ToolCategory::Content,
+            Lane::Workflow,
+            true,
+            false,
+            false,
+            true,
+            WRITE_UP,
+            &[
+                ErrorCode::DbError,
+                ErrorCode::NotFound,
+                ErrorCode::InvalidInput,
+            ],
+        ),
+        tool(
+            "check_draft",
+            ToolCategory::Content,
+            Lane::Workflow,
+            false,
+            false,
+            false,
+            true,
+            WRITE_UP,
+            &[ErrorCode::DbError, ErrorCode::NotFound],
+        ),
+        tool(
+            "promote_draft",
+            ToolCategory::Content,
+            Lane::Workflow,
+            true,
+            false,
+            false,
+            true,
+            WRITE_UP,
+            &[
+                ErrorCode::DbError,
+                ErrorCode::NotFound,
+                ErrorCode::InvalidInput,
+                ErrorCode::Conflict,
+            ],
+        ),
+        tool(
+            "schedule_draft",
+            ToolCategory::Content,
+            Lane::Workflow,
+            true,
+            false,
+            false,
+            true,
+            WRITE_UP,
+            &[
+                ErrorCode::DbError,
+                ErrorCode::NotFound,
+                ErrorCode::InvalidInput,
+            ],
+        ),
+        tool(
+            "delete_draft",
+            ToolCategory::Content,
+            Lane::Workflow,
+            true,
+            false,
+            false,
+            true,
+            WRITE_UP,
+            &[ErrorCode::DbError, ErrorCode::NotFound],
+        ),
         // ── Discovery Feed & Topics ──────────────────────────────────
         tool(
             "get_discovery_feed",
@@ -981,6 +1088,7 @@ fn all_curated_tools() -> Vec<ToolEntry> {
                 ErrorCode::PolicyDeniedRateLimited,
                 ErrorCode::PolicyDeniedHardRule,
                 ErrorCode::PolicyDeniedUserRule,
+                ErrorCode::PolicyDeniedKillSwitch,
                 ErrorCode::PolicyError,
             ],
         ),
@@ -1214,6 +1322,22 @@ fn all_curated_tools() -> Vec<ToolEntry> {
                 ErrorCode::DbError,
             ],
         ),
+        tool(
+            "plan_engagement_session",
+            ToolCategory::Composite,
+            Lane::Workflow,
+            false,
+            true,
+            false,
+            true,
+            WRITE_UP,
+            &[
+                ErrorCode::XNotConfigured,
+                ErrorCode::InvalidInput,
+                ErrorCode::XApiError,
+                ErrorCode::DbError,
+            ],
+        ),
         tool(
             "draft_replies_for_candidates",
             ToolCategory::Composite,
@@ -1248,6 +1372,7 @@ fn all_curated_tools() -> Vec<ToolEntry> {
                 ErrorCode::PolicyDeniedRateLimited,
                 ErrorCode::PolicyDeniedHardRule,
                 ErrorCode::PolicyDeniedUserRule,
+                ErrorCode::PolicyDeniedKillSwitch,
                 ErrorCode::PolicyError,
             ],
         ),
@@ -5,7 +5,7 @@ use std::time::Instant;
 use serde::Serialize;
 
 use tuitbot_core::config::Config;
-use tuitbot_core::scoring::{ScoringEngine, TweetData};
+use tuitbot_core::scoring::{EntityType, ScoringEngine, ScoringPlugin, TweetData};
 
 use super::response::{ToolMeta, ToolResponse};
 
@@ -18,6 +18,11 @@ struct ScoreOut {
     engagement: f32,
     reply_count: f32,
     content_type: f32,
+    entity_type: EntityType,
+    entity_adjustment: f32,
+    plugin_adjustment: f32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    plugin_rationale: Option<String>,
     meets_threshold: bool,
 }
 
@@ -30,6 +35,8 @@ pub struct ScoreTweetInput<'a> {
     pub retweets: u64,
     pub replies: u64,
     pub created_at: &'a str,
+    pub urls: &'a [String],
+    pub has_media: bool,
 }
 
 /// Score a tweet using the 6-signal scoring engine.
@@ -45,7 +52,8 @@ pub fn score_tweet(config: &Config, input: &ScoreTweetInput<'_>) -> String {
         .cloned()
         .collect();
 
-    let engine = ScoringEngine::new(config.scoring.clone(), keywords);
+    let engine = ScoringEngine::new(config.scoring.clone(), keywords)
+        .with_plugin(ScoringPlugin::load_or_warn(&config.scoring.plugin));
 
     let tweet_data = TweetData {
         text: input.text.to_string(),
@@ -55,8 +63,9 @@ pub fn score_tweet(config: &Config, input: &ScoreTweetInput<'_>) -> String {
         replies: input.replies,
         author_username: input.author_username.to_string(),
         author_followers: input.author_followers,
-        has_media: false,
+        has_media: input.has_media,
         is_quote_tweet: false,
+        urls: input.urls.to_vec(),
     };
 
     let score = engine.score_tweet(&tweet_data);
@@ -69,6 +78,10 @@ pub fn score_tweet(config: &Config, input: &ScoreTweetInput<'_>) -> String {
         engagement: score.engagement,
         reply_count: score.reply_count,
         content_type: score.content_type,
+        entity_type: score.entity_type,
+        entity_adjustment: score.entity_adjustment,
+        plugin_adjustment: score.plugin_adjustment,
+        plugin_rationale: score.plugin_rationale,
         meets_threshold: score.meets_threshold,
     };
 
@@ -88,6 +88,8 @@ impl SocialReadProvider for MockProvider {
             created_at: "2026-02-25T00:00:00Z".to_string(),
             public_metrics: PublicMetrics::default(),
             conversation_id: None,
+            entities: None,
+            attachments: None,
         })
     }
 
@@ -115,6 +117,8 @@ impl SocialReadProvider for MockProvider {
                 created_at: String::new(),
                 public_metrics: PublicMetrics::default(),
                 conversation_id: None,
+                entities: None,
+                attachments: None,
             }],
             includes: None,
             meta: SearchMeta {
@@ -158,6 +162,8 @@ impl SocialReadProvider for MockProvider {
                 created_at: String::new(),
                 public_metrics: PublicMetrics::default(),
                 conversation_id: None,
+                entities: None,
+                attachments: None,
             }],
             includes: None,
             meta: SearchMeta {
@@ -484,6 +490,7 @@ impl LlmProvider for MockLlmProvider {
             usage: tuitbot_core::llm::TokenUsage {
                 input_tokens: 10,
                 output_tokens: 5,
+                ..Default::default()
             },
             model: "mock-model".to_string(),
         })
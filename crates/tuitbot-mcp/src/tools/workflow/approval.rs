@@ -5,10 +5,12 @@ use std::time::Instant;
 use serde::Serialize;
 
 use tuitbot_core::config::Config;
+use tuitbot_core::error::StorageError;
 use tuitbot_core::storage;
-use tuitbot_core::storage::approval_queue::ReviewAction;
+use tuitbot_core::storage::approval_queue::{ApprovalComment, ReviewAction};
 use tuitbot_core::storage::DbPool;
 
+use crate::contract::ErrorCode;
 use crate::tools::response::{ToolMeta, ToolResponse};
 
 #[derive(Serialize)]
@@ -27,6 +29,7 @@ struct ApprovalItemOut {
     review_notes: Option<String>,
     reason: Option<String>,
     detected_risks: String,
+    latest_comments: Vec<ApprovalComment>,
 }
 
 fn item_to_out(item: &storage::approval_queue::ApprovalItem) -> ApprovalItemOut {
@@ -45,6 +48,7 @@ fn item_to_out(item: &storage::approval_queue::ApprovalItem) -> ApprovalItemOut
         review_notes: item.review_notes.clone(),
         reason: item.reason.clone(),
         detected_risks: item.detected_risks.clone(),
+        latest_comments: item.latest_comments.clone(),
     }
 }
 
@@ -52,7 +56,13 @@ fn item_to_out(item: &storage::approval_queue::ApprovalItem) -> ApprovalItemOut
 pub async fn list_pending(pool: &DbPool, config: &Config) -> String {
     let start = Instant::now();
 
-    match storage::approval_queue::get_pending(pool).await {
+    let pending = match storage::approval_queue::get_pending(pool).await {
+        Ok(mut items) => storage::approval_queue::attach_latest_comments(pool, &mut items)
+            .await
+            .map(|()| items),
+        Err(e) => Err(e),
+    };
+    match pending {
         Ok(items) => {
             let out: Vec<ApprovalItemOut> = items.iter().map(item_to_out).collect();
             let elapsed = start.elapsed().as_millis() as u64;
@@ -95,6 +105,16 @@ pub async fn get_pending_count(pool: &DbPool, config: &Config) -> String {
     }
 }
 
+/// Map a storage error to a tool error, surfacing lost review races as `conflict`.
+fn storage_error(e: StorageError, context: String) -> ToolResponse {
+    match e {
+        StorageError::Conflict { message } => {
+            ToolResponse::error(ErrorCode::Conflict, format!("{context}: {message}"))
+        }
+        e => ToolResponse::db_error(format!("{context}: {e}")),
+    }
+}
+
 /// Approve a specific item by ID.
 pub async fn approve_item(pool: &DbPool, id: i64, config: &Config) -> String {
     let start = Instant::now();
@@ -102,6 +122,7 @@ pub async fn approve_item(pool: &DbPool, id: i64, config: &Config) -> String {
     let review = ReviewAction {
         actor: Some("mcp_agent".to_string()),
         notes: None,
+        expected_version: None,
     };
     match storage::approval_queue::update_status_with_review(pool, id, "approved", &review).await {
         Ok(()) => {
@@ -116,7 +137,7 @@ pub async fn approve_item(pool: &DbPool, id: i64, config: &Config) -> String {
             let elapsed = start.elapsed().as_millis() as u64;
             let meta = ToolMeta::new(elapsed)
                 .with_workflow(config.mode.to_string(), config.effective_approval_mode());
-            ToolResponse::db_error(format!("Error approving item {id}: {e}"))
+            storage_error(e, format!("Error approving item {id}"))
                 .with_meta(meta)
                 .to_json()
         }
@@ -130,6 +151,7 @@ pub async fn reject_item(pool: &DbPool, id: i64, config: &Config) -> String {
     let review = ReviewAction {
         actor: Some("mcp_agent".to_string()),
         notes: None,
+        expected_version: None,
     };
     match storage::approval_queue::update_status_with_review(pool, id, "rejected", &review).await {
         Ok(()) => {
@@ -144,7 +166,7 @@ pub async fn reject_item(pool: &DbPool, id: i64, config: &Config) -> String {
             let elapsed = start.elapsed().as_millis() as u64;
             let meta = ToolMeta::new(elapsed)
                 .with_workflow(config.mode.to_string(), config.effective_approval_mode());
-            ToolResponse::db_error(format!("Error rejecting item {id}: {e}"))
+            storage_error(e, format!("Error rejecting item {id}"))
                 .with_meta(meta)
                 .to_json()
         }
@@ -158,6 +180,7 @@ pub async fn approve_all(pool: &DbPool, config: &Config) -> String {
     let review = ReviewAction {
         actor: Some("mcp_agent".to_string()),
         notes: None,
+        expected_version: None,
     };
     match storage::approval_queue::batch_approve(pool, config.max_batch_approve, &review).await {
         Ok(ids) => {
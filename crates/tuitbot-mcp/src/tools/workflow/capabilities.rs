@@ -550,17 +550,22 @@ mod tests {
             max_replies_per_day: 5,
             max_tweets_per_day: 6,
             max_threads_per_week: 1,
+            max_likes_per_day: 50,
+            max_follows_per_day: 20,
             min_action_delay_seconds: 30,
             max_action_delay_seconds: 120,
             max_replies_per_author_per_day: 1,
             banned_phrases: vec![],
             product_mention_ratio: 0.2,
+            reset_timezone: String::new(),
+            windows: Default::default(),
         };
         let intervals = tuitbot_core::config::IntervalsConfig {
             mentions_check_seconds: 300,
             discovery_search_seconds: 600,
             content_post_window_seconds: 14400,
             thread_interval_seconds: 604800,
+            discovery_dedup_window_seconds: 86400,
         };
         storage::rate_limits::init_rate_limits(&pool, &limits, &intervals)
             .await
@@ -626,7 +631,7 @@ mod tests {
         let rate_limits = parsed["data"]["rate_limits"]
             .as_array()
             .expect("rate_limits array");
-        assert_eq!(rate_limits.len(), 5);
+        assert_eq!(rate_limits.len(), 7);
         assert_eq!(parsed["data"]["recommended_max_actions"]["replies"], 5);
         assert_eq!(parsed["data"]["recommended_max_actions"]["tweets"], 6);
         assert_eq!(parsed["data"]["recommended_max_actions"]["threads"], 1);
@@ -0,0 +1,112 @@
+//! `plan_engagement_session` — turn a time budget into a ranked action plan.
+//!
+//! Delegates to `tuitbot_core::workflow::session_plan` for the actual logic,
+//! adding only MCP response envelope wrapping and telemetry. Drafts are
+//! included when an LLM provider is configured; nothing is posted.
+
+use std::sync::Arc;
+use std::time::Instant;
+
+use tuitbot_core::workflow::session_plan::{self, SessionPlanInput};
+use tuitbot_core::workflow::WorkflowError;
+
+use crate::state::SharedState;
+use crate::tools::response::{ErrorCode, ToolMeta, ToolResponse};
+
+/// Execute the `plan_engagement_session` composite tool.
+pub async fn execute(
+    state: &SharedState,
+    minutes: u32,
+    query: Option<&str>,
+    mention_product: bool,
+) -> String {
+    let start = Instant::now();
+
+    let x_client = match state.x_client.as_ref() {
+        Some(c) => c.as_ref(),
+        None => {
+            let elapsed = start.elapsed().as_millis() as u64;
+            return ToolResponse::x_not_configured()
+                .with_meta(ToolMeta::new(elapsed))
+                .to_json();
+        }
+    };
+
+    let llm: Option<Arc<dyn tuitbot_core::llm::LlmProvider>> =
+        state.llm_provider.as_ref().map(|_| {
+            Arc::new(crate::tools::workflow::content::ArcProvider {
+                state: Arc::clone(state),
+            }) as Arc<dyn tuitbot_core::llm::LlmProvider>
+        });
+
+    let result = session_plan::execute(
+        &state.pool,
+        x_client,
+        llm.as_ref(),
+        &state.config,
+        SessionPlanInput {
+            minutes,
+            query: query.map(String::from),
+            mention_product,
+        },
+    )
+    .await;
+
+    let elapsed = start.elapsed().as_millis() as u64;
+
+    match result {
+        Ok(plan) => {
+            crate::tools::workflow::telemetry::record(
+                &state.pool,
+                "plan_engagement_session",
+                "composite",
+                elapsed,
+                true,
+                None,
+                None,
+                None,
+            )
+            .await;
+            ToolResponse::success(plan)
+                .with_meta(ToolMeta::new(elapsed).with_workflow(
+                    state.config.mode.to_string(),
+                    state.config.effective_approval_mode(),
+                ))
+                .to_json()
+        }
+        Err(e) => {
+            let code = workflow_error_to_code(&e);
+            crate::tools::workflow::telemetry::record(
+                &state.pool,
+                "plan_engagement_session",
+                "composite",
+                elapsed,
+                false,
+                Some(code.as_str()),
+                None,
+                None,
+            )
+            .await;
+            ToolResponse::error(code, e.to_string())
+                .with_meta(ToolMeta::new(elapsed))
+                .to_json()
+        }
+    }
+}
+
+/// Map a `WorkflowError` to an MCP `ErrorCode`.
+fn workflow_error_to_code(e: &WorkflowError) -> ErrorCode {
+    match e {
+        WorkflowError::InvalidInput(_) => ErrorCode::InvalidInput,
+        WorkflowError::XNotConfigured => ErrorCode::XNotConfigured,
+        WorkflowError::LlmNotConfigured => ErrorCode::LlmNotConfigured,
+        WorkflowError::Llm(_) => ErrorCode::LlmError,
+        WorkflowError::Database(_) | WorkflowError::Storage(_) => ErrorCode::DbError,
+        WorkflowError::Toolkit(te) => match te {
+            tuitbot_core::toolkit::ToolkitError::XApi(_) => ErrorCode::XApiError,
+            tuitbot_core::toolkit::ToolkitError::InvalidInput { .. } => ErrorCode::InvalidInput,
+            tuitbot_core::toolkit::ToolkitError::TweetTooLong { .. } => ErrorCode::InvalidInput,
+            _ => ErrorCode::XApiError,
+        },
+    }
+}
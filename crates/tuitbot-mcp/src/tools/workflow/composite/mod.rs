@@ -9,6 +9,7 @@
 //! here for backward compatibility.
 
 pub mod draft_replies;
+pub mod engagement_plan;
 pub mod find_opportunities;
 pub mod propose_queue;
 pub mod thread_plan;
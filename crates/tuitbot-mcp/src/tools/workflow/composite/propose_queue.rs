@@ -7,12 +7,14 @@ use std::sync::Arc;
 use std::time::Instant;
 
 use tuitbot_core::mcp_policy::McpPolicyEvaluator;
+use tuitbot_core::storage::workflow_origins;
 use tuitbot_core::workflow::queue::{self, QueueInput};
 use tuitbot_core::workflow::{ProposeResult, QueueItem, WorkflowError};
 
 use crate::requests::ProposeItem;
 use crate::state::SharedState;
 use crate::tools::response::{ErrorCode, ToolMeta, ToolResponse};
+use crate::tools::workflow::origin;
 use crate::tools::workflow::policy_gate::{self, GateResult};
 
 /// Execute the `propose_and_queue_replies` composite tool.
@@ -72,7 +74,7 @@ pub async fn execute(state: &SharedState, items: &[ProposeItem], mention_product
     let _ = McpPolicyEvaluator::record_mutation(
         &state.pool,
         "propose_and_queue_replies",
-        &state.config.mcp_policy.rate_limits,
+        &state.config.mcp_policy.effective_rate_limits(),
     )
     .await;
 
@@ -80,6 +82,19 @@ pub async fn execute(state: &SharedState, items: &[ProposeItem], mention_product
 
     match result {
         Ok(results) => {
+            for r in &results {
+                if let ProposeResult::Queued {
+                    approval_queue_id, ..
+                } = r
+                {
+                    origin::link(
+                        &state.pool,
+                        workflow_origins::APPROVAL_QUEUE,
+                        *approval_queue_id,
+                    )
+                    .await;
+                }
+            }
             let has_error = results
                 .iter()
                 .any(|r| matches!(r, ProposeResult::Blocked { .. }));
@@ -98,10 +113,10 @@ pub async fn execute(state: &SharedState, items: &[ProposeItem], mention_product
             )
             .await;
             ToolResponse::success(&results)
-                .with_meta(ToolMeta::new(elapsed).with_workflow(
+                .with_meta(origin::attach(ToolMeta::new(elapsed).with_workflow(
                     state.config.mode.to_string(),
                     state.config.effective_approval_mode(),
-                ))
+                )))
                 .to_json()
         }
         Err(e) => {
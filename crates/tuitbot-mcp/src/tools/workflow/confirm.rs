@@ -0,0 +1,149 @@
+//! Confirmation mode tool: confirm_action.
+//!
+//! With `mcp_policy.confirmation_mode` enabled, mutation tools stage their
+//! call and return a confirmation token instead of executing. This tool
+//! confirms a staged call and replays it through the normal gateway, where
+//! policy is re-evaluated and the confirmed staged action is consumed.
+//!
+//! Calls whose gateway params don't carry the full arguments (universal
+//! `x_post` / `x_put` / `x_delete` requests) are confirmed but not replayed;
+//! the agent re-issues the identical call to execute them.
+
+use std::time::Instant;
+
+use serde::Deserialize;
+use tuitbot_core::storage::staged_actions::{self, StagedAction};
+
+use crate::state::SharedState;
+use crate::tools::response::{ErrorCode, ToolMeta, ToolResponse};
+
+use super::x_actions;
+
+/// Confirm a staged mutation by token and execute it.
+pub async fn confirm_action(state: &SharedState, token: &str) -> String {
+    let start = Instant::now();
+    let error = |code: ErrorCode, msg: String| {
+        let elapsed = start.elapsed().as_millis() as u64;
+        ToolResponse::error(code, msg)
+            .with_meta(ToolMeta::new(elapsed))
+            .to_json()
+    };
+
+    let staged = match staged_actions::get_by_token(&state.pool, token).await {
+        Ok(Some(s)) => s,
+        Ok(None) => {
+            return error(
+                ErrorCode::NotFound,
+                format!("No staged action for token '{token}'."),
+            )
+        }
+        Err(e) => {
+            return ToolResponse::db_error(format!("Error loading staged action: {e}")).to_json()
+        }
+    };
+
+    if staged.status == "executed" || staged.status == "rejected" {
+        return error(
+            ErrorCode::InvalidInput,
+            format!("Staged action was already {}.", staged.status),
+        );
+    }
+    if staged.is_expired() {
+        return error(
+            ErrorCode::InvalidInput,
+            format!(
+                "Staged action expired at {}. Call {} again to stage a new one.",
+                staged.expires_at, staged.tool_name
+            ),
+        );
+    }
+
+    match staged_actions::confirm(&state.pool, staged.id, "mcp").await {
+        Ok(true) => {}
+        Ok(false) => {
+            return error(
+                ErrorCode::InvalidInput,
+                "Staged action is no longer open for confirmation.".to_string(),
+            )
+        }
+        Err(e) => return ToolResponse::db_error(format!("Error confirming action: {e}")).to_json(),
+    }
+
+    match replay(state, &staged).await {
+        Some(result) => result,
+        None => {
+            let elapsed = start.elapsed().as_millis() as u64;
+            ToolResponse::success(serde_json::json!({
+                "confirmed": true,
+                "tool": staged.tool_name,
+                "expires_at": staged.expires_at,
+                "next_step": format!(
+                    "Re-issue the original {} call with identical arguments to execute it.",
+                    staged.tool_name
+                ),
+            }))
+            .with_meta(ToolMeta::new(elapsed))
+            .to_json()
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct ReplayParams {
+    text: Option<String>,
+    tweet_id: Option<String>,
+    target_user_id: Option<String>,
+    in_reply_to_id: Option<String>,
+    quoted_tweet_id: Option<String>,
+    tweets: Option<Vec<String>>,
+    media_ids: Option<serde_json::Value>,
+}
+
+/// Re-invoke a confirmed staged call. Returns `None` when the tool's staged
+/// params can't reconstruct the original call.
+async fn replay(state: &SharedState, staged: &StagedAction) -> Option<String> {
+    let p: ReplayParams = serde_json::from_str(&staged.params_json).ok()?;
+    let flat_media: Option<Vec<String>> = p
+        .media_ids
+        .clone()
+        .and_then(|v| serde_json::from_value(v).ok());
+    let media = flat_media.as_deref();
+
+    let result = match staged.tool_name.as_str() {
+        "post_tweet" => x_actions::post_tweet(state, p.text.as_deref()?, media).await,
+        "reply_to_tweet" => {
+            x_actions::reply_to_tweet(
+                state,
+                p.text.as_deref()?,
+                p.in_reply_to_id.as_deref()?,
+                media,
+            )
+            .await
+        }
+        "quote_tweet" => {
+            x_actions::quote_tweet(
+                state,
+                p.text.as_deref()?,
+                p.quoted_tweet_id.as_deref()?,
+                media,
+            )
+            .await
+        }
+        "post_thread" => {
+            let thread_media: Option<Vec<Vec<String>>> =
+                p.media_ids.and_then(|v| serde_json::from_value(v).ok());
+            x_actions::post_thread(state, p.tweets.as_deref()?, thread_media.as_deref()).await
+        }
+        "delete_tweet" => x_actions::delete_tweet(state, p.tweet_id.as_deref()?).await,
+        "like_tweet" => x_actions::like_tweet(state, p.tweet_id.as_deref()?).await,
+        "unlike_tweet" => x_actions::unlike_tweet(state, p.tweet_id.as_deref()?).await,
+        "retweet" => x_actions::retweet(state, p.tweet_id.as_deref()?).await,
+        "unretweet" => x_actions::unretweet(state, p.tweet_id.as_deref()?).await,
+        "bookmark_tweet" => x_actions::bookmark_tweet(state, p.tweet_id.as_deref()?).await,
+        "unbookmark_tweet" => x_actions::unbookmark_tweet(state, p.tweet_id.as_deref()?).await,
+        "follow_user" => x_actions::follow_user(state, p.target_user_id.as_deref()?).await,
+        "unfollow_user" => x_actions::unfollow_user(state, p.target_user_id.as_deref()?).await,
+        _ => return None,
+    };
+    Some(result)
+}
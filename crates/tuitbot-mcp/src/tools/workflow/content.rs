@@ -63,7 +63,8 @@ pub async fn generate_reply(
     let provider = Box::new(ArcProvider {
         state: Arc::clone(state),
     });
-    let gen = ContentGenerator::new(provider, business.clone());
+    let gen = ContentGenerator::new(provider, business.clone())
+        .with_disclosure(config.disclosure.clone());
 
     match gen
         .generate_reply(tweet_text, tweet_author, mention_product)
@@ -102,7 +103,8 @@ pub async fn generate_tweet(
     let provider = Box::new(ArcProvider {
         state: Arc::clone(state),
     });
-    let gen = ContentGenerator::new(provider, business.clone());
+    let gen = ContentGenerator::new(provider, business.clone())
+        .with_disclosure(config.disclosure.clone());
 
     match gen.generate_tweet(topic).await {
         Ok(output) => {
@@ -138,7 +140,8 @@ pub async fn generate_thread(
     let provider = Box::new(ArcProvider {
         state: Arc::clone(state),
     });
-    let gen = ContentGenerator::new(provider, business.clone());
+    let gen = ContentGenerator::new(provider, business.clone())
+        .with_disclosure(config.disclosure.clone());
 
     match gen.generate_thread(topic).await {
         Ok(output) => {
@@ -0,0 +1,240 @@
+//! Draft workspace tools: list, edit, check, promote, schedule, delete.
+//!
+//! Drafts are created with `compose_tweet` (no `scheduled_for`) and stay out
+//! of the approval queue until promoted.
+
+use std::time::Instant;
+
+use tuitbot_core::config::Config;
+use tuitbot_core::error::StorageError;
+use tuitbot_core::storage;
+use tuitbot_core::storage::accounts::DEFAULT_ACCOUNT_ID;
+use tuitbot_core::storage::scheduled_content::ScheduledContent;
+use tuitbot_core::storage::DbPool;
+use tuitbot_core::workflow::draft_workspace;
+use tuitbot_core::workflow::WorkflowError;
+
+use crate::contract::ErrorCode;
+use crate::tools::response::{ToolMeta, ToolResponse};
+
+/// Attach timing and workflow metadata and serialize.
+fn finish(response: ToolResponse, config: &Config, start: Instant) -> String {
+    let elapsed = start.elapsed().as_millis() as u64;
+    let meta = ToolMeta::new(elapsed)
+        .with_workflow(config.mode.to_string(), config.effective_approval_mode());
+    response.with_meta(meta).to_json()
+}
+
+fn storage_error(e: StorageError, context: &str) -> ToolResponse {
+    match e {
+        StorageError::Conflict { message } => {
+            ToolResponse::error(ErrorCode::Conflict, format!("{context}: {message}"))
+        }
+        e => ToolResponse::db_error(format!("{context}: {e}")),
+    }
+}
+
+fn workflow_error(e: WorkflowError, context: &str) -> ToolResponse {
+    match e {
+        WorkflowError::InvalidInput(msg) => ToolResponse::error(ErrorCode::InvalidInput, msg),
+        WorkflowError::Storage(e) => storage_error(e, context),
+        e => ToolResponse::db_error(format!("{context}: {e}")),
+    }
+}
+
+/// Load a draft, or build the `not_found` / `db_error` response.
+async fn load_draft(pool: &DbPool, id: i64) -> Result<ScheduledContent, ToolResponse> {
+    match storage::drafts::get_draft(pool, id).await {
+        Ok(Some(draft)) => Ok(draft),
+        Ok(None) => Err(ToolResponse::error(
+            ErrorCode::NotFound,
+            format!("Draft {id} not found."),
+        )),
+        Err(e) => Err(storage_error(e, "Error loading draft")),
+    }
+}
+
+/// List all drafts, newest first.
+pub async fn list_drafts(pool: &DbPool, config: &Config) -> String {
+    let start = Instant::now();
+    let response = match storage::scheduled_content::list_drafts(pool).await {
+        Ok(drafts) => ToolResponse::success(drafts),
+        Err(e) => storage_error(e, "Error listing drafts"),
+    };
+    finish(response, config, start)
+}
+
+/// Edit a draft's content and/or planned calendar slot.
+pub async fn edit_draft(
+    pool: &DbPool,
+    config: &Config,
+    id: i64,
+    content: Option<&str>,
+    planned_for: Option<&str>,
+) -> String {
+    let start = Instant::now();
+    let response = async {
+        if content.is_none() && planned_for.is_none() {
+            return ToolResponse::error(
+                ErrorCode::InvalidInput,
+                "Provide content, planned_for, or both.",
+            );
+        }
+        if content.is_some_and(|c| c.trim().is_empty()) {
+            return ToolResponse::error(ErrorCode::InvalidInput, "content must not be empty.");
+        }
+        if let Err(r) = load_draft(pool, id).await {
+            return r;
+        }
+        if let Some(content) = content {
+            if let Err(e) = storage::scheduled_content::update_draft(pool, id, content).await {
+                return storage_error(e, "Error updating draft");
+            }
+        }
+        if let Some(planned_for) = planned_for {
+            let planned_for = Some(planned_for).filter(|p| !p.is_empty());
+            if let Err(e) = storage::drafts::plan_draft(pool, id, planned_for).await {
+                return storage_error(e, "Error planning draft");
+            }
+        }
+        match load_draft(pool, id).await {
+            Ok(draft) => ToolResponse::success(draft),
+            Err(r) => r,
+        }
+    }
+    .await;
+    finish(response, config, start)
+}
+
+/// Run QA on a draft and store the result on it.
+pub async fn check_draft(pool: &DbPool, config: &Config, id: i64) -> String {
+    let start = Instant::now();
+    let response = match load_draft(pool, id).await {
+        Ok(draft) => {
+            match draft_workspace::check_draft(pool, DEFAULT_ACCOUNT_ID, config, &draft).await {
+                Ok(check) => ToolResponse::success(serde_json::json!({
+                    "draft_id": id,
+                    "check": check,
+                })),
+                Err(e) => workflow_error(e, "Error checking draft"),
+            }
+        }
+        Err(r) => r,
+    };
+    finish(response, config, start)
+}
+
+/// Promote a draft into the approval queue as a pending item.
+pub async fn promote_draft(pool: &DbPool, config: &Config, id: i64) -> String {
+    let start = Instant::now();
+    let response = match load_draft(pool, id).await {
+        Ok(draft) => {
+            match draft_workspace::promote_draft(pool, DEFAULT_ACCOUNT_ID, config, &draft).await {
+                Ok(promoted) => ToolResponse::success(promoted),
+                Err(e) => workflow_error(e, "Error promoting draft"),
+            }
+        }
+        Err(r) => r,
+    };
+    finish(response, config, start)
+}
+
+/// Schedule a draft for posting at `scheduled_for`.
+pub async fn schedule_draft(
+    pool: &DbPool,
+    config: &Config,
+    id: i64,
+    scheduled_for: &str,
+) -> String {
+    let start = Instant::now();
+    let response = if scheduled_for.trim().is_empty() {
+        ToolResponse::error(ErrorCode::InvalidInput, "scheduled_for must not be empty.")
+    } else {
+        match load_draft(pool, id).await {
+            Ok(_) => {
+                match storage::scheduled_content::schedule_draft(pool, id, scheduled_for).await {
+                    Ok(()) => ToolResponse::success(serde_json::json!({
+                        "draft_id": id,
+                        "status": "scheduled",
+                        "scheduled_for": scheduled_for,
+                    })),
+                    Err(e) => storage_error(e, "Error scheduling draft"),
+                }
+            }
+            Err(r) => r,
+        }
+    };
+    finish(response, config, start)
+}
+
+/// Delete (cancel) a draft.
+pub async fn delete_draft(pool: &DbPool, config: &Config, id: i64) -> String {
+    let start = Instant::now();
+    let response = match load_draft(pool, id).await {
+        Ok(_) => match storage::scheduled_content::delete_draft(pool, id).await {
+            Ok(()) => ToolResponse::success(serde_json::json!({
+                "draft_id": id,
+                "status": "cancelled",
+            })),
+            Err(e) => storage_error(e, "Error deleting draft"),
+        },
+        Err(r) => r,
+    };
+    finish(response, config, start)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tuitbot_core::storage::init_test_db;
+
+    fn parse(json: &str) -> serde_json::Value {
+        serde_json::from_str(json).expect("valid JSON")
+    }
+
+    #[tokio::test]
+    async fn edit_check_and_promote() {
+        let pool = init_test_db().await.expect("init db");
+        let config = Config::default();
+        let id = storage::scheduled_content::insert_draft(&pool, "tweet", "Draft", "mcp")
+            .await
+            .unwrap();
+
+        let edited = parse(
+            &edit_draft(
+                &pool,
+                &config,
+                id,
+                Some("Ship small PRs"),
+                Some("2026-03-02T09:00:00Z"),
+            )
+            .await,
+        );
+        assert_eq!(edited["success"], true);
+        assert_eq!(edited["data"]["content"], "Ship small PRs");
+        assert_eq!(edited["data"]["scheduled_for"], "2026-03-02T09:00:00Z");
+
+        let checked = parse(&check_draft(&pool, &config, id).await);
+        assert_eq!(checked["data"]["check"]["passed"], true);
+
+        let promoted = parse(&promote_draft(&pool, &config, id).await);
+        assert_eq!(promoted["success"], true);
+        assert!(promoted["data"]["approval_queue_id"].as_i64().unwrap() > 0);
+
+        let again = parse(&promote_draft(&pool, &config, id).await);
+        assert_eq!(again["error"]["code"], "not_found");
+    }
+
+    #[tokio::test]
+    async fn promote_failing_draft_is_invalid_input() {
+        let pool = init_test_db().await.expect("init db");
+        let config = Config::default();
+        let id = storage::scheduled_content::insert_draft(&pool, "tweet", "Check out X", "mcp")
+            .await
+            .unwrap();
+
+        let result = parse(&promote_draft(&pool, &config, id).await);
+        assert_eq!(result["success"], false);
+        assert_eq!(result["error"]["code"], "invalid_input");
+    }
+}
@@ -8,11 +8,14 @@ pub mod analytics;
 pub mod approval;
 pub mod capabilities;
 pub mod composite;
+pub mod confirm;
 pub mod content;
 pub mod context;
 pub mod discovery;
+pub mod drafts;
 pub mod health;
 pub mod mutation_audit;
+pub mod origin;
 pub mod policy_gate;
 pub mod rate_limits;
 pub mod replies;
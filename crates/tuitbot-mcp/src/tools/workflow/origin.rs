@@ -0,0 +1,93 @@
+//! Workflow origin for the tool call in progress.
+//!
+//! The invocation recorder resolves the calling agent, session, and goal for
+//! each tool call and runs the handler inside [`scope`]. Code that creates
+//! rows (gateway, compose, queue) reads it with [`current`] and links it via
+//! `storage::workflow_origins`, without threading it through every signature.
+//!
+//! Agents can set the fields explicitly in the request `_meta`
+//! (`agent`, `session_id`, `goal`); otherwise the agent defaults to the MCP
+//! client name and the session to the server process session.
+
+use std::future::Future;
+
+use serde_json::{Map, Value};
+use tuitbot_core::storage::workflow_origins::{self, WorkflowOrigin};
+use tuitbot_core::storage::DbPool;
+
+use crate::tools::response::ToolMeta;
+
+tokio::task_local! {
+    static ORIGIN: WorkflowOrigin;
+}
+
+/// Build the origin for a call from its request `_meta` and the client name.
+pub fn resolve(
+    meta: &Map<String, Value>,
+    client_name: Option<&str>,
+    default_session: &str,
+) -> WorkflowOrigin {
+    let field = |key: &str| {
+        meta.get(key)
+            .and_then(Value::as_str)
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .map(str::to_string)
+    };
+    WorkflowOrigin {
+        agent: field("agent").or_else(|| client_name.map(str::to_string)),
+        session_id: field("session_id").or_else(|| Some(default_session.to_string())),
+        goal: field("goal"),
+    }
+}
+
+/// Run `fut` with `origin` as the current workflow origin.
+pub async fn scope<F: Future>(origin: WorkflowOrigin, fut: F) -> F::Output {
+    ORIGIN.scope(origin, fut).await
+}
+
+/// The workflow origin of the tool call in progress, if any.
+pub fn current() -> Option<WorkflowOrigin> {
+    ORIGIN.try_with(Clone::clone).ok()
+}
+
+/// Echo the current origin in a response's workflow metadata.
+pub fn attach(meta: ToolMeta) -> ToolMeta {
+    match current() {
+        Some(o) => meta.with_origin(o.agent, o.session_id, o.goal),
+        None => meta,
+    }
+}
+
+/// Link the current origin to a created record (best-effort).
+pub async fn link(pool: &DbPool, record_type: &str, record_id: i64) {
+    if let Some(origin) = current() {
+        let _ = workflow_origins::record_origin(pool, record_type, record_id, &origin).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolve_prefers_meta_over_defaults() {
+        let meta = serde_json::json!({"agent": "planner", "goal": "launch week"});
+        let origin = resolve(meta.as_object().unwrap(), Some("claude-code"), "proc-1");
+        assert_eq!(origin.agent.as_deref(), Some("planner"));
+        assert_eq!(origin.session_id.as_deref(), Some("proc-1"));
+        assert_eq!(origin.goal.as_deref(), Some("launch week"));
+
+        let origin = resolve(&Map::new(), Some("claude-code"), "proc-1");
+        assert_eq!(origin.agent.as_deref(), Some("claude-code"));
+        assert!(origin.goal.is_none());
+    }
+
+    #[tokio::test]
+    async fn current_is_scoped() {
+        assert!(current().is_none());
+        let origin = resolve(&Map::new(), None, "s1");
+        let seen = scope(origin.clone(), async { current() }).await;
+        assert_eq!(seen, Some(origin));
+    }
+}
@@ -7,16 +7,21 @@
 //!   Returns either a [`MutationTicket`] to continue with, or an early-return JSON.
 //! - [`check_policy`]: Policy-only check (no idempotency/audit). Used by dry-run
 //!   validation tools that don't actually execute mutations.
+//!
+//! Gateway audit writes take a turn on the state's write queue.
 
 use std::time::Instant;
 
+use tuitbot_core::config::McpPolicyConfig;
+use tuitbot_core::mcp_policy::overrides::{self, EffectiveToolPolicy};
 use tuitbot_core::mcp_policy::PolicyDenialReason;
 use tuitbot_core::mutation_gateway::{
     DuplicateInfo, GatewayDecision, GatewayDenial, MutationGateway, MutationRequest, MutationTicket,
 };
-use tuitbot_core::storage::rate_limits;
+use tuitbot_core::storage::{draft_only, rate_limits};
 
 use crate::state::SharedState;
+use crate::tools::manifest::all_tools;
 
 use crate::tools::response::{ErrorCode, ToolMeta, ToolResponse};
 
@@ -48,15 +53,21 @@ pub async fn run_gateway(
         return GatewayResult::EarlyReturn(err);
     }
 
+    let origin = super::origin::current();
     let req = MutationRequest {
         pool: &state.pool,
         policy_config: &state.config.mcp_policy,
         mode: &state.config.mode,
         tool_name,
         params_json,
+        origin: origin.as_ref(),
     };
 
-    let decision = match MutationGateway::evaluate(&req).await {
+    let decision = match state
+        .write_queue
+        .write(MutationGateway::evaluate(&req))
+        .await
+    {
         Ok(d) => d,
         Err(e) => {
             let elapsed = start.elapsed().as_millis() as u64;
@@ -130,6 +141,38 @@ pub async fn run_gateway(
             GatewayResult::EarlyReturn(json)
         }
 
+        GatewayDecision::Staged(staged) => {
+            // Nothing executed, so confirming must not trip the in-memory dedup.
+            state.idempotency.forget(tool_name, params_json);
+            let elapsed = start.elapsed().as_millis() as u64;
+            super::telemetry::record(
+                &state.pool,
+                tool_name,
+                "mutation",
+                elapsed,
+                true,
+                None,
+                Some("staged"),
+                None,
+            )
+            .await;
+            let params = serde_json::from_str::<serde_json::Value>(&staged.params_json)
+                .unwrap_or(serde_json::Value::String(staged.params_json.clone()));
+            let json = ToolResponse::success(serde_json::json!({
+                "confirmation_required": true,
+                "confirmation_token": staged.token,
+                "expires_at": staged.expires_at,
+                "preview": {
+                    "tool": staged.tool_name,
+                    "params": params,
+                },
+                "next_step": "Call confirm_action with confirmation_token to execute.",
+            }))
+            .with_meta(ToolMeta::new(elapsed))
+            .to_json();
+            GatewayResult::EarlyReturn(json)
+        }
+
         GatewayDecision::Duplicate(info) => {
             let json = format_duplicate(&info, tool_name, start);
             GatewayResult::EarlyReturn(json)
@@ -149,15 +192,16 @@ pub async fn complete_gateway_success(
     let guidance = crate::tools::rollback::guidance_for(&ticket.tool_name, result_data);
     let rollback_json = crate::tools::rollback::guidance_to_json(&guidance);
 
-    let _ = MutationGateway::complete_success(
+    let limits = state.config.mcp_policy.effective_rate_limits();
+    let complete = MutationGateway::complete_success(
         &state.pool,
         ticket,
         &result_json,
         rollback_json.as_deref(),
         elapsed_ms,
-        &state.config.mcp_policy.rate_limits,
-    )
-    .await;
+        &limits,
+    );
+    let _ = state.write_queue.write(complete).await;
 
     let rollback_value = serde_json::to_value(&guidance).unwrap_or_default();
 
@@ -174,7 +218,8 @@ pub async fn complete_gateway_failure(
     start: Instant,
 ) -> ToolMeta {
     let elapsed_ms = start.elapsed().as_millis() as u64;
-    let _ = MutationGateway::complete_failure(&state.pool, ticket, error_msg, elapsed_ms).await;
+    let complete = MutationGateway::complete_failure(&state.pool, ticket, error_msg, elapsed_ms);
+    let _ = state.write_queue.write(complete).await;
 
     ToolMeta::new(elapsed_ms).with_correlation_id(&ticket.correlation_id)
 }
@@ -221,13 +266,14 @@ pub async fn check_policy(
 
     match decision {
         tuitbot_core::mcp_policy::PolicyDecision::Allow => GateResult::Proceed,
-        tuitbot_core::mcp_policy::PolicyDecision::Deny { reason, .. } => {
+        tuitbot_core::mcp_policy::PolicyDecision::Deny { reason, rule_id } => {
             let elapsed = start.elapsed().as_millis() as u64;
             let code = match &reason {
                 PolicyDenialReason::ToolBlocked => ErrorCode::PolicyDeniedBlocked,
                 PolicyDenialReason::RateLimited => ErrorCode::PolicyDeniedRateLimited,
                 PolicyDenialReason::HardRule => ErrorCode::PolicyDeniedHardRule,
                 PolicyDenialReason::UserRule => ErrorCode::PolicyDeniedUserRule,
+                PolicyDenialReason::KillSwitch => ErrorCode::PolicyDeniedKillSwitch,
             };
             super::telemetry::record(
                 &state.pool,
@@ -240,7 +286,7 @@ pub async fn check_policy(
                 None,
             )
             .await;
-            let json = ToolResponse::error(code, format!("Policy denied: {reason}"))
+            let json = ToolResponse::error(code, denial_message(&reason, rule_id.as_deref()))
                 .with_policy_decision("denied")
                 .with_meta(ToolMeta::new(elapsed))
                 .to_json();
@@ -293,9 +339,15 @@ pub async fn get_policy_status(state: &SharedState) -> String {
         Err(e) => serde_json::json!({"error": e.to_string()}),
     };
 
+    let draft_only = match draft_only::get_draft_only(&state.pool).await {
+        Ok(mode) => serde_json::json!(mode),
+        Err(e) => serde_json::json!({"error": e.to_string()}),
+    };
+
     let elapsed = start.elapsed().as_millis() as u64;
 
     ToolResponse::success(serde_json::json!({
+        "draft_only": draft_only,
         "enforce_for_mutations": state.config.mcp_policy.enforce_for_mutations,
         "require_approval_for": state.config.mcp_policy.require_approval_for,
         "blocked_tools": state.config.mcp_policy.blocked_tools,
@@ -306,11 +358,29 @@ pub async fn get_policy_status(state: &SharedState) -> String {
         "template": state.config.mcp_policy.template,
         "rules": state.config.mcp_policy.rules,
         "rate_limits": state.config.mcp_policy.rate_limits,
+        "tool_policies": tool_policies(&state.config.mcp_policy),
     }))
     .with_meta(ToolMeta::new(elapsed))
     .to_json()
 }
 
+/// Effective per-tool overrides for every mutation tool in the manifest,
+/// plus any configured tool the manifest doesn't know about.
+fn tool_policies(config: &McpPolicyConfig) -> Vec<EffectiveToolPolicy> {
+    let mut names: Vec<String> = all_tools()
+        .into_iter()
+        .filter(|t| t.mutation)
+        .map(|t| t.name)
+        .collect();
+    names.extend(config.tools.keys().cloned());
+    names.sort();
+    names.dedup();
+    names
+        .iter()
+        .map(|name| overrides::resolve_tool_policy(config, name))
+        .collect()
+}
+
 // ── Formatting helpers ─────────────────────────────────────────────────
 
 /// Format a policy denial into a JSON error response.
@@ -326,6 +396,7 @@ async fn format_denial(
         PolicyDenialReason::RateLimited => ErrorCode::PolicyDeniedRateLimited,
         PolicyDenialReason::HardRule => ErrorCode::PolicyDeniedHardRule,
         PolicyDenialReason::UserRule => ErrorCode::PolicyDeniedUserRule,
+        PolicyDenialReason::KillSwitch => ErrorCode::PolicyDeniedKillSwitch,
     };
     super::telemetry::record(
         &state.pool,
@@ -338,7 +409,8 @@ async fn format_denial(
         None,
     )
     .await;
-    let mut resp = ToolResponse::error(code, format!("Policy denied: {}", denial.reason))
+    let message = denial_message(&denial.reason, denial.rule_id.as_deref());
+    let mut resp = ToolResponse::error(code, message)
         .with_policy_decision("denied")
         .with_meta(ToolMeta::new(elapsed));
 
@@ -361,6 +433,19 @@ async fn format_denial(
     resp.to_json()
 }
 
+/// Human-readable denial message, naming the rule where it helps the caller.
+fn denial_message(reason: &PolicyDenialReason, rule_id: Option<&str>) -> String {
+    match (reason, rule_id) {
+        (PolicyDenialReason::KillSwitch, Some(reason)) => {
+            format!("Policy denied: kill switch engaged ({reason})")
+        }
+        (PolicyDenialReason::UserRule, Some(rule)) => {
+            format!("Policy denied: user rule {rule}")
+        }
+        _ => format!("Policy denied: {reason}"),
+    }
+}
+
 /// Format a duplicate detection into a JSON response.
 fn format_duplicate(info: &DuplicateInfo, tool_name: &str, start: Instant) -> String {
     let elapsed = start.elapsed().as_millis() as u64;
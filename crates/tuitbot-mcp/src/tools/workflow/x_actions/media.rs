@@ -4,12 +4,14 @@
 //! `tuitbot_core::toolkit::media`. File I/O, hashing, DB tracking,
 //! idempotency, and dry-run support remain here (workflow concerns).
 
+use std::sync::Mutex;
 use std::time::Instant;
 
 use serde::Serialize;
 
 use tuitbot_core::storage::media as media_storage;
 use tuitbot_core::toolkit::media as toolkit_media;
+use tuitbot_core::x_api::media::{UploadPhase, UploadProgress, CHUNK_SIZE};
 use tuitbot_core::x_api::types::MediaType;
 
 use crate::state::SharedState;
@@ -17,9 +19,6 @@ use crate::state::SharedState;
 use super::not_configured_response;
 use crate::tools::response::{ErrorCode, ToolMeta, ToolResponse};
 
-/// Chunk size constant (matches core/x_api/media.rs).
-const CHUNK_SIZE: usize = 5 * 1024 * 1024;
-
 /// Upload a media file for attachment to tweets.
 ///
 /// Tracks uploads in the `media_uploads` table for idempotent re-uploads.
@@ -75,6 +74,14 @@ pub async fn upload_media(
         }
     };
 
+    // Videos are checked against the configured duration/size limits up front.
+    if media_type == MediaType::Video {
+        let limits = toolkit_media::MediaLimits::from(&state.config.media);
+        if let Err(ref e) = toolkit_media::prepare_media_with_limits(&data, media_type, &limits) {
+            return super::toolkit_error_response(e, start);
+        }
+    }
+
     let file_size = data.len();
     let file_hash = media_storage::compute_file_hash(&data);
 
@@ -99,6 +106,7 @@ pub async fn upload_media(
                 cached: true,
                 file_hash: file_hash.clone(),
                 alt_text: existing.alt_text.clone(),
+                progress: None,
             })
             .with_meta(ToolMeta::new(elapsed))
             .to_json();
@@ -128,8 +136,41 @@ pub async fn upload_media(
     .await
     .ok();
 
-    // Upload via toolkit (includes size validation).
-    match toolkit_media::upload_media(client.as_ref(), &data, media_type).await {
+    // Progress: keep the latest snapshot for the response and mirror phase
+    // changes into the tracking row so other readers can follow along.
+    let latest: Mutex<Option<UploadProgress>> = Mutex::new(None);
+    let (phase_tx, mut phase_rx) = tokio::sync::mpsc::unbounded_channel::<UploadPhase>();
+    let status_writer = tracking_id.map(|tid| {
+        let pool = state.pool.clone();
+        tokio::spawn(async move {
+            while let Some(phase) = phase_rx.recv().await {
+                if phase == UploadPhase::Processing {
+                    let _ =
+                        media_storage::update_media_upload_status(&pool, tid, "processing").await;
+                }
+            }
+        })
+    });
+    let on_progress = |p: &UploadProgress| {
+        if let Ok(mut guard) = latest.lock() {
+            if guard.as_ref().map(|prev| prev.phase) != Some(p.phase) {
+                let _ = phase_tx.send(p.phase);
+            }
+            *guard = Some(p.clone());
+        }
+    };
+
+    // Upload via toolkit (includes size validation and segment retries).
+    let result =
+        toolkit_media::upload_media_with_progress(client.as_ref(), &data, media_type, &on_progress)
+            .await;
+    drop(phase_tx);
+    if let Some(writer) = status_writer {
+        let _ = writer.await;
+    }
+    let progress = latest.into_inner().ok().flatten();
+
+    match result {
         Ok(media_id) => {
             // Record success.
             if let Some(tid) = tracking_id {
@@ -148,6 +189,7 @@ pub async fn upload_media(
                 cached: false,
                 file_hash,
                 alt_text: alt_text.map(|s| s.to_string()),
+                progress,
             })
             .with_meta(ToolMeta::new(elapsed))
             .to_json()
@@ -261,6 +303,9 @@ struct UploadResult {
     cached: bool,
     file_hash: String,
     alt_text: Option<String>,
+    /// Final upload progress snapshot (segments sent, retries, processing).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    progress: Option<UploadProgress>,
 }
 
 #[derive(Serialize)]
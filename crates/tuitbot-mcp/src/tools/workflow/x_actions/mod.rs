@@ -1,7 +1,8 @@
 //! Direct X API tool implementations.
 //!
-//! Split into submodules by concern: read, write, engage, media, validate.
+//! Split into submodules by concern: read, write, dry_run, engage, media, validate.
 
+mod dry_run;
 mod engage;
 mod media;
 mod read;
@@ -31,6 +32,10 @@ fn toolkit_error_response(e: &tuitbot_core::toolkit::ToolkitError, start: Instan
             let code = match other {
                 ToolkitError::UnsupportedMediaType { .. } => ErrorCode::UnsupportedMediaType,
                 ToolkitError::MediaTooLarge { .. } => ErrorCode::MediaUploadError,
+                ToolkitError::UnsupportedMediaFormat { .. } => ErrorCode::UnsupportedMediaType,
+                ToolkitError::InvalidMediaDimensions { .. } | ToolkitError::VideoTooLong { .. } => {
+                    ErrorCode::MediaUploadError
+                }
                 ToolkitError::ThreadPartialFailure { .. } => ErrorCode::ThreadPartialFailure,
                 _ => ErrorCode::InvalidInput,
             };
@@ -60,6 +65,10 @@ fn format_toolkit_error_with_meta(
             let code = match other {
                 ToolkitError::UnsupportedMediaType { .. } => ErrorCode::UnsupportedMediaType,
                 ToolkitError::MediaTooLarge { .. } => ErrorCode::MediaUploadError,
+                ToolkitError::UnsupportedMediaFormat { .. } => ErrorCode::UnsupportedMediaType,
+                ToolkitError::InvalidMediaDimensions { .. } | ToolkitError::VideoTooLong { .. } => {
+                    ErrorCode::MediaUploadError
+                }
                 ToolkitError::ThreadPartialFailure { .. } => ErrorCode::ThreadPartialFailure,
                 _ => ErrorCode::InvalidInput,
             };
@@ -71,6 +80,7 @@ fn format_toolkit_error_with_meta(
 }
 
 // Re-export all public tool functions.
+pub use dry_run::{post_thread_dry_run, post_tweet_dry_run};
 pub use engage::{
     bookmark_tweet, follow_user, like_tweet, retweet, unbookmark_tweet, unfollow_user,
     unlike_tweet, unretweet,
@@ -81,10 +91,7 @@ pub use read::{
     get_tweet_by_id, get_tweet_liking_users, get_user_by_id, get_user_by_username,
     get_user_mentions, get_user_tweets, get_users_by_ids, get_x_usage, search_tweets,
 };
-pub use write::{
-    delete_tweet, post_thread, post_thread_dry_run, post_tweet, post_tweet_dry_run, quote_tweet,
-    reply_to_tweet,
-};
+pub use write::{delete_tweet, post_thread, post_tweet, quote_tweet, reply_to_tweet};
 
 /// Return an error response when the X client is not configured.
 pub(super) fn not_configured_response(start: Instant) -> String {
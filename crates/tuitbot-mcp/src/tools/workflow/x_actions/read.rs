@@ -1,11 +1,14 @@
 //! Read-only X API tools.
 //!
 //! All read tools delegate to the toolkit layer in `tuitbot-core`,
-//! except `get_x_usage` which requires DB access (workflow-only).
+//! except `get_x_usage` which requires DB access (workflow-only). Public
+//! reads go through the scraper instead when it is the selected backend.
 
 use std::time::Instant;
 
 use crate::contract::envelope::{PaginationInfo, ToolMeta, ToolResponse};
+use crate::kernel;
+use crate::provider::annotate_public_read;
 use crate::state::SharedState;
 use tuitbot_core::x_api::types::{SearchMeta, UsersMeta};
 
@@ -30,6 +33,10 @@ fn pagination_from_users(meta: &UsersMeta) -> PaginationInfo {
 /// Get a single tweet by ID — delegates to toolkit.
 pub async fn get_tweet_by_id(state: &SharedState, tweet_id: &str) -> String {
     let start = Instant::now();
+    if let Some(scraper) = &state.scraper {
+        let result = kernel::read::get_tweet(scraper, tweet_id).await;
+        return annotate_public_read(Some(scraper), result);
+    }
     let client = match state.x_client.as_ref() {
         Some(c) => c.as_ref(),
         None => return not_configured_response(start),
@@ -48,6 +55,10 @@ pub async fn get_tweet_by_id(state: &SharedState, tweet_id: &str) -> String {
 /// Look up a user by username — delegates to toolkit.
 pub async fn get_user_by_username(state: &SharedState, username: &str) -> String {
     let start = Instant::now();
+    if let Some(scraper) = &state.scraper {
+        let result = kernel::read::get_user_by_username(scraper, username).await;
+        return annotate_public_read(Some(scraper), result);
+    }
     let client = match state.x_client.as_ref() {
         Some(c) => c.as_ref(),
         None => return not_configured_response(start),
@@ -72,6 +83,12 @@ pub async fn search_tweets(
     pagination_token: Option<&str>,
 ) -> String {
     let start = Instant::now();
+    if let Some(scraper) = &state.scraper {
+        let result =
+            kernel::read::search_tweets(scraper, query, max_results, since_id, pagination_token)
+                .await;
+        return annotate_public_read(Some(scraper), result);
+    }
     let client = match state.x_client.as_ref() {
         Some(c) => c.as_ref(),
         None => return not_configured_response(start),
@@ -133,6 +150,11 @@ pub async fn get_user_tweets(
     pagination_token: Option<&str>,
 ) -> String {
     let start = Instant::now();
+    if let Some(scraper) = &state.scraper {
+        let result =
+            kernel::read::get_user_tweets(scraper, user_id, max_results, pagination_token).await;
+        return annotate_public_read(Some(scraper), result);
+    }
     let client = match state.x_client.as_ref() {
         Some(c) => c.as_ref(),
         None => return not_configured_response(start),
@@ -245,6 +267,10 @@ pub async fn get_following(
 /// Get a user by their ID — delegates to toolkit.
 pub async fn get_user_by_id(state: &SharedState, user_id: &str) -> String {
     let start = Instant::now();
+    if let Some(scraper) = &state.scraper {
+        let result = kernel::read::get_user_by_id(scraper, user_id).await;
+        return annotate_public_read(Some(scraper), result);
+    }
     let client = match state.x_client.as_ref() {
         Some(c) => c.as_ref(),
         None => return not_configured_response(start),
@@ -416,11 +442,17 @@ pub async fn get_x_usage(state: &SharedState, days: u32) -> String {
             }
         };
 
+    // Reconciliation history is informational; a failed read leaves it empty.
+    let reconciliations = tuitbot_core::storage::usage_reconciliation::list_recent(&state.pool, 5)
+        .await
+        .unwrap_or_default();
+
     let elapsed = start.elapsed().as_millis() as u64;
     ToolResponse::success(serde_json::json!({
         "summary": summary,
         "daily": daily,
         "endpoints": endpoints,
+        "reconciliations": reconciliations,
     }))
     .with_meta(ToolMeta::new(elapsed))
     .to_json()
@@ -138,3 +138,69 @@ async fn delete_tweet_always_requires_approval() {
     assert_eq!(parsed["success"], true);
     assert_eq!(parsed["data"]["routed_to_approval"], true);
 }
+
+// ── Confirmation mode (two-phase commit) ────────────────────────────
+
+#[tokio::test]
+async fn confirmation_mode_stages_then_confirm_executes() {
+    let mut config = allowed_config();
+    config.mcp_policy.confirmation_mode = true;
+    let state =
+        make_state_with_config(Some(Box::new(MockXApiClient)), Some("u1".into()), config).await;
+
+    let staged: serde_json::Value =
+        serde_json::from_str(&like_tweet(&state, "t1").await).expect("valid JSON");
+    assert_eq!(staged["success"], true);
+    assert_eq!(staged["data"]["confirmation_required"], true);
+    assert_eq!(staged["data"]["preview"]["tool"], "like_tweet");
+    assert_eq!(staged["data"]["preview"]["params"]["tweet_id"], "t1");
+    let token = staged["data"]["confirmation_token"]
+        .as_str()
+        .expect("token")
+        .to_string();
+
+    let confirm = crate::tools::workflow::confirm::confirm_action;
+    let executed: serde_json::Value =
+        serde_json::from_str(&confirm(&state, &token).await).expect("valid JSON");
+    assert_eq!(executed["success"], true);
+    assert_eq!(executed["data"]["liked"], true);
+
+    // Tokens are single-use.
+    let again: serde_json::Value =
+        serde_json::from_str(&confirm(&state, &token).await).expect("valid JSON");
+    assert_eq!(again["success"], false);
+    assert_eq!(again["error"]["code"], "invalid_input");
+
+    let unknown: serde_json::Value =
+        serde_json::from_str(&confirm(&state, "nope").await).expect("valid JSON");
+    assert_eq!(unknown["error"]["code"], "not_found");
+}
+
+#[tokio::test]
+async fn approval_item_records_workflow_origin() {
+    use crate::tools::workflow::origin;
+    use tuitbot_core::storage::workflow_origins;
+
+    let state = make_state_with_config(
+        Some(Box::new(MockXApiClient)),
+        Some("u1".into()),
+        approval_config(),
+    )
+    .await;
+    let meta = serde_json::json!({"agent": "planner", "goal": "launch week"});
+    let call_origin = origin::resolve(meta.as_object().unwrap(), None, "session-7");
+    let result = origin::scope(call_origin, post_tweet(&state, "Hello!", None)).await;
+    let parsed: serde_json::Value = serde_json::from_str(&result).expect("valid JSON");
+    let queue_id = parsed["data"]["approval_queue_id"]
+        .as_i64()
+        .expect("queued");
+
+    let linked =
+        workflow_origins::get_origin(&state.pool, workflow_origins::APPROVAL_QUEUE, queue_id)
+            .await
+            .expect("get origin")
+            .expect("origin linked");
+    assert_eq!(linked.agent.as_deref(), Some("planner"));
+    assert_eq!(linked.session_id.as_deref(), Some("session-7"));
+    assert_eq!(linked.goal.as_deref(), Some("launch week"));
+}
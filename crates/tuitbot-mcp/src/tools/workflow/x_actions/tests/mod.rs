@@ -11,6 +11,7 @@ use std::sync::Arc;
 use tuitbot_core::config::{Config, McpPolicyConfig};
 use tuitbot_core::error::XApiError;
 use tuitbot_core::storage;
+use tuitbot_core::storage::write_queue::WriteQueue;
 use tuitbot_core::x_api::types::*;
 use tuitbot_core::x_api::XApiClient;
 
@@ -40,6 +41,8 @@ impl XApiClient for MockXApiClient {
                 created_at: String::new(),
                 public_metrics: PublicMetrics::default(),
                 conversation_id: None,
+                entities: None,
+                attachments: None,
             }],
             includes: None,
             meta: SearchMeta {
@@ -95,6 +98,8 @@ impl XApiClient for MockXApiClient {
             created_at: "2026-02-24T00:00:00Z".to_string(),
             public_metrics: PublicMetrics::default(),
             conversation_id: None,
+            entities: None,
+            attachments: None,
         })
     }
 
@@ -187,6 +192,8 @@ impl XApiClient for MockXApiClient {
                 created_at: String::new(),
                 public_metrics: PublicMetrics::default(),
                 conversation_id: None,
+                entities: None,
+                attachments: None,
             }],
             includes: None,
             meta: SearchMeta {
@@ -273,6 +280,8 @@ impl XApiClient for MockXApiClient {
                 created_at: String::new(),
                 public_metrics: PublicMetrics::default(),
                 conversation_id: None,
+                entities: None,
+                attachments: None,
             }],
             includes: None,
             meta: SearchMeta {
@@ -298,6 +307,8 @@ impl XApiClient for MockXApiClient {
                 created_at: String::new(),
                 public_metrics: PublicMetrics::default(),
                 conversation_id: None,
+                entities: None,
+                attachments: None,
             }],
             includes: None,
             meta: SearchMeta {
@@ -424,6 +435,7 @@ async fn make_state(x_client: Option<Box<dyn XApiClient>>, user_id: Option<Strin
     config.mcp_policy.enforce_for_mutations = false;
     let pool = storage::init_test_db().await.expect("init db");
     Arc::new(AppState {
+        write_queue: WriteQueue::spawn(pool.clone()),
         pool,
         config,
         llm_provider: None,
@@ -431,6 +443,7 @@ async fn make_state(x_client: Option<Box<dyn XApiClient>>, user_id: Option<Strin
         authenticated_user_id: user_id,
         granted_scopes: vec![],
         idempotency: Arc::new(IdempotencyStore::new()),
+        scraper: None,
     })
 }
 
@@ -447,6 +460,7 @@ async fn make_state_with_config(
     .await
     .expect("init mcp rate limit");
     Arc::new(AppState {
+        write_queue: WriteQueue::spawn(pool.clone()),
         pool,
         config,
         llm_provider: None,
@@ -454,61 +468,66 @@ async fn make_state_with_config(
         authenticated_user_id: user_id,
         granted_scopes: vec![],
         idempotency: Arc::new(IdempotencyStore::new()),
+        scraper: None,
     })
 }
 
 // ── Policy config helpers ───────────────────────────────────────────
 
 fn blocked_config() -> Config {
-    let mut config = Config::default();
-    config.mcp_policy = McpPolicyConfig {
-        enforce_for_mutations: true,
-        blocked_tools: vec!["post_tweet".to_string()],
-        require_approval_for: Vec::new(),
-        dry_run_mutations: false,
-        max_mutations_per_hour: 20,
-        ..McpPolicyConfig::default()
-    };
-    config
+    Config {
+        mcp_policy: McpPolicyConfig {
+            enforce_for_mutations: true,
+            blocked_tools: vec!["post_tweet".to_string()],
+            require_approval_for: Vec::new(),
+            dry_run_mutations: false,
+            max_mutations_per_hour: 20,
+            ..McpPolicyConfig::default()
+        },
+        ..Config::default()
+    }
 }
 
 fn approval_config() -> Config {
-    let mut config = Config::default();
-    config.mcp_policy = McpPolicyConfig {
-        enforce_for_mutations: true,
-        require_approval_for: vec!["post_tweet".to_string()],
-        blocked_tools: Vec::new(),
-        dry_run_mutations: false,
-        max_mutations_per_hour: 20,
-        ..McpPolicyConfig::default()
-    };
-    config
+    Config {
+        mcp_policy: McpPolicyConfig {
+            enforce_for_mutations: true,
+            require_approval_for: vec!["post_tweet".to_string()],
+            blocked_tools: Vec::new(),
+            dry_run_mutations: false,
+            max_mutations_per_hour: 20,
+            ..McpPolicyConfig::default()
+        },
+        ..Config::default()
+    }
 }
 
 fn dry_run_config() -> Config {
-    let mut config = Config::default();
-    config.mcp_policy = McpPolicyConfig {
-        enforce_for_mutations: true,
-        require_approval_for: Vec::new(),
-        blocked_tools: Vec::new(),
-        dry_run_mutations: true,
-        max_mutations_per_hour: 20,
-        ..McpPolicyConfig::default()
-    };
-    config
+    Config {
+        mcp_policy: McpPolicyConfig {
+            enforce_for_mutations: true,
+            require_approval_for: Vec::new(),
+            blocked_tools: Vec::new(),
+            dry_run_mutations: true,
+            max_mutations_per_hour: 20,
+            ..McpPolicyConfig::default()
+        },
+        ..Config::default()
+    }
 }
 
 fn allowed_config() -> Config {
-    let mut config = Config::default();
-    config.mcp_policy = McpPolicyConfig {
-        enforce_for_mutations: true,
-        require_approval_for: Vec::new(),
-        blocked_tools: Vec::new(),
-        dry_run_mutations: false,
-        max_mutations_per_hour: 20,
-        ..McpPolicyConfig::default()
-    };
-    config
+    Config {
+        mcp_policy: McpPolicyConfig {
+            enforce_for_mutations: true,
+            require_approval_for: Vec::new(),
+            blocked_tools: Vec::new(),
+            dry_run_mutations: false,
+            max_mutations_per_hour: 20,
+            ..McpPolicyConfig::default()
+        },
+        ..Config::default()
+    }
 }
 
 fn composer_config() -> Config {
@@ -46,6 +46,7 @@ async fn get_x_usage_success() {
     let parsed: serde_json::Value = serde_json::from_str(&result).expect("valid JSON");
     assert_eq!(parsed["success"], true);
     assert!(parsed["data"]["summary"].is_object());
+    assert!(parsed["data"]["reconciliations"].is_array());
 }
 
 #[tokio::test]
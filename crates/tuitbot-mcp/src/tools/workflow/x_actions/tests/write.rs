@@ -261,6 +261,21 @@ async fn post_thread_dry_run_no_x_client() {
     assert_eq!(parsed["data"]["x_client_available"], false);
 }
 
+#[tokio::test]
+async fn post_thread_dry_run_applies_thread_format() {
+    let mut config = Config::default();
+    config.mcp_policy.enforce_for_mutations = false;
+    config.thread_format.numbering = tuitbot_core::config::ThreadNumbering::Prefix;
+    config.thread_format.end_cta = Some("Follow for more".to_string());
+    let state = make_state_with_config(None, None, config).await;
+    let tweets = vec!["Hook".to_string(), "Body".to_string()];
+    let result = post_thread_dry_run(&state, &tweets, None).await;
+    let parsed: serde_json::Value = serde_json::from_str(&result).expect("valid JSON");
+    assert_eq!(parsed["data"]["tweet_count"], 3);
+    assert_eq!(parsed["data"]["tweets"][0]["text"], "1/3 Hook");
+    assert_eq!(parsed["data"]["tweets"][2]["text"], "3/3 Follow for more");
+}
+
 // ── Policy dry-run test ─────────────────────────────────────────────
 
 #[tokio::test]
@@ -3,11 +3,21 @@
 //! All raw X API calls go through `tuitbot_core::toolkit::write`.
 //! All mutation governance (policy, idempotency, audit, rate recording) goes
 //! through the unified gateway in `policy_gate::run_gateway`.
+//! Tweets and replies are published via `workflow::idempotent_post`, which
+//! checks the timeline before re-sending a post whose outcome was unknown.
+//! Posts are refused while another process holds the posting lock.
 
 use std::time::Instant;
 
 use serde::Serialize;
 
+use tuitbot_core::automation::{post_exclusively, PostingLockError};
+use tuitbot_core::mutation_gateway::MutationTicket;
+use tuitbot_core::workflow::idempotent_post::{self, OutboundPost};
+use tuitbot_core::workflow::WorkflowError;
+use tuitbot_core::x_api::types::PostedTweet;
+use tuitbot_core::x_api::XApiClient;
+
 use crate::state::SharedState;
 
 use super::not_configured_response;
@@ -17,6 +27,76 @@ use crate::tools::workflow::policy_gate::{
     complete_gateway_failure, complete_gateway_success, run_gateway, GatewayResult,
 };
 
+/// Gateway params for a write tool: the full arguments, so a staged call can
+/// be replayed by `confirm_action`. `media_ids` is omitted when absent.
+fn gateway_params(mut params: serde_json::Value, media_ids: Option<&[String]>) -> String {
+    if let Some(ids) = media_ids {
+        params["media_ids"] = serde_json::json!(ids);
+    }
+    params.to_string()
+}
+
+/// Post or reply through the idempotent publish step, so an agent retrying
+/// after a timeout does not publish the same text twice.
+async fn publish_once(
+    state: &SharedState,
+    client: &dyn XApiClient,
+    text: &str,
+    in_reply_to_id: Option<&str>,
+    media_ids: Option<&[String]>,
+) -> Result<PostedTweet, WorkflowError> {
+    let post = OutboundPost {
+        scope: "mcp",
+        text,
+        in_reply_to_id,
+        quote_tweet_id: None,
+        media_ids,
+    };
+    let out = idempotent_post::post_once(
+        &state.pool,
+        client,
+        state.authenticated_user_id.as_deref(),
+        post,
+    )
+    .await?;
+    Ok(PostedTweet {
+        id: out.tweet_id,
+        text: text.to_string(),
+    })
+}
+
+/// Record a failed publish in the audit trail and build the error response.
+async fn publish_error_response(
+    state: &SharedState,
+    ticket: &MutationTicket,
+    e: &WorkflowError,
+    start: Instant,
+) -> String {
+    let msg = e.to_string();
+    let meta = complete_gateway_failure(state, ticket, &msg, start).await;
+    match e {
+        WorkflowError::Toolkit(te) => super::format_toolkit_error_with_meta(te, meta),
+        _ => ToolResponse::db_error(msg).with_meta(meta).to_json(),
+    }
+}
+
+/// Record a post refused by the cross-process posting lock and build the
+/// error response.
+async fn posting_lock_error_response(
+    state: &SharedState,
+    ticket: &MutationTicket,
+    e: &PostingLockError,
+    start: Instant,
+) -> String {
+    let msg = e.to_string();
+    let meta = complete_gateway_failure(state, ticket, &msg, start).await;
+    let code = match e {
+        PostingLockError::Held(_) => ErrorCode::Conflict,
+        PostingLockError::Storage(_) => ErrorCode::DbError,
+    };
+    ToolResponse::error(code, msg).with_meta(meta).to_json()
+}
+
 /// Post a new tweet, optionally with media.
 pub async fn post_tweet(state: &SharedState, text: &str, media_ids: Option<&[String]>) -> String {
     let start = Instant::now();
@@ -26,7 +106,7 @@ pub async fn post_tweet(state: &SharedState, text: &str, media_ids: Option<&[Str
     if let Some(err) = super::scraper_mutation_guard(state, start) {
         return err;
     }
-    let params = serde_json::json!({"text": text}).to_string();
+    let params = gateway_params(serde_json::json!({"text": text}), media_ids);
     let ticket = match run_gateway(state, "post_tweet", &params, start).await {
         GatewayResult::Proceed(t) => t,
         GatewayResult::EarlyReturn(r) => return r,
@@ -36,17 +116,15 @@ pub async fn post_tweet(state: &SharedState, text: &str, media_ids: Option<&[Str
         None => return not_configured_response(start),
     };
 
-    match tuitbot_core::toolkit::write::post_tweet(client.as_ref(), text, media_ids).await {
-        Ok(tweet) => {
+    let publish = publish_once(state, client.as_ref(), text, None, media_ids);
+    match post_exclusively(&state.pool, publish).await {
+        Ok(Ok(tweet)) => {
             let result_data = serde_json::to_value(&tweet).unwrap_or_default();
             let meta = complete_gateway_success(state, &ticket, &result_data, start).await;
             ToolResponse::success(&tweet).with_meta(meta).to_json()
         }
-        Err(ref e) => {
-            let msg = e.to_string();
-            let meta = complete_gateway_failure(state, &ticket, &msg, start).await;
-            super::format_toolkit_error_with_meta(e, meta)
-        }
+        Ok(Err(ref e)) => publish_error_response(state, &ticket, e, start).await,
+        Err(ref e) => posting_lock_error_response(state, &ticket, e, start).await,
     }
 }
 
@@ -64,7 +142,10 @@ pub async fn reply_to_tweet(
     if let Some(err) = super::scraper_mutation_guard(state, start) {
         return err;
     }
-    let params = serde_json::json!({"text": text, "in_reply_to_id": in_reply_to_id}).to_string();
+    let params = gateway_params(
+        serde_json::json!({"text": text, "in_reply_to_id": in_reply_to_id}),
+        media_ids,
+    );
     let ticket = match run_gateway(state, "reply_to_tweet", &params, start).await {
         GatewayResult::Proceed(t) => t,
         GatewayResult::EarlyReturn(r) => return r,
@@ -74,24 +155,21 @@ pub async fn reply_to_tweet(
         None => return not_configured_response(start),
     };
 
-    match tuitbot_core::toolkit::write::reply_to_tweet(
+    let publish = publish_once(
+        state,
         client.as_ref(),
         text,
-        in_reply_to_id,
+        Some(in_reply_to_id),
         media_ids,
-    )
-    .await
-    {
-        Ok(tweet) => {
+    );
+    match post_exclusively(&state.pool, publish).await {
+        Ok(Ok(tweet)) => {
             let result_data = serde_json::to_value(&tweet).unwrap_or_default();
             let meta = complete_gateway_success(state, &ticket, &result_data, start).await;
             ToolResponse::success(&tweet).with_meta(meta).to_json()
         }
-        Err(ref e) => {
-            let msg = e.to_string();
-            let meta = complete_gateway_failure(state, &ticket, &msg, start).await;
-            super::format_toolkit_error_with_meta(e, meta)
-        }
+        Ok(Err(ref e)) => publish_error_response(state, &ticket, e, start).await,
+        Err(ref e) => posting_lock_error_response(state, &ticket, e, start).await,
     }
 }
 
@@ -109,7 +187,10 @@ pub async fn quote_tweet(
     if let Some(err) = super::scraper_mutation_guard(state, start) {
         return err;
     }
-    let params = serde_json::json!({"text": text, "quoted_tweet_id": quoted_tweet_id}).to_string();
+    let params = gateway_params(
+        serde_json::json!({"text": text, "quoted_tweet_id": quoted_tweet_id}),
+        media_ids,
+    );
     let ticket = match run_gateway(state, "quote_tweet", &params, start).await {
         GatewayResult::Proceed(t) => t,
         GatewayResult::EarlyReturn(r) => return r,
@@ -122,17 +203,19 @@ pub async fn quote_tweet(
     // media_ids not forwarded: quote_tweet trait method has no media variant.
     let _ = media_ids;
 
-    match tuitbot_core::toolkit::write::quote_tweet(client.as_ref(), text, quoted_tweet_id).await {
-        Ok(tweet) => {
+    let publish = tuitbot_core::toolkit::write::quote_tweet(client.as_ref(), text, quoted_tweet_id);
+    match post_exclusively(&state.pool, publish).await {
+        Ok(Ok(tweet)) => {
             let result_data = serde_json::to_value(&tweet).unwrap_or_default();
             let meta = complete_gateway_success(state, &ticket, &result_data, start).await;
             ToolResponse::success(&tweet).with_meta(meta).to_json()
         }
-        Err(ref e) => {
+        Ok(Err(ref e)) => {
             let msg = e.to_string();
             let meta = complete_gateway_failure(state, &ticket, &msg, start).await;
             super::format_toolkit_error_with_meta(e, meta)
         }
+        Err(ref e) => posting_lock_error_response(state, &ticket, e, start).await,
     }
 }
 
@@ -175,10 +258,31 @@ pub async fn delete_tweet(state: &SharedState, tweet_id: &str) -> String {
     }
 }
 
+/// Apply the configured numbering, closing CTA, and hook re-quote.
+///
+/// Returns a `tweet_too_long` response naming the segment that no longer fits.
+pub(super) fn apply_thread_format(
+    state: &SharedState,
+    tweets: &[String],
+    start: Instant,
+) -> Result<Vec<String>, String> {
+    tuitbot_core::content::format_thread(&state.config.thread_format, tweets).map_err(|e| {
+        let elapsed = start.elapsed().as_millis() as u64;
+        let err_json = ToolResponse::error(ErrorCode::TweetTooLong, e.to_string())
+            .with_meta(ToolMeta::new(elapsed))
+            .to_json();
+        let mut parsed: serde_json::Value = serde_json::from_str(&err_json).unwrap_or_default();
+        if let Some(err_obj) = parsed.get_mut("error") {
+            err_obj["tweet_index"] = serde_json::json!(e.index);
+        }
+        serde_json::to_string(&parsed).unwrap_or(err_json)
+    })
+}
+
 /// Post a thread (ordered sequence of tweets).
 ///
-/// Validates ALL tweet lengths up front. Uses a single policy gate for the
-/// whole thread. Delegates to toolkit for the actual posting loop.
+/// Applies the configured thread formatting, then validates ALL tweet lengths
+/// up front. Uses a single policy gate for the whole thread. Delegates to toolkit for the actual posting loop.
 pub async fn post_thread(
     state: &SharedState,
     tweets: &[String],
@@ -196,6 +300,12 @@ pub async fn post_thread(
         .to_json();
     }
 
+    let tweets = match apply_thread_format(state, tweets, start) {
+        Ok(t) => t,
+        Err(e) => return e,
+    };
+    let tweets = tweets.as_slice();
+
     // Validate all tweet lengths up front (fast-fail before gateway).
     for (i, tweet_text) in tweets.iter().enumerate() {
         if let Some(err_json) = check_tweet_length(tweet_text, start) {
@@ -211,8 +321,11 @@ pub async fn post_thread(
         return err;
     }
 
-    let params =
-        serde_json::json!({"tweet_count": tweets.len(), "first_tweet": tweets[0]}).to_string();
+    let mut params = serde_json::json!({"tweets": tweets});
+    if let Some(ids) = media_ids {
+        params["media_ids"] = serde_json::json!(ids);
+    }
+    let params = params.to_string();
     let ticket = match run_gateway(state, "post_thread", &params, start).await {
         GatewayResult::Proceed(t) => t,
         GatewayResult::EarlyReturn(r) => return r,
@@ -223,8 +336,9 @@ pub async fn post_thread(
         None => return not_configured_response(start),
     };
 
-    match tuitbot_core::toolkit::write::post_thread(client.as_ref(), tweets, media_ids).await {
-        Ok(posted_ids) => {
+    let publish = tuitbot_core::toolkit::write::post_thread(client.as_ref(), tweets, media_ids);
+    match post_exclusively(&state.pool, publish).await {
+        Ok(Ok(posted_ids)) => {
             #[derive(Serialize)]
             struct ThreadResult {
                 thread_tweet_ids: Vec<String>,
@@ -241,11 +355,11 @@ pub async fn post_thread(
             let meta = complete_gateway_success(state, &ticket, &result_data, start).await;
             ToolResponse::success(result).with_meta(meta).to_json()
         }
-        Err(tuitbot_core::toolkit::ToolkitError::ThreadPartialFailure {
+        Ok(Err(tuitbot_core::toolkit::ToolkitError::ThreadPartialFailure {
             ref posted_ids,
             failed_index,
             ..
-        }) => {
+        })) => {
             let error_msg = format!(
                 "Thread failed at tweet {failed_index}. Posted {}/{} tweets. IDs: {posted_ids:?}",
                 posted_ids.len(),
@@ -267,159 +381,11 @@ pub async fn post_thread(
             });
             resp.to_json()
         }
-        Err(ref e) => {
+        Ok(Err(ref e)) => {
             let msg = e.to_string();
             let meta = complete_gateway_failure(state, &ticket, &msg, start).await;
             super::format_toolkit_error_with_meta(e, meta)
         }
+        Err(ref e) => posting_lock_error_response(state, &ticket, e, start).await,
     }
 }
-
-// ── Dry-run validation tools ──────────────────────────────────────────
-
-/// Validate a tweet without posting. Runs all checks (length, policy) but
-/// never calls the X API. Returns what *would* be posted.
-pub async fn post_tweet_dry_run(
-    state: &SharedState,
-    text: &str,
-    media_ids: Option<&[String]>,
-) -> String {
-    let start = Instant::now();
-
-    if let Some(err) = check_tweet_length(text, start) {
-        return err;
-    }
-
-    let params = serde_json::json!({"text": text}).to_string();
-    let policy_would_allow =
-        match super::super::policy_gate::check_policy(state, "post_tweet", &params, start).await {
-            super::super::policy_gate::GateResult::Proceed => true,
-            super::super::policy_gate::GateResult::EarlyReturn(_) => false,
-        };
-
-    let has_media = media_ids.is_some_and(|ids| !ids.is_empty());
-    let media_count = media_ids.map_or(0, |ids| ids.len());
-    let x_available = state.x_client.is_some();
-
-    let elapsed = start.elapsed().as_millis() as u64;
-    #[derive(Serialize)]
-    struct DryRunTweetResult {
-        dry_run: bool,
-        valid: bool,
-        text: String,
-        text_length: usize,
-        has_media: bool,
-        media_count: usize,
-        media_ids: Vec<String>,
-        policy_would_allow: bool,
-        x_client_available: bool,
-    }
-    ToolResponse::success(DryRunTweetResult {
-        dry_run: true,
-        valid: true,
-        text: text.to_string(),
-        text_length: text.len(),
-        has_media,
-        media_count,
-        media_ids: media_ids.map(|ids| ids.to_vec()).unwrap_or_default(),
-        policy_would_allow,
-        x_client_available: x_available,
-    })
-    .with_meta(ToolMeta::new(elapsed))
-    .to_json()
-}
-
-/// Validate a thread without posting. Runs all checks (lengths, policy) but
-/// never calls the X API. Returns what *would* be posted with reply chain plan.
-pub async fn post_thread_dry_run(
-    state: &SharedState,
-    tweets: &[String],
-    media_ids: Option<&[Vec<String>]>,
-) -> String {
-    let start = Instant::now();
-
-    if tweets.is_empty() {
-        let elapsed = start.elapsed().as_millis() as u64;
-        return ToolResponse::error(
-            ErrorCode::InvalidInput,
-            "Thread must contain at least one tweet.",
-        )
-        .with_meta(ToolMeta::new(elapsed))
-        .to_json();
-    }
-
-    let mut validation_results: Vec<TweetValidation> = Vec::with_capacity(tweets.len());
-
-    for (i, tweet_text) in tweets.iter().enumerate() {
-        let tweet_media = media_ids
-            .and_then(|m| m.get(i))
-            .cloned()
-            .unwrap_or_default();
-
-        if let Some(err_json) = check_tweet_length(tweet_text, start) {
-            let mut parsed: serde_json::Value = serde_json::from_str(&err_json).unwrap_or_default();
-            if let Some(err_obj) = parsed.get_mut("error") {
-                err_obj["tweet_index"] = serde_json::json!(i);
-            }
-            return serde_json::to_string(&parsed).unwrap_or(err_json);
-        }
-
-        let chain_action = if i == 0 {
-            "post_tweet".to_string()
-        } else {
-            format!("reply_to_tweet(parent=tweet_{})", i - 1)
-        };
-
-        validation_results.push(TweetValidation {
-            index: i,
-            text: tweet_text.clone(),
-            text_length: tweet_text.len(),
-            valid: true,
-            has_media: !tweet_media.is_empty(),
-            media_ids: tweet_media,
-            chain_action,
-        });
-    }
-
-    let params =
-        serde_json::json!({"tweet_count": tweets.len(), "first_tweet": tweets[0]}).to_string();
-    let policy_would_allow =
-        match super::super::policy_gate::check_policy(state, "post_thread", &params, start).await {
-            super::super::policy_gate::GateResult::Proceed => true,
-            super::super::policy_gate::GateResult::EarlyReturn(_) => false,
-        };
-
-    let x_available = state.x_client.is_some();
-    let elapsed = start.elapsed().as_millis() as u64;
-
-    #[derive(Serialize)]
-    struct DryRunThreadResult {
-        dry_run: bool,
-        valid: bool,
-        tweet_count: usize,
-        tweets: Vec<TweetValidation>,
-        policy_would_allow: bool,
-        x_client_available: bool,
-    }
-    ToolResponse::success(DryRunThreadResult {
-        dry_run: true,
-        valid: true,
-        tweet_count: tweets.len(),
-        tweets: validation_results,
-        policy_would_allow,
-        x_client_available: x_available,
-    })
-    .with_meta(ToolMeta::new(elapsed))
-    .to_json()
-}
-
-#[derive(Serialize)]
-struct TweetValidation {
-    index: usize,
-    text: String,
-    text_length: usize,
-    valid: bool,
-    has_media: bool,
-    media_ids: Vec<String>,
-    chain_action: String,
-}
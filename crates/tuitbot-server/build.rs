@@ -2,6 +2,8 @@ use std::path::Path;
 use std::{env, fs, process::Command};
 
 fn main() {
+    compile_protos();
+
     let out_dir = Path::new("dashboard-dist");
     let index = out_dir.join("index.html");
 
@@ -79,6 +81,19 @@ fn main() {
     }
 }
 
+/// Generate the gRPC service from `proto/`. Uses protox, so no `protoc`
+/// install is needed.
+fn compile_protos() {
+    println!("cargo:rerun-if-changed=proto/");
+    let descriptors = protox::compile(["tuitbot/v1/tuitbot.proto"], ["proto"])
+        .expect("failed to parse proto files");
+    tonic_prost_build::configure()
+        .type_attribute(".", "#[derive(serde::Serialize, serde::Deserialize)]")
+        .type_attribute(".", "#[serde(default)]")
+        .compile_fds(descriptors)
+        .expect("failed to generate gRPC code");
+}
+
 fn write_placeholder(index: &Path, out_dir: &Path) {
     fs::create_dir_all(out_dir).expect("failed to create dashboard-dist/");
     fs::write(
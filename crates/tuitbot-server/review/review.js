@@ -0,0 +1,200 @@
+// Keyboard-first approval review. Talks to the REST API with the session
+// cookie from the passphrase login, sending the CSRF token on writes.
+'use strict';
+
+const state = { items: [], selected: 0, editing: false, csrf: null };
+
+const $ = (id) => document.getElementById(id);
+
+async function api(method, path, body) {
+  const headers = { 'Content-Type': 'application/json' };
+  if (state.csrf && method !== 'GET') headers['X-CSRF-Token'] = state.csrf;
+  const res = await fetch('/api' + path, {
+    method,
+    headers,
+    credentials: 'same-origin',
+    body: body === undefined ? undefined : JSON.stringify(body),
+  });
+  const data = await res.json().catch(() => ({}));
+  if (!res.ok) throw new Error(data.error || res.statusText);
+  return data;
+}
+
+function toast(message) {
+  const el = $('toast');
+  el.textContent = message;
+  el.classList.add('visible');
+  clearTimeout(toast.timer);
+  toast.timer = setTimeout(() => el.classList.remove('visible'), 2500);
+}
+
+async function start() {
+  const status = await api('GET', '/auth/status');
+  if (!status.authenticated) {
+    $('login').hidden = false;
+    $('passphrase').focus();
+    return;
+  }
+  state.csrf = status.csrf_token || null;
+  $('login').hidden = true;
+  await refresh();
+}
+
+async function refresh() {
+  const current = state.items[state.selected];
+  state.items = await api('GET', '/approval?status=pending');
+  const kept = current ? state.items.findIndex((i) => i.id === current.id) : -1;
+  state.selected = Math.max(0, Math.min(kept >= 0 ? kept : state.selected, state.items.length - 1));
+  state.editing = false;
+  render();
+}
+
+function render() {
+  const list = $('items');
+  list.replaceChildren();
+  state.items.forEach((item, index) => {
+    const li = document.createElement('li');
+    if (index === state.selected) li.classList.add('selected');
+    if (item.status !== 'pending') li.classList.add(item.status);
+
+    const meta = document.createElement('div');
+    meta.className = 'meta';
+    const target = item.target_author ? ` → @${item.target_author}` : '';
+    meta.textContent = `#${item.id} · ${item.action_type}${target} · score ${Math.round(item.score)}`;
+    li.append(meta);
+
+    if (state.editing && index === state.selected) {
+      const area = document.createElement('textarea');
+      area.id = 'editor';
+      area.value = item.generated_content;
+      li.append(area);
+    } else {
+      const content = document.createElement('div');
+      content.className = 'content';
+      content.textContent = item.generated_content || '(no content)';
+      li.append(content);
+    }
+
+    if (Array.isArray(item.detected_risks) && item.detected_risks.length > 0) {
+      const risks = document.createElement('div');
+      risks.className = 'risks';
+      risks.textContent = 'Risks: ' + item.detected_risks.join(', ');
+      li.append(risks);
+    }
+    list.append(li);
+  });
+
+  const pending = state.items.filter((i) => i.status === 'pending').length;
+  $('count').textContent = `${pending} pending`;
+  $('empty').hidden = state.items.length > 0;
+
+  const selected = list.children[state.selected];
+  if (selected) selected.scrollIntoView({ block: 'nearest' });
+  if (state.editing) $('editor').focus();
+}
+
+function move(delta) {
+  if (state.items.length === 0) return;
+  state.selected = Math.max(0, Math.min(state.selected + delta, state.items.length - 1));
+  render();
+}
+
+async function review(decision) {
+  const item = state.items[state.selected];
+  if (!item || item.status !== 'pending') return;
+  try {
+    await api('POST', `/approval/${item.id}/${decision}`, {
+      actor: 'review-page',
+      expected_version: item.version,
+    });
+    item.status = decision === 'approve' ? 'approved' : 'rejected';
+    toast(`#${item.id} ${item.status}`);
+    move(1);
+  } catch (e) {
+    toast(`#${item.id}: ${e.message}`);
+  }
+}
+
+async function saveEdit() {
+  const item = state.items[state.selected];
+  const content = $('editor').value.trim();
+  if (!content) {
+    toast('Content cannot be empty');
+    return;
+  }
+  try {
+    const updated = await api('PATCH', `/approval/${item.id}`, { content, editor: 'review-page' });
+    state.items[state.selected] = updated;
+    state.editing = false;
+    toast(`#${item.id} saved`);
+    render();
+  } catch (e) {
+    toast(`#${item.id}: ${e.message}`);
+  }
+}
+
+document.addEventListener('keydown', (event) => {
+  if (!$('login').hidden) return;
+  if (state.editing) {
+    if (event.key === 'Escape') {
+      state.editing = false;
+      render();
+    } else if (event.key === 'Enter' && (event.ctrlKey || event.metaKey)) {
+      event.preventDefault();
+      saveEdit();
+    }
+    return;
+  }
+  if (event.ctrlKey || event.metaKey || event.altKey) return;
+  switch (event.key) {
+    case 'j':
+    case 'ArrowDown':
+      move(1);
+      break;
+    case 'k':
+    case 'ArrowUp':
+      move(-1);
+      break;
+    case 'a':
+      review('approve');
+      break;
+    case 'r':
+      review('reject');
+      break;
+    case 'e': {
+      const item = state.items[state.selected];
+      if (item && item.status === 'pending') {
+        event.preventDefault();
+        state.editing = true;
+        render();
+      }
+      break;
+    }
+    case 'u':
+      refresh().catch((e) => toast(e.message));
+      break;
+    case '?':
+      $('help').hidden = !$('help').hidden;
+      break;
+    default:
+      return;
+  }
+});
+
+$('help-toggle').addEventListener('click', () => {
+  $('help').hidden = !$('help').hidden;
+});
+
+$('login').addEventListener('submit', async (event) => {
+  event.preventDefault();
+  try {
+    const session = await api('POST', '/auth/login', { passphrase: $('passphrase').value });
+    state.csrf = session.csrf_token;
+    $('login').hidden = true;
+    await refresh();
+  } catch (e) {
+    toast(e.message);
+  }
+});
+
+start().catch((e) => toast(e.message));
@@ -0,0 +1,105 @@
+//! Background watcher that turns stored state into alert events.
+//!
+//! Automation loops may run in another process (`tuitbot run`) and only
+//! leave a trail in the database. This watcher polls for posting failures,
+//! an expiring X token, and approval items past their review SLA, and
+//! broadcasts them as [`WsEvent`]s, so the dashboard, the desktop shell,
+//! and notification channels can alert the user.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use tokio_util::sync::CancellationToken;
+use tuitbot_core::automation::approval_sla;
+use tuitbot_core::startup::{self, StoredTokens};
+use tuitbot_core::storage::action_log;
+
+use crate::state::AppState;
+use crate::ws::WsEvent;
+
+/// How often the watcher checks for new alerts.
+const POLL_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Most posting failures reported per poll.
+const MAX_FAILURES_PER_POLL: u32 = 20;
+
+/// Warn when the access token expires within this many hours.
+pub const TOKEN_WARNING_HOURS: i64 = 24;
+
+/// Hours until `tokens` expire, if they expire within [`TOKEN_WARNING_HOURS`]
+/// and cannot be refreshed automatically.
+pub fn token_expiry_warning(tokens: &StoredTokens, now: DateTime<Utc>) -> Option<i64> {
+    if tokens.refresh_token.is_some() {
+        return None;
+    }
+    let remaining = tokens.expires_at? - now;
+    (remaining.num_hours() < TOKEN_WARNING_HOURS).then(|| remaining.num_hours().max(0))
+}
+
+/// Poll for alerts and broadcast them until `cancel` fires.
+pub async fn run_alert_watcher(state: Arc<AppState>, cancel: CancellationToken) {
+    let mut last_action_id = action_log::latest_action_id(&state.db)
+        .await
+        .unwrap_or_default();
+    let mut warned_expiry: Option<DateTime<Utc>> = None;
+
+    let mut interval = tokio::time::interval(POLL_INTERVAL);
+    loop {
+        tokio::select! {
+            _ = cancel.cancelled() => break,
+            _ = interval.tick() => {}
+        }
+
+        match action_log::get_posting_failures_after(
+            &state.db,
+            last_action_id,
+            MAX_FAILURES_PER_POLL,
+        )
+        .await
+        {
+            Ok(failures) => {
+                for failure in failures {
+                    last_action_id = failure.id;
+                    let _ = state.event_tx.send(WsEvent::PostFailed {
+                        action_type: failure.action_type,
+                        message: failure.message.unwrap_or_default(),
+                        timestamp: failure.created_at,
+                    });
+                }
+            }
+            Err(e) => tracing::warn!(error = %e, "Failed to check for posting failures"),
+        }
+
+        if let Some(config) = state.current_config() {
+            match approval_sla::escalate_breaches(&state.db, &config, Utc::now()).await {
+                Ok(breaches) => {
+                    for breach in breaches {
+                        let _ = state.event_tx.send(WsEvent::ApprovalSlaBreached {
+                            id: breach.id,
+                            action_type: breach.action_type,
+                            priority: breach.priority,
+                            pending_hours: breach.pending_hours,
+                        });
+                    }
+                }
+                Err(e) => tracing::warn!(error = %e, "Failed to check approval SLA"),
+            }
+        }
+
+        if let Ok(tokens) = startup::load_tokens_from_file() {
+            if let Some(hours_remaining) = token_expiry_warning(&tokens, Utc::now()) {
+                if warned_expiry != tokens.expires_at {
+                    warned_expiry = tokens.expires_at;
+                    let _ = state.event_tx.send(WsEvent::TokenExpiring {
+                        expires_at: tokens
+                            .expires_at
+                            .map(|t| t.to_rfc3339())
+                            .unwrap_or_default(),
+                        hours_remaining,
+                    });
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,73 @@
+//! Read-only archive mode.
+//!
+//! Started with `--archive`, or against a database marked archived by
+//! `tuitbot archive`, the server keeps serving analytics and history but
+//! rejects every mutation with `403`. Background tasks are not started
+//! (see `main.rs`).
+
+use axum::extract::Request;
+use axum::http::Method;
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+
+use crate::error::ApiError;
+use crate::grpc::SERVICE_PATH;
+
+/// Non-GET routes that stay open: signing in and out changes no data.
+const ALLOWED_WRITES: &[&str] = &[
+    "/auth/login",
+    "/api/auth/login",
+    "/auth/logout",
+    "/api/auth/logout",
+];
+
+/// gRPC methods that only read. gRPC calls are always `POST`.
+const READ_ONLY_RPCS: &[&str] = &[
+    "Health",
+    "ListApprovals",
+    "GetApprovalStats",
+    "ListActivity",
+];
+
+/// Whether `method` on `path` may run in archive mode.
+pub fn is_allowed(method: &Method, path: &str) -> bool {
+    if let Some(rpc) = path
+        .strip_prefix(SERVICE_PATH)
+        .and_then(|rest| rest.strip_prefix('/'))
+    {
+        return READ_ONLY_RPCS.contains(&rpc);
+    }
+    matches!(*method, Method::GET | Method::HEAD | Method::OPTIONS)
+        || ALLOWED_WRITES.contains(&path)
+}
+
+/// Axum middleware rejecting mutations while archived.
+pub async fn read_only(request: Request, next: Next) -> Response {
+    if is_allowed(request.method(), request.uri().path()) {
+        return next.run(request).await;
+    }
+    ApiError::Forbidden("this install is archived; the server is read-only".to_string())
+        .into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn allows_reads_and_login_only() {
+        assert!(is_allowed(&Method::GET, "/approval"));
+        assert!(is_allowed(&Method::POST, "/auth/login"));
+        assert!(!is_allowed(&Method::POST, "/approval/1/approve"));
+        assert!(!is_allowed(&Method::PATCH, "/settings"));
+        assert!(!is_allowed(&Method::DELETE, "/targets/alice"));
+    }
+
+    #[test]
+    fn allows_read_only_rpcs() {
+        let path = |rpc: &str| format!("{SERVICE_PATH}/{rpc}");
+        assert!(is_allowed(&Method::POST, &path("ListApprovals")));
+        assert!(!is_allowed(&Method::POST, &path("ApproveItem")));
+        assert!(!is_allowed(&Method::POST, &path("EditApproval")));
+    }
+}
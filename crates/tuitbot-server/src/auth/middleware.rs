@@ -7,6 +7,9 @@
 //!
 //! For cookie-authenticated requests, mutating methods (POST/PATCH/DELETE/PUT)
 //! require a valid `X-CSRF-Token` header matching the session's CSRF token.
+//!
+//! Authenticated requests carry a [`ClientIdentity`] extension so the
+//! per-identity rate limiter only ever sees validated credentials.
 
 use std::sync::Arc;
 
@@ -17,6 +20,7 @@ use axum::response::{IntoResponse, Response};
 use serde_json::json;
 use tuitbot_core::auth::session;
 
+use crate::rate_limit::ClientIdentity;
 use crate::state::AppState;
 
 /// Extract the session cookie value from headers.
@@ -46,19 +50,25 @@ const AUTH_EXEMPT_PATHS: &[&str] = &[
     "/api/auth/login",
     "/auth/status",
     "/api/auth/status",
+    "/public/feed",
+    "/api/public/feed",
 ];
 
+/// Route prefixes that carry their own credential (a signed token in the path).
+const AUTH_EXEMPT_PREFIXES: &[&str] = &["/email-review/", "/api/email-review/"];
+
 /// Axum middleware that enforces multi-strategy authentication.
 pub async fn auth_middleware(
     State(state): State<Arc<AppState>>,
     headers: HeaderMap,
-    request: Request,
+    mut request: Request,
     next: Next,
 ) -> Response {
     let path = request.uri().path();
 
     // Skip auth for exempt endpoints.
-    if AUTH_EXEMPT_PATHS.contains(&path) {
+    if AUTH_EXEMPT_PATHS.contains(&path) || AUTH_EXEMPT_PREFIXES.iter().any(|p| path.starts_with(p))
+    {
         return next.run(request).await;
     }
 
@@ -70,6 +80,9 @@ pub async fn auth_middleware(
         .is_some_and(|token| token == state.api_token);
 
     if bearer_ok {
+        request
+            .extensions_mut()
+            .insert(ClientIdentity::token(&state.api_token));
         return next.run(request).await;
     }
 
@@ -97,6 +110,9 @@ pub async fn auth_middleware(
                             .into_response();
                     }
                 }
+                request
+                    .extensions_mut()
+                    .insert(ClientIdentity::session(&session_token));
                 return next.run(request).await;
             }
             Ok(None) => { /* session not found or expired — fall through to 401 */ }
@@ -11,37 +11,18 @@ use std::time::Instant;
 use axum::extract::State;
 use axum::http::{HeaderMap, StatusCode};
 use axum::response::IntoResponse;
-use serde::{Deserialize, Serialize};
 use serde_json::json;
 use tuitbot_core::auth::{passphrase, session};
 
 use crate::state::AppState;
 
+pub use tuitbot_types::auth::{AuthStatusResponse, LoginRequest, LoginResponse};
+
 /// Maximum login attempts per IP before rate limiting.
 const MAX_ATTEMPTS_PER_MINUTE: u32 = 5;
 /// Rate limit window in seconds.
 const RATE_LIMIT_WINDOW_SECS: u64 = 60;
 
-#[derive(Deserialize)]
-pub struct LoginRequest {
-    passphrase: String,
-}
-
-#[derive(Serialize)]
-pub struct LoginResponse {
-    csrf_token: String,
-    expires_at: String,
-}
-
-#[derive(Serialize)]
-pub struct AuthStatusResponse {
-    authenticated: bool,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    csrf_token: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    expires_at: Option<String>,
-}
-
 /// Extract client IP from X-Forwarded-For or fall back to a default.
 fn client_ip(headers: &HeaderMap) -> IpAddr {
     headers
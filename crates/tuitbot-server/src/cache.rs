@@ -0,0 +1,176 @@
+//! Response caching with ETag support for expensive read routes.
+//!
+//! Analytics queries are recomputed on every dashboard poll. Routes wrapped
+//! in [`cached`] keep their last successful response for [`CACHE_TTL`] and
+//! tag it with a content hash, so an unchanged response becomes a `304 Not
+//! Modified`. Any successful mutating request clears the cache through
+//! [`invalidate_on_write`]; writes made by the agent process are picked up
+//! once the TTL expires.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use axum::body::{Body, Bytes};
+use axum::extract::{Request, State};
+use axum::http::header::{CACHE_CONTROL, CONTENT_TYPE, ETAG, IF_NONE_MATCH};
+use axum::http::{HeaderValue, Method, StatusCode};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use sha2::{Digest, Sha256};
+
+use crate::state::AppState;
+
+/// How long a cached response is served before it is recomputed.
+pub const CACHE_TTL: Duration = Duration::from_secs(30);
+
+/// Largest response body that will be cached.
+const MAX_BODY_BYTES: usize = 4 * 1024 * 1024;
+
+#[derive(Clone)]
+struct CachedResponse {
+    etag: HeaderValue,
+    content_type: Option<HeaderValue>,
+    /// `Cache-Control` set by the route, if any.
+    cache_control: Option<HeaderValue>,
+    body: Bytes,
+    stored_at: Instant,
+}
+
+/// Cached GET responses keyed by account and request URI.
+#[derive(Default)]
+pub struct ResponseCache {
+    entries: Mutex<HashMap<String, CachedResponse>>,
+    /// Bumped on every invalidation, so a response computed before a write
+    /// is not stored after it.
+    generation: AtomicU64,
+}
+
+impl ResponseCache {
+    /// Drop every cached response.
+    pub fn invalidate(&self) {
+        let mut entries = self.lock();
+        self.generation.fetch_add(1, Ordering::SeqCst);
+        entries.clear();
+    }
+
+    /// Number of cached responses, including expired ones not yet replaced.
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn get(&self, key: &str) -> Option<CachedResponse> {
+        self.lock()
+            .get(key)
+            .filter(|entry| entry.stored_at.elapsed() < CACHE_TTL)
+            .cloned()
+    }
+
+    fn insert(&self, key: String, entry: CachedResponse, generation: u64) {
+        let mut entries = self.lock();
+        if self.generation.load(Ordering::SeqCst) == generation {
+            entries.insert(key, entry);
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<String, CachedResponse>> {
+        self.entries.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// Middleware: serve GET responses from the cache and answer `If-None-Match`.
+pub async fn cached(State(state): State<Arc<AppState>>, request: Request, next: Next) -> Response {
+    if request.method() != Method::GET {
+        return next.run(request).await;
+    }
+
+    let account = request
+        .headers()
+        .get("x-account-id")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or_default();
+    let key = format!("{account}|{}", request.uri());
+    let if_none_match = request.headers().get(IF_NONE_MATCH).cloned();
+
+    if let Some(entry) = state.response_cache.get(&key) {
+        return respond(entry, if_none_match.as_ref());
+    }
+    let generation = state.response_cache.generation.load(Ordering::SeqCst);
+
+    let response = next.run(request).await;
+    if response.status() != StatusCode::OK {
+        return response;
+    }
+
+    let (parts, body) = response.into_parts();
+    let body = match axum::body::to_bytes(body, MAX_BODY_BYTES).await {
+        Ok(body) => body,
+        Err(e) => {
+            tracing::warn!(error = %e, "Failed to buffer response for caching");
+            return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        }
+    };
+
+    let entry = CachedResponse {
+        etag: etag_for(&body),
+        content_type: parts.headers.get(CONTENT_TYPE).cloned(),
+        cache_control: parts.headers.get(CACHE_CONTROL).cloned(),
+        body,
+        stored_at: Instant::now(),
+    };
+    state.response_cache.insert(key, entry.clone(), generation);
+    respond(entry, if_none_match.as_ref())
+}
+
+/// Middleware: clear the response cache after a successful mutating request.
+pub async fn invalidate_on_write(
+    State(state): State<Arc<AppState>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let mutating = !matches!(
+        *request.method(),
+        Method::GET | Method::HEAD | Method::OPTIONS
+    );
+    let response = next.run(request).await;
+    if mutating && response.status().is_success() {
+        state.response_cache.invalidate();
+    }
+    response
+}
+
+/// Strong ETag derived from the response body.
+fn etag_for(body: &[u8]) -> HeaderValue {
+    let digest = Sha256::digest(body);
+    let tag = format!("\"{}\"", hex::encode(&digest[..16]));
+    HeaderValue::from_str(&tag).expect("hex ETag is a valid header value")
+}
+
+fn respond(entry: CachedResponse, if_none_match: Option<&HeaderValue>) -> Response {
+    let not_modified = if_none_match
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|v| v.split(',').any(|tag| tag.trim() == entry.etag));
+
+    let mut response = if not_modified {
+        StatusCode::NOT_MODIFIED.into_response()
+    } else {
+        let mut response = Response::new(Body::from(entry.body));
+        if let Some(content_type) = entry.content_type {
+            response.headers_mut().insert(CONTENT_TYPE, content_type);
+        }
+        response
+    };
+    response.headers_mut().insert(ETAG, entry.etag);
+    // Unless the route chose its own policy, let browsers keep the body but
+    // revalidate on every poll.
+    let cache_control = entry
+        .cache_control
+        .unwrap_or_else(|| HeaderValue::from_static("no-cache"));
+    response.headers_mut().insert(CACHE_CONTROL, cache_control);
+    response
+}
@@ -0,0 +1,444 @@
+//! Email notifications: a daily digest with optional approve/reject links.
+//!
+//! Once a day, at `notifications.email.digest_hour` in `schedule.timezone`,
+//! each active account gets a plain-text summary of the last 24 hours of
+//! activity and its pending approvals. With `approval_links` on, every
+//! pending item carries signed links to `/api/email-review/{token}`. The
+//! token is an HMAC over the account, item, decision, item version, and
+//! expiry, keyed with the server's API token, so a link only works for the
+//! item as it was when the digest went out and stops working once it
+//! expires or the API token changes.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::{DateTime, Timelike, Utc};
+use hmac::{Hmac, Mac};
+use lettre::message::header::ContentType;
+use lettre::transport::smtp::authentication::Credentials;
+use lettre::{AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor};
+use sha2::Sha256;
+use tokio_util::sync::CancellationToken;
+use tuitbot_core::config::EmailConfig;
+use tuitbot_core::storage::accounts::{self, DEFAULT_ACCOUNT_ID};
+use tuitbot_core::storage::approval_queue::{self, ApprovalItem};
+use tuitbot_core::storage::{action_log, DbPool};
+
+use crate::state::AppState;
+
+/// How often the digest task checks whether it is time to send.
+const POLL_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Longest excerpt of generated content shown per pending item.
+const EXCERPT_CHARS: usize = 280;
+
+/// A decision an approval link carries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReviewDecision {
+    Approve,
+    Reject,
+}
+
+impl ReviewDecision {
+    /// Verb used in tokens and confirmation pages.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Approve => "approve",
+            Self::Reject => "reject",
+        }
+    }
+
+    /// Approval queue status the decision moves an item to.
+    pub fn status(self) -> &'static str {
+        match self {
+            Self::Approve => "approved",
+            Self::Reject => "rejected",
+        }
+    }
+
+    fn parse(s: &str) -> Option<Self> {
+        match s {
+            "approve" => Some(Self::Approve),
+            "reject" => Some(Self::Reject),
+            _ => None,
+        }
+    }
+}
+
+/// The claims carried by a signed approval link.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReviewToken {
+    pub account_id: String,
+    pub item_id: i64,
+    pub decision: ReviewDecision,
+    /// Item version the link was issued for.
+    pub version: i64,
+    /// Unix timestamp after which the link is rejected.
+    pub expires_at: i64,
+}
+
+impl ReviewToken {
+    /// Encode and sign the token as `<hex payload>.<hex signature>`.
+    pub fn sign(&self, key: &str) -> String {
+        let payload = format!(
+            "{}|{}|{}|{}|{}",
+            self.account_id,
+            self.item_id,
+            self.decision.as_str(),
+            self.version,
+            self.expires_at
+        );
+        format!(
+            "{}.{}",
+            hex::encode(&payload),
+            hex::encode(signature(key, payload.as_bytes()))
+        )
+    }
+
+    /// Check the signature and expiry of `token` and decode its claims.
+    pub fn verify(token: &str, key: &str, now: DateTime<Utc>) -> Result<Self, &'static str> {
+        let (payload_hex, sig_hex) = token.split_once('.').ok_or("malformed link")?;
+        let payload = hex::decode(payload_hex).map_err(|_| "malformed link")?;
+        let sig = hex::decode(sig_hex).map_err(|_| "malformed link")?;
+
+        let mut mac = new_mac(key);
+        mac.update(&payload);
+        mac.verify_slice(&sig).map_err(|_| "invalid link")?;
+
+        let payload = String::from_utf8(payload).map_err(|_| "malformed link")?;
+        let parts: Vec<&str> = payload.split('|').collect();
+        let [account_id, item_id, decision, version, expires_at] = parts[..] else {
+            return Err("malformed link");
+        };
+        let claims = Self {
+            account_id: account_id.to_string(),
+            item_id: item_id.parse().map_err(|_| "malformed link")?,
+            decision: ReviewDecision::parse(decision).ok_or("malformed link")?,
+            version: version.parse().map_err(|_| "malformed link")?,
+            expires_at: expires_at.parse().map_err(|_| "malformed link")?,
+        };
+        if now.timestamp() > claims.expires_at {
+            return Err("link has expired");
+        }
+        Ok(claims)
+    }
+}
+
+fn new_mac(key: &str) -> Hmac<Sha256> {
+    Hmac::<Sha256>::new_from_slice(key.as_bytes()).expect("HMAC accepts keys of any length")
+}
+
+fn signature(key: &str, payload: &[u8]) -> Vec<u8> {
+    let mut mac = new_mac(key);
+    mac.update(payload);
+    mac.finalize().into_bytes().to_vec()
+}
+
+/// Signed approve/reject links for `item`, when approval links are on.
+fn review_links(
+    email: &EmailConfig,
+    key: &str,
+    account_id: &str,
+    item: &ApprovalItem,
+    now: DateTime<Utc>,
+) -> Option<(String, String)> {
+    if !email.approval_links {
+        return None;
+    }
+    let base = email.public_url.trim_end_matches('/');
+    let expires_at = now.timestamp() + i64::from(email.link_ttl_hours) * 3600;
+    let link = |decision| {
+        let token = ReviewToken {
+            account_id: account_id.to_string(),
+            item_id: item.id,
+            decision,
+            version: item.version,
+            expires_at,
+        };
+        format!("{base}/api/email-review/{}", token.sign(key))
+    };
+    Some((link(ReviewDecision::Approve), link(ReviewDecision::Reject)))
+}
+
+/// What one account's digest reports.
+#[derive(Debug, Default)]
+pub struct DigestSection {
+    pub account_label: String,
+    pub account_id: String,
+    /// Action counts over the last 24 hours, sorted by action type.
+    pub activity: Vec<(String, i64)>,
+    pub pending: Vec<ApprovalItem>,
+    pub pending_total: usize,
+}
+
+/// Render the plain-text digest body.
+pub fn render_digest(
+    sections: &[DigestSection],
+    email: &EmailConfig,
+    key: &str,
+    now: DateTime<Utc>,
+) -> String {
+    let mut out = String::from("Tuitbot daily digest\n");
+    for section in sections {
+        out.push('\n');
+        if sections.len() > 1 {
+            out.push_str(&format!("== {} ==\n\n", section.account_label));
+        }
+
+        out.push_str("Last 24 hours\n");
+        if section.activity.is_empty() {
+            out.push_str("  No activity.\n");
+        }
+        for (action_type, count) in &section.activity {
+            out.push_str(&format!("  {action_type}: {count}\n"));
+        }
+
+        out.push_str(&format!("\nPending approvals: {}\n", section.pending_total));
+        for item in &section.pending {
+            let target = if item.target_author.is_empty() {
+                String::new()
+            } else {
+                format!(" to @{}", item.target_author)
+            };
+            out.push_str(&format!(
+                "\n#{} {}{} (score {:.0})\n",
+                item.id, item.action_type, target, item.score
+            ));
+            out.push_str(&format!("  {}\n", excerpt(&item.generated_content)));
+            if let Some((approve, reject)) =
+                review_links(email, key, &section.account_id, item, now)
+            {
+                out.push_str(&format!("  Approve: {approve}\n  Reject:  {reject}\n"));
+            }
+        }
+        let hidden = section.pending_total.saturating_sub(section.pending.len());
+        if hidden > 0 {
+            out.push_str(&format!(
+                "\n...and {hidden} more in the dashboard approval queue.\n"
+            ));
+        }
+    }
+    out
+}
+
+fn excerpt(content: &str) -> String {
+    let flat = content.split_whitespace().collect::<Vec<_>>().join(" ");
+    if flat.chars().count() <= EXCERPT_CHARS {
+        return flat;
+    }
+    let cut: String = flat.chars().take(EXCERPT_CHARS).collect();
+    format!("{cut}...")
+}
+
+/// Gather digest sections for every active account.
+pub async fn collect_digest(
+    db: &DbPool,
+    max_items: u32,
+    now: DateTime<Utc>,
+) -> Result<Vec<DigestSection>, tuitbot_core::error::StorageError> {
+    let mut accounts: Vec<(String, String)> = accounts::list_accounts(db)
+        .await?
+        .into_iter()
+        .map(|a| (a.id, a.label))
+        .collect();
+    if accounts.is_empty() {
+        accounts.push((DEFAULT_ACCOUNT_ID.to_string(), "Default".to_string()));
+    }
+
+    let since = (now - chrono::Duration::hours(24))
+        .format("%Y-%m-%dT%H:%M:%SZ")
+        .to_string();
+    let mut sections = Vec::with_capacity(accounts.len());
+    for (account_id, account_label) in accounts {
+        let mut activity: Vec<(String, i64)> =
+            action_log::get_action_counts_since_for(db, &account_id, &since)
+                .await?
+                .into_iter()
+                .collect();
+        activity.sort();
+        let mut pending = approval_queue::get_pending_for(db, &account_id).await?;
+        let pending_total = pending.len();
+        pending.truncate(max_items as usize);
+        sections.push(DigestSection {
+            account_label,
+            account_id,
+            activity,
+            pending,
+            pending_total,
+        });
+    }
+    Ok(sections)
+}
+
+/// Send one message to every configured recipient.
+pub async fn send_email(email: &EmailConfig, subject: &str, body: String) -> anyhow::Result<()> {
+    let mut message = Message::builder()
+        .from(email.from.parse()?)
+        .subject(subject)
+        .header(ContentType::TEXT_PLAIN);
+    for to in &email.to {
+        message = message.to(to.parse()?);
+    }
+    let message = message.body(body)?;
+
+    let host = email.smtp_host.as_str();
+    let mut transport = match email.smtp_security.as_str() {
+        "tls" => AsyncSmtpTransport::<Tokio1Executor>::relay(host)?,
+        "none" => AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(host),
+        _ => AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(host)?,
+    }
+    .port(email.smtp_port);
+    if !email.smtp_username.is_empty() {
+        transport = transport.credentials(Credentials::new(
+            email.smtp_username.clone(),
+            email.smtp_password.clone().unwrap_or_default(),
+        ));
+    }
+    transport.build().send(message).await?;
+    Ok(())
+}
+
+/// Send the daily digest at `email.digest_hour` in `timezone` until `cancel`
+/// fires.
+///
+/// The last send date is kept in memory, so a restart during the digest
+/// hour sends that day's digest again.
+pub async fn run_email_digest(
+    state: Arc<AppState>,
+    email: EmailConfig,
+    timezone: String,
+    cancel: CancellationToken,
+) {
+    if !email.enabled {
+        return;
+    }
+    let tz: chrono_tz::Tz = timezone.parse().unwrap_or(chrono_tz::UTC);
+    tracing::info!(hour = email.digest_hour, timezone = %tz, "Email digest scheduled");
+
+    let mut last_sent = None;
+    let mut interval = tokio::time::interval(POLL_INTERVAL);
+    loop {
+        tokio::select! {
+            _ = cancel.cancelled() => break,
+            _ = interval.tick() => {}
+        }
+
+        let now = Utc::now();
+        let local = now.with_timezone(&tz);
+        if local.hour() != u32::from(email.digest_hour) || last_sent == Some(local.date_naive()) {
+            continue;
+        }
+        last_sent = Some(local.date_naive());
+
+        let sections = match collect_digest(&state.db, email.max_items, now).await {
+            Ok(sections) => sections,
+            Err(e) => {
+                tracing::warn!(error = %e, "Failed to collect email digest");
+                continue;
+            }
+        };
+        let pending: usize = sections.iter().map(|s| s.pending_total).sum();
+        let subject = format!("Tuitbot digest: {pending} pending approvals");
+        let body = render_digest(&sections, &email, &state.api_token, now);
+        match send_email(&email, &subject, body).await {
+            Ok(()) => tracing::info!(recipients = email.to.len(), "Email digest sent"),
+            Err(e) => tracing::warn!(error = %e, "Failed to send email digest"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEY: &str = "api-token";
+
+    fn token() -> ReviewToken {
+        ReviewToken {
+            account_id: DEFAULT_ACCOUNT_ID.to_string(),
+            item_id: 7,
+            decision: ReviewDecision::Reject,
+            version: 2,
+            expires_at: 1_000,
+        }
+    }
+
+    fn at(secs: i64) -> DateTime<Utc> {
+        DateTime::from_timestamp(secs, 0).unwrap()
+    }
+
+    #[test]
+    fn token_round_trips_until_expiry() {
+        let signed = token().sign(KEY);
+        assert_eq!(ReviewToken::verify(&signed, KEY, at(1_000)), Ok(token()));
+        assert_eq!(
+            ReviewToken::verify(&signed, KEY, at(1_001)),
+            Err("link has expired")
+        );
+    }
+
+    #[test]
+    fn token_rejects_tampering_and_other_keys() {
+        let signed = token().sign(KEY);
+        assert_eq!(
+            ReviewToken::verify(&signed, "other-token", at(0)),
+            Err("invalid link")
+        );
+
+        let mut forged = token();
+        forged.decision = ReviewDecision::Approve;
+        let forged = forged.sign(KEY);
+        let (payload, _) = forged.split_once('.').unwrap();
+        let (_, sig) = signed.split_once('.').unwrap();
+        assert_eq!(
+            ReviewToken::verify(&format!("{payload}.{sig}"), KEY, at(0)),
+            Err("invalid link")
+        );
+        assert_eq!(
+            ReviewToken::verify("nope", KEY, at(0)),
+            Err("malformed link")
+        );
+    }
+
+    #[tokio::test]
+    async fn digest_lists_activity_pending_and_links() {
+        let db = tuitbot_core::storage::init_test_db().await.unwrap();
+        for (target, content) in [("1", "Try\n clap."), ("2", "Second"), ("3", "Third")] {
+            approval_queue::enqueue(&db, "reply", target, "alice", content, "", "", 81.6, "[]")
+                .await
+                .unwrap();
+        }
+        action_log::log_action(&db, "reply", "success", None, None)
+            .await
+            .unwrap();
+
+        let now = Utc::now();
+        let sections = collect_digest(&db, 1, now).await.unwrap();
+        assert_eq!(sections.len(), 1);
+        assert_eq!(sections[0].pending_total, 3);
+        assert_eq!(sections[0].pending.len(), 1);
+
+        let mut email = EmailConfig::default();
+        let body = render_digest(&sections, &email, KEY, now);
+        assert!(body.contains("  reply: 1\n"), "{body}");
+        assert!(body.contains("Pending approvals: 3\n"));
+        assert!(body.contains("reply to @alice (score 82)\n  Try clap.\n"));
+        assert!(body.contains("...and 2 more"));
+        assert!(!body.contains("Approve:"));
+
+        email.approval_links = true;
+        email.public_url = "https://bot.example.com/".to_string();
+        let body = render_digest(&sections, &email, KEY, now);
+        let link = body
+            .lines()
+            .find_map(|l| l.trim().strip_prefix("Approve: "))
+            .unwrap();
+        let token = link
+            .strip_prefix("https://bot.example.com/api/email-review/")
+            .unwrap();
+        let claims = ReviewToken::verify(token, KEY, now).unwrap();
+        assert_eq!(claims.decision, ReviewDecision::Approve);
+        assert_eq!(claims.item_id, sections[0].pending[0].id);
+        assert_eq!(claims.expires_at, now.timestamp() + 48 * 3600);
+    }
+}
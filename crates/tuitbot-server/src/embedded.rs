@@ -0,0 +1,265 @@
+//! Running the API server inside another process.
+//!
+//! `tuitbot run --with-api` serves the dashboard API from the agent process,
+//! sharing its database pool and circuit breaker instead of opening a second
+//! connection from a separate `tuitbot-server` binary. The helpers here are
+//! also used by the standalone binary so both paths build the same state.
+
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use tokio::net::TcpListener;
+use tokio::sync::Mutex;
+use tokio_util::sync::CancellationToken;
+use tuitbot_core::auth::passphrase;
+use tuitbot_core::automation::circuit_breaker::CircuitBreaker;
+use tuitbot_core::automation::WatchtowerLoop;
+use tuitbot_core::config::{
+    Config, ContentSourcesConfig, DeploymentMode, EmailConfig, NotificationChannelConfig,
+    WebhookConfig,
+};
+use tuitbot_core::content::ContentGenerator;
+use tuitbot_core::llm::factory::create_provider;
+use tuitbot_core::storage::accounts::DEFAULT_ACCOUNT_ID;
+use tuitbot_core::storage::write_queue::WriteQueue;
+use tuitbot_core::storage::DbPool;
+use tuitbot_mcp::McpHttpService;
+
+use crate::auth;
+use crate::limits::HttpLimits;
+use crate::rate_limit::RateLimiter;
+use crate::state::AppState;
+use crate::ws::WsEvent;
+use crate::RouterOptions;
+
+/// Build the default account's content generator for AI assist endpoints.
+///
+/// Returns an empty map when no config is loaded or the LLM provider is not
+/// configured; the AI assist endpoints are disabled in that case.
+pub fn content_generators(config: Option<&Config>) -> HashMap<String, Arc<ContentGenerator>> {
+    let mut generators = HashMap::new();
+    let Some(config) = config else {
+        tracing::info!("Config not loaded — AI assist endpoints disabled");
+        return generators;
+    };
+    match create_provider(&config.llm) {
+        Ok(provider) => {
+            tracing::info!("LLM provider initialized for AI assist endpoints");
+            generators.insert(
+                DEFAULT_ACCOUNT_ID.to_string(),
+                Arc::new(
+                    ContentGenerator::new(provider, config.business.clone())
+                        .with_disclosure(config.disclosure.clone()),
+                ),
+            );
+        }
+        Err(e) => {
+            tracing::info!(error = %e, "LLM provider not configured — AI assist endpoints disabled");
+        }
+    }
+    generators
+}
+
+/// Start the Watchtower filesystem watcher if any content source is watched.
+///
+/// Returns the watcher's cancellation token, or `None` if nothing is watched.
+pub fn start_watchtower(
+    pool: &DbPool,
+    content_sources: &ContentSourcesConfig,
+    deployment_mode: &DeploymentMode,
+) -> Option<CancellationToken> {
+    let watched = content_sources
+        .sources
+        .iter()
+        .filter(|s| {
+            if !deployment_mode.allows_source_type(&s.source_type) {
+                tracing::warn!(
+                    source_type = %s.source_type,
+                    deployment_mode = %deployment_mode,
+                    "skipping content source incompatible with deployment mode"
+                );
+                return false;
+            }
+            s.watch && (s.path.is_some() || s.folder_id.is_some())
+        })
+        .count();
+    if watched == 0 {
+        return None;
+    }
+
+    let cancel = CancellationToken::new();
+    let watchtower = WatchtowerLoop::new(pool.clone(), content_sources.clone());
+    let cancel_clone = cancel.clone();
+    tokio::spawn(async move {
+        watchtower.run(cancel_clone).await;
+    });
+    tracing::info!(sources = watched, "Watchtower started");
+    Some(cancel)
+}
+
+/// Build the MCP endpoint if `[server] mcp_endpoint` is enabled.
+///
+/// Failures are logged and leave the REST API running without MCP.
+pub async fn mcp_service(
+    config: &Config,
+    pool: &DbPool,
+    cancel: CancellationToken,
+) -> Option<McpHttpService> {
+    if !config.server.mcp_endpoint {
+        return None;
+    }
+    match tuitbot_mcp::write_http_service(config.clone(), pool.clone(), cancel).await {
+        Ok(service) => {
+            tracing::info!("MCP endpoint mounted at /api/mcp/http");
+            Some(service)
+        }
+        Err(e) => {
+            tracing::error!(error = %e, "Failed to start MCP endpoint — serving REST API only");
+            None
+        }
+    }
+}
+
+/// An API server bound to its port and ready to serve.
+pub struct EmbeddedServer {
+    state: Arc<AppState>,
+    listener: TcpListener,
+    mcp: Option<McpHttpService>,
+    mcp_cancel: CancellationToken,
+    limits: HttpLimits,
+    webhooks: Vec<WebhookConfig>,
+    channels: Vec<NotificationChannelConfig>,
+    email: EmailConfig,
+    timezone: String,
+}
+
+impl EmbeddedServer {
+    /// Build server state on top of an existing pool and bind `[server]`
+    /// host and port from `config`. Pass the loops' `write_queue` so API
+    /// action-log writes wait their turn behind them.
+    ///
+    /// Binding happens here so a port conflict fails startup instead of
+    /// surfacing later from a background task.
+    pub async fn bind(
+        pool: DbPool,
+        config: &Config,
+        config_path: PathBuf,
+        circuit_breaker: Option<Arc<CircuitBreaker>>,
+        write_queue: Option<WriteQueue>,
+    ) -> anyhow::Result<Self> {
+        let data_dir = config_path
+            .parent()
+            .map(|p| p.to_path_buf())
+            .unwrap_or_else(|| PathBuf::from("."));
+
+        let api_token = auth::ensure_api_token(&data_dir)?;
+        if let Some(new_passphrase) = passphrase::ensure_passphrase(&data_dir)? {
+            println!("\n  Web login passphrase: {new_passphrase}");
+            println!("  (save this — it won't be shown again)\n");
+        }
+        let passphrase_hash = passphrase::load_passphrase_hash(&data_dir)?;
+
+        let host = config.server.host.clone();
+        let port = config.server.port;
+        let listener = TcpListener::bind(format!("{host}:{port}"))
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to bind API server to {host}:{port}: {e}"))?;
+
+        let content_sources = config.content_sources.clone();
+        let deployment_mode = config.deployment_mode.clone();
+        let watchtower_cancel = start_watchtower(&pool, &content_sources, &deployment_mode);
+        let (event_tx, _) = tokio::sync::broadcast::channel::<WsEvent>(256);
+        let mcp_cancel = CancellationToken::new();
+        let mcp = mcp_service(config, &pool, mcp_cancel.clone()).await;
+
+        let state = Arc::new(AppState {
+            db: pool,
+            config_path,
+            data_dir,
+            event_tx,
+            api_token,
+            passphrase_hash: tokio::sync::RwLock::new(passphrase_hash),
+            bind_host: host,
+            bind_port: port,
+            login_attempts: Mutex::new(HashMap::new()),
+            runtimes: Mutex::new(HashMap::new()),
+            content_generators: Mutex::new(content_generators(Some(config))),
+            circuit_breaker,
+            watchtower_cancel,
+            content_sources,
+            deployment_mode,
+            response_cache: Default::default(),
+            rate_limiter: RateLimiter::new(config.server.rate_limit.clone()),
+            write_queue,
+        });
+
+        Ok(Self {
+            state,
+            listener,
+            mcp,
+            mcp_cancel,
+            limits: HttpLimits::from(&config.server),
+            webhooks: config.notifications.webhooks.clone(),
+            channels: config.notifications.channels.clone(),
+            email: config.notifications.email.clone(),
+            timezone: config.schedule.timezone.clone(),
+        })
+    }
+
+    /// Address the server is listening on.
+    pub fn local_addr(&self) -> std::io::Result<SocketAddr> {
+        self.listener.local_addr()
+    }
+
+    /// Serve until `cancel` fires, then stop background tasks.
+    pub async fn serve(self, cancel: CancellationToken) {
+        let alerts_cancel = cancel.child_token();
+        tokio::spawn(crate::alerts::run_alert_watcher(
+            self.state.clone(),
+            alerts_cancel.clone(),
+        ));
+        tokio::spawn(crate::webhooks::run_webhook_dispatcher(
+            self.webhooks,
+            self.state.event_tx.subscribe(),
+            alerts_cancel.clone(),
+        ));
+        tokio::spawn(crate::notify::run_notification_dispatcher(
+            self.channels,
+            self.state.event_tx.subscribe(),
+            alerts_cancel.clone(),
+        ));
+        tokio::spawn(crate::email::run_email_digest(
+            self.state.clone(),
+            self.email,
+            self.timezone,
+            alerts_cancel.clone(),
+        ));
+
+        let watchtower_cancel = self.state.watchtower_cancel.clone();
+        let router = crate::build_router_with(
+            self.state,
+            RouterOptions {
+                mcp: self.mcp,
+                limits: self.limits,
+                archived: false,
+            },
+        );
+        if let Err(e) = axum::serve(
+            self.listener,
+            router.into_make_service_with_connect_info::<SocketAddr>(),
+        )
+        .with_graceful_shutdown(async move { cancel.cancelled().await })
+        .await
+        {
+            tracing::error!(error = %e, "API server stopped with an error");
+        }
+
+        alerts_cancel.cancel();
+        self.mcp_cancel.cancel();
+        if let Some(cancel) = watchtower_cancel {
+            cancel.cancel();
+        }
+    }
+}
@@ -20,11 +20,30 @@ pub enum ApiError {
     Internal(String),
     /// Forbidden — insufficient role/permissions.
     Forbidden(String),
+    /// Payload exceeds a configured size limit.
+    PayloadTooLarge(String),
+    /// Response body exceeds the configured size limit. This is a server-side
+    /// failure, not a problem with the request, so it maps to `500`.
+    ResponseTooLarge(String),
 }
 
 impl From<tuitbot_core::error::StorageError> for ApiError {
     fn from(err: tuitbot_core::error::StorageError) -> Self {
-        Self::Storage(err)
+        match err {
+            tuitbot_core::error::StorageError::Conflict { message } => Self::Conflict(message),
+            err => Self::Storage(err),
+        }
+    }
+}
+
+impl From<tuitbot_core::workflow::WorkflowError> for ApiError {
+    fn from(err: tuitbot_core::workflow::WorkflowError) -> Self {
+        use tuitbot_core::workflow::WorkflowError;
+        match err {
+            WorkflowError::Storage(e) => e.into(),
+            WorkflowError::InvalidInput(msg) => Self::BadRequest(msg),
+            err => Self::Internal(err.to_string()),
+        }
     }
 }
 
@@ -53,6 +72,11 @@ impl IntoResponse for ApiError {
                 (StatusCode::INTERNAL_SERVER_ERROR, msg)
             }
             Self::Forbidden(msg) => (StatusCode::FORBIDDEN, msg),
+            Self::PayloadTooLarge(msg) => (StatusCode::PAYLOAD_TOO_LARGE, msg),
+            Self::ResponseTooLarge(msg) => {
+                tracing::warn!("response too large: {msg}");
+                (StatusCode::INTERNAL_SERVER_ERROR, msg)
+            }
         };
 
         let body = axum::Json(json!({ "error": message }));
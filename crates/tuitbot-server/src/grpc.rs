@@ -0,0 +1,262 @@
+//! gRPC surface alongside the REST API.
+//!
+//! Implements `tuitbot.v1.Tuitbot` from `proto/tuitbot/v1/tuitbot.proto`.
+//! Each RPC calls the matching REST handler and converts its JSON into the
+//! generated message, so both surfaces return the same read models and run
+//! the same approval mutations (role checks, hooks, action log, WebSocket
+//! events). The service is mounted on the main router behind the REST auth
+//! and rate-limit middleware; see [`crate::build_router_with`].
+
+use std::sync::Arc;
+
+use axum::extract::{FromRequestParts, Path, Query, Request as HttpRequest, State};
+use axum::http::StatusCode;
+use axum::middleware::Next;
+use axum::response::Response as HttpResponse;
+use axum::Json;
+use serde::de::DeserializeOwned;
+use serde_json::{json, Map, Value};
+use tonic::{Request, Response, Status};
+
+use crate::account::AccountContext;
+use crate::error::ApiError;
+use crate::routes::{self, Pagination};
+use crate::state::AppState;
+
+/// Messages, client, and server generated from the proto file.
+pub mod pb {
+    tonic::include_proto!("tuitbot.v1");
+}
+
+use pb::tuitbot_server::{Tuitbot, TuitbotServer};
+
+/// Path prefix of every `tuitbot.v1.Tuitbot` method.
+pub const SERVICE_PATH: &str = "/tuitbot.v1.Tuitbot";
+
+/// The gRPC service, ready to mount on a router.
+pub fn service(state: Arc<AppState>) -> TuitbotServer<TuitbotGrpc> {
+    TuitbotServer::new(TuitbotGrpc { state })
+}
+
+/// `tuitbot.v1.Tuitbot` implementation backed by the REST handlers.
+pub struct TuitbotGrpc {
+    state: Arc<AppState>,
+}
+
+impl TuitbotGrpc {
+    /// Resolve the account from `x-account-id` metadata, like the REST
+    /// `AccountContext` extractor does from the header.
+    async fn account<T>(&self, request: &Request<T>) -> Result<AccountContext, Status> {
+        let (mut parts, ()) = axum::http::Request::new(()).into_parts();
+        parts.headers = request.metadata().clone().into_headers();
+        AccountContext::from_request_parts(&mut parts, &self.state)
+            .await
+            .map_err(|e| ApiError::from(e).into())
+    }
+}
+
+#[tonic::async_trait]
+impl Tuitbot for TuitbotGrpc {
+    async fn health(
+        &self,
+        _request: Request<pb::HealthRequest>,
+    ) -> Result<Response<pb::HealthResponse>, Status> {
+        let Json(value) = routes::health::health().await;
+        reply(value)
+    }
+
+    async fn list_approvals(
+        &self,
+        request: Request<pb::ListApprovalsRequest>,
+    ) -> Result<Response<pb::ListApprovalsResponse>, Status> {
+        let ctx = self.account(&request).await?;
+        let req = request.into_inner();
+        let query = params(&[
+            (
+                "status",
+                Some(req.status).filter(|s| !s.is_empty()).map(Value::from),
+            ),
+            ("type", req.action_type.map(Value::from)),
+            ("reviewed_by", req.reviewed_by.map(Value::from)),
+            ("since", req.since.map(Value::from)),
+        ])?;
+        let (_, Json(items)) = routes::approval::list_items(
+            State(self.state.clone()),
+            ctx,
+            Pagination::default(),
+            Query(query),
+        )
+        .await?;
+        reply(json!({ "items": items }))
+    }
+
+    async fn get_approval_stats(
+        &self,
+        request: Request<pb::GetApprovalStatsRequest>,
+    ) -> Result<Response<pb::ApprovalStats>, Status> {
+        let ctx = self.account(&request).await?;
+        let Json(value) = routes::approval::stats(State(self.state.clone()), ctx).await?;
+        reply(value)
+    }
+
+    async fn edit_approval(
+        &self,
+        request: Request<pb::EditApprovalRequest>,
+    ) -> Result<Response<pb::ApprovalItem>, Status> {
+        let ctx = self.account(&request).await?;
+        let req = request.into_inner();
+        let body = params(&[
+            ("content", Some(Value::from(req.content))),
+            (
+                "media_paths",
+                Some(req.media_paths)
+                    .filter(|p| !p.is_empty())
+                    .map(Value::from),
+            ),
+            (
+                "editor",
+                Some(req.editor).filter(|e| !e.is_empty()).map(Value::from),
+            ),
+        ])?;
+        let Json(value) =
+            routes::approval::edit_item(State(self.state.clone()), ctx, Path(req.id), Json(body))
+                .await?;
+        reply(value)
+    }
+
+    async fn approve_item(
+        &self,
+        request: Request<pb::ReviewRequest>,
+    ) -> Result<Response<pb::ReviewResponse>, Status> {
+        let ctx = self.account(&request).await?;
+        let req = request.into_inner();
+        let (id, review) = review_action(req)?;
+        let Json(value) = routes::approval::approve_item(
+            State(self.state.clone()),
+            ctx,
+            Path(id),
+            Some(Json(review)),
+        )
+        .await?;
+        reply(value)
+    }
+
+    async fn reject_item(
+        &self,
+        request: Request<pb::ReviewRequest>,
+    ) -> Result<Response<pb::ReviewResponse>, Status> {
+        let ctx = self.account(&request).await?;
+        let req = request.into_inner();
+        let (id, review) = review_action(req)?;
+        let Json(value) = routes::approval::reject_item(
+            State(self.state.clone()),
+            ctx,
+            Path(id),
+            Some(Json(review)),
+        )
+        .await?;
+        reply(value)
+    }
+
+    async fn list_activity(
+        &self,
+        request: Request<pb::ListActivityRequest>,
+    ) -> Result<Response<pb::ListActivityResponse>, Status> {
+        let ctx = self.account(&request).await?;
+        let req = request.into_inner();
+        let pagination = Pagination {
+            limit: Some(req.limit).filter(|l| *l > 0),
+            offset: Some(req.offset),
+            ..Pagination::default()
+        };
+        let query = params(&[
+            ("type", req.action_type.map(Value::from)),
+            ("status", req.status.map(Value::from)),
+        ])?;
+        let (_, Json(value)) = routes::activity::list_activity(
+            State(self.state.clone()),
+            ctx,
+            pagination,
+            Query(query),
+        )
+        .await?;
+        reply(value)
+    }
+}
+
+/// Turn the REST middleware's JSON rejections (auth, rate limit, size
+/// limits) into gRPC statuses, so clients get a status code instead of a
+/// protocol error.
+pub async fn translate_rejections(request: HttpRequest, next: Next) -> HttpResponse {
+    let response = next.run(request).await;
+    let status = response.status();
+    if status.is_success() {
+        return response;
+    }
+    let code = match status {
+        StatusCode::UNAUTHORIZED => tonic::Code::Unauthenticated,
+        StatusCode::FORBIDDEN => tonic::Code::PermissionDenied,
+        StatusCode::TOO_MANY_REQUESTS | StatusCode::PAYLOAD_TOO_LARGE => {
+            tonic::Code::ResourceExhausted
+        }
+        _ => tonic::Code::Unknown,
+    };
+    let body = axum::body::to_bytes(response.into_body(), 64 * 1024)
+        .await
+        .unwrap_or_default();
+    let message = serde_json::from_slice::<Value>(&body)
+        .ok()
+        .and_then(|v| v["error"].as_str().map(str::to_string))
+        .unwrap_or_else(|| status.to_string());
+    Status::new(code, message).into_http()
+}
+
+/// Build a REST query or body from the fields a gRPC request sets, so unset
+/// fields take the REST defaults.
+fn params<T: DeserializeOwned>(fields: &[(&str, Option<Value>)]) -> Result<T, Status> {
+    let map: Map<String, Value> = fields
+        .iter()
+        .filter_map(|(k, v)| v.clone().map(|v| (k.to_string(), v)))
+        .collect();
+    serde_json::from_value(Value::Object(map)).map_err(|e| Status::invalid_argument(e.to_string()))
+}
+
+fn review_action(
+    req: pb::ReviewRequest,
+) -> Result<(i64, tuitbot_core::storage::approval_queue::ReviewAction), Status> {
+    let review = params(&[
+        ("actor", req.actor.map(Value::from)),
+        ("notes", req.notes.map(Value::from)),
+        ("expected_version", req.expected_version.map(Value::from)),
+    ])?;
+    Ok((req.id, review))
+}
+
+/// Convert a REST handler's JSON into the RPC's response message.
+fn reply<T: DeserializeOwned>(value: Value) -> Result<Response<T>, Status> {
+    serde_json::from_value(value)
+        .map(Response::new)
+        .map_err(|e| Status::internal(format!("response does not fit the proto: {e}")))
+}
+
+impl From<ApiError> for Status {
+    fn from(err: ApiError) -> Self {
+        match err {
+            ApiError::Storage(e) => {
+                tracing::error!("storage error: {e}");
+                Status::internal(e.to_string())
+            }
+            ApiError::NotFound(msg) => Status::not_found(msg),
+            ApiError::BadRequest(msg) => Status::invalid_argument(msg),
+            ApiError::Conflict(msg) => Status::failed_precondition(msg),
+            ApiError::Internal(msg) => {
+                tracing::error!("internal error: {msg}");
+                Status::internal(msg)
+            }
+            ApiError::Forbidden(msg) => Status::permission_denied(msg),
+            ApiError::PayloadTooLarge(msg) | ApiError::ResponseTooLarge(msg) => {
+                Status::resource_exhausted(msg)
+            }
+        }
+    }
+}
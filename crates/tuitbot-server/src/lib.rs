@@ -2,36 +2,72 @@
 //!
 //! Exposes `tuitbot-core`'s storage layer as a REST API with read + write
 //! endpoints, multi-strategy auth (bearer token + session cookie), and a
-//! WebSocket for real-time events.
+//! WebSocket for real-time events. A gRPC service ([`grpc`]) shares the
+//! port, auth, and handlers.
 
 pub mod account;
+pub mod alerts;
+pub mod archive;
 pub mod auth;
+pub mod cache;
 pub mod dashboard;
+pub mod email;
+pub mod embedded;
 pub mod error;
+pub mod grpc;
+pub mod limits;
+pub mod notify;
+pub mod rate_limit;
+pub mod review;
 pub mod routes;
 pub mod state;
+pub mod webhooks;
 pub mod ws;
 
 use std::sync::Arc;
 
+use axum::extract::DefaultBodyLimit;
 use axum::middleware;
-use axum::routing::{delete, get, patch, post};
+use axum::routing::{get, patch, post};
 use axum::Router;
+use tower_http::compression::CompressionLayer;
 use tower_http::cors::CorsLayer;
 use tower_http::trace::TraceLayer;
+use tuitbot_mcp::McpHttpService;
 
+use crate::limits::HttpLimits;
 use crate::state::AppState;
 
+/// Request body limit for media uploads: the largest accepted video plus
+/// room for multipart framing.
+const MEDIA_UPLOAD_LIMIT: usize = 513 * 1024 * 1024;
+
+/// Optional router features and limits.
+#[derive(Default)]
+pub struct RouterOptions {
+    /// MCP service mounted at `/api/mcp/http`, behind the same auth
+    /// middleware as the REST routes.
+    pub mcp: Option<McpHttpService>,
+    /// Request and response size limits.
+    pub limits: HttpLimits,
+    /// Reject every mutation (see [`archive`]).
+    pub archived: bool,
+}
+
 /// Build the complete axum router with all API routes and middleware.
 pub fn build_router(state: Arc<AppState>) -> Router {
-    let api = Router::new()
-        .route("/health", get(routes::health::health))
-        .route("/health/detailed", get(routes::health::health_detailed))
-        // Auth
-        .route("/auth/login", post(auth::routes::login))
-        .route("/auth/logout", post(auth::routes::logout))
-        .route("/auth/status", get(auth::routes::status))
-        // Analytics
+    build_router_with(state, RouterOptions::default())
+}
+
+/// Build the router with explicit [`RouterOptions`].
+pub fn build_router_with(state: Arc<AppState>, options: RouterOptions) -> Router {
+    let RouterOptions {
+        mcp,
+        limits,
+        archived,
+    } = options;
+
+    let analytics = Router::new()
         .route("/analytics/summary", get(routes::analytics::summary))
         .route("/analytics/followers", get(routes::analytics::followers))
         .route(
@@ -39,10 +75,40 @@ pub fn build_router(state: Arc<AppState>) -> Router {
             get(routes::analytics::performance),
         )
         .route("/analytics/topics", get(routes::analytics::topics))
+        .route("/analytics/approvals", get(routes::analytics::approvals))
+        .route("/analytics/qa", get(routes::analytics::qa))
+        .route(
+            "/analytics/inspiration",
+            get(routes::analytics::inspiration),
+        )
+        .route("/analytics/threads", get(routes::analytics::threads))
+        .route(
+            "/analytics/reciprocity",
+            get(routes::analytics::reciprocity),
+        )
         .route(
             "/analytics/recent-performance",
             get(routes::analytics::recent_performance),
         )
+        .route_layer(middleware::from_fn_with_state(state.clone(), cache::cached));
+
+    // Unauthenticated; cached like analytics so embeds never hit the database per view.
+    let public = Router::new()
+        .route("/public/feed", get(routes::public::feed))
+        .route_layer(middleware::from_fn_with_state(state.clone(), cache::cached));
+
+    let mut api = Router::new()
+        .route("/health", get(routes::health::health))
+        .route("/health/detailed", get(routes::health::health_detailed))
+        // Auth
+        .route("/auth/login", post(auth::routes::login))
+        .route("/auth/logout", post(auth::routes::logout))
+        .route("/auth/status", get(auth::routes::status))
+        // Dashboard
+        .route("/summary", get(routes::summary::summary))
+        // Analytics (cached, see `cache`)
+        .merge(analytics)
+        .merge(public)
         // Approval
         .route("/approval/export", get(routes::approval::export_items))
         .route("/approval", get(routes::approval::list_items))
@@ -52,12 +118,28 @@ pub fn build_router(state: Arc<AppState>) -> Router {
             "/approval/{id}/history",
             get(routes::approval::get_edit_history),
         )
-        .route("/approval/{id}", patch(routes::approval::edit_item))
+        .route("/approval/deleted", get(routes::approval::list_deleted))
+        .route(
+            "/approval/{id}",
+            patch(routes::approval::edit_item).delete(routes::approval::delete_item),
+        )
+        .route(
+            "/approval/{id}/restore",
+            post(routes::approval::restore_item),
+        )
+        .route(
+            "/approval/{id}/comments",
+            get(routes::approval::list_comments).post(routes::approval::add_comment),
+        )
         .route(
             "/approval/{id}/approve",
             post(routes::approval::approve_item),
         )
         .route("/approval/{id}/reject", post(routes::approval::reject_item))
+        .route(
+            "/email-review/{token}",
+            get(routes::email_review::confirm).post(routes::email_review::apply),
+        )
         // Activity
         .route("/activity/export", get(routes::activity::export_activity))
         .route("/activity", get(routes::activity::list_activity))
@@ -67,6 +149,12 @@ pub fn build_router(state: Arc<AppState>) -> Router {
         )
         // Replies
         .route("/replies", get(routes::replies::list_replies))
+        .route(
+            "/replies/{id}/explanation",
+            get(routes::replies::get_explanation),
+        )
+        // Lifecycle hooks
+        .route("/hooks/runs", get(routes::hooks::list_runs))
         // Content
         .route(
             "/content/tweets",
@@ -90,7 +178,17 @@ pub fn build_router(state: Arc<AppState>) -> Router {
         )
         .route(
             "/content/drafts/{id}",
-            patch(routes::content::edit_draft).delete(routes::content::delete_draft),
+            get(routes::content::get_draft)
+                .patch(routes::content::edit_draft)
+                .delete(routes::content::delete_draft),
+        )
+        .route(
+            "/content/drafts/{id}/check",
+            post(routes::content::check_draft),
+        )
+        .route(
+            "/content/drafts/{id}/promote",
+            post(routes::content::promote_draft),
         )
         .route(
             "/content/drafts/{id}/schedule",
@@ -102,6 +200,10 @@ pub fn build_router(state: Arc<AppState>) -> Router {
         )
         // Ingest
         .route("/ingest", post(routes::ingest::ingest))
+        .route(
+            "/ingest/candidates",
+            post(routes::ingest::ingest_candidates),
+        )
         // Targets
         .route(
             "/targets",
@@ -115,9 +217,17 @@ pub fn build_router(state: Arc<AppState>) -> Router {
             "/targets/{username}/stats",
             get(routes::targets::target_stats),
         )
+        .route(
+            "/targets/removed",
+            get(routes::targets::list_removed_targets),
+        )
         .route(
             "/targets/{username}",
-            delete(routes::targets::remove_target),
+            patch(routes::targets::update_target).delete(routes::targets::remove_target),
+        )
+        .route(
+            "/targets/{username}/restore",
+            post(routes::targets::restore_target),
         )
         // Strategy
         .route("/strategy/current", get(routes::strategy::current))
@@ -150,6 +260,10 @@ pub fn build_router(state: Arc<AppState>) -> Router {
         // Discovery feed
         .route("/discovery/feed", get(routes::discovery::feed))
         .route("/discovery/keywords", get(routes::discovery::keywords))
+        .route(
+            "/discovery/keywords/stats",
+            get(routes::discovery::keyword_stats),
+        )
         .route(
             "/discovery/{tweet_id}/compose-reply",
             post(routes::discovery::compose_reply),
@@ -159,8 +273,12 @@ pub fn build_router(state: Arc<AppState>) -> Router {
             post(routes::discovery::queue_reply),
         )
         // Media
-        .route("/media/upload", post(routes::media::upload))
+        .route(
+            "/media/upload",
+            post(routes::media::upload).layer(DefaultBodyLimit::max(MEDIA_UPLOAD_LIMIT)),
+        )
         .route("/media/file", get(routes::media::serve_file))
+        .route("/media/{id}", get(routes::media::serve_by_id))
         // LAN settings
         .route(
             "/settings/lan",
@@ -203,6 +321,31 @@ pub fn build_router(state: Arc<AppState>) -> Router {
         )
         .route("/mcp/telemetry/errors", get(routes::mcp::telemetry_errors))
         .route("/mcp/telemetry/recent", get(routes::mcp::telemetry_recent))
+        .route("/mcp/staged", get(routes::mcp::list_staged))
+        .route(
+            "/mcp/staged/{id}/approve",
+            post(routes::mcp::approve_staged),
+        )
+        .route("/mcp/staged/{id}/reject", post(routes::mcp::reject_staged))
+        .route("/mcp/origins", get(routes::mcp::list_origins))
+        .route(
+            "/mcp/origins/{record_type}/{record_id}",
+            get(routes::mcp::get_origin),
+        )
+        // Admin
+        .route(
+            "/admin/kill",
+            get(routes::admin::get_kill_switch)
+                .post(routes::admin::engage_kill_switch)
+                .delete(routes::admin::release_kill_switch),
+        )
+        .route("/admin/resume", post(routes::admin::resume))
+        .route(
+            "/admin/draft-only",
+            get(routes::admin::get_draft_only)
+                .post(routes::admin::enable_draft_only)
+                .delete(routes::admin::disable_draft_only),
+        )
         // Runtime
         .route("/runtime/status", get(routes::runtime::status))
         .route("/runtime/start", post(routes::runtime::start))
@@ -225,16 +368,74 @@ pub fn build_router(state: Arc<AppState>) -> Router {
                 .delete(routes::accounts::delete_account),
         )
         // WebSocket
-        .route("/ws", get(ws::ws_handler))
+        .route("/ws", get(ws::ws_handler));
+    if let Some(mcp) = mcp {
+        api = api.route_service("/mcp/http", mcp);
+    }
+    let mut api = api
+        .layer(DefaultBodyLimit::max(limits.max_request_body_bytes))
+        .layer(middleware::from_fn_with_state(
+            limits,
+            limits::limit_response_size,
+        ))
+        .layer(middleware::from_fn_with_state(
+            state.clone(),
+            cache::invalidate_on_write,
+        ))
+        // Per-identity limit, charged once auth has validated the credential.
+        .layer(middleware::from_fn_with_state(
+            state.clone(),
+            rate_limit::rate_limit_identity,
+        ))
         // Auth middleware — applied to all routes; exempt paths handled internally.
         .layer(middleware::from_fn_with_state(
             state.clone(),
             auth::auth_middleware,
+        ))
+        // IP rate limiting runs first so throttled clients never reach auth or the database.
+        .layer(middleware::from_fn_with_state(
+            state.clone(),
+            rate_limit::rate_limit,
+        ));
+    if archived {
+        api = api.layer(middleware::from_fn(archive::read_only));
+    }
+
+    // gRPC clients address methods from the server root, so the service sits
+    // outside `/api` with the same middleware stack.
+    let mut grpc = Router::new()
+        .route_service(
+            &format!("{}/{{*method}}", grpc::SERVICE_PATH),
+            grpc::service(state.clone()),
+        )
+        .layer(middleware::from_fn_with_state(
+            state.clone(),
+            cache::invalidate_on_write,
+        ))
+        .layer(middleware::from_fn_with_state(
+            state.clone(),
+            rate_limit::rate_limit_identity,
+        ))
+        .layer(middleware::from_fn_with_state(
+            state.clone(),
+            auth::auth_middleware,
+        ))
+        .layer(middleware::from_fn_with_state(
+            state.clone(),
+            rate_limit::rate_limit,
         ));
+    if archived {
+        grpc = grpc.layer(middleware::from_fn(archive::read_only));
+    }
+    let grpc = grpc.layer(middleware::from_fn(grpc::translate_rejections));
 
     Router::new()
         .nest("/api", api)
+        .merge(grpc)
+        .route("/review", get(review::serve_page))
+        .route("/review/{file}", get(review::serve_asset))
         .fallback(dashboard::serve_dashboard)
+        .layer(CompressionLayer::new())
         .layer(CorsLayer::permissive())
         .layer(TraceLayer::new_for_http())
         .with_state(state)
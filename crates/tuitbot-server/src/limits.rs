@@ -0,0 +1,70 @@
+//! Request and response size limits.
+//!
+//! Request bodies are capped through axum's `DefaultBodyLimit` (media upload
+//! gets its own, larger limit). JSON responses larger than
+//! [`HttpLimits::max_response_bytes`] are replaced with a `500` whose body
+//! tells the client to page through the data instead.
+
+use axum::body::HttpBody as _;
+use axum::extract::{Request, State};
+use axum::http::header::CONTENT_TYPE;
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use tuitbot_core::config::ServerConfig;
+
+use crate::error::ApiError;
+
+/// Size limits applied by the router.
+#[derive(Debug, Clone, Copy)]
+pub struct HttpLimits {
+    /// Largest accepted request body, except media uploads.
+    pub max_request_body_bytes: usize,
+    /// Largest JSON response body sent before asking the client to paginate.
+    pub max_response_bytes: usize,
+}
+
+impl Default for HttpLimits {
+    fn default() -> Self {
+        Self::from(&ServerConfig::default())
+    }
+}
+
+impl From<&ServerConfig> for HttpLimits {
+    fn from(config: &ServerConfig) -> Self {
+        Self {
+            max_request_body_bytes: config.max_request_body_bytes,
+            max_response_bytes: config.max_response_bytes,
+        }
+    }
+}
+
+/// Middleware: reject JSON responses over the configured size.
+///
+/// Only bodies with a known exact size are checked; streamed responses
+/// (media files, WebSocket upgrades, MCP event streams) pass through.
+pub async fn limit_response_size(
+    State(limits): State<HttpLimits>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let response = next.run(request).await;
+
+    let is_json = response
+        .headers()
+        .get(CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|v| v.starts_with("application/json"));
+    let Some(size) = response.body().size_hint().exact() else {
+        return response;
+    };
+    if !is_json || size <= limits.max_response_bytes as u64 {
+        return response;
+    }
+
+    ApiError::ResponseTooLarge(format!(
+        "response of {size} bytes exceeds the {} byte limit; request a smaller page \
+         with `limit`/`offset` or a narrower `from`/`to` range",
+        limits.max_response_bytes
+    ))
+    .into_response()
+}
@@ -4,25 +4,31 @@
 //! for the desktop dashboard.
 
 use std::collections::HashMap;
+use std::net::SocketAddr;
 use std::sync::Arc;
 
 use anyhow::Result;
 use clap::Parser;
 use tokio::sync::Mutex;
-use tracing_subscriber::EnvFilter;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::{EnvFilter, Layer};
 use tuitbot_core::auth::passphrase;
+use tuitbot_core::automation::run_token_refresh_loop;
 use tuitbot_core::config::Config;
-use tuitbot_core::content::ContentGenerator;
-use tuitbot_core::llm::factory::create_provider;
 use tuitbot_core::storage;
-use tuitbot_core::storage::accounts::DEFAULT_ACCOUNT_ID;
+use tuitbot_core::storage::write_queue::WriteQueue;
+use tuitbot_core::x_api::auth::TokenManager;
 
 use tokio_util::sync::CancellationToken;
-use tuitbot_core::automation::WatchtowerLoop;
 use tuitbot_core::net::local_ip;
 use tuitbot_server::auth;
+use tuitbot_server::embedded;
+use tuitbot_server::limits::HttpLimits;
+use tuitbot_server::rate_limit::RateLimiter;
 use tuitbot_server::state::AppState;
 use tuitbot_server::ws::WsEvent;
+use tuitbot_server::RouterOptions;
 
 /// Tuitbot API server — serves the dashboard REST API.
 #[derive(Parser)]
@@ -43,13 +49,22 @@ struct Cli {
     /// Reset the web login passphrase and print the new one.
     #[arg(long)]
     reset_passphrase: bool,
+
+    /// Serve analytics and history read-only, with no background tasks.
+    /// Implied when the database was archived with `tuitbot archive`.
+    #[arg(long)]
+    archive: bool,
 }
 
 #[tokio::main]
 async fn main() -> Result<()> {
     // Initialize tracing (respects RUST_LOG env var).
-    tracing_subscriber::fmt()
-        .with_env_filter(EnvFilter::try_from_default_env().unwrap_or_else(|_| "info".into()))
+    tracing_subscriber::registry()
+        .with(
+            tracing_subscriber::fmt::layer()
+                .with_filter(EnvFilter::try_from_default_env().unwrap_or_else(|_| "info".into())),
+        )
+        .with(storage::query_log::layer())
         .init();
 
     let cli = Cli::parse();
@@ -68,8 +83,19 @@ async fn main() -> Result<()> {
         "starting tuitbot server"
     );
 
+    // Load config for storage, server settings, and content generator.
+    let loaded_config = Config::load(Some(&cli.config)).ok();
+    if let Some(config) = &loaded_config {
+        storage::query_log::configure(&config.storage);
+    }
+
     let pool = storage::init_db(&db_path.to_string_lossy()).await?;
 
+    let archived = cli.archive || storage::archive::is_archived(&pool).await?;
+    if archived {
+        tracing::info!("archive mode: serving read-only, background tasks disabled");
+    }
+
     // Ensure the API token file exists and read it.
     let api_token = auth::ensure_api_token(db_dir)?;
     tracing::info!(token_path = %db_dir.join("api_token").display(), "API token ready");
@@ -98,9 +124,6 @@ async fn main() -> Result<()> {
 
     let data_dir = db_dir.to_path_buf();
 
-    // Load config for server settings and content generator.
-    let loaded_config = Config::load(Some(&cli.config)).ok();
-
     // Determine effective bind host/port: CLI flags override config values.
     let bind_host = if cli.host != "127.0.0.1" {
         cli.host.clone()
@@ -119,28 +142,8 @@ async fn main() -> Result<()> {
             .unwrap_or(cli.port)
     };
 
-    // Try to initialize content generator from config (optional — AI assist endpoints need it).
-    let content_generator = match Config::load(Some(&cli.config)) {
-        Ok(config) => match create_provider(&config.llm) {
-            Ok(provider) => {
-                tracing::info!("LLM provider initialized for AI assist endpoints");
-                Some(Arc::new(ContentGenerator::new(provider, config.business)))
-            }
-            Err(e) => {
-                tracing::info!(error = %e, "LLM provider not configured — AI assist endpoints disabled");
-                None
-            }
-        },
-        Err(e) => {
-            tracing::info!(error = %e, "Config not loaded — AI assist endpoints disabled");
-            None
-        }
-    };
-
-    let mut content_generators = HashMap::new();
-    if let Some(cg) = content_generator {
-        content_generators.insert(DEFAULT_ACCOUNT_ID.to_string(), cg);
-    }
+    // Initialize the content generator from config (optional — AI assist endpoints need it).
+    let content_generators = embedded::content_generators(loaded_config.as_ref());
 
     // Extract content_sources config for Watchtower.
     let content_sources = loaded_config
@@ -155,37 +158,20 @@ async fn main() -> Result<()> {
         .unwrap_or_default();
 
     // Conditionally start the Watchtower filesystem watcher.
-    let watchtower_cancel = {
-        let watch_sources: Vec<_> = content_sources
-            .sources
-            .iter()
-            .filter(|s| {
-                if !deployment_mode.allows_source_type(&s.source_type) {
-                    tracing::warn!(
-                        source_type = %s.source_type,
-                        deployment_mode = %deployment_mode,
-                        "skipping content source incompatible with deployment mode"
-                    );
-                    return false;
-                }
-                s.watch && (s.path.is_some() || s.folder_id.is_some())
-            })
-            .collect();
-
-        if !watch_sources.is_empty() {
-            let cancel = CancellationToken::new();
-            let watchtower = WatchtowerLoop::new(pool.clone(), content_sources.clone());
-            let cancel_clone = cancel.clone();
-            tokio::spawn(async move {
-                watchtower.run(cancel_clone).await;
-            });
-            tracing::info!(sources = watch_sources.len(), "Watchtower started");
-            Some(cancel)
-        } else {
-            None
-        }
+    let watchtower_cancel = if archived {
+        None
+    } else {
+        embedded::start_watchtower(&pool, &content_sources, &deployment_mode)
     };
 
+    let rate_limiter = loaded_config
+        .as_ref()
+        .map(|c| RateLimiter::new(c.server.rate_limit.clone()))
+        .unwrap_or_default();
+
+    // Route handlers that log activity batch their inserts through here.
+    let write_queue = (!archived).then(|| WriteQueue::spawn(pool.clone()));
+
     let state = Arc::new(AppState {
         db: pool,
         config_path,
@@ -202,9 +188,40 @@ async fn main() -> Result<()> {
         watchtower_cancel: watchtower_cancel.clone(),
         content_sources,
         deployment_mode,
+        response_cache: Default::default(),
+        rate_limiter,
+        write_queue,
     });
 
-    let router = tuitbot_server::build_router(state);
+    // Background tasks: alerts, notifications, the email digest, and the
+    // embedded MCP server. None of them run in archive mode.
+    let alerts_cancel = CancellationToken::new();
+    if !archived {
+        spawn_background_tasks(&state, loaded_config.as_ref(), &alerts_cancel);
+    }
+
+    // Optionally serve the write-profile MCP server from this process.
+    let mcp_cancel = CancellationToken::new();
+    let mcp = match &loaded_config {
+        Some(config) if !archived => {
+            embedded::mcp_service(config, &state.db, mcp_cancel.clone()).await
+        }
+        _ => None,
+    };
+
+    let limits = loaded_config
+        .as_ref()
+        .map(|c| HttpLimits::from(&c.server))
+        .unwrap_or_default();
+
+    let router = tuitbot_server::build_router_with(
+        state,
+        RouterOptions {
+            mcp,
+            limits,
+            archived,
+        },
+    );
 
     // Warn about network exposure when binding to 0.0.0.0.
     if bind_host == "0.0.0.0" {
@@ -216,12 +233,75 @@ async fn main() -> Result<()> {
 
     let listener = tokio::net::TcpListener::bind(format!("{}:{}", bind_host, bind_port)).await?;
     tracing::info!("listening on http://{}:{}", bind_host, bind_port);
-    axum::serve(listener, router).await?;
+    axum::serve(
+        listener,
+        router.into_make_service_with_connect_info::<SocketAddr>(),
+    )
+    .await?;
 
-    // Cancel watchtower on shutdown.
+    // Cancel background tasks on shutdown.
+    alerts_cancel.cancel();
+    mcp_cancel.cancel();
     if let Some(cancel) = watchtower_cancel {
         cancel.cancel();
     }
 
     Ok(())
 }
+
+/// Start the alert watcher, notification dispatchers, and email digest.
+fn spawn_background_tasks(
+    state: &Arc<AppState>,
+    config: Option<&Config>,
+    cancel: &CancellationToken,
+) {
+    // Broadcast posting failures and token expiry warnings as they appear.
+    tokio::spawn(tuitbot_server::alerts::run_alert_watcher(
+        state.clone(),
+        cancel.clone(),
+    ));
+
+    // Forward events to configured notification webhooks.
+    let webhooks = config
+        .map(|c| c.notifications.webhooks.clone())
+        .unwrap_or_default();
+    tokio::spawn(tuitbot_server::webhooks::run_webhook_dispatcher(
+        webhooks,
+        state.event_tx.subscribe(),
+        cancel.clone(),
+    ));
+
+    // Forward events to configured notification channels.
+    let channels = config
+        .map(|c| c.notifications.channels.clone())
+        .unwrap_or_default();
+    tokio::spawn(tuitbot_server::notify::run_notification_dispatcher(
+        channels,
+        state.event_tx.subscribe(),
+        cancel.clone(),
+    ));
+
+    // Keep the X API tokens fresh so the dashboard and any agent sharing the
+    // token file never find them expired. The loop gets a child token: a
+    // revoked refresh token stops only this task.
+    if let Some(config) = config {
+        let client_id = config.x_api.client_id.clone();
+        let cancel = cancel.child_token();
+        tokio::spawn(async move {
+            match TokenManager::load(&client_id).await {
+                Ok(tokens) => run_token_refresh_loop(Arc::new(tokens), cancel).await,
+                Err(e) => tracing::info!("X API token refresh not started: {e}"),
+            }
+        });
+    }
+
+    // Send the daily email digest, if configured.
+    if let Some(config) = config {
+        tokio::spawn(tuitbot_server::email::run_email_digest(
+            state.clone(),
+            config.notifications.email.clone(),
+            config.schedule.timezone.clone(),
+            cancel.clone(),
+        ));
+    }
+}
@@ -0,0 +1,193 @@
+//! Parsing of notification URLs and the per-service request payloads.
+
+use serde_json::{json, Value};
+use tuitbot_core::config::EmailConfig;
+
+/// A parsed notification URL.
+#[derive(Debug, Clone)]
+pub enum Channel {
+    Discord {
+        webhook_id: String,
+        token: String,
+    },
+    Slack {
+        tokens: [String; 3],
+    },
+    Telegram {
+        bot_token: String,
+        chat_ids: Vec<String>,
+    },
+    Email(Box<EmailConfig>),
+}
+
+impl Channel {
+    /// Parse a notification URL.
+    pub fn parse(url: &str) -> Result<Self, String> {
+        let (scheme, rest) = url
+            .trim()
+            .split_once("://")
+            .ok_or_else(|| "missing scheme, e.g. discord://".to_string())?;
+        let (location, query) = rest.split_once('?').unwrap_or((rest, ""));
+        let segments: Vec<String> = location
+            .split('/')
+            .filter(|s| !s.is_empty())
+            .map(percent_decode)
+            .collect();
+
+        match scheme {
+            "discord" => match &segments[..] {
+                [webhook_id, token] => Ok(Self::Discord {
+                    webhook_id: webhook_id.clone(),
+                    token: token.clone(),
+                }),
+                _ => Err("expected discord://<webhook_id>/<webhook_token>".to_string()),
+            },
+            "slack" => match &segments[..] {
+                [a, b, c] => Ok(Self::Slack {
+                    tokens: [a.clone(), b.clone(), c.clone()],
+                }),
+                _ => Err("expected slack://<token_a>/<token_b>/<token_c>".to_string()),
+            },
+            "tgram" => match &segments[..] {
+                [bot_token, chat_ids @ ..] if !chat_ids.is_empty() => Ok(Self::Telegram {
+                    bot_token: bot_token.clone(),
+                    chat_ids: chat_ids.to_vec(),
+                }),
+                _ => Err("expected tgram://<bot_token>/<chat_id>".to_string()),
+            },
+            "mailto" | "mailtos" => parse_mailto(scheme == "mailtos", location, query),
+            other => Err(format!("unsupported scheme '{other}'")),
+        }
+    }
+
+    /// Service name, for logs and `tuitbot notify test`.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::Discord { .. } => "discord",
+            Self::Slack { .. } => "slack",
+            Self::Telegram { .. } => "telegram",
+            Self::Email(_) => "email",
+        }
+    }
+
+    /// HTTP requests that deliver a message: URL and JSON body.
+    pub(super) fn requests(&self, title: &str, body: &str) -> Vec<(String, Value)> {
+        let text = if body.is_empty() {
+            title.to_string()
+        } else {
+            format!("{title}\n{body}")
+        };
+        match self {
+            Self::Discord { webhook_id, token } => vec![(
+                format!("https://discord.com/api/webhooks/{webhook_id}/{token}"),
+                json!({ "content": text }),
+            )],
+            Self::Slack { tokens: [a, b, c] } => vec![(
+                format!("https://hooks.slack.com/services/{a}/{b}/{c}"),
+                json!({ "text": text }),
+            )],
+            Self::Telegram {
+                bot_token,
+                chat_ids,
+            } => chat_ids
+                .iter()
+                .map(|chat_id| {
+                    (
+                        format!("https://api.telegram.org/bot{bot_token}/sendMessage"),
+                        json!({ "chat_id": chat_id, "text": text }),
+                    )
+                })
+                .collect(),
+            Self::Email(_) => Vec::new(),
+        }
+    }
+}
+
+fn parse_mailto(implicit_tls: bool, location: &str, query: &str) -> Result<Channel, String> {
+    let (userinfo, hostport) = match location.trim_end_matches('/').rsplit_once('@') {
+        Some((userinfo, hostport)) => (Some(userinfo), hostport),
+        None => (None, location.trim_end_matches('/')),
+    };
+    let (username, password) = match userinfo {
+        Some(info) => match info.split_once(':') {
+            Some((user, pass)) => (percent_decode(user), Some(percent_decode(pass))),
+            None => (percent_decode(info), None),
+        },
+        None => (String::new(), None),
+    };
+    let (host, port) = match hostport.rsplit_once(':') {
+        Some((host, port)) => (
+            host,
+            Some(
+                port.parse::<u16>()
+                    .map_err(|_| format!("invalid port '{port}'"))?,
+            ),
+        ),
+        None => (hostport, None),
+    };
+    if host.is_empty() {
+        return Err("expected mailto://[user:pass@]<smtp_host>?to=<address>".to_string());
+    }
+
+    let mut to = Vec::new();
+    let mut from = None;
+    for (key, value) in query
+        .split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .map(|(k, v)| (k, percent_decode(v)))
+    {
+        match key {
+            "to" => to.extend(
+                value
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|a| !a.is_empty())
+                    .map(String::from),
+            ),
+            "from" => from = Some(value),
+            _ => {}
+        }
+    }
+    // A login that is an address doubles as sender and default recipient.
+    if username.contains('@') {
+        if to.is_empty() {
+            to.push(username.clone());
+        }
+        from.get_or_insert_with(|| username.clone());
+    }
+    let Some(from) = from.or_else(|| to.first().cloned()) else {
+        return Err("mailto:// needs a to= address".to_string());
+    };
+
+    Ok(Channel::Email(Box::new(EmailConfig {
+        smtp_host: host.to_string(),
+        smtp_port: port.unwrap_or(if implicit_tls { 465 } else { 587 }),
+        smtp_security: if implicit_tls { "tls" } else { "starttls" }.to_string(),
+        smtp_username: username,
+        smtp_password: password,
+        from,
+        to,
+        ..EmailConfig::default()
+    })))
+}
+
+fn percent_decode(s: &str) -> String {
+    let bytes = s.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' {
+            if let Some(byte) = s
+                .get(i + 1..i + 3)
+                .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+            {
+                out.push(byte);
+                i += 3;
+                continue;
+            }
+        }
+        out.push(bytes[i]);
+        i += 1;
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
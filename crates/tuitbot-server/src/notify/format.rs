@@ -0,0 +1,138 @@
+//! Human-readable message text for each event.
+
+use crate::ws::WsEvent;
+
+/// Title and body of the message sent for `event`.
+pub fn describe(event: &WsEvent) -> (String, String) {
+    match event {
+        WsEvent::ActionPerformed {
+            action_type,
+            target,
+            content,
+            ..
+        } => {
+            let target = if target.is_empty() {
+                String::new()
+            } else {
+                format!(" ({target})")
+            };
+            (
+                format!("Tuitbot {action_type} sent{target}"),
+                content.clone(),
+            )
+        }
+        WsEvent::ApprovalQueued {
+            id,
+            action_type,
+            content,
+            ..
+        } => (
+            format!("New {action_type} awaiting approval (#{id})"),
+            content.clone(),
+        ),
+        WsEvent::ApprovalUpdated {
+            id,
+            status,
+            action_type,
+            actor,
+        } => (
+            format!("{action_type} #{id} {status}"),
+            actor
+                .as_ref()
+                .map(|a| format!("by {a}"))
+                .unwrap_or_default(),
+        ),
+        WsEvent::FollowerUpdate { count, change } => {
+            (format!("Followers: {count} ({change:+})"), String::new())
+        }
+        WsEvent::RuntimeStatus {
+            running,
+            active_loops,
+        } => (
+            if *running {
+                "Tuitbot automation started".to_string()
+            } else {
+                "Tuitbot automation stopped".to_string()
+            },
+            active_loops.join(", "),
+        ),
+        WsEvent::TweetDiscovered {
+            tweet_id,
+            author,
+            score,
+            ..
+        } => (
+            format!("Tweet by @{author} scored {score:.0}"),
+            format!("https://x.com/{author}/status/{tweet_id}"),
+        ),
+        WsEvent::ActionSkipped {
+            action_type,
+            reason,
+            ..
+        } => (format!("Tuitbot {action_type} skipped"), reason.clone()),
+        WsEvent::ContentScheduled {
+            id,
+            content_type,
+            scheduled_for,
+        } => (
+            format!("{content_type} #{id} scheduled"),
+            scheduled_for
+                .as_ref()
+                .map(|t| format!("for {t}"))
+                .unwrap_or_default(),
+        ),
+        WsEvent::CircuitBreakerTripped {
+            state,
+            error_count,
+            cooldown_remaining_seconds,
+            ..
+        } => (
+            format!("Tuitbot circuit breaker {state}"),
+            format!("{error_count} errors, cooldown {cooldown_remaining_seconds}s"),
+        ),
+        WsEvent::KillSwitchChanged { active, reason, .. } => (
+            if *active {
+                "Tuitbot kill switch engaged".to_string()
+            } else {
+                "Tuitbot kill switch released".to_string()
+            },
+            reason.clone().unwrap_or_default(),
+        ),
+        WsEvent::DraftOnlyChanged { active, reason, .. } => (
+            if *active {
+                "Tuitbot draft-only mode on".to_string()
+            } else {
+                "Tuitbot draft-only mode lifted".to_string()
+            },
+            if *active {
+                reason.clone().unwrap_or_default()
+            } else {
+                "Approved items will be posted again.".to_string()
+            },
+        ),
+        WsEvent::PostFailed {
+            action_type,
+            message,
+            ..
+        } => (
+            format!("Tuitbot {action_type} failed to post"),
+            message.clone(),
+        ),
+        WsEvent::TokenExpiring {
+            hours_remaining, ..
+        } => (
+            format!("X token expires in {hours_remaining}h"),
+            "Run `tuitbot auth` to sign in again.".to_string(),
+        ),
+        WsEvent::ApprovalSlaBreached {
+            id,
+            action_type,
+            priority,
+            pending_hours,
+        } => (
+            format!("Approval #{id} waiting {pending_hours:.0}h"),
+            format!("This {action_type} is past its review SLA (priority {priority})."),
+        ),
+        WsEvent::Error { message } => ("Tuitbot error".to_string(), message.clone()),
+    }
+}
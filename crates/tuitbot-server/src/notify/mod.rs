@@ -0,0 +1,170 @@
+//! Chat and email notifications from Apprise-style URLs.
+//!
+//! Each `[[notifications.channels]]` entry is a URL whose scheme picks the
+//! service:
+//!
+//! | URL | Sends to |
+//! |-----|----------|
+//! | `discord://<webhook_id>/<webhook_token>` | Discord webhook |
+//! | `slack://<token_a>/<token_b>/<token_c>` | Slack incoming webhook |
+//! | `tgram://<bot_token>/<chat_id>[/<chat_id>...]` | Telegram bot |
+//! | `mailto://[user:pass@]<smtp_host>[:port]?to=<addr>[&from=<addr>]` | SMTP with STARTTLS (`mailtos://` for implicit TLS) |
+//!
+//! Unlike webhooks, channels get a short human-readable message per event.
+//! Failed deliveries are retried with exponential backoff.
+
+mod channel;
+mod format;
+#[cfg(test)]
+mod tests;
+
+use std::time::Duration;
+
+use serde_json::Value;
+use tokio::sync::broadcast::{self, error::RecvError};
+use tokio_util::sync::CancellationToken;
+use tuitbot_core::config::NotificationChannelConfig;
+
+use crate::webhooks::event_name;
+use crate::ws::WsEvent;
+
+pub use channel::Channel;
+pub use format::describe;
+
+/// Per-request timeout for channel deliveries.
+const DELIVERY_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Delay before the first retry; doubled for each further attempt.
+const RETRY_BACKOFF: Duration = Duration::from_secs(2);
+
+/// Send one message to `channel`, retrying up to `retries` more times.
+pub async fn send(
+    client: &reqwest::Client,
+    channel: &Channel,
+    title: &str,
+    body: &str,
+    retries: u32,
+) -> Result<(), String> {
+    let backoff = RETRY_BACKOFF;
+    if let Channel::Email(email) = channel {
+        return with_retries(retries, backoff, || async {
+            crate::email::send_email(email, title, body.to_string())
+                .await
+                .map_err(|e| (true, e.to_string()))
+        })
+        .await;
+    }
+    for (url, payload) in channel.requests(title, body) {
+        with_retries(retries, backoff, || post(client, &url, &payload)).await?;
+    }
+    Ok(())
+}
+
+/// POST `payload`; errors are flagged retryable unless the service rejected
+/// the request outright.
+async fn post(client: &reqwest::Client, url: &str, payload: &Value) -> Result<(), (bool, String)> {
+    let response = client
+        .post(url)
+        .json(payload)
+        .send()
+        .await
+        .map_err(|e| (true, e.without_url().to_string()))?;
+    let status = response.status();
+    if status.is_success() {
+        return Ok(());
+    }
+    let retryable = status.is_server_error() || status.as_u16() == 429;
+    Err((retryable, format!("HTTP {status}")))
+}
+
+async fn with_retries<F, Fut>(retries: u32, backoff: Duration, mut attempt: F) -> Result<(), String>
+where
+    F: FnMut() -> Fut,
+    Fut: std::future::Future<Output = Result<(), (bool, String)>>,
+{
+    let mut delay = backoff;
+    let mut remaining = retries;
+    loop {
+        match attempt().await {
+            Ok(()) => return Ok(()),
+            Err((true, _)) if remaining > 0 => {
+                remaining -= 1;
+                tokio::time::sleep(delay).await;
+                delay *= 2;
+            }
+            Err((_, message)) => return Err(message),
+        }
+    }
+}
+
+/// A client suited to channel deliveries.
+pub fn http_client() -> reqwest::Client {
+    reqwest::Client::builder()
+        .timeout(DELIVERY_TIMEOUT)
+        .build()
+        .unwrap_or_default()
+}
+
+/// Deliver events from `events` to `channels` until `cancel` fires.
+///
+/// Channels whose URL does not parse are logged and skipped. Each delivery
+/// runs in its own task, so a slow or retrying channel never delays others.
+pub async fn run_notification_dispatcher(
+    channels: Vec<NotificationChannelConfig>,
+    mut events: broadcast::Receiver<WsEvent>,
+    cancel: CancellationToken,
+) {
+    let channels: Vec<(NotificationChannelConfig, Channel)> = channels
+        .into_iter()
+        .filter_map(|config| match Channel::parse(&config.url) {
+            Ok(channel) => Some((config, channel)),
+            Err(e) => {
+                tracing::warn!(scheme = config.scheme(), error = %e, "Ignoring notification channel");
+                None
+            }
+        })
+        .collect();
+    if channels.is_empty() {
+        return;
+    }
+    tracing::info!(channels = channels.len(), "Notification channels started");
+
+    let client = http_client();
+    loop {
+        let event = tokio::select! {
+            _ = cancel.cancelled() => break,
+            received = events.recv() => match received {
+                Ok(event) => event,
+                Err(RecvError::Lagged(skipped)) => {
+                    tracing::warn!(skipped, "Notification dispatcher fell behind, events dropped");
+                    continue;
+                }
+                Err(RecvError::Closed) => break,
+            },
+        };
+
+        let name = event_name(&event);
+        let (title, body) = describe(&event);
+        for (config, channel) in &channels {
+            if !config.wants(&name) {
+                continue;
+            }
+            let client = client.clone();
+            let channel = channel.clone();
+            let (title, body) = (title.clone(), body.clone());
+            let retries = config.retries;
+            tokio::spawn(async move {
+                match send(&client, &channel, &title, &body, retries).await {
+                    Ok(()) => tracing::debug!(channel = channel.kind(), "Notification delivered"),
+                    Err(e) => tracing::warn!(
+                        channel = channel.kind(),
+                        error = %e,
+                        "Notification delivery failed"
+                    ),
+                }
+            });
+        }
+    }
+
+    tracing::info!("Notification dispatcher stopped");
+}
@@ -0,0 +1,325 @@
+//! Per-client request rate limiting for the HTTP API.
+//!
+//! Each client gets a token bucket holding up to `burst` requests, refilled
+//! at `per_second`. [`rate_limit`] runs before authentication and keys every
+//! request on its IP address: the peer address, or the `X-Forwarded-For`
+//! client when the peer is a configured trusted proxy. Once
+//! `auth_middleware` has validated a bearer token or session it records a
+//! [`ClientIdentity`], and [`rate_limit_identity`] charges that identity's
+//! bucket too. Unvalidated credentials never select a bucket, so a client
+//! cannot dodge its limit by sending made-up tokens or forwarding headers.
+//!
+//! A client that runs dry gets `429 Too Many Requests` with a `Retry-After`
+//! header, so a runaway dashboard or script cannot starve the automation
+//! loops of database access.
+
+use std::collections::HashMap;
+use std::net::{IpAddr, SocketAddr};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use axum::extract::{ConnectInfo, Request, State};
+use axum::http::header::RETRY_AFTER;
+use axum::http::{HeaderMap, HeaderValue, StatusCode};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use axum::Json;
+use serde::Serialize;
+use serde_json::json;
+use sha2::{Digest, Sha256};
+use tuitbot_core::config::ApiRateLimitConfig;
+
+use crate::state::AppState;
+
+/// Paths that are never rate limited: liveness probes and the long-lived
+/// WebSocket connection.
+const EXEMPT_PATHS: &[&str] = &["/health", "/api/health", "/ws", "/api/ws"];
+
+/// Tracked clients above which idle buckets are pruned.
+const PRUNE_THRESHOLD: usize = 10_000;
+
+struct Bucket {
+    tokens: f64,
+    updated: Instant,
+}
+
+/// Rate limiter counters, as reported by `/api/health/detailed`.
+#[derive(Debug, Clone, Serialize)]
+pub struct RateLimitStats {
+    pub enabled: bool,
+    pub burst: u32,
+    pub per_second: f64,
+    /// Clients with a bucket in memory.
+    pub tracked_clients: usize,
+    /// Requests rejected with 429 since startup.
+    pub throttled_total: u64,
+}
+
+/// Token-bucket rate limiter keyed by client identity.
+pub struct RateLimiter {
+    config: ApiRateLimitConfig,
+    trusted_proxies: Vec<IpAddr>,
+    buckets: Mutex<HashMap<String, Bucket>>,
+    throttled: AtomicU64,
+}
+
+impl Default for RateLimiter {
+    fn default() -> Self {
+        Self::new(ApiRateLimitConfig::default())
+    }
+}
+
+impl RateLimiter {
+    pub fn new(config: ApiRateLimitConfig) -> Self {
+        let trusted_proxies = config
+            .trusted_proxies
+            .iter()
+            .filter_map(|proxy| proxy.parse().ok())
+            .collect();
+        Self {
+            config,
+            trusted_proxies,
+            buckets: Mutex::new(HashMap::new()),
+            throttled: AtomicU64::new(0),
+        }
+    }
+
+    /// Take one request from `client`'s bucket.
+    ///
+    /// Returns `Err(wait)` with the time until a request is available when
+    /// the bucket is empty.
+    pub fn check(&self, client: &str, now: Instant) -> Result<(), Duration> {
+        if !self.config.enabled {
+            return Ok(());
+        }
+        let burst = f64::from(self.config.burst.max(1));
+        let rate = self.config.per_second.max(f64::MIN_POSITIVE);
+
+        let mut buckets = self.buckets.lock().unwrap_or_else(|e| e.into_inner());
+        if buckets.len() >= PRUNE_THRESHOLD {
+            buckets.retain(|_, b| {
+                b.tokens + now.duration_since(b.updated).as_secs_f64() * rate < burst
+            });
+        }
+
+        let bucket = buckets.entry(client.to_string()).or_insert(Bucket {
+            tokens: burst,
+            updated: now,
+        });
+        let elapsed = now.saturating_duration_since(bucket.updated).as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * rate).min(burst);
+        bucket.updated = now;
+
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            Ok(())
+        } else {
+            self.throttled.fetch_add(1, Ordering::Relaxed);
+            Err(Duration::from_secs_f64((1.0 - bucket.tokens) / rate))
+        }
+    }
+
+    pub fn stats(&self) -> RateLimitStats {
+        let tracked_clients = self.buckets.lock().unwrap_or_else(|e| e.into_inner()).len();
+        RateLimitStats {
+            enabled: self.config.enabled,
+            burst: self.config.burst,
+            per_second: self.config.per_second,
+            tracked_clients,
+            throttled_total: self.throttled.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// A bearer token or session validated by `auth_middleware`.
+///
+/// Stored in request extensions as a hashed key, so the bucket map never
+/// holds credentials in the clear.
+#[derive(Debug, Clone)]
+pub struct ClientIdentity(String);
+
+impl ClientIdentity {
+    pub fn token(token: &str) -> Self {
+        Self(format!("token:{}", short_hash(token)))
+    }
+
+    pub fn session(session: &str) -> Self {
+        Self(format!("session:{}", short_hash(session)))
+    }
+}
+
+fn short_hash(value: &str) -> String {
+    hex::encode(&Sha256::digest(value.as_bytes())[..8])
+}
+
+/// The address a request came from.
+///
+/// `X-Forwarded-For` is only read when the peer is a trusted proxy. The
+/// header is walked from the right, skipping further trusted proxies, so the
+/// result is the first hop no trusted proxy vouches for.
+fn client_ip(headers: &HeaderMap, peer: Option<IpAddr>, trusted: &[IpAddr]) -> IpAddr {
+    let peer = peer.unwrap_or(IpAddr::from([127, 0, 0, 1]));
+    if !trusted.contains(&peer) {
+        return peer;
+    }
+    let forwarded = headers
+        .get_all("x-forwarded-for")
+        .iter()
+        .filter_map(|v| v.to_str().ok())
+        .flat_map(|v| v.split(','))
+        .filter_map(|ip| ip.trim().parse::<IpAddr>().ok())
+        .collect::<Vec<_>>();
+    forwarded
+        .into_iter()
+        .rev()
+        .find(|ip| !trusted.contains(ip))
+        .unwrap_or(peer)
+}
+
+/// Middleware: reject requests from IP addresses over their rate limit.
+///
+/// Runs before authentication so throttled clients never reach the database.
+pub async fn rate_limit(
+    State(state): State<Arc<AppState>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    if EXEMPT_PATHS.contains(&request.uri().path()) {
+        return next.run(request).await;
+    }
+
+    let peer = request
+        .extensions()
+        .get::<ConnectInfo<SocketAddr>>()
+        .map(|info| info.0.ip());
+    let ip = client_ip(request.headers(), peer, &state.rate_limiter.trusted_proxies);
+    charge(&state.rate_limiter, &format!("ip:{ip}"), request, next).await
+}
+
+/// Middleware: reject authenticated clients over their rate limit.
+///
+/// Layered inside `auth_middleware`, which attaches the [`ClientIdentity`];
+/// requests without one were already charged by IP in [`rate_limit`].
+pub async fn rate_limit_identity(
+    State(state): State<Arc<AppState>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    match request.extensions().get::<ClientIdentity>().cloned() {
+        Some(identity) => charge(&state.rate_limiter, &identity.0, request, next).await,
+        None => next.run(request).await,
+    }
+}
+
+async fn charge(limiter: &RateLimiter, client: &str, request: Request, next: Next) -> Response {
+    match limiter.check(client, Instant::now()) {
+        Ok(()) => next.run(request).await,
+        Err(wait) => {
+            let retry_after = (wait.as_secs_f64().ceil() as u64).max(1);
+            tracing::warn!(client = %client, retry_after, "API request rate limited");
+            let mut response = (
+                StatusCode::TOO_MANY_REQUESTS,
+                Json(json!({
+                    "error": format!("rate limit exceeded; retry in {retry_after}s"),
+                    "retry_after": retry_after,
+                })),
+            )
+                .into_response();
+            response
+                .headers_mut()
+                .insert(RETRY_AFTER, HeaderValue::from(retry_after));
+            response
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn limiter(burst: u32, per_second: f64) -> RateLimiter {
+        RateLimiter::new(ApiRateLimitConfig {
+            enabled: true,
+            burst,
+            per_second,
+            trusted_proxies: Vec::new(),
+        })
+    }
+
+    #[test]
+    fn allows_burst_then_throttles() {
+        let limiter = limiter(3, 1.0);
+        let now = Instant::now();
+        for _ in 0..3 {
+            assert!(limiter.check("a", now).is_ok());
+        }
+        let wait = limiter.check("a", now).unwrap_err();
+        assert_eq!(wait, Duration::from_secs(1));
+        assert_eq!(limiter.stats().throttled_total, 1);
+
+        // Other clients have their own bucket.
+        assert!(limiter.check("b", now).is_ok());
+    }
+
+    #[test]
+    fn refills_at_steady_rate() {
+        let limiter = limiter(1, 2.0);
+        let now = Instant::now();
+        assert!(limiter.check("a", now).is_ok());
+        assert!(limiter.check("a", now).is_err());
+        assert!(limiter.check("a", now + Duration::from_millis(500)).is_ok());
+    }
+
+    #[test]
+    fn disabled_never_throttles() {
+        let limiter = RateLimiter::new(ApiRateLimitConfig {
+            enabled: false,
+            burst: 1,
+            per_second: 0.1,
+            trusted_proxies: Vec::new(),
+        });
+        let now = Instant::now();
+        for _ in 0..10 {
+            assert!(limiter.check("a", now).is_ok());
+        }
+    }
+
+    #[test]
+    fn client_ip_ignores_forwarded_header_from_untrusted_peer() {
+        let mut headers = HeaderMap::new();
+        headers.insert("x-forwarded-for", "10.0.0.5".parse().unwrap());
+        let peer = IpAddr::from([192, 168, 1, 20]);
+        assert_eq!(client_ip(&headers, Some(peer), &[]), peer);
+    }
+
+    #[test]
+    fn client_ip_reads_forwarded_header_behind_trusted_proxy() {
+        let proxy = IpAddr::from([10, 0, 0, 1]);
+        let inner = IpAddr::from([10, 0, 0, 2]);
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "x-forwarded-for",
+            "1.1.1.1, 203.0.113.7, 10.0.0.2".parse().unwrap(),
+        );
+
+        // The spoofable leftmost entry is skipped in favour of the last
+        // address no trusted proxy added.
+        let ip = client_ip(&headers, Some(proxy), &[proxy, inner]);
+        assert_eq!(ip, IpAddr::from([203, 0, 113, 7]));
+
+        // Without a usable header the proxy itself is the client.
+        assert_eq!(client_ip(&HeaderMap::new(), Some(proxy), &[proxy]), proxy);
+    }
+
+    #[test]
+    fn identity_keys_hide_credentials() {
+        let ClientIdentity(key) = ClientIdentity::token("secret");
+        assert!(key.starts_with("token:"));
+        assert!(!key.contains("secret"));
+
+        let ClientIdentity(key) = ClientIdentity::session("secret");
+        assert!(key.starts_with("session:"));
+        assert!(!key.contains("secret"));
+    }
+}
@@ -0,0 +1,47 @@
+//! Serves the keyboard-first approval review page at `/review`.
+//!
+//! A single static page baked into the binary, for reviewing the approval
+//! queue from a browser over an SSH tunnel without the desktop app. The
+//! page itself is public; its script signs in with the web passphrase and
+//! uses the REST approval routes.
+
+use axum::extract::Path;
+use axum::http::{header, StatusCode};
+use axum::response::{IntoResponse, Response};
+use rust_embed::Embed;
+
+#[derive(Embed)]
+#[folder = "review/"]
+struct ReviewAssets;
+
+/// Only same-origin scripts and styles, and API calls to this server.
+const CONTENT_SECURITY_POLICY: &str =
+    "default-src 'none'; script-src 'self'; style-src 'self'; connect-src 'self'; \
+     form-action 'none'; frame-ancestors 'none'";
+
+/// `GET /review` — the review page.
+pub async fn serve_page() -> Response {
+    asset_response("index.html")
+}
+
+/// `GET /review/{file}` — the page's script and stylesheet.
+pub async fn serve_asset(Path(file): Path<String>) -> Response {
+    asset_response(&file)
+}
+
+fn asset_response(path: &str) -> Response {
+    let Some(file) = ReviewAssets::get(path) else {
+        return (StatusCode::NOT_FOUND, "Not found").into_response();
+    };
+    let mime = mime_guess::from_path(path).first_or_octet_stream();
+    (
+        StatusCode::OK,
+        [
+            (header::CONTENT_TYPE, mime.as_ref()),
+            (header::CACHE_CONTROL, "no-cache"),
+            (header::CONTENT_SECURITY_POLICY, CONTENT_SECURITY_POLICY),
+        ],
+        file.data.clone(),
+    )
+        .into_response()
+}
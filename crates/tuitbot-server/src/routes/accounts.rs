@@ -7,7 +7,6 @@ use std::sync::Arc;
 
 use axum::extract::{Path, State};
 use axum::Json;
-use serde::Deserialize;
 use serde_json::{json, Value};
 use tuitbot_core::storage::accounts;
 
@@ -15,6 +14,10 @@ use crate::account::{require_mutate, AccountContext, Role};
 use crate::error::ApiError;
 use crate::state::AppState;
 
+pub use tuitbot_types::accounts::{
+    CreateAccountRequest, RemoveRoleRequest, SetRoleRequest, UpdateAccountRequest,
+};
+
 /// `GET /api/accounts` — list all active accounts (admin only).
 pub async fn list_accounts(
     State(state): State<Arc<AppState>>,
@@ -38,11 +41,6 @@ pub async fn get_account(
     Ok(Json(json!(account)))
 }
 
-#[derive(Deserialize)]
-pub struct CreateAccountRequest {
-    pub label: String,
-}
-
 /// `POST /api/accounts` — create a new account (admin only).
 pub async fn create_account(
     State(state): State<Arc<AppState>>,
@@ -60,12 +58,6 @@ pub async fn create_account(
     Ok(Json(json!(account)))
 }
 
-#[derive(Deserialize)]
-pub struct UpdateAccountRequest {
-    pub label: Option<String>,
-    pub config_overrides: Option<String>,
-}
-
 /// `PATCH /api/accounts/{id}` — update account config/label (admin only).
 pub async fn update_account(
     State(state): State<Arc<AppState>>,
@@ -125,12 +117,6 @@ pub async fn list_roles(
     Ok(Json(json!(roles)))
 }
 
-#[derive(Deserialize)]
-pub struct SetRoleRequest {
-    pub actor: String,
-    pub role: String,
-}
-
 /// `POST /api/accounts/{id}/roles` — set a role for an actor on an account.
 pub async fn set_role(
     State(state): State<Arc<AppState>>,
@@ -150,11 +136,6 @@ pub async fn set_role(
     Ok(Json(json!({"status": "ok"})))
 }
 
-#[derive(Deserialize)]
-pub struct RemoveRoleRequest {
-    pub actor: String,
-}
-
 /// `DELETE /api/accounts/{id}/roles` — remove a role assignment.
 pub async fn remove_role(
     State(state): State<Arc<AppState>>,
@@ -3,68 +3,69 @@
 use std::sync::Arc;
 
 use axum::extract::{Query, State};
+use axum::http::HeaderMap;
 use axum::Json;
 use serde::Deserialize;
 use serde_json::{json, Value};
+use tuitbot_core::storage::action_log::{ActionFilter, ACTION_SORT_FIELDS};
+use tuitbot_core::storage::activity::ActivityEvent;
+use tuitbot_core::storage::pagination::Sort;
 use tuitbot_core::storage::{action_log, rate_limits};
 
+use super::{page_headers, Pagination};
 use crate::account::AccountContext;
 use crate::error::ApiError;
 use crate::state::AppState;
 
-/// Query parameters for the activity endpoint.
+/// Filters for the activity endpoint; paging comes from [`Pagination`].
 #[derive(Deserialize)]
 pub struct ActivityQuery {
-    /// Maximum number of actions to return (default: 50).
-    #[serde(default = "default_limit")]
-    pub limit: u32,
-    /// Offset for pagination (default: 0).
-    #[serde(default)]
-    pub offset: u32,
     /// Filter by action type. Use "all" or omit for no filter.
     #[serde(rename = "type")]
     pub action_type: Option<String>,
     /// Filter by status (e.g. "failure" for errors).
     pub status: Option<String>,
+    /// Actions logged at or after this ISO-8601 timestamp.
+    pub since: Option<String>,
+    /// Actions logged before this ISO-8601 timestamp.
+    pub until: Option<String>,
 }
 
-fn default_limit() -> u32 {
-    50
-}
-
-/// `GET /api/activity` — paginated, filterable action log.
+/// `GET /api/activity` — paginated, filterable action log, newest first
+/// (default limit: 50).
+///
+/// Each row is returned as an [`ActivityEvent`]: the raw fields plus a
+/// kind, icon hint, entity links, and a one-line summary. Sortable by
+/// `created_at`, `type`, and `status`.
 pub async fn list_activity(
     State(state): State<Arc<AppState>>,
     ctx: AccountContext,
+    pagination: Pagination,
     Query(params): Query<ActivityQuery>,
-) -> Result<Json<Value>, ApiError> {
-    let type_filter =
-        params
-            .action_type
-            .as_deref()
-            .and_then(|t| if t == "all" { None } else { Some(t) });
-    let status_filter = params.status.as_deref();
-
-    let actions = action_log::get_actions_paginated_for(
-        &state.db,
-        &ctx.account_id,
-        params.limit,
-        params.offset,
-        type_filter,
-        status_filter,
-    )
-    .await?;
-
-    let total =
-        action_log::get_actions_count_for(&state.db, &ctx.account_id, type_filter, status_filter)
-            .await?;
-
-    Ok(Json(json!({
-        "actions": actions,
-        "total": total,
-        "limit": params.limit,
-        "offset": params.offset,
-    })))
+) -> Result<(HeaderMap, Json<Value>), ApiError> {
+    let page = pagination.page(50, ACTION_SORT_FIELDS, Some(Sort::desc("created_at")))?;
+    let filter = ActionFilter {
+        action_type: params.action_type.as_deref().filter(|t| *t != "all"),
+        status: params.status.as_deref(),
+        since: params.since.as_deref(),
+        until: params.until.as_deref(),
+    };
+
+    let actions =
+        action_log::get_actions_page_for(&state.db, &ctx.account_id, &filter, &page).await?;
+    let headers = page_headers(&actions);
+    let actions = actions.map(ActivityEvent::from);
+
+    Ok((
+        headers,
+        Json(json!({
+            "actions": actions.items,
+            "total": actions.total,
+            "limit": page.limit,
+            "offset": page.offset,
+            "next_cursor": actions.next_cursor,
+        })),
+    ))
 }
 
 /// Query parameters for the activity export endpoint.
@@ -0,0 +1,128 @@
+//! Administrative controls.
+//!
+//! - `GET    /api/admin/kill` — current kill switch state
+//! - `POST   /api/admin/kill` — engage the kill switch (halts all mutations)
+//! - `DELETE /api/admin/kill` — release the kill switch
+//! - `POST   /api/admin/resume` — lift safe mode after a crash or failure streak
+//! - `GET    /api/admin/draft-only` — current draft-only state
+//! - `POST   /api/admin/draft-only` — disable posting; items are queued and held
+//! - `DELETE /api/admin/draft-only` — lift draft-only mode, posting held items
+
+use std::sync::Arc;
+
+use axum::extract::State;
+use axum::Json;
+use tuitbot_core::automation::safe_mode;
+use tuitbot_core::storage::draft_only::{self, DraftOnlyState};
+use tuitbot_core::storage::kill_switch::{self, KillSwitchState};
+
+use crate::account::{require_mutate, AccountContext};
+use crate::error::ApiError;
+use crate::state::AppState;
+use crate::ws::WsEvent;
+
+pub use tuitbot_types::admin::{EnableDraftOnlyRequest, EngageKillSwitchRequest};
+
+/// `GET /api/admin/kill` — return the kill switch state.
+pub async fn get_kill_switch(
+    State(state): State<Arc<AppState>>,
+) -> Result<Json<KillSwitchState>, ApiError> {
+    Ok(Json(kill_switch::get_kill_switch(&state.db).await?))
+}
+
+/// `POST /api/admin/kill` — engage the kill switch.
+pub async fn engage_kill_switch(
+    State(state): State<Arc<AppState>>,
+    ctx: AccountContext,
+    Json(body): Json<EngageKillSwitchRequest>,
+) -> Result<Json<KillSwitchState>, ApiError> {
+    require_mutate(&ctx)?;
+    let reason = body.reason.trim();
+    if reason.is_empty() {
+        return Err(ApiError::BadRequest(
+            "reason is required to engage the kill switch".to_string(),
+        ));
+    }
+
+    let switch = kill_switch::engage_kill_switch(&state.db, reason, "api").await?;
+    broadcast(&state, &switch);
+    Ok(Json(switch))
+}
+
+/// `DELETE /api/admin/kill` — release the kill switch.
+pub async fn release_kill_switch(
+    State(state): State<Arc<AppState>>,
+    ctx: AccountContext,
+) -> Result<Json<KillSwitchState>, ApiError> {
+    require_mutate(&ctx)?;
+    let switch = kill_switch::release_kill_switch(&state.db, "api").await?;
+    broadcast(&state, &switch);
+    Ok(Json(switch))
+}
+
+/// `POST /api/admin/resume` — lift safe mode. A kill switch engaged by hand
+/// is left alone (409); release it with `DELETE /api/admin/kill`.
+pub async fn resume(
+    State(state): State<Arc<AppState>>,
+    ctx: AccountContext,
+) -> Result<Json<KillSwitchState>, ApiError> {
+    require_mutate(&ctx)?;
+    let Some(switch) = safe_mode::resume(&state.db, "api").await? else {
+        return Err(ApiError::Conflict("not in safe mode".to_string()));
+    };
+    broadcast(&state, &switch);
+    Ok(Json(switch))
+}
+
+/// `GET /api/admin/draft-only` — return the draft-only state.
+pub async fn get_draft_only(
+    State(state): State<Arc<AppState>>,
+) -> Result<Json<DraftOnlyState>, ApiError> {
+    Ok(Json(draft_only::get_draft_only(&state.db).await?))
+}
+
+/// `POST /api/admin/draft-only` — turn draft-only mode on.
+pub async fn enable_draft_only(
+    State(state): State<Arc<AppState>>,
+    ctx: AccountContext,
+    Json(body): Json<EnableDraftOnlyRequest>,
+) -> Result<Json<DraftOnlyState>, ApiError> {
+    require_mutate(&ctx)?;
+    let reason = body
+        .reason
+        .as_deref()
+        .map(str::trim)
+        .filter(|r| !r.is_empty());
+    let mode = draft_only::enable_draft_only(&state.db, reason, "api").await?;
+    broadcast_draft_only(&state, &mode);
+    Ok(Json(mode))
+}
+
+/// `DELETE /api/admin/draft-only` — lift draft-only mode.
+pub async fn disable_draft_only(
+    State(state): State<Arc<AppState>>,
+    ctx: AccountContext,
+) -> Result<Json<DraftOnlyState>, ApiError> {
+    require_mutate(&ctx)?;
+    let mode = draft_only::disable_draft_only(&state.db, "api").await?;
+    broadcast_draft_only(&state, &mode);
+    Ok(Json(mode))
+}
+
+fn broadcast_draft_only(state: &AppState, mode: &DraftOnlyState) {
+    let _ = state.event_tx.send(WsEvent::DraftOnlyChanged {
+        active: mode.active,
+        reason: mode.reason.clone(),
+        source: mode.source.clone(),
+        timestamp: mode.changed_at.clone().unwrap_or_default(),
+    });
+}
+
+fn broadcast(state: &AppState, switch: &KillSwitchState) {
+    let _ = state.event_tx.send(WsEvent::KillSwitchChanged {
+        active: switch.active,
+        reason: switch.reason.clone(),
+        source: switch.source.clone(),
+        timestamp: switch.changed_at.clone().unwrap_or_default(),
+    });
+}
@@ -6,7 +6,14 @@ use axum::extract::{Query, State};
 use axum::Json;
 use serde::Deserialize;
 use serde_json::{json, Value};
+use tuitbot_core::automation::approval_sla;
 use tuitbot_core::storage::analytics;
+use tuitbot_core::storage::analytics_range::{self, AnalyticsRange, Granularity};
+use tuitbot_core::storage::approval_metrics;
+use tuitbot_core::storage::qa_flags;
+use tuitbot_core::storage::reciprocity;
+use tuitbot_core::storage::thread_performance;
+use tuitbot_types::analytics::{InspirationResponse, ReciprocityResponse, ThreadAnalyticsResponse};
 
 use crate::account::AccountContext;
 use crate::error::ApiError;
@@ -18,6 +25,90 @@ pub struct FollowersQuery {
     /// Number of days of follower snapshots to return (default: 7).
     #[serde(default = "default_days")]
     pub days: u32,
+    /// Range start (YYYY-MM-DD or RFC 3339). Switches to the bucketed response.
+    pub from: Option<String>,
+    /// Range end, inclusive for dates. Switches to the bucketed response.
+    pub to: Option<String>,
+    /// Bucket size: `hour`, `day` (default), or `week`.
+    pub granularity: Option<Granularity>,
+}
+
+/// Query parameters for the performance endpoint.
+#[derive(Deserialize)]
+pub struct PerformanceQuery {
+    /// Range start (default: 30 days before `to`).
+    pub from: Option<String>,
+    /// Range end (default: now).
+    pub to: Option<String>,
+    /// Bucket size: `hour`, `day` (default), or `week`.
+    #[serde(default)]
+    pub granularity: Granularity,
+}
+
+/// Query parameters for the approvals endpoint.
+#[derive(Deserialize)]
+pub struct ApprovalsQuery {
+    /// Range start over enqueue time (default: 30 days before `to`).
+    pub from: Option<String>,
+    /// Range end (default: now).
+    pub to: Option<String>,
+}
+
+/// Query parameters for the QA trends endpoint.
+#[derive(Deserialize)]
+pub struct QaTrendsQuery {
+    /// Range start (default: 30 days before `to`).
+    pub from: Option<String>,
+    /// Range end (default: now).
+    pub to: Option<String>,
+    /// Maximum number of top offending rules (default: 10).
+    #[serde(default = "default_qa_rule_limit")]
+    pub limit: u32,
+}
+
+/// Query parameters for the inspiration comparison endpoint.
+#[derive(Deserialize)]
+pub struct InspirationQuery {
+    /// Range start over post time (default: 30 days before `to`).
+    pub from: Option<String>,
+    /// Range end (default: now).
+    pub to: Option<String>,
+}
+
+/// Query parameters for the thread position breakdown endpoint.
+#[derive(Deserialize)]
+pub struct ThreadsQuery {
+    /// Range start over thread post time (default: 30 days before `to`).
+    pub from: Option<String>,
+    /// Range end (default: now).
+    pub to: Option<String>,
+}
+
+/// Query parameters for the reciprocity report endpoint.
+#[derive(Deserialize)]
+pub struct ReciprocityQuery {
+    /// Range start over reply and mention time (default: 30 days before `to`).
+    pub from: Option<String>,
+    /// Range end (default: now).
+    pub to: Option<String>,
+    /// Minimum replies sent for an author to be listed (default: 3).
+    #[serde(default = "default_min_outbound")]
+    pub min_outbound: u32,
+    /// Maximum number of authors to return (default: 20).
+    #[serde(default = "default_reciprocity_limit")]
+    pub limit: u32,
+}
+
+fn default_min_outbound() -> u32 {
+    3
+}
+
+fn default_reciprocity_limit() -> u32 {
+    20
+}
+
+fn default_qa_rule_limit() -> u32 {
+    10
 }
 
 fn default_days() -> u32 {
@@ -49,34 +140,175 @@ fn default_recent_limit() -> u32 {
 }
 
 /// `GET /api/analytics/followers` — follower snapshots over time.
+///
+/// Without `from`/`to`/`granularity`, returns the raw snapshots for the last
+/// `days` days (newest first). With any of them, returns bucketed counts and
+/// a comparison against the preceding period of equal length.
 pub async fn followers(
     State(state): State<Arc<AppState>>,
     ctx: AccountContext,
     Query(params): Query<FollowersQuery>,
 ) -> Result<Json<Value>, ApiError> {
+    if params.from.is_some() || params.to.is_some() || params.granularity.is_some() {
+        let range = parse_range(params.from.as_deref(), params.to.as_deref(), params.days)?;
+        let granularity = params.granularity.unwrap_or_default();
+        let series = analytics_range::get_follower_series_for(
+            &state.db,
+            &ctx.account_id,
+            &range,
+            granularity,
+        )
+        .await?;
+        let summary =
+            analytics_range::get_follower_range_summary_for(&state.db, &ctx.account_id, &range)
+                .await?;
+        return Ok(Json(json!({
+            "range": range,
+            "granularity": granularity,
+            "series": series,
+            "summary": summary,
+        })));
+    }
+
     let snapshots =
         analytics::get_follower_snapshots_for(&state.db, &ctx.account_id, params.days).await?;
     Ok(Json(json!(snapshots)))
 }
 
 /// `GET /api/analytics/performance` — reply and tweet performance summaries.
+///
+/// All-time averages plus a bucketed series and period-over-period summary
+/// for the requested range (default: last 30 days by day).
 pub async fn performance(
     State(state): State<Arc<AppState>>,
     ctx: AccountContext,
+    Query(params): Query<PerformanceQuery>,
 ) -> Result<Json<Value>, ApiError> {
+    let range = parse_range(params.from.as_deref(), params.to.as_deref(), 30)?;
     let avg_reply = analytics::get_avg_reply_engagement_for(&state.db, &ctx.account_id).await?;
     let avg_tweet = analytics::get_avg_tweet_engagement_for(&state.db, &ctx.account_id).await?;
     let (reply_count, tweet_count) =
         analytics::get_performance_counts_for(&state.db, &ctx.account_id).await?;
+    let series = analytics_range::get_performance_series_for(
+        &state.db,
+        &ctx.account_id,
+        &range,
+        params.granularity,
+    )
+    .await?;
+    let summary =
+        analytics_range::get_performance_range_summary_for(&state.db, &ctx.account_id, &range)
+            .await?;
 
     Ok(Json(json!({
         "avg_reply_engagement": avg_reply,
         "avg_tweet_engagement": avg_tweet,
         "measured_replies": reply_count,
         "measured_tweets": tweet_count,
+        "range": range,
+        "granularity": params.granularity,
+        "series": series,
+        "summary": summary,
     })))
 }
 
+/// `GET /api/analytics/approvals` — approval queue review metrics.
+pub async fn approvals(
+    State(state): State<Arc<AppState>>,
+    ctx: AccountContext,
+    Query(params): Query<ApprovalsQuery>,
+) -> Result<Json<Value>, ApiError> {
+    let range = parse_range(params.from.as_deref(), params.to.as_deref(), 30)?;
+    let metrics =
+        approval_metrics::get_approval_metrics_for(&state.db, &ctx.account_id, &range).await?;
+    let sla = match state.current_config() {
+        Some(config) if config.approval_sla.enabled => Some(
+            approval_sla::get_sla_compliance_for(&state.db, &ctx.account_id, &range, &config)
+                .await?,
+        ),
+        _ => None,
+    };
+    Ok(Json(json!({
+        "range": range,
+        "metrics": metrics,
+        "sla": sla,
+    })))
+}
+
+/// `GET /api/analytics/qa` — daily QA flag counts and top offending rules.
+pub async fn qa(
+    State(state): State<Arc<AppState>>,
+    ctx: AccountContext,
+    Query(params): Query<QaTrendsQuery>,
+) -> Result<Json<Value>, ApiError> {
+    let range = parse_range(params.from.as_deref(), params.to.as_deref(), 30)?;
+    let trends =
+        qa_flags::get_qa_trends_for(&state.db, &ctx.account_id, &range, params.limit).await?;
+    Ok(Json(json!({
+        "range": range,
+        "trends": trends,
+    })))
+}
+
+/// `GET /api/analytics/inspiration` — trend-driven vs calendar-driven tweet performance.
+pub async fn inspiration(
+    State(state): State<Arc<AppState>>,
+    ctx: AccountContext,
+    Query(params): Query<InspirationQuery>,
+) -> Result<Json<InspirationResponse>, ApiError> {
+    let range = parse_range(params.from.as_deref(), params.to.as_deref(), 30)?;
+    let groups =
+        analytics::get_inspiration_performance_for(&state.db, &ctx.account_id, &range).await?;
+    Ok(Json(InspirationResponse {
+        range: range.into(),
+        groups,
+    }))
+}
+
+/// `GET /api/analytics/threads` — thread performance by tweet position, with drop-off insights.
+pub async fn threads(
+    State(state): State<Arc<AppState>>,
+    ctx: AccountContext,
+    Query(params): Query<ThreadsQuery>,
+) -> Result<Json<ThreadAnalyticsResponse>, ApiError> {
+    let range = parse_range(params.from.as_deref(), params.to.as_deref(), 30)?;
+    let report =
+        thread_performance::get_position_report_for(&state.db, &ctx.account_id, &range).await?;
+    Ok(Json(ThreadAnalyticsResponse {
+        range: range.into(),
+        report,
+    }))
+}
+
+/// `GET /api/analytics/reciprocity` — authors we reply to most who rarely engage back.
+pub async fn reciprocity(
+    State(state): State<Arc<AppState>>,
+    ctx: AccountContext,
+    Query(params): Query<ReciprocityQuery>,
+) -> Result<Json<ReciprocityResponse>, ApiError> {
+    let range = parse_range(params.from.as_deref(), params.to.as_deref(), 30)?;
+    let authors = reciprocity::get_one_sided_for(
+        &state.db,
+        &ctx.account_id,
+        &range,
+        params.min_outbound,
+        params.limit,
+    )
+    .await?;
+    Ok(Json(ReciprocityResponse {
+        range: range.into(),
+        authors,
+    }))
+}
+
+fn parse_range(
+    from: Option<&str>,
+    to: Option<&str>,
+    default_days: u32,
+) -> Result<AnalyticsRange, ApiError> {
+    AnalyticsRange::parse(from, to, default_days).map_err(|e| ApiError::BadRequest(e.to_string()))
+}
+
 /// `GET /api/analytics/topics` — topic performance scores.
 pub async fn topics(
     State(state): State<Arc<AppState>>,
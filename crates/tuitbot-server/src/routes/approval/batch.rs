@@ -0,0 +1,139 @@
+//! Batch approval of pending items.
+
+use std::sync::Arc;
+
+use axum::extract::State;
+use axum::Json;
+use serde_json::{json, Value};
+use tuitbot_core::config::Config;
+use tuitbot_core::hooks::{HookEvent, HookGate, HookRunner};
+use tuitbot_core::storage::approval_queue::{self, ApprovalItem, ReviewAction};
+
+use super::BatchApproveRequest;
+use crate::account::{require_approve, AccountContext};
+use crate::error::ApiError;
+use crate::state::AppState;
+use crate::ws::WsEvent;
+
+/// `POST /api/approval/approve-all` — batch-approve pending items.
+pub async fn approve_all(
+    State(state): State<Arc<AppState>>,
+    ctx: AccountContext,
+    body: Option<Json<BatchApproveRequest>>,
+) -> Result<Json<Value>, ApiError> {
+    require_approve(&ctx)?;
+
+    let config = read_config(&state);
+    let max_batch = config.max_batch_approve;
+    let hooks = state.hooks_for(&ctx.account_id);
+
+    let body = body.map(|b| b.0);
+    let review = body.as_ref().map(|b| b.review.clone()).unwrap_or_default();
+
+    let approved_ids = if let Some(ids) = body.as_ref().and_then(|b| b.ids.as_ref()) {
+        // Approve specific IDs (still clamped to max_batch).
+        let clamped: Vec<&i64> = ids.iter().take(max_batch).collect();
+        let mut approved = Vec::with_capacity(clamped.len());
+        for &id in &clamped {
+            if let Ok(Some(item)) =
+                approval_queue::get_by_id_for(&state.db, &ctx.account_id, *id).await
+            {
+                if approve_unless_hooks_deny(&state, &ctx.account_id, &hooks, &item, &review).await
+                {
+                    approved.push(*id);
+                }
+            }
+        }
+        approved
+    } else {
+        // Approve oldest N pending items.
+        let effective_max = body
+            .as_ref()
+            .and_then(|b| b.max)
+            .map(|m| m.min(max_batch))
+            .unwrap_or(max_batch);
+
+        if hooks.has(HookEvent::PreApprove) {
+            let review = ReviewAction {
+                expected_version: None,
+                ..review.clone()
+            };
+            let pending = approval_queue::get_pending_for(&state.db, &ctx.account_id).await?;
+            let mut approved = Vec::new();
+            for item in pending.iter().take(effective_max) {
+                if approve_unless_hooks_deny(&state, &ctx.account_id, &hooks, item, &review).await {
+                    approved.push(item.id);
+                }
+            }
+            approved
+        } else {
+            let approve = approval_queue::batch_approve_for(
+                &state.db,
+                &ctx.account_id,
+                effective_max,
+                &review,
+            );
+            state.write(approve).await?
+        }
+    };
+
+    let count = approved_ids.len();
+
+    // Log to action log.
+    let metadata = json!({
+        "count": count,
+        "ids": approved_ids,
+        "actor": review.actor,
+        "max_configured": max_batch,
+    });
+    let _ = state
+        .log_action_for(
+            &ctx.account_id,
+            "approval_batch_approved",
+            "success",
+            Some(&format!("Batch approved {count} items")),
+            Some(&metadata.to_string()),
+        )
+        .await;
+
+    let _ = state.event_tx.send(WsEvent::ApprovalUpdated {
+        id: 0,
+        status: "approved_all".to_string(),
+        action_type: String::new(),
+        actor: review.actor,
+    });
+
+    Ok(Json(
+        json!({"status": "approved", "count": count, "ids": approved_ids, "max_batch": max_batch}),
+    ))
+}
+
+/// Approve `item` unless its `pre_approve` hooks deny it. Returns whether it
+/// was approved.
+async fn approve_unless_hooks_deny(
+    state: &AppState,
+    account_id: &str,
+    hooks: &HookRunner,
+    item: &ApprovalItem,
+    review: &ReviewAction,
+) -> bool {
+    match hooks
+        .gate_item(HookEvent::PreApprove, &state.db, item)
+        .await
+    {
+        Ok(HookGate::Allow | HookGate::Modify(_)) => {}
+        Ok(HookGate::Deny { .. }) | Err(_) => return false,
+    }
+    let approve = approval_queue::update_status_with_review_for(
+        &state.db, account_id, item.id, "approved", review,
+    );
+    state.write(approve).await.is_ok()
+}
+
+/// Read the config from disk (best-effort, returns defaults on failure).
+fn read_config(state: &AppState) -> Config {
+    std::fs::read_to_string(&state.config_path)
+        .ok()
+        .and_then(|s| toml::from_str(&s).ok())
+        .unwrap_or_default()
+}
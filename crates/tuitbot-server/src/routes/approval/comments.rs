@@ -0,0 +1,55 @@
+//! Reviewer comment threads on approval items.
+
+use std::sync::Arc;
+
+use axum::extract::{Path, State};
+use axum::Json;
+use tuitbot_core::storage::approval_queue::{self, ApprovalComment};
+
+use super::AddCommentRequest;
+use crate::account::{require_approve, AccountContext};
+use crate::error::ApiError;
+use crate::state::AppState;
+
+/// `GET /api/approval/:id/comments` — the full comment thread, oldest first.
+pub async fn list_comments(
+    State(state): State<Arc<AppState>>,
+    ctx: AccountContext,
+    Path(id): Path<i64>,
+) -> Result<Json<Vec<ApprovalComment>>, ApiError> {
+    approval_queue::get_by_id_for(&state.db, &ctx.account_id, id)
+        .await?
+        .ok_or_else(|| ApiError::NotFound(format!("approval item {id} not found")))?;
+
+    let comments = approval_queue::get_comments(&state.db, id).await?;
+    Ok(Json(comments))
+}
+
+/// `POST /api/approval/:id/comments` — comment on an item, or reply to a comment.
+pub async fn add_comment(
+    State(state): State<Arc<AppState>>,
+    ctx: AccountContext,
+    Path(id): Path<i64>,
+    Json(body): Json<AddCommentRequest>,
+) -> Result<Json<ApprovalComment>, ApiError> {
+    require_approve(&ctx)?;
+
+    let text = body.body.trim();
+    if text.is_empty() {
+        return Err(ApiError::BadRequest(
+            "comment body cannot be empty".to_string(),
+        ));
+    }
+    approval_queue::get_by_id_for(&state.db, &ctx.account_id, id)
+        .await?
+        .ok_or_else(|| ApiError::NotFound(format!("approval item {id} not found")))?;
+
+    let add = approval_queue::add_comment(&state.db, id, body.parent_id, &body.author, text);
+    let comment = state.write(add).await?.ok_or_else(|| {
+        ApiError::BadRequest(format!(
+            "parent comment {} is not on approval item {id}",
+            body.parent_id.unwrap_or_default()
+        ))
+    })?;
+    Ok(Json(comment))
+}
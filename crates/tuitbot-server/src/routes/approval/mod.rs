@@ -0,0 +1,226 @@
+//! Approval queue endpoints.
+
+mod batch;
+mod comments;
+mod export;
+mod transition;
+mod trash;
+
+use std::sync::Arc;
+
+use axum::extract::{Path, Query, State};
+use axum::http::HeaderMap;
+use axum::Json;
+use serde::Deserialize;
+use serde_json::{json, Value};
+use tuitbot_core::storage::approval_queue::{self, ApprovalFilter, APPROVAL_SORT_FIELDS};
+use tuitbot_core::storage::pagination::MAX_PAGE_LIMIT;
+
+use super::{page_headers, Pagination};
+use crate::account::{require_approve, AccountContext};
+use crate::error::ApiError;
+use crate::state::AppState;
+
+pub use batch::approve_all;
+pub use comments::{add_comment, list_comments};
+pub use export::{export_items, ExportQuery};
+pub(crate) use transition::{transition, TransitionError};
+pub use trash::{delete_item, list_deleted, restore_item};
+pub use tuitbot_types::approval::{
+    AddCommentRequest, BatchApproveRequest, EditContentRequest, RestoreItemResponse,
+};
+
+/// Filters for listing approval items; paging comes from [`Pagination`].
+#[derive(Deserialize)]
+pub struct ApprovalQuery {
+    /// Comma-separated status values (default: "pending").
+    #[serde(default = "default_status")]
+    pub status: String,
+    /// Filter by action type (reply, tweet, thread_tweet, quote, like, follow).
+    #[serde(rename = "type")]
+    pub action_type: Option<String>,
+    /// Filter by reviewer name.
+    pub reviewed_by: Option<String>,
+    /// Filter by target author.
+    pub author: Option<String>,
+    /// Filter by items created since this ISO-8601 timestamp.
+    pub since: Option<String>,
+    /// Filter by items created before this ISO-8601 timestamp.
+    pub until: Option<String>,
+}
+
+fn default_status() -> String {
+    "pending".to_string()
+}
+
+/// `GET /api/approval` — list approval items with optional status/type/reviewer/author/date filters.
+///
+/// Items come in queue order unless `sort` is given (`created_at`, `score`,
+/// `priority`, `author`). The default limit is the page maximum, so the
+/// queue view still sees every pending item.
+pub async fn list_items(
+    State(state): State<Arc<AppState>>,
+    ctx: AccountContext,
+    pagination: Pagination,
+    Query(params): Query<ApprovalQuery>,
+) -> Result<(HeaderMap, Json<Value>), ApiError> {
+    let page = pagination.page(MAX_PAGE_LIMIT, APPROVAL_SORT_FIELDS, None)?;
+    let statuses: Vec<&str> = params.status.split(',').map(|s| s.trim()).collect();
+    let filter = ApprovalFilter {
+        statuses: &statuses,
+        action_type: params.action_type.as_deref(),
+        reviewed_by: params.reviewed_by.as_deref(),
+        author: params.author.as_deref(),
+        since: params.since.as_deref(),
+        until: params.until.as_deref(),
+    };
+
+    let mut items =
+        approval_queue::get_filtered_page_for(&state.db, &ctx.account_id, &filter, &page).await?;
+    approval_queue::attach_latest_comments(&state.db, &mut items.items).await?;
+    Ok((page_headers(&items), Json(json!(items.items))))
+}
+
+/// `GET /api/approval/stats` — counts by status.
+pub async fn stats(
+    State(state): State<Arc<AppState>>,
+    ctx: AccountContext,
+) -> Result<Json<Value>, ApiError> {
+    let stats = approval_queue::get_stats_for(&state.db, &ctx.account_id).await?;
+    Ok(Json(json!(stats)))
+}
+
+/// `PATCH /api/approval/:id` — edit content before approving.
+pub async fn edit_item(
+    State(state): State<Arc<AppState>>,
+    ctx: AccountContext,
+    Path(id): Path<i64>,
+    Json(body): Json<EditContentRequest>,
+) -> Result<Json<Value>, ApiError> {
+    require_approve(&ctx)?;
+
+    let item = approval_queue::get_by_id_for(&state.db, &ctx.account_id, id).await?;
+    let item = item.ok_or_else(|| ApiError::NotFound(format!("approval item {id} not found")))?;
+    if item.is_engagement() {
+        return Err(ApiError::BadRequest(format!(
+            "{} items have no content to edit",
+            item.action_type
+        )));
+    }
+
+    let content = body.content.trim();
+    if content.is_empty() {
+        return Err(ApiError::BadRequest("content cannot be empty".to_string()));
+    }
+
+    // Record edit history before updating (queries by PK, implicitly scoped).
+    if content != item.generated_content {
+        let record = approval_queue::record_edit(
+            &state.db,
+            id,
+            &body.editor,
+            "generated_content",
+            &item.generated_content,
+            content,
+        );
+        let _ = state.write(record).await;
+    }
+
+    state
+        .write(approval_queue::update_content_for(
+            &state.db,
+            &ctx.account_id,
+            id,
+            content,
+        ))
+        .await?;
+
+    if let Some(media_paths) = &body.media_paths {
+        let media_json = serde_json::to_string(media_paths).unwrap_or_else(|_| "[]".to_string());
+
+        // Record media_paths edit if changed.
+        if media_json != item.media_paths {
+            let record = approval_queue::record_edit(
+                &state.db,
+                id,
+                &body.editor,
+                "media_paths",
+                &item.media_paths,
+                &media_json,
+            );
+            let _ = state.write(record).await;
+        }
+
+        let update =
+            approval_queue::update_media_paths_for(&state.db, &ctx.account_id, id, &media_json);
+        state.write(update).await?;
+    }
+
+    // Log to action log.
+    let metadata = json!({
+        "approval_id": id,
+        "editor": body.editor,
+        "field": "generated_content",
+    });
+    let _ = state
+        .log_action_for(
+            &ctx.account_id,
+            "approval_edited",
+            "success",
+            Some(&format!("Edited approval item {id}")),
+            Some(&metadata.to_string()),
+        )
+        .await;
+
+    let updated = approval_queue::get_by_id_for(&state.db, &ctx.account_id, id)
+        .await?
+        .expect("item was just verified to exist");
+    Ok(Json(json!(updated)))
+}
+
+/// `POST /api/approval/:id/approve` — approve a queued item.
+pub async fn approve_item(
+    State(state): State<Arc<AppState>>,
+    ctx: AccountContext,
+    Path(id): Path<i64>,
+    body: Option<Json<approval_queue::ReviewAction>>,
+) -> Result<Json<Value>, ApiError> {
+    require_approve(&ctx)?;
+
+    let item = approval_queue::get_by_id_for(&state.db, &ctx.account_id, id).await?;
+    let item = item.ok_or_else(|| ApiError::NotFound(format!("approval item {id} not found")))?;
+
+    let review = body.map(|b| b.0).unwrap_or_default();
+    transition(&state, &ctx.account_id, &item, "approved", review).await?;
+
+    Ok(Json(json!({"status": "approved", "id": id})))
+}
+
+/// `POST /api/approval/:id/reject` — reject a queued item.
+pub async fn reject_item(
+    State(state): State<Arc<AppState>>,
+    ctx: AccountContext,
+    Path(id): Path<i64>,
+    body: Option<Json<approval_queue::ReviewAction>>,
+) -> Result<Json<Value>, ApiError> {
+    require_approve(&ctx)?;
+
+    let item = approval_queue::get_by_id_for(&state.db, &ctx.account_id, id).await?;
+    let item = item.ok_or_else(|| ApiError::NotFound(format!("approval item {id} not found")))?;
+
+    let review = body.map(|b| b.0).unwrap_or_default();
+    transition(&state, &ctx.account_id, &item, "rejected", review).await?;
+
+    Ok(Json(json!({"status": "rejected", "id": id})))
+}
+
+/// `GET /api/approval/:id/history` — get edit history for an item.
+pub async fn get_edit_history(
+    State(state): State<Arc<AppState>>,
+    _ctx: AccountContext,
+    Path(id): Path<i64>,
+) -> Result<Json<Value>, ApiError> {
+    // Query by approval_id PK is already implicitly scoped.
+    let history = approval_queue::get_edit_history(&state.db, id).await?;
+    Ok(Json(json!(history)))
+}
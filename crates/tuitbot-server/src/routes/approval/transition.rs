@@ -0,0 +1,119 @@
+//! Approve/reject state transition shared by the dashboard, gRPC, and
+//! email-link review paths.
+
+use serde_json::json;
+use tuitbot_core::error::StorageError;
+use tuitbot_core::hooks::{HookAction, HookEvent, HookGate, HookRunner};
+use tuitbot_core::storage::approval_queue::{self, ApprovalItem, ReviewAction};
+
+use crate::error::ApiError;
+use crate::state::AppState;
+use crate::ws::WsEvent;
+
+/// Why a review decision was not applied.
+pub(crate) enum TransitionError {
+    /// A `pre_approve` hook held the item back.
+    Denied { hook: String, reason: String },
+    /// Hook bookkeeping or the status update failed. A
+    /// [`StorageError::Conflict`] means the item changed since it was read.
+    Storage(StorageError),
+}
+
+impl From<StorageError> for TransitionError {
+    fn from(err: StorageError) -> Self {
+        Self::Storage(err)
+    }
+}
+
+impl From<TransitionError> for ApiError {
+    fn from(err: TransitionError) -> Self {
+        match err {
+            TransitionError::Denied { hook, reason } => {
+                Self::Conflict(format!("approval denied by hook {hook}: {reason}"))
+            }
+            TransitionError::Storage(e) => e.into(),
+        }
+    }
+}
+
+/// Move `item` to `status` (`approved` or `rejected`) on behalf of a reviewer.
+///
+/// Approvals re-run the `pre_approve` hooks first; a rewrite bumps the
+/// version the reviewer saw. Rejections notify the `on_denial` hooks. Either
+/// way the decision is recorded in the action log and broadcast to
+/// dashboard clients.
+pub(crate) async fn transition(
+    state: &AppState,
+    account_id: &str,
+    item: &ApprovalItem,
+    status: &str,
+    mut review: ReviewAction,
+) -> Result<(), TransitionError> {
+    let hooks = state.hooks_for(account_id);
+    if status == "approved" {
+        match hooks
+            .gate_item(HookEvent::PreApprove, &state.db, item)
+            .await?
+        {
+            HookGate::Allow => {}
+            HookGate::Modify(_) => review.expected_version = review.expected_version.map(|v| v + 1),
+            HookGate::Deny { hook, reason } => {
+                return Err(TransitionError::Denied { hook, reason });
+            }
+        }
+    }
+    let update = approval_queue::update_status_with_review_for(
+        &state.db, account_id, item.id, status, &review,
+    );
+    state.write(update).await?;
+
+    if status == "rejected" {
+        notify_rejection(hooks, item, &review);
+    }
+
+    let verb = if status == "approved" {
+        "Approved"
+    } else {
+        "Rejected"
+    };
+    let metadata = json!({
+        "approval_id": item.id,
+        "actor": review.actor,
+        "notes": review.notes,
+        "action_type": item.action_type,
+    });
+    let _ = state
+        .log_action_for(
+            account_id,
+            &format!("approval_{status}"),
+            "success",
+            Some(&format!("{verb} item {}", item.id)),
+            Some(&metadata.to_string()),
+        )
+        .await;
+
+    let _ = state.event_tx.send(WsEvent::ApprovalUpdated {
+        id: item.id,
+        status: status.to_string(),
+        action_type: item.action_type.clone(),
+        actor: review.actor,
+    });
+
+    Ok(())
+}
+
+/// Notify the `on_denial` hooks that a reviewer rejected `item`, without
+/// holding up the response.
+fn notify_rejection(hooks: HookRunner, item: &ApprovalItem, review: &ReviewAction) {
+    if !hooks.has(HookEvent::OnDenial) {
+        return;
+    }
+    let mut action = HookAction::from_item(item);
+    action.reason = Some(match (&review.actor, &review.notes) {
+        (Some(actor), Some(notes)) => format!("rejected by {actor}: {notes}"),
+        (Some(actor), None) => format!("rejected by {actor}"),
+        (None, Some(notes)) => format!("rejected: {notes}"),
+        (None, None) => "rejected".to_string(),
+    });
+    tokio::spawn(async move { hooks.notify(HookEvent::OnDenial, &action).await });
+}
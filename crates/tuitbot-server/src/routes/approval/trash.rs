@@ -0,0 +1,107 @@
+//! Soft delete, restore, and the trash listing.
+
+use std::sync::Arc;
+
+use axum::extract::{Path, State};
+use axum::Json;
+use serde_json::{json, Value};
+use tuitbot_core::storage::approval_queue;
+
+use super::RestoreItemResponse;
+use crate::account::{require_approve, AccountContext};
+use crate::error::ApiError;
+use crate::state::AppState;
+use crate::ws::WsEvent;
+
+/// `DELETE /api/approval/:id` — soft-delete an item. It can be restored
+/// until the retention cleanup purges it.
+pub async fn delete_item(
+    State(state): State<Arc<AppState>>,
+    ctx: AccountContext,
+    Path(id): Path<i64>,
+) -> Result<Json<Value>, ApiError> {
+    require_approve(&ctx)?;
+
+    let item = approval_queue::get_by_id_for(&state.db, &ctx.account_id, id).await?;
+    let item = item.ok_or_else(|| ApiError::NotFound(format!("approval item {id} not found")))?;
+
+    let delete = approval_queue::soft_delete_for(&state.db, &ctx.account_id, id);
+    if !state.write(delete).await? {
+        return Err(ApiError::Conflict(format!(
+            "approval item {id} is being posted and cannot be deleted"
+        )));
+    }
+
+    let metadata = json!({"approval_id": id, "action_type": item.action_type});
+    let _ = state
+        .log_action_for(
+            &ctx.account_id,
+            "approval_deleted",
+            "success",
+            Some(&format!("Deleted item {id}")),
+            Some(&metadata.to_string()),
+        )
+        .await;
+
+    let _ = state.event_tx.send(WsEvent::ApprovalUpdated {
+        id,
+        status: "deleted".to_string(),
+        action_type: item.action_type,
+        actor: None,
+    });
+
+    Ok(Json(json!({"status": "deleted", "id": id})))
+}
+
+/// `POST /api/approval/:id/restore` — restore a deleted item, or return a
+/// rejected item to the pending queue.
+pub async fn restore_item(
+    State(state): State<Arc<AppState>>,
+    ctx: AccountContext,
+    Path(id): Path<i64>,
+) -> Result<Json<RestoreItemResponse>, ApiError> {
+    require_approve(&ctx)?;
+
+    let restore = approval_queue::restore_for(&state.db, &ctx.account_id, id);
+    if !state.write(restore).await? {
+        return Err(ApiError::NotFound(format!(
+            "no deleted or rejected approval item {id}"
+        )));
+    }
+    let item = approval_queue::get_by_id_for(&state.db, &ctx.account_id, id).await?;
+    let item = item.ok_or_else(|| ApiError::NotFound(format!("approval item {id} not found")))?;
+
+    let metadata = json!({"approval_id": id, "action_type": item.action_type});
+    let _ = state
+        .log_action_for(
+            &ctx.account_id,
+            "approval_restored",
+            "success",
+            Some(&format!("Restored item {id} as {}", item.status)),
+            Some(&metadata.to_string()),
+        )
+        .await;
+
+    let _ = state.event_tx.send(WsEvent::ApprovalUpdated {
+        id,
+        status: item.status.clone(),
+        action_type: item.action_type.clone(),
+        actor: None,
+    });
+
+    Ok(Json(RestoreItemResponse {
+        status: "restored".to_string(),
+        id,
+        item_status: item.status,
+    }))
+}
+
+/// `GET /api/approval/deleted` — soft-deleted items, most recent first.
+pub async fn list_deleted(
+    State(state): State<Arc<AppState>>,
+    ctx: AccountContext,
+) -> Result<Json<Value>, ApiError> {
+    let mut items = approval_queue::get_deleted_for(&state.db, &ctx.account_id).await?;
+    approval_queue::attach_latest_comments(&state.db, &mut items).await?;
+    Ok(Json(json!(items)))
+}
@@ -8,7 +8,6 @@ use std::sync::Arc;
 use axum::extract::State;
 use axum::http::StatusCode;
 use axum::Json;
-use serde::{Deserialize, Serialize};
 
 use tuitbot_core::content::ContentGenerator;
 use tuitbot_core::storage;
@@ -17,6 +16,12 @@ use crate::account::AccountContext;
 use crate::error::ApiError;
 use crate::state::AppState;
 
+pub use tuitbot_types::assist::{
+    AssistImproveRequest, AssistImproveResponse, AssistReplyRequest, AssistReplyResponse,
+    AssistThreadRequest, AssistThreadResponse, AssistTopicsResponse, AssistTweetRequest,
+    AssistTweetResponse, ModeResponse, OptimalTime, OptimalTimesResponse, TopicRecommendation,
+};
+
 // ---------------------------------------------------------------------------
 // Helpers
 // ---------------------------------------------------------------------------
@@ -38,17 +43,6 @@ async fn get_generator(
 // POST /api/assist/tweet
 // ---------------------------------------------------------------------------
 
-#[derive(Deserialize)]
-pub struct AssistTweetRequest {
-    pub topic: String,
-}
-
-#[derive(Serialize)]
-pub struct AssistTweetResponse {
-    pub content: String,
-    pub topic: String,
-}
-
 pub async fn assist_tweet(
     State(state): State<Arc<AppState>>,
     ctx: AccountContext,
@@ -71,19 +65,6 @@ pub async fn assist_tweet(
 // POST /api/assist/reply
 // ---------------------------------------------------------------------------
 
-#[derive(Deserialize)]
-pub struct AssistReplyRequest {
-    pub tweet_text: String,
-    pub tweet_author: String,
-    #[serde(default)]
-    pub mention_product: bool,
-}
-
-#[derive(Serialize)]
-pub struct AssistReplyResponse {
-    pub content: String,
-}
-
 pub async fn assist_reply(
     State(state): State<Arc<AppState>>,
     ctx: AccountContext,
@@ -105,17 +86,6 @@ pub async fn assist_reply(
 // POST /api/assist/thread
 // ---------------------------------------------------------------------------
 
-#[derive(Deserialize)]
-pub struct AssistThreadRequest {
-    pub topic: String,
-}
-
-#[derive(Serialize)]
-pub struct AssistThreadResponse {
-    pub tweets: Vec<String>,
-    pub topic: String,
-}
-
 pub async fn assist_thread(
     State(state): State<Arc<AppState>>,
     ctx: AccountContext,
@@ -138,18 +108,6 @@ pub async fn assist_thread(
 // POST /api/assist/improve
 // ---------------------------------------------------------------------------
 
-#[derive(Deserialize)]
-pub struct AssistImproveRequest {
-    pub draft: String,
-    #[serde(default)]
-    pub context: Option<String>,
-}
-
-#[derive(Serialize)]
-pub struct AssistImproveResponse {
-    pub content: String,
-}
-
 pub async fn assist_improve(
     State(state): State<Arc<AppState>>,
     ctx: AccountContext,
@@ -185,17 +143,6 @@ pub async fn assist_improve(
 // GET /api/assist/topics
 // ---------------------------------------------------------------------------
 
-#[derive(Serialize)]
-pub struct AssistTopicsResponse {
-    pub topics: Vec<TopicRecommendation>,
-}
-
-#[derive(Serialize)]
-pub struct TopicRecommendation {
-    pub topic: String,
-    pub score: f64,
-}
-
 pub async fn assist_topics(
     State(state): State<Arc<AppState>>,
     ctx: AccountContext,
@@ -217,18 +164,6 @@ pub async fn assist_topics(
 // GET /api/assist/optimal-times
 // ---------------------------------------------------------------------------
 
-#[derive(Serialize)]
-pub struct OptimalTimesResponse {
-    pub times: Vec<OptimalTime>,
-}
-
-#[derive(Serialize)]
-pub struct OptimalTime {
-    pub hour: u32,
-    pub avg_engagement: f64,
-    pub post_count: i64,
-}
-
 pub async fn assist_optimal_times(
     State(state): State<Arc<AppState>>,
     ctx: AccountContext,
@@ -252,12 +187,6 @@ pub async fn assist_optimal_times(
 // GET /api/assist/mode
 // ---------------------------------------------------------------------------
 
-#[derive(Serialize)]
-pub struct ModeResponse {
-    pub mode: String,
-    pub approval_mode: bool,
-}
-
 pub async fn get_mode(
     State(state): State<Arc<AppState>>,
     _ctx: AccountContext,
@@ -4,7 +4,7 @@ use std::sync::Arc;
 
 use axum::extract::{Query, State};
 use axum::Json;
-use serde::{Deserialize, Serialize};
+use serde::Deserialize;
 use serde_json::{json, Value};
 use tuitbot_core::storage::{approval_queue, replies, scheduled_content, threads};
 
@@ -14,22 +14,7 @@ use crate::state::AppState;
 
 use super::read_config;
 
-/// A unified calendar item merging content from all sources.
-#[derive(Debug, Serialize)]
-pub struct CalendarItem {
-    pub id: i64,
-    pub content_type: String,
-    pub content: String,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub target_author: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub topic: Option<String>,
-    pub timestamp: String,
-    pub status: String,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub performance_score: Option<f64>,
-    pub source: String,
-}
+pub use tuitbot_types::content::CalendarItem;
 
 /// Query parameters for the calendar endpoint.
 #[derive(Deserialize)]
@@ -4,7 +4,6 @@ use std::sync::Arc;
 
 use axum::extract::State;
 use axum::Json;
-use serde::Deserialize;
 use serde_json::{json, Value};
 use tuitbot_core::content::{
     serialize_blocks_for_storage, tweet_weighted_len, validate_thread_blocks, ThreadBlock,
@@ -17,41 +16,20 @@ use crate::error::ApiError;
 use crate::state::AppState;
 use crate::ws::WsEvent;
 
-use super::read_approval_mode;
-
-/// A single thread block in an API request payload.
-#[derive(Debug, Deserialize)]
-pub struct ThreadBlockRequest {
-    /// Client-generated stable UUID.
-    pub id: String,
-    /// Tweet text content.
-    pub text: String,
-    /// Per-block media file paths.
-    #[serde(default)]
-    pub media_paths: Vec<String>,
-    /// Zero-based ordering index.
-    pub order: u32,
-}
+use super::{read_approval_mode, read_poster};
 
-impl ThreadBlockRequest {
-    /// Convert to the core domain type.
-    pub(crate) fn into_core(self) -> ThreadBlock {
-        ThreadBlock {
-            id: self.id,
-            text: self.text,
-            media_paths: self.media_paths,
-            order: self.order,
-        }
-    }
-}
+pub use tuitbot_types::content::{
+    ComposeRequest, ComposeThreadRequest, ComposeTweetRequest, ThreadBlockRequest,
+};
 
-/// Request body for composing a manual tweet.
-#[derive(Deserialize)]
-pub struct ComposeTweetRequest {
-    /// The tweet text.
-    pub text: String,
-    /// Optional ISO 8601 timestamp to schedule the tweet.
-    pub scheduled_for: Option<String>,
+/// Convert a thread block from the request body to the core domain type.
+pub(crate) fn block_into_core(block: ThreadBlockRequest) -> ThreadBlock {
+    ThreadBlock {
+        id: block.id,
+        text: block.text,
+        media_paths: block.media_paths,
+        order: block.order,
+    }
 }
 
 /// `POST /api/content/tweets` — compose and queue a manual tweet.
@@ -68,22 +46,23 @@ pub async fn compose_tweet(
     }
 
     // Check if approval mode is enabled.
-    let approval_mode = read_approval_mode(&state)?;
+    let approval_mode = read_approval_mode(&state).await?;
 
     if approval_mode {
-        let id = approval_queue::enqueue_for(
-            &state.db,
-            &ctx.account_id,
-            "tweet",
-            "", // no target tweet
-            "", // no target author
-            text,
-            "", // no topic
-            "", // no archetype
-            0.0,
-            "[]",
-        )
-        .await?;
+        let id = state
+            .write(approval_queue::enqueue_for(
+                &state.db,
+                &ctx.account_id,
+                "tweet",
+                "", // no target tweet
+                "", // no target author
+                text,
+                "", // no topic
+                "", // no archetype
+                0.0,
+                "[]",
+            ))
+            .await?;
 
         let _ = state.event_tx.send(WsEvent::ApprovalQueued {
             id,
@@ -95,6 +74,7 @@ pub async fn compose_tweet(
         Ok(Json(json!({
             "status": "queued_for_approval",
             "id": id,
+            "poster": read_poster(&state).await,
         })))
     } else {
         // Without X API client in AppState, we can only acknowledge the intent.
@@ -106,15 +86,6 @@ pub async fn compose_tweet(
     }
 }
 
-/// Request body for composing a manual thread.
-#[derive(Deserialize)]
-pub struct ComposeThreadRequest {
-    /// The tweets forming the thread.
-    pub tweets: Vec<String>,
-    /// Optional ISO 8601 timestamp to schedule the thread.
-    pub scheduled_for: Option<String>,
-}
-
 /// `POST /api/content/threads` — compose and queue a manual thread.
 pub async fn compose_thread(
     State(state): State<Arc<AppState>>,
@@ -129,23 +100,24 @@ pub async fn compose_thread(
         ));
     }
 
-    let approval_mode = read_approval_mode(&state)?;
+    let approval_mode = read_approval_mode(&state).await?;
     let combined = body.tweets.join("\n---\n");
 
     if approval_mode {
-        let id = approval_queue::enqueue_for(
-            &state.db,
-            &ctx.account_id,
-            "thread",
-            "",
-            "",
-            &combined,
-            "",
-            "",
-            0.0,
-            "[]",
-        )
-        .await?;
+        let id = state
+            .write(approval_queue::enqueue_for(
+                &state.db,
+                &ctx.account_id,
+                "thread",
+                "",
+                "",
+                &combined,
+                "",
+                "",
+                0.0,
+                "[]",
+            ))
+            .await?;
 
         let _ = state.event_tx.send(WsEvent::ApprovalQueued {
             id,
@@ -157,6 +129,7 @@ pub async fn compose_thread(
         Ok(Json(json!({
             "status": "queued_for_approval",
             "id": id,
+            "poster": read_poster(&state).await,
         })))
     } else {
         Ok(Json(json!({
@@ -167,23 +140,6 @@ pub async fn compose_thread(
     }
 }
 
-/// Request body for the unified compose endpoint.
-#[derive(Deserialize)]
-pub struct ComposeRequest {
-    /// Content type: "tweet" or "thread".
-    pub content_type: String,
-    /// Content text (string for tweet, JSON array string for thread).
-    pub content: String,
-    /// Optional ISO 8601 timestamp to schedule the content.
-    pub scheduled_for: Option<String>,
-    /// Optional local media file paths to attach (top-level, used for tweets).
-    #[serde(default)]
-    pub media_paths: Option<Vec<String>>,
-    /// Optional structured thread blocks. Takes precedence over `content` for threads.
-    #[serde(default)]
-    pub blocks: Option<Vec<ThreadBlockRequest>>,
-}
-
 /// `POST /api/content/compose` — compose manual content (tweet or thread).
 pub async fn compose(
     State(state): State<Arc<AppState>>,
@@ -268,7 +224,7 @@ async fn compose_thread_blocks_flow(
     body: &ComposeRequest,
     block_requests: Vec<ThreadBlockRequest>,
 ) -> Result<Json<Value>, ApiError> {
-    let core_blocks: Vec<ThreadBlock> = block_requests.into_iter().map(|b| b.into_core()).collect();
+    let core_blocks: Vec<ThreadBlock> = block_requests.into_iter().map(block_into_core).collect();
 
     validate_thread_blocks(&core_blocks).map_err(|e| ApiError::BadRequest(e.api_message()))?;
 
@@ -287,23 +243,24 @@ async fn compose_thread_blocks_flow(
         sorted.iter().flat_map(|b| b.media_paths.clone()).collect()
     };
 
-    let approval_mode = read_approval_mode(state)?;
+    let approval_mode = read_approval_mode(state).await?;
 
     if approval_mode {
         let media_json = serde_json::to_string(&all_media).unwrap_or_else(|_| "[]".to_string());
-        let id = approval_queue::enqueue_for(
-            &state.db,
-            &ctx.account_id,
-            "thread",
-            "",
-            "",
-            &content,
-            "",
-            "",
-            0.0,
-            &media_json,
-        )
-        .await?;
+        let id = state
+            .write(approval_queue::enqueue_for(
+                &state.db,
+                &ctx.account_id,
+                "thread",
+                "",
+                "",
+                &content,
+                "",
+                "",
+                0.0,
+                &media_json,
+            ))
+            .await?;
 
         let _ = state.event_tx.send(WsEvent::ApprovalQueued {
             id,
@@ -315,17 +272,19 @@ async fn compose_thread_blocks_flow(
         Ok(Json(json!({
             "status": "queued_for_approval",
             "id": id,
+            "poster": read_poster(state).await,
             "block_ids": block_ids,
         })))
     } else {
-        let id = scheduled_content::insert_for(
-            &state.db,
-            &ctx.account_id,
-            "thread",
-            &content,
-            body.scheduled_for.as_deref(),
-        )
-        .await?;
+        let id = state
+            .write(scheduled_content::insert_for(
+                &state.db,
+                &ctx.account_id,
+                "thread",
+                &content,
+                body.scheduled_for.as_deref(),
+            ))
+            .await?;
 
         let _ = state.event_tx.send(WsEvent::ContentScheduled {
             id,
@@ -348,24 +307,25 @@ async fn persist_content(
     body: &ComposeRequest,
     content: &str,
 ) -> Result<Json<Value>, ApiError> {
-    let approval_mode = read_approval_mode(state)?;
+    let approval_mode = read_approval_mode(state).await?;
 
     if approval_mode {
         let media_paths = body.media_paths.as_deref().unwrap_or(&[]);
         let media_json = serde_json::to_string(media_paths).unwrap_or_else(|_| "[]".to_string());
-        let id = approval_queue::enqueue_for(
-            &state.db,
-            &ctx.account_id,
-            &body.content_type,
-            "",
-            "",
-            content,
-            "",
-            "",
-            0.0,
-            &media_json,
-        )
-        .await?;
+        let id = state
+            .write(approval_queue::enqueue_for(
+                &state.db,
+                &ctx.account_id,
+                &body.content_type,
+                "",
+                "",
+                content,
+                "",
+                "",
+                0.0,
+                &media_json,
+            ))
+            .await?;
 
         let _ = state.event_tx.send(WsEvent::ApprovalQueued {
             id,
@@ -377,16 +337,18 @@ async fn persist_content(
         Ok(Json(json!({
             "status": "queued_for_approval",
             "id": id,
+            "poster": read_poster(state).await,
         })))
     } else {
-        let id = scheduled_content::insert_for(
-            &state.db,
-            &ctx.account_id,
-            &body.content_type,
-            content,
-            body.scheduled_for.as_deref(),
-        )
-        .await?;
+        let id = state
+            .write(scheduled_content::insert_for(
+                &state.db,
+                &ctx.account_id,
+                &body.content_type,
+                content,
+                body.scheduled_for.as_deref(),
+            ))
+            .await?;
 
         let _ = state.event_tx.send(WsEvent::ContentScheduled {
             id,
@@ -4,33 +4,22 @@ use std::sync::Arc;
 
 use axum::extract::{Path, State};
 use axum::Json;
-use serde::Deserialize;
 use serde_json::{json, Value};
 use tuitbot_core::content::{
     serialize_blocks_for_storage, tweet_weighted_len, validate_thread_blocks, ThreadBlock,
     MAX_TWEET_CHARS,
 };
-use tuitbot_core::storage::{approval_queue, scheduled_content};
+use tuitbot_core::storage::{approval_queue, drafts, scheduled_content};
+use tuitbot_core::workflow::draft_workspace;
 
 use crate::account::{require_mutate, AccountContext};
 use crate::error::ApiError;
 use crate::state::AppState;
 
-use super::compose::ThreadBlockRequest;
+use super::compose::block_into_core;
+use super::{read_config, read_poster};
 
-#[derive(Deserialize)]
-pub struct CreateDraftRequest {
-    pub content_type: String,
-    pub content: String,
-    #[serde(default = "default_source")]
-    pub source: String,
-    #[serde(default)]
-    pub blocks: Option<Vec<ThreadBlockRequest>>,
-}
-
-fn default_source() -> String {
-    "manual".to_string()
-}
+pub use tuitbot_types::content::{CreateDraftRequest, EditDraftRequest, ScheduleDraftRequest};
 
 pub async fn list_drafts(
     State(state): State<Arc<AppState>>,
@@ -42,6 +31,16 @@ pub async fn list_drafts(
     Ok(Json(drafts))
 }
 
+/// `GET /api/content/drafts/{id}` — a single draft with its QA fields.
+pub async fn get_draft(
+    State(state): State<Arc<AppState>>,
+    ctx: AccountContext,
+    Path(id): Path<i64>,
+) -> Result<Json<scheduled_content::ScheduledContent>, ApiError> {
+    let draft = load_draft(&state, &ctx, id).await?;
+    Ok(Json(draft))
+}
+
 pub async fn create_draft(
     State(state): State<Arc<AppState>>,
     ctx: AccountContext,
@@ -55,7 +54,7 @@ pub async fn create_draft(
     let content = if body.content_type == "thread" {
         if let Some(block_requests) = blocks {
             let core_blocks: Vec<ThreadBlock> =
-                block_requests.into_iter().map(|b| b.into_core()).collect();
+                block_requests.into_iter().map(block_into_core).collect();
             validate_thread_blocks(&core_blocks)
                 .map_err(|e| ApiError::BadRequest(e.api_message()))?;
             serialize_blocks_for_storage(&core_blocks)
@@ -68,17 +67,24 @@ pub async fn create_draft(
         body.content.clone()
     };
 
-    let id = scheduled_content::insert_draft_for(
-        &state.db,
-        &ctx.account_id,
-        &body.content_type,
-        &content,
-        &body.source,
-    )
-    .await
-    .map_err(ApiError::Storage)?;
+    let id = state
+        .write(scheduled_content::insert_draft_for(
+            &state.db,
+            &ctx.account_id,
+            &body.content_type,
+            &content,
+            &body.source,
+        ))
+        .await
+        .map_err(ApiError::Storage)?;
+
+    if let Some(planned_for) = body.planned_for.as_deref() {
+        drafts::plan_draft_for(&state.db, &ctx.account_id, id, Some(planned_for)).await?;
+    }
 
-    Ok(Json(json!({ "id": id, "status": "draft" })))
+    Ok(Json(
+        json!({ "id": id, "status": "draft", "planned_for": body.planned_for }),
+    ))
 }
 
 /// Validate draft content for legacy (non-blocks) payloads.
@@ -102,14 +108,6 @@ fn validate_draft_content(content_type: &str, content: &str) -> Result<(), ApiEr
     Ok(())
 }
 
-#[derive(Deserialize)]
-pub struct EditDraftRequest {
-    #[serde(default)]
-    pub content: Option<String>,
-    #[serde(default)]
-    pub blocks: Option<Vec<ThreadBlockRequest>>,
-}
-
 pub async fn edit_draft(
     State(state): State<Arc<AppState>>,
     ctx: AccountContext,
@@ -120,25 +118,42 @@ pub async fn edit_draft(
 
     let content = if let Some(block_requests) = body.blocks {
         let core_blocks: Vec<ThreadBlock> =
-            block_requests.into_iter().map(|b| b.into_core()).collect();
+            block_requests.into_iter().map(block_into_core).collect();
         validate_thread_blocks(&core_blocks).map_err(|e| ApiError::BadRequest(e.api_message()))?;
-        serialize_blocks_for_storage(&core_blocks)
+        Some(serialize_blocks_for_storage(&core_blocks))
     } else if let Some(ref text) = body.content {
         if text.trim().is_empty() {
             return Err(ApiError::BadRequest(
                 "content must not be empty".to_string(),
             ));
         }
-        text.clone()
+        Some(text.clone())
+    } else if body.planned_for.is_some() {
+        None
     } else {
         return Err(ApiError::BadRequest(
-            "must provide either 'content' or 'blocks'".to_string(),
+            "must provide 'content', 'blocks', or 'planned_for'".to_string(),
         ));
     };
 
-    scheduled_content::update_draft_for(&state.db, &ctx.account_id, id, &content)
-        .await
-        .map_err(ApiError::Storage)?;
+    if let Some(content) = content {
+        state
+            .write(scheduled_content::update_draft_for(
+                &state.db,
+                &ctx.account_id,
+                id,
+                &content,
+            ))
+            .await
+            .map_err(ApiError::Storage)?;
+    }
+
+    if let Some(planned_for) = body.planned_for.as_deref() {
+        let planned_for = Some(planned_for).filter(|p| !p.is_empty());
+        if !drafts::plan_draft_for(&state.db, &ctx.account_id, id, planned_for).await? {
+            return Err(ApiError::NotFound(format!("Draft {id} not found")));
+        }
+    }
 
     Ok(Json(json!({ "id": id, "status": "draft" })))
 }
@@ -150,18 +165,18 @@ pub async fn delete_draft(
 ) -> Result<Json<Value>, ApiError> {
     require_mutate(&ctx)?;
 
-    scheduled_content::delete_draft_for(&state.db, &ctx.account_id, id)
+    state
+        .write(scheduled_content::delete_draft_for(
+            &state.db,
+            &ctx.account_id,
+            id,
+        ))
         .await
         .map_err(ApiError::Storage)?;
 
     Ok(Json(json!({ "id": id, "status": "cancelled" })))
 }
 
-#[derive(Deserialize)]
-pub struct ScheduleDraftRequest {
-    pub scheduled_for: String,
-}
-
 pub async fn schedule_draft(
     State(state): State<Arc<AppState>>,
     ctx: AccountContext,
@@ -170,7 +185,13 @@ pub async fn schedule_draft(
 ) -> Result<Json<Value>, ApiError> {
     require_mutate(&ctx)?;
 
-    scheduled_content::schedule_draft_for(&state.db, &ctx.account_id, id, &body.scheduled_for)
+    state
+        .write(scheduled_content::schedule_draft_for(
+            &state.db,
+            &ctx.account_id,
+            id,
+            &body.scheduled_for,
+        ))
         .await
         .map_err(ApiError::Storage)?;
 
@@ -200,32 +221,106 @@ pub async fn publish_draft(
     }
 
     // Queue into approval queue for immediate posting.
-    let queue_id = approval_queue::enqueue_for(
-        &state.db,
-        &ctx.account_id,
-        &item.content_type,
-        "", // no target tweet
-        "", // no target author
-        &item.content,
-        "",  // topic
-        "",  // archetype
-        0.0, // score
-        "[]",
-    )
-    .await
-    .map_err(ApiError::Storage)?;
+    let queue_id = state
+        .write(approval_queue::enqueue_for(
+            &state.db,
+            &ctx.account_id,
+            &item.content_type,
+            "", // no target tweet
+            "", // no target author
+            &item.content,
+            "",  // topic
+            "",  // archetype
+            0.0, // score
+            "[]",
+        ))
+        .await
+        .map_err(ApiError::Storage)?;
 
     // Mark as approved immediately so the approval poster picks it up.
-    approval_queue::update_status_for(&state.db, &ctx.account_id, queue_id, "approved")
+    state
+        .write(approval_queue::update_status_for(
+            &state.db,
+            &ctx.account_id,
+            queue_id,
+            "approved",
+        ))
         .await
         .map_err(ApiError::Storage)?;
 
     // Mark the draft as posted.
-    scheduled_content::update_status_for(&state.db, &ctx.account_id, id, "posted", None)
+    state
+        .write(scheduled_content::update_status_for(
+            &state.db,
+            &ctx.account_id,
+            id,
+            "posted",
+            None,
+        ))
         .await
         .map_err(ApiError::Storage)?;
 
-    Ok(Json(
-        json!({ "id": id, "approval_queue_id": queue_id, "status": "queued_for_posting" }),
-    ))
+    // Draft-only mode holds approved items until it is lifted.
+    let held = tuitbot_core::storage::draft_only::is_draft_only_active(&state.db)
+        .await
+        .unwrap_or(true);
+    let status = if held {
+        "held_draft_only"
+    } else {
+        "queued_for_posting"
+    };
+
+    Ok(Json(json!({
+        "id": id,
+        "approval_queue_id": queue_id,
+        "status": status,
+        "poster": read_poster(&state).await,
+    })))
+}
+
+/// `POST /api/content/drafts/{id}/check` — run QA on a draft and store the result.
+pub async fn check_draft(
+    State(state): State<Arc<AppState>>,
+    ctx: AccountContext,
+    Path(id): Path<i64>,
+) -> Result<Json<Value>, ApiError> {
+    require_mutate(&ctx)?;
+
+    let draft = load_draft(&state, &ctx, id).await?;
+    let config = read_config(&state)?;
+    let check = draft_workspace::check_draft(&state.db, &ctx.account_id, &config, &draft).await?;
+
+    Ok(Json(json!({ "id": id, "check": check })))
+}
+
+/// `POST /api/content/drafts/{id}/promote` — submit a draft to the approval
+/// queue as a pending item for review.
+pub async fn promote_draft(
+    State(state): State<Arc<AppState>>,
+    ctx: AccountContext,
+    Path(id): Path<i64>,
+) -> Result<Json<Value>, ApiError> {
+    require_mutate(&ctx)?;
+
+    let draft = load_draft(&state, &ctx, id).await?;
+    let config = read_config(&state)?;
+    let promoted =
+        draft_workspace::promote_draft(&state.db, &ctx.account_id, &config, &draft).await?;
+
+    Ok(Json(json!({
+        "id": id,
+        "status": "promoted",
+        "approval_queue_id": promoted.approval_queue_id,
+        "check": promoted.check,
+    })))
+}
+
+async fn load_draft(
+    state: &AppState,
+    ctx: &AccountContext,
+    id: i64,
+) -> Result<scheduled_content::ScheduledContent, ApiError> {
+    drafts::get_draft_for(&state.db, &ctx.account_id, id)
+        .await?
+        .ok_or_else(|| ApiError::NotFound(format!("Draft {id} not found")))
 }
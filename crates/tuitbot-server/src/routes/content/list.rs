@@ -3,25 +3,29 @@
 use std::sync::Arc;
 
 use axum::extract::{Query, State};
+use axum::http::HeaderMap;
 use axum::Json;
 use serde::Deserialize;
 use serde_json::{json, Value};
-use tuitbot_core::storage::threads;
+use tuitbot_core::storage::pagination::Sort;
+use tuitbot_core::storage::threads::{self, TweetFilter, TWEET_SORT_FIELDS};
 
 use crate::account::AccountContext;
 use crate::error::ApiError;
+use crate::routes::{page_headers, Pagination};
 use crate::state::AppState;
 
-/// Query parameters for the tweets endpoint.
+/// Filters for the tweets endpoint; paging comes from [`Pagination`].
 #[derive(Deserialize)]
 pub struct TweetsQuery {
-    /// Maximum number of tweets to return (default: 50).
-    #[serde(default = "default_tweet_limit")]
-    pub limit: u32,
-}
-
-fn default_tweet_limit() -> u32 {
-    50
+    /// Filter by status (e.g. "sent").
+    pub status: Option<String>,
+    /// Filter by topic.
+    pub topic: Option<String>,
+    /// Tweets posted at or after this ISO-8601 timestamp.
+    pub since: Option<String>,
+    /// Tweets posted before this ISO-8601 timestamp.
+    pub until: Option<String>,
 }
 
 /// Query parameters for the threads endpoint.
@@ -36,15 +40,24 @@ fn default_thread_limit() -> u32 {
     20
 }
 
-/// `GET /api/content/tweets` — recent original tweets posted.
+/// `GET /api/content/tweets` — original tweets posted, newest first
+/// (default limit: 50). Sortable by `created_at`, `topic`, and `status`.
 pub async fn list_tweets(
     State(state): State<Arc<AppState>>,
     ctx: AccountContext,
+    pagination: Pagination,
     Query(params): Query<TweetsQuery>,
-) -> Result<Json<Value>, ApiError> {
+) -> Result<(HeaderMap, Json<Value>), ApiError> {
+    let page = pagination.page(50, TWEET_SORT_FIELDS, Some(Sort::desc("created_at")))?;
+    let filter = TweetFilter {
+        status: params.status.as_deref(),
+        topic: params.topic.as_deref(),
+        since: params.since.as_deref(),
+        until: params.until.as_deref(),
+    };
     let tweets =
-        threads::get_recent_original_tweets_for(&state.db, &ctx.account_id, params.limit).await?;
-    Ok(Json(json!(tweets)))
+        threads::get_original_tweets_page_for(&state.db, &ctx.account_id, &filter, &page).await?;
+    Ok((page_headers(&tweets), Json(json!(tweets.items))))
 }
 
 /// `GET /api/content/threads` — recent threads posted.
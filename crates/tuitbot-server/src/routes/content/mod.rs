@@ -6,7 +6,9 @@ mod drafts;
 mod list;
 mod scheduled;
 
+use tuitbot_core::automation::posting_lock::POSTING_LOCK;
 use tuitbot_core::config::Config;
+use tuitbot_core::storage::process_lock;
 
 use crate::error::ApiError;
 use crate::state::AppState;
@@ -15,7 +17,8 @@ use crate::state::AppState;
 pub use calendar::{calendar, schedule};
 pub use compose::{compose, compose_thread, compose_tweet};
 pub use drafts::{
-    create_draft, delete_draft, edit_draft, list_drafts, publish_draft, schedule_draft,
+    check_draft, create_draft, delete_draft, edit_draft, get_draft, list_drafts, promote_draft,
+    publish_draft, schedule_draft,
 };
 pub use list::{list_threads, list_tweets};
 pub use scheduled::{cancel_scheduled, edit_scheduled};
@@ -31,10 +34,31 @@ pub use scheduled::EditScheduledRequest;
 // Shared helpers
 // ---------------------------------------------------------------------------
 
-/// Read `approval_mode` from the config file.
-fn read_approval_mode(state: &AppState) -> Result<bool, ApiError> {
+/// Whether composed content should go to the approval queue: `approval_mode`
+/// from the config file, or draft-only mode being on (unreadable state fails
+/// closed).
+async fn read_approval_mode(state: &AppState) -> Result<bool, ApiError> {
     let config = read_config(state)?;
-    Ok(config.effective_approval_mode())
+    if config.effective_approval_mode() {
+        return Ok(true);
+    }
+    Ok(
+        tuitbot_core::storage::draft_only::is_draft_only_active(&state.db)
+            .await
+            .unwrap_or(true),
+    )
+}
+
+/// The process holding the posting lock, which will post approved items.
+///
+/// `None` when no posting loop is running, so queued items wait until one
+/// starts.
+async fn read_poster(state: &AppState) -> Option<String> {
+    process_lock::get_lock_holder(&state.db, POSTING_LOCK)
+        .await
+        .ok()
+        .flatten()
+        .map(|holder| holder.holder)
 }
 
 /// Read the full config from the config file.
@@ -4,7 +4,6 @@ use std::sync::Arc;
 
 use axum::extract::{Path, State};
 use axum::Json;
-use serde::Deserialize;
 use serde_json::{json, Value};
 use tuitbot_core::storage::scheduled_content;
 
@@ -12,14 +11,7 @@ use crate::account::{require_mutate, AccountContext};
 use crate::error::ApiError;
 use crate::state::AppState;
 
-/// Request body for editing a scheduled content item.
-#[derive(Deserialize)]
-pub struct EditScheduledRequest {
-    /// Updated content text.
-    pub content: Option<String>,
-    /// Updated scheduled time.
-    pub scheduled_for: Option<String>,
-}
+pub use tuitbot_types::content::EditScheduledRequest;
 
 /// `PATCH /api/content/scheduled/{id}` — edit a scheduled content item.
 pub async fn edit_scheduled(
@@ -46,14 +38,15 @@ pub async fn edit_scheduled(
         None => item.scheduled_for.as_deref(),
     };
 
-    scheduled_content::update_content_for(
-        &state.db,
-        &ctx.account_id,
-        id,
-        new_content,
-        new_scheduled_for,
-    )
-    .await?;
+    state
+        .write(scheduled_content::update_content_for(
+            &state.db,
+            &ctx.account_id,
+            id,
+            new_content,
+            new_scheduled_for,
+        ))
+        .await?;
 
     let updated = scheduled_content::get_by_id_for(&state.db, &ctx.account_id, id)
         .await?
@@ -80,7 +73,13 @@ pub async fn cancel_scheduled(
         ));
     }
 
-    scheduled_content::cancel_for(&state.db, &ctx.account_id, id).await?;
+    state
+        .write(scheduled_content::cancel_for(
+            &state.db,
+            &ctx.account_id,
+            id,
+        ))
+        .await?;
 
     Ok(Json(json!({
         "status": "cancelled",
@@ -4,7 +4,7 @@ use std::sync::Arc;
 
 use axum::extract::{Path, Query, State};
 use axum::Json;
-use serde::{Deserialize, Serialize};
+use serde::Deserialize;
 use serde_json::{json, Value};
 use tuitbot_core::content::ContentGenerator;
 use tuitbot_core::storage::{self, approval_queue};
@@ -13,6 +13,10 @@ use crate::account::{require_mutate, AccountContext};
 use crate::error::ApiError;
 use crate::state::AppState;
 
+pub use tuitbot_types::discovery::{
+    ComposeReplyRequest, ComposeReplyResponse, DiscoveryTweet, QueueReplyRequest,
+};
+
 // ---------------------------------------------------------------------------
 // Helpers
 // ---------------------------------------------------------------------------
@@ -51,20 +55,6 @@ fn default_feed_limit() -> u32 {
     20
 }
 
-#[derive(Serialize)]
-pub struct DiscoveryTweet {
-    pub id: String,
-    pub author_username: String,
-    pub content: String,
-    pub relevance_score: f64,
-    pub matched_keyword: Option<String>,
-    pub like_count: i64,
-    pub retweet_count: i64,
-    pub reply_count: i64,
-    pub replied_to: bool,
-    pub discovered_at: String,
-}
-
 pub async fn feed(
     State(state): State<Arc<AppState>>,
     ctx: AccountContext,
@@ -112,21 +102,33 @@ pub async fn keywords(
 }
 
 // ---------------------------------------------------------------------------
-// POST /api/discovery/{tweet_id}/compose-reply
+// GET /api/discovery/keywords/stats
 // ---------------------------------------------------------------------------
 
 #[derive(Deserialize)]
-pub struct ComposeReplyRequest {
-    #[serde(default)]
-    pub mention_product: bool,
+pub struct KeywordStatsQuery {
+    #[serde(default = "default_stats_days")]
+    pub days: u32,
 }
 
-#[derive(Serialize)]
-pub struct ComposeReplyResponse {
-    pub content: String,
-    pub tweet_id: String,
+fn default_stats_days() -> u32 {
+    30
 }
 
+pub async fn keyword_stats(
+    State(state): State<Arc<AppState>>,
+    ctx: AccountContext,
+    Query(q): Query<KeywordStatsQuery>,
+) -> Result<Json<Vec<storage::keyword_hits::KeywordStats>>, ApiError> {
+    let stats =
+        storage::keyword_hits::get_keyword_stats_for(&state.db, &ctx.account_id, q.days).await?;
+    Ok(Json(stats))
+}
+
+// ---------------------------------------------------------------------------
+// POST /api/discovery/{tweet_id}/compose-reply
+// ---------------------------------------------------------------------------
+
 pub async fn compose_reply(
     State(state): State<Arc<AppState>>,
     ctx: AccountContext,
@@ -157,11 +159,6 @@ pub async fn compose_reply(
 // POST /api/discovery/{tweet_id}/queue-reply
 // ---------------------------------------------------------------------------
 
-#[derive(Deserialize)]
-pub struct QueueReplyRequest {
-    pub content: String,
-}
-
 pub async fn queue_reply(
     State(state): State<Arc<AppState>>,
     ctx: AccountContext,
@@ -182,22 +179,29 @@ pub async fn queue_reply(
         .map(|t| t.author_username)
         .unwrap_or_default();
 
-    let queue_id = approval_queue::enqueue_for(
-        &state.db,
-        &ctx.account_id,
-        "reply",
-        &tweet_id,
-        &target_author,
-        &body.content,
-        "",  // topic
-        "",  // archetype
-        0.0, // score
-        "[]",
-    )
-    .await?;
+    let queue_id = state
+        .write(approval_queue::enqueue_for(
+            &state.db,
+            &ctx.account_id,
+            "reply",
+            &tweet_id,
+            &target_author,
+            &body.content,
+            "",  // topic
+            "",  // archetype
+            0.0, // score
+            "[]",
+        ))
+        .await?;
 
     // Auto-approve for immediate posting.
-    storage::approval_queue::update_status_for(&state.db, &ctx.account_id, queue_id, "approved")
+    state
+        .write(storage::approval_queue::update_status_for(
+            &state.db,
+            &ctx.account_id,
+            queue_id,
+            "approved",
+        ))
         .await?;
 
     Ok(Json(json!({
@@ -0,0 +1,150 @@
+//! Approve/reject links from the email digest.
+//!
+//! The signed token in the path is the only credential, so these routes are
+//! exempt from session and bearer auth. `GET` only renders a confirmation
+//! page, because mail scanners prefetch links; the decision is applied by
+//! the page's `POST` form.
+
+use std::sync::Arc;
+
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::response::Html;
+use tuitbot_core::error::StorageError;
+use tuitbot_core::storage::approval_queue::{self, ApprovalItem, ReviewAction};
+
+use super::approval::{transition, TransitionError};
+use crate::email::ReviewToken;
+use crate::state::AppState;
+
+/// Reviewer recorded for decisions made from email.
+const EMAIL_ACTOR: &str = "email";
+
+type Page = (StatusCode, Html<String>);
+
+/// `GET /api/email-review/{token}` — confirm an emailed decision.
+pub async fn confirm(State(state): State<Arc<AppState>>, Path(token): Path<String>) -> Page {
+    let (claims, item) = match load(&state, &token).await {
+        Ok(found) => found,
+        Err(page) => return page,
+    };
+    let verb = claims.decision.as_str();
+    page(
+        StatusCode::OK,
+        &format!("{} {} #{}?", capitalize(verb), item.action_type, item.id),
+        &format!(
+            "<blockquote>{}</blockquote>\
+             <form method=\"post\"><button type=\"submit\">{}</button></form>",
+            escape(&item.generated_content),
+            capitalize(verb)
+        ),
+    )
+}
+
+/// `POST /api/email-review/{token}` — apply an emailed decision.
+pub async fn apply(State(state): State<Arc<AppState>>, Path(token): Path<String>) -> Page {
+    let (claims, item) = match load(&state, &token).await {
+        Ok(found) => found,
+        Err(page) => return page,
+    };
+    let status = claims.decision.status();
+    let review = ReviewAction {
+        actor: Some(EMAIL_ACTOR.to_string()),
+        notes: None,
+        expected_version: Some(claims.version),
+    };
+    match transition(&state, &claims.account_id, &item, status, review).await {
+        Ok(()) => {}
+        Err(TransitionError::Denied { hook, reason }) => {
+            return page(
+                StatusCode::CONFLICT,
+                "Held by policy",
+                &format!(
+                    "<p>Hook {} denied this approval: {}</p>",
+                    escape(&hook),
+                    escape(&reason)
+                ),
+            );
+        }
+        Err(TransitionError::Storage(StorageError::Conflict { .. })) => return stale(),
+        Err(TransitionError::Storage(e)) => {
+            tracing::warn!(id = item.id, error = %e, "Email review failed");
+            return page(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Something went wrong",
+                "<p>Try again from the dashboard.</p>",
+            );
+        }
+    }
+
+    page(
+        StatusCode::OK,
+        &format!("{} #{}", capitalize(status), item.id),
+        "<p>You can close this tab.</p>",
+    )
+}
+
+/// Verify the token and fetch the item it was issued for.
+async fn load(state: &AppState, token: &str) -> Result<(ReviewToken, ApprovalItem), Page> {
+    let claims =
+        ReviewToken::verify(token, &state.api_token, chrono::Utc::now()).map_err(|reason| {
+            page(
+                StatusCode::FORBIDDEN,
+                "Link not valid",
+                &format!("<p>This review link is not valid: {reason}.</p>"),
+            )
+        })?;
+    let item = approval_queue::get_by_id_for(&state.db, &claims.account_id, claims.item_id)
+        .await
+        .ok()
+        .flatten()
+        .ok_or_else(|| {
+            page(
+                StatusCode::NOT_FOUND,
+                "Item not found",
+                "<p>This item no longer exists.</p>",
+            )
+        })?;
+    if item.version != claims.version {
+        return Err(stale());
+    }
+    Ok((claims, item))
+}
+
+fn stale() -> Page {
+    page(
+        StatusCode::CONFLICT,
+        "Already reviewed",
+        "<p>This item was reviewed or edited after the email was sent. \
+         Open the dashboard to see its current state.</p>",
+    )
+}
+
+fn page(status: StatusCode, title: &str, body: &str) -> Page {
+    let title = escape(title);
+    (
+        status,
+        Html(format!(
+            "<!doctype html><html><head><meta charset=\"utf-8\">\
+             <meta name=\"viewport\" content=\"width=device-width\">\
+             <title>{title}</title></head>\
+             <body style=\"font-family:sans-serif;max-width:40em;margin:2em auto\">\
+             <h1>{title}</h1>{body}</body></html>"
+        )),
+    )
+}
+
+fn capitalize(s: &str) -> String {
+    let mut chars = s.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+fn escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
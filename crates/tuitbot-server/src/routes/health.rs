@@ -38,10 +38,49 @@ pub async fn health_detailed(State(state): State<Arc<AppState>>) -> Json<Value>
         ("disabled".to_string(), 0, 0)
     };
 
+    // LLM provider breakers (providers used by this process)
+    let llm_providers = tuitbot_core::llm::resilience::provider_health();
+    let llm_open = llm_providers.iter().any(|p| p.state == "open");
+
+    // Account visibility (latest self-check, if any)
+    let visibility = tuitbot_core::storage::account_health::get_latest_health_check(&state.db)
+        .await
+        .ok()
+        .flatten();
+    let visibility_status = visibility
+        .as_ref()
+        .map_or("unknown", |check| check.status.as_str());
+
+    // Compliance kill switch
+    let kill_switch = tuitbot_core::storage::kill_switch::get_kill_switch(&state.db)
+        .await
+        .unwrap_or_default();
+
+    // Draft-only mode (posting disabled, items held)
+    let draft_only = tuitbot_core::storage::draft_only::get_draft_only(&state.db)
+        .await
+        .unwrap_or_default();
+
+    // Archived install (served read-only)
+    let archive = tuitbot_core::storage::archive::get_archive_state(&state.db)
+        .await
+        .unwrap_or_default();
+
+    // API rate limiter
+    let rate_limit = state.rate_limiter.stats();
+
+    // Shared write queue (absent when writes go straight to the pool)
+    let write_queue = state.write_queue.as_ref().map(|q| q.stats());
+
     // Overall status
     let overall = if !db_health.reachable {
         "unhealthy"
-    } else if !db_health.wal_mode || cb_state == "open" {
+    } else if !db_health.wal_mode
+        || cb_state == "open"
+        || llm_open
+        || visibility_status == "degraded"
+        || kill_switch.active
+    {
         "degraded"
     } else {
         "healthy"
@@ -68,6 +107,45 @@ pub async fn health_detailed(State(state): State<Arc<AppState>>) -> Json<Value>
                 "error_count": cb_error_count,
                 "cooldown_remaining_seconds": cb_cooldown,
             },
+            "llm": {
+                "healthy": !llm_open,
+                "providers": llm_providers,
+            },
+            "kill_switch": {
+                "healthy": !kill_switch.active,
+                "active": kill_switch.active,
+                "reason": kill_switch.reason,
+                "changed_at": kill_switch.changed_at,
+            },
+            "draft_only": {
+                "healthy": true,
+                "active": draft_only.active,
+                "reason": draft_only.reason,
+                "changed_at": draft_only.changed_at,
+            },
+            "archive": {
+                "healthy": true,
+                "archived": archive.archived,
+                "changed_at": archive.changed_at,
+            },
+            "rate_limit": {
+                "healthy": true,
+                "enabled": rate_limit.enabled,
+                "burst": rate_limit.burst,
+                "per_second": rate_limit.per_second,
+                "tracked_clients": rate_limit.tracked_clients,
+                "throttled_total": rate_limit.throttled_total,
+            },
+            "write_queue": {
+                "healthy": true,
+                "enabled": write_queue.is_some(),
+                "stats": write_queue,
+            },
+            "visibility": {
+                "healthy": visibility_status != "degraded",
+                "status": visibility_status,
+                "last_check": visibility,
+            },
         },
     }))
 }
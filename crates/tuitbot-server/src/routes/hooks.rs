@@ -0,0 +1,42 @@
+//! Lifecycle hook endpoints.
+
+use std::sync::Arc;
+
+use axum::extract::{Query, State};
+use axum::Json;
+use serde::Deserialize;
+use tuitbot_core::storage::hook_runs::{self, HookRun};
+
+use crate::account::AccountContext;
+use crate::error::ApiError;
+use crate::state::AppState;
+
+/// Query parameters for the hook runs endpoint.
+#[derive(Deserialize)]
+pub struct HookRunsQuery {
+    /// Only runs for this approval item.
+    pub item_id: Option<i64>,
+    /// Maximum number of runs to return (default: 50).
+    #[serde(default = "default_limit")]
+    pub limit: u32,
+}
+
+fn default_limit() -> u32 {
+    50
+}
+
+/// `GET /api/hooks/runs` — recent hook runs with their verdicts, newest first.
+pub async fn list_runs(
+    State(state): State<Arc<AppState>>,
+    ctx: AccountContext,
+    Query(params): Query<HookRunsQuery>,
+) -> Result<Json<Vec<HookRun>>, ApiError> {
+    let runs = hook_runs::list_recent_for(
+        &state.db,
+        &ctx.account_id,
+        params.item_id,
+        params.limit.min(500),
+    )
+    .await?;
+    Ok(Json(runs))
+}
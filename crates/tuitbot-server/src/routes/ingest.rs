@@ -1,7 +1,10 @@
-//! Ingest endpoint for the Watchtower pipeline.
+//! Ingest endpoints.
 //!
-//! Accepts inline content nodes for direct ingestion (e.g. from iOS Shortcuts
-//! or Telegram) and file hints for future filesystem scanning.
+//! `POST /api/ingest` accepts inline content nodes for direct ingestion into
+//! the Watchtower pipeline (e.g. from iOS Shortcuts or Telegram) and file
+//! hints for future filesystem scanning. `POST /api/ingest/candidates` lets
+//! external systems push reply candidates through scoring, generation, and
+//! the approval queue.
 
 use std::path::PathBuf;
 use std::sync::Arc;
@@ -9,48 +12,24 @@ use std::time::Instant;
 
 use axum::extract::State;
 use axum::Json;
-use serde::{Deserialize, Serialize};
+use serde_json::json;
 use sha2::{Digest, Sha256};
 use tuitbot_core::automation::watchtower as wt;
-use tuitbot_core::storage::watchtower;
+use tuitbot_core::config::Config;
+use tuitbot_core::startup;
+use tuitbot_core::storage::{approval_queue, watchtower};
+use tuitbot_core::workflow::ingest::{self as candidate_ingest, IngestInput};
+use tuitbot_core::x_api::{XApiClient, XApiHttpClient};
 
+use crate::account::{require_mutate, AccountContext};
 use crate::error::ApiError;
 use crate::state::AppState;
+use crate::ws::WsEvent;
 
-// ---------------------------------------------------------------------------
-// Request / response types
-// ---------------------------------------------------------------------------
-
-#[derive(Deserialize)]
-pub struct IngestRequest {
-    /// Specific files to re-scan (relative paths within source).
-    #[serde(default)]
-    pub file_hints: Vec<String>,
-    /// Re-ingest even if content hash is unchanged.
-    #[serde(default)]
-    pub force: bool,
-    /// Inline content nodes for direct ingestion (Shortcuts/Telegram).
-    #[serde(default)]
-    pub inline_nodes: Vec<InlineNode>,
-}
-
-#[derive(Deserialize)]
-pub struct InlineNode {
-    pub relative_path: String,
-    pub body_text: String,
-    #[serde(default)]
-    pub title: Option<String>,
-    #[serde(default)]
-    pub tags: Option<String>,
-}
-
-#[derive(Serialize)]
-pub struct IngestResponse {
-    pub ingested: u32,
-    pub skipped: u32,
-    pub errors: Vec<String>,
-    pub duration_ms: u64,
-}
+pub use tuitbot_types::ingest::{
+    CandidateInput, CandidateResult, IngestCandidatesRequest, IngestCandidatesResponse,
+    IngestRequest, IngestResponse, InlineNode,
+};
 
 // ---------------------------------------------------------------------------
 // Handler
@@ -164,3 +143,116 @@ pub async fn ingest(
         duration_ms,
     }))
 }
+
+/// `POST /api/ingest/candidates` — score externally supplied candidates and
+/// queue generated replies for approval.
+///
+/// Uses the same bearer token (or session) auth as every other API route.
+/// Tweet candidates are fetched with the stored X API tokens and skipped
+/// when there are none.
+pub async fn ingest_candidates(
+    State(state): State<Arc<AppState>>,
+    ctx: AccountContext,
+    Json(body): Json<IngestCandidatesRequest>,
+) -> Result<Json<IngestCandidatesResponse>, ApiError> {
+    require_mutate(&ctx)?;
+
+    let gen = state
+        .content_generators
+        .lock()
+        .await
+        .get(&ctx.account_id)
+        .cloned()
+        .ok_or(ApiError::BadRequest(
+            "LLM not configured — set llm.provider and llm.api_key in config.toml".to_string(),
+        ))?;
+    let config = load_config(&state)?;
+    let x_client = x_client(&state, &config).await;
+
+    let results = candidate_ingest::execute(
+        &state.db,
+        &ctx.account_id,
+        x_client.as_ref().map(|c| c as &dyn XApiClient),
+        &gen,
+        &config,
+        IngestInput {
+            candidates: body.candidates,
+            mention_product: body.mention_product,
+        },
+    )
+    .await?;
+
+    let mut response = IngestCandidatesResponse {
+        queued: 0,
+        below_threshold: 0,
+        skipped: 0,
+        errors: 0,
+        results: Vec::new(),
+    };
+    for result in &results {
+        match result.status.as_str() {
+            "queued" => response.queued += 1,
+            "below_threshold" => response.below_threshold += 1,
+            "skipped" => response.skipped += 1,
+            _ => response.errors += 1,
+        }
+        let Some(id) = result.approval_queue_id else {
+            continue;
+        };
+        if let Ok(Some(item)) = approval_queue::get_by_id_for(&state.db, &ctx.account_id, id).await
+        {
+            let _ = state.event_tx.send(WsEvent::ApprovalQueued {
+                id,
+                action_type: item.action_type,
+                content: item.generated_content,
+                media_paths: vec![],
+            });
+        }
+    }
+
+    let metadata = json!({
+        "received": results.len(),
+        "queued": response.queued,
+    });
+    let _ = state
+        .log_action_for(
+            &ctx.account_id,
+            "ingest_candidates",
+            "success",
+            Some(&format!(
+                "Ingested {} candidates, queued {}",
+                results.len(),
+                response.queued
+            )),
+            Some(&metadata.to_string()),
+        )
+        .await;
+
+    response.results = results;
+    Ok(Json(response))
+}
+
+/// An X API client from the stored tokens, if they are present and unexpired.
+///
+/// The server's token refresh task keeps the token file current.
+async fn x_client(state: &AppState, config: &Config) -> Option<XApiHttpClient> {
+    let tokens = startup::load_tokens_from_file().ok()?;
+    if tokens.is_expired() {
+        return None;
+    }
+    let client = XApiHttpClient::from_config(tokens.access_token, &config.x_api)
+        .with_protected_entities(&config.blocklist.protected);
+    client.set_pool(state.db.clone()).await;
+    Some(client)
+}
+
+fn load_config(state: &AppState) -> Result<Config, ApiError> {
+    let contents = std::fs::read_to_string(&state.config_path).map_err(|e| {
+        ApiError::BadRequest(format!(
+            "could not read config file {}: {e}",
+            state.config_path.display()
+        ))
+    })?;
+    toml::from_str(&contents)
+        .map_err(|e| ApiError::BadRequest(format!("failed to parse config: {e}")))
+}
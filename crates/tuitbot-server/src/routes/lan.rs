@@ -9,22 +9,14 @@ use std::sync::Arc;
 use axum::extract::State;
 use axum::http::StatusCode;
 use axum::response::IntoResponse;
-use serde::{Deserialize, Serialize};
 use serde_json::json;
 use tuitbot_core::auth::passphrase;
-use tuitbot_core::config::Config;
+use tuitbot_core::config::{history, Config};
 use tuitbot_core::net::local_ip;
 
 use crate::state::AppState;
 
-#[derive(Serialize)]
-struct LanStatus {
-    bind_host: String,
-    bind_port: u16,
-    lan_enabled: bool,
-    local_ip: Option<String>,
-    passphrase_configured: bool,
-}
+pub use tuitbot_types::lan::{LanStatus, ToggleLanRequest};
 
 /// `GET /api/settings/lan` — return current LAN/server status.
 pub async fn get_status(State(state): State<Arc<AppState>>) -> impl IntoResponse {
@@ -67,11 +59,6 @@ pub async fn reset_passphrase(State(state): State<Arc<AppState>>) -> impl IntoRe
     }
 }
 
-#[derive(Deserialize)]
-pub struct ToggleLanRequest {
-    host: String,
-}
-
 /// `PATCH /api/settings/lan` — toggle LAN mode by updating config.toml.
 pub async fn toggle_lan(
     State(state): State<Arc<AppState>>,
@@ -112,7 +99,7 @@ pub async fn toggle_lan(
         }
     };
 
-    if let Err(e) = std::fs::write(&state.config_path, toml_str) {
+    if let Err(e) = history::write_config(&state.config_path, &toml_str, "api") {
         return (
             StatusCode::INTERNAL_SERVER_ERROR,
             axum::Json(json!({"error": format!("failed to write config: {e}")})),
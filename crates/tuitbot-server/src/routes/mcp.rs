@@ -7,11 +7,14 @@ use axum::extract::{Path, Query, State};
 use axum::Json;
 use serde::Deserialize;
 use serde_json::{json, Value};
-use tuitbot_core::config::Config;
+use tuitbot_core::config::{history, Config};
 use tuitbot_core::mcp_policy::templates;
 use tuitbot_core::mcp_policy::types::PolicyTemplateName;
+use tuitbot_core::storage::staged_actions::{self, StagedAction};
+use tuitbot_core::storage::workflow_origins::{self, OriginFilter, OriginRecord};
 use tuitbot_core::storage::{mcp_telemetry, rate_limits};
 
+use crate::account::{require_approve, AccountContext};
 use crate::error::ApiError;
 use crate::state::AppState;
 
@@ -41,6 +44,19 @@ fn default_limit() -> u32 {
     50
 }
 
+#[derive(Deserialize)]
+pub struct OriginsQuery {
+    /// Only origins from this agent session.
+    pub session_id: Option<String>,
+    /// Only origins from this agent.
+    pub agent: Option<String>,
+    /// Only origins of this record type (e.g. `approval_queue`).
+    pub record_type: Option<String>,
+    /// Maximum entries to return (default: 50).
+    #[serde(default = "default_limit")]
+    pub limit: u32,
+}
+
 // ---------------------------------------------------------------------------
 // Policy endpoints
 // ---------------------------------------------------------------------------
@@ -76,6 +92,8 @@ pub async fn get_policy(State(state): State<Arc<AppState>>) -> Result<Json<Value
         "template": config.mcp_policy.template,
         "rules": config.mcp_policy.rules,
         "rate_limits": config.mcp_policy.rate_limits,
+        "tools": config.mcp_policy.tools,
+        "categories": config.mcp_policy.categories,
     })))
 }
 
@@ -117,12 +135,16 @@ pub async fn patch_policy(
     let config: Config = toml::from_str(&merged_str)
         .map_err(|e| ApiError::BadRequest(format!("merged config is invalid: {e}")))?;
 
-    std::fs::write(&state.config_path, &merged_str).map_err(|e| {
-        ApiError::BadRequest(format!(
-            "could not write config file {}: {e}",
-            state.config_path.display()
-        ))
-    })?;
+    history::write_config(&state.config_path, &merged_str, "api")
+        .map_err(|e| ApiError::BadRequest(format!("could not write config file: {e}")))?;
+
+    // Initialize counters for any newly configured per-tool/category caps
+    if let Err(e) =
+        rate_limits::init_policy_rate_limits(&state.db, &config.mcp_policy.effective_rate_limits())
+            .await
+    {
+        tracing::warn!("Failed to initialize policy rate limits: {e}");
+    }
 
     Ok(Json(json!({
         "enforce_for_mutations": config.mcp_policy.enforce_for_mutations,
@@ -133,6 +155,8 @@ pub async fn patch_policy(
         "template": config.mcp_policy.template,
         "rules": config.mcp_policy.rules,
         "rate_limits": config.mcp_policy.rate_limits,
+        "tools": config.mcp_policy.tools,
+        "categories": config.mcp_policy.categories,
     })))
 }
 
@@ -188,16 +212,13 @@ pub async fn apply_template(
     let config: Config = toml::from_str(&merged_str)
         .map_err(|e| ApiError::BadRequest(format!("merged config is invalid: {e}")))?;
 
-    std::fs::write(&state.config_path, &merged_str).map_err(|e| {
-        ApiError::BadRequest(format!(
-            "could not write config file {}: {e}",
-            state.config_path.display()
-        ))
-    })?;
+    history::write_config(&state.config_path, &merged_str, "api")
+        .map_err(|e| ApiError::BadRequest(format!("could not write config file: {e}")))?;
 
     // Initialize rate limit rows for the new template limits
     if let Err(e) =
-        rate_limits::init_policy_rate_limits(&state.db, &config.mcp_policy.rate_limits).await
+        rate_limits::init_policy_rate_limits(&state.db, &config.mcp_policy.effective_rate_limits())
+            .await
     {
         tracing::warn!("Failed to initialize policy rate limits: {e}");
     }
@@ -253,6 +274,41 @@ pub async fn telemetry_recent(
     Ok(Json(json!(entries)))
 }
 
+// ---------------------------------------------------------------------------
+// Workflow origins
+// ---------------------------------------------------------------------------
+
+/// `GET /api/mcp/origins` — rows created by MCP tools, with their agent,
+/// session, and goal.
+pub async fn list_origins(
+    State(state): State<Arc<AppState>>,
+    ctx: AccountContext,
+    Query(params): Query<OriginsQuery>,
+) -> Result<Json<Vec<OriginRecord>>, ApiError> {
+    let filter = OriginFilter {
+        session_id: params.session_id,
+        agent: params.agent,
+        record_type: params.record_type,
+        limit: params.limit,
+    };
+    let origins = workflow_origins::list_origins_for(&state.db, &ctx.account_id, &filter).await?;
+    Ok(Json(origins))
+}
+
+/// `GET /api/mcp/origins/{record_type}/{record_id}` — origin of one row,
+/// e.g. which agent session queued approval item 42.
+pub async fn get_origin(
+    State(state): State<Arc<AppState>>,
+    Path((record_type, record_id)): Path<(String, i64)>,
+) -> Result<Json<OriginRecord>, ApiError> {
+    workflow_origins::get_origin(&state.db, &record_type, record_id)
+        .await?
+        .map(Json)
+        .ok_or_else(|| {
+            ApiError::NotFound(format!("no workflow origin for {record_type} {record_id}"))
+        })
+}
+
 // ---------------------------------------------------------------------------
 // Helpers
 // ---------------------------------------------------------------------------
@@ -317,6 +373,69 @@ fn merge_toml(base: &mut toml::Value, patch: &toml::Value) {
     }
 }
 
+// ---------------------------------------------------------------------------
+// Staged actions (confirmation mode)
+// ---------------------------------------------------------------------------
+
+/// `GET /api/mcp/staged` — staged mutations awaiting confirmation.
+pub async fn list_staged(
+    State(state): State<Arc<AppState>>,
+    ctx: AccountContext,
+) -> Result<Json<Vec<StagedAction>>, ApiError> {
+    let actions = staged_actions::list_open_for(&state.db, &ctx.account_id).await?;
+    Ok(Json(actions))
+}
+
+/// `POST /api/mcp/staged/{id}/approve` — confirm a staged mutation.
+///
+/// The MCP server executes it on the agent's next `confirm_action` call
+/// or identical tool call.
+pub async fn approve_staged(
+    State(state): State<Arc<AppState>>,
+    ctx: AccountContext,
+    Path(id): Path<i64>,
+) -> Result<Json<StagedAction>, ApiError> {
+    resolve_staged(&state, &ctx, id, true).await
+}
+
+/// `POST /api/mcp/staged/{id}/reject` — reject a staged mutation.
+pub async fn reject_staged(
+    State(state): State<Arc<AppState>>,
+    ctx: AccountContext,
+    Path(id): Path<i64>,
+) -> Result<Json<StagedAction>, ApiError> {
+    resolve_staged(&state, &ctx, id, false).await
+}
+
+async fn resolve_staged(
+    state: &AppState,
+    ctx: &AccountContext,
+    id: i64,
+    approve: bool,
+) -> Result<Json<StagedAction>, ApiError> {
+    require_approve(ctx)?;
+
+    let resolved = if approve {
+        staged_actions::confirm(&state.db, id, "dashboard").await?
+    } else {
+        staged_actions::reject(&state.db, id, "dashboard").await?
+    };
+    let action = staged_actions::get_by_id(&state.db, id)
+        .await?
+        .ok_or_else(|| ApiError::NotFound(format!("staged action {id} not found")))?;
+    if !resolved {
+        return Err(ApiError::Conflict(format!(
+            "staged action {id} is {} and can no longer be resolved",
+            if action.is_expired() {
+                "expired"
+            } else {
+                action.status.as_str()
+            }
+        )));
+    }
+    Ok(Json(action))
+}
+
 /// Convert JSON to TOML, skipping nulls in objects.
 fn json_to_toml(json: &serde_json::Value) -> Result<toml::Value, String> {
     match json {
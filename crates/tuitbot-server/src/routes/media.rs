@@ -2,7 +2,8 @@
 
 use std::sync::Arc;
 
-use axum::extract::{Multipart, Query, State};
+use axum::body::Body;
+use axum::extract::{Multipart, Path, Query, State};
 use axum::http::header;
 use axum::response::{IntoResponse, Response};
 use axum::Json;
@@ -94,3 +95,52 @@ pub async fn serve_file(
 
     Ok(([(header::CONTENT_TYPE, content_type)], data).into_response())
 }
+
+/// `GET /api/media/{id}` — stream a stored media file by its media ID.
+///
+/// The ID is the stored file name (`{uuid}.{ext}`) derived from an approval
+/// item's `media_paths`. Only files inside `{data_dir}/media/` are served,
+/// and files larger than X's limit for their type are refused.
+pub async fn serve_by_id(
+    State(state): State<Arc<AppState>>,
+    _ctx: AccountContext,
+    Path(id): Path<String>,
+) -> Result<Response, ApiError> {
+    if !media::is_valid_media_id(&id) {
+        return Err(ApiError::BadRequest("invalid media id".to_string()));
+    }
+    let path = media::resolve_media_id(&state.data_dir, &id)
+        .ok_or_else(|| ApiError::NotFound(format!("media {id} not found")))?;
+
+    let media_type = media::detect_media_type(&id, None)
+        .ok_or_else(|| ApiError::BadRequest("unsupported media type".to_string()))?;
+
+    let file = tokio::fs::File::open(&path)
+        .await
+        .map_err(|e| ApiError::NotFound(format!("media {id} not found: {e}")))?;
+    let size = file
+        .metadata()
+        .await
+        .map_err(|e| ApiError::Internal(format!("failed to stat media: {e}")))?
+        .len();
+
+    if size > media_type.max_size() {
+        return Err(ApiError::PayloadTooLarge(format!(
+            "media file size {size}B exceeds maximum {}B for {}",
+            media_type.max_size(),
+            media_type.mime_type()
+        )));
+    }
+
+    let body = Body::from_stream(tokio_util::io::ReaderStream::new(file));
+    Ok((
+        [
+            (header::CONTENT_TYPE, media_type.mime_type().to_string()),
+            (header::CONTENT_LENGTH, size.to_string()),
+            (header::X_CONTENT_TYPE_OPTIONS, "nosniff".to_string()),
+            (header::CACHE_CONTROL, "private, max-age=3600".to_string()),
+        ],
+        body,
+    )
+        .into_response())
+}
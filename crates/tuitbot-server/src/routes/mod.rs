@@ -2,19 +2,100 @@
 
 pub mod accounts;
 pub mod activity;
+pub mod admin;
 pub mod analytics;
 pub mod approval;
 pub mod assist;
 pub mod content;
 pub mod costs;
 pub mod discovery;
+pub mod email_review;
 pub mod health;
+pub mod hooks;
 pub mod ingest;
 pub mod lan;
 pub mod mcp;
 pub mod media;
+pub mod public;
 pub mod replies;
 pub mod runtime;
 pub mod settings;
 pub mod strategy;
+pub mod summary;
 pub mod targets;
+
+use axum::extract::{FromRequestParts, Query};
+use axum::http::request::Parts;
+use axum::http::{HeaderMap, HeaderValue};
+use serde::Deserialize;
+use tuitbot_core::storage::pagination::{Page, PageRequest, Sort, MAX_PAGE_LIMIT};
+
+use crate::error::ApiError;
+
+/// Shared `?limit=&offset=&cursor=&sort=` parameters for list endpoints.
+///
+/// `sort` is a field name, prefixed with `-` for descending order. `cursor`
+/// is the `X-Next-Cursor` value of the previous page and needs a
+/// chronological sort (`created_at`). List bodies keep their shape; the
+/// total and next cursor are sent as `X-Total-Count` and `X-Next-Cursor`.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct Pagination {
+    pub limit: Option<u32>,
+    pub offset: Option<u32>,
+    pub cursor: Option<i64>,
+    pub sort: Option<String>,
+}
+
+impl<S: Send + Sync> FromRequestParts<S> for Pagination {
+    type Rejection = ApiError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let Query(pagination) = Query::<Pagination>::from_request_parts(parts, state)
+            .await
+            .map_err(|e| ApiError::BadRequest(e.body_text()))?;
+        Ok(pagination)
+    }
+}
+
+impl Pagination {
+    /// Resolve into a page request. `fields` are the sortable (field,
+    /// column) pairs; `default_sort` applies when `sort` is absent.
+    pub fn page(
+        &self,
+        default_limit: u32,
+        fields: &[(&str, &'static str)],
+        default_sort: Option<Sort>,
+    ) -> Result<PageRequest, ApiError> {
+        let limit = self.limit.unwrap_or(default_limit);
+        if !(1..=MAX_PAGE_LIMIT).contains(&limit) {
+            return Err(ApiError::BadRequest(format!(
+                "limit must be between 1 and {MAX_PAGE_LIMIT}"
+            )));
+        }
+        let sort = match self.sort.as_deref() {
+            Some(spec) => Some(Sort::parse(spec, fields).map_err(ApiError::BadRequest)?),
+            None => default_sort,
+        };
+        if self.cursor.is_some() && !sort.is_some_and(|s| s.is_chronological()) {
+            return Err(ApiError::BadRequest(
+                "cursor needs a created_at sort; use offset instead".to_string(),
+            ));
+        }
+        Ok(PageRequest {
+            limit,
+            offset: self.offset.unwrap_or(0),
+            cursor: self.cursor,
+            sort,
+        })
+    }
+}
+
+/// `X-Total-Count` and, when there is a next page, `X-Next-Cursor`.
+pub fn page_headers<T>(page: &Page<T>) -> HeaderMap {
+    let mut headers = HeaderMap::new();
+    headers.insert("x-total-count", HeaderValue::from(page.total));
+    if let Some(cursor) = page.next_cursor {
+        headers.insert("x-next-cursor", HeaderValue::from(cursor));
+    }
+    headers
+}
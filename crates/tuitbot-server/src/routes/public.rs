@@ -0,0 +1,83 @@
+//! Public recent-activity feed for embedding on a website.
+//!
+//! `GET /api/public/feed` needs no authentication, so it is off unless
+//! `server.public_feed.enabled` is set and returns only the fields listed
+//! in `server.public_feed.fields`. Settings are read from the config file
+//! on each uncached request, so turning the feed off takes effect without
+//! a restart.
+
+use std::sync::Arc;
+
+use axum::extract::State;
+use axum::http::header::CACHE_CONTROL;
+use axum::http::HeaderValue;
+use axum::response::{IntoResponse, Response};
+use axum::Json;
+use serde_json::{json, Map, Value};
+use tuitbot_core::config::{Config, PublicFeedConfig};
+use tuitbot_core::storage::accounts::DEFAULT_ACCOUNT_ID;
+use tuitbot_core::storage::public_feed::{self, PublicPost};
+
+use crate::error::ApiError;
+use crate::state::AppState;
+
+/// `GET /api/public/feed` — recently sent posts of the default account.
+///
+/// Responds `404` while the feed is disabled. Browsers and proxies may cache
+/// the response for `server.public_feed.cache_seconds`.
+pub async fn feed(State(state): State<Arc<AppState>>) -> Result<Response, ApiError> {
+    let settings = load_settings(&state);
+    if !settings.enabled {
+        return Err(ApiError::NotFound("public feed is disabled".to_string()));
+    }
+
+    let posts = public_feed::get_recent_public_posts_for(
+        &state.db,
+        DEFAULT_ACCOUNT_ID,
+        settings.include_replies,
+        settings.limit,
+    )
+    .await?;
+    let posts: Vec<Value> = posts
+        .iter()
+        .map(|post| project(post, &settings.fields))
+        .collect();
+
+    let mut response = Json(json!({ "posts": posts })).into_response();
+    let cache_control = format!("public, max-age={}", settings.cache_seconds);
+    if let Ok(value) = HeaderValue::from_str(&cache_control) {
+        response.headers_mut().insert(CACHE_CONTROL, value);
+    }
+    Ok(response)
+}
+
+/// Feed settings from the config file. An unreadable config disables the feed.
+fn load_settings(state: &AppState) -> PublicFeedConfig {
+    std::fs::read_to_string(&state.config_path)
+        .ok()
+        .and_then(|contents| toml::from_str::<Config>(&contents).ok())
+        .map(|config| config.server.public_feed)
+        .unwrap_or_default()
+}
+
+/// Keep only the configured fields of a post.
+fn project(post: &PublicPost, fields: &[String]) -> Value {
+    let mut out = Map::new();
+    for field in fields {
+        let value = match field.as_str() {
+            "kind" => json!(post.kind),
+            "text" => json!(post.text),
+            "url" => json!(status_url(&post.tweet_id)),
+            "created_at" => json!(post.created_at),
+            "topic" => json!(post.topic),
+            "in_reply_to_url" => json!(post.in_reply_to.as_deref().map(status_url)),
+            _ => continue,
+        };
+        out.insert(field.clone(), value);
+    }
+    Value::Object(out)
+}
+
+fn status_url(tweet_id: &str) -> String {
+    format!("https://x.com/i/status/{tweet_id}")
+}
@@ -2,39 +2,76 @@
 
 use std::sync::Arc;
 
-use axum::extract::{Query, State};
+use axum::extract::{Path, Query, State};
+use axum::http::HeaderMap;
 use axum::Json;
 use serde::Deserialize;
 use serde_json::{json, Value};
-use tuitbot_core::storage::replies;
+use tuitbot_core::storage::pagination::Sort;
+use tuitbot_core::storage::replies::{ReplyFilter, REPLY_SORT_FIELDS};
+use tuitbot_core::storage::{replies, reply_explanations};
+use tuitbot_types::replies::{ReplyExplanationResponse, SentReply};
 
+use super::{page_headers, Pagination};
 use crate::account::AccountContext;
 use crate::error::ApiError;
 use crate::state::AppState;
 
-/// Query parameters for the replies endpoint.
+/// Filters for the replies endpoint; paging comes from [`Pagination`].
 #[derive(Deserialize)]
 pub struct RepliesQuery {
-    /// Maximum number of replies to return (default: 50).
-    #[serde(default = "default_limit")]
-    pub limit: u32,
-    /// Offset for pagination (default: 0).
-    #[serde(default)]
-    pub offset: u32,
+    /// Filter by status (e.g. "sent").
+    pub status: Option<String>,
+    /// Replies sent at or after this ISO-8601 timestamp.
+    pub since: Option<String>,
+    /// Replies sent before this ISO-8601 timestamp.
+    pub until: Option<String>,
 }
 
-fn default_limit() -> u32 {
-    50
-}
-
-/// `GET /api/replies` — recent replies sent.
+/// `GET /api/replies` — replies sent, newest first (default limit: 50).
+///
+/// Sortable by `created_at` and `status`.
 pub async fn list_replies(
     State(state): State<Arc<AppState>>,
     ctx: AccountContext,
+    pagination: Pagination,
     Query(params): Query<RepliesQuery>,
-) -> Result<Json<Value>, ApiError> {
-    let replies =
-        replies::get_recent_replies_for(&state.db, &ctx.account_id, params.limit, params.offset)
-            .await?;
-    Ok(Json(json!(replies)))
+) -> Result<(HeaderMap, Json<Value>), ApiError> {
+    let page = pagination.page(50, REPLY_SORT_FIELDS, Some(Sort::desc("created_at")))?;
+    let filter = ReplyFilter {
+        status: params.status.as_deref(),
+        since: params.since.as_deref(),
+        until: params.until.as_deref(),
+    };
+    let replies = replies::get_replies_page_for(&state.db, &ctx.account_id, &filter, &page).await?;
+    Ok((page_headers(&replies), Json(json!(replies.items))))
+}
+
+/// `GET /api/replies/{id}/explanation` — why a reply was sent.
+///
+/// Returns the reply with its stored explanation (`null` for replies sent
+/// before explanations were recorded, or by a path that does not record one).
+pub async fn get_explanation(
+    State(state): State<Arc<AppState>>,
+    ctx: AccountContext,
+    Path(id): Path<i64>,
+) -> Result<Json<ReplyExplanationResponse>, ApiError> {
+    let reply = replies::get_reply_for(&state.db, &ctx.account_id, id)
+        .await?
+        .ok_or_else(|| ApiError::NotFound(format!("reply {id} not found")))?;
+    let explanation = reply_explanations::get_for(&state.db, &ctx.account_id, id).await?;
+    Ok(Json(ReplyExplanationResponse {
+        reply: SentReply {
+            id: reply.id,
+            target_tweet_id: reply.target_tweet_id,
+            reply_tweet_id: reply.reply_tweet_id,
+            reply_content: reply.reply_content,
+            llm_provider: reply.llm_provider,
+            llm_model: reply.llm_model,
+            created_at: reply.created_at,
+            status: reply.status,
+            error_message: reply.error_message,
+        },
+        explanation,
+    }))
 }
@@ -5,7 +5,12 @@ use std::sync::Arc;
 use axum::extract::State;
 use axum::Json;
 use serde_json::{json, Value};
+use tuitbot_core::automation::content_mix::plan_status_for;
+use tuitbot_core::automation::safe_mode::safe_mode_reason;
+use tuitbot_core::automation::warmup::warmup_status_for;
 use tuitbot_core::automation::Runtime;
+use tuitbot_core::config::Config;
+use tuitbot_core::storage::draft_only;
 
 use crate::account::{require_mutate, AccountContext};
 use crate::error::ApiError;
@@ -15,7 +20,13 @@ use crate::ws::WsEvent;
 /// `GET /api/runtime/status` — check if the automation runtime is running.
 ///
 /// Also returns `deployment_mode` and `capabilities` so the frontend can
-/// adapt its source-type UI without platform guessing.
+/// adapt its source-type UI without platform guessing, `warmup` with
+/// the current account warm-up phase (`null` when off or complete), and
+/// `content_plan` with today's content mix plan and progress (`null` when
+/// the planner is off or has not planned today yet), and `safe_mode` with
+/// why posting is halted after a crash or failure streak (`null` when not
+/// in safe mode), and `draft_only` with whether posting is disabled while
+/// items are held in the approval queue.
 pub async fn status(
     State(state): State<Arc<AppState>>,
     ctx: AccountContext,
@@ -25,12 +36,27 @@ pub async fn status(
     let running = runtime.is_some();
     let task_count = runtime.map_or(0, |r| r.task_count());
     let capabilities = state.deployment_mode.capabilities();
+    drop(runtimes);
+
+    let (warmup, content_plan) = match Config::load(Some(&state.config_path.to_string_lossy())) {
+        Ok(config) => (
+            warmup_status_for(&state.db, &ctx.account_id, &config).await?,
+            plan_status_for(&state.db, &ctx.account_id, &config).await?,
+        ),
+        Err(_) => (None, None),
+    };
+    let safe_mode = safe_mode_reason(&state.db).await?;
+    let draft_only = draft_only::get_draft_only(&state.db).await?;
 
     Ok(Json(json!({
         "running": running,
         "task_count": task_count,
         "deployment_mode": state.deployment_mode,
         "capabilities": capabilities,
+        "warmup": warmup,
+        "content_plan": content_plan,
+        "safe_mode": safe_mode,
+        "draft_only": draft_only,
     })))
 }
 
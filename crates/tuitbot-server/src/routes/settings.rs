@@ -6,52 +6,22 @@ use std::time::Instant;
 
 use axum::extract::State;
 use axum::Json;
-use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use tuitbot_core::config::{Config, LlmConfig};
+use tuitbot_core::config::{history, Config, LlmConfig};
 use tuitbot_core::error::ConfigError;
 use tuitbot_core::llm::factory::create_provider;
 
 use crate::error::ApiError;
 use crate::state::AppState;
 
+pub use tuitbot_types::settings::{
+    TestLlmRequest, TestLlmResponse, ValidationErrorItem, ValidationResponse,
+};
+
 // ---------------------------------------------------------------------------
 // Request / response types
 // ---------------------------------------------------------------------------
 
-#[derive(Serialize)]
-struct ValidationResponse {
-    valid: bool,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
-    errors: Vec<ValidationErrorItem>,
-}
-
-#[derive(Serialize)]
-struct ValidationErrorItem {
-    field: String,
-    message: String,
-}
-
-#[derive(Deserialize)]
-pub struct TestLlmRequest {
-    pub provider: String,
-    #[serde(default)]
-    pub api_key: Option<String>,
-    #[serde(default)]
-    pub model: String,
-    #[serde(default)]
-    pub base_url: Option<String>,
-}
-
-#[derive(Serialize)]
-struct TestResult {
-    success: bool,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    error: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    latency_ms: Option<u64>,
-}
-
 // ---------------------------------------------------------------------------
 // Helpers
 // ---------------------------------------------------------------------------
@@ -165,20 +135,8 @@ pub async fn init_settings(
             .map_err(|e| ApiError::BadRequest(format!("failed to create config directory: {e}")))?;
     }
 
-    std::fs::write(&state.config_path, &toml_str).map_err(|e| {
-        ApiError::BadRequest(format!(
-            "could not write config file {}: {e}",
-            state.config_path.display()
-        ))
-    })?;
-
-    // Set file permissions to 0600 on Unix.
-    #[cfg(unix)]
-    {
-        use std::os::unix::fs::PermissionsExt;
-        let _ =
-            std::fs::set_permissions(&state.config_path, std::fs::Permissions::from_mode(0o600));
-    }
+    history::write_config(&state.config_path, &toml_str, "api")
+        .map_err(|e| ApiError::BadRequest(format!("could not write config file: {e}")))?;
 
     let json = serde_json::to_value(config)
         .map_err(|e| ApiError::BadRequest(format!("failed to serialize config: {e}")))?;
@@ -224,12 +182,8 @@ pub async fn patch_settings(
 
     let (merged_str, config) = merge_patch_and_parse(&state.config_path, &patch)?;
 
-    std::fs::write(&state.config_path, &merged_str).map_err(|e| {
-        ApiError::BadRequest(format!(
-            "could not write config file {}: {e}",
-            state.config_path.display()
-        ))
-    })?;
+    history::write_config(&state.config_path, &merged_str, "api")
+        .map_err(|e| ApiError::BadRequest(format!("could not write config file: {e}")))?;
 
     let json = serde_json::to_value(config)
         .map_err(|e| ApiError::BadRequest(format!("failed to serialize config: {e}")))?;
@@ -279,13 +233,14 @@ pub async fn test_llm(Json(body): Json<TestLlmRequest>) -> Result<Json<Value>, A
         api_key: body.api_key,
         model: body.model,
         base_url: body.base_url,
+        ..Default::default()
     };
 
     let provider = match create_provider(&llm_config) {
         Ok(p) => p,
         Err(e) => {
             return Ok(Json(
-                serde_json::to_value(TestResult {
+                serde_json::to_value(TestLlmResponse {
                     success: false,
                     error: Some(e.to_string()),
                     latency_ms: None,
@@ -300,7 +255,7 @@ pub async fn test_llm(Json(body): Json<TestLlmRequest>) -> Result<Json<Value>, A
 
     match provider.health_check().await {
         Ok(()) => Ok(Json(
-            serde_json::to_value(TestResult {
+            serde_json::to_value(TestLlmResponse {
                 success: true,
                 error: None,
                 latency_ms: Some(latency_ms(&start)),
@@ -308,7 +263,7 @@ pub async fn test_llm(Json(body): Json<TestLlmRequest>) -> Result<Json<Value>, A
             .unwrap(),
         )),
         Err(e) => Ok(Json(
-            serde_json::to_value(TestResult {
+            serde_json::to_value(TestLlmResponse {
                 success: false,
                 error: Some(e.to_string()),
                 latency_ms: Some(latency_ms(&start)),
@@ -107,6 +107,8 @@ fn report_to_json(report: strategy::StrategyReportRow) -> Value {
         serde_json::from_str(&report.top_content_json).unwrap_or_else(|_| json!([]));
     let recommendations: Value =
         serde_json::from_str(&report.recommendations_json).unwrap_or_else(|_| json!([]));
+    let approval_metrics: Value =
+        serde_json::from_str(&report.approval_metrics_json).unwrap_or_else(|_| json!({}));
 
     json!({
         "id": report.id,
@@ -127,6 +129,7 @@ fn report_to_json(report: strategy::StrategyReportRow) -> Value {
         "bottom_topics": bottom_topics,
         "top_content": top_content,
         "recommendations": recommendations,
+        "approval_metrics": approval_metrics,
         "created_at": report.created_at,
     })
 }
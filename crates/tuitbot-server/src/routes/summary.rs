@@ -0,0 +1,65 @@
+//! Dashboard summary endpoint.
+
+use std::sync::Arc;
+
+use axum::extract::State;
+use axum::Json;
+use serde_json::{json, Value};
+use tuitbot_core::startup;
+use tuitbot_core::storage::dashboard;
+
+use crate::account::AccountContext;
+use crate::error::ApiError;
+use crate::state::AppState;
+
+/// Number of upcoming scheduled posts included in the summary.
+const UPCOMING_SLOTS: u32 = 3;
+
+/// `GET /api/summary` — everything the home view needs in one call.
+///
+/// Combines today's action usage against limits, pending approvals, the next
+/// scheduled posts, follower delta, X token expiry, and loop health. Database
+/// data is read in two queries.
+pub async fn summary(
+    State(state): State<Arc<AppState>>,
+    ctx: AccountContext,
+) -> Result<Json<Value>, ApiError> {
+    let data =
+        dashboard::get_dashboard_summary_for(&state.db, &ctx.account_id, UPCOMING_SLOTS).await?;
+
+    let runtimes = state.runtimes.lock().await;
+    let runtime = runtimes.get(&ctx.account_id);
+    let running = runtime.is_some();
+    let task_count = runtime.map_or(0, |r| r.task_count());
+    drop(runtimes);
+
+    let circuit_breaker = match &state.circuit_breaker {
+        Some(cb) => cb.state().await.to_string(),
+        None => "disabled".to_string(),
+    };
+
+    // Missing or unreadable token file reports as null rather than failing the summary.
+    let token = startup::load_tokens_from_file().ok().map(|tokens| {
+        json!({
+            "expires_at": tokens.expires_at,
+            "expired": tokens.is_expired(),
+            "expires_in_seconds": tokens.time_until_expiry().map(|d| d.num_seconds()),
+        })
+    });
+
+    Ok(Json(json!({
+        "usage": data.usage,
+        "pending_approvals": data.pending_approvals,
+        "upcoming": data.upcoming,
+        "followers": data.followers,
+        "token": token,
+        "loop_health": {
+            "running": running,
+            "task_count": task_count,
+            "circuit_breaker": circuit_breaker,
+            "visibility": data.visibility_status.as_deref().unwrap_or("unknown"),
+            "kill_switch_active": data.kill_switch_active,
+            "draft_only_active": data.draft_only_active,
+        },
+    })))
+}
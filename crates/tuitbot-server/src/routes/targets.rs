@@ -6,12 +6,15 @@ use axum::extract::{Path, Query, State};
 use axum::Json;
 use serde::Deserialize;
 use serde_json::{json, Value};
+use tuitbot_core::config::TargetTier;
 use tuitbot_core::storage::target_accounts;
 
 use crate::account::{require_mutate, AccountContext};
 use crate::error::ApiError;
 use crate::state::AppState;
 
+pub use tuitbot_types::targets::{AddTargetRequest, RestoreTargetResponse, UpdateTargetRequest};
+
 /// `GET /api/targets` — list target accounts with enriched data.
 pub async fn list_targets(
     State(state): State<Arc<AppState>>,
@@ -22,13 +25,6 @@ pub async fn list_targets(
     Ok(Json(json!(accounts)))
 }
 
-/// Request body for adding a target account.
-#[derive(Deserialize)]
-pub struct AddTargetRequest {
-    /// Username of the target account (without @).
-    pub username: String,
-}
-
 /// `POST /api/targets` — add a new target account.
 pub async fn add_target(
     State(state): State<Arc<AppState>>,
@@ -43,6 +39,8 @@ pub async fn add_target(
         return Err(ApiError::BadRequest("username is required".to_string()));
     }
 
+    let tier = body.tier.as_deref().map(parse_tier).transpose()?;
+
     // Check if already exists and active.
     if let Some(existing) =
         target_accounts::get_target_account_by_username_for(&state.db, &ctx.account_id, username)
@@ -59,13 +57,51 @@ pub async fn add_target(
     // resolve the real X user ID when it runs target monitoring.
     target_accounts::upsert_target_account_for(&state.db, &ctx.account_id, username, username)
         .await?;
+    if let Some(tier) = tier {
+        target_accounts::set_target_tier_for(&state.db, &ctx.account_id, username, tier.as_str())
+            .await?;
+    }
 
     Ok(Json(
         json!({"status": "added", "username": username.to_string()}),
     ))
 }
 
-/// `DELETE /api/targets/:username` — deactivate a target account.
+/// `PATCH /api/targets/:username` — change the tier of a target account.
+pub async fn update_target(
+    State(state): State<Arc<AppState>>,
+    ctx: AccountContext,
+    Path(username): Path<String>,
+    Json(body): Json<UpdateTargetRequest>,
+) -> Result<Json<Value>, ApiError> {
+    require_mutate(&ctx)?;
+
+    let tier = parse_tier(&body.tier)?;
+    let updated =
+        target_accounts::set_target_tier_for(&state.db, &ctx.account_id, &username, tier.as_str())
+            .await?;
+
+    if !updated {
+        return Err(ApiError::NotFound(format!(
+            "active target account @{username} not found"
+        )));
+    }
+
+    Ok(Json(
+        json!({"status": "updated", "username": username, "tier": tier.as_str()}),
+    ))
+}
+
+fn parse_tier(tier: &str) -> Result<TargetTier, ApiError> {
+    TargetTier::parse(tier).ok_or_else(|| {
+        ApiError::BadRequest(format!(
+            "invalid tier '{tier}', must be one of: dream_100, general"
+        ))
+    })
+}
+
+/// `DELETE /api/targets/:username` — deactivate a target account. It can be
+/// restored until the retention cleanup purges it.
 pub async fn remove_target(
     State(state): State<Arc<AppState>>,
     ctx: AccountContext,
@@ -86,6 +122,39 @@ pub async fn remove_target(
     Ok(Json(json!({"status": "removed", "username": username})))
 }
 
+/// `POST /api/targets/:username/restore` — reactivate a removed target account.
+pub async fn restore_target(
+    State(state): State<Arc<AppState>>,
+    ctx: AccountContext,
+    Path(username): Path<String>,
+) -> Result<Json<RestoreTargetResponse>, ApiError> {
+    require_mutate(&ctx)?;
+
+    let restored =
+        target_accounts::restore_target_account_for(&state.db, &ctx.account_id, &username).await?;
+
+    if !restored {
+        return Err(ApiError::NotFound(format!(
+            "removed target account @{username} not found"
+        )));
+    }
+
+    Ok(Json(RestoreTargetResponse {
+        status: "restored".to_string(),
+        username,
+    }))
+}
+
+/// `GET /api/targets/removed` — removed target accounts that can still be restored.
+pub async fn list_removed_targets(
+    State(state): State<Arc<AppState>>,
+    ctx: AccountContext,
+) -> Result<Json<Value>, ApiError> {
+    let accounts =
+        target_accounts::get_deleted_target_accounts_for(&state.db, &ctx.account_id).await?;
+    Ok(Json(json!(accounts)))
+}
+
 /// Query parameters for the timeline endpoint.
 #[derive(Deserialize)]
 pub struct TimelineQuery {
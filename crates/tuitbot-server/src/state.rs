@@ -1,6 +1,7 @@
 //! Shared application state for the tuitbot server.
 
 use std::collections::HashMap;
+use std::future::Future;
 use std::net::IpAddr;
 use std::path::PathBuf;
 use std::sync::Arc;
@@ -10,10 +11,15 @@ use tokio::sync::{broadcast, Mutex, RwLock};
 use tokio_util::sync::CancellationToken;
 use tuitbot_core::automation::circuit_breaker::CircuitBreaker;
 use tuitbot_core::automation::Runtime;
-use tuitbot_core::config::{ContentSourcesConfig, DeploymentMode};
+use tuitbot_core::config::{Config, ContentSourcesConfig, DeploymentMode};
 use tuitbot_core::content::ContentGenerator;
-use tuitbot_core::storage::DbPool;
+use tuitbot_core::error::StorageError;
+use tuitbot_core::hooks::HookRunner;
+use tuitbot_core::storage::write_queue::{write_via, WriteQueue};
+use tuitbot_core::storage::{action_log, DbPool};
 
+use crate::cache::ResponseCache;
+use crate::rate_limit::RateLimiter;
 use crate::ws::WsEvent;
 
 /// Shared application state accessible by all route handlers.
@@ -48,4 +54,68 @@ pub struct AppState {
     pub content_sources: ContentSourcesConfig,
     /// Deployment mode (desktop, self_host, or cloud).
     pub deployment_mode: DeploymentMode,
+    /// Cached responses for expensive read routes.
+    pub response_cache: ResponseCache,
+    /// Per-client request rate limiter for the API.
+    pub rate_limiter: RateLimiter,
+    /// Write queue shared with in-process automation loops. Only action-log
+    /// writes use it (None: write to the pool directly).
+    pub write_queue: Option<WriteQueue>,
+}
+
+impl AppState {
+    /// The config file as it is on disk now, if it parses.
+    pub fn current_config(&self) -> Option<Config> {
+        std::fs::read_to_string(&self.config_path)
+            .ok()
+            .and_then(|s| toml::from_str::<Config>(&s).ok())
+    }
+
+    /// Lifecycle hooks from the current config file, recording runs for
+    /// `account_id`.
+    pub fn hooks_for(&self, account_id: &str) -> HookRunner {
+        let hooks = self
+            .current_config()
+            .map(|config| config.hooks)
+            .unwrap_or_default();
+        HookRunner::new(hooks)
+            .with_pool(self.db.clone())
+            .with_account(account_id)
+    }
+
+    /// Insert an action log entry for `account_id`, through the write queue
+    /// when one is attached.
+    pub async fn log_action_for(
+        &self,
+        account_id: &str,
+        action_type: &str,
+        status: &str,
+        message: Option<&str>,
+        metadata: Option<&str>,
+    ) -> Result<(), StorageError> {
+        match &self.write_queue {
+            Some(writes) => {
+                writes
+                    .log_action_for(account_id, action_type, status, message, metadata)
+                    .await
+            }
+            None => {
+                action_log::log_action_for(
+                    &self.db,
+                    account_id,
+                    action_type,
+                    status,
+                    message,
+                    metadata,
+                )
+                .await
+            }
+        }
+    }
+
+    /// Run a storage write, taking a turn on the write queue when one is
+    /// attached. Don't log through [`Self::log_action_for`] inside `write`.
+    pub async fn write<F: Future>(&self, write: F) -> F::Output {
+        write_via(self.write_queue.as_ref(), write).await
+    }
 }
@@ -0,0 +1,299 @@
+//! Outbound webhook notifications.
+//!
+//! Forwards [`WsEvent`]s to the webhooks in `[[notifications.webhooks]]` as
+//! JSON `POST`s. Payloads are flat objects so no-code tools such as Zapier
+//! and Make can map fields without custom parsing; a per-event template can
+//! rename and combine fields.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use serde_json::{Map, Value};
+use tokio::sync::broadcast::{self, error::RecvError};
+use tokio_util::sync::CancellationToken;
+use tuitbot_core::config::WebhookConfig;
+
+use crate::ws::WsEvent;
+
+/// Per-request timeout for webhook deliveries.
+const DELIVERY_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Snake-case name of an event, as used in `events` filters and templates.
+pub fn event_name(event: &WsEvent) -> String {
+    let value = serde_json::to_value(event).unwrap_or_default();
+    let tag = value["type"].as_str().unwrap_or_default();
+    let mut name = String::with_capacity(tag.len() + 4);
+    for (i, c) in tag.chars().enumerate() {
+        if c.is_ascii_uppercase() {
+            if i > 0 {
+                name.push('_');
+            }
+            name.push(c.to_ascii_lowercase());
+        } else {
+            name.push(c);
+        }
+    }
+    name
+}
+
+/// Build the payload `hook` receives for `event`, or `None` if the hook does
+/// not subscribe to it.
+pub fn render_payload(hook: &WebhookConfig, event: &WsEvent) -> Option<Value> {
+    let name = event_name(event);
+    if !hook.wants(&name) {
+        return None;
+    }
+
+    let mut fields = match serde_json::to_value(event) {
+        Ok(Value::Object(fields)) => fields,
+        _ => Map::new(),
+    };
+    fields.remove("type");
+    fields.insert("event".to_string(), Value::String(name.clone()));
+    fields
+        .entry("timestamp")
+        .or_insert_with(|| Value::String(chrono::Utc::now().to_rfc3339()));
+
+    Some(match hook.templates.get(&name) {
+        Some(template) => Value::Object(apply_template(template, &fields)),
+        None => Value::Object(fields),
+    })
+}
+
+/// Render each template value against the event fields.
+fn apply_template(
+    template: &HashMap<String, String>,
+    fields: &Map<String, Value>,
+) -> Map<String, Value> {
+    template
+        .iter()
+        .map(|(key, pattern)| (key.clone(), render_value(pattern, fields)))
+        .collect()
+}
+
+/// A lone `{{field}}` keeps the field's JSON type; anything else is rendered
+/// as a string with every placeholder substituted.
+fn render_value(pattern: &str, fields: &Map<String, Value>) -> Value {
+    let trimmed = pattern.trim();
+    if let Some(field) = trimmed
+        .strip_prefix("{{")
+        .and_then(|rest| rest.strip_suffix("}}"))
+        .filter(|field| !field.contains("{{") && !field.contains("}}"))
+    {
+        return fields.get(field.trim()).cloned().unwrap_or(Value::Null);
+    }
+
+    let mut out = String::with_capacity(pattern.len());
+    let mut rest = pattern;
+    while let Some(start) = rest.find("{{") {
+        out.push_str(&rest[..start]);
+        let after = &rest[start + 2..];
+        match after.find("}}") {
+            Some(end) => {
+                let field = after[..end].trim();
+                match fields.get(field) {
+                    Some(Value::String(s)) => out.push_str(s),
+                    Some(Value::Null) | None => {}
+                    Some(other) => out.push_str(&other.to_string()),
+                }
+                rest = &after[end + 2..];
+            }
+            None => {
+                out.push_str(&rest[start..]);
+                rest = "";
+            }
+        }
+    }
+    out.push_str(rest);
+    Value::String(out)
+}
+
+/// Deliver events from `events` to `webhooks` until `cancel` fires.
+///
+/// Deliveries are fire-and-forget: a failing webhook is logged and never
+/// retried, and never delays other webhooks or events.
+pub async fn run_webhook_dispatcher(
+    webhooks: Vec<WebhookConfig>,
+    mut events: broadcast::Receiver<WsEvent>,
+    cancel: CancellationToken,
+) {
+    if webhooks.is_empty() {
+        return;
+    }
+    tracing::info!(webhooks = webhooks.len(), "Webhook dispatcher started");
+
+    let client = reqwest::Client::builder()
+        .timeout(DELIVERY_TIMEOUT)
+        .build()
+        .unwrap_or_default();
+
+    loop {
+        let event = tokio::select! {
+            _ = cancel.cancelled() => break,
+            received = events.recv() => match received {
+                Ok(event) => event,
+                Err(RecvError::Lagged(skipped)) => {
+                    tracing::warn!(skipped, "Webhook dispatcher fell behind, events dropped");
+                    continue;
+                }
+                Err(RecvError::Closed) => break,
+            },
+        };
+
+        for hook in &webhooks {
+            let Some(payload) = render_payload(hook, &event) else {
+                continue;
+            };
+            let client = client.clone();
+            let url = hook.url.clone();
+            tokio::spawn(async move {
+                let result = client.post(&url).json(&payload).send().await;
+                match result.and_then(|r| r.error_for_status()) {
+                    Ok(_) => tracing::debug!(url = %url, "Webhook delivered"),
+                    Err(e) => tracing::warn!(url = %url, error = %e, "Webhook delivery failed"),
+                }
+            });
+        }
+    }
+
+    tracing::info!("Webhook dispatcher stopped");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tuitbot_core::config::NOTIFICATION_EVENTS;
+    use wiremock::matchers::{body_json, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn queued() -> WsEvent {
+        WsEvent::ApprovalQueued {
+            id: 7,
+            action_type: "reply".to_string(),
+            content: "Try clap.".to_string(),
+            media_paths: vec![],
+        }
+    }
+
+    fn hook(events: &[&str]) -> WebhookConfig {
+        WebhookConfig {
+            url: "https://hooks.example.com/catch".to_string(),
+            events: events.iter().map(|e| e.to_string()).collect(),
+            templates: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn event_names_match_config_list() {
+        let events = [
+            queued(),
+            WsEvent::FollowerUpdate {
+                count: 10,
+                change: 1,
+            },
+            WsEvent::CircuitBreakerTripped {
+                state: "open".to_string(),
+                error_count: 3,
+                cooldown_remaining_seconds: 60,
+                timestamp: String::new(),
+            },
+            WsEvent::Error {
+                message: "boom".to_string(),
+            },
+        ];
+        let names: Vec<String> = events.iter().map(event_name).collect();
+        assert_eq!(
+            names,
+            [
+                "approval_queued",
+                "follower_update",
+                "circuit_breaker_tripped",
+                "error"
+            ]
+        );
+        for name in &names {
+            assert!(NOTIFICATION_EVENTS.contains(&name.as_str()));
+        }
+    }
+
+    #[test]
+    fn default_payload_is_flat_and_filtered() {
+        let payload = render_payload(&hook(&[]), &queued()).unwrap();
+        assert_eq!(payload["event"], "approval_queued");
+        assert_eq!(payload["id"], 7);
+        assert_eq!(payload["content"], "Try clap.");
+        assert!(payload["timestamp"].is_string());
+        assert!(payload.get("type").is_none());
+
+        assert!(render_payload(&hook(&["post_failed"]), &queued()).is_none());
+    }
+
+    #[test]
+    fn template_maps_fields() {
+        let mut hook = hook(&["approval_queued"]);
+        hook.templates.insert(
+            "approval_queued".to_string(),
+            HashMap::from([
+                (
+                    "text".to_string(),
+                    "New {{action_type}} #{{id}}: {{content}}{{missing}}".to_string(),
+                ),
+                ("item_id".to_string(), "{{ id }}".to_string()),
+                ("unknown".to_string(), "{{nope}}".to_string()),
+                ("literal".to_string(), "Tuitbot".to_string()),
+            ]),
+        );
+
+        let payload = render_payload(&hook, &queued()).unwrap();
+        assert_eq!(payload["text"], "New reply #7: Try clap.");
+        assert_eq!(payload["item_id"], 7);
+        assert_eq!(payload["unknown"], Value::Null);
+        assert_eq!(payload["literal"], "Tuitbot");
+        assert_eq!(payload.as_object().unwrap().len(), 4);
+    }
+
+    #[tokio::test]
+    async fn dispatcher_posts_subscribed_events() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/catch"))
+            .and(body_json(serde_json::json!({ "item_id": 7 })))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let mut hook = hook(&["approval_queued"]);
+        hook.url = format!("{}/catch", server.uri());
+        hook.templates.insert(
+            "approval_queued".to_string(),
+            HashMap::from([("item_id".to_string(), "{{id}}".to_string())]),
+        );
+
+        let (tx, rx) = broadcast::channel(8);
+        let cancel = CancellationToken::new();
+        let task = tokio::spawn(run_webhook_dispatcher(vec![hook], rx, cancel.clone()));
+
+        tx.send(WsEvent::FollowerUpdate {
+            count: 1,
+            change: 1,
+        })
+        .unwrap();
+        tx.send(queued()).unwrap();
+
+        for _ in 0..50 {
+            if !server
+                .received_requests()
+                .await
+                .unwrap_or_default()
+                .is_empty()
+            {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+        cancel.cancel();
+        task.await.unwrap();
+        server.verify().await;
+    }
+}
@@ -79,6 +79,39 @@ pub enum WsEvent {
         cooldown_remaining_seconds: u64,
         timestamp: String,
     },
+    /// The compliance kill switch was engaged or released.
+    KillSwitchChanged {
+        active: bool,
+        reason: Option<String>,
+        source: Option<String>,
+        timestamp: String,
+    },
+    /// Draft-only mode was turned on or lifted.
+    DraftOnlyChanged {
+        active: bool,
+        reason: Option<String>,
+        source: Option<String>,
+        timestamp: String,
+    },
+    /// Posting a tweet, thread, or reply to X failed.
+    PostFailed {
+        action_type: String,
+        message: String,
+        timestamp: String,
+    },
+    /// The X access token expires soon and cannot be refreshed automatically.
+    TokenExpiring {
+        expires_at: String,
+        hours_remaining: i64,
+    },
+    /// A pending approval item passed another `approval_sla.hours` and was
+    /// escalated to `priority`.
+    ApprovalSlaBreached {
+        id: i64,
+        action_type: String,
+        priority: i64,
+        pending_hours: f64,
+    },
     /// An error occurred.
     Error { message: String },
 }
@@ -9,18 +9,25 @@ use tokio::sync::Mutex;
 use tower::ServiceExt;
 use tuitbot_core::storage;
 
+use tuitbot_server::rate_limit::RateLimiter;
 use tuitbot_server::state::AppState;
 use tuitbot_server::ws::WsEvent;
 
 /// The test API token used across all tests.
 const TEST_TOKEN: &str = "test-token-abc123";
 
-/// Create the test router backed by an in-memory SQLite database.
-async fn test_router() -> axum::Router {
+/// Create test server state backed by an in-memory SQLite database.
+async fn test_state() -> Arc<AppState> {
+    test_state_with_rate_limiter(RateLimiter::default()).await
+}
+
+/// Create test server state with a specific API rate limiter.
+async fn test_state_with_rate_limiter(rate_limiter: RateLimiter) -> Arc<AppState> {
     let pool = storage::init_test_db().await.expect("init test db");
     let (event_tx, _) = tokio::sync::broadcast::channel::<WsEvent>(256);
+    let write_queue = storage::write_queue::WriteQueue::spawn(pool.clone());
 
-    let state = Arc::new(AppState {
+    Arc::new(AppState {
         db: pool,
         config_path: std::path::PathBuf::from("/tmp/test-config.toml"),
         data_dir: std::path::PathBuf::from("/tmp"),
@@ -36,9 +43,15 @@ async fn test_router() -> axum::Router {
         watchtower_cancel: None,
         content_sources: Default::default(),
         deployment_mode: Default::default(),
-    });
+        response_cache: Default::default(),
+        rate_limiter,
+        write_queue: Some(write_queue),
+    })
+}
 
-    tuitbot_server::build_router(state)
+/// Create the test router backed by an in-memory SQLite database.
+async fn test_router() -> axum::Router {
+    tuitbot_server::build_router(test_state().await)
 }
 
 /// Helper: send a GET request with auth and parse JSON from the response.
@@ -57,6 +70,26 @@ async fn get_json(router: axum::Router, path: &str) -> (StatusCode, serde_json::
     (status, json)
 }
 
+/// Helper: like [`get_json`], also returning the response headers.
+async fn get_with_headers(
+    router: axum::Router,
+    path: &str,
+) -> (StatusCode, axum::http::HeaderMap, serde_json::Value) {
+    let req = Request::builder()
+        .uri(path)
+        .header("Authorization", format!("Bearer {TEST_TOKEN}"))
+        .body(Body::empty())
+        .expect("build request");
+
+    let response = router.oneshot(req).await.expect("send request");
+    let status = response.status();
+    let headers = response.headers().clone();
+    let body = response.into_body().collect().await.expect("read body");
+    let json: serde_json::Value = serde_json::from_slice(&body.to_bytes()).expect("parse JSON");
+
+    (status, headers, json)
+}
+
 /// Helper: send a POST request with auth and JSON body.
 async fn post_json(
     router: axum::Router,
@@ -135,6 +168,222 @@ async fn health_returns_ok() {
     assert_eq!(response.status(), StatusCode::OK);
 }
 
+#[tokio::test]
+async fn review_page_is_served_without_auth() {
+    let router = test_router().await;
+    let get = |uri: &str| {
+        Request::builder()
+            .uri(uri)
+            .body(Body::empty())
+            .expect("build request")
+    };
+
+    let response = router.clone().oneshot(get("/review")).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(response.headers()["content-type"]
+        .to_str()
+        .unwrap()
+        .starts_with("text/html"));
+    assert!(response.headers().contains_key("content-security-policy"));
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    assert!(String::from_utf8_lossy(&body).contains("/review/review.js"));
+
+    let response = router
+        .clone()
+        .oneshot(get("/review/review.js"))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(response.headers()["content-type"]
+        .to_str()
+        .unwrap()
+        .contains("javascript"));
+
+    let response = router.oneshot(get("/review/missing.js")).await.unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn health_detailed_reports_visibility_unknown_without_checks() {
+    let router = test_router().await;
+    let (status, body) = get_json(router, "/api/health/detailed").await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["checks"]["visibility"]["status"], "unknown");
+    assert_eq!(body["checks"]["visibility"]["healthy"], true);
+    assert!(body["checks"]["visibility"]["last_check"].is_null());
+    assert_eq!(body["checks"]["write_queue"]["enabled"], true);
+    assert_eq!(body["checks"]["write_queue"]["stats"]["depth"], 0);
+    assert_eq!(body["checks"]["llm"]["healthy"], true);
+    assert!(body["checks"]["llm"]["providers"].is_array());
+}
+
+// ============================================================
+// Admin kill switch
+// ============================================================
+
+#[tokio::test]
+async fn kill_switch_engage_and_release() {
+    let router = test_router().await;
+
+    let (status, body) = get_json(router.clone(), "/api/admin/kill").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["active"], false);
+
+    let (status, body) = post_json(
+        router.clone(),
+        "/api/admin/kill",
+        serde_json::json!({"reason": "legal review"}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["active"], true);
+    assert_eq!(body["reason"], "legal review");
+    assert_eq!(body["source"], "api");
+
+    let (_, health) = get_json(router.clone(), "/api/health/detailed").await;
+    assert_eq!(health["status"], "degraded");
+    assert_eq!(health["checks"]["kill_switch"]["active"], true);
+
+    let (status, body) = delete_json(router.clone(), "/api/admin/kill").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["active"], false);
+    assert!(body["reason"].is_null());
+}
+
+#[tokio::test]
+async fn draft_only_enable_and_disable() {
+    let router = test_router().await;
+
+    let (status, body) = get_json(router.clone(), "/api/admin/draft-only").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["active"], false);
+
+    let (status, body) = post_json(
+        router.clone(),
+        "/api/admin/draft-only",
+        serde_json::json!({"reason": "launch week"}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["active"], true);
+    assert_eq!(body["reason"], "launch week");
+    assert_eq!(body["source"], "api");
+
+    let (_, health) = get_json(router.clone(), "/api/health/detailed").await;
+    assert_eq!(health["checks"]["draft_only"]["active"], true);
+    let (_, runtime) = get_json(router.clone(), "/api/runtime/status").await;
+    assert_eq!(runtime["draft_only"]["active"], true);
+
+    let (status, body) = delete_json(router.clone(), "/api/admin/draft-only").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["active"], false);
+}
+
+#[tokio::test]
+async fn resume_lifts_safe_mode_only() {
+    let state = test_state().await;
+    let router = tuitbot_server::build_router(state.clone());
+
+    let (status, _) = post_json(router.clone(), "/api/admin/resume", serde_json::json!({})).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+
+    tuitbot_core::storage::kill_switch::engage_kill_switch(
+        &state.db,
+        "previous session crashed",
+        tuitbot_core::automation::safe_mode::SAFE_MODE_SOURCE,
+    )
+    .await
+    .expect("engage");
+    let (_, body) = get_json(router.clone(), "/api/runtime/status").await;
+    assert_eq!(body["safe_mode"], "previous session crashed");
+
+    let (status, body) =
+        post_json(router.clone(), "/api/admin/resume", serde_json::json!({})).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["active"], false);
+    assert_eq!(body["source"], "api");
+    let (_, body) = get_json(router.clone(), "/api/runtime/status").await;
+    assert!(body["safe_mode"].is_null());
+}
+
+#[tokio::test]
+async fn archive_mode_serves_reads_and_rejects_mutations() {
+    let state = test_state().await;
+    tuitbot_core::storage::archive::set_archived(&state.db, true, "cli")
+        .await
+        .expect("archive");
+    let options = tuitbot_server::RouterOptions {
+        archived: true,
+        ..Default::default()
+    };
+    let router = tuitbot_server::build_router_with(state, options);
+
+    let (status, _) = get_json(router.clone(), "/api/approval").await;
+    assert_eq!(status, StatusCode::OK);
+    let (_, health) = get_json(router.clone(), "/api/health/detailed").await;
+    assert_eq!(health["checks"]["archive"]["archived"], true);
+
+    let (status, body) = post_json(
+        router.clone(),
+        "/api/admin/kill",
+        serde_json::json!({"reason": "legal review"}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    assert!(body["error"].as_str().unwrap().contains("archived"));
+    let (status, _) = delete_json(router.clone(), "/api/targets/alice").await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    let (status, body) = post_json(
+        router,
+        "/api/auth/login",
+        serde_json::json!({"passphrase": "wrong"}),
+    )
+    .await;
+    assert_ne!(status, StatusCode::FORBIDDEN, "{body}");
+}
+
+#[tokio::test]
+async fn kill_switch_requires_reason() {
+    let router = test_router().await;
+    let (status, _) = post_json(
+        router,
+        "/api/admin/kill",
+        serde_json::json!({"reason": "   "}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
+// ============================================================
+// MCP staged actions
+// ============================================================
+
+#[tokio::test]
+async fn mcp_staged_actions_empty_and_missing() {
+    let router = test_router().await;
+
+    let (status, body) = get_json(router.clone(), "/api/mcp/staged").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body, serde_json::json!([]));
+
+    let (status, _) = post_json(router, "/api/mcp/staged/999/approve", serde_json::json!({})).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn mcp_origins_empty_and_missing() {
+    let router = test_router().await;
+
+    let (status, body) = get_json(router.clone(), "/api/mcp/origins?session_id=s1").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body, serde_json::json!([]));
+
+    let (status, _) = get_json(router, "/api/mcp/origins/approval_queue/1").await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
 // ============================================================
 // Auth middleware
 // ============================================================
@@ -192,6 +441,74 @@ async fn analytics_performance_returns_object() {
     let (status, body) = get_json(router, "/api/analytics/performance").await;
     assert_eq!(status, StatusCode::OK);
     assert!(body["avg_reply_engagement"].is_number());
+    assert!(body["series"].is_array());
+    assert!(body["summary"]["measured_replies"]["delta"].is_number());
+}
+
+#[tokio::test]
+async fn analytics_followers_range_returns_series() {
+    let router = test_router().await;
+    let (status, body) = get_json(
+        router.clone(),
+        "/api/analytics/followers?from=2026-03-01&to=2026-03-07&granularity=week",
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["granularity"], "week");
+    assert!(body["series"].is_array());
+    assert!(body["summary"]["net_change"]["current"].is_number());
+
+    let (status, _) = get_json(router, "/api/analytics/performance?from=soon").await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn analytics_approvals_returns_metrics() {
+    let router = test_router().await;
+    let (status, body) = get_json(router, "/api/analytics/approvals").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["metrics"]["enqueued"], 0);
+    assert!(body["metrics"]["by_action_type"].is_array());
+    assert!(body["metrics"]["median_review_secs"].is_null());
+    // No SLA configured.
+    assert!(body["sla"].is_null());
+}
+
+#[tokio::test]
+async fn analytics_qa_returns_trends() {
+    let router = test_router().await;
+    let (status, body) = get_json(router, "/api/analytics/qa?limit=5").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["trends"]["total"]["current"], 0.0);
+    assert!(body["trends"]["top_rules"].is_array());
+}
+
+#[tokio::test]
+async fn analytics_inspiration_returns_groups() {
+    let router = test_router().await;
+    let (status, body) = get_json(router, "/api/analytics/inspiration").await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(body["groups"].is_array());
+    assert!(body["range"].is_object());
+}
+
+#[tokio::test]
+async fn analytics_threads_returns_report() {
+    let router = test_router().await;
+    let (status, body) = get_json(router, "/api/analytics/threads").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["report"]["threads_measured"], 0);
+    assert!(body["report"]["curve"].is_array());
+    assert!(body["report"]["insights"].is_array());
+}
+
+#[tokio::test]
+async fn analytics_reciprocity_returns_authors() {
+    let router = test_router().await;
+    let (status, body) = get_json(router, "/api/analytics/reciprocity?min_outbound=1").await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(body["authors"].is_array());
+    assert!(body["range"].is_object());
 }
 
 #[tokio::test]
@@ -230,6 +547,287 @@ async fn approval_reject_not_found() {
     assert_eq!(status, StatusCode::NOT_FOUND);
 }
 
+#[tokio::test]
+async fn approval_double_approve_conflicts() {
+    let pool = storage::init_test_db().await.expect("init test db");
+    let (event_tx, _) = tokio::sync::broadcast::channel::<WsEvent>(256);
+    let state = Arc::new(AppState {
+        db: pool.clone(),
+        config_path: std::path::PathBuf::from("/tmp/test-config.toml"),
+        data_dir: std::path::PathBuf::from("/tmp"),
+        event_tx,
+        api_token: TEST_TOKEN.to_string(),
+        passphrase_hash: tokio::sync::RwLock::new(None),
+        bind_host: "127.0.0.1".to_string(),
+        bind_port: 3001,
+        login_attempts: Mutex::new(std::collections::HashMap::new()),
+        content_generators: Mutex::new(std::collections::HashMap::new()),
+        runtimes: Mutex::new(std::collections::HashMap::new()),
+        circuit_breaker: None,
+        watchtower_cancel: None,
+        content_sources: Default::default(),
+        deployment_mode: Default::default(),
+        response_cache: Default::default(),
+        rate_limiter: Default::default(),
+        write_queue: None,
+    });
+    let router = tuitbot_server::build_router(state);
+
+    let id = tuitbot_core::storage::approval_queue::enqueue(
+        &pool, "tweet", "", "", "A", "General", "", 0.0, "[]",
+    )
+    .await
+    .expect("enqueue");
+    let path = format!("/api/approval/{id}/approve");
+
+    // A stale version loses even while the item is still pending.
+    let (status, _) = post_json(
+        router.clone(),
+        &path,
+        serde_json::json!({"expected_version": 7}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CONFLICT);
+
+    let (status, _) = post_json(router.clone(), &path, serde_json::json!({})).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, body) = post_json(router, &path, serde_json::json!({})).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    assert!(body["error"].as_str().unwrap().contains("already approved"));
+}
+
+#[tokio::test]
+async fn public_feed_is_off_by_default() {
+    let router = test_router().await;
+    let req = Request::builder()
+        .uri("/api/public/feed")
+        .body(Body::empty())
+        .expect("build request");
+    let response = router.oneshot(req).await.expect("send request");
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn public_feed_serves_configured_fields_without_auth() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let config_path = dir.path().join("config.toml");
+    std::fs::write(
+        &config_path,
+        r#"
+[server.public_feed]
+enabled = true
+fields = ["kind", "url"]
+cache_seconds = 60
+"#,
+    )
+    .expect("write config");
+
+    let pool = storage::init_test_db().await.expect("init test db");
+    let tweet = storage::threads::OriginalTweet {
+        id: 0,
+        tweet_id: Some("t1".to_string()),
+        content: "Hello".to_string(),
+        topic: Some("rust".to_string()),
+        llm_provider: Some("openai".to_string()),
+        created_at: "2026-03-01T10:00:00Z".to_string(),
+        status: "sent".to_string(),
+        error_message: None,
+        inspiration: "calendar".to_string(),
+    };
+    storage::threads::insert_original_tweet(&pool, &tweet)
+        .await
+        .expect("insert tweet");
+    let (event_tx, _) = tokio::sync::broadcast::channel::<WsEvent>(256);
+    let state = Arc::new(AppState {
+        db: pool,
+        config_path,
+        data_dir: dir.path().to_path_buf(),
+        event_tx,
+        api_token: TEST_TOKEN.to_string(),
+        passphrase_hash: tokio::sync::RwLock::new(None),
+        bind_host: "127.0.0.1".to_string(),
+        bind_port: 3001,
+        login_attempts: Mutex::new(std::collections::HashMap::new()),
+        content_generators: Mutex::new(std::collections::HashMap::new()),
+        runtimes: Mutex::new(std::collections::HashMap::new()),
+        circuit_breaker: None,
+        watchtower_cancel: None,
+        content_sources: Default::default(),
+        deployment_mode: Default::default(),
+        response_cache: Default::default(),
+        rate_limiter: Default::default(),
+        write_queue: None,
+    });
+    let router = tuitbot_server::build_router(state);
+
+    let req = Request::builder()
+        .uri("/api/public/feed")
+        .body(Body::empty())
+        .expect("build request");
+    let response = router.oneshot(req).await.expect("send request");
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()["cache-control"], "public, max-age=60");
+    let body = response.into_body().collect().await.expect("read body");
+    let json: serde_json::Value = serde_json::from_slice(&body.to_bytes()).expect("parse JSON");
+    assert_eq!(
+        json["posts"],
+        serde_json::json!([{"kind": "tweet", "url": "https://x.com/i/status/t1"}])
+    );
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn approval_pre_approve_hook_denies_and_is_traced() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let config_path = dir.path().join("config.toml");
+    std::fs::write(
+        &config_path,
+        r#"
+[[hooks.scripts]]
+name = "policy"
+event = "pre_approve"
+command = "/bin/sh"
+args = ["-c", "echo '{\"verdict\":\"deny\",\"reason\":\"off-brand\"}'"]
+"#,
+    )
+    .expect("write config");
+
+    let pool = storage::init_test_db().await.expect("init test db");
+    let (event_tx, _) = tokio::sync::broadcast::channel::<WsEvent>(256);
+    let state = Arc::new(AppState {
+        db: pool.clone(),
+        config_path,
+        data_dir: dir.path().to_path_buf(),
+        event_tx,
+        api_token: TEST_TOKEN.to_string(),
+        passphrase_hash: tokio::sync::RwLock::new(None),
+        bind_host: "127.0.0.1".to_string(),
+        bind_port: 3001,
+        login_attempts: Mutex::new(std::collections::HashMap::new()),
+        content_generators: Mutex::new(std::collections::HashMap::new()),
+        runtimes: Mutex::new(std::collections::HashMap::new()),
+        circuit_breaker: None,
+        watchtower_cancel: None,
+        content_sources: Default::default(),
+        deployment_mode: Default::default(),
+        response_cache: Default::default(),
+        rate_limiter: Default::default(),
+        write_queue: None,
+    });
+    let router = tuitbot_server::build_router(state);
+
+    let id = tuitbot_core::storage::approval_queue::enqueue(
+        &pool, "tweet", "", "", "A", "General", "", 0.0, "[]",
+    )
+    .await
+    .expect("enqueue");
+
+    let (status, body) = post_json(
+        router.clone(),
+        &format!("/api/approval/{id}/approve"),
+        serde_json::json!({}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    assert!(body["error"].as_str().unwrap().contains("off-brand"));
+
+    let (status, body) = get_json(router, &format!("/api/hooks/runs?item_id={id}")).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body[0]["hook_name"], "policy");
+    assert_eq!(body[0]["verdict"], "deny");
+}
+
+#[tokio::test]
+async fn approval_delete_and_restore() {
+    let state = test_state().await;
+    let pool = state.db.clone();
+    let router = tuitbot_server::build_router(state);
+
+    let id = tuitbot_core::storage::approval_queue::enqueue(
+        &pool, "tweet", "", "", "A", "General", "", 0.0, "[]",
+    )
+    .await
+    .expect("enqueue");
+
+    let (status, body) = delete_json(router.clone(), &format!("/api/approval/{id}")).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["status"], "deleted");
+
+    let (_, body) = get_json(router.clone(), "/api/approval").await;
+    assert!(body.as_array().unwrap().is_empty());
+    let (_, body) = get_json(router.clone(), "/api/approval/deleted").await;
+    assert_eq!(body[0]["id"], id);
+
+    let path = format!("/api/approval/{id}/restore");
+    let (status, body) = post_json(router.clone(), &path, serde_json::json!({})).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["item_status"], "pending");
+    let (_, body) = get_json(router.clone(), "/api/approval").await;
+    assert_eq!(body.as_array().unwrap().len(), 1);
+
+    // Rejections can be undone too.
+    post_json(
+        router.clone(),
+        &format!("/api/approval/{id}/reject"),
+        serde_json::json!({}),
+    )
+    .await;
+    let (status, body) = post_json(router.clone(), &path, serde_json::json!({})).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["item_status"], "pending");
+
+    let (status, _) = post_json(router, &path, serde_json::json!({})).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn approval_comments_thread_into_list_payload() {
+    let state = test_state().await;
+    let pool = state.db.clone();
+    let router = tuitbot_server::build_router(state);
+
+    let id = tuitbot_core::storage::approval_queue::enqueue(
+        &pool, "tweet", "", "", "A", "General", "", 0.0, "[]",
+    )
+    .await
+    .expect("enqueue");
+    let path = format!("/api/approval/{id}/comments");
+
+    let (status, root) = post_json(
+        router.clone(),
+        &path,
+        serde_json::json!({"body": "Needs a source", "author": "alice"}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let (status, reply) = post_json(
+        router.clone(),
+        &path,
+        serde_json::json!({"body": "Added one", "parent_id": root["id"]}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(reply["author"], "dashboard");
+    assert_eq!(reply["parent_id"], root["id"]);
+
+    let (status, _) = post_json(
+        router.clone(),
+        &path,
+        serde_json::json!({"body": "Orphan", "parent_id": 9999}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    let (_, thread) = get_json(router.clone(), &path).await;
+    assert_eq!(thread.as_array().unwrap().len(), 2);
+
+    let (_, items) = get_json(router, "/api/approval").await;
+    let latest = items[0]["latest_comments"].as_array().unwrap();
+    assert_eq!(latest.len(), 2);
+    assert_eq!(latest[1]["body"], "Added one");
+}
+
 #[tokio::test]
 async fn approval_stats_returns_counts() {
     let pool = storage::init_test_db().await.expect("init test db");
@@ -250,6 +848,9 @@ async fn approval_stats_returns_counts() {
         watchtower_cancel: None,
         content_sources: Default::default(),
         deployment_mode: Default::default(),
+        response_cache: Default::default(),
+        rate_limiter: Default::default(),
+        write_queue: None,
     });
     let router = tuitbot_server::build_router(state);
 
@@ -295,6 +896,9 @@ async fn approval_list_with_status_filter() {
         watchtower_cancel: None,
         content_sources: Default::default(),
         deployment_mode: Default::default(),
+        response_cache: Default::default(),
+        rate_limiter: Default::default(),
+        write_queue: None,
     });
     let router = tuitbot_server::build_router(state);
 
@@ -330,6 +934,51 @@ async fn approval_list_with_status_filter() {
     assert_eq!(body.as_array().unwrap().len(), 2);
 }
 
+#[tokio::test]
+async fn approval_list_sorts_filters_and_pages() {
+    let state = test_state().await;
+    for (tweet, author, content, score) in [
+        ("t1", "alice", "A1", 10.0),
+        ("t2", "bob", "B1", 30.0),
+        ("t3", "alice", "A2", 20.0),
+    ] {
+        storage::approval_queue::enqueue(
+            &state.db, "reply", tweet, author, content, "General", "", score, "[]",
+        )
+        .await
+        .expect("enqueue");
+    }
+    let router = tuitbot_server::build_router(state);
+
+    let (status, body) = get_json(router.clone(), "/api/approval?author=alice&sort=-score").await;
+    assert_eq!(status, StatusCode::OK);
+    let contents: Vec<&str> = body
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|item| item["generated_content"].as_str().unwrap())
+        .collect();
+    assert_eq!(contents, ["A2", "A1"]);
+
+    // Queue order pages by offset only.
+    let (_, headers, body) = get_with_headers(router.clone(), "/api/approval?limit=1").await;
+    assert_eq!(body.as_array().unwrap().len(), 1);
+    assert_eq!(headers["x-total-count"], "3");
+    assert!(headers.get("x-next-cursor").is_none());
+
+    let (_, headers, _) =
+        get_with_headers(router.clone(), "/api/approval?limit=1&sort=created_at").await;
+    let cursor = headers["x-next-cursor"].to_str().unwrap().to_string();
+    let (status, body) = get_json(
+        router,
+        &format!("/api/approval?limit=5&sort=created_at&cursor={cursor}"),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body.as_array().unwrap().len(), 2);
+    assert_eq!(body[0]["generated_content"], "B1");
+}
+
 #[tokio::test]
 async fn approval_edit_content() {
     let pool = storage::init_test_db().await.expect("init test db");
@@ -350,6 +999,9 @@ async fn approval_edit_content() {
         watchtower_cancel: None,
         content_sources: Default::default(),
         deployment_mode: Default::default(),
+        response_cache: Default::default(),
+        rate_limiter: Default::default(),
+        write_queue: None,
     });
     let router = tuitbot_server::build_router(state);
 
@@ -380,11 +1032,52 @@ async fn approval_edit_not_found() {
         serde_json::json!({"content": "Something"}),
     )
     .await;
-    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn approval_edit_empty_content() {
+    let pool = storage::init_test_db().await.expect("init test db");
+    let (event_tx, _) = tokio::sync::broadcast::channel::<WsEvent>(256);
+    let state = Arc::new(AppState {
+        db: pool.clone(),
+        config_path: std::path::PathBuf::from("/tmp/test-config.toml"),
+        data_dir: std::path::PathBuf::from("/tmp"),
+        event_tx,
+        api_token: TEST_TOKEN.to_string(),
+        passphrase_hash: tokio::sync::RwLock::new(None),
+        bind_host: "127.0.0.1".to_string(),
+        bind_port: 3001,
+        login_attempts: Mutex::new(std::collections::HashMap::new()),
+        content_generators: Mutex::new(std::collections::HashMap::new()),
+        runtimes: Mutex::new(std::collections::HashMap::new()),
+        circuit_breaker: None,
+        watchtower_cancel: None,
+        content_sources: Default::default(),
+        deployment_mode: Default::default(),
+        response_cache: Default::default(),
+        rate_limiter: Default::default(),
+        write_queue: None,
+    });
+    let router = tuitbot_server::build_router(state);
+
+    let id = tuitbot_core::storage::approval_queue::enqueue(
+        &pool, "tweet", "", "", "Original", "General", "", 0.0, "[]",
+    )
+    .await
+    .expect("enqueue");
+
+    let (status, _) = patch_json(
+        router,
+        &format!("/api/approval/{id}"),
+        serde_json::json!({"content": "   "}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
 }
 
 #[tokio::test]
-async fn approval_edit_empty_content() {
+async fn approval_edit_engagement_item_rejected() {
     let pool = storage::init_test_db().await.expect("init test db");
     let (event_tx, _) = tokio::sync::broadcast::channel::<WsEvent>(256);
     let state = Arc::new(AppState {
@@ -403,11 +1096,14 @@ async fn approval_edit_empty_content() {
         watchtower_cancel: None,
         content_sources: Default::default(),
         deployment_mode: Default::default(),
+        response_cache: Default::default(),
+        rate_limiter: Default::default(),
+        write_queue: None,
     });
     let router = tuitbot_server::build_router(state);
 
     let id = tuitbot_core::storage::approval_queue::enqueue(
-        &pool, "tweet", "", "", "Original", "General", "", 0.0, "[]",
+        &pool, "like", "t1", "", "", "", "", 0.0, "[]",
     )
     .await
     .expect("enqueue");
@@ -415,12 +1111,58 @@ async fn approval_edit_empty_content() {
     let (status, _) = patch_json(
         router,
         &format!("/api/approval/{id}"),
-        serde_json::json!({"content": "   "}),
+        serde_json::json!({"content": "Something"}),
     )
     .await;
     assert_eq!(status, StatusCode::BAD_REQUEST);
 }
 
+// ============================================================
+// Media
+// ============================================================
+
+#[tokio::test]
+async fn media_by_id_streams_stored_file() {
+    let router = test_router().await;
+    let stored = storage::media::store_media(
+        std::path::Path::new("/tmp"),
+        b"fake png bytes",
+        "preview.png",
+        tuitbot_core::x_api::types::MediaType::Image(tuitbot_core::x_api::types::ImageFormat::Png),
+    )
+    .await
+    .expect("store media");
+    let id = storage::media::media_id_for_path(&stored.path).expect("media id");
+
+    let req = Request::builder()
+        .uri(format!("/api/media/{id}"))
+        .header("Authorization", format!("Bearer {TEST_TOKEN}"))
+        .body(Body::empty())
+        .expect("build request");
+    let response = router.oneshot(req).await.expect("send request");
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()["content-type"], "image/png");
+    let bytes = response.into_body().collect().await.expect("read body");
+    assert_eq!(&bytes.to_bytes()[..], b"fake png bytes");
+
+    storage::media::cleanup_media(&[stored.path]).await;
+}
+
+#[tokio::test]
+async fn media_by_id_rejects_invalid_and_missing_ids() {
+    let router = test_router().await;
+    let (status, _) = get_json(router.clone(), "/api/media/..%2F..%2Fetc%2Fpasswd").await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    let (status, _) = get_json(
+        router,
+        "/api/media/00000000-0000-0000-0000-000000000000.jpg",
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
 // ============================================================
 // Activity
 // ============================================================
@@ -437,6 +1179,66 @@ async fn activity_returns_paginated_object() {
     assert!(body["offset"].is_number());
 }
 
+#[tokio::test]
+async fn activity_entries_carry_kind_summary_and_links() {
+    let state = test_state().await;
+    storage::action_log::log_action(
+        &state.db,
+        "reply_posted",
+        "success",
+        Some("Posted approved item 3"),
+        Some(r#"{"approval_id":3,"tweet_id":"555"}"#),
+    )
+    .await
+    .expect("log");
+    let router = tuitbot_server::build_router(state);
+
+    let (status, body) = get_json(router, "/api/activity").await;
+    assert_eq!(status, StatusCode::OK);
+    let event = &body["actions"][0];
+    assert_eq!(event["action_type"], "reply_posted");
+    assert_eq!(event["kind"], "approval");
+    assert_eq!(event["summary"], "Posted reply: Posted approved item 3");
+    assert_eq!(event["links"]["approval_id"], 3);
+    assert_eq!(event["links"]["tweet_url"], "https://x.com/i/status/555");
+}
+
+#[tokio::test]
+async fn activity_pages_by_cursor_with_count_headers() {
+    let state = test_state().await;
+    for i in 0..3 {
+        storage::action_log::log_action(
+            &state.db,
+            "reply",
+            "success",
+            Some(&format!("r{i}")),
+            None,
+        )
+        .await
+        .expect("log");
+    }
+    let router = tuitbot_server::build_router(state);
+
+    let (status, headers, body) = get_with_headers(router.clone(), "/api/activity?limit=2").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["actions"].as_array().unwrap().len(), 2);
+    assert_eq!(body["actions"][0]["message"], "r2");
+    assert_eq!(headers["x-total-count"], "3");
+    let cursor = headers["x-next-cursor"].to_str().unwrap().to_string();
+    assert_eq!(body["next_cursor"].to_string(), cursor);
+
+    let (status, headers, body) = get_with_headers(
+        router,
+        &format!("/api/activity?limit=2&cursor={cursor}&type=reply"),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["actions"].as_array().unwrap().len(), 1);
+    assert_eq!(body["actions"][0]["message"], "r0");
+    assert_eq!(headers["x-total-count"], "3");
+    assert!(headers.get("x-next-cursor").is_none());
+}
+
 // ============================================================
 // Replies
 // ============================================================
@@ -449,6 +1251,65 @@ async fn replies_returns_array() {
     assert!(body.is_array());
 }
 
+#[tokio::test]
+async fn replies_reject_invalid_paging() {
+    let router = test_router().await;
+    for path in [
+        "/api/replies?limit=0",
+        "/api/replies?limit=501",
+        "/api/replies?sort=secret",
+        "/api/replies?sort=status&cursor=5",
+        "/api/replies?offset=-1",
+    ] {
+        let (status, body) = get_json(router.clone(), path).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST, "{path}: {body}");
+    }
+    let (status, headers, body) =
+        get_with_headers(router, "/api/replies?limit=10&offset=20&sort=-status").await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(body.is_array());
+    assert_eq!(headers["x-total-count"], "0");
+}
+
+#[tokio::test]
+async fn reply_explanation_returns_stored_explanation() {
+    let state = test_state().await;
+    let reply = storage::replies::ReplySent {
+        id: 0,
+        target_tweet_id: "t1".to_string(),
+        reply_tweet_id: None,
+        reply_content: "Have you tried profiling first?".to_string(),
+        llm_provider: None,
+        llm_model: None,
+        created_at: "2026-03-01T12:00:00Z".to_string(),
+        status: "pending".to_string(),
+        error_message: None,
+    };
+    let id = storage::replies::insert_reply(&state.db, &reply)
+        .await
+        .unwrap();
+    let explanation = storage::reply_explanations::ReplyExplanation {
+        source: "discovery".to_string(),
+        matched_keywords: vec!["rust".to_string()],
+        archetype: Some("ask_question".to_string()),
+        qa_score: Some(100.0),
+        ..Default::default()
+    };
+    storage::reply_explanations::save(&state.db, id, &explanation)
+        .await
+        .unwrap();
+
+    let router = tuitbot_server::build_router(state);
+    let (status, body) = get_json(router.clone(), &format!("/api/replies/{id}/explanation")).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["reply"]["target_tweet_id"], "t1");
+    assert_eq!(body["explanation"]["matched_keywords"][0], "rust");
+    assert_eq!(body["explanation"]["archetype"], "ask_question");
+
+    let (status, _) = get_json(router, "/api/replies/999/explanation").await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
 // ============================================================
 // Content (read + write)
 // ============================================================
@@ -542,6 +1403,9 @@ async fn add_and_list_target() {
         watchtower_cancel: None,
         content_sources: Default::default(),
         deployment_mode: Default::default(),
+        response_cache: Default::default(),
+        rate_limiter: Default::default(),
+        write_queue: None,
     });
     let router = tuitbot_server::build_router(state);
 
@@ -583,6 +1447,9 @@ async fn add_duplicate_target_fails() {
         watchtower_cancel: None,
         content_sources: Default::default(),
         deployment_mode: Default::default(),
+        response_cache: Default::default(),
+        rate_limiter: Default::default(),
+        write_queue: None,
     });
     let router = tuitbot_server::build_router(state);
 
@@ -625,6 +1492,9 @@ async fn remove_target_works() {
         watchtower_cancel: None,
         content_sources: Default::default(),
         deployment_mode: Default::default(),
+        response_cache: Default::default(),
+        rate_limiter: Default::default(),
+        write_queue: None,
     });
     let router = tuitbot_server::build_router(state);
 
@@ -643,11 +1513,341 @@ async fn remove_target_works() {
     assert_eq!(body["status"], "removed");
 
     // List should be empty.
-    let (status, body) = get_json(router, "/api/targets").await;
+    let (status, body) = get_json(router.clone(), "/api/targets").await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(body.as_array().unwrap().is_empty());
+
+    // The removed target can be restored.
+    let (_, body) = get_json(router.clone(), "/api/targets/removed").await;
+    assert_eq!(body[0]["username"], "removeme");
+    let (status, body) = post_json(
+        router.clone(),
+        "/api/targets/removeme/restore",
+        serde_json::json!({}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["status"], "restored");
+    let (_, body) = get_json(router, "/api/targets").await;
+    assert_eq!(body.as_array().unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn keyword_stats_empty() {
+    let router = test_router().await;
+    let (status, body) = get_json(router, "/api/discovery/keywords/stats?days=7").await;
     assert_eq!(status, StatusCode::OK);
     assert!(body.as_array().unwrap().is_empty());
 }
 
+#[tokio::test]
+async fn dashboard_summary_empty() {
+    let router = test_router().await;
+    let (status, body) = get_json(router, "/api/summary").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["pending_approvals"], 0);
+    assert_eq!(body["usage"]["replies"]["used"], 0);
+    assert!(body["upcoming"].as_array().unwrap().is_empty());
+    assert!(body["followers"]["delta"].is_null());
+    assert_eq!(body["loop_health"]["running"], false);
+    assert_eq!(body["loop_health"]["visibility"], "unknown");
+}
+
+#[test]
+fn token_expiry_warning_skips_refreshable_tokens() {
+    use tuitbot_core::startup::StoredTokens;
+    use tuitbot_server::alerts::token_expiry_warning;
+
+    let now = chrono::Utc::now();
+    let mut tokens = StoredTokens {
+        access_token: "access".to_string(),
+        refresh_token: None,
+        expires_at: Some(now + chrono::Duration::hours(5)),
+        scopes: vec![],
+    };
+    assert_eq!(token_expiry_warning(&tokens, now), Some(5));
+
+    tokens.expires_at = Some(now + chrono::Duration::hours(48));
+    assert_eq!(token_expiry_warning(&tokens, now), None);
+
+    tokens.expires_at = Some(now + chrono::Duration::hours(5));
+    tokens.refresh_token = Some("refresh".to_string());
+    assert_eq!(token_expiry_warning(&tokens, now), None);
+}
+
+#[tokio::test]
+async fn embedded_server_serves_shared_pool() {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tuitbot_server::embedded::EmbeddedServer;
+
+    let dir = tempfile::tempdir().expect("tempdir");
+    let pool = storage::init_test_db().await.expect("init test db");
+    let mut config = tuitbot_core::config::Config::default();
+    config.server.port = 0;
+
+    let server = EmbeddedServer::bind(pool, &config, dir.path().join("config.toml"), None, None)
+        .await
+        .expect("bind");
+    let addr = server.local_addr().expect("local addr");
+    assert!(dir.path().join("api_token").exists());
+
+    let cancel = tokio_util::sync::CancellationToken::new();
+    let task = tokio::spawn(server.serve(cancel.clone()));
+
+    let mut stream = tokio::net::TcpStream::connect(addr).await.expect("connect");
+    stream
+        .write_all(b"GET /api/health HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n")
+        .await
+        .expect("write");
+    let mut response = String::new();
+    stream.read_to_string(&mut response).await.expect("read");
+    assert!(response.starts_with("HTTP/1.1 200"), "{response}");
+
+    cancel.cancel();
+    task.await.expect("server task");
+}
+
+#[tokio::test]
+async fn mcp_endpoint_requires_auth_and_initializes() {
+    let state = test_state().await;
+    let cancel = tokio_util::sync::CancellationToken::new();
+    let mcp = tuitbot_mcp::write_http_service(
+        tuitbot_core::config::Config::default(),
+        state.db.clone(),
+        cancel.clone(),
+    )
+    .await
+    .expect("mcp service");
+    let router = tuitbot_server::build_router_with(
+        state,
+        tuitbot_server::RouterOptions {
+            mcp: Some(mcp),
+            ..Default::default()
+        },
+    );
+
+    let initialize = serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": "initialize",
+        "params": {
+            "protocolVersion": "2025-03-26",
+            "capabilities": {},
+            "clientInfo": { "name": "test", "version": "0.0.0" }
+        }
+    });
+    let request = |token: Option<&str>| {
+        let mut builder = Request::builder()
+            .method("POST")
+            .uri("/api/mcp/http")
+            .header("Content-Type", "application/json")
+            .header("Accept", "application/json, text/event-stream");
+        if let Some(token) = token {
+            builder = builder.header("Authorization", format!("Bearer {token}"));
+        }
+        builder
+            .body(Body::from(serde_json::to_vec(&initialize).unwrap()))
+            .expect("build request")
+    };
+
+    let response = router.clone().oneshot(request(None)).await.unwrap();
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    let response = router.oneshot(request(Some(TEST_TOKEN))).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(response.headers().contains_key("mcp-session-id"));
+
+    cancel.cancel();
+}
+
+/// Helper: GET with auth and an optional `If-None-Match`; returns status and ETag.
+async fn get_with_etag(
+    router: axum::Router,
+    path: &str,
+    if_none_match: Option<&str>,
+) -> (StatusCode, String) {
+    let mut builder = Request::builder()
+        .uri(path)
+        .header("Authorization", format!("Bearer {TEST_TOKEN}"));
+    if let Some(tag) = if_none_match {
+        builder = builder.header("If-None-Match", tag);
+    }
+    let response = router
+        .oneshot(builder.body(Body::empty()).expect("build request"))
+        .await
+        .expect("send request");
+    let etag = response
+        .headers()
+        .get("etag")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or_default()
+        .to_string();
+    (response.status(), etag)
+}
+
+#[tokio::test]
+async fn analytics_etag_returns_not_modified() {
+    let router = test_router().await;
+
+    let (status, etag) = get_with_etag(router.clone(), "/api/analytics/summary", None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(etag.starts_with('"'));
+
+    let (status, same) = get_with_etag(router.clone(), "/api/analytics/summary", Some(&etag)).await;
+    assert_eq!(status, StatusCode::NOT_MODIFIED);
+    assert_eq!(same, etag);
+
+    let (status, _) = get_with_etag(router, "/api/analytics/summary", Some("\"stale\"")).await;
+    assert_eq!(status, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn analytics_cache_invalidated_by_writes() {
+    let state = test_state().await;
+    let id = storage::approval_queue::enqueue(
+        &state.db, "reply", "t1", "alice", "Hello", "", "", 80.0, "[]",
+    )
+    .await
+    .expect("enqueue");
+    let router = tuitbot_server::build_router(state.clone());
+
+    let (_, before) = get_with_etag(router.clone(), "/api/analytics/approvals", None).await;
+    assert!(!state.response_cache.is_empty());
+
+    let (status, _) = post_json(
+        router.clone(),
+        &format!("/api/approval/{id}/approve"),
+        serde_json::json!({}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(state.response_cache.is_empty());
+
+    let (status, after) = get_with_etag(router, "/api/analytics/approvals", Some(&before)).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_ne!(after, before);
+}
+
+#[tokio::test]
+async fn responses_are_gzip_compressed_when_accepted() {
+    let router = test_router().await;
+    let req = Request::builder()
+        .uri("/api/analytics/summary")
+        .header("Authorization", format!("Bearer {TEST_TOKEN}"))
+        .header("Accept-Encoding", "gzip")
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(req).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers().get("content-encoding").unwrap(), "gzip");
+}
+
+#[tokio::test]
+async fn oversized_responses_ask_for_pagination() {
+    let options = tuitbot_server::RouterOptions {
+        limits: tuitbot_server::limits::HttpLimits {
+            max_request_body_bytes: 64,
+            max_response_bytes: 16,
+        },
+        ..Default::default()
+    };
+    let router = tuitbot_server::build_router_with(test_state().await, options);
+
+    let (status, body) = get_json(router.clone(), "/api/analytics/summary").await;
+    assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+    assert!(body["error"].as_str().unwrap().contains("limit"));
+
+    let body = serde_json::json!({ "content_type": "tweet", "content": "x".repeat(200) });
+    let req = Request::builder()
+        .method("POST")
+        .uri("/api/content/compose")
+        .header("Authorization", format!("Bearer {TEST_TOKEN}"))
+        .header("Content-Type", "application/json")
+        .body(Body::from(serde_json::to_vec(&body).unwrap()))
+        .unwrap();
+    let response = router.oneshot(req).await.unwrap();
+    assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
+}
+
+#[tokio::test]
+async fn rate_limited_clients_get_retry_after() {
+    let limiter = RateLimiter::new(tuitbot_core::config::ApiRateLimitConfig {
+        enabled: true,
+        burst: 2,
+        per_second: 0.5,
+        trusted_proxies: Vec::new(),
+    });
+    let state = test_state_with_rate_limiter(limiter).await;
+    let router = tuitbot_server::build_router(state.clone());
+
+    for _ in 0..2 {
+        let (status, _) = get_json(router.clone(), "/api/analytics/summary").await;
+        assert_eq!(status, StatusCode::OK);
+    }
+
+    // A forged forwarding header does not buy a fresh allowance.
+    let req = Request::builder()
+        .uri("/api/analytics/summary")
+        .header("Authorization", format!("Bearer {TEST_TOKEN}"))
+        .header("X-Forwarded-For", "203.0.113.9")
+        .body(Body::empty())
+        .unwrap();
+    let response = router.clone().oneshot(req).await.unwrap();
+    assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+    assert_eq!(response.headers().get("retry-after").unwrap(), "2");
+
+    // Health probes are never throttled.
+    let req = Request::builder()
+        .uri("/api/health")
+        .body(Body::empty())
+        .unwrap();
+    let response = router.oneshot(req).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(state.rate_limiter.stats().throttled_total, 1);
+}
+
+#[tokio::test]
+async fn update_target_tier() {
+    let router = test_router().await;
+
+    let (status, _) = post_json(
+        router.clone(),
+        "/api/targets",
+        serde_json::json!({"username": "dreamer"}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, body) = patch_json(
+        router.clone(),
+        "/api/targets/dreamer",
+        serde_json::json!({"tier": "dream_100"}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["tier"], "dream_100");
+
+    let (status, body) = get_json(router.clone(), "/api/targets").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body[0]["tier"], "dream_100");
+
+    let (status, _) = patch_json(
+        router.clone(),
+        "/api/targets/dreamer",
+        serde_json::json!({"tier": "vip"}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    let (status, _) = patch_json(
+        router,
+        "/api/targets/nobody",
+        serde_json::json!({"tier": "general"}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
 #[tokio::test]
 async fn remove_nonexistent_target_fails() {
     let router = test_router().await;
@@ -665,6 +1865,7 @@ async fn runtime_status_initially_stopped() {
     let (status, body) = get_json(router, "/api/runtime/status").await;
     assert_eq!(status, StatusCode::OK);
     assert_eq!(body["running"], false);
+    assert!(body["warmup"].is_null());
 }
 
 #[tokio::test]
@@ -687,6 +1888,9 @@ async fn runtime_start_and_stop() {
         watchtower_cancel: None,
         content_sources: Default::default(),
         deployment_mode: Default::default(),
+        response_cache: Default::default(),
+        rate_limiter: Default::default(),
+        write_queue: None,
     });
     let router = tuitbot_server::build_router(state);
 
@@ -746,6 +1950,9 @@ async fn settings_get_returns_json() {
         watchtower_cancel: None,
         content_sources: Default::default(),
         deployment_mode: Default::default(),
+        response_cache: Default::default(),
+        rate_limiter: Default::default(),
+        write_queue: None,
     });
     let router = tuitbot_server::build_router(state);
 
@@ -779,6 +1986,9 @@ async fn settings_patch_round_trips() {
         watchtower_cancel: None,
         content_sources: Default::default(),
         deployment_mode: Default::default(),
+        response_cache: Default::default(),
+        rate_limiter: Default::default(),
+        write_queue: None,
     });
     let router = tuitbot_server::build_router(state);
 
@@ -849,6 +2059,43 @@ async fn post_ingest_requires_auth() {
     assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
 }
 
+#[tokio::test]
+async fn post_ingest_candidates_requires_auth() {
+    let router = test_router().await;
+    let req = Request::builder()
+        .method("POST")
+        .uri("/api/ingest/candidates")
+        .header("Content-Type", "application/json")
+        .body(Body::from(
+            serde_json::to_vec(&serde_json::json!({"candidates": []})).unwrap(),
+        ))
+        .expect("build request");
+    let response = router.oneshot(req).await.expect("send request");
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn post_ingest_candidates_requires_llm() {
+    let router = test_router().await;
+    let (status, body) = post_json(
+        router,
+        "/api/ingest/candidates",
+        serde_json::json!({
+            "candidates": [{
+                "source": "zapier",
+                "text": "Which Rust web framework?",
+                "tweet_url": "https://x.com/alice/status/123"
+            }]
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert!(body["error"]
+        .as_str()
+        .unwrap()
+        .contains("LLM not configured"));
+}
+
 #[tokio::test]
 async fn post_ingest_idempotent() {
     let pool = storage::init_test_db().await.expect("init test db");
@@ -869,6 +2116,9 @@ async fn post_ingest_idempotent() {
         watchtower_cancel: None,
         content_sources: Default::default(),
         deployment_mode: Default::default(),
+        response_cache: Default::default(),
+        rate_limiter: Default::default(),
+        write_queue: None,
     });
     let router = tuitbot_server::build_router(state);
 
@@ -971,6 +2221,9 @@ async fn config_status_capabilities_match_cloud_mode() {
         watchtower_cancel: None,
         content_sources: Default::default(),
         deployment_mode: DeploymentMode::Cloud,
+        response_cache: Default::default(),
+        rate_limiter: Default::default(),
+        write_queue: None,
     });
     let router = tuitbot_server::build_router(state);
 
@@ -991,3 +2244,87 @@ async fn config_status_capabilities_match_cloud_mode() {
     assert_eq!(body["capabilities"]["file_picker_native"], false);
     assert_eq!(body["capabilities"]["google_drive"], true);
 }
+
+/// Helper: send an unauthenticated request and return the status.
+async fn send_unauthenticated(router: axum::Router, method: &str, path: &str) -> StatusCode {
+    let req = Request::builder()
+        .method(method)
+        .uri(path)
+        .body(Body::empty())
+        .expect("build request");
+    router.oneshot(req).await.expect("send request").status()
+}
+
+#[tokio::test]
+async fn email_review_link_approves_once() {
+    use tuitbot_server::email::{ReviewDecision, ReviewToken};
+
+    let state = test_state().await;
+    let id = storage::approval_queue::enqueue(
+        &state.db,
+        "reply",
+        "123",
+        "alice",
+        "Try clap.",
+        "",
+        "",
+        80.0,
+        "[]",
+    )
+    .await
+    .expect("enqueue");
+    let token = ReviewToken {
+        account_id: storage::accounts::DEFAULT_ACCOUNT_ID.to_string(),
+        item_id: id,
+        decision: ReviewDecision::Approve,
+        version: 0,
+        expires_at: chrono::Utc::now().timestamp() + 3600,
+    };
+    let path = format!("/api/email-review/{}", token.sign(TEST_TOKEN));
+    let router = tuitbot_server::build_router(state.clone());
+
+    // Viewing the link only confirms.
+    assert_eq!(
+        send_unauthenticated(router.clone(), "GET", &path).await,
+        StatusCode::OK
+    );
+    let item = storage::approval_queue::get_by_id(&state.db, id)
+        .await
+        .expect("get")
+        .expect("item");
+    assert_eq!(item.status, "pending");
+
+    assert_eq!(
+        send_unauthenticated(router.clone(), "POST", &path).await,
+        StatusCode::OK
+    );
+    let item = storage::approval_queue::get_by_id(&state.db, id)
+        .await
+        .expect("get")
+        .expect("item");
+    assert_eq!(item.status, "approved");
+    assert_eq!(item.reviewed_by.as_deref(), Some("email"));
+
+    // The link is bound to the reviewed version and cannot be replayed.
+    assert_eq!(
+        send_unauthenticated(router, "POST", &path).await,
+        StatusCode::CONFLICT
+    );
+}
+
+#[tokio::test]
+async fn email_review_rejects_forged_links() {
+    let router = test_router().await;
+    let forged = tuitbot_server::email::ReviewToken {
+        account_id: storage::accounts::DEFAULT_ACCOUNT_ID.to_string(),
+        item_id: 1,
+        decision: tuitbot_server::email::ReviewDecision::Approve,
+        version: 0,
+        expires_at: chrono::Utc::now().timestamp() + 3600,
+    }
+    .sign("not-the-api-token");
+    assert_eq!(
+        send_unauthenticated(router, "POST", &format!("/api/email-review/{forged}")).await,
+        StatusCode::FORBIDDEN
+    );
+}
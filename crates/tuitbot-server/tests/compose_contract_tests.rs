@@ -17,6 +17,10 @@ const TEST_TOKEN: &str = "test-token-abc123";
 
 async fn test_router() -> axum::Router {
     let pool = storage::init_test_db().await.expect("init test db");
+    test_router_with_pool(pool)
+}
+
+fn test_router_with_pool(pool: storage::DbPool) -> axum::Router {
     let (event_tx, _) = tokio::sync::broadcast::channel::<WsEvent>(256);
 
     let state = Arc::new(AppState {
@@ -35,6 +39,9 @@ async fn test_router() -> axum::Router {
         watchtower_cancel: None,
         content_sources: Default::default(),
         deployment_mode: Default::default(),
+        response_cache: Default::default(),
+        rate_limiter: Default::default(),
+        write_queue: None,
     });
 
     tuitbot_server::build_router(state)
@@ -612,6 +619,83 @@ async fn list_drafts_returns_blocks_in_content() {
     assert!(parsed["blocks"].is_array());
 }
 
+#[tokio::test]
+async fn draft_check_and_promote_to_approval_queue() {
+    let router = test_router().await;
+
+    let (status, create_body) = post_json(
+        router.clone(),
+        "/api/content/drafts",
+        serde_json::json!({
+            "content_type": "tweet",
+            "content": "Ship small PRs",
+            "planned_for": "2026-03-02T09:00:00Z"
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let id = create_body["id"].as_i64().unwrap();
+
+    let (status, body) = get_json(router.clone(), &format!("/api/content/drafts/{id}")).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["scheduled_for"], "2026-03-02T09:00:00Z");
+    assert_eq!(body["status"], "draft");
+
+    let (status, body) = post_json(
+        router.clone(),
+        &format!("/api/content/drafts/{id}/check"),
+        serde_json::json!({}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["check"]["passed"], true);
+
+    let (status, body) = post_json(
+        router.clone(),
+        &format!("/api/content/drafts/{id}/promote"),
+        serde_json::json!({}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["status"], "promoted");
+    assert!(body["approval_queue_id"].as_i64().unwrap() > 0);
+
+    // Promoted drafts leave the workspace.
+    let (status, _) = post_json(
+        router,
+        &format!("/api/content/drafts/{id}/promote"),
+        serde_json::json!({}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn promote_draft_with_banned_phrase_rejected() {
+    let router = test_router().await;
+
+    let (status, create_body) = post_json(
+        router.clone(),
+        "/api/content/drafts",
+        serde_json::json!({
+            "content_type": "tweet",
+            "content": "You should check out my app"
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let id = create_body["id"].as_i64().unwrap();
+
+    let (status, body) = post_json(
+        router,
+        &format!("/api/content/drafts/{id}/promote"),
+        serde_json::json!({}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert!(body["error"].as_str().unwrap().contains("banned phrase"));
+}
+
 // ============================================================
 // Edge cases
 // ============================================================
@@ -657,3 +741,31 @@ async fn compose_blocks_with_urls_respects_weighted_length() {
     .await;
     assert_eq!(status, StatusCode::OK);
 }
+
+#[tokio::test]
+async fn publish_draft_reports_posting_lock_holder() {
+    let pool = storage::init_test_db().await.expect("init test db");
+    let router = test_router_with_pool(pool.clone());
+
+    let (status, create_body) = post_json(
+        router.clone(),
+        "/api/content/drafts",
+        serde_json::json!({"content_type": "tweet", "content": "Ship it"}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let id = create_body["id"].as_i64().unwrap();
+
+    storage::process_lock::try_acquire_lock(&pool, "posting", "tuitbot pid 42", 300)
+        .await
+        .expect("take lock");
+
+    let (status, body) = post_json(
+        router,
+        &format!("/api/content/drafts/{id}/publish"),
+        serde_json::json!({}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["poster"], "tuitbot pid 42");
+}
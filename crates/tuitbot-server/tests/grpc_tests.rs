@@ -0,0 +1,239 @@
+//! Round-trip tests for the gRPC service against the REST handlers.
+
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use axum::body::Body;
+use axum::http::Request;
+use http_body_util::BodyExt;
+use tokio::sync::Mutex;
+use tonic::transport::Channel;
+use tower::ServiceExt;
+use tuitbot_core::storage;
+
+use tuitbot_server::grpc::pb;
+use tuitbot_server::grpc::pb::tuitbot_client::TuitbotClient;
+use tuitbot_server::state::AppState;
+use tuitbot_server::ws::WsEvent;
+
+const TEST_TOKEN: &str = "test-token-abc123";
+
+async fn test_state() -> Arc<AppState> {
+    let pool = storage::init_test_db().await.expect("init test db");
+    let (event_tx, _) = tokio::sync::broadcast::channel::<WsEvent>(256);
+
+    Arc::new(AppState {
+        db: pool,
+        config_path: std::path::PathBuf::from("/tmp/test-config.toml"),
+        data_dir: std::path::PathBuf::from("/tmp"),
+        event_tx,
+        api_token: TEST_TOKEN.to_string(),
+        passphrase_hash: tokio::sync::RwLock::new(None),
+        bind_host: "127.0.0.1".to_string(),
+        bind_port: 3001,
+        login_attempts: Mutex::new(std::collections::HashMap::new()),
+        content_generators: Mutex::new(std::collections::HashMap::new()),
+        runtimes: Mutex::new(std::collections::HashMap::new()),
+        circuit_breaker: None,
+        watchtower_cancel: None,
+        content_sources: Default::default(),
+        deployment_mode: Default::default(),
+        response_cache: Default::default(),
+        rate_limiter: Default::default(),
+        write_queue: None,
+    })
+}
+
+/// Serve the full router on an ephemeral port and connect a gRPC client.
+async fn serve(state: Arc<AppState>) -> TuitbotClient<Channel> {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+        .await
+        .expect("bind");
+    let addr = listener.local_addr().expect("local addr");
+    let router = tuitbot_server::build_router(state);
+    tokio::spawn(async move {
+        axum::serve(
+            listener,
+            router.into_make_service_with_connect_info::<SocketAddr>(),
+        )
+        .await
+        .expect("serve");
+    });
+    TuitbotClient::connect(format!("http://{addr}"))
+        .await
+        .expect("connect")
+}
+
+fn authed<T>(message: T) -> tonic::Request<T> {
+    let mut request = tonic::Request::new(message);
+    request.metadata_mut().insert(
+        "authorization",
+        format!("Bearer {TEST_TOKEN}").parse().expect("metadata"),
+    );
+    request
+}
+
+async fn rest_get(state: &Arc<AppState>, path: &str) -> serde_json::Value {
+    let req = Request::builder()
+        .uri(path)
+        .header("Authorization", format!("Bearer {TEST_TOKEN}"))
+        .body(Body::empty())
+        .expect("build request");
+    let response = tuitbot_server::build_router(state.clone())
+        .oneshot(req)
+        .await
+        .expect("send request");
+    let bytes = response.into_body().collect().await.expect("read body");
+    serde_json::from_slice(&bytes.to_bytes()).expect("parse JSON")
+}
+
+/// Every field of the gRPC message matches the REST JSON.
+fn assert_matches_rest(grpc: &impl serde::Serialize, rest: &serde_json::Value) {
+    let grpc = serde_json::to_value(grpc).expect("serialize");
+    for (key, value) in grpc.as_object().expect("message object") {
+        let rest_value = &rest[key];
+        match value {
+            serde_json::Value::Object(_) => assert_matches_rest(value, rest_value),
+            serde_json::Value::Array(items) if items.iter().any(|i| i.is_object()) => {
+                for (i, item) in items.iter().enumerate() {
+                    assert_matches_rest(item, &rest_value[i]);
+                }
+            }
+            _ => assert_eq!(value, rest_value, "field {key}"),
+        }
+    }
+}
+
+#[tokio::test]
+async fn approval_read_models_match_rest() {
+    let state = test_state().await;
+    for (tweet_id, author, content) in [
+        ("123", "alice", "Great point about borrow checking!"),
+        ("456", "bob", "Async traits finally landed, nice."),
+    ] {
+        storage::approval_queue::enqueue(
+            &state.db,
+            "reply",
+            tweet_id,
+            author,
+            content,
+            "Rust",
+            "AgreeAndExpand",
+            80.0,
+            "[\"/tmp/a.png\"]",
+        )
+        .await
+        .expect("enqueue");
+    }
+    let mut client = serve(state.clone()).await;
+
+    let items = client
+        .list_approvals(authed(pb::ListApprovalsRequest::default()))
+        .await
+        .expect("list")
+        .into_inner()
+        .items;
+    let rest = rest_get(&state, "/api/approval").await;
+    assert_eq!(items.len(), 2);
+    assert_eq!(items[0].media_paths, vec!["/tmp/a.png".to_string()]);
+    for (i, item) in items.iter().enumerate() {
+        assert_matches_rest(item, &rest[i]);
+    }
+
+    let stats = client
+        .get_approval_stats(authed(pb::GetApprovalStatsRequest {}))
+        .await
+        .expect("stats")
+        .into_inner();
+    assert_eq!(stats.pending, 2);
+    assert_matches_rest(&stats, &rest_get(&state, "/api/approval/stats").await);
+}
+
+#[tokio::test]
+async fn approval_mutations_are_visible_over_rest() {
+    let state = test_state().await;
+    let id = storage::approval_queue::enqueue(
+        &state.db, "tweet", "", "", "Draft", "General", "", 0.0, "[]",
+    )
+    .await
+    .expect("enqueue");
+    let mut client = serve(state.clone()).await;
+
+    let edited = client
+        .edit_approval(authed(pb::EditApprovalRequest {
+            id,
+            content: "Edited draft".to_string(),
+            ..Default::default()
+        }))
+        .await
+        .expect("edit")
+        .into_inner();
+    assert_eq!(edited.generated_content, "Edited draft");
+    let history = rest_get(&state, &format!("/api/approval/{id}/history")).await;
+    assert_eq!(history[0]["editor"], "dashboard");
+
+    // The REST version check applies: a stale version is refused.
+    let stale = client
+        .approve_item(authed(pb::ReviewRequest {
+            id,
+            expected_version: Some(edited.version - 1),
+            ..Default::default()
+        }))
+        .await
+        .expect_err("stale version");
+    assert_eq!(stale.code(), tonic::Code::FailedPrecondition);
+
+    let approved = client
+        .approve_item(authed(pb::ReviewRequest {
+            id,
+            actor: Some("ops".to_string()),
+            ..Default::default()
+        }))
+        .await
+        .expect("approve")
+        .into_inner();
+    assert_eq!(approved.status, "approved");
+
+    let rest = rest_get(&state, "/api/approval?status=approved").await;
+    assert_eq!(rest[0]["id"], id);
+    assert_eq!(rest[0]["reviewed_by"], "ops");
+
+    let missing = client
+        .reject_item(authed(pb::ReviewRequest {
+            id: id + 100,
+            ..Default::default()
+        }))
+        .await
+        .expect_err("unknown item");
+    assert_eq!(missing.code(), tonic::Code::NotFound);
+
+    let activity = client
+        .list_activity(authed(pb::ListActivityRequest::default()))
+        .await
+        .expect("activity")
+        .into_inner();
+    assert_eq!(activity.limit, 50);
+    assert!(activity
+        .actions
+        .iter()
+        .any(|a| a.action_type == "approval_approved"));
+    assert_matches_rest(&activity, &rest_get(&state, "/api/activity").await);
+}
+
+#[tokio::test]
+async fn calls_need_the_api_token() {
+    let mut client = serve(test_state().await).await;
+
+    let err = client
+        .get_approval_stats(pb::GetApprovalStatsRequest {})
+        .await
+        .expect_err("no token");
+    assert_eq!(err.code(), tonic::Code::Unauthenticated);
+
+    let health = client
+        .health(authed(pb::HealthRequest {}))
+        .await
+        .expect("health")
+        .into_inner();
+    assert_eq!(health.status, "ok");
+}
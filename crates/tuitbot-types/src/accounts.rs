@@ -0,0 +1,29 @@
+//! Account registry payloads (`/api/accounts`).
+
+use serde::{Deserialize, Serialize};
+
+/// Body for `POST /api/accounts`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateAccountRequest {
+    pub label: String,
+}
+
+/// Body for `PATCH /api/accounts/{id}`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UpdateAccountRequest {
+    pub label: Option<String>,
+    pub config_overrides: Option<String>,
+}
+
+/// Body for `POST /api/accounts/{id}/roles`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SetRoleRequest {
+    pub actor: String,
+    pub role: String,
+}
+
+/// Body for `DELETE /api/accounts/{id}/roles`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RemoveRoleRequest {
+    pub actor: String,
+}
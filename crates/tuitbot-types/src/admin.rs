@@ -0,0 +1,19 @@
+//! Admin payloads: the compliance kill switch (`/api/admin/kill`) and
+//! draft-only mode (`/api/admin/draft-only`).
+
+use serde::{Deserialize, Serialize};
+
+/// Body for `POST /api/admin/kill`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EngageKillSwitchRequest {
+    /// Why mutations are being halted.
+    pub reason: String,
+}
+
+/// Body for `POST /api/admin/draft-only`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct EnableDraftOnlyRequest {
+    /// Why posting is being disabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
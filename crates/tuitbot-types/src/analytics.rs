@@ -0,0 +1,99 @@
+//! Analytics payloads (`/api/analytics`).
+
+use serde::{Deserialize, Serialize};
+
+/// The UTC range a report covers, as ISO-8601 timestamps without offset.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DateRange {
+    pub from: String,
+    pub to: String,
+}
+
+/// Response of `GET /api/analytics/threads`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThreadAnalyticsResponse {
+    pub range: DateRange,
+    pub report: ThreadPositionReport,
+}
+
+/// Response of `GET /api/analytics/inspiration`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InspirationResponse {
+    pub range: DateRange,
+    pub groups: Vec<InspirationPerformance>,
+}
+
+/// Performance of original tweets grouped by where their topic came from.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InspirationPerformance {
+    /// `calendar` or `trend`.
+    pub inspiration: String,
+    /// Tweets posted in the range.
+    pub post_count: i64,
+    /// Posts with measured performance.
+    pub measured_count: i64,
+    /// Average performance score of measured posts.
+    pub avg_performance: f64,
+    /// Average impressions of measured posts.
+    pub avg_impressions: f64,
+}
+
+/// Response of `GET /api/analytics/reciprocity`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReciprocityResponse {
+    pub range: DateRange,
+    pub authors: Vec<AuthorReciprocity>,
+}
+
+/// Our engagement with an author compared with theirs with us.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AuthorReciprocity {
+    pub author_id: String,
+    pub author_username: String,
+    /// Replies we sent to the author's tweets.
+    pub outbound: i64,
+    /// Mentions and replies the author sent us.
+    pub inbound: i64,
+    /// `inbound / outbound`; 0.0 when we never engaged.
+    pub ratio: f64,
+}
+
+/// Performance of thread tweets in one role: `hook`, `body`, or `cta`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RolePerformance {
+    pub role: String,
+    /// Measured tweets in this role.
+    pub tweets: i64,
+    pub avg_impressions: f64,
+    /// Likes, retweets, and replies per impression.
+    pub avg_engagement_rate: f64,
+    pub avg_performance: f64,
+}
+
+/// One point on the drop-off curve.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PositionRetention {
+    /// 0-indexed position in the thread.
+    pub position: i64,
+    /// Threads measured at this position.
+    pub threads: i64,
+    pub avg_impressions: f64,
+    /// Average impressions relative to the hook (1.0 at the hook).
+    pub avg_retention: f64,
+}
+
+/// A plain-language finding about thread structure.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ThreadInsight {
+    pub title: String,
+    pub description: String,
+}
+
+/// Thread performance by role and position for a range.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThreadPositionReport {
+    pub threads_measured: i64,
+    pub roles: Vec<RolePerformance>,
+    pub curve: Vec<PositionRetention>,
+    pub insights: Vec<ThreadInsight>,
+}
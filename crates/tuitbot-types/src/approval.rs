@@ -0,0 +1,78 @@
+//! Approval queue payloads (`/api/approval`).
+
+use serde::{Deserialize, Serialize};
+
+/// Body for `PATCH /api/approval/{id}`: edit content before approving.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EditContentRequest {
+    pub content: String,
+    /// Optional updated media paths.
+    #[serde(default)]
+    pub media_paths: Option<Vec<String>>,
+    /// Who made the edit (default: "dashboard").
+    #[serde(default = "default_editor")]
+    pub editor: String,
+}
+
+fn default_editor() -> String {
+    "dashboard".to_string()
+}
+
+/// Body for `POST /api/approval/{id}/comments`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AddCommentRequest {
+    pub body: String,
+    /// Who wrote the comment (default: "dashboard").
+    #[serde(default = "default_editor")]
+    pub author: String,
+    /// Comment this one replies to.
+    #[serde(default)]
+    pub parent_id: Option<i64>,
+}
+
+/// A reviewer comment on an approval item, as returned by
+/// `GET`/`POST /api/approval/{id}/comments`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ApprovalComment {
+    pub id: i64,
+    pub approval_id: i64,
+    /// Comment this one replies to, if any.
+    pub parent_id: Option<i64>,
+    pub author: String,
+    pub body: String,
+    pub created_at: String,
+}
+
+/// Response of `POST /api/approval/{id}/restore`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RestoreItemResponse {
+    /// Always `restored`.
+    pub status: String,
+    pub id: i64,
+    /// Status the item returned to (`pending` for rejected items).
+    pub item_status: String,
+}
+
+/// Optional review metadata for approve/reject actions.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ReviewAction {
+    pub actor: Option<String>,
+    pub notes: Option<String>,
+    /// Reject the transition if the item's version has moved past this one.
+    #[serde(default)]
+    pub expected_version: Option<i64>,
+}
+
+/// Body for `POST /api/approval/approve-all`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BatchApproveRequest {
+    /// Maximum number of items to approve (clamped to server config).
+    #[serde(default)]
+    pub max: Option<usize>,
+    /// Specific IDs to approve (if provided, `max` is ignored).
+    #[serde(default)]
+    pub ids: Option<Vec<i64>>,
+    /// Review metadata.
+    #[serde(default)]
+    pub review: ReviewAction,
+}
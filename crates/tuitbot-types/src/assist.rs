@@ -0,0 +1,83 @@
+//! AI assist payloads (`/api/assist`).
+
+use serde::{Deserialize, Serialize};
+
+/// Body for `POST /api/assist/tweet`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AssistTweetRequest {
+    pub topic: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AssistTweetResponse {
+    pub content: String,
+    pub topic: String,
+}
+
+/// Body for `POST /api/assist/reply`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AssistReplyRequest {
+    pub tweet_text: String,
+    pub tweet_author: String,
+    #[serde(default)]
+    pub mention_product: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AssistReplyResponse {
+    pub content: String,
+}
+
+/// Body for `POST /api/assist/thread`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AssistThreadRequest {
+    pub topic: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AssistThreadResponse {
+    pub tweets: Vec<String>,
+    pub topic: String,
+}
+
+/// Body for `POST /api/assist/improve`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AssistImproveRequest {
+    pub draft: String,
+    #[serde(default)]
+    pub context: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AssistImproveResponse {
+    pub content: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AssistTopicsResponse {
+    pub topics: Vec<TopicRecommendation>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TopicRecommendation {
+    pub topic: String,
+    pub score: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OptimalTimesResponse {
+    pub times: Vec<OptimalTime>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OptimalTime {
+    pub hour: u32,
+    pub avg_engagement: f64,
+    pub post_count: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModeResponse {
+    pub mode: String,
+    pub approval_mode: bool,
+}
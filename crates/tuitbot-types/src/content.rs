@@ -0,0 +1,114 @@
+//! Content payloads (`/api/content`): compose, scheduled content, drafts, calendar.
+
+use serde::{Deserialize, Serialize};
+
+/// One tweet of a structured thread.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThreadBlockRequest {
+    /// Client-generated stable UUID.
+    pub id: String,
+    /// Tweet text content.
+    pub text: String,
+    /// Per-block media file paths.
+    #[serde(default)]
+    pub media_paths: Vec<String>,
+    /// Zero-based ordering index.
+    pub order: u32,
+}
+
+/// Body for `POST /api/content/tweets`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ComposeTweetRequest {
+    /// The tweet text.
+    pub text: String,
+    /// Optional ISO 8601 timestamp to schedule the tweet.
+    pub scheduled_for: Option<String>,
+}
+
+/// Body for `POST /api/content/threads`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ComposeThreadRequest {
+    /// The tweets forming the thread.
+    pub tweets: Vec<String>,
+    /// Optional ISO 8601 timestamp to schedule the thread.
+    pub scheduled_for: Option<String>,
+}
+
+/// Body for `POST /api/content/compose`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ComposeRequest {
+    /// Content type: "tweet" or "thread".
+    pub content_type: String,
+    /// Content text (string for tweet, JSON array string for thread).
+    pub content: String,
+    /// Optional ISO 8601 timestamp to schedule the content.
+    pub scheduled_for: Option<String>,
+    /// Optional local media file paths to attach (top-level, used for tweets).
+    #[serde(default)]
+    pub media_paths: Option<Vec<String>>,
+    /// Optional structured thread blocks. Takes precedence over `content` for threads.
+    #[serde(default)]
+    pub blocks: Option<Vec<ThreadBlockRequest>>,
+}
+
+/// Body for `PATCH /api/content/scheduled/{id}`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct EditScheduledRequest {
+    /// Updated content text.
+    pub content: Option<String>,
+    /// Updated scheduled time.
+    pub scheduled_for: Option<String>,
+}
+
+/// Body for `POST /api/content/drafts`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateDraftRequest {
+    pub content_type: String,
+    pub content: String,
+    #[serde(default = "default_source")]
+    pub source: String,
+    #[serde(default)]
+    pub blocks: Option<Vec<ThreadBlockRequest>>,
+    /// Planned calendar slot (ISO 8601). Does not schedule the draft.
+    #[serde(default)]
+    pub planned_for: Option<String>,
+}
+
+fn default_source() -> String {
+    "manual".to_string()
+}
+
+/// Body for `PATCH /api/content/drafts/{id}`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct EditDraftRequest {
+    #[serde(default)]
+    pub content: Option<String>,
+    #[serde(default)]
+    pub blocks: Option<Vec<ThreadBlockRequest>>,
+    /// New planned calendar slot; an empty string clears it.
+    #[serde(default)]
+    pub planned_for: Option<String>,
+}
+
+/// Body for `POST /api/content/drafts/{id}/schedule`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScheduleDraftRequest {
+    pub scheduled_for: String,
+}
+
+/// One entry of the `GET /api/content/calendar` response.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CalendarItem {
+    pub id: i64,
+    pub content_type: String,
+    pub content: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_author: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub topic: Option<String>,
+    pub timestamp: String,
+    pub status: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub performance_score: Option<f64>,
+    pub source: String,
+}
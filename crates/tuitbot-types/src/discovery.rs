@@ -0,0 +1,37 @@
+//! Discovery feed payloads (`/api/discovery`).
+
+use serde::{Deserialize, Serialize};
+
+/// One entry of the `GET /api/discovery/feed` response.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DiscoveryTweet {
+    pub id: String,
+    pub author_username: String,
+    pub content: String,
+    pub relevance_score: f64,
+    pub matched_keyword: Option<String>,
+    pub like_count: i64,
+    pub retweet_count: i64,
+    pub reply_count: i64,
+    pub replied_to: bool,
+    pub discovered_at: String,
+}
+
+/// Body for `POST /api/discovery/{tweet_id}/compose-reply`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ComposeReplyRequest {
+    #[serde(default)]
+    pub mention_product: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ComposeReplyResponse {
+    pub content: String,
+    pub tweet_id: String,
+}
+
+/// Body for `POST /api/discovery/{tweet_id}/queue-reply`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QueueReplyRequest {
+    pub content: String,
+}
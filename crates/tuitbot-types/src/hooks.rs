@@ -0,0 +1,20 @@
+//! Lifecycle hook payloads (`/api/hooks`).
+
+use serde::{Deserialize, Serialize};
+
+/// A recorded hook invocation, as listed by `GET /api/hooks/runs`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HookRun {
+    pub id: i64,
+    pub event: String,
+    pub hook_name: String,
+    pub action_type: String,
+    pub item_id: Option<i64>,
+    pub target_id: Option<String>,
+    /// `allow`, `deny`, `modify`, or `error`.
+    pub verdict: String,
+    pub reason: Option<String>,
+    pub exit_code: Option<i64>,
+    pub duration_ms: i64,
+    pub created_at: String,
+}
@@ -0,0 +1,99 @@
+//! Content and candidate ingestion payloads (`/api/ingest`).
+
+use serde::{Deserialize, Serialize};
+
+/// Body for `POST /api/ingest`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct IngestRequest {
+    /// Specific files to re-scan (relative paths within source).
+    #[serde(default)]
+    pub file_hints: Vec<String>,
+    /// Re-ingest even if content hash is unchanged.
+    #[serde(default)]
+    pub force: bool,
+    /// Inline content nodes for direct ingestion (Shortcuts/Telegram).
+    #[serde(default)]
+    pub inline_nodes: Vec<InlineNode>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InlineNode {
+    pub relative_path: String,
+    pub body_text: String,
+    #[serde(default)]
+    pub title: Option<String>,
+    #[serde(default)]
+    pub tags: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IngestResponse {
+    pub ingested: u32,
+    pub skipped: u32,
+    pub errors: Vec<String>,
+    pub duration_ms: u64,
+}
+
+/// Body for `POST /api/ingest/candidates`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct IngestCandidatesRequest {
+    pub candidates: Vec<CandidateInput>,
+    /// Whether generated replies may mention the product.
+    #[serde(default)]
+    pub mention_product: bool,
+}
+
+/// One externally supplied candidate: a tweet, or raw text from elsewhere.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CandidateInput {
+    /// Name of the pushing system (e.g. `zapier`), kept for analytics.
+    pub source: String,
+    /// Text to score and reply to. Ignored for tweets, whose text is
+    /// fetched from X.
+    #[serde(default)]
+    pub text: String,
+    /// X post URL. When set, the tweet is fetched from X and the queued
+    /// reply targets it.
+    #[serde(default)]
+    pub tweet_url: Option<String>,
+    /// Where a non-X item lives, shown to the reviewer.
+    #[serde(default)]
+    pub url: Option<String>,
+    #[serde(default)]
+    pub author_username: Option<String>,
+    #[serde(default)]
+    pub author_followers: u64,
+    #[serde(default)]
+    pub likes: u64,
+    #[serde(default)]
+    pub retweets: u64,
+    #[serde(default)]
+    pub replies: u64,
+    /// ISO-8601 creation time (default: now).
+    #[serde(default)]
+    pub created_at: Option<String>,
+}
+
+/// Outcome for one candidate, in request order.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CandidateResult {
+    /// Tweet ID, or `ingest:<source>:<hash>` for raw text.
+    pub candidate_id: String,
+    /// `queued`, `below_threshold`, `skipped`, or `error`.
+    pub status: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub score: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub approval_queue_id: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IngestCandidatesResponse {
+    pub queued: u32,
+    pub below_threshold: u32,
+    pub skipped: u32,
+    pub errors: u32,
+    pub results: Vec<CandidateResult>,
+}
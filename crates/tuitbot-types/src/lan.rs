@@ -0,0 +1,19 @@
+//! LAN access payloads (`/api/settings/lan`).
+
+use serde::{Deserialize, Serialize};
+
+/// Response of `GET /api/settings/lan`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LanStatus {
+    pub bind_host: String,
+    pub bind_port: u16,
+    pub lan_enabled: bool,
+    pub local_ip: Option<String>,
+    pub passphrase_configured: bool,
+}
+
+/// Body for `PATCH /api/settings/lan`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ToggleLanRequest {
+    pub host: String,
+}
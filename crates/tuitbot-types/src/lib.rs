@@ -0,0 +1,21 @@
+//! Request and response bodies for the Tuitbot HTTP API.
+//!
+//! `tuitbot-server` uses these types in its handlers and `tuitbot-client`
+//! uses them to call the API, so both sides share one definition of every
+//! typed payload. Modules follow the server's route groups. Endpoints that
+//! return free-form JSON have no type here.
+
+pub mod accounts;
+pub mod admin;
+pub mod analytics;
+pub mod approval;
+pub mod assist;
+pub mod auth;
+pub mod content;
+pub mod discovery;
+pub mod hooks;
+pub mod ingest;
+pub mod lan;
+pub mod replies;
+pub mod settings;
+pub mod targets;
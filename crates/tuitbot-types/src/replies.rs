@@ -0,0 +1,99 @@
+//! Reply payloads (`/api/replies`).
+
+use serde::{Deserialize, Serialize};
+
+/// A sent reply, as returned alongside its explanation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SentReply {
+    pub id: i64,
+    /// Tweet ID that was replied to.
+    pub target_tweet_id: String,
+    /// Our reply's X tweet ID (None if post failed).
+    pub reply_tweet_id: Option<String>,
+    pub reply_content: String,
+    pub llm_provider: Option<String>,
+    pub llm_model: Option<String>,
+    pub created_at: String,
+    /// `sent`, `failed`, or `deleted`.
+    pub status: String,
+    pub error_message: Option<String>,
+}
+
+/// Response of `GET /api/replies/{id}/explanation`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReplyExplanationResponse {
+    pub reply: SentReply,
+    /// `None` for replies sent before explanations were recorded, or by a
+    /// path that does not record one.
+    pub explanation: Option<ReplyExplanation>,
+}
+
+/// Compact explanation of why a reply was sent.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ReplyExplanation {
+    /// Loop that produced the reply: `discovery`, `mentions`, or `target`.
+    pub source: String,
+    /// Configured keywords that matched the original tweet.
+    #[serde(default)]
+    pub matched_keywords: Vec<String>,
+    /// Score breakdown, when the tweet was scored.
+    #[serde(default)]
+    pub score: Option<ScoreSignals>,
+    /// Threshold the score was compared against.
+    #[serde(default)]
+    pub threshold: Option<f32>,
+    /// Reply archetype used to steer generation, if one was chosen.
+    #[serde(default)]
+    pub archetype: Option<String>,
+    /// Winning ancestors injected into the prompt.
+    #[serde(default)]
+    pub ancestors: Vec<AncestorRef>,
+    /// Content pillar whose override (tone, language, emoji, approval)
+    /// applied to the reply.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub topic_override: Option<String>,
+    /// Draft QA score (0-100) of the final text.
+    #[serde(default)]
+    pub qa_score: Option<f64>,
+}
+
+/// Per-signal breakdown of a tweet's score.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ScoreSignals {
+    /// Total score (0-100).
+    pub total: f32,
+    /// Keyword relevance component.
+    pub keyword_relevance: f32,
+    /// Author follower component.
+    pub follower: f32,
+    /// Recency component.
+    pub recency: f32,
+    /// Engagement rate component.
+    pub engagement: f32,
+    /// Reply count component.
+    pub reply_count: f32,
+    /// Content type component.
+    pub content_type: f32,
+    /// Adjustment applied for the detected entity type.
+    pub entity_adjustment: f32,
+    /// Points removed because the tweet was past the freshness decay age.
+    #[serde(default)]
+    pub freshness_penalty: f32,
+    /// Adjustment returned by the scoring plugin.
+    #[serde(default)]
+    pub plugin_adjustment: f32,
+    /// The scoring plugin's rationale for its adjustment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub plugin_rationale: Option<String>,
+}
+
+/// A past post whose performance informed the reply prompt.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AncestorRef {
+    /// Tweet ID of the ancestor.
+    pub tweet_id: String,
+    /// Truncated content of the ancestor.
+    pub content_preview: String,
+    /// Normalized engagement score (0.0-1.0) the ancestor earned.
+    pub engagement_score: f64,
+}
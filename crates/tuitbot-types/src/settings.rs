@@ -0,0 +1,39 @@
+//! Settings payloads (`/api/settings`).
+
+use serde::{Deserialize, Serialize};
+
+/// Response of `POST /api/settings/validate`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ValidationResponse {
+    pub valid: bool,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<ValidationErrorItem>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ValidationErrorItem {
+    pub field: String,
+    pub message: String,
+}
+
+/// Body for `POST /api/settings/test-llm`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TestLlmRequest {
+    pub provider: String,
+    #[serde(default)]
+    pub api_key: Option<String>,
+    #[serde(default)]
+    pub model: String,
+    #[serde(default)]
+    pub base_url: Option<String>,
+}
+
+/// Response of `POST /api/settings/test-llm`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TestLlmResponse {
+    pub success: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latency_ms: Option<u64>,
+}
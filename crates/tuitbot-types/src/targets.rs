@@ -0,0 +1,27 @@
+//! Target account payloads (`/api/targets`).
+
+use serde::{Deserialize, Serialize};
+
+/// Body for `POST /api/targets`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AddTargetRequest {
+    /// Username of the target account (without @).
+    pub username: String,
+    /// Optional tier (`dream_100` or `general`, default: general).
+    pub tier: Option<String>,
+}
+
+/// Body for `PATCH /api/targets/{username}`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateTargetRequest {
+    /// New tier (`dream_100` or `general`).
+    pub tier: String,
+}
+
+/// Response of `POST /api/targets/{username}/restore`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RestoreTargetResponse {
+    /// Always `restored`.
+    pub status: String,
+    pub username: String,
+}
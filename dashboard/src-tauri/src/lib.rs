@@ -1,18 +1,14 @@
-use std::collections::HashMap;
-use std::sync::Arc;
+mod notifications;
+mod server;
 
-use tauri::Manager;
-use tokio::sync::Mutex;
-use tuitbot_core::auth::passphrase;
-use tuitbot_core::config::{ContentSourcesConfig, DeploymentMode};
+use tauri::{AppHandle, Manager, State};
 use tuitbot_core::startup::data_dir;
-use tuitbot_core::storage;
-use tuitbot_server::auth;
-use tuitbot_server::state::AppState;
-use tuitbot_server::ws::WsEvent;
 
-/// Shared reference to the embedded server's application state.
-struct EmbeddedState(Arc<AppState>);
+use notifications::{NotificationCenter, NotificationSettings};
+use server::{LogBuffer, PortCheck, ServerController, ServerSettings, ServerStatus};
+
+/// Default number of log lines returned by `get_server_logs`.
+const DEFAULT_LOG_LINES: usize = 200;
 
 /// Read the API token from ~/.tuitbot/api_token.
 fn read_api_token() -> Result<String, String> {
@@ -28,8 +24,89 @@ fn get_api_token() -> Result<String, String> {
     read_api_token()
 }
 
+/// Tauri command: current embedded server state, port, and last error.
+#[tauri::command]
+async fn get_server_status(
+    controller: State<'_, ServerController>,
+) -> Result<ServerStatus, String> {
+    Ok(controller.status().await)
+}
+
+/// Tauri command: stop and start the embedded server.
+#[tauri::command]
+async fn restart_server(
+    app: AppHandle,
+    controller: State<'_, ServerController>,
+) -> Result<ServerStatus, String> {
+    controller.restart(&app).await
+}
+
+/// Tauri command: the newest server log lines, oldest first.
+#[tauri::command]
+fn get_server_logs(controller: State<'_, ServerController>, limit: Option<usize>) -> Vec<String> {
+    controller.logs(limit.unwrap_or(DEFAULT_LOG_LINES))
+}
+
+/// Tauri command: check whether a port is free before switching to it.
+#[tauri::command]
+async fn check_port(
+    controller: State<'_, ServerController>,
+    port: u16,
+) -> Result<PortCheck, String> {
+    Ok(controller.check_port(port).await)
+}
+
+/// Tauri command: saved port and config path.
+#[tauri::command]
+fn get_server_settings(controller: State<'_, ServerController>) -> ServerSettings {
+    controller.settings()
+}
+
+/// Tauri command: save a new port/config path and restart to apply it.
+#[tauri::command]
+async fn update_server_settings(
+    app: AppHandle,
+    controller: State<'_, ServerController>,
+    settings: ServerSettings,
+) -> Result<ServerStatus, String> {
+    controller.update_settings(&app, settings).await
+}
+
+/// Tauri command: which server events raise a native notification.
+#[tauri::command]
+fn get_notification_settings(center: State<'_, NotificationCenter>) -> NotificationSettings {
+    center.settings()
+}
+
+/// Tauri command: save per-event-type notification toggles.
+#[tauri::command]
+fn update_notification_settings(
+    center: State<'_, NotificationCenter>,
+    settings: NotificationSettings,
+) -> Result<NotificationSettings, String> {
+    center.update(settings)?;
+    Ok(settings)
+}
+
+/// Route server tracing output into `logs` so the frontend can read it.
+fn init_server_logging(logs: LogBuffer) {
+    let subscriber = tracing_subscriber::fmt()
+        .with_max_level(tracing::Level::INFO)
+        .with_ansi(false)
+        .with_writer(logs)
+        .finish();
+    // Not `try_init`: that would also claim the `log` facade, which the log
+    // plugin needs in debug builds.
+    if let Err(e) = tracing::subscriber::set_global_default(subscriber) {
+        log::warn!("Failed to install server log capture: {}", e);
+    }
+}
+
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
+    let logs = LogBuffer::default();
+    init_server_logging(logs.clone());
+
     let app = tauri::Builder::default()
         .plugin(tauri_plugin_autostart::init(
             tauri_plugin_autostart::MacosLauncher::LaunchAgent,
@@ -39,7 +116,7 @@ pub fn run() {
         .plugin(tauri_plugin_notification::init())
         .plugin(tauri_plugin_store::Builder::new().build())
         .plugin(tauri_plugin_updater::Builder::new().build())
-        .setup(|app| {
+        .setup(move |app| {
             if cfg!(debug_assertions) {
                 app.handle().plugin(
                     tauri_plugin_log::Builder::default()
@@ -48,71 +125,35 @@ pub fn run() {
                 )?;
             }
 
-            // Initialize the embedded server: DB + token + broadcast channel.
-            let state = tauri::async_runtime::block_on(async {
-                let dir = data_dir();
-                std::fs::create_dir_all(&dir).expect("failed to create ~/.tuitbot/");
-
-                let config_path = dir.join("config.toml");
-                let db_path = dir.join("tuitbot.db");
-
-                let pool = storage::init_db(&db_path.to_string_lossy())
-                    .await
-                    .expect("failed to init database");
-
-                let api_token =
-                    auth::ensure_api_token(&dir).expect("failed to create API token");
+            app.manage(NotificationCenter::new(data_dir()));
+            app.manage(ServerController::new(data_dir(), logs));
 
-                // Ensure passphrase exists (Tauri uses bearer tokens, but the
-                // hash is needed if the user later accesses via web browser).
-                if let Err(e) = passphrase::ensure_passphrase(&dir) {
-                    log::warn!("Failed to initialize passphrase: {}", e);
+            // Start the embedded server. A failure (e.g. port conflict) is
+            // reported to the frontend instead of aborting the app.
+            let handle = app.handle().clone();
+            tauri::async_runtime::block_on(async move {
+                let controller = handle.state::<ServerController>();
+                if let Err(e) = controller.start(&handle).await {
+                    log::error!("Embedded server failed to start: {}", e);
                 }
-                let passphrase_hash = passphrase::load_passphrase_hash(&dir)
-                    .ok()
-                    .flatten();
-
-                let (event_tx, _) = tokio::sync::broadcast::channel::<WsEvent>(256);
-
-                Arc::new(AppState {
-                    db: pool,
-                    config_path,
-                    data_dir: dir,
-                    event_tx,
-                    api_token,
-                    passphrase_hash: tokio::sync::RwLock::new(passphrase_hash),
-                    bind_host: "127.0.0.1".to_string(),
-                    bind_port: 3001,
-                    login_attempts: Mutex::new(HashMap::new()),
-                    runtimes: Mutex::new(HashMap::new()),
-                    content_generators: Mutex::new(HashMap::new()),
-                    circuit_breaker: None,
-                    watchtower_cancel: None,
-                    content_sources: ContentSourcesConfig::default(),
-                    deployment_mode: DeploymentMode::Desktop,
-                })
             });
 
-            // Spawn the axum server on a background tokio task.
-            let router = tuitbot_server::build_router(state.clone());
-            tauri::async_runtime::spawn(async move {
-                let listener = tokio::net::TcpListener::bind("127.0.0.1:3001")
-                    .await
-                    .expect("failed to bind to port 3001");
-                log::info!("Embedded server listening on http://127.0.0.1:3001");
-                if let Err(e) = axum::serve(listener, router).await {
-                    log::error!("Embedded server error: {}", e);
-                }
-            });
-
-            app.manage(EmbeddedState(state));
-
             // --- System tray ---
             build_system_tray(app)?;
 
             Ok(())
         })
-        .invoke_handler(tauri::generate_handler![get_api_token])
+        .invoke_handler(tauri::generate_handler![
+            get_api_token,
+            get_server_status,
+            restart_server,
+            get_server_logs,
+            check_port,
+            get_server_settings,
+            update_server_settings,
+            get_notification_settings,
+            update_notification_settings
+        ])
         .build(tauri::generate_context!())
         .expect("error while building tauri application");
 
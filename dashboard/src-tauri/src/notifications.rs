@@ -0,0 +1,148 @@
+//! Native OS notifications driven by embedded server events.
+//!
+//! Each time the server starts, [`spawn_forwarder`] subscribes to its event
+//! broadcast and turns selected events into notifications. Which event
+//! types notify is controlled by [`NotificationSettings`], saved in the
+//! data directory and edited from the app's settings page.
+
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager};
+use tauri_plugin_notification::NotificationExt;
+use tokio::sync::broadcast;
+use tuitbot_server::ws::WsEvent;
+
+/// Desktop notification settings file inside the data directory.
+const SETTINGS_FILE: &str = "desktop_notifications.json";
+
+const TITLE: &str = "Tuitbot";
+
+/// Per-event-type notification toggles.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NotificationSettings {
+    /// A new item is waiting in the approval queue.
+    #[serde(default = "enabled")]
+    pub pending_approval: bool,
+    /// Posting a tweet, thread, or reply failed.
+    #[serde(default = "enabled")]
+    pub post_failure: bool,
+    /// The X token expires within 24 hours and cannot refresh itself.
+    #[serde(default = "enabled")]
+    pub token_expiry: bool,
+    /// The compliance kill switch was engaged.
+    #[serde(default = "enabled")]
+    pub kill_switch: bool,
+}
+
+fn enabled() -> bool {
+    true
+}
+
+impl Default for NotificationSettings {
+    fn default() -> Self {
+        Self {
+            pending_approval: true,
+            post_failure: true,
+            token_expiry: true,
+            kill_switch: true,
+        }
+    }
+}
+
+impl NotificationSettings {
+    /// Notification body for `event`, or `None` if it is off or not notifiable.
+    pub fn message_for(&self, event: &WsEvent) -> Option<String> {
+        match event {
+            WsEvent::ApprovalQueued { action_type, .. } if self.pending_approval => {
+                Some(format!("New {action_type} pending approval"))
+            }
+            WsEvent::PostFailed {
+                action_type,
+                message,
+                ..
+            } if self.post_failure => Some(if message.is_empty() {
+                format!("Posting failed ({action_type})")
+            } else {
+                format!("Posting failed ({action_type}): {message}")
+            }),
+            WsEvent::TokenExpiring {
+                hours_remaining, ..
+            } if self.token_expiry => Some(format!(
+                "X access expires in {hours_remaining}h. Reconnect your account in Settings > X API."
+            )),
+            WsEvent::KillSwitchChanged {
+                active: true,
+                reason,
+                ..
+            } if self.kill_switch => Some(match reason {
+                Some(reason) => format!("Kill switch engaged: {reason}. All posting halted."),
+                None => "Kill switch engaged. All posting halted.".to_string(),
+            }),
+            _ => None,
+        }
+    }
+}
+
+/// Holds the notification settings shared by IPC commands and the forwarder.
+pub struct NotificationCenter {
+    dir: PathBuf,
+    settings: Mutex<NotificationSettings>,
+}
+
+impl NotificationCenter {
+    /// Load saved settings from the data directory, falling back to defaults.
+    pub fn new(dir: PathBuf) -> Self {
+        let settings = load_settings(&dir);
+        Self {
+            dir,
+            settings: Mutex::new(settings),
+        }
+    }
+
+    pub fn settings(&self) -> NotificationSettings {
+        *self.settings.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Persist new settings; they apply to the next event.
+    pub fn update(&self, settings: NotificationSettings) -> Result<(), String> {
+        let contents = serde_json::to_string_pretty(&settings).map_err(|e| e.to_string())?;
+        std::fs::write(self.dir.join(SETTINGS_FILE), contents)
+            .map_err(|e| format!("Failed to save notification settings: {e}"))?;
+        *self.settings.lock().unwrap_or_else(|e| e.into_inner()) = settings;
+        Ok(())
+    }
+}
+
+fn load_settings(dir: &Path) -> NotificationSettings {
+    std::fs::read_to_string(dir.join(SETTINGS_FILE))
+        .ok()
+        .and_then(|contents| serde_json::from_str(&contents).ok())
+        .unwrap_or_default()
+}
+
+/// Forward server events to native notifications until the server's
+/// event channel closes.
+pub fn spawn_forwarder(app: AppHandle, mut events: broadcast::Receiver<WsEvent>) {
+    tauri::async_runtime::spawn(async move {
+        loop {
+            let event = match events.recv().await {
+                Ok(event) => event,
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    tracing::warn!("Notification forwarder skipped {skipped} events");
+                    continue;
+                }
+                Err(broadcast::error::RecvError::Closed) => break,
+            };
+
+            let settings = app.state::<NotificationCenter>().settings();
+            let Some(body) = settings.message_for(&event) else {
+                continue;
+            };
+            if let Err(e) = app.notification().builder().title(TITLE).body(body).show() {
+                tracing::warn!("Failed to show notification: {e}");
+            }
+        }
+    });
+}
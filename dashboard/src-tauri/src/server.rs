@@ -0,0 +1,490 @@
+//! Embedded server lifecycle: start, stop, restart, recent logs, and port checks.
+//!
+//! The desktop app runs `tuitbot-server` in-process. [`ServerController`]
+//! owns the running instance and emits a [`SERVER_STATE_EVENT`] to the
+//! frontend whenever the server's state changes.
+
+use std::collections::{HashMap, VecDeque};
+use std::io;
+use std::net::{Ipv4Addr, SocketAddr};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex as StdMutex};
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use tauri::async_runtime::JoinHandle;
+use tauri::{AppHandle, Emitter, Manager};
+use tokio::sync::{oneshot, Mutex};
+use tokio_util::sync::CancellationToken;
+use tuitbot_core::auth::passphrase;
+use tuitbot_core::config::{ContentSourcesConfig, DeploymentMode};
+use tuitbot_core::storage;
+use tuitbot_server::auth;
+use tuitbot_server::state::AppState;
+use tuitbot_server::ws::WsEvent;
+
+/// Event emitted to the frontend with a [`ServerStatus`] payload.
+pub const SERVER_STATE_EVENT: &str = "server-state";
+
+/// Port the embedded server listens on unless overridden in settings.
+pub const DEFAULT_PORT: u16 = 3001;
+
+/// Desktop server settings file inside the data directory.
+const SETTINGS_FILE: &str = "desktop_server.json";
+
+/// Number of log lines kept for [`ServerController::logs`].
+const LOG_CAPACITY: usize = 500;
+
+/// How long to wait for in-flight requests when stopping the server.
+const SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Port and config path chosen in the app's settings.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ServerSettings {
+    #[serde(default = "default_port")]
+    pub port: u16,
+    /// Config file to load. `None` uses `config.toml` in the data directory.
+    #[serde(default)]
+    pub config_path: Option<PathBuf>,
+}
+
+fn default_port() -> u16 {
+    DEFAULT_PORT
+}
+
+impl Default for ServerSettings {
+    fn default() -> Self {
+        Self {
+            port: DEFAULT_PORT,
+            config_path: None,
+        }
+    }
+}
+
+impl ServerSettings {
+    /// Load settings from the data directory, falling back to defaults.
+    pub fn load(dir: &Path) -> Self {
+        std::fs::read_to_string(dir.join(SETTINGS_FILE))
+            .ok()
+            .and_then(|contents| serde_json::from_str(&contents).ok())
+            .unwrap_or_default()
+    }
+
+    /// Persist settings to the data directory.
+    pub fn save(&self, dir: &Path) -> Result<(), String> {
+        let contents = serde_json::to_string_pretty(self).map_err(|e| e.to_string())?;
+        std::fs::write(dir.join(SETTINGS_FILE), contents)
+            .map_err(|e| format!("Failed to save server settings: {e}"))
+    }
+
+    /// Reject settings the server could never start with.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.port < 1024 {
+            return Err(format!(
+                "Port {} is reserved. Choose a port between 1024 and 65535.",
+                self.port
+            ));
+        }
+        if let Some(path) = &self.config_path {
+            if !path.is_file() {
+                return Err(format!("Config file not found at {}", path.display()));
+            }
+        }
+        Ok(())
+    }
+
+    fn resolved_config_path(&self, dir: &Path) -> PathBuf {
+        self.config_path
+            .clone()
+            .unwrap_or_else(|| dir.join("config.toml"))
+    }
+}
+
+/// Lifecycle state of the embedded server.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ServerState {
+    Starting,
+    Running,
+    Stopped,
+    Failed,
+}
+
+/// Server state as reported to the frontend.
+#[derive(Debug, Clone, Serialize)]
+pub struct ServerStatus {
+    pub state: ServerState,
+    pub port: u16,
+    pub config_path: PathBuf,
+    /// Actionable error message when `state` is `failed`.
+    pub error: Option<String>,
+}
+
+/// Result of probing whether a port can be bound.
+#[derive(Debug, Clone, Serialize)]
+pub struct PortCheck {
+    pub port: u16,
+    pub available: bool,
+    /// The embedded server itself is listening on this port.
+    pub in_use_by_tuitbot: bool,
+    pub error: Option<String>,
+}
+
+/// Turn a bind failure into a message that tells the user what to do.
+pub fn describe_bind_error(port: u16, error: &io::Error) -> String {
+    match error.kind() {
+        io::ErrorKind::AddrInUse => format!(
+            "Port {port} is already in use. Another Tuitbot window, a standalone \
+             tuitbot-server, or `tuitbot run --with-api` may be running; quit it, or \
+             choose a different port in Settings > Desktop Server."
+        ),
+        io::ErrorKind::PermissionDenied => format!(
+            "Not allowed to listen on port {port}. Choose a port between 1024 and 65535 \
+             in Settings > Desktop Server."
+        ),
+        _ => format!("Failed to listen on port {port}: {error}"),
+    }
+}
+
+/// Ring buffer of recent server log lines, fed by the tracing subscriber.
+#[derive(Clone, Default)]
+pub struct LogBuffer {
+    lines: Arc<StdMutex<VecDeque<String>>>,
+}
+
+impl LogBuffer {
+    fn push(&self, line: String) {
+        let mut lines = self.lines.lock().unwrap_or_else(|e| e.into_inner());
+        if lines.len() == LOG_CAPACITY {
+            lines.pop_front();
+        }
+        lines.push_back(line);
+    }
+
+    /// The newest `limit` lines, oldest first.
+    pub fn recent(&self, limit: usize) -> Vec<String> {
+        let lines = self.lines.lock().unwrap_or_else(|e| e.into_inner());
+        let skip = lines.len().saturating_sub(limit);
+        lines.iter().skip(skip).cloned().collect()
+    }
+}
+
+/// Per-event writer; buffered output is split into lines on drop.
+pub struct LogWriter {
+    buffer: LogBuffer,
+    pending: Vec<u8>,
+}
+
+impl io::Write for LogWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.pending.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl Drop for LogWriter {
+    fn drop(&mut self) {
+        let text = String::from_utf8_lossy(&self.pending);
+        for line in text.lines().filter(|l| !l.trim().is_empty()) {
+            self.buffer.push(line.to_string());
+        }
+    }
+}
+
+impl<'a> tracing_subscriber::fmt::MakeWriter<'a> for LogBuffer {
+    type Writer = LogWriter;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        LogWriter {
+            buffer: self.clone(),
+            pending: Vec::new(),
+        }
+    }
+}
+
+struct RunningServer {
+    state: Arc<AppState>,
+    shutdown: oneshot::Sender<()>,
+    alerts_cancel: CancellationToken,
+    task: JoinHandle<()>,
+}
+
+struct Inner {
+    status: ServerStatus,
+    running: Option<RunningServer>,
+}
+
+/// Owns the embedded server and reports its state to the frontend.
+pub struct ServerController {
+    dir: PathBuf,
+    logs: LogBuffer,
+    inner: Mutex<Inner>,
+}
+
+impl ServerController {
+    pub fn new(dir: PathBuf, logs: LogBuffer) -> Self {
+        let settings = ServerSettings::load(&dir);
+        let status = ServerStatus {
+            state: ServerState::Stopped,
+            port: settings.port,
+            config_path: settings.resolved_config_path(&dir),
+            error: None,
+        };
+        Self {
+            dir,
+            logs,
+            inner: Mutex::new(Inner {
+                status,
+                running: None,
+            }),
+        }
+    }
+
+    /// Data directory holding the database, tokens, and settings.
+    pub fn data_dir(&self) -> &Path {
+        &self.dir
+    }
+
+    pub async fn status(&self) -> ServerStatus {
+        self.inner.lock().await.status.clone()
+    }
+
+    pub fn settings(&self) -> ServerSettings {
+        ServerSettings::load(&self.dir)
+    }
+
+    /// The newest `limit` server log lines, oldest first.
+    pub fn logs(&self, limit: usize) -> Vec<String> {
+        self.logs.recent(limit)
+    }
+
+    /// Start the server with the saved settings. No-op if already running.
+    pub async fn start(&self, app: &AppHandle) -> Result<ServerStatus, String> {
+        let mut inner = self.inner.lock().await;
+        if inner.running.is_some() {
+            return Ok(inner.status.clone());
+        }
+
+        let settings = self.settings();
+        let config_path = settings.resolved_config_path(&self.dir);
+        self.set_status(
+            app,
+            &mut inner,
+            ServerStatus {
+                state: ServerState::Starting,
+                port: settings.port,
+                config_path: config_path.clone(),
+                error: None,
+            },
+        );
+
+        let addr = SocketAddr::from((Ipv4Addr::LOCALHOST, settings.port));
+        let listener = match tokio::net::TcpListener::bind(addr).await {
+            Ok(listener) => listener,
+            Err(e) => {
+                let message = describe_bind_error(settings.port, &e);
+                tracing::error!("{message}");
+                return Err(self.fail(app, &mut inner, message));
+            }
+        };
+
+        let state = match build_state(&self.dir, config_path.clone(), settings.port).await {
+            Ok(state) => state,
+            Err(message) => {
+                tracing::error!("{message}");
+                return Err(self.fail(app, &mut inner, message));
+            }
+        };
+
+        crate::notifications::spawn_forwarder(app.clone(), state.event_tx.subscribe());
+        let alerts_cancel = CancellationToken::new();
+        tauri::async_runtime::spawn(tuitbot_server::alerts::run_alert_watcher(
+            state.clone(),
+            alerts_cancel.clone(),
+        ));
+
+        let router = tuitbot_server::build_router(state.clone());
+        let (shutdown, shutdown_rx) = oneshot::channel::<()>();
+        let handle = app.clone();
+        let port = settings.port;
+        let task = tauri::async_runtime::spawn(async move {
+            tracing::info!("Embedded server listening on http://127.0.0.1:{port}");
+            let served = axum::serve(listener, router)
+                .with_graceful_shutdown(async {
+                    let _ = shutdown_rx.await;
+                })
+                .await;
+            if let Err(e) = served {
+                tracing::error!("Embedded server error: {e}");
+                let controller = handle.state::<ServerController>();
+                controller
+                    .server_exited(&handle, format!("Server stopped unexpectedly: {e}"))
+                    .await;
+            }
+        });
+
+        inner.running = Some(RunningServer {
+            state,
+            shutdown,
+            alerts_cancel,
+            task,
+        });
+        let status = ServerStatus {
+            state: ServerState::Running,
+            port,
+            config_path,
+            error: None,
+        };
+        self.set_status(app, &mut inner, status.clone());
+        Ok(status)
+    }
+
+    /// Stop the server, waiting briefly for in-flight requests.
+    pub async fn stop(&self, app: &AppHandle) -> ServerStatus {
+        let mut inner = self.inner.lock().await;
+        if let Some(running) = inner.running.take() {
+            running.alerts_cancel.cancel();
+            let _ = running.shutdown.send(());
+            if tokio::time::timeout(SHUTDOWN_TIMEOUT, running.task)
+                .await
+                .is_err()
+            {
+                tracing::warn!("Embedded server did not stop within {SHUTDOWN_TIMEOUT:?}");
+            }
+            let mut runtimes = running.state.runtimes.lock().await;
+            for (_, mut runtime) in runtimes.drain() {
+                runtime.shutdown().await;
+            }
+            tracing::info!("Embedded server stopped");
+        }
+        let status = ServerStatus {
+            state: ServerState::Stopped,
+            error: None,
+            ..inner.status.clone()
+        };
+        self.set_status(app, &mut inner, status.clone());
+        status
+    }
+
+    /// Stop the server if running, then start it with the saved settings.
+    pub async fn restart(&self, app: &AppHandle) -> Result<ServerStatus, String> {
+        self.stop(app).await;
+        self.start(app).await
+    }
+
+    /// Validate and save new settings, then restart to apply them.
+    pub async fn update_settings(
+        &self,
+        app: &AppHandle,
+        settings: ServerSettings,
+    ) -> Result<ServerStatus, String> {
+        settings.validate()?;
+        let current = self.status().await;
+        if settings.port != current.port || current.state != ServerState::Running {
+            let check = self.check_port(settings.port).await;
+            if !check.available && !check.in_use_by_tuitbot {
+                return Err(check.error.unwrap_or_default());
+            }
+        }
+        settings.save(&self.dir)?;
+        self.restart(app).await
+    }
+
+    /// Probe whether `port` can be bound on localhost.
+    pub async fn check_port(&self, port: u16) -> PortCheck {
+        let status = self.status().await;
+        if status.state == ServerState::Running && status.port == port {
+            return PortCheck {
+                port,
+                available: false,
+                in_use_by_tuitbot: true,
+                error: None,
+            };
+        }
+        match std::net::TcpListener::bind((Ipv4Addr::LOCALHOST, port)) {
+            Ok(_) => PortCheck {
+                port,
+                available: true,
+                in_use_by_tuitbot: false,
+                error: None,
+            },
+            Err(e) => PortCheck {
+                port,
+                available: false,
+                in_use_by_tuitbot: false,
+                error: Some(describe_bind_error(port, &e)),
+            },
+        }
+    }
+
+    /// Record an unexpected exit of the serve task.
+    async fn server_exited(&self, app: &AppHandle, message: String) {
+        let mut inner = self.inner.lock().await;
+        inner.running = None;
+        self.fail(app, &mut inner, message);
+    }
+
+    fn fail(&self, app: &AppHandle, inner: &mut Inner, message: String) -> String {
+        let status = ServerStatus {
+            state: ServerState::Failed,
+            error: Some(message.clone()),
+            ..inner.status.clone()
+        };
+        self.set_status(app, inner, status);
+        message
+    }
+
+    fn set_status(&self, app: &AppHandle, inner: &mut Inner, status: ServerStatus) {
+        inner.status = status;
+        if let Err(e) = app.emit(SERVER_STATE_EVENT, &inner.status) {
+            tracing::warn!("Failed to emit {SERVER_STATE_EVENT}: {e}");
+        }
+    }
+}
+
+/// Build the shared server state: database, API token, and broadcast channel.
+async fn build_state(dir: &Path, config_path: PathBuf, port: u16) -> Result<Arc<AppState>, String> {
+    std::fs::create_dir_all(dir)
+        .map_err(|e| format!("Failed to create {}: {e}", dir.display()))?;
+
+    let db_path = dir.join("tuitbot.db");
+    let pool = storage::init_db(&db_path.to_string_lossy())
+        .await
+        .map_err(|e| format!("Failed to open database at {}: {e}", db_path.display()))?;
+
+    let api_token =
+        auth::ensure_api_token(dir).map_err(|e| format!("Failed to create API token: {e}"))?;
+
+    // Ensure passphrase exists (Tauri uses bearer tokens, but the
+    // hash is needed if the user later accesses via web browser).
+    if let Err(e) = passphrase::ensure_passphrase(dir) {
+        tracing::warn!("Failed to initialize passphrase: {e}");
+    }
+    let passphrase_hash = passphrase::load_passphrase_hash(dir).ok().flatten();
+
+    let (event_tx, _) = tokio::sync::broadcast::channel::<WsEvent>(256);
+
+    Ok(Arc::new(AppState {
+        db: pool,
+        config_path,
+        data_dir: dir.to_path_buf(),
+        event_tx,
+        api_token,
+        passphrase_hash: tokio::sync::RwLock::new(passphrase_hash),
+        bind_host: "127.0.0.1".to_string(),
+        bind_port: port,
+        login_attempts: Mutex::new(HashMap::new()),
+        runtimes: Mutex::new(HashMap::new()),
+        content_generators: Mutex::new(HashMap::new()),
+        circuit_breaker: None,
+        watchtower_cancel: None,
+        content_sources: ContentSourcesConfig::default(),
+        deployment_mode: DeploymentMode::Desktop,
+        response_cache: Default::default(),
+        rate_limiter: Default::default(),
+    }))
+}
@@ -1,11 +1,24 @@
+let localPort = 3001;
+
 function resolveBaseUrl(): string {
 	if (typeof window === 'undefined') return '';
-	if ('__TAURI_INTERNALS__' in window) return 'http://localhost:3001';
+	if ('__TAURI_INTERNALS__' in window) return `http://localhost:${localPort}`;
 	if (window.location.port === '5173') return 'http://localhost:3001';
 	return '';
 }
 
-const BASE_URL = resolveBaseUrl();
+let BASE_URL = resolveBaseUrl();
+
+/** Point the desktop app at the embedded server's current port. */
+export function setServerPort(port: number) {
+	localPort = port;
+	BASE_URL = resolveBaseUrl();
+}
+
+/** Port of the embedded server in the desktop app. */
+export function serverPort(): number {
+	return localPort;
+}
 let token: string = '';
 let accountId: string = '00000000-0000-0000-0000-000000000000';
 let authMode: 'bearer' | 'cookie' = 'bearer';
@@ -99,6 +112,20 @@ export interface RuntimeStatus {
 	capabilities: DeploymentCapabilities;
 }
 
+export interface KillSwitchState {
+	active: boolean;
+	reason: string | null;
+	source: string | null;
+	changed_at: string | null;
+}
+
+export interface DraftOnlyState {
+	active: boolean;
+	reason: string | null;
+	source: string | null;
+	changed_at: string | null;
+}
+
 // --- Shared types ---
 
 export interface HealthResponse {
@@ -146,6 +173,111 @@ export interface FollowerSnapshot {
 	tweet_count: number;
 }
 
+export type Granularity = 'hour' | 'day' | 'week';
+
+export interface AnalyticsRangeParams {
+	from?: string;
+	to?: string;
+	granularity?: Granularity;
+}
+
+export interface PeriodDelta {
+	current: number;
+	previous: number;
+	delta: number;
+	delta_pct: number | null;
+}
+
+export interface FollowerBucket {
+	bucket: string;
+	follower_count: number;
+	following_count: number;
+	tweet_count: number;
+}
+
+export interface FollowerRangeResponse {
+	range: { from: string; to: string };
+	granularity: Granularity;
+	series: FollowerBucket[];
+	summary: { followers: PeriodDelta; net_change: PeriodDelta };
+}
+
+export interface PerformanceBucket {
+	bucket: string;
+	replies: number;
+	tweets: number;
+	avg_reply_score: number;
+	avg_tweet_score: number;
+	likes: number;
+	impressions: number;
+}
+
+export interface PerformanceResponse {
+	avg_reply_engagement: number;
+	avg_tweet_engagement: number;
+	measured_replies: number;
+	measured_tweets: number;
+	range: { from: string; to: string };
+	granularity: Granularity;
+	series: PerformanceBucket[];
+	summary: {
+		measured_replies: PeriodDelta;
+		measured_tweets: PeriodDelta;
+		avg_reply_engagement: PeriodDelta;
+		avg_tweet_engagement: PeriodDelta;
+	};
+}
+
+export interface ApprovalBreakdown {
+	key: string;
+	approved: number;
+	rejected: number;
+	approval_rate: number;
+}
+
+export interface ReviewerThroughput {
+	reviewer: string;
+	reviewed: number;
+	approved: number;
+	rejected: number;
+	avg_review_secs: number;
+}
+
+export interface ApprovalMetrics {
+	enqueued: number;
+	pending: number;
+	approved: number;
+	rejected: number;
+	expired: number;
+	approval_rate: number;
+	expired_share: number;
+	median_review_secs: number | null;
+	by_action_type: ApprovalBreakdown[];
+	by_topic: ApprovalBreakdown[];
+	reviewers: ReviewerThroughput[];
+}
+
+export interface QaFlagDay {
+	day: string;
+	category: string;
+	severity: 'hard' | 'soft';
+	count: number;
+}
+
+export interface QaRuleTrend {
+	code: string;
+	category: string;
+	severity: 'hard' | 'soft';
+	count: PeriodDelta;
+}
+
+export interface QaFlagTrends {
+	total: PeriodDelta;
+	hard: PeriodDelta;
+	series: QaFlagDay[];
+	top_rules: QaRuleTrend[];
+}
+
 export interface PerformanceItem {
 	content_type: string;
 	content_preview: string;
@@ -157,6 +289,44 @@ export interface PerformanceItem {
 	posted_at: string;
 }
 
+export interface InspirationPerformance {
+	inspiration: 'calendar' | 'trend';
+	post_count: number;
+	measured_count: number;
+	avg_performance: number;
+	avg_impressions: number;
+}
+
+export interface ThreadRolePerformance {
+	role: 'hook' | 'body' | 'cta';
+	tweets: number;
+	avg_impressions: number;
+	avg_engagement_rate: number;
+	avg_performance: number;
+}
+
+export interface ThreadPositionRetention {
+	position: number;
+	threads: number;
+	avg_impressions: number;
+	avg_retention: number;
+}
+
+export interface ThreadPositionReport {
+	threads_measured: number;
+	roles: ThreadRolePerformance[];
+	curve: ThreadPositionRetention[];
+	insights: { title: string; description: string }[];
+}
+
+export interface ActivityLinks {
+	approval_id: number | null;
+	reply_id: number | null;
+	tweet_id: string | null;
+	tweet_url: string | null;
+	in_reply_to_url: string | null;
+}
+
 export interface ActionLogEntry {
 	id: number;
 	action_type: string;
@@ -164,6 +334,11 @@ export interface ActionLogEntry {
 	message: string | null;
 	metadata: string | null;
 	created_at: string;
+	/** Normalized fields; set on rows from /api/activity. */
+	kind?: string;
+	icon?: string;
+	summary?: string;
+	links?: ActivityLinks;
 }
 
 export interface ActivityResponse {
@@ -173,6 +348,47 @@ export interface ActivityResponse {
 	offset: number;
 }
 
+export interface SentReply {
+	id: number;
+	target_tweet_id: string;
+	reply_tweet_id: string | null;
+	reply_content: string;
+	llm_provider: string | null;
+	llm_model: string | null;
+	created_at: string;
+	status: string;
+	error_message: string | null;
+}
+
+export interface ReplyScoreSignals {
+	total: number;
+	keyword_relevance: number;
+	follower: number;
+	recency: number;
+	engagement: number;
+	reply_count: number;
+	content_type: number;
+	entity_adjustment: number;
+	freshness_penalty: number;
+	plugin_adjustment: number;
+	plugin_rationale?: string;
+}
+
+export interface ReplyExplanation {
+	source: string;
+	matched_keywords: string[];
+	score: ReplyScoreSignals | null;
+	threshold: number | null;
+	archetype: string | null;
+	ancestors: { tweet_id: string; content_preview: string; engagement_score: number }[];
+	qa_score: number | null;
+}
+
+export interface ReplyExplanationResponse {
+	reply: SentReply;
+	explanation: ReplyExplanation | null;
+}
+
 export interface ActionUsage {
 	used: number;
 	max: number;
@@ -184,6 +400,22 @@ export interface RateLimitUsage {
 	threads: ActionUsage;
 }
 
+export interface DashboardSummary {
+	usage: RateLimitUsage;
+	pending_approvals: number;
+	upcoming: { id: number; content_type: string; scheduled_for: string }[];
+	followers: { count: number | null; delta: number | null };
+	token: { expires_at: string | null; expired: boolean; expires_in_seconds: number | null } | null;
+	loop_health: {
+		running: boolean;
+		task_count: number;
+		circuit_breaker: string;
+		visibility: string;
+		kill_switch_active: boolean;
+		draft_only_active: boolean;
+	};
+}
+
 export interface ApprovalItem {
 	id: number;
 	action_type: string;
@@ -207,6 +439,22 @@ export interface ApprovalItem {
 	qa_override_by?: string;
 	qa_override_note?: string;
 	qa_override_at?: string;
+	/** For `collapsed` items, the duplicate that was kept instead. */
+	collapsed_into?: number | null;
+	/** Bumped on every change; send back as `expected_version` to detect concurrent edits. */
+	version: number;
+	/** Most recent reviewer comments, oldest first. */
+	latest_comments?: ApprovalComment[];
+}
+
+export interface ApprovalComment {
+	id: number;
+	approval_id: number;
+	/** Comment this one replies to. */
+	parent_id: number | null;
+	author: string;
+	body: string;
+	created_at: string;
 }
 
 export interface EditHistoryEntry {
@@ -383,6 +631,11 @@ export interface TuitbotConfig {
 		engagement_rate_max: number;
 		reply_count_max: number;
 		content_type_max: number;
+		freshness: {
+			discovery_decay_after_hours: number;
+			discovery_max_age_hours: number;
+			mentions_max_age_hours: number;
+		};
 	};
 	limits: {
 		max_replies_per_day: number;
@@ -504,6 +757,8 @@ export interface StrategyReport {
 	bottom_topics: TopicPerformance[];
 	top_content: ContentHighlight[];
 	recommendations: Recommendation[];
+	/** Empty for reports computed before approval metrics existed. */
+	approval_metrics: Partial<ApprovalMetrics>;
 }
 
 export interface StrategyInputs {
@@ -525,6 +780,8 @@ export interface CostSummary {
 	calls_7d: number;
 	calls_30d: number;
 	calls_all_time: number;
+	savings_30d: number;
+	savings_all_time: number;
 }
 
 export interface DailyCostSummary {
@@ -542,6 +799,8 @@ export interface ModelCostBreakdown {
 	calls: number;
 	input_tokens: number;
 	output_tokens: number;
+	cache_read_tokens: number;
+	savings: number;
 }
 
 export interface TypeCostBreakdown {
@@ -693,8 +952,29 @@ export interface McpTelemetryEntry {
 	created_at: string;
 }
 
+export interface McpStagedAction {
+	id: number;
+	token: string;
+	tool_name: string;
+	params_json: string;
+	params_hash: string;
+	status: 'pending' | 'confirmed' | 'executed' | 'rejected';
+	created_at: string;
+	expires_at: string;
+	resolved_at: string | null;
+	resolved_by: string | null;
+}
+
 // --- File upload helper ---
 
+function rangeQuery(params: AnalyticsRangeParams): string {
+	const query = new URLSearchParams();
+	if (params.from) query.set('from', params.from);
+	if (params.to) query.set('to', params.to);
+	query.set('granularity', params.granularity ?? 'day');
+	return query.toString();
+}
+
 async function uploadFile(path: string, file: File): Promise<MediaUploadResponse> {
 	const formData = new FormData();
 	formData.append('file', file);
@@ -742,6 +1022,24 @@ export interface Account {
 export const api = {
 	health: () => request<HealthResponse>('/api/health'),
 
+	admin: {
+		killSwitch: () => request<KillSwitchState>('/api/admin/kill'),
+		engageKillSwitch: (reason: string) =>
+			request<KillSwitchState>('/api/admin/kill', {
+				method: 'POST',
+				body: JSON.stringify({ reason })
+			}),
+		releaseKillSwitch: () => request<KillSwitchState>('/api/admin/kill', { method: 'DELETE' }),
+		draftOnly: () => request<DraftOnlyState>('/api/admin/draft-only'),
+		enableDraftOnly: (reason?: string) =>
+			request<DraftOnlyState>('/api/admin/draft-only', {
+				method: 'POST',
+				body: JSON.stringify({ reason: reason || null })
+			}),
+		disableDraftOnly: () =>
+			request<DraftOnlyState>('/api/admin/draft-only', { method: 'DELETE' })
+	},
+
 	runtime: {
 		status: () => request<RuntimeStatus>('/api/runtime/status'),
 		start: () => request<{ status: string }>('/api/runtime/start', { method: 'POST' }),
@@ -797,12 +1095,55 @@ export const api = {
 			request<{ status: string }>(`/api/accounts/${id}`, { method: 'DELETE' })
 	},
 
+	summary: () => request<DashboardSummary>('/api/summary'),
+
 	analytics: {
 		summary: () => request<AnalyticsSummary>('/api/analytics/summary'),
 		followers: (days: number = 30) =>
 			request<FollowerSnapshot[]>(`/api/analytics/followers?days=${days}`),
+		followerRange: (params: AnalyticsRangeParams) =>
+			request<FollowerRangeResponse>(`/api/analytics/followers?${rangeQuery(params)}`),
+		performance: (params: AnalyticsRangeParams = {}) =>
+			request<PerformanceResponse>(`/api/analytics/performance?${rangeQuery(params)}`),
 		topics: (limit: number = 10) =>
 			request<ContentScore[]>(`/api/analytics/topics?limit=${limit}`),
+		approvals: (params: { from?: string; to?: string } = {}) => {
+			const query = new URLSearchParams();
+			if (params.from) query.set('from', params.from);
+			if (params.to) query.set('to', params.to);
+			const qs = query.toString();
+			return request<{ range: { from: string; to: string }; metrics: ApprovalMetrics }>(
+				`/api/analytics/approvals${qs ? `?${qs}` : ''}`
+			);
+		},
+		qa: (params: { from?: string; to?: string; limit?: number } = {}) => {
+			const query = new URLSearchParams();
+			if (params.from) query.set('from', params.from);
+			if (params.to) query.set('to', params.to);
+			if (params.limit) query.set('limit', params.limit.toString());
+			const qs = query.toString();
+			return request<{ range: { from: string; to: string }; trends: QaFlagTrends }>(
+				`/api/analytics/qa${qs ? `?${qs}` : ''}`
+			);
+		},
+		inspiration: (params: { from?: string; to?: string } = {}) => {
+			const query = new URLSearchParams();
+			if (params.from) query.set('from', params.from);
+			if (params.to) query.set('to', params.to);
+			const qs = query.toString();
+			return request<{ range: { from: string; to: string }; groups: InspirationPerformance[] }>(
+				`/api/analytics/inspiration${qs ? `?${qs}` : ''}`
+			);
+		},
+		threads: (params: { from?: string; to?: string } = {}) => {
+			const query = new URLSearchParams();
+			if (params.from) query.set('from', params.from);
+			if (params.to) query.set('to', params.to);
+			const qs = query.toString();
+			return request<{ range: { from: string; to: string }; report: ThreadPositionReport }>(
+				`/api/analytics/threads${qs ? `?${qs}` : ''}`
+			);
+		},
 		recentPerformance: (limit: number = 20) =>
 			request<PerformanceItem[]>(`/api/analytics/recent-performance?limit=${limit}`)
 	},
@@ -826,6 +1167,13 @@ export const api = {
 		}
 	},
 
+	replies: {
+		list: (limit: number = 20, offset: number = 0) =>
+			request<SentReply[]>(`/api/replies?limit=${limit}&offset=${offset}`),
+		explanation: (id: number) =>
+			request<ReplyExplanationResponse>(`/api/replies/${id}/explanation`)
+	},
+
 	content: {
 		calendar: (from: string, to: string) =>
 			request<CalendarItem[]>(`/api/content/calendar?from=${from}&to=${to}`),
@@ -916,6 +1264,12 @@ export const api = {
 				`/api/targets/${encodeURIComponent(username)}`,
 				{ method: 'DELETE' }
 			),
+		removed: () => request<TargetAccount[]>('/api/targets/removed'),
+		restore: (username: string) =>
+			request<{ status: string; username: string }>(
+				`/api/targets/${encodeURIComponent(username)}/restore`,
+				{ method: 'POST' }
+			),
 		timeline: (username: string, limit: number = 50) =>
 			request<TargetTimelineItem[]>(
 				`/api/targets/${encodeURIComponent(username)}/timeline?limit=${limit}`
@@ -953,7 +1307,12 @@ export const api = {
 	media: {
 		upload: (file: File) => uploadFile('/api/media/upload', file),
 		fileUrl: (path: string) =>
-			`${BASE_URL}/api/media/file?path=${encodeURIComponent(path)}`
+			`${BASE_URL}/api/media/file?path=${encodeURIComponent(path)}`,
+		/** Preview URL by media ID (the stored file name), never exposing the local path. */
+		previewUrl: (path: string) => {
+			const id = path.split(/[\\/]/).pop() ?? '';
+			return `${BASE_URL}/api/media/${encodeURIComponent(id)}`;
+		}
 	},
 
 	approval: {
@@ -977,6 +1336,14 @@ export const api = {
 				method: 'POST',
 				body: JSON.stringify({ actor, notes })
 			}),
+		delete: (id: number) =>
+			request<{ status: string; id: number }>(`/api/approval/${id}`, { method: 'DELETE' }),
+		restore: (id: number) =>
+			request<{ status: string; id: number; item_status: string }>(
+				`/api/approval/${id}/restore`,
+				{ method: 'POST' }
+			),
+		deleted: () => request<ApprovalItem[]>('/api/approval/deleted'),
 		edit: (id: number, content: string, media_paths?: string[], editor?: string) =>
 			request<ApprovalItem>(`/api/approval/${id}`, {
 				method: 'PATCH',
@@ -1000,6 +1367,12 @@ export const api = {
 			),
 		editHistory: (id: number) =>
 			request<EditHistoryEntry[]>(`/api/approval/${id}/history`),
+		comments: (id: number) => request<ApprovalComment[]>(`/api/approval/${id}/comments`),
+		addComment: (id: number, body: string, parent_id?: number, author: string = 'dashboard') =>
+			request<ApprovalComment>(`/api/approval/${id}/comments`, {
+				method: 'POST',
+				body: JSON.stringify({ body, author, ...(parent_id !== undefined && { parent_id }) })
+			}),
 		exportUrl: (format: 'csv' | 'json', status?: string, type_?: string): string => {
 			const query = new URLSearchParams({ format });
 			if (status) query.set('status', status);
@@ -1106,7 +1479,12 @@ export const api = {
 		telemetryErrors: (hours: number = 24) =>
 			request<McpErrorBreakdown[]>(`/api/mcp/telemetry/errors?hours=${hours}`),
 		telemetryRecent: (limit: number = 50) =>
-			request<McpTelemetryEntry[]>(`/api/mcp/telemetry/recent?limit=${limit}`)
+			request<McpTelemetryEntry[]>(`/api/mcp/telemetry/recent?limit=${limit}`),
+		stagedActions: () => request<McpStagedAction[]>('/api/mcp/staged'),
+		approveStaged: (id: number) =>
+			request<McpStagedAction>(`/api/mcp/staged/${id}/approve`, { method: 'POST' }),
+		rejectStaged: (id: number) =>
+			request<McpStagedAction>(`/api/mcp/staged/${id}/reject`, { method: 'POST' })
 	},
 
 	discovery: {
@@ -0,0 +1,28 @@
+/**
+ * Native notification toggles for the desktop app.
+ *
+ * The desktop shell turns server events into OS notifications; these
+ * settings choose which event types do. Only available inside Tauri.
+ */
+
+export interface NotificationSettings {
+	pending_approval: boolean;
+	post_failure: boolean;
+	token_expiry: boolean;
+	kill_switch: boolean;
+}
+
+async function invoke<T>(cmd: string, args?: Record<string, unknown>): Promise<T> {
+	const { invoke } = await import('@tauri-apps/api/core');
+	return invoke<T>(cmd, args);
+}
+
+export function getNotificationSettings(): Promise<NotificationSettings> {
+	return invoke<NotificationSettings>('get_notification_settings');
+}
+
+export function updateNotificationSettings(
+	settings: NotificationSettings
+): Promise<NotificationSettings> {
+	return invoke<NotificationSettings>('update_notification_settings', { settings });
+}
@@ -0,0 +1,24 @@
+import { writable } from 'svelte/store';
+import { api, type DraftOnlyState } from '$lib/api';
+
+/** Current draft-only mode state (null until loaded). */
+export const draftOnly = writable<DraftOnlyState | null>(null);
+
+/** Fetch the draft-only state from the server. */
+export async function loadDraftOnly() {
+	try {
+		draftOnly.set(await api.admin.draftOnly());
+	} catch {
+		// Leave the previous state in place if the request fails.
+	}
+}
+
+/** Turn draft-only mode on, queueing all posts for review. */
+export async function enableDraftOnly(reason?: string) {
+	draftOnly.set(await api.admin.enableDraftOnly(reason));
+}
+
+/** Turn draft-only mode off, releasing held items for posting. */
+export async function disableDraftOnly() {
+	draftOnly.set(await api.admin.disableDraftOnly());
+}
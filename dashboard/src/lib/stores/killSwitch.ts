@@ -0,0 +1,24 @@
+import { writable } from 'svelte/store';
+import { api, type KillSwitchState } from '$lib/api';
+
+/** Current compliance kill switch state (null until loaded). */
+export const killSwitch = writable<KillSwitchState | null>(null);
+
+/** Fetch the kill switch state from the server. */
+export async function loadKillSwitch() {
+	try {
+		killSwitch.set(await api.admin.killSwitch());
+	} catch {
+		// Leave the previous state in place if the request fails.
+	}
+}
+
+/** Engage the kill switch, halting all mutations. */
+export async function engageKillSwitch(reason: string) {
+	killSwitch.set(await api.admin.engageKillSwitch(reason));
+}
+
+/** Release the kill switch, re-enabling mutations. */
+export async function releaseKillSwitch() {
+	killSwitch.set(await api.admin.releaseKillSwitch());
+}
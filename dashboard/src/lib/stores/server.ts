@@ -0,0 +1,89 @@
+/**
+ * Embedded server lifecycle for the desktop app.
+ *
+ * Wraps the Tauri IPC commands that control the in-process server and keeps
+ * `serverStatus` in sync with the `server-state` events it emits. Outside
+ * Tauri every call is a no-op and `serverStatus` stays null.
+ */
+
+import { writable } from 'svelte/store';
+import { setServerPort } from '$lib/api';
+
+export type ServerState = 'starting' | 'running' | 'stopped' | 'failed';
+
+export interface ServerStatus {
+	state: ServerState;
+	port: number;
+	config_path: string;
+	error: string | null;
+}
+
+export interface ServerSettings {
+	port: number;
+	config_path: string | null;
+}
+
+export interface PortCheck {
+	port: number;
+	available: boolean;
+	in_use_by_tuitbot: boolean;
+	error: string | null;
+}
+
+/** Latest embedded server status (null outside the desktop app). */
+export const serverStatus = writable<ServerStatus | null>(null);
+
+function isTauri(): boolean {
+	return typeof window !== 'undefined' && '__TAURI_INTERNALS__' in window;
+}
+
+async function invoke<T>(cmd: string, args?: Record<string, unknown>): Promise<T> {
+	const { invoke } = await import('@tauri-apps/api/core');
+	return invoke<T>(cmd, args);
+}
+
+function applyStatus(status: ServerStatus) {
+	setServerPort(status.port);
+	serverStatus.set(status);
+}
+
+/**
+ * Load the current status and subscribe to state changes.
+ * `onRunning` fires whenever the server (re)starts, e.g. to reconnect the WebSocket.
+ */
+export async function initServerStatus(onRunning?: (status: ServerStatus) => void) {
+	if (!isTauri()) return;
+
+	applyStatus(await invoke<ServerStatus>('get_server_status'));
+
+	const { listen } = await import('@tauri-apps/api/event');
+	await listen<ServerStatus>('server-state', (event) => {
+		applyStatus(event.payload);
+		if (event.payload.state === 'running') onRunning?.(event.payload);
+	});
+}
+
+export async function restartServer(): Promise<ServerStatus> {
+	const status = await invoke<ServerStatus>('restart_server');
+	applyStatus(status);
+	return status;
+}
+
+export function getServerLogs(limit?: number): Promise<string[]> {
+	return invoke<string[]>('get_server_logs', { limit });
+}
+
+export function checkPort(port: number): Promise<PortCheck> {
+	return invoke<PortCheck>('check_port', { port });
+}
+
+export function getServerSettings(): Promise<ServerSettings> {
+	return invoke<ServerSettings>('get_server_settings');
+}
+
+/** Save settings and restart the server to apply them. */
+export async function updateServerSettings(settings: ServerSettings): Promise<ServerStatus> {
+	const status = await invoke<ServerStatus>('update_server_settings', { settings });
+	applyStatus(status);
+	return status;
+}
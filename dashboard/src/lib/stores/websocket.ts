@@ -1,8 +1,11 @@
 import { writable } from 'svelte/store';
+import { killSwitch } from './killSwitch';
+import { draftOnly } from './draftOnly';
+import { serverPort } from '$lib/api';
 
 /** Events pushed by the tuitbot-server WebSocket. */
 export interface WsEvent {
-    type: 'ActionPerformed' | 'ApprovalQueued' | 'ApprovalUpdated' | 'FollowerUpdate' | 'RuntimeStatus' | 'ContentScheduled' | 'Error';
+    type: 'ActionPerformed' | 'ApprovalQueued' | 'ApprovalUpdated' | 'FollowerUpdate' | 'RuntimeStatus' | 'ContentScheduled' | 'KillSwitchChanged' | 'DraftOnlyChanged' | 'PostFailed' | 'TokenExpiring' | 'ApprovalSlaBreached' | 'Error';
     [key: string]: unknown;
 }
 
@@ -51,7 +54,10 @@ async function sendNativeNotification(title: string, body: string) {
  */
 function resolveWsBase(): string {
     if (typeof window === 'undefined') return 'ws://localhost:3001';
-    if ('__TAURI_INTERNALS__' in window || window.location.port === '5173') {
+    if ('__TAURI_INTERNALS__' in window) {
+        return `ws://localhost:${serverPort()}`;
+    }
+    if (window.location.port === '5173') {
         return 'ws://localhost:3001';
     }
     const protocol = window.location.protocol === 'https:' ? 'wss:' : 'ws:';
@@ -102,9 +108,31 @@ export function connectWs(token?: string) {
                 runtimeRunning.set(event.running as boolean);
             }
 
-            // Native notifications when app is in background
+            // Track kill switch changes made from any client or the CLI
+            if (event.type === 'KillSwitchChanged') {
+                const active = event.active as boolean;
+                killSwitch.set({
+                    active,
+                    reason: (event.reason as string | null) ?? null,
+                    source: (event.source as string | null) ?? null,
+                    changed_at: event.timestamp as string
+                });
+            }
+
+            // Track draft-only mode changes made from any client or the CLI
+            if (event.type === 'DraftOnlyChanged') {
+                draftOnly.set({
+                    active: event.active as boolean,
+                    reason: (event.reason as string | null) ?? null,
+                    source: (event.source as string | null) ?? null,
+                    changed_at: event.timestamp as string
+                });
+            }
+
+            // Native notifications when app is in background. Approvals,
+            // posting failures, token expiry, and the kill switch are
+            // notified by the desktop shell (see Settings > Notifications).
             if (event.type === 'ApprovalQueued') {
-                sendNativeNotification('Tuitbot', 'New item pending approval');
                 consecutiveErrors = 0;
             } else if (event.type === 'FollowerUpdate') {
                 const count = event.count as number;
@@ -0,0 +1,12 @@
+//! DER parsing of PKCS#8 / PKCS#1 RSA private keys.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use tuitbot_core::fuzzing::gdrive_parse_pkcs8_der;
+
+fuzz_target!(|der: &[u8]| {
+    if let Some((n_len, d_len)) = gdrive_parse_pkcs8_der(der) {
+        assert!(n_len + d_len <= der.len());
+    }
+});
@@ -0,0 +1,12 @@
+//! PEM decoding and PKCS#8 parsing of Google Drive service-account keys.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use tuitbot_core::fuzzing::gdrive_parse_pem_key;
+
+fuzz_target!(|pem: &str| {
+    if let Some((n_len, d_len)) = gdrive_parse_pem_key(pem) {
+        assert!(n_len + d_len <= pem.len());
+    }
+});
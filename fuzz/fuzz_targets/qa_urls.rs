@@ -0,0 +1,17 @@
+//! URL extraction, domain parsing, and query-key parsing in the QA link checks.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use tuitbot_core::fuzzing::{qa_extract_domain, qa_parse_query_keys, qa_parse_urls};
+
+fuzz_target!(|input: (&str, &str)| {
+    let (text, url) = input;
+    for (found, domain, keys) in qa_parse_urls(text) {
+        assert!(text.contains(&found));
+        assert!(domain.is_none_or(|d| !d.is_empty()));
+        assert!(keys.iter().all(|k| !k.is_empty()));
+    }
+    let _ = qa_extract_domain(url);
+    assert!(qa_parse_query_keys(url).iter().all(|k| !k.is_empty()));
+});
@@ -0,0 +1,17 @@
+//! `truncate_at_sentence` stays within the limit and cuts on char boundaries.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use tuitbot_core::content::length::{truncate_at_sentence, tweet_weighted_len};
+
+fuzz_target!(|input: (u16, &str)| {
+    let (max, text) = input;
+    let max = usize::from(max % 600).max(3);
+
+    let result = truncate_at_sentence(text, max);
+    assert!(tweet_weighted_len(&result) <= max);
+    if tweet_weighted_len(text) <= max {
+        assert_eq!(result, text);
+    }
+});
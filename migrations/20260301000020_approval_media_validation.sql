@@ -0,0 +1,3 @@
+-- Per-file media validation results recorded before upload.
+-- JSON array of MediaValidation objects (format, sizes, dimensions, transcode warnings).
+ALTER TABLE approval_queue ADD COLUMN media_validation TEXT DEFAULT '[]';
@@ -0,0 +1,12 @@
+-- Compliance kill switch: a single persisted flag that halts every
+-- mutation path (automation loops, approval posting, MCP mutation tools)
+-- until explicitly released. Engage/release history lives in action_log.
+CREATE TABLE IF NOT EXISTS kill_switch (
+    id         INTEGER PRIMARY KEY CHECK (id = 1),
+    active     INTEGER NOT NULL DEFAULT 0,
+    reason     TEXT,                                -- Why the switch was engaged
+    source     TEXT,                                -- Who changed it: 'cli', 'api', ...
+    changed_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))
+);
+
+INSERT OR IGNORE INTO kill_switch (id, active) VALUES (1, 0);
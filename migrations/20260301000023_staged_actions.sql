@@ -0,0 +1,19 @@
+-- MCP confirmation mode: mutations staged for two-phase commit.
+-- A mutation tool call stores its parameters here and returns a token;
+-- `confirm_action` (or a dashboard approval) confirms it within the TTL.
+CREATE TABLE IF NOT EXISTS staged_actions (
+    id          INTEGER PRIMARY KEY AUTOINCREMENT,
+    account_id  TEXT NOT NULL DEFAULT '00000000-0000-0000-0000-000000000000',
+    token       TEXT NOT NULL UNIQUE,
+    tool_name   TEXT NOT NULL,
+    params_json TEXT NOT NULL,
+    params_hash TEXT NOT NULL,                      -- Matches mutation_audit.params_hash
+    status      TEXT NOT NULL DEFAULT 'pending',    -- 'pending', 'confirmed', 'executed', 'rejected'
+    created_at  TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
+    expires_at  TEXT NOT NULL,
+    resolved_at TEXT,
+    resolved_by TEXT                                -- Who confirmed/rejected: 'mcp', 'dashboard'
+);
+
+CREATE INDEX IF NOT EXISTS idx_staged_actions_lookup
+    ON staged_actions(account_id, tool_name, params_hash, status);
@@ -0,0 +1,21 @@
+-- MCP tool invocation log for debugging and replay.
+-- Every tool call on a DB-backed MCP server is recorded with redacted
+-- arguments; read-only invocations can be re-executed with `tuitbot mcp replay`.
+CREATE TABLE IF NOT EXISTS mcp_invocations (
+    id          INTEGER PRIMARY KEY AUTOINCREMENT,
+    account_id  TEXT NOT NULL DEFAULT '00000000-0000-0000-0000-000000000000',
+    session_id  TEXT NOT NULL,                      -- One per MCP server process
+    tool_name   TEXT NOT NULL,
+    args_hash   TEXT NOT NULL,                      -- SHA-256 of the unredacted arguments
+    args_json   TEXT NOT NULL,                      -- Arguments with secrets redacted
+    read_only   INTEGER NOT NULL DEFAULT 0,
+    success     INTEGER NOT NULL,
+    error_code  TEXT,
+    latency_ms  INTEGER NOT NULL,
+    created_at  TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))
+);
+
+CREATE INDEX IF NOT EXISTS idx_mcp_invocations_session
+    ON mcp_invocations(session_id, id);
+CREATE INDEX IF NOT EXISTS idx_mcp_invocations_tool
+    ON mcp_invocations(tool_name, created_at);
@@ -0,0 +1,3 @@
+-- Optimistic concurrency for approval status transitions. Incremented on
+-- every change; reviews and posting claims compare-and-swap on it.
+ALTER TABLE approval_queue ADD COLUMN version INTEGER NOT NULL DEFAULT 0;
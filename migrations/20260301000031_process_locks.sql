@@ -0,0 +1,9 @@
+-- Cross-process advisory locks (e.g. the approval posting lock).
+-- A holder keeps its lock by refreshing heartbeat_at; a lock whose
+-- heartbeat is older than the caller's timeout may be taken over.
+CREATE TABLE IF NOT EXISTS process_locks (
+    name         TEXT PRIMARY KEY,        -- lock name, e.g. 'posting'
+    holder       TEXT NOT NULL,           -- process identity, e.g. 'tuitbot pid 4242'
+    acquired_at  TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
+    heartbeat_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))
+);
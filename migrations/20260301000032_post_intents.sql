@@ -0,0 +1,19 @@
+-- Outbound post intents for client-side idempotency.
+-- An intent is written before the X API call and completed with the
+-- resulting tweet ID, so a retry after an ambiguous failure (e.g. a
+-- timeout) can detect that the post already went out.
+CREATE TABLE IF NOT EXISTS post_intents (
+    id             INTEGER PRIMARY KEY AUTOINCREMENT,
+    account_id     TEXT NOT NULL DEFAULT '00000000-0000-0000-0000-000000000000',
+    dedup_key      TEXT NOT NULL,             -- SHA-256 of scope, action, target, text
+    action_type    TEXT NOT NULL,             -- tweet, reply
+    in_reply_to_id TEXT NOT NULL DEFAULT '',
+    content        TEXT NOT NULL,
+    status         TEXT NOT NULL DEFAULT 'pending', -- pending, posted, failed
+    tweet_id       TEXT,
+    attempts       INTEGER NOT NULL DEFAULT 0,
+    last_error     TEXT,
+    created_at     TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
+    updated_at     TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
+    UNIQUE (account_id, dedup_key)
+);
@@ -0,0 +1,3 @@
+-- Priority tier per target account ('dream_100' or 'general').
+-- The tier selects the engagement cadence configured under [targets.tiers].
+ALTER TABLE target_accounts ADD COLUMN tier TEXT NOT NULL DEFAULT 'general';
@@ -0,0 +1,16 @@
+-- Newest tweet ID seen per target account, so each pass fetches only new tweets.
+ALTER TABLE target_accounts ADD COLUMN since_id TEXT;
+
+-- Replies sent or queued to each target account. Tier cadence counts
+-- interactions from this table over a rolling window.
+CREATE TABLE IF NOT EXISTS target_reply_history (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    owner_account_id TEXT NOT NULL DEFAULT '00000000-0000-0000-0000-000000000000',
+    account_id TEXT NOT NULL,
+    tweet_id TEXT NOT NULL,
+    status TEXT NOT NULL,
+    created_at TEXT NOT NULL DEFAULT (datetime('now'))
+);
+
+CREATE INDEX IF NOT EXISTS idx_target_reply_history_account
+    ON target_reply_history(owner_account_id, account_id, created_at);
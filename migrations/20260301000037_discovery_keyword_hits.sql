@@ -0,0 +1,13 @@
+-- Every keyword that surfaced a discovered tweet. Doubles as the dedup cache
+-- shared across keywords and loops: a tweet with a recent hit is not
+-- re-scored, while each keyword still gets credit for finding it.
+CREATE TABLE IF NOT EXISTS discovery_keyword_hits (
+    account_id TEXT NOT NULL DEFAULT '00000000-0000-0000-0000-000000000000',
+    tweet_id TEXT NOT NULL,
+    keyword TEXT NOT NULL,
+    first_seen_at TEXT NOT NULL,
+    PRIMARY KEY (account_id, tweet_id, keyword)
+);
+
+CREATE INDEX IF NOT EXISTS idx_discovery_keyword_hits_seen
+    ON discovery_keyword_hits(account_id, first_seen_at);
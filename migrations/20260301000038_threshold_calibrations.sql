@@ -0,0 +1,12 @@
+-- One row per UTC day with the effective discovery score threshold and the
+-- reply volume that drove the adjustment.
+CREATE TABLE IF NOT EXISTS threshold_calibrations (
+    account_id TEXT NOT NULL DEFAULT '00000000-0000-0000-0000-000000000000',
+    day TEXT NOT NULL,
+    previous_threshold INTEGER NOT NULL,
+    threshold INTEGER NOT NULL,
+    replies INTEGER NOT NULL,
+    target_replies INTEGER NOT NULL,
+    created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
+    PRIMARY KEY (account_id, day)
+);
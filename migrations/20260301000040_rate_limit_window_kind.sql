@@ -0,0 +1,6 @@
+-- Each rate limit row records how its window is aligned: 'calendar' windows
+-- start at local midnight, 'rolling' windows at the first request after the
+-- previous one ended.
+ALTER TABLE rate_limits ADD COLUMN window_kind TEXT NOT NULL DEFAULT 'calendar';
+
+UPDATE rate_limits SET window_kind = 'rolling' WHERE period_seconds % 86400 != 0;
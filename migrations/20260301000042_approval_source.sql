@@ -0,0 +1,3 @@
+-- External system that supplied an ingested candidate (POST /api/ingest/candidates).
+-- Empty for items produced by the automation loops.
+ALTER TABLE approval_queue ADD COLUMN source TEXT NOT NULL DEFAULT '';
@@ -0,0 +1,18 @@
+-- Per-position metrics for posted thread tweets. `retention` is the tweet's
+-- impressions relative to the thread's hook, forming the drop-off curve.
+CREATE TABLE IF NOT EXISTS thread_tweet_performance (
+    tweet_id TEXT PRIMARY KEY,
+    account_id TEXT NOT NULL DEFAULT '00000000-0000-0000-0000-000000000000',
+    thread_id INTEGER NOT NULL REFERENCES threads(id) ON DELETE CASCADE,
+    position INTEGER NOT NULL,
+    likes_received INTEGER NOT NULL DEFAULT 0,
+    retweets_received INTEGER NOT NULL DEFAULT 0,
+    replies_received INTEGER NOT NULL DEFAULT 0,
+    impressions INTEGER NOT NULL DEFAULT 0,
+    performance_score REAL NOT NULL DEFAULT 0.0,
+    retention REAL,
+    measured_at TEXT NOT NULL DEFAULT (datetime('now'))
+);
+
+CREATE INDEX IF NOT EXISTS idx_thread_tweet_performance_thread
+    ON thread_tweet_performance(account_id, thread_id);
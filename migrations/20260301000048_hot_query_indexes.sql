@@ -0,0 +1,21 @@
+-- Indexes for the lookups that slow down as tables grow.
+--
+-- Already covered, so not repeated here:
+--   rate_limits(account_id, action_type)  primary key (every rate-limit lookup)
+
+-- Pending approval queue, oldest first.
+CREATE INDEX IF NOT EXISTS idx_approval_queue_status_account_created
+    ON approval_queue(status, account_id, created_at);
+
+-- Reply dedup ("already replied to this tweet?"). The single-column
+-- account_id and target_tweet_id indexes leave the planner to pick one.
+CREATE INDEX IF NOT EXISTS idx_replies_sent_account_target
+    ON replies_sent(account_id, target_tweet_id);
+
+-- Recent reply texts for phrasing dedup, newest first.
+CREATE INDEX IF NOT EXISTS idx_replies_sent_account_created
+    ON replies_sent(account_id, created_at);
+
+-- Discovery feed and retention cleanup, by discovery time.
+CREATE INDEX IF NOT EXISTS idx_discovered_tweets_account_discovered
+    ON discovered_tweets(account_id, discovered_at);
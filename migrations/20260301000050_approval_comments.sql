@@ -0,0 +1,16 @@
+-- Reviewer comment threads on approval items.
+--
+-- review_notes holds only the final decision note; comments let several
+-- reviewers discuss an item before (and after) it is decided. A comment
+-- with parent_id set is a reply to another comment on the same item.
+CREATE TABLE IF NOT EXISTS approval_comments (
+    id          INTEGER PRIMARY KEY AUTOINCREMENT,
+    approval_id INTEGER NOT NULL REFERENCES approval_queue(id),
+    parent_id   INTEGER REFERENCES approval_comments(id),
+    author      TEXT NOT NULL,
+    body        TEXT NOT NULL,
+    created_at  TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))
+);
+
+CREATE INDEX IF NOT EXISTS idx_approval_comments_approval
+    ON approval_comments(approval_id, created_at);
@@ -0,0 +1,10 @@
+-- Warm-up progress per account.
+--
+-- started_at marks the start of the current warm-up; daily caps ramp up
+-- week by week from there. A restart after a long pause resets it.
+-- completed_at is set once the schedule has run its course.
+CREATE TABLE IF NOT EXISTS account_warmup (
+    account_id   TEXT PRIMARY KEY,
+    started_at   TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
+    completed_at TEXT
+);
@@ -0,0 +1,25 @@
+-- Results of lifecycle hook runs (pre_post, post_post, pre_approve,
+-- on_denial), kept as a decision trace for custom policies.
+--
+-- `verdict` is allow, deny, modify, or error. `item_id` links to the
+-- approval item when the hook ran for one; `target_id` is the tweet being
+-- replied to or quoted, or the posted tweet for post_post.
+CREATE TABLE IF NOT EXISTS hook_runs (
+    id          INTEGER PRIMARY KEY AUTOINCREMENT,
+    account_id  TEXT NOT NULL DEFAULT '00000000-0000-0000-0000-000000000000',
+    event       TEXT NOT NULL,
+    hook_name   TEXT NOT NULL,
+    action_type TEXT NOT NULL,
+    item_id     INTEGER,
+    target_id   TEXT,
+    verdict     TEXT NOT NULL,
+    reason      TEXT,
+    exit_code   INTEGER,
+    duration_ms INTEGER NOT NULL DEFAULT 0,
+    created_at  TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))
+);
+
+CREATE INDEX IF NOT EXISTS idx_hook_runs_account_created
+    ON hook_runs(account_id, created_at);
+CREATE INDEX IF NOT EXISTS idx_hook_runs_item
+    ON hook_runs(item_id);
@@ -0,0 +1,11 @@
+-- Archived installs: set by `tuitbot archive` when the bot is retired.
+-- While archived, the server runs read-only and `tuitbot run`/`tick`
+-- refuse to start, so history stays browsable but nothing changes.
+CREATE TABLE IF NOT EXISTS archive_state (
+    id          INTEGER PRIMARY KEY CHECK (id = 1),
+    archived    INTEGER NOT NULL DEFAULT 0,
+    source      TEXT,                                -- Who changed it: 'cli', ...
+    changed_at  TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))
+);
+
+INSERT OR IGNORE INTO archive_state (id, archived) VALUES (1, 0);
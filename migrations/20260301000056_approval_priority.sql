@@ -0,0 +1,6 @@
+-- Review priority for pending approval items. Raised by one each time an
+-- item outlives another `approval_sla.hours`; higher priorities sort first.
+ALTER TABLE approval_queue ADD COLUMN priority INTEGER NOT NULL DEFAULT 0;
+
+CREATE INDEX IF NOT EXISTS idx_approval_queue_pending_priority
+    ON approval_queue(account_id, status, priority);
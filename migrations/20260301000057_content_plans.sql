@@ -0,0 +1,19 @@
+-- Daily content mix plans per account.
+--
+-- One row per local day holds how many replies, original tweets, and
+-- threads the planner allocated at day start. The actual_* columns are
+-- filled in once the day is over, for the plan-vs-actual report.
+CREATE TABLE IF NOT EXISTS content_plans (
+    account_id       TEXT NOT NULL,
+    day              TEXT NOT NULL,
+    replies          INTEGER NOT NULL,
+    originals        INTEGER NOT NULL,
+    threads          INTEGER NOT NULL,
+    rationale        TEXT NOT NULL DEFAULT '',
+    created_at       TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
+    actual_replies   INTEGER,
+    actual_originals INTEGER,
+    actual_threads   INTEGER,
+    reported_at      TEXT,
+    PRIMARY KEY (account_id, day)
+);
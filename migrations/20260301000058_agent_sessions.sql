@@ -0,0 +1,15 @@
+-- One row per `tuitbot run` session, used to start in safe mode after the
+-- previous session crashed or kept failing to post.
+--
+-- ended_at is set on clean shutdown; a row left open with a stale
+-- heartbeat_at means the process died. consecutive_failures counts posting
+-- failures since the last successful post; last_error is the latest one.
+CREATE TABLE IF NOT EXISTS agent_sessions (
+    id                   INTEGER PRIMARY KEY AUTOINCREMENT,
+    pid                  INTEGER NOT NULL,
+    started_at           TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
+    heartbeat_at         TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
+    ended_at             TEXT,
+    consecutive_failures INTEGER NOT NULL DEFAULT 0,
+    last_error           TEXT
+);
@@ -0,0 +1,13 @@
+-- Draft-only mode: a single persisted flag under which the full pipeline
+-- keeps generating and queueing items, but nothing is posted to X, not even
+-- approved items. Held items stay in the approval queue until it is lifted.
+-- On/off history lives in action_log.
+CREATE TABLE IF NOT EXISTS draft_only_mode (
+    id         INTEGER PRIMARY KEY CHECK (id = 1),
+    active     INTEGER NOT NULL DEFAULT 0,
+    reason     TEXT,                                -- Why the mode was turned on
+    source     TEXT,                                -- Who changed it: 'cli', 'api', ...
+    changed_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))
+);
+
+INSERT OR IGNORE INTO draft_only_mode (id, active) VALUES (1, 0);
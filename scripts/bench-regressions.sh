@@ -0,0 +1,65 @@
+#!/usr/bin/env bash
+# Track criterion benchmarks for the scoring, dedup and approval-queue hot paths.
+#
+# Usage:
+#   bash scripts/bench-regressions.sh save  [baseline]              # record a baseline
+#   bash scripts/bench-regressions.sh check [baseline] [max_pct]    # compare against it
+#
+# `check` fails when any benchmark's mean time grew by more than `max_pct`
+# percent (default 15) relative to the saved baseline (default "main").
+# Benchmarks missing from the baseline are reported but never fail.
+# Results live in target/criterion/; HTML reports are not generated.
+#
+# Exit codes:
+#   0 — baseline saved, or no regressions
+#   1 — at least one benchmark regressed
+#
+# Used in CI (bench-regression job) and before cutting a release.
+
+set -euo pipefail
+
+MODE="${1:-check}"
+BASELINE="${2:-main}"
+MAX_PCT="${3:-15}"
+BENCHES=(--bench scoring --bench dedup --bench approval_queue)
+
+cd "$(git rev-parse --show-toplevel)"
+
+case "$MODE" in
+  save)
+    cargo bench -p tuitbot-core "${BENCHES[@]}" -- --save-baseline "$BASELINE"
+    echo "Saved baseline '$BASELINE'"
+    exit 0
+    ;;
+  check)
+    cargo bench -p tuitbot-core "${BENCHES[@]}" -- --baseline-lenient "$BASELINE"
+    ;;
+  *)
+    echo "Unknown mode '$MODE' (expected save or check)" >&2
+    exit 2
+    ;;
+esac
+
+# ── Scan relative changes ───────────────────────────────────────────
+
+REGRESSED=0
+while IFS= read -r -d '' estimates; do
+  bench="${estimates#target/criterion/}"
+  bench="${bench%/change/estimates.json}"
+  # {"mean":{"confidence_interval":{...},"point_estimate":0.0123,...},...}
+  change=$(sed -E 's/^\{"mean":\{"confidence_interval":\{[^}]*\},"point_estimate":([-0-9.eE+]+).*/\1/' "$estimates")
+  pct=$(awk -v c="$change" 'BEGIN { printf "%.1f", c * 100 }')
+  if awk -v c="$change" -v max="$MAX_PCT" 'BEGIN { exit !(c * 100 > max) }'; then
+    echo "REGRESSION: $bench is ${pct}% slower than '$BASELINE'"
+    REGRESSED=1
+  else
+    echo "ok: $bench (${pct}%)"
+  fi
+done < <(find target/criterion -path '*/change/estimates.json' -print0)
+
+if [ "$REGRESSED" -ne 0 ]; then
+  echo ""
+  echo "Benchmarks regressed by more than ${MAX_PCT}%."
+  exit 1
+fi
+echo "No benchmark regressions above ${MAX_PCT}%."